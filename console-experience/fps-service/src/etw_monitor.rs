@@ -15,10 +15,11 @@
 /// - Administrator rights to create ETW sessions
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-// Tracing removed - Windows Services don't have stdout/stderr (Session 0)
+use tracing::{debug, info, warn};
 use windows::core::Result as WinResult;
 use windows::core::{GUID, PCWSTR, PWSTR};
 use windows::Win32::Foundation::{CloseHandle, FILETIME};
@@ -27,6 +28,10 @@ use windows::Win32::System::Threading::{
     OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
 };
 
+use crate::game_detector::{self, PresentMode};
+use crate::ipc_protocol::ProcessStats;
+use crate::pid_cache::{PidCache, PidCacheStats};
+
 /// DXGI provider GUID for capturing Present events
 /// Source: https://github.com/GameTechDev/PresentMon
 const DXGI_PROVIDER_GUID: GUID = GUID::from_values(
@@ -44,9 +49,43 @@ const DWM_PROVIDER_GUID: GUID = GUID::from_values(
     [0x99, 0xf4, 0x9e, 0x0b, 0x4e, 0xab, 0xf7, 0xc4],
 );
 
+/// Microsoft-Windows-DxgKrnl provider for present mode detection
+const DXGKRNL_PROVIDER_GUID: GUID = GUID::from_values(
+    0x802ec45a,
+    0x1e99,
+    0x4b83,
+    [0x99, 0x20, 0x87, 0xc9, 0x82, 0x77, 0xba, 0x9d],
+);
+
+/// DxgKrnl keywords: Base | Present
+const DXGKRNL_KEYWORDS: u64 = 0x1 | 0x800_0000;
+
+/// Microsoft-Windows-Kernel-Process provider for process exits
+const KERNEL_PROCESS_PROVIDER_GUID: GUID = GUID::from_values(
+    0x22fd2cd6,
+    0x1a6a,
+    0x4f87,
+    [0xa3, 0xfb, 0x6e, 0x46, 0xb6, 0xeb, 0x8f, 0x4d],
+);
+
+/// Kernel-Process keyword: WINEVENT_KEYWORD_PROCESS
+const KERNEL_PROCESS_KEYWORDS: u64 = 0x10;
+
+/// ProcessStop event ID (Kernel-Process)
+const PROCESS_STOP_EVENT_ID: u16 = 2;
+
 /// Present event ID (DXGI)
 const PRESENT_EVENT_ID: u16 = 42;
 
+/// DxgKrnl event IDs revealing the present mode (see PresentMon)
+mod dxgkrnl_event {
+    pub const BLIT_INFO: u16 = 166;
+    pub const FLIP_INFO: u16 = 168;
+    pub const PRESENT_HISTORY_START: u16 = 171;
+    pub const PRESENT_HISTORY_DETAILED_START: u16 = 215;
+    pub const FLIP_MULTI_PLANE_OVERLAY_INFO: u16 = 252;
+}
+
 /// Session name for our ETW trace
 const SESSION_NAME: &str = "BalamFpsSession";
 
@@ -55,6 +94,29 @@ const SESSION_NAME: &str = "BalamFpsSession";
 static FRAME_TIMES_PER_PROCESS: Lazy<Mutex<HashMap<u32, VecDeque<Instant>>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Frames older than this are dropped
+const FRAME_WINDOW: Duration = Duration::from_secs(5);
+
+/// Most processes with frame times at once
+const MAX_TRACKED_PROCESSES: usize = 64;
+
+/// Frame times sent in a stats frame history
+const FRAME_HISTORY_LEN: usize = 120;
+
+/// Process names are looked up again after this long (blacklist refresh)
+const PROCESS_NAME_TTL: Duration = Duration::from_secs(60);
+
+/// Most cached process names at once
+const PROCESS_NAME_CACHE_CAPACITY: usize = 256;
+
+/// Cache sizes and counters, sent over IPC for debugging
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default)]
+pub struct CacheStats {
+    pub process_names: PidCacheStats,
+    /// Processes with frame times
+    pub frame_processes: usize,
+}
+
 /// Blacklist of system processes to ignore (PID-based tracking uses process names via separate lookup)
 /// These process names should NOT be tracked for FPS
 const PROCESS_BLACKLIST: &[&str] = &[
@@ -106,16 +168,19 @@ impl EtwMonitor {
 
     /// Start ETW trace session
     pub fn start(&mut self) -> WinResult<()> {
-        // info!("🎬 Starting ETW trace session for DXGI/DWM events...");
+        info!("🎬 Starting ETW trace session for DXGI/DWM events...");
 
         // Try to start ETW session, but continue in simulation mode if it fails
         match self.try_start_etw() {
             Ok(session_handle) => {
-                // info!("✅ ETW trace session started successfully");
+                info!("✅ ETW trace session started successfully");
                 *self.session_handle.lock() = Some(session_handle);
             }
-            Err(_e) => {
-                // info!("⚠️ ETW session failed ({}), continuing in simulation mode", e);
+            Err(e) => {
+                warn!(
+                    "⚠️ ETW session failed ({}), continuing in simulation mode",
+                    e
+                );
             }
         }
 
@@ -123,7 +188,7 @@ impl EtwMonitor {
         *self.should_stop.lock() = false;
         self.spawn_processing_thread(CONTROLTRACE_HANDLE::default());
 
-        // info!("✅ FPS monitoring started");
+        info!("✅ FPS monitoring started");
         Ok(())
     }
 
@@ -143,7 +208,7 @@ impl EtwMonitor {
 
     /// Stop ETW trace session
     pub fn stop(&mut self) -> WinResult<()> {
-        // info!("🛑 Stopping ETW trace session...");
+        info!("🛑 Stopping ETW trace session...");
 
         // Signal stop
         *self.should_stop.lock() = true;
@@ -160,10 +225,30 @@ impl EtwMonitor {
             let _ = thread.join();
         }
 
-        // info!("✅ ETW trace session stopped");
+        info!("✅ ETW trace session stopped");
         Ok(())
     }
 
+    /// Whether `ProcessTrace` returned while the session was meant to run.
+    ///
+    /// It only returns when the session stops, so this means the session died
+    /// under the service (sleep, another tool stopping it) and events are lost.
+    pub fn processing_ended(&self) -> bool {
+        !*self.should_stop.lock()
+            && self.session_handle.lock().is_some()
+            && self
+                .processing_thread
+                .lock()
+                .as_ref()
+                .is_some_and(|thread| thread.is_finished())
+    }
+
+    /// Tears the trace session down and creates it again.
+    pub fn restart(&mut self) -> WinResult<()> {
+        self.stop()?;
+        self.start()
+    }
+
     /// Get current FPS and active game PID
     pub fn get_fps(&self) -> (f32, Option<u32>) {
         self.update_fps();
@@ -172,24 +257,7 @@ impl EtwMonitor {
 
     /// Get PID of the game with highest FPS (active game)
     fn get_active_game_pid(&self) -> Option<u32> {
-        let map = FRAME_TIMES_PER_PROCESS.lock();
-        let now = std::time::Instant::now();
-        let one_second_ago = now - std::time::Duration::from_secs(1);
-
-        let mut max_fps = 0.0f32;
-        let mut max_fps_pid = None;
-
-        for (&pid, times) in map.iter() {
-            let recent_frames = times.iter().filter(|&&time| time > one_second_ago).count();
-            let fps = recent_frames as f32;
-
-            if fps > max_fps && (10.0..=240.0).contains(&fps) {
-                max_fps = fps;
-                max_fps_pid = Some(pid);
-            }
-        }
-
-        max_fps_pid
+        active_process()
     }
 
     /// Stop any existing trace session with our name
@@ -234,7 +302,10 @@ impl EtwMonitor {
             )
             .ok()?;
 
-            // info!("📝 ETW trace session created with handle: {:?}", session_handle);
+            info!(
+                "📝 ETW trace session created with handle: {:?}",
+                session_handle
+            );
             Ok(session_handle)
         }
     }
@@ -284,7 +355,7 @@ impl EtwMonitor {
             )
             .ok()?;
 
-            // info!("✅ DXGI provider enabled");
+            info!("✅ DXGI provider enabled");
 
             // Enable DWM provider (for Vulkan/OpenGL support)
             EnableTraceEx2(
@@ -299,7 +370,42 @@ impl EtwMonitor {
             )
             .ok()?;
 
-            // info!("✅ DWM provider enabled");
+            info!("✅ DWM provider enabled");
+
+            // Enable DxgKrnl provider (present mode); optional, FPS works without it
+            let dxgkrnl = EnableTraceEx2(
+                session_handle,
+                &DXGKRNL_PROVIDER_GUID as *const GUID,
+                1, // EVENT_CONTROL_CODE_ENABLE_PROVIDER
+                4, // TRACE_LEVEL_INFORMATION
+                DXGKRNL_KEYWORDS,
+                0,
+                0,
+                Some(&params),
+            );
+            if dxgkrnl.is_ok() {
+                info!("✅ DxgKrnl provider enabled");
+            } else {
+                warn!("⚠️ DxgKrnl provider unavailable, present mode stays unknown");
+            }
+
+            // Enable Kernel-Process provider (exits clear per-PID caches); optional,
+            // caches still expire without it
+            let kernel_process = EnableTraceEx2(
+                session_handle,
+                &KERNEL_PROCESS_PROVIDER_GUID as *const GUID,
+                1, // EVENT_CONTROL_CODE_ENABLE_PROVIDER
+                4, // TRACE_LEVEL_INFORMATION
+                KERNEL_PROCESS_KEYWORDS,
+                0,
+                0,
+                Some(&params),
+            );
+            if kernel_process.is_ok() {
+                info!("✅ Kernel-Process provider enabled");
+            } else {
+                warn!("⚠️ Kernel-Process provider unavailable, caches rely on expiry");
+            }
 
             Ok(())
         }
@@ -330,7 +436,7 @@ impl EtwMonitor {
 
     /// Spawn background thread to process ETW events
     fn spawn_processing_thread(&mut self, _session_handle: CONTROLTRACE_HANDLE) {
-        let _should_stop = self.should_stop.clone();
+        let should_stop = self.should_stop.clone();
 
         let thread = std::thread::spawn(move || {
             unsafe {
@@ -376,9 +482,9 @@ impl EtwMonitor {
                 // Cleanup
                 CloseTrace(trace_handle);
 
-                if result != 0 {
-                    // ProcessTrace failed - typically ERROR_CANCELLED when service stops
-                    // debug!("ProcessTrace ended with code: {}", result);
+                // ERROR_CANCELLED is expected when the service stops the session
+                if !*should_stop.lock() {
+                    warn!("⚠️ ProcessTrace returned unexpectedly (code {})", result);
                 }
             }
         });
@@ -400,17 +506,7 @@ impl EtwMonitor {
         let one_second_ago = now - Duration::from_secs(1);
 
         // First pass: Clean up old frames and remove empty processes
-        map.retain(|_pid, times| {
-            // Remove frames older than 5 seconds
-            while times
-                .front()
-                .is_some_and(|&time| now.duration_since(time) > Duration::from_secs(5))
-            {
-                times.pop_front();
-            }
-            // Keep process only if it has recent frames
-            !times.is_empty()
-        });
+        evict_stale_frames(&mut map, now);
 
         // Second pass: Find process with highest FPS (within reasonable game range)
         let mut max_fps = 0.0f32;
@@ -456,17 +552,129 @@ static LAST_LOG: Lazy<Mutex<Instant>> = Lazy::new(|| Mutex::new(Instant::now()))
 
 /// Cache of process names (PID -> process name)
 /// Avoids repeated QueryFullProcessImageName calls
-static PROCESS_NAME_CACHE: Lazy<Mutex<HashMap<u32, String>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
+static PROCESS_NAME_CACHE: Lazy<Mutex<PidCache<String>>> =
+    Lazy::new(|| Mutex::new(PidCache::new(PROCESS_NAME_TTL, PROCESS_NAME_CACHE_CAPACITY)));
+
+/// PID of the game with highest FPS (within the game range)
+pub fn active_process() -> Option<u32> {
+    let map = FRAME_TIMES_PER_PROCESS.lock();
+    let now = Instant::now();
+    let one_second_ago = now - Duration::from_secs(1);
+
+    let mut max_fps = 0.0f32;
+    let mut max_fps_pid = None;
+
+    for (&pid, times) in map.iter() {
+        let recent_frames = times.iter().filter(|&&time| time > one_second_ago).count();
+        let fps = recent_frames as f32;
+
+        if fps > max_fps && (10.0..=240.0).contains(&fps) {
+            max_fps = fps;
+            max_fps_pid = Some(pid);
+        }
+    }
+
+    max_fps_pid
+}
+
+/// Processes that presented a frame in the last second
+pub fn tracked_pids() -> Vec<u32> {
+    let one_second_ago = Instant::now() - Duration::from_secs(1);
+    let mut pids: Vec<u32> = FRAME_TIMES_PER_PROCESS
+        .lock()
+        .iter()
+        .filter(|(_, times)| times.back().is_some_and(|&time| time > one_second_ago))
+        .map(|(&pid, _)| pid)
+        .collect();
+    pids.sort_unstable();
+    pids
+}
+
+/// Frame statistics of a process, if it presented recently
+pub fn stats(pid: u32) -> Option<ProcessStats> {
+    let map = FRAME_TIMES_PER_PROCESS.lock();
+    process_stats(pid, map.get(&pid)?, Instant::now())
+}
+
+/// Frame statistics from a process' frame timestamps
+fn process_stats(pid: u32, times: &VecDeque<Instant>, now: Instant) -> Option<ProcessStats> {
+    let one_second_ago = now - Duration::from_secs(1);
+    let recent: Vec<&Instant> = times
+        .iter()
+        .filter(|&&time| now.duration_since(time) <= FRAME_WINDOW)
+        .collect();
+    // (frame time, frame end) of each pair of consecutive frames
+    let frames: Vec<(f32, Instant)> = recent
+        .windows(2)
+        .map(|pair| {
+            (
+                pair[1].duration_since(*pair[0]).as_secs_f32() * 1000.0,
+                *pair[1],
+            )
+        })
+        .collect();
+    if frames.is_empty() {
+        return None;
+    }
+
+    let mean = |times: &[f32]| times.iter().sum::<f32>() / times.len().max(1) as f32;
+    let fps_of = |ms: f32| if ms > 0.0 { 1000.0 / ms } else { 0.0 };
+    let frame_times: Vec<f32> = frames.iter().map(|&(ms, _)| ms).collect();
+    let last_second: Vec<f32> = frames
+        .iter()
+        .filter(|&&(_, end)| end > one_second_ago)
+        .map(|&(ms, _)| ms)
+        .collect();
+
+    let mut slowest = frame_times.clone();
+    slowest.sort_unstable_by(|a, b| b.total_cmp(a));
+    let one_percent = slowest.len().div_ceil(100);
+
+    Some(ProcessStats {
+        pid,
+        fps: last_second.len() as f32,
+        avg_fps: fps_of(mean(&frame_times)),
+        fps_1_percent_low: fps_of(mean(&slowest[..one_percent])),
+        frame_time_ms: mean(&last_second),
+        frame_history_ms: frame_times[frame_times.len().saturating_sub(FRAME_HISTORY_LEN)..]
+            .to_vec(),
+    })
+}
+
+/// Current cache sizes and counters
+pub fn cache_stats() -> CacheStats {
+    CacheStats {
+        process_names: PROCESS_NAME_CACHE.lock().stats(),
+        frame_processes: FRAME_TIMES_PER_PROCESS.lock().len(),
+    }
+}
+
+/// Drops everything known about an exited process, so a new process
+/// reusing its PID starts clean
+fn forget_process(pid: u32) {
+    PROCESS_NAME_CACHE.lock().invalidate(pid);
+    FRAME_TIMES_PER_PROCESS.lock().remove(&pid);
+    game_detector::forget_process(pid);
+}
+
+/// Drops frames older than `FRAME_WINDOW` and processes left without any
+fn evict_stale_frames(map: &mut HashMap<u32, VecDeque<Instant>>, now: Instant) {
+    map.retain(|_pid, times| {
+        while times
+            .front()
+            .is_some_and(|&time| now.duration_since(time) > FRAME_WINDOW)
+        {
+            times.pop_front();
+        }
+        !times.is_empty()
+    });
+}
 
 /// Get process name from PID (cached)
 fn get_process_name(pid: u32) -> Option<String> {
     // Check cache first
-    {
-        let cache = PROCESS_NAME_CACHE.lock();
-        if let Some(name) = cache.get(&pid) {
-            return Some(name.clone());
-        }
+    if let Some(name) = PROCESS_NAME_CACHE.lock().get(pid, Instant::now()) {
+        return Some(name);
     }
 
     // Query process name
@@ -489,7 +697,9 @@ fn get_process_name(pid: u32) -> Option<String> {
             let name = path.rsplit('\\').next().unwrap_or(&path).to_lowercase();
 
             // Cache it
-            PROCESS_NAME_CACHE.lock().insert(pid, name.clone());
+            PROCESS_NAME_CACHE
+                .lock()
+                .insert(pid, name.clone(), Instant::now());
 
             Some(name)
         } else {
@@ -535,11 +745,28 @@ unsafe extern "system" fn event_record_callback(event_record: *mut EVENT_RECORD)
         let mut last = LAST_LOG.lock();
         let now = Instant::now();
         if now.duration_since(*last) > Duration::from_secs(5) {
-            // info!("📊 ETW callback called {} times (Provider: {:?}, EventID: {})", *count, provider_guid, event_id);
+            debug!(
+                "📊 ETW callback called {} times (Provider: {:?}, EventID: {})",
+                *count, provider_guid, event_id
+            );
             *last = now;
         }
     }
 
+    if provider_guid == KERNEL_PROCESS_PROVIDER_GUID {
+        if event_id == PROCESS_STOP_EVENT_ID {
+            forget_process(stopped_process_of(record).unwrap_or(process_id));
+        }
+        return;
+    }
+
+    if provider_guid == DXGKRNL_PROVIDER_GUID {
+        if let Some(mode) = present_mode_of(event_id) {
+            game_detector::register_present_mode(process_id, mode);
+        }
+        return;
+    }
+
     // ONLY capture DXGI Present events (DirectX games)
     // DWM events disabled because Desktop generates too many frames
     if provider_guid == DXGI_PROVIDER_GUID && event_id == PRESENT_EVENT_ID {
@@ -550,10 +777,28 @@ unsafe extern "system" fn event_record_callback(event_record: *mut EVENT_RECORD)
 
         // debug!("✅ DXGI Present event from PID {}", process_id);
 
+        if let Some((sync_interval, flags)) = present_params_of(record) {
+            game_detector::register_present_params(process_id, sync_interval, flags);
+        }
+
         // Track frames per process
+        let now = Instant::now();
         let mut map = FRAME_TIMES_PER_PROCESS.lock();
+        if !map.contains_key(&process_id) && map.len() >= MAX_TRACKED_PROCESSES {
+            // Make room: drop processes that stopped presenting, then the quietest
+            evict_stale_frames(&mut map, now);
+            if map.len() >= MAX_TRACKED_PROCESSES {
+                let quietest = map
+                    .iter()
+                    .min_by_key(|(_, times)| times.back().copied())
+                    .map(|(&pid, _)| pid);
+                if let Some(pid) = quietest {
+                    map.remove(&pid);
+                }
+            }
+        }
         let times = map.entry(process_id).or_default();
-        times.push_back(Instant::now());
+        times.push_back(now);
 
         // Keep only last 5 seconds per process (max ~500 frames @ 100fps)
         while times.len() > 500 {
@@ -561,3 +806,70 @@ unsafe extern "system" fn event_record_callback(event_record: *mut EVENT_RECORD)
         }
     }
 }
+
+/// PID of a Kernel-Process ProcessStop event (first payload field)
+unsafe fn stopped_process_of(record: &EVENT_RECORD) -> Option<u32> {
+    if record.UserData.is_null() || record.UserDataLength < 4 {
+        return None;
+    }
+    let data = std::slice::from_raw_parts(record.UserData as *const u8, 4);
+    data.try_into().ok().map(u32::from_le_bytes)
+}
+
+/// SyncInterval and Flags of a DXGI Present_Start event
+///
+/// Payload: pIDXGISwapChain (pointer, 4 bytes for 32-bit processes), Flags (u32),
+/// SyncInterval (i32).
+unsafe fn present_params_of(record: &EVENT_RECORD) -> Option<(u32, u32)> {
+    let is_32_bit = u32::from(record.EventHeader.Flags) & EVENT_HEADER_FLAG_32_BIT_HEADER != 0;
+    let pointer_size = if is_32_bit { 4 } else { 8 };
+    if record.UserData.is_null() || usize::from(record.UserDataLength) < pointer_size + 8 {
+        return None;
+    }
+    let data = std::slice::from_raw_parts(
+        record.UserData as *const u8,
+        usize::from(record.UserDataLength),
+    );
+    let word =
+        |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap_or_default());
+    Some((word(pointer_size + 4), word(pointer_size)))
+}
+
+/// Present mode shown by a DxgKrnl event
+///
+/// Legacy flips only happen in exclusive fullscreen; flip-model presents
+/// go through present history (composed) unless scanned out on a plane.
+fn present_mode_of(event_id: u16) -> Option<PresentMode> {
+    match event_id {
+        dxgkrnl_event::FLIP_INFO => Some(PresentMode::FullscreenExclusive),
+        dxgkrnl_event::FLIP_MULTI_PLANE_OVERLAY_INFO => Some(PresentMode::HardwareFlip),
+        dxgkrnl_event::BLIT_INFO
+        | dxgkrnl_event::PRESENT_HISTORY_START
+        | dxgkrnl_event::PRESENT_HISTORY_DETAILED_START => Some(PresentMode::Composed),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_stats() {
+        let now = Instant::now();
+        // A 100 ms hitch, then 99 frames at 60 FPS ending now
+        let hitch = now - Duration::from_micros(99 * 16_667);
+        let times: VecDeque<Instant> = std::iter::once(hitch - Duration::from_millis(100))
+            .chain((0..100).map(|frame| hitch + Duration::from_micros(frame * 16_667)))
+            .collect();
+
+        let stats = process_stats(7, &times, now).unwrap();
+        assert_eq!(stats.pid, 7);
+        assert!((stats.fps - 60.0).abs() <= 1.0);
+        assert!((stats.fps_1_percent_low - 10.0).abs() < 0.1);
+        assert!(stats.avg_fps > 50.0 && stats.avg_fps < 60.0);
+        assert_eq!(stats.frame_history_ms.len(), 100);
+
+        assert!(process_stats(7, &VecDeque::from([now]), now).is_none());
+    }
+}
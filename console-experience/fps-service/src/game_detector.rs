@@ -3,6 +3,8 @@
 /// Detects:
 /// - DirectX version (9, 11, 12) from ETW events and process analysis
 /// - Fullscreen Optimization (FSO) status from registry
+/// - Swapchain present mode from DxgKrnl ETW events
+/// - Present() sync interval and tearing flag from DXGI ETW events
 /// - Whether TOPMOST overlay will work
 ///
 /// # Strategy Selection
@@ -10,8 +12,10 @@
 /// - DX9 or (DX11 without FSO) → DLL injection needed
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 use windows::core::Result as WinResult;
 use windows::Win32::Foundation::CloseHandle;
 use windows::Win32::System::Threading::{
@@ -30,6 +34,53 @@ pub struct GameInfo {
     pub dx_version: u32, // 9, 11, or 12
     pub has_fso: bool,
     pub is_compatible_topmost: bool,
+    pub present_mode: PresentMode,
+    /// Sync interval of the last Present() (0 = no VSync), if seen recently
+    pub sync_interval: Option<u32>,
+    /// Last Present() passed DXGI_PRESENT_ALLOW_TEARING
+    pub allow_tearing: bool,
+}
+
+/// How a game's frames reach the screen
+///
+/// Mirrors PresentMon's classification, reduced to what matters for overlays:
+/// anything DWM composes (or can demote to composition) is covered by a
+/// TOPMOST window, a legacy flip owns the display.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PresentMode {
+    /// No present seen yet
+    #[default]
+    Unknown,
+    /// Flip model or blit, composed by DWM
+    Composed,
+    /// Flip model scanned out directly (independent flip / MPO)
+    HardwareFlip,
+    /// Legacy flip: exclusive fullscreen
+    FullscreenExclusive,
+}
+
+/// Present modes in detection priority (last wins)
+const PRESENT_MODES: [PresentMode; 3] = [
+    PresentMode::Composed,
+    PresentMode::HardwareFlip,
+    PresentMode::FullscreenExclusive,
+];
+
+/// Present mode evidence older than this is ignored
+const PRESENT_MODE_TTL: Duration = Duration::from_secs(2);
+
+/// DXGI_PRESENT_TEST: checks occlusion, nothing is presented
+const DXGI_PRESENT_TEST: u32 = 0x1;
+/// DXGI_PRESENT_ALLOW_TEARING: flip model may tear with sync interval 0
+const DXGI_PRESENT_ALLOW_TEARING: u32 = 0x200;
+
+/// Parameters of a process' last Present() call
+#[derive(Clone, Copy, Debug)]
+struct PresentParams {
+    sync_interval: u32,
+    allow_tearing: bool,
+    seen: Instant,
 }
 
 /// DirectX version cache (PID → DX version)
@@ -37,6 +88,16 @@ pub struct GameInfo {
 static DX_VERSION_CACHE: Lazy<Mutex<HashMap<u32, u32>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Present mode evidence (PID → last sighting of each `PRESENT_MODES` entry)
+/// Populated by DxgKrnl ETW events
+static PRESENT_MODE_CACHE: Lazy<Mutex<HashMap<u32, [Option<Instant>; 3]>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Last Present() parameters (PID → sync interval / flags)
+/// Populated by DXGI ETW events
+static PRESENT_PARAMS_CACHE: Lazy<Mutex<HashMap<u32, PresentParams>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 /// Get game information for a given process ID
 pub fn get_game_info(pid: u32) -> Option<GameInfo> {
     let (name, exe_path) = get_process_info(pid)?;
@@ -50,6 +111,8 @@ pub fn get_game_info(pid: u32) -> Option<GameInfo> {
     // Determine if TOPMOST overlay will work
     let is_compatible_topmost = is_topmost_compatible(dx_version, has_fso);
 
+    let params = get_present_params(pid, Instant::now());
+
     Some(GameInfo {
         pid,
         name,
@@ -57,6 +120,9 @@ pub fn get_game_info(pid: u32) -> Option<GameInfo> {
         dx_version,
         has_fso,
         is_compatible_topmost,
+        present_mode: get_present_mode(pid, Instant::now()),
+        sync_interval: params.map(|p| p.sync_interval),
+        allow_tearing: params.is_some_and(|p| p.allow_tearing),
     })
 }
 
@@ -66,6 +132,80 @@ pub fn register_dx_version(pid: u32, version: u32) {
     DX_VERSION_CACHE.lock().insert(pid, version);
 }
 
+/// Register a present seen in a DxgKrnl ETW event
+/// Called from the ETW callback, so it only touches the cache
+pub fn register_present_mode(pid: u32, mode: PresentMode) {
+    let Some(index) = PRESENT_MODES.iter().position(|&m| m == mode) else {
+        return;
+    };
+    let now = Instant::now();
+    let mut cache = PRESENT_MODE_CACHE.lock();
+    if !cache.contains_key(&pid) && cache.len() >= 64 {
+        // Drop processes that stopped presenting
+        cache.retain(|_, seen| {
+            seen.iter()
+                .flatten()
+                .any(|&time| now.duration_since(time) < PRESENT_MODE_TTL)
+        });
+    }
+    cache.entry(pid).or_default()[index] = Some(now);
+}
+
+/// Register the parameters of a DXGI Present() call
+/// Called from the ETW callback for every frame, so it only touches the cache
+pub fn register_present_params(pid: u32, sync_interval: u32, flags: u32) {
+    if flags & DXGI_PRESENT_TEST != 0 {
+        return;
+    }
+    let now = Instant::now();
+    let mut cache = PRESENT_PARAMS_CACHE.lock();
+    if !cache.contains_key(&pid) && cache.len() >= 64 {
+        // Drop processes that stopped presenting
+        cache.retain(|_, params| now.duration_since(params.seen) < PRESENT_MODE_TTL);
+    }
+    cache.insert(
+        pid,
+        PresentParams {
+            sync_interval,
+            allow_tearing: flags & DXGI_PRESENT_ALLOW_TEARING != 0,
+            seen: now,
+        },
+    );
+}
+
+/// Drop everything cached for an exited process (its PID may be reused)
+pub fn forget_process(pid: u32) {
+    DX_VERSION_CACHE.lock().remove(&pid);
+    PRESENT_MODE_CACHE.lock().remove(&pid);
+    PRESENT_PARAMS_CACHE.lock().remove(&pid);
+}
+
+/// Last Present() parameters of a process, if it presented recently
+fn get_present_params(pid: u32, now: Instant) -> Option<PresentParams> {
+    PRESENT_PARAMS_CACHE
+        .lock()
+        .get(&pid)
+        .copied()
+        .filter(|params| now.duration_since(params.seen) < PRESENT_MODE_TTL)
+}
+
+/// Current present mode of a process
+///
+/// A game switching modes (e.g. Alt+Enter) briefly shows both; the mode
+/// owning the display wins.
+fn get_present_mode(pid: u32, now: Instant) -> PresentMode {
+    let cache = PRESENT_MODE_CACHE.lock();
+    let Some(seen) = cache.get(&pid) else {
+        return PresentMode::Unknown;
+    };
+    PRESENT_MODES
+        .iter()
+        .zip(seen)
+        .rev()
+        .find(|(_, time)| time.is_some_and(|time| now.duration_since(time) < PRESENT_MODE_TTL))
+        .map_or(PresentMode::Unknown, |(&mode, _)| mode)
+}
+
 /// Get process name and executable path
 fn get_process_info(pid: u32) -> Option<(String, String)> {
     unsafe {
@@ -249,4 +389,41 @@ mod tests {
         assert_eq!(hash1, hash2); // Same path = same hash
         assert_ne!(hash1, hash3); // Different path = different hash
     }
+
+    #[test]
+    fn test_present_mode_priority() {
+        let pid = u32::MAX;
+        assert_eq!(get_present_mode(pid, Instant::now()), PresentMode::Unknown);
+
+        register_present_mode(pid, PresentMode::Composed);
+        assert_eq!(get_present_mode(pid, Instant::now()), PresentMode::Composed);
+
+        register_present_mode(pid, PresentMode::FullscreenExclusive);
+        register_present_mode(pid, PresentMode::Composed);
+        assert_eq!(
+            get_present_mode(pid, Instant::now()),
+            PresentMode::FullscreenExclusive
+        );
+
+        // Stale evidence expires
+        let later = Instant::now() + PRESENT_MODE_TTL;
+        assert_eq!(get_present_mode(pid, later), PresentMode::Unknown);
+    }
+
+    #[test]
+    fn test_present_params() {
+        let pid = u32::MAX - 1;
+        register_present_params(pid, 1, 0);
+        register_present_params(pid, 0, DXGI_PRESENT_TEST); // Occlusion check, ignored
+        let params = get_present_params(pid, Instant::now()).unwrap();
+        assert_eq!(params.sync_interval, 1);
+        assert!(!params.allow_tearing);
+
+        register_present_params(pid, 0, DXGI_PRESENT_ALLOW_TEARING);
+        let params = get_present_params(pid, Instant::now()).unwrap();
+        assert_eq!(params.sync_interval, 0);
+        assert!(params.allow_tearing);
+
+        assert!(get_present_params(pid, Instant::now() + PRESENT_MODE_TTL).is_none());
+    }
 }
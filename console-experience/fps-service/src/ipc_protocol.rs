@@ -0,0 +1,108 @@
+/// IPC Protocol - Framed query protocol of the FPS service
+///
+/// Spoken on `\\.\pipe\BalamFpsQuery` (duplex). Every frame is a
+/// little-endian `u32` length followed by that many bytes of JSON.
+/// Clients send `Request`s; the service answers each one with a `Message`
+/// and pushes `Message::Update` for subscribed PIDs on its own.
+///
+/// Kept in sync with `adapters/fps_service/protocol.rs` in the app.
+use serde::{Deserialize, Serialize};
+
+/// Frames larger than this are rejected (a frame history is ~1 KiB)
+pub const MAX_FRAME_BYTES: usize = 64 * 1024;
+
+/// Frame statistics of one process
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProcessStats {
+    pub pid: u32,
+    /// Frames presented in the last second
+    pub fps: f32,
+    /// Average over the whole frame window (5 seconds)
+    pub avg_fps: f32,
+    /// FPS of the slowest 1% of frames in the window
+    pub fps_1_percent_low: f32,
+    /// Mean frame time over the last second
+    pub frame_time_ms: f32,
+    /// Latest frame times, oldest first
+    pub frame_history_ms: Vec<f32>,
+}
+
+/// Client → service
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    /// Push `Update`s for this PID until unsubscribed or disconnected
+    Subscribe {
+        pid: u32,
+    },
+    Unsubscribe {
+        pid: u32,
+    },
+    GetStats {
+        pid: u32,
+    },
+    ListTrackedPids,
+}
+
+/// Service → client
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// Answer to `GetStats` (`None` without recent frames)
+    Stats {
+        pid: u32,
+        stats: Option<ProcessStats>,
+    },
+    /// Answer to `ListTrackedPids`; `active` is the likely game
+    TrackedPids {
+        pids: Vec<u32>,
+        active: Option<u32>,
+    },
+    /// Answer to `Subscribe` / `Unsubscribe`
+    Ok,
+    /// Pushed for subscribed PIDs with recent frames
+    Update {
+        stats: ProcessStats,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Length-prefixed JSON frame of `value`
+pub fn encode_frame<T: Serialize>(value: &T) -> Vec<u8> {
+    let body = serde_json::to_vec(value).unwrap_or_default();
+    let mut frame = Vec::with_capacity(4 + body.len());
+    frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&body);
+    frame
+}
+
+/// Body length announced by a frame header
+pub fn frame_length(header: [u8; 4]) -> Result<usize, String> {
+    let length = u32::from_le_bytes(header) as usize;
+    if length > MAX_FRAME_BYTES {
+        return Err(format!("Frame of {length} bytes exceeds {MAX_FRAME_BYTES}"));
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_round_trip() {
+        let frame = encode_frame(&Request::Subscribe { pid: 42 });
+        let length = frame_length(frame[..4].try_into().unwrap()).unwrap();
+        assert_eq!(length, frame.len() - 4);
+        assert_eq!(
+            std::str::from_utf8(&frame[4..]).unwrap(),
+            r#"{"type":"subscribe","pid":42}"#
+        );
+
+        let request: Request = serde_json::from_slice(&frame[4..]).unwrap();
+        assert_eq!(request, Request::Subscribe { pid: 42 });
+        assert!(frame_length((MAX_FRAME_BYTES as u32 + 1).to_le_bytes()).is_err());
+    }
+}
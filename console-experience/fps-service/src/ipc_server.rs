@@ -1,15 +1,50 @@
 /// IPC Server - Named Pipe server for sharing FPS data
 ///
 /// Robust named pipe implementation for IPC between service and Tauri app.
+///
+/// Two pipes:
+/// - `\\.\pipe\BalamFps`: one JSON snapshot per connection (overlay detector)
+/// - `\\.\pipe\BalamFpsQuery`: framed request/response protocol with
+///   per-PID stats and pushed updates (see `ipc_protocol`)
 use parking_lot::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::sync::Arc;
-// Tracing removed - Windows Services don't have stdout/stderr (Session 0)
+use std::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
 use windows::core::Result as WinResult;
+use windows::core::{s, w, PCSTR, PCWSTR};
 use windows::Win32::Foundation::*;
+use windows::Win32::Security::Authorization::{
+    ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1,
+};
+use windows::Win32::Security::{PSECURITY_DESCRIPTOR, SECURITY_ATTRIBUTES};
 use windows::Win32::Storage::FileSystem::*;
 use windows::Win32::System::Pipes::*;
 
+use crate::etw_monitor::{self, CacheStats};
+use crate::game_detector::PresentMode;
+use crate::ipc_protocol::{encode_frame, frame_length, Message, Request};
+
+/// Query pipe name
+const QUERY_PIPE_NAME: PCSTR = s!(r"\\.\pipe\BalamFpsQuery");
+
+/// Who may use the query pipe (SDDL): SYSTEM and administrators, plus
+/// read/write for interactive users so the unelevated app can send requests
+const QUERY_PIPE_SDDL: PCWSTR = w!("D:P(A;;GA;;;SY)(A;;GA;;;BA)(A;;GRGW;;;IU)");
+
+/// How often subscribed clients get an `Update`
+const PUSH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How often a client connection checks for requests
+const CLIENT_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Query pipe instances, one thread each (the app needs one or two)
+const MAX_QUERY_CLIENTS: u32 = 4;
+
+/// How long a client may take to send the rest of a started frame
+const FRAME_READ_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// Game state information
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct GameState {
@@ -18,6 +53,9 @@ pub struct GameState {
     pub dx_version: u32,
     pub has_fso: bool,
     pub is_compatible_topmost: bool,
+    pub present_mode: PresentMode,
+    pub sync_interval: Option<u32>,
+    pub allow_tearing: bool,
 }
 
 /// FPS data structure (expanded with game info)
@@ -25,6 +63,9 @@ pub struct GameState {
 pub struct FpsData {
     pub fps: f32,
     pub game_state: Option<GameState>, // None if no game running
+    /// Per-process caches of the ETW callback (debugging)
+    #[serde(default)]
+    pub cache_stats: CacheStats,
 }
 
 /// IPC Server for FPS sharing
@@ -62,13 +103,20 @@ impl IpcServer {
             })
             .map_err(|_| windows::core::Error::from_win32())?;
 
+        let running = self.running.clone();
+        std::thread::Builder::new()
+            .name("IPC Query Server".to_string())
+            .spawn(move || run_query_server(running))
+            .map_err(|_| windows::core::Error::from_win32())?;
+
         Ok(())
     }
 
     /// Stop IPC server
     pub fn stop(&mut self) -> WinResult<()> {
-        // info!("🛑 Stopping IPC server...");
+        info!("🛑 Stopping IPC server...");
         *self.running.lock() = false;
+        wake_query_server();
         Ok(())
     }
 
@@ -124,18 +172,22 @@ fn run_pipe_server(
                 PIPE_ACCESS_OUTBOUND | FILE_FLAG_FIRST_PIPE_INSTANCE,
                 PIPE_TYPE_MESSAGE | PIPE_READMODE_MESSAGE | PIPE_WAIT,
                 1,    // Only 1 instance (not unlimited) - prevents duplicate services
-                512,  // Out buffer
+                4096, // Out buffer (game state and cache stats)
                 0,    // In buffer (not needed for outbound)
                 0,    // Timeout
                 None, // Use default security (allows Everyone to read)
             );
 
             if let Err(e) = &pipe_handle {
-                // error!("❌ CreateNamedPipeA failed: {} (code: 0x{:08X})", e, e.code().0);
-                // error!("   This usually means:");
-                // error!("   1. Pipe already exists from crashed service - restart Windows");
-                // error!("   2. Another instance is running - stop other services");
-                // error!("   3. Permissions issue - verify LocalSystem can create pipes");
+                error!(
+                    "❌ CreateNamedPipeA failed: {} (code: 0x{:08X})",
+                    e,
+                    e.code().0
+                );
+                error!("   This usually means:");
+                error!("   1. Pipe already exists from crashed service - restart Windows");
+                error!("   2. Another instance is running - stop other services");
+                error!("   3. Permissions issue - verify LocalSystem can create pipes");
 
                 let _ = fs::OpenOptions::new()
                     .create(true)
@@ -165,7 +217,7 @@ fn run_pipe_server(
                 });
 
             if pipe_handle == INVALID_HANDLE_VALUE {
-                // error!("❌ Failed to create pipe");
+                error!("❌ Failed to create pipe");
                 std::thread::sleep(std::time::Duration::from_secs(1));
                 continue;
             }
@@ -200,10 +252,17 @@ fn run_pipe_server(
                     dx_version: info.dx_version,
                     has_fso: info.has_fso,
                     is_compatible_topmost: info.is_compatible_topmost,
+                    present_mode: info.present_mode,
+                    sync_interval: info.sync_interval,
+                    allow_tearing: info.allow_tearing,
                 })
             });
 
-            let data = FpsData { fps, game_state };
+            let data = FpsData {
+                fps,
+                game_state,
+                cache_stats: crate::etw_monitor::cache_stats(),
+            };
             let json = serde_json::to_string(&data).unwrap_or_default();
             let response = json.as_bytes();
 
@@ -213,9 +272,9 @@ fn run_pipe_server(
                 WriteFile(pipe_handle, Some(response), Some(&mut bytes_written), None);
 
             if write_result.is_ok() {
-                // debug!("📤 Sent FPS: {:.1}", fps);
+                debug!("📤 Sent FPS: {:.1}", fps);
             } else {
-                // error!("❌ Failed to write to pipe");
+                error!("❌ Failed to write to pipe");
             }
 
             // Cleanup
@@ -231,3 +290,230 @@ fn run_pipe_server(
 
     Ok(())
 }
+
+/// Security attributes of the query pipe. The descriptor is built once and
+/// lives as long as the service.
+fn query_pipe_security() -> WinResult<SECURITY_ATTRIBUTES> {
+    let mut descriptor = PSECURITY_DESCRIPTOR::default();
+    unsafe {
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(
+            QUERY_PIPE_SDDL,
+            SDDL_REVISION_1,
+            &mut descriptor,
+            None,
+        )?;
+    }
+    Ok(SECURITY_ATTRIBUTES {
+        nLength: std::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor.0,
+        bInheritHandle: FALSE,
+    })
+}
+
+/// Accepts query clients, each served on its own thread
+fn run_query_server(running: Arc<Mutex<bool>>) {
+    let security = match query_pipe_security() {
+        Ok(security) => security,
+        Err(e) => {
+            error!("❌ Failed to build query pipe security: {}", e);
+            return;
+        }
+    };
+
+    // The first instance must be ours: a process that created the pipe
+    // before the service would otherwise receive the app's requests
+    let mut first_instance = FILE_FLAG_FIRST_PIPE_INSTANCE;
+
+    while *running.lock() {
+        let pipe = unsafe {
+            CreateNamedPipeA(
+                QUERY_PIPE_NAME,
+                PIPE_ACCESS_DUPLEX | first_instance,
+                PIPE_TYPE_BYTE | PIPE_READMODE_BYTE | PIPE_WAIT,
+                MAX_QUERY_CLIENTS,
+                4096, // Out buffer
+                4096, // In buffer
+                0,    // Timeout
+                Some(&security),
+            )
+        };
+        let pipe = match pipe {
+            Ok(pipe) => pipe,
+            Err(e) if e.code() == ERROR_PIPE_BUSY.to_hresult() => {
+                // Every instance has a client: wait for one to leave
+                std::thread::sleep(PUSH_INTERVAL);
+                continue;
+            }
+            Err(e) => {
+                error!("❌ Failed to create query pipe: {}", e);
+                std::thread::sleep(Duration::from_secs(5));
+                continue;
+            }
+        };
+        first_instance = FILE_FLAGS_AND_ATTRIBUTES(0);
+
+        // ERROR_PIPE_CONNECTED means client was already connected, which is success
+        let connected = match unsafe { ConnectNamedPipe(pipe, None) } {
+            Ok(_) => true,
+            Err(e) => e.code() == ERROR_PIPE_CONNECTED.to_hresult(),
+        };
+        if !connected || !*running.lock() {
+            // Also how `wake_query_server` ends the wait on stop
+            unsafe {
+                let _ = DisconnectNamedPipe(pipe);
+                let _ = CloseHandle(pipe);
+            }
+            continue;
+        }
+
+        // HANDLE is not Send: the raw value crosses to the client thread
+        let raw = pipe.0;
+        let running = running.clone();
+        let spawned = std::thread::Builder::new()
+            .name("IPC Query Client".to_string())
+            .spawn(move || serve_query_client(HANDLE(raw), &running));
+        if spawned.is_err() {
+            warn!("⚠️ Failed to start query client thread");
+            let _ = unsafe { CloseHandle(pipe) };
+        }
+    }
+}
+
+/// Connects to the query pipe once so a listener blocked in
+/// `ConnectNamedPipe` sees the stop flag
+fn wake_query_server() {
+    let client = unsafe {
+        CreateFileA(
+            QUERY_PIPE_NAME,
+            (GENERIC_READ | GENERIC_WRITE).0,
+            FILE_SHARE_NONE,
+            None,
+            OPEN_EXISTING,
+            FILE_ATTRIBUTE_NORMAL,
+            HANDLE::default(),
+        )
+    };
+    if let Ok(client) = client {
+        let _ = unsafe { CloseHandle(client) };
+    }
+}
+
+/// Answers a client's requests and pushes its subscriptions until it leaves
+fn serve_query_client(pipe: HANDLE, running: &Mutex<bool>) {
+    debug!("✅ Query client connected");
+    let mut subscriptions = BTreeSet::new();
+    let mut next_push = Instant::now();
+
+    while *running.lock() {
+        let mut available = 0u32;
+        if unsafe { PeekNamedPipe(pipe, None, 0, None, Some(&mut available), None) }.is_err() {
+            break; // Client disconnected
+        }
+
+        if available >= 4 {
+            let body = match read_frame(pipe) {
+                Ok(body) => body,
+                Err(message) => {
+                    warn!("⚠️ Dropping query client: {}", message);
+                    break;
+                }
+            };
+            let reply = match serde_json::from_slice(&body) {
+                Ok(request) => handle_request(request, &mut subscriptions),
+                Err(e) => Message::Error {
+                    message: format!("Invalid request: {e}"),
+                },
+            };
+            if write_message(pipe, &reply).is_err() {
+                break;
+            }
+            continue;
+        }
+
+        if !subscriptions.is_empty() && Instant::now() >= next_push {
+            next_push = Instant::now() + PUSH_INTERVAL;
+            let updates = subscriptions
+                .iter()
+                .filter_map(|&pid| etw_monitor::stats(pid))
+                .map(|stats| Message::Update { stats });
+            if updates
+                .map(|update| write_message(pipe, &update))
+                .any(|r| r.is_err())
+            {
+                break;
+            }
+        }
+
+        std::thread::sleep(CLIENT_POLL_INTERVAL);
+    }
+
+    unsafe {
+        let _ = DisconnectNamedPipe(pipe);
+        let _ = CloseHandle(pipe);
+    }
+    debug!("🔌 Query client disconnected");
+}
+
+fn handle_request(request: Request, subscriptions: &mut BTreeSet<u32>) -> Message {
+    match request {
+        Request::Subscribe { pid } => {
+            subscriptions.insert(pid);
+            Message::Ok
+        }
+        Request::Unsubscribe { pid } => {
+            subscriptions.remove(&pid);
+            Message::Ok
+        }
+        Request::GetStats { pid } => Message::Stats {
+            pid,
+            stats: etw_monitor::stats(pid),
+        },
+        Request::ListTrackedPids => Message::TrackedPids {
+            pids: etw_monitor::tracked_pids(),
+            active: etw_monitor::active_process(),
+        },
+    }
+}
+
+/// Body of the next frame sent by the client
+fn read_frame(pipe: HANDLE) -> Result<Vec<u8>, String> {
+    let mut header = [0u8; 4];
+    read_exact(pipe, &mut header)?;
+    let mut body = vec![0u8; frame_length(header)?];
+    read_exact(pipe, &mut body)?;
+    Ok(body)
+}
+
+/// Reads only bytes already in the pipe, so a client that stops mid-frame
+/// is dropped after `FRAME_READ_TIMEOUT` instead of blocking its thread
+fn read_exact(pipe: HANDLE, mut buffer: &mut [u8]) -> Result<(), String> {
+    let deadline = Instant::now() + FRAME_READ_TIMEOUT;
+    while !buffer.is_empty() {
+        let mut available = 0u32;
+        unsafe { PeekNamedPipe(pipe, None, 0, None, Some(&mut available), None) }
+            .map_err(|_| "Client closed the pipe".to_string())?;
+        if available == 0 {
+            if Instant::now() >= deadline {
+                return Err("Timed out reading a frame".to_string());
+            }
+            std::thread::sleep(CLIENT_POLL_INTERVAL);
+            continue;
+        }
+
+        let len = buffer.len().min(available as usize);
+        let mut read = 0u32;
+        unsafe { ReadFile(pipe, Some(&mut buffer[..len]), Some(&mut read), None) }
+            .map_err(|e| format!("Read failed: {e}"))?;
+        if read == 0 {
+            return Err("Client closed the pipe".to_string());
+        }
+        buffer = &mut buffer[read as usize..];
+    }
+    Ok(())
+}
+
+fn write_message(pipe: HANDLE, message: &Message) -> WinResult<()> {
+    let frame = encode_frame(message);
+    let mut written = 0u32;
+    unsafe { WriteFile(pipe, Some(&frame), Some(&mut written), None) }
+}
@@ -0,0 +1,47 @@
+/// Service logging
+///
+/// The service runs as LocalSystem in Session 0: there is no console, so
+/// tracing goes to a daily rotated file in `%ProgramData%\Balam\logs`
+/// (`fps-service.<date>.log`, last 7 kept). The Balam app reads this folder
+/// for `get_recent_logs` and the diagnostics export, so the file name and
+/// line format must stay in sync with `infrastructure::logging` in the app.
+use std::fs;
+use std::path::PathBuf;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+
+const MAX_LOG_FILES: usize = 7;
+
+fn log_dir() -> PathBuf {
+    std::env::var_os("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"))
+        .join("Balam")
+        .join("logs")
+}
+
+/// Installs the file subscriber.
+///
+/// Returns `None` if the log folder is not writable; the service keeps
+/// running without logs (the heartbeat file still works). Keep the guard
+/// alive until the service exits so buffered lines are flushed.
+pub fn init() -> Option<WorkerGuard> {
+    let dir = log_dir();
+    fs::create_dir_all(&dir).ok()?;
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("fps-service")
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(&dir)
+        .ok()?;
+
+    let (writer, guard) = tracing_appender::non_blocking(appender);
+    tracing_subscriber::fmt()
+        .with_writer(writer)
+        .with_max_level(LevelFilter::INFO)
+        .with_ansi(false)
+        .init();
+    Some(guard)
+}
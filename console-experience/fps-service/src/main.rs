@@ -18,18 +18,24 @@
 /// ```
 mod etw_monitor;
 mod game_detector;
+mod ipc_protocol;
 mod ipc_server;
+mod logging;
+mod pid_cache;
 mod service;
 
-// Tracing removed - Windows Services don't have stdout/stderr (Session 0)
+use tracing::{error, info};
 
 fn main() {
-    // NO LOGGING for Windows Service - stdout/stderr don't exist!
-    // Use heartbeat file instead for debugging
+    // No console in Session 0: logs go to %ProgramData%\Balam\logs
+    let log_guard = logging::init();
+    info!("🚀 Balam FPS Service v{} starting", env!("CARGO_PKG_VERSION"));
 
     // Run as Windows Service
-    if let Err(_e) = service::run() {
-        // Can't log errors - service has no console
+    if let Err(e) = service::run() {
+        error!("❌ Service dispatcher failed: {}", e);
+        // process::exit skips destructors: flush pending lines first
+        drop(log_guard);
         std::process::exit(1);
     }
 }
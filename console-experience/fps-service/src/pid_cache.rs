@@ -0,0 +1,142 @@
+/// PID Cache - Bounded per-process cache for the ETW callback
+///
+/// Windows reuses PIDs, so a value cached for a PID can describe a process
+/// that has since exited. Entries expire after a TTL, are dropped when the
+/// process exits, and the oldest go first once the capacity is reached.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Counters of a cache, sent over IPC for debugging
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PidCacheStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// Entries dropped for age or capacity
+    pub evictions: u64,
+    /// Entries dropped because their process exited
+    pub invalidations: u64,
+}
+
+struct Entry<V> {
+    value: V,
+    inserted: Instant,
+}
+
+/// PID-keyed cache with a time-to-live and a size cap
+pub struct PidCache<V> {
+    entries: HashMap<u32, Entry<V>>,
+    ttl: Duration,
+    capacity: usize,
+    stats: PidCacheStats,
+}
+
+impl<V: Clone> PidCache<V> {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            ttl,
+            capacity,
+            stats: PidCacheStats::default(),
+        }
+    }
+
+    /// Cached value of a process, if still fresh
+    pub fn get(&mut self, pid: u32, now: Instant) -> Option<V> {
+        let fresh = match self.entries.get(&pid) {
+            Some(entry) if now.duration_since(entry.inserted) < self.ttl => {
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                self.entries.remove(&pid);
+                self.stats.evictions += 1;
+                None
+            }
+            None => None,
+        };
+        if fresh.is_some() {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+        }
+        fresh
+    }
+
+    /// Caches a value, making room first when full
+    pub fn insert(&mut self, pid: u32, value: V, now: Instant) {
+        if !self.entries.contains_key(&pid) && self.entries.len() >= self.capacity {
+            let before = self.entries.len();
+            let ttl = self.ttl;
+            self.entries
+                .retain(|_, entry| now.duration_since(entry.inserted) < ttl);
+            if self.entries.len() >= self.capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.inserted)
+                    .map(|(&pid, _)| pid);
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.stats.evictions += (before - self.entries.len()) as u64;
+        }
+        self.entries.insert(
+            pid,
+            Entry {
+                value,
+                inserted: now,
+            },
+        );
+    }
+
+    /// Forgets an exited process
+    pub fn invalidate(&mut self, pid: u32) {
+        if self.entries.remove(&pid).is_some() {
+            self.stats.invalidations += 1;
+        }
+    }
+
+    pub fn stats(&self) -> PidCacheStats {
+        PidCacheStats {
+            entries: self.entries.len(),
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_expire_are_capped_and_invalidated() {
+        let now = Instant::now();
+        let mut cache = PidCache::new(Duration::from_secs(60), 2);
+        cache.insert(1, "game.exe", now);
+        cache.insert(2, "dwm.exe", now + Duration::from_secs(1));
+        assert_eq!(cache.get(1, now), Some("game.exe"));
+
+        // Full: the oldest entry makes room
+        cache.insert(3, "explorer.exe", now + Duration::from_secs(2));
+        assert_eq!(cache.get(1, now), None);
+        assert_eq!(cache.get(3, now), Some("explorer.exe"));
+
+        // PID reused after the process exited
+        cache.invalidate(3);
+        assert_eq!(cache.get(3, now), None);
+
+        assert_eq!(cache.get(2, now + Duration::from_secs(61)), None);
+        assert_eq!(
+            cache.stats(),
+            PidCacheStats {
+                entries: 0,
+                hits: 2,
+                misses: 3,
+                evictions: 2,
+                invalidations: 1,
+            }
+        );
+    }
+}
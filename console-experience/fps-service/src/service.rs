@@ -1,15 +1,33 @@
 /// Windows Service implementation
 ///
-/// Handles service lifecycle (start, stop, pause) and coordinates
-/// ETW monitoring and IPC server.
+/// Handles service lifecycle (start, stop, pause, power events) and
+/// coordinates ETW monitoring and IPC server.
+///
+/// The ETW session does not survive sleep/hibernate: it is torn down on
+/// suspend and recreated on resume, and a health check recreates it whenever
+/// `ProcessTrace` returns on its own.
 use crate::{etw_monitor::EtwMonitor, ipc_server::IpcServer};
 use parking_lot::Mutex;
+use std::ffi::c_void;
 use std::sync::Arc;
-use std::time::Duration;
-// Tracing removed - Windows Services don't have stdout/stderr (Session 0)
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
 use windows::core::Result as WinResult;
 use windows::core::PCWSTR;
+use windows::Win32::Foundation::{ERROR_CALL_NOT_IMPLEMENTED, NO_ERROR};
 use windows::Win32::System::Services::*;
+use windows::Win32::UI::WindowsAndMessaging::{PBT_APMRESUMEAUTOMATIC, PBT_APMSUSPEND};
+
+/// User-defined control code sent by the Balam app after the PC resumes
+/// (kept in sync with `FPS_SERVICE_CONTROL_RESTART_ETW` in the app).
+const CONTROL_RESTART_ETW: u32 = 128;
+
+/// How often the processing thread is checked on.
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Longest wait between restarts of a session that keeps dying.
+const MAX_RESTART_DELAY: Duration = Duration::from_secs(5 * 60);
+/// A restarted session alive this long resets the backoff.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
 
 static mut SERVICE_STATUS_HANDLE: SERVICE_STATUS_HANDLE = unsafe { std::mem::zeroed() };
 static SERVICE_STATE: Mutex<Option<Arc<ServiceState>>> = Mutex::new(None);
@@ -20,6 +38,57 @@ struct ServiceState {
     etw_monitor: Arc<Mutex<EtwMonitor>>,
     ipc_server: Arc<Mutex<IpcServer>>,
     should_stop: Arc<Mutex<bool>>,
+    restart_etw: Arc<Mutex<bool>>,
+    suspend_etw: Arc<Mutex<bool>>,
+}
+
+/// Backoff of the processing thread health check.
+struct HealthCheck {
+    /// Restarts since the session last ran stable
+    failures: u32,
+    last_restart: Option<Instant>,
+    next_check: Instant,
+}
+
+impl HealthCheck {
+    fn new(now: Instant) -> Self {
+        Self {
+            failures: 0,
+            last_restart: None,
+            next_check: now + HEALTH_CHECK_INTERVAL,
+        }
+    }
+
+    /// Whether a session whose processing ended (`processing_ended`) should be
+    /// restarted now; schedules the next check either way.
+    fn should_restart(&mut self, processing_ended: bool, now: Instant) -> bool {
+        if now < self.next_check {
+            return false;
+        }
+        if !processing_ended {
+            if self
+                .last_restart
+                .is_some_and(|restarted| now.duration_since(restarted) >= STABLE_AFTER)
+            {
+                self.failures = 0;
+                self.last_restart = None;
+            }
+            self.next_check = now + HEALTH_CHECK_INTERVAL;
+            return false;
+        }
+        self.failures += 1;
+        self.last_restart = Some(now);
+        self.next_check = now + restart_delay(self.failures);
+        true
+    }
+}
+
+/// Wait before checking a session restarted `failures` times in a row
+/// (5s, 10s, 20s, ... up to 5 minutes).
+fn restart_delay(failures: u32) -> Duration {
+    HEALTH_CHECK_INTERVAL
+        .saturating_mul(1u32 << failures.saturating_sub(1).min(16))
+        .min(MAX_RESTART_DELAY)
 }
 
 /// Main service entry point
@@ -46,8 +115,8 @@ pub fn run() -> WinResult<()> {
 
 /// Service main function
 unsafe extern "system" fn service_main(_argc: u32, _argv: *mut windows::core::PWSTR) {
-    if let Err(_e) = service_main_impl() {
-        // error!("Service main failed: {}", e);
+    if let Err(e) = service_main_impl() {
+        error!("Service main failed: {}", e);
         // CRITICAL: Report service stopped on error
         let _ = report_status(SERVICE_STOPPED, 1, 0);
     }
@@ -57,9 +126,11 @@ fn service_main_impl() -> WinResult<()> {
     // Register control handler
     unsafe {
         let service_name = to_wide("BalamFpsService");
-        SERVICE_STATUS_HANDLE = RegisterServiceCtrlHandlerW(
+        // The Ex handler is the only one that receives power events
+        SERVICE_STATUS_HANDLE = RegisterServiceCtrlHandlerExW(
             PCWSTR::from_raw(service_name.as_ptr()),
             Some(service_control_handler),
+            None,
         )?;
     }
 
@@ -70,11 +141,15 @@ fn service_main_impl() -> WinResult<()> {
     let etw_monitor = Arc::new(Mutex::new(EtwMonitor::new()?));
     let ipc_server = Arc::new(Mutex::new(IpcServer::new()?));
     let should_stop = Arc::new(Mutex::new(false));
+    let restart_etw = Arc::new(Mutex::new(false));
+    let suspend_etw = Arc::new(Mutex::new(false));
 
     let state = Arc::new(ServiceState {
         etw_monitor: etw_monitor.clone(),
         ipc_server: ipc_server.clone(),
         should_stop: should_stop.clone(),
+        restart_etw: restart_etw.clone(),
+        suspend_etw: suspend_etw.clone(),
     });
 
     *SERVICE_STATE.lock() = Some(state);
@@ -95,7 +170,31 @@ fn service_main_impl() -> WinResult<()> {
     }
 
     // Main loop - keep service alive
+    let mut health = HealthCheck::new(Instant::now());
+    let mut suspended = false;
     while !*should_stop.lock() {
+        // The trace session does not survive sleep: stop it before, recreate it after
+        if std::mem::take(&mut *suspend_etw.lock()) && !suspended {
+            info!("💤 System suspending, stopping ETW trace session");
+            let _ = etw_monitor.lock().stop();
+            suspended = true;
+        }
+        if std::mem::take(&mut *restart_etw.lock()) {
+            info!("🔁 Restarting ETW trace session");
+            let _ = etw_monitor.lock().restart();
+            suspended = false;
+            health = HealthCheck::new(Instant::now());
+        } else if !suspended {
+            let ended = etw_monitor.lock().processing_ended();
+            if health.should_restart(ended, Instant::now()) {
+                warn!(
+                    "⚠️ ETW processing thread ended, restarting the trace session (attempt {})",
+                    health.failures
+                );
+                let _ = etw_monitor.lock().restart();
+            }
+        }
+
         // Simplified loop - just sleep
         // TODO: Re-enable FPS monitoring once we verify service stays running
 
@@ -132,16 +231,43 @@ fn service_main_impl() -> WinResult<()> {
 }
 
 /// Service control handler
-unsafe extern "system" fn service_control_handler(control: u32) {
+unsafe extern "system" fn service_control_handler(
+    control: u32,
+    event_type: u32,
+    _event_data: *mut c_void,
+    _context: *mut c_void,
+) -> u32 {
     match control {
         1 => {
             // SERVICE_CONTROL_STOP
-            // info!("🛑 Received STOP signal");
+            info!("🛑 Received STOP signal");
             if let Some(state) = SERVICE_STATE.lock().as_ref() {
                 *state.should_stop.lock() = true;
             }
             let _ = report_status(SERVICE_STOP_PENDING, 0, 3000);
         }
+        CONTROL_RESTART_ETW => {
+            info!("🔁 Received ETW restart request");
+            if let Some(state) = SERVICE_STATE.lock().as_ref() {
+                *state.restart_etw.lock() = true;
+            }
+        }
+        SERVICE_CONTROL_POWEREVENT => match event_type {
+            PBT_APMSUSPEND => {
+                info!("💤 Received suspend power event");
+                if let Some(state) = SERVICE_STATE.lock().as_ref() {
+                    *state.suspend_etw.lock() = true;
+                }
+            }
+            // Sent on every resume, with or without a user present
+            PBT_APMRESUMEAUTOMATIC => {
+                info!("⏰ Received resume power event");
+                if let Some(state) = SERVICE_STATE.lock().as_ref() {
+                    *state.restart_etw.lock() = true;
+                }
+            }
+            _ => {}
+        },
         2 => { // SERVICE_CONTROL_PAUSE
         }
         3 => { // SERVICE_CONTROL_CONTINUE
@@ -149,9 +275,11 @@ unsafe extern "system" fn service_control_handler(control: u32) {
         4 => { // SERVICE_CONTROL_INTERROGATE
         }
         _ => {
-            // warn!("⚠️ Unhandled control code: {}", control);
+            warn!("⚠️ Unhandled control code: {}", control);
+            return ERROR_CALL_NOT_IMPLEMENTED.0;
         }
     }
+    NO_ERROR.0
 }
 
 /// Report service status to Windows
@@ -169,7 +297,7 @@ fn report_status(
             dwControlsAccepted: if current_state == SERVICE_START_PENDING {
                 Default::default()
             } else {
-                SERVICE_ACCEPT_STOP
+                SERVICE_ACCEPT_STOP | SERVICE_ACCEPT_POWEREVENT
             },
             dwWin32ExitCode: exit_code,
             dwServiceSpecificExitCode: 0,
@@ -192,3 +320,30 @@ fn report_status(
 fn to_wide(s: &str) -> Vec<u16> {
     s.encode_utf16().chain(std::iter::once(0)).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_check_backs_off_and_recovers() {
+        assert_eq!(restart_delay(1), Duration::from_secs(5));
+        assert_eq!(restart_delay(3), Duration::from_secs(20));
+        assert_eq!(restart_delay(40), MAX_RESTART_DELAY);
+
+        let start = Instant::now();
+        let mut health = HealthCheck::new(start);
+        assert!(!health.should_restart(true, start));
+
+        let first = start + HEALTH_CHECK_INTERVAL;
+        assert!(health.should_restart(true, first));
+        // Still dead right after the restart: wait for the backoff
+        assert!(!health.should_restart(true, first + Duration::from_secs(1)));
+        assert!(health.should_restart(true, first + Duration::from_secs(5)));
+        assert_eq!(health.failures, 2);
+
+        // Alive for a minute: the next failure starts the backoff over
+        assert!(!health.should_restart(false, first + Duration::from_secs(70)));
+        assert_eq!(health.failures, 0);
+    }
+}
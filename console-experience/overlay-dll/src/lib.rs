@@ -18,7 +18,9 @@
 /// - Only injected into whitelisted games
 /// - Minimal performance impact
 /// - Clean unhooking on detach
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+mod overlay_state;
+
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering};
 use windows::Win32::Foundation::{BOOL, HINSTANCE};
 use windows::Win32::System::SystemServices::{
     DLL_PROCESS_ATTACH, DLL_PROCESS_DETACH, DLL_THREAD_ATTACH, DLL_THREAD_DETACH,
@@ -28,6 +30,13 @@ use windows::Win32::System::SystemServices::{
 static OVERLAY_VISIBLE: AtomicBool = AtomicBool::new(true);
 static OVERLAY_FPS: AtomicU32 = AtomicU32::new(0);
 static HOOKS_INSTALLED: AtomicBool = AtomicBool::new(false);
+/// HUD level: 0 = off, 1 = FPS, 2 = FPS + battery, 3 = full
+static OVERLAY_LEVEL: AtomicU8 = AtomicU8::new(3);
+/// Frames rendered since the level was last read from Balam
+static FRAMES_SINCE_SYNC: AtomicU32 = AtomicU32::new(0);
+
+/// Frames between reads of the level Balam publishes
+const LEVEL_SYNC_FRAMES: u32 = 60;
 
 /// DLL entry point
 ///
@@ -105,6 +114,21 @@ pub extern "C" fn update_fps(fps: f32) {
     OVERLAY_FPS.store(fps.to_bits(), Ordering::SeqCst);
 }
 
+/// Set the HUD level (0 = off, 1 = FPS, 2 = FPS + battery, 3 = full)
+///
+/// Balam's `overlay-level-changed` reaches the DLL through shared memory;
+/// this is the same state for direct calls.
+#[no_mangle]
+pub extern "C" fn set_overlay_level(level: u8) {
+    OVERLAY_LEVEL.store(level.min(3), Ordering::SeqCst);
+}
+
+/// Get current HUD level
+#[no_mangle]
+pub extern "C" fn get_overlay_level() -> u8 {
+    OVERLAY_LEVEL.load(Ordering::SeqCst)
+}
+
 /// Get current overlay visibility
 #[no_mangle]
 pub extern "C" fn is_overlay_visible() -> bool {
@@ -124,14 +148,19 @@ pub extern "C" fn get_fps() -> f32 {
 /// This will be implemented with DirectX + ImGui rendering.
 #[no_mangle]
 pub extern "C" fn render_overlay() {
-    if !OVERLAY_VISIBLE.load(Ordering::SeqCst) {
+    if FRAMES_SINCE_SYNC.fetch_add(1, Ordering::Relaxed) % LEVEL_SYNC_FRAMES == 0 {
+        if let Some(level) = overlay_state::read_level() {
+            set_overlay_level(level);
+        }
+    }
+    if !OVERLAY_VISIBLE.load(Ordering::SeqCst) || get_overlay_level() == 0 {
         return;
     }
 
     // TODO: Render ImGui UI
     // 1. Get DirectX device from hook context
     // 2. Initialize ImGui context if needed
-    // 3. Build UI (FPS counter, menu, etc.)
+    // 3. Build UI for the level (FPS, + battery, full HUD)
     // 4. Render ImGui to DirectX
 
     // Placeholder implementation
@@ -0,0 +1,46 @@
+/// Overlay State - HUD level published by Balam
+///
+/// Balam owns `Local\BalamOverlayState` and writes the current HUD level
+/// (0 = off, 1 = FPS, 2 = FPS + battery, 3 = full) whenever it changes.
+/// The DLL only reads it.
+use windows::core::w;
+use windows::Win32::Foundation::{CloseHandle, FALSE};
+use windows::Win32::System::Memory::{
+    MapViewOfFile, OpenFileMappingW, UnmapViewOfFile, FILE_MAP_READ,
+};
+
+/// Must match the backend's `OVERLAY_STATE_MAGIC`
+const MAGIC: u32 = 0xBA1A_0001;
+
+/// Shared overlay state (must match the backend's `OverlayState`)
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct OverlayState {
+    magic: u32,
+    level: u32,
+}
+
+/// Reads the HUD level, `None` when Balam has not published one.
+pub fn read_level() -> Option<u8> {
+    unsafe {
+        let mapping =
+            OpenFileMappingW(FILE_MAP_READ.0, FALSE, w!("Local\\BalamOverlayState")).ok()?;
+        let view = MapViewOfFile(
+            mapping,
+            FILE_MAP_READ,
+            0,
+            0,
+            std::mem::size_of::<OverlayState>(),
+        );
+        let state =
+            (!view.Value.is_null()).then(|| view.Value.cast::<OverlayState>().read_volatile());
+        if !view.Value.is_null() {
+            UnmapViewOfFile(view).ok();
+        }
+        CloseHandle(mapping).ok();
+
+        state
+            .filter(|state| state.magic == MAGIC && state.level <= 3)
+            .map(|state| state.level as u8)
+    }
+}
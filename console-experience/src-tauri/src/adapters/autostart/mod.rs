@@ -0,0 +1,3 @@
+mod task_scheduler_autostart;
+
+pub use task_scheduler_autostart::TaskSchedulerAutostart;
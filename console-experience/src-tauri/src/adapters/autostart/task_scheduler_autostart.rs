@@ -0,0 +1,203 @@
+/// Task Scheduler Autostart Adapter
+///
+/// Registers a logon task (`\Balam\Autostart`) instead of a `Run` key entry:
+/// - Runs with highest privileges, so TDP/FPS features work without a UAC prompt
+/// - Starts even on battery and is never killed by the idle/time limit
+///
+/// The task definition is imported with `schtasks /Create /XML`, the same
+/// mechanism used by `installServiceTask.bat` for the RyzenAdj service task.
+use std::path::Path;
+use std::process::Command;
+use tracing::{info, warn};
+
+use crate::domain::autostart::{AutostartOptions, LaunchOptions, AUTOSTART_TASK_NAME};
+use crate::infrastructure::encoding::escape_xml;
+
+/// Logon task registration through `schtasks.exe`.
+pub struct TaskSchedulerAutostart;
+
+impl TaskSchedulerAutostart {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Whether the autostart task exists.
+    #[must_use]
+    pub fn is_registered(&self) -> bool {
+        Command::new("schtasks")
+            .args(["/Query", "/TN", AUTOSTART_TASK_NAME])
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    /// Launch flags of the registered task (`None` if not registered).
+    #[must_use]
+    pub fn registered_launch_options(&self) -> Option<LaunchOptions> {
+        let output = Command::new("schtasks")
+            .args(["/Query", "/TN", AUTOSTART_TASK_NAME, "/XML"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+
+        let xml = String::from_utf8_lossy(&output.stdout);
+        let arguments = extract_element(&xml, "Arguments").unwrap_or_default();
+        Some(LaunchOptions::from_args(arguments.split_whitespace()))
+    }
+
+    /// Creates (or replaces) the logon task for `executable`.
+    ///
+    /// # Errors
+    /// Returns error if the task file cannot be written or `schtasks` fails
+    /// (creating a highest-privilege task requires administrator rights).
+    pub fn register(&self, executable: &Path, options: &AutostartOptions) -> Result<(), String> {
+        let xml = build_task_xml(&executable.to_string_lossy(), &options.launch_arguments().join(" "));
+
+        // schtasks expects the file encoding declared in the XML header (UTF-16 LE + BOM)
+        let mut bytes = vec![0xFF, 0xFE];
+        bytes.extend(xml.encode_utf16().flat_map(u16::to_le_bytes));
+        let xml_path = std::env::temp_dir().join("balam-autostart-task.xml");
+        std::fs::write(&xml_path, bytes).map_err(|e| format!("Failed to write task definition: {e}"))?;
+
+        let output = Command::new("schtasks")
+            .args(["/Create", "/TN", AUTOSTART_TASK_NAME, "/XML"])
+            .arg(&xml_path)
+            .arg("/F")
+            .output();
+
+        if let Err(e) = std::fs::remove_file(&xml_path) {
+            warn!("Failed to remove temporary task definition: {}", e);
+        }
+
+        let output = output.map_err(|e| format!("Failed to run schtasks: {e}"))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to create autostart task: {}", stderr.trim()));
+        }
+
+        info!("🚀 Autostart task registered: {}", executable.display());
+        Ok(())
+    }
+
+    /// Deletes the logon task (no-op if it does not exist).
+    ///
+    /// # Errors
+    /// Returns error if `schtasks` fails to delete an existing task.
+    pub fn unregister(&self) -> Result<(), String> {
+        if !self.is_registered() {
+            return Ok(());
+        }
+
+        let output = Command::new("schtasks")
+            .args(["/Delete", "/TN", AUTOSTART_TASK_NAME, "/F"])
+            .output()
+            .map_err(|e| format!("Failed to run schtasks: {e}"))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Failed to delete autostart task: {}", stderr.trim()));
+        }
+
+        info!("Autostart task removed");
+        Ok(())
+    }
+}
+
+impl Default for TaskSchedulerAutostart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds the Task Scheduler XML for a logon task running `command` with `arguments`.
+fn build_task_xml(command: &str, arguments: &str) -> String {
+    let working_dir = Path::new(command)
+        .parent()
+        .map(|dir| dir.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-16"?>
+<Task version="1.2" xmlns="http://schemas.microsoft.com/windows/2004/02/mit/task">
+  <RegistrationInfo>
+    <Description>Starts Balam Console Experience at sign-in</Description>
+    <URI>\{AUTOSTART_TASK_NAME}</URI>
+  </RegistrationInfo>
+  <Triggers>
+    <LogonTrigger>
+      <Enabled>true</Enabled>
+    </LogonTrigger>
+  </Triggers>
+  <Principals>
+    <Principal id="Author">
+      <LogonType>InteractiveToken</LogonType>
+      <RunLevel>HighestAvailable</RunLevel>
+    </Principal>
+  </Principals>
+  <Settings>
+    <MultipleInstancesPolicy>IgnoreNew</MultipleInstancesPolicy>
+    <DisallowStartIfOnBatteries>false</DisallowStartIfOnBatteries>
+    <StopIfGoingOnBatteries>false</StopIfGoingOnBatteries>
+    <AllowHardTerminate>true</AllowHardTerminate>
+    <StartWhenAvailable>false</StartWhenAvailable>
+    <IdleSettings>
+      <StopOnIdleEnd>false</StopOnIdleEnd>
+      <RestartOnIdle>false</RestartOnIdle>
+    </IdleSettings>
+    <AllowStartOnDemand>true</AllowStartOnDemand>
+    <Enabled>true</Enabled>
+    <Hidden>false</Hidden>
+    <ExecutionTimeLimit>PT0S</ExecutionTimeLimit>
+    <Priority>4</Priority>
+  </Settings>
+  <Actions Context="Author">
+    <Exec>
+      <Command>{}</Command>
+      <Arguments>{}</Arguments>
+      <WorkingDirectory>{}</WorkingDirectory>
+    </Exec>
+  </Actions>
+</Task>
+"#,
+        escape_xml(command),
+        escape_xml(arguments),
+        escape_xml(&working_dir),
+    )
+}
+
+/// Text content of the first `<name>` element (no nested elements expected).
+fn extract_element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let open = format!("<{name}>");
+    let close = format!("</{name}>");
+    let start = xml.find(&open)? + open.len();
+    let end = xml[start..].find(&close)? + start;
+    Some(xml[start..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_task_xml_contains_command_and_arguments() {
+        let xml = build_task_xml(r"C:\Program Files\Balam\balam.exe", "--minimized --start-in-library");
+        assert!(xml.contains(r"<Command>C:\Program Files\Balam\balam.exe</Command>"));
+        assert!(xml.contains(r"<WorkingDirectory>C:\Program Files\Balam</WorkingDirectory>"));
+        assert!(xml.contains("<RunLevel>HighestAvailable</RunLevel>"));
+        assert_eq!(
+            extract_element(&xml, "Arguments"),
+            Some("--minimized --start-in-library")
+        );
+    }
+
+    #[test]
+    fn test_task_xml_escapes_special_characters() {
+        let xml = build_task_xml(r"C:\Games & Apps\balam.exe", "");
+        assert!(xml.contains(r"C:\Games &amp; Apps\balam.exe"));
+    }
+
+    #[test]
+    fn test_extract_missing_element() {
+        assert_eq!(extract_element("<Task></Task>", "Arguments"), None);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::ports::bluetooth_port::BluetoothAudioProfile;
+use windows::core::{ComInterface, GUID, HSTRING};
+use windows::Devices::Bluetooth::BluetoothLEDevice;
+use windows::Devices::Bluetooth::GenericAttributeProfile::{
+    GattCharacteristicUuids, GattCommunicationStatus, GattServiceUuids,
+};
+use windows::Devices::Enumeration::{DeviceInformation, DeviceInformationKind};
+use windows::Foundation::Collections::IIterable;
+use windows::Foundation::IReference;
+use windows::Storage::Streams::DataReader;
+use windows::Win32::Media::Audio::{eConsole, eRender, IMMDevice, IMMDeviceEnumerator, MMDeviceEnumerator};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, StructuredStorage::PropVariantToStringAlloc,
+    StructuredStorage::PropVariantToUInt32, CLSCTX_ALL, COINIT_MULTITHREADED, STGM,
+};
+use windows::Win32::UI::Shell::PropertiesSystem::PROPERTYKEY;
+
+/// `DEVPKEY_Bluetooth_Battery`: level Windows keeps from HFP AT commands
+/// (and from HID-over-GATT devices), set on the device node.
+const BATTERY_PROPERTY: &str = "{104EA319-6EE2-4701-BD47-8DDBF425BBE5} 2";
+
+/// `PKEY_DeviceInterface_FriendlyName`: on Bluetooth endpoints, the device name.
+const PKEY_INTERFACE_FRIENDLY_NAME: PROPERTYKEY = PROPERTYKEY {
+    fmtid: GUID::from_u128(0x026e516e_b814_414b_83cd_856d6fef4822),
+    pid: 2,
+};
+
+/// `PKEY_AudioEndpoint_FormFactor` (`EndpointFormFactor`).
+const PKEY_ENDPOINT_FORM_FACTOR: PROPERTYKEY = PROPERTYKEY {
+    fmtid: GUID::from_u128(0x1da5d803_d492_4edd_8c23_e0c0ffee7f0e),
+    pid: 0,
+};
+const FORM_FACTOR_HEADSET: u32 = 5;
+const FORM_FACTOR_HANDSET: u32 = 6;
+
+/// Parses "AA:BB:CC:DD:EE:FF" (or dash separated) into the 48-bit address.
+pub(crate) fn parse_address(address: &str) -> Option<u64> {
+    let parts: Vec<&str> = address.split([':', '-']).collect();
+    if parts.len() != 6 {
+        return None;
+    }
+    parts.iter().try_fold(0u64, |acc, part| {
+        (part.len() == 2)
+            .then(|| u8::from_str_radix(part, 16).ok())
+            .flatten()
+            .map(|byte| (acc << 8) | u64::from(byte))
+    })
+}
+
+/// Battery level of a connected device.
+///
+/// Tries the level Windows already tracks on the device node (classic
+/// headsets report it over HFP), then reads the GATT Battery Service.
+pub(crate) fn battery_percent(address: u64) -> Option<u8> {
+    pnp_battery(address)
+        .or_else(|| gatt_battery(address))
+        .map(|percent| percent.min(100))
+}
+
+fn pnp_battery(address: u64) -> Option<u8> {
+    // Device nodes are BTHENUM\DEV_<addr>\... (classic) or BTHLE\DEV_<addr>\... (LE)
+    let filter = HSTRING::from(format!("System.Devices.DeviceInstanceId:~~\"DEV_{address:012X}\""));
+    let properties = IIterable::<HSTRING>::try_from(vec![HSTRING::from(BATTERY_PROPERTY)]).ok()?;
+    let nodes = DeviceInformation::FindAllAsyncWithKindAqsFilterAndAdditionalProperties(
+        &filter,
+        &properties,
+        DeviceInformationKind::Device,
+    )
+    .ok()?
+    .get()
+    .ok()?;
+
+    nodes.into_iter().find_map(|node| {
+        let value = node.Properties().ok()?.Lookup(&HSTRING::from(BATTERY_PROPERTY)).ok()?;
+        value.cast::<IReference<u8>>().ok()?.Value().ok()
+    })
+}
+
+fn gatt_battery(address: u64) -> Option<u8> {
+    let device = BluetoothLEDevice::FromBluetoothAddressAsync(address).ok()?.get().ok()?;
+    let services = device
+        .GetGattServicesForUuidAsync(GattServiceUuids::Battery().ok()?)
+        .ok()?
+        .get()
+        .ok()?;
+    if services.Status().ok()? != GattCommunicationStatus::Success {
+        return None;
+    }
+    let service = services.Services().ok()?.GetAt(0).ok()?;
+
+    let level = (|| {
+        let characteristics = service
+            .GetCharacteristicsForUuidAsync(GattCharacteristicUuids::BatteryLevel().ok()?)
+            .ok()?
+            .get()
+            .ok()?;
+        let characteristic = characteristics.Characteristics().ok()?.GetAt(0).ok()?;
+        let read = characteristic.ReadValueAsync().ok()?.get().ok()?;
+        if read.Status().ok()? != GattCommunicationStatus::Success {
+            return None;
+        }
+        DataReader::FromBuffer(&read.Value().ok()?).ok()?.ReadByte().ok()
+    })();
+
+    // Release the GATT session so the device can sleep
+    let _ = service.Close();
+    let _ = device.Close();
+    level
+}
+
+/// Profile of a Bluetooth audio endpoint from its form factor: Windows
+/// exposes hands-free as a headset endpoint and A2DP as headphones/speakers.
+fn profile_for_form_factor(form_factor: u32) -> BluetoothAudioProfile {
+    if matches!(form_factor, FORM_FACTOR_HEADSET | FORM_FACTOR_HANDSET) {
+        BluetoothAudioProfile::HandsFree
+    } else {
+        BluetoothAudioProfile::A2dp
+    }
+}
+
+/// Profile in use when the default output is this device, `None` otherwise.
+pub(crate) fn audio_profile(device_name: &str) -> Option<BluetoothAudioProfile> {
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL).ok()?;
+        let default: IMMDevice = enumerator.GetDefaultAudioEndpoint(eRender, eConsole).ok()?;
+
+        let store = default.OpenPropertyStore(STGM(0)).ok()?;
+        let interface_name = PropVariantToStringAlloc(&store.GetValue(&PKEY_INTERFACE_FRIENDLY_NAME).ok()?)
+            .ok()?
+            .to_string()
+            .ok()?;
+        if !interface_name.trim().eq_ignore_ascii_case(device_name.trim()) {
+            return None;
+        }
+
+        let form_factor = PropVariantToUInt32(&store.GetValue(&PKEY_ENDPOINT_FORM_FACTOR).ok()?).ok()?;
+        Some(profile_for_form_factor(form_factor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_address() {
+        assert_eq!(parse_address("00:1A:7D:DA:71:13"), Some(0x001A_7DDA_7113));
+        assert_eq!(parse_address("00-1a-7d-da-71-13"), Some(0x001A_7DDA_7113));
+        assert_eq!(parse_address("00:1A:7D:DA:71"), None);
+        assert_eq!(parse_address("00:1A:7D:DA:71:1"), None);
+        assert_eq!(parse_address("00:1A:7D:DA:71:XZ"), None);
+    }
+
+    #[test]
+    fn test_profile_for_form_factor() {
+        assert_eq!(
+            profile_for_form_factor(FORM_FACTOR_HEADSET),
+            BluetoothAudioProfile::HandsFree
+        );
+        assert_eq!(profile_for_form_factor(3), BluetoothAudioProfile::A2dp);
+        assert_eq!(profile_for_form_factor(1), BluetoothAudioProfile::A2dp);
+    }
+}
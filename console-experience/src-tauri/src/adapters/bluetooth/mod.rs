@@ -1,3 +1,6 @@
+mod device_details;
+mod pairing;
+mod service_state;
 pub mod windows_bluetooth_adapter;
 
 pub use windows_bluetooth_adapter::WindowsBluetoothAdapter;
@@ -0,0 +1,181 @@
+use crate::domain::BalamError;
+use crate::ports::bluetooth_port::{BluetoothPairingCeremony, PairingCeremonyKind, PairingListener};
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::time::Duration;
+use tracing::{info, warn};
+use windows::core::HSTRING;
+use windows::Devices::Enumeration::{
+    DeviceInformationCustomPairing, DeviceInformationPairing, DevicePairingKinds, DevicePairingRequestedEventArgs,
+    DevicePairingResultStatus,
+};
+use windows::Foundation::TypedEventHandler;
+
+/// How long a ceremony waits for the user before the pairing is declined.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The user's answer to a ceremony.
+struct PairingResponse {
+    accept: bool,
+    pin: Option<String>,
+}
+
+/// Ceremonies waiting for `respond`, by request id.
+static PENDING: Mutex<Vec<(u64, Sender<PairingResponse>)>> = Mutex::new(Vec::new());
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Delivers the user's answer to a waiting ceremony.
+pub(crate) fn respond(request_id: u64, accept: bool, pin: Option<String>) -> Result<(), BalamError> {
+    let sender = {
+        let mut pending = PENDING.lock();
+        let index = pending
+            .iter()
+            .position(|(id, _)| *id == request_id)
+            .ok_or_else(|| BalamError::NotFound(format!("Pairing request {request_id}")))?;
+        pending.swap_remove(index).1
+    };
+    sender
+        .send(PairingResponse { accept, pin })
+        .map_err(|_| BalamError::NotFound(format!("Pairing request {request_id}")))
+}
+
+/// Pairs through `DeviceInformationCustomPairing` so PIN and confirmation
+/// steps reach the frontend instead of a system dialog behind the app.
+pub(crate) fn pair(
+    pairing: &DeviceInformationPairing,
+    address: &str,
+    preset_pin: String,
+    listener: Option<PairingListener>,
+) -> Result<DevicePairingResultStatus, BalamError> {
+    let custom: DeviceInformationCustomPairing = pairing
+        .Custom()
+        .map_err(|e| BalamError::platform("Failed to get custom pairing", e))?;
+
+    let address = address.to_string();
+    let handler = TypedEventHandler::new(
+        move |_: &Option<DeviceInformationCustomPairing>, args: &Option<DevicePairingRequestedEventArgs>| {
+            if let Some(args) = args {
+                handle_request(args, &address, &preset_pin, listener.as_ref())?;
+            }
+            Ok(())
+        },
+    );
+    let token = custom
+        .PairingRequested(&handler)
+        .map_err(|e| BalamError::platform("Failed to subscribe to pairing requests", e))?;
+
+    let kinds = DevicePairingKinds::ConfirmOnly
+        | DevicePairingKinds::DisplayPin
+        | DevicePairingKinds::ProvidePin
+        | DevicePairingKinds::ConfirmPinMatch;
+    let result = custom
+        .PairAsync(kinds)
+        .map_err(|e| BalamError::platform("Failed to initiate pairing", e))
+        .and_then(|operation| {
+            operation
+                .get()
+                .map_err(|e| BalamError::platform("Failed to await pairing", e))
+        });
+    let _ = custom.RemovePairingRequested(token);
+
+    result?
+        .Status()
+        .map_err(|e| BalamError::platform("Failed to get status", e))
+}
+
+fn handle_request(
+    args: &DevicePairingRequestedEventArgs,
+    address: &str,
+    preset_pin: &str,
+    listener: Option<&PairingListener>,
+) -> windows::core::Result<()> {
+    let kind = args.PairingKind()?;
+    let pin = args.Pin().ok().map(|pin| pin.to_string()).filter(|pin| !pin.is_empty());
+
+    let ceremony_kind = if kind == DevicePairingKinds::ConfirmOnly {
+        // The user already chose to pair this device
+        return args.Accept();
+    } else if kind == DevicePairingKinds::DisplayPin {
+        args.Accept()?;
+        PairingCeremonyKind::DisplayPin
+    } else if kind == DevicePairingKinds::ProvidePin && !preset_pin.is_empty() {
+        return args.AcceptWithPin(&HSTRING::from(preset_pin));
+    } else if kind == DevicePairingKinds::ProvidePin {
+        PairingCeremonyKind::ProvidePin
+    } else if kind == DevicePairingKinds::ConfirmPinMatch {
+        PairingCeremonyKind::ConfirmPinMatch
+    } else {
+        warn!("⚠️ Unsupported pairing kind {:?}, declining", kind);
+        return Ok(());
+    };
+
+    let Some(listener) = listener else {
+        // Nobody to ask: a PIN cannot be guessed, a match confirmation can be trusted
+        if ceremony_kind == PairingCeremonyKind::ConfirmPinMatch {
+            args.Accept()?;
+        }
+        return Ok(());
+    };
+
+    let request_id = NEXT_REQUEST_ID.fetch_add(1, Ordering::SeqCst);
+    let ceremony = BluetoothPairingCeremony {
+        request_id,
+        address: address.to_string(),
+        kind: ceremony_kind,
+        pin,
+    };
+    info!("🔐 Pairing ceremony {:?} for {}", ceremony.kind, address);
+    if ceremony_kind == PairingCeremonyKind::DisplayPin {
+        listener(ceremony);
+        return Ok(());
+    }
+
+    // Hold the ceremony open until the user answers, without blocking the WinRT thread
+    let deferral = args.GetDeferral()?;
+    let (sender, receiver) = mpsc::channel();
+    PENDING.lock().push((request_id, sender));
+    listener(ceremony);
+
+    let args = args.clone();
+    std::thread::spawn(move || {
+        let response = receiver.recv_timeout(RESPONSE_TIMEOUT);
+        PENDING.lock().retain(|(id, _)| *id != request_id);
+        let accepted = match response {
+            Ok(PairingResponse { accept: true, pin }) => match ceremony_kind {
+                PairingCeremonyKind::ProvidePin => args.AcceptWithPin(&HSTRING::from(pin.unwrap_or_default())),
+                _ => args.Accept(),
+            }
+            .is_ok(),
+            Ok(_) => false,
+            Err(_) => {
+                warn!("⚠️ Pairing request {} timed out", request_id);
+                false
+            },
+        };
+        if !accepted {
+            info!("🔐 Pairing request {} declined", request_id);
+        }
+        let _ = deferral.Complete();
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_respond_delivers_once() {
+        let (sender, receiver) = mpsc::channel();
+        PENDING.lock().push((u64::MAX, sender));
+
+        respond(u64::MAX, true, Some("1234".to_string())).unwrap();
+        let response = receiver.try_recv().unwrap();
+        assert!(response.accept);
+        assert_eq!(response.pin.as_deref(), Some("1234"));
+
+        // Already answered
+        assert!(matches!(respond(u64::MAX, false, None), Err(BalamError::NotFound(_))));
+    }
+}
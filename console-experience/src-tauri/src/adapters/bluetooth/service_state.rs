@@ -0,0 +1,158 @@
+use crate::domain::BalamError;
+use parking_lot::Mutex;
+use tracing::{info, warn};
+use windows::core::GUID;
+use windows::Win32::Devices::Bluetooth::{
+    BluetoothEnumerateInstalledServices, BluetoothFindFirstRadio, BluetoothFindRadioClose, BluetoothGetDeviceInfo,
+    BluetoothSetServiceState, BLUETOOTH_DEVICE_INFO, BLUETOOTH_FIND_RADIO_PARAMS, BLUETOOTH_SERVICE_DISABLE,
+    BLUETOOTH_SERVICE_ENABLE,
+};
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+
+const ERROR_SUCCESS: u32 = 0;
+const ERROR_MORE_DATA: u32 = 234;
+
+/// Services switched off by [`disconnect`], per device address, so
+/// [`reconnect`] can switch exactly those back on.
+static DISABLED_SERVICES: Mutex<Vec<(u64, Vec<GUID>)>> = Mutex::new(Vec::new());
+
+/// First local Bluetooth radio, closed on drop.
+struct RadioHandle(HANDLE);
+
+impl RadioHandle {
+    fn open() -> Result<Self, BalamError> {
+        let params = BLUETOOTH_FIND_RADIO_PARAMS {
+            dwSize: std::mem::size_of::<BLUETOOTH_FIND_RADIO_PARAMS>() as u32,
+        };
+        let mut radio = HANDLE::default();
+        unsafe {
+            let find = BluetoothFindFirstRadio(&params, &mut radio).map_err(|_| BalamError::BluetoothUnavailable)?;
+            let _ = BluetoothFindRadioClose(find);
+        }
+        Ok(Self(radio))
+    }
+}
+
+impl Drop for RadioHandle {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.0);
+        }
+    }
+}
+
+fn device_info(radio: &RadioHandle, address: u64) -> Result<BLUETOOTH_DEVICE_INFO, BalamError> {
+    let mut info = BLUETOOTH_DEVICE_INFO {
+        dwSize: std::mem::size_of::<BLUETOOTH_DEVICE_INFO>() as u32,
+        ..Default::default()
+    };
+    info.Address.Anonymous.ullLong = address;
+    let status = unsafe { BluetoothGetDeviceInfo(radio.0, &mut info) };
+    if status != ERROR_SUCCESS {
+        return Err(BalamError::NotFound(format!("Bluetooth device {address:012X}")));
+    }
+    Ok(info)
+}
+
+fn installed_services(radio: &RadioHandle, info: &BLUETOOTH_DEVICE_INFO) -> Result<Vec<GUID>, BalamError> {
+    let mut count = 0u32;
+    let status = unsafe { BluetoothEnumerateInstalledServices(radio.0, info, &mut count, None) };
+    if status != ERROR_SUCCESS && status != ERROR_MORE_DATA {
+        return Err(BalamError::Platform(format!(
+            "BluetoothEnumerateInstalledServices failed: {status}"
+        )));
+    }
+
+    let mut services = vec![GUID::zeroed(); count as usize];
+    if count > 0 {
+        let status =
+            unsafe { BluetoothEnumerateInstalledServices(radio.0, info, &mut count, Some(services.as_mut_ptr())) };
+        if status != ERROR_SUCCESS {
+            return Err(BalamError::Platform(format!(
+                "BluetoothEnumerateInstalledServices failed: {status}"
+            )));
+        }
+        services.truncate(count as usize);
+    }
+    Ok(services)
+}
+
+fn set_service_state(
+    radio: &RadioHandle,
+    info: &BLUETOOTH_DEVICE_INFO,
+    service: &GUID,
+    flags: u32,
+) -> Result<(), BalamError> {
+    let status = unsafe { BluetoothSetServiceState(radio.0, info, service, flags) };
+    if status == ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(BalamError::Platform(format!(
+            "BluetoothSetServiceState({service:?}) failed: {status}"
+        )))
+    }
+}
+
+/// Drops the connection by disabling every service Windows installed for
+/// the device (audio sink, hands-free, HID...). The pairing is kept.
+///
+/// Returns `false` if the device was not connected.
+pub(crate) fn disconnect(address: u64) -> Result<bool, BalamError> {
+    let radio = RadioHandle::open()?;
+    let info = device_info(&radio, address)?;
+    if !info.fConnected.as_bool() {
+        return Ok(false);
+    }
+
+    let services = installed_services(&radio, &info)?;
+    let mut disabled = Vec::with_capacity(services.len());
+    for service in &services {
+        match set_service_state(&radio, &info, service, BLUETOOTH_SERVICE_DISABLE) {
+            Ok(()) => disabled.push(*service),
+            Err(e) => warn!("⚠️ {}", e),
+        }
+    }
+    if disabled.is_empty() && !services.is_empty() {
+        return Err(BalamError::Platform(
+            "No Bluetooth service could be disabled".to_string(),
+        ));
+    }
+
+    info!("🔌 Disabled {} service(s) on {:012X}", disabled.len(), address);
+    let mut registry = DISABLED_SERVICES.lock();
+    registry.retain(|(known, _)| *known != address);
+    registry.push((address, disabled));
+    Ok(true)
+}
+
+/// Re-enables the services a previous [`disconnect`] switched off, which
+/// lets Windows reconnect the device. Returns `false` if there were none.
+pub(crate) fn reconnect(address: u64) -> Result<bool, BalamError> {
+    if !DISABLED_SERVICES.lock().iter().any(|(known, _)| *known == address) {
+        return Ok(false);
+    }
+    let radio = RadioHandle::open()?;
+    let info = device_info(&radio, address)?;
+
+    let services = {
+        let mut registry = DISABLED_SERVICES.lock();
+        let index = registry.iter().position(|(known, _)| *known == address);
+        index.map(|index| registry.swap_remove(index).1).unwrap_or_default()
+    };
+    let mut failed = Vec::new();
+    for service in services {
+        if let Err(e) = set_service_state(&radio, &info, &service, BLUETOOTH_SERVICE_ENABLE) {
+            warn!("⚠️ {}", e);
+            failed.push(service);
+        }
+    }
+    if !failed.is_empty() {
+        // Keep them so a retry (or the next connect) can finish the job
+        DISABLED_SERVICES.lock().push((address, failed));
+        return Err(BalamError::Platform(
+            "Some Bluetooth services could not be re-enabled".to_string(),
+        ));
+    }
+    info!("🔌 Re-enabled services on {:012X}", address);
+    Ok(true)
+}
@@ -1,5 +1,7 @@
+use super::{device_details, pairing, service_state};
+use crate::domain::BalamError;
 use crate::ports::bluetooth_port::{
-    BluetoothDevice, BluetoothDeviceType, BluetoothPairingConfig, BluetoothPairingState, BluetoothPort,
+    BluetoothDevice, BluetoothDeviceType, BluetoothPairingConfig, BluetoothPairingState, BluetoothPort, PairingListener,
 };
 use tracing::{error, info, warn};
 use windows::Devices::Bluetooth::BluetoothDevice as WinBluetoothDevice;
@@ -28,6 +30,56 @@ impl WindowsBluetoothAdapter {
         Self
     }
 
+    /// Finds the Bluetooth radio.
+    ///
+    /// # Errors
+    /// Returns `BalamError::BluetoothUnavailable` if the machine has no Bluetooth radio.
+    fn bluetooth_radio() -> Result<Radio, BalamError> {
+        let radios = Radio::GetRadiosAsync()
+            .map_err(|e| {
+                error!("❌ Failed to get radios: {e}");
+                BalamError::platform("Failed to get radios", e)
+            })?
+            .get()
+            .map_err(|e| {
+                error!("❌ Failed to await radios: {e}");
+                BalamError::platform("Failed to await radios", e)
+            })?;
+
+        let radio_count = radios
+            .Size()
+            .map_err(|e| BalamError::platform("Failed to get radio count", e))?;
+        info!("📡 Found {} radio(s)", radio_count);
+
+        for i in 0..radio_count {
+            let radio = radios
+                .GetAt(i)
+                .map_err(|e| BalamError::Platform(format!("Failed to get radio {i}: {e}")))?;
+            let kind = radio
+                .Kind()
+                .map_err(|e| BalamError::platform("Failed to get radio kind", e))?;
+            if kind == RadioKind::Bluetooth {
+                return Ok(radio);
+            }
+        }
+
+        error!("❌ No Bluetooth radio found");
+        Err(BalamError::BluetoothUnavailable)
+    }
+
+    /// Fails early with `BalamError::BluetoothOff` instead of an empty scan or a
+    /// generic pairing failure when the radio is turned off.
+    fn require_radio_on() -> Result<(), BalamError> {
+        let state = Self::bluetooth_radio()?
+            .State()
+            .map_err(|e| BalamError::platform("Failed to get state", e))?;
+        if state == RadioState::On {
+            Ok(())
+        } else {
+            Err(BalamError::BluetoothOff)
+        }
+    }
+
     /// Converts Windows device class to our enum.
     fn convert_device_type(class_of_device: Option<u32>) -> BluetoothDeviceType {
         if let Some(cod) = class_of_device {
@@ -51,24 +103,26 @@ impl WindowsBluetoothAdapter {
     /// Converts `DeviceInformation` to `BluetoothDevice`.
     ///
     /// Uses `block_in_place` for fast (<100ms) `WinRT` calls.
-    fn convert_device_info(device_info: DeviceInformation) -> Result<BluetoothDevice, String> {
+    fn convert_device_info(device_info: DeviceInformation) -> Result<BluetoothDevice, BalamError> {
         // Fast operation - use block_in_place instead of spawn_blocking
         let result = tokio::task::block_in_place(|| {
-            let device_id = device_info.Id().map_err(|e| format!("Failed to get device ID: {e}"))?;
+            let device_id = device_info
+                .Id()
+                .map_err(|e| BalamError::platform("Failed to get device ID", e))?;
 
             let bt_device = WinBluetoothDevice::FromIdAsync(&device_id)
-                .map_err(|e| format!("Failed to get Bluetooth device future: {e}"))?
+                .map_err(|e| BalamError::platform("Failed to get Bluetooth device future", e))?
                 .get()
-                .map_err(|e| format!("Failed to await Bluetooth device: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to await Bluetooth device", e))?;
 
             let name = device_info
                 .Name()
-                .map_err(|e| format!("Failed to get name: {e}"))?
+                .map_err(|e| BalamError::platform("Failed to get name", e))?
                 .to_string();
 
             let address = bt_device
                 .BluetoothAddress()
-                .map_err(|e| format!("Failed to get address: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get address", e))?;
             let address_str = format!(
                 "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
                 (address >> 40) & 0xFF,
@@ -83,13 +137,15 @@ impl WindowsBluetoothAdapter {
 
             let connection_status = bt_device
                 .ConnectionStatus()
-                .map_err(|e| format!("Failed to get connection status: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get connection status", e))?;
             let is_connected = connection_status == windows::Devices::Bluetooth::BluetoothConnectionStatus::Connected;
 
             let pairing = device_info
                 .Pairing()
-                .map_err(|e| format!("Failed to get pairing: {e}"))?;
-            let is_paired = pairing.IsPaired().map_err(|e| format!("Failed to check paired: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get pairing", e))?;
+            let is_paired = pairing
+                .IsPaired()
+                .map_err(|e| BalamError::platform("Failed to check paired", e))?;
 
             let pairing_state = if is_paired {
                 BluetoothPairingState::Paired
@@ -97,7 +153,7 @@ impl WindowsBluetoothAdapter {
                 BluetoothPairingState::Unpaired
             };
 
-            Ok::<BluetoothDevice, String>(BluetoothDevice {
+            Ok::<BluetoothDevice, BalamError>(BluetoothDevice {
                 name,
                 address: address_str,
                 signal_strength: None,
@@ -105,6 +161,8 @@ impl WindowsBluetoothAdapter {
                 pairing_state,
                 is_connected,
                 is_remembered: is_paired,
+                battery_percent: None,
+                audio_profile: None,
             })
         });
 
@@ -120,97 +178,66 @@ impl Default for WindowsBluetoothAdapter {
 
 #[async_trait::async_trait]
 impl BluetoothPort for WindowsBluetoothAdapter {
-    async fn is_bluetooth_available(&self) -> Result<bool, String> {
+    async fn is_bluetooth_available(&self) -> Result<bool, BalamError> {
         info!("🔍 Checking Bluetooth availability...");
 
         // Fast operation - use block_in_place (doesn't consume thread pool)
-        let result = tokio::task::block_in_place(|| {
-            let radios = Radio::GetRadiosAsync()
-                .map_err(|e| {
-                    error!("❌ Failed to get radios: {e}");
-                    format!("Failed to get radios: {e}")
-                })?
-                .get()
-                .map_err(|e| {
-                    error!("❌ Failed to await radios: {e}");
-                    format!("Failed to await radios: {e}")
-                })?;
-
-            let radio_count = radios.Size().map_err(|e| format!("Failed to get radio count: {e}"))?;
-            info!("📡 Found {} radio(s)", radio_count);
-
-            for i in 0..radio_count {
-                let radio = radios.GetAt(i).map_err(|e| format!("Failed to get radio {i}: {e}"))?;
-                let kind = radio.Kind().map_err(|e| format!("Failed to get radio kind: {e}"))?;
-
-                if kind == RadioKind::Bluetooth {
-                    let state = radio.State().map_err(|e| format!("Failed to get state: {e}"))?;
-                    let is_on = state == RadioState::On;
-                    info!("✅ Bluetooth radio: {:?} (enabled: {})", state, is_on);
-                    return Ok(is_on);
-                }
-            }
-
-            info!("⚠️ No Bluetooth radio found");
-            Ok(false)
-        });
-
-        result
+        tokio::task::block_in_place(|| match Self::bluetooth_radio() {
+            Ok(radio) => {
+                let state = radio
+                    .State()
+                    .map_err(|e| BalamError::platform("Failed to get state", e))?;
+                let is_on = state == RadioState::On;
+                info!("✅ Bluetooth radio: {:?} (enabled: {})", state, is_on);
+                Ok(is_on)
+            },
+            Err(BalamError::BluetoothUnavailable) => {
+                info!("⚠️ No Bluetooth radio found");
+                Ok(false)
+            },
+            Err(e) => Err(e),
+        })
     }
 
-    async fn set_bluetooth_enabled(&self, enabled: bool) -> Result<(), String> {
+    async fn set_bluetooth_enabled(&self, enabled: bool) -> Result<(), BalamError> {
         info!("🔧 Setting Bluetooth: {}", if enabled { "ON" } else { "OFF" });
 
         // Fast operation - use block_in_place
         tokio::task::block_in_place(|| {
-            let radios = Radio::GetRadiosAsync()
-                .map_err(|e| format!("Failed to get radios: {e}"))?
+            let radio = Self::bluetooth_radio()?;
+            let state = if enabled { RadioState::On } else { RadioState::Off };
+            let access = radio
+                .SetStateAsync(state)
+                .map_err(|e| BalamError::platform("Failed to set state", e))?
                 .get()
-                .map_err(|e| format!("Failed to await radios: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to await state change", e))?;
 
-            let radio_count = radios.Size().map_err(|e| format!("Failed to get count: {e}"))?;
-
-            for i in 0..radio_count {
-                let radio = radios.GetAt(i).map_err(|e| format!("Failed to get radio {i}: {e}"))?;
-                let kind = radio.Kind().map_err(|e| format!("Failed to get kind: {e}"))?;
-
-                if kind == RadioKind::Bluetooth {
-                    let state = if enabled { RadioState::On } else { RadioState::Off };
-                    let access = radio
-                        .SetStateAsync(state)
-                        .map_err(|e| format!("Failed to set state: {e}"))?
-                        .get()
-                        .map_err(|e| format!("Failed to await state change: {e}"))?;
-
-                    if access != RadioAccessStatus::Allowed {
-                        error!("❌ Radio access denied: {:?}", access);
-                        return Err(format!("Access denied: {access:?}"));
-                    }
-
-                    info!("✅ Bluetooth set to: {:?}", state);
-                    return Ok(());
-                }
+            if access != RadioAccessStatus::Allowed {
+                error!("❌ Radio access denied: {:?}", access);
+                return Err(BalamError::AccessDenied(format!("Bluetooth radio: {access:?}")));
             }
 
-            error!("❌ No Bluetooth radio found");
-            Err("No Bluetooth radio found".to_string())
+            info!("✅ Bluetooth set to: {:?}", state);
+            Ok(())
         })
     }
 
-    async fn get_paired_devices(&self) -> Result<Vec<BluetoothDevice>, String> {
+    async fn get_paired_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
         info!("📋 Getting paired devices...");
 
         // Fast operation - use block_in_place
         let device_infos = tokio::task::block_in_place(|| {
             let selector = WinBluetoothDevice::GetDeviceSelectorFromPairingState(true)
-                .map_err(|e| format!("Failed to create selector: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to create selector", e))?;
 
             let devices = DeviceInformation::FindAllAsyncAqsFilter(&selector)
-                .map_err(|e| format!("Failed to find devices: {e}"))?
+                .map_err(|e| BalamError::platform("Failed to find devices", e))?
                 .get()
-                .map_err(|e| format!("Failed to await devices: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to await devices", e))?;
 
-            let count = devices.Size().map_err(|e| format!("Failed to get count: {e}"))?;
+            let count = devices
+                .Size()
+                .map_err(|e| BalamError::platform("Failed to get count", e))?;
             info!("📱 Found {} paired device(s)", count);
 
             let mut infos = Vec::new();
@@ -220,7 +247,7 @@ impl BluetoothPort for WindowsBluetoothAdapter {
                 }
             }
 
-            Ok::<Vec<DeviceInformation>, String>(infos)
+            Ok::<Vec<DeviceInformation>, BalamError>(infos)
         })?;
 
         // Convert devices (fast synchronous operation)
@@ -236,16 +263,18 @@ impl BluetoothPort for WindowsBluetoothAdapter {
         Ok(converted)
     }
 
-    async fn scan_devices(&self) -> Result<Vec<BluetoothDevice>, String> {
+    async fn scan_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
         info!("🔎 Scanning for Bluetooth devices (2-10 seconds)...");
 
+        tokio::task::block_in_place(Self::require_radio_on)?;
+
         // SLOW operation - use spawn_blocking to avoid blocking runtime
         let device_infos = tokio::task::spawn_blocking(|| {
             info!("🔄 Device scan started in background thread...");
 
             let selector = WinBluetoothDevice::GetDeviceSelectorFromPairingState(false).map_err(|e| {
                 error!("❌ Selector creation failed: {e}");
-                format!("Failed to create selector: {e}")
+                BalamError::platform("Failed to create selector", e)
             })?;
 
             info!("⏳ Waiting for device discovery...");
@@ -253,15 +282,17 @@ impl BluetoothPort for WindowsBluetoothAdapter {
             let devices = DeviceInformation::FindAllAsyncAqsFilter(&selector)
                 .map_err(|e| {
                     error!("❌ Device discovery failed: {e}");
-                    format!("Failed to find devices: {e}")
+                    BalamError::platform("Failed to find devices", e)
                 })?
                 .get()
                 .map_err(|e| {
                     error!("❌ Await failed: {e}");
-                    format!("Failed to await devices: {e}")
+                    BalamError::platform("Failed to await devices", e)
                 })?;
 
-            let count = devices.Size().map_err(|e| format!("Failed to get count: {e}"))?;
+            let count = devices
+                .Size()
+                .map_err(|e| BalamError::platform("Failed to get count", e))?;
             info!("✅ Discovery complete: {} device(s) found", count);
 
             let mut infos = Vec::new();
@@ -271,10 +302,10 @@ impl BluetoothPort for WindowsBluetoothAdapter {
                 }
             }
 
-            Ok::<Vec<DeviceInformation>, String>(infos)
+            Ok::<Vec<DeviceInformation>, BalamError>(infos)
         })
         .await
-        .map_err(|e| format!("Task join error: {e}"))??;
+        .map_err(|e| BalamError::platform("Task join error", e))??;
 
         info!("🔄 Converting {} discovered devices...", device_infos.len());
 
@@ -291,38 +322,49 @@ impl BluetoothPort for WindowsBluetoothAdapter {
         Ok(converted)
     }
 
-    async fn pair_device(&self, config: BluetoothPairingConfig) -> Result<(), String> {
+    async fn pair_device(
+        &self,
+        config: BluetoothPairingConfig,
+        listener: Option<PairingListener>,
+    ) -> Result<(), BalamError> {
         info!("🔗 Pairing with device: {}", config.address);
 
-        let address = config.address.clone();
+        tokio::task::block_in_place(Self::require_radio_on)?;
+        let BluetoothPairingConfig { address, pin } = config;
 
         // Medium operation - use spawn_blocking
         tokio::task::spawn_blocking(move || {
             use windows::Devices::Enumeration::DevicePairingResultStatus;
 
             let selector = WinBluetoothDevice::GetDeviceSelectorFromPairingState(false)
-                .map_err(|e| format!("Failed to create selector: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to create selector", e))?;
 
             let devices = DeviceInformation::FindAllAsyncAqsFilter(&selector)
-                .map_err(|e| format!("Failed to find devices: {e}"))?
+                .map_err(|e| BalamError::platform("Failed to find devices", e))?
                 .get()
-                .map_err(|e| format!("Failed to await devices: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to await devices", e))?;
 
-            let count = devices.Size().map_err(|e| format!("Failed to get count: {e}"))?;
+            let count = devices
+                .Size()
+                .map_err(|e| BalamError::platform("Failed to get count", e))?;
             info!("🔍 Searching {} devices for {}", count, address);
 
             for i in 0..count {
-                let device_info = devices.GetAt(i).map_err(|e| format!("Failed to get device {i}: {e}"))?;
-                let device_id = device_info.Id().map_err(|e| format!("Failed to get ID: {e}"))?;
+                let device_info = devices
+                    .GetAt(i)
+                    .map_err(|e| BalamError::Platform(format!("Failed to get device {i}: {e}")))?;
+                let device_id = device_info
+                    .Id()
+                    .map_err(|e| BalamError::platform("Failed to get ID", e))?;
 
                 let bt_device = WinBluetoothDevice::FromIdAsync(&device_id)
-                    .map_err(|e| format!("Failed to get device: {e}"))?
+                    .map_err(|e| BalamError::platform("Failed to get device", e))?
                     .get()
-                    .map_err(|e| format!("Failed to await device: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to await device", e))?;
 
                 let bt_address = bt_device
                     .BluetoothAddress()
-                    .map_err(|e| format!("Failed to get address: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to get address", e))?;
                 let address_str = format!(
                     "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
                     (bt_address >> 40) & 0xFF,
@@ -338,14 +380,8 @@ impl BluetoothPort for WindowsBluetoothAdapter {
 
                     let pairing = device_info
                         .Pairing()
-                        .map_err(|e| format!("Failed to get pairing: {e}"))?;
-                    let result = pairing
-                        .PairAsync()
-                        .map_err(|e| format!("Failed to initiate pairing: {e}"))?
-                        .get()
-                        .map_err(|e| format!("Failed to await pairing: {e}"))?;
-
-                    let status = result.Status().map_err(|e| format!("Failed to get status: {e}"))?;
+                        .map_err(|e| BalamError::platform("Failed to get pairing", e))?;
+                    let status = pairing::pair(&pairing, &address, pin, listener)?;
 
                     if status == DevicePairingResultStatus::Paired || status == DevicePairingResultStatus::AlreadyPaired
                     {
@@ -353,18 +389,27 @@ impl BluetoothPort for WindowsBluetoothAdapter {
                         return Ok(());
                     }
                     error!("❌ Pairing failed: {:?}", status);
-                    return Err(format!("Pairing failed: {status:?}"));
+                    return Err(BalamError::Platform(format!("Pairing failed: {status:?}")));
                 }
             }
 
             error!("❌ Device not found: {}", address);
-            Err(format!("Device not found: {address}"))
+            Err(BalamError::NotFound(format!("Bluetooth device {address}")))
         })
         .await
-        .map_err(|e| format!("Task join error: {e}"))?
+        .map_err(|e| BalamError::platform("Task join error", e))?
     }
 
-    async fn unpair_device(&self, address: &str) -> Result<(), String> {
+    async fn respond_to_pairing(&self, request_id: u64, accept: bool, pin: Option<String>) -> Result<(), BalamError> {
+        info!(
+            "🔐 Pairing request {}: {}",
+            request_id,
+            if accept { "accepted" } else { "declined" }
+        );
+        pairing::respond(request_id, accept, pin)
+    }
+
+    async fn unpair_device(&self, address: &str) -> Result<(), BalamError> {
         info!("🔓 Unpairing device: {}", address);
 
         let address = address.to_string();
@@ -374,28 +419,34 @@ impl BluetoothPort for WindowsBluetoothAdapter {
             use windows::Devices::Enumeration::DeviceUnpairingResultStatus;
 
             let selector = WinBluetoothDevice::GetDeviceSelectorFromPairingState(true)
-                .map_err(|e| format!("Failed to create selector: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to create selector", e))?;
 
             let devices = DeviceInformation::FindAllAsyncAqsFilter(&selector)
-                .map_err(|e| format!("Failed to find devices: {e}"))?
+                .map_err(|e| BalamError::platform("Failed to find devices", e))?
                 .get()
-                .map_err(|e| format!("Failed to await devices: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to await devices", e))?;
 
-            let count = devices.Size().map_err(|e| format!("Failed to get count: {e}"))?;
+            let count = devices
+                .Size()
+                .map_err(|e| BalamError::platform("Failed to get count", e))?;
             info!("🔍 Searching {} paired devices for {}", count, address);
 
             for i in 0..count {
-                let device_info = devices.GetAt(i).map_err(|e| format!("Failed to get device {i}: {e}"))?;
-                let device_id = device_info.Id().map_err(|e| format!("Failed to get ID: {e}"))?;
+                let device_info = devices
+                    .GetAt(i)
+                    .map_err(|e| BalamError::Platform(format!("Failed to get device {i}: {e}")))?;
+                let device_id = device_info
+                    .Id()
+                    .map_err(|e| BalamError::platform("Failed to get ID", e))?;
 
                 let bt_device = WinBluetoothDevice::FromIdAsync(&device_id)
-                    .map_err(|e| format!("Failed to get device: {e}"))?
+                    .map_err(|e| BalamError::platform("Failed to get device", e))?
                     .get()
-                    .map_err(|e| format!("Failed to await device: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to await device", e))?;
 
                 let bt_address = bt_device
                     .BluetoothAddress()
-                    .map_err(|e| format!("Failed to get address: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to get address", e))?;
                 let address_str = format!(
                     "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
                     (bt_address >> 40) & 0xFF,
@@ -411,14 +462,16 @@ impl BluetoothPort for WindowsBluetoothAdapter {
 
                     let pairing = device_info
                         .Pairing()
-                        .map_err(|e| format!("Failed to get pairing: {e}"))?;
+                        .map_err(|e| BalamError::platform("Failed to get pairing", e))?;
                     let result = pairing
                         .UnpairAsync()
-                        .map_err(|e| format!("Failed to initiate unpair: {e}"))?
+                        .map_err(|e| BalamError::platform("Failed to initiate unpair", e))?
                         .get()
-                        .map_err(|e| format!("Failed to await unpair: {e}"))?;
+                        .map_err(|e| BalamError::platform("Failed to await unpair", e))?;
 
-                    let status = result.Status().map_err(|e| format!("Failed to get status: {e}"))?;
+                    let status = result
+                        .Status()
+                        .map_err(|e| BalamError::platform("Failed to get status", e))?;
 
                     if status == DeviceUnpairingResultStatus::Unpaired
                         || status == DeviceUnpairingResultStatus::AlreadyUnpaired
@@ -427,29 +480,88 @@ impl BluetoothPort for WindowsBluetoothAdapter {
                         return Ok(());
                     }
                     error!("❌ Unpair failed: {:?}", status);
-                    return Err(format!("Unpair failed: {status:?}"));
+                    return Err(BalamError::Platform(format!("Unpair failed: {status:?}")));
                 }
             }
 
             error!("❌ Device not found: {}", address);
-            Err(format!("Device not found: {address}"))
+            Err(BalamError::NotFound(format!("Bluetooth device {address}")))
         })
         .await
-        .map_err(|e| format!("Task join error: {e}"))?
+        .map_err(|e| BalamError::platform("Task join error", e))?
     }
 
-    async fn connect_device(&self, address: &str) -> Result<(), String> {
-        info!("🔌 Connecting to: {} (Windows auto-connect)", address);
-        // Windows auto-connects paired devices when in range
+    async fn connect_device(&self, address: &str) -> Result<(), BalamError> {
+        let raw_address = device_details::parse_address(address)
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid Bluetooth address: {address}")))?;
+
+        tokio::task::spawn_blocking(move || service_state::reconnect(raw_address))
+            .await
+            .map_err(|e| BalamError::platform("Task join error", e))?
+            .map(|reenabled| {
+                if !reenabled {
+                    // Windows auto-connects paired devices when in range
+                    info!("🔌 Connecting to: {:012X} (Windows auto-connect)", raw_address);
+                }
+            })
+    }
+
+    async fn disconnect_device(&self, address: &str) -> Result<(), BalamError> {
+        info!("🔌 Disconnecting: {}", address);
+        let raw_address = device_details::parse_address(address)
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid Bluetooth address: {address}")))?;
+
+        // Service teardown waits for the drivers to unload - use spawn_blocking
+        let disconnected = tokio::task::spawn_blocking(move || service_state::disconnect(raw_address))
+            .await
+            .map_err(|e| BalamError::platform("Task join error", e))??;
+        if !disconnected {
+            info!("ℹ️ {} was not connected", address);
+        }
         Ok(())
     }
 
-    async fn disconnect_device(&self, address: &str) -> Result<(), String> {
-        info!("🔌 Disconnect requested: {} (not supported via API)", address);
-        Err("Disconnect not supported. Use Windows Settings.".to_string())
+    async fn get_device_details(&self, address: &str) -> Result<BluetoothDevice, BalamError> {
+        info!("🔋 Getting details for: {}", address);
+
+        let raw_address = device_details::parse_address(address)
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid Bluetooth address: {address}")))?;
+
+        // Fast operation - use block_in_place
+        let device_info = tokio::task::block_in_place(|| {
+            WinBluetoothDevice::FromBluetoothAddressAsync(raw_address)
+                .map_err(|e| BalamError::platform("Failed to get Bluetooth device future", e))?
+                .get()
+                .map_err(|_| BalamError::NotFound(format!("Bluetooth device {address}")))?
+                .DeviceInformation()
+                .map_err(|e| BalamError::platform("Failed to get device information", e))
+        })?;
+        let mut device = Self::convert_device_info(device_info)?;
+        if !device.is_connected {
+            return Ok(device);
+        }
+
+        // GATT reads can take a second - use spawn_blocking
+        let name = device.name.clone();
+        let (battery_percent, audio_profile) = tokio::task::spawn_blocking(move || {
+            (
+                device_details::battery_percent(raw_address),
+                device_details::audio_profile(&name),
+            )
+        })
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?;
+
+        device.battery_percent = battery_percent;
+        device.audio_profile = audio_profile;
+        info!(
+            "✅ {}: battery {:?}, audio profile {:?}",
+            device.name, battery_percent, audio_profile
+        );
+        Ok(device)
     }
 
-    async fn get_connected_devices(&self) -> Result<Vec<BluetoothDevice>, String> {
+    async fn get_connected_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
         info!("📡 Getting connected devices...");
         let paired = self.get_paired_devices().await?;
         let connected: Vec<BluetoothDevice> = paired.into_iter().filter(|d| d.is_connected).collect();
@@ -0,0 +1,211 @@
+use crate::adapters::game::WindowsGameAdapter;
+use crate::domain::BalamError;
+use crate::ports::screen_capture_port::{CapturedFrame, ScreenCapturePort};
+use tracing::info;
+use windows::core::ComInterface;
+use windows::Win32::Foundation::{HMODULE, HWND, POINT, RECT};
+use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_UNKNOWN;
+use windows::Win32::Graphics::Direct3D11::{
+    D3D11CreateDevice, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, D3D11_CPU_ACCESS_READ,
+    D3D11_CREATE_DEVICE_BGRA_SUPPORT, D3D11_MAPPED_SUBRESOURCE, D3D11_MAP_READ, D3D11_SDK_VERSION,
+    D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+};
+use windows::Win32::Graphics::Dxgi::{
+    CreateDXGIFactory1, IDXGIFactory1, IDXGIOutput1, IDXGIOutputDuplication, IDXGIResource, DXGI_OUTDUPL_FRAME_INFO,
+};
+use windows::Win32::Graphics::Gdi::{ClientToScreen, MonitorFromWindow, HMONITOR, MONITOR_DEFAULTTONEAREST};
+use windows::Win32::UI::WindowsAndMessaging::{GetClientRect, GetForegroundWindow};
+
+/// How long to wait for the desktop image.
+const FRAME_TIMEOUT_MS: u32 = 500;
+
+/// Duplication of the display a window is on.
+struct OutputDuplication {
+    device: ID3D11Device,
+    context: ID3D11DeviceContext,
+    duplication: IDXGIOutputDuplication,
+    /// Position of the display on the virtual desktop
+    desktop: RECT,
+}
+
+/// Captures game windows with DXGI Desktop Duplication.
+///
+/// Duplication sees exactly what is on screen, which also covers
+/// exclusive-fullscreen and flip-model games that GDI captures as black.
+pub struct DxgiScreenCapture;
+
+impl DxgiScreenCapture {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Client area of `hwnd` on the virtual desktop (no title bar or borders).
+    fn client_area(hwnd: HWND) -> Result<RECT, BalamError> {
+        let mut rect = RECT::default();
+        let mut origin = POINT::default();
+        unsafe {
+            GetClientRect(hwnd, &mut rect).map_err(|e| BalamError::platform("Failed to read window size", e))?;
+            if !ClientToScreen(hwnd, &mut origin).as_bool() {
+                return Err(BalamError::Platform("Failed to locate window on screen".to_string()));
+            }
+        }
+        Ok(RECT {
+            left: origin.x,
+            top: origin.y,
+            right: origin.x + rect.right,
+            bottom: origin.y + rect.bottom,
+        })
+    }
+
+    /// Duplicates the output showing `monitor`, on the GPU that drives it.
+    fn duplicate_output(monitor: HMONITOR) -> Result<OutputDuplication, BalamError> {
+        unsafe {
+            let factory: IDXGIFactory1 =
+                CreateDXGIFactory1().map_err(|e| BalamError::platform("Failed to create DXGI factory", e))?;
+            let mut adapter_index = 0;
+            while let Ok(adapter) = factory.EnumAdapters1(adapter_index) {
+                adapter_index += 1;
+                let mut output_index = 0;
+                while let Ok(output) = adapter.EnumOutputs(output_index) {
+                    output_index += 1;
+                    let Ok(desc) = output.GetDesc() else { continue };
+                    if desc.Monitor != monitor {
+                        continue;
+                    }
+
+                    let mut device = None;
+                    let mut context = None;
+                    D3D11CreateDevice(
+                        &adapter,
+                        D3D_DRIVER_TYPE_UNKNOWN,
+                        HMODULE::default(),
+                        D3D11_CREATE_DEVICE_BGRA_SUPPORT,
+                        None,
+                        D3D11_SDK_VERSION,
+                        Some(&mut device),
+                        None,
+                        Some(&mut context),
+                    )
+                    .map_err(|e| BalamError::platform("Failed to create D3D11 device", e))?;
+                    let (Some(device), Some(context)) = (device, context) else {
+                        return Err(BalamError::Platform("D3D11 device not created".to_string()));
+                    };
+                    let duplication = output
+                        .cast::<IDXGIOutput1>()
+                        .and_then(|output| output.DuplicateOutput(&device))
+                        .map_err(|e| BalamError::platform("Failed to duplicate display", e))?;
+                    return Ok(OutputDuplication {
+                        device,
+                        context,
+                        duplication,
+                        desktop: desc.DesktopCoordinates,
+                    });
+                }
+            }
+        }
+        Err(BalamError::NotFound("Display showing the game window".to_string()))
+    }
+
+    /// Copies `area` (virtual desktop coordinates) out of the current desktop image.
+    fn read_area(output: &OutputDuplication, area: RECT) -> Result<CapturedFrame, BalamError> {
+        unsafe {
+            // The first frame of a new duplication is the full current image
+            let mut frame_info = DXGI_OUTDUPL_FRAME_INFO::default();
+            let mut resource: Option<IDXGIResource> = None;
+            output
+                .duplication
+                .AcquireNextFrame(FRAME_TIMEOUT_MS, &mut frame_info, &mut resource)
+                .map_err(|e| BalamError::platform("Failed to acquire desktop frame", e))?;
+            let result = resource
+                .ok_or_else(|| BalamError::Platform("Desktop frame was empty".to_string()))
+                .and_then(|resource| Self::copy_area(output, &resource, area));
+            let _ = output.duplication.ReleaseFrame();
+            result
+        }
+    }
+
+    unsafe fn copy_area(
+        output: &OutputDuplication,
+        resource: &IDXGIResource,
+        area: RECT,
+    ) -> Result<CapturedFrame, BalamError> {
+        let texture: ID3D11Texture2D = resource
+            .cast()
+            .map_err(|e| BalamError::platform("Desktop frame is not a texture", e))?;
+        let mut desc = D3D11_TEXTURE2D_DESC::default();
+        texture.GetDesc(&mut desc);
+
+        // Window rectangle relative to the display, clamped to it
+        let left = (area.left - output.desktop.left).clamp(0, desc.Width as i32) as u32;
+        let top = (area.top - output.desktop.top).clamp(0, desc.Height as i32) as u32;
+        let right = (area.right - output.desktop.left).clamp(0, desc.Width as i32) as u32;
+        let bottom = (area.bottom - output.desktop.top).clamp(0, desc.Height as i32) as u32;
+        if right <= left || bottom <= top {
+            return Err(BalamError::NotFound("Game window is not on screen".to_string()));
+        }
+
+        desc.Usage = D3D11_USAGE_STAGING;
+        desc.BindFlags = 0;
+        desc.CPUAccessFlags = D3D11_CPU_ACCESS_READ.0 as u32;
+        desc.MiscFlags = 0;
+        desc.MipLevels = 1;
+        desc.ArraySize = 1;
+        let mut staging = None;
+        output
+            .device
+            .CreateTexture2D(&desc, None, Some(&mut staging))
+            .map_err(|e| BalamError::platform("Failed to create staging texture", e))?;
+        let staging = staging.ok_or_else(|| BalamError::Platform("Staging texture not created".to_string()))?;
+        output.context.CopyResource(&staging, &texture);
+
+        let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+        output
+            .context
+            .Map(&staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped))
+            .map_err(|e| BalamError::platform("Failed to read desktop frame", e))?;
+
+        let (width, height) = (right - left, bottom - top);
+        let mut rgb = Vec::with_capacity(width as usize * height as usize * 3);
+        for y in top..bottom {
+            let row = std::slice::from_raw_parts(
+                mapped.pData.cast::<u8>().add(y as usize * mapped.RowPitch as usize),
+                desc.Width as usize * 4,
+            );
+            // Duplication frames are always B8G8R8A8
+            for bgra in row[left as usize * 4..right as usize * 4].chunks_exact(4) {
+                rgb.extend_from_slice(&[bgra[2], bgra[1], bgra[0]]);
+            }
+        }
+        output.context.Unmap(&staging, 0);
+
+        Ok(CapturedFrame { width, height, rgb })
+    }
+}
+
+impl Default for DxgiScreenCapture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScreenCapturePort for DxgiScreenCapture {
+    fn capture_window(&self, pid: Option<u32>) -> Result<CapturedFrame, BalamError> {
+        let hwnd = match pid {
+            Some(pid) => WindowsGameAdapter::new()
+                .main_window(pid)
+                .ok_or_else(|| BalamError::NotFound(format!("Window of PID {pid}")))?,
+            None => unsafe { GetForegroundWindow() },
+        };
+        if hwnd.0 == 0 {
+            return Err(BalamError::NotFound("Window to capture".to_string()));
+        }
+
+        let area = Self::client_area(hwnd)?;
+        let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+        let output = Self::duplicate_output(monitor)?;
+        let frame = Self::read_area(&output, area)?;
+        info!("📸 Captured {}x{} window", frame.width, frame.height);
+        Ok(frame)
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+use windows::core::GUID;
+use windows::Win32::System::Com::CoTaskMemFree;
+use windows::Win32::UI::Shell::{FOLDERID_Pictures, FOLDERID_Videos, SHGetKnownFolderPath, KF_FLAG_DEFAULT};
+
+/// A user library folder (Pictures, Videos), following OneDrive or
+/// manual redirection; `%USERPROFILE%\<fallback>` if the shell has none.
+pub(crate) fn user_folder(id: &GUID, fallback: &str) -> PathBuf {
+    unsafe {
+        if let Ok(path) = SHGetKnownFolderPath(id, KF_FLAG_DEFAULT, None) {
+            let dir = path.to_string().ok().map(PathBuf::from);
+            CoTaskMemFree(Some(path.0 as *const _));
+            if let Some(dir) = dir {
+                return dir;
+            }
+        }
+    }
+    std::env::var_os("USERPROFILE")
+        .map(|profile| PathBuf::from(profile).join(fallback))
+        .unwrap_or_else(std::env::temp_dir)
+}
+
+/// `Pictures\Balam\Screenshots`, one folder per game.
+pub(crate) fn screenshots_dir() -> PathBuf {
+    user_folder(&FOLDERID_Pictures, "Pictures")
+        .join("Balam")
+        .join("Screenshots")
+}
+
+/// `Videos\Balam\Clips`, one folder per game.
+pub(crate) fn clips_dir() -> PathBuf {
+    user_folder(&FOLDERID_Videos, "Videos").join("Balam").join("Clips")
+}
@@ -0,0 +1,281 @@
+use super::known_folders::{clips_dir, screenshots_dir};
+use super::mp4_segments::first_frame;
+use super::screenshot_store::{modified_secs, write_thumbnail, THUMBNAIL_DIR};
+use crate::domain::media::{media_file_stem, media_id, parse_media_id, MediaItem, MediaKind};
+use crate::domain::BalamError;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Per game folder: file name → library id of the game it was taken in.
+const INDEX_FILE: &str = ".media.json";
+
+/// Screenshots and clips of every game, as one gallery.
+///
+/// Captures stay plain files in the user's Pictures and Videos folders;
+/// the game id of each is kept in a small index next to them, so renaming
+/// a game (or its folder name changing) does not split its gallery.
+pub struct MediaLibrary {
+    screenshots: PathBuf,
+    clips: PathBuf,
+}
+
+impl MediaLibrary {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_roots(screenshots_dir(), clips_dir())
+    }
+
+    #[must_use]
+    pub fn with_roots(screenshots: PathBuf, clips: PathBuf) -> Self {
+        Self { screenshots, clips }
+    }
+
+    fn root(&self, kind: MediaKind) -> &Path {
+        match kind {
+            MediaKind::Screenshot => &self.screenshots,
+            MediaKind::Clip => &self.clips,
+        }
+    }
+
+    /// Records which game a new capture belongs to.
+    ///
+    /// # Errors
+    /// Returns error if the index cannot be written.
+    pub fn tag(&self, capture: &Path, game_id: &str) -> Result<(), BalamError> {
+        let (Some(folder), Some(file_name)) = (capture.parent(), capture.file_name()) else {
+            return Err(BalamError::InvalidArgument(format!(
+                "Not a capture: {}",
+                capture.display()
+            )));
+        };
+        let mut index = read_index(folder);
+        index.insert(file_name.to_string_lossy().into_owned(), game_id.to_string());
+        write_index(folder, &index)
+    }
+
+    /// Every capture, newest first; `game_id` limits it to one game's.
+    ///
+    /// Clips get their thumbnail (first frame) the first time they are listed.
+    ///
+    /// # Errors
+    /// Returns error if a capture folder exists but cannot be read.
+    pub fn list(&self, game_id: Option<&str>) -> Result<Vec<MediaItem>, BalamError> {
+        let mut items = Vec::new();
+        for kind in [MediaKind::Screenshot, MediaKind::Clip] {
+            let folders = match fs::read_dir(self.root(kind)) {
+                Ok(entries) => entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(BalamError::platform("Failed to read capture folder", e)),
+            };
+            for folder in folders {
+                let index = read_index(&folder);
+                let Ok(entries) = fs::read_dir(&folder) else { continue };
+                for path in entries.flatten().map(|entry| entry.path()) {
+                    if !has_extension(&path, kind) {
+                        continue;
+                    }
+                    let file_name = path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let tagged = index.get(&file_name);
+                    if game_id.is_some_and(|wanted| tagged.map(String::as_str) != Some(wanted)) {
+                        continue;
+                    }
+                    items.push(self.item(kind, &path, tagged.cloned()));
+                }
+            }
+        }
+        items.sort_by(|a, b| b.captured_at.cmp(&a.captured_at).then_with(|| b.path.cmp(&a.path)));
+        Ok(items)
+    }
+
+    /// Renames a capture (and its thumbnail), keeping the extension.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` for an unusable name or id or a name that
+    /// is already taken, `NotFound` if the capture is gone.
+    pub fn rename(&self, id: &str, new_name: &str) -> Result<MediaItem, BalamError> {
+        let (kind, path) = self.resolve(id)?;
+        let stem = media_file_stem(new_name)
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid name: {new_name}")))?;
+        let extension = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let folder = path.parent().unwrap_or(self.root(kind)).to_path_buf();
+        let renamed = folder.join(format!("{stem}.{extension}"));
+        if renamed == path {
+            return Ok(self.item(kind, &path, read_index(&folder).get(&file_name_of(&path)).cloned()));
+        }
+        if renamed.exists() {
+            return Err(BalamError::InvalidArgument(format!(
+                "{stem}.{extension} already exists"
+            )));
+        }
+
+        fs::rename(&path, &renamed).map_err(|e| BalamError::platform("Failed to rename capture", e))?;
+        let _ = fs::rename(thumbnail_of(&path), thumbnail_of(&renamed));
+
+        let mut index = read_index(&folder);
+        let game_id = index.remove(&file_name_of(&path));
+        if let Some(game_id) = &game_id {
+            index.insert(file_name_of(&renamed), game_id.clone());
+            write_index(&folder, &index)?;
+        }
+        info!("🖼️ Renamed {} → {}", path.display(), renamed.display());
+        Ok(self.item(kind, &renamed, game_id))
+    }
+
+    /// Deletes a capture, its thumbnail and its index entry.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` for a malformed id, `NotFound` if the
+    /// capture is gone, or the file system error.
+    pub fn delete(&self, id: &str) -> Result<(), BalamError> {
+        let (_, path) = self.resolve(id)?;
+        fs::remove_file(&path).map_err(|e| BalamError::platform("Failed to delete capture", e))?;
+        let _ = fs::remove_file(thumbnail_of(&path));
+
+        if let Some(folder) = path.parent() {
+            let mut index = read_index(folder);
+            if index.remove(&file_name_of(&path)).is_some() {
+                write_index(folder, &index)?;
+            }
+        }
+        info!("🗑️ Deleted {}", path.display());
+        Ok(())
+    }
+
+    fn resolve(&self, id: &str) -> Result<(MediaKind, PathBuf), BalamError> {
+        let (kind, folder, file_name) =
+            parse_media_id(id).ok_or_else(|| BalamError::InvalidArgument(format!("Invalid media id: {id}")))?;
+        let path = self.root(kind).join(folder).join(file_name);
+        if !path.is_file() || !has_extension(&path, kind) {
+            return Err(BalamError::NotFound(format!("Capture {id}")));
+        }
+        Ok((kind, path))
+    }
+
+    fn item(&self, kind: MediaKind, path: &Path, game_id: Option<String>) -> MediaItem {
+        let game = path
+            .parent()
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let file_name = file_name_of(path);
+        let thumbnail = thumbnail_of(path);
+        if kind == MediaKind::Clip && !thumbnail.exists() {
+            create_clip_thumbnail(path, &thumbnail);
+        }
+
+        MediaItem {
+            id: media_id(kind, &game, &file_name),
+            kind,
+            name: path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path: path.to_string_lossy().into_owned(),
+            thumbnail_path: thumbnail.exists().then(|| thumbnail.to_string_lossy().into_owned()),
+            game,
+            game_id,
+            captured_at: modified_secs(path),
+            size_bytes: fs::metadata(path).map_or(0, |meta| meta.len()),
+        }
+    }
+}
+
+impl Default for MediaLibrary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn has_extension(path: &Path, kind: MediaKind) -> bool {
+    let wanted = match kind {
+        MediaKind::Screenshot => "png",
+        MediaKind::Clip => "mp4",
+    };
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case(wanted))
+}
+
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// `<folder>\.thumbnails\<stem>.png` (the same name screenshots are saved with).
+fn thumbnail_of(path: &Path) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.parent()
+        .unwrap_or(Path::new(""))
+        .join(THUMBNAIL_DIR)
+        .join(format!("{stem}.png"))
+}
+
+fn create_clip_thumbnail(clip: &Path, thumbnail: &Path) {
+    let written = first_frame(clip).and_then(|frame| {
+        if let Some(dir) = thumbnail.parent() {
+            fs::create_dir_all(dir).map_err(|e| BalamError::platform("Failed to create thumbnail folder", e))?;
+        }
+        write_thumbnail(thumbnail, &frame)
+    });
+    if let Err(e) = written {
+        warn!("No thumbnail for {}: {}", clip.display(), e);
+    }
+}
+
+fn read_index(folder: &Path) -> BTreeMap<String, String> {
+    fs::read_to_string(folder.join(INDEX_FILE))
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn write_index(folder: &Path, index: &BTreeMap<String, String>) -> Result<(), BalamError> {
+    let json =
+        serde_json::to_string_pretty(index).map_err(|e| BalamError::platform("Failed to encode media index", e))?;
+    fs::write(folder.join(INDEX_FILE), json).map_err(|e| BalamError::platform("Failed to write media index", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_list_rename_delete() {
+        let root = std::env::temp_dir().join(format!("balam_media_{}", uuid::Uuid::new_v4()));
+        let library = MediaLibrary::with_roots(root.join("shots"), root.join("clips"));
+        let folder = root.join("shots").join("Hades II");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("a.png"), b"png").unwrap();
+        fs::write(folder.join("b.png"), b"png").unwrap();
+
+        library.tag(&folder.join("a.png"), "steam_1145350").unwrap();
+        assert_eq!(library.list(None).unwrap().len(), 2);
+        let tagged = library.list(Some("steam_1145350")).unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].id, "screenshot:Hades II/a.png");
+
+        let renamed = library.rename(&tagged[0].id, "Final boss").unwrap();
+        assert_eq!(renamed.name, "Final boss");
+        assert_eq!(renamed.game_id.as_deref(), Some("steam_1145350"));
+        assert!(matches!(
+            library.rename(&renamed.id, "b"),
+            Err(BalamError::InvalidArgument(_))
+        ));
+
+        library.delete(&renamed.id).unwrap();
+        assert!(library.list(Some("steam_1145350")).unwrap().is_empty());
+        assert!(matches!(library.delete(&renamed.id), Err(BalamError::NotFound(_))));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
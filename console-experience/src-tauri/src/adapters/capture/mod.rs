@@ -0,0 +1,11 @@
+mod dxgi_capture;
+mod known_folders;
+mod media_library;
+mod mp4_segments;
+mod replay_recorder;
+mod screenshot_store;
+
+pub use dxgi_capture::DxgiScreenCapture;
+pub use media_library::MediaLibrary;
+pub use replay_recorder::WgcReplayRecorder;
+pub use screenshot_store::ScreenshotStore;
@@ -0,0 +1,292 @@
+//! H.264 / MP4 through the Media Foundation sink writer: short segment
+//! files while recording, joined without re-encoding when a clip is saved.
+
+use crate::domain::media::ReplaySegment;
+use crate::domain::BalamError;
+use crate::ports::screen_capture_port::CapturedFrame;
+use std::path::Path;
+use windows::core::HSTRING;
+use windows::Win32::Media::MediaFoundation::{
+    IMFAttributes, IMFByteStream, IMFMediaType, IMFSample, IMFSinkWriter, MFCreateAttributes, MFCreateMediaType,
+    MFCreateMemoryBuffer, MFCreateSample, MFCreateSinkWriterFromURL, MFCreateSourceReaderFromURL, MFMediaType_Video,
+    MFShutdown, MFStartup, MFVideoFormat_H264, MFVideoFormat_RGB32, MFVideoInterlace_Progressive, MFSTARTUP_FULL,
+    MF_MT_AVG_BITRATE, MF_MT_DEFAULT_STRIDE, MF_MT_FRAME_RATE, MF_MT_FRAME_SIZE, MF_MT_INTERLACE_MODE,
+    MF_MT_MAJOR_TYPE, MF_MT_PIXEL_ASPECT_RATIO, MF_MT_SUBTYPE, MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS,
+    MF_SOURCE_READERF_ENDOFSTREAM, MF_SOURCE_READER_ENABLE_VIDEO_PROCESSING, MF_SOURCE_READER_FIRST_VIDEO_STREAM,
+    MF_VERSION,
+};
+use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
+
+/// Media Foundation time unit (100 ns) per millisecond.
+const TICKS_PER_MS: i64 = 10_000;
+
+/// Two 32-bit values packed the way `MF_MT_FRAME_SIZE` / `MF_MT_FRAME_RATE` expect.
+fn pack(high: u32, low: u32) -> u64 {
+    (u64::from(high) << 32) | u64::from(low)
+}
+
+fn video_type(subtype: &windows::core::GUID, width: u32, height: u32, fps: u32) -> windows::core::Result<IMFMediaType> {
+    unsafe {
+        let media_type = MFCreateMediaType()?;
+        media_type.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+        media_type.SetGUID(&MF_MT_SUBTYPE, subtype)?;
+        media_type.SetUINT32(&MF_MT_INTERLACE_MODE, MFVideoInterlace_Progressive.0 as u32)?;
+        media_type.SetUINT64(&MF_MT_FRAME_SIZE, pack(width, height))?;
+        media_type.SetUINT64(&MF_MT_FRAME_RATE, pack(fps, 1))?;
+        media_type.SetUINT64(&MF_MT_PIXEL_ASPECT_RATIO, pack(1, 1))?;
+        Ok(media_type)
+    }
+}
+
+fn hardware_attributes() -> windows::core::Result<IMFAttributes> {
+    unsafe {
+        let mut attributes = None;
+        MFCreateAttributes(&mut attributes, 1)?;
+        let attributes = attributes.ok_or_else(windows::core::Error::from_win32)?;
+        // GPU encoder when the driver has one (the software encoder is the fallback)
+        attributes.SetUINT32(&MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS, 1)?;
+        Ok(attributes)
+    }
+}
+
+/// One segment being encoded from BGRA frames.
+pub(crate) struct SegmentWriter {
+    writer: IMFSinkWriter,
+    stream: u32,
+    path: String,
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    frames: u32,
+    /// Timestamp of the last frame written, in milliseconds from the segment start
+    last_ms: u64,
+    frame_ms: u64,
+}
+
+impl SegmentWriter {
+    pub(crate) fn create(
+        path: &Path,
+        width: u32,
+        height: u32,
+        fps: u32,
+        bitrate_kbps: u32,
+    ) -> Result<Self, BalamError> {
+        let create = || -> windows::core::Result<(IMFSinkWriter, u32)> {
+            unsafe {
+                let writer = MFCreateSinkWriterFromURL(
+                    &HSTRING::from(path.as_os_str()),
+                    None::<&IMFByteStream>,
+                    &hardware_attributes()?,
+                )?;
+                let output = video_type(&MFVideoFormat_H264, width, height, fps)?;
+                output.SetUINT32(&MF_MT_AVG_BITRATE, bitrate_kbps * 1000)?;
+                let stream = writer.AddStream(&output)?;
+
+                let input = video_type(&MFVideoFormat_RGB32, width, height, fps)?;
+                // Positive stride: rows are top-down, as they come from the GPU
+                input.SetUINT32(&MF_MT_DEFAULT_STRIDE, width * 4)?;
+                writer.SetInputMediaType(stream, &input, None)?;
+                writer.BeginWriting()?;
+                Ok((writer, stream))
+            }
+        };
+        let (writer, stream) = create().map_err(|e| BalamError::platform("Failed to start H.264 encoder", e))?;
+        Ok(Self {
+            writer,
+            stream,
+            path: path.to_string_lossy().into_owned(),
+            width,
+            height,
+            frames: 0,
+            last_ms: 0,
+            frame_ms: u64::from(1000 / fps.max(1)),
+        })
+    }
+
+    /// Milliseconds since the first frame.
+    pub(crate) fn elapsed_ms(&self) -> u64 {
+        self.last_ms
+    }
+
+    /// Encodes one frame of `width * height` BGRA pixels, `row_pitch` bytes per row.
+    ///
+    /// # Safety
+    /// `pixels` must point to at least `height` rows of `row_pitch` bytes.
+    pub(crate) unsafe fn write_frame(
+        &mut self,
+        pixels: *const u8,
+        row_pitch: usize,
+        at_ms: u64,
+    ) -> Result<(), BalamError> {
+        let row_bytes = self.width as usize * 4;
+        let length = row_bytes * self.height as usize;
+        let write = || -> windows::core::Result<()> {
+            let buffer = MFCreateMemoryBuffer(length as u32)?;
+            let mut data = std::ptr::null_mut();
+            buffer.Lock(&mut data, None, None)?;
+            for row in 0..self.height as usize {
+                std::ptr::copy_nonoverlapping(pixels.add(row * row_pitch), data.add(row * row_bytes), row_bytes);
+            }
+            buffer.Unlock()?;
+            buffer.SetCurrentLength(length as u32)?;
+
+            let sample = MFCreateSample()?;
+            sample.AddBuffer(&buffer)?;
+            sample.SetSampleTime(at_ms as i64 * TICKS_PER_MS)?;
+            sample.SetSampleDuration(self.frame_ms as i64 * TICKS_PER_MS)?;
+            self.writer.WriteSample(self.stream, &sample)
+        };
+        write().map_err(|e| BalamError::platform("Failed to encode frame", e))?;
+        self.frames += 1;
+        self.last_ms = at_ms;
+        Ok(())
+    }
+
+    /// Closes the file. `None` if no frame was written (the file is deleted).
+    pub(crate) fn finish(self) -> Option<ReplaySegment> {
+        let finalized = unsafe { self.writer.Finalize() };
+        if self.frames == 0 || finalized.is_err() {
+            let _ = std::fs::remove_file(&self.path);
+            return None;
+        }
+        Some(ReplaySegment {
+            path: self.path,
+            duration_ms: self.last_ms + self.frame_ms,
+            width: self.width,
+            height: self.height,
+        })
+    }
+}
+
+/// Joins segments into one MP4, copying the H.264 samples as they are.
+///
+/// Returns the clip length in milliseconds.
+pub(crate) fn concatenate(segments: &[ReplaySegment], destination: &Path) -> Result<u64, BalamError> {
+    let join = || -> windows::core::Result<u64> {
+        unsafe {
+            let writer = MFCreateSinkWriterFromURL(
+                &HSTRING::from(destination.as_os_str()),
+                None::<&IMFByteStream>,
+                None::<&IMFAttributes>,
+            )?;
+            let first_stream = MF_SOURCE_READER_FIRST_VIDEO_STREAM.0 as u32;
+            let mut stream = None;
+            let mut offset_ms = 0u64;
+
+            for segment in segments {
+                let reader =
+                    MFCreateSourceReaderFromURL(&HSTRING::from(segment.path.as_str()), None::<&IMFAttributes>)?;
+                if stream.is_none() {
+                    // Same type in and out: no encoder is inserted (passthrough)
+                    let native = reader.GetNativeMediaType(first_stream, 0)?;
+                    let index = writer.AddStream(&native)?;
+                    writer.SetInputMediaType(index, &native, None)?;
+                    writer.BeginWriting()?;
+                    stream = Some(index);
+                }
+                let Some(index) = stream else { break };
+
+                loop {
+                    let mut flags = 0u32;
+                    let mut timestamp = 0i64;
+                    let mut sample: Option<IMFSample> = None;
+                    reader.ReadSample(
+                        first_stream,
+                        0,
+                        None,
+                        Some(&mut flags),
+                        Some(&mut timestamp),
+                        Some(&mut sample),
+                    )?;
+                    if flags & MF_SOURCE_READERF_ENDOFSTREAM.0 as u32 != 0 {
+                        break;
+                    }
+                    if let Some(sample) = sample {
+                        sample.SetSampleTime(timestamp + offset_ms as i64 * TICKS_PER_MS)?;
+                        writer.WriteSample(index, &sample)?;
+                    }
+                }
+                offset_ms += segment.duration_ms;
+            }
+            writer.Finalize()?;
+            Ok(offset_ms)
+        }
+    };
+    join().map_err(|e| {
+        let _ = std::fs::remove_file(destination);
+        BalamError::platform("Failed to write replay clip", e)
+    })
+}
+
+/// Decodes the first frame of a video (for clip thumbnails).
+pub(crate) fn first_frame(path: &Path) -> Result<CapturedFrame, BalamError> {
+    let decode = || -> windows::core::Result<CapturedFrame> {
+        unsafe {
+            let mut attributes = None;
+            MFCreateAttributes(&mut attributes, 1)?;
+            let attributes = attributes.ok_or_else(windows::core::Error::from_win32)?;
+            // Lets the reader convert H.264 output (NV12) to RGB32
+            attributes.SetUINT32(&MF_SOURCE_READER_ENABLE_VIDEO_PROCESSING, 1)?;
+            let reader = MFCreateSourceReaderFromURL(&HSTRING::from(path.as_os_str()), &attributes)?;
+
+            let stream = MF_SOURCE_READER_FIRST_VIDEO_STREAM.0 as u32;
+            let wanted = MFCreateMediaType()?;
+            wanted.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)?;
+            wanted.SetGUID(&MF_MT_SUBTYPE, &MFVideoFormat_RGB32)?;
+            reader.SetCurrentMediaType(stream, None, &wanted)?;
+            let current = reader.GetCurrentMediaType(stream)?;
+            let size = current.GetUINT64(&MF_MT_FRAME_SIZE)?;
+            let (width, height) = ((size >> 32) as u32, size as u32);
+            // Uncompressed RGB is bottom-up unless the type says otherwise
+            let stride = current
+                .GetUINT32(&MF_MT_DEFAULT_STRIDE)
+                .map_or(-(width as i32 * 4), |stride| stride as i32);
+
+            let mut sample: Option<IMFSample> = None;
+            let mut flags = 0u32;
+            reader.ReadSample(stream, 0, None, Some(&mut flags), None, Some(&mut sample))?;
+            let sample = sample.ok_or_else(windows::core::Error::from_win32)?;
+            let buffer = sample.ConvertToContiguousBuffer()?;
+
+            let mut data = std::ptr::null_mut();
+            let mut length = 0u32;
+            buffer.Lock(&mut data, None, Some(&mut length))?;
+            let row_bytes = width as usize * 4;
+            let mut rgb = Vec::with_capacity(width as usize * height as usize * 3);
+            if length as usize >= row_bytes * height as usize {
+                for y in 0..height as usize {
+                    let row = if stride < 0 { height as usize - 1 - y } else { y };
+                    let pixels = std::slice::from_raw_parts(data.add(row * row_bytes), row_bytes);
+                    for bgra in pixels.chunks_exact(4) {
+                        rgb.extend_from_slice(&[bgra[2], bgra[1], bgra[0]]);
+                    }
+                }
+            }
+            buffer.Unlock()?;
+            if rgb.is_empty() {
+                return Err(windows::core::Error::from_win32());
+            }
+            Ok(CapturedFrame { width, height, rgb })
+        }
+    };
+
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        MFStartup(MF_VERSION, MFSTARTUP_FULL)
+            .map_err(|e| BalamError::platform("Failed to start Media Foundation", e))?;
+    }
+    let frame = decode().map_err(|e| BalamError::platform("Failed to decode clip", e));
+    unsafe {
+        let _ = MFShutdown();
+    }
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_attribute() {
+        assert_eq!(pack(1920, 1080), 0x0000_0780_0000_0438);
+    }
+}
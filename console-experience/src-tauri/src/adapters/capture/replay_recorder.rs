@@ -0,0 +1,394 @@
+use super::known_folders::clips_dir;
+use super::mp4_segments::{concatenate, SegmentWriter};
+use crate::adapters::game::WindowsGameAdapter;
+use crate::domain::media::{game_folder_name, segments_for_clip, segments_to_prune, ReplayClip, ReplaySegment};
+use crate::domain::BalamError;
+use crate::infrastructure::app_dirs;
+use crate::infrastructure::clock::now_secs;
+use crate::ports::replay_port::{ReplayConfig, ReplayRecorderPort};
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use windows::core::ComInterface;
+use windows::Graphics::Capture::{
+    Direct3D11CaptureFrame, Direct3D11CaptureFramePool, GraphicsCaptureItem, GraphicsCaptureSession,
+};
+use windows::Graphics::DirectX::Direct3D11::IDirect3DDevice;
+use windows::Graphics::DirectX::DirectXPixelFormat;
+use windows::Win32::Foundation::{HMODULE, HWND};
+use windows::Win32::Graphics::Direct3D::D3D_DRIVER_TYPE_HARDWARE;
+use windows::Win32::Graphics::Direct3D11::{
+    D3D11CreateDevice, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, D3D11_CPU_ACCESS_READ,
+    D3D11_CREATE_DEVICE_BGRA_SUPPORT, D3D11_MAPPED_SUBRESOURCE, D3D11_MAP_READ, D3D11_SDK_VERSION,
+    D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+};
+use windows::Win32::Graphics::Dxgi::{IDXGIAdapter, IDXGIDevice};
+use windows::Win32::Media::MediaFoundation::{MFShutdown, MFStartup, MFSTARTUP_FULL, MF_VERSION};
+use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
+use windows::Win32::System::WinRT::Direct3D11::{CreateDirect3D11DeviceFromDXGIDevice, IDirect3DDxgiInterfaceAccess};
+use windows::Win32::System::WinRT::Graphics::Capture::IGraphicsCaptureItemInterop;
+use windows::Win32::UI::WindowsAndMessaging::IsWindow;
+
+/// Length of one recording segment; clips start on a segment boundary.
+const SEGMENT_MS: u64 = 5_000;
+/// How long `save_clip` waits for the segment in progress to be closed.
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(5);
+const PIXEL_FORMAT: DirectXPixelFormat = DirectXPixelFormat::B8G8R8A8UIntNormalized;
+
+enum RecorderCommand {
+    /// Close the current segment so it can be saved (acknowledged when done)
+    Flush(Sender<()>),
+    Stop,
+}
+
+struct Recording {
+    pid: u32,
+    commands: Sender<RecorderCommand>,
+    thread: JoinHandle<()>,
+}
+
+/// Replay buffer on Windows.Graphics.Capture and the Media Foundation H.264 encoder.
+///
+/// The game window is encoded into 5-second MP4 segments under
+/// `%LOCALAPPDATA%\com.console.experience\replay`; segments older than the
+/// buffer are deleted as new ones complete. Saving joins the newest ones into
+/// `Videos\Balam\Clips\<Game>` without re-encoding.
+pub struct WgcReplayRecorder {
+    recording: Mutex<Option<Recording>>,
+    segments: Arc<Mutex<Vec<ReplaySegment>>>,
+    buffer_dir: PathBuf,
+}
+
+impl WgcReplayRecorder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            recording: Mutex::new(None),
+            segments: Arc::new(Mutex::new(Vec::new())),
+            buffer_dir: app_dirs::app_data_dir().join("replay"),
+        }
+    }
+
+    /// Deletes the previous game's buffer.
+    fn clear_buffer(&self) {
+        self.segments.lock().clear();
+        if let Ok(entries) = std::fs::read_dir(&self.buffer_dir) {
+            for entry in entries.flatten() {
+                let _ = std::fs::remove_file(entry.path());
+            }
+        }
+    }
+}
+
+impl Default for WgcReplayRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReplayRecorderPort for WgcReplayRecorder {
+    fn start(&self, pid: u32, config: ReplayConfig) -> Result<(), BalamError> {
+        if !GraphicsCaptureSession::IsSupported().unwrap_or(false) {
+            return Err(BalamError::NotSupported("Window capture".to_string()));
+        }
+        let hwnd = WindowsGameAdapter::new()
+            .main_window(pid)
+            .ok_or_else(|| BalamError::NotFound(format!("Window of PID {pid}")))?;
+
+        self.stop();
+        self.clear_buffer();
+        std::fs::create_dir_all(&self.buffer_dir)
+            .map_err(|e| BalamError::platform("Failed to create replay folder", e))?;
+
+        let (commands, receiver) = mpsc::channel();
+        let segments = Arc::clone(&self.segments);
+        let buffer_dir = self.buffer_dir.clone();
+        let thread = std::thread::Builder::new()
+            .name("replay-recorder".to_string())
+            .spawn(move || {
+                if let Err(e) = record(hwnd, config, &receiver, &segments, &buffer_dir) {
+                    warn!("Replay recording stopped: {}", e);
+                }
+            })
+            .map_err(|e| BalamError::platform("Failed to start replay recorder", e))?;
+
+        info!("🎬 Replay buffer recording PID {} ({}s)", pid, config.buffer_seconds);
+        *self.recording.lock() = Some(Recording { pid, commands, thread });
+        Ok(())
+    }
+
+    fn stop(&self) {
+        let Some(recording) = self.recording.lock().take() else {
+            return;
+        };
+        let _ = recording.commands.send(RecorderCommand::Stop);
+        let _ = recording.thread.join();
+        info!("🎬 Replay buffer stopped");
+    }
+
+    fn recording_pid(&self) -> Option<u32> {
+        self.recording
+            .lock()
+            .as_ref()
+            .filter(|recording| !recording.thread.is_finished())
+            .map(|recording| recording.pid)
+    }
+
+    fn buffered_seconds(&self) -> u32 {
+        let segments = self.segments.lock();
+        let ms: u64 = segments_for_clip(&segments, u32::MAX)
+            .iter()
+            .map(|segment| segment.duration_ms)
+            .sum();
+        (ms / 1000) as u32
+    }
+
+    fn save_clip(&self, game_title: &str, seconds: u32) -> Result<ReplayClip, BalamError> {
+        if let Some(recording) = self.recording.lock().as_ref() {
+            let (ack, done) = mpsc::channel();
+            if recording.commands.send(RecorderCommand::Flush(ack)).is_ok() {
+                let _ = done.recv_timeout(FLUSH_TIMEOUT);
+            }
+        }
+
+        let game = game_folder_name(game_title);
+        let folder = clips_dir().join(&game);
+        std::fs::create_dir_all(&folder).map_err(|e| BalamError::platform("Failed to create clip folder", e))?;
+        let path = folder.join(format!("{}.mp4", chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")));
+
+        // Held while joining so the recorder cannot prune these files meanwhile
+        let segments = self.segments.lock();
+        let clip = segments_for_clip(&segments, seconds);
+        if clip.is_empty() {
+            return Err(BalamError::NotFound("Nothing recorded yet".to_string()));
+        }
+        let duration_ms = concatenate(clip, &path)?;
+        drop(segments);
+
+        info!("🎬 Replay saved: {} ({} ms)", path.display(), duration_ms);
+        Ok(ReplayClip {
+            path: path.to_string_lossy().into_owned(),
+            game,
+            duration_seconds: duration_ms as f64 / 1000.0,
+            saved_at: now_secs(),
+        })
+    }
+}
+
+/// Recorder thread body: runs until stopped or the window closes.
+fn record(
+    hwnd: HWND,
+    config: ReplayConfig,
+    commands: &Receiver<RecorderCommand>,
+    segments: &Mutex<Vec<ReplaySegment>>,
+    buffer_dir: &std::path::Path,
+) -> Result<(), BalamError> {
+    unsafe {
+        let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+        MFStartup(MF_VERSION, MFSTARTUP_FULL)
+            .map_err(|e| BalamError::platform("Failed to start Media Foundation", e))?;
+    }
+    let result = capture_loop(hwnd, config, commands, segments, buffer_dir);
+    unsafe {
+        let _ = MFShutdown();
+    }
+    result
+}
+
+fn capture_loop(
+    hwnd: HWND,
+    config: ReplayConfig,
+    commands: &Receiver<RecorderCommand>,
+    segments: &Mutex<Vec<ReplaySegment>>,
+    buffer_dir: &std::path::Path,
+) -> Result<(), BalamError> {
+    let capture = Capture::open(hwnd).map_err(|e| BalamError::platform("Failed to capture game window", e))?;
+    let frame_interval_ms = u64::from(1000 / config.fps.max(1));
+    let started = Instant::now();
+    let mut next_frame_ms = 0;
+    let mut writer: Option<SegmentWriter> = None;
+    let mut segment_start_ms = 0;
+    let mut segment_index = 0u32;
+
+    let result = loop {
+        match commands.try_recv() {
+            Ok(RecorderCommand::Flush(ack)) => {
+                finish_segment(writer.take(), segments, config.buffer_seconds);
+                let _ = ack.send(());
+            },
+            Ok(RecorderCommand::Stop) | Err(TryRecvError::Disconnected) => break Ok(()),
+            Err(TryRecvError::Empty) => {},
+        }
+        if !unsafe { IsWindow(hwnd) }.as_bool() {
+            info!("🎬 Game window closed");
+            break Ok(());
+        }
+
+        let Ok(frame) = capture.pool.TryGetNextFrame() else {
+            std::thread::sleep(Duration::from_millis(2));
+            continue;
+        };
+        let now_ms = started.elapsed().as_millis() as u64;
+        if now_ms < next_frame_ms {
+            // Game renders faster than the recording frame rate
+            continue;
+        }
+        next_frame_ms = (now_ms / frame_interval_ms + 1) * frame_interval_ms;
+
+        let (width, height) = match capture.frame_size(&frame) {
+            Ok(Some(size)) => size,
+            Ok(None) => continue,
+            Err(e) => break Err(BalamError::platform("Failed to resize capture", e)),
+        };
+        let rotate = writer.as_ref().map_or(true, |current| {
+            (current.width, current.height) != (width, height) || current.elapsed_ms() >= SEGMENT_MS
+        });
+        if rotate {
+            finish_segment(writer.take(), segments, config.buffer_seconds);
+            let path = buffer_dir.join(format!("segment-{segment_index:05}.mp4"));
+            segment_index += 1;
+            match SegmentWriter::create(&path, width, height, config.fps, config.bitrate_kbps) {
+                Ok(created) => writer = Some(created),
+                Err(e) => break Err(e),
+            }
+            segment_start_ms = now_ms;
+        }
+        if let Some(current) = writer.as_mut() {
+            if let Err(e) = capture.encode(&frame, current, now_ms - segment_start_ms) {
+                break Err(e);
+            }
+        }
+    };
+
+    finish_segment(writer, segments, config.buffer_seconds);
+    let _ = capture.session.Close();
+    let _ = capture.pool.Close();
+    result
+}
+
+/// Adds a finished segment to the buffer and drops the ones no longer needed.
+fn finish_segment(writer: Option<SegmentWriter>, segments: &Mutex<Vec<ReplaySegment>>, buffer_seconds: u32) {
+    let Some(segment) = writer.and_then(SegmentWriter::finish) else {
+        return;
+    };
+    let mut segments = segments.lock();
+    segments.push(segment);
+    let prune = segments_to_prune(&segments, buffer_seconds);
+    for old in segments.drain(..prune) {
+        let _ = std::fs::remove_file(&old.path);
+    }
+}
+
+/// Windows.Graphics.Capture session on one window.
+struct Capture {
+    device: ID3D11Device,
+    context: ID3D11DeviceContext,
+    direct3d: IDirect3DDevice,
+    pool: Direct3D11CaptureFramePool,
+    session: GraphicsCaptureSession,
+    size: Mutex<windows::Graphics::SizeInt32>,
+    /// CPU-readable copy of the last frame, recreated when the size changes
+    staging: Mutex<Option<ID3D11Texture2D>>,
+}
+
+impl Capture {
+    fn open(hwnd: HWND) -> windows::core::Result<Self> {
+        unsafe {
+            let mut device = None;
+            let mut context = None;
+            D3D11CreateDevice(
+                None::<&IDXGIAdapter>,
+                D3D_DRIVER_TYPE_HARDWARE,
+                HMODULE::default(),
+                D3D11_CREATE_DEVICE_BGRA_SUPPORT,
+                None,
+                D3D11_SDK_VERSION,
+                Some(&mut device),
+                None,
+                Some(&mut context),
+            )?;
+            let (Some(device), Some(context)) = (device, context) else {
+                return Err(windows::core::Error::from_win32());
+            };
+            let direct3d: IDirect3DDevice =
+                CreateDirect3D11DeviceFromDXGIDevice(&device.cast::<IDXGIDevice>()?)?.cast()?;
+
+            let interop = windows::core::factory::<GraphicsCaptureItem, IGraphicsCaptureItemInterop>()?;
+            let item: GraphicsCaptureItem = interop.CreateForWindow(hwnd)?;
+            let size = item.Size()?;
+            let pool = Direct3D11CaptureFramePool::CreateFreeThreaded(&direct3d, PIXEL_FORMAT, 2, size)?;
+            let session = pool.CreateCaptureSession(&item)?;
+            session.StartCapture()?;
+
+            Ok(Self {
+                device,
+                context,
+                direct3d,
+                pool,
+                session,
+                size: Mutex::new(size),
+                staging: Mutex::new(None),
+            })
+        }
+    }
+
+    /// Encoded size of the frame (even, as H.264 requires), or `None` when the
+    /// window was resized: the pool is rebuilt and the frame skipped.
+    fn frame_size(&self, frame: &Direct3D11CaptureFrame) -> windows::core::Result<Option<(u32, u32)>> {
+        let content = frame.ContentSize()?;
+        let mut size = self.size.lock();
+        if (content.Width, content.Height) != (size.Width, size.Height) {
+            *size = content;
+            self.pool.Recreate(&self.direct3d, PIXEL_FORMAT, 2, content)?;
+            return Ok(None);
+        }
+        let (width, height) = (size.Width.max(0) as u32 & !1, size.Height.max(0) as u32 & !1);
+        Ok((width > 0 && height > 0).then_some((width, height)))
+    }
+
+    fn encode(&self, frame: &Direct3D11CaptureFrame, writer: &mut SegmentWriter, at_ms: u64) -> Result<(), BalamError> {
+        unsafe {
+            let texture: ID3D11Texture2D = frame
+                .Surface()
+                .and_then(|surface| surface.cast::<IDirect3DDxgiInterfaceAccess>())
+                .and_then(|access| access.GetInterface())
+                .map_err(|e| BalamError::platform("Failed to read capture frame", e))?;
+            let mut desc = D3D11_TEXTURE2D_DESC::default();
+            texture.GetDesc(&mut desc);
+
+            let mut staging = self.staging.lock();
+            let reusable = staging.as_ref().is_some_and(|existing| {
+                let mut existing_desc = D3D11_TEXTURE2D_DESC::default();
+                existing.GetDesc(&mut existing_desc);
+                (existing_desc.Width, existing_desc.Height) == (desc.Width, desc.Height)
+            });
+            if !reusable {
+                desc.Usage = D3D11_USAGE_STAGING;
+                desc.BindFlags = 0;
+                desc.CPUAccessFlags = D3D11_CPU_ACCESS_READ.0 as u32;
+                desc.MiscFlags = 0;
+                let mut created = None;
+                self.device
+                    .CreateTexture2D(&desc, None, Some(&mut created))
+                    .map_err(|e| BalamError::platform("Failed to create staging texture", e))?;
+                *staging = created;
+            }
+            let Some(staging) = staging.as_ref() else {
+                return Err(BalamError::Platform("Staging texture not created".to_string()));
+            };
+
+            self.context.CopyResource(staging, &texture);
+            let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+            self.context
+                .Map(staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped))
+                .map_err(|e| BalamError::platform("Failed to read capture frame", e))?;
+            let result = writer.write_frame(mapped.pData.cast::<u8>(), mapped.RowPitch as usize, at_ms);
+            self.context.Unmap(staging, 0);
+            result
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use super::known_folders::screenshots_dir;
+use crate::domain::media::{game_folder_name, thumbnail_size, Screenshot};
+use crate::domain::BalamError;
+use crate::ports::screen_capture_port::CapturedFrame;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tracing::{info, warn};
+
+/// Thumbnails live next to the captures, hidden from the game folder listing.
+pub(crate) const THUMBNAIL_DIR: &str = ".thumbnails";
+
+/// Screenshots on disk: `Pictures\Balam\Screenshots\<Game>\<timestamp>.png`.
+pub struct ScreenshotStore {
+    root: PathBuf,
+}
+
+impl ScreenshotStore {
+    /// Store under the user's Pictures library (follows OneDrive redirection).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_root(screenshots_dir())
+    }
+
+    #[must_use]
+    pub fn with_root(root: PathBuf) -> Self {
+        Self { root }
+    }
+
+    /// Writes the frame (and its thumbnail) into the game's folder.
+    ///
+    /// # Errors
+    /// Returns error if the folder or the PNG cannot be written.
+    pub fn save(&self, game_title: &str, frame: &CapturedFrame) -> Result<Screenshot, BalamError> {
+        let game = game_folder_name(game_title);
+        let folder = self.root.join(&game);
+        fs::create_dir_all(folder.join(THUMBNAIL_DIR))
+            .map_err(|e| BalamError::platform("Failed to create screenshot folder", e))?;
+
+        let stamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+        let mut name = format!("{stamp}.png");
+        let mut counter = 2;
+        while folder.join(&name).exists() {
+            name = format!("{stamp}_{counter}.png");
+            counter += 1;
+        }
+
+        let path = folder.join(&name);
+        write_png(&path, frame.width, frame.height, &frame.rgb)?;
+
+        let thumbnail = folder.join(THUMBNAIL_DIR).join(&name);
+        let thumbnail_path = match write_thumbnail(&thumbnail, frame) {
+            Ok(()) => Some(thumbnail.to_string_lossy().into_owned()),
+            Err(e) => {
+                warn!("Failed to write thumbnail: {}", e);
+                None
+            },
+        };
+
+        info!("📸 Screenshot saved: {}", path.display());
+        Ok(Screenshot {
+            path: path.to_string_lossy().into_owned(),
+            thumbnail_path,
+            game,
+            taken_at: modified_secs(&path),
+        })
+    }
+
+    /// Every screenshot, newest first, optionally only one game's folder.
+    ///
+    /// # Errors
+    /// Returns error if the screenshot folder exists but cannot be read.
+    pub fn list(&self, game_title: Option<&str>) -> Result<Vec<Screenshot>, BalamError> {
+        let folders: Vec<PathBuf> = match game_title {
+            Some(title) => vec![self.root.join(game_folder_name(title))],
+            None => match fs::read_dir(&self.root) {
+                Ok(entries) => entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect(),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(e) => return Err(BalamError::platform("Failed to read screenshot folder", e)),
+            },
+        };
+
+        let mut screenshots = Vec::new();
+        for folder in folders {
+            let Ok(entries) = fs::read_dir(&folder) else { continue };
+            let game = folder
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if !path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("png")) {
+                    continue;
+                }
+                let thumbnail = path
+                    .file_name()
+                    .map(|name| folder.join(THUMBNAIL_DIR).join(name))
+                    .filter(|thumbnail| thumbnail.exists());
+                screenshots.push(Screenshot {
+                    taken_at: modified_secs(&path),
+                    path: path.to_string_lossy().into_owned(),
+                    thumbnail_path: thumbnail.map(|thumbnail| thumbnail.to_string_lossy().into_owned()),
+                    game: game.clone(),
+                });
+            }
+        }
+        screenshots.sort_by(|a, b| b.taken_at.cmp(&a.taken_at).then_with(|| b.path.cmp(&a.path)));
+        Ok(screenshots)
+    }
+}
+
+impl Default for ScreenshotStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writes a gallery-sized PNG of the frame.
+pub(crate) fn write_thumbnail(path: &Path, frame: &CapturedFrame) -> Result<(), BalamError> {
+    let (width, height) = thumbnail_size(frame.width, frame.height);
+    write_png(path, width, height, &downscale(frame, width, height))
+}
+
+pub(crate) fn modified_secs(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn write_png(path: &Path, width: u32, height: u32, rgb: &[u8]) -> Result<(), BalamError> {
+    let file = File::create(path).map_err(|e| BalamError::platform("Failed to create screenshot file", e))?;
+    let mut encoder = png::Encoder::new(BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(rgb))
+        .map_err(|e| BalamError::platform("Failed to encode PNG", e))
+}
+
+/// Box-filter resize (each output pixel averages the source pixels it covers).
+fn downscale(frame: &CapturedFrame, width: u32, height: u32) -> Vec<u8> {
+    let (src_width, src_height) = (frame.width as usize, frame.height as usize);
+    let (width, height) = (width as usize, height as usize);
+    let mut out = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        let (y0, y1) = source_span(y, src_height, height);
+        for x in 0..width {
+            let (x0, x1) = source_span(x, src_width, width);
+            let mut sum = [0u64; 3];
+            for sy in y0..y1 {
+                for sx in x0..x1 {
+                    let index = (sy * src_width + sx) * 3;
+                    for (channel, total) in sum.iter_mut().enumerate() {
+                        *total += u64::from(frame.rgb[index + channel]);
+                    }
+                }
+            }
+            let count = ((y1 - y0) * (x1 - x0)) as u64;
+            out.extend(sum.iter().map(|total| (total / count) as u8));
+        }
+    }
+    out
+}
+
+/// Source pixels `[start, end)` covered by output pixel `index` (at least one).
+fn source_span(index: usize, source: usize, target: usize) -> (usize, usize) {
+    let start = index * source / target;
+    (start, ((index + 1) * source / target).max(start + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(width: u32, height: u32) -> CapturedFrame {
+        // Left half black, right half white
+        let rgb = (0..width * height)
+            .flat_map(|i| {
+                let value = if i % width < width / 2 { 0 } else { 255 };
+                [value, value, value]
+            })
+            .collect();
+        CapturedFrame { width, height, rgb }
+    }
+
+    #[test]
+    fn test_downscale_averages_blocks() {
+        let small = downscale(&frame(8, 4), 2, 1);
+        assert_eq!(small, vec![0, 0, 0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn test_save_and_list_per_game() {
+        let root = std::env::temp_dir().join(format!("balam_shots_{}", uuid::Uuid::new_v4()));
+        let store = ScreenshotStore::with_root(root.clone());
+
+        let first = store.save("Elden Ring", &frame(640, 360)).unwrap();
+        let second = store.save("Elden Ring", &frame(640, 360)).unwrap();
+        store.save("Hades II", &frame(100, 50)).unwrap();
+        assert_ne!(first.path, second.path);
+        assert!(first.thumbnail_path.is_some());
+
+        assert_eq!(store.list(Some("Elden Ring")).unwrap().len(), 2);
+        let all = store.list(None).unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(all.iter().any(|shot| shot.game == "Hades II"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,3 @@
+mod windows_shell_adapter;
+
+pub use windows_shell_adapter::WindowsShellAdapter;
@@ -0,0 +1,185 @@
+/// Windows Shell Adapter - Winlogon shell replacement
+///
+/// Replaces `explorer.exe` with the Balam boot supervisor by writing the
+/// Winlogon `Shell` value:
+/// - Per user: `HKCU\Software\Microsoft\Windows NT\CurrentVersion\Winlogon\Shell`
+/// - Machine-wide: same path under HKLM (requires administrator)
+///
+/// The previous value is backed up under `Software\Balam\ConsoleMode` in the
+/// same hive so `unregister()` restores exactly what was there before.
+use std::io;
+use tracing::{info, warn};
+use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, KEY_READ, KEY_WRITE};
+use winreg::{RegKey, HKEY};
+
+use crate::domain::console_mode::{is_balam_shell, supervisor_shell_command, ShellScope, EXPLORER_SHELL};
+
+const WINLOGON_KEY: &str = r"Software\Microsoft\Windows NT\CurrentVersion\Winlogon";
+const BACKUP_KEY: &str = r"Software\Balam\ConsoleMode";
+const SHELL_VALUE: &str = "Shell";
+const PREVIOUS_SHELL_VALUE: &str = "PreviousShell";
+
+/// Winlogon shell registry adapter.
+pub struct WindowsShellAdapter;
+
+impl WindowsShellAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn hive(scope: ShellScope) -> HKEY {
+        match scope {
+            ShellScope::CurrentUser => HKEY_CURRENT_USER,
+            ShellScope::AllUsers => HKEY_LOCAL_MACHINE,
+        }
+    }
+
+    /// Reads the `Shell` value configured in a scope (`None` if not set).
+    #[must_use]
+    pub fn shell_in_scope(&self, scope: ShellScope) -> Option<String> {
+        RegKey::predef(Self::hive(scope))
+            .open_subkey_with_flags(WINLOGON_KEY, KEY_READ)
+            .and_then(|key| key.get_value::<String, _>(SHELL_VALUE))
+            .ok()
+            .filter(|shell| !shell.trim().is_empty())
+    }
+
+    /// Effective shell for the current user (per-user value overrides HKLM).
+    #[must_use]
+    pub fn effective_shell(&self) -> String {
+        self.shell_in_scope(ShellScope::CurrentUser)
+            .or_else(|| self.shell_in_scope(ShellScope::AllUsers))
+            .unwrap_or_else(|| EXPLORER_SHELL.to_string())
+    }
+
+    /// Scope where Balam is currently registered as shell, if any.
+    #[must_use]
+    pub fn registered_scope(&self) -> Option<ShellScope> {
+        [ShellScope::CurrentUser, ShellScope::AllUsers]
+            .into_iter()
+            .find(|scope| self.shell_in_scope(*scope).is_some_and(|s| is_balam_shell(&s)))
+    }
+
+    /// Registers the boot supervisor as the Windows shell.
+    ///
+    /// # Arguments
+    /// * `scope` - Per-user or machine-wide registration
+    /// * `supervisor_path` - Absolute path to `watchdog.exe`
+    ///
+    /// # Errors
+    /// Returns error if the registry cannot be written (e.g. HKLM without admin).
+    pub fn register(&self, scope: ShellScope, supervisor_path: &str) -> Result<(), String> {
+        let hive = RegKey::predef(Self::hive(scope));
+
+        // Back up previous shell once (re-registering must not overwrite the backup)
+        let previous = self.shell_in_scope(scope);
+        if previous.as_deref().is_some_and(is_balam_shell) {
+            info!("Balam already registered as shell ({:?}), updating command", scope);
+        } else {
+            let (backup, _) = hive
+                .create_subkey(BACKUP_KEY)
+                .map_err(|e| format!("Failed to create backup key: {e}"))?;
+            backup
+                .set_value(PREVIOUS_SHELL_VALUE, &previous.unwrap_or_default())
+                .map_err(|e| format!("Failed to back up previous shell: {e}"))?;
+        }
+
+        let (winlogon, _) = hive
+            .create_subkey(WINLOGON_KEY)
+            .map_err(|e| Self::access_error(&e, scope))?;
+        winlogon
+            .set_value(SHELL_VALUE, &supervisor_shell_command(supervisor_path))
+            .map_err(|e| Self::access_error(&e, scope))?;
+
+        info!("🎮 Console mode registered ({:?}): {}", scope, supervisor_path);
+        Ok(())
+    }
+
+    /// Restores the shell that was configured before `register()`.
+    ///
+    /// For the per-user scope an empty backup means "no override", so the value
+    /// is deleted and Windows falls back to the machine-wide shell. For the
+    /// machine-wide scope `explorer.exe` is written.
+    ///
+    /// # Errors
+    /// Returns error if the registry cannot be written.
+    pub fn unregister(&self, scope: ShellScope) -> Result<(), String> {
+        let hive = RegKey::predef(Self::hive(scope));
+
+        let previous = hive
+            .open_subkey_with_flags(BACKUP_KEY, KEY_READ)
+            .and_then(|key| key.get_value::<String, _>(PREVIOUS_SHELL_VALUE))
+            .ok()
+            .filter(|shell| !shell.trim().is_empty() && !is_balam_shell(shell));
+
+        let winlogon = hive
+            .open_subkey_with_flags(WINLOGON_KEY, KEY_READ | KEY_WRITE)
+            .map_err(|e| Self::access_error(&e, scope))?;
+
+        match (scope, previous) {
+            (_, Some(shell)) => winlogon.set_value(SHELL_VALUE, &shell),
+            (ShellScope::CurrentUser, None) => match winlogon.delete_value(SHELL_VALUE) {
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+                other => other,
+            },
+            (ShellScope::AllUsers, None) => winlogon.set_value(SHELL_VALUE, &EXPLORER_SHELL),
+        }
+        .map_err(|e| Self::access_error(&e, scope))?;
+
+        if let Err(e) = hive.delete_subkey_all(BACKUP_KEY) {
+            warn!("Failed to remove console mode backup key: {}", e);
+        }
+
+        info!("🖥️ Console mode unregistered ({:?}), explorer restored", scope);
+        Ok(())
+    }
+
+    /// Restores explorer in every scope where Balam is registered.
+    ///
+    /// Used by the watchdog's safe-mode path: after a crash loop the next
+    /// sign-in must boot into a working desktop. Machine-wide restore is
+    /// best-effort (the watchdog may not be elevated).
+    pub fn restore_explorer(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        for scope in [ShellScope::CurrentUser, ShellScope::AllUsers] {
+            if self.shell_in_scope(scope).is_some_and(|s| is_balam_shell(&s)) {
+                if let Err(e) = self.unregister(scope) {
+                    errors.push(e);
+                }
+            }
+        }
+        errors
+    }
+
+    fn access_error(e: &io::Error, scope: ShellScope) -> String {
+        if e.kind() == io::ErrorKind::PermissionDenied {
+            format!("Access denied writing {scope:?} shell. Administrator privileges required.")
+        } else {
+            format!("Failed to update Winlogon shell: {e}")
+        }
+    }
+}
+
+impl Default for WindowsShellAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_shell_is_never_empty() {
+        let adapter = WindowsShellAdapter::new();
+        assert!(!adapter.effective_shell().is_empty());
+    }
+
+    #[test]
+    fn test_registered_scope_does_not_panic() {
+        let adapter = WindowsShellAdapter::new();
+        let _ = adapter.registered_scope();
+    }
+}
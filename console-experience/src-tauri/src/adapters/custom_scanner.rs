@@ -0,0 +1,355 @@
+use crate::domain::custom_scanner::{
+    custom_game_id, has_wildcards, parse_custom_game_id, render_launch, wildcard_match, GlobRule, RegistryRoot,
+    RegistryRule, ScannerManifest, TitleSource, MANIFEST_EXTENSIONS,
+};
+use crate::domain::errors::ScanError;
+use crate::domain::standalone::expand_env_vars;
+use crate::domain::{Game, GameSource, InstallState};
+use crate::infrastructure::app_dirs;
+use crate::ports::GameScanner;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+use winreg::RegKey;
+
+/// Folder of the scanner manifests, relative to the app data folder.
+const MANIFEST_DIR: &str = "scanners.d";
+
+/// Deepest folder level a `**` pattern descends to.
+const MAX_GLOB_DEPTH: usize = 6;
+
+/// Scanner driven by user manifests, for stores the app does not support.
+///
+/// Every `*.toml` / `*.json` file of `%LOCALAPPDATA%\com.console.experience\scanners.d`
+/// is a [`ScannerManifest`] describing where a store installs its games
+/// (path patterns, registry keys) and how to start them (protocol template).
+/// Manifests are read on every scan; an invalid one is logged and skipped
+/// without affecting the others.
+///
+/// # Metadata Quality
+/// - **Title**: Registry display name, or folder / executable name
+/// - **Executable Path**: First executable matching the rule
+/// - **Cover Art**: None (fetched by the metadata sync like manual games)
+pub struct CustomScanner {
+    dir: PathBuf,
+}
+
+impl CustomScanner {
+    /// Creates a scanner reading the default manifest folder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_dir(Self::default_dir())
+    }
+
+    /// Creates a scanner reading manifests from `dir`.
+    #[must_use]
+    pub fn with_dir(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// `%LOCALAPPDATA%\com.console.experience\scanners.d`
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir().join(MANIFEST_DIR)
+    }
+
+    /// Valid manifests of the folder, sorted by file name.
+    #[must_use]
+    pub fn manifests(&self) -> Vec<ScannerManifest> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|extension| extension.to_str())
+                    .is_some_and(|extension| {
+                        MANIFEST_EXTENSIONS
+                            .iter()
+                            .any(|known| extension.eq_ignore_ascii_case(known))
+                    })
+            })
+            .collect();
+        files.sort();
+
+        let mut keys = HashSet::new();
+        files
+            .iter()
+            .filter_map(|file| {
+                let extension = file.extension()?.to_str()?;
+                let manifest = fs::read_to_string(file)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| ScannerManifest::parse(extension, &content));
+                match manifest {
+                    Ok(manifest) if keys.insert(manifest.key.clone()) => Some(manifest),
+                    Ok(manifest) => {
+                        warn!("Scanner manifest {:?} reuses key \"{}\", skipped", file, manifest.key);
+                        None
+                    },
+                    Err(e) => {
+                        warn!("Invalid scanner manifest {:?}: {}", file, e);
+                        None
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Protocol URL starting a custom game, when its manifest has a launch
+    /// template (`None` = start the executable).
+    ///
+    /// # Errors
+    /// Returns error if the template is not a protocol URL.
+    pub fn launch_url(id: &str, path: &str) -> Result<Option<String>, String> {
+        let Some((key, raw_id)) = parse_custom_game_id(id) else {
+            return Ok(None);
+        };
+        let Some(template) = Self::new()
+            .manifests()
+            .into_iter()
+            .find(|manifest| manifest.key == key)
+            .and_then(|manifest| manifest.launch)
+        else {
+            return Ok(None);
+        };
+        let dir = Path::new(path)
+            .parent()
+            .map(|dir| dir.to_string_lossy().to_string())
+            .unwrap_or_default();
+        render_launch(&template, raw_id, path, &dir).map(Some)
+    }
+
+    fn scan_internal(&self) -> Vec<Game> {
+        let manifests = self.manifests();
+        if manifests.is_empty() {
+            return Vec::new();
+        }
+        info!("Scanning {} custom scanner manifests...", manifests.len());
+
+        let mut games = Vec::new();
+        for manifest in &manifests {
+            let mut seen = HashSet::new();
+            let found = manifest
+                .glob
+                .iter()
+                .flat_map(|rule| scan_glob(manifest, rule))
+                .chain(manifest.registry.iter().flat_map(|rule| scan_registry(manifest, rule)))
+                .filter(|game| seen.insert(game.id.clone()));
+            let before = games.len();
+            games.extend(found);
+            debug!("{}: {} games", manifest.name, games.len() - before);
+        }
+        info!("Custom scan complete. Found {} games", games.len());
+        games
+    }
+}
+
+impl Default for CustomScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameScanner for CustomScanner {
+    fn scan(&self) -> Result<Vec<Game>, ScanError> {
+        Ok(self.scan_internal())
+    }
+
+    fn source(&self) -> GameSource {
+        GameSource::Manual
+    }
+
+    fn name(&self) -> &'static str {
+        "Custom"
+    }
+
+    fn priority(&self) -> u32 {
+        4 // Same tier as the registry scanner, registered before it
+    }
+}
+
+fn scan_glob(manifest: &ScannerManifest, rule: &GlobRule) -> Vec<Game> {
+    let Some(pattern) = expand_env_vars(&rule.pattern, |name| std::env::var(name).ok()) else {
+        debug!("{}: undefined variable in {}", manifest.name, rule.pattern);
+        return Vec::new();
+    };
+    expand_glob(&pattern)
+        .into_iter()
+        .filter(|path| is_game_executable(manifest, path))
+        .filter_map(|executable| {
+            let folder = executable.parent()?.file_name()?.to_string_lossy().to_string();
+            let title = match rule.title {
+                TitleSource::Folder => folder.clone(),
+                TitleSource::FileStem => executable.file_stem()?.to_string_lossy().to_string(),
+            };
+            Some(game(manifest, &folder, title, &executable))
+        })
+        .collect()
+}
+
+fn scan_registry(manifest: &ScannerManifest, rule: &RegistryRule) -> Vec<Game> {
+    let root = RegKey::predef(match rule.root {
+        RegistryRoot::LocalMachine => HKEY_LOCAL_MACHINE,
+        RegistryRoot::CurrentUser => HKEY_CURRENT_USER,
+    });
+    let Ok(key) = root.open_subkey(&rule.key) else {
+        debug!("{}: registry key {} not found", manifest.name, rule.key);
+        return Vec::new();
+    };
+
+    let entries: Vec<(String, RegKey)> = match &rule.subkeys {
+        Some(pattern) => key
+            .enum_keys()
+            .flatten()
+            .filter(|name| wildcard_match(pattern, name))
+            .filter_map(|name| key.open_subkey(&name).ok().map(|subkey| (name, subkey)))
+            .collect(),
+        None => {
+            let name = rule.key.rsplit('\\').next().unwrap_or(&rule.key).to_string();
+            vec![(name, key)]
+        },
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|(name, entry)| {
+            let title: String = entry.get_value(&rule.title_value).ok()?;
+            let install_dir: String = entry.get_value(&rule.path_value).ok()?;
+            let raw_id = match &rule.id_value {
+                Some(value) => entry.get_value::<String, _>(value).ok()?,
+                None => name,
+            };
+            let pattern = format!("{}\\{}", install_dir.trim_end_matches(['\\', '/']), rule.executable);
+            let executable = expand_glob(&pattern)
+                .into_iter()
+                .find(|path| is_game_executable(manifest, path))?;
+            Some(game(manifest, &raw_id, title, &executable))
+        })
+        .collect()
+}
+
+fn game(manifest: &ScannerManifest, raw_id: &str, title: String, executable: &Path) -> Game {
+    Game {
+        id: custom_game_id(&manifest.key, raw_id),
+        raw_id: raw_id.to_string(),
+        title,
+        path: executable.to_string_lossy().to_string(),
+        image: None,
+        hero_image: None,
+        logo: None,
+        last_played: None,
+        source: GameSource::Manual,
+        install_state: InstallState::Installed,
+        launcher_dependency: None,
+        update_pending: false,
+    }
+}
+
+fn is_game_executable(manifest: &ScannerManifest, path: &Path) -> bool {
+    path.is_file()
+        && path
+            .file_name()
+            .is_some_and(|name| !manifest.is_excluded(&name.to_string_lossy()))
+}
+
+/// Paths matching a glob pattern, sorted.
+fn expand_glob(pattern: &str) -> Vec<PathBuf> {
+    let segments: Vec<&str> = pattern
+        .split(['\\', '/'])
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let literal = segments.iter().take_while(|segment| !has_wildcards(segment)).count();
+    // Joined as text: `PathBuf::from("C:").join(..)` would be drive-relative
+    let base = if pattern.starts_with(['\\', '/']) {
+        format!("\\{}", segments[..literal].join("\\"))
+    } else {
+        format!("{}\\", segments[..literal].join("\\"))
+    };
+
+    let mut matches = Vec::new();
+    expand_segments(Path::new(&base), &segments[literal..], 0, &mut matches);
+    matches.sort();
+    matches.dedup();
+    matches
+}
+
+fn expand_segments(dir: &Path, segments: &[&str], depth: usize, matches: &mut Vec<PathBuf>) {
+    let Some((&segment, rest)) = segments.split_first() else {
+        if dir.exists() {
+            matches.push(dir.to_path_buf());
+        }
+        return;
+    };
+
+    if segment == "**" {
+        expand_segments(dir, rest, depth, matches);
+        if depth < MAX_GLOB_DEPTH {
+            for subdir in subdirectories(dir) {
+                expand_segments(&subdir, segments, depth + 1, matches);
+            }
+        }
+        return;
+    }
+
+    if !has_wildcards(segment) {
+        expand_segments(&dir.join(segment), rest, depth, matches);
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        if wildcard_match(segment, &entry.file_name().to_string_lossy()) {
+            expand_segments(&entry.path(), rest, depth, matches);
+        }
+    }
+}
+
+fn subdirectories(dir: &Path) -> Vec<PathBuf> {
+    fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_finds_executables_and_skips_excluded_ones() {
+        let root = std::env::temp_dir().join(format!("balam_custom_scanner_{}", std::process::id()));
+        let game_dir = root.join("Library").join("Some Game").join("bin");
+        fs::create_dir_all(&game_dir).unwrap();
+        fs::write(game_dir.join("SomeGame.exe"), b"").unwrap();
+        fs::write(game_dir.join("unins000.exe"), b"").unwrap();
+        fs::write(game_dir.join("readme.txt"), b"").unwrap();
+
+        let manifest = ScannerManifest::parse(
+            "json",
+            &serde_json::json!({
+                "name": "Test",
+                "key": "test",
+                "glob": [{ "pattern": format!("{}\\Library\\**\\*.exe", root.display()) }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let games = scan_glob(&manifest, &manifest.glob[0]);
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].id, "custom_test_bin");
+        assert_eq!(games[0].title, "bin");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,213 @@
+use crate::domain::display::ColorProfile;
+use crate::domain::BalamError;
+use once_cell::sync::OnceCell;
+use std::ffi::c_void;
+use tracing::{info, warn};
+use windows::core::PCWSTR;
+use windows::Win32::Graphics::Gdi::{
+    CreateDCW, DeleteDC, EnumDisplayDevicesW, DISPLAY_DEVICEW, DISPLAY_DEVICE_ATTACHED_TO_DESKTOP,
+};
+use windows::Win32::UI::ColorSystem::SetDeviceGammaRamp;
+
+// NvAPI entry points, resolved through nvapi_QueryInterface
+const NVAPI_INITIALIZE: u32 = 0x0150_E828;
+const NVAPI_ENUM_NVIDIA_DISPLAY_HANDLE: u32 = 0x9ABD_D40D;
+const NVAPI_GET_DVC_INFO: u32 = 0x4085_DE45;
+const NVAPI_SET_DVC_LEVEL: u32 = 0x1724_09B4;
+const NVAPI_OK: i32 = 0;
+
+/// NV_DISPLAY_DVC_INFO (version 1)
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)] // Written by the driver
+struct NvDvcInfo {
+    version: u32,
+    current_level: i32,
+    min_level: i32,
+    max_level: i32,
+}
+
+type NvQueryInterfaceFn = unsafe extern "C" fn(u32) -> *const c_void;
+type NvInitializeFn = unsafe extern "C" fn() -> i32;
+type NvEnumDisplayHandleFn = unsafe extern "C" fn(u32, *mut *mut c_void) -> i32;
+type NvGetDvcInfoFn = unsafe extern "C" fn(*mut c_void, u32, *mut NvDvcInfo) -> i32;
+type NvSetDvcLevelFn = unsafe extern "C" fn(*mut c_void, u32, i32) -> i32;
+
+/// NVIDIA digital vibrance through nvapi64.dll (installed with the driver).
+struct NvApi {
+    _lib: libloading::Library,
+    enum_display_handle: NvEnumDisplayHandleFn,
+    get_dvc_info: NvGetDvcInfoFn,
+    set_dvc_level: NvSetDvcLevelFn,
+}
+
+/// Loaded once per process; `None` without an NVIDIA driver.
+static NVAPI: OnceCell<Option<NvApi>> = OnceCell::new();
+
+impl NvApi {
+    fn get() -> Option<&'static NvApi> {
+        NVAPI.get_or_init(|| unsafe { Self::load() }).as_ref()
+    }
+
+    unsafe fn load() -> Option<Self> {
+        let lib = libloading::Library::new("nvapi64.dll").ok()?;
+        let query: libloading::Symbol<NvQueryInterfaceFn> = lib.get(b"nvapi_QueryInterface").ok()?;
+        let resolve = |id: u32| {
+            let ptr = query(id);
+            (!ptr.is_null()).then_some(ptr)
+        };
+
+        let initialize: NvInitializeFn = std::mem::transmute(resolve(NVAPI_INITIALIZE)?);
+        let enum_display_handle: NvEnumDisplayHandleFn =
+            std::mem::transmute(resolve(NVAPI_ENUM_NVIDIA_DISPLAY_HANDLE)?);
+        let get_dvc_info: NvGetDvcInfoFn = std::mem::transmute(resolve(NVAPI_GET_DVC_INFO)?);
+        let set_dvc_level: NvSetDvcLevelFn = std::mem::transmute(resolve(NVAPI_SET_DVC_LEVEL)?);
+
+        if initialize() != NVAPI_OK {
+            warn!("NvAPI initialization failed, digital vibrance unavailable");
+            return None;
+        }
+
+        info!("NvAPI loaded (digital vibrance available)");
+        Some(Self {
+            _lib: lib,
+            enum_display_handle,
+            get_dvc_info,
+            set_dvc_level,
+        })
+    }
+
+    /// Sets vibrance (0-100 mapped onto the driver's DVC range) on every NVIDIA display.
+    fn set_vibrance(&self, vibrance: u32) -> Result<usize, BalamError> {
+        let mut updated = 0;
+        for index in 0.. {
+            let mut handle: *mut c_void = std::ptr::null_mut();
+            if unsafe { (self.enum_display_handle)(index, &mut handle) } != NVAPI_OK {
+                break;
+            }
+
+            let mut info = NvDvcInfo {
+                version: std::mem::size_of::<NvDvcInfo>() as u32 | (1 << 16),
+                ..NvDvcInfo::default()
+            };
+            let status = unsafe { (self.get_dvc_info)(handle, 0, &mut info) };
+            if status != NVAPI_OK {
+                return Err(BalamError::Platform(format!("NvAPI_GetDVCInfo failed: {status}")));
+            }
+
+            let range = i64::from(info.max_level - info.min_level);
+            let level = info.min_level + (range * i64::from(vibrance) / 100) as i32;
+            let status = unsafe { (self.set_dvc_level)(handle, 0, level) };
+            if status != NVAPI_OK {
+                return Err(BalamError::Platform(format!("NvAPI_SetDVCLevel failed: {status}")));
+            }
+            updated += 1;
+        }
+        Ok(updated)
+    }
+}
+
+/// Display color control: gamma ramps (temperature, gamma) and digital vibrance.
+///
+/// # Features
+/// - **Night light / temperature**: `SetDeviceGammaRamp` on every display attached to the desktop
+/// - **Gamma**: folded into the same ramp
+/// - **Vibrance**: NVIDIA digital vibrance (NvAPI); ignored with a warning on other GPUs
+///
+/// Gamma ramps are reset by Windows on display mode changes and resume, so
+/// callers re-apply the effective profile after those events.
+pub struct ColorManager;
+
+impl Default for ColorManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Applies a color profile to every display.
+    ///
+    /// # Errors
+    /// - `BalamError::InvalidArgument` - Profile values out of range
+    /// - `BalamError::NotFound` - No display attached to the desktop
+    /// - `BalamError::NotSupported` - The driver rejected the gamma ramp
+    pub fn apply(&self, profile: &ColorProfile) -> Result<(), BalamError> {
+        profile.validate().map_err(BalamError::InvalidArgument)?;
+        self.set_gamma_ramp(&profile.gamma_ramp())?;
+
+        match NvApi::get() {
+            Some(nvapi) => {
+                nvapi.set_vibrance(profile.vibrance)?;
+            },
+            None if profile.vibrance > 0 => {
+                warn!("Vibrance {} requested but not supported on this GPU", profile.vibrance);
+            },
+            None => {},
+        }
+
+        info!(
+            "🎨 Color profile applied ({}K, gamma {}, vibrance {})",
+            profile.temperature_k, profile.gamma, profile.vibrance
+        );
+        Ok(())
+    }
+
+    /// Checks whether digital vibrance can be adjusted (NVIDIA driver present).
+    #[must_use]
+    pub fn supports_vibrance(&self) -> bool {
+        NvApi::get().is_some()
+    }
+
+    #[allow(clippy::unused_self)]
+    fn set_gamma_ramp(&self, ramp: &[[u16; 256]; 3]) -> Result<(), BalamError> {
+        let mut applied = 0;
+        let mut rejected = Vec::new();
+
+        unsafe {
+            let mut device: DISPLAY_DEVICEW = std::mem::zeroed();
+            device.cb = std::mem::size_of::<DISPLAY_DEVICEW>() as u32;
+
+            let mut index = 0;
+            while EnumDisplayDevicesW(PCWSTR::null(), index, &mut device, 0).as_bool() {
+                index += 1;
+                if (device.StateFlags & DISPLAY_DEVICE_ATTACHED_TO_DESKTOP) == 0 {
+                    continue;
+                }
+
+                let hdc = CreateDCW(PCWSTR::null(), PCWSTR(device.DeviceName.as_ptr()), PCWSTR::null(), None);
+                if hdc.is_invalid() {
+                    continue;
+                }
+
+                if SetDeviceGammaRamp(hdc, ramp.as_ptr() as *const c_void).as_bool() {
+                    applied += 1;
+                } else {
+                    rejected.push(
+                        String::from_utf16_lossy(&device.DeviceName)
+                            .trim_end_matches('\0')
+                            .to_string(),
+                    );
+                }
+                let _ = DeleteDC(hdc);
+            }
+        }
+
+        if applied == 0 && rejected.is_empty() {
+            return Err(BalamError::NotFound("No display attached to the desktop".to_string()));
+        }
+        if !rejected.is_empty() {
+            warn!("Gamma ramp rejected by: {:?}", rejected);
+            if applied == 0 {
+                return Err(BalamError::NotSupported(
+                    "Gamma ramp rejected by the display driver".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use crate::adapters::display::monitors;
+use crate::domain::BalamError;
+use tracing::{debug, info, warn};
+use windows::Win32::Devices::Display::{
+    DestroyPhysicalMonitors, GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR,
+    GetVCPFeatureAndVCPFeatureReply, SetVCPFeature, PHYSICAL_MONITOR,
+};
+use windows::Win32::Graphics::Gdi::HMONITOR;
+
+/// MCCS VCP code of the luminance (brightness) control
+const VCP_BRIGHTNESS: u8 = 0x10;
+/// MCCS VCP code of the contrast control
+const VCP_CONTRAST: u8 = 0x12;
+
+/// Physical monitors behind one GDI monitor, released on drop.
+struct PhysicalMonitors(Vec<PHYSICAL_MONITOR>);
+
+impl PhysicalMonitors {
+    fn of(monitor: HMONITOR) -> Option<Self> {
+        unsafe {
+            let mut count = 0u32;
+            if GetNumberOfPhysicalMonitorsFromHMONITOR(monitor, &mut count).is_err() || count == 0 {
+                return None;
+            }
+            let mut physical = vec![PHYSICAL_MONITOR::default(); count as usize];
+            GetPhysicalMonitorsFromHMONITOR(monitor, &mut physical).ok()?;
+            Some(Self(physical))
+        }
+    }
+
+    /// Current and maximum value of a VCP control, from the first physical
+    /// monitor that answers.
+    fn read(&self, code: u8) -> Option<(u32, u32)> {
+        self.0.iter().find_map(|monitor| read_vcp(monitor, code))
+    }
+
+    /// Writes a VCP control on every physical monitor; `true` if one accepted it.
+    fn write(&self, code: u8, level: u32) -> bool {
+        let mut written = false;
+        for monitor in &self.0 {
+            // Values are in the monitor's own range
+            let Some((_, maximum)) = read_vcp(monitor, code) else {
+                continue;
+            };
+            let value = percent_to_vcp(level, maximum);
+            written |= unsafe { SetVCPFeature(monitor.hPhysicalMonitor, code, value) } != 0;
+        }
+        written
+    }
+}
+
+fn read_vcp(monitor: &PHYSICAL_MONITOR, code: u8) -> Option<(u32, u32)> {
+    let mut current = 0u32;
+    let mut maximum = 0u32;
+    let ok = unsafe {
+        GetVCPFeatureAndVCPFeatureReply(
+            monitor.hPhysicalMonitor,
+            code,
+            None,
+            &raw mut current,
+            Some(&raw mut maximum),
+        )
+    } != 0;
+    (ok && maximum > 0).then_some((current, maximum))
+}
+
+impl Drop for PhysicalMonitors {
+    fn drop(&mut self) {
+        if !self.0.is_empty() {
+            let _ = unsafe { DestroyPhysicalMonitors(&self.0) };
+        }
+    }
+}
+
+/// DDC/CI control of external monitors (brightness and contrast).
+///
+/// Talks MCCS over the monitor's I²C bus through the Windows monitor
+/// configuration API (`dxva2`). Each command is a bus round trip of ~40-50ms,
+/// so nothing is polled: values are read and written on demand.
+///
+/// Monitors are picked by GDI device name (`\\.\DISPLAY2`); `None` means every
+/// external monitor, which is what the single brightness slider drives when
+/// docked.
+///
+/// # Requirements
+/// DDC/CI enabled in the monitor's on-screen menu (on by default on most
+/// monitors, often off on TVs).
+pub struct DdcCiAdapter;
+
+impl Default for DdcCiAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DdcCiAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Brightness (0-100) of a monitor, `None` if it does not answer DDC/CI.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    pub fn get_brightness(&self, device: Option<&str>) -> Result<Option<u32>, BalamError> {
+        self.read(device, VCP_BRIGHTNESS)
+    }
+
+    /// Sets the brightness (0-100) of a monitor, or of every external one.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    /// - `BalamError::NotSupported` - No monitor accepted the command
+    pub fn set_brightness(&self, device: Option<&str>, level: u32) -> Result<(), BalamError> {
+        self.write(device, VCP_BRIGHTNESS, level)
+    }
+
+    /// Contrast (0-100) of a monitor, `None` if it does not answer DDC/CI.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    pub fn get_contrast(&self, device: Option<&str>) -> Result<Option<u32>, BalamError> {
+        self.read(device, VCP_CONTRAST)
+    }
+
+    /// Sets the contrast (0-100) of a monitor, or of every external one.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    /// - `BalamError::NotSupported` - No monitor accepted the command
+    pub fn set_contrast(&self, device: Option<&str>, level: u32) -> Result<(), BalamError> {
+        self.write(device, VCP_CONTRAST, level)
+    }
+
+    /// Whether any external monitor answers a DDC/CI brightness read.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        self.read(None, VCP_BRIGHTNESS).is_ok_and(|level| level.is_some())
+    }
+
+    #[allow(clippy::unused_self)]
+    fn read(&self, device: Option<&str>, code: u8) -> Result<Option<u32>, BalamError> {
+        let level = Self::targets(device)?
+            .iter()
+            .find_map(|monitors| monitors.read(code))
+            .map(|(current, maximum)| vcp_to_percent(current, maximum));
+        debug!("DDC/CI VCP 0x{:02X} of {:?}: {:?}", code, device, level);
+        Ok(level)
+    }
+
+    #[allow(clippy::unused_self)]
+    fn write(&self, device: Option<&str>, code: u8, level: u32) -> Result<(), BalamError> {
+        let level = level.min(100);
+        let mut written = false;
+        for monitors in Self::targets(device)? {
+            written |= monitors.write(code, level);
+        }
+        if written {
+            info!(
+                "✅ DDC/CI VCP 0x{:02X} set to {}% on {}",
+                code,
+                level,
+                device.unwrap_or("all monitors")
+            );
+            Ok(())
+        } else {
+            warn!("No monitor accepted DDC/CI VCP 0x{:02X}", code);
+            Err(BalamError::NotSupported(
+                "DDC/CI (enable it in the monitor's menu)".to_string(),
+            ))
+        }
+    }
+
+    /// Physical monitors of the named GDI monitor, or of every external one.
+    fn targets(device: Option<&str>) -> Result<Vec<PhysicalMonitors>, BalamError> {
+        let gdi = monitors::enumerate();
+        let selected: Vec<HMONITOR> = match device {
+            Some(device) => {
+                let monitor = gdi
+                    .iter()
+                    .find(|monitor| monitor.device_name.eq_ignore_ascii_case(device))
+                    .ok_or_else(|| BalamError::NotFound(format!("Monitor {device}")))?;
+                vec![monitor.handle]
+            },
+            None => {
+                let targets = monitors::targets();
+                gdi.iter()
+                    .filter(|monitor| {
+                        !targets
+                            .get(&monitor.device_name)
+                            .is_some_and(|target| target.is_internal)
+                    })
+                    .map(|monitor| monitor.handle)
+                    .collect()
+            },
+        };
+        Ok(selected.into_iter().filter_map(PhysicalMonitors::of).collect())
+    }
+}
+
+/// VCP value scaled to 0-100 (monitors report their own maximum, usually 100).
+fn vcp_to_percent(value: u32, maximum: u32) -> u32 {
+    if maximum == 0 {
+        return 0;
+    }
+    ((u64::from(value.min(maximum)) * 100 + u64::from(maximum) / 2) / u64::from(maximum)) as u32
+}
+
+/// Percentage scaled to a monitor's VCP range.
+fn percent_to_vcp(level: u32, maximum: u32) -> u32 {
+    ((u64::from(level.min(100)) * u64::from(maximum) + 50) / 100) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vcp_scaling_round_trips() {
+        assert_eq!(vcp_to_percent(50, 100), 50);
+        assert_eq!(vcp_to_percent(255, 255), 100);
+        assert_eq!(vcp_to_percent(128, 255), 50);
+        assert_eq!(vcp_to_percent(300, 100), 100);
+        assert_eq!(vcp_to_percent(10, 0), 0);
+
+        assert_eq!(percent_to_vcp(50, 255), 128);
+        assert_eq!(percent_to_vcp(150, 100), 100);
+        for level in 0..=100 {
+            assert_eq!(vcp_to_percent(percent_to_vcp(level, 255), 255), level);
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use crate::domain::scaling::{
+    sharpness_from_driver, sharpness_to_driver, ScalingCapabilities, ScalingState, ScalingTechnology,
+};
+use crate::domain::BalamError;
+use crate::ports::scaling_port::GpuScalingPort;
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use std::ffi::c_void;
+use tracing::{info, warn};
+
+// NvAPI driver settings (DRS) entry points, resolved through nvapi_QueryInterface
+const NVAPI_INITIALIZE: u32 = 0x0150_E828;
+const NVAPI_DRS_CREATE_SESSION: u32 = 0x0694_D52E;
+const NVAPI_DRS_DESTROY_SESSION: u32 = 0xDAD9_CFF8;
+const NVAPI_DRS_LOAD_SETTINGS: u32 = 0x375D_BD6B;
+const NVAPI_DRS_SAVE_SETTINGS: u32 = 0xFCBC_7E14;
+const NVAPI_DRS_GET_BASE_PROFILE: u32 = 0xDA84_66A0;
+const NVAPI_DRS_GET_SETTING: u32 = 0x73BF_8338;
+const NVAPI_DRS_SET_SETTING: u32 = 0x577D_D202;
+const NVAPI_OK: i32 = 0;
+/// The setting was never changed from the driver default
+const NVAPI_SETTING_NOT_FOUND: i32 = -160;
+
+/// NV_QUALITY_UPSCALING (NVIDIA Image Scaling), global profile: 0 = off, 1 = on
+const NIS_SETTING_ID: u32 = 0x1044_4444;
+const NVDRS_DWORD_TYPE: u32 = 0;
+
+const ADLX_OK: i32 = 0;
+
+/// NVDRS_SETTING (version 1). Values are a union of a DWORD, a wide string
+/// and a 4096-byte binary blob; only the DWORD (first word) is used here.
+#[repr(C)]
+#[allow(dead_code)] // Written by the driver
+struct NvDrsSetting {
+    version: u32,
+    setting_name: [u16; 2048],
+    setting_id: u32,
+    setting_type: u32,
+    setting_location: u32,
+    is_current_predefined: u32,
+    is_predefined_valid: u32,
+    predefined_value: [u32; 1025],
+    current_value: [u32; 1025],
+}
+
+impl NvDrsSetting {
+    fn dword(setting_id: u32, value: u32) -> Box<Self> {
+        let mut setting = Box::new(Self {
+            version: std::mem::size_of::<Self>() as u32 | (1 << 16),
+            setting_name: [0; 2048],
+            setting_id,
+            setting_type: NVDRS_DWORD_TYPE,
+            setting_location: 0,
+            is_current_predefined: 0,
+            is_predefined_valid: 0,
+            predefined_value: [0; 1025],
+            current_value: [0; 1025],
+        });
+        setting.current_value[0] = value;
+        setting
+    }
+}
+
+type NvQueryInterfaceFn = unsafe extern "C" fn(u32) -> *const c_void;
+type NvInitializeFn = unsafe extern "C" fn() -> i32;
+type NvDrsCreateSessionFn = unsafe extern "C" fn(*mut *mut c_void) -> i32;
+type NvDrsSessionFn = unsafe extern "C" fn(*mut c_void) -> i32;
+type NvDrsGetBaseProfileFn = unsafe extern "C" fn(*mut c_void, *mut *mut c_void) -> i32;
+type NvDrsGetSettingFn = unsafe extern "C" fn(*mut c_void, *mut c_void, u32, *mut NvDrsSetting) -> i32;
+type NvDrsSetSettingFn = unsafe extern "C" fn(*mut c_void, *mut c_void, *mut NvDrsSetting) -> i32;
+
+/// NVIDIA Image Scaling through the driver's global (base) profile.
+struct NvDrs {
+    _lib: libloading::Library,
+    create_session: NvDrsCreateSessionFn,
+    destroy_session: NvDrsSessionFn,
+    load_settings: NvDrsSessionFn,
+    save_settings: NvDrsSessionFn,
+    get_base_profile: NvDrsGetBaseProfileFn,
+    get_setting: NvDrsGetSettingFn,
+    set_setting: NvDrsSetSettingFn,
+}
+
+/// Loaded once per process; `None` without an NVIDIA driver.
+static NV_DRS: OnceCell<Option<NvDrs>> = OnceCell::new();
+
+impl NvDrs {
+    fn get() -> Option<&'static NvDrs> {
+        NV_DRS.get_or_init(|| unsafe { Self::load() }).as_ref()
+    }
+
+    unsafe fn load() -> Option<Self> {
+        let lib = libloading::Library::new("nvapi64.dll").ok()?;
+        let query: libloading::Symbol<NvQueryInterfaceFn> = lib.get(b"nvapi_QueryInterface").ok()?;
+        let resolve = |id: u32| {
+            let ptr = query(id);
+            (!ptr.is_null()).then_some(ptr)
+        };
+
+        let initialize: NvInitializeFn = std::mem::transmute(resolve(NVAPI_INITIALIZE)?);
+        let drs = Self {
+            create_session: std::mem::transmute(resolve(NVAPI_DRS_CREATE_SESSION)?),
+            destroy_session: std::mem::transmute(resolve(NVAPI_DRS_DESTROY_SESSION)?),
+            load_settings: std::mem::transmute(resolve(NVAPI_DRS_LOAD_SETTINGS)?),
+            save_settings: std::mem::transmute(resolve(NVAPI_DRS_SAVE_SETTINGS)?),
+            get_base_profile: std::mem::transmute(resolve(NVAPI_DRS_GET_BASE_PROFILE)?),
+            get_setting: std::mem::transmute(resolve(NVAPI_DRS_GET_SETTING)?),
+            set_setting: std::mem::transmute(resolve(NVAPI_DRS_SET_SETTING)?),
+            _lib: lib,
+        };
+
+        if initialize() != NVAPI_OK {
+            warn!("NvAPI initialization failed, NVIDIA Image Scaling unavailable");
+            return None;
+        }
+
+        info!("NvAPI driver settings loaded (NVIDIA Image Scaling available)");
+        Some(drs)
+    }
+
+    /// Runs `f` on the global profile of a fresh DRS session.
+    fn with_base_profile<T>(
+        &self,
+        f: impl FnOnce(*mut c_void, *mut c_void) -> Result<T, BalamError>,
+    ) -> Result<T, BalamError> {
+        let check = |status: i32, call: &str| {
+            if status == NVAPI_OK {
+                Ok(())
+            } else {
+                Err(BalamError::Platform(format!("NvAPI_DRS_{call} failed: {status}")))
+            }
+        };
+
+        let mut session: *mut c_void = std::ptr::null_mut();
+        check(unsafe { (self.create_session)(&mut session) }, "CreateSession")?;
+        let result = check(unsafe { (self.load_settings)(session) }, "LoadSettings").and_then(|()| {
+            let mut profile: *mut c_void = std::ptr::null_mut();
+            check(
+                unsafe { (self.get_base_profile)(session, &mut profile) },
+                "GetBaseProfile",
+            )?;
+            f(session, profile)
+        });
+        unsafe { (self.destroy_session)(session) };
+        result
+    }
+
+    fn is_enabled(&self) -> Result<bool, BalamError> {
+        self.with_base_profile(|session, profile| {
+            let mut setting = NvDrsSetting::dword(NIS_SETTING_ID, 0);
+            match unsafe { (self.get_setting)(session, profile, NIS_SETTING_ID, setting.as_mut()) } {
+                NVAPI_OK => Ok(setting.current_value[0] != 0),
+                NVAPI_SETTING_NOT_FOUND => Ok(false),
+                status => Err(BalamError::Platform(format!("NvAPI_DRS_GetSetting failed: {status}"))),
+            }
+        })
+    }
+
+    fn set_enabled(&self, enabled: bool) -> Result<(), BalamError> {
+        self.with_base_profile(|session, profile| {
+            let mut setting = NvDrsSetting::dword(NIS_SETTING_ID, u32::from(enabled));
+            let status = unsafe { (self.set_setting)(session, profile, setting.as_mut()) };
+            if status != NVAPI_OK {
+                return Err(BalamError::Platform(format!("NvAPI_DRS_SetSetting failed: {status}")));
+            }
+            let status = unsafe { (self.save_settings)(session) };
+            if status != NVAPI_OK {
+                return Err(BalamError::Platform(format!("NvAPI_DRS_SaveSettings failed: {status}")));
+            }
+            Ok(())
+        })
+    }
+}
+
+/// ADLX_IntRange
+#[repr(C)]
+#[derive(Default)]
+#[allow(dead_code)] // Written by the driver
+struct AdlxIntRange {
+    min_value: i32,
+    max_value: i32,
+    step: i32,
+}
+
+/// IADLXSystem (only the slots up to `Get3DSettingsServices`)
+#[repr(C)]
+#[allow(dead_code)] // Unused slots keep the layout
+struct AdlxSystemVtbl {
+    get_hybrid_graphics_type: *const c_void,
+    get_gpus: *const c_void,
+    query_interface: *const c_void,
+    get_displays_services: *const c_void,
+    get_desktops_services: *const c_void,
+    get_gpus_changed_handling: *const c_void,
+    enable_log: *const c_void,
+    get_3d_settings_services: unsafe extern "system" fn(*mut AdlxObject, *mut *mut AdlxObject) -> i32,
+}
+
+/// IADLX3DSettingsServices (only the slots up to `GetRadeonSuperResolution`)
+#[repr(C)]
+#[allow(dead_code)] // Unused slots keep the layout
+struct Adlx3DSettingsServicesVtbl {
+    acquire: *const c_void,
+    release: unsafe extern "system" fn(*mut AdlxObject) -> i32,
+    query_interface: *const c_void,
+    get_anti_lag: *const c_void,
+    get_chill: *const c_void,
+    get_boost: *const c_void,
+    get_image_sharpening: *const c_void,
+    get_enhanced_sync: *const c_void,
+    get_wait_for_vertical_refresh: *const c_void,
+    get_frame_rate_target_control: *const c_void,
+    get_anti_aliasing: *const c_void,
+    get_morphological_anti_aliasing: *const c_void,
+    get_anisotropic_filtering: *const c_void,
+    get_tessellation: *const c_void,
+    get_radeon_super_resolution: unsafe extern "system" fn(*mut AdlxObject, *mut *mut AdlxObject) -> i32,
+}
+
+/// IADLX3DRadeonSuperResolution
+#[repr(C)]
+#[allow(dead_code)] // Unused slots keep the layout
+struct AdlxRsrVtbl {
+    acquire: *const c_void,
+    release: *const c_void,
+    query_interface: *const c_void,
+    is_supported: unsafe extern "system" fn(*mut AdlxObject, *mut bool) -> i32,
+    is_enabled: unsafe extern "system" fn(*mut AdlxObject, *mut bool) -> i32,
+    get_sharpness_range: unsafe extern "system" fn(*mut AdlxObject, *mut AdlxIntRange) -> i32,
+    get_sharpness: unsafe extern "system" fn(*mut AdlxObject, *mut i32) -> i32,
+    set_enabled: unsafe extern "system" fn(*mut AdlxObject, bool) -> i32,
+    set_sharpness: unsafe extern "system" fn(*mut AdlxObject, i32) -> i32,
+}
+
+/// ADLX interface pointer: a pointer to its vtable.
+#[repr(C)]
+struct AdlxObject {
+    vtbl: *const c_void,
+}
+
+type AdlxQueryFullVersionFn = unsafe extern "system" fn(*mut u64) -> i32;
+type AdlxInitializeFn = unsafe extern "system" fn(u64, *mut *mut AdlxObject) -> i32;
+
+/// AMD Radeon Super Resolution through ADLX (amdadlx64.dll, installed with Adrenalin).
+struct AdlxRsr {
+    _lib: libloading::Library,
+    /// IADLX3DRadeonSuperResolution, kept for the whole process
+    rsr: Mutex<*mut AdlxObject>,
+    sharpness_range: (i32, i32),
+}
+
+// The interface is only touched behind the mutex; ADLX objects are free-threaded.
+unsafe impl Send for AdlxRsr {}
+unsafe impl Sync for AdlxRsr {}
+
+/// Loaded once per process; `None` without an AMD driver with RSR support.
+static ADLX_RSR: OnceCell<Option<AdlxRsr>> = OnceCell::new();
+
+impl AdlxRsr {
+    fn get() -> Option<&'static AdlxRsr> {
+        ADLX_RSR.get_or_init(|| unsafe { Self::load() }).as_ref()
+    }
+
+    unsafe fn load() -> Option<Self> {
+        let lib = libloading::Library::new("amdadlx64.dll").ok()?;
+        let query_version: libloading::Symbol<AdlxQueryFullVersionFn> = lib.get(b"ADLXQueryFullVersion").ok()?;
+        let initialize: libloading::Symbol<AdlxInitializeFn> = lib.get(b"ADLXInitialize").ok()?;
+
+        let mut version = 0u64;
+        let mut system: *mut AdlxObject = std::ptr::null_mut();
+        if query_version(&mut version) != ADLX_OK || initialize(version, &mut system) != ADLX_OK || system.is_null() {
+            warn!("ADLX initialization failed, Radeon Super Resolution unavailable");
+            return None;
+        }
+
+        let system_vtbl = &*((*system).vtbl as *const AdlxSystemVtbl);
+        let mut services: *mut AdlxObject = std::ptr::null_mut();
+        if (system_vtbl.get_3d_settings_services)(system, &mut services) != ADLX_OK || services.is_null() {
+            warn!("ADLX 3D settings unavailable, Radeon Super Resolution unavailable");
+            return None;
+        }
+
+        let services_vtbl = &*((*services).vtbl as *const Adlx3DSettingsServicesVtbl);
+        let mut rsr: *mut AdlxObject = std::ptr::null_mut();
+        let status = (services_vtbl.get_radeon_super_resolution)(services, &mut rsr);
+        (services_vtbl.release)(services);
+        if status != ADLX_OK || rsr.is_null() {
+            return None;
+        }
+
+        let vtbl = &*((*rsr).vtbl as *const AdlxRsrVtbl);
+        let mut supported = false;
+        if (vtbl.is_supported)(rsr, &mut supported) != ADLX_OK || !supported {
+            info!("Radeon Super Resolution not supported by this GPU");
+            return None;
+        }
+        let mut range = AdlxIntRange::default();
+        if (vtbl.get_sharpness_range)(rsr, &mut range) != ADLX_OK {
+            range = AdlxIntRange {
+                min_value: 0,
+                max_value: 100,
+                step: 1,
+            };
+        }
+
+        info!("ADLX loaded (Radeon Super Resolution available)");
+        Some(Self {
+            _lib: lib,
+            rsr: Mutex::new(rsr),
+            sharpness_range: (range.min_value, range.max_value),
+        })
+    }
+
+    fn state(&self) -> Result<ScalingState, BalamError> {
+        let rsr = self.rsr.lock();
+        let vtbl = unsafe { &*((**rsr).vtbl as *const AdlxRsrVtbl) };
+        let mut enabled = false;
+        let mut sharpness = 0;
+        let status = unsafe { (vtbl.is_enabled)(*rsr, &mut enabled) };
+        if status != ADLX_OK {
+            return Err(BalamError::Platform(format!("ADLX RSR IsEnabled failed: {status}")));
+        }
+        let status = unsafe { (vtbl.get_sharpness)(*rsr, &mut sharpness) };
+        if status != ADLX_OK {
+            return Err(BalamError::Platform(format!("ADLX RSR GetSharpness failed: {status}")));
+        }
+        let (min, max) = self.sharpness_range;
+        Ok(ScalingState {
+            enabled,
+            sharpness: Some(sharpness_from_driver(sharpness, min, max)),
+        })
+    }
+
+    fn set_state(&self, state: ScalingState) -> Result<(), BalamError> {
+        let rsr = self.rsr.lock();
+        let vtbl = unsafe { &*((**rsr).vtbl as *const AdlxRsrVtbl) };
+        if let Some(sharpness) = state.sharpness {
+            let (min, max) = self.sharpness_range;
+            let status = unsafe { (vtbl.set_sharpness)(*rsr, sharpness_to_driver(sharpness, min, max)) };
+            if status != ADLX_OK {
+                return Err(BalamError::Platform(format!("ADLX RSR SetSharpness failed: {status}")));
+            }
+        }
+        let status = unsafe { (vtbl.set_enabled)(*rsr, state.enabled) };
+        if status != ADLX_OK {
+            return Err(BalamError::Platform(format!("ADLX RSR SetEnabled failed: {status}")));
+        }
+        Ok(())
+    }
+}
+
+/// Driver upscaling: AMD RSR through ADLX, NVIDIA NIS through NvAPI.
+///
+/// # Notes
+/// - Both upscale games that run fullscreen below the panel's resolution
+///   (720p on a 1080p panel); windowed games are left alone by the driver.
+/// - AMD is tried first: on handhelds and hybrid laptops with a Radeon iGPU,
+///   that's the GPU driving the built-in panel.
+/// - Changes are global and persist in the driver until set back.
+pub struct DriverScalingAdapter;
+
+impl Default for DriverScalingAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DriverScalingAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl GpuScalingPort for DriverScalingAdapter {
+    fn capabilities(&self) -> ScalingCapabilities {
+        if AdlxRsr::get().is_some() {
+            ScalingCapabilities {
+                technology: Some(ScalingTechnology::Rsr),
+                adjustable_sharpness: true,
+            }
+        } else if NvDrs::get().is_some() {
+            ScalingCapabilities {
+                technology: Some(ScalingTechnology::Nis),
+                adjustable_sharpness: false,
+            }
+        } else {
+            ScalingCapabilities::unsupported()
+        }
+    }
+
+    fn state(&self) -> Result<ScalingState, BalamError> {
+        if let Some(rsr) = AdlxRsr::get() {
+            return rsr.state();
+        }
+        if let Some(nis) = NvDrs::get() {
+            return Ok(ScalingState {
+                enabled: nis.is_enabled()?,
+                sharpness: None,
+            });
+        }
+        Err(BalamError::NotSupported(
+            "No GPU driver upscaling (NVIDIA NIS or AMD RSR)".to_string(),
+        ))
+    }
+
+    fn set_state(&self, state: ScalingState) -> Result<(), BalamError> {
+        if let Some(rsr) = AdlxRsr::get() {
+            return rsr.set_state(state);
+        }
+        if let Some(nis) = NvDrs::get() {
+            return nis.set_enabled(state.enabled);
+        }
+        Err(BalamError::NotSupported(
+            "No GPU driver upscaling (NVIDIA NIS or AMD RSR)".to_string(),
+        ))
+    }
+}
@@ -1,5 +1,6 @@
 use crate::adapters::display::DisplayConfigManager;
 use crate::domain::display::DisplayInfo;
+use crate::domain::BalamError;
 use std::sync::Arc;
 
 /// HDR Manager - High-level orchestrator for HDR control.
@@ -38,7 +39,7 @@ use std::sync::Arc;
 ///
 /// // Toggle HDR
 /// manager.set_hdr_enabled(0, true)?; // Enable HDR on display 0
-/// # Ok::<(), String>(())
+/// # Ok::<(), console_experience_lib::domain::BalamError>(())
 /// ```
 ///
 /// # Performance
@@ -80,10 +81,10 @@ impl HdrManager {
     /// for display in displays {
     ///     println!("{}: HDR={:?}", display.name, display.hdr);
     /// }
-    /// # Ok::<(), String>(())
+    /// # Ok::<(), console_experience_lib::domain::BalamError>(())
     /// ```
-    pub fn get_displays(&self) -> Result<Vec<DisplayInfo>, String> {
-        self.display_config.get_displays()
+    pub fn get_displays(&self) -> Result<Vec<DisplayInfo>, BalamError> {
+        self.display_config.get_displays().map_err(BalamError::Platform)
     }
 
     /// Gets the primary display (first in enumeration).
@@ -157,32 +158,32 @@ impl HdrManager {
     /// `Ok(())` if HDR state was set successfully.
     ///
     /// # Errors
-    /// - `Err("Display not found")` - Invalid display ID
-    /// - `Err("HDR not supported")` - Display doesn't support HDR
-    /// - `Err(...)` - DisplayConfig API error
+    /// - `BalamError::NotFound` - Invalid display ID
+    /// - `BalamError::NotSupported` - Display doesn't support HDR
+    /// - `BalamError::Platform` - DisplayConfig API error
     ///
     /// # Examples
     /// ```rust
     /// # use console_experience_lib::adapters::display::HdrManager;
     /// let manager = HdrManager::new();
     /// manager.set_hdr_enabled(0, true)?; // Enable HDR on primary display
-    /// # Ok::<(), String>(())
+    /// # Ok::<(), console_experience_lib::domain::BalamError>(())
     /// ```
-    pub fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), String> {
+    pub fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), BalamError> {
         // Get display info
         let displays = self.get_displays()?;
         let display = displays
             .into_iter()
             .find(|d| d.id == display_id)
-            .ok_or_else(|| format!("Display {} not found", display_id))?;
+            .ok_or_else(|| BalamError::NotFound(format!("Display {display_id}")))?;
 
         // Check HDR support
         let hdr = display
             .hdr
-            .ok_or_else(|| format!("Display {} has no HDR info", display_id))?;
+            .ok_or_else(|| BalamError::NotSupported(format!("Display {display_id} reports no HDR info")))?;
 
         if !hdr.supported {
-            return Err(format!("Display {} does not support HDR", display_id));
+            return Err(BalamError::NotSupported(format!("HDR on display {display_id}")));
         }
 
         // Convert adapter_id tuple back to LUID
@@ -192,7 +193,9 @@ impl HdrManager {
         };
 
         // Set HDR state
-        self.display_config.set_hdr_state(luid, display_id, enabled)
+        self.display_config
+            .set_hdr_state(luid, display_id, enabled)
+            .map_err(BalamError::Platform)
     }
 }
 
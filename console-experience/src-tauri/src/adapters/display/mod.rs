@@ -1,7 +1,16 @@
+pub mod color_manager;
+pub mod ddc_ci_adapter;
 pub mod display_config;
+pub mod gpu_scaling_adapter;
 pub mod hdr_manager;
+pub mod monitors;
+pub mod window_manager;
 pub mod windows_display_adapter;
 
+pub use color_manager::ColorManager;
+pub use ddc_ci_adapter::DdcCiAdapter;
 pub use display_config::DisplayConfigManager;
+pub use gpu_scaling_adapter::DriverScalingAdapter;
 pub use hdr_manager::HdrManager;
+pub use window_manager::Win32WindowManager;
 pub use windows_display_adapter::WindowsDisplayAdapter;
@@ -0,0 +1,182 @@
+/// Monitor enumeration - GDI monitors joined with their DisplayConfig targets
+///
+/// GDI knows where each monitor sits on the desktop (`\\.\DISPLAYn`, bounds,
+/// primary flag); DisplayConfig knows what is plugged in (EDID name, built-in
+/// or external connector). Both are queried fresh on every call so hot-plugged
+/// monitors show up without a restart.
+use std::collections::HashMap;
+use tracing::debug;
+use windows::Win32::Devices::Display::{
+    DestroyPhysicalMonitors, DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes,
+    GetNumberOfPhysicalMonitorsFromHMONITOR, GetPhysicalMonitorsFromHMONITOR, QueryDisplayConfig,
+    DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+    DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED,
+    DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_LVDS,
+    DISPLAYCONFIG_OUTPUT_TECHNOLOGY_UDI_EMBEDDED, DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_SOURCE_DEVICE_NAME,
+    DISPLAYCONFIG_TARGET_DEVICE_NAME, PHYSICAL_MONITOR, QDC_ONLY_ACTIVE_PATHS,
+};
+use windows::Win32::Foundation::{BOOL, ERROR_SUCCESS, HWND, LPARAM, RECT, TRUE};
+use windows::Win32::Graphics::Gdi::{
+    EnumDisplayMonitors, GetMonitorInfoW, MonitorFromWindow, HDC, HMONITOR, MONITORINFO, MONITORINFOEXW,
+    MONITORINFOF_PRIMARY, MONITOR_DEFAULTTONEAREST,
+};
+
+use crate::domain::display::MonitorBounds;
+
+/// A monitor as GDI sees it.
+pub struct GdiMonitor {
+    pub handle: HMONITOR,
+    pub device_name: String,
+    pub bounds: MonitorBounds,
+    pub is_primary: bool,
+}
+
+/// What DisplayConfig reports for the monitor behind a GDI device name.
+pub struct MonitorTarget {
+    pub friendly_name: String,
+    pub is_internal: bool,
+}
+
+/// Every monitor of the desktop, primary first.
+pub fn enumerate() -> Vec<GdiMonitor> {
+    unsafe extern "system" fn enum_proc(monitor: HMONITOR, _: HDC, _: *mut RECT, lparam: LPARAM) -> BOOL {
+        let monitors = &mut *(lparam.0 as *mut Vec<GdiMonitor>);
+        if let Some(info) = gdi_monitor(monitor) {
+            monitors.push(info);
+        }
+        TRUE
+    }
+
+    let mut monitors: Vec<GdiMonitor> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            HDC::default(),
+            None,
+            Some(enum_proc),
+            LPARAM(&raw mut monitors as isize),
+        );
+    }
+    monitors.sort_by_key(|monitor| !monitor.is_primary);
+    monitors
+}
+
+/// GDI device name of the monitor showing most of `hwnd`.
+pub fn device_name_of_window(hwnd: HWND) -> Option<String> {
+    let monitor = unsafe { MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST) };
+    gdi_monitor(monitor).map(|monitor| monitor.device_name)
+}
+
+fn gdi_monitor(monitor: HMONITOR) -> Option<GdiMonitor> {
+    let mut info = MONITORINFOEXW {
+        monitorInfo: MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFOEXW>() as u32,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    unsafe { GetMonitorInfoW(monitor, (&raw mut info).cast::<MONITORINFO>()) }
+        .as_bool()
+        .then(|| {
+            let rect = info.monitorInfo.rcMonitor;
+            GdiMonitor {
+                handle: monitor,
+                device_name: wide_to_string(&info.szDevice),
+                bounds: MonitorBounds {
+                    x: rect.left,
+                    y: rect.top,
+                    width: (rect.right - rect.left).unsigned_abs(),
+                    height: (rect.bottom - rect.top).unsigned_abs(),
+                },
+                is_primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+            }
+        })
+}
+
+/// DisplayConfig targets keyed by the GDI device name of their source.
+///
+/// Empty when DisplayConfig is unavailable; callers fall back to GDI names.
+pub fn targets() -> HashMap<String, MonitorTarget> {
+    let mut targets = HashMap::new();
+    unsafe {
+        let mut path_count = 0u32;
+        let mut mode_count = 0u32;
+        if GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count).is_err() {
+            return targets;
+        }
+        let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+        let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+        if let Err(e) = QueryDisplayConfig(
+            QDC_ONLY_ACTIVE_PATHS,
+            &mut path_count,
+            paths.as_mut_ptr(),
+            &mut mode_count,
+            modes.as_mut_ptr(),
+            None,
+        ) {
+            debug!("QueryDisplayConfig failed: {}", e);
+            return targets;
+        }
+        paths.truncate(path_count as usize);
+
+        for path in &paths {
+            let mut source = DISPLAYCONFIG_SOURCE_DEVICE_NAME {
+                header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                    r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+                    size: std::mem::size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32,
+                    adapterId: path.sourceInfo.adapterId,
+                    id: path.sourceInfo.id,
+                },
+                ..Default::default()
+            };
+            let mut target = DISPLAYCONFIG_TARGET_DEVICE_NAME {
+                header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                    r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+                    size: std::mem::size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME>() as u32,
+                    adapterId: path.targetInfo.adapterId,
+                    id: path.targetInfo.id,
+                },
+                ..Default::default()
+            };
+            if DisplayConfigGetDeviceInfo(&raw mut source.header) != ERROR_SUCCESS.0 as i32
+                || DisplayConfigGetDeviceInfo(&raw mut target.header) != ERROR_SUCCESS.0 as i32
+            {
+                continue;
+            }
+
+            let technology = target.outputTechnology;
+            targets.insert(
+                wide_to_string(&source.viewGdiDeviceName),
+                MonitorTarget {
+                    friendly_name: wide_to_string(&target.monitorFriendlyDeviceName),
+                    is_internal: technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL
+                        || technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED
+                        || technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_UDI_EMBEDDED
+                        || technology == DISPLAYCONFIG_OUTPUT_TECHNOLOGY_LVDS,
+                },
+            );
+        }
+    }
+    targets
+}
+
+/// Whether the monitor exposes a physical monitor handle, which DDC/CI needs.
+pub fn has_physical_monitor(monitor: HMONITOR) -> bool {
+    unsafe {
+        let mut count = 0u32;
+        if GetNumberOfPhysicalMonitorsFromHMONITOR(monitor, &mut count).is_err() || count == 0 {
+            return false;
+        }
+        let mut physical = vec![PHYSICAL_MONITOR::default(); count as usize];
+        if GetPhysicalMonitorsFromHMONITOR(monitor, &mut physical).is_err() {
+            return false;
+        }
+        let _ = DestroyPhysicalMonitors(&physical);
+        physical.iter().any(|monitor| !monitor.hPhysicalMonitor.is_invalid())
+    }
+}
+
+/// UTF-16 buffer up to its first NUL.
+fn wide_to_string(buffer: &[u16]) -> String {
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..len])
+}
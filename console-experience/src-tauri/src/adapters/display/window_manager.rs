@@ -0,0 +1,240 @@
+/// Window Manager - switches game windows between windowed, borderless and exclusive fullscreen
+///
+/// Borderless is done from outside the game: the frame styles are removed and
+/// the window is stretched over its monitor. Exclusive fullscreen belongs to
+/// the game's swapchain, so entering or leaving it sends Alt+Enter to the
+/// game window, the toggle most DirectX titles implement.
+use std::thread;
+use std::time::Duration;
+use tracing::info;
+use windows::Win32::Foundation::{HWND, RECT};
+use windows::Win32::Graphics::Gdi::{GetMonitorInfoW, MonitorFromWindow, MONITORINFO, MONITOR_DEFAULTTONEAREST};
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS, KEYEVENTF_KEYUP, VIRTUAL_KEY, VK_MENU,
+    VK_RETURN,
+};
+use windows::Win32::UI::Shell::{SHQueryUserNotificationState, QUNS_RUNNING_D3D_FULL_SCREEN};
+use windows::Win32::UI::WindowsAndMessaging::{
+    GetForegroundWindow, GetWindowLongPtrW, GetWindowRect, SetForegroundWindow, SetWindowLongPtrW, SetWindowPos,
+    GWL_EXSTYLE, GWL_STYLE, HWND_TOP, SWP_FRAMECHANGED, SWP_NOOWNERZORDER, SWP_SHOWWINDOW, WS_BORDER, WS_CAPTION,
+    WS_DLGFRAME, WS_EX_CLIENTEDGE, WS_EX_DLGMODALFRAME, WS_EX_STATICEDGE, WS_EX_WINDOWEDGE, WS_MAXIMIZEBOX,
+    WS_MINIMIZEBOX, WS_OVERLAPPEDWINDOW, WS_POPUP, WS_SYSMENU, WS_THICKFRAME,
+};
+
+use crate::adapters::game::WindowsGameAdapter;
+use crate::domain::display::GameWindowMode;
+use crate::domain::BalamError;
+use crate::ports::WindowManagerPort;
+
+/// Time a game gets to switch display modes after Alt+Enter.
+const MODE_SWITCH_WAIT: Duration = Duration::from_millis(1500);
+/// Share of the work area a restored framed window covers.
+const WINDOWED_PERCENT: i32 = 80;
+
+/// Frame bits removed for borderless.
+const FRAME_STYLE: u32 =
+    WS_CAPTION.0 | WS_THICKFRAME.0 | WS_BORDER.0 | WS_DLGFRAME.0 | WS_SYSMENU.0 | WS_MINIMIZEBOX.0 | WS_MAXIMIZEBOX.0;
+const FRAME_EX_STYLE: u32 = WS_EX_DLGMODALFRAME.0 | WS_EX_CLIENTEDGE.0 | WS_EX_STATICEDGE.0 | WS_EX_WINDOWEDGE.0;
+
+/// Style of a frameless popup window.
+fn borderless_style(style: u32) -> u32 {
+    (style & !FRAME_STYLE) | WS_POPUP.0
+}
+
+/// Style of a regular framed window.
+fn windowed_style(style: u32) -> u32 {
+    (style & !WS_POPUP.0) | WS_OVERLAPPEDWINDOW.0
+}
+
+/// Win32 implementation of [`WindowManagerPort`].
+pub struct Win32WindowManager {
+    games: WindowsGameAdapter,
+}
+
+impl Default for Win32WindowManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Win32WindowManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            games: WindowsGameAdapter::new(),
+        }
+    }
+
+    fn window(&self, pid: u32) -> Result<HWND, BalamError> {
+        self.games
+            .main_window(pid)
+            .ok_or_else(|| BalamError::NotFound(format!("No visible window for PID {pid}")))
+    }
+
+    fn mode_of(hwnd: HWND) -> GameWindowMode {
+        unsafe {
+            // Only the foreground app can hold exclusive mode
+            let exclusive = GetForegroundWindow() == hwnd
+                && SHQueryUserNotificationState().is_ok_and(|state| state == QUNS_RUNNING_D3D_FULL_SCREEN);
+            if exclusive {
+                return GameWindowMode::ExclusiveFullscreen;
+            }
+            let style = GetWindowLongPtrW(hwnd, GWL_STYLE) as u32;
+            let framed = style & (WS_CAPTION.0 | WS_THICKFRAME.0) != 0;
+            let mut rect = RECT::default();
+            let covers_monitor = GetWindowRect(hwnd, &raw mut rect).is_ok()
+                && monitor_info(hwnd).is_some_and(|info| rect == info.rcMonitor);
+            if !framed && covers_monitor {
+                GameWindowMode::Borderless
+            } else {
+                GameWindowMode::Windowed
+            }
+        }
+    }
+
+    /// Asks the game to toggle exclusive fullscreen and waits for the switch.
+    fn toggle_exclusive(hwnd: HWND) -> Result<(), BalamError> {
+        let key = |vk: VIRTUAL_KEY, flags: KEYBD_EVENT_FLAGS| INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: vk,
+                    dwFlags: flags,
+                    ..Default::default()
+                },
+            },
+        };
+        let inputs = [
+            key(VK_MENU, KEYBD_EVENT_FLAGS(0)),
+            key(VK_RETURN, KEYBD_EVENT_FLAGS(0)),
+            key(VK_RETURN, KEYEVENTF_KEYUP),
+            key(VK_MENU, KEYEVENTF_KEYUP),
+        ];
+        unsafe {
+            // Alt+Enter goes to the foreground window
+            let _ = SetForegroundWindow(hwnd);
+            let sent = SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+            if sent as usize != inputs.len() {
+                return Err(BalamError::Platform(format!(
+                    "SendInput delivered {sent} of {} key events",
+                    inputs.len()
+                )));
+            }
+        }
+        thread::sleep(MODE_SWITCH_WAIT);
+        Ok(())
+    }
+
+    /// Restyles a (non-exclusive) window and moves it into place.
+    fn restyle(hwnd: HWND, mode: GameWindowMode) -> Result<(), BalamError> {
+        let info = monitor_info(hwnd)
+            .ok_or_else(|| BalamError::platform("Failed to read monitor", windows::core::Error::from_win32()))?;
+        unsafe {
+            let style = GetWindowLongPtrW(hwnd, GWL_STYLE) as u32;
+            let ex_style = GetWindowLongPtrW(hwnd, GWL_EXSTYLE) as u32;
+            let rect = if mode == GameWindowMode::Borderless {
+                SetWindowLongPtrW(hwnd, GWL_STYLE, borderless_style(style) as isize);
+                SetWindowLongPtrW(hwnd, GWL_EXSTYLE, (ex_style & !FRAME_EX_STYLE) as isize);
+                info.rcMonitor
+            } else {
+                SetWindowLongPtrW(hwnd, GWL_STYLE, windowed_style(style) as isize);
+                SetWindowLongPtrW(hwnd, GWL_EXSTYLE, (ex_style | WS_EX_WINDOWEDGE.0) as isize);
+                centered(info.rcWork, WINDOWED_PERCENT)
+            };
+            SetWindowPos(
+                hwnd,
+                HWND_TOP,
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                SWP_FRAMECHANGED | SWP_NOOWNERZORDER | SWP_SHOWWINDOW,
+            )
+            .map_err(|e| BalamError::platform("Failed to resize game window", e))
+        }
+    }
+}
+
+impl WindowManagerPort for Win32WindowManager {
+    fn window_mode(&self, pid: u32) -> Result<GameWindowMode, BalamError> {
+        Ok(Self::mode_of(self.window(pid)?))
+    }
+
+    fn set_window_mode(&self, pid: u32, mode: GameWindowMode) -> Result<(), BalamError> {
+        let hwnd = self.window(pid)?;
+        let current = Self::mode_of(hwnd);
+        if current == mode {
+            return Ok(());
+        }
+
+        if current == GameWindowMode::ExclusiveFullscreen || mode == GameWindowMode::ExclusiveFullscreen {
+            Self::toggle_exclusive(hwnd)?;
+            if mode == GameWindowMode::ExclusiveFullscreen {
+                info!("🖥️ Asked PID {} to enter exclusive fullscreen", pid);
+                return Ok(());
+            }
+        }
+        // Games often recreate their window when leaving exclusive mode
+        let hwnd = self.window(pid)?;
+        if Self::mode_of(hwnd) != mode {
+            Self::restyle(hwnd, mode)?;
+        }
+        info!("🖥️ Switched PID {} to {:?}", pid, mode);
+        Ok(())
+    }
+}
+
+fn monitor_info(hwnd: HWND) -> Option<MONITORINFO> {
+    unsafe {
+        let monitor = MonitorFromWindow(hwnd, MONITOR_DEFAULTTONEAREST);
+        let mut info = MONITORINFO {
+            cbSize: std::mem::size_of::<MONITORINFO>() as u32,
+            ..Default::default()
+        };
+        GetMonitorInfoW(monitor, &raw mut info).as_bool().then_some(info)
+    }
+}
+
+/// `percent` of `area`, centered in it.
+fn centered(area: RECT, percent: i32) -> RECT {
+    let width = (area.right - area.left) * percent / 100;
+    let height = (area.bottom - area.top) * percent / 100;
+    let left = area.left + (area.right - area.left - width) / 2;
+    let top = area.top + (area.bottom - area.top - height) / 2;
+    RECT {
+        left,
+        top,
+        right: left + width,
+        bottom: top + height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styles_round_trip() {
+        let framed = WS_OVERLAPPEDWINDOW.0;
+        let borderless = borderless_style(framed);
+        assert_eq!(borderless & (WS_CAPTION.0 | WS_THICKFRAME.0), 0);
+        assert_ne!(borderless & WS_POPUP.0, 0);
+        assert_eq!(windowed_style(borderless) & FRAME_STYLE, framed & FRAME_STYLE);
+
+        let area = RECT {
+            left: 0,
+            top: 0,
+            right: 1000,
+            bottom: 500,
+        };
+        assert_eq!(
+            centered(area, 80),
+            RECT {
+                left: 100,
+                top: 50,
+                right: 900,
+                bottom: 450,
+            }
+        );
+    }
+}
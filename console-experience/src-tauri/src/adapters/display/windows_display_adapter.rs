@@ -1,10 +1,16 @@
-use crate::adapters::display::HdrManager;
-use crate::domain::display::{BrightnessConfig, DisplayInfo, RefreshRateConfig};
+use crate::adapters::display::{monitors, ColorManager, DdcCiAdapter, HdrManager};
+use crate::domain::display::{
+    BrightnessConfig, BrightnessControl, ColorProfile, DisplayInfo, DisplayMode, MonitorInfo, RefreshRateConfig,
+};
+use crate::domain::BalamError;
 use crate::ports::display_port::DisplayPort;
 use serde::Deserialize;
 use tracing::{info, warn};
+use windows::core::{HSTRING, PCWSTR};
+use windows::Win32::Foundation::HWND;
 use windows::Win32::Graphics::Gdi::{
-    ChangeDisplaySettingsW, EnumDisplaySettingsW, CDS_UPDATEREGISTRY, DEVMODEW, DISP_CHANGE_SUCCESSFUL,
+    ChangeDisplaySettingsExW, ChangeDisplaySettingsW, EnumDisplaySettingsW, CDS_TEST, CDS_TYPE, CDS_UPDATEREGISTRY,
+    DEVMODEW, DISP_CHANGE_BADMODE, DISP_CHANGE_SUCCESSFUL, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT, DM_PELSWIDTH,
     ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE,
 };
 use wmi::WMIConnection;
@@ -41,10 +47,21 @@ struct WmiSetBrightnessParams {
     timeout: u32,
 }
 
+/// GDI device name argument: `None` is the primary display.
+fn device_param(device: Option<&HSTRING>) -> PCWSTR {
+    device.map_or(PCWSTR::null(), |name| PCWSTR(name.as_ptr()))
+}
+
 /// Windows-native implementation of `DisplayPort`.
-/// Uses WMI for laptop brightness, DDC/CI for external monitors, GDI for refresh rate, and DisplayConfig for HDR.
+/// Uses WMI for laptop brightness, DDC/CI for external monitors, GDI for refresh rate, DisplayConfig for HDR
+/// and gamma ramps (plus NvAPI vibrance) for color.
+///
+/// Per-monitor calls take the GDI device name (`\\.\DISPLAY2`); the others act on
+/// the primary display.
 pub struct WindowsDisplayAdapter {
     hdr_manager: HdrManager,
+    color_manager: ColorManager,
+    ddc_ci: DdcCiAdapter,
 }
 
 impl Default for WindowsDisplayAdapter {
@@ -58,21 +75,23 @@ impl WindowsDisplayAdapter {
     pub fn new() -> Self {
         Self {
             hdr_manager: HdrManager::new(),
+            color_manager: ColorManager::new(),
+            ddc_ci: DdcCiAdapter::new(),
         }
     }
 
     /// Gets brightness using WMI (Windows Management Instrumentation).
     /// Works on laptops with integrated displays.
     #[allow(clippy::unused_self)]
-    fn get_brightness_wmi(&self) -> Result<Option<u32>, String> {
+    fn get_brightness_wmi(&self) -> Result<Option<u32>, BalamError> {
         // Connect to WMI namespace (root\WMI for monitor brightness)
-        let wmi_con =
-            WMIConnection::with_namespace_path("root\\WMI").map_err(|e| format!("Failed to connect to WMI: {e}"))?;
+        let wmi_con = WMIConnection::with_namespace_path("root\\WMI")
+            .map_err(|e| BalamError::platform("Failed to connect to WMI", e))?;
 
         // Query WmiMonitorBrightness class
         let results: Vec<WmiMonitorBrightness> = wmi_con
             .query()
-            .map_err(|e| format!("WMI brightness query failed: {e}"))?;
+            .map_err(|e| BalamError::platform("WMI brightness query failed", e))?;
 
         if let Some(brightness) = results.first() {
             info!("Current brightness (WMI): {}%", brightness.current_brightness);
@@ -87,20 +106,20 @@ impl WindowsDisplayAdapter {
     /// Sets brightness using WMI.
     /// Only works on devices with `WmiMonitorBrightnessMethods` support.
     #[allow(clippy::unused_self)]
-    fn set_brightness_wmi(&self, level: u32) -> Result<(), String> {
-        let wmi_con =
-            WMIConnection::with_namespace_path("root\\WMI").map_err(|e| format!("Failed to connect to WMI: {e}"))?;
+    fn set_brightness_wmi(&self, level: u32) -> Result<(), BalamError> {
+        let wmi_con = WMIConnection::with_namespace_path("root\\WMI")
+            .map_err(|e| BalamError::platform("Failed to connect to WMI", e))?;
 
         // Query for brightness methods instances
         let methods: Vec<WmiMonitorBrightnessMethods> = wmi_con
             .query()
-            .map_err(|e| format!("WMI brightness methods query failed: {e}"))?;
+            .map_err(|e| BalamError::platform("WMI brightness methods query failed", e))?;
 
         // Find active instance
         let active_instance = methods
             .into_iter()
             .find(|m| m.active)
-            .ok_or("No active monitor brightness instance found")?;
+            .ok_or_else(|| BalamError::NotSupported("No active monitor brightness instance".to_string()))?;
 
         info!(
             "Setting brightness to {}% via WMI (path: {})",
@@ -117,66 +136,46 @@ impl WindowsDisplayAdapter {
         // The Class type parameter specifies which WMI class to get the method definition from
         let result: WmiMethodResult = wmi_con
             .exec_instance_method::<WmiMonitorBrightnessMethods, _>(&active_instance.path, "WmiSetBrightness", params)
-            .map_err(|e| format!("Failed to execute WmiSetBrightness: {e}"))?;
+            .map_err(|e| BalamError::platform("Failed to execute WmiSetBrightness", e))?;
 
         if result.return_value == 0 {
             info!("✅ Brightness set successfully to {}%", level);
             Ok(())
         } else {
             warn!("WmiSetBrightness returned non-zero: {}", result.return_value);
-            Err(format!(
+            Err(BalamError::Platform(format!(
                 "WmiSetBrightness failed with return value: {}",
                 result.return_value
-            ))
+            )))
         }
     }
 
-    /// Gets brightness using DDC/CI protocol for external monitors.
-    /// This is a workaround for desktop setups.
-    #[allow(clippy::unused_self, clippy::unnecessary_wraps)]
-    fn get_brightness_ddcci(&self) -> Result<Option<u32>, String> {
-        // DDC/CI requires:
-        // 1. Enumerate monitors via SetupAPI
-        // 2. Send VCP (Virtual Control Panel) commands
-        // 3. Read brightness value (VCP code 0x10)
-        // TODO: Implement DDC/CI via Win32 DeviceIoControl
-        warn!("DDC/CI brightness query not yet implemented");
-        Ok(None)
-    }
-
-    /// Sets brightness using DDC/CI for external monitors.
-    #[allow(clippy::unused_self)]
-    fn set_brightness_ddcci(&self, level: u32) -> Result<(), String> {
-        // DDC/CI VCP code 0x10 (brightness)
-        // TODO: Implement DDC/CI control
-        warn!("DDC/CI brightness set not yet implemented for level {}", level);
-        Err("DDC/CI brightness control not yet implemented".to_string())
-    }
-
     /// Gets current refresh rate using GDI.
     #[allow(clippy::unused_self)]
-    fn get_current_refresh_rate_gdi(&self) -> Result<u32, String> {
+    fn get_current_refresh_rate_gdi(&self, device: Option<&HSTRING>) -> Result<u32, BalamError> {
         unsafe {
             let mut devmode = DEVMODEW {
                 dmSize: std::mem::size_of::<DEVMODEW>() as u16,
                 ..Default::default()
             };
 
-            let result = EnumDisplaySettingsW(None, ENUM_CURRENT_SETTINGS, &raw mut devmode);
+            let result = EnumDisplaySettingsW(device_param(device), ENUM_CURRENT_SETTINGS, &raw mut devmode);
 
             if result.as_bool() {
                 let hz = devmode.dmDisplayFrequency;
                 info!("Current refresh rate: {}Hz", hz);
                 Ok(hz)
             } else {
-                Err("Failed to query current display settings".to_string())
+                Err(BalamError::Platform(
+                    "Failed to query current display settings".to_string(),
+                ))
             }
         }
     }
 
-    /// Sets refresh rate using GDI `ChangeDisplaySettings`.
+    /// Sets refresh rate using GDI `ChangeDisplaySettingsEx`.
     #[allow(clippy::unused_self)]
-    fn set_refresh_rate_gdi(&self, hz: u32) -> Result<(), String> {
+    fn set_refresh_rate_gdi(&self, device: Option<&HSTRING>, hz: u32) -> Result<(), BalamError> {
         unsafe {
             let mut devmode = DEVMODEW {
                 dmSize: std::mem::size_of::<DEVMODEW>() as u16,
@@ -184,8 +183,10 @@ impl WindowsDisplayAdapter {
             };
 
             // Get current settings first
-            if !EnumDisplaySettingsW(None, ENUM_CURRENT_SETTINGS, &raw mut devmode).as_bool() {
-                return Err("Failed to get current display settings".to_string());
+            if !EnumDisplaySettingsW(device_param(device), ENUM_CURRENT_SETTINGS, &raw mut devmode).as_bool() {
+                return Err(BalamError::Platform(
+                    "Failed to get current display settings".to_string(),
+                ));
             }
 
             // Modify only refresh rate
@@ -194,21 +195,34 @@ impl WindowsDisplayAdapter {
 
             info!("Attempting to set refresh rate to {}Hz", hz);
 
-            let result = ChangeDisplaySettingsW(Some(&raw const devmode), CDS_UPDATEREGISTRY);
+            let result = ChangeDisplaySettingsExW(
+                device_param(device),
+                Some(&raw const devmode),
+                HWND::default(),
+                CDS_UPDATEREGISTRY,
+                None,
+            );
 
             if result == DISP_CHANGE_SUCCESSFUL {
                 info!("Refresh rate changed successfully to {}Hz", hz);
                 Ok(())
             } else {
                 warn!("Failed to change refresh rate. Code: {:?}", result);
-                Err(format!("Failed to set refresh rate to {hz}Hz"))
+                // DISP_CHANGE_BADMODE: the display has no mode at this frequency
+                if result == DISP_CHANGE_BADMODE {
+                    Err(BalamError::InvalidArgument(format!(
+                        "{hz}Hz is not supported by this display"
+                    )))
+                } else {
+                    Err(BalamError::Platform(format!("Failed to set refresh rate to {hz}Hz")))
+                }
             }
         }
     }
 
-    /// Enumerates all supported refresh rates for the current display.
+    /// Enumerates all supported refresh rates of a display.
     #[allow(clippy::unused_self)]
-    fn enumerate_refresh_rates_gdi(&self) -> Vec<u32> {
+    fn enumerate_refresh_rates_gdi(&self, device: Option<&HSTRING>) -> Vec<u32> {
         unsafe {
             let mut rates = Vec::new();
             let mut mode_num = 0u32;
@@ -219,7 +233,11 @@ impl WindowsDisplayAdapter {
 
             // Enumerate all display modes
             loop {
-                let result = EnumDisplaySettingsW(None, ENUM_DISPLAY_SETTINGS_MODE(mode_num), &raw mut devmode);
+                let result = EnumDisplaySettingsW(
+                    device_param(device),
+                    ENUM_DISPLAY_SETTINGS_MODE(mode_num),
+                    &raw mut devmode,
+                );
                 if !result.as_bool() {
                     break;
                 }
@@ -237,19 +255,149 @@ impl WindowsDisplayAdapter {
             rates
         }
     }
+
+    /// Enumerates monitors with their brightness control.
+    ///
+    /// WMI is only queried when a built-in panel is present.
+    fn enumerate_monitors(&self) -> Vec<MonitorInfo> {
+        let mut targets = monitors::targets();
+        let mut wmi_available = None;
+
+        monitors::enumerate()
+            .into_iter()
+            .map(|monitor| {
+                let target = targets.remove(&monitor.device_name);
+                let is_internal = target.as_ref().is_some_and(|target| target.is_internal);
+                let wmi = is_internal
+                    && *wmi_available.get_or_insert_with(|| self.get_brightness_wmi().ok().flatten().is_some());
+                let ddc_ci = !is_internal && monitors::has_physical_monitor(monitor.handle);
+                let device = HSTRING::from(monitor.device_name.as_str());
+
+                MonitorInfo {
+                    name: target
+                        .map(|target| target.friendly_name)
+                        .filter(|name| !name.is_empty())
+                        .unwrap_or_else(|| monitor.device_name.trim_start_matches(r"\\.\").to_string()),
+                    device_name: monitor.device_name,
+                    is_primary: monitor.is_primary,
+                    is_internal,
+                    bounds: monitor.bounds,
+                    refresh_hz: self.get_current_refresh_rate_gdi(Some(&device)).unwrap_or(0),
+                    brightness: BrightnessControl::detect(is_internal, wmi, ddc_ci),
+                }
+            })
+            .collect()
+    }
+
+    fn monitor(&self, device_name: &str) -> Result<MonitorInfo, BalamError> {
+        self.enumerate_monitors()
+            .into_iter()
+            .find(|monitor| monitor.device_name.eq_ignore_ascii_case(device_name))
+            .ok_or_else(|| BalamError::NotFound(format!("Monitor {device_name}")))
+    }
+
+    /// Gets current resolution and refresh rate using GDI.
+    #[allow(clippy::unused_self)]
+    fn get_current_mode_gdi(&self) -> Result<DisplayMode, BalamError> {
+        unsafe {
+            let mut devmode = DEVMODEW {
+                dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+                ..Default::default()
+            };
+
+            if !EnumDisplaySettingsW(None, ENUM_CURRENT_SETTINGS, &raw mut devmode).as_bool() {
+                return Err(BalamError::Platform(
+                    "Failed to query current display settings".to_string(),
+                ));
+            }
+            Ok(DisplayMode {
+                width: devmode.dmPelsWidth,
+                height: devmode.dmPelsHeight,
+                refresh_hz: devmode.dmDisplayFrequency,
+            })
+        }
+    }
+
+    /// Enumerates every display mode (one entry per resolution / refresh rate pair).
+    #[allow(clippy::unused_self)]
+    fn enumerate_modes_gdi(&self) -> Vec<DisplayMode> {
+        unsafe {
+            let mut modes: Vec<DisplayMode> = Vec::new();
+            let mut mode_num = 0u32;
+            let mut devmode = DEVMODEW {
+                dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+                ..Default::default()
+            };
+
+            // The same resolution/frequency repeats for each color depth
+            while EnumDisplaySettingsW(None, ENUM_DISPLAY_SETTINGS_MODE(mode_num), &raw mut devmode).as_bool() {
+                let mode = DisplayMode {
+                    width: devmode.dmPelsWidth,
+                    height: devmode.dmPelsHeight,
+                    refresh_hz: devmode.dmDisplayFrequency,
+                };
+                if !modes.contains(&mode) {
+                    modes.push(mode);
+                }
+                mode_num += 1;
+            }
+            modes
+        }
+    }
+
+    /// Sets resolution and refresh rate with one `ChangeDisplaySettings` call, tested first.
+    #[allow(clippy::unused_self)]
+    fn set_mode_gdi(&self, mode: DisplayMode, persist: bool) -> Result<(), BalamError> {
+        unsafe {
+            let mut devmode = DEVMODEW {
+                dmSize: std::mem::size_of::<DEVMODEW>() as u16,
+                ..Default::default()
+            };
+
+            if !EnumDisplaySettingsW(None, ENUM_CURRENT_SETTINGS, &raw mut devmode).as_bool() {
+                return Err(BalamError::Platform(
+                    "Failed to get current display settings".to_string(),
+                ));
+            }
+
+            devmode.dmPelsWidth = mode.width;
+            devmode.dmPelsHeight = mode.height;
+            devmode.dmDisplayFrequency = mode.refresh_hz;
+            devmode.dmFields = DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY;
+
+            let label = format!("{}x{} @ {}Hz", mode.width, mode.height, mode.refresh_hz);
+            if ChangeDisplaySettingsW(Some(&raw const devmode), CDS_TEST) != DISP_CHANGE_SUCCESSFUL {
+                return Err(BalamError::InvalidArgument(format!(
+                    "{label} is not supported by this display"
+                )));
+            }
+
+            info!("Switching display mode to {} (persist: {})", label, persist);
+            let flags = if persist { CDS_UPDATEREGISTRY } else { CDS_TYPE(0) };
+            let result = ChangeDisplaySettingsW(Some(&raw const devmode), flags);
+            if result == DISP_CHANGE_SUCCESSFUL {
+                Ok(())
+            } else {
+                warn!("Failed to change display mode. Code: {:?}", result);
+                Err(BalamError::Platform(format!(
+                    "Failed to switch display mode to {label}"
+                )))
+            }
+        }
+    }
 }
 
 impl DisplayPort for WindowsDisplayAdapter {
-    fn get_brightness(&self) -> Result<Option<u32>, String> {
+    fn get_brightness(&self) -> Result<Option<u32>, BalamError> {
         // Try WMI first (laptops), fallback to DDC/CI (external monitors)
         if let Ok(Some(level)) = self.get_brightness_wmi() {
             return Ok(Some(level));
         }
 
-        self.get_brightness_ddcci()
+        self.ddc_ci.get_brightness(None)
     }
 
-    fn set_brightness(&self, config: BrightnessConfig) -> Result<(), String> {
+    fn set_brightness(&self, config: BrightnessConfig) -> Result<(), BalamError> {
         let level = BrightnessConfig::clamp(config.level);
 
         // Try WMI first, fallback to DDC/CI
@@ -257,27 +405,87 @@ impl DisplayPort for WindowsDisplayAdapter {
             return Ok(());
         }
 
-        self.set_brightness_ddcci(level)
+        // Docked: every external monitor follows the slider
+        self.ddc_ci.set_brightness(None, level)
+    }
+
+    fn get_refresh_rate(&self) -> Result<u32, BalamError> {
+        self.get_current_refresh_rate_gdi(None)
+    }
+
+    fn set_refresh_rate(&self, config: RefreshRateConfig) -> Result<(), BalamError> {
+        self.set_refresh_rate_gdi(None, config.hz)
+    }
+
+    fn get_supported_refresh_rates(&self) -> Result<Vec<u32>, BalamError> {
+        Ok(self.enumerate_refresh_rates_gdi(None))
+    }
+
+    fn get_monitors(&self) -> Result<Vec<MonitorInfo>, BalamError> {
+        Ok(self.enumerate_monitors())
+    }
+
+    fn get_monitor_brightness(&self, device_name: &str) -> Result<Option<u32>, BalamError> {
+        match self.monitor(device_name)?.brightness {
+            BrightnessControl::Wmi => self.get_brightness_wmi(),
+            BrightnessControl::DdcCi => self.ddc_ci.get_brightness(Some(device_name)),
+            BrightnessControl::Unsupported => Ok(None),
+        }
+    }
+
+    fn set_monitor_brightness(&self, device_name: &str, config: BrightnessConfig) -> Result<(), BalamError> {
+        let level = BrightnessConfig::clamp(config.level);
+        match self.monitor(device_name)?.brightness {
+            BrightnessControl::Wmi => self.set_brightness_wmi(level),
+            BrightnessControl::DdcCi => self.ddc_ci.set_brightness(Some(device_name), level),
+            BrightnessControl::Unsupported => {
+                Err(BalamError::NotSupported(format!("Brightness control of {device_name}")))
+            },
+        }
     }
 
-    fn get_refresh_rate(&self) -> Result<u32, String> {
-        self.get_current_refresh_rate_gdi()
+    fn get_monitor_contrast(&self, device_name: &str) -> Result<Option<u32>, BalamError> {
+        match self.monitor(device_name)?.brightness {
+            BrightnessControl::DdcCi => self.ddc_ci.get_contrast(Some(device_name)),
+            _ => Ok(None),
+        }
     }
 
-    fn set_refresh_rate(&self, config: RefreshRateConfig) -> Result<(), String> {
-        self.set_refresh_rate_gdi(config.hz)
+    fn set_monitor_contrast(&self, device_name: &str, level: u32) -> Result<(), BalamError> {
+        match self.monitor(device_name)?.brightness {
+            BrightnessControl::DdcCi => self.ddc_ci.set_contrast(Some(device_name), level),
+            _ => Err(BalamError::NotSupported(format!("Contrast control of {device_name}"))),
+        }
     }
 
-    fn get_supported_refresh_rates(&self) -> Result<Vec<u32>, String> {
-        Ok(self.enumerate_refresh_rates_gdi())
+    fn get_monitor_refresh_rates(&self, device_name: &str) -> Result<Vec<u32>, BalamError> {
+        let device = HSTRING::from(self.monitor(device_name)?.device_name.as_str());
+        Ok(self.enumerate_refresh_rates_gdi(Some(&device)))
+    }
+
+    fn set_monitor_refresh_rate(&self, device_name: &str, config: RefreshRateConfig) -> Result<(), BalamError> {
+        let device = HSTRING::from(self.monitor(device_name)?.device_name.as_str());
+        self.set_refresh_rate_gdi(Some(&device), config.hz)
+    }
+
+    fn get_display_mode(&self) -> Result<DisplayMode, BalamError> {
+        self.get_current_mode_gdi()
+    }
+
+    fn get_display_modes(&self) -> Result<Vec<DisplayMode>, BalamError> {
+        Ok(self.enumerate_modes_gdi())
+    }
+
+    fn set_display_mode(&self, mode: DisplayMode, persist: bool) -> Result<(), BalamError> {
+        self.set_mode_gdi(mode, persist)
     }
 
     fn supports_brightness_control(&self) -> bool {
-        // Check if WMI brightness is available (quick query)
-        self.get_brightness_wmi().ok().flatten().is_some()
+        // Check if WMI brightness is available (quick query), then DDC/CI
+        self.get_brightness_wmi().ok().flatten().is_some() || self.ddc_ci.is_available()
     }
 
-    fn get_displays(&self) -> Result<Vec<DisplayInfo>, String> {
+    fn get_displays(&self) -> Result<Vec<DisplayInfo>, BalamError> {
         self.hdr_manager.get_displays()
     }
 
@@ -293,9 +501,17 @@ impl DisplayPort for WindowsDisplayAdapter {
         self.hdr_manager.is_hdr_enabled(display_id)
     }
 
-    fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), String> {
+    fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), BalamError> {
         self.hdr_manager.set_hdr_enabled(display_id, enabled)
     }
+
+    fn set_color_profile(&self, profile: &ColorProfile) -> Result<(), BalamError> {
+        self.color_manager.apply(profile)
+    }
+
+    fn supports_vibrance(&self) -> bool {
+        self.color_manager.supports_vibrance()
+    }
 }
 
 #[cfg(test)]
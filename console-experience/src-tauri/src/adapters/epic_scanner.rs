@@ -1,12 +1,21 @@
+use crate::adapters::legendary_adapter::LegendaryAdapter;
 use crate::config::ExclusionConfig;
 use crate::domain::errors::ScanError;
-use crate::domain::{Game, GameSource};
+use crate::domain::game_move::relocate_path;
+use crate::domain::{Game, GameSource, InstallState};
 use crate::ports::GameScanner;
 use serde::Deserialize;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::info;
 
+/// Directory where the Epic Games Launcher stores `.item` install manifests.
+const MANIFEST_DIR: &str = "C:\\ProgramData\\Epic\\EpicGamesLauncher\\Data\\Manifests";
+/// Install list the launcher reads on startup (must agree with the manifests).
+const LAUNCHER_INSTALLED: &str = "C:\\ProgramData\\Epic\\UnrealEngineLauncher\\LauncherInstalled.dat";
+/// Manifest fields holding paths inside the install folder.
+const MANIFEST_PATH_FIELDS: [&str; 3] = ["InstallLocation", "ManifestLocation", "StagingLocation"];
+
 #[derive(Deserialize, Debug)]
 struct EpicManifest {
     #[serde(rename = "DisplayName")]
@@ -17,6 +26,36 @@ struct EpicManifest {
     launch_executable: String,
     #[serde(rename = "CatalogItemId")]
     catalog_item_id: String,
+    #[serde(rename = "AppName", default)]
+    app_name: String,
+    /// Set while the launcher is still downloading the install
+    #[serde(rename = "bIsIncompleteInstall", default)]
+    is_incomplete_install: bool,
+    /// Set when the launcher must verify the files before the next launch
+    #[serde(rename = "bNeedsValidation", default)]
+    needs_validation: bool,
+    /// Folder of a partly downloaded update (empty when none)
+    #[serde(rename = "StagingLocation", default)]
+    staging_location: String,
+}
+
+impl EpicManifest {
+    fn install_state(&self) -> InstallState {
+        let update_staged = !self.staging_location.is_empty()
+            && Path::new(&self.staging_location)
+                .read_dir()
+                .is_ok_and(|mut entries| entries.next().is_some());
+        InstallState::from_epic_manifest(self.is_incomplete_install, self.needs_validation, update_staged)
+    }
+}
+
+/// What the launcher needs to start and monitor an Epic game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpicLaunchInfo {
+    /// Launcher app name used in `com.epicgames.launcher://apps/<AppName>`
+    pub app_name: String,
+    /// Real game executable (`InstallLocation` + `LaunchExecutable`)
+    pub executable_path: PathBuf,
 }
 
 /// Epic Games Store scanner.
@@ -28,6 +67,8 @@ struct EpicManifest {
 /// - **Manifest Directory**: `%ProgramData%\Epic\EpicGamesLauncher\Data\Manifests\`
 /// - **Manifest Format**: JSON (`.item` files)
 /// - **Metadata Quality**: High (`DisplayName`, `InstallLocation`, `LaunchExecutable`)
+/// - **Legendary / Heroic**: `installed.json` of the Legendary config folders,
+///   for games installed without the Epic Games Launcher
 ///
 /// # Performance
 /// Typical scan time: **200-500ms** for 50-100 games.
@@ -79,13 +120,93 @@ impl EpicScanner {
         Self::new().scan_internal().unwrap_or_default()
     }
 
+    /// Looks up the install manifest of a catalog item (the `raw_id` of Epic games).
+    ///
+    /// # Returns
+    /// `None` if no manifest matches or the manifest has no `AppName`.
+    #[must_use]
+    pub fn find_launch_info(catalog_item_id: &str) -> Option<EpicLaunchInfo> {
+        fs::read_dir(MANIFEST_DIR)
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("item"))
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .filter_map(|content| serde_json::from_str::<EpicManifest>(&content).ok())
+            .find(|manifest| manifest.catalog_item_id == catalog_item_id)
+            .filter(|manifest| !manifest.app_name.is_empty())
+            .map(|manifest| EpicLaunchInfo {
+                executable_path: Path::new(&manifest.install_location).join(&manifest.launch_executable),
+                app_name: manifest.app_name,
+            })
+    }
+
+    /// Install folder (`InstallLocation`) of a catalog item.
+    #[must_use]
+    pub fn find_install_location(catalog_item_id: &str) -> Option<PathBuf> {
+        Self::manifest_files()
+            .filter_map(|path| fs::read_to_string(path).ok())
+            .filter_map(|content| serde_json::from_str::<EpicManifest>(&content).ok())
+            .find(|manifest| manifest.catalog_item_id == catalog_item_id)
+            .map(|manifest| PathBuf::from(manifest.install_location))
+    }
+
+    /// Points the install manifest and `LauncherInstalled.dat` at a moved install.
+    ///
+    /// The Epic Games Launcher must be closed: it rewrites both files from
+    /// memory when it exits.
+    ///
+    /// # Errors
+    /// Returns error if no manifest matches or a file cannot be rewritten.
+    pub fn relocate_install(catalog_item_id: &str, old_location: &Path, new_location: &Path) -> Result<(), String> {
+        let manifest_path = Self::manifest_files()
+            .find(|path| {
+                fs::read_to_string(path)
+                    .ok()
+                    .and_then(|content| serde_json::from_str::<EpicManifest>(&content).ok())
+                    .is_some_and(|manifest| manifest.catalog_item_id == catalog_item_id)
+            })
+            .ok_or_else(|| format!("No Epic manifest for {catalog_item_id}"))?;
+
+        rewrite_json(&manifest_path, |manifest| {
+            relocate_fields(manifest, &MANIFEST_PATH_FIELDS, old_location, new_location);
+        })?;
+
+        if Path::new(LAUNCHER_INSTALLED).exists() {
+            rewrite_json(Path::new(LAUNCHER_INSTALLED), |installed| {
+                if let Some(list) = installed
+                    .get_mut("InstallationList")
+                    .and_then(serde_json::Value::as_array_mut)
+                {
+                    for item in list.iter_mut().filter(|item| item["CatalogItemId"] == catalog_item_id) {
+                        relocate_fields(item, &["InstallLocation"], old_location, new_location);
+                    }
+                }
+            })?;
+        }
+
+        info!(
+            "Epic install {} relocated to {}",
+            catalog_item_id,
+            new_location.display()
+        );
+        Ok(())
+    }
+
+    fn manifest_files() -> impl Iterator<Item = PathBuf> {
+        fs::read_dir(MANIFEST_DIR)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|s| s.to_str()) == Some("item"))
+    }
+
     fn scan_internal(&self) -> Result<Vec<Game>, ScanError> {
         let mut games = Vec::new();
         info!("Scanning Epic Games...");
 
-        let manifest_path = "C:\\ProgramData\\Epic\\EpicGamesLauncher\\Data\\Manifests";
-
-        if let Ok(entries) = fs::read_dir(manifest_path) {
+        if let Ok(entries) = fs::read_dir(MANIFEST_DIR) {
             for entry in entries.flatten() {
                 let path = entry.path();
                 if path.extension().and_then(|s| s.to_str()) == Some("item") {
@@ -104,6 +225,7 @@ impl EpicScanner {
                             let install_path = Path::new(&manifest.install_location);
                             let full_exe_path = install_path.join(&manifest.launch_executable);
                             let catalog_item_id = &manifest.catalog_item_id;
+                            let install_state = manifest.install_state();
 
                             games.push(Game {
                                 id: format!("epic_{catalog_item_id}"),
@@ -115,6 +237,9 @@ impl EpicScanner {
                                 logo: None,
                                 last_played: None,
                                 source: GameSource::Epic,
+                                install_state,
+                                launcher_dependency: None,
+                                update_pending: install_state.is_update_pending(),
                             });
                         }
                     }
@@ -122,9 +247,76 @@ impl EpicScanner {
             }
         }
 
+        self.import_legendary(&mut games);
+
         info!("Epic Games scan complete. Found {} games", games.len());
         Ok(games)
     }
+
+    /// Adds games installed through Legendary that the launcher does not know.
+    ///
+    /// They keep the catalog item id as `raw_id` when Legendary has cached
+    /// metadata, so the same game installed both ways is listed once.
+    fn import_legendary(&self, games: &mut Vec<Game>) {
+        for (config_dir, install) in LegendaryAdapter::installed_games() {
+            if self
+                .exclusions
+                .epic_patterns
+                .iter()
+                .any(|pattern| install.title.contains(pattern))
+            {
+                continue;
+            }
+            let raw_id = LegendaryAdapter::catalog_item_id(&config_dir, &install.app_name)
+                .unwrap_or_else(|| install.app_name.clone());
+            if games.iter().any(|game| game.raw_id == raw_id) {
+                continue;
+            }
+
+            let full_exe_path = Path::new(&install.install_path).join(&install.executable);
+            let install_state = if LegendaryAdapter::update_pending(&config_dir, &install) {
+                InstallState::UpdateRequired
+            } else {
+                InstallState::Installed
+            };
+            games.push(Game {
+                id: format!("epic_{raw_id}"),
+                raw_id,
+                title: install.title,
+                path: full_exe_path.to_string_lossy().to_string(),
+                image: None,
+                hero_image: None,
+                logo: None,
+                last_played: None,
+                source: GameSource::Epic,
+                install_state,
+                launcher_dependency: None,
+                update_pending: install_state.is_update_pending(),
+            });
+        }
+    }
+}
+
+/// Rewrites string path fields that point inside `old_root`.
+fn relocate_fields(value: &mut serde_json::Value, fields: &[&str], old_root: &Path, new_root: &Path) {
+    for field in fields {
+        let relocated = value
+            .get(*field)
+            .and_then(serde_json::Value::as_str)
+            .and_then(|path| relocate_path(Path::new(path), old_root, new_root));
+        if let Some(relocated) = relocated {
+            value[*field] = serde_json::Value::String(relocated.to_string_lossy().to_string());
+        }
+    }
+}
+
+fn rewrite_json(path: &Path, modify: impl FnOnce(&mut serde_json::Value)) -> Result<(), String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let mut value: serde_json::Value =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid JSON in {}: {e}", path.display()))?;
+    modify(&mut value);
+    let content = serde_json::to_string_pretty(&value).map_err(|e| e.to_string())?;
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {e}", path.display()))
 }
 
 impl Default for EpicScanner {
@@ -188,5 +380,42 @@ mod tests {
         let manifest = manifest.unwrap();
         assert_eq!(manifest.display_name, "Fortnite");
         assert_eq!(manifest.catalog_item_id, "4fe75bbc5a674f4f9b356b5c90567da5");
+        assert!(manifest.app_name.is_empty());
+    }
+
+    #[test]
+    fn test_manifest_app_name() {
+        let json = r#"{
+            "DisplayName": "Fortnite",
+            "InstallLocation": "C:\\Program Files\\Epic Games\\Fortnite",
+            "LaunchExecutable": "FortniteGame/Binaries/Win64/FortniteClient-Win64-Shipping.exe",
+            "CatalogItemId": "4fe75bbc5a674f4f9b356b5c90567da5",
+            "AppName": "Fortnite"
+        }"#;
+
+        let manifest: EpicManifest = serde_json::from_str(json).unwrap();
+        assert_eq!(manifest.app_name, "Fortnite");
+    }
+
+    #[test]
+    fn test_relocate_fields_only_touches_install_paths() {
+        let mut manifest = serde_json::json!({
+            "InstallLocation": "C:\\Games\\Hades",
+            "StagingLocation": "C:\\Games\\Hades\\.egstore\\bps",
+            "ManifestLocation": "C:\\ProgramData\\Epic\\Manifests",
+            "DisplayName": "Hades"
+        });
+
+        relocate_fields(
+            &mut manifest,
+            &MANIFEST_PATH_FIELDS,
+            Path::new("C:\\Games\\Hades"),
+            Path::new("E:\\Games\\Hades"),
+        );
+
+        assert_eq!(manifest["InstallLocation"], "E:\\Games\\Hades");
+        assert_eq!(manifest["StagingLocation"], "E:\\Games\\Hades\\.egstore\\bps");
+        assert_eq!(manifest["ManifestLocation"], "C:\\ProgramData\\Epic\\Manifests");
+        assert_eq!(manifest["DisplayName"], "Hades");
     }
 }
@@ -0,0 +1,3 @@
+mod windows_foreground_events;
+
+pub use windows_foreground_events::WindowsForegroundEvents;
@@ -0,0 +1,145 @@
+use crate::ports::foreground_port::{ForegroundCallback, ForegroundEventsPort};
+use std::sync::mpsc;
+use std::sync::OnceLock;
+use tracing::{info, warn};
+use windows::Win32::Foundation::{CloseHandle, HWND};
+use windows::Win32::System::Diagnostics::ToolHelp::{
+    CreateToolhelp32Snapshot, Module32FirstW, Module32NextW, MODULEENTRY32W, TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32,
+};
+use windows::Win32::UI::Accessibility::{SetWinEventHook, HWINEVENTHOOK};
+use windows::Win32::UI::WindowsAndMessaging::{
+    DispatchMessageW, GetForegroundWindow, GetMessageW, GetWindowThreadProcessId, TranslateMessage,
+    EVENT_SYSTEM_FOREGROUND, MSG, WINEVENT_OUTOFCONTEXT,
+};
+
+/// Steam injects its overlay renderer into every game it starts with the
+/// overlay or Steam Input on; the Steam Input XInput/HID hooks live in it.
+const STEAM_INPUT_MODULES: &[&str] = &["gameoverlayrenderer64.dll", "gameoverlayrenderer.dll"];
+
+/// The WinEvent callback has no context argument, so the subscriber lives here.
+static CALLBACK: OnceLock<ForegroundCallback> = OnceLock::new();
+
+/// Windows implementation of `ForegroundEventsPort`.
+///
+/// `SetWinEventHook(EVENT_SYSTEM_FOREGROUND)` out of context, on a
+/// dedicated thread that pumps messages (the hook is delivered through
+/// that thread's queue). No polling.
+pub struct WindowsForegroundEvents;
+
+impl Default for WindowsForegroundEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowsForegroundEvents {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ForegroundEventsPort for WindowsForegroundEvents {
+    fn subscribe(&self, callback: ForegroundCallback) -> bool {
+        if CALLBACK.set(callback).is_err() {
+            warn!("Foreground events already subscribed");
+            return false;
+        }
+
+        let (registered_tx, registered_rx) = mpsc::channel();
+        let spawned = std::thread::Builder::new()
+            .name("foreground-events".to_string())
+            .spawn(move || unsafe {
+                let hook = SetWinEventHook(
+                    EVENT_SYSTEM_FOREGROUND,
+                    EVENT_SYSTEM_FOREGROUND,
+                    None,
+                    Some(on_foreground),
+                    0,
+                    0,
+                    WINEVENT_OUTOFCONTEXT,
+                );
+                let _ = registered_tx.send(!hook.is_invalid());
+                if hook.is_invalid() {
+                    return;
+                }
+                report(GetForegroundWindow());
+
+                let mut msg = MSG::default();
+                while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                    let _ = TranslateMessage(&msg);
+                    DispatchMessageW(&msg);
+                }
+            });
+        if let Err(e) = spawned {
+            warn!("Failed to start foreground event thread: {}", e);
+            return false;
+        }
+
+        let registered = registered_rx.recv().unwrap_or(false);
+        if registered {
+            info!("🪟 Foreground window hook registered");
+        } else {
+            warn!("SetWinEventHook(EVENT_SYSTEM_FOREGROUND) failed");
+        }
+        registered
+    }
+
+    fn uses_steam_input(&self, pid: u32) -> bool {
+        unsafe {
+            let Ok(snapshot) = CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, pid) else {
+                // Protected or already gone
+                return false;
+            };
+            let mut entry = MODULEENTRY32W {
+                dwSize: std::mem::size_of::<MODULEENTRY32W>() as u32,
+                ..Default::default()
+            };
+            let mut found = false;
+            let mut more = Module32FirstW(snapshot, &mut entry).is_ok();
+            while more {
+                let name = String::from_utf16_lossy(&entry.szModule);
+                if is_steam_input_module(name.trim_end_matches('\0')) {
+                    found = true;
+                    break;
+                }
+                more = Module32NextW(snapshot, &mut entry).is_ok();
+            }
+            let _ = CloseHandle(snapshot);
+            found
+        }
+    }
+
+    fn executable_path(&self, pid: u32) -> Option<String> {
+        crate::adapters::process_events::executable_path(pid)
+    }
+}
+
+fn is_steam_input_module(name: &str) -> bool {
+    STEAM_INPUT_MODULES
+        .iter()
+        .any(|module| name.eq_ignore_ascii_case(module))
+}
+
+unsafe extern "system" fn on_foreground(
+    _hook: HWINEVENTHOOK,
+    _event: u32,
+    hwnd: HWND,
+    _object: i32,
+    _child: i32,
+    _thread: u32,
+    _time: u32,
+) {
+    report(hwnd);
+}
+
+unsafe fn report(hwnd: HWND) {
+    if hwnd.0 == 0 {
+        return;
+    }
+    let mut pid = 0u32;
+    GetWindowThreadProcessId(hwnd, Some(&mut pid));
+    if let (Some(callback), true) = (CALLBACK.get(), pid != 0) {
+        callback(pid);
+    }
+}
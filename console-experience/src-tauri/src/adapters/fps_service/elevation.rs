@@ -1,6 +1,7 @@
 /// UAC Elevation Helper
 ///
 /// Uses Windows ShellExecuteW API with "runas" verb to execute commands with elevation.
+use crate::infrastructure::encoding::to_wide;
 use std::path::Path;
 use windows::core::{w, PCWSTR};
 use windows::Win32::Foundation::HWND;
@@ -35,11 +36,9 @@ pub fn execute_elevated(
 
     unsafe {
         // Convert paths to wide strings
-        let exe_wide = to_wide_string(exe_path.to_string_lossy().as_ref());
-        let args_wide = arguments.map(to_wide_string);
-        let dir_wide = working_dir
-            .as_ref()
-            .map(|p| to_wide_string(p.as_ref().to_string_lossy().as_ref()));
+        let exe_wide = to_wide(exe_path);
+        let args_wide = arguments.map(to_wide);
+        let dir_wide = working_dir.as_ref().map(|p| to_wide(p.as_ref()));
 
         // Execute with elevation
         let result = ShellExecuteW(
@@ -69,11 +68,6 @@ pub fn execute_elevated(
     Ok(())
 }
 
-/// Convert string to wide (UTF-16) null-terminated
-fn to_wide_string(s: &str) -> Vec<u16> {
-    s.encode_utf16().chain(std::iter::once(0)).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,36 +1,54 @@
-/// FPS Client - Named Pipe client for reading FPS from service
+/// FPS Client - Named Pipe client of the FPS service query protocol
 ///
-/// Connects to `\\.\pipe\BalamFps` and reads current FPS value.
+/// Connects to `\\.\pipe\BalamFpsQuery` from a background task and keeps the
+/// latest per-PID stats the service pushes or answers with.
 ///
 /// # Performance
-/// - <1ms per request (cached)
-/// - Non-blocking
-/// - Auto-reconnect on failure
+/// - Getters only read cached state (<1ms, never block on the pipe)
+/// - Subscribed PIDs are pushed every 500ms by the service
+/// - Auto-reconnect with backoff; subscriptions are replayed after reconnecting
 use parking_lot::Mutex;
-use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
-use windows::core::Result as WinResult;
-use windows::Win32::Foundation::{CloseHandle, GENERIC_READ, INVALID_HANDLE_VALUE};
-use windows::Win32::Storage::FileSystem::{
-    CreateFileA, ReadFile, FILE_ATTRIBUTE_NORMAL, FILE_SHARE_READ, OPEN_EXISTING,
-};
-use windows::Win32::System::Pipes::{SetNamedPipeHandleState, PIPE_READMODE_MESSAGE};
-
-const CACHE_DURATION: Duration = Duration::from_millis(100); // Cache for 100ms
-
-/// FPS data structure
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct FpsData {
-    pub fps: f32,
+use tokio::io::{AsyncWriteExt, ReadHalf, WriteHalf};
+use tokio::net::windows::named_pipe::{ClientOptions, NamedPipeClient};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tracing::{debug, info, warn};
+
+use super::protocol::{encode_frame, read_frame, Message, ProcessStats, Request, QUERY_PIPE_NAME};
+
+/// Stats older than this are treated as missing (service stopped pushing)
+const STATS_MAX_AGE: Duration = Duration::from_secs(2);
+
+/// How often the tracked PIDs are refreshed
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// First reconnect delay after the pipe is lost
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_millis(500);
+/// Reconnect delay cap while the service is not running
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(10);
+
+/// State shared between the client and its connection task
+#[derive(Default)]
+struct Shared {
+    connected: bool,
+    /// PIDs that presented frames in the last second
+    tracked: Vec<u32>,
+    /// Process the service considers the game
+    active: Option<u32>,
+    stats: HashMap<u32, (ProcessStats, Instant)>,
+    /// Wanted subscriptions, replayed after reconnecting
+    subscriptions: BTreeSet<u32>,
+    /// PID subscribed through [`FpsClient::follow`]
+    followed: Option<u32>,
 }
 
 /// FPS Client
 pub struct FpsClient {
-    /// Cached FPS value
-    cached_fps: Arc<Mutex<Option<f32>>>,
-    /// Last update time
-    last_update: Arc<Mutex<Instant>>,
+    shared: Arc<Mutex<Shared>>,
+    /// Requests for the connection task (started on first use)
+    commands: OnceLock<UnboundedSender<Request>>,
 }
 
 impl FpsClient {
@@ -38,89 +56,100 @@ impl FpsClient {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            cached_fps: Arc::new(Mutex::new(None)),
-            last_update: Arc::new(Mutex::new(Instant::now().checked_sub(CACHE_DURATION).unwrap())),
+            shared: Arc::new(Mutex::new(Shared::default())),
+            commands: OnceLock::new(),
         }
     }
 
     /// Get current FPS
     ///
-    /// Returns cached value if fresh (<100ms old), otherwise queries service.
-    ///
     /// # Returns
     /// - `Some(fps)` - FPS value available
-    /// - `None` - Service not available or not responding
+    /// - `None` - Service not available or no recent frames
     #[must_use]
     pub fn get_fps(&self) -> Option<f32> {
-        let now = Instant::now();
-        let last_update = *self.last_update.lock();
-
-        // Return cached value if fresh
-        if now.duration_since(last_update) < CACHE_DURATION {
-            if let Some(fps) = *self.cached_fps.lock() {
-                return Some(fps);
-            }
+        self.current_stats().map(|stats| stats.fps)
+    }
+
+    /// Stats of the followed PID, or of the process the service considers
+    /// the game when nothing is followed
+    #[must_use]
+    pub fn current_stats(&self) -> Option<ProcessStats> {
+        self.connection();
+        let pid = {
+            let shared = self.shared.lock();
+            shared.followed.or(shared.active)
+        };
+        pid.and_then(|pid| self.stats(pid))
+    }
+
+    /// Latest stats of a process, if fresh
+    #[must_use]
+    pub fn stats(&self, pid: u32) -> Option<ProcessStats> {
+        self.connection();
+        let shared = self.shared.lock();
+        shared
+            .stats
+            .get(&pid)
+            .filter(|(_, received)| received.elapsed() < STATS_MAX_AGE)
+            .map(|(stats, _)| stats.clone())
+    }
+
+    /// PIDs that presented frames in the last second
+    #[must_use]
+    pub fn tracked_pids(&self) -> Vec<u32> {
+        self.connection();
+        self.shared.lock().tracked.clone()
+    }
+
+    /// Receive pushed stats of `pid` until [`Self::unsubscribe`]
+    pub fn subscribe(&self, pid: u32) {
+        if self.shared.lock().subscriptions.insert(pid) {
+            let _ = self.connection().send(Request::Subscribe { pid });
         }
+    }
 
-        // Query service
-        match Self::query_service() {
-            Ok(fps) => {
-                *self.cached_fps.lock() = Some(fps);
-                *self.last_update.lock() = now;
-                Some(fps)
-            },
-            Err(_) => {
-                // Service not available - silent fail (expected when service not running)
-                None
-            },
+    pub fn unsubscribe(&self, pid: u32) {
+        let removed = {
+            let mut shared = self.shared.lock();
+            shared.stats.remove(&pid);
+            shared.subscriptions.remove(&pid)
+        };
+        if removed {
+            let _ = self.connection().send(Request::Unsubscribe { pid });
         }
     }
 
-    /// Query FPS from service via named pipe
-    fn query_service() -> WinResult<f32> {
-        unsafe {
-            // Open named pipe (READ ONLY - default security allows Everyone to read)
-            let pipe_handle = CreateFileA(
-                windows::core::s!(r"\\.\pipe\BalamFps"),
-                GENERIC_READ.0, // Only READ access (not WRITE)
-                FILE_SHARE_READ,
-                None,
-                OPEN_EXISTING,
-                FILE_ATTRIBUTE_NORMAL,
-                None,
-            )?;
-
-            if pipe_handle == INVALID_HANDLE_VALUE {
-                return Err(windows::core::Error::from_win32());
-            }
-
-            // Set pipe mode to message
-            let mode = PIPE_READMODE_MESSAGE;
-            let _ = SetNamedPipeHandleState(pipe_handle, Some(&mode), None, None);
-
-            // No write needed - server sends data immediately on connect
-            // Read response
-            let mut buffer = [0u8; 512];
-            let mut bytes_read = 0u32;
-            ReadFile(pipe_handle, Some(&mut buffer), Some(&mut bytes_read), None)?;
-
-            // Close handle
-            let _ = CloseHandle(pipe_handle);
-
-            // Parse JSON response
-            let json =
-                std::str::from_utf8(&buffer[..bytes_read as usize]).map_err(|_| windows::core::Error::from_win32())?;
-
-            let data: FpsData = serde_json::from_str(json).map_err(|_| windows::core::Error::from_win32())?;
-
-            Ok(data.fps)
+    /// Subscribes to `pid` in place of the previously followed process
+    ///
+    /// `None` falls back to the process the service considers the game.
+    pub fn follow(&self, pid: Option<u32>) {
+        let previous = std::mem::replace(&mut self.shared.lock().followed, pid);
+        if previous == pid {
+            return;
+        }
+        if let Some(previous) = previous {
+            self.unsubscribe(previous);
+        }
+        if let Some(pid) = pid {
+            self.subscribe(pid);
         }
     }
 
     /// Check if service is available
     #[must_use]
     pub fn is_service_available(&self) -> bool {
-        Self::query_service().is_ok()
+        self.connection();
+        self.shared.lock().connected
+    }
+
+    /// Sender of the connection task, started on first use
+    fn connection(&self) -> &UnboundedSender<Request> {
+        self.commands.get_or_init(|| {
+            let (sender, receiver) = mpsc::unbounded_channel();
+            tauri::async_runtime::spawn(run(self.shared.clone(), receiver));
+            sender
+        })
     }
 }
 
@@ -130,6 +159,123 @@ impl Default for FpsClient {
     }
 }
 
+/// Connects to the service until the client is dropped
+async fn run(shared: Arc<Mutex<Shared>>, mut commands: UnboundedReceiver<Request>) {
+    let mut delay = RECONNECT_INITIAL_DELAY;
+
+    while !commands.is_closed() {
+        match ClientOptions::new().open(QUERY_PIPE_NAME) {
+            Ok(client) => {
+                info!("✅ Connected to FPS service");
+                delay = RECONNECT_INITIAL_DELAY;
+                shared.lock().connected = true;
+                let result = serve(client, &shared, &mut commands).await;
+                {
+                    let mut shared = shared.lock();
+                    shared.connected = false;
+                    shared.tracked.clear();
+                    shared.active = None;
+                    shared.stats.clear();
+                }
+                match result {
+                    Ok(()) => break, // Client dropped
+                    Err(e) => warn!("FPS service pipe lost: {}", e),
+                }
+            },
+            Err(e) => {
+                // Expected while the service is not installed or not running
+                debug!("FPS service unavailable ({}), retrying in {:?}", e, delay);
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+            },
+        }
+    }
+}
+
+/// Exchanges frames until the pipe breaks (`Err`) or the client is dropped (`Ok`)
+async fn serve(
+    client: NamedPipeClient,
+    shared: &Mutex<Shared>,
+    commands: &mut UnboundedReceiver<Request>,
+) -> std::io::Result<()> {
+    let (mut reader, mut writer) = tokio::io::split(client);
+
+    // Requests queued while disconnected are superseded by the wanted state
+    while commands.try_recv().is_ok() {}
+    let subscriptions: Vec<u32> = shared.lock().subscriptions.iter().copied().collect();
+    for pid in subscriptions {
+        send(&mut writer, &Request::Subscribe { pid }).await?;
+    }
+
+    // Reading stays in its own loop: a frame is never cut by `select!`
+    tokio::select! {
+        result = receive(&mut reader, shared) => result,
+        result = transmit(&mut writer, shared, commands) => result,
+    }
+}
+
+async fn receive(reader: &mut ReadHalf<NamedPipeClient>, shared: &Mutex<Shared>) -> std::io::Result<()> {
+    loop {
+        let message = read_frame(reader).await?;
+        handle_message(shared, message);
+    }
+}
+
+async fn transmit(
+    writer: &mut WriteHalf<NamedPipeClient>,
+    shared: &Mutex<Shared>,
+    commands: &mut UnboundedReceiver<Request>,
+) -> std::io::Result<()> {
+    let mut refresh = tokio::time::interval(REFRESH_INTERVAL);
+    loop {
+        tokio::select! {
+            request = commands.recv() => match request {
+                Some(request) => send(writer, &request).await?,
+                None => return Ok(()),
+            },
+            _ = refresh.tick() => {
+                send(writer, &Request::ListTrackedPids).await?;
+                // Stats of the game are polled when nobody subscribed to it
+                let unsubscribed_active = {
+                    let shared = shared.lock();
+                    shared.active.filter(|pid| !shared.subscriptions.contains(pid))
+                };
+                if let Some(pid) = unsubscribed_active {
+                    send(writer, &Request::GetStats { pid }).await?;
+                }
+            },
+        }
+    }
+}
+
+async fn send(writer: &mut WriteHalf<NamedPipeClient>, request: &Request) -> std::io::Result<()> {
+    writer.write_all(&encode_frame(request)).await
+}
+
+fn handle_message(shared: &Mutex<Shared>, message: Message) {
+    let mut shared = shared.lock();
+    match message {
+        Message::TrackedPids { pids, active } => {
+            shared.tracked = pids;
+            shared.active = active;
+        },
+        Message::Stats {
+            pid,
+            stats: Some(stats),
+        } => {
+            shared.stats.insert(pid, (stats, Instant::now()));
+        },
+        Message::Stats { pid, stats: None } => {
+            shared.stats.remove(&pid);
+        },
+        Message::Update { stats } => {
+            shared.stats.insert(stats.pid, (stats, Instant::now()));
+        },
+        Message::Ok => {},
+        Message::Error { message } => warn!("FPS service rejected a request: {}", message),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -4,8 +4,10 @@
 /// Balam FPS Windows Service.
 pub mod elevation;
 pub mod fps_client;
+pub mod protocol;
 pub mod service_installer;
 
 pub use elevation::execute_elevated;
 pub use fps_client::FpsClient;
+pub use protocol::ProcessStats;
 pub use service_installer::FpsServiceInstaller;
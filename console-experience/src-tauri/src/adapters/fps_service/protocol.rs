@@ -0,0 +1,117 @@
+/// FPS Service Query Protocol
+///
+/// Framed protocol of `\\.\pipe\BalamFpsQuery`: every frame is a
+/// little-endian `u32` length followed by that many bytes of JSON.
+///
+/// Kept in sync with `fps-service/src/ipc_protocol.rs`.
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Duplex pipe served by the FPS service
+pub const QUERY_PIPE_NAME: &str = r"\\.\pipe\BalamFpsQuery";
+
+/// Frames larger than this are rejected (a frame history is ~1 KiB)
+pub const MAX_FRAME_BYTES: usize = 64 * 1024;
+
+/// Frame statistics of one process
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProcessStats {
+    pub pid: u32,
+    /// Frames presented in the last second
+    pub fps: f32,
+    /// Average over the service's frame window (5 seconds)
+    pub avg_fps: f32,
+    /// FPS of the slowest 1% of frames in the window
+    pub fps_1_percent_low: f32,
+    /// Mean frame time over the last second
+    pub frame_time_ms: f32,
+    /// Latest frame times, oldest first
+    pub frame_history_ms: Vec<f32>,
+}
+
+/// Client → service
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Request {
+    /// Push `Update`s for this PID until unsubscribed or disconnected
+    Subscribe {
+        pid: u32,
+    },
+    Unsubscribe {
+        pid: u32,
+    },
+    GetStats {
+        pid: u32,
+    },
+    ListTrackedPids,
+}
+
+/// Service → client
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Message {
+    /// Answer to `GetStats` (`None` without recent frames)
+    Stats {
+        pid: u32,
+        stats: Option<ProcessStats>,
+    },
+    /// Answer to `ListTrackedPids`; `active` is the likely game
+    TrackedPids {
+        pids: Vec<u32>,
+        active: Option<u32>,
+    },
+    /// Answer to `Subscribe` / `Unsubscribe`
+    Ok,
+    /// Pushed for subscribed PIDs with recent frames
+    Update {
+        stats: ProcessStats,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Length-prefixed JSON frame of `value`
+pub fn encode_frame<T: Serialize>(value: &T) -> Vec<u8> {
+    let body = serde_json::to_vec(value).unwrap_or_default();
+    let mut frame = Vec::with_capacity(4 + body.len());
+    frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    frame.extend_from_slice(&body);
+    frame
+}
+
+/// Reads the next message sent by the service.
+///
+/// Not cancel-safe: keep it out of `select!` branches that may be dropped.
+pub async fn read_frame<R: AsyncRead + Unpin>(reader: &mut R) -> std::io::Result<Message> {
+    let length = reader.read_u32_le().await? as usize;
+    if length > MAX_FRAME_BYTES {
+        return Err(std::io::Error::other(format!(
+            "Frame of {length} bytes exceeds {MAX_FRAME_BYTES}"
+        )));
+    }
+    let mut body = vec![0u8; length];
+    reader.read_exact(&mut body).await?;
+    serde_json::from_slice(&body).map_err(std::io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_frames_round_trip_and_oversized_frames_are_rejected() {
+        let message = Message::TrackedPids {
+            pids: vec![4, 1234],
+            active: Some(1234),
+        };
+        let mut frames = encode_frame(&message);
+        frames.extend_from_slice(&encode_frame(&Message::Ok));
+        frames.extend_from_slice(&(MAX_FRAME_BYTES as u32 + 1).to_le_bytes());
+
+        let mut reader = frames.as_slice();
+        assert_eq!(read_frame(&mut reader).await.unwrap(), message);
+        assert_eq!(read_frame(&mut reader).await.unwrap(), Message::Ok);
+        assert!(read_frame(&mut reader).await.is_err());
+    }
+}
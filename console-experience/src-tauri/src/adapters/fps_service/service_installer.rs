@@ -4,6 +4,7 @@
 ///
 /// # Security
 /// Requires administrator privileges to install/uninstall service.
+use crate::infrastructure::encoding::to_wide;
 use std::path::PathBuf;
 use tracing::info;
 use windows::core::PCWSTR;
@@ -14,11 +15,14 @@ use windows::Win32::System::Services::{
     OpenServiceW, QueryServiceStatus, StartServiceW, SC_MANAGER_CONNECT, SC_MANAGER_CREATE_SERVICE, SERVICE_ALL_ACCESS,
     SERVICE_AUTO_START, SERVICE_CONFIG_DELAYED_AUTO_START_INFO, SERVICE_CONFIG_DESCRIPTION, SERVICE_CONTROL_STOP,
     SERVICE_DELAYED_AUTO_START_INFO, SERVICE_DESCRIPTIONW, SERVICE_ERROR_NORMAL, SERVICE_QUERY_STATUS, SERVICE_RUNNING,
-    SERVICE_START, SERVICE_STATUS, SERVICE_STOP, SERVICE_STOPPED, SERVICE_WIN32_OWN_PROCESS,
+    SERVICE_START, SERVICE_STATUS, SERVICE_STOP, SERVICE_STOPPED, SERVICE_USER_DEFINED_CONTROL,
+    SERVICE_WIN32_OWN_PROCESS,
 };
 
 const SERVICE_NAME: &str = "BalamFpsService";
 const SERVICE_DISPLAY_NAME: &str = "Balam FPS Monitoring Service";
+/// User-defined control: recreate the ETW trace session (handled in `fps-service/src/service.rs`).
+pub const FPS_SERVICE_CONTROL_RESTART_ETW: u32 = 128;
 const SERVICE_DESCRIPTION: &str = "Provides FPS monitoring for Balam Console using ETW (Event Tracing for Windows)";
 
 /// FPS Service Installer
@@ -53,9 +57,9 @@ impl FpsServiceInstaller {
             }
 
             // Convert paths to wide strings using w! macro for literals
-            let service_name = Self::to_wide(SERVICE_NAME);
-            let display_name = Self::to_wide(SERVICE_DISPLAY_NAME);
-            let binary_path = Self::to_wide(&service_binary_path.to_string_lossy());
+            let service_name = to_wide(SERVICE_NAME);
+            let display_name = to_wide(SERVICE_DISPLAY_NAME);
+            let binary_path = to_wide(&service_binary_path.to_string_lossy());
 
             // Create service - using PCWSTR::from_raw() for safety
             let service = CreateServiceW(
@@ -82,7 +86,7 @@ impl FpsServiceInstaller {
             let service = service.unwrap();
 
             // Set description
-            let description = Self::to_wide(SERVICE_DESCRIPTION);
+            let description = to_wide(SERVICE_DESCRIPTION);
             let service_desc = SERVICE_DESCRIPTIONW {
                 lpDescription: windows::core::PWSTR::from_raw(description.as_ptr().cast_mut()),
             };
@@ -127,7 +131,7 @@ impl FpsServiceInstaller {
             }
 
             // Open service
-            let service_name = Self::to_wide(SERVICE_NAME);
+            let service_name = to_wide(SERVICE_NAME);
             let service = OpenServiceW(
                 scm,
                 PCWSTR::from_raw(service_name.as_ptr()),
@@ -176,7 +180,7 @@ impl FpsServiceInstaller {
             let scm =
                 OpenSCManagerW(None, None, SC_MANAGER_CONNECT).map_err(|e| format!("Failed to open SCM: {}", e))?;
 
-            let service_name = Self::to_wide(SERVICE_NAME);
+            let service_name = to_wide(SERVICE_NAME);
             let service = OpenServiceW(
                 scm,
                 PCWSTR::from_raw(service_name.as_ptr()),
@@ -211,7 +215,7 @@ impl FpsServiceInstaller {
             let scm =
                 OpenSCManagerW(None, None, SC_MANAGER_CONNECT).map_err(|e| format!("Failed to open SCM: {}", e))?;
 
-            let service_name = Self::to_wide(SERVICE_NAME);
+            let service_name = to_wide(SERVICE_NAME);
             let service = OpenServiceW(
                 scm,
                 PCWSTR::from_raw(service_name.as_ptr()),
@@ -231,6 +235,44 @@ impl FpsServiceInstaller {
         Ok(())
     }
 
+    /// Asks the running service to recreate its ETW trace session (after resume from sleep).
+    ///
+    /// User-defined controls are granted to interactive users by the default
+    /// service DACL, so this works without elevation.
+    ///
+    /// # Errors
+    /// Returns error if the service is not running or rejects the control.
+    pub fn restart_etw() -> Result<(), String> {
+        unsafe {
+            let scm =
+                OpenSCManagerW(None, None, SC_MANAGER_CONNECT).map_err(|e| format!("Failed to open SCM: {}", e))?;
+
+            let service_name = to_wide(SERVICE_NAME);
+            let service = match OpenServiceW(
+                scm,
+                PCWSTR::from_raw(service_name.as_ptr()),
+                SERVICE_USER_DEFINED_CONTROL,
+            ) {
+                Ok(service) => service,
+                Err(e) => {
+                    let _ = CloseServiceHandle(scm);
+                    return Err(format!("Failed to open service: {}", e));
+                },
+            };
+
+            let mut status = SERVICE_STATUS::default();
+            let result = ControlService(service, FPS_SERVICE_CONTROL_RESTART_ETW, &mut status)
+                .map_err(|e| format!("Failed to send ETW restart: {}", e));
+
+            let _ = CloseServiceHandle(service);
+            let _ = CloseServiceHandle(scm);
+            result?;
+        }
+
+        info!("🔁 FPS service asked to restart its ETW session");
+        Ok(())
+    }
+
     /// Check if service is installed
     #[must_use]
     pub fn is_installed() -> bool {
@@ -243,7 +285,7 @@ impl FpsServiceInstaller {
                 return false;
             }
 
-            let service_name = Self::to_wide(SERVICE_NAME);
+            let service_name = to_wide(SERVICE_NAME);
             let service = OpenServiceW(scm, PCWSTR::from_raw(service_name.as_ptr()), SERVICE_QUERY_STATUS);
 
             let installed = service.is_ok();
@@ -269,7 +311,7 @@ impl FpsServiceInstaller {
                 return false;
             }
 
-            let service_name = Self::to_wide(SERVICE_NAME);
+            let service_name = to_wide(SERVICE_NAME);
             let Ok(service) = OpenServiceW(scm, PCWSTR::from_raw(service_name.as_ptr()), SERVICE_QUERY_STATUS) else {
                 let _ = CloseServiceHandle(scm);
                 return false;
@@ -284,11 +326,6 @@ impl FpsServiceInstaller {
             running
         }
     }
-
-    /// Convert string to wide (UTF-16) null-terminated
-    fn to_wide(s: &str) -> Vec<u16> {
-        s.encode_utf16().chain(std::iter::once(0)).collect()
-    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,95 @@
+use crate::domain::integrity::FileDigest;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Read buffer; progress is reported per chunk so large archives still advance.
+const CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Hashes every file of an install folder (SHA-256).
+pub struct FileHasher;
+
+impl FileHasher {
+    /// Hashes all files under `root`, keyed by `/`-separated relative path.
+    ///
+    /// `on_progress` receives the total bytes hashed so far.
+    ///
+    /// # Errors
+    /// Returns error if the folder cannot be listed. Unreadable files (bad
+    /// sectors on a corrupted card) are left out and show up as missing.
+    pub fn hash_tree(root: &Path, on_progress: &mut dyn FnMut(u64)) -> Result<BTreeMap<String, FileDigest>, String> {
+        if !root.is_dir() {
+            return Err(format!("Install folder not found: {}", root.display()));
+        }
+
+        let mut digests = BTreeMap::new();
+        let mut hashed = 0u64;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+
+        for entry in WalkDir::new(root).into_iter().filter_map(Result::ok) {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let Ok(relative) = entry.path().strip_prefix(root) else {
+                continue;
+            };
+            let key = relative.to_string_lossy().replace('\\', "/");
+
+            if let Ok(digest) = Self::hash_file(entry.path(), &mut buffer, |chunk| {
+                hashed += chunk;
+                on_progress(hashed);
+            }) {
+                digests.insert(key, digest);
+            }
+        }
+        Ok(digests)
+    }
+
+    fn hash_file(path: &Path, buffer: &mut [u8], mut on_chunk: impl FnMut(u64)) -> std::io::Result<FileDigest> {
+        let mut file = File::open(path)?;
+        let mut hasher = Sha256::new();
+        let mut size = 0u64;
+        loop {
+            let read = file.read(buffer)?;
+            if read == 0 {
+                break;
+            }
+            hasher.update(&buffer[..read]);
+            size += read as u64;
+            on_chunk(read as u64);
+        }
+        Ok(FileDigest {
+            size,
+            sha256: format!("{:x}", hasher.finalize()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_hash_tree_uses_relative_keys() {
+        let root = std::env::temp_dir().join(format!("balam_hash_{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(root.join("data")).unwrap();
+        fs::write(root.join("data").join("a.txt"), b"abc").unwrap();
+
+        let mut progress = 0;
+        let digests = FileHasher::hash_tree(&root, &mut |bytes| progress = bytes).unwrap();
+
+        let digest = &digests["data/a.txt"];
+        assert_eq!(digest.size, 3);
+        assert_eq!(
+            digest.sha256,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(progress, 3);
+
+        let _ = fs::remove_dir_all(root);
+    }
+}
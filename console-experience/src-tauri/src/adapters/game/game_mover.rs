@@ -0,0 +1,290 @@
+use crate::domain::game_move::MovePhase;
+use crate::infrastructure::app_dirs::known_folder;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use walkdir::WalkDir;
+use windows::core::HSTRING;
+use windows::Win32::Storage::FileSystem::GetDiskFreeSpaceExW;
+use windows::Win32::UI::Shell::{
+    FOLDERID_Desktop, FOLDERID_Documents, FOLDERID_Downloads, FOLDERID_LocalAppData, FOLDERID_Music, FOLDERID_Pictures,
+    FOLDERID_Profile, FOLDERID_ProgramData, FOLDERID_ProgramFiles, FOLDERID_ProgramFilesX86, FOLDERID_Public,
+    FOLDERID_RoamingAppData, FOLDERID_UserProfiles, FOLDERID_Videos, FOLDERID_Windows,
+};
+
+/// Copy buffer; progress is reported after every chunk so multi-GB pak files still move the bar.
+const CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Size of a directory tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    pub files: u64,
+    pub bytes: u64,
+}
+
+/// Moves game installs between drives.
+///
+/// The copy is verified (file count and total size) before anything else
+/// changes; a failed copy removes the partial destination and leaves the
+/// source untouched. Deleting the source is a separate step so callers can
+/// update the library and store manifests first.
+pub struct GameMover;
+
+impl GameMover {
+    /// Counts files and bytes under `dir`.
+    ///
+    /// # Errors
+    /// Returns error if a file or directory cannot be read.
+    pub fn measure(dir: &Path) -> Result<TreeStats, String> {
+        let mut stats = TreeStats::default();
+        for entry in WalkDir::new(dir) {
+            let entry = entry.map_err(|e| format!("Failed to read {}: {e}", dir.display()))?;
+            if entry.file_type().is_file() {
+                let metadata = entry
+                    .metadata()
+                    .map_err(|e| format!("Failed to read {}: {e}", entry.path().display()))?;
+                stats.files += 1;
+                stats.bytes += metadata.len();
+            }
+        }
+        Ok(stats)
+    }
+
+    /// Free bytes available to the current user on the volume containing `path`.
+    ///
+    /// # Errors
+    /// Returns error if the volume cannot be queried (missing drive, ejected card).
+    pub fn free_space(path: &Path) -> Result<u64, String> {
+        let mut available = 0u64;
+        unsafe {
+            GetDiskFreeSpaceExW(
+                &HSTRING::from(path.as_os_str()),
+                Some(&mut available as *mut u64),
+                None,
+                None,
+            )
+            .map_err(|e| format!("Failed to query free space on {}: {e}", path.display()))?;
+        }
+        Ok(available)
+    }
+
+    /// Copies `source` to `destination` and verifies the copy.
+    ///
+    /// `on_progress` receives the phase and copied/total bytes.
+    ///
+    /// # Errors
+    /// Returns error if the destination already exists, there is not enough
+    /// free space, or copying/verification fails (the partial copy is removed).
+    pub fn copy_verified(
+        source: &Path,
+        destination: &Path,
+        on_progress: &mut dyn FnMut(MovePhase, u64, u64),
+    ) -> Result<TreeStats, String> {
+        on_progress(MovePhase::Preparing, 0, 0);
+        if destination.exists() {
+            return Err(format!("Destination already exists: {}", destination.display()));
+        }
+
+        let stats = Self::measure(source)?;
+        let volume = destination.ancestors().last().unwrap_or(destination);
+        let available = Self::free_space(volume)?;
+        if available < stats.bytes {
+            return Err(format!(
+                "Not enough space on {}: {} MB needed, {} MB free",
+                volume.display(),
+                stats.bytes / 1_048_576,
+                available / 1_048_576
+            ));
+        }
+
+        info!(
+            "📦 Copying {} files ({} MB) to {}",
+            stats.files,
+            stats.bytes / 1_048_576,
+            destination.display()
+        );
+        let result = Self::copy_tree(source, destination, stats.bytes, on_progress).and_then(|()| {
+            on_progress(MovePhase::Verifying, stats.bytes, stats.bytes);
+            let copied = Self::measure(destination)?;
+            if copied == stats {
+                Ok(())
+            } else {
+                Err(format!(
+                    "Verification failed: copied {} files / {} bytes, expected {} / {}",
+                    copied.files, copied.bytes, stats.files, stats.bytes
+                ))
+            }
+        });
+
+        if let Err(e) = result {
+            warn!("Game copy failed, removing partial copy: {}", e);
+            let _ = fs::remove_dir_all(destination);
+            return Err(e);
+        }
+        Ok(stats)
+    }
+
+    /// Deletes the original install after the move was recorded.
+    ///
+    /// Only for folders a store owns (Epic install locations); manual games
+    /// use [`Self::remove_copied`].
+    ///
+    /// # Errors
+    /// Returns error if files are locked (the copy is kept either way).
+    pub fn remove_source(source: &Path) -> Result<(), String> {
+        fs::remove_dir_all(source).map_err(|e| format!("Failed to delete {}: {e}", source.display()))
+    }
+
+    /// Deletes from `source` only the files that exist in the copy at
+    /// `destination`, then the folders left empty. Anything else stays.
+    ///
+    /// # Errors
+    /// Returns error if a copied file cannot be deleted (the copy is kept either way).
+    pub fn remove_copied(source: &Path, destination: &Path) -> Result<(), String> {
+        for entry in WalkDir::new(destination).contents_first(true) {
+            let entry = entry.map_err(|e| format!("Failed to read {}: {e}", destination.display()))?;
+            let relative = entry.path().strip_prefix(destination).map_err(|e| e.to_string())?;
+            let original = source.join(relative);
+            if entry.file_type().is_file() {
+                fs::remove_file(&original).map_err(|e| format!("Failed to delete {}: {e}", original.display()))?;
+            } else if entry.file_type().is_dir() {
+                // Fails while the folder still holds files that were not moved
+                let _ = fs::remove_dir(&original);
+            }
+        }
+        Ok(())
+    }
+
+    /// User, shell and system folders that are never a game's own folder.
+    #[must_use]
+    pub fn protected_folders() -> Vec<PathBuf> {
+        [
+            FOLDERID_Profile,
+            FOLDERID_UserProfiles,
+            FOLDERID_Public,
+            FOLDERID_Desktop,
+            FOLDERID_Documents,
+            FOLDERID_Downloads,
+            FOLDERID_Music,
+            FOLDERID_Pictures,
+            FOLDERID_Videos,
+            FOLDERID_LocalAppData,
+            FOLDERID_RoamingAppData,
+            FOLDERID_ProgramData,
+            FOLDERID_ProgramFiles,
+            FOLDERID_ProgramFilesX86,
+            FOLDERID_Windows,
+        ]
+        .iter()
+        .filter_map(known_folder)
+        .collect()
+    }
+
+    /// Executables directly in `dir` other than `game_exe`.
+    #[must_use]
+    pub fn other_programs(dir: &Path, game_exe: &Path) -> Vec<String> {
+        fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path != game_exe
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("exe") || ext.eq_ignore_ascii_case("msi"))
+            })
+            .filter_map(|path| path.file_name().map(|name| name.to_string_lossy().to_string()))
+            .collect()
+    }
+
+    fn copy_tree(
+        source: &Path,
+        destination: &Path,
+        total_bytes: u64,
+        on_progress: &mut dyn FnMut(MovePhase, u64, u64),
+    ) -> Result<(), String> {
+        let mut copied = 0u64;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+
+        for entry in WalkDir::new(source) {
+            let entry = entry.map_err(|e| format!("Failed to read {}: {e}", source.display()))?;
+            let relative = entry.path().strip_prefix(source).map_err(|e| e.to_string())?;
+            let target = destination.join(relative);
+
+            if entry.file_type().is_dir() {
+                fs::create_dir_all(&target).map_err(|e| format!("Failed to create {}: {e}", target.display()))?;
+                continue;
+            }
+
+            Self::copy_file(entry.path(), &target, &mut buffer, |chunk| {
+                copied += chunk;
+                on_progress(MovePhase::Copying, copied, total_bytes);
+            })
+            .map_err(|e| format!("Failed to copy {}: {e}", entry.path().display()))?;
+        }
+        Ok(())
+    }
+
+    fn copy_file(source: &Path, target: &Path, buffer: &mut [u8], mut on_chunk: impl FnMut(u64)) -> io::Result<()> {
+        let mut reader = File::open(source)?;
+        let mut writer = File::create(target)?;
+        loop {
+            let read = reader.read(buffer)?;
+            if read == 0 {
+                break;
+            }
+            writer.write_all(&buffer[..read])?;
+            on_chunk(read as u64);
+        }
+        writer.sync_all()?;
+
+        // Keep timestamps: some launchers compare them to detect modified installs
+        if let Ok(modified) = fs::metadata(source).and_then(|m| m.modified()) {
+            let _ = writer.set_modified(modified);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copy_verified_copies_tree() {
+        let root = std::env::temp_dir().join(format!("balam_move_{}", uuid::Uuid::new_v4()));
+        let source = root.join("source");
+        fs::create_dir_all(source.join("data")).unwrap();
+        fs::write(source.join("game.exe"), b"binary").unwrap();
+        fs::write(source.join("data").join("level.pak"), vec![7u8; 4096]).unwrap();
+
+        let destination = root.join("moved");
+        let mut last_phase = None;
+        let stats = GameMover::copy_verified(&source, &destination, &mut |phase, _, _| {
+            last_phase = Some(phase);
+        })
+        .unwrap();
+
+        assert_eq!(stats, TreeStats { files: 2, bytes: 4102 });
+        assert_eq!(last_phase, Some(MovePhase::Verifying));
+        assert_eq!(
+            fs::read(destination.join("data").join("level.pak")).unwrap().len(),
+            4096
+        );
+
+        // A second move onto the same destination is refused
+        assert!(GameMover::copy_verified(&source, &destination, &mut |_, _, _| {}).is_err());
+
+        // Files added after the copy are not the game's: they stay
+        fs::write(source.join("notes.txt"), b"mine").unwrap();
+        GameMover::remove_copied(&source, &destination).unwrap();
+        assert!(!source.join("game.exe").exists());
+        assert!(!source.join("data").exists());
+        assert!(source.join("notes.txt").exists());
+
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod file_hasher;
+pub mod game_mover;
 pub mod windows_game_adapter;
 
+pub use file_hasher::FileHasher;
+pub use game_mover::GameMover;
 pub use windows_game_adapter::WindowsGameAdapter;
@@ -3,11 +3,22 @@ use crate::ports::game_management_port::GameManagementPort;
 use std::thread;
 use std::time::Duration;
 use tracing::{info, warn};
-use windows::Win32::Foundation::{CloseHandle, BOOL, HWND, LPARAM, WAIT_OBJECT_0, WAIT_TIMEOUT};
+use windows::core::{s, w, PCSTR};
+use windows::Win32::Foundation::{
+    CloseHandle, BOOL, HANDLE, HWND, LPARAM, NTSTATUS, RECT, WAIT_OBJECT_0, WAIT_TIMEOUT,
+};
+use windows::Win32::System::LibraryLoader::{GetModuleHandleW, GetProcAddress};
 use windows::Win32::System::Threading::{
-    OpenProcess, TerminateProcess, WaitForSingleObject, PROCESS_QUERY_INFORMATION, PROCESS_TERMINATE,
+    OpenProcess, TerminateProcess, WaitForSingleObject, PROCESS_QUERY_INFORMATION, PROCESS_SUSPEND_RESUME,
+    PROCESS_TERMINATE,
+};
+use windows::Win32::UI::WindowsAndMessaging::{
+    EnumWindows, GetForegroundWindow, GetWindow, GetWindowRect, GetWindowThreadProcessId, IsIconic, IsWindowVisible,
+    SendMessageW, SetForegroundWindow, ShowWindow, GW_OWNER, SW_RESTORE, WM_CLOSE,
 };
-use windows::Win32::UI::WindowsAndMessaging::{EnumWindows, GetWindowThreadProcessId, SendMessageW, WM_CLOSE};
+
+/// Signature shared by `NtSuspendProcess` and `NtResumeProcess`.
+type NtProcessControlFn = unsafe extern "system" fn(HANDLE) -> NTSTATUS;
 
 /// Windows implementation of game management using Win32 APIs.
 ///
@@ -78,6 +89,100 @@ impl WindowsGameAdapter {
             _ => Err("WaitForSingleObject failed".to_string()),
         }
     }
+
+    /// PID owning the foreground window (`None` if no window has focus).
+    #[must_use]
+    pub fn foreground_pid(&self) -> Option<u32> {
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if hwnd.0 == 0 {
+                return None;
+            }
+            let mut pid = 0u32;
+            let _ = GetWindowThreadProcessId(hwnd, Some(&raw mut pid));
+            (pid != 0).then_some(pid)
+        }
+    }
+
+    /// Main window of a process: the largest visible, unowned top-level
+    /// window (launchers and splash screens are usually smaller).
+    #[must_use]
+    pub fn main_window(&self, pid: u32) -> Option<HWND> {
+        unsafe extern "system" fn enum_proc(hwnd: HWND, lparam: LPARAM) -> BOOL {
+            let (target_pid, best_ptr) = *(lparam.0 as *const (u32, *mut (HWND, i64)));
+            let mut window_pid = 0u32;
+            let _ = GetWindowThreadProcessId(hwnd, Some(&raw mut window_pid));
+
+            if window_pid == target_pid && IsWindowVisible(hwnd).as_bool() && GetWindow(hwnd, GW_OWNER).0 == 0 {
+                let mut rect = RECT::default();
+                if GetWindowRect(hwnd, &raw mut rect).is_ok() {
+                    let area = i64::from(rect.right - rect.left) * i64::from(rect.bottom - rect.top);
+                    unsafe {
+                        if area >= (*best_ptr).1 {
+                            *best_ptr = (hwnd, area);
+                        }
+                    }
+                }
+            }
+
+            BOOL(1) // Continue enumeration
+        }
+
+        unsafe {
+            let mut best = (HWND(0), -1i64);
+            let best_ptr = &raw mut best;
+            let lparam = LPARAM(std::ptr::from_ref(&(pid, best_ptr)) as isize);
+            if let Err(e) = EnumWindows(Some(enum_proc), lparam) {
+                warn!("EnumWindows failed: {}", e);
+                return None;
+            }
+            let hwnd = best.0;
+            (hwnd.0 != 0).then_some(hwnd)
+        }
+    }
+
+    /// Brings the main window of a process to the foreground.
+    ///
+    /// # Errors
+    /// Returns error if the process has no visible window.
+    pub fn focus_process_window(&self, pid: u32) -> Result<(), String> {
+        let hwnd = self
+            .main_window(pid)
+            .ok_or_else(|| format!("No visible window found for PID {pid}"))?;
+
+        unsafe {
+            if IsIconic(hwnd).as_bool() {
+                let _ = ShowWindow(hwnd, SW_RESTORE);
+            }
+            if !SetForegroundWindow(hwnd).as_bool() {
+                return Err(format!("Windows refused to focus PID {pid}"));
+            }
+        }
+
+        info!("🎯 Focused window of PID {}", pid);
+        Ok(())
+    }
+
+    /// Calls an undocumented-but-stable ntdll process control routine
+    /// (`NtSuspendProcess` / `NtResumeProcess`), resolved at runtime because
+    /// they are not part of the Win32 import libraries.
+    unsafe fn call_ntdll_process_control(name: PCSTR, pid: u32) -> Result<(), String> {
+        let ntdll = GetModuleHandleW(w!("ntdll.dll")).map_err(|e| format!("Failed to get ntdll handle: {e}"))?;
+        let routine = GetProcAddress(ntdll, name).ok_or("Process control routine not available")?;
+
+        // SAFETY: both routines take a process handle and return an NTSTATUS
+        let routine: NtProcessControlFn = std::mem::transmute(routine);
+
+        let handle = OpenProcess(PROCESS_SUSPEND_RESUME, false, pid)
+            .map_err(|e| format!("Failed to open process {pid}: {e}"))?;
+        let status = routine(handle);
+        let _ = CloseHandle(handle);
+
+        if status.is_err() {
+            return Err(format!("NTSTATUS 0x{:08X}", status.0));
+        }
+        Ok(())
+    }
 }
 
 impl Default for WindowsGameAdapter {
@@ -153,6 +258,29 @@ impl GameManagementPort for WindowsGameAdapter {
         // For MVP, always return true
         Ok(true)
     }
+
+    fn suspend_game(&self, pid: u32) -> Result<(), String> {
+        unsafe { Self::call_ntdll_process_control(s!("NtSuspendProcess"), pid) }
+            .map_err(|e| format!("Failed to suspend PID {pid}: {e}"))?;
+        info!("⏸️ Game PID {} suspended", pid);
+        Ok(())
+    }
+
+    fn resume_game(&self, pid: u32) -> Result<(), String> {
+        unsafe { Self::call_ntdll_process_control(s!("NtResumeProcess"), pid) }
+            .map_err(|e| format!("Failed to resume PID {pid}: {e}"))?;
+        info!("▶️ Game PID {} resumed", pid);
+        Ok(())
+    }
+
+    fn foreground_pid(&self) -> Option<u32> {
+        // Inherent method (also used by the window manager)
+        Self::foreground_pid(self)
+    }
+
+    fn focus_window(&self, pid: u32) -> Result<(), String> {
+        self.focus_process_window(pid)
+    }
 }
 
 #[cfg(test)]
@@ -174,4 +302,11 @@ mod tests {
                                                   // This is acceptable behavior - not a critical error
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_suspend_nonexistent_process() {
+        let adapter = WindowsGameAdapter::new();
+        assert!(adapter.suspend_game(999_999).is_err());
+        assert!(adapter.resume_game(999_999).is_err());
+    }
 }
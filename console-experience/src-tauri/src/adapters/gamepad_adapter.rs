@@ -1,15 +1,26 @@
 use gilrs::{Button, Gilrs};
+use parking_lot::{Condvar, Mutex};
 use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, Runtime};
-use tracing::info;
+use tracing::{info, warn};
+use windows::Foundation::EventHandler;
+use windows::Gaming::Input::{Gamepad, RawGameController};
 use windows::Win32::UI::Input::XboxController::{
     XInputGetState, XINPUT_GAMEPAD_A, XINPUT_GAMEPAD_B, XINPUT_GAMEPAD_DPAD_DOWN, XINPUT_GAMEPAD_DPAD_LEFT,
-    XINPUT_GAMEPAD_DPAD_RIGHT, XINPUT_GAMEPAD_DPAD_UP, XINPUT_GAMEPAD_LEFT_SHOULDER, XINPUT_GAMEPAD_RIGHT_SHOULDER,
-    XINPUT_GAMEPAD_START,
+    XINPUT_GAMEPAD_DPAD_RIGHT, XINPUT_GAMEPAD_DPAD_UP, XINPUT_GAMEPAD_START,
 };
 
+use crate::application::services::overlay_renderer_monitor::PING_SCRIPT;
+use crate::application::DIContainer;
+use crate::domain::browser::BrowserAction;
+use crate::domain::gamepad_polling::{poll_interval, DEFAULT_POLL_INTERVAL_MS};
+use crate::domain::overlay_layout::OverlayLevel;
+use crate::domain::{HotkeyAction, Notification, NotificationCategory, NotificationPriority};
+use crate::ports::NotificationPort;
+
 #[derive(Serialize, Clone, Copy, PartialEq)]
 pub enum ControllerType {
     Xbox,
@@ -19,6 +30,65 @@ pub enum ControllerType {
     Keyboard,
 }
 
+/// Set on resume from sleep: controllers re-enumerate and gilrs keeps stale handles.
+static RESET_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Makes the listener re-open its gamepad backends on the next poll.
+pub fn reset_after_resume() {
+    RESET_REQUESTED.store(true, Ordering::SeqCst);
+    WAKER.wake();
+}
+
+/// `controller.poll_interval_ms`, kept in sync with the settings.
+static POLL_INTERVAL_MS: AtomicU64 = AtomicU64::new(DEFAULT_POLL_INTERVAL_MS);
+
+/// Lets a controller arrival cut the idle sleep of the polling loop short.
+struct PollWaker {
+    woken: Mutex<bool>,
+    wake: Condvar,
+}
+
+impl PollWaker {
+    const fn new() -> Self {
+        Self {
+            woken: Mutex::new(false),
+            wake: Condvar::new(),
+        }
+    }
+
+    fn wake(&self) {
+        *self.woken.lock() = true;
+        self.wake.notify_one();
+    }
+
+    /// Sleeps for `timeout` or until woken.
+    fn sleep(&self, timeout: Duration) {
+        let mut woken = self.woken.lock();
+        if !*woken {
+            let _ = self.wake.wait_for(&mut woken, timeout);
+        }
+        *woken = false;
+    }
+}
+
+static WAKER: PollWaker = PollWaker::new();
+
+/// Wakes the loop when a controller is plugged in (Windows.Gaming.Input
+/// reports XInput pads as `Gamepad`, the rest as `RawGameController`).
+fn watch_controller_arrivals() {
+    let gamepads = Gamepad::GamepadAdded(&EventHandler::new(|_, _| {
+        WAKER.wake();
+        Ok(())
+    }));
+    let controllers = RawGameController::RawGameControllerAdded(&EventHandler::new(|_, _| {
+        WAKER.wake();
+        Ok(())
+    }));
+    if let Err(e) = gamepads.and(controllers) {
+        warn!("🎮 Controller arrival notifications unavailable, polling only: {}", e);
+    }
+}
+
 struct ButtonState {
     pressed: bool,
 }
@@ -52,11 +122,24 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
         let mut btn_left = ButtonState::new();
         let mut btn_right = ButtonState::new();
         let mut btn_menu = ButtonState::new();
-        let mut btn_toggle_overlay = ButtonState::new();
+        let mut btn_chord = ButtonState::new();
+
+        // Gamepad chords come from the same HotkeyManager as keyboard shortcuts (off in safe mode)
+        let hotkeys = app
+            .try_state::<DIContainer>()
+            .filter(|_| !crate::application::safe_mode::is_active())
+            .map(|c| c.hotkey_manager.clone());
+        let notifications = app.try_state::<DIContainer>().map(|c| c.notifications.clone());
+        // Any gamepad input keeps the device awake (idle auto-suspend)
+        let idle = app.try_state::<DIContainer>().map(|c| c.idle.clone());
+        // Paused while a Steam Input game is in front (it already gets the pad)
+        let focus = app.try_state::<DIContainer>().map(|c| c.focus.clone());
+        // Pings the overlay WebView; navigation goes Rust-native while it is suspended
+        let renderer = app.try_state::<DIContainer>().map(|c| c.overlay_renderer.clone());
+        let mut last_packet: u32 = 0;
 
         let mut current_controller = ControllerType::Keyboard;
         let mut gilrs = Gilrs::new().ok();
-        let mut keepalive_counter: u32 = 0;
 
         // Overlay navigation state — tracked entirely in Rust so critical actions
         // (Resume, Back) work even if the WebView renderer is throttled/suspended.
@@ -64,7 +147,29 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
         let mut overlay_confirm_pending = false; // Close Game confirm dialog is open
         let mut overlay_was_visible = false;
 
+        // Slower polling while idle: the rate follows the settings and drops
+        // with no controller attached or no Balam window on screen
+        if let Some(container) = app.try_state::<DIContainer>() {
+            POLL_INTERVAL_MS.store(
+                container.settings_service.get().controller.poll_interval_ms,
+                Ordering::Relaxed,
+            );
+            container.settings_service.subscribe(Box::new(|settings| {
+                POLL_INTERVAL_MS.store(settings.controller.poll_interval_ms, Ordering::Relaxed);
+                WAKER.wake();
+            }));
+        }
+        watch_controller_arrivals();
+
         loop {
+            if RESET_REQUESTED.swap(false, Ordering::SeqCst) {
+                info!("🎮 Re-opening gamepads after resume");
+                gilrs = Gilrs::new().ok();
+                last_packet = 0;
+                // Forces a fresh controller-type-changed for the frontend
+                current_controller = ControllerType::Keyboard;
+            }
+
             let mut pressed_a = false;
             let mut pressed_b = false;
             let mut pressed_up = false;
@@ -80,6 +185,13 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
             let mut xinput_state = unsafe { std::mem::zeroed() };
             if unsafe { XInputGetState(0, &raw mut xinput_state) } == 0 {
                 detected_type = ControllerType::Xbox;
+                // The packet number changes on every button, stick or trigger change
+                if xinput_state.dwPacketNumber != last_packet {
+                    last_packet = xinput_state.dwPacketNumber;
+                    if let Some(idle) = &idle {
+                        idle.record_activity();
+                    }
+                }
                 let b = xinput_state.Gamepad.wButtons.0;
                 let s = &xinput_state.Gamepad;
 
@@ -118,22 +230,65 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
                     pressed_left = true;
                 }
 
-                let lb = (b & XINPUT_GAMEPAD_LEFT_SHOULDER.0) != 0;
-                let rb = (b & XINPUT_GAMEPAD_RIGHT_SHOULDER.0) != 0;
-
-                // LB+RB+Start: Toggle game overlay (native overlay system)
-                // Uses ButtonState to only fire ONCE on press (not every 8ms poll cycle)
-                let is_toggle_combo = lb && rb && pressed_menu;
-                if btn_toggle_overlay.update(is_toggle_combo) {
-                    if let Some(win) = app.get_webview_window("main") {
-                        let _ = win.emit("nav", "TOGGLE_OVERLAY");
+                // Configurable chords (default LB+RB+Start: toggle game overlay)
+                // Uses ButtonState to only fire ONCE on press (not every poll cycle)
+                let chord = hotkeys.as_ref().and_then(|m| m.chord_action(b));
+                if btn_chord.update(chord.is_some()) {
+                    if let Some((action, _)) = chord {
+                        match action {
+                            HotkeyAction::ToggleOverlay => {
+                                if let Some(win) = app.get_webview_window("main") {
+                                    let _ = win.emit("nav", "TOGGLE_OVERLAY");
+                                }
+                            },
+                            HotkeyAction::ToggleMicMute => {
+                                if let Err(e) = crate::application::commands::toggle_microphone_mute(&app) {
+                                    warn!("Failed to toggle microphone mute: {}", e);
+                                }
+                            },
+                            HotkeyAction::TakeScreenshot => {
+                                crate::application::commands::take_screenshot_in_background(&app);
+                            },
+                            HotkeyAction::SaveReplay => {
+                                crate::application::commands::save_replay_in_background(&app);
+                            },
+                            HotkeyAction::CycleOverlayLevel => {
+                                if let Err(e) =
+                                    crate::application::commands::apply_overlay_level(&app, OverlayLevel::next)
+                                {
+                                    warn!("Failed to cycle overlay level: {}", e);
+                                }
+                            },
+                            other => {
+                                if let Some(event) = other.frontend_event() {
+                                    let _ = app.emit(event, true);
+                                }
+                            },
+                        }
                     }
                 }
-                if is_toggle_combo {
-                    pressed_menu = false; // Consume to prevent MENU event firing simultaneously
+                if let Some((_, mask)) = chord {
+                    // Consume chord buttons to prevent their nav events firing simultaneously
+                    if (mask & XINPUT_GAMEPAD_START.0) != 0 {
+                        pressed_menu = false;
+                    }
+                    if (mask & XINPUT_GAMEPAD_A.0) != 0 {
+                        pressed_a = false;
+                    }
+                    if (mask & XINPUT_GAMEPAD_B.0) != 0 {
+                        pressed_b = false;
+                    }
                 }
             } else if let Some(ref mut g) = gilrs {
-                while g.next_event().is_some() {}
+                let mut had_events = false;
+                while g.next_event().is_some() {
+                    had_events = true;
+                }
+                if had_events {
+                    if let Some(idle) = &idle {
+                        idle.record_activity();
+                    }
+                }
                 if let Some((_, gamepad)) = g.gamepads().next() {
                     let name = gamepad.name().to_lowercase();
                     detected_type = if name.contains("playstation") || name.contains("dual") {
@@ -168,6 +323,17 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
             // Emit Type if changed OR on every button press (to ensure frontend sync)
             if detected_type != current_controller || (any_button_pressed && detected_type != ControllerType::Keyboard)
             {
+                // Gamepad → keyboard means the last controller was unplugged
+                if detected_type == ControllerType::Keyboard {
+                    if let Some(notifications) = &notifications {
+                        notifications.notify(Notification::new(
+                            NotificationCategory::Controller,
+                            NotificationPriority::High,
+                            "Control desconectado",
+                            "Vuelve a conectar el control para seguir navegando",
+                        ));
+                    }
+                }
                 current_controller = detected_type;
                 let type_str = match current_controller {
                     ControllerType::Xbox => "XBOX",
@@ -195,8 +361,32 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
                     let _ = ov.emit("overlay-focus-changed", 0i32);
                 }
             }
+            if overlay_is_visible != overlay_was_visible {
+                crate::application::commands::sync_companion_pip_with_overlay(&app, overlay_is_visible);
+            }
             overlay_was_visible = overlay_is_visible;
 
+            // Renderer health: a suspended WebView would swallow every JS-routed action
+            let native_nav = if let Some(renderer) = &renderer {
+                let check = renderer.check(overlay_is_visible, Instant::now());
+                if check.ping {
+                    if let Some(ref ov) = overlay_win_opt {
+                        let _ = ov.eval(PING_SCRIPT);
+                    }
+                }
+                if let Some(suspended) = check.changed {
+                    if suspended {
+                        warn!("🎮 Overlay renderer not responding, switching to native navigation");
+                    } else {
+                        info!("🎮 Overlay renderer responding again");
+                    }
+                    let _ = app.emit("overlay-renderer-health", !suspended);
+                }
+                renderer.native_nav()
+            } else {
+                false
+            };
+
             if overlay_is_visible {
                 // ─── OVERLAY: Rust-Native Navigation ─────────────────────────────
                 // Critical actions (Resume, Back) are executed directly from Rust,
@@ -234,7 +424,13 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
 
                     // A (CONFIRM)
                     if btn_a.update(pressed_a) {
-                        if overlay_confirm_pending {
+                        if overlay_confirm_pending && native_nav {
+                            // Second A on Close Game while JS is suspended: the
+                            // confirm dialog cannot be shown, the press confirms
+                            overlay_confirm_pending = false;
+                            let _ = ov.hide();
+                            close_focused_game_in_background(&app);
+                        } else if overlay_confirm_pending {
                             // Confirm dialog is open: forward CONFIRM to JS so the
                             // focused button (Cancel or Close Game) gets .click()ed
                             let _ = ov.emit("nav", "CONFIRM");
@@ -250,7 +446,8 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
                                     let _ = ov.emit("overlay-action", "OPEN_QUICK_SETTINGS");
                                 },
                                 2 => {
-                                    // Close Game: open confirm dialog via JS (non-critical)
+                                    // Close Game: open confirm dialog via JS (non-critical).
+                                    // In native mode a second A confirms.
                                     overlay_confirm_pending = true;
                                     let _ = ov.emit("overlay-action", "CLOSE_GAME_REQUEST");
                                 },
@@ -290,9 +487,29 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
                         }
                     }
                 }
+            } else if crate::application::commands::store_browser_focused(&app) {
+                // ─── STORE BROWSER: emulated focus and scrolling ────────────────
+                // Web pages know nothing about the controller: D-pad up/down
+                // moves focus like Tab, left/right scrolls a page, A clicks,
+                // B goes back and Menu closes the browser.
+                let presses = [
+                    (btn_up.update(pressed_up), BrowserAction::FocusPrevious),
+                    (btn_down.update(pressed_down), BrowserAction::FocusNext),
+                    (btn_left.update(pressed_left), BrowserAction::ScrollUp),
+                    (btn_right.update(pressed_right), BrowserAction::ScrollDown),
+                    (btn_a.update(pressed_a), BrowserAction::Activate),
+                    (btn_b.update(pressed_b), BrowserAction::Back),
+                    (btn_menu.update(pressed_menu), BrowserAction::Close),
+                ];
+                for (_, action) in presses.into_iter().filter(|(pressed, _)| *pressed) {
+                    if let Err(e) = crate::application::commands::run_store_browser_action(&app, action) {
+                        warn!("🎮 Store browser action failed: {}", e);
+                    }
+                }
             } else {
                 // ─── MAIN WINDOW: JS-based Navigation ───────────────────────────
-                if let Some(win) = app.get_webview_window("main") {
+                let handed_off = focus.as_ref().is_some_and(|f| f.navigation_suppressed());
+                if let Some(win) = app.get_webview_window("main").filter(|_| !handed_off) {
                     if win.is_visible().unwrap_or(false) {
                         if btn_a.update(pressed_a) {
                             let _ = win.emit("nav", "CONFIRM");
@@ -319,21 +536,41 @@ pub fn start_gamepad_listener<R: Runtime>(app: AppHandle<R>) {
                 }
             }
 
-            // Keepalive: every 5 seconds, execute a no-op in the overlay WebView.
-            // WebView2 can suspend JS execution when it detects the window is occluded
-            // by a fullscreen game. eval() bypasses that suspension and keeps the
-            // event loop alive so Tauri nav events continue to be processed.
-            keepalive_counter += 1;
-            if keepalive_counter >= 625 {
-                keepalive_counter = 0;
-                if let Some(overlay) = app.get_webview_window("overlay") {
-                    if overlay.is_visible().unwrap_or(false) {
-                        let _ = overlay.eval("void 0");
-                    }
-                }
-            }
+            let window_visible = overlay_is_visible
+                || app
+                    .get_webview_window("main")
+                    .is_some_and(|win| win.is_visible().unwrap_or(false))
+                || crate::application::commands::store_browser_focused(&app);
+            WAKER.sleep(poll_interval(
+                POLL_INTERVAL_MS.load(Ordering::Relaxed),
+                detected_type != ControllerType::Keyboard,
+                window_visible,
+            ));
+        }
+    });
+}
 
-            thread::sleep(Duration::from_millis(8));
+/// Closes the focused game without the overlay's JS confirm dialog (native
+/// navigation). Off the input thread: a graceful close waits for the game.
+fn close_focused_game_in_background<R: Runtime>(app: &AppHandle<R>) {
+    let Some(container) = app.try_state::<DIContainer>() else {
+        return;
+    };
+    let Some(pid) = container
+        .active_games_tracker
+        .capture_target()
+        .and_then(|info| info.pid)
+    else {
+        warn!("🎮 No running game to close");
+        return;
+    };
+    let game_adapter = container.game_adapter.clone();
+    let spawned = thread::Builder::new().name("close-game".to_string()).spawn(move || {
+        if let Err(e) = game_adapter.close_game(pid) {
+            warn!("Failed to close game {}: {}", pid, e);
         }
     });
+    if let Err(e) = spawned {
+        warn!("Failed to start close-game thread: {}", e);
+    }
 }
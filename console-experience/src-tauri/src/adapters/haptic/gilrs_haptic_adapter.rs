@@ -4,161 +4,177 @@
 /// Follows best practices from Gilrs documentation:
 /// - Dual-motor patterns (strong + weak for texture)
 /// - `EffectBuilder` API for robust cross-platform support
-/// - Non-blocking async execution
-use crate::domain::haptic::HapticFeedback;
+/// - Whole patterns uploaded as one effect, timed by the force feedback thread
+use crate::domain::haptic::{HapticPattern, HapticPulse};
 use crate::ports::haptic_port::HapticPort;
 use gilrs::{
-    ff::{BaseEffect, BaseEffectType, EffectBuilder, Replay, Ticks},
+    ff::{BaseEffect, BaseEffectType, Effect, EffectBuilder, Envelope, Repeat, Replay, Ticks},
     Gilrs,
 };
-use std::sync::{Arc, Mutex};
+use std::sync::Mutex;
 use tracing::{debug, error, warn};
 
 /// Gilrs haptic adapter
 ///
 /// Manages gamepad force feedback via Gilrs library.
-/// Maintains Gilrs instance in Arc<Mutex<>> for thread-safe access.
+/// The Gilrs instance is created on first use and kept for the app lifetime,
+/// so the effect of a running pattern stays alive until it ends or is replaced.
 pub struct GilrsHapticAdapter {
-    /// Shared Gilrs instance for gamepad events
-    gilrs: Arc<Mutex<Gilrs>>,
+    /// Shared Gilrs instance (`None` until first use or if initialization failed)
+    gilrs: Mutex<Option<Gilrs>>,
+    /// Effect of the pattern being played; dropping it stops the rumble
+    current: Mutex<Option<Effect>>,
+}
+
+impl Default for GilrsHapticAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl GilrsHapticAdapter {
-    /// Create new Gilrs haptic adapter
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the Gilrs library fails to initialize.
-    pub fn new() -> Result<Self, String> {
-        match Gilrs::new() {
-            Ok(gilrs) => {
-                debug!("Gilrs haptic adapter initialized");
-                Ok(Self {
-                    gilrs: Arc::new(Mutex::new(gilrs)),
-                })
-            },
-            Err(e) => {
+    /// Create new Gilrs haptic adapter (Gilrs itself is initialized lazily)
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            gilrs: Mutex::new(None),
+            current: Mutex::new(None),
+        }
+    }
+
+    /// Runs `f` with the Gilrs instance, initializing it if needed.
+    fn with_gilrs<T>(&self, f: impl FnOnce(&mut Gilrs) -> Result<T, String>) -> Result<T, String> {
+        let mut slot = self.gilrs.lock().map_err(|e| {
+            error!("Failed to lock Gilrs mutex: {}", e);
+            "Mutex lock failed".to_string()
+        })?;
+        if slot.is_none() {
+            let gilrs = Gilrs::new().map_err(|e| {
                 error!("Failed to initialize Gilrs: {}", e);
-                Err(format!("Gilrs initialization failed: {e}"))
-            },
+                format!("Gilrs initialization failed: {e}")
+            })?;
+            debug!("Gilrs haptic adapter initialized");
+            *slot = Some(gilrs);
         }
+        let gilrs = slot.as_mut().ok_or("Gilrs unavailable")?;
+        // Connection changes are only picked up while events are pumped
+        while gilrs.next_event().is_some() {}
+        f(gilrs)
     }
 }
 
-#[async_trait::async_trait]
-impl HapticPort for GilrsHapticAdapter {
-    async fn trigger(&self, feedback: HapticFeedback) -> Result<(), String> {
-        let gilrs = self.gilrs.clone();
-        let magnitude = feedback.intensity.to_magnitude();
-        let duration_ms = feedback.duration_ms;
+/// Converts magnitude (0.0-1.0) to u16 (0-65535)
+fn motor_magnitude(magnitude: f32) -> u16 {
+    (magnitude.clamp(0.0, 1.0) * 65535.0) as u16
+}
 
-        // Run on blocking thread pool to avoid blocking Tauri event loop
-        tokio::task::spawn_blocking(move || {
-            let mut gilrs = match gilrs.lock() {
-                Ok(g) => g,
-                Err(e) => {
-                    error!("Failed to lock Gilrs mutex: {}", e);
-                    return Err("Mutex lock failed".to_string());
-                },
-            };
+/// Both motors of a pulse.
+///
+/// After its `after` offset, a base effect repeats every `play_for +
+/// with_delay` for as long as the effect plays; a delay of the whole pattern
+/// length makes the pulse play exactly once.
+fn pulse_effects(pulse: &HapticPulse, pattern_ms: u32) -> [BaseEffect; 2] {
+    let scheduling = Replay {
+        after: Ticks::from_ms(pulse.start_ms),
+        play_for: Ticks::from_ms(pulse.duration_ms),
+        with_delay: Ticks::from_ms(pattern_ms),
+    };
+    let envelope = Envelope {
+        attack_length: Ticks::from_ms(pulse.attack_ms),
+        attack_level: 0.0,
+        fade_length: Ticks::from_ms(pulse.fade_ms),
+        fade_level: 0.0,
+    };
+    [
+        // Strong motor: Low-frequency heavy rumble
+        BaseEffect {
+            kind: BaseEffectType::Strong {
+                magnitude: motor_magnitude(pulse.strong),
+            },
+            scheduling,
+            envelope,
+        },
+        // Weak motor: High-frequency buzzy detail
+        BaseEffect {
+            kind: BaseEffectType::Weak {
+                magnitude: motor_magnitude(pulse.weak),
+            },
+            scheduling,
+            envelope,
+        },
+    ]
+}
 
+impl HapticPort for GilrsHapticAdapter {
+    fn play(&self, pattern: &HapticPattern) -> Result<(), String> {
+        let pattern_ms = pattern.duration_ms();
+        if pattern_ms == 0 {
+            return Ok(());
+        }
+
+        let effect = self.with_gilrs(|gilrs| {
             // Collect gamepad IDs that support force feedback
             // (Avoids borrow checker issue when calling builder.finish())
             let ff_gamepads: Vec<_> = gilrs
                 .gamepads()
                 .filter(|(_id, gp)| gp.is_ff_supported())
-                .map(|(id, gp)| (id, gp.name().to_string()))
+                .map(|(id, _gp)| id)
                 .collect();
 
             if ff_gamepads.is_empty() {
                 // No gamepads with force feedback available - graceful degradation
                 debug!("No gamepads with force feedback connected");
-                return Ok(()); // Not an error, just no haptic available
+                return Ok(None); // Not an error, just no haptic available
             }
 
-            // Trigger haptic on all collected gamepads
-            let mut triggered_count = 0;
-
-            for (id, name) in ff_gamepads {
-                // Convert magnitude (0.0-1.0) to u16 (0-65535)
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let strong_magnitude = (magnitude * 65535.0) as u16;
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let weak_magnitude = (magnitude * 0.7 * 65535.0) as u16; // 70% for texture
-
-                // Clamp duration to u32 for Ticks API
-                let duration = Ticks::from_ms(u32::try_from(duration_ms.min(u64::from(u32::MAX))).unwrap_or(u32::MAX));
-
-                // Get gamepad reference for builder
-                let gamepad = gilrs.gamepad(id);
+            let mut builder = EffectBuilder::new();
+            for id in &ff_gamepads {
+                builder.add_gamepad(&gilrs.gamepad(*id));
+            }
+            for pulse in &pattern.pulses {
+                for base in pulse_effects(pulse, pattern_ms) {
+                    builder.add_effect(base);
+                }
+            }
+            builder.repeat(Repeat::For(Ticks::from_ms(pattern_ms)));
 
-                // Create dual-motor rumble effect
-                // Strong motor: Low-frequency heavy rumble
-                // Weak motor: High-frequency buzzy detail
-                let mut builder = EffectBuilder::new();
-                builder
-                    .add_gamepad(&gamepad)
-                    .add_effect(BaseEffect {
-                        kind: BaseEffectType::Strong {
-                            magnitude: strong_magnitude,
-                        },
-                        scheduling: Replay {
-                            play_for: duration,
-                            with_delay: Ticks::from_ms(0),
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    })
-                    .add_effect(BaseEffect {
-                        kind: BaseEffectType::Weak {
-                            magnitude: weak_magnitude,
-                        },
-                        scheduling: Replay {
-                            play_for: duration,
-                            with_delay: Ticks::from_ms(0),
-                            ..Default::default()
-                        },
-                        ..Default::default()
-                    })
-                    .repeat(gilrs::ff::Repeat::For(duration));
+            let effect = builder.finish(gilrs).map_err(|e| {
+                warn!("Failed to build force feedback effect: {:?}", e);
+                format!("Failed to build haptic pattern: {e}")
+            })?;
+            effect
+                .play()
+                .map_err(|e| format!("Failed to play haptic pattern: {e}"))?;
+            debug!(
+                "Haptic pattern '{}' playing on {} gamepad(s) ({}ms)",
+                pattern.name,
+                ff_gamepads.len(),
+                pattern_ms
+            );
+            Ok(Some(effect))
+        })?;
 
-                // Finish building and play effect
-                match builder.finish(&mut gilrs) {
-                    Ok(effect) => match effect.play() {
-                        Ok(()) => {
-                            debug!(
-                                "Haptic triggered on gamepad: {} (magnitude: {}, duration: {}ms)",
-                                name, magnitude, duration_ms
-                            );
-                            triggered_count += 1;
-                        },
-                        Err(e) => {
-                            warn!("Failed to play haptic on {}: {:?}", name, e);
-                        },
-                    },
-                    Err(e) => {
-                        warn!("Failed to build force feedback effect: {:?}", e);
-                    },
-                }
+        if let Some(effect) = effect {
+            // Replacing the previous effect stops it
+            if let Ok(mut current) = self.current.lock() {
+                *current = Some(effect);
             }
+        }
+        Ok(())
+    }
 
-            debug!("Haptic feedback triggered on {} gamepad(s)", triggered_count);
-            Ok(())
-        })
-        .await
-        .map_err(|e| format!("Task join error: {e}"))?
+    fn stop(&self) {
+        let effect = self.current.lock().ok().and_then(|mut current| current.take());
+        if let Some(effect) = effect {
+            if let Err(e) = effect.stop() {
+                warn!("Failed to stop haptic pattern: {:?}", e);
+            }
+        }
     }
 
     fn is_supported(&self) -> bool {
-        match self.gilrs.lock() {
-            Ok(gilrs) => {
-                // Check if at least one connected gamepad supports force feedback
-                gilrs.gamepads().any(|(_id, gamepad)| gamepad.is_ff_supported())
-            },
-            Err(e) => {
-                error!("Failed to lock Gilrs mutex: {}", e);
-                false
-            },
-        }
+        // Check if at least one connected gamepad supports force feedback
+        self.with_gilrs(|gilrs| Ok(gilrs.gamepads().any(|(_id, gamepad)| gamepad.is_ff_supported())))
+            .unwrap_or(false)
     }
 }
@@ -0,0 +1,181 @@
+use super::mouse::MouseMover;
+use super::switch_pro;
+use crate::application::DIContainer;
+use crate::domain::controller_input::{
+    gyro_mouse_delta, parse_dualsense, parse_switch_pro, ExtraButton, HidControllerKind, MotionState,
+};
+use crate::domain::settings::ControllerSettings;
+use hidapi::{HidApi, HidDevice};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tracing::{info, warn};
+
+/// How often a missing controller is looked for.
+const RESCAN_INTERVAL: Duration = Duration::from_secs(3);
+/// Read timeout; both controllers report every 4-8 ms while connected.
+const READ_TIMEOUT_MS: i32 = 100;
+/// Settings are re-read at this pace rather than on every report.
+const SETTINGS_REFRESH: Duration = Duration::from_millis(500);
+/// Cursor travel for a swipe across the whole touchpad.
+const TOUCHPAD_MOUSE_PIXELS: f32 = 1200.0;
+
+struct OpenController {
+    kind: HidControllerKind,
+    device: HidDevice,
+}
+
+/// Raw HID backend next to XInput/gilrs (see `gamepad_adapter`), for what
+/// those cannot see on DualSense and Switch Pro controllers:
+///
+/// - **Extra buttons** (touchpad click, mute, Edge paddles/Fn, Home, Capture)
+///   emit `controller-extra-button` and, when bound in `controller.extra_buttons`,
+///   a `nav` event to the main window.
+/// - **Gyro / touchpad mouse** for games without motion support, only while a
+///   game (not Balam, not a Steam Input game) is in front.
+pub fn start_hid_listener<R: Runtime>(app: AppHandle<R>) {
+    let spawned = thread::Builder::new().name("hid-input".to_string()).spawn(move || {
+        let Some(container) = app.try_state::<DIContainer>().map(|c| c.inner().clone()) else {
+            return;
+        };
+        let mut api: Option<HidApi> = None;
+        let mut controller: Option<OpenController> = None;
+        let mut settings = container.settings_service.get().controller;
+        let mut settings_read = Instant::now();
+        let mut previous = MotionState::default();
+        let mut last_report = Instant::now();
+        let mut mouse = MouseMover::default();
+        let mut buffer = [0u8; 128];
+
+        loop {
+            if settings_read.elapsed() >= SETTINGS_REFRESH {
+                settings = container.settings_service.get().controller;
+                settings_read = Instant::now();
+            }
+            if !settings.raw_hid {
+                controller = None;
+                thread::sleep(RESCAN_INTERVAL);
+                continue;
+            }
+
+            let Some(open) = controller.as_ref() else {
+                controller = find_controller(&mut api);
+                if controller.is_none() {
+                    thread::sleep(RESCAN_INTERVAL);
+                }
+                continue;
+            };
+
+            let length = match open.device.read_timeout(&mut buffer, READ_TIMEOUT_MS) {
+                Ok(0) => continue,
+                Ok(length) => length,
+                Err(e) => {
+                    info!("🎮 {:?} disconnected: {}", open.kind, e);
+                    controller = None;
+                    continue;
+                },
+            };
+            let report = &buffer[..length];
+            let state = match open.kind {
+                HidControllerKind::DualSense | HidControllerKind::DualSenseEdge => parse_dualsense(report),
+                HidControllerKind::SwitchPro => parse_switch_pro(report),
+            };
+            let Some(state) = state else { continue };
+            let elapsed = last_report.elapsed().as_secs_f32().min(0.05);
+            last_report = Instant::now();
+
+            let pressed = state.buttons & !previous.buttons;
+            if pressed != 0 {
+                container.idle.record_activity();
+                for button in ExtraButton::ALL.into_iter().filter(|b| pressed & b.mask() != 0) {
+                    on_extra_button(&app, &container, &settings, button);
+                }
+            }
+
+            if mouse_emulation_active(&container) {
+                let (mut dx, mut dy) = (0.0, 0.0);
+                if settings.gyro_mouse {
+                    (dx, dy) = gyro_mouse_delta(state.gyro_dps, elapsed, settings.gyro_sensitivity);
+                }
+                if settings.touchpad_mouse {
+                    if let (Some(now), Some(before)) = (state.touch, previous.touch) {
+                        dx += (now.0 - before.0) * TOUCHPAD_MOUSE_PIXELS;
+                        dy += (now.1 - before.1) * TOUCHPAD_MOUSE_PIXELS;
+                    }
+                }
+                mouse.move_by(dx, dy);
+            } else {
+                mouse.reset();
+            }
+            previous = state;
+        }
+    });
+    if let Err(e) = spawned {
+        warn!("Failed to start HID listener: {}", e);
+    }
+}
+
+/// Opens the first supported controller.
+fn find_controller(api: &mut Option<HidApi>) -> Option<OpenController> {
+    match api {
+        Some(api) => {
+            let _ = api.refresh_devices();
+        },
+        None => match HidApi::new() {
+            Ok(created) => *api = Some(created),
+            Err(e) => {
+                warn!("HID API unavailable: {}", e);
+                return None;
+            },
+        },
+    }
+    let api = api.as_ref()?;
+
+    api.device_list().find_map(|info| {
+        let kind = HidControllerKind::from_ids(info.vendor_id(), info.product_id())?;
+        let device = info.open_device(api).ok()?;
+        match kind {
+            HidControllerKind::DualSense | HidControllerKind::DualSenseEdge => {
+                // Reading the calibration feature report switches Bluetooth to full reports
+                let mut calibration = [0u8; 41];
+                calibration[0] = 0x05;
+                let _ = device.get_feature_report(&mut calibration);
+            },
+            HidControllerKind::SwitchPro => switch_pro::enable_full_reports(&device),
+        }
+        info!("🎮 Raw HID: {:?} connected", kind);
+        Some(OpenController { kind, device })
+    })
+}
+
+fn on_extra_button<R: Runtime>(
+    app: &AppHandle<R>,
+    container: &DIContainer,
+    settings: &ControllerSettings,
+    button: ExtraButton,
+) {
+    // Lets the settings UI capture the button for binding
+    let _ = app.emit("controller-extra-button", button);
+
+    let Some(action) = settings.extra_button_action(button) else {
+        return;
+    };
+    if container.focus.navigation_suppressed() {
+        return;
+    }
+    if let Some(main) = app.get_webview_window("main") {
+        if main.is_visible().unwrap_or(false) {
+            let _ = main.emit("nav", action.event_payload());
+        }
+    }
+}
+
+/// Mouse emulation only drives games: never Balam's own windows, and not
+/// games that already get the gyro through Steam Input.
+fn mouse_emulation_active(container: &DIContainer) -> bool {
+    let focus = container.focus.current();
+    focus.foreground_pid.is_some()
+        && !focus.balam_focused
+        && !focus.steam_input
+        && !container.active_games_tracker.list_active().is_empty()
+}
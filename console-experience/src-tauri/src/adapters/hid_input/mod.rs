@@ -0,0 +1,5 @@
+mod hid_listener;
+mod mouse;
+mod switch_pro;
+
+pub use hid_listener::start_hid_listener;
@@ -0,0 +1,42 @@
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_MOUSE, MOUSEEVENTF_MOVE, MOUSEINPUT,
+};
+
+/// Relative mouse movement that keeps the sub-pixel remainder, so slow
+/// gyro motion still moves the cursor instead of rounding to zero.
+#[derive(Default)]
+pub(super) struct MouseMover {
+    remainder: (f32, f32),
+}
+
+impl MouseMover {
+    pub(super) fn move_by(&mut self, dx: f32, dy: f32) {
+        let x = self.remainder.0 + dx;
+        let y = self.remainder.1 + dy;
+        let (whole_x, whole_y) = (x.trunc(), y.trunc());
+        self.remainder = (x - whole_x, y - whole_y);
+        if x.abs() < 1.0 && y.abs() < 1.0 {
+            return;
+        }
+
+        let input = INPUT {
+            r#type: INPUT_MOUSE,
+            Anonymous: INPUT_0 {
+                mi: MOUSEINPUT {
+                    dx: whole_x as i32,
+                    dy: whole_y as i32,
+                    dwFlags: MOUSEEVENTF_MOVE,
+                    ..Default::default()
+                },
+            },
+        };
+        unsafe {
+            SendInput(&[input], std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    /// Drops the remainder (motion stopped or emulation switched off).
+    pub(super) fn reset(&mut self) {
+        self.remainder = (0.0, 0.0);
+    }
+}
@@ -0,0 +1,33 @@
+use hidapi::HidDevice;
+use tracing::debug;
+
+/// Neutral rumble data sent with every subcommand.
+const NEUTRAL_RUMBLE: [u8; 8] = [0x00, 0x01, 0x40, 0x40, 0x00, 0x01, 0x40, 0x40];
+
+/// Switches a Switch Pro controller to full reports (`0x30`) with the IMU on.
+///
+/// Out of the box it only sends simple button reports. The USB handshake
+/// (`0x80` commands) fails harmlessly over Bluetooth.
+pub(super) fn enable_full_reports(device: &HidDevice) {
+    for command in [0x02u8, 0x03, 0x02, 0x04] {
+        if device.write(&[0x80, command]).is_err() {
+            break;
+        }
+    }
+    // Subcommand 0x03: input report mode (0x30 = standard full)
+    send_subcommand(device, 0, 0x03, 0x30);
+    // Subcommand 0x40: enable the 6-axis sensor
+    send_subcommand(device, 1, 0x40, 0x01);
+}
+
+fn send_subcommand(device: &HidDevice, counter: u8, subcommand: u8, argument: u8) {
+    let mut packet = [0u8; 49];
+    packet[0] = 0x01;
+    packet[1] = counter & 0x0F;
+    packet[2..10].copy_from_slice(&NEUTRAL_RUMBLE);
+    packet[10] = subcommand;
+    packet[11] = argument;
+    if let Err(e) = device.write(&packet) {
+        debug!("Switch Pro subcommand {:#04x} failed: {}", subcommand, e);
+    }
+}
@@ -0,0 +1,251 @@
+/// Hotkey Manager - Configurable global shortcuts and gamepad chords
+///
+/// Owns the mapping between user bindings (from `HotkeySettings`) and actions:
+/// - Keyboard: registered system-wide through `tauri_plugin_global_shortcut`
+/// - Gamepad: chords matched against the XInput button mask by the gamepad listener
+///
+/// Rebinding at runtime is done by calling `apply()` again: shortcuts that are
+/// no longer bound are unregistered and new ones registered, so the plugin never
+/// holds stale registrations.
+use crate::domain::hotkey::{GamepadChordBinding, HotkeyAction};
+use crate::domain::settings::HotkeySettings;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Shortcut};
+use tracing::{info, warn};
+
+/// Media keys registered by the app itself (volume handling), never rebindable
+const RESERVED_KEYS: &[Code] = &[Code::AudioVolumeUp, Code::AudioVolumeDown, Code::AudioVolumeMute];
+
+/// Runtime registry of keyboard shortcuts and gamepad chords.
+pub struct HotkeyManager {
+    bindings: RwLock<HashMap<Shortcut, HotkeyAction>>,
+    chords: RwLock<Vec<GamepadChordBinding>>,
+}
+
+impl HotkeyManager {
+    /// Creates an empty manager (nothing registered until `apply()`).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            bindings: RwLock::new(HashMap::new()),
+            chords: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Shortcuts that are always registered by the app (volume keys).
+    #[must_use]
+    pub fn reserved_shortcuts() -> Vec<Shortcut> {
+        RESERVED_KEYS.iter().map(|code| Shortcut::new(None, *code)).collect()
+    }
+
+    /// Parses an accelerator string such as `"Ctrl+Shift+Q"`.
+    ///
+    /// # Errors
+    /// Returns error if the string is not a valid accelerator.
+    pub fn parse_accelerator(accelerator: &str) -> Result<Shortcut, String> {
+        accelerator
+            .parse::<Shortcut>()
+            .map_err(|e| format!("Invalid shortcut '{accelerator}': {e}"))
+    }
+
+    /// Parses all keyboard bindings and detects conflicts.
+    ///
+    /// A conflict is two actions sharing the same shortcut, or a binding that
+    /// collides with a reserved media key. Unbound actions (empty string) are skipped.
+    ///
+    /// # Errors
+    /// Returns error naming the first invalid or conflicting binding.
+    pub fn resolve(settings: &HotkeySettings) -> Result<HashMap<Shortcut, HotkeyAction>, String> {
+        let reserved = Self::reserved_shortcuts();
+        let mut resolved: HashMap<Shortcut, HotkeyAction> = HashMap::new();
+
+        for (action, accelerator) in settings.keyboard_bindings() {
+            if accelerator.trim().is_empty() {
+                continue;
+            }
+
+            let shortcut = Self::parse_accelerator(accelerator)?;
+
+            if reserved.contains(&shortcut) {
+                return Err(format!("Shortcut '{accelerator}' is reserved for volume control"));
+            }
+
+            if let Some(existing) = resolved.insert(shortcut, action) {
+                return Err(format!(
+                    "Shortcut '{accelerator}' is assigned to both {existing:?} and {action:?}"
+                ));
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Validates keyboard bindings and gamepad chords without registering anything.
+    ///
+    /// # Errors
+    /// Returns error describing the first invalid binding or conflict.
+    pub fn validate(settings: &HotkeySettings) -> Result<(), String> {
+        Self::resolve(settings)?;
+
+        let mut seen_masks: HashMap<u16, HotkeyAction> = HashMap::new();
+        for chord in &settings.gamepad_chords {
+            chord.validate()?;
+            if let Some(existing) = seen_masks.insert(chord.mask(), chord.action) {
+                return Err(format!(
+                    "Gamepad chord is assigned to both {existing:?} and {:?}",
+                    chord.action
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Registers bindings from settings, replacing any previous registration.
+    ///
+    /// # Errors
+    /// Returns error if settings are invalid (nothing changes) or if some
+    /// shortcuts could not be registered because another application owns them
+    /// (the remaining shortcuts stay active).
+    pub fn apply<R: Runtime>(&self, app: &AppHandle<R>, settings: &HotkeySettings) -> Result<(), String> {
+        Self::validate(settings)?;
+        let resolved = Self::resolve(settings)?;
+
+        let global_shortcut = app.global_shortcut();
+        let mut bindings = self.bindings.write();
+
+        // Unregister shortcuts that are no longer bound
+        for shortcut in bindings.keys() {
+            if !resolved.contains_key(shortcut) {
+                if let Err(e) = global_shortcut.unregister(*shortcut) {
+                    warn!("Failed to unregister shortcut {:?}: {}", shortcut, e);
+                }
+            }
+        }
+
+        // Register new shortcuts
+        let mut failed = Vec::new();
+        let mut active = HashMap::new();
+        for (shortcut, action) in resolved {
+            if bindings.contains_key(&shortcut) || global_shortcut.is_registered(shortcut) {
+                active.insert(shortcut, action);
+                continue;
+            }
+
+            match global_shortcut.register(shortcut) {
+                Ok(()) => {
+                    active.insert(shortcut, action);
+                },
+                Err(e) => {
+                    warn!("Failed to register shortcut for {:?}: {}", action, e);
+                    failed.push(format!("{action:?}"));
+                },
+            }
+        }
+
+        info!("⌨️ {} hotkeys registered", active.len());
+        *bindings = active;
+        *self.chords.write() = settings.gamepad_chords.clone();
+
+        if failed.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Shortcuts already in use by another application: {}",
+                failed.join(", ")
+            ))
+        }
+    }
+
+    /// Action bound to a pressed keyboard shortcut.
+    #[must_use]
+    pub fn action_for(&self, shortcut: &Shortcut) -> Option<HotkeyAction> {
+        self.bindings.read().get(shortcut).copied()
+    }
+
+    /// Action whose gamepad chord is fully held in the XInput button mask.
+    ///
+    /// Returns the action together with the chord mask so the caller can
+    /// consume those buttons (avoid firing their navigation events as well).
+    #[must_use]
+    pub fn chord_action(&self, buttons: u16) -> Option<(HotkeyAction, u16)> {
+        self.chords
+            .read()
+            .iter()
+            .filter(|chord| chord.is_held(buttons))
+            // Prefer the most specific chord when several match
+            .max_by_key(|chord| chord.mask().count_ones())
+            .map(|chord| (chord.action, chord.mask()))
+    }
+}
+
+impl Default for HotkeyManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::hotkey::GamepadButton;
+
+    #[test]
+    fn test_default_settings_resolve() {
+        let resolved = HotkeyManager::resolve(&HotkeySettings::default()).unwrap();
+        assert_eq!(resolved.len(), 4);
+    }
+
+    #[test]
+    fn test_duplicate_shortcut_is_conflict() {
+        let mut settings = HotkeySettings::default();
+        settings.toggle_wifi_panel = settings.toggle_overlay.clone();
+
+        assert!(HotkeyManager::validate(&settings).is_err());
+    }
+
+    #[test]
+    fn test_reserved_key_is_conflict() {
+        let mut settings = HotkeySettings::default();
+        settings.toggle_overlay = "AudioVolumeUp".to_string();
+
+        assert!(HotkeyManager::validate(&settings).is_err());
+    }
+
+    #[test]
+    fn test_empty_binding_is_unbound() {
+        let mut settings = HotkeySettings::default();
+        settings.toggle_bluetooth_panel = String::new();
+
+        assert_eq!(HotkeyManager::resolve(&settings).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_accelerator_rejected() {
+        assert!(HotkeyManager::parse_accelerator("Ctrl+NotAKey").is_err());
+    }
+
+    #[test]
+    fn test_chord_action_prefers_most_specific() {
+        let manager = HotkeyManager::new();
+        *manager.chords.write() = vec![
+            GamepadChordBinding {
+                action: HotkeyAction::ToggleWifiPanel,
+                buttons: vec![GamepadButton::LB, GamepadButton::RB],
+            },
+            GamepadChordBinding {
+                action: HotkeyAction::ToggleOverlay,
+                buttons: vec![GamepadButton::LB, GamepadButton::RB, GamepadButton::Start],
+            },
+        ];
+
+        let held = 0x0100 | 0x0200 | 0x0010;
+        assert_eq!(
+            manager.chord_action(held).map(|(action, _)| action),
+            Some(HotkeyAction::ToggleOverlay)
+        );
+        assert_eq!(manager.chord_action(0x1000), None);
+    }
+}
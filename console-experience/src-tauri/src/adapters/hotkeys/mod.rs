@@ -0,0 +1,3 @@
+mod hotkey_manager;
+
+pub use hotkey_manager::HotkeyManager;
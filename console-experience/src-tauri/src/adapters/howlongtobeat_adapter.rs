@@ -0,0 +1,114 @@
+use crate::domain::completion_time::{is_title_match, normalize_title, CompletionTimes};
+use crate::domain::BalamError;
+use crate::ports::CompletionTimePort;
+use serde::Deserialize;
+use std::time::Duration;
+
+const BASE_URL: &str = "https://howlongtobeat.com";
+const SEARCH_URL: &str = "https://howlongtobeat.com/api/search";
+
+/// One search result; times are in seconds, 0 when nobody reported one.
+#[derive(Debug, Deserialize)]
+struct SearchEntry {
+    game_id: u64,
+    game_name: String,
+    #[serde(default)]
+    comp_main: u64,
+    #[serde(default)]
+    comp_plus: u64,
+    #[serde(default)]
+    comp_100: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    data: Vec<SearchEntry>,
+}
+
+fn hours(seconds: u64) -> Option<f32> {
+    (seconds > 0).then(|| (seconds as f32 / 3600.0 * 10.0).round() / 10.0)
+}
+
+/// Completion times from `HowLongToBeat`'s public search.
+pub struct HowLongToBeatAdapter {
+    client: Option<reqwest::blocking::Client>,
+}
+
+impl HowLongToBeatAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(10))
+            // The API rejects requests without a browser-like agent
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) BalamGridEngine/1.0")
+            .build()
+            .ok();
+        Self { client }
+    }
+}
+
+impl Default for HowLongToBeatAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompletionTimePort for HowLongToBeatAdapter {
+    fn lookup(&self, title: &str) -> Result<Option<CompletionTimes>, BalamError> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| BalamError::Platform("HTTP client unavailable".to_string()))?;
+        let search = normalize_title(title);
+        if search.is_empty() {
+            return Ok(None);
+        }
+
+        let body = serde_json::json!({
+            "searchType": "games",
+            "searchTerms": search.split(' ').collect::<Vec<_>>(),
+            "searchPage": 1,
+            "size": 20,
+            "searchOptions": {
+                "games": {
+                    "userId": 0,
+                    "platform": "",
+                    "sortCategory": "popular",
+                    "rangeCategory": "main",
+                    "rangeTime": { "min": null, "max": null },
+                    "gameplay": { "perspective": "", "flow": "", "genre": "" },
+                    "rangeYear": { "min": "", "max": "" },
+                    "modifier": ""
+                },
+                "users": { "sortCategory": "postcount" },
+                "filter": "",
+                "sort": 0,
+                "randomizer": 0
+            }
+        });
+        let response: SearchResponse = client
+            .post(SEARCH_URL)
+            .header(reqwest::header::REFERER, BASE_URL)
+            .header(reqwest::header::ORIGIN, BASE_URL)
+            .json(&body)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::json)
+            .map_err(|e| BalamError::platform("HowLongToBeat search failed", e))?;
+
+        // Results are sorted by popularity; only an exact title counts, a
+        // sequel's times would be worse than none
+        Ok(response
+            .data
+            .into_iter()
+            .find(|entry| is_title_match(title, &entry.game_name))
+            .map(|entry| CompletionTimes {
+                main_story_hours: hours(entry.comp_main),
+                main_extra_hours: hours(entry.comp_plus),
+                completionist_hours: hours(entry.comp_100),
+                source_url: Some(format!("{BASE_URL}/game/{}", entry.game_id)),
+                matched_title: entry.game_name,
+            }))
+    }
+}
@@ -81,18 +81,14 @@ impl IdentityEngine {
         None
     }
 
-    /// Extracts the icon from a .exe file and saves it as PNG
-    ///
-    /// Returns the path to the saved icon file, or `None` if extraction failed.
+    /// Extracts the icon from a .exe file
     ///
     /// # Arguments
     /// * `exe_path` - Path to the .exe file
-    /// * `output_dir` - Directory where to save the extracted icon
-    /// * `game_id` - Unique game ID for naming the icon file
     ///
     /// # Returns
-    /// `Some(path)` if icon was successfully extracted and saved, `None` otherwise
-    pub fn extract_icon(exe_path: &str, output_dir: &Path, game_id: &str) -> Option<String> {
+    /// The icon as an `.ico` (or PNG) file, `None` if extraction failed
+    pub fn extract_icon(exe_path: &str) -> Option<Vec<u8>> {
         let p = Path::new(exe_path);
         if !p.exists() || p.is_dir() || p.extension().is_none_or(|ext| ext != "exe") {
             return None;
@@ -145,21 +141,11 @@ impl IdentityEngine {
 
         match icon_result {
             Ok(icon_data) => {
-                // The icon data from PE is in ICO format
-                // Save as .ico and let the frontend handle it
-                let ico_filename = format!("{game_id}_icon.ico");
-                let ico_path = output_dir.join(&ico_filename);
-
-                match fs::write(&ico_path, icon_data) {
-                    Ok(()) => {
-                        info!("Successfully extracted icon for {} to {:?}", game_id, ico_path);
-                        Some(ico_path.display().to_string())
-                    },
-                    Err(e) => {
-                        warn!("Failed to write icon file: {}", e);
-                        None
-                    },
+                let icon = Self::icon_file(icon_data);
+                if icon.is_none() {
+                    warn!("Unsupported icon format in {}", exe_path);
                 }
+                icon
             },
             Err(FindError::NotFound) => {
                 warn!("Icon not found in {}", exe_path);
@@ -171,4 +157,59 @@ impl IdentityEngine {
             },
         }
     }
+
+    /// Turns an `RT_ICON` resource into an icon file.
+    ///
+    /// Resources hold a single image without the `.ico` header: PNG data
+    /// is returned as is, a bitmap gets a one-entry icon directory.
+    fn icon_file(resource: &[u8]) -> Option<Vec<u8>> {
+        const PNG_SIGNATURE: &[u8] = b"\x89PNG";
+        const ICON_DIR_SIZE: u32 = 6 + 16;
+
+        if resource.starts_with(PNG_SIGNATURE) {
+            return Some(resource.to_vec());
+        }
+        // BITMAPINFOHEADER: the height covers the color and mask bitmaps
+        let read_u32 = |offset: usize| Some(u32::from_le_bytes(resource.get(offset..offset + 4)?.try_into().ok()?));
+        let width = read_u32(4)?;
+        let height = read_u32(8)? / 2;
+        let bit_count = u16::from_le_bytes(resource.get(14..16)?.try_into().ok()?);
+        if read_u32(0)? != 40 || width == 0 || width > 256 || height == 0 || height > 256 {
+            return None;
+        }
+
+        let mut icon = Vec::with_capacity(ICON_DIR_SIZE as usize + resource.len());
+        icon.extend_from_slice(&[0, 0, 1, 0, 1, 0]); // reserved, type: icon, one image
+        icon.extend_from_slice(&[width as u8, height as u8, 0, 0]); // 256 is stored as 0
+        icon.extend_from_slice(&1u16.to_le_bytes()); // color planes
+        icon.extend_from_slice(&bit_count.to_le_bytes());
+        icon.extend_from_slice(&u32::try_from(resource.len()).ok()?.to_le_bytes());
+        icon.extend_from_slice(&ICON_DIR_SIZE.to_le_bytes());
+        icon.extend_from_slice(resource);
+        Some(icon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_resources_become_icon_files() {
+        let mut bitmap = vec![0u8; 40];
+        bitmap[..4].copy_from_slice(&40u32.to_le_bytes());
+        bitmap[4..8].copy_from_slice(&256u32.to_le_bytes());
+        bitmap[8..12].copy_from_slice(&512u32.to_le_bytes());
+        bitmap[14..16].copy_from_slice(&32u16.to_le_bytes());
+
+        let icon = IdentityEngine::icon_file(&bitmap).unwrap();
+        assert_eq!(&icon[..6], &[0, 0, 1, 0, 1, 0]);
+        assert_eq!(&icon[6..8], &[0, 0], "256x256 is stored as 0");
+        assert_eq!(&icon[18..22], &22u32.to_le_bytes());
+        assert_eq!(&icon[22..], &bitmap[..]);
+
+        let png = b"\x89PNG\r\n\x1a\nrest".to_vec();
+        assert_eq!(IdentityEngine::icon_file(&png), Some(png));
+        assert_eq!(IdentityEngine::icon_file(&[1, 2, 3]), None);
+    }
 }
@@ -0,0 +1,198 @@
+use crate::domain::errors::ScanError;
+use crate::domain::{Game, GameSource, InstallState};
+use crate::ports::GameScanner;
+use rusqlite::{Connection, OpenFlags};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// butler's database, relative to `%APPDATA%`.
+const BUTLER_DB: &str = "itch\\db\\butler.db";
+
+/// One row per installed upload ("cave"), with its game and install location.
+const INSTALLED_GAMES_QUERY: &str = "
+    SELECT games.id, games.title, games.cover_url, games.still_cover_url,
+           caves.verdict, caves.install_folder_name, install_locations.path
+    FROM caves
+    JOIN games ON games.id = caves.game_id
+    LEFT JOIN install_locations ON install_locations.id = caves.install_location_id";
+
+/// butler's analysis of an install folder (`caves.verdict`).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Verdict {
+    #[serde(default)]
+    base_path: String,
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+}
+
+/// A launchable file butler found.
+#[derive(Debug, Deserialize)]
+struct Candidate {
+    /// Relative to the install folder
+    path: String,
+    flavor: String,
+}
+
+impl Verdict {
+    /// The Windows executable to launch, if butler found one.
+    fn windows_executable(&self, fallback_base: Option<&Path>) -> Option<PathBuf> {
+        let candidate = self.candidates.iter().find(|candidate| candidate.flavor == "windows")?;
+        let base = if self.base_path.is_empty() {
+            fallback_base?.to_path_buf()
+        } else {
+            PathBuf::from(&self.base_path)
+        };
+        Some(base.join(&candidate.path))
+    }
+}
+
+/// itch.io game scanner.
+///
+/// Discovers games installed by the itch app by reading butler's SQLite
+/// database (opened read-only; the itch app may be running).
+///
+/// # Data Sources
+/// - **Database**: `%APPDATA%\itch\db\butler.db`
+///   (`caves` = installs, `games` = itch.io metadata, `install_locations`)
+///
+/// # Metadata Quality
+/// - **Title**: From `games.title`
+/// - **Executable Path**: First Windows launch candidate of the install verdict
+/// - **Cover Art**: `games.still_cover_url` (the static version of animated
+///   covers), downloaded by the metadata sync like any remote cover
+///
+/// # Thread Safety
+/// Safe to use concurrently via `Arc<ItchScanner>`.
+pub struct ItchScanner {
+    database: Option<PathBuf>,
+}
+
+impl ItchScanner {
+    /// Creates a new itch.io scanner for the current user's itch app.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            database: std::env::var_os("APPDATA").map(|appdata| Path::new(&appdata).join(BUTLER_DB)),
+        }
+    }
+
+    fn scan_internal(&self) -> Result<Vec<Game>, ScanError> {
+        let Some(database) = self.database.as_ref().filter(|path| path.exists()) else {
+            return Ok(Vec::new());
+        };
+        info!("Scanning itch.io games...");
+
+        let connection = Connection::open_with_flags(database, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| ScanError::IoError(format!("Failed to open butler.db: {e}")))?;
+        let mut statement = connection
+            .prepare(INSTALLED_GAMES_QUERY)
+            .map_err(|e| ScanError::ParseError(format!("Unexpected butler.db schema: {e}")))?;
+        let rows = statement
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                    row.get::<_, Option<String>>(6)?,
+                ))
+            })
+            .map_err(|e| ScanError::ParseError(format!("Failed to read butler.db: {e}")))?;
+
+        let mut games: Vec<Game> = Vec::new();
+        for (game_id, title, cover_url, still_cover_url, verdict, folder_name, location) in rows.flatten() {
+            // Install folder when the verdict has no base path: <location>\apps\<folder>
+            let fallback_base = location
+                .zip(folder_name)
+                .map(|(location, folder)| Path::new(&location).join("apps").join(folder));
+            let executable = verdict
+                .and_then(|verdict| serde_json::from_str::<Verdict>(&verdict).ok())
+                .and_then(|verdict| verdict.windows_executable(fallback_base.as_deref()));
+            let Some(executable) = executable else {
+                warn!("No Windows executable for itch.io game {}", title);
+                continue;
+            };
+
+            let raw_id = game_id.to_string();
+            // One game can have several installed uploads (demo, full game)
+            if games.iter().any(|game| game.raw_id == raw_id) {
+                continue;
+            }
+            games.push(Game {
+                id: format!("itch_{raw_id}"),
+                raw_id,
+                title,
+                path: executable.to_string_lossy().to_string(),
+                image: still_cover_url.filter(|url| !url.is_empty()).or(cover_url),
+                hero_image: None,
+                logo: None,
+                last_played: None,
+                source: GameSource::Itch,
+                install_state: InstallState::Installed,
+                launcher_dependency: None,
+                update_pending: false,
+            });
+        }
+
+        info!("itch.io scan complete. Found {} games", games.len());
+        Ok(games)
+    }
+}
+
+impl Default for ItchScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameScanner for ItchScanner {
+    fn scan(&self) -> Result<Vec<Game>, ScanError> {
+        self.scan_internal()
+    }
+
+    fn source(&self) -> GameSource {
+        GameSource::Itch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_implements_trait() {
+        let scanner = ItchScanner::new();
+        assert_eq!(scanner.source(), GameSource::Itch);
+    }
+
+    #[test]
+    fn test_verdict_windows_executable() {
+        let verdict: Verdict = serde_json::from_str(
+            r#"{
+                "basePath": "C:\\itch\\apps\\celeste",
+                "totalSize": 1024,
+                "candidates": [
+                    {"path": "celeste.sh", "depth": 1, "flavor": "script"},
+                    {"path": "Celeste.exe", "depth": 1, "flavor": "windows", "arch": "386"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            verdict.windows_executable(None),
+            Some(PathBuf::from("C:\\itch\\apps\\celeste").join("Celeste.exe"))
+        );
+
+        let relative: Verdict =
+            serde_json::from_str(r#"{"candidates": [{"path": "game.exe", "flavor": "windows"}]}"#).unwrap();
+        assert_eq!(relative.windows_executable(None), None);
+        assert_eq!(
+            relative.windows_executable(Some(Path::new("D:\\itch\\apps\\game"))),
+            Some(PathBuf::from("D:\\itch\\apps\\game").join("game.exe"))
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//! Runs pre-launch / post-exit hook programs with a timeout.
+mod runner;
+
+pub use runner::HookRunner;
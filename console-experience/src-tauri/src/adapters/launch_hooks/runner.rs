@@ -0,0 +1,80 @@
+use crate::domain::launch_hooks::LaunchHook;
+use std::os::windows::process::CommandExt;
+use std::path::Path;
+use std::process::{Child, Command};
+use std::time::{Duration, Instant};
+
+/// Scripts run hidden; companion apps keep their window.
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Starts hook programs.
+pub struct HookRunner;
+
+impl HookRunner {
+    /// Command line for `hook`: scripts go through their interpreter.
+    fn command(hook: &LaunchHook) -> Command {
+        let extension = Path::new(&hook.command)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let mut command = match extension.as_deref() {
+            Some("ps1") => {
+                let mut command = Command::new("powershell.exe");
+                command.args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-File", &hook.command]);
+                command
+            },
+            Some("bat" | "cmd") => {
+                let mut command = Command::new("cmd.exe");
+                command.arg("/C").arg(&hook.command);
+                command
+            },
+            _ => Command::new(&hook.command),
+        };
+        command.args(&hook.args);
+        if let Some(dir) = &hook.working_dir {
+            command.current_dir(dir);
+        }
+        if extension.is_some_and(|ext| matches!(ext.as_str(), "ps1" | "bat" | "cmd")) {
+            command.creation_flags(CREATE_NO_WINDOW);
+        }
+        command
+    }
+
+    /// Runs `hook` with `env` added to its environment.
+    ///
+    /// Waiting hooks must exit with code 0 within their timeout (they are
+    /// killed otherwise); the others are returned still running.
+    ///
+    /// # Errors
+    /// Returns the spawn error, the non-zero exit code or the timeout.
+    pub fn run(hook: &LaunchHook, env: &[(&str, &str)]) -> Result<Option<Child>, String> {
+        let mut child = Self::command(hook)
+            .envs(env.iter().copied())
+            .spawn()
+            .map_err(|e| format!("Failed to start {}: {e}", hook.command))?;
+        if !hook.wait {
+            return Ok(Some(child));
+        }
+
+        let deadline = Instant::now() + Duration::from_secs(u64::from(hook.timeout_secs));
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) if status.success() => return Ok(None),
+                Ok(Some(status)) => {
+                    return Err(format!(
+                        "Exited with code {}",
+                        status.code().map_or_else(|| "?".to_string(), |code| code.to_string())
+                    ))
+                },
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(format!("Timed out after {}s", hook.timeout_secs));
+                },
+                Ok(None) => std::thread::sleep(POLL_INTERVAL),
+                Err(e) => return Err(format!("Failed to wait for {}: {e}", hook.command)),
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::domain::legendary::{
+    legendary_launch_args, legendary_update_pending, parse_legendary_build_versions, parse_legendary_installed,
+    LegendaryInstall, LegendarySession,
+};
+use chrono::Utc;
+use serde::Deserialize;
+use std::fs;
+use std::os::windows::process::CommandExt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{info, warn};
+
+/// Legendary runs hidden; the game opens its own window.
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+/// Legendary executable bundled with Heroic, relative to `%LOCALAPPDATA%`
+/// (newer builds add the `x64` level).
+const HEROIC_LEGENDARY: [&str; 2] = [
+    "Programs\\heroic\\resources\\app.asar.unpacked\\build\\bin\\x64\\win32\\legendary.exe",
+    "Programs\\heroic\\resources\\app.asar.unpacked\\build\\bin\\win32\\legendary.exe",
+];
+
+#[derive(Deserialize)]
+struct GameMetadataFile {
+    metadata: GameMetadata,
+}
+
+#[derive(Deserialize)]
+struct GameMetadata {
+    /// Epic catalog item id (the `raw_id` of launcher-installed games)
+    id: String,
+}
+
+/// Epic games through the [Legendary](https://github.com/derrod/legendary) CLI.
+///
+/// Reads the install list and saved session from Legendary's config folder
+/// (standalone or Heroic's) and runs `legendary` for launches and sign-in.
+pub struct LegendaryAdapter {
+    executable: PathBuf,
+}
+
+impl LegendaryAdapter {
+    /// Finds Legendary: `custom_path`, then `legendary.exe` on `PATH`, then
+    /// the copy bundled with Heroic.
+    #[must_use]
+    pub fn locate(custom_path: &str) -> Option<Self> {
+        let custom = Some(PathBuf::from(custom_path)).filter(|_| !custom_path.is_empty());
+        let on_path = std::env::var_os("PATH")
+            .into_iter()
+            .flat_map(|paths| std::env::split_paths(&paths).collect::<Vec<_>>())
+            .map(|dir| dir.join("legendary.exe"));
+        let heroic = std::env::var_os("LOCALAPPDATA")
+            .into_iter()
+            .flat_map(|local| HEROIC_LEGENDARY.map(|relative| Path::new(&local).join(relative)));
+
+        custom
+            .into_iter()
+            .chain(on_path)
+            .chain(heroic)
+            .find(|path| path.is_file())
+            .map(|executable| Self { executable })
+    }
+
+    /// Legendary config folders that exist: `LEGENDARY_CONFIG_PATH`, the
+    /// standalone default and Heroic's.
+    #[must_use]
+    pub fn config_dirs() -> Vec<PathBuf> {
+        let standalone = std::env::var_os("USERPROFILE").map(|home| Path::new(&home).join(".config\\legendary"));
+        let heroic =
+            std::env::var_os("APPDATA").map(|roaming| Path::new(&roaming).join("heroic\\legendaryConfig\\legendary"));
+        let mut dirs: Vec<PathBuf> = std::env::var_os("LEGENDARY_CONFIG_PATH")
+            .map(PathBuf::from)
+            .into_iter()
+            .chain(standalone)
+            .chain(heroic)
+            .filter(|dir| dir.is_dir())
+            .collect();
+        dirs.dedup();
+        dirs
+    }
+
+    /// Games installed through Legendary, with the config folder listing them.
+    #[must_use]
+    pub fn installed_games() -> Vec<(PathBuf, LegendaryInstall)> {
+        let mut games: Vec<(PathBuf, LegendaryInstall)> = Vec::new();
+        for dir in Self::config_dirs() {
+            let Ok(content) = fs::read_to_string(dir.join("installed.json")) else {
+                continue;
+            };
+            match parse_legendary_installed(&content) {
+                Ok(installed) => {
+                    for game in installed {
+                        if !games.iter().any(|(_, known)| known.app_name == game.app_name) {
+                            games.push((dir.clone(), game));
+                        }
+                    }
+                },
+                Err(e) => warn!("Skipping {}: {}", dir.display(), e),
+            }
+        }
+        games
+    }
+
+    /// Epic catalog item id of a Legendary app, from its cached metadata.
+    #[must_use]
+    pub fn catalog_item_id(config_dir: &Path, app_name: &str) -> Option<String> {
+        let content = fs::read_to_string(config_dir.join("metadata").join(format!("{app_name}.json"))).ok()?;
+        serde_json::from_str::<GameMetadataFile>(&content)
+            .ok()
+            .map(|file| file.metadata.id)
+    }
+
+    /// Legendary install of an Epic game (`raw_id` is the catalog item id, or
+    /// the app name for games without cached metadata).
+    #[must_use]
+    pub fn find_install(raw_id: &str) -> Option<(PathBuf, LegendaryInstall)> {
+        Self::installed_games().into_iter().find(|(dir, game)| {
+            game.app_name == raw_id || Self::catalog_item_id(dir, &game.app_name).is_some_and(|id| id == raw_id)
+        })
+    }
+
+    /// Whether Legendary's cached assets list a newer build of the game.
+    #[must_use]
+    pub fn update_pending(config_dir: &Path, game: &LegendaryInstall) -> bool {
+        fs::read_to_string(config_dir.join("assets.json"))
+            .is_ok_and(|content| legendary_update_pending(game, &parse_legendary_build_versions(&content)))
+    }
+
+    /// Whether the saved Epic session can still be refreshed.
+    #[must_use]
+    pub fn session_valid(config_dir: &Path) -> bool {
+        fs::read_to_string(config_dir.join("user.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<LegendarySession>(&content).ok())
+            .is_some_and(|session| session.is_valid_at(Utc::now()))
+    }
+
+    /// Starts a game through `legendary launch` (offline when the session is
+    /// gone and the game allows it).
+    ///
+    /// # Errors
+    /// Returns error if the game needs a sign-in or Legendary cannot start.
+    pub fn launch(&self, config_dir: &Path, game: &LegendaryInstall) -> Result<(), String> {
+        let args = legendary_launch_args(game, Self::session_valid(config_dir))?;
+        info!("Executing Legendary: legendary {}", args.join(" "));
+        Command::new(&self.executable)
+            .args(&args)
+            .env("LEGENDARY_CONFIG_PATH", config_dir)
+            .creation_flags(CREATE_NO_WINDOW)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to start Legendary: {e}"))
+    }
+
+    /// Downloads and applies the game's pending update (`legendary update`),
+    /// in the background.
+    ///
+    /// # Errors
+    /// Returns error if Legendary cannot start.
+    pub fn update(&self, config_dir: &Path, game: &LegendaryInstall) -> Result<(), String> {
+        info!("Executing Legendary: legendary update {} --yes", game.app_name);
+        Command::new(&self.executable)
+            .args(["update", &game.app_name, "--yes"])
+            .env("LEGENDARY_CONFIG_PATH", config_dir)
+            .creation_flags(CREATE_NO_WINDOW)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| format!("Failed to start Legendary: {e}"))
+    }
+
+    /// Signs Legendary in to Epic: with an authorization code from the Epic
+    /// login page, or by importing the Epic Games Launcher's session.
+    ///
+    /// # Errors
+    /// Returns Legendary's error output if sign-in fails.
+    pub fn login(&self, authorization_code: Option<&str>) -> Result<(), String> {
+        let mut command = Command::new(&self.executable);
+        command.arg("auth");
+        match authorization_code {
+            Some(code) => command.args(["--code", code]),
+            None => command.arg("--import"),
+        };
+        if let Some(dir) = Self::config_dirs().first() {
+            command.env("LEGENDARY_CONFIG_PATH", dir);
+        }
+        let output = command
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map_err(|e| format!("Failed to start Legendary: {e}"))?;
+        if output.status.success() {
+            info!("Legendary signed in to Epic");
+            Ok(())
+        } else {
+            Err(format!(
+                "Legendary sign-in failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_catalog_item_id() {
+        let json = r#"{"app_name": "Sugar", "metadata": {"id": "cd2bcbd7c2a44a4bb6e3e1fbb73e0b8e", "title": "Hades"}}"#;
+        let file: GameMetadataFile = serde_json::from_str(json).unwrap();
+        assert_eq!(file.metadata.id, "cd2bcbd7c2a44a4bb6e3e1fbb73e0b8e");
+    }
+}
@@ -1,162 +1,57 @@
 use crate::adapters::identity_engine::IdentityEngine;
 use crate::adapters::microsoft_store_adapter::MicrosoftStoreAdapter;
+use crate::adapters::thumbnail_cache;
+use crate::domain::artwork::{ArtworkEntry, StoredImage};
 use crate::domain::{Game, GameSource};
 use std::fs;
-use std::path::{Path, PathBuf};
-use tauri::{AppHandle, Manager};
-use tracing::{error, info, warn};
+use std::path::Path;
+use tracing::{info, warn};
 
+/// Downloads and extracts game artwork into the thumbnail cache.
+///
+/// Slow (network, PE parsing, image decoding): runs on the artwork workers,
+/// never while the library is being served.
 pub struct MetadataAdapter;
 
 impl MetadataAdapter {
-    pub fn get_covers_dir(app_handle: &AppHandle) -> PathBuf {
-        let path = app_handle
-            .path()
-            .app_local_data_dir()
-            .unwrap_or_default()
-            .join("covers");
-        if !path.exists() {
-            info!("Creating covers directory at: {:?}", path);
-            if let Err(e) = fs::create_dir_all(&path) {
-                error!("Failed to create covers directory: {}", e);
-            }
+    /// Fetches the cover, hero and logo of a game into `dir`.
+    ///
+    /// Sources are the game's artwork URLs (or local files, such as covers
+    /// cached by older versions); Xbox games ask the Microsoft Store, and
+    /// games without a cover fall back to their executable's icon.
+    pub fn fetch_artwork(game: &Game, dir: &Path, ms_store_adapter: &MicrosoftStoreAdapter) -> ArtworkEntry {
+        if game.source == GameSource::Xbox {
+            return Self::fetch_xbox_artwork(game, dir, ms_store_adapter);
         }
-        path
-    }
-
-    pub fn ensure_metadata_cached(games: &mut Vec<Game>, app_handle: &AppHandle) -> bool {
-        let covers_dir = Self::get_covers_dir(app_handle);
-        let mut any_updated = false;
-
-        // Initialize MS Store adapter for Xbox games
-        let ms_store_adapter = MicrosoftStoreAdapter::new();
 
-        info!(
-            "Syncing metadata for {} games. Cache dir: {:?}",
-            games.len(),
-            covers_dir
-        );
-
-        for game in games {
-            // Special handling for Xbox games - use Microsoft Store API
-            if game.source == GameSource::Xbox {
-                if Self::process_xbox_game(game, &covers_dir, &ms_store_adapter) {
-                    any_updated = true;
-                }
-                continue;
-            }
-            // Process Cover
-            if let Some(img_url) = &game.image {
-                if img_url.starts_with("http") {
-                    let game_id = &game.id;
-                    let local_filename = format!("{game_id}_cover.jpg");
-                    let local_path = covers_dir.join(&local_filename);
-
-                    if local_path.exists() {
-                        game.image = Some(local_path.display().to_string());
-                        any_updated = true;
-                    } else {
-                        match Self::download_image(img_url) {
-                            Ok(data) => match fs::write(&local_path, data) {
-                                Ok(()) => {
-                                    info!("Successfully saved cover for {} to {:?}", game.title, local_path);
-                                    game.image = Some(local_path.display().to_string());
-                                    any_updated = true;
-                                },
-                                Err(e) => error!("Failed to download cover for {}: {}", game.title, e),
-                            },
-                            Err(e) => error!("Failed to download cover for {}: {}", game.title, e),
-                        }
-                    }
-                }
-            } else {
-                // FALLBACK: No image URL provided, try to extract icon from .exe
+        let cover = match &game.image {
+            Some(source) => Self::store_source(game, "cover", source, dir, true),
+            None => {
                 info!("No image URL for {}, attempting icon extraction from exe", game.title);
-
-                // Check if icon already exists
-                let game_id = &game.id;
-                let icon_filename = format!("{game_id}_icon.ico");
-                let icon_path = covers_dir.join(&icon_filename);
-
-                if icon_path.exists() {
-                    game.image = Some(icon_path.display().to_string());
-                    any_updated = true;
-                    info!("Using cached icon for {}", game.title);
-                } else {
-                    // Try to extract icon from the game's exe
-                    if let Some(icon_path_str) = IdentityEngine::extract_icon(&game.path, &covers_dir, &game.id) {
-                        game.image = Some(icon_path_str);
-                        any_updated = true;
-                        info!("Successfully extracted icon for {}", game.title);
-                    } else {
-                        warn!("Failed to extract icon for {} from {}", game.title, game.path);
-                        // Icon will remain None, frontend will use placeholder
-                    }
+                let icon = IdentityEngine::extract_icon(&game.path);
+                if icon.is_none() {
+                    // Icon will remain None, frontend will use placeholder
+                    warn!("Failed to extract icon for {} from {}", game.title, game.path);
                 }
-            }
-
-            // Process Hero
-            if let Some(hero_url) = &game.hero_image {
-                if hero_url.starts_with("http") {
-                    let game_id = &game.id;
-                    let local_filename = format!("{game_id}_hero.jpg");
-                    let local_path = covers_dir.join(&local_filename);
-
-                    if local_path.exists() {
-                        game.hero_image = Some(local_path.display().to_string());
-                        any_updated = true;
-                    } else if let Ok(data) = Self::download_image(hero_url) {
-                        if fs::write(&local_path, data).is_ok() {
-                            info!("Successfully saved hero for {} to {:?}", game.title, local_path);
-                            game.hero_image = Some(local_path.display().to_string());
-                            any_updated = true;
-                        }
-                    }
-                }
-            }
-
-            // Process Logo
-            if let Some(logo_url) = &game.logo {
-                if logo_url.starts_with("http") {
-                    let game_id = &game.id;
-                    let local_filename = format!("{game_id}_logo.png");
-                    let local_path = covers_dir.join(&local_filename);
+                icon.and_then(|icon| Self::store(game, "icon", &icon, dir, true))
+            },
+        };
 
-                    if local_path.exists() {
-                        game.logo = Some(local_path.display().to_string());
-                        any_updated = true;
-                    } else if let Ok(data) = Self::download_image(logo_url) {
-                        if fs::write(&local_path, data).is_ok() {
-                            info!("Successfully saved logo for {} to {:?}", game.title, local_path);
-                            game.logo = Some(local_path.display().to_string());
-                            any_updated = true;
-                        }
-                    }
-                }
-            }
+        ArtworkEntry {
+            cover,
+            hero: game
+                .hero_image
+                .as_ref()
+                .and_then(|source| Self::store_source(game, "hero", source, dir, false)),
+            logo: game
+                .logo
+                .as_ref()
+                .and_then(|source| Self::store_source(game, "logo", source, dir, false)),
         }
-
-        any_updated
     }
 
     /// Process Xbox game metadata using Microsoft Store API
-    fn process_xbox_game(game: &mut Game, covers_dir: &Path, ms_store_adapter: &MicrosoftStoreAdapter) -> bool {
-        let mut any_updated = false;
-
-        // Check if we already have cached images
-        let game_id = &game.id;
-        let cover_path = covers_dir.join(format!("{game_id}_cover.jpg"));
-        let hero_path = covers_dir.join(format!("{game_id}_hero.jpg"));
-        let logo_path = covers_dir.join(format!("{game_id}_logo.png"));
-
-        // If all images exist, use them
-        if cover_path.exists() && hero_path.exists() && logo_path.exists() {
-            game.image = Some(cover_path.display().to_string());
-            game.hero_image = Some(hero_path.display().to_string());
-            game.logo = Some(logo_path.display().to_string());
-            return true;
-        }
-
+    fn fetch_xbox_artwork(game: &Game, dir: &Path, ms_store_adapter: &MicrosoftStoreAdapter) -> ArtworkEntry {
         info!("Fetching Xbox game artwork from Microsoft Store for: {}", game.title);
 
         // Fetch artwork from MS Store API using PackageFamilyName (stored in raw_id)
@@ -164,11 +59,10 @@ impl MetadataAdapter {
             Ok(art) => art,
             Err(e) => {
                 warn!("Failed to fetch MS Store artwork for {}: {}", game.title, e);
-                return false;
+                return ArtworkEntry::default();
             },
         };
 
-        // Download and cache cover image
         // Fallback priority: Cover -> Hero -> Logo
         let cover_source = artwork
             .cover_url
@@ -176,63 +70,46 @@ impl MetadataAdapter {
             .or(artwork.hero_url.as_ref())
             .or(artwork.logo_url.as_ref());
 
-        if let Some(cover_url) = cover_source {
-            if !cover_path.exists() {
-                match Self::download_image(cover_url) {
-                    Ok(data) => {
-                        if fs::write(&cover_path, data).is_ok() {
-                            info!("✅ Saved Xbox cover for {} to {:?}", game.title, cover_path);
-                            game.image = Some(cover_path.display().to_string());
-                            any_updated = true;
-                        }
-                    },
-                    Err(e) => warn!("Failed to download Xbox cover for {}: {}", game.title, e),
-                }
-            } else {
-                game.image = Some(cover_path.display().to_string());
-                any_updated = true;
-            }
+        ArtworkEntry {
+            cover: cover_source.and_then(|url| Self::store_source(game, "Xbox cover", url, dir, true)),
+            hero: artwork
+                .hero_url
+                .as_ref()
+                .and_then(|url| Self::store_source(game, "Xbox hero", url, dir, false)),
+            logo: artwork
+                .logo_url
+                .as_ref()
+                .and_then(|url| Self::store_source(game, "Xbox logo", url, dir, false)),
         }
+    }
 
-        // Download and cache hero image
-        if let Some(hero_url) = &artwork.hero_url {
-            if !hero_path.exists() {
-                match Self::download_image(hero_url) {
-                    Ok(data) => {
-                        if fs::write(&hero_path, data).is_ok() {
-                            info!("✅ Saved Xbox hero for {} to {:?}", game.title, hero_path);
-                            game.hero_image = Some(hero_path.display().to_string());
-                            any_updated = true;
-                        }
-                    },
-                    Err(e) => warn!("Failed to download Xbox hero for {}: {}", game.title, e),
-                }
-            } else {
-                game.hero_image = Some(hero_path.display().to_string());
-                any_updated = true;
-            }
+    /// Reads an image from a URL or a local file and stores it.
+    fn store_source(game: &Game, kind: &str, source: &str, dir: &Path, with_thumbnails: bool) -> Option<StoredImage> {
+        let data = if source.starts_with("http") {
+            Self::download_image(source)
+        } else {
+            fs::read(source).map_err(|e| format!("Failed to read {source}: {e}"))
+        };
+        match data {
+            Ok(data) => Self::store(game, kind, &data, dir, with_thumbnails),
+            Err(e) => {
+                warn!("Failed to fetch {} for {}: {}", kind, game.title, e);
+                None
+            },
         }
+    }
 
-        // Download and cache logo image
-        if let Some(logo_url) = &artwork.logo_url {
-            if !logo_path.exists() {
-                match Self::download_image(logo_url) {
-                    Ok(data) => {
-                        if fs::write(&logo_path, data).is_ok() {
-                            info!("✅ Saved Xbox logo for {} to {:?}", game.title, logo_path);
-                            game.logo = Some(logo_path.display().to_string());
-                            any_updated = true;
-                        }
-                    },
-                    Err(e) => warn!("Failed to download Xbox logo for {}: {}", game.title, e),
-                }
-            } else {
-                game.logo = Some(logo_path.display().to_string());
-                any_updated = true;
-            }
+    fn store(game: &Game, kind: &str, data: &[u8], dir: &Path, with_thumbnails: bool) -> Option<StoredImage> {
+        match thumbnail_cache::store(dir, data, with_thumbnails) {
+            Ok(stored) => {
+                info!("✅ Cached {} for {} ({})", kind, game.title, stored.file_name());
+                Some(stored)
+            },
+            Err(e) => {
+                warn!("Failed to cache {} for {}: {}", kind, game.title, e);
+                None
+            },
         }
-
-        any_updated
     }
 
     fn download_image(url: &str) -> Result<Vec<u8>, String> {
@@ -4,6 +4,10 @@ use std::time::Duration;
 use tracing::{info, warn};
 
 const MS_STORE_API_BASE: &str = "https://displaycatalog.md.mp.microsoft.com/v7.0";
+/// Public product list ("sigl") of the PC Game Pass catalog.
+const GAME_PASS_PC_SIGL: &str = "https://catalog.gamepass.com/sigls/v2?id=fdd9e2a7-0fee-49f6-ad69-4354098401ff";
+/// Product IDs per DisplayCatalog batch request.
+const CATALOG_BATCH_SIZE: usize = 20;
 
 /// Microsoft Store API adapter for fetching Xbox/UWP game artwork.
 /// Uses the public DisplayCatalog API (no authentication required).
@@ -21,15 +25,32 @@ struct ProductsResponse {
 
 #[derive(Debug, Deserialize)]
 struct Product {
+    #[serde(rename = "ProductId", default)]
+    product_id: String,
     #[serde(rename = "LocalizedProperties")]
     localized_properties: Vec<LocalizedProperty>,
+    #[serde(rename = "Properties", default)]
+    properties: Option<ProductProperties>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProductProperties {
+    #[serde(rename = "PackageFamilyName", default)]
+    package_family_name: Option<String>,
+}
+
+/// Entry of a Game Pass sigl (the first one describes the list itself).
+#[derive(Debug, Deserialize)]
+struct SiglEntry {
+    #[serde(default)]
+    id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct LocalizedProperty {
     #[serde(rename = "ProductTitle")]
     product_title: String,
-    #[serde(rename = "Images")]
+    #[serde(rename = "Images", default)]
     images: Vec<Image>,
 }
 
@@ -45,6 +66,14 @@ struct Image {
     height: u32,
 }
 
+/// Game Pass catalog entry resolved through DisplayCatalog
+#[derive(Debug, Clone)]
+pub struct CatalogProduct {
+    pub product_id: String,
+    pub title: String,
+    pub package_family_name: Option<String>,
+}
+
 /// Game artwork URLs fetched from Microsoft Store
 #[derive(Debug, Clone)]
 pub struct GameArtwork {
@@ -183,6 +212,83 @@ impl MicrosoftStoreAdapter {
             logo_url,
         })
     }
+
+    /// Fetch the PC Game Pass catalog.
+    ///
+    /// Reads the public Game Pass product list, then resolves titles and
+    /// PackageFamilyNames through DisplayCatalog in batches.
+    ///
+    /// # Errors
+    /// Returns error if the product list cannot be fetched; failed batches
+    /// are skipped.
+    pub fn fetch_game_pass_catalog(&self) -> Result<Vec<CatalogProduct>, String> {
+        let url = format!("{GAME_PASS_PC_SIGL}&language={}&market={}", self.language, self.market);
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .map_err(|e| format!("Network error: {e}"))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(format!("HTTP Error: {status}"));
+        }
+        let entries: Vec<SiglEntry> = response.json().map_err(|e| format!("JSON parse error: {e}"))?;
+        let product_ids: Vec<String> = entries.into_iter().filter_map(|entry| entry.id).collect();
+        info!("Game Pass catalog lists {} products", product_ids.len());
+
+        let mut catalog = Vec::with_capacity(product_ids.len());
+        for batch in product_ids.chunks(CATALOG_BATCH_SIZE) {
+            match self.fetch_products(batch) {
+                Ok(products) => catalog.extend(products),
+                Err(e) => warn!("Skipping Game Pass batch: {}", e),
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// Look up one Store product (title and PackageFamilyName) by product ID.
+    ///
+    /// # Errors
+    /// Returns error if the request fails or the product is unknown.
+    pub fn fetch_product(&self, product_id: &str) -> Result<CatalogProduct, String> {
+        self.fetch_products(&[product_id.to_string()])?
+            .into_iter()
+            .next()
+            .ok_or_else(|| format!("No product found for {product_id}"))
+    }
+
+    fn fetch_products(&self, product_ids: &[String]) -> Result<Vec<CatalogProduct>, String> {
+        let url = format!(
+            "{}/products?bigIds={}&market={}&languages={}",
+            MS_STORE_API_BASE,
+            product_ids.join(","),
+            self.market,
+            self.language
+        );
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .map_err(|e| format!("Network error: {e}"))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            return Err(format!("HTTP Error: {status}"));
+        }
+        let products_response: ProductsResponse = response.json().map_err(|e| format!("JSON parse error: {e}"))?;
+
+        Ok(products_response
+            .products
+            .into_iter()
+            .filter_map(|product| {
+                let title = product.localized_properties.first()?.product_title.clone();
+                Some(CatalogProduct {
+                    product_id: product.product_id,
+                    title,
+                    package_family_name: product.properties.and_then(|p| p.package_family_name),
+                })
+            })
+            .collect())
+    }
 }
 
 impl Default for MicrosoftStoreAdapter {
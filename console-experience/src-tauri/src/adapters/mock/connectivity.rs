@@ -0,0 +1,246 @@
+use crate::domain::wifi::WiFiStrengthConfig;
+use crate::domain::BalamError;
+use crate::ports::bluetooth_port::PairingListener;
+use crate::ports::network_port::{NetworkAdapterInfo, NetworkAdapterKind, NetworkDetails};
+use crate::ports::{
+    BluetoothDevice, BluetoothDeviceType, BluetoothPairingConfig, BluetoothPairingState, BluetoothPort, NetworkPort,
+    WiFiConfig, WiFiNetwork, WiFiPort, WiFiSecurity,
+};
+use parking_lot::Mutex;
+use std::time::Duration;
+
+/// One wired adapter on 192.168.1.20 that answers every ping in 20ms.
+pub struct MockNetworkAdapter;
+
+impl NetworkPort for MockNetworkAdapter {
+    fn get_network_details(&self) -> Result<NetworkDetails, BalamError> {
+        Ok(NetworkDetails {
+            adapters: vec![NetworkAdapterInfo {
+                name: "Ethernet".to_string(),
+                description: "Mock Ethernet Controller".to_string(),
+                kind: NetworkAdapterKind::Ethernet,
+                is_up: true,
+                link_speed_mbps: Some(1000),
+                mac_address: Some("00:11:22:33:44:55".to_string()),
+                ipv4_addresses: vec!["192.168.1.20".to_string()],
+                ipv6_addresses: Vec::new(),
+                gateways: vec!["192.168.1.1".to_string()],
+                dns_servers: vec!["192.168.1.1".to_string()],
+            }],
+            primary_adapter: Some("Ethernet".to_string()),
+            vpn_active: false,
+        })
+    }
+
+    fn ping(&self, _host: &str, _timeout: Duration) -> Result<Option<Duration>, BalamError> {
+        Ok(Some(Duration::from_millis(20)))
+    }
+}
+
+struct WiFiState {
+    connected: Option<String>,
+    saved: Vec<String>,
+}
+
+/// Two networks in range (`Home`, WPA2, and `Cafe`, open), none joined.
+pub struct MockWiFiAdapter {
+    state: Mutex<WiFiState>,
+}
+
+impl Default for MockWiFiAdapter {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(WiFiState {
+                connected: None,
+                saved: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl MockWiFiAdapter {
+    fn in_range(&self) -> Vec<WiFiNetwork> {
+        let connected = self.state.lock().connected.clone();
+        [("Home", -45, WiFiSecurity::WPA2), ("Cafe", -70, WiFiSecurity::Open)]
+            .into_iter()
+            .map(|(ssid, signal_strength, security)| WiFiNetwork {
+                ssid: ssid.to_string(),
+                bssid: None,
+                signal_strength,
+                frequency: 5000,
+                security,
+                is_connected: connected.as_deref() == Some(ssid),
+            })
+            .collect()
+    }
+}
+
+impl WiFiPort for MockWiFiAdapter {
+    fn get_current_network(&self) -> Result<Option<WiFiNetwork>, BalamError> {
+        Ok(self.in_range().into_iter().find(|n| n.is_connected))
+    }
+
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, BalamError> {
+        Ok(self.in_range())
+    }
+
+    fn connect_network(&self, config: WiFiConfig) -> Result<(), BalamError> {
+        if !self.in_range().iter().any(|n| n.ssid == config.ssid) {
+            return Err(BalamError::NotFound(format!("Network {}", config.ssid)));
+        }
+        let mut state = self.state.lock();
+        if config.auto_connect && !state.saved.contains(&config.ssid) {
+            state.saved.push(config.ssid.clone());
+        }
+        state.connected = Some(config.ssid);
+        Ok(())
+    }
+
+    fn disconnect(&self) -> Result<(), BalamError> {
+        self.state.lock().connected = None;
+        Ok(())
+    }
+
+    fn forget_network(&self, ssid: &str) -> Result<(), BalamError> {
+        let mut state = self.state.lock();
+        state.saved.retain(|saved| saved != ssid);
+        if state.connected.as_deref() == Some(ssid) {
+            state.connected = None;
+        }
+        Ok(())
+    }
+
+    fn get_saved_networks(&self) -> Result<Vec<String>, BalamError> {
+        Ok(self.state.lock().saved.clone())
+    }
+
+    fn get_signal_strength(&self) -> Result<Option<u32>, BalamError> {
+        Ok(self
+            .get_current_network()?
+            .map(|n| WiFiStrengthConfig::from_dbm(n.signal_strength)))
+    }
+}
+
+struct BluetoothState {
+    enabled: bool,
+    devices: Vec<BluetoothDevice>,
+}
+
+/// Radio with one controller in pairing mode nearby.
+pub struct MockBluetoothAdapter {
+    state: Mutex<BluetoothState>,
+}
+
+impl Default for MockBluetoothAdapter {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(BluetoothState {
+                enabled: true,
+                devices: vec![BluetoothDevice {
+                    name: "Wireless Controller".to_string(),
+                    address: "00:1A:7D:DA:71:13".to_string(),
+                    signal_strength: Some(-50),
+                    device_type: BluetoothDeviceType::Peripheral,
+                    pairing_state: BluetoothPairingState::Unpaired,
+                    is_connected: false,
+                    is_remembered: false,
+                    battery_percent: None,
+                    audio_profile: None,
+                }],
+            }),
+        }
+    }
+}
+
+impl MockBluetoothAdapter {
+    /// Runs `f` on the device, failing like the radio would.
+    fn with_device<T>(&self, address: &str, f: impl FnOnce(&mut BluetoothDevice) -> T) -> Result<T, BalamError> {
+        let mut state = self.state.lock();
+        if !state.enabled {
+            return Err(BalamError::BluetoothOff);
+        }
+        state
+            .devices
+            .iter_mut()
+            .find(|d| d.address == address)
+            .map(f)
+            .ok_or_else(|| BalamError::NotFound(format!("Bluetooth device {address}")))
+    }
+
+    fn devices(&self, filter: impl Fn(&BluetoothDevice) -> bool) -> Result<Vec<BluetoothDevice>, BalamError> {
+        let state = self.state.lock();
+        if !state.enabled {
+            return Err(BalamError::BluetoothOff);
+        }
+        Ok(state.devices.iter().filter(|d| filter(d)).cloned().collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl BluetoothPort for MockBluetoothAdapter {
+    async fn get_paired_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
+        self.devices(|d| d.pairing_state == BluetoothPairingState::Paired)
+    }
+
+    async fn scan_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
+        self.devices(|_| true)
+    }
+
+    async fn pair_device(
+        &self,
+        config: BluetoothPairingConfig,
+        _listener: Option<PairingListener>,
+    ) -> Result<(), BalamError> {
+        self.with_device(&config.address, |device| {
+            device.pairing_state = BluetoothPairingState::Paired;
+            device.is_remembered = true;
+            device.is_connected = true;
+        })
+    }
+
+    async fn respond_to_pairing(&self, request_id: u64, _accept: bool, _pin: Option<String>) -> Result<(), BalamError> {
+        Err(BalamError::NotFound(format!("Pairing request {request_id}")))
+    }
+
+    async fn unpair_device(&self, address: &str) -> Result<(), BalamError> {
+        self.with_device(address, |device| {
+            device.pairing_state = BluetoothPairingState::Unpaired;
+            device.is_remembered = false;
+            device.is_connected = false;
+        })
+    }
+
+    async fn connect_device(&self, address: &str) -> Result<(), BalamError> {
+        self.with_device(address, |device| device.is_connected = true)
+    }
+
+    async fn disconnect_device(&self, address: &str) -> Result<(), BalamError> {
+        self.with_device(address, |device| device.is_connected = false)
+    }
+
+    async fn get_device_details(&self, address: &str) -> Result<BluetoothDevice, BalamError> {
+        self.with_device(address, |device| BluetoothDevice {
+            battery_percent: Some(80),
+            ..device.clone()
+        })
+    }
+
+    async fn get_connected_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError> {
+        self.devices(|d| d.is_connected)
+    }
+
+    async fn is_bluetooth_available(&self) -> Result<bool, BalamError> {
+        Ok(true)
+    }
+
+    async fn set_bluetooth_enabled(&self, enabled: bool) -> Result<(), BalamError> {
+        let mut state = self.state.lock();
+        state.enabled = enabled;
+        if !enabled {
+            for device in &mut state.devices {
+                device.is_connected = false;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::domain::game_process::GameProcess;
+use crate::ports::GameManagementPort;
+use parking_lot::Mutex;
+
+/// Running game set by the test; closing it clears it.
+#[derive(Default)]
+pub struct MockGameAdapter {
+    current: Mutex<Option<GameProcess>>,
+    suspended: Mutex<Vec<u32>>,
+    foreground: Mutex<Option<u32>>,
+}
+
+impl MockGameAdapter {
+    /// Pretends `game` is running in the foreground.
+    pub fn set_current_game(&self, game: Option<GameProcess>) {
+        *self.current.lock() = game;
+    }
+
+    /// PIDs currently suspended.
+    #[must_use]
+    pub fn suspended(&self) -> Vec<u32> {
+        self.suspended.lock().clone()
+    }
+}
+
+impl GameManagementPort for MockGameAdapter {
+    fn get_current_game(&self) -> Result<Option<GameProcess>, String> {
+        Ok(self.current.lock().clone())
+    }
+
+    fn close_game(&self, pid: u32) -> Result<bool, String> {
+        let mut current = self.current.lock();
+        if current.as_ref().is_some_and(|game| game.pid == pid) {
+            *current = None;
+            Ok(true)
+        } else {
+            Err(format!("Process {pid} not found"))
+        }
+    }
+
+    fn is_process_responding(&self, pid: u32) -> Result<bool, String> {
+        Ok(!self.suspended.lock().contains(&pid))
+    }
+
+    fn suspend_game(&self, pid: u32) -> Result<(), String> {
+        let mut suspended = self.suspended.lock();
+        if !suspended.contains(&pid) {
+            suspended.push(pid);
+        }
+        Ok(())
+    }
+
+    fn resume_game(&self, pid: u32) -> Result<(), String> {
+        self.suspended.lock().retain(|p| *p != pid);
+        Ok(())
+    }
+
+    fn foreground_pid(&self) -> Option<u32> {
+        *self.foreground.lock()
+    }
+
+    fn focus_window(&self, pid: u32) -> Result<(), String> {
+        *self.foreground.lock() = Some(pid);
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+//! In-memory port implementations for tests (`mock-adapters` feature).
+//!
+//! They keep what was set (volume, brightness, connected network...) so a
+//! command can be called and its effect read back, and record power actions
+//! instead of performing them.
+mod connectivity;
+mod game;
+mod system;
+
+pub use connectivity::{MockBluetoothAdapter, MockNetworkAdapter, MockWiFiAdapter};
+pub use game::MockGameAdapter;
+pub use system::{MockDisplayAdapter, MockSystemAdapter};
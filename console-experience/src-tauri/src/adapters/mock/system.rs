@@ -0,0 +1,342 @@
+use crate::domain::display::{
+    BrightnessConfig, BrightnessControl, ColorProfile, DisplayInfo, DisplayMode, MonitorBounds, MonitorInfo,
+    RefreshRateConfig,
+};
+use crate::domain::BalamError;
+use crate::ports::display_port::DisplayPort;
+use crate::ports::system_port::{AudioDevice, AudioDeviceType, ConnectionType, SystemPort, SystemStatus};
+use parking_lot::Mutex;
+
+struct SystemState {
+    volume: u32,
+    outputs: Vec<AudioDevice>,
+    inputs: Vec<AudioDevice>,
+    mic_volume: u32,
+    mic_muted: bool,
+    power_actions: Vec<&'static str>,
+}
+
+/// Desktop with one speaker, one headset and one microphone.
+pub struct MockSystemAdapter {
+    state: Mutex<SystemState>,
+}
+
+fn device(id: &str, name: &str, device_type: AudioDeviceType, is_default: bool) -> AudioDevice {
+    AudioDevice {
+        id: id.to_string(),
+        name: name.to_string(),
+        device_type,
+        is_default,
+    }
+}
+
+fn set_default(devices: &mut [AudioDevice], device_id: &str) -> Result<(), BalamError> {
+    if !devices.iter().any(|d| d.id == device_id) {
+        return Err(BalamError::NotFound(format!("Audio device {device_id}")));
+    }
+    for device in devices {
+        device.is_default = device.id == device_id;
+    }
+    Ok(())
+}
+
+impl Default for MockSystemAdapter {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(SystemState {
+                volume: 50,
+                outputs: vec![
+                    device("speakers", "Speakers", AudioDeviceType::Speakers, true),
+                    device("headset", "Headset", AudioDeviceType::Headphones, false),
+                ],
+                inputs: vec![device("mic", "Microphone", AudioDeviceType::Microphone, true)],
+                mic_volume: 80,
+                mic_muted: false,
+                power_actions: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl MockSystemAdapter {
+    /// Power actions requested so far (`shutdown`, `restart`, `logout`, `suspend`).
+    #[must_use]
+    pub fn power_actions(&self) -> Vec<&'static str> {
+        self.state.lock().power_actions.clone()
+    }
+
+    fn record(&self, action: &'static str) -> Result<(), BalamError> {
+        self.state.lock().power_actions.push(action);
+        Ok(())
+    }
+}
+
+impl SystemPort for MockSystemAdapter {
+    fn get_status(&self) -> SystemStatus {
+        SystemStatus {
+            battery_level: None,
+            is_charging: false,
+            network_name: None,
+            volume: self.state.lock().volume,
+            connection_type: ConnectionType::Ethernet,
+        }
+    }
+
+    fn set_volume(&self, level: u32) -> Result<(), BalamError> {
+        if level > 100 {
+            return Err(BalamError::InvalidArgument(format!("Volume {level} exceeds 100")));
+        }
+        self.state.lock().volume = level;
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<(), BalamError> {
+        self.record("shutdown")
+    }
+
+    fn restart(&self) -> Result<(), BalamError> {
+        self.record("restart")
+    }
+
+    fn logout(&self) -> Result<(), BalamError> {
+        self.record("logout")
+    }
+
+    fn suspend(&self) -> Result<(), BalamError> {
+        self.record("suspend")
+    }
+
+    fn list_audio_devices(&self) -> Result<Vec<AudioDevice>, BalamError> {
+        Ok(self.state.lock().outputs.clone())
+    }
+
+    fn set_default_audio_device(&self, device_id: &str) -> Result<(), BalamError> {
+        set_default(&mut self.state.lock().outputs, device_id)
+    }
+
+    fn list_input_devices(&self) -> Result<Vec<AudioDevice>, BalamError> {
+        Ok(self.state.lock().inputs.clone())
+    }
+
+    fn set_default_input_device(&self, device_id: &str) -> Result<(), BalamError> {
+        set_default(&mut self.state.lock().inputs, device_id)
+    }
+
+    fn get_mic_volume(&self) -> Result<u32, BalamError> {
+        Ok(self.state.lock().mic_volume)
+    }
+
+    fn set_mic_volume(&self, level: u32) -> Result<(), BalamError> {
+        if level > 100 {
+            return Err(BalamError::InvalidArgument(format!("Volume {level} exceeds 100")));
+        }
+        self.state.lock().mic_volume = level;
+        Ok(())
+    }
+
+    fn is_mic_muted(&self) -> Result<bool, BalamError> {
+        Ok(self.state.lock().mic_muted)
+    }
+
+    fn set_mic_muted(&self, muted: bool) -> Result<(), BalamError> {
+        self.state.lock().mic_muted = muted;
+        Ok(())
+    }
+}
+
+struct DisplayState {
+    brightness: u32,
+    refresh_rate: u32,
+    resolution: (u32, u32),
+    color_profile: Option<ColorProfile>,
+}
+
+/// One 1920x1200 60/120/144 Hz panel (also 1280x800) with brightness control and no HDR.
+pub struct MockDisplayAdapter {
+    state: Mutex<DisplayState>,
+}
+
+impl Default for MockDisplayAdapter {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(DisplayState {
+                brightness: 70,
+                refresh_rate: 60,
+                resolution: (1920, 1200),
+                color_profile: None,
+            }),
+        }
+    }
+}
+
+impl MockDisplayAdapter {
+    const REFRESH_RATES: [u32; 3] = [60, 120, 144];
+    const RESOLUTIONS: [(u32, u32); 2] = [(1920, 1200), (1280, 800)];
+    const DEVICE_NAME: &'static str = r"\\.\DISPLAY1";
+
+    fn check_monitor(device_name: &str) -> Result<(), BalamError> {
+        if device_name == Self::DEVICE_NAME {
+            Ok(())
+        } else {
+            Err(BalamError::NotFound(format!("Monitor {device_name}")))
+        }
+    }
+
+    /// Last color profile applied.
+    #[must_use]
+    pub fn color_profile(&self) -> Option<ColorProfile> {
+        self.state.lock().color_profile
+    }
+}
+
+impl DisplayPort for MockDisplayAdapter {
+    fn get_brightness(&self) -> Result<Option<u32>, BalamError> {
+        Ok(Some(self.state.lock().brightness))
+    }
+
+    fn set_brightness(&self, config: BrightnessConfig) -> Result<(), BalamError> {
+        self.state.lock().brightness = config.level;
+        Ok(())
+    }
+
+    fn get_refresh_rate(&self) -> Result<u32, BalamError> {
+        Ok(self.state.lock().refresh_rate)
+    }
+
+    fn set_refresh_rate(&self, config: RefreshRateConfig) -> Result<(), BalamError> {
+        if !Self::REFRESH_RATES.contains(&config.hz) {
+            return Err(BalamError::InvalidArgument(format!(
+                "Unsupported refresh rate {}Hz",
+                config.hz
+            )));
+        }
+        self.state.lock().refresh_rate = config.hz;
+        Ok(())
+    }
+
+    fn get_supported_refresh_rates(&self) -> Result<Vec<u32>, BalamError> {
+        Ok(Self::REFRESH_RATES.to_vec())
+    }
+
+    fn get_monitors(&self) -> Result<Vec<MonitorInfo>, BalamError> {
+        let state = self.state.lock();
+        Ok(vec![MonitorInfo {
+            device_name: Self::DEVICE_NAME.to_string(),
+            name: "Built-in Display".to_string(),
+            is_primary: true,
+            is_internal: true,
+            bounds: MonitorBounds {
+                x: 0,
+                y: 0,
+                width: state.resolution.0,
+                height: state.resolution.1,
+            },
+            refresh_hz: state.refresh_rate,
+            brightness: BrightnessControl::Wmi,
+        }])
+    }
+
+    fn get_monitor_brightness(&self, device_name: &str) -> Result<Option<u32>, BalamError> {
+        Self::check_monitor(device_name)?;
+        self.get_brightness()
+    }
+
+    fn set_monitor_brightness(&self, device_name: &str, config: BrightnessConfig) -> Result<(), BalamError> {
+        Self::check_monitor(device_name)?;
+        self.set_brightness(config)
+    }
+
+    fn get_monitor_contrast(&self, device_name: &str) -> Result<Option<u32>, BalamError> {
+        Self::check_monitor(device_name)?;
+        Ok(None)
+    }
+
+    fn set_monitor_contrast(&self, device_name: &str, _level: u32) -> Result<(), BalamError> {
+        Self::check_monitor(device_name)?;
+        Err(BalamError::NotSupported(format!("Contrast control of {device_name}")))
+    }
+
+    fn get_monitor_refresh_rates(&self, device_name: &str) -> Result<Vec<u32>, BalamError> {
+        Self::check_monitor(device_name)?;
+        self.get_supported_refresh_rates()
+    }
+
+    fn set_monitor_refresh_rate(&self, device_name: &str, config: RefreshRateConfig) -> Result<(), BalamError> {
+        Self::check_monitor(device_name)?;
+        self.set_refresh_rate(config)
+    }
+
+    fn get_display_mode(&self) -> Result<DisplayMode, BalamError> {
+        let state = self.state.lock();
+        Ok(DisplayMode {
+            width: state.resolution.0,
+            height: state.resolution.1,
+            refresh_hz: state.refresh_rate,
+        })
+    }
+
+    fn get_display_modes(&self) -> Result<Vec<DisplayMode>, BalamError> {
+        Ok(Self::RESOLUTIONS
+            .iter()
+            .flat_map(|&(width, height)| {
+                Self::REFRESH_RATES.iter().map(move |&refresh_hz| DisplayMode {
+                    width,
+                    height,
+                    refresh_hz,
+                })
+            })
+            .collect())
+    }
+
+    fn set_display_mode(&self, mode: DisplayMode, _persist: bool) -> Result<(), BalamError> {
+        if !self.get_display_modes()?.contains(&mode) {
+            return Err(BalamError::InvalidArgument(format!(
+                "Unsupported display mode {}x{} @ {}Hz",
+                mode.width, mode.height, mode.refresh_hz
+            )));
+        }
+        let mut state = self.state.lock();
+        state.resolution = (mode.width, mode.height);
+        state.refresh_rate = mode.refresh_hz;
+        Ok(())
+    }
+
+    fn supports_brightness_control(&self) -> bool {
+        true
+    }
+
+    fn get_displays(&self) -> Result<Vec<DisplayInfo>, BalamError> {
+        Ok(self.get_primary_display().into_iter().collect())
+    }
+
+    fn get_primary_display(&self) -> Option<DisplayInfo> {
+        Some(DisplayInfo {
+            id: 0,
+            name: "Built-in Display".to_string(),
+            is_primary: true,
+            adapter_id: (0, 0),
+            hdr: None,
+        })
+    }
+
+    fn is_hdr_supported(&self, _display_id: u32) -> bool {
+        false
+    }
+
+    fn is_hdr_enabled(&self, _display_id: u32) -> bool {
+        false
+    }
+
+    fn set_hdr_enabled(&self, _display_id: u32, _enabled: bool) -> Result<(), BalamError> {
+        Err(BalamError::NotSupported("HDR on this display".to_string()))
+    }
+
+    fn set_color_profile(&self, profile: &ColorProfile) -> Result<(), BalamError> {
+        self.state.lock().color_profile = Some(*profile);
+        Ok(())
+    }
+
+    fn supports_vibrance(&self) -> bool {
+        false
+    }
+}
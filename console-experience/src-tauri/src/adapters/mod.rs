@@ -1,22 +1,51 @@
+pub mod autostart;
 pub mod battlenet_scanner;
 pub mod bluetooth;
+pub mod capture;
+pub mod console_mode;
+pub mod custom_scanner;
 pub mod display;
 pub mod epic_scanner;
+pub mod focus;
 pub mod fps_service;
 pub mod game;
 pub mod gamepad_adapter;
 pub mod haptic;
+pub mod hid_input;
+pub mod hotkeys;
+pub mod howlongtobeat_adapter;
 pub mod identity_engine;
+pub mod itch_scanner;
+pub mod launch_hooks;
+pub mod legendary_adapter;
 pub mod local_scanner;
 pub mod metadata_adapter;
 pub mod microsoft_store_adapter;
+#[cfg(any(test, feature = "mock-adapters"))]
+pub mod mock;
+pub mod network;
+pub mod notifications;
 pub mod overlay;
 pub mod performance;
 pub mod performance_monitoring;
+pub mod privilege;
+pub mod process_events;
 pub mod process_launcher;
 pub mod registry_scanner;
+pub mod remote;
+pub mod settings;
 pub mod steam_scanner;
+pub mod standalone_scanner;
+pub mod sunshine;
+pub mod system_events;
+pub mod telemetry;
+pub mod text_input;
+pub mod thermal;
+pub mod thumbnail_cache;
+pub mod update;
+pub mod weather;
 pub mod wifi;
 pub mod window_monitor;
 pub mod windows_system_adapter;
+pub mod xbox_manager;
 pub mod xbox_scanner;
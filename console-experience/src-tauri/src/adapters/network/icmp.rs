@@ -0,0 +1,65 @@
+use crate::domain::BalamError;
+use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs};
+use std::time::Duration;
+use windows::Win32::NetworkManagement::IpHelper::{IcmpCloseHandle, IcmpCreateFile, IcmpSendEcho, ICMP_ECHO_REPLY};
+
+/// `IP_SUCCESS` from ipexport.h.
+const IP_SUCCESS: u32 = 0;
+const PAYLOAD: &[u8] = b"balam-ping";
+
+/// First IPv4 address of `host` (literal or DNS name).
+pub(crate) fn resolve_ipv4(host: &str) -> Result<Ipv4Addr, BalamError> {
+    let host = host.trim();
+    if let Ok(ip) = host.parse::<Ipv4Addr>() {
+        return Ok(ip);
+    }
+    (host, 0)
+        .to_socket_addrs()
+        .ok()
+        .and_then(|mut addresses| {
+            addresses.find_map(|address| match address.ip() {
+                IpAddr::V4(ip) => Some(ip),
+                IpAddr::V6(_) => None,
+            })
+        })
+        .ok_or_else(|| BalamError::InvalidArgument(format!("'{host}' has no IPv4 address")))
+}
+
+/// One ICMP echo through `IcmpSendEcho` (no raw socket, so no admin rights).
+pub(crate) fn echo(ip: Ipv4Addr, timeout: Duration) -> Result<Option<Duration>, BalamError> {
+    let handle = unsafe { IcmpCreateFile() }.map_err(|e| BalamError::platform("Failed to open ICMP handle", e))?;
+
+    // Reply header + echoed payload + room for an ICMP error message
+    let mut reply = vec![0u8; std::mem::size_of::<ICMP_ECHO_REPLY>() + PAYLOAD.len() + 8];
+    let replies = unsafe {
+        IcmpSendEcho(
+            handle,
+            u32::from_ne_bytes(ip.octets()),
+            PAYLOAD.as_ptr().cast(),
+            PAYLOAD.len() as u16,
+            None,
+            reply.as_mut_ptr().cast(),
+            reply.len() as u32,
+            timeout.as_millis().min(u128::from(u32::MAX)) as u32,
+        )
+    };
+    let _ = unsafe { IcmpCloseHandle(handle) };
+
+    if replies == 0 {
+        // Timed out or unreachable
+        return Ok(None);
+    }
+    let reply = unsafe { reply.as_ptr().cast::<ICMP_ECHO_REPLY>().read_unaligned() };
+    Ok((reply.Status == IP_SUCCESS).then(|| Duration::from_millis(u64::from(reply.RoundTripTime))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_ipv4_literal() {
+        assert_eq!(resolve_ipv4(" 1.1.1.1 ").unwrap(), Ipv4Addr::new(1, 1, 1, 1));
+        assert!(matches!(resolve_ipv4("::1"), Err(BalamError::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,4 @@
+mod icmp;
+mod windows_network_adapter;
+
+pub use windows_network_adapter::WindowsNetworkAdapter;
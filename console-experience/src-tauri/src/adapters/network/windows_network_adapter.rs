@@ -0,0 +1,267 @@
+use super::icmp;
+use crate::domain::BalamError;
+use crate::ports::network_port::{NetworkAdapterInfo, NetworkAdapterKind, NetworkDetails, NetworkPort};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+use tracing::info;
+use windows::Win32::NetworkManagement::IpHelper::{
+    GetAdaptersAddresses, GAA_FLAG_INCLUDE_GATEWAYS, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_MULTICAST,
+    IP_ADAPTER_ADDRESSES_LH,
+};
+use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR_IN, SOCKADDR_IN6, SOCKET_ADDRESS};
+
+const ERROR_SUCCESS: u32 = 0;
+const ERROR_BUFFER_OVERFLOW: u32 = 111;
+
+/// `IF_TYPE_*` values from ipifcons.h.
+const IF_TYPE_ETHERNET_CSMACD: u32 = 6;
+const IF_TYPE_PPP: u32 = 23;
+const IF_TYPE_SOFTWARE_LOOPBACK: u32 = 24;
+const IF_TYPE_IEEE80211: u32 = 71;
+
+/// Driver descriptions of common VPN clients (they mostly register as
+/// Ethernet or virtual adapters, so the interface type alone is not enough).
+const VPN_MARKERS: &[&str] = &[
+    "vpn",
+    "wireguard",
+    "wintun",
+    "tap-windows",
+    "openvpn",
+    "tailscale",
+    "zerotier",
+    "anyconnect",
+    "fortinet",
+    "globalprotect",
+    "pangp",
+    "nordlynx",
+];
+
+/// Windows implementation of `NetworkPort` using `GetAdaptersAddresses`.
+pub struct WindowsNetworkAdapter;
+
+impl WindowsNetworkAdapter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Raw adapter list; the buffer is grown until it fits.
+    fn adapter_buffer() -> Result<Vec<u64>, BalamError> {
+        let flags = GAA_FLAG_INCLUDE_GATEWAYS | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_MULTICAST;
+        let mut size = 16 * 1024u32;
+        for _ in 0..3 {
+            // u64 elements keep the buffer aligned for IP_ADAPTER_ADDRESSES_LH
+            let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+            let result = unsafe {
+                GetAdaptersAddresses(
+                    u32::from(AF_UNSPEC.0),
+                    flags,
+                    None,
+                    Some(buffer.as_mut_ptr().cast::<IP_ADAPTER_ADDRESSES_LH>()),
+                    &mut size,
+                )
+            };
+            match result {
+                ERROR_SUCCESS => return Ok(buffer),
+                ERROR_BUFFER_OVERFLOW => continue,
+                code => {
+                    return Err(BalamError::Platform(format!(
+                        "GetAdaptersAddresses failed: error {code}"
+                    )));
+                },
+            }
+        }
+        Err(BalamError::Platform(
+            "GetAdaptersAddresses: adapter list kept growing".to_string(),
+        ))
+    }
+
+    /// Converts one entry of the adapter list.
+    ///
+    /// # Safety
+    /// `adapter` must point into a buffer filled by `GetAdaptersAddresses`.
+    unsafe fn convert_adapter(adapter: &IP_ADAPTER_ADDRESSES_LH) -> NetworkAdapterInfo {
+        let name = adapter.FriendlyName.to_string().unwrap_or_default();
+        let description = adapter.Description.to_string().unwrap_or_default();
+        let kind = classify_adapter(adapter.IfType, &description);
+
+        let mac_len = (adapter.PhysicalAddressLength as usize).min(adapter.PhysicalAddress.len());
+        let mac_address = (mac_len > 0).then(|| {
+            adapter.PhysicalAddress[..mac_len]
+                .iter()
+                .map(|byte| format!("{byte:02X}"))
+                .collect::<Vec<_>>()
+                .join(":")
+        });
+
+        let mut ipv4_addresses = Vec::new();
+        let mut ipv6_addresses = Vec::new();
+        let mut unicast = adapter.FirstUnicastAddress;
+        while let Some(entry) = unicast.as_ref() {
+            match socket_ip(&entry.Address) {
+                Some(IpAddr::V4(ip)) => ipv4_addresses.push(ip.to_string()),
+                Some(IpAddr::V6(ip)) => ipv6_addresses.push(ip.to_string()),
+                None => {},
+            }
+            unicast = entry.Next;
+        }
+
+        let mut gateways = Vec::new();
+        let mut gateway = adapter.FirstGatewayAddress;
+        while let Some(entry) = gateway.as_ref() {
+            gateways.extend(socket_ip(&entry.Address).map(|ip| ip.to_string()));
+            gateway = entry.Next;
+        }
+
+        let mut dns_servers = Vec::new();
+        let mut dns = adapter.FirstDnsServerAddress;
+        while let Some(entry) = dns.as_ref() {
+            dns_servers.extend(socket_ip(&entry.Address).map(|ip| ip.to_string()));
+            dns = entry.Next;
+        }
+
+        NetworkAdapterInfo {
+            name,
+            description,
+            kind,
+            is_up: adapter.OperStatus == IfOperStatusUp,
+            link_speed_mbps: link_speed_mbps(adapter.TransmitLinkSpeed),
+            mac_address,
+            ipv4_addresses,
+            ipv6_addresses,
+            gateways,
+            dns_servers,
+        }
+    }
+}
+
+impl Default for WindowsNetworkAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkPort for WindowsNetworkAdapter {
+    fn get_network_details(&self) -> Result<NetworkDetails, BalamError> {
+        let buffer = Self::adapter_buffer()?;
+
+        let mut adapters = Vec::new();
+        let mut primary: Option<(u32, String)> = None;
+        let mut current = buffer.as_ptr().cast::<IP_ADAPTER_ADDRESSES_LH>();
+        unsafe {
+            while let Some(adapter) = current.as_ref() {
+                current = adapter.Next;
+                if adapter.IfType == IF_TYPE_SOFTWARE_LOOPBACK {
+                    continue;
+                }
+
+                let info = Self::convert_adapter(adapter);
+                // Hide idle virtual plumbing (Hyper-V switches, Teredo...) but keep real NICs
+                if !info.is_up && info.kind == NetworkAdapterKind::Other {
+                    continue;
+                }
+                if info.is_up
+                    && !info.gateways.is_empty()
+                    && primary.as_ref().is_none_or(|(metric, _)| adapter.Ipv4Metric < *metric)
+                {
+                    primary = Some((adapter.Ipv4Metric, info.name.clone()));
+                }
+                adapters.push(info);
+            }
+        }
+
+        let vpn_active = adapters
+            .iter()
+            .any(|adapter| adapter.kind == NetworkAdapterKind::Vpn && adapter.is_up);
+        info!(
+            "🌐 {} network adapter(s), primary: {:?}, VPN: {}",
+            adapters.len(),
+            primary.as_ref().map(|(_, name)| name),
+            vpn_active
+        );
+        Ok(NetworkDetails {
+            adapters,
+            primary_adapter: primary.map(|(_, name)| name),
+            vpn_active,
+        })
+    }
+
+    fn ping(&self, host: &str, timeout: Duration) -> Result<Option<Duration>, BalamError> {
+        icmp::echo(icmp::resolve_ipv4(host)?, timeout)
+    }
+}
+
+/// Adapter kind from its interface type and driver description.
+fn classify_adapter(if_type: u32, description: &str) -> NetworkAdapterKind {
+    let description = description.to_lowercase();
+    if if_type == IF_TYPE_PPP || VPN_MARKERS.iter().any(|marker| description.contains(marker)) {
+        return NetworkAdapterKind::Vpn;
+    }
+    match if_type {
+        IF_TYPE_IEEE80211 => NetworkAdapterKind::WiFi,
+        // Hyper-V / VirtualBox / WSL switches report as Ethernet too
+        IF_TYPE_ETHERNET_CSMACD if !description.contains("virtual") && !description.contains("hyper-v") => {
+            NetworkAdapterKind::Ethernet
+        },
+        _ => NetworkAdapterKind::Other,
+    }
+}
+
+/// Bits per second → Mbps (`u64::MAX` means unknown).
+fn link_speed_mbps(bits_per_second: u64) -> Option<u64> {
+    (bits_per_second != 0 && bits_per_second != u64::MAX).then_some(bits_per_second / 1_000_000)
+}
+
+/// Reads the IP out of a `SOCKET_ADDRESS`.
+///
+/// # Safety
+/// `address.lpSockaddr` must be null or point to a valid sockaddr of its family.
+unsafe fn socket_ip(address: &SOCKET_ADDRESS) -> Option<IpAddr> {
+    let sockaddr = address.lpSockaddr.as_ref()?;
+    if sockaddr.sa_family == AF_INET {
+        let ipv4 = &*address.lpSockaddr.cast::<SOCKADDR_IN>();
+        Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(ipv4.sin_addr.S_un.S_addr))))
+    } else if sockaddr.sa_family == AF_INET6 {
+        let ipv6 = &*address.lpSockaddr.cast::<SOCKADDR_IN6>();
+        Some(IpAddr::V6(Ipv6Addr::from(ipv6.sin6_addr.u.Byte)))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_adapter() {
+        assert_eq!(
+            classify_adapter(IF_TYPE_ETHERNET_CSMACD, "Realtek PCIe 2.5GbE Family Controller"),
+            NetworkAdapterKind::Ethernet
+        );
+        assert_eq!(
+            classify_adapter(IF_TYPE_IEEE80211, "Intel(R) Wi-Fi 6E AX211 160MHz"),
+            NetworkAdapterKind::WiFi
+        );
+        assert_eq!(
+            // IF_TYPE_PROP_VIRTUAL
+            classify_adapter(53, "WireGuard Tunnel"),
+            NetworkAdapterKind::Vpn
+        );
+        assert_eq!(
+            classify_adapter(IF_TYPE_ETHERNET_CSMACD, "TAP-Windows Adapter V9"),
+            NetworkAdapterKind::Vpn
+        );
+        assert_eq!(
+            classify_adapter(IF_TYPE_ETHERNET_CSMACD, "Hyper-V Virtual Ethernet Adapter"),
+            NetworkAdapterKind::Other
+        );
+    }
+
+    #[test]
+    fn test_link_speed() {
+        assert_eq!(link_speed_mbps(1_000_000_000), Some(1000));
+        assert_eq!(link_speed_mbps(u64::MAX), None);
+    }
+}
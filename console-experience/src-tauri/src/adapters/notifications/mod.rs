@@ -0,0 +1,4 @@
+mod notification_router;
+mod windows_toast;
+
+pub use notification_router::NotificationRouter;
@@ -0,0 +1,158 @@
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use tauri::{AppHandle, Emitter};
+use tracing::{debug, info, warn};
+use windows::Win32::UI::Shell::{
+    SHQueryUserNotificationState, QUNS_BUSY, QUNS_PRESENTATION_MODE, QUNS_RUNNING_D3D_FULL_SCREEN,
+};
+
+use super::windows_toast::show_toast;
+use crate::adapters::game::WindowsGameAdapter;
+use crate::domain::notification::{Notification, NotificationDelivery};
+use crate::ports::notification_port::NotificationPort;
+
+/// Notifications kept for the in-app notification center.
+const HISTORY_CAPACITY: usize = 50;
+
+/// Payload of the `notification` event.
+#[derive(Serialize)]
+struct NotificationEvent<'a> {
+    #[serde(flatten)]
+    notification: &'a Notification,
+    delivery: NotificationDelivery,
+}
+
+/// Routes notifications to Windows toasts and to the Balam UI/overlay.
+///
+/// # Channels
+/// - **Toast:** `ToastNotificationManager` (see `windows_toast`)
+/// - **Overlay:** `notification` event to every webview; the main window and
+///   the in-game overlay show a banner when `delivery.overlay` is set
+///
+/// # Do-Not-Disturb
+/// Active while another app runs fullscreen (`SHQueryUserNotificationState`
+/// reports busy/D3D fullscreen/presentation), excluding Balam's own window.
+///
+/// Created before Tauri starts (DI container); events are only emitted once
+/// `attach` has been called in `setup`.
+pub struct NotificationRouter {
+    app_handle: OnceLock<AppHandle>,
+    history: Mutex<VecDeque<Notification>>,
+    next_id: AtomicU64,
+}
+
+impl NotificationRouter {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            app_handle: OnceLock::new(),
+            history: Mutex::new(VecDeque::with_capacity(HISTORY_CAPACITY)),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Connects the router to the running app (enables overlay events).
+    pub fn attach(&self, app_handle: AppHandle) {
+        if self.app_handle.set(app_handle).is_err() {
+            debug!("Notification router already attached");
+        }
+    }
+}
+
+impl Default for NotificationRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationPort for NotificationRouter {
+    fn notify(&self, mut notification: Notification) -> NotificationDelivery {
+        notification.id = self.next_id.fetch_add(1, Ordering::Relaxed);
+
+        let do_not_disturb = self.is_do_not_disturb();
+        let delivery = NotificationDelivery::route(notification.priority, do_not_disturb);
+        info!(
+            "🔔 Notification #{} [{:?}] {} (toast: {}, overlay: {}, dnd: {})",
+            notification.id,
+            notification.priority,
+            notification.title,
+            delivery.toast,
+            delivery.overlay,
+            do_not_disturb
+        );
+
+        if delivery.toast {
+            if let Err(e) = show_toast(&notification) {
+                warn!("Toast failed: {}", e);
+            }
+        }
+
+        // Always emitted so the notification center stays in sync; the UI only
+        // shows a banner when delivery.overlay is set
+        if let Some(app_handle) = self.app_handle.get() {
+            let event = NotificationEvent {
+                notification: &notification,
+                delivery,
+            };
+            if let Err(e) = app_handle.emit("notification", &event) {
+                warn!("Failed to emit notification event: {}", e);
+            }
+        }
+
+        let mut history = self.history.lock();
+        if history.len() == HISTORY_CAPACITY {
+            history.pop_back();
+        }
+        history.push_front(notification);
+
+        delivery
+    }
+
+    fn is_do_not_disturb(&self) -> bool {
+        let Ok(state) = (unsafe { SHQueryUserNotificationState() }) else {
+            return false;
+        };
+        let fullscreen = state == QUNS_BUSY || state == QUNS_RUNNING_D3D_FULL_SCREEN || state == QUNS_PRESENTATION_MODE;
+
+        // Balam's own UI is fullscreen too
+        fullscreen && WindowsGameAdapter::new().foreground_pid() != Some(std::process::id())
+    }
+
+    fn history(&self) -> Vec<Notification> {
+        self.history.lock().iter().cloned().collect()
+    }
+
+    fn clear_history(&self) {
+        self.history.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::notification::{NotificationCategory, NotificationPriority};
+
+    #[test]
+    fn test_history_is_bounded_and_newest_first() {
+        let router = NotificationRouter::new();
+        for i in 0..(HISTORY_CAPACITY + 5) {
+            router.notify(Notification::new(
+                NotificationCategory::System,
+                NotificationPriority::Low,
+                format!("n{i}"),
+                "",
+            ));
+        }
+
+        let history = router.history();
+        assert_eq!(history.len(), HISTORY_CAPACITY);
+        assert_eq!(history[0].title, format!("n{}", HISTORY_CAPACITY + 4));
+        assert!(history[0].id > history[1].id);
+
+        router.clear_history();
+        assert!(router.history().is_empty());
+    }
+}
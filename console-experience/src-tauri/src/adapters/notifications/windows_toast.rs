@@ -0,0 +1,75 @@
+use windows::core::HSTRING;
+use windows::Data::Xml::Dom::XmlDocument;
+use windows::UI::Notifications::{ToastNotification, ToastNotificationManager};
+
+use crate::domain::notification::{Notification, NotificationPriority};
+use crate::infrastructure::encoding::escape_xml;
+
+/// App User Model ID registered by the installer's Start Menu shortcut
+/// (the Tauri bundle identifier). Unpackaged apps need it to show toasts.
+const APP_USER_MODEL_ID: &str = "com.console.experience";
+
+/// Shows a notification as a Windows toast.
+///
+/// # Errors
+/// Returns error if the toast XML cannot be loaded or the notifier is
+/// unavailable (e.g. the app was started without its Start Menu shortcut).
+pub fn show_toast(notification: &Notification) -> Result<(), String> {
+    let xml = XmlDocument::new().map_err(|e| format!("Failed to create toast XML: {e}"))?;
+    xml.LoadXml(&HSTRING::from(build_toast_xml(notification)))
+        .map_err(|e| format!("Invalid toast XML: {e}"))?;
+
+    let toast = ToastNotification::CreateToastNotification(&xml).map_err(|e| format!("Failed to create toast: {e}"))?;
+    ToastNotificationManager::CreateToastNotifierWithId(&HSTRING::from(APP_USER_MODEL_ID))
+        .and_then(|notifier| notifier.Show(&toast))
+        .map_err(|e| format!("Failed to show toast: {e}"))
+}
+
+fn build_toast_xml(notification: &Notification) -> String {
+    // Low priority toasts stay silent; critical ones stay on screen until dismissed
+    let audio = if notification.priority == NotificationPriority::Low {
+        r#"<audio silent="true"/>"#
+    } else {
+        ""
+    };
+    let duration = if notification.priority == NotificationPriority::Critical {
+        r#" duration="long""#
+    } else {
+        ""
+    };
+
+    format!(
+        r#"<toast{duration}><visual><binding template="ToastGeneric"><text>{}</text><text>{}</text></binding></visual>{audio}</toast>"#,
+        escape_xml(&notification.title),
+        escape_xml(&notification.body),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::notification::NotificationCategory;
+
+    #[test]
+    fn test_build_toast_xml_escapes_text() {
+        let notification = Notification::new(
+            NotificationCategory::GameLaunch,
+            NotificationPriority::High,
+            "No se pudo iniciar <Halo>",
+            "Tom & Jerry",
+        );
+        let xml = build_toast_xml(&notification);
+        assert!(xml.contains("<text>No se pudo iniciar &lt;Halo&gt;</text>"));
+        assert!(xml.contains("<text>Tom &amp; Jerry</text>"));
+        assert!(!xml.contains("silent"));
+    }
+
+    #[test]
+    fn test_build_toast_xml_priority_options() {
+        let low = Notification::new(NotificationCategory::System, NotificationPriority::Low, "a", "b");
+        assert!(build_toast_xml(&low).contains(r#"<audio silent="true"/>"#));
+
+        let critical = Notification::new(NotificationCategory::Battery, NotificationPriority::Critical, "a", "b");
+        assert!(build_toast_xml(&critical).starts_with(r#"<toast duration="long">"#));
+    }
+}
@@ -0,0 +1,96 @@
+/// Overlay Conflicts - finds overlays that compete with Balam's injection
+///
+/// Reads the running processes, the Game Bar registry switches and RTSS'
+/// global profile. Game Bar can be turned off and on again from here:
+/// - `HKCU\Software\Microsoft\GameBar\UseNexusForGameBarEnabled` (Win+G)
+/// - `HKCU\Software\Microsoft\Windows\CurrentVersion\GameDVR\AppCaptureEnabled`
+/// - `HKCU\System\GameConfigStore\GameDVR_Enabled` (background capture)
+use std::fs;
+use std::path::Path;
+use tracing::info;
+use winreg::enums::{HKEY_CURRENT_USER, KEY_READ};
+use winreg::RegKey;
+
+use crate::application::services::ProcessInspectionService;
+use crate::domain::overlay_conflict::OverlayEnvironment;
+use crate::domain::BalamError;
+
+/// `(key, value)` pairs that together enable Game Bar.
+const GAME_BAR_SWITCHES: [(&str, &str); 3] = [
+    (r"Software\Microsoft\GameBar", "UseNexusForGameBarEnabled"),
+    (
+        r"Software\Microsoft\Windows\CurrentVersion\GameDVR",
+        "AppCaptureEnabled",
+    ),
+    (r"System\GameConfigStore", "GameDVR_Enabled"),
+];
+
+/// Scans the system for conflicting overlays.
+pub struct OverlayConflictScanner;
+
+impl OverlayConflictScanner {
+    /// Processes, Game Bar state and RTSS hooking, as seen right now.
+    #[must_use]
+    pub fn environment(processes: &ProcessInspectionService) -> OverlayEnvironment {
+        let processes = processes.processes();
+
+        let rtss_hooking = processes
+            .iter()
+            .find(|process| process.name.eq_ignore_ascii_case("rtss.exe"))
+            .and_then(|process| process.exe.as_deref())
+            .and_then(Path::parent)
+            .and_then(|dir| fs::read_to_string(dir.join("Profiles").join("Global")).ok())
+            .and_then(|profile| ini_flag(&profile, "Hooking", "EnableHooking"));
+
+        OverlayEnvironment {
+            processes: processes.iter().map(|process| process.name.to_lowercase()).collect(),
+            game_bar_enabled: Self::game_bar_enabled(),
+            rtss_hooking,
+        }
+    }
+
+    /// Whether any Game Bar switch is on (Win+G defaults to on when unset).
+    #[must_use]
+    pub fn game_bar_enabled() -> bool {
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        GAME_BAR_SWITCHES.iter().enumerate().any(|(index, (path, value))| {
+            hkcu.open_subkey_with_flags(path, KEY_READ)
+                .and_then(|key| key.get_value::<u32, _>(value))
+                .map_or(index == 0, |enabled| enabled != 0)
+        })
+    }
+
+    /// Turns Game Bar (overlay and background capture) off or back on.
+    ///
+    /// # Errors
+    /// Returns error if a registry value cannot be written.
+    pub fn set_game_bar_enabled(enabled: bool) -> Result<(), BalamError> {
+        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+        for (path, value) in GAME_BAR_SWITCHES {
+            let (key, _) = hkcu
+                .create_subkey(path)
+                .map_err(|e| BalamError::platform(&format!("Failed to open {path}"), e))?;
+            key.set_value(value, &u32::from(enabled))
+                .map_err(|e| BalamError::platform(&format!("Failed to write {value}"), e))?;
+        }
+        info!("🎮 Xbox Game Bar {}", if enabled { "enabled" } else { "disabled" });
+        Ok(())
+    }
+}
+
+/// Value of `key` in `[section]` of an INI file, as a 0/1 flag.
+fn ini_flag(content: &str, section: &str, key: &str) -> Option<bool> {
+    let mut in_section = false;
+    for line in content.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+            in_section = name.eq_ignore_ascii_case(section);
+        } else if in_section {
+            if let Some((name, value)) = line.split_once('=') {
+                if name.trim().eq_ignore_ascii_case(key) {
+                    return Some(value.trim() != "0");
+                }
+            }
+        }
+    }
+    None
+}
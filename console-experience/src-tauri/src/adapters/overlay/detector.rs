@@ -4,6 +4,21 @@
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+/// Swapchain present mode reported by the FPS service (DxgKrnl ETW events)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PresentMode {
+    /// No present seen yet, or an FPS service without present mode support
+    #[default]
+    Unknown,
+    /// Flip model or blit, composed by DWM
+    Composed,
+    /// Flip model scanned out directly (independent flip / MPO)
+    HardwareFlip,
+    /// Legacy flip: exclusive fullscreen
+    FullscreenExclusive,
+}
+
 /// Game information (mirrors fps-service GameState)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameInfo {
@@ -12,6 +27,29 @@ pub struct GameInfo {
     pub dx_version: u32,
     pub has_fso: bool,
     pub is_compatible_topmost: bool,
+    #[serde(default)]
+    pub present_mode: PresentMode,
+    /// Sync interval of the game's last Present() (0 = no VSync), if reported
+    #[serde(default)]
+    pub sync_interval: Option<u32>,
+    /// The game presents with DXGI_PRESENT_ALLOW_TEARING
+    #[serde(default)]
+    pub allow_tearing: bool,
+}
+
+impl GameInfo {
+    /// Whether a TOPMOST window shows above the game.
+    ///
+    /// The present mode decides when the FPS service reports one; before the
+    /// first present the DirectX version / FSO guess stands in.
+    #[must_use]
+    pub fn topmost_visible(&self) -> bool {
+        match self.present_mode {
+            PresentMode::Composed | PresentMode::HardwareFlip => true,
+            PresentMode::FullscreenExclusive => false,
+            PresentMode::Unknown => self.is_compatible_topmost,
+        }
+    }
 }
 
 /// FPS service response structure
@@ -29,6 +67,12 @@ struct GameState {
     pub dx_version: u32,
     pub has_fso: bool,
     pub is_compatible_topmost: bool,
+    #[serde(default)]
+    pub present_mode: PresentMode,
+    #[serde(default)]
+    pub sync_interval: Option<u32>,
+    #[serde(default)]
+    pub allow_tearing: bool,
 }
 
 /// Get game info from FPS service via IPC
@@ -36,6 +80,19 @@ struct GameState {
 /// Reads from Named Pipe: \\.\pipe\BalamFps
 /// Returns None if no game is running
 pub fn get_game_info_from_fps_service() -> Result<Option<GameInfo>, String> {
+    Ok(read_fps_service()?.1)
+}
+
+/// Current FPS and game info from the FPS service, read in one request
+/// (so both describe the same game).
+///
+/// # Errors
+/// Returns error if the FPS service is not running or sent an invalid response.
+pub fn get_fps_snapshot_from_fps_service() -> Result<(f32, Option<GameInfo>), String> {
+    read_fps_service()
+}
+
+fn read_fps_service() -> Result<(f32, Option<GameInfo>), String> {
     use windows::Win32::Foundation::{CloseHandle, GENERIC_READ, INVALID_HANDLE_VALUE};
     use windows::Win32::Storage::FileSystem::{
         CreateFileW, ReadFile, FILE_ATTRIBUTE_NORMAL, FILE_SHARE_NONE, OPEN_EXISTING,
@@ -76,13 +133,17 @@ pub fn get_game_info_from_fps_service() -> Result<Option<GameInfo>, String> {
         let data: FpsData = serde_json::from_str(&json_str).map_err(|e| format!("Failed to parse JSON: {}", e))?;
 
         // Convert to GameInfo
-        Ok(data.game_state.map(|state| GameInfo {
+        let game_info = data.game_state.map(|state| GameInfo {
             pid: state.pid,
             name: state.name,
             dx_version: state.dx_version,
             has_fso: state.has_fso,
             is_compatible_topmost: state.is_compatible_topmost,
-        }))
+            present_mode: state.present_mode,
+            sync_interval: state.sync_interval,
+            allow_tearing: state.allow_tearing,
+        });
+        Ok((data.fps, game_info))
     }
 }
 
@@ -312,11 +312,11 @@ impl OverlayStrategy for DllOverlay {
     /// Check if compatible with given game
     ///
     /// DLL injection works with:
-    /// - DX9 games
-    /// - DX11 games without FSO
+    /// - Exclusive fullscreen games
+    /// - Without a present mode: DX9 games and DX11 games without FSO
     /// - Must be whitelisted for safety
     fn is_compatible(&self, game: &GameInfo) -> bool {
-        !game.is_compatible_topmost && Self::is_whitelisted(&game.name)
+        !game.topmost_visible() && Self::is_whitelisted(&game.name)
     }
 
     /// Get overlay type identifier
@@ -344,6 +344,7 @@ pub fn get_whitelist() -> &'static [&'static str] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::overlay::detector::PresentMode;
 
     #[test]
     fn test_whitelist_check() {
@@ -369,6 +370,9 @@ mod tests {
             dx_version: 9,
             has_fso: false,
             is_compatible_topmost: false,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         assert!(overlay.is_compatible(&dx9_game));
 
@@ -379,6 +383,9 @@ mod tests {
             dx_version: 9,
             has_fso: false,
             is_compatible_topmost: false,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         assert!(!overlay.is_compatible(&dx9_non_whitelisted));
 
@@ -389,6 +396,9 @@ mod tests {
             dx_version: 12,
             has_fso: true,
             is_compatible_topmost: true,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         assert!(!overlay.is_compatible(&dx12_game));
     }
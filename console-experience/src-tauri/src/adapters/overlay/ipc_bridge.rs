@@ -3,10 +3,95 @@
 /// Uses shared memory for high-performance IPC.
 /// DLL reads overlay state and writes input events.
 ///
-/// # Implementation (Fase 5)
-/// - Shared memory setup
-/// - Overlay state synchronization
-/// - Input event handling
-// Placeholder for Fase 5
-#[allow(dead_code)]
-pub struct IpcBridge {}
+/// # Implementation
+/// - Overlay state: Balam owns `Local\BalamOverlayState` and writes the HUD
+///   level into it; the injected DLL polls it while rendering
+/// - Input event handling (Fase 5)
+use parking_lot::Mutex;
+use windows::core::w;
+use windows::Win32::Foundation::{CloseHandle, HANDLE, INVALID_HANDLE_VALUE};
+use windows::Win32::System::Memory::{
+    CreateFileMappingW, MapViewOfFile, UnmapViewOfFile, FILE_MAP_ALL_ACCESS, MEMORY_MAPPED_VIEW_ADDRESS, PAGE_READWRITE,
+};
+
+use crate::domain::overlay_layout::OverlayLevel;
+use crate::domain::BalamError;
+
+/// Marks an initialized state block (must match the DLL).
+const OVERLAY_STATE_MAGIC: u32 = 0xBA1A_0001;
+
+/// Shared overlay state (must match the DLL's `OverlayState`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct OverlayState {
+    magic: u32,
+    level: u32,
+}
+
+/// The mapping lives as long as Balam: closing the last handle would drop it.
+static BRIDGE: Mutex<Option<IpcBridge>> = Mutex::new(None);
+
+/// Balam's side of the shared overlay state.
+pub struct IpcBridge {
+    mapping: HANDLE,
+    view: MEMORY_MAPPED_VIEW_ADDRESS,
+}
+
+// SAFETY: the view is only written through `BRIDGE`'s lock.
+unsafe impl Send for IpcBridge {}
+
+impl IpcBridge {
+    /// Publishes the HUD level for injected overlays, creating the shared
+    /// memory on first use.
+    ///
+    /// # Errors
+    /// Returns error if the shared memory cannot be created or mapped.
+    pub fn publish_overlay_level(level: OverlayLevel) -> Result<(), BalamError> {
+        let mut bridge = BRIDGE.lock();
+        if bridge.is_none() {
+            *bridge = Some(Self::create()?);
+        }
+        if let Some(bridge) = bridge.as_ref() {
+            let state = OverlayState {
+                magic: OVERLAY_STATE_MAGIC,
+                level: u32::from(u8::from(level)),
+            };
+            // SAFETY: the view spans one `OverlayState`
+            unsafe { bridge.view.Value.cast::<OverlayState>().write_volatile(state) };
+        }
+        Ok(())
+    }
+
+    fn create() -> Result<Self, BalamError> {
+        // SAFETY: a pagefile-backed mapping sized for one `OverlayState`,
+        // unmapped and closed on drop.
+        unsafe {
+            let mapping = CreateFileMappingW(
+                INVALID_HANDLE_VALUE,
+                None,
+                PAGE_READWRITE,
+                0,
+                std::mem::size_of::<OverlayState>() as u32,
+                w!("Local\\BalamOverlayState"),
+            )
+            .map_err(|e| BalamError::platform("Failed to create overlay state memory", e))?;
+            let view = MapViewOfFile(mapping, FILE_MAP_ALL_ACCESS, 0, 0, std::mem::size_of::<OverlayState>());
+            if view.Value.is_null() {
+                let error = windows::core::Error::from_win32();
+                let _ = CloseHandle(mapping);
+                return Err(BalamError::platform("Failed to map overlay state memory", error));
+            }
+            Ok(Self { mapping, view })
+        }
+    }
+}
+
+impl Drop for IpcBridge {
+    fn drop(&mut self) {
+        // SAFETY: both were created by `create` and are released once
+        unsafe {
+            let _ = UnmapViewOfFile(self.view);
+            let _ = CloseHandle(self.mapping);
+        }
+    }
+}
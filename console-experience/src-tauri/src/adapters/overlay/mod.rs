@@ -1,23 +1,32 @@
+pub mod conflicts;
 pub mod detector;
 pub mod dll_overlay;
 pub mod ipc_bridge;
+pub mod rtss;
+pub mod rtss_limiter;
+pub mod rtss_overlay;
 /// Overlay Module - Strategy pattern for game overlays
 ///
 /// Provides unified interface for different overlay methods:
-/// - TOPMOST window (for modern games with FSO)
-/// - DLL injection (for legacy games without FSO)
+/// - TOPMOST window (for composed and hardware flip swapchains)
+/// - RTSS OSD (for exclusive fullscreen, while RTSS runs)
+/// - DLL injection (for exclusive fullscreen or legacy games without FSO)
 ///
 /// # Architecture
 /// ```
 /// GameInfo → select_strategy() → OverlayMethod
 ///                                      ↓
-///                           ┌──────────┴──────────┐
-///                           ↓                     ↓
-///                    TopMostOverlay        DllOverlay
+///                   ┌──────────────────┼──────────────────┐
+///                   ↓                  ↓                  ↓
+///            TopMostOverlay       RtssOverlay        DllOverlay
 /// ```
 pub mod strategy;
 pub mod topmost_overlay;
 
 // Re-export main APIs
-pub use detector::{get_game_info_from_fps_service, GameInfo};
-pub use strategy::{select_strategy, OverlayMethod, OverlayStrategy, OverlayType};
+pub use conflicts::OverlayConflictScanner;
+pub use detector::{get_fps_snapshot_from_fps_service, get_game_info_from_fps_service, GameInfo, PresentMode};
+pub use ipc_bridge::IpcBridge;
+pub use rtss::RtssOsd;
+pub use rtss_limiter::RtssFrameLimiter;
+pub use strategy::{choose_overlay_type, select_strategy, OverlayMethod, OverlayStrategy, OverlayType, StrategyChoice};
@@ -0,0 +1,170 @@
+/// RTSS OSD - shows Balam's HUD text through RivaTuner Statistics Server
+///
+/// Games already hooked by RTSS can show the HUD without Balam injecting
+/// anything. Text is written to an OSD slot of RTSS' shared memory
+/// (`RTSSSharedMemoryV2`), owned by `Balam`; RTSS renders every slot.
+///
+/// Layout of the shared memory (RTSS SDK, `RTSSSharedMemory.h`):
+/// - Header: signature `RTSS`, version, entry sizes and array offsets, OSD frame counter
+/// - OSD entry: `szOSD[256]`, `szOSDOwner[256]`, `szOSDEx[4096]` (v2.7+)
+use std::ffi::c_void;
+use std::fmt::Write as _;
+use windows::core::w;
+use windows::Win32::Foundation::{CloseHandle, FALSE};
+use windows::Win32::System::Memory::{
+    MapViewOfFile, OpenFileMappingW, UnmapViewOfFile, FILE_MAP_ALL_ACCESS, FILE_MAP_READ,
+};
+
+use crate::domain::overlay_layout::{OverlayCorner, OverlayLayout};
+use crate::domain::BalamError;
+
+const SIGNATURE: u32 = u32::from_be_bytes(*b"RTSS");
+const MIN_VERSION: u32 = 0x0002_0000;
+/// First version with the 4 KB `szOSDEx` buffer
+const OSD_EX_VERSION: u32 = 0x0002_0007;
+const OWNER: &[u8] = b"Balam";
+const OSD_LEN: usize = 256;
+const OSD_OWNER_OFFSET: usize = 256;
+const OSD_EX_OFFSET: usize = 512;
+const OSD_EX_LEN: usize = 4096;
+/// Margin from the screen edge, in pixels
+const MARGIN: i32 = 8;
+/// RTSS color tag for warnings (red)
+const WARNING_COLOR: &str = "<C=FF4040>";
+
+/// Header fields, as `u32` indexes into the mapping.
+mod header {
+    pub const SIGNATURE: usize = 0;
+    pub const VERSION: usize = 1;
+    pub const OSD_ENTRY_SIZE: usize = 5;
+    pub const OSD_ARR_OFFSET: usize = 6;
+    pub const OSD_ARR_SIZE: usize = 7;
+    pub const OSD_FRAME: usize = 8;
+}
+
+/// Writer for Balam's RTSS OSD slot.
+pub struct RtssOsd;
+
+impl RtssOsd {
+    /// Whether RTSS is running (its shared memory exists).
+    #[must_use]
+    pub fn is_running() -> bool {
+        // SAFETY: the handle is closed right away
+        unsafe {
+            OpenFileMappingW(FILE_MAP_READ.0, FALSE, w!("RTSSSharedMemoryV2"))
+                .map(|mapping| {
+                    let _ = CloseHandle(mapping);
+                })
+                .is_ok()
+        }
+    }
+
+    /// OSD text for `lines` laid out per `layout`, followed by `warnings` in red.
+    ///
+    /// Starts with an RTSS position tag: negative coordinates anchor to the
+    /// right or bottom edge.
+    #[must_use]
+    pub fn format(layout: &OverlayLayout, lines: &[String], warnings: &[&str]) -> String {
+        let (x, y) = match layout.corner {
+            OverlayCorner::TopLeft => (MARGIN, MARGIN),
+            OverlayCorner::TopRight => (-MARGIN, MARGIN),
+            OverlayCorner::BottomLeft => (MARGIN, -MARGIN),
+            OverlayCorner::BottomRight => (-MARGIN, -MARGIN),
+        };
+        let mut text = format!("<P={x},{y}>{}", lines.join("\n"));
+        for warning in warnings {
+            let _ = write!(text, "\n{WARNING_COLOR}{warning}<C>");
+        }
+        text
+    }
+
+    /// Shows `text` (empty hides Balam's slot).
+    ///
+    /// Returns `false` when RTSS is not running.
+    ///
+    /// # Errors
+    /// Returns error if RTSS' shared memory is too old or has no free OSD slot.
+    pub fn update(text: &str) -> Result<bool, BalamError> {
+        // SAFETY: the view is only accessed within the bounds RTSS declares in
+        // its header and unmapped before the handle is closed.
+        unsafe {
+            let Ok(mapping) = OpenFileMappingW(FILE_MAP_ALL_ACCESS.0, FALSE, w!("RTSSSharedMemoryV2")) else {
+                return Ok(false);
+            };
+            let view = MapViewOfFile(mapping, FILE_MAP_ALL_ACCESS, 0, 0, 0);
+            let result = if view.Value.is_null() {
+                Err(BalamError::platform(
+                    "Failed to map RTSS shared memory",
+                    windows::core::Error::from_win32(),
+                ))
+            } else {
+                let result = write_osd(view.Value, text);
+                let _ = UnmapViewOfFile(view);
+                result
+            };
+            let _ = CloseHandle(mapping);
+            result.map(|()| true)
+        }
+    }
+}
+
+/// Writes `text` into Balam's OSD slot, claiming a free one the first time.
+unsafe fn write_osd(base: *mut c_void, text: &str) -> Result<(), BalamError> {
+    let header = base.cast::<u32>();
+    let field = |index: usize| header.add(index).read_volatile();
+    if field(header::SIGNATURE) != SIGNATURE || field(header::VERSION) < MIN_VERSION {
+        return Err(BalamError::platform(
+            "Unsupported RTSS shared memory",
+            format!("version {:#x}", field(header::VERSION)),
+        ));
+    }
+    let version = field(header::VERSION);
+    let entry_size = field(header::OSD_ENTRY_SIZE) as usize;
+    let entries = base.cast::<u8>().add(field(header::OSD_ARR_OFFSET) as usize);
+    let count = field(header::OSD_ARR_SIZE) as usize;
+    let owner = |slot: usize| entries.add(slot * entry_size + OSD_OWNER_OFFSET);
+
+    // Our own slot first, else the first free one
+    let slot = (0..count)
+        .find(|slot| c_str_eq(owner(*slot), OWNER))
+        .or_else(|| (0..count).find(|slot| owner(*slot).read_volatile() == 0));
+    let Some(slot) = slot else {
+        return Err(BalamError::platform(
+            "No free RTSS OSD slot",
+            format!("{count} slots in use"),
+        ));
+    };
+
+    let entry = entries.add(slot * entry_size);
+    if text.is_empty() {
+        // Release the slot
+        entry.write_volatile(0);
+        entry.add(OSD_EX_OFFSET).write_volatile(0);
+        owner(slot).write_volatile(0);
+    } else {
+        write_c_str(owner(slot), OWNER, OSD_LEN);
+        if version >= OSD_EX_VERSION && entry_size >= OSD_EX_OFFSET + OSD_EX_LEN {
+            write_c_str(entry.add(OSD_EX_OFFSET), text.as_bytes(), OSD_EX_LEN);
+        } else {
+            write_c_str(entry, text.as_bytes(), OSD_LEN);
+        }
+    }
+    let frame = header.add(header::OSD_FRAME);
+    frame.write_volatile(frame.read_volatile().wrapping_add(1));
+    Ok(())
+}
+
+unsafe fn c_str_eq(ptr: *const u8, expected: &[u8]) -> bool {
+    expected
+        .iter()
+        .enumerate()
+        .all(|(index, byte)| ptr.add(index).read_volatile() == *byte)
+        && ptr.add(expected.len()).read_volatile() == 0
+}
+
+/// Copies `bytes` NUL-terminated, truncated to `capacity`.
+unsafe fn write_c_str(ptr: *mut u8, bytes: &[u8], capacity: usize) {
+    let len = bytes.len().min(capacity - 1);
+    std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, len);
+    ptr.add(len).write_volatile(0);
+}
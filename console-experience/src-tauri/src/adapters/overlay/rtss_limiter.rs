@@ -0,0 +1,121 @@
+/// RTSS frame limiter - global FPS cap through RivaTuner Statistics Server
+///
+/// The limit lives in RTSS' global profile. It is changed through the
+/// profile API exported by `RTSSHooks64.dll` (from the RTSS install folder):
+/// load the profile, set `FramerateLimit`, save it and ask RTSS to reload.
+/// An empty profile name is the global profile; 0 means no limit.
+use once_cell::sync::OnceCell;
+use std::ffi::{c_char, c_void};
+use std::path::PathBuf;
+use tracing::info;
+use winreg::enums::{HKEY_LOCAL_MACHINE, KEY_READ};
+use winreg::RegKey;
+
+use crate::domain::BalamError;
+
+const INSTALL_KEY: &str = r"SOFTWARE\WOW6432Node\Unwinder\RTSS";
+const HOOKS_DLL: &str = "RTSSHooks64.dll";
+const GLOBAL_PROFILE: &[u8] = b"\0";
+const FRAMERATE_LIMIT: &[u8] = b"FramerateLimit\0";
+
+type ProfileFn = unsafe extern "C" fn(*const c_char);
+type PropertyFn = unsafe extern "C" fn(*const c_char, *mut c_void, u32) -> i32;
+type UpdateProfilesFn = unsafe extern "C" fn();
+
+struct RtssHooks {
+    _lib: libloading::Library,
+    load_profile: ProfileFn,
+    save_profile: ProfileFn,
+    get_property: PropertyFn,
+    set_property: PropertyFn,
+    update_profiles: UpdateProfilesFn,
+}
+
+/// Loaded once per process; `None` without RTSS installed.
+static RTSS_HOOKS: OnceCell<Option<RtssHooks>> = OnceCell::new();
+
+impl RtssHooks {
+    fn get() -> Option<&'static RtssHooks> {
+        RTSS_HOOKS.get_or_init(|| unsafe { Self::load() }).as_ref()
+    }
+
+    unsafe fn load() -> Option<Self> {
+        let install_dir: String = RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey_with_flags(INSTALL_KEY, KEY_READ)
+            .and_then(|key| key.get_value("InstallDir"))
+            .ok()?;
+        let lib = libloading::Library::new(PathBuf::from(install_dir).join(HOOKS_DLL)).ok()?;
+
+        let hooks = Self {
+            load_profile: *lib.get::<ProfileFn>(b"LoadProfile").ok()?,
+            save_profile: *lib.get::<ProfileFn>(b"SaveProfile").ok()?,
+            get_property: *lib.get::<PropertyFn>(b"GetProfileProperty").ok()?,
+            set_property: *lib.get::<PropertyFn>(b"SetProfileProperty").ok()?,
+            update_profiles: *lib.get::<UpdateProfilesFn>(b"UpdateProfiles").ok()?,
+            _lib: lib,
+        };
+        info!("RTSS profile API loaded (frame limiter available)");
+        Some(hooks)
+    }
+}
+
+/// Global FPS cap of RTSS.
+pub struct RtssFrameLimiter;
+
+impl RtssFrameLimiter {
+    /// Whether RTSS is installed (its profile API could be loaded).
+    #[must_use]
+    pub fn is_available() -> bool {
+        RtssHooks::get().is_some()
+    }
+
+    /// Current global limit (0 = unlimited).
+    ///
+    /// # Errors
+    /// Returns error if RTSS is not installed or the property cannot be read.
+    pub fn limit() -> Result<u32, BalamError> {
+        let hooks = RtssHooks::get().ok_or_else(|| BalamError::NotSupported("RTSS frame limiter".to_string()))?;
+        let mut limit: u32 = 0;
+        // SAFETY: NUL-terminated names and a buffer of the size passed
+        let read = unsafe {
+            (hooks.load_profile)(GLOBAL_PROFILE.as_ptr().cast());
+            (hooks.get_property)(
+                FRAMERATE_LIMIT.as_ptr().cast(),
+                std::ptr::addr_of_mut!(limit).cast(),
+                std::mem::size_of::<u32>() as u32,
+            )
+        };
+        if read == 0 {
+            return Err(BalamError::Platform("Failed to read the RTSS frame limit".to_string()));
+        }
+        Ok(limit)
+    }
+
+    /// Caps every game RTSS hooks at `fps` (0 removes the cap).
+    ///
+    /// # Errors
+    /// Returns error if RTSS is not installed or rejects the property.
+    pub fn set_limit(fps: u32) -> Result<(), BalamError> {
+        let hooks = RtssHooks::get().ok_or_else(|| BalamError::NotSupported("RTSS frame limiter".to_string()))?;
+        let mut limit = fps;
+        // SAFETY: NUL-terminated names and a buffer of the size passed
+        let written = unsafe {
+            (hooks.load_profile)(GLOBAL_PROFILE.as_ptr().cast());
+            let written = (hooks.set_property)(
+                FRAMERATE_LIMIT.as_ptr().cast(),
+                std::ptr::addr_of_mut!(limit).cast(),
+                std::mem::size_of::<u32>() as u32,
+            );
+            if written != 0 {
+                (hooks.save_profile)(GLOBAL_PROFILE.as_ptr().cast());
+                (hooks.update_profiles)();
+            }
+            written
+        };
+        if written == 0 {
+            return Err(BalamError::Platform("Failed to set the RTSS frame limit".to_string()));
+        }
+        info!("🎯 RTSS frame limit set to {}", fps);
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+/// RTSS Overlay - OSD-based overlay for exclusive fullscreen games
+///
+/// A TOPMOST window cannot cover a game flipping in exclusive fullscreen.
+/// When RivaTuner Statistics Server is running it has already hooked the
+/// game's swapchain, so Balam's HUD goes through its OSD instead of
+/// injecting anything.
+///
+/// # Architecture
+/// ```
+/// RtssOverlay::show() → RTSS running?
+///     ↓
+/// update_rtss_overlay (frontend tick) → RtssOsd → RTSS renders in-game
+/// ```
+use super::detector::{GameInfo, PresentMode};
+use super::rtss::RtssOsd;
+use super::strategy::{OverlayStrategy, OverlayType};
+use tauri::AppHandle;
+
+/// RTSS overlay implementation
+///
+/// Only checks that RTSS is there: the HUD text itself is written by
+/// `update_rtss_overlay`.
+#[derive(Debug, Default)]
+pub struct RtssOverlay;
+
+impl RtssOverlay {
+    /// Create new RTSS overlay instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl OverlayStrategy for RtssOverlay {
+    /// Show overlay through RTSS
+    fn show(&self, _app: &AppHandle) -> Result<(), String> {
+        if RtssOsd::is_running() {
+            Ok(())
+        } else {
+            Err("RTSS is not running".to_string())
+        }
+    }
+
+    /// Hide overlay by releasing Balam's OSD slot
+    fn hide(&self) -> Result<(), String> {
+        RtssOsd::update("").map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    /// Check if compatible with given game
+    ///
+    /// RTSS overlay works with exclusive fullscreen games while RTSS runs.
+    fn is_compatible(&self, game: &GameInfo) -> bool {
+        game.present_mode == PresentMode::FullscreenExclusive && RtssOsd::is_running()
+    }
+
+    /// Get overlay type identifier
+    fn get_type(&self) -> OverlayType {
+        OverlayType::Rtss
+    }
+}
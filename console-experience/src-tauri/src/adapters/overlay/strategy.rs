@@ -1,9 +1,12 @@
 /// Strategy Pattern - Overlay method selection
 ///
 /// Automatically selects the best overlay method based on game capabilities.
-use super::detector::GameInfo;
+use super::detector::{GameInfo, PresentMode};
 use super::dll_overlay::DllOverlay;
+use super::rtss::RtssOsd;
+use super::rtss_overlay::RtssOverlay;
 use super::topmost_overlay::TopMostOverlay;
+use serde::Serialize;
 use tauri::AppHandle;
 
 /// Overlay strategy trait - common interface for all overlay methods
@@ -22,23 +25,43 @@ pub trait OverlayStrategy: Send + Sync {
 }
 
 /// Overlay type identifier
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum OverlayType {
     TopMost,
+    Rtss,
     DllInjection,
 }
 
+/// Overlay type picked for a game, with the reason shown to the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct StrategyChoice {
+    pub overlay_type: OverlayType,
+    pub reason: &'static str,
+}
+
 /// Overlay method enum - wraps different strategies
 pub enum OverlayMethod {
     TopMost(TopMostOverlay),
+    Rtss(RtssOverlay),
     DllInjection(DllOverlay),
 }
 
 impl OverlayMethod {
+    /// Create the strategy for an overlay type
+    #[must_use]
+    pub fn new(overlay_type: OverlayType) -> Self {
+        match overlay_type {
+            OverlayType::TopMost => Self::TopMost(TopMostOverlay::new()),
+            OverlayType::Rtss => Self::Rtss(RtssOverlay::new()),
+            OverlayType::DllInjection => Self::DllInjection(DllOverlay::new()),
+        }
+    }
+
     /// Show overlay using selected strategy
     pub fn show(&self, app: &AppHandle) -> Result<(), String> {
         match self {
             Self::TopMost(overlay) => overlay.show(app),
+            Self::Rtss(overlay) => overlay.show(app),
             Self::DllInjection(overlay) => overlay.show(app),
         }
     }
@@ -47,6 +70,7 @@ impl OverlayMethod {
     pub fn hide(&self) -> Result<(), String> {
         match self {
             Self::TopMost(overlay) => overlay.hide(),
+            Self::Rtss(overlay) => overlay.hide(),
             Self::DllInjection(overlay) => overlay.hide(),
         }
     }
@@ -56,6 +80,7 @@ impl OverlayMethod {
     pub fn get_type(&self) -> OverlayType {
         match self {
             Self::TopMost(overlay) => overlay.get_type(),
+            Self::Rtss(overlay) => overlay.get_type(),
             Self::DllInjection(overlay) => overlay.get_type(),
         }
     }
@@ -63,20 +88,46 @@ impl OverlayMethod {
 
 /// Select best overlay strategy for given game
 ///
-/// Decision matrix:
-/// - DX12: Always TOPMOST (FSO built-in)
-/// - DX11 + FSO: TOPMOST
-/// - DX11 without FSO: DLL injection
-/// - DX9: DLL injection
+/// See [`choose_overlay_type`] for the decision matrix.
 #[must_use]
 pub fn select_strategy(game: &GameInfo) -> OverlayMethod {
-    if game.is_compatible_topmost {
-        // Modern game with FSO support
-        OverlayMethod::TopMost(TopMostOverlay::new())
-    } else {
-        // Legacy game or no FSO - use DLL injection
-        OverlayMethod::DllInjection(DllOverlay::new())
-    }
+    OverlayMethod::new(choose_overlay_type(game, RtssOsd::is_running).overlay_type)
+}
+
+/// Pick the overlay type from the swapchain's present mode
+///
+/// Decision matrix:
+/// - Composed / hardware flip: TOPMOST (DWM composes the window over the game)
+/// - Exclusive fullscreen: RTSS when it is running, else DLL injection
+/// - No present mode yet: DX12 or DX11 + FSO → TOPMOST, else DLL injection
+///
+/// `rtss_running` is only asked for exclusive fullscreen games.
+#[must_use]
+pub fn choose_overlay_type(game: &GameInfo, rtss_running: impl FnOnce() -> bool) -> StrategyChoice {
+    let (overlay_type, reason) = match game.present_mode {
+        PresentMode::Composed => (OverlayType::TopMost, "Game is composed by DWM"),
+        PresentMode::HardwareFlip => (
+            OverlayType::TopMost,
+            "Game uses hardware flip; the overlay window returns it to composition",
+        ),
+        PresentMode::FullscreenExclusive if rtss_running() => (
+            OverlayType::Rtss,
+            "Game is in exclusive fullscreen; drawing through RTSS",
+        ),
+        PresentMode::FullscreenExclusive => (
+            OverlayType::DllInjection,
+            "Game is in exclusive fullscreen and RTSS is not running",
+        ),
+        PresentMode::Unknown if game.is_compatible_topmost => (
+            OverlayType::TopMost,
+            "Present mode unknown; DirectX version and FSO allow a window overlay",
+        ),
+        PresentMode::Unknown => (
+            OverlayType::DllInjection,
+            "Present mode unknown; DirectX version or disabled FSO rule out a window overlay",
+        ),
+    };
+    StrategyChoice { overlay_type, reason }
 }
 
 #[cfg(test)]
@@ -92,6 +143,9 @@ mod tests {
             dx_version: 12,
             has_fso: true,
             is_compatible_topmost: true,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         let strategy = select_strategy(&dx12_game);
         assert_eq!(strategy.get_type(), OverlayType::TopMost);
@@ -103,6 +157,9 @@ mod tests {
             dx_version: 11,
             has_fso: true,
             is_compatible_topmost: true,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         let strategy = select_strategy(&dx11_fso);
         assert_eq!(strategy.get_type(), OverlayType::TopMost);
@@ -114,8 +171,36 @@ mod tests {
             dx_version: 9,
             has_fso: false,
             is_compatible_topmost: false,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         let strategy = select_strategy(&dx9_game);
         assert_eq!(strategy.get_type(), OverlayType::DllInjection);
     }
+
+    #[test]
+    fn test_strategy_follows_present_mode() {
+        let mut game = GameInfo {
+            pid: 1237,
+            name: "game.exe".to_string(),
+            dx_version: 9,
+            has_fso: false,
+            is_compatible_topmost: false,
+            present_mode: PresentMode::Composed,
+            sync_interval: None,
+            allow_tearing: false,
+        };
+        // Composition wins over the DirectX guess; RTSS is not consulted
+        let choice = choose_overlay_type(&game, || unreachable!());
+        assert_eq!(choice.overlay_type, OverlayType::TopMost);
+
+        game.present_mode = PresentMode::FullscreenExclusive;
+        game.is_compatible_topmost = true;
+        assert_eq!(choose_overlay_type(&game, || true).overlay_type, OverlayType::Rtss);
+        assert_eq!(
+            choose_overlay_type(&game, || false).overlay_type,
+            OverlayType::DllInjection
+        );
+    }
 }
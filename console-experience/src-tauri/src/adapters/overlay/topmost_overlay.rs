@@ -8,6 +8,7 @@
 /// - Configure WS_EX_TOPMOST + WS_EX_LAYERED extended styles
 /// - Opacity control (0.0 → 0.98) for visibility
 /// - Click-through management for input passthrough
+/// - Follows the game onto its monitor on multi-monitor setups
 ///
 /// # Architecture
 /// ```
@@ -19,7 +20,12 @@
 /// ```
 use super::detector::GameInfo;
 use super::strategy::{OverlayStrategy, OverlayType};
-use tauri::{AppHandle, Manager, WebviewUrl, WebviewWindowBuilder};
+use crate::adapters::display::monitors;
+use crate::adapters::game::WindowsGameAdapter;
+use crate::application::DIContainer;
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, PhysicalSize, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+};
 use windows::Win32::Foundation::HWND;
 use windows::Win32::UI::WindowsAndMessaging::{
     GetWindowLongPtrW, SetLayeredWindowAttributes, SetWindowLongPtrW, SetWindowPos, GWL_EXSTYLE, HWND_TOPMOST,
@@ -125,6 +131,7 @@ impl TopMostOverlay {
             .always_on_top(true)
             .skip_taskbar(true)
             .visible(false) // Start hidden, will be shown after style configuration
+            .additional_browser_args(crate::WEBVIEW2_BROWSER_ARGS)
             .build()
             .map_err(|e| format!("Failed to create overlay window: {}", e))?;
 
@@ -155,6 +162,7 @@ impl OverlayStrategy for TopMostOverlay {
             .get_webview_window(&self.window_label)
             .ok_or("Overlay window not found")?;
 
+        follow_game_monitor(app, &window);
         window.show().map_err(|e| format!("Failed to show overlay: {}", e))?;
         window.set_focus().map_err(|e| format!("Failed to focus overlay: {}", e))?;
 
@@ -178,10 +186,10 @@ impl OverlayStrategy for TopMostOverlay {
     /// Check if compatible with given game
     ///
     /// TOPMOST overlay works with:
-    /// - DX12 games (always have FSO)
-    /// - DX11 games with FSO enabled
+    /// - Composed or hardware flip swapchains
+    /// - Without a present mode: DX12 games (always have FSO) and DX11 games with FSO enabled
     fn is_compatible(&self, game: &GameInfo) -> bool {
-        game.is_compatible_topmost
+        game.topmost_visible()
     }
 
     /// Get overlay type identifier
@@ -190,6 +198,59 @@ impl OverlayStrategy for TopMostOverlay {
     }
 }
 
+/// Window of the running game: the tracked session's, else the foreground
+/// window when it is not one of Balam's own
+fn game_window(app: &AppHandle) -> Option<HWND> {
+    let games = WindowsGameAdapter::new();
+    let tracked = app
+        .try_state::<DIContainer>()
+        .and_then(|container| container.active_games_tracker.capture_target())
+        .and_then(|game| game.pid)
+        .and_then(|pid| games.main_window(pid));
+    tracked.or_else(|| {
+        games
+            .foreground_pid()
+            .filter(|&pid| pid != std::process::id())
+            .and_then(|pid| games.main_window(pid))
+    })
+}
+
+/// Move the overlay onto the monitor showing the game
+///
+/// A fullscreen window covers the monitor it sits on, so the overlay leaves
+/// fullscreen, moves and re-enters it. Single-monitor setups never move.
+pub fn follow_game_monitor(app: &AppHandle, window: &WebviewWindow) {
+    let Some(game) = game_window(app) else {
+        return;
+    };
+    let Ok(overlay) = window.hwnd() else {
+        return;
+    };
+    let Some(target) = monitors::device_name_of_window(game) else {
+        return;
+    };
+    if monitors::device_name_of_window(HWND(overlay.0 as isize)).as_deref() == Some(target.as_str()) {
+        return;
+    }
+    let Some(monitor) = monitors::enumerate()
+        .into_iter()
+        .find(|monitor| monitor.device_name == target)
+    else {
+        return;
+    };
+
+    tracing::info!("🖥️ Moving overlay to {} with the game", target);
+    let bounds = monitor.bounds;
+    let moved = window
+        .set_fullscreen(false)
+        .and_then(|()| window.set_position(PhysicalPosition::new(bounds.x, bounds.y)))
+        .and_then(|()| window.set_size(PhysicalSize::new(bounds.width, bounds.height)))
+        .and_then(|()| window.set_fullscreen(true));
+    if let Err(e) = moved {
+        tracing::warn!("Failed to move overlay to {}: {}", target, e);
+    }
+}
+
 /// Enable click-through for overlay window
 ///
 /// When enabled, mouse clicks pass through to the game below.
@@ -221,9 +282,21 @@ pub fn set_overlay_opacity(app: &AppHandle, opacity: f64) -> Result<(), String>
     TopMostOverlay::set_window_opacity(hwnd.0, opacity)
 }
 
+/// Keep another Balam window (e.g. the companion PiP) above the game
+///
+/// Applies the overlay's TOPMOST/LAYERED styles (clicks not passed through)
+/// and `opacity`. Also shows the window.
+pub fn pin_above_games<R: Runtime>(window: &WebviewWindow<R>, opacity: f64) -> Result<(), String> {
+    let hwnd = window.hwnd().map_err(|e| format!("Failed to get HWND: {}", e))?;
+
+    TopMostOverlay::configure_window_styles(hwnd.0, false)?;
+    TopMostOverlay::set_window_opacity(hwnd.0, opacity)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::overlay::detector::PresentMode;
 
     #[test]
     fn test_overlay_creation() {
@@ -242,6 +315,9 @@ mod tests {
             dx_version: 12,
             has_fso: true,
             is_compatible_topmost: true,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         assert!(overlay.is_compatible(&dx12_game));
 
@@ -252,6 +328,9 @@ mod tests {
             dx_version: 9,
             has_fso: false,
             is_compatible_topmost: false,
+            present_mode: PresentMode::Unknown,
+            sync_interval: None,
+            allow_tearing: false,
         };
         assert!(!overlay.is_compatible(&dx9_game));
     }
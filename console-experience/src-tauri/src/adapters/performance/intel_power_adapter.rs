@@ -0,0 +1,168 @@
+use super::winring0::WinRing0;
+use super::RyzenAdjAdapter;
+use crate::domain::performance::TDPConfig;
+use crate::domain::BalamError;
+use crate::ports::performance_port::{HardwareVendor, PerformancePort};
+use parking_lot::Mutex;
+use tracing::{info, warn};
+
+/// RAPL power unit (bits 3:0 = 1/2^n W).
+const MSR_RAPL_POWER_UNIT: u32 = 0x606;
+/// Package PL1 (bits 14:0) and PL2 (bits 46:32); bit 63 locks the register.
+const MSR_PKG_POWER_LIMIT: u32 = 0x610;
+/// Package TDP (14:0), minimum (30:16) and maximum (46:32) power from the firmware.
+const MSR_PKG_POWER_INFO: u32 = 0x614;
+
+const POWER_LIMIT_MASK: u64 = 0x7FFF;
+const PL1_ENABLE: u64 = 1 << 15;
+const PL2_SHIFT: u32 = 32;
+const PL2_ENABLE: u64 = 1 << 47;
+const POWER_LIMIT_LOCK: u64 = 1 << 63;
+
+/// Intel package power limits (PL1/PL2) through the WinRing0 driver.
+///
+/// WinRing0x64 is the signed driver already shipped for `RyzenAdj`; it gives
+/// MSR access from user mode (administrator required). Only the MSR copy of
+/// the limits is written: firmware that also enforces the MMIO copy may cap
+/// values above its own limit.
+pub struct IntelPowerLimitAdapter {
+    msr: Mutex<Option<WinRing0>>,
+}
+
+impl Default for IntelPowerLimitAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntelPowerLimitAdapter {
+    /// Creates the adapter; the driver is loaded on first use.
+    #[must_use]
+    pub fn new() -> Self {
+        Self { msr: Mutex::new(None) }
+    }
+
+    /// Runs `f` with the driver loaded.
+    fn with_msr<T>(&self, f: impl FnOnce(&WinRing0) -> Result<T, BalamError>) -> Result<T, BalamError> {
+        let mut guard = self.msr.lock();
+        if guard.is_none() {
+            *guard = Some(WinRing0::load()?);
+            info!("WinRing0 loaded for Intel power limits");
+        }
+        f(guard.as_ref().expect("driver loaded above"))
+    }
+
+    /// Reads the power unit and current limit register.
+    fn read_limits(msr: &WinRing0) -> Result<(f64, u64), BalamError> {
+        let unit = power_unit_watts(msr.read_msr(MSR_RAPL_POWER_UNIT)?);
+        Ok((unit, msr.read_msr(MSR_PKG_POWER_LIMIT)?))
+    }
+}
+
+/// Watts per RAPL power unit.
+fn power_unit_watts(unit_msr: u64) -> f64 {
+    1.0 / f64::from(1u32 << (unit_msr & 0xF))
+}
+
+/// PL1 in whole watts.
+fn decode_pl1(limit_msr: u64, unit: f64) -> u32 {
+    ((limit_msr & POWER_LIMIT_MASK) as f64 * unit).round() as u32
+}
+
+/// Sets PL1 and PL2 to `watts`, keeping time windows and clamp bits.
+fn encode_power_limits(current: u64, watts: u32, unit: f64) -> u64 {
+    let raw = ((f64::from(watts) / unit).round() as u64).min(POWER_LIMIT_MASK);
+    let cleared = current & !(POWER_LIMIT_MASK | (POWER_LIMIT_MASK << PL2_SHIFT));
+    cleared | raw | (raw << PL2_SHIFT) | PL1_ENABLE | PL2_ENABLE
+}
+
+/// Minimum and maximum package power (watts) from `MSR_PKG_POWER_INFO`, if the firmware fills it.
+fn firmware_range(info_msr: u64, unit: f64) -> Option<(u32, u32)> {
+    let min = ((info_msr >> 16) & POWER_LIMIT_MASK) as f64 * unit;
+    let max = ((info_msr >> 32) & POWER_LIMIT_MASK) as f64 * unit;
+    (min >= 1.0 && max > min).then(|| (min.round() as u32, max.round() as u32))
+}
+
+impl PerformancePort for IntelPowerLimitAdapter {
+    fn detect_hardware(&self) -> Result<HardwareVendor, BalamError> {
+        Ok(HardwareVendor::Intel)
+    }
+
+    fn get_tdp_config(&self) -> Result<TDPConfig, BalamError> {
+        self.with_msr(|msr| {
+            let (unit, limits) = Self::read_limits(msr)?;
+            let (min, max) = msr
+                .read(MSR_PKG_POWER_INFO)
+                .ok()
+                .and_then(|info| firmware_range(info, unit))
+                .unwrap_or_else(RyzenAdjAdapter::get_hardware_limits);
+            let current = decode_pl1(limits, unit).clamp(min, max);
+            TDPConfig::new(current, min, max).map_err(BalamError::InvalidArgument)
+        })
+    }
+
+    fn set_tdp(&self, watts: u32) -> Result<(), BalamError> {
+        let config = self.get_tdp_config()?;
+        let clamped_watts = config.clamp(watts);
+        if clamped_watts != watts {
+            warn!("TDP {} out of range, clamped to {}", watts, clamped_watts);
+        }
+
+        self.with_msr(|msr| {
+            let (unit, limits) = Self::read_limits(msr)?;
+            if limits & POWER_LIMIT_LOCK != 0 {
+                return Err(BalamError::NotSupported(
+                    "TDP control: power limits are locked by the BIOS".to_string(),
+                ));
+            }
+            info!("Setting Intel PL1/PL2 to {}W", clamped_watts);
+            msr.write_msr(MSR_PKG_POWER_LIMIT, encode_power_limits(limits, clamped_watts, unit))
+        })
+    }
+
+    fn supports_tdp_control(&self) -> bool {
+        match self.with_msr(|msr| Self::read_limits(msr)) {
+            Ok((_, limits)) if limits & POWER_LIMIT_LOCK != 0 => {
+                warn!("Intel power limits are locked by the BIOS");
+                false
+            },
+            Ok(_) => true,
+            Err(e) => {
+                warn!("Intel TDP control not available: {}", e);
+                false
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Typical client unit: 1/8 W
+    const UNIT: f64 = 0.125;
+
+    #[test]
+    fn test_power_limit_round_trip() {
+        assert!((power_unit_watts(0x000A_0E03) - UNIT).abs() < f64::EPSILON);
+
+        // PL1 28W / PL2 64W with time windows and clamp bits set
+        let current = 0x0042_8200_00DD_80E0;
+        let encoded = encode_power_limits(current, 15, UNIT);
+
+        assert_eq!(decode_pl1(encoded, UNIT), 15);
+        assert_eq!((encoded >> PL2_SHIFT) & POWER_LIMIT_MASK, 120);
+        // Time window / clamp bits untouched
+        assert_eq!(encoded & 0x00FE_0000, current & 0x00FE_0000);
+        assert_ne!(encoded & PL1_ENABLE, 0);
+        assert_ne!(encoded & PL2_ENABLE, 0);
+    }
+
+    #[test]
+    fn test_firmware_range_ignores_empty_register() {
+        assert_eq!(firmware_range(0, UNIT), None);
+        // min 8W (64 units), max 35W (280 units)
+        let info = (280u64 << 32) | (64 << 16) | 120;
+        assert_eq!(firmware_range(info, UNIT), Some((8, 35)));
+    }
+}
@@ -1,3 +1,15 @@
+pub mod intel_power_adapter;
+pub mod power_plan;
+pub mod process_priority;
 pub mod ryzenadj_adapter;
+pub mod ryzenadj_downloader;
+pub mod tdp_controller;
+pub(crate) mod winring0;
 
+pub use intel_power_adapter::IntelPowerLimitAdapter;
+pub use power_plan::PowerPlanAdapter;
+pub use process_priority::{ProcessPriorityAdapter, ProcessScheduling};
 pub use ryzenadj_adapter::RyzenAdjAdapter;
+pub use ryzenadj_downloader::RyzenAdjDownloader;
+pub use tdp_controller::TdpController;
+pub(crate) use winring0::WinRing0;
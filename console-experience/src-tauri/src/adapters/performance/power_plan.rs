@@ -0,0 +1,221 @@
+use crate::domain::performance::{CpuBoostMode, PowerMode, PowerPlan};
+use crate::domain::BalamError;
+use once_cell::sync::OnceCell;
+use std::process::Command;
+use tracing::info;
+use windows::core::GUID;
+
+/// Power mode overlays (same GUIDs the Settings app writes).
+const OVERLAY_BEST_EFFICIENCY: GUID = GUID::from_u128(0x961cc777_2547_4f9d_8174_7d86181b8a7a);
+const OVERLAY_BALANCED: GUID = GUID::zeroed();
+const OVERLAY_BEST_PERFORMANCE: GUID = GUID::from_u128(0xded574b5_45a0_4f42_8737_46345c09c238);
+
+type SetOverlayFn = unsafe extern "system" fn(GUID) -> u32;
+type GetOverlayFn = unsafe extern "system" fn(*mut GUID) -> u32;
+
+/// powrprof.dll overlay entry points (exported but not in the SDK headers).
+struct OverlayApi {
+    _lib: libloading::Library,
+    set: SetOverlayFn,
+    get: GetOverlayFn,
+}
+
+static OVERLAY_API: OnceCell<Option<OverlayApi>> = OnceCell::new();
+
+/// Windows power plans, power mode and CPU boost.
+///
+/// Plans and boost go through `powercfg` (boost is a hidden setting of the
+/// processor subgroup, written for both AC and DC); the power mode slider
+/// goes through the powrprof overlay API.
+pub struct PowerPlanAdapter;
+
+impl PowerPlanAdapter {
+    /// Installed power plans.
+    ///
+    /// # Errors
+    /// Returns error if `powercfg` fails.
+    pub fn list_plans() -> Result<Vec<PowerPlan>, BalamError> {
+        Ok(parse_plan_list(&Self::powercfg(&["/list"])?))
+    }
+
+    /// Activates a power plan.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if no plan has that GUID.
+    pub fn set_active_plan(guid: &str) -> Result<(), BalamError> {
+        if !Self::list_plans()?
+            .iter()
+            .any(|plan| plan.guid.eq_ignore_ascii_case(guid))
+        {
+            return Err(BalamError::NotFound(format!("Power plan {guid}")));
+        }
+        Self::powercfg(&["/setactive", guid])?;
+        info!("⚡ Power plan set to {}", guid);
+        Ok(())
+    }
+
+    /// Current power mode (only the three standard overlays are recognised).
+    ///
+    /// # Errors
+    /// Returns `NotSupported` on Windows builds without power mode overlays.
+    pub fn power_mode() -> Result<PowerMode, BalamError> {
+        let api = Self::overlay_api()?;
+        let mut guid = GUID::zeroed();
+        let status = unsafe { (api.get)(&raw mut guid) };
+        if status != 0 {
+            return Err(BalamError::Platform(format!(
+                "PowerGetEffectiveOverlayScheme failed: {status}"
+            )));
+        }
+        // Custom OEM overlays read as balanced
+        Ok(if guid == OVERLAY_BEST_EFFICIENCY {
+            PowerMode::BestEfficiency
+        } else if guid == OVERLAY_BEST_PERFORMANCE {
+            PowerMode::BestPerformance
+        } else {
+            PowerMode::Balanced
+        })
+    }
+
+    /// Moves the power mode slider.
+    ///
+    /// # Errors
+    /// Returns `NotSupported` on Windows builds without power mode overlays.
+    pub fn set_power_mode(mode: PowerMode) -> Result<(), BalamError> {
+        let guid = match mode {
+            PowerMode::BestEfficiency => OVERLAY_BEST_EFFICIENCY,
+            PowerMode::Balanced => OVERLAY_BALANCED,
+            PowerMode::BestPerformance => OVERLAY_BEST_PERFORMANCE,
+        };
+        let status = unsafe { (Self::overlay_api()?.set)(guid) };
+        if status != 0 {
+            return Err(BalamError::Platform(format!(
+                "PowerSetActiveOverlayScheme failed: {status}"
+            )));
+        }
+        info!("⚡ Power mode set to {:?}", mode);
+        Ok(())
+    }
+
+    /// CPU boost mode of the active plan (AC value).
+    ///
+    /// # Errors
+    /// Returns error if `powercfg` fails or reports an unknown mode.
+    pub fn boost_mode() -> Result<CpuBoostMode, BalamError> {
+        let output = Self::powercfg(&["/query", "SCHEME_CURRENT", "SUB_PROCESSOR", "PERFBOOSTMODE"])?;
+        let (ac, _dc) = parse_current_indices(&output)
+            .ok_or_else(|| BalamError::Platform("Unexpected powercfg output for PERFBOOSTMODE".to_string()))?;
+        CpuBoostMode::from_index(ac).ok_or_else(|| BalamError::NotSupported(format!("CPU boost mode index {ac}")))
+    }
+
+    /// Sets CPU boost on the active plan, on battery and plugged in.
+    ///
+    /// # Errors
+    /// Returns error if `powercfg` fails.
+    pub fn set_boost_mode(mode: CpuBoostMode) -> Result<(), BalamError> {
+        let index = mode.index().to_string();
+        for command in ["/setacvalueindex", "/setdcvalueindex"] {
+            Self::powercfg(&[command, "SCHEME_CURRENT", "SUB_PROCESSOR", "PERFBOOSTMODE", &index])?;
+        }
+        // Re-activating the plan makes the new value take effect immediately
+        Self::powercfg(&["/setactive", "SCHEME_CURRENT"])?;
+        info!("⚡ CPU boost set to {:?}", mode);
+        Ok(())
+    }
+
+    fn powercfg(args: &[&str]) -> Result<String, BalamError> {
+        let output = Command::new("powercfg")
+            .args(args)
+            .output()
+            .map_err(|e| BalamError::platform("Failed to run powercfg", e))?;
+        if !output.status.success() {
+            return Err(BalamError::Platform(format!(
+                "powercfg {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stdout).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn overlay_api() -> Result<&'static OverlayApi, BalamError> {
+        OVERLAY_API
+            .get_or_init(|| unsafe {
+                let lib = libloading::Library::new("powrprof.dll").ok()?;
+                let set = *lib.get::<SetOverlayFn>(b"PowerSetActiveOverlayScheme").ok()?;
+                let get = *lib.get::<GetOverlayFn>(b"PowerGetEffectiveOverlayScheme").ok()?;
+                Some(OverlayApi { _lib: lib, set, get })
+            })
+            .as_ref()
+            .ok_or_else(|| BalamError::NotSupported("Power modes (Windows 10 1709 or newer)".to_string()))
+    }
+}
+
+/// Parses `powercfg /list` (works with any display language: only the GUID,
+/// the parenthesised name and the trailing `*` are used).
+fn parse_plan_list(output: &str) -> Vec<PowerPlan> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(':')?;
+            let rest = rest.trim();
+            let guid = rest.get(..36)?;
+            if guid.matches('-').count() != 4 {
+                return None;
+            }
+            let name_start = rest.find('(')?;
+            let name_end = rest.rfind(')')?;
+            Some(PowerPlan {
+                guid: guid.to_string(),
+                name: rest.get(name_start + 1..name_end)?.to_string(),
+                active: rest[name_end..].contains('*'),
+            })
+        })
+        .collect()
+}
+
+/// AC and DC value indices: the last two hex values of `powercfg /query`.
+fn parse_current_indices(output: &str) -> Option<(u32, u32)> {
+    let values: Vec<u32> = output
+        .lines()
+        .filter_map(|line| {
+            let hex = line.rsplit_once("0x")?.1.trim();
+            u32::from_str_radix(hex, 16).ok()
+        })
+        .collect();
+    match values.as_slice() {
+        [.., ac, dc] => Some((*ac, *dc)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plan_list_any_language() {
+        let output = "Combinaciones de energía existentes (* Activo)\n\
+            -----------------------------------\n\
+            GUID del plan de energía: 381b4222-f694-41f0-9685-ff5bb260df2e  (Equilibrado) *\n\
+            GUID del plan de energía: 8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c  (Alto rendimiento (AC))\n";
+        let plans = parse_plan_list(output);
+
+        assert_eq!(plans.len(), 2);
+        assert_eq!(plans[0].name, "Equilibrado");
+        assert!(plans[0].active);
+        assert_eq!(plans[1].guid, "8c5e7fda-e8bf-4a96-9a85-a6e23a8c635c");
+        assert_eq!(plans[1].name, "Alto rendimiento (AC)");
+        assert!(!plans[1].active);
+    }
+
+    #[test]
+    fn test_parse_current_indices() {
+        let output = "Possible Setting Index: 002\n\
+            Possible Setting Friendly Name: Aggressive\n\
+            Current AC Power Setting Index: 0x00000002\n\
+            Current DC Power Setting Index: 0x00000000\n";
+        assert_eq!(parse_current_indices(output), Some((2, 0)));
+        assert_eq!(parse_current_indices("nothing here"), None);
+    }
+}
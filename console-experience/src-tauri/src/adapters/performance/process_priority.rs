@@ -0,0 +1,155 @@
+use crate::domain::performance::{performance_core_mask, ProcessPriority};
+use crate::domain::BalamError;
+use once_cell::sync::OnceCell;
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::System::SystemInformation::{
+    GetLogicalProcessorInformationEx, RelationProcessorCore, SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX,
+};
+use windows::Win32::System::Threading::{
+    GetPriorityClass, GetProcessAffinityMask, OpenProcess, SetPriorityClass, SetProcessAffinityMask,
+    ABOVE_NORMAL_PRIORITY_CLASS, HIGH_PRIORITY_CLASS, PROCESS_CREATION_FLAGS, PROCESS_QUERY_LIMITED_INFORMATION,
+    PROCESS_SET_INFORMATION,
+};
+
+/// P-core mask, read once (`None` on CPUs with a single core class).
+static PERFORMANCE_CORES: OnceCell<Option<u64>> = OnceCell::new();
+
+/// Priority class and affinity of a process, kept to put them back later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessScheduling {
+    pub priority_class: u32,
+    pub affinity_mask: u64,
+    /// Every logical processor the process could use
+    pub system_mask: u64,
+}
+
+/// Closes the process handle when dropped.
+struct ProcessHandle(HANDLE);
+
+impl Drop for ProcessHandle {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = CloseHandle(self.0);
+        }
+    }
+}
+
+/// Process priority class and CPU affinity (`SetPriorityClass`,
+/// `SetProcessAffinityMask`).
+///
+/// Only group 0 is handled: Windows keeps a process in one processor group
+/// unless it opts into more, and consumer CPUs have a single group.
+pub struct ProcessPriorityAdapter;
+
+impl ProcessPriorityAdapter {
+    fn open(pid: u32) -> Result<ProcessHandle, BalamError> {
+        unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION | PROCESS_SET_INFORMATION, false, pid) }
+            .map(ProcessHandle)
+            .map_err(|e| BalamError::platform(&format!("Failed to open process {pid}"), e))
+    }
+
+    /// Current priority class and affinity of `pid`.
+    ///
+    /// # Errors
+    /// Returns error if the process is gone or not accessible.
+    pub fn scheduling(pid: u32) -> Result<ProcessScheduling, BalamError> {
+        let process = Self::open(pid)?;
+        let priority_class = unsafe { GetPriorityClass(process.0) };
+        if priority_class == 0 {
+            return Err(BalamError::Platform(format!("GetPriorityClass failed for {pid}")));
+        }
+        let (mut affinity_mask, mut system_mask) = (0usize, 0usize);
+        unsafe { GetProcessAffinityMask(process.0, &raw mut affinity_mask, &raw mut system_mask) }
+            .map_err(|e| BalamError::platform("GetProcessAffinityMask failed", e))?;
+        Ok(ProcessScheduling {
+            priority_class,
+            affinity_mask: affinity_mask as u64,
+            system_mask: system_mask as u64,
+        })
+    }
+
+    /// Sets the priority class of `pid`.
+    ///
+    /// # Errors
+    /// Returns error if the process is gone or not accessible.
+    pub fn set_priority(pid: u32, priority: ProcessPriority) -> Result<(), BalamError> {
+        let class = match priority {
+            ProcessPriority::AboveNormal => ABOVE_NORMAL_PRIORITY_CLASS,
+            ProcessPriority::High => HIGH_PRIORITY_CLASS,
+        };
+        Self::set_priority_class(pid, class.0)
+    }
+
+    fn set_priority_class(pid: u32, class: u32) -> Result<(), BalamError> {
+        let process = Self::open(pid)?;
+        unsafe { SetPriorityClass(process.0, PROCESS_CREATION_FLAGS(class)) }
+            .map_err(|e| BalamError::platform("SetPriorityClass failed", e))
+    }
+
+    /// Restricts `pid` to the processors in `mask`.
+    ///
+    /// # Errors
+    /// Returns error if the process is gone, not accessible or `mask` selects
+    /// no processor of its group.
+    pub fn set_affinity(pid: u32, mask: u64) -> Result<(), BalamError> {
+        let process = Self::open(pid)?;
+        unsafe { SetProcessAffinityMask(process.0, mask as usize) }
+            .map_err(|e| BalamError::platform("SetProcessAffinityMask failed", e))
+    }
+
+    /// Puts back what [`Self::scheduling`] returned.
+    ///
+    /// # Errors
+    /// Returns error if the process is gone or not accessible.
+    pub fn restore(pid: u32, saved: &ProcessScheduling) -> Result<(), BalamError> {
+        Self::set_priority_class(pid, saved.priority_class)?;
+        Self::set_affinity(pid, saved.affinity_mask)
+    }
+
+    /// Logical processors of the fastest core class (P-cores), `None` on
+    /// CPUs without E-cores.
+    #[must_use]
+    pub fn performance_cores() -> Option<u64> {
+        *PERFORMANCE_CORES.get_or_init(|| match Self::read_cores() {
+            Ok(cores) => performance_core_mask(&cores),
+            Err(e) => {
+                tracing::warn!("Failed to read CPU core classes: {}", e);
+                None
+            },
+        })
+    }
+
+    /// `(efficiency class, group 0 mask)` per physical core.
+    fn read_cores() -> Result<Vec<(u8, u64)>, BalamError> {
+        let mut length = 0u32;
+        // First call only reports the buffer size
+        let _ = unsafe { GetLogicalProcessorInformationEx(RelationProcessorCore, None, &raw mut length) };
+        // u64 words keep the records aligned
+        let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+        unsafe {
+            GetLogicalProcessorInformationEx(
+                RelationProcessorCore,
+                Some(buffer.as_mut_ptr().cast::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>()),
+                &raw mut length,
+            )
+        }
+        .map_err(|e| BalamError::platform("GetLogicalProcessorInformationEx failed", e))?;
+
+        let mut cores = Vec::new();
+        let base = buffer.as_ptr().cast::<u8>();
+        let mut offset = 0usize;
+        while offset < length as usize {
+            let record = unsafe { &*base.add(offset).cast::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION_EX>() };
+            if record.Size == 0 {
+                break;
+            }
+            let processor = unsafe { &record.Anonymous.Processor };
+            let group = processor.GroupMask[0];
+            if group.Group == 0 {
+                cores.push((processor.EfficiencyClass, group.Mask as u64));
+            }
+            offset += record.Size as usize;
+        }
+        Ok(cores)
+    }
+}
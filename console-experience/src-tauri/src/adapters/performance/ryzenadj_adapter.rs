@@ -1,10 +1,16 @@
-use crate::domain::performance::TDPConfig;
+use super::RyzenAdjDownloader;
+use crate::domain::performance::{TDPConfig, TdpBackend};
+use crate::domain::BalamError;
 use crate::ports::performance_port::{HardwareVendor, PerformancePort};
+use std::os::windows::process::CommandExt;
+use std::path::PathBuf;
+use std::process::Command;
 use std::sync::Mutex;
 use tracing::{error, info, warn};
 
 /// AMD `RyzenAdj` adapter using FFI to libryzenadj.dll.
 /// This is a native implementation that directly interfaces with AMD hardware registers.
+/// Falls back to `ryzenadj.exe` when only the CLI is present.
 ///
 /// Safety justification: FFI with external library is inherently unsafe, but:
 /// - We validate all inputs before passing to C library
@@ -26,6 +32,23 @@ struct RyzenAdjHandle {
     ryzen_handle: *mut std::ffi::c_void,
 }
 
+// SAFETY: the handle is only used while holding the adapter mutex
+unsafe impl Send for RyzenAdjHandle {}
+
+const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+/// Folders searched for the `RyzenAdj` files: installer resources first, then
+/// the copy fetched by `RyzenAdjDownloader`.
+fn search_paths(file: &str) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(file),                        // Current directory (dev mode)
+        PathBuf::from("bin").join(file),            // Relative to binary
+        PathBuf::from("resources").join(file),      // Tauri resources folder
+        PathBuf::from("..").join(file),             // Tauri bundled resources (one level up from exe)
+        RyzenAdjDownloader::tools_dir().join(file), // Downloaded release
+    ]
+}
+
 impl Default for RyzenAdjAdapter {
     fn default() -> Self {
         Self::new()
@@ -44,51 +67,45 @@ impl RyzenAdjAdapter {
 
     /// Attempts to load libryzenadj.dll and initialize.
     /// Returns Ok if successful, Err if library not found or initialization failed.
-    fn ensure_initialized(&self) -> Result<(), String> {
+    fn ensure_initialized(&self) -> Result<(), BalamError> {
         let mut handle = self
             .library_handle
             .lock()
-            .map_err(|e| format!("Mutex lock failed: {e}"))?;
+            .map_err(|e| BalamError::platform("Mutex lock failed", e))?;
 
         if handle.is_none() {
             info!("Initializing RyzenAdj library...");
 
-            // Try loading from multiple locations
-            let lib_paths = [
-                "libryzenadj.dll",           // Current directory (dev mode)
-                "bin/libryzenadj.dll",       // Relative to binary
-                "resources/libryzenadj.dll", // Tauri resources folder
-                "../libryzenadj.dll",        // Tauri bundled resources (one level up from exe)
-            ];
-
-            let lib = lib_paths
+            let lib = search_paths("libryzenadj.dll")
                 .iter()
                 .find_map(|path| unsafe { libloading::Library::new(path).ok() })
                 .ok_or_else(|| {
-                    "libryzenadj.dll not found. Please ensure it's in the application directory".to_string()
+                    BalamError::NotFound(
+                        "libryzenadj.dll not found. Please ensure it's in the application directory".to_string(),
+                    )
                 })?;
 
             // Load function symbols
             unsafe {
                 let init_fn: libloading::Symbol<unsafe extern "C" fn() -> *mut std::ffi::c_void> = lib
                     .get(b"init_ryzenadj")
-                    .map_err(|e| format!("Failed to load init_ryzenadj: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to load init_ryzenadj", e))?;
 
                 let cleanup_fn: libloading::Symbol<unsafe extern "C" fn(*mut std::ffi::c_void)> = lib
                     .get(b"cleanup_ryzenadj")
-                    .map_err(|e| format!("Failed to load cleanup_ryzenadj: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to load cleanup_ryzenadj", e))?;
 
                 let set_stapm_fn: libloading::Symbol<unsafe extern "C" fn(*mut std::ffi::c_void, u32) -> i32> = lib
                     .get(b"set_stapm_limit")
-                    .map_err(|e| format!("Failed to load set_stapm_limit: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to load set_stapm_limit", e))?;
 
                 let set_fast_fn: libloading::Symbol<unsafe extern "C" fn(*mut std::ffi::c_void, u32) -> i32> = lib
                     .get(b"set_fast_limit")
-                    .map_err(|e| format!("Failed to load set_fast_limit: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to load set_fast_limit", e))?;
 
                 let set_slow_fn: libloading::Symbol<unsafe extern "C" fn(*mut std::ffi::c_void, u32) -> i32> = lib
                     .get(b"set_slow_limit")
-                    .map_err(|e| format!("Failed to load set_slow_limit: {e}"))?;
+                    .map_err(|e| BalamError::platform("Failed to load set_slow_limit", e))?;
 
                 // Initialize RyzenAdj
                 let ryzen_handle = init_fn();
@@ -96,7 +113,7 @@ impl RyzenAdjAdapter {
                     warn!("RyzenAdj initialization failed. This CPU model may not be supported for TDP control.");
                     warn!("Desktop Ryzen CPUs (like 3900X) are not supported by RyzenAdj.");
                     warn!("TDP control is only available on Ryzen Mobile/APU chips (handhelds/laptops).");
-                    return Err("TDP control not supported on this CPU model. Only Ryzen Mobile/APU chips are supported (ROG Ally, Legion Go, etc.)".to_string());
+                    return Err(BalamError::NotSupported("TDP control on this CPU model. Only Ryzen Mobile/APU chips are supported (ROG Ally, Legion Go, etc.)".to_string()));
                 }
 
                 info!("RyzenAdj initialized successfully");
@@ -180,7 +197,7 @@ impl RyzenAdjAdapter {
     /// Gets hardware TDP limits based on detected system type.
     /// Laptop/Handheld (with battery): 5-30W
     /// Desktop (no battery): 65-142W (Ryzen 3900X range)
-    fn get_hardware_limits() -> (u32, u32) {
+    pub(crate) fn get_hardware_limits() -> (u32, u32) {
         if Self::is_battery_powered() {
             info!("Battery detected: Using handheld TDP limits (5-30W)");
             (5, 30)
@@ -192,15 +209,15 @@ impl RyzenAdjAdapter {
 
     /// Sets TDP using `RyzenAdj` FFI.
     /// Applies to STAPM, FAST, and SLOW limits simultaneously for consistency.
-    fn set_tdp_ffi(&self, watts: u32) -> Result<(), String> {
+    fn set_tdp_ffi(&self, watts: u32) -> Result<(), BalamError> {
         let handle_guard = self
             .library_handle
             .lock()
-            .map_err(|e| format!("Mutex lock failed: {e}"))?;
+            .map_err(|e| BalamError::platform("Mutex lock failed", e))?;
 
         let handle = handle_guard
             .as_ref()
-            .ok_or_else(|| "RyzenAdj not initialized".to_string())?;
+            .ok_or_else(|| BalamError::Platform("RyzenAdj not initialized".to_string()))?;
 
         let milliwatts = watts * 1000;
 
@@ -217,13 +234,59 @@ impl RyzenAdjAdapter {
                     "RyzenAdj set_limits failed: stapm={}, fast={}, slow={}",
                     stapm_result, fast_result, slow_result
                 );
-                return Err(format!("Failed to set TDP to {watts}W"));
+                return Err(BalamError::Platform(format!("Failed to set TDP to {watts}W")));
             }
         }
 
         info!("TDP set successfully to {}W", watts);
         Ok(())
     }
+
+    /// Locates `ryzenadj.exe` (bundled or downloaded).
+    fn find_cli() -> Option<PathBuf> {
+        search_paths("ryzenadj.exe").into_iter().find(|path| path.exists())
+    }
+
+    /// Sets TDP by running `ryzenadj.exe` (same three limits as the FFI path).
+    fn set_tdp_cli(cli: &PathBuf, watts: u32) -> Result<(), BalamError> {
+        let milliwatts = watts * 1000;
+        info!("Setting TDP to {}W via {}", watts, cli.display());
+
+        let output = Command::new(cli)
+            .args([
+                format!("--stapm-limit={milliwatts}"),
+                format!("--fast-limit={milliwatts}"),
+                format!("--slow-limit={milliwatts}"),
+            ])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map_err(|e| BalamError::platform("Failed to run ryzenadj.exe", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            error!("ryzenadj.exe failed: {}", stderr.trim());
+            return Err(BalamError::Platform(format!(
+                "Failed to set TDP to {watts}W: {}",
+                stderr.trim()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Backend `set_tdp` will use: the library if it loads, otherwise the CLI.
+    ///
+    /// Returns `None` on non-AMD CPUs or when neither is usable.
+    pub fn backend(&self) -> Option<TdpBackend> {
+        if self.detect_hardware().ok()? != HardwareVendor::AMD {
+            return None;
+        }
+        match self.ensure_initialized() {
+            Ok(()) => Some(TdpBackend::RyzenAdjLibrary),
+            // Chip rejected by RyzenAdj itself: the CLI would fail the same way
+            Err(BalamError::NotSupported(_)) => None,
+            Err(_) => Self::find_cli().map(|_| TdpBackend::RyzenAdjCli),
+        }
+    }
 }
 
 impl Drop for RyzenAdjHandle {
@@ -238,7 +301,7 @@ impl Drop for RyzenAdjHandle {
 }
 
 impl PerformancePort for RyzenAdjAdapter {
-    fn detect_hardware(&self) -> Result<HardwareVendor, String> {
+    fn detect_hardware(&self) -> Result<HardwareVendor, BalamError> {
         if Self::detect_amd_cpu() {
             info!("Detected AMD CPU");
             Ok(HardwareVendor::AMD)
@@ -248,10 +311,10 @@ impl PerformancePort for RyzenAdjAdapter {
         }
     }
 
-    fn get_tdp_config(&self) -> Result<TDPConfig, String> {
+    fn get_tdp_config(&self) -> Result<TDPConfig, BalamError> {
         // Ensure hardware is supported
         if self.detect_hardware()? != HardwareVendor::AMD {
-            return Err("TDP control only supported on AMD CPUs".to_string());
+            return Err(BalamError::NotSupported("TDP control (AMD CPUs only)".to_string()));
         }
 
         let (min, max) = Self::get_hardware_limits();
@@ -259,13 +322,13 @@ impl PerformancePort for RyzenAdjAdapter {
         // Default to balanced TDP
         let current = u32::midpoint(min, max);
 
-        TDPConfig::new(current, min, max)
+        TDPConfig::new(current, min, max).map_err(BalamError::InvalidArgument)
     }
 
-    fn set_tdp(&self, watts: u32) -> Result<(), String> {
+    fn set_tdp(&self, watts: u32) -> Result<(), BalamError> {
         // Validate hardware
         if self.detect_hardware()? != HardwareVendor::AMD {
-            return Err("TDP control only supported on AMD CPUs".to_string());
+            return Err(BalamError::NotSupported("TDP control (AMD CPUs only)".to_string()));
         }
 
         // Validate and clamp TDP
@@ -276,33 +339,29 @@ impl PerformancePort for RyzenAdjAdapter {
             warn!("TDP {} out of range, clamped to {}", watts, clamped_watts);
         }
 
-        // Ensure library is loaded
-        self.ensure_initialized()?;
-
-        // Apply TDP
-        self.set_tdp_ffi(clamped_watts)
+        // Prefer the library; the CLI covers installs where the DLL fails to load
+        match self.ensure_initialized() {
+            Ok(()) => self.set_tdp_ffi(clamped_watts),
+            Err(BalamError::NotSupported(reason)) => Err(BalamError::NotSupported(reason)),
+            Err(e) => match Self::find_cli() {
+                Some(cli) => {
+                    warn!("libryzenadj unavailable ({}), using ryzenadj.exe", e);
+                    Self::set_tdp_cli(&cli, clamped_watts)
+                },
+                None => Err(e),
+            },
+        }
     }
 
     fn supports_tdp_control(&self) -> bool {
-        // Check if AMD CPU
-        let is_amd = self
-            .detect_hardware()
-            .map(|vendor| vendor == HardwareVendor::AMD)
-            .unwrap_or(false);
-
-        if !is_amd {
-            return false;
-        }
-
-        // Try to initialize RyzenAdj
-        // If it fails (e.g., desktop CPU not supported), return false gracefully
-        match self.ensure_initialized() {
-            Ok(()) => {
-                info!("TDP control is supported on this hardware");
+        // Library or CLI usable; desktop CPUs rejected by RyzenAdj return false gracefully
+        match self.backend() {
+            Some(backend) => {
+                info!("TDP control is supported on this hardware ({:?})", backend);
                 true
             },
-            Err(e) => {
-                warn!("TDP control not supported: {}", e);
+            None => {
+                warn!("TDP control not supported on this hardware");
                 false
             },
         }
@@ -0,0 +1,146 @@
+use crate::domain::BalamError;
+use crate::infrastructure::app_dirs;
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/FlyGoat/RyzenAdj/releases/latest";
+
+/// Files kept from the release archive (CLI, library and the drivers they load).
+const TOOL_FILES: [&str; 5] = [
+    "ryzenadj.exe",
+    "libryzenadj.dll",
+    "WinRing0x64.dll",
+    "WinRing0x64.sys",
+    "inpoutx64.dll",
+];
+
+/// Fetches the official `RyzenAdj` Windows release when the installer did not
+/// bundle it (portable builds, dev machines).
+///
+/// Files go to `%LOCALAPPDATA%\com.console.experience\tools\ryzenadj`, which
+/// `RyzenAdjAdapter` and `IntelPowerLimitAdapter` search after the app folder.
+pub struct RyzenAdjDownloader;
+
+impl RyzenAdjDownloader {
+    /// Folder holding the downloaded tools.
+    #[must_use]
+    pub fn tools_dir() -> PathBuf {
+        app_dirs::app_data_dir().join("tools").join("ryzenadj")
+    }
+
+    /// `true` if a previous download left the CLI and library in place.
+    #[must_use]
+    pub fn is_installed() -> bool {
+        let dir = Self::tools_dir();
+        dir.join("ryzenadj.exe").exists() && dir.join("libryzenadj.dll").exists()
+    }
+
+    /// Downloads the latest Windows release and extracts it into [`Self::tools_dir`].
+    ///
+    /// Blocking (network); call from a background thread.
+    ///
+    /// # Errors
+    /// Returns error if GitHub is unreachable, the release has no Windows
+    /// archive, or the files cannot be written.
+    pub fn download() -> Result<PathBuf, BalamError> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .user_agent("BalamGridEngine/1.0")
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))?;
+
+        let release: serde_json::Value = client
+            .get(LATEST_RELEASE_URL)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::json)
+            .map_err(|e| BalamError::platform("Failed to query RyzenAdj releases", e))?;
+        let url = Self::windows_asset_url(&release)
+            .ok_or_else(|| BalamError::NotFound("RyzenAdj Windows release archive".to_string()))?;
+
+        info!("⬇️ Downloading RyzenAdj from {}", url);
+        let archive = client
+            .get(&url)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::bytes)
+            .map_err(|e| BalamError::platform("Failed to download RyzenAdj", e))?;
+
+        let dir = Self::tools_dir();
+        Self::extract(&archive, &dir)?;
+        info!("✅ RyzenAdj installed to {}", dir.display());
+        Ok(dir)
+    }
+
+    /// Picks the Windows zip from a GitHub release payload.
+    fn windows_asset_url(release: &serde_json::Value) -> Option<String> {
+        release["assets"]
+            .as_array()?
+            .iter()
+            .find(|asset| {
+                asset["name"]
+                    .as_str()
+                    .is_some_and(|name| name.to_lowercase().contains("win") && name.ends_with(".zip"))
+            })
+            .and_then(|asset| asset["browser_download_url"].as_str())
+            .map(str::to_string)
+    }
+
+    /// Extracts the known tool files, ignoring folder structure and anything else.
+    fn extract(archive: &[u8], dir: &Path) -> Result<(), BalamError> {
+        let mut zip = zip::ZipArchive::new(Cursor::new(archive))
+            .map_err(|e| BalamError::platform("Invalid RyzenAdj archive", e))?;
+        fs::create_dir_all(dir).map_err(|e| BalamError::platform("Failed to create tools folder", e))?;
+
+        let mut extracted = 0;
+        for index in 0..zip.len() {
+            let mut entry = zip
+                .by_index(index)
+                .map_err(|e| BalamError::platform("Corrupt RyzenAdj archive", e))?;
+            let Some(name) = entry
+                .enclosed_name()
+                .and_then(|path| path.file_name().map(|n| n.to_string_lossy().to_string()))
+            else {
+                continue;
+            };
+            if entry.is_dir() || !TOOL_FILES.iter().any(|f| f.eq_ignore_ascii_case(&name)) {
+                continue;
+            }
+
+            let mut file = fs::File::create(dir.join(&name))
+                .map_err(|e| BalamError::platform(&format!("Failed to write {name}"), e))?;
+            std::io::copy(&mut entry, &mut file)
+                .map_err(|e| BalamError::platform(&format!("Failed to write {name}"), e))?;
+            extracted += 1;
+        }
+
+        if extracted == 0 {
+            warn!("RyzenAdj archive contained none of the expected files");
+            return Err(BalamError::NotFound("ryzenadj.exe in release archive".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows_asset_url_skips_source_archives() {
+        let release = serde_json::json!({
+            "assets": [
+                { "name": "ryzenadj-linux.tar.gz", "browser_download_url": "https://example.com/linux" },
+                { "name": "ryzenadj-win64.zip", "browser_download_url": "https://example.com/win64" }
+            ]
+        });
+        assert_eq!(
+            RyzenAdjDownloader::windows_asset_url(&release).as_deref(),
+            Some("https://example.com/win64")
+        );
+        assert_eq!(RyzenAdjDownloader::windows_asset_url(&serde_json::json!({})), None);
+    }
+}
@@ -0,0 +1,98 @@
+use super::{IntelPowerLimitAdapter, RyzenAdjAdapter};
+use crate::domain::performance::{TDPConfig, TdpBackend};
+use crate::domain::BalamError;
+use crate::ports::performance_port::{HardwareVendor, PerformancePort};
+use once_cell::sync::OnceCell;
+use tracing::info;
+
+/// Routes TDP control to the backend matching the CPU vendor.
+///
+/// AMD goes through `RyzenAdjAdapter` (library, then CLI); Intel through
+/// `IntelPowerLimitAdapter`. Anything else reports no TDP support.
+pub struct TdpController {
+    vendor: OnceCell<HardwareVendor>,
+    amd: RyzenAdjAdapter,
+    intel: IntelPowerLimitAdapter,
+}
+
+impl Default for TdpController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TdpController {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            vendor: OnceCell::new(),
+            amd: RyzenAdjAdapter::new(),
+            intel: IntelPowerLimitAdapter::new(),
+        }
+    }
+
+    /// Backend used for this machine, `None` if TDP cannot be controlled.
+    pub fn backend(&self) -> Option<TdpBackend> {
+        match self.vendor() {
+            HardwareVendor::AMD => self.amd.backend(),
+            HardwareVendor::Intel => self.intel.supports_tdp_control().then_some(TdpBackend::IntelMsr),
+            HardwareVendor::Unknown => None,
+        }
+    }
+
+    fn vendor(&self) -> HardwareVendor {
+        *self.vendor.get_or_init(|| {
+            let vendor = cpu_vendor();
+            info!("CPU vendor for TDP control: {:?}", vendor);
+            vendor
+        })
+    }
+
+    fn port(&self) -> Result<&dyn PerformancePort, BalamError> {
+        match self.vendor() {
+            HardwareVendor::AMD => Ok(&self.amd),
+            HardwareVendor::Intel => Ok(&self.intel),
+            HardwareVendor::Unknown => Err(BalamError::NotSupported("TDP control on this CPU".to_string())),
+        }
+    }
+}
+
+/// Reads the CPUID vendor string.
+fn cpu_vendor() -> HardwareVendor {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use std::arch::x86_64::__cpuid;
+        let cpuid = unsafe { __cpuid(0) };
+        let mut vendor = [0u8; 12];
+        vendor[..4].copy_from_slice(&cpuid.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&cpuid.edx.to_le_bytes());
+        vendor[8..].copy_from_slice(&cpuid.ecx.to_le_bytes());
+        match &vendor {
+            b"AuthenticAMD" => HardwareVendor::AMD,
+            b"GenuineIntel" => HardwareVendor::Intel,
+            _ => HardwareVendor::Unknown,
+        }
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        HardwareVendor::Unknown
+    }
+}
+
+impl PerformancePort for TdpController {
+    fn detect_hardware(&self) -> Result<HardwareVendor, BalamError> {
+        Ok(self.vendor())
+    }
+
+    fn get_tdp_config(&self) -> Result<TDPConfig, BalamError> {
+        self.port()?.get_tdp_config()
+    }
+
+    fn set_tdp(&self, watts: u32) -> Result<(), BalamError> {
+        self.port()?.set_tdp(watts)
+    }
+
+    fn supports_tdp_control(&self) -> bool {
+        self.backend().is_some()
+    }
+}
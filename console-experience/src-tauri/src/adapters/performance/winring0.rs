@@ -0,0 +1,106 @@
+use super::RyzenAdjDownloader;
+use crate::domain::BalamError;
+use std::path::PathBuf;
+
+/// Loaded WinRing0x64.dll: MSR and I/O port access from user mode.
+///
+/// Shared by the Intel power-limit and GPD fan adapters. Loading the driver
+/// needs administrator rights; `DeinitializeOls` runs on drop.
+pub(crate) struct WinRing0 {
+    _lib: libloading::Library,
+    deinitialize: unsafe extern "system" fn(),
+    rdmsr: unsafe extern "system" fn(u32, *mut u32, *mut u32) -> i32,
+    wrmsr: unsafe extern "system" fn(u32, u32, u32) -> i32,
+    read_io_port_byte: unsafe extern "system" fn(u16) -> u8,
+    write_io_port_byte: unsafe extern "system" fn(u16, u8),
+}
+
+// SAFETY: owners keep the driver behind a mutex, so calls are never concurrent
+unsafe impl Send for WinRing0 {}
+
+impl WinRing0 {
+    /// Loads the DLL (bundled or downloaded with `RyzenAdj`) and starts the driver.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - DLL not bundled nor downloaded
+    /// - `BalamError::AccessDenied` - Driver failed to start (not elevated)
+    pub(crate) fn load() -> Result<Self, BalamError> {
+        let candidates = [
+            PathBuf::from("WinRing0x64.dll"),
+            PathBuf::from("resources/WinRing0x64.dll"),
+            PathBuf::from("../WinRing0x64.dll"),
+            RyzenAdjDownloader::tools_dir().join("WinRing0x64.dll"),
+        ];
+        let lib = candidates
+            .iter()
+            .find_map(|path| unsafe { libloading::Library::new(path).ok() })
+            .ok_or_else(|| BalamError::NotFound("WinRing0x64.dll".to_string()))?;
+
+        unsafe {
+            let initialize = *lib
+                .get::<unsafe extern "system" fn() -> i32>(b"InitializeOls")
+                .map_err(|e| BalamError::platform("Failed to load InitializeOls", e))?;
+            let deinitialize = *lib
+                .get::<unsafe extern "system" fn()>(b"DeinitializeOls")
+                .map_err(|e| BalamError::platform("Failed to load DeinitializeOls", e))?;
+            let rdmsr = *lib
+                .get::<unsafe extern "system" fn(u32, *mut u32, *mut u32) -> i32>(b"Rdmsr")
+                .map_err(|e| BalamError::platform("Failed to load Rdmsr", e))?;
+            let wrmsr = *lib
+                .get::<unsafe extern "system" fn(u32, u32, u32) -> i32>(b"Wrmsr")
+                .map_err(|e| BalamError::platform("Failed to load Wrmsr", e))?;
+            let read_io_port_byte = *lib
+                .get::<unsafe extern "system" fn(u16) -> u8>(b"ReadIoPortByte")
+                .map_err(|e| BalamError::platform("Failed to load ReadIoPortByte", e))?;
+            let write_io_port_byte = *lib
+                .get::<unsafe extern "system" fn(u16, u8)>(b"WriteIoPortByte")
+                .map_err(|e| BalamError::platform("Failed to load WriteIoPortByte", e))?;
+
+            if initialize() == 0 {
+                return Err(BalamError::AccessDenied(
+                    "WinRing0 driver could not be loaded (run as administrator)".to_string(),
+                ));
+            }
+
+            Ok(Self {
+                _lib: lib,
+                deinitialize,
+                rdmsr,
+                wrmsr,
+                read_io_port_byte,
+                write_io_port_byte,
+            })
+        }
+    }
+
+    /// Reads a model-specific register.
+    pub(crate) fn read_msr(&self, index: u32) -> Result<u64, BalamError> {
+        let (mut eax, mut edx) = (0u32, 0u32);
+        if unsafe { (self.rdmsr)(index, &raw mut eax, &raw mut edx) } == 0 {
+            return Err(BalamError::Platform(format!("Failed to read MSR {index:#x}")));
+        }
+        Ok((u64::from(edx) << 32) | u64::from(eax))
+    }
+
+    /// Writes a model-specific register.
+    pub(crate) fn write_msr(&self, index: u32, value: u64) -> Result<(), BalamError> {
+        if unsafe { (self.wrmsr)(index, value as u32, (value >> 32) as u32) } == 0 {
+            return Err(BalamError::Platform(format!("Failed to write MSR {index:#x}")));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn read_port(&self, port: u16) -> u8 {
+        unsafe { (self.read_io_port_byte)(port) }
+    }
+
+    pub(crate) fn write_port(&self, port: u16, value: u8) {
+        unsafe { (self.write_io_port_byte)(port, value) }
+    }
+}
+
+impl Drop for WinRing0 {
+    fn drop(&mut self) {
+        unsafe { (self.deinitialize)() };
+    }
+}
@@ -1,28 +1,44 @@
 use std::mem;
 use std::sync::{Arc, Mutex};
 use tracing::{debug, info};
-use windows::core::PCWSTR;
-use windows::Win32::Foundation::LUID;
+use windows::core::{s, w, PCWSTR};
+use windows::Win32::Foundation::{CloseHandle, HANDLE, LUID, NTSTATUS};
 use windows::Win32::Graphics::Gdi::{EnumDisplayDevicesW, DISPLAY_DEVICEW, DISPLAY_DEVICE_PRIMARY_DEVICE};
+use windows::Win32::System::LibraryLoader::{GetProcAddress, LoadLibraryW};
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
 
 // Manual D3DKMT type definitions (not available in windows-rs 0.52)
 // These are from d3dkmthk.h in Windows SDK
 
-#[allow(dead_code)]
+/// `D3DKMT_QUERYSTATISTICS_TYPE` values used here.
+const D3DKMT_QUERYSTATISTICS_ADAPTER: u32 = 0;
+const D3DKMT_QUERYSTATISTICS_SEGMENT: u32 = 3;
+const D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT: u32 = 4;
+
+type D3DKMTQueryStatisticsFn = unsafe extern "system" fn(*const D3DKMT_QUERYSTATISTICS) -> NTSTATUS;
+type D3DKMTOpenAdapterFromGdiDisplayNameFn =
+    unsafe extern "system" fn(*mut D3DKMT_OPENADAPTERFROMGDIDISPLAYNAME) -> NTSTATUS;
+type D3DKMTCloseAdapterFn = unsafe extern "system" fn(*const D3DKMT_CLOSEADAPTER) -> NTSTATUS;
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 struct D3DKMT_QUERYSTATISTICS {
     query_type: u32,
     adapter_luid: LUID,
-    process_handle: *mut std::ffi::c_void,
+    process_handle: HANDLE,
     query_result: D3DKMT_QUERYSTATISTICS_RESULT,
+    /// Query input union; segment queries take the segment id
+    segment_id: u32,
+    _query_padding: u32,
 }
 
 #[repr(C)]
 #[derive(Copy, Clone)]
 union D3DKMT_QUERYSTATISTICS_RESULT {
     adapter_info: std::mem::ManuallyDrop<D3DKMT_QUERYSTATISTICS_ADAPTER_INFO>,
-    _padding: [u8; 4096], // Large enough for any result type
+    segment_info: std::mem::ManuallyDrop<D3DKMT_QUERYSTATISTICS_SEGMENT_INFO>,
+    process_segment_info: std::mem::ManuallyDrop<D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_INFO>,
+    _padding: [u64; 97], // sizeof(D3DKMT_QUERYSTATISTICS_RESULT) = 776 bytes
 }
 
 #[allow(dead_code)]
@@ -35,6 +51,50 @@ struct D3DKMT_QUERYSTATISTICS_ADAPTER_INFO {
     _reserved: [u32; 13],
 }
 
+/// Leading fields of `D3DKMT_QUERYSTATISTICS_SEGMENT_INFORMATION`.
+#[allow(dead_code)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct D3DKMT_QUERYSTATISTICS_SEGMENT_INFO {
+    commit_limit: u64,
+    bytes_committed: u64,
+    bytes_resident: u64,
+    _memory: [u64; 2],
+    /// Non-zero for aperture (shared system memory) segments
+    aperture: u32,
+}
+
+/// Leading fields of `D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_INFORMATION`.
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT_INFO {
+    bytes_committed: u64,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct D3DKMT_OPENADAPTERFROMGDIDISPLAYNAME {
+    device_name: [u16; 32],
+    adapter_handle: u32,
+    adapter_luid: LUID,
+    vid_pn_source_id: u32,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+struct D3DKMT_CLOSEADAPTER {
+    adapter_handle: u32,
+}
+
+/// Primary GPU found by [`D3DKMTAdapter::ensure_initialized`].
+struct PrimaryAdapter {
+    luid: LUID,
+    query_statistics: D3DKMTQueryStatisticsFn,
+    /// Segments in the GPU's own memory (non-aperture): dedicated VRAM, or
+    /// the carve-out reserved for an iGPU
+    dedicated_segments: Vec<u32>,
+}
+
 /// D3DKMT adapter for cross-vendor GPU monitoring (NVIDIA, AMD, Intel).
 ///
 /// Uses Windows DirectX Kernel Mode Thunks (D3DKMT) API to query GPU metrics.
@@ -50,6 +110,8 @@ struct D3DKMT_QUERYSTATISTICS_ADAPTER_INFO {
 /// - GPU utilization percentage (0-100) - **Limited in D3DKMT**
 /// - GPU temperature in Celsius - **NOT AVAILABLE**
 /// - GPU power draw in Watts - **NOT AVAILABLE**
+/// - Dedicated GPU memory, adapter-wide and per process (what Task Manager
+///   shows as "Dedicated GPU memory")
 ///
 /// # Important Limitations
 /// D3DKMT API has significant limitations compared to vendor-specific APIs:
@@ -70,8 +132,8 @@ struct D3DKMT_QUERYSTATISTICS_ADAPTER_INFO {
 /// - `Ok(None)` - Metric not supported by D3DKMT API
 /// - `Err(...)` - D3DKMT error (driver issue, API call failed)
 pub struct D3DKMTAdapter {
-    /// GPU adapter LUID (Locally Unique Identifier)
-    adapter_luid: Arc<Mutex<Option<LUID>>>,
+    /// Primary GPU adapter (LUID and dedicated memory segments)
+    adapter: Arc<Mutex<Option<PrimaryAdapter>>>,
     /// Initialization attempted flag
     init_attempted: Arc<Mutex<bool>>,
 }
@@ -81,7 +143,7 @@ impl D3DKMTAdapter {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            adapter_luid: Arc::new(Mutex::new(None)),
+            adapter: Arc::new(Mutex::new(None)),
             init_attempted: Arc::new(Mutex::new(false)),
         }
     }
@@ -100,12 +162,12 @@ impl D3DKMTAdapter {
                 .map_err(|e| format!("Failed to lock init_attempted mutex: {e}"))?;
 
             if *attempted {
-                let luid_guard = self
-                    .adapter_luid
+                let adapter_guard = self
+                    .adapter
                     .lock()
-                    .map_err(|e| format!("Failed to lock adapter_luid mutex: {e}"))?;
+                    .map_err(|e| format!("Failed to lock adapter mutex: {e}"))?;
 
-                return if luid_guard.is_some() {
+                return if adapter_guard.is_some() {
                     Ok(())
                 } else {
                     Err("D3DKMT initialization failed previously".to_string())
@@ -126,6 +188,19 @@ impl D3DKMTAdapter {
         info!("Initializing D3DKMT GPU monitoring...");
 
         unsafe {
+            let gdi32 = LoadLibraryW(w!("gdi32.dll")).map_err(|e| format!("Failed to load gdi32.dll: {e}"))?;
+            let query_statistics =
+                GetProcAddress(gdi32, s!("D3DKMTQueryStatistics")).ok_or("D3DKMTQueryStatistics not available")?;
+            let open_adapter = GetProcAddress(gdi32, s!("D3DKMTOpenAdapterFromGdiDisplayName"))
+                .ok_or("D3DKMTOpenAdapterFromGdiDisplayName not available")?;
+            let close_adapter =
+                GetProcAddress(gdi32, s!("D3DKMTCloseAdapter")).ok_or("D3DKMTCloseAdapter not available")?;
+
+            // SAFETY: signatures from d3dkmthk.h
+            let query_statistics: D3DKMTQueryStatisticsFn = mem::transmute(query_statistics);
+            let open_adapter: D3DKMTOpenAdapterFromGdiDisplayNameFn = mem::transmute(open_adapter);
+            let close_adapter: D3DKMTCloseAdapterFn = mem::transmute(close_adapter);
+
             let mut display_device: DISPLAY_DEVICEW = mem::zeroed();
             display_device.cb = mem::size_of::<DISPLAY_DEVICEW>() as u32;
 
@@ -133,21 +208,42 @@ impl D3DKMTAdapter {
             while EnumDisplayDevicesW(PCWSTR::null(), device_num, &mut display_device, 0).as_bool() {
                 // Check if this is the primary display
                 if (display_device.StateFlags & DISPLAY_DEVICE_PRIMARY_DEVICE) != 0 {
-                    // Extract LUID from device name
-                    // Note: This is a simplified approach. In production, you'd parse
-                    // the device string to get the actual LUID
-                    let luid = LUID {
-                        LowPart: device_num,
-                        HighPart: 0,
+                    // The LUID comes from opening the adapter behind the GDI
+                    // device name (\\.\DISPLAY1); the handle isn't needed afterwards
+                    let mut open = D3DKMT_OPENADAPTERFROMGDIDISPLAYNAME {
+                        device_name: display_device.DeviceName,
+                        adapter_handle: 0,
+                        adapter_luid: LUID::default(),
+                        vid_pn_source_id: 0,
                     };
-
-                    info!("Found primary GPU adapter (LUID: {:?})", luid);
-
-                    let mut luid_guard = self
-                        .adapter_luid
+                    let status = open_adapter(&mut open);
+                    if status.is_err() {
+                        return Err(format!(
+                            "D3DKMTOpenAdapterFromGdiDisplayName failed: 0x{:08X}",
+                            status.0
+                        ));
+                    }
+                    let _ = close_adapter(&D3DKMT_CLOSEADAPTER {
+                        adapter_handle: open.adapter_handle,
+                    });
+                    let luid = open.adapter_luid;
+
+                    let dedicated_segments = Self::find_dedicated_segments(query_statistics, luid);
+                    info!(
+                        "Found primary GPU adapter (LUID: {:?}, {} dedicated memory segments)",
+                        luid,
+                        dedicated_segments.len()
+                    );
+
+                    let mut adapter_guard = self
+                        .adapter
                         .lock()
-                        .map_err(|e| format!("Failed to lock adapter_luid mutex: {e}"))?;
-                    *luid_guard = Some(luid);
+                        .map_err(|e| format!("Failed to lock adapter mutex: {e}"))?;
+                    *adapter_guard = Some(PrimaryAdapter {
+                        luid,
+                        query_statistics,
+                        dedicated_segments,
+                    });
 
                     return Ok(());
                 }
@@ -159,6 +255,27 @@ impl D3DKMTAdapter {
         Err("No primary GPU adapter found".to_string())
     }
 
+    /// Ids of the adapter's non-aperture memory segments.
+    unsafe fn find_dedicated_segments(query_statistics: D3DKMTQueryStatisticsFn, luid: LUID) -> Vec<u32> {
+        let mut query: D3DKMT_QUERYSTATISTICS = mem::zeroed();
+        query.query_type = D3DKMT_QUERYSTATISTICS_ADAPTER;
+        query.adapter_luid = luid;
+        if query_statistics(&query).is_err() {
+            return Vec::new();
+        }
+        let nb_segments = query.query_result.adapter_info.nb_segments;
+
+        (0..nb_segments)
+            .filter(|&segment_id| {
+                let mut query: D3DKMT_QUERYSTATISTICS = mem::zeroed();
+                query.query_type = D3DKMT_QUERYSTATISTICS_SEGMENT;
+                query.adapter_luid = luid;
+                query.segment_id = segment_id;
+                query_statistics(&query).is_ok() && query.query_result.segment_info.aperture == 0
+            })
+            .collect()
+    }
+
     /// Checks if D3DKMT is available (GPU adapter initialized).
     #[must_use]
     pub fn is_available(&self) -> bool {
@@ -213,6 +330,83 @@ impl D3DKMTAdapter {
         debug!("D3DKMT does not support power monitoring - returning None");
         Ok(None)
     }
+
+    /// Gets dedicated GPU memory usage of the primary adapter in bytes.
+    ///
+    /// Sums the non-aperture segments: VRAM on a discrete GPU, the memory
+    /// reserved for the GPU (UMA frame buffer) on an iGPU.
+    ///
+    /// # Returns
+    /// - `Ok(Some((used, total)))` - Resident and committable bytes
+    /// - `Ok(None)` - Adapter has no dedicated segment
+    pub fn get_vram_usage(&self) -> Result<Option<(u64, u64)>, String> {
+        self.ensure_initialized()?;
+        let adapter_guard = self
+            .adapter
+            .lock()
+            .map_err(|e| format!("Failed to lock adapter mutex: {e}"))?;
+        let adapter = adapter_guard.as_ref().ok_or("D3DKMT not initialized")?;
+        if adapter.dedicated_segments.is_empty() {
+            return Ok(None);
+        }
+
+        let (mut used, mut total) = (0u64, 0u64);
+        for &segment_id in &adapter.dedicated_segments {
+            unsafe {
+                let mut query: D3DKMT_QUERYSTATISTICS = mem::zeroed();
+                query.query_type = D3DKMT_QUERYSTATISTICS_SEGMENT;
+                query.adapter_luid = adapter.luid;
+                query.segment_id = segment_id;
+                let status = (adapter.query_statistics)(&query);
+                if status.is_err() {
+                    return Err(format!("D3DKMTQueryStatistics (segment) failed: 0x{:08X}", status.0));
+                }
+                used += query.query_result.segment_info.bytes_resident;
+                total += query.query_result.segment_info.commit_limit;
+            }
+        }
+        Ok(Some((used, total)))
+    }
+
+    /// Gets the dedicated GPU memory committed by `pid` in bytes.
+    ///
+    /// # Returns
+    /// - `Ok(Some(bytes))` - Memory in the adapter's dedicated segments
+    /// - `Ok(None)` - No dedicated segment, or the process is not accessible
+    pub fn get_process_vram_usage(&self, pid: u32) -> Result<Option<u64>, String> {
+        self.ensure_initialized()?;
+        let adapter_guard = self
+            .adapter
+            .lock()
+            .map_err(|e| format!("Failed to lock adapter mutex: {e}"))?;
+        let adapter = adapter_guard.as_ref().ok_or("D3DKMT not initialized")?;
+        if adapter.dedicated_segments.is_empty() {
+            return Ok(None);
+        }
+
+        unsafe {
+            let Ok(process) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+                return Ok(None);
+            };
+            let committed = adapter.dedicated_segments.iter().try_fold(0u64, |sum, &segment_id| {
+                let mut query: D3DKMT_QUERYSTATISTICS = mem::zeroed();
+                query.query_type = D3DKMT_QUERYSTATISTICS_PROCESS_SEGMENT;
+                query.adapter_luid = adapter.luid;
+                query.process_handle = process;
+                query.segment_id = segment_id;
+                let status = (adapter.query_statistics)(&query);
+                if status.is_err() {
+                    return Err(format!(
+                        "D3DKMTQueryStatistics (process segment) failed: 0x{:08X}",
+                        status.0
+                    ));
+                }
+                Ok(sum + query.query_result.process_segment_info.bytes_committed)
+            });
+            let _ = CloseHandle(process);
+            committed.map(Some)
+        }
+    }
 }
 
 impl Default for D3DKMTAdapter {
@@ -1,9 +1,11 @@
 pub mod d3dkmt_adapter;
 pub mod nvml_adapter;
 pub mod pdh_adapter;
+pub mod process_metrics;
 pub mod windows_perf_monitor;
 
 pub use d3dkmt_adapter::D3DKMTAdapter;
 pub use nvml_adapter::NVMLAdapter;
 pub use pdh_adapter::PdhAdapter;
+pub use process_metrics::ProcessMetricsSampler;
 pub use windows_perf_monitor::WindowsPerfMonitor;
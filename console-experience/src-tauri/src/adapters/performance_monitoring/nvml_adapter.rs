@@ -1,4 +1,4 @@
-use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+use nvml_wrapper::enum_wrappers::device::{Clock, TemperatureSensor};
 use nvml_wrapper::{Device, Nvml};
 use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
@@ -22,6 +22,7 @@ use tracing::{debug, info, warn};
 /// - GPU utilization percentage (0-100)
 /// - GPU temperature in Celsius
 /// - GPU power draw in Watts
+/// - GPU graphics clock in MHz
 /// - GPU memory usage (used/total MB)
 ///
 /// # Error Handling
@@ -237,6 +238,25 @@ impl NVMLAdapter {
         })
     }
 
+    /// Gets the current graphics clock in MHz.
+    ///
+    /// # Returns
+    /// - `Ok(`Some`(mhz))` - Clock available
+    /// - `Ok(`None`)` - Clock not reported by this GPU
+    /// - `Err(...)` - NVML error
+    pub fn get_gpu_clock(&self) -> Result<Option<u32>, String> {
+        self.with_device(|device| match device.clock_info(Clock::Graphics) {
+            Ok(mhz) => {
+                debug!("GPU clock: {} MHz", mhz);
+                Ok(Some(mhz))
+            },
+            Err(e) => {
+                warn!("Failed to get GPU clock: {e}");
+                Ok(None)
+            },
+        })
+    }
+
     /// Gets GPU memory usage (used/total in MB).
     ///
     /// # Returns
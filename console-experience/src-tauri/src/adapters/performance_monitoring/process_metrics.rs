@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Instant;
+use sysinfo::{Pid, System};
+use tracing::{debug, info, warn};
+use windows::core::{w, PCWSTR};
+use windows::Win32::Foundation::{CloseHandle, ERROR_SUCCESS};
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterArrayW, PdhOpenQueryW,
+    PDH_FMT_COUNTERVALUE_ITEM_W, PDH_FMT_DOUBLE,
+};
+use windows::Win32::System::ProcessStatus::{
+    GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS, PROCESS_MEMORY_COUNTERS_EX2,
+};
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+use crate::adapters::performance_monitoring::D3DKMTAdapter;
+use crate::domain::performance::ProcessMetrics;
+
+// PDH handle types (opaque pointers)
+#[allow(non_camel_case_types)]
+type PDH_HQUERY = isize;
+#[allow(non_camel_case_types)]
+type PDH_HCOUNTER = isize;
+
+/// `PDH_MORE_DATA`: buffer too small, size returned in `lpdwBufferSize`.
+const PDH_MORE_DATA: u32 = 0x8000_07D2;
+
+/// Per-process resource sampler for multi-game sessions.
+///
+/// # Data Sources
+/// - **CPU/RAM/disk I/O:** sysinfo process table (refreshed only for requested PIDs)
+/// - **Private working set:** `GetProcessMemoryInfo` (`PROCESS_MEMORY_COUNTERS_EX2`)
+/// - **GPU:** `\GPU Engine(*engtype_3D)\Utilization Percentage` instance array.
+///   Instance names start with `pid_<PID>_`, so one PDH query covers every
+///   running game; engines of the same PID are summed.
+/// - **Dedicated GPU memory:** D3DKMT process segment statistics of the primary GPU
+///
+/// # Sampling
+/// CPU, GPU and disk I/O are deltas between two samples: the first call for
+/// a new PID reports 0. Poll at ≥500ms (the overlay refresh rate).
+pub struct ProcessMetricsSampler {
+    system: Mutex<System>,
+    /// When each PID was last sampled (disk I/O rates)
+    last_sample: Mutex<HashMap<u32, Instant>>,
+    gpu: Mutex<Option<GpuEngineCounter>>,
+    gpu_init_attempted: Mutex<bool>,
+    d3dkmt: D3DKMTAdapter,
+}
+
+struct GpuEngineCounter {
+    query: PDH_HQUERY,
+    counter: PDH_HCOUNTER,
+}
+
+impl ProcessMetricsSampler {
+    /// Creates a sampler (PDH is initialized on first use).
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            system: Mutex::new(System::new()),
+            last_sample: Mutex::new(HashMap::new()),
+            gpu: Mutex::new(None),
+            gpu_init_attempted: Mutex::new(false),
+            d3dkmt: D3DKMTAdapter::new(),
+        }
+    }
+
+    /// Samples CPU, RAM, GPU usage and GPU memory of the given processes.
+    ///
+    /// PIDs that no longer exist are omitted from the result.
+    pub fn sample(&self, pids: &[u32]) -> HashMap<u32, ProcessMetrics> {
+        let mut metrics = HashMap::new();
+        if pids.is_empty() {
+            return metrics;
+        }
+
+        let cores = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get) as f32;
+
+        if let (Ok(mut system), Ok(mut last_sample)) = (self.system.lock(), self.last_sample.lock()) {
+            let now = Instant::now();
+            for &pid in pids {
+                let sys_pid = Pid::from_u32(pid);
+                if !system.refresh_process(sys_pid) {
+                    continue;
+                }
+                if let Some(process) = system.process(sys_pid) {
+                    // Disk counters cover the time since the previous refresh of this PID
+                    let elapsed = last_sample
+                        .insert(pid, now)
+                        .map_or(0.0, |previous| now.duration_since(previous).as_secs_f64());
+                    let disk = process.disk_usage();
+                    let rate = |bytes: u64| {
+                        if elapsed > 0.0 {
+                            (bytes as f64 / elapsed) as u64
+                        } else {
+                            0
+                        }
+                    };
+                    metrics.insert(
+                        pid,
+                        ProcessMetrics {
+                            pid,
+                            cpu_usage: (process.cpu_usage() / cores).clamp(0.0, 100.0),
+                            ram_used_mb: process.memory() / (1024 * 1024),
+                            gpu_usage: None,
+                            private_working_set_mb: private_working_set(pid).map(|bytes| bytes / (1024 * 1024)),
+                            gpu_dedicated_mb: self
+                                .d3dkmt
+                                .get_process_vram_usage(pid)
+                                .ok()
+                                .flatten()
+                                .map(|bytes| bytes / (1024 * 1024)),
+                            disk_read_bytes_per_sec: rate(disk.read_bytes),
+                            disk_write_bytes_per_sec: rate(disk.written_bytes),
+                        },
+                    );
+                }
+            }
+            // Forget exited PIDs (a reused PID starts a fresh delta)
+            last_sample.retain(|pid, _| metrics.contains_key(pid) || !pids.contains(pid));
+        } else {
+            warn!("Failed to lock process sampler system");
+        }
+
+        if let Some(gpu) = self.sample_gpu() {
+            for (pid, entry) in &mut metrics {
+                entry.gpu_usage = Some(gpu.get(pid).copied().unwrap_or(0.0).clamp(0.0, 100.0));
+            }
+        }
+
+        metrics
+    }
+
+    /// Per-PID 3D engine utilization (`None` if PDH is unavailable).
+    fn sample_gpu(&self) -> Option<HashMap<u32, f32>> {
+        self.ensure_gpu_initialized();
+        let guard = self.gpu.lock().ok()?;
+        let gpu = guard.as_ref()?;
+
+        unsafe {
+            if PdhCollectQueryData(gpu.query) != ERROR_SUCCESS.0 {
+                return None;
+            }
+
+            let mut buffer_size = 0u32;
+            let mut item_count = 0u32;
+            let result =
+                PdhGetFormattedCounterArrayW(gpu.counter, PDH_FMT_DOUBLE, &mut buffer_size, &mut item_count, None);
+            if result != PDH_MORE_DATA || buffer_size == 0 {
+                return Some(HashMap::new());
+            }
+
+            // Items are followed by their instance name strings in the same buffer
+            let item_size = std::mem::size_of::<PDH_FMT_COUNTERVALUE_ITEM_W>();
+            let mut buffer: Vec<PDH_FMT_COUNTERVALUE_ITEM_W> =
+                Vec::with_capacity((buffer_size as usize).div_ceil(item_size));
+            let result = PdhGetFormattedCounterArrayW(
+                gpu.counter,
+                PDH_FMT_DOUBLE,
+                &mut buffer_size,
+                &mut item_count,
+                Some(buffer.as_mut_ptr()),
+            );
+            if result != ERROR_SUCCESS.0 {
+                debug!("PdhGetFormattedCounterArrayW failed with code: {result}");
+                return None;
+            }
+            buffer.set_len(item_count as usize);
+
+            let mut usage: HashMap<u32, f32> = HashMap::new();
+            for item in &buffer {
+                let Ok(name) = item.szName.to_string() else { continue };
+                if let Some(pid) = parse_engine_instance_pid(&name) {
+                    *usage.entry(pid).or_default() += item.FmtValue.Anonymous.doubleValue as f32;
+                }
+            }
+            Some(usage)
+        }
+    }
+
+    fn ensure_gpu_initialized(&self) {
+        let Ok(mut attempted) = self.gpu_init_attempted.lock() else {
+            return;
+        };
+        if *attempted {
+            return;
+        }
+        *attempted = true;
+
+        unsafe {
+            let mut query: PDH_HQUERY = std::mem::zeroed();
+            if PdhOpenQueryW(PCWSTR::null(), 0, &mut query) != ERROR_SUCCESS.0 {
+                warn!("Per-process GPU monitoring unavailable (PdhOpenQueryW failed)");
+                return;
+            }
+
+            let mut counter: PDH_HCOUNTER = std::mem::zeroed();
+            let path = w!("\\GPU Engine(*engtype_3D)\\Utilization Percentage");
+            if PdhAddEnglishCounterW(query, path, 0, &mut counter) != ERROR_SUCCESS.0 {
+                let _ = PdhCloseQuery(query);
+                warn!("Per-process GPU monitoring unavailable (GPU Engine counter missing)");
+                return;
+            }
+
+            // Baseline sample: rate counters need two collections
+            let _ = PdhCollectQueryData(query);
+
+            if let Ok(mut gpu) = self.gpu.lock() {
+                *gpu = Some(GpuEngineCounter { query, counter });
+                info!("Per-process GPU monitoring initialized");
+            }
+        }
+    }
+}
+
+impl Default for ProcessMetricsSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ProcessMetricsSampler {
+    fn drop(&mut self) {
+        if let Ok(mut gpu) = self.gpu.lock() {
+            if let Some(gpu) = gpu.take() {
+                unsafe {
+                    let _ = PdhCloseQuery(gpu.query);
+                }
+            }
+        }
+    }
+}
+
+/// Private working set of `pid` in bytes (`None` if the process is not accessible).
+fn private_working_set(pid: u32) -> Option<u64> {
+    unsafe {
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut counters = PROCESS_MEMORY_COUNTERS_EX2 {
+            cb: std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX2>() as u32,
+            ..Default::default()
+        };
+        let result = GetProcessMemoryInfo(
+            process,
+            (&raw mut counters).cast::<PROCESS_MEMORY_COUNTERS>(),
+            counters.cb,
+        );
+        let _ = CloseHandle(process);
+        result.ok().map(|()| counters.PrivateWorkingSetSize as u64)
+    }
+}
+
+/// Extracts the PID from a GPU Engine instance name
+/// (e.g. `pid_1234_luid_0x00000000_0x0000C9C5_phys_0_eng_0_engtype_3D`).
+fn parse_engine_instance_pid(instance: &str) -> Option<u32> {
+    instance.strip_prefix("pid_")?.split('_').next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_engine_instance_pid() {
+        assert_eq!(
+            parse_engine_instance_pid("pid_1234_luid_0x00000000_0x0000C9C5_phys_0_eng_0_engtype_3D"),
+            Some(1234)
+        );
+        assert_eq!(parse_engine_instance_pid("luid_0x0_phys_0"), None);
+        assert_eq!(parse_engine_instance_pid("pid_abc_luid"), None);
+    }
+
+    #[test]
+    fn test_sample_skips_missing_processes() {
+        let sampler = ProcessMetricsSampler::new();
+        assert!(sampler.sample(&[]).is_empty());
+        assert!(!sampler.sample(&[999_999]).contains_key(&999_999));
+    }
+}
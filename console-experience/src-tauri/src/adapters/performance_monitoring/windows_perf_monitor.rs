@@ -1,5 +1,5 @@
 use crate::adapters::fps_service::FpsClient;
-use crate::adapters::performance_monitoring::{NVMLAdapter, PdhAdapter};
+use crate::adapters::performance_monitoring::{D3DKMTAdapter, NVMLAdapter, PdhAdapter, ProcessMetricsSampler};
 use crate::domain::performance::{FPSStats, PerformanceMetrics};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -13,13 +13,17 @@ use tracing::{info, warn};
 /// - **CPU/RAM:** sysinfo (fast, cross-platform)
 /// - **GPU (NVIDIA):** `NVML` adapter (official API, full metrics)
 /// - **GPU (AMD/Intel):** `PDH` adapter (Performance Counters, usage only)
+/// - **VRAM:** `NVML` on NVIDIA, `D3DKMT` segment statistics otherwise
 /// - **FPS:** `FpsClient` (Windows Service via Named Pipe)
+/// - **Game process:** `ProcessMetricsSampler` (CPU, private working set, 3D
+///   engine, dedicated GPU memory and disk I/O of the PID passed to
+///   [`Self::get_metrics_for`])
 ///
 /// # Performance
 /// - CPU/RAM: <10ms per query
 /// - GPU (`NVML`): <5ms per query
 /// - GPU (`PDH`): <10ms per query
-/// - FPS (Service): <1ms per query (cached, Named Pipe)
+/// - FPS (Service): <1ms per query (pushed over Named Pipe)
 /// - Total overhead: <2% CPU
 ///
 /// # Architecture
@@ -46,8 +50,12 @@ pub struct WindowsPerfMonitor {
     nvml: Arc<NVMLAdapter>,
     /// `PDH` adapter for universal GPU metrics (lazy initialized)
     pdh: Arc<PdhAdapter>,
+    /// `D3DKMT` adapter for dedicated GPU memory on AMD/Intel (lazy initialized)
+    d3dkmt: Arc<D3DKMTAdapter>,
     /// FPS Service client (Windows Service via Named Pipe)
     fps_client: Arc<FpsClient>,
+    /// Per-process sampler for the game's own share
+    processes: ProcessMetricsSampler,
     /// Last time system metrics were refreshed (for rate limiting)
     #[allow(dead_code)]
     last_refresh: Arc<Mutex<Instant>>,
@@ -104,7 +112,9 @@ impl WindowsPerfMonitor {
             system: system_arc,
             nvml: Arc::new(NVMLAdapter::new()),
             pdh: Arc::new(PdhAdapter::new()),
+            d3dkmt: Arc::new(D3DKMTAdapter::new()),
             fps_client: Arc::new(FpsClient::new()),
+            processes: ProcessMetricsSampler::new(),
             last_refresh,
         }
     }
@@ -223,6 +233,36 @@ impl WindowsPerfMonitor {
         }
     }
 
+    /// Gets the average current CPU clock in MHz (`None` if not reported).
+    fn get_cpu_clock(&self) -> Option<u32> {
+        let system_guard = self.system.lock().ok()?;
+        let cpus = system_guard.cpus();
+        let total: u64 = cpus.iter().map(|cpu| cpu.frequency()).sum();
+        (total > 0).then(|| (total / cpus.len() as u64) as u32)
+    }
+
+    /// Gets the GPU graphics clock in MHz.
+    ///
+    /// Uses NVML for NVIDIA GPUs. Returns `None` if not available.
+    fn get_gpu_clock(&self) -> Option<u32> {
+        self.nvml.get_gpu_clock().ok().flatten()
+    }
+
+    /// Gets dedicated GPU memory (used_mb, total_mb).
+    ///
+    /// NVML on NVIDIA GPUs, `D3DKMT` otherwise: on an iGPU the total is the
+    /// memory reserved for it, not the shared system memory it can spill into.
+    fn get_vram(&self) -> Option<(u64, u64)> {
+        if let Ok(Some((used_mb, total_mb))) = self.nvml.get_gpu_memory() {
+            return Some((used_mb as u64, total_mb as u64));
+        }
+        self.d3dkmt
+            .get_vram_usage()
+            .ok()
+            .flatten()
+            .map(|(used, total)| (used / (1024 * 1024), total / (1024 * 1024)))
+    }
+
     /// Gets complete performance metrics.
     ///
     /// # Returns
@@ -234,14 +274,31 @@ impl WindowsPerfMonitor {
     /// - GPU: 0% if `NVML` not available
     /// - FPS: `None` if FPS Service not available
     pub fn get_metrics(&self) -> PerformanceMetrics {
+        self.get_metrics_for(None)
+    }
+
+    /// Gets complete performance metrics plus the share of `game_pid`.
+    ///
+    /// `game` is `None` without a PID (Steam games) or once the process exited.
+    /// Per-process usage is a delta between calls: poll at ≥500ms.
+    pub fn get_metrics_for(&self, game_pid: Option<u32>) -> PerformanceMetrics {
         let cpu_usage = self.get_cpu_usage();
         let (ram_used_gb, ram_total_gb) = self.get_ram_usage();
         let gpu_usage = self.get_gpu_usage();
         let gpu_temp_c = self.get_gpu_temp();
         let gpu_power_w = self.get_gpu_power();
-
-        // Get FPS from FPS Service (Windows Service via Named Pipe)
-        let fps = self.fps_client.get_fps().map(FPSStats::new);
+        let vram = self.get_vram();
+
+        // Get FPS from FPS Service (Windows Service via Named Pipe): stats of
+        // the game PID when known, else of the process the service detected
+        let game_pid = game_pid.filter(|pid| *pid != 0);
+        self.fps_client.follow(game_pid);
+        let fps = self.fps_client.current_stats().map(|stats| FPSStats {
+            current_fps: stats.fps,
+            avg_fps_1s: stats.fps,
+            fps_1_percent_low: stats.fps_1_percent_low,
+            frame_time_ms: stats.frame_time_ms,
+        });
 
         PerformanceMetrics {
             cpu_usage,
@@ -251,7 +308,12 @@ impl WindowsPerfMonitor {
             gpu_temp_c,
             cpu_temp_c: None, // CPU temp not available via sysinfo on Windows
             gpu_power_w,
+            cpu_clock_mhz: self.get_cpu_clock(),
+            gpu_clock_mhz: self.get_gpu_clock(),
+            vram_used_mb: vram.map(|(used, _)| used),
+            vram_total_mb: vram.map(|(_, total)| total),
             fps,
+            game: game_pid.and_then(|pid| self.processes.sample(&[pid]).remove(&pid)),
         }
     }
 
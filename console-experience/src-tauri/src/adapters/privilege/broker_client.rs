@@ -0,0 +1,164 @@
+use super::peer::is_process_running;
+use crate::adapters::fps_service::execute_elevated;
+use crate::domain::privilege::{
+    BrokerRequest, BrokerResponse, PrivilegedOperation, BROKER_EXECUTABLE, BROKER_PARENT_FLAG, BROKER_PIPE_NAME,
+    BROKER_TOKEN_FLAG,
+};
+use crate::domain::BalamError;
+use parking_lot::Mutex;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::os::windows::io::AsRawHandle;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::System::Pipes::GetNamedPipeServerProcessId;
+
+/// Time for the player to answer the UAC prompt and the broker to come up.
+const LAUNCH_TIMEOUT: Duration = Duration::from_secs(60);
+const CONNECT_RETRY: Duration = Duration::from_millis(250);
+/// `ERROR_PIPE_BUSY`: every instance is serving another request.
+const ERROR_PIPE_BUSY: i32 = 231;
+
+/// Sends allow-listed operations to the elevated broker.
+///
+/// The broker is started with UAC on the first privileged operation and then
+/// serves every later one for the rest of the session, so the player is not
+/// prompted again mid-game. It only answers requests carrying this client's
+/// token, and the client only talks to a pipe served by the broker
+/// executable from the install directory (no other process can squat the
+/// pipe name and collect the token).
+pub struct PrivilegeBrokerClient {
+    token: String,
+    /// One request at a time; also keeps two commands from launching two brokers
+    connection: Mutex<()>,
+}
+
+impl Default for PrivilegeBrokerClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PrivilegeBrokerClient {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            token: format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple()),
+            connection: Mutex::new(()),
+        }
+    }
+
+    /// Runs `operation` as administrator, starting the broker (UAC prompt) if needed.
+    ///
+    /// Blocking; call from a worker thread.
+    ///
+    /// # Errors
+    /// - `BalamError::AccessDenied` - UAC declined or the broker rejected the request
+    /// - `BalamError::Timeout` - The broker did not come up
+    /// - `BalamError::Platform` - The operation itself failed
+    pub fn execute(&self, operation: &PrivilegedOperation) -> Result<(), BalamError> {
+        let _connection = self.connection.lock();
+        let pipe = match Self::connect()? {
+            Some(pipe) => pipe,
+            None => self.launch()?,
+        };
+        self.send(pipe, operation)
+    }
+
+    /// Like [`Self::execute`], but never prompts: fails with `NotFound` when
+    /// the broker is not already running (background repairs).
+    ///
+    /// # Errors
+    /// Same as [`Self::execute`].
+    pub fn execute_if_running(&self, operation: &PrivilegedOperation) -> Result<(), BalamError> {
+        let _connection = self.connection.lock();
+        let pipe = Self::connect()?.ok_or_else(|| BalamError::NotFound("Privilege broker".to_string()))?;
+        self.send(pipe, operation)
+    }
+
+    fn broker_path() -> Result<PathBuf, BalamError> {
+        std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(|dir| dir.join(BROKER_EXECUTABLE)))
+            .ok_or_else(|| BalamError::NotFound(BROKER_EXECUTABLE.to_string()))
+    }
+
+    /// Opens the broker pipe, `None` if no broker is running.
+    fn connect() -> Result<Option<File>, BalamError> {
+        let started = Instant::now();
+        loop {
+            match OpenOptions::new().read(true).write(true).open(BROKER_PIPE_NAME) {
+                Ok(pipe) => {
+                    Self::verify_server(&pipe)?;
+                    return Ok(Some(pipe));
+                },
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) if e.raw_os_error() == Some(ERROR_PIPE_BUSY) && started.elapsed() < LAUNCH_TIMEOUT => {
+                    std::thread::sleep(CONNECT_RETRY);
+                },
+                Err(e) => return Err(BalamError::platform("Failed to connect to privilege broker", e)),
+            }
+        }
+    }
+
+    /// Refuses to send the token to anything but our broker executable.
+    fn verify_server(pipe: &File) -> Result<(), BalamError> {
+        let mut pid = 0u32;
+        unsafe { GetNamedPipeServerProcessId(HANDLE(pipe.as_raw_handle() as isize), &mut pid) }
+            .map_err(|e| BalamError::platform("Failed to identify privilege broker", e))?;
+        if is_process_running(pid, &Self::broker_path()?) {
+            Ok(())
+        } else {
+            warn!("🚫 Broker pipe is served by an unexpected process (PID {})", pid);
+            Err(BalamError::AccessDenied(
+                "Privilege broker pipe is served by another program".to_string(),
+            ))
+        }
+    }
+
+    fn launch(&self) -> Result<File, BalamError> {
+        let broker = Self::broker_path()?;
+        info!("🛡️ Starting privilege broker (UAC prompt)");
+        let arguments = format!(
+            "{BROKER_TOKEN_FLAG} {} {BROKER_PARENT_FLAG} {}",
+            self.token,
+            std::process::id()
+        );
+        execute_elevated(&broker, Some(&arguments), broker.parent()).map_err(BalamError::AccessDenied)?;
+
+        let started = Instant::now();
+        while started.elapsed() < LAUNCH_TIMEOUT {
+            if let Some(pipe) = Self::connect()? {
+                info!("✅ Privilege broker ready");
+                return Ok(pipe);
+            }
+            std::thread::sleep(CONNECT_RETRY);
+        }
+        Err(BalamError::Timeout("Privilege broker did not start".to_string()))
+    }
+
+    fn send(&self, mut pipe: File, operation: &PrivilegedOperation) -> Result<(), BalamError> {
+        let request = BrokerRequest {
+            token: self.token.clone(),
+            operation: operation.clone(),
+        };
+        let mut line = serde_json::to_vec(&request).map_err(|e| BalamError::platform("Invalid broker request", e))?;
+        line.push(b'\n');
+        pipe.write_all(&line)
+            .map_err(|e| BalamError::platform("Failed to send broker request", e))?;
+
+        let mut answer = String::new();
+        BufReader::new(pipe)
+            .read_line(&mut answer)
+            .map_err(|e| BalamError::platform("Failed to read broker response", e))?;
+        match serde_json::from_str(&answer).map_err(|e| BalamError::platform("Invalid broker response", e))? {
+            BrokerResponse::Done => Ok(()),
+            BrokerResponse::Failed { message } => Err(BalamError::Platform(message)),
+            BrokerResponse::Unauthorized => Err(BalamError::AccessDenied(
+                "Privilege broker rejected the request".to_string(),
+            )),
+        }
+    }
+}
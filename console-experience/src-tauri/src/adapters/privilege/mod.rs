@@ -0,0 +1,6 @@
+//! Client side of the elevated privilege broker (`balam-broker.exe`).
+mod broker_client;
+mod peer;
+
+pub use broker_client::PrivilegeBrokerClient;
+pub use peer::{is_process_running, process_executable};
@@ -0,0 +1,21 @@
+use std::path::{Path, PathBuf};
+use sysinfo::{Pid, System};
+
+/// Executable of a running process, `None` if it has exited.
+#[must_use]
+pub fn process_executable(pid: u32) -> Option<PathBuf> {
+    let pid = Pid::from_u32(pid);
+    let mut sys = System::new();
+    if !sys.refresh_process(pid) {
+        return None;
+    }
+    sys.process(pid)
+        .and_then(|process| process.exe())
+        .map(Path::to_path_buf)
+}
+
+/// Whether `pid` runs exactly `expected` (paths compared case-insensitively).
+#[must_use]
+pub fn is_process_running(pid: u32, expected: &Path) -> bool {
+    process_executable(pid).is_some_and(|exe| exe.to_string_lossy().eq_ignore_ascii_case(&expected.to_string_lossy()))
+}
@@ -0,0 +1,216 @@
+/// Process events from the `Microsoft-Windows-Kernel-Process` ETW provider
+///
+/// Real-time trace session delivering starts and exits as they happen.
+/// Creating the session needs administrator rights. A session left behind
+/// by a crash is replaced on the next start.
+use std::ffi::c_void;
+
+use crate::domain::BalamError;
+use crate::ports::{ProcessEvent, ProcessEventCallback, ProcessEventsPort};
+use tracing::warn;
+use windows::core::{GUID, PCWSTR, PWSTR};
+use windows::Win32::Foundation::FILETIME;
+use windows::Win32::System::Diagnostics::Etw::{
+    ControlTraceW, EnableTraceEx2, StartTraceW, CONTROLTRACE_HANDLE, ENABLE_TRACE_PARAMETERS,
+    ENABLE_TRACE_PARAMETERS_VERSION_2, EVENT_RECORD, EVENT_TRACE_CONTROL_STOP, EVENT_TRACE_LOGFILEW,
+    EVENT_TRACE_PROPERTIES, EVENT_TRACE_REAL_TIME_MODE, PROCESSTRACE_HANDLE, PROCESS_TRACE_MODE_EVENT_RECORD,
+    PROCESS_TRACE_MODE_REAL_TIME, WNODE_FLAG_TRACED_GUID, WNODE_HEADER,
+};
+
+/// Microsoft-Windows-Kernel-Process
+const KERNEL_PROCESS_PROVIDER_GUID: GUID = GUID::from_values(
+    0x22fb_2cd6,
+    0x0e7b,
+    0x422b,
+    [0xa0, 0xc7, 0x2f, 0xad, 0x1f, 0xd0, 0xe7, 0x16],
+);
+
+/// `WINEVENT_KEYWORD_PROCESS`: process start/stop only
+const PROCESS_KEYWORD: u64 = 0x10;
+
+const PROCESS_START_EVENT_ID: u16 = 1;
+const PROCESS_STOP_EVENT_ID: u16 = 2;
+
+const SESSION_NAME: &str = "BalamProcessEvents";
+
+// ETW consumer APIs (declared by hand, as in the FPS service)
+#[link(name = "advapi32")]
+extern "system" {
+    fn OpenTraceW(logfile: *mut EVENT_TRACE_LOGFILEW) -> PROCESSTRACE_HANDLE;
+    fn ProcessTrace(
+        handlearray: *const PROCESSTRACE_HANDLE,
+        handlecount: u32,
+        starttime: *const FILETIME,
+        endtime: *const FILETIME,
+    ) -> u32;
+    fn CloseTrace(tracehandle: PROCESSTRACE_HANDLE) -> u32;
+}
+
+/// Kernel-Process ETW source.
+pub struct EtwProcessEvents;
+
+fn session_name() -> Vec<u16> {
+    SESSION_NAME.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// Session properties followed by room for the session name.
+#[repr(C)]
+struct SessionProperties {
+    properties: EVENT_TRACE_PROPERTIES,
+    name: [u16; 64],
+}
+
+impl SessionProperties {
+    fn new() -> Self {
+        Self {
+            properties: EVENT_TRACE_PROPERTIES {
+                Wnode: WNODE_HEADER {
+                    BufferSize: std::mem::size_of::<Self>() as u32,
+                    Flags: WNODE_FLAG_TRACED_GUID,
+                    ..Default::default()
+                },
+                BufferSize: 16, // KB: process events are rare
+                MinimumBuffers: 2,
+                MaximumBuffers: 8,
+                LogFileMode: EVENT_TRACE_REAL_TIME_MODE,
+                LoggerNameOffset: std::mem::size_of::<EVENT_TRACE_PROPERTIES>() as u32,
+                ..Default::default()
+            },
+            name: [0; 64],
+        }
+    }
+}
+
+/// Maps a Kernel-Process event to a process event; both events start with
+/// the process ID. The executable is resolved by the caller (the event's
+/// image name is an NT device path).
+fn parse_event(event_id: u16, user_data: &[u8]) -> Option<ProcessEvent> {
+    let pid = u32::from_le_bytes(user_data.get(..4)?.try_into().ok()?);
+    match event_id {
+        PROCESS_START_EVENT_ID => Some(ProcessEvent::Started {
+            pid,
+            executable_path: None,
+        }),
+        PROCESS_STOP_EVENT_ID => Some(ProcessEvent::Exited { pid }),
+        _ => None,
+    }
+}
+
+unsafe extern "system" fn on_event(record: *mut EVENT_RECORD) {
+    let Some(record) = record.as_ref() else {
+        return;
+    };
+    if record.UserContext.is_null() || record.UserData.is_null() {
+        return;
+    }
+    let user_data = std::slice::from_raw_parts(record.UserData as *const u8, usize::from(record.UserDataLength));
+    let Some(mut event) = parse_event(record.EventHeader.EventDescriptor.Id, user_data) else {
+        return;
+    };
+    if let ProcessEvent::Started { pid, executable_path } = &mut event {
+        *executable_path = super::executable_path(*pid);
+    }
+    let callback = &*(record.UserContext as *const ProcessEventCallback);
+    callback(&event);
+}
+
+impl ProcessEventsPort for EtwProcessEvents {
+    fn name(&self) -> &'static str {
+        "etw"
+    }
+
+    fn subscribe(&self, callback: ProcessEventCallback) -> Result<(), BalamError> {
+        let name = session_name();
+        unsafe {
+            // Replace a session left behind by a previous run
+            let mut stale = SessionProperties::new();
+            let _ = ControlTraceW(
+                CONTROLTRACE_HANDLE::default(),
+                PCWSTR(name.as_ptr()),
+                &mut stale.properties,
+                EVENT_TRACE_CONTROL_STOP,
+            );
+
+            let mut properties = SessionProperties::new();
+            let mut session = CONTROLTRACE_HANDLE::default();
+            StartTraceW(&mut session, PCWSTR(name.as_ptr()), &mut properties.properties)
+                .ok()
+                .map_err(|e| BalamError::platform("Failed to start ETW session", e))?;
+
+            let params = ENABLE_TRACE_PARAMETERS {
+                Version: ENABLE_TRACE_PARAMETERS_VERSION_2,
+                ..Default::default()
+            };
+            let enabled = EnableTraceEx2(
+                session,
+                &KERNEL_PROCESS_PROVIDER_GUID as *const GUID,
+                1, // EVENT_CONTROL_CODE_ENABLE_PROVIDER
+                4, // TRACE_LEVEL_INFORMATION
+                PROCESS_KEYWORD,
+                0,
+                0,
+                Some(&params),
+            )
+            .ok();
+            if let Err(e) = enabled {
+                let _ = ControlTraceW(
+                    session,
+                    PCWSTR::null(),
+                    &mut properties.properties,
+                    EVENT_TRACE_CONTROL_STOP,
+                );
+                return Err(BalamError::platform("Failed to enable Kernel-Process provider", e));
+            }
+        }
+
+        // The callback lives as long as the session: for the rest of the app
+        let context = Box::into_raw(Box::new(callback)) as usize;
+        std::thread::Builder::new()
+            .name("etw-process-events".to_string())
+            .spawn(move || unsafe {
+                let mut name = session_name();
+                let mut logfile: EVENT_TRACE_LOGFILEW = std::mem::zeroed();
+                logfile.LoggerName = PWSTR(name.as_mut_ptr());
+                logfile.Anonymous1.ProcessTraceMode = PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD;
+                logfile.Anonymous2.EventRecordCallback = Some(on_event);
+                logfile.Context = context as *mut c_void;
+
+                let trace = OpenTraceW(&mut logfile);
+                if trace.Value == 0 || trace.Value == u64::MAX {
+                    warn!("Failed to open the process events trace");
+                    return;
+                }
+                // Blocks until the session stops
+                let result = ProcessTrace(&trace, 1, std::ptr::null(), std::ptr::null());
+                CloseTrace(trace);
+                warn!("Process events trace ended ({})", result);
+            })
+            .map(|_| ())
+            .map_err(|e| BalamError::platform("Failed to start process events thread", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernel_process_events_are_parsed() {
+        let mut start = 4242u32.to_le_bytes().to_vec();
+        start.extend_from_slice(&[0; 20]);
+        assert_eq!(
+            parse_event(PROCESS_START_EVENT_ID, &start),
+            Some(ProcessEvent::Started {
+                pid: 4242,
+                executable_path: None
+            })
+        );
+        assert_eq!(
+            parse_event(PROCESS_STOP_EVENT_ID, &start),
+            Some(ProcessEvent::Exited { pid: 4242 })
+        );
+        // Thread events and truncated payloads are ignored
+        assert_eq!(parse_event(3, &start), None);
+        assert_eq!(parse_event(PROCESS_STOP_EVENT_ID, &[1, 2]), None);
+    }
+}
@@ -0,0 +1,58 @@
+/// Process event sources
+///
+/// - ETW (`Microsoft-Windows-Kernel-Process`): instant, needs administrator rights
+/// - WMI instance events: no rights needed, up to a second late, needs the WMI service
+/// - WTS process list polling: works everywhere, up to a poll interval late
+mod etw_process_events;
+mod wmi_process_events;
+mod wts_process_events;
+
+pub use etw_process_events::EtwProcessEvents;
+pub use wmi_process_events::WmiProcessEvents;
+pub use wts_process_events::WtsProcessEvents;
+
+use crate::domain::BalamError;
+use crate::ports::{ProcessEventCallback, ProcessEventsPort};
+use tracing::{info, warn};
+use windows::core::PWSTR;
+use windows::Win32::Foundation::CloseHandle;
+use windows::Win32::System::Threading::{
+    OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32, PROCESS_QUERY_LIMITED_INFORMATION,
+};
+
+/// Subscribes `callback` to the first source that starts, best first.
+///
+/// # Returns
+/// The name of the source delivering events.
+///
+/// # Errors
+/// Returns error if no source could be started.
+pub fn subscribe_best(callback: &ProcessEventCallback) -> Result<&'static str, BalamError> {
+    let sources: [Box<dyn ProcessEventsPort>; 3] = [
+        Box::new(EtwProcessEvents),
+        Box::new(WmiProcessEvents),
+        Box::new(WtsProcessEvents),
+    ];
+    for source in sources {
+        match source.subscribe(callback.clone()) {
+            Ok(()) => {
+                info!("Process events from {}", source.name());
+                return Ok(source.name());
+            },
+            Err(e) => warn!("Process events: {} unavailable ({})", source.name(), e),
+        }
+    }
+    Err(BalamError::NotSupported("Process events".to_string()))
+}
+
+/// Full path of the process's executable (`None` for protected or exited processes).
+pub(crate) fn executable_path(pid: u32) -> Option<String> {
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut size = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(handle, PROCESS_NAME_WIN32, PWSTR(buffer.as_mut_ptr()), &mut size);
+        let _ = CloseHandle(handle);
+        (result.is_ok() && size > 0).then(|| String::from_utf16_lossy(&buffer[..size as usize]))
+    }
+}
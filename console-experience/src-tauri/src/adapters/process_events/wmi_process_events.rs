@@ -0,0 +1,117 @@
+/// Process events from WMI intrinsic instance events
+///
+/// `__InstanceCreationEvent` / `__InstanceDeletionEvent` on `Win32_Process`
+/// need no administrator rights (unlike `Win32_ProcessStartTrace`): WMI
+/// polls the process list (`WITHIN 1`) and reports the differences.
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::domain::BalamError;
+use crate::ports::{ProcessEvent, ProcessEventCallback, ProcessEventsPort};
+use serde::de::DeserializeOwned;
+use tracing::{error, warn};
+use wmi::WMIConnection;
+
+const PROCESS_CREATION_QUERY: &str =
+    "SELECT * FROM __InstanceCreationEvent WITHIN 1 WHERE TargetInstance ISA 'Win32_Process'";
+const PROCESS_DELETION_QUERY: &str =
+    "SELECT * FROM __InstanceDeletionEvent WITHIN 1 WHERE TargetInstance ISA 'Win32_Process'";
+
+/// How long to wait for WMI to accept the subscription
+const SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct InstanceEvent {
+    target_instance: ProcessInstance,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename = "Win32_Process")]
+#[serde(rename_all = "PascalCase")]
+struct ProcessInstance {
+    process_id: u32,
+    /// `None` for protected processes
+    executable_path: Option<String>,
+}
+
+/// WMI instance event source.
+pub struct WmiProcessEvents;
+
+/// Runs `query` on its own thread (WMI connections are not `Send`), passing
+/// every event through `to_event`; returns once the subscription is accepted.
+fn listen<T: DeserializeOwned + 'static>(
+    thread_name: &str,
+    query: &'static str,
+    callback: ProcessEventCallback,
+    to_event: fn(T) -> ProcessEvent,
+) -> Result<(), BalamError> {
+    let (ready_tx, ready_rx) = mpsc::channel();
+    thread::Builder::new()
+        .name(thread_name.to_string())
+        .spawn(move || {
+            let iterator = match WMIConnection::new()
+                .map_err(|e| BalamError::platform("WMI connection failed", format!("{e:?}")))
+                .and_then(|wmi_con| {
+                    wmi_con
+                        .raw_notification::<T>(query)
+                        .map_err(|e| BalamError::platform("WMI subscription failed", format!("{e:?}")))
+                }) {
+                Ok(iterator) => {
+                    let _ = ready_tx.send(Ok(()));
+                    iterator
+                },
+                Err(e) => {
+                    let _ = ready_tx.send(Err(e));
+                    return;
+                },
+            };
+
+            for result in iterator {
+                match result {
+                    Ok(event) => callback(&to_event(event)),
+                    Err(e) => {
+                        error!("Error receiving WMI process event: {:?}", e);
+                        break;
+                    },
+                }
+            }
+            warn!("WMI process event listener stopped ({})", query);
+        })
+        .map_err(|e| BalamError::platform("Failed to start WMI listener", e))?;
+
+    ready_rx
+        .recv_timeout(SUBSCRIBE_TIMEOUT)
+        .map_err(|_| BalamError::Timeout("WMI subscription".to_string()))?
+}
+
+impl ProcessEventsPort for WmiProcessEvents {
+    fn name(&self) -> &'static str {
+        "wmi"
+    }
+
+    fn subscribe(&self, callback: ProcessEventCallback) -> Result<(), BalamError> {
+        listen(
+            "wmi-process-starts",
+            PROCESS_CREATION_QUERY,
+            callback.clone(),
+            |event: InstanceEvent| ProcessEvent::Started {
+                pid: event.target_instance.process_id,
+                executable_path: event.target_instance.executable_path,
+            },
+        )?;
+        // Starts alone are still useful: exits are a bonus
+        if let Err(e) = listen(
+            "wmi-process-exits",
+            PROCESS_DELETION_QUERY,
+            callback,
+            |event: InstanceEvent| ProcessEvent::Exited {
+                pid: event.target_instance.process_id,
+            },
+        ) {
+            warn!("WMI process exits unavailable: {}", e);
+        }
+        Ok(())
+    }
+}
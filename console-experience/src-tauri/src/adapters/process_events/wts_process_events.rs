@@ -0,0 +1,107 @@
+/// Process events from polling the WTS process list
+///
+/// Last resort when neither ETW nor WMI is available: the process list is
+/// read every [`POLL_INTERVAL`] and compared with the previous one.
+/// Processes that live shorter than one interval are missed.
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::domain::BalamError;
+use crate::ports::{ProcessEvent, ProcessEventCallback, ProcessEventsPort};
+use windows::Win32::System::RemoteDesktop::{
+    WTSEnumerateProcessesW, WTSFreeMemory, WTS_CURRENT_SERVER_HANDLE, WTS_PROCESS_INFOW,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// WTS polling source.
+pub struct WtsProcessEvents;
+
+/// PIDs of every running process.
+fn running_pids() -> Result<HashSet<u32>, BalamError> {
+    unsafe {
+        let mut processes: *mut WTS_PROCESS_INFOW = std::ptr::null_mut();
+        let mut count = 0u32;
+        WTSEnumerateProcessesW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &mut processes, &mut count)
+            .map_err(|e| BalamError::platform("Failed to enumerate processes", e))?;
+        if processes.is_null() {
+            return Ok(HashSet::new());
+        }
+        let pids = std::slice::from_raw_parts(processes, count as usize)
+            .iter()
+            .map(|process| process.ProcessId)
+            .collect();
+        WTSFreeMemory(processes.cast());
+        Ok(pids)
+    }
+}
+
+/// Events between two process lists: exits first (PIDs get reused).
+fn diff(previous: &HashSet<u32>, current: &HashSet<u32>) -> Vec<ProcessEvent> {
+    let mut exited: Vec<u32> = previous.difference(current).copied().collect();
+    let mut started: Vec<u32> = current.difference(previous).copied().collect();
+    exited.sort_unstable();
+    started.sort_unstable();
+    exited
+        .into_iter()
+        .map(|pid| ProcessEvent::Exited { pid })
+        .chain(started.into_iter().map(|pid| ProcessEvent::Started {
+            pid,
+            executable_path: None,
+        }))
+        .collect()
+}
+
+impl ProcessEventsPort for WtsProcessEvents {
+    fn name(&self) -> &'static str {
+        "wts"
+    }
+
+    fn subscribe(&self, callback: ProcessEventCallback) -> Result<(), BalamError> {
+        // Processes already running are not reported
+        let mut previous = running_pids()?;
+        std::thread::Builder::new()
+            .name("wts-process-events".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(POLL_INTERVAL);
+                let Ok(current) = running_pids() else {
+                    continue;
+                };
+                for mut event in diff(&previous, &current) {
+                    if let ProcessEvent::Started { pid, executable_path } = &mut event {
+                        *executable_path = super::executable_path(*pid);
+                    }
+                    callback(&event);
+                }
+                previous = current;
+            })
+            .map(|_| ())
+            .map_err(|e| BalamError::platform("Failed to start process polling thread", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_list_changes_become_events() {
+        let previous = HashSet::from([4, 100, 200]);
+        let current = HashSet::from([4, 200, 300, 250]);
+        assert_eq!(
+            diff(&previous, &current),
+            [
+                ProcessEvent::Exited { pid: 100 },
+                ProcessEvent::Started {
+                    pid: 250,
+                    executable_path: None
+                },
+                ProcessEvent::Started {
+                    pid: 300,
+                    executable_path: None
+                },
+            ]
+        );
+        assert!(diff(&current, &current).is_empty());
+    }
+}
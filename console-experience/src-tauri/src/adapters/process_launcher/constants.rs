@@ -2,7 +2,7 @@
 // PROCESS LAUNCHER CONSTANTS
 // =============================================================================
 
-/// Steam registry watchdog timeout
+/// Default Steam registry watchdog timeout (`launch.steam_timeout_seconds`)
 /// Industry standard: 15-30s (Playnite, GOG Galaxy)
 /// Research shows:
 /// - Normal launch: 15-20s on modern systems
@@ -11,6 +11,35 @@
 /// Source: https://steamcommunity.com/discussions/forum/0/2976275080133332609/
 pub const STEAM_TIMEOUT_SECONDS: u64 = 30;
 
+/// Default Epic game startup timeout (`launch.epic_timeout_seconds`)
+/// Covers starting the Epic Games Launcher, sign-in, cloud sync and the
+/// DRM relaunch (EGL starts the exe, which exits and relaunches itself).
+pub const EPIC_TIMEOUT_SECONDS: u64 = 90;
+
+/// Grace period before an Epic game is considered closed
+/// The DRM relaunch leaves a short window with no game process running.
+pub const EPIC_EXIT_GRACE_SECONDS: u64 = 10;
+
+/// Default standalone launcher startup timeout (`launch.standalone_timeout_seconds`)
+/// Launchers (Riot Client, Minecraft Launcher) patch themselves and may wait
+/// for the user to press Play before the game process starts.
+pub const STANDALONE_TIMEOUT_SECONDS: u64 = 180;
+
+/// Time a cold-booting store client gets to come up before a launch fails
+/// Covers self-updates and the automatic sign-in.
+pub const LAUNCHER_READY_TIMEOUT_SECONDS: u64 = 60;
+
+/// Time a store client without a readiness signal (EA app, Ubisoft Connect)
+/// must run before games are launched, so it has signed in
+pub const LAUNCHER_SETTLE_SECONDS: u64 = 5;
+
+/// Steam disk I/O rate that counts as Steam working on the game before it
+/// starts (validation, shader cache), extending adaptive timeouts
+pub const STEAM_BUSY_DISK_BYTES_PER_SECOND: u64 = 5 * 1024 * 1024;
+
+/// Polling interval for process-scan watchdogs (a full scan costs 50-200ms)
+pub const PROCESS_SCAN_INTERVAL_MS: u64 = 1000;
+
 /// Xbox explorer fallback timeout
 pub const XBOX_EXPLORER_TIMEOUT_SECONDS: u64 = 5;
 
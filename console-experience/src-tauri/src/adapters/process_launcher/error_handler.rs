@@ -2,10 +2,13 @@
 // CENTRALIZED ERROR EMISSION
 // =============================================================================
 
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
 use tracing::error;
 
-use crate::domain::GameLaunchError;
+use crate::application::DIContainer;
+use crate::domain::launch_history::LaunchOutcome;
+use crate::domain::{GameLaunchError, Notification, NotificationCategory, NotificationPriority};
+use crate::ports::NotificationPort;
 
 /// Emit game launch error event to frontend
 ///
@@ -17,7 +20,22 @@ pub fn emit_launch_error(app_handle: &AppHandle, error: GameLaunchError) {
         error.game_title, error.store, error.reason
     );
 
-    // Emit to frontend for UI notification
+    // Launch history, then a toast/overlay banner (the launch may have failed while Balam was hidden)
+    if let Some(container) = app_handle.try_state::<DIContainer>() {
+        container.launch_history.finish(
+            &error.game_id,
+            LaunchOutcome::from(&error.reason),
+            Some(error.reason.description()),
+        );
+        container.notifications.notify(Notification::new(
+            NotificationCategory::GameLaunch,
+            NotificationPriority::High,
+            format!("No se pudo iniciar {}", error.game_title),
+            error.reason.description(),
+        ));
+    }
+
+    // Emit to frontend for the detailed error dialog
     if let Err(e) = app_handle.emit("game-launch-failed", &error) {
         error!("Failed to emit game-launch-failed event: {}", e);
     }
@@ -4,14 +4,28 @@
 
 use std::process::Command;
 use std::sync::Arc;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
 use tracing::{info, warn};
 
+use crate::adapters::custom_scanner::CustomScanner;
+use crate::adapters::epic_scanner::EpicScanner;
+use crate::adapters::legendary_adapter::LegendaryAdapter;
+use crate::adapters::standalone_scanner::StandaloneScanner;
 use crate::application::ActiveGamesTracker;
+use crate::domain::launcher_dependency::{LauncherDependency, LauncherWaitProgress, StoreClientState};
+use crate::domain::standalone::standalone_descriptor;
+use crate::domain::GameLaunchError;
 
-use super::pre_flight::pre_launch_check;
+use super::constants::LAUNCHER_READY_TIMEOUT_SECONDS;
+use super::error_handler::emit_launch_error;
+use super::launcher_dependencies::ensure_launcher_ready;
+use super::pre_flight::{pre_launch_check, steam_client_state};
 use super::uwp::launch_uwp_app;
-use super::watchdogs::{start_steam_registry_watchdog, start_watchdog, start_xbox_explorer_watchdog};
+use super::watchdogs::{
+    start_epic_manifest_watchdog, start_process_name_watchdog, start_steam_registry_watchdog, start_watchdog,
+    start_xbox_explorer_watchdog,
+};
 use super::window_manager::minimize_window;
 
 /// Launch a game and monitor its lifecycle.
@@ -19,10 +33,17 @@ use super::window_manager::minimize_window;
 /// This function handles different launch strategies based on the game ID:
 /// - Steam: Uses the `steam://` protocol (returns `None` for PID).
 /// - Xbox/UWP: Uses native Windows COM activation to get a real PID (returns `Some(pid)` or `None`).
+/// - Epic: Uses the `com.epicgames.launcher://` protocol, or `legendary launch` when
+///   `legendary` is given (returns `None`; the watchdog fills in the PID).
+/// - Standalone: The game's own launcher, followed by process name (returns `None`).
+/// - Custom: The manifest's protocol template, followed by process name (returns `None`),
+///   or a native launch when the manifest has none.
 /// - Native: Standard executable launch (returns `Some(pid)`).
 ///
 /// # Arguments
 /// * `executable_name` - Optional executable name for overlay injection (e.g., "SkyrimSE.exe")
+/// * `legendary` - Start Epic games through Legendary instead of the Epic Games Launcher
+/// * `launcher_dependency` - Store client started (and waited for) before the game
 ///
 /// # Returns
 /// - `Ok(Some(pid))` - Game launched successfully with a real PID
@@ -34,6 +55,8 @@ pub fn launch_game_process(
     app_handle: &AppHandle,
     tracker: Arc<ActiveGamesTracker>,
     executable_name: Option<String>,
+    legendary: Option<LegendaryAdapter>,
+    launcher_dependency: Option<LauncherDependency>,
 ) -> Result<Option<u32>, String> {
     info!("Launching game: {} ({})", id, path);
 
@@ -44,6 +67,44 @@ pub fn launch_game_process(
     // Avoids waiting for timeout if game is already running
     pre_launch_check(id, "El juego")?;
 
+    // ========================================================================
+    // LAUNCHER DEPENDENCY: Start the DRM store client before the game
+    // ========================================================================
+    // Waits for a cold-booting client instead of letting the protocol launch
+    // time out
+    if let Some(dependency) = launcher_dependency {
+        let ready = ensure_launcher_ready(dependency, &mut |elapsed_secs, ready| {
+            let _ = app_handle.emit(
+                "launcher-wait-progress",
+                LauncherWaitProgress {
+                    game_id: id.to_string(),
+                    launcher: dependency,
+                    elapsed_secs,
+                    timeout_secs: LAUNCHER_READY_TIMEOUT_SECONDS,
+                    ready,
+                },
+            );
+        });
+
+        // Steam says why it cannot launch (updating, signed out) instead of
+        // leaving the user with a generic timeout
+        if dependency == LauncherDependency::Steam {
+            let state = steam_client_state();
+            if let Some(reason) = state.blocking_reason() {
+                let description = reason.description();
+                emit_launch_error(
+                    app_handle,
+                    GameLaunchError::steam_unavailable(id.to_string(), "El juego".to_string(), reason),
+                );
+                return Err(description);
+            }
+            if state == StoreClientState::Offline {
+                warn!("Steam is in offline mode: online-only games will not start");
+            }
+        }
+        ready?;
+    }
+
     let app_handle_clone = app_handle.clone();
     let game_id = id.to_string();
 
@@ -51,6 +112,15 @@ pub fn launch_game_process(
         launch_steam_game(id, app_handle_clone, tracker, game_id, executable_name)
     } else if id.starts_with("xbox_") {
         launch_xbox_game(path, app_handle_clone, tracker, game_id)
+    } else if id.starts_with("epic_") {
+        match legendary {
+            Some(legendary) => launch_legendary_game(&legendary, id, app_handle_clone, tracker, game_id),
+            None => launch_epic_game(id, path, app_handle_clone, tracker, game_id),
+        }
+    } else if id.starts_with("standalone_") {
+        launch_standalone_game(id, path, app_handle_clone, tracker, game_id)
+    } else if id.starts_with("custom_") {
+        launch_custom_game(id, path, app_handle_clone, tracker, game_id)
     } else {
         launch_native_game(path, app_handle_clone, tracker, game_id)
     }
@@ -89,6 +159,146 @@ fn launch_steam_game(
     Ok(None) // Steam doesn't provide real PID
 }
 
+/// Launch Epic game via the Epic Games Launcher protocol
+///
+/// Starting the exe directly breaks games using EGL ownership checks (they
+/// exit and ask EGL to relaunch them), so the launcher is always asked to
+/// start the game and the watchdog follows the exe from the manifest.
+fn launch_epic_game(
+    id: &str,
+    path: &str,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) -> Result<Option<u32>, String> {
+    let catalog_item_id = id.trim_start_matches("epic_");
+
+    let Some(launch_info) = EpicScanner::find_launch_info(catalog_item_id) else {
+        warn!(
+            "No Epic manifest with AppName for {}. Falling back to native launch...",
+            id
+        );
+        return launch_native_game(path, app_handle, tracker, game_id);
+    };
+
+    let epic_url = format!(
+        "com.epicgames.launcher://apps/{}?action=launch&silent=true",
+        launch_info.app_name
+    );
+    info!("Executing Epic Command: {}", epic_url);
+
+    Command::new("explorer")
+        .arg(&epic_url)
+        .spawn()
+        .map_err(|e| format!("Failed to launch Epic command: {e}"))?;
+
+    minimize_window(&app_handle);
+
+    start_epic_manifest_watchdog(launch_info.executable_path, app_handle, tracker, game_id);
+
+    Ok(None) // PID is resolved by the watchdog once the real exe starts
+}
+
+/// Launch Epic game via `legendary launch`
+///
+/// Legendary refreshes its Epic session by itself; without a session, games
+/// that allow it start offline. The watchdog follows the exe from
+/// Legendary's install list.
+fn launch_legendary_game(
+    legendary: &LegendaryAdapter,
+    id: &str,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) -> Result<Option<u32>, String> {
+    let raw_id = id.trim_start_matches("epic_");
+    let (config_dir, install) =
+        LegendaryAdapter::find_install(raw_id).ok_or_else(|| format!("{id} is not installed through Legendary"))?;
+
+    legendary.launch(&config_dir, &install)?;
+
+    minimize_window(&app_handle);
+
+    let executable_path = std::path::Path::new(&install.install_path).join(&install.executable);
+    start_epic_manifest_watchdog(executable_path, app_handle, tracker, game_id);
+
+    Ok(None) // Legendary starts the game as its own child; the watchdog finds it
+}
+
+/// Launch a well-known standalone game through its own launcher
+///
+/// Started via `cmd /C start` so games that require elevation (HoYoverse
+/// titles) get their UAC prompt; the watchdog follows the descriptor's
+/// process names since the launcher outlives the game.
+fn launch_standalone_game(
+    id: &str,
+    path: &str,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) -> Result<Option<u32>, String> {
+    let descriptor = standalone_descriptor(id.trim_start_matches("standalone_"))
+        .ok_or_else(|| format!("Unknown standalone game: {id}"))?;
+
+    let program = match descriptor.launcher {
+        Some(launcher) => {
+            let (install_dir, _) = StandaloneScanner::locate(descriptor)
+                .ok_or_else(|| format!("{} is not installed", descriptor.title))?;
+            install_dir.join(launcher).to_string_lossy().to_string()
+        },
+        None => path.to_string(),
+    };
+    info!("Executing launcher: {} {}", program, descriptor.launch_args.join(" "));
+
+    Command::new("cmd")
+        .args(["/C", "start", ""])
+        .arg(&program)
+        .args(descriptor.launch_args)
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {e}", descriptor.title))?;
+
+    minimize_window(&app_handle);
+
+    let process_names = descriptor.watch_processes.iter().map(ToString::to_string).collect();
+    start_process_name_watchdog(process_names, app_handle, tracker, game_id);
+
+    Ok(None) // The launcher starts the game; the watchdog finds it by name
+}
+
+/// Launch a game found by a custom scanner manifest
+///
+/// Manifests with a launch template start the game through their store's
+/// protocol and the watchdog follows the executable by name; the others
+/// start the executable directly.
+fn launch_custom_game(
+    id: &str,
+    path: &str,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) -> Result<Option<u32>, String> {
+    let Some(url) = CustomScanner::launch_url(id, path)? else {
+        return launch_native_game(path, app_handle, tracker, game_id);
+    };
+    info!("Opening custom scanner URL: {}", url);
+
+    // Through the shell's protocol handler, never a command line
+    app_handle
+        .opener()
+        .open_url(&url, None::<&str>)
+        .map_err(|e| format!("Failed to launch {url}: {e}"))?;
+
+    minimize_window(&app_handle);
+
+    let executable = std::path::Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| "Invalid game path".to_string())?;
+    start_process_name_watchdog(vec![executable], app_handle, tracker, game_id);
+
+    Ok(None) // The store starts the game; the watchdog finds it by name
+}
+
 /// Launch Xbox/UWP game via native COM activation
 fn launch_xbox_game(
     path: &str,
@@ -116,13 +326,10 @@ fn launch_xbox_game(
 
             minimize_window(&app_handle);
 
-            // CRITICAL FIX: Add watchdog for explorer fallback
-            // Can't monitor PID, but can timeout if game doesn't start
+            // No PID from explorer: the watchdog finds the package's process
+            // (or times out if the game doesn't start)
             start_xbox_explorer_watchdog(path.to_string(), app_handle, tracker, game_id);
 
-            // NOTE: Can't inject overlay for explorer fallback (no PID)
-            warn!("⚠️ Xbox explorer fallback: overlay injection not possible (no PID)");
-
             Ok(None)
         },
     }
@@ -0,0 +1,152 @@
+// =============================================================================
+// LAUNCHER DEPENDENCIES (DRM store clients)
+// =============================================================================
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::info;
+use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+use winreg::RegKey;
+
+use super::constants::{LAUNCHER_READY_TIMEOUT_SECONDS, LAUNCHER_SETTLE_SECONDS, PROCESS_SCAN_INTERVAL_MS};
+use super::pre_flight::game_process_exists;
+use crate::domain::launcher_dependency::{infer_launcher_dependency, LauncherDependency};
+use crate::domain::Game;
+
+/// Store client a scanned game needs, from its store and install folder
+pub fn detect_launcher_dependency(game: &Game) -> Option<LauncherDependency> {
+    let path = Path::new(&game.path);
+    let folder = if path.is_dir() { Some(path) } else { path.parent() };
+    let file_names: Vec<String> = folder
+        .and_then(|folder| fs::read_dir(folder).ok())
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    infer_launcher_dependency(game.source, &file_names)
+}
+
+/// Make sure a store client is running and ready before a game is launched
+///
+/// Starts the client minimized if needed, then waits until Steam reports a
+/// signed-in user (or, for clients without such a signal, until the client
+/// has run for `LAUNCHER_SETTLE_SECONDS`). A cold-booting Steam would
+/// otherwise swallow the `steam://run` request.
+///
+/// Blocks for up to `LAUNCHER_READY_TIMEOUT_SECONDS`: call it off the main
+/// thread. `on_wait` gets the seconds waited (and whether the client is
+/// ready) after every check, for progress reporting.
+///
+/// # Errors
+/// Returns error if the client is not installed or not ready in time.
+pub fn ensure_launcher_ready(dependency: LauncherDependency, on_wait: &mut dyn FnMut(u64, bool)) -> Result<(), String> {
+    let running = launcher_running(dependency);
+    if running && (dependency != LauncherDependency::Steam || steam_signed_in()) {
+        return Ok(());
+    }
+    if !running {
+        start_launcher(dependency)?;
+    }
+
+    info!("Waiting for {} to be ready...", dependency.display_name());
+    let started = Instant::now();
+    let deadline = started + Duration::from_secs(LAUNCHER_READY_TIMEOUT_SECONDS);
+    let mut running_since: Option<Instant> = None;
+    on_wait(0, false);
+
+    while Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(PROCESS_SCAN_INTERVAL_MS));
+
+        if !launcher_running(dependency) {
+            running_since = None;
+            on_wait(started.elapsed().as_secs(), false);
+            continue;
+        }
+        let since = *running_since.get_or_insert_with(Instant::now);
+
+        let ready = match dependency {
+            LauncherDependency::Steam => steam_signed_in(),
+            LauncherDependency::EaApp | LauncherDependency::UbisoftConnect => {
+                since.elapsed() >= Duration::from_secs(LAUNCHER_SETTLE_SECONDS)
+            },
+        };
+        on_wait(started.elapsed().as_secs(), ready);
+        if ready {
+            info!("{} is ready", dependency.display_name());
+            return Ok(());
+        }
+    }
+
+    Err(format!(
+        "{} did not start within {}s",
+        dependency.display_name(),
+        LAUNCHER_READY_TIMEOUT_SECONDS
+    ))
+}
+
+fn launcher_running(dependency: LauncherDependency) -> bool {
+    dependency.process_names().iter().any(|name| game_process_exists(name))
+}
+
+/// Steam writes the signed-in account to `ActiveProcess\ActiveUser` (0 until login)
+fn steam_signed_in() -> bool {
+    RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey("Software\\Valve\\Steam\\ActiveProcess")
+        .and_then(|key| key.get_value::<u32, _>("ActiveUser"))
+        .is_ok_and(|user| user != 0)
+}
+
+/// Installed client executable, from the client's own registry keys
+fn launcher_executable(dependency: LauncherDependency) -> Option<PathBuf> {
+    let path = match dependency {
+        LauncherDependency::Steam => RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey("Software\\Valve\\Steam")
+            .and_then(|key| key.get_value::<String, _>("SteamExe"))
+            .ok()
+            .map(PathBuf::from),
+        LauncherDependency::EaApp => RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey("SOFTWARE\\Electronic Arts\\EA Desktop")
+            .and_then(|key| key.get_value::<String, _>("DesktopAppPath"))
+            .ok()
+            .map(PathBuf::from),
+        LauncherDependency::UbisoftConnect => RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey("SOFTWARE\\WOW6432Node\\Ubisoft\\Launcher")
+            .and_then(|key| key.get_value::<String, _>("InstallDir"))
+            .ok()
+            .and_then(|dir| {
+                dependency
+                    .process_names()
+                    .iter()
+                    .map(|exe| Path::new(&dir).join(exe))
+                    .find(|path| path.is_file())
+            }),
+    };
+    path.filter(|path| path.is_file())
+}
+
+/// Start a store client minimized (Steam straight to the tray)
+fn start_launcher(dependency: LauncherDependency) -> Result<(), String> {
+    let executable =
+        launcher_executable(dependency).ok_or_else(|| format!("{} is not installed", dependency.display_name()))?;
+    info!(
+        "Starting {} minimized: {}",
+        dependency.display_name(),
+        executable.display()
+    );
+
+    let mut command = Command::new("cmd");
+    command.args(["/C", "start", "", "/min"]).arg(&executable);
+    if dependency == LauncherDependency::Steam {
+        command.arg("-silent");
+    }
+    command
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to start {}: {e}", dependency.display_name()))
+}
@@ -10,6 +10,9 @@
 // Architecture:
 // - constants: Timeout and polling configuration
 // - pre_flight: Pre-launch validation to detect already-running games
+// - launcher_dependencies: Store clients (DRM) started before the game
+// - process_tree: Launcher → game child process following
+// - process_tuning: Per-game priority class / CPU affinity of the game process
 // - launch_strategies: Platform-specific launch logic
 // - watchdogs: Process monitoring for different launchers
 // - window_manager: Application window control
@@ -19,13 +22,19 @@
 pub mod constants;
 pub mod error_handler;
 pub mod launch_strategies;
+pub mod launcher_dependencies;
 pub mod pre_flight;
+pub mod process_tree;
+pub mod process_tuning;
 pub mod uwp;
 pub mod watchdogs;
 pub mod window_manager;
 
 // Re-export main public API
 pub use launch_strategies::launch_game_process;
+pub use launcher_dependencies::detect_launcher_dependency;
+pub use process_tuning::restore_game_process;
+pub use uwp::terminate_uwp_package;
 
 // Re-export for testing
 #[cfg(test)]
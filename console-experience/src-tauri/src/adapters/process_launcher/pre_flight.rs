@@ -2,16 +2,14 @@
 // PRE-FLIGHT CHECKS (Performance Optimization)
 // =============================================================================
 
-use std::sync::{Mutex, OnceLock};
-use sysinfo::System;
+use std::fs;
+use std::path::Path;
 use tracing::info;
 use winreg::enums::HKEY_CURRENT_USER;
 use winreg::RegKey;
 
-/// Global System instance for process checking (performance optimization)
-/// Keeping the same instance is much faster than recreating it
-#[allow(dead_code)]
-static SYSTEM_INSTANCE: OnceLock<Mutex<System>> = OnceLock::new();
+use crate::application::services::ProcessInspectionService;
+use crate::domain::launcher_dependency::{steam_wants_offline, StoreClientState};
 
 /// Check if a Steam game is already running via registry
 ///
@@ -34,19 +32,10 @@ pub fn steam_game_is_running(app_id: &str) -> bool {
 
 /// Check if a game process is already running
 ///
-/// Performance: 50-200ms (process scan)
-/// Uses cached System instance for better performance
-#[allow(dead_code)]
+/// Performance: up to 50-200ms (process scan), shared with the other
+/// process checks through the app-wide process table
 pub fn game_process_exists(exe_name: &str) -> bool {
-    let sys = SYSTEM_INSTANCE.get_or_init(|| Mutex::new(System::new_all()));
-
-    let mut sys = sys.lock().unwrap_or_else(|e| e.into_inner());
-    sys.refresh_processes();
-
-    let exists = sys
-        .processes()
-        .values()
-        .any(|p| p.name().eq_ignore_ascii_case(exe_name));
+    let exists = ProcessInspectionService::shared().is_name_running(exe_name);
 
     if exists {
         info!("Pre-flight check: Process {} already running", exe_name);
@@ -55,6 +44,49 @@ pub fn game_process_exists(exe_name: &str) -> bool {
     exists
 }
 
+/// Current state of the Steam client
+///
+/// Performance: 50-200ms (process scan) + registry reads
+/// - `ActiveProcess\pid` is written once the client (and `steam://`
+///   handling) is up; while Steam is still bootstrapping/updating it is 0
+/// - `ActiveProcess\ActiveUser` is 0 until an account signs in
+/// - Offline mode comes from the most recent account in `loginusers.vdf`
+pub fn steam_client_state() -> StoreClientState {
+    if !game_process_exists("steam.exe") {
+        return StoreClientState::NotRunning;
+    }
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let active_process = hkcu.open_subkey("Software\\Valve\\Steam\\ActiveProcess");
+    let read = |name: &str| {
+        active_process
+            .as_ref()
+            .ok()
+            .and_then(|key| key.get_value::<u32, _>(name).ok())
+            .unwrap_or_default()
+    };
+
+    if read("pid") == 0 {
+        return StoreClientState::Updating;
+    }
+    if read("ActiveUser") == 0 {
+        return StoreClientState::SignedOut;
+    }
+
+    let wants_offline = hkcu
+        .open_subkey("Software\\Valve\\Steam")
+        .and_then(|key| key.get_value::<String, _>("SteamPath"))
+        .ok()
+        .and_then(|steam_path| fs::read_to_string(Path::new(&steam_path).join("config").join("loginusers.vdf")).ok())
+        .is_some_and(|content| steam_wants_offline(&content));
+
+    if wants_offline {
+        StoreClientState::Offline
+    } else {
+        StoreClientState::Online
+    }
+}
+
 /// Pre-flight check before launching game
 ///
 /// Detects if game is already running BEFORE attempting launch.
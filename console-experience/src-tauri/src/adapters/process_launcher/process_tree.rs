@@ -0,0 +1,179 @@
+// =============================================================================
+// PROCESS TREE TRACKING
+// =============================================================================
+
+use std::collections::HashMap;
+
+use crate::application::services::ProcessInfo;
+
+/// One row of a process snapshot (PID, parent PID, start time in seconds since epoch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessEntry {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub start_time: u64,
+}
+
+/// Follows a launched process and every descendant it spawns.
+///
+/// Many games start through a launcher (`launcher.exe` → `game_real.exe`)
+/// that exits as soon as the real game is running. Watching only the launched
+/// PID reports the game as closed while it is still being played; the tree is
+/// only considered gone when no member is alive.
+///
+/// Built on parent-PID walking (sysinfo reads it from
+/// `NtQuerySystemInformation`). Job Objects are not used because launchers
+/// commonly spawn the game with `CREATE_BREAKAWAY_FROM_JOB`.
+///
+/// # PID Reuse
+/// Windows keeps the parent PID of orphaned children, so every member ever
+/// seen is remembered with its start time: a child is adopted only if it
+/// started after that member, and a member only counts as alive while its PID
+/// still has the same start time.
+pub struct ProcessTree {
+    root: u32,
+    /// Every PID that ever belonged to the tree → start time (`None` until seen)
+    known: HashMap<u32, Option<u64>>,
+    /// Members alive in the last snapshot
+    alive: Vec<ProcessEntry>,
+}
+
+impl ProcessTree {
+    /// Starts tracking the tree rooted at `root`.
+    #[must_use]
+    pub fn new(root: u32) -> Self {
+        Self {
+            root,
+            known: HashMap::from([(root, None)]),
+            alive: Vec::new(),
+        }
+    }
+
+    /// Refreshes membership from the current process table.
+    pub fn refresh(&mut self, processes: &[ProcessInfo]) {
+        let snapshot: Vec<ProcessEntry> = processes
+            .iter()
+            .map(|process| ProcessEntry {
+                pid: process.pid,
+                parent: process.parent,
+                start_time: process.start_time,
+            })
+            .collect();
+        self.update(&snapshot);
+    }
+
+    /// Refreshes membership from a process snapshot.
+    pub fn update(&mut self, snapshot: &[ProcessEntry]) {
+        // Record the root's start time the first time it is seen
+        if self.known.get(&self.root) == Some(&None) {
+            if let Some(entry) = snapshot.iter().find(|e| e.pid == self.root) {
+                self.known.insert(self.root, Some(entry.start_time));
+            }
+        }
+
+        // Adopt descendants until no new member appears (grandchildren included)
+        loop {
+            let mut adopted = false;
+            for entry in snapshot {
+                if self.known.contains_key(&entry.pid) {
+                    continue;
+                }
+                let parent_start = entry.parent.and_then(|parent| self.known.get(&parent).copied());
+                if let Some(parent_start) = parent_start {
+                    if parent_start.is_none_or(|start| entry.start_time >= start) {
+                        self.known.insert(entry.pid, Some(entry.start_time));
+                        adopted = true;
+                    }
+                }
+            }
+            if !adopted {
+                break;
+            }
+        }
+
+        self.alive = snapshot
+            .iter()
+            .filter(|entry| {
+                self.known
+                    .get(&entry.pid)
+                    .is_some_and(|start| start.is_none_or(|start| start == entry.start_time))
+            })
+            .copied()
+            .collect();
+    }
+
+    /// Whether any process of the tree is still running.
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        !self.alive.is_empty()
+    }
+
+    /// PIDs of the tree alive in the last snapshot.
+    #[must_use]
+    pub fn pids(&self) -> Vec<u32> {
+        self.alive.iter().map(|entry| entry.pid).collect()
+    }
+
+    /// Process that best represents the game: the root while it runs,
+    /// otherwise the most recently started descendant.
+    #[must_use]
+    pub fn primary_pid(&self) -> Option<u32> {
+        if self.alive.iter().any(|entry| entry.pid == self.root) {
+            return Some(self.root);
+        }
+        self.alive
+            .iter()
+            .max_by_key(|entry| entry.start_time)
+            .map(|entry| entry.pid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(pid: u32, parent: Option<u32>, start_time: u64) -> ProcessEntry {
+        ProcessEntry {
+            pid,
+            parent,
+            start_time,
+        }
+    }
+
+    #[test]
+    fn test_follows_child_after_launcher_exits() {
+        let mut tree = ProcessTree::new(100);
+        tree.update(&[entry(4, None, 1), entry(100, Some(4), 10), entry(200, Some(100), 12)]);
+        assert!(tree.is_alive());
+        assert_eq!(tree.primary_pid(), Some(100));
+
+        // Launcher exits, real game keeps running
+        tree.update(&[entry(4, None, 1), entry(200, Some(100), 12)]);
+        assert!(tree.is_alive());
+        assert_eq!(tree.pids(), vec![200]);
+        assert_eq!(tree.primary_pid(), Some(200));
+
+        tree.update(&[entry(4, None, 1)]);
+        assert!(!tree.is_alive());
+    }
+
+    #[test]
+    fn test_adopts_orphan_spawned_between_polls() {
+        let mut tree = ProcessTree::new(100);
+        tree.update(&[entry(100, None, 10)]);
+
+        // Launcher spawned a child and exited before the next poll
+        tree.update(&[entry(300, Some(100), 15), entry(301, Some(300), 16)]);
+        assert_eq!(tree.pids(), vec![300, 301]);
+    }
+
+    #[test]
+    fn test_ignores_reused_pid() {
+        let mut tree = ProcessTree::new(100);
+        tree.update(&[entry(100, None, 10)]);
+
+        // PID 100 reused by an unrelated process, plus an older unrelated child
+        tree.update(&[entry(100, Some(4), 50), entry(500, Some(100), 5)]);
+        assert!(!tree.is_alive());
+    }
+}
@@ -0,0 +1,121 @@
+// =============================================================================
+// GAME PROCESS PRIORITY / AFFINITY
+// =============================================================================
+//
+// Applies the priority class and CPU affinity of a game's power profile once
+// a watchdog knows the real game process (after launcher hand-overs), and
+// puts the original values back when the game ends or hands over again.
+
+use parking_lot::Mutex;
+use tauri::{AppHandle, Manager};
+use tracing::{debug, info, warn};
+
+use crate::adapters::performance::{ProcessPriorityAdapter, ProcessScheduling};
+use crate::application::services::ProcessInfo;
+use crate::application::DIContainer;
+use crate::domain::performance::GamePowerProfile;
+
+/// Tuned processes by game id, with what they had before.
+static TUNED: Mutex<Vec<(String, u32, ProcessScheduling)>> = Mutex::new(Vec::new());
+
+fn profile(app_handle: &AppHandle, game_id: &str) -> Option<GamePowerProfile> {
+    let container = app_handle.try_state::<DIContainer>()?;
+    let profile = container
+        .settings_service
+        .get()
+        .performance
+        .game_power_profiles
+        .get(game_id)
+        .copied()?;
+    profile.tunes_process().then_some(profile)
+}
+
+/// Whether `game_id` has a priority or affinity to apply.
+#[must_use]
+pub fn wants_tuning(app_handle: &AppHandle, game_id: &str) -> bool {
+    profile(app_handle, game_id).is_some()
+}
+
+/// Applies the game's priority/affinity to `pid`.
+///
+/// A previously tuned process of the same game (the launcher before a
+/// hand-over) gets its original values back first. Failures are logged: the
+/// game keeps running with default scheduling.
+pub fn tune_game_process(app_handle: &AppHandle, game_id: &str, pid: u32) {
+    let Some(profile) = profile(app_handle, game_id) else {
+        return;
+    };
+    if TUNED
+        .lock()
+        .iter()
+        .any(|(id, tuned_pid, _)| id == game_id && *tuned_pid == pid)
+    {
+        return;
+    }
+    restore_game_process(game_id);
+
+    let saved = match ProcessPriorityAdapter::scheduling(pid) {
+        Ok(saved) => saved,
+        Err(e) => {
+            warn!("Cannot tune process {} of {}: {}", pid, game_id, e);
+            return;
+        },
+    };
+    if let Some(priority) = profile.priority {
+        if let Err(e) = ProcessPriorityAdapter::set_priority(pid, priority) {
+            warn!("Failed to set priority of {}: {}", game_id, e);
+        }
+    }
+    if let Some(affinity) = profile.affinity {
+        match affinity.resolve(saved.system_mask, ProcessPriorityAdapter::performance_cores()) {
+            Some(mask) => {
+                if let Err(e) = ProcessPriorityAdapter::set_affinity(pid, mask) {
+                    warn!("Failed to set CPU affinity of {}: {}", game_id, e);
+                }
+            },
+            None => debug!("Affinity {:?} leaves every core in use for {}", affinity, game_id),
+        }
+    }
+    info!(
+        "🎯 Process {} of {} tuned (priority {:?}, affinity {:?})",
+        pid, game_id, profile.priority, profile.affinity
+    );
+    TUNED.lock().push((game_id.to_string(), pid, saved));
+}
+
+/// Puts back the priority/affinity changed by [`tune_game_process`].
+///
+/// Nothing to do if the process already exited.
+pub fn restore_game_process(game_id: &str) {
+    let Some((_, pid, saved)) = ({
+        let mut tuned = TUNED.lock();
+        let index = tuned.iter().position(|(id, _, _)| id == game_id);
+        index.map(|index| tuned.remove(index))
+    }) else {
+        return;
+    };
+    match ProcessPriorityAdapter::restore(pid, &saved) {
+        Ok(()) => info!("🎯 Process {} of {} scheduling restored", pid, game_id),
+        Err(e) => debug!("Process {} of {} not restored (exited?): {}", pid, game_id, e),
+    }
+}
+
+/// Largest process running from `install_dir` (games started through a
+/// store client, where the launch returns no PID).
+#[must_use]
+pub fn find_process_in_dir(processes: &[ProcessInfo], install_dir: &str) -> Option<u32> {
+    let dir = format!(
+        "{}\\",
+        install_dir.to_lowercase().replace('/', "\\").trim_end_matches('\\')
+    );
+    processes
+        .iter()
+        .filter(|process| {
+            process
+                .exe
+                .as_ref()
+                .is_some_and(|exe| exe.to_string_lossy().to_lowercase().starts_with(&dir))
+        })
+        .max_by_key(|process| process.memory_bytes)
+        .map(|process| process.pid)
+}
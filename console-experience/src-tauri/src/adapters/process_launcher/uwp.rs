@@ -2,8 +2,219 @@
 // UWP (Universal Windows Platform) ACTIVATION
 // =============================================================================
 
-use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_LOCAL_SERVER, COINIT_APARTMENTTHREADED};
-use windows::Win32::UI::Shell::{ApplicationActivationManager, IApplicationActivationManager};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+use windows::core::{GUID, HSTRING, PCWSTR, PWSTR};
+use windows::Management::Deployment::PackageManager;
+use windows::Win32::Foundation::{CloseHandle, ERROR_INSUFFICIENT_BUFFER, ERROR_SUCCESS};
+use windows::Win32::Storage::Packaging::Appx::{
+    ClosePackageInfo, GetApplicationUserModelId, GetPackageFullName, GetPackageInfo, GetPackagesByPackageFamily,
+    OpenPackageInfoByFullName, _PACKAGE_INFO_REFERENCE, PACKAGE_FILTER_HEAD, PACKAGE_INFO,
+};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CLSCTX_INPROC_SERVER, CLSCTX_LOCAL_SERVER, COINIT_APARTMENTTHREADED,
+};
+use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+use windows::Win32::UI::Shell::{ApplicationActivationManager, IApplicationActivationManager, IPackageDebugSettings};
+
+/// CLSID of the `PackageDebugSettings` coclass (shobjidl_core.h).
+const CLSID_PACKAGE_DEBUG_SETTINGS: GUID = GUID::from_u128(0xB1AE_C16F_2383_4852_B0E9_8F0B_1DC6_6B4D);
+
+/// `APPLICATION_USER_MODEL_ID_MAX_LENGTH` (appmodel.h), terminator included.
+const AUMID_MAX_LENGTH: usize = 130;
+/// `PACKAGE_FULL_NAME_MAX_LENGTH` (appmodel.h) plus the terminator.
+const PACKAGE_FULL_NAME_MAX_LENGTH: usize = 128;
+
+/// How a running process belongs to a UWP package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageProcess {
+    /// Runs as the launched app (same AppUserModelId)
+    App,
+    /// Another process of the package: helper, broker or a GDK game's real exe
+    Package,
+}
+
+/// Package identity of a running process.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessIdentity {
+    /// `GetApplicationUserModelId`; `None` for processes that are not an app
+    pub app_user_model_id: Option<String>,
+    /// `GetPackageFullName`; `None` for processes without package identity
+    pub package_full_name: Option<String>,
+}
+
+/// The package of a launched UWP app, resolved once per launch.
+///
+/// Running processes are matched by their package identity instead of their
+/// path: `GetApplicationUserModelId` names the app a process runs as and
+/// `GetPackageFullName` the package it belongs to. Processes started without
+/// identity are matched by install folder (`OpenPackageInfoByFullName`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UwpPackage {
+    pub app_user_model_id: String,
+    pub family_name: String,
+    /// Installed versions of the family (`GetPackagesByPackageFamily`)
+    pub full_names: Vec<String>,
+    /// Install folder of each installed version
+    pub install_dirs: Vec<PathBuf>,
+}
+
+impl UwpPackage {
+    /// Looks up the installed packages of an AppUserModelId's family.
+    ///
+    /// A package that cannot be queried still matches processes by
+    /// AppUserModelId.
+    #[must_use]
+    pub fn resolve(app_user_model_id: &str) -> Self {
+        let family_name = package_family_name(app_user_model_id)
+            .unwrap_or(app_user_model_id)
+            .to_string();
+        let full_names = packages_by_family(&family_name);
+        let install_dirs = full_names
+            .iter()
+            .filter_map(|full_name| package_path(full_name))
+            .collect();
+        debug!("UWP package {}: {:?} in {:?}", family_name, full_names, install_dirs);
+        Self {
+            app_user_model_id: app_user_model_id.to_string(),
+            family_name,
+            full_names,
+            install_dirs,
+        }
+    }
+
+    /// How the process of `pid` belongs to the package, if it does.
+    #[must_use]
+    pub fn identify(&self, pid: u32, exe: Option<&Path>) -> Option<PackageProcess> {
+        self.classify(&process_identity(pid), exe)
+    }
+
+    fn classify(&self, identity: &ProcessIdentity, exe: Option<&Path>) -> Option<PackageProcess> {
+        if let Some(app_user_model_id) = &identity.app_user_model_id {
+            if app_user_model_id.eq_ignore_ascii_case(&self.app_user_model_id) {
+                return Some(PackageProcess::App);
+            }
+            if package_family_name(app_user_model_id)
+                .is_some_and(|family| family.eq_ignore_ascii_case(&self.family_name))
+            {
+                return Some(PackageProcess::Package);
+            }
+        }
+        if let Some(full_name) = &identity.package_full_name {
+            // Identity is authoritative: another package's process is never ours
+            return self
+                .full_names
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(full_name))
+                .then_some(PackageProcess::Package);
+        }
+        let exe = exe?;
+        self.install_dirs
+            .iter()
+            .any(|dir| exe.starts_with(dir))
+            .then_some(PackageProcess::Package)
+    }
+}
+
+/// Package identity of a running process (empty for processes that cannot
+/// be opened or have none).
+#[must_use]
+pub fn process_identity(pid: u32) -> ProcessIdentity {
+    unsafe {
+        let Ok(process) = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) else {
+            return ProcessIdentity::default();
+        };
+
+        let mut app_user_model_id = [0u16; AUMID_MAX_LENGTH];
+        let mut length = app_user_model_id.len() as u32;
+        let app_user_model_id =
+            (GetApplicationUserModelId(process, &mut length, PWSTR(app_user_model_id.as_mut_ptr())) == ERROR_SUCCESS)
+                .then(|| wide_to_string(&app_user_model_id));
+
+        let mut full_name = [0u16; PACKAGE_FULL_NAME_MAX_LENGTH];
+        let mut length = full_name.len() as u32;
+        let package_full_name = (GetPackageFullName(process, &mut length, PWSTR(full_name.as_mut_ptr()))
+            == ERROR_SUCCESS)
+            .then(|| wide_to_string(&full_name));
+
+        let _ = CloseHandle(process);
+        ProcessIdentity {
+            app_user_model_id,
+            package_full_name,
+        }
+    }
+}
+
+/// Full names of the installed packages of a family.
+fn packages_by_family(family_name: &str) -> Vec<String> {
+    let family = HSTRING::from(family_name);
+    unsafe {
+        let mut count = 0u32;
+        let mut buffer_length = 0u32;
+        let sized = GetPackagesByPackageFamily(&family, &mut count, None, &mut buffer_length, PWSTR::null());
+        if sized != ERROR_INSUFFICIENT_BUFFER || count == 0 {
+            return Vec::new();
+        }
+
+        let mut names = vec![PWSTR::null(); count as usize];
+        let mut buffer = vec![0u16; buffer_length as usize];
+        let result = GetPackagesByPackageFamily(
+            &family,
+            &mut count,
+            Some(names.as_mut_ptr()),
+            &mut buffer_length,
+            PWSTR(buffer.as_mut_ptr()),
+        );
+        if result != ERROR_SUCCESS {
+            return Vec::new();
+        }
+        names
+            .iter()
+            .take(count as usize)
+            .filter_map(|name| name.to_string().ok())
+            .collect()
+    }
+}
+
+/// Install folder of an installed package.
+fn package_path(full_name: &str) -> Option<PathBuf> {
+    unsafe {
+        let mut reference: *mut _PACKAGE_INFO_REFERENCE = std::ptr::null_mut();
+        if OpenPackageInfoByFullName(&HSTRING::from(full_name), 0, &mut reference) != ERROR_SUCCESS {
+            return None;
+        }
+
+        let mut length = 0u32;
+        let mut count = 0u32;
+        let mut path = None;
+        if GetPackageInfo(reference, PACKAGE_FILTER_HEAD, &mut length, None, Some(&mut count))
+            == ERROR_INSUFFICIENT_BUFFER
+        {
+            // u64 storage: PACKAGE_INFO holds pointers and needs their alignment
+            let mut buffer = vec![0u64; (length as usize).div_ceil(8)];
+            if GetPackageInfo(
+                reference,
+                PACKAGE_FILTER_HEAD,
+                &mut length,
+                Some(buffer.as_mut_ptr().cast()),
+                Some(&mut count),
+            ) == ERROR_SUCCESS
+                && count > 0
+            {
+                let info = &*buffer.as_ptr().cast::<PACKAGE_INFO>();
+                path = info.path.to_string().ok().map(PathBuf::from);
+            }
+        }
+
+        let _ = ClosePackageInfo(reference);
+        path
+    }
+}
+
+fn wide_to_string(buffer: &[u16]) -> String {
+    let length = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..length])
+}
 
 /// Activates a UWP application natively using COM interfaces.
 ///
@@ -37,6 +248,68 @@ pub fn launch_uwp_app(app_user_model_id: &str) -> Result<u32, String> {
     }
 }
 
+/// Terminates every process of the package an AppUserModelId belongs to.
+///
+/// Resolves the installed `PackageFullName`(s) of the AUMID's package family via
+/// `PackageManager`, then calls `IPackageDebugSettings::TerminateAllProcesses`,
+/// the documented way to end a UWP app (background tasks and broker processes
+/// included), instead of matching process names.
+///
+/// # Errors
+/// Returns error if the AUMID is malformed, no package of the family is
+/// installed for the current user, or termination fails for every package.
+pub fn terminate_uwp_package(app_user_model_id: &str) -> Result<(), String> {
+    let family_name = package_family_name(app_user_model_id).ok_or("Invalid AppUserModelId")?;
+
+    let manager = PackageManager::new().map_err(|e| format!("Failed to create PackageManager: {e}"))?;
+    // Empty SID = current user
+    let packages = manager
+        .FindPackagesByUserSecurityIdPackageFamilyName(&HSTRING::new(), &HSTRING::from(family_name))
+        .map_err(|e| format!("Failed to query packages for {family_name}: {e}"))?;
+
+    let full_names: Vec<HSTRING> = packages
+        .into_iter()
+        .filter_map(|package| package.Id().and_then(|id| id.FullName()).ok())
+        .collect();
+    if full_names.is_empty() {
+        return Err(format!("No installed package for family {family_name}"));
+    }
+
+    unsafe {
+        // We ignore the error because it likely means COM is already initialized
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+
+        let debug_settings: IPackageDebugSettings =
+            CoCreateInstance(&CLSID_PACKAGE_DEBUG_SETTINGS, None, CLSCTX_INPROC_SERVER)
+                .map_err(|e| format!("Failed to create PackageDebugSettings: {e}"))?;
+
+        let mut terminated = 0;
+        for full_name in &full_names {
+            match debug_settings.TerminateAllProcesses(PCWSTR::from_raw(full_name.as_ptr())) {
+                Ok(()) => {
+                    info!("Terminated UWP package: {}", full_name);
+                    terminated += 1;
+                },
+                Err(e) => warn!("Failed to terminate UWP package {}: {}", full_name, e),
+            }
+        }
+
+        if terminated == 0 {
+            return Err(format!("Failed to terminate package family {family_name}"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Package family name of an AppUserModelId (`<Family>!<AppId>`).
+fn package_family_name(app_user_model_id: &str) -> Option<&str> {
+    app_user_model_id
+        .split_once('!')
+        .map(|(family, _)| family)
+        .filter(|family| !family.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -46,4 +319,63 @@ mod tests {
         let result = launch_uwp_app("InvalidAppId_NotReal!App");
         assert!(result.is_err(), "Invalid UWP app ID should return error");
     }
+
+    #[test]
+    fn test_package_family_name() {
+        assert_eq!(
+            package_family_name("Microsoft.MinecraftUWP_8wekyb3d8bbwe!App"),
+            Some("Microsoft.MinecraftUWP_8wekyb3d8bbwe")
+        );
+        assert_eq!(package_family_name("C:\\Games\\game.exe"), None);
+        assert_eq!(package_family_name("!App"), None);
+    }
+
+    #[test]
+    fn test_processes_match_by_package_identity() {
+        let package = UwpPackage {
+            app_user_model_id: "Microsoft.624F8B84B80_8wekyb3d8bbwe!Game".to_string(),
+            family_name: "Microsoft.624F8B84B80_8wekyb3d8bbwe".to_string(),
+            full_names: vec!["Microsoft.624F8B84B80_1.0.0.0_x64__8wekyb3d8bbwe".to_string()],
+            install_dirs: vec![PathBuf::from("C:\\XboxGames\\Forza Horizon 5\\Content")],
+        };
+        let identity = |aumid: Option<&str>, full_name: Option<&str>| ProcessIdentity {
+            app_user_model_id: aumid.map(str::to_string),
+            package_full_name: full_name.map(str::to_string),
+        };
+        let game_exe = Path::new("C:\\XboxGames\\Forza Horizon 5\\Content\\ForzaHorizon5.exe");
+
+        assert_eq!(
+            package.classify(&identity(Some("microsoft.624f8b84b80_8wekyb3d8bbwe!Game"), None), None),
+            Some(PackageProcess::App)
+        );
+        assert_eq!(
+            package.classify(
+                &identity(None, Some("Microsoft.624F8B84B80_1.0.0.0_x64__8wekyb3d8bbwe")),
+                Some(game_exe)
+            ),
+            Some(PackageProcess::Package)
+        );
+        // No identity: matched by install folder only
+        assert_eq!(
+            package.classify(&ProcessIdentity::default(), Some(game_exe)),
+            Some(PackageProcess::Package)
+        );
+        assert_eq!(
+            package.classify(
+                &ProcessIdentity::default(),
+                Some(Path::new("C:\\Windows\\explorer.exe"))
+            ),
+            None
+        );
+        // Another package's process in the same folder is not the game
+        assert_eq!(
+            package.classify(&identity(None, Some("Other_1.0.0.0_x64__abc")), Some(game_exe)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_terminate_uwp_package_handles_unknown_family() {
+        assert!(terminate_uwp_package("InvalidFamily_NotReal!App").is_err());
+    }
 }
@@ -0,0 +1,152 @@
+// =============================================================================
+// EPIC MANIFEST WATCHDOG
+// =============================================================================
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+use super::super::constants::{EPIC_EXIT_GRACE_SECONDS, PROCESS_SCAN_INTERVAL_MS};
+use super::super::error_handler::emit_launch_error;
+use super::super::process_tuning::tune_game_process;
+use super::super::window_manager::restore_window_after_session;
+use crate::application::services::ProcessInfo;
+use crate::application::ActiveGamesTracker;
+use crate::domain::GameLaunchError;
+
+/// Start Epic manifest-based watchdog
+///
+/// Monitors the real game executable from the manifest's `LaunchExecutable`
+/// instead of a PID: the launcher protocol gives no PID, and EGL's DRM check
+/// makes the game exit and relaunch itself right after start.
+///
+/// # Events
+/// - Emits `game-process-started` when the game exe appears
+/// - Emits `game-ended` once no instance has run for `EPIC_EXIT_GRACE_SECONDS`
+pub fn start_epic_manifest_watchdog(
+    executable_path: PathBuf,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) {
+    thread::spawn(move || {
+        #[derive(serde::Serialize, Clone)]
+        struct GameStartedPayload {
+            game_id: String,
+            executable_name: Option<String>,
+        }
+
+        #[derive(serde::Serialize, Clone)]
+        struct GameEndedPayload {
+            game_id: String,
+            play_time_seconds: u64,
+        }
+
+        let timeout_seconds = super::launch_settings(&app_handle).epic_timeout_seconds;
+        info!(
+            ">>> Epic Manifest Watchdog STARTED for: {} (timeout: {}s) <<<",
+            executable_path.display(),
+            timeout_seconds
+        );
+
+        let executable_name = executable_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned());
+        let launch_time = Instant::now();
+        let mut start_time: Option<Instant> = None;
+        let mut last_seen = Instant::now();
+        let mut tracked_pid: Option<u32> = None;
+        let processes = super::processes(&app_handle);
+
+        loop {
+            thread::sleep(Duration::from_millis(PROCESS_SCAN_INTERVAL_MS));
+
+            // Stop if the session was killed/closed from the UI
+            if start_time.is_some() && tracker.get(&game_id).is_none() {
+                info!("Epic session {} no longer tracked, stopping watchdog", game_id);
+                break;
+            }
+
+            let pid = find_process_by_exe(&processes.processes(), &executable_path);
+
+            if let Some(pid) = pid {
+                last_seen = Instant::now();
+
+                if start_time.is_none() {
+                    info!("Epic game running (PID {}). Monitoring...", pid);
+                    start_time = Some(Instant::now());
+
+                    let payload = GameStartedPayload {
+                        game_id: game_id.clone(),
+                        executable_name: executable_name.clone(),
+                    };
+                    if let Err(e) = app_handle.emit("game-process-started", &payload) {
+                        error!("Failed to emit game-process-started event: {}", e);
+                    }
+                }
+
+                // Follow the relaunched process so suspend/focus/metrics target the real game
+                if tracked_pid != Some(pid) {
+                    tracked_pid = Some(pid);
+                    tracker.set_pid(&game_id, Some(pid));
+                    tune_game_process(&app_handle, &game_id, pid);
+                }
+            } else if let Some(started) = start_time {
+                if last_seen.elapsed() < Duration::from_secs(EPIC_EXIT_GRACE_SECONDS) {
+                    continue;
+                }
+
+                let play_time_seconds = started.elapsed().as_secs().saturating_sub(EPIC_EXIT_GRACE_SECONDS);
+                info!(
+                    "Epic game stopped. Session duration: {}s ({:.1}min)",
+                    play_time_seconds,
+                    play_time_seconds as f64 / 60.0
+                );
+
+                tracker.unregister(&game_id);
+
+                let payload = GameEndedPayload {
+                    game_id: game_id.clone(),
+                    play_time_seconds,
+                };
+                if let Err(e) = app_handle.emit("game-ended", &payload) {
+                    error!("Failed to emit game-ended event: {}", e);
+                }
+
+                restore_window_after_session(&app_handle, &tracker);
+                break;
+            } else if launch_time.elapsed() >= Duration::from_secs(timeout_seconds) {
+                warn!("Epic game startup TIMEOUT after {}s - emitting error", timeout_seconds);
+
+                let game_info = tracker.get(&game_id);
+                tracker.unregister(&game_id);
+
+                if let Some(info) = game_info {
+                    let error = GameLaunchError::epic_timeout(game_id.clone(), info.game.title, timeout_seconds);
+                    emit_launch_error(&app_handle, error);
+                }
+
+                restore_window_after_session(&app_handle, &tracker);
+                break;
+            }
+        }
+    });
+}
+
+/// PID of a running process whose executable matches `executable_path`.
+fn find_process_by_exe(processes: &[ProcessInfo], executable_path: &Path) -> Option<u32> {
+    let target = executable_path.to_string_lossy().to_lowercase().replace('/', "\\");
+
+    processes
+        .iter()
+        .find(|process| {
+            process
+                .exe
+                .as_ref()
+                .is_some_and(|exe| exe.to_string_lossy().to_lowercase() == target)
+        })
+        .map(|process| process.pid)
+}
@@ -4,14 +4,66 @@
 //
 // Process monitoring strategies for different game launchers:
 // - Steam: Registry-based monitoring (most reliable)
+// - Epic: Manifest executable monitoring (survives the EGL DRM relaunch)
 // - PID: Generic process ID tracking
-// - Xbox: Explorer fallback for UWP apps
+// - Process name: Games started through their own launcher
+// - Xbox: Explorer fallback for UWP apps (found by package identity)
+//
+// Steam, PID and Xbox monitors share one async supervisor task instead of a
+// polling thread per game.
 
+pub mod epic;
 pub mod pid;
+pub mod process_name;
 pub mod steam;
+pub mod supervisor;
 pub mod xbox;
 
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+use crate::application::services::ProcessInspectionService;
+use crate::application::DIContainer;
+use crate::domain::settings::LaunchSettings;
+
 // Re-export main functions
+pub use epic::start_epic_manifest_watchdog;
 pub use pid::start_watchdog;
+pub use process_name::start_process_name_watchdog;
 pub use steam::start_steam_registry_watchdog;
+pub use supervisor::WatchdogSupervisor;
 pub use xbox::start_xbox_explorer_watchdog;
+
+/// Launch timeouts from the user's settings (defaults if unavailable)
+fn launch_settings(app_handle: &AppHandle) -> LaunchSettings {
+    app_handle
+        .try_state::<DIContainer>()
+        .map(|container| container.settings_service.get().launch)
+        .unwrap_or_default()
+}
+
+/// The app-wide process table (shared by every watchdog)
+fn processes(app_handle: &AppHandle) -> Arc<ProcessInspectionService> {
+    app_handle
+        .try_state::<DIContainer>()
+        .map_or_else(ProcessInspectionService::shared, |container| container.processes.clone())
+}
+
+/// The supervisor running the game monitors
+fn supervisor(app_handle: &AppHandle) -> Arc<WatchdogSupervisor> {
+    app_handle
+        .try_state::<DIContainer>()
+        .map_or_else(WatchdogSupervisor::shared, |container| container.watchdogs.clone())
+}
+
+#[derive(serde::Serialize, Clone)]
+struct GameStartedPayload {
+    game_id: String,
+    executable_name: Option<String>,
+}
+
+#[derive(serde::Serialize, Clone)]
+struct GameEndedPayload {
+    game_id: String,
+    play_time_seconds: u64,
+}
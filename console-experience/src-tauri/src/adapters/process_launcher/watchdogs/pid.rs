@@ -3,86 +3,130 @@
 // =============================================================================
 
 use std::sync::Arc;
-use std::thread;
 use std::time::{Duration, Instant};
-use sysinfo::{Pid, System};
 use tauri::{AppHandle, Emitter};
 use tracing::{error, info, warn};
 
 use super::super::constants::QUICK_EXIT_THRESHOLD_SECONDS;
 use super::super::error_handler::emit_launch_error;
-use super::super::window_manager::restore_window;
+use super::super::process_tree::ProcessTree;
+use super::super::process_tuning::tune_game_process;
+use super::super::window_manager::restore_window_after_session;
+use super::supervisor::{GameMonitor, Poll, PollContext};
+use super::GameEndedPayload;
 use crate::application::ActiveGamesTracker;
 use crate::domain::GameLaunchError;
 
 /// Start generic PID-based watchdog
 ///
-/// Monitors a process by PID. Works for native executables and some launchers.
+/// Monitors a process and every child it spawns, so games started through a
+/// launcher (`launcher.exe` → `game.exe`) are only reported as ended when the
+/// whole process tree is gone. The tracker PID follows the real game process.
 pub fn start_watchdog(pid: u32, app_handle: AppHandle, tracker: Arc<ActiveGamesTracker>, game_id: String) {
-    thread::spawn(move || {
-        #[derive(serde::Serialize, Clone)]
-        struct GameEndedPayload {
-            game_id: String,
-            play_time_seconds: u64,
+    info!("PID Watchdog started for: {} (game: {})", pid, game_id);
+
+    let monitor = PidMonitor {
+        pid,
+        tree: ProcessTree::new(pid),
+        primary_pid: pid,
+        start_time: Instant::now(),
+        tuned: false,
+        app_handle: app_handle.clone(),
+        tracker,
+        game_id,
+    };
+    super::supervisor(&app_handle).watch(Box::new(monitor));
+}
+
+struct PidMonitor {
+    /// PID the game was launched with
+    pid: u32,
+    tree: ProcessTree,
+    primary_pid: u32,
+    start_time: Instant,
+    tuned: bool,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+}
+
+impl GameMonitor for PidMonitor {
+    fn interval(&self) -> Duration {
+        // Process exits wake the monitor earlier
+        Duration::from_secs(2)
+    }
+
+    fn watched_pids(&self) -> Vec<u32> {
+        vec![self.pid, self.primary_pid]
+    }
+
+    fn poll(&mut self, context: &PollContext) -> Poll {
+        // Check if any process of the tree is still alive
+        self.tree.refresh(context.processes());
+        if let Some(current) = self.tree.primary_pid() {
+            if current != self.primary_pid {
+                info!(
+                    "Game {} handed over from PID {} to PID {}",
+                    self.game_id, self.primary_pid, current
+                );
+                self.primary_pid = current;
+                self.tracker.set_pid(&self.game_id, Some(current));
+                self.tuned = false;
+            }
+            // Priority/affinity of the game profile follow the real game process
+            if !self.tuned {
+                tune_game_process(&self.app_handle, &self.game_id, current);
+                self.tuned = true;
+            }
         }
 
-        let mut sys = System::new_all();
-        let target_pid = Pid::from_u32(pid);
-        let start_time = Instant::now();
-
-        info!("PID Watchdog started for: {} (game: {})", pid, game_id);
-
-        loop {
-            // Check every 2 seconds
-            thread::sleep(Duration::from_secs(2));
-
-            // Refresh process list specifically
-            sys.refresh_processes();
-
-            // Check if process is still alive
-            if sys.process(target_pid).is_none() {
-                let runtime = start_time.elapsed().as_secs();
-                info!("Process {} ended after {}s. Restoring window.", pid, runtime);
-
-                // Check if it's a quick exit (< 5 seconds = likely a failure)
-                if runtime < QUICK_EXIT_THRESHOLD_SECONDS {
-                    warn!(
-                        "Quick exit detected ({}s < {}s) - emitting error",
-                        runtime, QUICK_EXIT_THRESHOLD_SECONDS
-                    );
-
-                    // Get game info before unregistering
-                    let game_info = tracker.get(&game_id);
-                    tracker.unregister(&game_id);
-
-                    // Emit error to frontend
-                    if let Some(info) = game_info {
-                        let error = GameLaunchError::native_quick_exit(
-                            game_id.clone(),
-                            info.game.title,
-                            runtime,
-                            info.game.source.display_name().to_string(),
-                        );
-                        emit_launch_error(&app_handle, error);
-                    }
-                } else {
-                    // Normal exit (game ran for more than 5 seconds)
-                    tracker.unregister(&game_id);
-                }
-
-                // Emit event to frontend with play time
-                let payload = GameEndedPayload {
-                    game_id: game_id.clone(),
-                    play_time_seconds: runtime,
-                };
-
-                if let Err(e) = app_handle.emit("game-ended", &payload) {
-                    error!("Failed to emit game-ended event: {}", e);
-                }
-
-                restore_window(&app_handle);
-                break; // Exit watchdog
+        if self.tree.is_alive() {
+            return Poll::Continue;
+        }
+
+        let runtime = self.start_time.elapsed().as_secs();
+        info!(
+            "Process tree of {} ended after {}s. Restoring window.",
+            self.pid, runtime
+        );
+
+        // Check if it's a quick exit (< 5 seconds = likely a failure)
+        if runtime < QUICK_EXIT_THRESHOLD_SECONDS {
+            warn!(
+                "Quick exit detected ({}s < {}s) - emitting error",
+                runtime, QUICK_EXIT_THRESHOLD_SECONDS
+            );
+
+            // Get game info before unregistering
+            let game_info = self.tracker.get(&self.game_id);
+            self.tracker.unregister(&self.game_id);
+
+            // Emit error to frontend
+            if let Some(info) = game_info {
+                let error = GameLaunchError::native_quick_exit(
+                    self.game_id.clone(),
+                    info.game.title,
+                    runtime,
+                    info.game.source.display_name().to_string(),
+                );
+                emit_launch_error(&self.app_handle, error);
             }
+        } else {
+            // Normal exit (game ran for more than 5 seconds)
+            self.tracker.unregister(&self.game_id);
         }
-    });
+
+        // Emit event to frontend with play time
+        let payload = GameEndedPayload {
+            game_id: self.game_id.clone(),
+            play_time_seconds: runtime,
+        };
+
+        if let Err(e) = self.app_handle.emit("game-ended", &payload) {
+            error!("Failed to emit game-ended event: {}", e);
+        }
+
+        restore_window_after_session(&self.app_handle, &self.tracker);
+        Poll::Finished
+    }
 }
@@ -0,0 +1,145 @@
+// =============================================================================
+// PROCESS NAME WATCHDOG
+// =============================================================================
+
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter};
+use tracing::{error, info, warn};
+
+use super::super::constants::{EPIC_EXIT_GRACE_SECONDS, PROCESS_SCAN_INTERVAL_MS};
+use super::super::error_handler::emit_launch_error;
+use super::super::process_tuning::tune_game_process;
+use super::super::window_manager::restore_window_after_session;
+use crate::application::services::ProcessInfo;
+use crate::application::ActiveGamesTracker;
+use crate::domain::GameLaunchError;
+
+/// Start process-name watchdog
+///
+/// Monitors games started through their own launcher (Riot Client,
+/// Minecraft Launcher): the launcher keeps running after the game exits, so
+/// the session follows the game's process names instead of a PID.
+///
+/// # Events
+/// - Emits `game-process-started` when one of the processes appears
+/// - Emits `game-ended` once none has run for `EPIC_EXIT_GRACE_SECONDS`
+///   (launchers restart the game while patching, like EGL's DRM relaunch)
+pub fn start_process_name_watchdog(
+    process_names: Vec<String>,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+) {
+    thread::spawn(move || {
+        #[derive(serde::Serialize, Clone)]
+        struct GameStartedPayload {
+            game_id: String,
+            executable_name: Option<String>,
+        }
+
+        #[derive(serde::Serialize, Clone)]
+        struct GameEndedPayload {
+            game_id: String,
+            play_time_seconds: u64,
+        }
+
+        let timeout_seconds = super::launch_settings(&app_handle).standalone_timeout_seconds;
+        info!(
+            ">>> Process Name Watchdog STARTED for: {:?} (timeout: {}s) <<<",
+            process_names, timeout_seconds
+        );
+
+        let launch_time = Instant::now();
+        let mut start_time: Option<Instant> = None;
+        let mut last_seen = Instant::now();
+        let mut tracked_pid: Option<u32> = None;
+        let processes = super::processes(&app_handle);
+
+        loop {
+            thread::sleep(Duration::from_millis(PROCESS_SCAN_INTERVAL_MS));
+
+            // Stop if the session was killed/closed from the UI
+            if start_time.is_some() && tracker.get(&game_id).is_none() {
+                info!("Session {} no longer tracked, stopping watchdog", game_id);
+                break;
+            }
+
+            let found = find_process_by_name(&processes.processes(), &process_names);
+
+            if let Some((pid, name)) = found {
+                last_seen = Instant::now();
+
+                if start_time.is_none() {
+                    info!("Game running as {} (PID {}). Monitoring...", name, pid);
+                    start_time = Some(Instant::now());
+
+                    let payload = GameStartedPayload {
+                        game_id: game_id.clone(),
+                        executable_name: Some(name),
+                    };
+                    if let Err(e) = app_handle.emit("game-process-started", &payload) {
+                        error!("Failed to emit game-process-started event: {}", e);
+                    }
+                }
+
+                if tracked_pid != Some(pid) {
+                    tracked_pid = Some(pid);
+                    tracker.set_pid(&game_id, Some(pid));
+                    tune_game_process(&app_handle, &game_id, pid);
+                }
+            } else if let Some(started) = start_time {
+                if last_seen.elapsed() < Duration::from_secs(EPIC_EXIT_GRACE_SECONDS) {
+                    continue;
+                }
+
+                let play_time_seconds = started.elapsed().as_secs().saturating_sub(EPIC_EXIT_GRACE_SECONDS);
+                info!(
+                    "Game stopped. Session duration: {}s ({:.1}min)",
+                    play_time_seconds,
+                    play_time_seconds as f64 / 60.0
+                );
+
+                tracker.unregister(&game_id);
+
+                let payload = GameEndedPayload {
+                    game_id: game_id.clone(),
+                    play_time_seconds,
+                };
+                if let Err(e) = app_handle.emit("game-ended", &payload) {
+                    error!("Failed to emit game-ended event: {}", e);
+                }
+
+                restore_window_after_session(&app_handle, &tracker);
+                break;
+            } else if launch_time.elapsed() >= Duration::from_secs(timeout_seconds) {
+                warn!(
+                    "Launcher game startup TIMEOUT after {}s - emitting error",
+                    timeout_seconds
+                );
+
+                let game_info = tracker.get(&game_id);
+                tracker.unregister(&game_id);
+
+                if let Some(info) = game_info {
+                    let error = GameLaunchError::launcher_timeout(game_id.clone(), info.game.title, timeout_seconds);
+                    emit_launch_error(&app_handle, error);
+                }
+
+                restore_window_after_session(&app_handle, &tracker);
+                break;
+            }
+        }
+    });
+}
+
+/// PID and name of a running process named like one of `names` (case-insensitive).
+fn find_process_by_name(processes: &[ProcessInfo], names: &[String]) -> Option<(u32, String)> {
+    processes.iter().find_map(|process| {
+        names
+            .iter()
+            .any(|target| target.eq_ignore_ascii_case(&process.name))
+            .then(|| (process.pid, process.name.clone()))
+    })
+}
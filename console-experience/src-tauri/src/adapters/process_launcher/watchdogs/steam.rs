@@ -3,24 +3,34 @@
 // =============================================================================
 
 use std::sync::Arc;
-use std::thread;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
 use tracing::{error, info, warn};
 use winreg::enums::HKEY_CURRENT_USER;
 use winreg::RegKey;
 
-use super::super::constants::{POLLING_INTERVAL_MS, STEAM_TIMEOUT_SECONDS};
+use super::super::constants::{POLLING_INTERVAL_MS, PROCESS_SCAN_INTERVAL_MS, STEAM_BUSY_DISK_BYTES_PER_SECOND};
 use super::super::error_handler::emit_launch_error;
-use super::super::window_manager::restore_window;
+use super::super::pre_flight::steam_client_state;
+use super::super::process_tuning::{find_process_in_dir, tune_game_process, wants_tuning};
+use super::super::window_manager::restore_window_after_session;
+use super::supervisor::{GameMonitor, Poll, PollContext};
+use super::{GameEndedPayload, GameStartedPayload};
+use crate::application::services::ProcessInfo;
 use crate::application::ActiveGamesTracker;
-use crate::domain::GameLaunchError;
+use crate::domain::launch_timeout::AdaptiveTimeout;
+use crate::domain::launcher_dependency::StoreClientState;
+use crate::domain::{GameLaunchError, LaunchFailureReason};
 
 /// Start Steam registry-based watchdog
 ///
 /// Monitors the Steam registry key to detect game start/stop.
 /// More reliable than PID tracking for Steam games.
 ///
+/// With adaptive timeouts, the startup deadline moves while Steam is busy
+/// with the game: the app's `Updating` flag, a shader pre-compile
+/// (`fossilize_replay.exe`) or Steam reading/writing the disk.
+///
 /// # Events
 /// - Emits `game-process-started` when game starts (with executable_name if available)
 /// - Emits `game-ended` when game stops
@@ -31,116 +41,211 @@ pub fn start_steam_registry_watchdog(
     game_id: String,
     executable_name: Option<String>,
 ) {
-    thread::spawn(move || {
-        #[derive(serde::Serialize, Clone)]
-        struct GameStartedPayload {
-            game_id: String,
-            executable_name: Option<String>,
+    let timeout = super::launch_settings(&app_handle).steam_timeout();
+    info!(
+        ">>> Steam Registry Watchdog STARTED for AppID: {} (timeout: {}s, polling: {}ms) <<<",
+        app_id,
+        timeout.seconds(),
+        POLLING_INTERVAL_MS
+    );
+
+    let monitor = SteamMonitor {
+        key_path: format!("Software\\Valve\\Steam\\Apps\\{app_id}"),
+        timeout,
+        launch_time: Instant::now(),
+        start_time: None,
+        steam_disk_bytes: None,
+        untuned: wants_tuning(&app_handle, &game_id),
+        last_scan: Instant::now(),
+        app_handle: app_handle.clone(),
+        tracker,
+        game_id,
+        executable_name,
+    };
+    super::supervisor(&app_handle).watch(Box::new(monitor));
+}
+
+struct SteamMonitor {
+    key_path: String,
+    timeout: AdaptiveTimeout,
+    launch_time: Instant,
+    /// Set once Steam reports the game running
+    start_time: Option<Instant>,
+    /// Steam's disk total at the last scan
+    steam_disk_bytes: Option<u64>,
+    // Steam gives no PID: the game process is looked up in its install
+    // folder, only when the game profile has a priority/affinity to apply.
+    // Before the game starts, the same scan watches Steam's activity.
+    untuned: bool,
+    last_scan: Instant,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+    executable_name: Option<String>,
+}
+
+impl SteamMonitor {
+    fn is_running(&self) -> bool {
+        RegKey::predef(HKEY_CURRENT_USER)
+            .open_subkey(&self.key_path)
+            .and_then(|key| key.get_value::<u32, _>("Running"))
+            .is_ok_and(|running| running == 1)
+    }
+
+    fn scan_due(&self) -> bool {
+        self.last_scan.elapsed() >= Duration::from_millis(PROCESS_SCAN_INTERVAL_MS)
+    }
+
+    fn on_running(&mut self, context: &PollContext) {
+        if self.start_time.is_none() {
+            info!("Steam reported game running! Monitoring...");
+            self.start_time = Some(Instant::now()); // Record start time
+
+            // Emit event for overlay auto-injector (separation of concerns)
+            let payload = GameStartedPayload {
+                game_id: self.game_id.clone(),
+                executable_name: self.executable_name.clone(),
+            };
+
+            if let Err(e) = self.app_handle.emit("game-process-started", &payload) {
+                error!("Failed to emit game-process-started event: {}", e);
+            }
         }
 
-        #[derive(serde::Serialize, Clone)]
-        struct GameEndedPayload {
-            game_id: String,
-            play_time_seconds: u64,
+        if self.untuned && self.scan_due() {
+            self.last_scan = Instant::now();
+            let pid = self
+                .tracker
+                .get(&self.game_id)
+                .and_then(|info| find_process_in_dir(context.processes(), &info.path));
+            if let Some(pid) = pid {
+                tune_game_process(&self.app_handle, &self.game_id, pid);
+                self.untuned = false;
+            }
         }
+    }
+
+    fn on_stopped(&self, start: Instant) {
+        // Game closed normally
+        info!("Steam reported game stopped. Restoring window.");
+
+        // Calculate play time
+        let play_time_seconds = start.elapsed().as_secs();
 
         info!(
-            ">>> Steam Registry Watchdog STARTED for AppID: {} (timeout: {}s, polling: {}ms) <<<",
-            app_id, STEAM_TIMEOUT_SECONDS, POLLING_INTERVAL_MS
+            "⏱️ Game session duration: {}s ({:.1}min)",
+            play_time_seconds,
+            play_time_seconds as f64 / 60.0
         );
 
-        let hkcu = RegKey::predef(HKEY_CURRENT_USER);
-        let key_path = format!("Software\\Valve\\Steam\\Apps\\{app_id}");
+        self.tracker.unregister(&self.game_id);
 
-        let mut game_has_started = false;
-        let mut start_time: Option<Instant> = None;
-        let mut attempts = 0;
-        let max_attempts = (STEAM_TIMEOUT_SECONDS * 1000) / POLLING_INTERVAL_MS;
+        // Emit event to frontend with play time
+        let payload = GameEndedPayload {
+            game_id: self.game_id.clone(),
+            play_time_seconds,
+        };
 
-        loop {
-            thread::sleep(Duration::from_millis(POLLING_INTERVAL_MS));
-
-            let mut is_running = false;
+        if let Err(e) = self.app_handle.emit("game-ended", &payload) {
+            error!("Failed to emit game-ended event: {}", e);
+        }
 
-            if let Ok(key) = hkcu.open_subkey(&key_path) {
-                if let Ok(running_val) = key.get_value::<u32, _>("Running") {
-                    if running_val == 1 {
-                        is_running = true;
-                    }
-                }
+        restore_window_after_session(&self.app_handle, &self.tracker);
+    }
+
+    /// Game hasn't started yet - extends the wait while Steam works on it;
+    /// `true` once the startup timed out
+    fn check_startup(&mut self, context: &PollContext) -> bool {
+        if self.scan_due() {
+            let interval = self.last_scan.elapsed();
+            self.last_scan = Instant::now();
+            let steam_active = steam_busy(context.processes(), &mut self.steam_disk_bytes, interval);
+            let busy = steam_app_updating(&self.key_path) || steam_active;
+            if busy && self.timeout.observe_activity(self.launch_time.elapsed()) {
+                info!(
+                    "Steam is working on the game, waiting up to {}s",
+                    self.timeout.seconds()
+                );
             }
+        }
 
-            if is_running {
-                if !game_has_started {
-                    info!("Steam reported game running! Monitoring...");
-                    game_has_started = true;
-                    start_time = Some(Instant::now()); // Record start time
-
-                    // Emit event for overlay auto-injector (separation of concerns)
-                    let payload = GameStartedPayload {
-                        game_id: game_id.clone(),
-                        executable_name: executable_name.clone(),
-                    };
-
-                    if let Err(e) = app_handle.emit("game-process-started", &payload) {
-                        error!("Failed to emit game-process-started event: {}", e);
-                    }
-                }
-            } else if game_has_started {
-                // Game closed normally
-                info!("Steam reported game stopped. Restoring window.");
-
-                // Calculate play time
-                let play_time_seconds = if let Some(start) = start_time {
-                    start.elapsed().as_secs()
-                } else {
-                    0
-                };
+        if !self.timeout.is_expired(self.launch_time.elapsed()) {
+            return false;
+        }
 
-                info!(
-                    "⏱️ Game session duration: {}s ({:.1}min)",
-                    play_time_seconds,
-                    play_time_seconds as f64 / 60.0
-                );
+        warn!(
+            "Steam game startup TIMEOUT after {}s - emitting error",
+            self.timeout.seconds()
+        );
 
-                tracker.unregister(&game_id);
-
-                // Emit event to frontend with play time
-                let payload = GameEndedPayload {
-                    game_id: game_id.clone(),
-                    play_time_seconds,
-                };
-
-                if let Err(e) = app_handle.emit("game-ended", &payload) {
-                    error!("Failed to emit game-ended event: {}", e);
-                }
-
-                restore_window(&app_handle);
-                break;
-            } else {
-                // Game hasn't started yet - check timeout
-                attempts += 1;
-                if attempts >= max_attempts {
-                    warn!(
-                        "Steam game startup TIMEOUT after {}s - emitting error",
-                        STEAM_TIMEOUT_SECONDS
-                    );
-
-                    // Get game info from tracker before unregistering
-                    let game_info = tracker.get(&game_id);
-                    tracker.unregister(&game_id);
-
-                    // Emit error to frontend
-                    if let Some(info) = game_info {
-                        let error =
-                            GameLaunchError::steam_timeout(game_id.clone(), info.game.title, STEAM_TIMEOUT_SECONDS);
-                        emit_launch_error(&app_handle, error);
-                    }
-
-                    restore_window(&app_handle);
-                    break;
-                }
-            }
+        // Get game info from tracker before unregistering
+        let game_info = self.tracker.get(&self.game_id);
+        self.tracker.unregister(&self.game_id);
+
+        // Emit error to frontend
+        // Steam's own state explains most timeouts (offline mode,
+        // signed out mid-launch, self-update)
+        if let Some(info) = game_info {
+            let reason = match steam_client_state() {
+                StoreClientState::Offline => Some(LaunchFailureReason::OfflineMode),
+                state => state.blocking_reason(),
+            };
+            let error = match reason {
+                Some(reason) => GameLaunchError::steam_unavailable(self.game_id.clone(), info.game.title, reason),
+                None => GameLaunchError::steam_timeout(self.game_id.clone(), info.game.title, self.timeout.seconds()),
+            };
+            emit_launch_error(&self.app_handle, error);
+        }
+
+        restore_window_after_session(&self.app_handle, &self.tracker);
+        true
+    }
+}
+
+impl GameMonitor for SteamMonitor {
+    fn interval(&self) -> Duration {
+        Duration::from_millis(POLLING_INTERVAL_MS)
+    }
+
+    fn poll(&mut self, context: &PollContext) -> Poll {
+        if self.is_running() {
+            self.on_running(context);
+            return Poll::Continue;
+        }
+        match self.start_time {
+            Some(start) => {
+                self.on_stopped(start);
+                Poll::Finished
+            },
+            None if self.check_startup(context) => Poll::Finished,
+            None => Poll::Continue,
         }
-    });
+    }
+}
+
+/// Steam's `Updating` flag of the app (download, update or file validation)
+fn steam_app_updating(key_path: &str) -> bool {
+    RegKey::predef(HKEY_CURRENT_USER)
+        .open_subkey(key_path)
+        .and_then(|key| key.get_value::<u32, _>("Updating"))
+        .is_ok_and(|updating| updating == 1)
+}
+
+/// Whether Steam is compiling shaders or moving data since the last scan,
+/// `interval` ago (`disk_bytes` keeps Steam's disk total between scans)
+fn steam_busy(processes: &[ProcessInfo], disk_bytes: &mut Option<u64>, interval: Duration) -> bool {
+    let total: u64 = processes
+        .iter()
+        .filter(|process| process.name.eq_ignore_ascii_case("steam.exe"))
+        .map(|process| process.disk_total_bytes)
+        .sum();
+    // A restarted Steam starts counting from zero again
+    let moved = disk_bytes
+        .replace(total)
+        .map_or(0, |previous| total.saturating_sub(previous));
+
+    processes
+        .iter()
+        .any(|process| process.name.eq_ignore_ascii_case("fossilize_replay.exe"))
+        || u128::from(moved) * 1000 / interval.as_millis().max(1) >= u128::from(STEAM_BUSY_DISK_BYTES_PER_SECOND)
 }
@@ -0,0 +1,242 @@
+// =============================================================================
+// WATCHDOG SUPERVISOR
+// =============================================================================
+
+use std::cell::OnceCell;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tracing::{info, warn};
+
+use crate::application::services::{ProcessInfo, ProcessInspectionService};
+use crate::ports::ProcessEvent;
+
+/// What a monitor wants after a poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Poll {
+    Continue,
+    Finished,
+}
+
+/// Shared by every monitor polled in the same round.
+pub struct PollContext<'a> {
+    service: &'a ProcessInspectionService,
+    snapshot: OnceCell<Vec<ProcessInfo>>,
+}
+
+impl<'a> PollContext<'a> {
+    fn new(service: &'a ProcessInspectionService) -> Self {
+        Self {
+            service,
+            snapshot: OnceCell::new(),
+        }
+    }
+
+    /// Process list, read once per round and only if a monitor asks.
+    pub fn processes(&self) -> &[ProcessInfo] {
+        self.snapshot.get_or_init(|| self.service.processes())
+    }
+}
+
+/// One active game followed by the supervisor.
+pub trait GameMonitor: Send {
+    /// Time between two polls
+    fn interval(&self) -> Duration;
+
+    /// PIDs whose exit is worth an immediate poll
+    fn watched_pids(&self) -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// Checks the game once. Runs on the blocking pool: registry reads and
+    /// process scans are fine, sleeping is not.
+    fn poll(&mut self, context: &PollContext) -> Poll;
+}
+
+enum Command {
+    Watch(Box<dyn GameMonitor>),
+    ProcessExited(u32),
+}
+
+struct Scheduled {
+    monitor: Box<dyn GameMonitor>,
+    next_poll: Instant,
+}
+
+/// Runs every game monitor on one async task.
+///
+/// Replaces a thread with a sleep loop per launch: monitors are polled on
+/// their own timers from a single task, and process exits reported by the
+/// process-event stream wake the monitors watching that PID right away.
+/// With no game running the task sleeps until the next launch.
+pub struct WatchdogSupervisor {
+    processes: Arc<ProcessInspectionService>,
+    /// Started with the first monitor
+    commands: OnceLock<UnboundedSender<Command>>,
+}
+
+impl WatchdogSupervisor {
+    #[must_use]
+    pub fn new(processes: Arc<ProcessInspectionService>) -> Self {
+        Self {
+            processes,
+            commands: OnceLock::new(),
+        }
+    }
+
+    /// The app-wide instance (also held by the DI container).
+    #[must_use]
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<WatchdogSupervisor>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::new(ProcessInspectionService::shared()))))
+    }
+
+    fn commands(&self) -> &UnboundedSender<Command> {
+        self.commands.get_or_init(|| {
+            let (commands, receiver) = mpsc::unbounded_channel();
+            tauri::async_runtime::spawn(run(receiver, Arc::clone(&self.processes)));
+            info!("Watchdog supervisor started");
+            commands
+        })
+    }
+
+    /// Follows a game until its monitor reports it finished.
+    pub fn watch(&self, monitor: Box<dyn GameMonitor>) {
+        if self.commands().send(Command::Watch(monitor)).is_err() {
+            warn!("Watchdog supervisor stopped; game will not be monitored");
+        }
+    }
+
+    /// Feeds process exits from the process-event stream.
+    pub fn on_process_event(&self, event: &ProcessEvent) {
+        if let (ProcessEvent::Exited { pid }, Some(commands)) = (event, self.commands.get()) {
+            let _ = commands.send(Command::ProcessExited(*pid));
+        }
+    }
+}
+
+/// Supervisor loop: waits for the earliest monitor deadline or a command,
+/// then polls every due monitor in one blocking round.
+async fn run(mut commands: UnboundedReceiver<Command>, processes: Arc<ProcessInspectionService>) {
+    let mut monitors: Vec<Scheduled> = Vec::new();
+
+    loop {
+        let deadline = monitors.iter().map(|scheduled| scheduled.next_poll).min();
+        let command = match deadline {
+            Some(deadline) => tokio::select! {
+                command = commands.recv() => match command {
+                    Some(command) => Some(command),
+                    None => return,
+                },
+                () = tokio::time::sleep_until(tokio::time::Instant::from_std(deadline)) => None,
+            },
+            None => match commands.recv().await {
+                Some(command) => Some(command),
+                None => return,
+            },
+        };
+
+        match command {
+            Some(Command::Watch(monitor)) => monitors.push(Scheduled {
+                next_poll: Instant::now() + monitor.interval(),
+                monitor,
+            }),
+            Some(Command::ProcessExited(pid)) => {
+                for scheduled in &mut monitors {
+                    if scheduled.monitor.watched_pids().contains(&pid) {
+                        scheduled.next_poll = Instant::now();
+                    }
+                }
+            },
+            None => {},
+        }
+
+        let now = Instant::now();
+        let (due, waiting): (Vec<Scheduled>, Vec<Scheduled>) =
+            monitors.into_iter().partition(|scheduled| scheduled.next_poll <= now);
+        monitors = waiting;
+        if due.is_empty() {
+            continue;
+        }
+
+        let processes = Arc::clone(&processes);
+        let polled = tokio::task::spawn_blocking(move || {
+            let context = PollContext::new(&processes);
+            due.into_iter()
+                .filter_map(|mut scheduled| match scheduled.monitor.poll(&context) {
+                    Poll::Continue => {
+                        scheduled.next_poll = Instant::now() + scheduled.monitor.interval();
+                        Some(scheduled)
+                    },
+                    Poll::Finished => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .await;
+        match polled {
+            Ok(polled) => monitors.extend(polled),
+            Err(e) => warn!("Watchdog poll round failed, its games are no longer monitored: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingMonitor {
+        pid: u32,
+        interval: Duration,
+        polls: Arc<AtomicUsize>,
+        finish_after: usize,
+    }
+
+    impl GameMonitor for CountingMonitor {
+        fn interval(&self) -> Duration {
+            self.interval
+        }
+
+        fn watched_pids(&self) -> Vec<u32> {
+            vec![self.pid]
+        }
+
+        fn poll(&mut self, _context: &PollContext) -> Poll {
+            if self.polls.fetch_add(1, Ordering::SeqCst) + 1 >= self.finish_after {
+                Poll::Finished
+            } else {
+                Poll::Continue
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_monitors_run_on_timers_and_wake_on_exit() {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(run(receiver, Arc::new(ProcessInspectionService::new())));
+
+        let fast = Arc::new(AtomicUsize::new(0));
+        let slow = Arc::new(AtomicUsize::new(0));
+        for (pid, interval, polls) in [
+            (1, Duration::from_millis(10), &fast),
+            (2, Duration::from_secs(3600), &slow),
+        ] {
+            let monitor = CountingMonitor {
+                pid,
+                interval,
+                polls: Arc::clone(polls),
+                finish_after: 3,
+            };
+            let _ = commands.send(Command::Watch(Box::new(monitor)));
+        }
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(fast.load(Ordering::SeqCst), 3, "finished monitors are dropped");
+        assert_eq!(slow.load(Ordering::SeqCst), 0);
+
+        // The exit of a watched PID polls its monitor right away
+        let _ = commands.send(Command::ProcessExited(2));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(slow.load(Ordering::SeqCst), 1);
+    }
+}
@@ -2,132 +2,214 @@
 // XBOX EXPLORER FALLBACK WATCHDOG
 // =============================================================================
 
+use std::collections::HashSet;
 use std::sync::Arc;
-use std::thread;
 use std::time::{Duration, Instant};
-use sysinfo::System;
 use tauri::{AppHandle, Emitter};
 use tracing::{error, info, warn};
 
 use super::super::constants::{POLLING_INTERVAL_MS, XBOX_EXPLORER_TIMEOUT_SECONDS};
 use super::super::error_handler::emit_launch_error;
-use super::super::window_manager::restore_window;
+use super::super::process_tree::ProcessTree;
+use super::super::process_tuning::tune_game_process;
+use super::super::uwp::{PackageProcess, UwpPackage};
+use super::super::window_manager::restore_window_after_session;
+use super::supervisor::{GameMonitor, Poll, PollContext};
+use super::{GameEndedPayload, GameStartedPayload};
 use crate::application::ActiveGamesTracker;
 use crate::domain::GameLaunchError;
 
 /// Xbox Explorer Fallback Watchdog
 ///
 /// Monitors Xbox/UWP game launched via explorer.exe fallback.
-/// Since we don't have a PID, we use a timeout-based approach with process scanning.
+///
+/// The fallback returns no PID: the game's process is found by its package
+/// identity (see [`UwpPackage`]), then followed like a PID launch (process
+/// tree, tracker PID, game profile tuning). Each process is identified once,
+/// so polls only open processes started since the last one.
 pub fn start_xbox_explorer_watchdog(
     app_user_model_id: String,
     app_handle: AppHandle,
     tracker: Arc<ActiveGamesTracker>,
     game_id: String,
 ) {
-    thread::spawn(move || {
-        #[derive(serde::Serialize, Clone)]
-        struct GameEndedPayload {
-            game_id: String,
-            play_time_seconds: u64,
+    info!(
+        ">>> Xbox Explorer Watchdog STARTED for: {} (timeout: {}s, polling: {}ms) <<<",
+        app_user_model_id, XBOX_EXPLORER_TIMEOUT_SECONDS, POLLING_INTERVAL_MS
+    );
+
+    let monitor = XboxMonitor {
+        package: UwpPackage::resolve(&app_user_model_id),
+        checked: HashSet::new(),
+        tree: None,
+        primary_pid: None,
+        attempts: 0,
+        max_attempts: (XBOX_EXPLORER_TIMEOUT_SECONDS * 1000) / POLLING_INTERVAL_MS,
+        start_time: None,
+        app_handle: app_handle.clone(),
+        tracker,
+        game_id,
+    };
+    super::supervisor(&app_handle).watch(Box::new(monitor));
+}
+
+struct XboxMonitor {
+    package: UwpPackage,
+    /// Processes already found not to belong to the package (PID, start time)
+    checked: HashSet<(u32, u64)>,
+    /// Set once the game process is detected
+    tree: Option<ProcessTree>,
+    primary_pid: Option<u32>,
+    attempts: u64,
+    max_attempts: u64,
+    /// Set once the game process is detected
+    start_time: Option<Instant>,
+    app_handle: AppHandle,
+    tracker: Arc<ActiveGamesTracker>,
+    game_id: String,
+}
+
+impl XboxMonitor {
+    /// A process of the package not followed yet, the launched app first.
+    fn find_package_process(&mut self, context: &PollContext) -> Option<u32> {
+        let mut found = None;
+        for process in context.processes() {
+            if self.checked.contains(&(process.pid, process.start_time)) {
+                continue;
+            }
+            match self.package.identify(process.pid, process.exe.as_deref()) {
+                Some(PackageProcess::App) => return Some(process.pid),
+                Some(PackageProcess::Package) => found = found.or(Some(process.pid)),
+                None => {
+                    self.checked.insert((process.pid, process.start_time));
+                },
+            }
         }
+        found
+    }
+
+    /// Follows `pid` (and its children) as the game.
+    fn follow(&mut self, pid: u32, context: &PollContext) {
+        info!("Xbox game process of {} found: PID {}", self.package.family_name, pid);
+        let mut tree = ProcessTree::new(pid);
+        tree.refresh(context.processes());
+        self.tree = Some(tree);
+    }
+
+    /// Tracker PID and game profile follow the real game process.
+    fn update_primary_pid(&mut self) {
+        let current = self.tree.as_ref().and_then(ProcessTree::primary_pid);
+        if let Some(current) = current.filter(|&current| self.primary_pid != Some(current)) {
+            self.primary_pid = Some(current);
+            self.tracker.set_pid(&self.game_id, Some(current));
+            tune_game_process(&self.app_handle, &self.game_id, current);
+        }
+    }
+
+    fn on_ended(&self, start: Instant) {
+        // Game was running, now stopped
+        info!("Xbox game process ended. Restoring window.");
+
+        // Calculate play time
+        let play_time_seconds = start.elapsed().as_secs();
 
         info!(
-            ">>> Xbox Explorer Watchdog STARTED for: {} (timeout: {}s, polling: {}ms) <<<",
-            app_user_model_id, XBOX_EXPLORER_TIMEOUT_SECONDS, POLLING_INTERVAL_MS
+            "⏱️ Xbox game session duration: {}s ({:.1}min)",
+            play_time_seconds,
+            play_time_seconds as f64 / 60.0
         );
 
-        let mut attempts = 0;
-        let mut game_detected = false;
-        let mut start_time: Option<Instant> = None;
-        let max_attempts = (XBOX_EXPLORER_TIMEOUT_SECONDS * 1000) / POLLING_INTERVAL_MS;
-
-        // Extract package family name from AppUserModelId
-        // Format: "Microsoft.MinecraftUWP_8wekyb3d8bbwe!App" -> "Microsoft.MinecraftUWP"
-        let package_name = app_user_model_id
-            .split('!')
-            .next()
-            .unwrap_or(&app_user_model_id)
-            .split('_')
-            .next()
-            .unwrap_or(&app_user_model_id);
-
-        loop {
-            thread::sleep(Duration::from_millis(POLLING_INTERVAL_MS));
-
-            // Scan for processes matching the package name
-            let mut sys = System::new_all();
-            sys.refresh_all();
-
-            let found = sys.processes().values().any(|process| {
-                if let Some(exe) = process.exe() {
-                    let exe_str = exe.to_string_lossy().to_lowercase();
-                    exe_str.contains(&package_name.to_lowercase())
-                } else {
-                    false
-                }
-            });
+        self.tracker.unregister(&self.game_id);
 
-            if found {
-                if !game_detected {
-                    info!("Xbox game process detected! Monitoring...");
-                    game_detected = true;
-                    start_time = Some(Instant::now()); // Record start time
-                }
-            } else if game_detected {
-                // Game was running, now stopped
-                info!("Xbox game process ended. Restoring window.");
-
-                // Calculate play time
-                let play_time_seconds = if let Some(start) = start_time {
-                    start.elapsed().as_secs()
-                } else {
-                    0
-                };
+        // Emit event to frontend with play time
+        let payload = GameEndedPayload {
+            game_id: self.game_id.clone(),
+            play_time_seconds,
+        };
 
-                info!(
-                    "⏱️ Xbox game session duration: {}s ({:.1}min)",
-                    play_time_seconds,
-                    play_time_seconds as f64 / 60.0
-                );
+        if let Err(e) = self.app_handle.emit("game-ended", &payload) {
+            error!("Failed to emit game-ended event: {}", e);
+        }
 
-                tracker.unregister(&game_id);
+        restore_window_after_session(&self.app_handle, &self.tracker);
+    }
 
-                // Emit event to frontend with play time
-                let payload = GameEndedPayload {
-                    game_id: game_id.clone(),
-                    play_time_seconds,
-                };
+    fn on_timeout(&self) {
+        warn!(
+            "Xbox explorer fallback TIMEOUT after {}s - emitting error",
+            XBOX_EXPLORER_TIMEOUT_SECONDS
+        );
 
-                if let Err(e) = app_handle.emit("game-ended", &payload) {
-                    error!("Failed to emit game-ended event: {}", e);
-                }
+        // Get game info before unregistering
+        let game_info = self.tracker.get(&self.game_id);
+        self.tracker.unregister(&self.game_id);
+
+        // Emit error to frontend
+        if let Some(info) = game_info {
+            let error = GameLaunchError::xbox_explorer_fallback(self.game_id.clone(), info.game.title);
+            emit_launch_error(&self.app_handle, error);
+        }
+
+        restore_window_after_session(&self.app_handle, &self.tracker);
+    }
+}
 
-                restore_window(&app_handle);
-                break;
+impl GameMonitor for XboxMonitor {
+    fn interval(&self) -> Duration {
+        if self.tree.is_some() {
+            // Process exits wake the monitor earlier
+            Duration::from_secs(2)
+        } else {
+            Duration::from_millis(POLLING_INTERVAL_MS)
+        }
+    }
+
+    fn watched_pids(&self) -> Vec<u32> {
+        self.tree.as_ref().map(ProcessTree::pids).unwrap_or_default()
+    }
+
+    fn poll(&mut self, context: &PollContext) -> Poll {
+        if let Some(tree) = &mut self.tree {
+            tree.refresh(context.processes());
+        }
+        // A UWP game may restart into a new process of its package (not a child)
+        let alive = self.tree.as_ref().is_some_and(ProcessTree::is_alive);
+        if !alive {
+            if let Some(pid) = self.find_package_process(context) {
+                self.follow(pid, context);
             } else {
-                // Game hasn't started yet - check timeout
-                attempts += 1;
-                if attempts >= max_attempts {
-                    warn!(
-                        "Xbox explorer fallback TIMEOUT after {}s - emitting error",
-                        XBOX_EXPLORER_TIMEOUT_SECONDS
-                    );
-
-                    // Get game info before unregistering
-                    let game_info = tracker.get(&game_id);
-                    tracker.unregister(&game_id);
-
-                    // Emit error to frontend
-                    if let Some(info) = game_info {
-                        let error = GameLaunchError::xbox_explorer_fallback(game_id.clone(), info.game.title);
-                        emit_launch_error(&app_handle, error);
-                    }
-
-                    restore_window(&app_handle);
-                    break;
+                self.tree = None;
+            }
+        }
+        self.update_primary_pid();
+
+        if self.tree.is_some() {
+            if self.start_time.is_none() {
+                info!("Xbox game process detected! Monitoring...");
+                self.start_time = Some(Instant::now()); // Record start time
+
+                let payload = GameStartedPayload {
+                    game_id: self.game_id.clone(),
+                    executable_name: None,
+                };
+                if let Err(e) = self.app_handle.emit("game-process-started", &payload) {
+                    error!("Failed to emit game-process-started event: {}", e);
                 }
             }
+            return Poll::Continue;
+        }
+
+        if let Some(start) = self.start_time {
+            self.on_ended(start);
+            return Poll::Finished;
+        }
+
+        // Game hasn't started yet - check timeout
+        self.attempts += 1;
+        if self.attempts >= self.max_attempts {
+            self.on_timeout();
+            return Poll::Finished;
         }
-    });
+        Poll::Continue
+    }
 }
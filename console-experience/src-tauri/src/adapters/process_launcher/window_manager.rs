@@ -3,6 +3,10 @@
 // =============================================================================
 
 use tauri::{AppHandle, Manager};
+use tracing::info;
+
+use crate::adapters::game::WindowsGameAdapter;
+use crate::application::ActiveGamesTracker;
 
 /// Minimize (hide) the application window
 pub fn minimize_window(app_handle: &AppHandle) {
@@ -18,3 +22,21 @@ pub fn restore_window(app_handle: &AppHandle) {
         let _ = window.set_focus();
     }
 }
+
+/// Restore the application window after a game session ends
+///
+/// Skipped when another tracked game owns the foreground window: with several
+/// sessions running, one game exiting must not pull Balam over the game the
+/// user is playing. Call after unregistering the finished session.
+pub fn restore_window_after_session(app_handle: &AppHandle, tracker: &ActiveGamesTracker) {
+    let foreground_session = WindowsGameAdapter::new()
+        .foreground_pid()
+        .and_then(|pid| tracker.find_by_pid(pid));
+
+    if let Some((game_id, _)) = foreground_session {
+        info!("Session ended while {} is in foreground - keeping Balam hidden", game_id);
+        return;
+    }
+
+    restore_window(app_handle);
+}
@@ -1,16 +1,23 @@
+use crate::application::services::SettingsService;
 use crate::config::ExclusionConfig;
 use crate::domain::errors::ScanError;
-use crate::domain::{Game, GameSource};
+use crate::domain::registry_rules::{RegistryEntry, RegistryScanRules};
+use crate::domain::{Game, GameSource, InstallState};
 use crate::ports::GameScanner;
 use std::path::Path;
+use std::sync::Arc;
 use tracing::info;
 use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
 use winreg::RegKey;
 
 /// Windows Registry scanner for games not managed by Steam/Epic/Xbox.
 /// Scans Uninstall registry keys for potential games (GOG, independent games, etc.).
+///
+/// Entries are filtered with the user's [`RegistryScanRules`] (read from
+/// settings on every scan), on top of the exclusion config.
 pub struct RegistryScanner {
     exclusions: ExclusionConfig,
+    settings: Option<Arc<SettingsService>>,
 }
 
 impl RegistryScanner {
@@ -19,6 +26,16 @@ impl RegistryScanner {
     pub fn new() -> Self {
         Self {
             exclusions: ExclusionConfig::load_or_default(),
+            settings: None,
+        }
+    }
+
+    /// Creates a Registry scanner following the library's registry rules.
+    #[must_use]
+    pub fn with_settings(settings: Arc<SettingsService>) -> Self {
+        Self {
+            settings: Some(settings),
+            ..Self::new()
         }
     }
 
@@ -28,9 +45,17 @@ impl RegistryScanner {
         Self::new().scan_internal().unwrap_or_default()
     }
 
+    fn rules(&self) -> RegistryScanRules {
+        self.settings
+            .as_ref()
+            .map(|settings| settings.get().library.registry_rules)
+            .unwrap_or_default()
+    }
+
     fn scan_internal(&self) -> Result<Vec<Game>, ScanError> {
         let mut games = Vec::new();
         info!("Scanning Windows Registry for independent games...");
+        let rules = self.rules();
 
         let paths = [
             (
@@ -70,42 +95,32 @@ impl RegistryScanner {
                             continue;
                         }
 
-                        let lower_title = title.to_lowercase();
-                        let lower_path = install_location.to_lowercase();
-
-                        // Check if it's a game based on common paths, but EXCLUDE managed stores
-                        let is_potential_game = lower_path.contains("game")
-                            || lower_path.contains("gog")
-                            || lower_path.contains("riot games")
-                            || lower_path.contains("ubisoft");
-
                         // Skip Steam/Epic folders even in path checks
+                        let lower_path = install_location.to_lowercase();
                         if lower_path.contains("steamapps") || lower_path.contains("epic games") {
                             continue;
                         }
 
-                        let publisher: String = item_key
-                            .get_value::<String, _>("Publisher")
-                            .unwrap_or_default()
-                            .to_lowercase();
-
                         // Check against exclusion config
+                        let lower_title = title.to_lowercase();
                         let is_excluded = self
                             .exclusions
                             .registry_excludes
                             .iter()
                             .any(|pattern| lower_title.contains(&pattern.to_lowercase()));
 
-                        let is_software = is_excluded
-                            || publisher.contains("microsoft")
-                            || publisher.contains("google")
-                            || publisher.contains("adobe")
-                            || publisher.contains("nvidia")
-                            || publisher.contains("intel")
-                            || lower_title.contains("driver")
-                            || lower_title.contains("update");
-
-                        if is_potential_game && !is_software {
+                        let entry = RegistryEntry {
+                            key_name: name.clone(),
+                            title: title.clone(),
+                            publisher: item_key.get_value("Publisher").unwrap_or_default(),
+                            install_location: install_location.clone(),
+                            estimated_size_kb: item_key.get_value::<u32, _>("EstimatedSize").ok().map(u64::from),
+                            system_component: item_key
+                                .get_value::<u32, _>("SystemComponent")
+                                .is_ok_and(|flag| flag == 1),
+                        };
+
+                        if !is_excluded && rules.is_game(&entry) {
                             let mut exe_path = display_icon.split(',').next().unwrap_or_default().to_string();
 
                             if exe_path.is_empty() || !Path::new(&exe_path).exists() {
@@ -130,6 +145,9 @@ impl RegistryScanner {
                                     logo: None,
                                     last_played: None,
                                     source: GameSource::Manual,
+                                    install_state: InstallState::Installed,
+                                    launcher_dependency: None,
+                                    update_pending: false,
                                 });
                             }
                         }
@@ -158,6 +176,10 @@ impl GameScanner for RegistryScanner {
         GameSource::Manual
     }
 
+    fn name(&self) -> &'static str {
+        "Registry"
+    }
+
     fn priority(&self) -> u32 {
         4 // Lowest priority - manual/registry games
     }
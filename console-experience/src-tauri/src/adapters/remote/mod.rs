@@ -0,0 +1,6 @@
+//! Companion phone remote: touchpad input and pairing helpers.
+mod pairing;
+mod pointer;
+
+pub use pairing::{lan_address, qr_svg};
+pub use pointer::RemotePointer;
@@ -0,0 +1,41 @@
+use crate::domain::BalamError;
+use qrcode::render::svg;
+use qrcode::QrCode;
+use std::net::{IpAddr, UdpSocket};
+
+/// Address of the interface that routes to the internet, i.e. the one a
+/// phone on the same Wi-Fi reaches.
+///
+/// Connecting a UDP socket only selects a route; nothing is sent.
+#[must_use]
+pub fn lan_address() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    let address = socket.local_addr().ok()?.ip();
+    (!address.is_unspecified() && !address.is_loopback()).then_some(address)
+}
+
+/// Renders `text` as a QR code (SVG markup).
+///
+/// # Errors
+/// Returns error if `text` is too long for a QR code.
+pub fn qr_svg(text: &str) -> Result<String, BalamError> {
+    let code = QrCode::new(text.as_bytes()).map_err(|e| BalamError::platform("Failed to build QR code", e))?;
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(240, 240)
+        .quiet_zone(true)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qr_svg_renders_pairing_url() {
+        let svg = qr_svg("balam-remote://192.168.1.20:47810/?key=0123456789abcdef").unwrap();
+        assert!(svg.starts_with("<?xml") || svg.starts_with("<svg"));
+        assert!(svg.contains("</svg>"));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::domain::remote::PointerButton;
+use crate::domain::BalamError;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_MOUSE, MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP, MOUSEEVENTF_MIDDLEDOWN,
+    MOUSEEVENTF_MIDDLEUP, MOUSEEVENTF_MOVE, MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP, MOUSEEVENTF_WHEEL, MOUSEINPUT,
+    MOUSE_EVENT_FLAGS,
+};
+
+/// One wheel notch (`WHEEL_DELTA`).
+const WHEEL_DELTA: f32 = 120.0;
+
+fn mouse_input(dx: i32, dy: i32, data: i32, flags: MOUSE_EVENT_FLAGS) -> INPUT {
+    INPUT {
+        r#type: INPUT_MOUSE,
+        Anonymous: INPUT_0 {
+            mi: MOUSEINPUT {
+                dx,
+                dy,
+                mouseData: data as u32,
+                dwFlags: flags,
+                ..Default::default()
+            },
+        },
+    }
+}
+
+fn send(inputs: &[INPUT]) -> Result<(), BalamError> {
+    let sent = unsafe { SendInput(inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        Err(BalamError::Platform(format!(
+            "SendInput delivered {sent} of {} mouse events",
+            inputs.len()
+        )))
+    }
+}
+
+/// Phone touchpad for one connection.
+///
+/// Keeps the sub-pixel (and sub-notch) remainder so slow finger motion still
+/// moves the cursor instead of rounding to zero.
+#[derive(Default)]
+pub struct RemotePointer {
+    remainder: (f32, f32),
+    scroll_remainder: f32,
+}
+
+impl RemotePointer {
+    /// Moves the cursor by `dx`, `dy` pixels.
+    ///
+    /// # Errors
+    /// Returns a platform error if Windows blocked the input.
+    pub fn move_by(&mut self, dx: f32, dy: f32) -> Result<(), BalamError> {
+        let x = self.remainder.0 + dx;
+        let y = self.remainder.1 + dy;
+        let (whole_x, whole_y) = (x.trunc(), y.trunc());
+        self.remainder = (x - whole_x, y - whole_y);
+        if x.abs() < 1.0 && y.abs() < 1.0 {
+            return Ok(());
+        }
+        send(&[mouse_input(whole_x as i32, whole_y as i32, 0, MOUSEEVENTF_MOVE)])
+    }
+
+    /// Presses and releases `button`.
+    ///
+    /// # Errors
+    /// Returns a platform error if Windows blocked the input.
+    pub fn click(&mut self, button: PointerButton) -> Result<(), BalamError> {
+        let (down, up) = match button {
+            PointerButton::Left => (MOUSEEVENTF_LEFTDOWN, MOUSEEVENTF_LEFTUP),
+            PointerButton::Right => (MOUSEEVENTF_RIGHTDOWN, MOUSEEVENTF_RIGHTUP),
+            PointerButton::Middle => (MOUSEEVENTF_MIDDLEDOWN, MOUSEEVENTF_MIDDLEUP),
+        };
+        send(&[mouse_input(0, 0, 0, down), mouse_input(0, 0, 0, up)])
+    }
+
+    /// Scrolls by `notches` (fractional notches accumulate).
+    ///
+    /// # Errors
+    /// Returns a platform error if Windows blocked the input.
+    pub fn scroll(&mut self, notches: f32) -> Result<(), BalamError> {
+        let amount = (self.scroll_remainder + notches) * WHEEL_DELTA;
+        let whole = amount.trunc();
+        self.scroll_remainder = (amount - whole) / WHEEL_DELTA;
+        if amount.abs() < 1.0 {
+            return Ok(());
+        }
+        send(&[mouse_input(0, 0, whole as i32, MOUSEEVENTF_WHEEL)])
+    }
+}
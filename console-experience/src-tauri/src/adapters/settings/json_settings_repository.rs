@@ -0,0 +1,177 @@
+/// JSON Settings Repository
+///
+/// Stores `AppSettings` as pretty-printed JSON in the app local data directory
+/// (`%LOCALAPPDATA%\com.console.experience\settings.json`, same folder Tauri
+/// uses for `games_cache.json`).
+///
+/// Older files are migrated step by step on load: each step receives the raw
+/// JSON of version N and returns JSON of version N + 1.
+use crate::domain::settings::{AppSettings, SETTINGS_SCHEMA_VERSION};
+use crate::infrastructure::app_dirs;
+use crate::ports::settings_port::SettingsRepository;
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+const SETTINGS_FILE_NAME: &str = "settings.json";
+
+/// A single migration step from `version` to `version + 1`.
+type MigrationStep = fn(Value) -> Value;
+
+/// Migration chain indexed by source version (index 0 migrates v0 → v1).
+const MIGRATIONS: &[MigrationStep] = &[migrate_v0_to_v1];
+
+/// v0 files were written before versioning existed: they have no
+/// `schema_version` key but are otherwise compatible.
+fn migrate_v0_to_v1(mut value: Value) -> Value {
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("schema_version".to_string(), Value::from(1));
+    }
+    value
+}
+
+/// File-backed settings repository.
+pub struct JsonSettingsRepository {
+    path: PathBuf,
+}
+
+impl JsonSettingsRepository {
+    /// Creates a repository backed by the given file.
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Default settings location in the app local data directory
+    /// (`config/settings.json` when the user profile cannot be resolved).
+    #[must_use]
+    pub fn default_path() -> PathBuf {
+        app_dirs::app_data_dir().join(SETTINGS_FILE_NAME)
+    }
+
+    /// Path of the backing file.
+    #[must_use]
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Runs all migration steps needed to bring `value` to the current schema.
+    ///
+    /// # Errors
+    /// Returns error if the file was written by a newer build.
+    fn migrate(mut value: Value) -> Result<Value, String> {
+        let mut version = value
+            .get("schema_version")
+            .and_then(Value::as_u64)
+            .map_or(0, |v| v as u32);
+
+        if version > SETTINGS_SCHEMA_VERSION {
+            return Err(format!(
+                "Settings schema v{version} is newer than supported v{SETTINGS_SCHEMA_VERSION}"
+            ));
+        }
+
+        while version < SETTINGS_SCHEMA_VERSION {
+            let step = MIGRATIONS
+                .get(version as usize)
+                .ok_or_else(|| format!("Missing settings migration from v{version}"))?;
+            value = step(value);
+            info!("⚙️ Migrated settings v{} → v{}", version, version + 1);
+            version += 1;
+        }
+
+        Ok(value)
+    }
+}
+
+impl Default for JsonSettingsRepository {
+    fn default() -> Self {
+        Self::new(Self::default_path())
+    }
+}
+
+impl SettingsRepository for JsonSettingsRepository {
+    fn load(&self) -> Result<Option<AppSettings>, String> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+
+        let content = fs::read_to_string(&self.path).map_err(|e| format!("Failed to read {:?}: {e}", self.path))?;
+        let raw: Value = serde_json::from_str(&content).map_err(|e| format!("Failed to parse settings.json: {e}"))?;
+
+        let original_version = raw.get("schema_version").and_then(Value::as_u64);
+        let migrated = Self::migrate(raw)?;
+        let settings: AppSettings =
+            serde_json::from_value(migrated).map_err(|e| format!("Invalid settings.json: {e}"))?;
+
+        // Persist migrated file so the chain only runs once
+        if original_version != Some(u64::from(SETTINGS_SCHEMA_VERSION)) {
+            if let Err(e) = self.save(&settings) {
+                warn!("Failed to persist migrated settings: {}", e);
+            }
+        }
+
+        Ok(Some(settings))
+    }
+
+    fn save(&self, settings: &AppSettings) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create settings directory: {e}"))?;
+        }
+
+        let json = serde_json::to_string_pretty(settings).map_err(|e| format!("Failed to serialize settings: {e}"))?;
+
+        // Write to a temp file first so a crash never leaves a truncated settings.json
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json).map_err(|e| format!("Failed to write settings: {e}"))?;
+        fs::rename(&tmp_path, &self.path).map_err(|e| format!("Failed to replace settings file: {e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_repo(name: &str) -> JsonSettingsRepository {
+        let path = std::env::temp_dir().join(format!("balam_settings_{name}_{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+        JsonSettingsRepository::new(path)
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_none() {
+        let repo = temp_repo("missing");
+        assert_eq!(repo.load().unwrap(), None);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let repo = temp_repo("roundtrip");
+        let mut settings = AppSettings::default();
+        settings.overlay.opacity = 0.75;
+
+        repo.save(&settings).unwrap();
+        assert_eq!(repo.load().unwrap(), Some(settings));
+
+        let _ = fs::remove_file(repo.path());
+    }
+
+    #[test]
+    fn test_unversioned_file_is_migrated() {
+        let repo = temp_repo("v0");
+        fs::write(repo.path(), r#"{"overlay":{"opacity":0.5,"click_through":true}}"#).unwrap();
+
+        let settings = repo.load().unwrap().unwrap();
+        assert_eq!(settings.schema_version, SETTINGS_SCHEMA_VERSION);
+        assert!(settings.overlay.click_through);
+
+        let _ = fs::remove_file(repo.path());
+    }
+
+    #[test]
+    fn test_newer_schema_is_rejected() {
+        let value = serde_json::json!({ "schema_version": SETTINGS_SCHEMA_VERSION + 1 });
+        assert!(JsonSettingsRepository::migrate(value).is_err());
+    }
+}
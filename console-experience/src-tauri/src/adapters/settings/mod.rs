@@ -0,0 +1,3 @@
+mod json_settings_repository;
+
+pub use json_settings_repository::JsonSettingsRepository;
@@ -0,0 +1,152 @@
+use crate::domain::errors::ScanError;
+use crate::domain::standalone::{expand_env_vars, StandaloneGameDescriptor, STANDALONE_GAMES};
+use crate::domain::{Game, GameSource, InstallState};
+use crate::ports::GameScanner;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+use winreg::enums::{HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE};
+use winreg::RegKey;
+
+/// Scanner for well-known games with their own launchers (Minecraft,
+/// HoYoverse titles, Riot games).
+///
+/// Each title of [`STANDALONE_GAMES`] is looked up in the uninstall registry,
+/// then in its usual install folders. Runs before the generic registry
+/// scanner so these games get their descriptor-based launch.
+pub struct StandaloneScanner;
+
+impl StandaloneScanner {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Install folder and game executable of a descriptor, if installed.
+    ///
+    /// The executable may sit one folder below the install folder (e.g. the
+    /// `Genshin Impact Game` folder next to the old launcher).
+    #[must_use]
+    pub fn locate(descriptor: &StandaloneGameDescriptor) -> Option<(PathBuf, PathBuf)> {
+        let uninstall_roots = [
+            (
+                HKEY_LOCAL_MACHINE,
+                "SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            ),
+            (
+                HKEY_LOCAL_MACHINE,
+                "SOFTWARE\\WOW6432Node\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            ),
+            (
+                HKEY_CURRENT_USER,
+                "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall",
+            ),
+        ];
+        let from_registry = descriptor.uninstall_keys.iter().flat_map(|key| {
+            uninstall_roots.iter().filter_map(move |(root, path)| {
+                RegKey::predef(*root)
+                    .open_subkey(format!("{path}\\{key}"))
+                    .and_then(|item| item.get_value::<String, _>("InstallLocation"))
+                    .ok()
+                    .filter(|location| !location.is_empty())
+            })
+        });
+        let common = descriptor
+            .common_paths
+            .iter()
+            .filter_map(|path| expand_env_vars(path, |name| std::env::var(name).ok()));
+
+        from_registry
+            .chain(common)
+            .map(PathBuf::from)
+            .find_map(|dir| find_executable(&dir, descriptor.executable))
+    }
+
+    fn scan_internal() -> Vec<Game> {
+        info!("Scanning standalone launcher games...");
+        let games: Vec<Game> = STANDALONE_GAMES
+            .iter()
+            .filter_map(|descriptor| {
+                let (_, executable) = Self::locate(descriptor)?;
+                Some(Game {
+                    id: format!("standalone_{}", descriptor.key),
+                    raw_id: descriptor.key.to_string(),
+                    title: descriptor.title.to_string(),
+                    path: executable.to_string_lossy().to_string(),
+                    image: None,
+                    hero_image: None,
+                    logo: None,
+                    last_played: None,
+                    source: GameSource::Manual,
+                    install_state: InstallState::Installed,
+                    launcher_dependency: None,
+                    update_pending: false,
+                })
+            })
+            .collect();
+        info!("Standalone scan complete. Found {} games", games.len());
+        games
+    }
+}
+
+/// `(install folder, executable)` for `executable` in `dir` or one of its subfolders.
+fn find_executable(dir: &Path, executable: &str) -> Option<(PathBuf, PathBuf)> {
+    let direct = dir.join(executable);
+    if direct.is_file() {
+        return Some((dir.to_path_buf(), direct));
+    }
+    fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .find_map(|subdir| {
+            let candidate = subdir.join(executable);
+            candidate.is_file().then_some((subdir, candidate))
+        })
+}
+
+impl Default for StandaloneScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameScanner for StandaloneScanner {
+    fn scan(&self) -> Result<Vec<Game>, ScanError> {
+        Ok(Self::scan_internal())
+    }
+
+    fn source(&self) -> GameSource {
+        GameSource::Manual
+    }
+
+    fn name(&self) -> &'static str {
+        "Standalone"
+    }
+
+    fn priority(&self) -> u32 {
+        4 // Same tier as the registry scanner, registered before it
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_executable_in_subfolder() {
+        let root = std::env::temp_dir().join(format!("balam_standalone_{}", std::process::id()));
+        let game_dir = root.join("Genshin Impact Game");
+        fs::create_dir_all(&game_dir).unwrap();
+        fs::write(game_dir.join("GenshinImpact.exe"), b"").unwrap();
+
+        assert_eq!(
+            find_executable(&root, "GenshinImpact.exe"),
+            Some((game_dir.clone(), game_dir.join("GenshinImpact.exe")))
+        );
+        assert_eq!(find_executable(&root, "StarRail.exe"), None);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
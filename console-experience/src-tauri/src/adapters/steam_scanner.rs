@@ -1,10 +1,22 @@
 use crate::config::ExclusionConfig;
 use crate::domain::errors::ScanError;
-use crate::domain::{Game, GameSource};
+use crate::domain::launcher_dependency::LauncherDependency;
+use crate::domain::{Game, GameSource, InstallState};
 use crate::ports::GameScanner;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use steamlocate::SteamDir;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
+
+/// Library locations probed on every drive (relative to the drive root).
+const DRIVE_LIBRARY_DIRS: &[&str] = &[
+    "SteamLibrary",
+    "Steam",
+    r"Games\SteamLibrary",
+    r"Games\Steam",
+    r"Program Files (x86)\Steam",
+    r"Program Files\Steam",
+];
 
 /// Steam game scanner using steamlocate library.
 ///
@@ -15,6 +27,12 @@ use tracing::{info, warn};
 /// - **Registry**: `HKEY_LOCAL_MACHINE\SOFTWARE\Valve\Steam` for Steam install path
 /// - **Manifest Files**: `steamapps/appmanifest_<appid>.acf` (VDF format)
 /// - **Library Config**: `steamapps/libraryfolders.vdf` for additional libraries
+/// - **Drive Probe**: `X:\SteamLibrary`, `X:\Games\Steam`, ... on every drive
+///
+/// # Install State
+/// The manifest `StateFlags` are mapped to [`InstallState`]: games that are
+/// downloading, updating or preallocating are listed with their state (the UI
+/// shows them greyed out), stale manifests of uninstalled games are skipped.
 ///
 /// # Metadata Quality
 /// - **Title**: From `appmanifest.name`
@@ -83,23 +101,9 @@ impl SteamScanner {
             Ok(steam_dir) => {
                 let steam_path = steam_dir.path().to_path_buf();
 
-                // 1. Scan default library
-                let default_steamapps = steam_path.join("steamapps");
-                self.scan_folder(&default_steamapps, &mut games);
-
-                // 2. Scan additional libraries via libraryfolders.vdf
-                let vdf_path = default_steamapps.join("libraryfolders.vdf");
-                if vdf_path.exists() {
-                    if let Ok(content) = std::fs::read_to_string(&vdf_path) {
-                        let additional_paths = Self::parse_library_folders(&content);
-                        for path in additional_paths {
-                            let lib_steamapps = PathBuf::from(path).join("steamapps");
-                            if lib_steamapps.exists() && lib_steamapps != default_steamapps {
-                                info!("Scanning additional Steam library: {:?}", lib_steamapps);
-                                self.scan_folder(&lib_steamapps, &mut games);
-                            }
-                        }
-                    }
+                for steamapps in Self::library_folders(&steam_path) {
+                    info!("Scanning Steam library: {:?}", steamapps);
+                    self.scan_folder(&steamapps, &mut games);
                 }
             },
             Err(e) => {
@@ -112,7 +116,54 @@ impl SteamScanner {
         Ok(games)
     }
 
-    fn scan_folder(&self, steamapps_path: &std::path::Path, games: &mut Vec<Game>) {
+    /// Collects every `steamapps` folder: the default library, the ones listed in
+    /// `libraryfolders.vdf`, and libraries found at well-known paths on every drive
+    /// (covers secondary SSDs whose library was added by another Steam install or
+    /// lost from the config after a reinstall).
+    fn library_folders(steam_path: &Path) -> Vec<PathBuf> {
+        let default_steamapps = steam_path.join("steamapps");
+        let mut candidates = vec![default_steamapps.clone()];
+
+        for vdf_path in [
+            default_steamapps.join("libraryfolders.vdf"),
+            steam_path.join("config").join("libraryfolders.vdf"),
+        ] {
+            if let Ok(content) = std::fs::read_to_string(&vdf_path) {
+                candidates.extend(
+                    Self::parse_library_folders(&content)
+                        .into_iter()
+                        .map(|path| PathBuf::from(path).join("steamapps")),
+                );
+            }
+        }
+
+        // Only probe drive letters that are currently mapped
+        let mask = unsafe { windows::Win32::Storage::FileSystem::GetLogicalDrives() };
+        for i in 0..26u8 {
+            if mask & (1 << i) == 0 {
+                continue;
+            }
+            let root = PathBuf::from(format!("{}:\\", (b'A' + i) as char));
+            candidates.extend(DRIVE_LIBRARY_DIRS.iter().map(|dir| root.join(dir).join("steamapps")));
+        }
+
+        let mut seen = HashSet::new();
+        candidates
+            .into_iter()
+            .filter(|path| path.is_dir())
+            .filter(|path| seen.insert(Self::normalize_path(path)))
+            .collect()
+    }
+
+    /// Case-insensitive key for deduplicating library paths.
+    fn normalize_path(path: &Path) -> String {
+        path.to_string_lossy()
+            .replace('/', "\\")
+            .trim_end_matches('\\')
+            .to_lowercase()
+    }
+
+    fn scan_folder(&self, steamapps_path: &Path, games: &mut Vec<Game>) {
         if let Ok(entries) = std::fs::read_dir(steamapps_path) {
             for entry in entries.flatten() {
                 let path = entry.path();
@@ -125,38 +176,9 @@ impl SteamScanner {
                             continue;
                         }
 
-                        // Prevent duplicates if already found in another drive
-                        if games.iter().any(|g| g.raw_id == app_id_str) {
-                            continue;
-                        }
-
                         if let Ok(content) = std::fs::read_to_string(&path) {
-                            let name = Self::extract_value(&content, "name");
-                            let install_dir = Self::extract_value(&content, "installdir");
-
-                            if let (Some(name), Some(install_dir)) = (name, install_dir) {
-                                let image_url = format!(
-                                    "https://cdn.akamai.steamstatic.com/steam/apps/{app_id_str}/library_600x900_2x.jpg"
-                                );
-                                let hero_url = format!(
-                                    "https://cdn.akamai.steamstatic.com/steam/apps/{app_id_str}/library_hero.jpg"
-                                );
-                                let logo_url =
-                                    format!("https://cdn.akamai.steamstatic.com/steam/apps/{app_id_str}/logo.png");
-
-                                let common_path = steamapps_path.join("common").join(install_dir);
-
-                                games.push(Game {
-                                    id: format!("steam_{app_id_str}"),
-                                    raw_id: app_id_str.to_string(),
-                                    title: name,
-                                    path: common_path.display().to_string(),
-                                    image: Some(image_url),
-                                    hero_image: Some(hero_url),
-                                    logo: Some(logo_url),
-                                    last_played: None,
-                                    source: GameSource::Steam,
-                                });
+                            if let Some(game) = Self::parse_manifest(&content, app_id_str, steamapps_path) {
+                                Self::merge_game(games, game);
                             }
                         }
                     }
@@ -165,9 +187,61 @@ impl SteamScanner {
         }
     }
 
+    /// Builds a game from an appmanifest (`None` if incomplete or not installed).
+    fn parse_manifest(content: &str, app_id: &str, steamapps_path: &Path) -> Option<Game> {
+        let name = Self::extract_value(content, "name")?;
+        let install_dir = Self::extract_value(content, "installdir")?;
+
+        // Manifests without StateFlags are from very old clients: treat as installed
+        let install_state = Self::extract_value(content, "StateFlags")
+            .and_then(|flags| flags.parse::<u32>().ok())
+            .map_or(InstallState::Installed, InstallState::from_steam_state_flags);
+        if !install_state.is_listed() {
+            debug!("Skipping Steam app {} ({}): not installed", app_id, name);
+            return None;
+        }
+
+        let image_url = format!("https://cdn.akamai.steamstatic.com/steam/apps/{app_id}/library_600x900_2x.jpg");
+        let hero_url = format!("https://cdn.akamai.steamstatic.com/steam/apps/{app_id}/library_hero.jpg");
+        let logo_url = format!("https://cdn.akamai.steamstatic.com/steam/apps/{app_id}/logo.png");
+
+        let common_path = steamapps_path.join("common").join(install_dir);
+
+        Some(Game {
+            id: format!("steam_{app_id}"),
+            raw_id: app_id.to_string(),
+            title: name,
+            path: common_path.display().to_string(),
+            image: Some(image_url),
+            hero_image: Some(hero_url),
+            logo: Some(logo_url),
+            last_played: None,
+            source: GameSource::Steam,
+            update_pending: install_state.is_update_pending(),
+            install_state,
+            launcher_dependency: Some(LauncherDependency::Steam),
+        })
+    }
+
+    /// Adds a game, resolving duplicates found in several libraries.
+    ///
+    /// A leftover manifest (e.g. an old copy on another drive) must not hide the
+    /// playable install, so a playable entry replaces a non-playable one.
+    fn merge_game(games: &mut Vec<Game>, game: Game) {
+        match games.iter_mut().find(|g| g.raw_id == game.raw_id) {
+            Some(existing) => {
+                if !existing.install_state.is_playable() && game.install_state.is_playable() {
+                    *existing = game;
+                }
+            },
+            None => games.push(game),
+        }
+    }
+
     fn extract_value(content: &str, key: &str) -> Option<String> {
+        let quoted_key = format!("\"{key}\"");
         for line in content.lines() {
-            if line.contains(key) {
+            if line.trim_start().starts_with(&quoted_key) {
                 let parts: Vec<&str> = line.split('"').collect();
                 if parts.len() >= 4 {
                     return Some(parts[3].to_string());
@@ -245,6 +319,65 @@ mod tests {
         assert_eq!(install_dir, Some("Half-Life 2".to_string()));
     }
 
+    #[test]
+    fn test_extract_value_matches_whole_key() {
+        let content = r#"
+            "LastOwner"    "76561198000000000"
+            "name"    "Portal 2"
+        "#;
+
+        assert_eq!(
+            SteamScanner::extract_value(content, "name"),
+            Some("Portal 2".to_string())
+        );
+        assert_eq!(SteamScanner::extract_value(content, "Owner"), None);
+    }
+
+    #[test]
+    fn test_parse_manifest_install_state() {
+        let manifest = |flags: u32| {
+            format!(
+                "\"AppState\"\n{{\n\t\"appid\"\t\t\"620\"\n\t\"name\"\t\t\"Portal 2\"\n\t\"StateFlags\"\t\t\"{flags}\"\n\t\"installdir\"\t\t\"Portal 2\"\n}}"
+            )
+        };
+        let library = Path::new(r"D:\SteamLibrary\steamapps");
+
+        let game = SteamScanner::parse_manifest(&manifest(4), "620", library).unwrap();
+        assert_eq!(game.install_state, InstallState::Installed);
+        assert_eq!(game.id, "steam_620");
+        assert!(game.path.ends_with("Portal 2"));
+
+        let game = SteamScanner::parse_manifest(&manifest(1026), "620", library).unwrap();
+        assert_eq!(game.install_state, InstallState::Downloading);
+
+        assert!(SteamScanner::parse_manifest(&manifest(1), "620", library).is_none());
+    }
+
+    #[test]
+    fn test_merge_prefers_playable_install() {
+        let library = Path::new(r"C:\Steam\steamapps");
+        let manifest = |flags: u32| format!("\"name\" \"Game\"\n\"installdir\" \"Game\"\n\"StateFlags\" \"{flags}\"");
+
+        let entry = |flags: u32| SteamScanner::parse_manifest(&manifest(flags), "10", library).unwrap();
+
+        // Broken copy first, then the real install, then a stale download elsewhere
+        let mut games = Vec::new();
+        SteamScanner::merge_game(&mut games, entry(6 | 32));
+        SteamScanner::merge_game(&mut games, entry(4));
+        SteamScanner::merge_game(&mut games, entry(1026));
+
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].install_state, InstallState::Installed);
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            SteamScanner::normalize_path(Path::new(r"D:\SteamLibrary\steamapps\")),
+            SteamScanner::normalize_path(Path::new("d:/steamlibrary/steamapps"))
+        );
+    }
+
     #[test]
     fn test_parse_library_folders() {
         let content = r#"
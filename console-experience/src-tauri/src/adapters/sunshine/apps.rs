@@ -0,0 +1,52 @@
+use crate::adapters::epic_scanner::EpicScanner;
+use crate::domain::sunshine::SunshineApp;
+use crate::domain::{Game, GameSource};
+use std::path::Path;
+
+/// How Sunshine starts a library game.
+///
+/// Store games go through their launcher URL like Balam does (DRM and
+/// ownership checks stay happy); everything else runs the executable, so
+/// Sunshine can end the stream when the game exits.
+#[must_use]
+pub fn sunshine_app_for(game: &Game) -> SunshineApp {
+    let mut app = SunshineApp {
+        balam_id: game.id.clone(),
+        name: game.title.clone(),
+        cmd: None,
+        detached: Vec::new(),
+        working_dir: None,
+        image_path: local_png(game.image.as_deref()),
+    };
+
+    match game.source {
+        GameSource::Steam => app.detached.push(format!("steam://rungameid/{}", game.raw_id)),
+        GameSource::Xbox => app
+            .detached
+            .push(format!("explorer.exe shell:AppsFolder\\{}", game.path)),
+        GameSource::Epic => match EpicScanner::find_launch_info(&game.raw_id) {
+            Some(info) => app.detached.push(format!(
+                "explorer.exe com.epicgames.launcher://apps/{}?action=launch&silent=true",
+                info.app_name
+            )),
+            None => native(&mut app, &game.path),
+        },
+        GameSource::BattleNet | GameSource::Itch | GameSource::Manual => native(&mut app, &game.path),
+    }
+    app
+}
+
+fn native(app: &mut SunshineApp, path: &str) {
+    app.cmd = Some(format!("\"{path}\""));
+    app.working_dir = Path::new(path).parent().map(|dir| dir.to_string_lossy().to_string());
+}
+
+/// Sunshine only shows local PNG covers.
+fn local_png(image: Option<&str>) -> Option<String> {
+    image
+        .filter(|image| {
+            let path = Path::new(image);
+            path.is_absolute() && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+        })
+        .map(str::to_string)
+}
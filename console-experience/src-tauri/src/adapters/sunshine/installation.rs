@@ -0,0 +1,104 @@
+use crate::domain::sunshine::{conf_value, count_balam_apps, merge_apps, web_ui_port, SunshineApp};
+use crate::domain::BalamError;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::info;
+use winreg::enums::HKEY_LOCAL_MACHINE;
+use winreg::RegKey;
+
+/// Registry key the Sunshine installer writes its directory to.
+const SUNSHINE_REGISTRY_KEY: &str = r"SOFTWARE\LizardByte\Sunshine";
+
+/// A local Sunshine installation and its configuration files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SunshineInstallation {
+    pub install_dir: PathBuf,
+}
+
+impl SunshineInstallation {
+    /// Finds Sunshine from its registry key, then the default install folder.
+    #[must_use]
+    pub fn detect() -> Option<Self> {
+        let from_registry = RegKey::predef(HKEY_LOCAL_MACHINE)
+            .open_subkey(SUNSHINE_REGISTRY_KEY)
+            .and_then(|key| key.get_value::<String, _>(""))
+            .ok()
+            .map(PathBuf::from);
+        let default = std::env::var_os("ProgramFiles").map(|dir| Path::new(&dir).join("Sunshine"));
+
+        from_registry
+            .into_iter()
+            .chain(default)
+            .find(|dir| dir.join("sunshine.exe").is_file())
+            .map(|install_dir| Self { install_dir })
+    }
+
+    fn config_dir(&self) -> PathBuf {
+        self.install_dir.join("config")
+    }
+
+    /// `sunshine.conf` contents (empty when Sunshine runs on defaults).
+    fn conf(&self) -> String {
+        fs::read_to_string(self.config_dir().join("sunshine.conf")).unwrap_or_default()
+    }
+
+    /// App list file, honouring a `file_apps` override (relative to the config folder).
+    #[must_use]
+    pub fn apps_path(&self) -> PathBuf {
+        match conf_value(&self.conf(), "file_apps") {
+            Some(file) if Path::new(file).is_absolute() => PathBuf::from(file),
+            Some(file) => self.config_dir().join(file),
+            None => self.config_dir().join("apps.json"),
+        }
+    }
+
+    #[must_use]
+    pub fn web_ui_url(&self) -> String {
+        format!("https://localhost:{}", web_ui_port(&self.conf()))
+    }
+
+    fn read_apps(&self) -> Result<serde_json::Value, BalamError> {
+        match fs::read_to_string(self.apps_path()) {
+            Ok(content) => {
+                serde_json::from_str(&content).map_err(|e| BalamError::platform("Sunshine apps.json is malformed", e))
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(serde_json::json!({ "apps": [] })),
+            Err(e) => Err(BalamError::platform("Failed to read Sunshine apps.json", e)),
+        }
+    }
+
+    /// Library games currently in the app list.
+    #[must_use]
+    pub fn synced_games(&self) -> usize {
+        self.read_apps().map_or(0, |document| count_balam_apps(&document))
+    }
+
+    /// Writes `apps` as the Balam entries of the app list.
+    ///
+    /// Sunshine picks the new list up the next time Moonlight asks for it.
+    ///
+    /// # Errors
+    /// `AccessDenied` when the config folder is not writable for this user
+    /// (some installs keep it admin-only), platform error otherwise.
+    pub fn write_apps(&self, apps: &[SunshineApp]) -> Result<(), BalamError> {
+        let path = self.apps_path();
+        let document = merge_apps(self.read_apps()?, apps);
+        let content = serde_json::to_string_pretty(&document)
+            .map_err(|e| BalamError::platform("Failed to serialize Sunshine apps", e))?;
+
+        // Write-then-rename so Sunshine never reads a half-written list
+        let staging = path.with_extension("json.balam");
+        let written = fs::write(&staging, content).and_then(|()| fs::rename(&staging, &path));
+        match written {
+            Ok(()) => {
+                info!("📺 Synced {} games to {}", apps.len(), path.display());
+                Ok(())
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => Err(BalamError::AccessDenied(format!(
+                "Cannot write {} (allow your user to modify Sunshine's config folder)",
+                path.display()
+            ))),
+            Err(e) => Err(BalamError::platform("Failed to write Sunshine apps.json", e)),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Sunshine streaming host: installation, app list, service and web API.
+mod apps;
+mod installation;
+mod service;
+mod web_api;
+
+pub use apps::sunshine_app_for;
+pub use installation::SunshineInstallation;
+pub use service::SunshineService;
+pub use web_api::SunshineWebApi;
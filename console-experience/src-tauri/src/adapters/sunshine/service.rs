@@ -0,0 +1,82 @@
+use crate::domain::sunshine::SUNSHINE_SERVICE_NAME;
+use tracing::info;
+use windows::core::PCWSTR;
+use windows::Win32::System::Services::{
+    CloseServiceHandle, ControlService, OpenSCManagerW, OpenServiceW, QueryServiceStatus, StartServiceW, SC_HANDLE,
+    SC_MANAGER_CONNECT, SERVICE_CONTROL_STOP, SERVICE_QUERY_STATUS, SERVICE_RUNNING, SERVICE_START, SERVICE_STATUS,
+    SERVICE_STOP, SERVICE_STOPPED,
+};
+
+/// Control of the Sunshine Windows service.
+///
+/// Starting and stopping need administrator rights (the command layer goes
+/// through the privilege broker); querying does not.
+pub struct SunshineService;
+
+impl SunshineService {
+    /// Opens the service with `access`, runs `action` and closes the handles.
+    fn with_service<T>(access: u32, action: impl FnOnce(SC_HANDLE) -> Result<T, String>) -> Result<T, String> {
+        let name: Vec<u16> = SUNSHINE_SERVICE_NAME.encode_utf16().chain(std::iter::once(0)).collect();
+        unsafe {
+            let scm = OpenSCManagerW(None, None, SC_MANAGER_CONNECT).map_err(|e| format!("Failed to open SCM: {e}"))?;
+            let service = match OpenServiceW(scm, PCWSTR::from_raw(name.as_ptr()), access) {
+                Ok(service) => service,
+                Err(e) => {
+                    let _ = CloseServiceHandle(scm);
+                    return Err(format!("Failed to open Sunshine service: {e}"));
+                },
+            };
+            let result = action(service);
+            let _ = CloseServiceHandle(service);
+            let _ = CloseServiceHandle(scm);
+            result
+        }
+    }
+
+    fn state(service: SC_HANDLE) -> Option<u32> {
+        let mut status = SERVICE_STATUS::default();
+        unsafe { QueryServiceStatus(service, &mut status) }
+            .ok()
+            .map(|()| status.dwCurrentState.0)
+    }
+
+    /// Starts Sunshine (no-op if it is running).
+    ///
+    /// # Errors
+    /// Returns error if the service is missing or refuses to start.
+    pub fn start() -> Result<(), String> {
+        Self::with_service(SERVICE_START | SERVICE_QUERY_STATUS, |service| {
+            if Self::state(service) == Some(SERVICE_RUNNING.0) {
+                return Ok(());
+            }
+            unsafe { StartServiceW(service, None) }.map_err(|e| format!("Failed to start Sunshine: {e}"))
+        })?;
+        info!("📺 Sunshine service started");
+        Ok(())
+    }
+
+    /// Stops Sunshine (no-op if it is stopped); active streams end.
+    ///
+    /// # Errors
+    /// Returns error if the service is missing or refuses to stop.
+    pub fn stop() -> Result<(), String> {
+        Self::with_service(SERVICE_STOP | SERVICE_QUERY_STATUS, |service| {
+            if Self::state(service) == Some(SERVICE_STOPPED.0) {
+                return Ok(());
+            }
+            let mut status = SERVICE_STATUS::default();
+            unsafe { ControlService(service, SERVICE_CONTROL_STOP, &mut status) }
+                .map_err(|e| format!("Failed to stop Sunshine: {e}"))
+        })?;
+        info!("📺 Sunshine service stopped");
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_running() -> bool {
+        Self::with_service(SERVICE_QUERY_STATUS, |service| Ok(Self::state(service)))
+            .ok()
+            .flatten()
+            == Some(SERVICE_RUNNING.0)
+    }
+}
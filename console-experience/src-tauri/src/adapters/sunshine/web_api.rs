@@ -0,0 +1,67 @@
+use super::installation::SunshineInstallation;
+use crate::domain::BalamError;
+use std::time::Duration;
+use tracing::info;
+
+/// Sunshine's local web API (the one behind its web UI).
+///
+/// Authenticated with the Sunshine web UI credentials, which Balam never
+/// stores: the player types them when pairing.
+pub struct SunshineWebApi {
+    base_url: String,
+}
+
+impl SunshineWebApi {
+    #[must_use]
+    pub fn new(installation: &SunshineInstallation) -> Self {
+        Self {
+            base_url: installation.web_ui_url(),
+        }
+    }
+
+    /// Completes Moonlight pairing with the PIN Moonlight shows.
+    ///
+    /// # Errors
+    /// - `AccessDenied` - Wrong web UI credentials
+    /// - `InvalidArgument` - Sunshine rejected the PIN (no pairing in progress)
+    /// - `Platform` - Sunshine is not running
+    pub fn submit_pin(&self, pin: &str, device_name: &str, username: &str, password: &str) -> Result<(), BalamError> {
+        // Sunshine serves a self-signed certificate on localhost
+        let client = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(true)
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))?;
+
+        let response = client
+            .post(format!("{}/api/pin", self.base_url))
+            .basic_auth(username, Some(password))
+            .json(&serde_json::json!({ "pin": pin, "name": device_name }))
+            .send()
+            .map_err(|e| BalamError::platform("Sunshine is not reachable", e))?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(BalamError::AccessDenied(
+                "Wrong Sunshine username or password".to_string(),
+            ));
+        }
+        let body: serde_json::Value = response
+            .error_for_status()
+            .map_err(|e| BalamError::platform("Sunshine rejected the request", e))?
+            .json()
+            .map_err(|e| BalamError::platform("Unexpected Sunshine response", e))?;
+
+        // Sunshine answers `{"status": "true"}` (older versions: a boolean)
+        match body.get("status") {
+            Some(serde_json::Value::Bool(true)) => {},
+            Some(serde_json::Value::String(status)) if status == "true" => {},
+            _ => {
+                return Err(BalamError::InvalidArgument(
+                    "Sunshine rejected the PIN (start pairing in Moonlight first)".to_string(),
+                ))
+            },
+        }
+        info!("📺 Paired Moonlight device '{}'", device_name);
+        Ok(())
+    }
+}
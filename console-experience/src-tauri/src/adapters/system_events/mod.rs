@@ -0,0 +1,3 @@
+mod windows_system_events;
+
+pub use windows_system_events::WindowsSystemEvents;
@@ -0,0 +1,283 @@
+use crate::adapters::windows_system_adapter::WindowsSystemAdapter;
+use crate::domain::BalamError;
+use crate::ports::system_events_port::{
+    SleepCallback, SleepTransition, SystemEventCallback, SystemEventKind, SystemEventsPort,
+};
+use std::ffi::c_void;
+use std::sync::mpsc;
+use tracing::{info, warn};
+use windows::core::GUID;
+use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+use windows::Win32::Media::Audio::Endpoints::{
+    IAudioEndpointVolume, IAudioEndpointVolumeCallback, IAudioEndpointVolumeCallback_Impl,
+};
+use windows::Win32::Media::Audio::AUDIO_VOLUME_NOTIFICATION_DATA;
+use windows::Win32::NetworkManagement::WiFi::{
+    WlanCloseHandle, WlanOpenHandle, WlanRegisterNotification, L2_NOTIFICATION_DATA, WLAN_NOTIFICATION_SOURCE_ACM,
+};
+use windows::Win32::System::Com::{CoInitializeEx, COINIT_MULTITHREADED};
+use windows::Win32::System::Power::{
+    PowerRegisterSuspendResumeNotification, PowerSettingRegisterNotification, DEVICE_NOTIFY_CALLBACK,
+    DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS,
+};
+
+/// GUID_BATTERY_PERCENTAGE_REMAINING: a7ad8041-b45a-4cae-87a3-eecbb468a9e1
+const GUID_BATTERY_PERCENTAGE_REMAINING: GUID = GUID::from_u128(0xa7ad8041_b45a_4cae_87a3_eecbb468a9e1);
+/// GUID_ACDC_POWER_SOURCE: 5d3e9a59-e9d5-4b00-a6bd-ff34ff516548
+const GUID_ACDC_POWER_SOURCE: GUID = GUID::from_u128(0x5d3e9a59_e9d5_4b00_a6bd_ff34ff516548);
+
+// WM_POWERBROADCAST event types delivered to the suspend/resume callback
+const PBT_APMSUSPEND: u32 = 0x4;
+const PBT_APMRESUMEAUTOMATIC: u32 = 0x12;
+
+// WLAN_NOTIFICATION_ACM codes that change the connection shown in the status
+const ACM_CONNECTION_COMPLETE: u32 = 10;
+const ACM_INTERFACE_ARRIVAL: u32 = 13;
+const ACM_INTERFACE_REMOVAL: u32 = 14;
+const ACM_DISCONNECTED: u32 = 21;
+
+/// Native registrations, owned by the registration thread for the app lifetime.
+///
+/// The callback boxes are the context pointers handed to Windows and must
+/// not move or drop while registered.
+#[derive(Default)]
+#[allow(dead_code)] // Fields are only held, never read
+struct Registrations {
+    power_params: Vec<Box<DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS>>,
+    power_context: Option<Box<SystemEventCallback>>,
+    volume: Option<(IAudioEndpointVolume, IAudioEndpointVolumeCallback)>,
+    wlan_handle: Option<HANDLE>,
+    wlan_context: Option<Box<SystemEventCallback>>,
+}
+
+/// Windows implementation of `SystemEventsPort`.
+///
+/// - **Power:** `PowerSettingRegisterNotification` for battery percentage and AC/DC source
+/// - **Volume:** `IAudioEndpointVolumeCallback` on the default output device
+/// - **Network:** `WlanRegisterNotification` (connect, disconnect, adapter plugged/removed)
+/// - **Sleep:** `PowerRegisterSuspendResumeNotification`, the windowless form of
+///   `WM_POWERBROADCAST` (`PBT_APMSUSPEND` / `PBT_APMRESUMEAUTOMATIC`)
+///
+/// Everything is registered from a dedicated MTA thread that stays parked,
+/// so COM objects and callback contexts never leave their thread.
+pub struct WindowsSystemEvents;
+
+impl Default for WindowsSystemEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowsSystemEvents {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SystemEventsPort for WindowsSystemEvents {
+    fn subscribe(&self, callback: SystemEventCallback) -> Vec<SystemEventKind> {
+        let (result_tx, result_rx) = mpsc::channel();
+
+        let spawned = std::thread::Builder::new()
+            .name("system-events".to_string())
+            .spawn(move || {
+                unsafe {
+                    // The volume callback is invoked from audio service threads
+                    let _ = CoInitializeEx(None, COINIT_MULTITHREADED);
+                }
+
+                let mut registrations = Registrations::default();
+                let mut active = Vec::new();
+
+                for (kind, result) in [
+                    (SystemEventKind::Power, register_power(&mut registrations, &callback)),
+                    (SystemEventKind::Volume, register_volume(&mut registrations, &callback)),
+                    (SystemEventKind::Network, register_wlan(&mut registrations, &callback)),
+                ] {
+                    match result {
+                        Ok(()) => active.push(kind),
+                        Err(e) => warn!("{:?} change notifications unavailable: {}", kind, e),
+                    }
+                }
+
+                info!("📡 System change notifications registered: {:?}", active);
+                let _ = result_tx.send(active);
+
+                // Keep the registrations alive
+                loop {
+                    std::thread::park();
+                }
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start system events thread: {}", e);
+            return Vec::new();
+        }
+        result_rx.recv().unwrap_or_default()
+    }
+
+    fn subscribe_sleep(&self, callback: SleepCallback) -> bool {
+        // Registered for the app lifetime: context and parameters are leaked on purpose
+        let context: &'static SleepCallback = Box::leak(Box::new(callback));
+        let params: &'static DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS =
+            Box::leak(Box::new(DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS {
+                Callback: Some(sleep_callback),
+                Context: context as *const SleepCallback as *mut c_void,
+            }));
+        let mut handle: *mut c_void = std::ptr::null_mut();
+
+        let result = unsafe {
+            PowerRegisterSuspendResumeNotification(
+                DEVICE_NOTIFY_CALLBACK,
+                HANDLE(params as *const DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS as isize),
+                &mut handle,
+            )
+        };
+        if result != ERROR_SUCCESS {
+            warn!("Suspend/resume notifications unavailable: {:?}", result);
+            return false;
+        }
+        info!("📡 Suspend/resume notifications registered");
+        true
+    }
+}
+
+unsafe extern "system" fn sleep_callback(context: *const c_void, event_type: u32, _setting: *const c_void) -> u32 {
+    let transition = match event_type {
+        PBT_APMSUSPEND => SleepTransition::Suspending,
+        // Always sent on wake; PBT_APMRESUMESUSPEND only follows when a user is present
+        PBT_APMRESUMEAUTOMATIC => SleepTransition::Resumed,
+        _ => return ERROR_SUCCESS.0,
+    };
+    if !context.is_null() {
+        let callback = &*(context as *const SleepCallback);
+        callback(transition);
+    }
+    ERROR_SUCCESS.0
+}
+
+fn register_power(registrations: &mut Registrations, callback: &SystemEventCallback) -> Result<(), BalamError> {
+    // Stored before registering: a failure on the second setting leaves the first one live
+    let context = registrations.power_context.insert(Box::new(callback.clone()));
+    let context_ptr = &**context as *const SystemEventCallback as *mut c_void;
+
+    for setting in [GUID_BATTERY_PERCENTAGE_REMAINING, GUID_ACDC_POWER_SOURCE] {
+        let params = Box::new(DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS {
+            Callback: Some(power_callback),
+            Context: context_ptr,
+        });
+        let mut handle: *mut c_void = std::ptr::null_mut();
+
+        let result = unsafe {
+            PowerSettingRegisterNotification(
+                &setting,
+                DEVICE_NOTIFY_CALLBACK,
+                HANDLE(&*params as *const DEVICE_NOTIFY_SUBSCRIBE_PARAMETERS as isize),
+                &mut handle,
+            )
+        };
+        if result != ERROR_SUCCESS {
+            return Err(BalamError::Platform(format!(
+                "PowerSettingRegisterNotification failed: {result:?}"
+            )));
+        }
+        registrations.power_params.push(params);
+    }
+
+    Ok(())
+}
+
+unsafe extern "system" fn power_callback(context: *const c_void, _event_type: u32, _setting: *const c_void) -> u32 {
+    if !context.is_null() {
+        let callback = &*(context as *const SystemEventCallback);
+        callback(SystemEventKind::Power);
+    }
+    ERROR_SUCCESS.0
+}
+
+/// Forwards `OnNotify` (volume or mute changed) to the callback.
+#[windows::core::implement(IAudioEndpointVolumeCallback)]
+struct VolumeNotifier {
+    callback: SystemEventCallback,
+}
+
+#[allow(non_snake_case)]
+impl IAudioEndpointVolumeCallback_Impl for VolumeNotifier {
+    fn OnNotify(&self, _data: *mut AUDIO_VOLUME_NOTIFICATION_DATA) -> windows::core::Result<()> {
+        (self.callback)(SystemEventKind::Volume);
+        Ok(())
+    }
+}
+
+/// Registers on the default output device at startup. A later change of the
+/// default device is picked up by the fallback poll.
+fn register_volume(registrations: &mut Registrations, callback: &SystemEventCallback) -> Result<(), BalamError> {
+    let volume = WindowsSystemAdapter::new().get_volume_interface()?;
+    let notifier: IAudioEndpointVolumeCallback = VolumeNotifier {
+        callback: callback.clone(),
+    }
+    .into();
+
+    unsafe {
+        volume
+            .RegisterControlChangeNotify(&notifier)
+            .map_err(|e| BalamError::platform("RegisterControlChangeNotify failed", e))?;
+    }
+
+    registrations.volume = Some((volume, notifier));
+    Ok(())
+}
+
+fn register_wlan(registrations: &mut Registrations, callback: &SystemEventCallback) -> Result<(), BalamError> {
+    let mut handle = HANDLE::default();
+    let mut negotiated_version = 0u32;
+    let context = Box::new(callback.clone());
+
+    unsafe {
+        let result = WlanOpenHandle(2, None, &mut negotiated_version, &mut handle);
+        if result != ERROR_SUCCESS.0 {
+            // ERROR_SERVICE_NOT_ACTIVE when the machine has no Wi-Fi
+            return Err(BalamError::WifiUnavailable);
+        }
+
+        let result = WlanRegisterNotification(
+            handle,
+            WLAN_NOTIFICATION_SOURCE_ACM,
+            true,
+            Some(wlan_callback),
+            Some(&*context as *const SystemEventCallback as *const c_void),
+            None,
+            None,
+        );
+        if result != ERROR_SUCCESS.0 {
+            let _ = WlanCloseHandle(handle, None);
+            return Err(BalamError::Platform(format!(
+                "WlanRegisterNotification failed: error {result}"
+            )));
+        }
+    }
+
+    registrations.wlan_handle = Some(handle);
+    registrations.wlan_context = Some(context);
+    Ok(())
+}
+
+unsafe extern "system" fn wlan_callback(data: *mut L2_NOTIFICATION_DATA, context: *mut c_void) {
+    if data.is_null() || context.is_null() {
+        return;
+    }
+
+    let notification = &*data;
+    if notification.NotificationSource != WLAN_NOTIFICATION_SOURCE_ACM {
+        return;
+    }
+
+    if matches!(
+        notification.NotificationCode,
+        ACM_CONNECTION_COMPLETE | ACM_DISCONNECTED | ACM_INTERFACE_ARRIVAL | ACM_INTERFACE_REMOVAL
+    ) {
+        let callback = &*(context as *const SystemEventCallback);
+        callback(SystemEventKind::Network);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::domain::telemetry::TelemetryBatch;
+use crate::domain::BalamError;
+use crate::ports::TelemetrySinkPort;
+use std::time::Duration;
+
+/// Posts batches as JSON to the configured endpoint.
+pub struct HttpTelemetrySink;
+
+impl TelemetrySinkPort for HttpTelemetrySink {
+    fn send(&self, endpoint: &str, batch: &TelemetryBatch) -> Result<(), BalamError> {
+        reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(15))
+            .user_agent("BalamGridEngine/1.0")
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))?
+            .post(endpoint)
+            .json(batch)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map(|_| ())
+            .map_err(|e| BalamError::platform("Telemetry upload failed", e))
+    }
+}
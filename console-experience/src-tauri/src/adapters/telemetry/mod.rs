@@ -0,0 +1,4 @@
+//! Telemetry upload over HTTPS.
+mod http_sink;
+
+pub use http_sink::HttpTelemetrySink;
@@ -0,0 +1,73 @@
+use crate::domain::text_input::VirtualKey;
+use crate::domain::BalamError;
+use windows::Win32::UI::Input::KeyboardAndMouse::{
+    SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS, KEYEVENTF_EXTENDEDKEY, KEYEVENTF_KEYUP,
+    KEYEVENTF_UNICODE, VIRTUAL_KEY,
+};
+
+fn key_input(vk: u16, scan: u16, flags: KEYBD_EVENT_FLAGS) -> INPUT {
+    INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: VIRTUAL_KEY(vk),
+                wScan: scan,
+                dwFlags: flags,
+                ..Default::default()
+            },
+        },
+    }
+}
+
+fn key_press(key: VirtualKey) -> [INPUT; 2] {
+    let flags = if key.is_extended() {
+        KEYEVENTF_EXTENDEDKEY
+    } else {
+        KEYBD_EVENT_FLAGS(0)
+    };
+    [
+        key_input(key.code(), 0, flags),
+        key_input(key.code(), 0, flags | KEYEVENTF_KEYUP),
+    ]
+}
+
+fn send(inputs: &[INPUT]) -> Result<(), BalamError> {
+    let sent = unsafe { SendInput(inputs, std::mem::size_of::<INPUT>() as i32) };
+    if sent as usize == inputs.len() {
+        Ok(())
+    } else {
+        // UIPI drops input aimed at windows of higher integrity without telling which
+        Err(BalamError::Platform(format!(
+            "SendInput delivered {sent} of {} key events",
+            inputs.len()
+        )))
+    }
+}
+
+/// Types text as Unicode key events, independent of the keyboard layout.
+/// Line breaks become Enter presses, which every text control understands.
+pub(super) fn type_text(text: &str) -> Result<(), BalamError> {
+    let mut inputs = Vec::with_capacity(text.len() * 2);
+    for ch in text.chars() {
+        if ch == '\n' {
+            inputs.extend(key_press(VirtualKey::Enter));
+            continue;
+        }
+        if ch == '\r' {
+            continue;
+        }
+        let mut units = [0u16; 2];
+        for unit in ch.encode_utf16(&mut units) {
+            inputs.push(key_input(0, *unit, KEYEVENTF_UNICODE));
+            inputs.push(key_input(0, *unit, KEYEVENTF_UNICODE | KEYEVENTF_KEYUP));
+        }
+    }
+    if inputs.is_empty() {
+        return Ok(());
+    }
+    send(&inputs)
+}
+
+pub(super) fn press_key(key: VirtualKey) -> Result<(), BalamError> {
+    send(&key_press(key))
+}
@@ -0,0 +1,5 @@
+mod keystrokes;
+mod touch_keyboard;
+mod windows_text_input;
+
+pub use windows_text_input::WindowsTextInput;
@@ -0,0 +1,130 @@
+//! Windows touch keyboard control.
+//!
+//! There is no public API to show the touch keyboard outside of a focused
+//! UWP text box. `ITipInvocation::Toggle` (implemented by the `UIHostNoLaunch`
+//! coclass of TabTip) is what the taskbar keyboard button calls; it works
+//! for Win32 apps on Windows 10 and 11, as long as TabTip is running.
+
+use crate::domain::BalamError;
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::info;
+use windows::core::{w, IUnknown, IUnknown_Vtbl, GUID, HRESULT, PCWSTR};
+use windows::Win32::Foundation::HWND;
+use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_CLOAKED};
+use windows::Win32::System::Com::{CoCreateInstance, CoInitializeEx, CLSCTX_LOCAL_SERVER, COINIT_APARTMENTTHREADED};
+use windows::Win32::UI::WindowsAndMessaging::{
+    FindWindowExW, FindWindowW, GetDesktopWindow, GetWindowLongW, IsWindowVisible, GWL_STYLE, WS_DISABLED,
+};
+
+/// CLSID of `UIHostNoLaunch` (TabTip's invocation object).
+const CLSID_UI_HOST_NO_LAUNCH: GUID = GUID::from_u128(0x4CE5_76FA_83DC_4F88_951C_9D07_82B4_E376);
+
+/// How long a freshly started TabTip gets to register its COM server.
+const STARTUP_TIMEOUT: Duration = Duration::from_millis(1500);
+const STARTUP_POLL: Duration = Duration::from_millis(100);
+
+#[windows::core::interface("37C994E7-432B-4834-A2F7-DCE1F13B834B")]
+unsafe trait ITipInvocation: IUnknown {
+    unsafe fn Toggle(&self, wnd: HWND) -> HRESULT;
+}
+
+fn tip_invocation() -> windows::core::Result<ITipInvocation> {
+    unsafe {
+        // Already initialized (in either model) is fine
+        let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+        CoCreateInstance(&CLSID_UI_HOST_NO_LAUNCH, None, CLSCTX_LOCAL_SERVER)
+    }
+}
+
+/// `TabTip.exe` under Common Files (the 64-bit one on 64-bit Windows).
+fn tabtip_path() -> PathBuf {
+    let common = std::env::var_os("CommonProgramW6432")
+        .or_else(|| std::env::var_os("CommonProgramFiles"))
+        .map_or_else(|| PathBuf::from(r"C:\Program Files\Common Files"), PathBuf::from);
+    common.join("microsoft shared").join("ink").join("TabTip.exe")
+}
+
+/// Connects to TabTip, starting it first if its server is not up.
+fn connect() -> Result<ITipInvocation, BalamError> {
+    if let Ok(tip) = tip_invocation() {
+        return Ok(tip);
+    }
+
+    let path = tabtip_path();
+    info!("⌨️ Starting touch keyboard: {}", path.display());
+    std::process::Command::new(&path)
+        .spawn()
+        .map_err(|e| BalamError::platform("Failed to start TabTip", e))?;
+
+    let started = std::time::Instant::now();
+    loop {
+        std::thread::sleep(STARTUP_POLL);
+        match tip_invocation() {
+            Ok(tip) => return Ok(tip),
+            Err(e) if started.elapsed() >= STARTUP_TIMEOUT => {
+                return Err(BalamError::platform("Touch keyboard did not start", e));
+            },
+            Err(_) => {},
+        }
+    }
+}
+
+fn toggle() -> Result<(), BalamError> {
+    let tip = connect()?;
+    unsafe { tip.Toggle(GetDesktopWindow()) }
+        .ok()
+        .map_err(|e| BalamError::platform("Failed to toggle touch keyboard", e))
+}
+
+fn is_cloaked(hwnd: HWND) -> bool {
+    let mut cloaked = 0u32;
+    let result = unsafe {
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_CLOAKED,
+            std::ptr::from_mut(&mut cloaked).cast(),
+            std::mem::size_of::<u32>() as u32,
+        )
+    };
+    result.is_ok() && cloaked != 0
+}
+
+/// Whether the keyboard is on screen.
+///
+/// Windows 10 draws it in TabTip's `IPTip_Main_Window` (hidden = disabled
+/// style); Windows 11 moved it into TextInputHost, whose core window stays
+/// visible and is cloaked instead.
+pub(super) fn is_visible() -> bool {
+    unsafe {
+        let tabtip = FindWindowW(w!("IPTip_Main_Window"), PCWSTR::null());
+        if tabtip.0 != 0 {
+            let style = GetWindowLongW(tabtip, GWL_STYLE) as u32;
+            if IsWindowVisible(tabtip).as_bool() && style & WS_DISABLED.0 == 0 {
+                return true;
+            }
+        }
+
+        let host = FindWindowExW(
+            None,
+            None,
+            w!("Windows.UI.Core.CoreWindow"),
+            w!("Microsoft Text Input Application"),
+        );
+        host.0 != 0 && IsWindowVisible(host).as_bool() && !is_cloaked(host)
+    }
+}
+
+pub(super) fn show() -> Result<(), BalamError> {
+    if is_visible() {
+        return Ok(());
+    }
+    toggle()
+}
+
+pub(super) fn hide() -> Result<(), BalamError> {
+    if !is_visible() {
+        return Ok(());
+    }
+    toggle()
+}
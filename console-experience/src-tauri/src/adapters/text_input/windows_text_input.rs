@@ -0,0 +1,43 @@
+use super::{keystrokes, touch_keyboard};
+use crate::domain::text_input::VirtualKey;
+use crate::domain::BalamError;
+use crate::ports::text_input_port::TextInputPort;
+
+/// Windows implementation of `TextInputPort`: the touch keyboard through
+/// `ITipInvocation`, keystrokes through `SendInput`.
+pub struct WindowsTextInput;
+
+impl Default for WindowsTextInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowsTextInput {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl TextInputPort for WindowsTextInput {
+    fn show_touch_keyboard(&self) -> Result<(), BalamError> {
+        touch_keyboard::show()
+    }
+
+    fn hide_touch_keyboard(&self) -> Result<(), BalamError> {
+        touch_keyboard::hide()
+    }
+
+    fn is_touch_keyboard_visible(&self) -> bool {
+        touch_keyboard::is_visible()
+    }
+
+    fn type_text(&self, text: &str) -> Result<(), BalamError> {
+        keystrokes::type_text(text)
+    }
+
+    fn press_key(&self, key: VirtualKey) -> Result<(), BalamError> {
+        keystrokes::press_key(key)
+    }
+}
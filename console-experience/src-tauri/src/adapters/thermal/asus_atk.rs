@@ -0,0 +1,136 @@
+use crate::domain::thermal::{FanCurve, FAN_CURVE_POINTS};
+use crate::domain::BalamError;
+use windows::core::w;
+use windows::Win32::Foundation::{CloseHandle, GENERIC_READ, GENERIC_WRITE, HANDLE};
+use windows::Win32::Storage::FileSystem::{
+    CreateFileW, FILE_ATTRIBUTE_NORMAL, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
+};
+use windows::Win32::System::IO::DeviceIoControl;
+
+/// IOCTL of the ASUS ATK ACPI driver (same interface Armoury Crate uses).
+const ATK_CONTROL_CODE: u32 = 0x0022_240C;
+/// ACPI method ids: "DSTS" (get device status) and "DEVS" (set device status).
+const METHOD_DSTS: u32 = u32::from_le_bytes(*b"DSTS");
+const METHOD_DEVS: u32 = u32::from_le_bytes(*b"DEVS");
+
+const DEVICE_CPU_FAN: u32 = 0x0011_0013;
+const DEVICE_CPU_TEMP: u32 = 0x0012_0094;
+const DEVICE_CPU_FAN_CURVE: u32 = 0x0011_0024;
+const DEVICE_GPU_FAN_CURVE: u32 = 0x0011_0025;
+/// Thermal policy; writing it reloads the firmware's default fan curves.
+const DEVICE_THERMAL_POLICY: u32 = 0x0012_0075;
+const THERMAL_POLICY_BALANCED: u32 = 0;
+
+/// ROG Ally fan access through `\\.\ATKACPI`.
+///
+/// The embedded controller runs the curve itself, so no control loop is needed.
+pub struct AsusAtk;
+
+impl AsusAtk {
+    /// Fan speed in RPM (the firmware reports hundreds of RPM).
+    pub fn fan_rpm() -> Result<u32, BalamError> {
+        let mut value = Self::device_get(DEVICE_CPU_FAN)? - 65536;
+        if value < 0 {
+            value += 65536;
+        }
+        if value <= 0 || value > 100 {
+            return Ok(0);
+        }
+        Ok(value as u32 * 100)
+    }
+
+    /// CPU temperature in °C.
+    pub fn cpu_temperature() -> Result<f32, BalamError> {
+        let value = Self::device_get(DEVICE_CPU_TEMP)? - 65536;
+        if value <= 0 || value > 125 {
+            return Err(BalamError::Platform(format!(
+                "Invalid ATK temperature reading: {value}"
+            )));
+        }
+        Ok(value as f32)
+    }
+
+    /// Writes the curve to both fans (the Ally's fans share one heatsink).
+    pub fn apply_curve(curve: &FanCurve) -> Result<(), BalamError> {
+        let bytes = Self::curve_bytes(curve);
+        for device in [DEVICE_CPU_FAN_CURVE, DEVICE_GPU_FAN_CURVE] {
+            let mut args = device.to_le_bytes().to_vec();
+            args.extend_from_slice(&bytes);
+            Self::call(METHOD_DEVS, &args)?;
+        }
+        Ok(())
+    }
+
+    /// Re-selects the balanced thermal policy, which restores the stock curves.
+    pub fn restore_auto() -> Result<(), BalamError> {
+        let mut args = DEVICE_THERMAL_POLICY.to_le_bytes().to_vec();
+        args.extend_from_slice(&THERMAL_POLICY_BALANCED.to_le_bytes());
+        Self::call(METHOD_DEVS, &args).map(|_| ())
+    }
+
+    /// EC layout: 8 temperatures followed by 8 duties.
+    fn curve_bytes(curve: &FanCurve) -> [u8; FAN_CURVE_POINTS * 2] {
+        let mut bytes = [0u8; FAN_CURVE_POINTS * 2];
+        for (i, point) in curve.points.iter().take(FAN_CURVE_POINTS).enumerate() {
+            bytes[i] = point.temp_c;
+            bytes[FAN_CURVE_POINTS + i] = point.fan_percent;
+        }
+        bytes
+    }
+
+    fn device_get(device: u32) -> Result<i32, BalamError> {
+        let mut args = device.to_le_bytes().to_vec();
+        args.extend_from_slice(&[0; 4]);
+        Self::call(METHOD_DSTS, &args)
+    }
+
+    /// Sends `method` with `args`; returns the first output dword.
+    fn call(method: u32, args: &[u8]) -> Result<i32, BalamError> {
+        let mut input = method.to_le_bytes().to_vec();
+        input.extend_from_slice(&(args.len() as u32).to_le_bytes());
+        input.extend_from_slice(args);
+        let mut output = [0u8; 16];
+        let mut returned = 0u32;
+
+        unsafe {
+            let handle: HANDLE = CreateFileW(
+                w!(r"\\.\ATKACPI"),
+                (GENERIC_READ | GENERIC_WRITE).0,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                None,
+                OPEN_EXISTING,
+                FILE_ATTRIBUTE_NORMAL,
+                None,
+            )
+            .map_err(|_| BalamError::NotFound("ASUS ATK ACPI driver".to_string()))?;
+
+            let result = DeviceIoControl(
+                handle,
+                ATK_CONTROL_CODE,
+                Some(input.as_ptr().cast()),
+                input.len() as u32,
+                Some(output.as_mut_ptr().cast()),
+                output.len() as u32,
+                Some(&raw mut returned),
+                None,
+            );
+            let _ = CloseHandle(handle);
+            result.map_err(|e| BalamError::platform("ATK ACPI call failed", e))?;
+        }
+
+        Ok(i32::from_le_bytes([output[0], output[1], output[2], output[3]]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_bytes_layout() {
+        let bytes = AsusAtk::curve_bytes(&FanCurve::default());
+        assert_eq!(&bytes[..3], &[40, 50, 60]);
+        assert_eq!(bytes[FAN_CURVE_POINTS], 0);
+        assert_eq!(bytes[FAN_CURVE_POINTS * 2 - 1], 100);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::adapters::performance::WinRing0;
+use crate::domain::BalamError;
+use parking_lot::Mutex;
+
+/// Super I/O index/data ports used to reach the GPD embedded controller RAM.
+const EC_ADDR_PORT: u16 = 0x4E;
+const EC_DATA_PORT: u16 = 0x4F;
+
+/// EC RAM registers (GPD Win 4 / Win Max 2 firmware).
+const REG_MANUAL_ENABLE: u16 = 0x0275;
+const REG_DUTY: u16 = 0x1809;
+const REG_RPM_HIGH: u16 = 0x0218;
+const REG_RPM_LOW: u16 = 0x0219;
+/// Duty register value for 100%.
+const DUTY_MAX: u8 = 127;
+
+/// GPD fan access through EC RAM (WinRing0 port I/O).
+///
+/// The EC only takes a fixed duty, so the app runs the curve in a control
+/// loop; `restore_auto` hands the fan back to the EC's own table.
+pub struct GpdEc {
+    driver: Mutex<Option<WinRing0>>,
+}
+
+impl Default for GpdEc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GpdEc {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            driver: Mutex::new(None),
+        }
+    }
+
+    pub fn fan_rpm(&self) -> Result<u32, BalamError> {
+        self.with_ec(|ec| {
+            let high = Self::read(ec, REG_RPM_HIGH);
+            let low = Self::read(ec, REG_RPM_LOW);
+            Ok(u32::from(u16::from_be_bytes([high, low])))
+        })
+    }
+
+    pub fn set_duty(&self, percent: u8) -> Result<(), BalamError> {
+        let duty = (u16::from(percent.min(100)) * u16::from(DUTY_MAX) / 100) as u8;
+        self.with_ec(|ec| {
+            Self::write(ec, REG_MANUAL_ENABLE, 1);
+            Self::write(ec, REG_DUTY, duty);
+            Ok(())
+        })
+    }
+
+    pub fn restore_auto(&self) -> Result<(), BalamError> {
+        self.with_ec(|ec| {
+            Self::write(ec, REG_MANUAL_ENABLE, 0);
+            Ok(())
+        })
+    }
+
+    fn with_ec<T>(&self, f: impl FnOnce(&WinRing0) -> Result<T, BalamError>) -> Result<T, BalamError> {
+        let mut guard = self.driver.lock();
+        if guard.is_none() {
+            *guard = Some(WinRing0::load()?);
+        }
+        f(guard.as_ref().expect("driver loaded above"))
+    }
+
+    /// Selects an EC RAM address through the Super I/O index registers 0x10/0x11.
+    fn select(ec: &WinRing0, offset: u16) {
+        let [high, low] = offset.to_be_bytes();
+        for (index, value) in [(0x11, high), (0x10, low)] {
+            ec.write_port(EC_ADDR_PORT, 0x2E);
+            ec.write_port(EC_DATA_PORT, index);
+            ec.write_port(EC_ADDR_PORT, 0x2F);
+            ec.write_port(EC_DATA_PORT, value);
+        }
+        ec.write_port(EC_ADDR_PORT, 0x2E);
+        ec.write_port(EC_DATA_PORT, 0x12);
+        ec.write_port(EC_ADDR_PORT, 0x2F);
+    }
+
+    fn read(ec: &WinRing0, offset: u16) -> u8 {
+        Self::select(ec, offset);
+        ec.read_port(EC_DATA_PORT)
+    }
+
+    fn write(ec: &WinRing0, offset: u16, value: u8) {
+        Self::select(ec, offset);
+        ec.write_port(EC_DATA_PORT, value);
+    }
+}
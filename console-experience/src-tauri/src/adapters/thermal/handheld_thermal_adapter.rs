@@ -0,0 +1,135 @@
+use super::{AsusAtk, GpdEc, LenovoFan};
+use crate::domain::thermal::{FanCurve, HandheldModel};
+use crate::domain::BalamError;
+use crate::ports::thermal_port::ThermalPort;
+use serde::Deserialize;
+use tracing::{info, warn};
+use wmi::WMIConnection;
+
+/// ACPI thermal zone reading (`root\WMI`, tenths of Kelvin).
+#[derive(Deserialize, Debug)]
+#[serde(rename = "MSAcpi_ThermalZoneTemperature")]
+struct ThermalZoneTemperature {
+    #[serde(rename = "CurrentTemperature")]
+    current_temperature: u32,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename = "Win32_ComputerSystem")]
+struct ComputerSystem {
+    #[serde(rename = "Manufacturer")]
+    manufacturer: String,
+    #[serde(rename = "Model")]
+    model: String,
+}
+
+/// `ThermalPort` for the supported handhelds, picked from the SMBIOS model.
+///
+/// - **ROG Ally**: ATK ACPI fan curves (firmware-run)
+/// - **Legion Go**: Lenovo WMI fan table (firmware-run)
+/// - **GPD Win 4 / Max 2**: EC duty, driven by the app's control loop
+pub struct HandheldThermalAdapter {
+    model: HandheldModel,
+    gpd: GpdEc,
+}
+
+impl Default for HandheldThermalAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HandheldThermalAdapter {
+    /// Detects the device (one WMI query).
+    #[must_use]
+    pub fn new() -> Self {
+        let model = Self::detect_model();
+        info!("Fan control device: {:?}", model);
+        Self {
+            model,
+            gpd: GpdEc::new(),
+        }
+    }
+
+    fn detect_model() -> HandheldModel {
+        let systems: Vec<ComputerSystem> = match WMIConnection::new().and_then(|wmi_con| wmi_con.query()) {
+            Ok(systems) => systems,
+            Err(e) => {
+                warn!("Could not read SMBIOS model: {}", e);
+                return HandheldModel::Unknown;
+            },
+        };
+        systems.first().map_or(HandheldModel::Unknown, |system| {
+            HandheldModel::from_smbios(&system.manufacturer, &system.model)
+        })
+    }
+
+    /// Hottest ACPI thermal zone (the CPU zone on the supported handhelds).
+    fn acpi_temperature() -> Result<f32, BalamError> {
+        let wmi_con = WMIConnection::with_namespace_path("root\\WMI")
+            .map_err(|e| BalamError::platform("Failed to connect to WMI", e))?;
+        let zones: Vec<ThermalZoneTemperature> = wmi_con
+            .query()
+            .map_err(|e| BalamError::platform("Thermal zone query failed", e))?;
+        zones
+            .iter()
+            .map(|zone| zone.current_temperature as f32 / 10.0 - 273.15)
+            .reduce(f32::max)
+            .ok_or_else(|| BalamError::NotSupported("ACPI thermal zones".to_string()))
+    }
+
+    fn unsupported() -> BalamError {
+        BalamError::NotSupported("Fan control on this device".to_string())
+    }
+}
+
+impl ThermalPort for HandheldThermalAdapter {
+    fn model(&self) -> HandheldModel {
+        self.model
+    }
+
+    fn fan_rpm(&self) -> Result<u32, BalamError> {
+        match self.model {
+            HandheldModel::RogAlly => AsusAtk::fan_rpm(),
+            HandheldModel::LegionGo => LenovoFan::fan_rpm(),
+            HandheldModel::GpdWin => self.gpd.fan_rpm(),
+            HandheldModel::Unknown => Err(Self::unsupported()),
+        }
+    }
+
+    fn cpu_temperature(&self) -> Result<f32, BalamError> {
+        match self.model {
+            HandheldModel::RogAlly => AsusAtk::cpu_temperature(),
+            HandheldModel::LegionGo | HandheldModel::GpdWin => Self::acpi_temperature(),
+            HandheldModel::Unknown => Err(Self::unsupported()),
+        }
+    }
+
+    fn supports_hardware_curve(&self) -> bool {
+        matches!(self.model, HandheldModel::RogAlly | HandheldModel::LegionGo)
+    }
+
+    fn apply_curve(&self, curve: &FanCurve) -> Result<(), BalamError> {
+        match self.model {
+            HandheldModel::RogAlly => AsusAtk::apply_curve(curve),
+            HandheldModel::LegionGo => LenovoFan::apply_curve(curve),
+            HandheldModel::GpdWin | HandheldModel::Unknown => Err(Self::unsupported()),
+        }
+    }
+
+    fn set_fan_duty(&self, percent: u8) -> Result<(), BalamError> {
+        match self.model {
+            HandheldModel::GpdWin => self.gpd.set_duty(percent),
+            _ => Err(Self::unsupported()),
+        }
+    }
+
+    fn restore_auto(&self) -> Result<(), BalamError> {
+        match self.model {
+            HandheldModel::RogAlly => AsusAtk::restore_auto(),
+            HandheldModel::LegionGo => LenovoFan::restore_auto(),
+            HandheldModel::GpdWin => self.gpd.restore_auto(),
+            HandheldModel::Unknown => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::domain::thermal::FanCurve;
+use crate::domain::BalamError;
+use serde::{Deserialize, Serialize};
+use wmi::WMIConnection;
+
+/// Temperatures of the 10 slots in the Lenovo fan table (10°C steps).
+const TABLE_TEMPS_C: [u8; 10] = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+
+/// Smart fan modes of `LENOVO_GAMEZONE_DATA.SetSmartFanMode`.
+const SMART_FAN_BALANCED: u32 = 2;
+const SMART_FAN_CUSTOM: u32 = 255;
+
+#[derive(Deserialize, Debug)]
+#[serde(rename = "LENOVO_FAN_METHOD")]
+struct LenovoFanMethod {
+    #[serde(rename = "__Path")]
+    path: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename = "LENOVO_GAMEZONE_DATA")]
+struct LenovoGameZoneData {
+    #[serde(rename = "__Path")]
+    path: String,
+}
+
+#[derive(Serialize, Debug)]
+struct FanIdParams {
+    #[serde(rename = "FanID")]
+    fan_id: u32,
+}
+
+#[derive(Deserialize, Debug)]
+struct FanSpeedResult {
+    #[serde(rename = "CurrentFanSpeed")]
+    current_fan_speed: u32,
+}
+
+#[derive(Serialize, Debug)]
+struct FanTableParams {
+    #[serde(rename = "FanTable")]
+    fan_table: Vec<u8>,
+}
+
+#[derive(Serialize, Debug)]
+struct SmartFanModeParams {
+    #[serde(rename = "Data")]
+    data: u32,
+}
+
+/// Output of methods without meaningful results.
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+struct EmptyResult {}
+
+/// Legion Go fan access through Lenovo's WMI provider (`root\WMI`).
+///
+/// Like the ASUS EC, the firmware runs the table itself once custom mode is selected.
+pub struct LenovoFan;
+
+impl LenovoFan {
+    pub fn fan_rpm() -> Result<u32, BalamError> {
+        let wmi_con = Self::connect()?;
+        let method = Self::instance::<LenovoFanMethod>(&wmi_con)?.path;
+        let result: FanSpeedResult = wmi_con
+            .exec_instance_method::<LenovoFanMethod, _>(&method, "Fan_GetCurrentFanSpeed", FanIdParams { fan_id: 0 })
+            .map_err(|e| BalamError::platform("Fan_GetCurrentFanSpeed failed", e))?;
+        Ok(result.current_fan_speed)
+    }
+
+    pub fn apply_curve(curve: &FanCurve) -> Result<(), BalamError> {
+        let wmi_con = Self::connect()?;
+        Self::set_smart_fan_mode(&wmi_con, SMART_FAN_CUSTOM)?;
+
+        let method = Self::instance::<LenovoFanMethod>(&wmi_con)?.path;
+        let _: EmptyResult = wmi_con
+            .exec_instance_method::<LenovoFanMethod, _>(
+                &method,
+                "Fan_Set_Table",
+                FanTableParams {
+                    fan_table: Self::table_bytes(curve),
+                },
+            )
+            .map_err(|e| BalamError::platform("Fan_Set_Table failed", e))?;
+        Ok(())
+    }
+
+    /// Back to the balanced smart fan mode (stock table).
+    pub fn restore_auto() -> Result<(), BalamError> {
+        Self::set_smart_fan_mode(&Self::connect()?, SMART_FAN_BALANCED)
+    }
+
+    /// Table layout: mode, fan id, reserved dword, then 10 little-endian duties.
+    fn table_bytes(curve: &FanCurve) -> Vec<u8> {
+        let mut bytes = vec![1u8, 0];
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        for temp in TABLE_TEMPS_C {
+            bytes.extend_from_slice(&u16::from(curve.duty_at(f32::from(temp))).to_le_bytes());
+        }
+        bytes
+    }
+
+    fn set_smart_fan_mode(wmi_con: &WMIConnection, mode: u32) -> Result<(), BalamError> {
+        let game_zone = Self::instance::<LenovoGameZoneData>(wmi_con)?.path;
+        let _: EmptyResult = wmi_con
+            .exec_instance_method::<LenovoGameZoneData, _>(
+                &game_zone,
+                "SetSmartFanMode",
+                SmartFanModeParams { data: mode },
+            )
+            .map_err(|e| BalamError::platform("SetSmartFanMode failed", e))?;
+        Ok(())
+    }
+
+    fn connect() -> Result<WMIConnection, BalamError> {
+        WMIConnection::with_namespace_path("root\\WMI").map_err(|e| BalamError::platform("Failed to connect to WMI", e))
+    }
+
+    fn instance<T: serde::de::DeserializeOwned>(wmi_con: &WMIConnection) -> Result<T, BalamError> {
+        let instances: Vec<T> = wmi_con
+            .query()
+            .map_err(|e| BalamError::platform("Lenovo WMI query failed", e))?;
+        instances
+            .into_iter()
+            .next()
+            .ok_or_else(|| BalamError::NotFound("Lenovo fan WMI interface".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_samples_curve_every_ten_degrees() {
+        let bytes = LenovoFan::table_bytes(&FanCurve::default());
+        assert_eq!(bytes.len(), 6 + 20);
+        // 50°C → 20%, 100°C → 100%
+        assert_eq!(u16::from_le_bytes([bytes[6 + 8], bytes[6 + 9]]), 20);
+        assert_eq!(u16::from_le_bytes([bytes[24], bytes[25]]), 100);
+    }
+}
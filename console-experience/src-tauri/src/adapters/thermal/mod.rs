@@ -0,0 +1,9 @@
+pub mod asus_atk;
+pub mod gpd_ec;
+pub mod handheld_thermal_adapter;
+pub mod lenovo_fan;
+
+pub use asus_atk::AsusAtk;
+pub use gpd_ec::GpdEc;
+pub use handheld_thermal_adapter::HandheldThermalAdapter;
+pub use lenovo_fan::LenovoFan;
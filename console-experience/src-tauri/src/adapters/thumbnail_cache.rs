@@ -0,0 +1,97 @@
+/// Content-addressed image store for game artwork
+///
+/// Images are saved under the SHA-256 of their bytes, so storing the same
+/// image twice (shared covers, re-downloads after a rescan) is free.
+/// Formats the webview cannot show well (icons) are re-encoded as PNG.
+use crate::domain::artwork::{thumbnail_widths, StoredImage};
+use image::imageops::FilterType;
+use image::ImageFormat;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// Hex SHA-256 of `bytes`.
+fn content_hash(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Stores an image in `dir`; `with_thumbnails` also writes the cover
+/// thumbnails (skipping those already on disk).
+///
+/// # Errors
+/// Returns an error if the bytes are not a supported image or a file
+/// cannot be written.
+pub fn store(dir: &Path, bytes: &[u8], with_thumbnails: bool) -> Result<StoredImage, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("Failed to create artwork directory: {e}"))?;
+
+    let format = image::guess_format(bytes).map_err(|e| format!("Unknown image format: {e}"))?;
+    let image = image::load_from_memory_with_format(bytes, format).map_err(|e| format!("Invalid image: {e}"))?;
+
+    let keep_original = matches!(format, ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP);
+    let stored = StoredImage {
+        hash: content_hash(bytes),
+        extension: if keep_original {
+            format.extensions_str().first().copied().unwrap_or("img").to_string()
+        } else {
+            "png".to_string()
+        },
+        thumbnails: if with_thumbnails {
+            thumbnail_widths(image.width())
+        } else {
+            Vec::new()
+        },
+    };
+
+    let original = dir.join(stored.file_name());
+    if !original.exists() {
+        let written = if keep_original {
+            fs::write(&original, bytes).map_err(|e| e.to_string())
+        } else {
+            image
+                .save_with_format(&original, ImageFormat::Png)
+                .map_err(|e| e.to_string())
+        };
+        written.map_err(|e| format!("Failed to save {}: {e}", original.display()))?;
+    }
+
+    for &width in &stored.thumbnails {
+        let path = dir.join(stored.thumbnail_file_name(width));
+        if path.exists() {
+            continue;
+        }
+        image
+            .resize(width, u32::MAX, FilterType::Triangle)
+            .save_with_format(&path, ImageFormat::Png)
+            .map_err(|e| format!("Failed to save {}: {e}", path.display()))?;
+    }
+
+    Ok(stored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_images_are_stored_once_with_thumbnails() {
+        let dir = std::env::temp_dir().join(format!("balam_artwork_{}", uuid::Uuid::new_v4()));
+        let mut png = Vec::new();
+        image::RgbaImage::new(640, 960)
+            .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+            .unwrap();
+
+        let stored = store(&dir, &png, true).unwrap();
+        assert_eq!(stored.extension, "png");
+        assert_eq!(stored.thumbnails, vec![256, 600]);
+        let thumbnail = image::open(dir.join(stored.thumbnail_file_name(256))).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (256, 384));
+
+        // Same bytes, same files
+        assert_eq!(store(&dir, &png, true).unwrap(), stored);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+        assert!(store(&dir, b"not an image", true).is_err());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,262 @@
+use super::privileges::{create_admin_only_dir, spawn_unelevated, staging_root};
+use super::release_feed::updates_dir;
+use super::signature::verify_package;
+use crate::adapters::fps_service::execute_elevated;
+use crate::application::commands::is_elevated;
+use crate::domain::update::{PackageKind, UpdateMarker, UPDATE_HELPER_FLAG, UPDATE_INSTALL_DIR_FLAG};
+use crate::domain::BalamError;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{error, info, warn};
+
+/// File name of every helper copy.
+const HELPER_FILE_NAME: &str = "balam-updater.exe";
+/// Prefix of the admin-only folders the elevated helper installs from.
+const STAGING_PREFIX: &str = "balam-update-";
+
+/// Applies a verified package to the install directory.
+///
+/// The helper is a copy of `watchdog.exe` started with [`UPDATE_HELPER_FLAG`]:
+/// 1. Balam starts a copy in the updates folder at medium integrity; it
+///    waits for Balam to exit and relaunches it once the update is done
+/// 2. When the install needs admin rights, that copy asks UAC to run the
+///    install directory's own `watchdog.exe`, which copies itself to a new
+///    admin-only folder and starts the copy
+/// 3. The copy that installs re-verifies the package from a folder the user
+///    cannot write to, so nothing is swapped between the check and the install
+pub struct UpdateInstaller;
+
+impl UpdateInstaller {
+    /// Copies the watchdog to the updates folder and starts it as the update
+    /// helper, unelevated even when Balam runs as administrator.
+    ///
+    /// # Errors
+    /// Returns error if the watchdog is missing or the helper cannot start.
+    pub fn launch_helper(install_dir: &Path) -> Result<(), BalamError> {
+        let dir = updates_dir()?;
+        fs::create_dir_all(&dir).map_err(|e| BalamError::platform("Failed to create updates folder", e))?;
+        let helper = dir.join(HELPER_FILE_NAME);
+        fs::copy(install_dir.join("watchdog.exe"), &helper)
+            .map_err(|e| BalamError::platform("Failed to copy update helper", e))?;
+
+        let args = [
+            OsStr::new(UPDATE_HELPER_FLAG),
+            OsStr::new(UPDATE_INSTALL_DIR_FLAG),
+            install_dir.as_os_str(),
+        ];
+        info!("🚀 Starting update helper");
+        if is_elevated() {
+            return spawn_unelevated(&helper, &args, &dir);
+        }
+        Command::new(&helper)
+            .args(args)
+            .current_dir(&dir)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| BalamError::platform("Failed to start update helper", e))
+    }
+
+    /// Asks UAC to run the install directory's own `watchdog.exe` as the
+    /// elevated helper (never a copy the user could have replaced).
+    ///
+    /// # Errors
+    /// Returns error if the prompt is declined or the helper cannot start.
+    pub fn launch_elevated_helper(install_dir: &Path) -> Result<(), BalamError> {
+        info!("🛡️ Starting elevated update helper");
+        execute_elevated(
+            install_dir.join("watchdog.exe"),
+            Some(UPDATE_HELPER_FLAG),
+            Some(install_dir),
+        )
+        .map_err(BalamError::Platform)
+    }
+
+    /// Copies the running (elevated) helper into a new admin-only folder and
+    /// starts the copy to install into `install_dir`.
+    ///
+    /// # Errors
+    /// Returns error if the folder cannot be created or the copy cannot start.
+    pub fn stage_elevated_helper(install_dir: &Path) -> Result<(), BalamError> {
+        let current = std::env::current_exe().map_err(|e| BalamError::platform("Failed to locate update helper", e))?;
+        let dir = staging_root()?.join(format!("{STAGING_PREFIX}{}", uuid::Uuid::new_v4().simple()));
+        create_admin_only_dir(&dir)?;
+        let helper = dir.join(HELPER_FILE_NAME);
+        fs::copy(current, &helper).map_err(|e| BalamError::platform("Failed to stage update helper", e))?;
+
+        Command::new(&helper)
+            .arg(UPDATE_HELPER_FLAG)
+            .arg(UPDATE_INSTALL_DIR_FLAG)
+            .arg(install_dir)
+            .current_dir(&dir)
+            .spawn()
+            .map(|_| ())
+            .map_err(|e| BalamError::platform("Failed to start staged update helper", e))
+    }
+
+    /// Admin-only folder of the running helper, if it was staged by
+    /// [`Self::stage_elevated_helper`].
+    #[must_use]
+    pub fn staged_helper_dir() -> Option<PathBuf> {
+        let dir = std::env::current_exe().ok()?.parent()?.to_path_buf();
+        let staged = dir.parent() == staging_root().ok().as_deref()
+            && dir
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with(STAGING_PREFIX));
+        staged.then_some(dir)
+    }
+
+    /// Copies the marker's package into `dir` and verifies the copy's
+    /// checksum and signature: the file Balam verified sits in a folder the
+    /// user can write to.
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` if the package does not verify, or a platform
+    /// error if it cannot be copied.
+    pub fn stage_package(marker: &UpdateMarker, dir: &Path) -> Result<PathBuf, BalamError> {
+        let source = Path::new(&marker.package_path);
+        let name = source
+            .file_name()
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid update package: {}", marker.package_path)))?;
+        // Read once and verify the bytes that are written, not the file again
+        let data = fs::read(source).map_err(|e| BalamError::platform("Failed to read update", e))?;
+        verify_package(&data, &marker.sha256, &marker.signature)?;
+
+        let mut staged_name = OsStr::new("verified-").to_os_string();
+        staged_name.push(name);
+        let staged = dir.join(staged_name);
+        let _ = fs::remove_file(&staged);
+        fs::write(&staged, data).map_err(|e| BalamError::platform("Failed to stage update", e))?;
+        Ok(staged)
+    }
+
+    /// Installs `package` (from [`Self::stage_package`]) into `install_dir`.
+    ///
+    /// # Errors
+    /// Returns error if the installer fails or a delta cannot be applied (the
+    /// install directory is rolled back in that case).
+    pub fn install(kind: PackageKind, package: &Path, install_dir: &Path) -> Result<(), BalamError> {
+        info!("📦 Installing {} ({:?})", package.display(), kind);
+        match kind {
+            PackageKind::Full => Self::run_installer(package),
+            PackageKind::Delta => Self::apply_delta(package, install_dir),
+        }
+    }
+
+    /// Silent NSIS install; it reuses the install directory of the previous version.
+    fn run_installer(installer: &Path) -> Result<(), BalamError> {
+        let status = Command::new(installer)
+            .arg("/S")
+            .status()
+            .map_err(|e| BalamError::platform("Failed to run installer", e))?;
+        if status.success() {
+            Ok(())
+        } else {
+            Err(BalamError::Platform(format!("Installer exited with {status}")))
+        }
+    }
+
+    /// Copies every file of the delta zip over `install_dir`.
+    ///
+    /// Replaced files are kept as `<name>.old` until all files are written;
+    /// on any failure the new files are removed and the backups restored.
+    fn apply_delta(package: &Path, install_dir: &Path) -> Result<(), BalamError> {
+        let archive = fs::File::open(package).map_err(|e| BalamError::platform("Failed to open update", e))?;
+        let mut zip = zip::ZipArchive::new(archive).map_err(|e| BalamError::platform("Invalid update archive", e))?;
+
+        let mut written: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+        let result = (0..zip.len()).try_for_each(|index| {
+            let mut entry = zip
+                .by_index(index)
+                .map_err(|e| BalamError::platform("Corrupt update archive", e))?;
+            let Some(relative) = entry.enclosed_name() else {
+                return Err(BalamError::InvalidArgument(format!(
+                    "Unsafe path in update: {}",
+                    entry.name()
+                )));
+            };
+            if entry.is_dir() {
+                return Ok(());
+            }
+
+            let target = install_dir.join(relative);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            let backup = target.exists().then(|| {
+                let mut name = target.as_os_str().to_owned();
+                name.push(".old");
+                PathBuf::from(name)
+            });
+            if let Some(backup) = &backup {
+                let _ = fs::remove_file(backup);
+                // Renaming works on running executables, overwriting does not
+                fs::rename(&target, backup)?;
+            }
+            written.push((target.clone(), backup));
+
+            let mut file = fs::File::create(&target)?;
+            std::io::copy(&mut entry, &mut file)?;
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            error!("❌ Delta update failed, rolling back: {}", e);
+            for (target, backup) in written.iter().rev() {
+                let _ = fs::remove_file(target);
+                if let Some(backup) = backup {
+                    if let Err(e) = fs::rename(backup, target) {
+                        error!("❌ Failed to restore {}: {}", target.display(), e);
+                    }
+                }
+            }
+            return Err(e);
+        }
+
+        for backup in written.iter().filter_map(|(_, backup)| backup.as_ref()) {
+            if fs::remove_file(backup).is_err() {
+                warn!("Leaving {} behind (still in use)", backup.display());
+            }
+        }
+        info!("✅ Replaced {} file(s)", written.len());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    #[test]
+    fn test_apply_delta_replaces_and_adds_files() {
+        let root = std::env::temp_dir().join(format!("balam_delta_{}", uuid::Uuid::new_v4()));
+        let install_dir = root.join("app");
+        fs::create_dir_all(&install_dir).unwrap();
+        fs::write(install_dir.join("console-experience.exe"), b"old").unwrap();
+        fs::write(install_dir.join("keep.dll"), b"keep").unwrap();
+
+        let package = root.join("delta.zip");
+        let mut zip = zip::ZipWriter::new(fs::File::create(&package).unwrap());
+        zip.start_file("console-experience.exe", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"new").unwrap();
+        zip.start_file("resources/overlay.dll", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"overlay").unwrap();
+        zip.finish().unwrap();
+
+        UpdateInstaller::apply_delta(&package, &install_dir).unwrap();
+
+        assert_eq!(fs::read(install_dir.join("console-experience.exe")).unwrap(), b"new");
+        assert_eq!(
+            fs::read(install_dir.join("resources").join("overlay.dll")).unwrap(),
+            b"overlay"
+        );
+        assert_eq!(fs::read(install_dir.join("keep.dll")).unwrap(), b"keep");
+        assert!(!install_dir.join("console-experience.exe.old").exists());
+        let _ = fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,54 @@
+use super::release_feed::updates_dir;
+use crate::domain::update::UpdateMarker;
+use crate::domain::BalamError;
+use crate::infrastructure::clock::now_secs;
+use std::fs;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// `update.pending` in the updates folder, shared by Balam, the helper and the watchdog.
+pub struct UpdateMarkerStore;
+
+impl UpdateMarkerStore {
+    /// # Errors
+    /// Returns error if the updates folder cannot be resolved.
+    pub fn path() -> Result<PathBuf, BalamError> {
+        Ok(updates_dir()?.join("update.pending"))
+    }
+
+    /// # Errors
+    /// Returns error if the marker cannot be written.
+    pub fn write(marker: &UpdateMarker) -> Result<(), BalamError> {
+        let json = serde_json::to_string_pretty(marker)
+            .map_err(|e| BalamError::platform("Failed to serialize update marker", e))?;
+        fs::create_dir_all(updates_dir()?).map_err(|e| BalamError::platform("Failed to create updates folder", e))?;
+        fs::write(Self::path()?, json).map_err(|e| BalamError::platform("Failed to write update marker", e))
+    }
+
+    /// Pending update, ignoring (and removing) a marker left by a dead update.
+    #[must_use]
+    pub fn read() -> Option<UpdateMarker> {
+        let json = fs::read_to_string(Self::path().ok()?).ok()?;
+        let marker: Option<UpdateMarker> = serde_json::from_str(&json).ok();
+        let now = now_secs();
+        match marker {
+            Some(marker) if !marker.is_stale(now) => Some(marker),
+            _ => {
+                warn!("🗑️ Removing stale or unreadable update marker");
+                Self::clear();
+                None
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn is_pending() -> bool {
+        Self::read().is_some()
+    }
+
+    pub fn clear() {
+        if let Ok(path) = Self::path() {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
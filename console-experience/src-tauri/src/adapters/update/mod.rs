@@ -0,0 +1,11 @@
+//! Self-update: release feed, signature check and the install step run by the helper.
+mod installer;
+mod marker;
+mod privileges;
+mod release_feed;
+mod signature;
+
+pub use installer::UpdateInstaller;
+pub use marker::UpdateMarkerStore;
+pub use release_feed::{updates_dir, HttpReleaseFeed};
+pub use signature::verify_release_signature;
@@ -0,0 +1,122 @@
+use crate::domain::BalamError;
+use crate::infrastructure::encoding::to_wide;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use windows::core::{w, PCWSTR, PWSTR};
+use windows::Win32::Foundation::{CloseHandle, HANDLE};
+use windows::Win32::Security::Authorization::{ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1};
+use windows::Win32::Security::{
+    DuplicateTokenEx, SecurityImpersonation, TokenPrimary, PSECURITY_DESCRIPTOR, SECURITY_ATTRIBUTES,
+    TOKEN_ADJUST_DEFAULT, TOKEN_ADJUST_SESSIONID, TOKEN_ASSIGN_PRIMARY, TOKEN_DUPLICATE, TOKEN_QUERY,
+};
+use windows::Win32::Storage::FileSystem::CreateDirectoryW;
+use windows::Win32::System::Com::CoTaskMemFree;
+use windows::Win32::System::Threading::{
+    CreateProcessWithTokenW, OpenProcess, OpenProcessToken, CREATE_PROCESS_LOGON_FLAGS, PROCESS_CREATION_FLAGS,
+    PROCESS_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION, STARTUPINFOW,
+};
+use windows::Win32::UI::Shell::{FOLDERID_Windows, SHGetKnownFolderPath, KF_FLAG_DEFAULT};
+use windows::Win32::UI::WindowsAndMessaging::{GetShellWindow, GetWindowThreadProcessId};
+
+/// Owner and access of a staging folder (SDDL): owned by administrators,
+/// full control for SYSTEM and administrators only, nothing inherited.
+const ADMIN_ONLY_SDDL: PCWSTR = w!("O:BAD:P(A;OICI;FA;;;SY)(A;OICI;FA;;;BA)");
+
+/// `%SystemRoot%\Temp`, resolved through the shell (not the environment,
+/// which the user controls). Users may create folders there but cannot
+/// rename or delete folders they do not own.
+pub(crate) fn staging_root() -> Result<PathBuf, BalamError> {
+    unsafe {
+        let path = SHGetKnownFolderPath(&FOLDERID_Windows, KF_FLAG_DEFAULT, None)
+            .map_err(|e| BalamError::platform("Failed to resolve Windows folder", e))?;
+        let dir = path.to_string().map(PathBuf::from);
+        CoTaskMemFree(Some(path.0 as *const _));
+        dir.map(|dir| dir.join("Temp"))
+            .map_err(|e| BalamError::platform("Invalid Windows folder", e))
+    }
+}
+
+/// Creates `path` writable by SYSTEM and administrators only.
+///
+/// Fails if the folder already exists: a folder someone else prepared is
+/// never reused.
+pub(crate) fn create_admin_only_dir(path: &Path) -> Result<(), BalamError> {
+    let mut descriptor = PSECURITY_DESCRIPTOR::default();
+    unsafe {
+        ConvertStringSecurityDescriptorToSecurityDescriptorW(ADMIN_ONLY_SDDL, SDDL_REVISION_1, &mut descriptor, None)
+    }
+    .map_err(|e| BalamError::platform("Failed to build staging folder ACL", e))?;
+    let attributes = SECURITY_ATTRIBUTES {
+        nLength: std::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor.0,
+        bInheritHandle: false.into(),
+    };
+    let wide = to_wide(path.as_os_str());
+    // The descriptor's small allocation is left to the helper's exit
+    unsafe { CreateDirectoryW(PCWSTR(wide.as_ptr()), Some(&attributes)) }
+        .map_err(|e| BalamError::platform("Failed to create staging folder", e))
+}
+
+/// Starts `exe` with the desktop shell's token: medium integrity, even when
+/// the caller is elevated.
+///
+/// # Errors
+/// Returns `NotFound` without a desktop shell (explorer.exe is not running).
+pub(crate) fn spawn_unelevated(exe: &Path, args: &[&OsStr], dir: &Path) -> Result<(), BalamError> {
+    let mut command_line = format!("\"{}\"", exe.display());
+    for arg in args {
+        command_line.push_str(&format!(" \"{}\"", arg.to_string_lossy()));
+    }
+    let mut command_line = to_wide(&command_line);
+    let application = to_wide(exe.as_os_str());
+    let current_dir = to_wide(dir.as_os_str());
+
+    unsafe {
+        let shell = GetShellWindow();
+        if shell.0 == 0 {
+            return Err(BalamError::NotFound("Desktop shell".to_string()));
+        }
+        let mut shell_pid = 0u32;
+        GetWindowThreadProcessId(shell, Some(&mut shell_pid));
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, shell_pid)
+            .map_err(|e| BalamError::platform("Failed to open desktop shell", e))?;
+        let mut shell_token = HANDLE::default();
+        let opened = OpenProcessToken(process, TOKEN_DUPLICATE, &mut shell_token);
+        let _ = CloseHandle(process);
+        opened.map_err(|e| BalamError::platform("Failed to read desktop shell token", e))?;
+
+        let mut token = HANDLE::default();
+        let duplicated = DuplicateTokenEx(
+            shell_token,
+            TOKEN_QUERY | TOKEN_DUPLICATE | TOKEN_ASSIGN_PRIMARY | TOKEN_ADJUST_DEFAULT | TOKEN_ADJUST_SESSIONID,
+            None,
+            SecurityImpersonation,
+            TokenPrimary,
+            &mut token,
+        );
+        let _ = CloseHandle(shell_token);
+        duplicated.map_err(|e| BalamError::platform("Failed to duplicate desktop shell token", e))?;
+
+        let startup = STARTUPINFOW {
+            cb: std::mem::size_of::<STARTUPINFOW>() as u32,
+            ..Default::default()
+        };
+        let mut info = PROCESS_INFORMATION::default();
+        let created = CreateProcessWithTokenW(
+            token,
+            CREATE_PROCESS_LOGON_FLAGS(0),
+            PCWSTR(application.as_ptr()),
+            PWSTR(command_line.as_mut_ptr()),
+            PROCESS_CREATION_FLAGS(0),
+            None,
+            PCWSTR(current_dir.as_ptr()),
+            &startup,
+            &mut info,
+        );
+        let _ = CloseHandle(token);
+        created.map_err(|e| BalamError::platform("Failed to start process unelevated", e))?;
+        let _ = CloseHandle(info.hThread);
+        let _ = CloseHandle(info.hProcess);
+    }
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use super::signature::verify_release_signature;
+use crate::domain::update::{ReleaseManifest, UpdateArtifact};
+use crate::domain::BalamError;
+use crate::infrastructure::app_dirs;
+use crate::ports::ReleaseFeedPort;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Folder receiving downloaded packages, the update marker and the helper.
+///
+/// # Errors
+/// Returns `NotFound` when the user profile cannot be resolved: updates are
+/// never staged in a shared folder such as the working directory or temp.
+pub fn updates_dir() -> Result<PathBuf, BalamError> {
+    app_dirs::try_app_data_dir()
+        .map(|dir| dir.join("updates"))
+        .ok_or_else(|| BalamError::NotFound("App data folder".to_string()))
+}
+
+/// Release feed served over HTTPS (GitHub release assets by default).
+pub struct HttpReleaseFeed;
+
+impl HttpReleaseFeed {
+    fn client() -> Result<reqwest::blocking::Client, BalamError> {
+        reqwest::blocking::Client::builder()
+            // Installers are large; only the connection phase is bounded
+            .connect_timeout(Duration::from_secs(15))
+            .user_agent("BalamGridEngine/1.0")
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))
+    }
+
+    /// Streams the response into `path`, hashing as it goes.
+    fn stream_to_file(
+        artifact: &UpdateArtifact,
+        path: &Path,
+        on_progress: &dyn Fn(u64, u64),
+    ) -> Result<String, BalamError> {
+        let mut response = Self::client()?
+            .get(&artifact.url)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map_err(|e| BalamError::platform("Failed to download update", e))?;
+        let mut file = File::create(path).map_err(|e| BalamError::platform("Failed to write update", e))?;
+        let mut hasher = Sha256::new();
+        let mut buffer = vec![0u8; 64 * 1024];
+        let mut downloaded = 0u64;
+
+        loop {
+            let read = response
+                .read(&mut buffer)
+                .map_err(|e| BalamError::platform("Update download interrupted", e))?;
+            if read == 0 {
+                break;
+            }
+            downloaded += read as u64;
+            if downloaded > artifact.size {
+                return Err(BalamError::AccessDenied(format!(
+                    "Update is larger than announced ({} bytes)",
+                    artifact.size
+                )));
+            }
+            hasher.update(&buffer[..read]);
+            file.write_all(&buffer[..read])
+                .map_err(|e| BalamError::platform("Failed to write update", e))?;
+            on_progress(downloaded, artifact.size);
+        }
+
+        if downloaded != artifact.size {
+            return Err(BalamError::AccessDenied(format!(
+                "Update size mismatch: expected {} bytes, got {downloaded}",
+                artifact.size
+            )));
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+}
+
+/// Local file name for an artifact (last URL segment, query string dropped).
+fn file_name(url: &str) -> Option<&str> {
+    url.split(['?', '#'])
+        .next()?
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty() && !name.contains(['\\', ':']) && *name != "..")
+}
+
+impl ReleaseFeedPort for HttpReleaseFeed {
+    fn fetch_manifest(&self, feed_url: &str) -> Result<ReleaseManifest, BalamError> {
+        let body = Self::client()?
+            .get(feed_url)
+            .timeout(Duration::from_secs(30))
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::text)
+            .map_err(|e| BalamError::platform("Failed to fetch release feed", e))?;
+        serde_json::from_str(&body).map_err(|e| BalamError::InvalidArgument(format!("Invalid release feed: {e}")))
+    }
+
+    fn download(&self, artifact: &UpdateArtifact, on_progress: &dyn Fn(u64, u64)) -> Result<PathBuf, BalamError> {
+        let name = file_name(&artifact.url)
+            .ok_or_else(|| BalamError::InvalidArgument(format!("Invalid update URL: {}", artifact.url)))?;
+        let dir = updates_dir()?;
+        fs::create_dir_all(&dir).map_err(|e| BalamError::platform("Failed to create updates folder", e))?;
+        let path = dir.join(name);
+
+        info!("⬇️ Downloading update from {}", artifact.url);
+        let verified = Self::stream_to_file(artifact, &path, on_progress).and_then(|sha256| {
+            if !sha256.eq_ignore_ascii_case(&artifact.sha256) {
+                return Err(BalamError::AccessDenied("Update checksum does not match".to_string()));
+            }
+            let data = fs::read(&path).map_err(|e| BalamError::platform("Failed to read update", e))?;
+            verify_release_signature(&data, &artifact.signature)
+        });
+
+        if let Err(e) = verified {
+            warn!("🗑️ Discarding update package: {}", e);
+            let _ = fs::remove_file(&path);
+            return Err(e);
+        }
+        info!("✅ Update verified: {}", path.display());
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name_from_release_url() {
+        assert_eq!(
+            file_name("https://example.com/download/v1.3.0/balam-1.3.0-setup.exe?raw=1"),
+            Some("balam-1.3.0-setup.exe")
+        );
+        assert_eq!(file_name("https://example.com/download/"), None);
+    }
+}
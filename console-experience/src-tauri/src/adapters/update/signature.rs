@@ -0,0 +1,63 @@
+use crate::domain::BalamError;
+use minisign_verify::{PublicKey, Signature};
+use sha2::{Digest, Sha256};
+
+/// Minisign public key of the release pipeline, baked in at build time.
+///
+/// Local and CI test builds leave it unset; they can check for updates but
+/// refuse to install anything.
+const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("BALAM_UPDATE_PUBKEY");
+
+/// Checks `signature` (minisign text) over `data` with the release key.
+///
+/// # Errors
+/// Returns `AccessDenied` when no key is compiled in or the signature does
+/// not match, `InvalidArgument` when the signature cannot be parsed.
+pub fn verify_release_signature(data: &[u8], signature: &str) -> Result<(), BalamError> {
+    let key = RELEASE_PUBLIC_KEY
+        .ok_or_else(|| BalamError::AccessDenied("This build has no update signing key".to_string()))?;
+    verify(key, data, signature)
+}
+
+/// Checks a whole package: its SHA-256 (hex) first, then its signature.
+///
+/// # Errors
+/// Returns `AccessDenied` when either does not match (see
+/// [`verify_release_signature`]).
+pub fn verify_package(data: &[u8], sha256: &str, signature: &str) -> Result<(), BalamError> {
+    if !format!("{:x}", Sha256::digest(data)).eq_ignore_ascii_case(sha256) {
+        return Err(BalamError::AccessDenied("Update checksum does not match".to_string()));
+    }
+    verify_release_signature(data, signature)
+}
+
+fn verify(public_key: &str, data: &[u8], signature: &str) -> Result<(), BalamError> {
+    let key =
+        PublicKey::from_base64(public_key.trim()).map_err(|e| BalamError::platform("Invalid update signing key", e))?;
+    let signature = Signature::decode(signature)
+        .map_err(|e| BalamError::InvalidArgument(format!("Invalid update signature: {e}")))?;
+    key.verify(data, &signature, false)
+        .map_err(|_| BalamError::AccessDenied("Update signature does not match".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_checksum_checked_first() {
+        assert!(matches!(
+            verify_package(b"payload", "00", "not a signature"),
+            Err(BalamError::AccessDenied(message)) if message.contains("checksum")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_signature() {
+        let key = "RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3";
+        assert!(matches!(
+            verify(key, b"payload", "not a signature"),
+            Err(BalamError::InvalidArgument(_))
+        ));
+    }
+}
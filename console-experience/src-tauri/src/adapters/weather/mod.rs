@@ -0,0 +1,4 @@
+//! Weather for the home screen tile.
+mod open_meteo;
+
+pub use open_meteo::OpenMeteoWeather;
@@ -0,0 +1,59 @@
+use crate::domain::dashboard::{DashboardSettings, TemperatureUnit, WeatherCondition, WeatherProvider, WeatherReport};
+use crate::domain::BalamError;
+use crate::infrastructure::clock::now_secs;
+use crate::ports::WeatherPort;
+use serde::Deserialize;
+use std::time::Duration;
+
+const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+#[derive(Deserialize)]
+struct ForecastResponse {
+    current: CurrentWeather,
+}
+
+#[derive(Deserialize)]
+struct CurrentWeather {
+    temperature_2m: f64,
+    weather_code: u32,
+}
+
+/// Current weather from Open-Meteo (free, no API key, no account).
+pub struct OpenMeteoWeather;
+
+impl WeatherPort for OpenMeteoWeather {
+    fn current(&self, settings: &DashboardSettings) -> Result<WeatherReport, BalamError> {
+        if settings.weather_provider != WeatherProvider::OpenMeteo {
+            return Err(BalamError::NotSupported("Weather provider disabled".to_string()));
+        }
+        let unit = match settings.temperature_unit {
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit",
+        };
+
+        let response: ForecastResponse = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent("BalamGridEngine/1.0")
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))?
+            .get(FORECAST_URL)
+            .query(&[
+                ("latitude", settings.latitude.to_string()),
+                ("longitude", settings.longitude.to_string()),
+                ("current", "temperature_2m,weather_code".to_string()),
+                ("temperature_unit", unit.to_string()),
+            ])
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .and_then(reqwest::blocking::Response::json)
+            .map_err(|e| BalamError::platform("Failed to fetch weather", e))?;
+
+        Ok(WeatherReport {
+            temperature: response.current.temperature_2m,
+            unit: settings.temperature_unit,
+            condition: WeatherCondition::from_wmo_code(response.current.weather_code),
+            location: settings.location_name.clone(),
+            fetched_at_secs: now_secs(),
+        })
+    }
+}
@@ -0,0 +1,84 @@
+use crate::domain::wifi::Connectivity;
+use crate::domain::BalamError;
+use reqwest::header::LOCATION;
+use std::time::Duration;
+use tracing::info;
+
+/// The probe Windows' own network status indicator uses.
+const PROBE_URL: &str = "http://www.msftconnecttest.com/connecttest.txt";
+const PROBE_BODY: &str = "Microsoft Connect Test";
+
+/// Detects captive portals the way Windows NCSI does: fetch a known text
+/// file over plain HTTP and see whether something else answers.
+pub struct CaptivePortalProbe;
+
+impl CaptivePortalProbe {
+    /// Probes the current network.
+    ///
+    /// Blocking (network, up to 5s); call from a background thread.
+    ///
+    /// # Errors
+    /// Returns error if the HTTP client cannot be created.
+    pub fn probe() -> Result<Connectivity, BalamError> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(5))
+            // The redirect target is the portal URL we are looking for
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|e| BalamError::platform("Failed to create HTTP client", e))?;
+
+        let Ok(response) = client.get(PROBE_URL).send() else {
+            return Ok(Connectivity::NoInternet);
+        };
+        let status = response.status().as_u16();
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = response.text().unwrap_or_default();
+
+        let connectivity = classify(status, location.as_deref(), &body);
+        info!("🌐 Connectivity probe: {:?}", connectivity);
+        Ok(connectivity)
+    }
+}
+
+/// Interprets the probe response.
+fn classify(status: u16, location: Option<&str>, body: &str) -> Connectivity {
+    match status {
+        200 if body.trim() == PROBE_BODY => Connectivity::Online,
+        300..=399 => location.map_or(Connectivity::NoInternet, |url| Connectivity::CaptivePortal {
+            portal_url: url.to_string(),
+        }),
+        // Portals that answer with their login page directly (or a meta
+        // refresh) still open it when the probe URL is loaded in a browser
+        200 => Connectivity::CaptivePortal {
+            portal_url: PROBE_URL.to_string(),
+        },
+        _ => Connectivity::NoInternet,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_probe_responses() {
+        assert_eq!(classify(200, None, "Microsoft Connect Test"), Connectivity::Online);
+        assert_eq!(
+            classify(302, Some("http://login.hotel.example/?mac=aa"), ""),
+            Connectivity::CaptivePortal {
+                portal_url: "http://login.hotel.example/?mac=aa".to_string()
+            }
+        );
+        assert_eq!(
+            classify(200, None, "<html>Welcome guest</html>"),
+            Connectivity::CaptivePortal {
+                portal_url: PROBE_URL.to_string()
+            }
+        );
+        assert_eq!(classify(503, None, ""), Connectivity::NoInternet);
+    }
+}
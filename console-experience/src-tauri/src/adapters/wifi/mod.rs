@@ -1,3 +1,6 @@
+mod captive_portal;
 mod windows_wifi_adapter;
+mod wlan_profile;
 
+pub use captive_portal::CaptivePortalProbe;
 pub use windows_wifi_adapter::WindowsWiFiAdapter;
@@ -1,4 +1,7 @@
+use super::wlan_profile;
 use crate::domain::wifi::WiFiStrengthConfig;
+use crate::domain::BalamError;
+use crate::infrastructure::encoding::to_wide;
 use crate::ports::wifi_port::{WiFiConfig, WiFiNetwork, WiFiPort, WiFiSecurity};
 use std::ffi::c_void;
 use std::ptr;
@@ -6,14 +9,29 @@ use std::sync::{Arc, Condvar, Mutex};
 use std::time::Duration;
 use tracing::{error, info, warn};
 use windows::core::PCWSTR;
-use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+use windows::Win32::Foundation::{
+    ERROR_ACCESS_DENIED, ERROR_INVALID_PARAMETER, ERROR_NOT_FOUND, ERROR_SERVICE_NOT_ACTIVE, ERROR_SUCCESS, HANDLE,
+};
 use windows::Win32::NetworkManagement::WiFi::{
     WlanCloseHandle, WlanConnect, WlanDeleteProfile, WlanDisconnect, WlanEnumInterfaces, WlanFreeMemory,
     WlanGetAvailableNetworkList, WlanGetProfileList, WlanOpenHandle, WlanRegisterNotification, WlanScan,
-    DOT11_BSS_TYPE, L2_NOTIFICATION_DATA, WLAN_AVAILABLE_NETWORK, WLAN_CONNECTION_MODE, WLAN_CONNECTION_PARAMETERS,
-    WLAN_INTERFACE_INFO_LIST, WLAN_NOTIFICATION_SOURCE_ACM, WLAN_PROFILE_INFO_LIST,
+    WlanSetProfile, WlanSetProfileEapXmlUserData, DOT11_BSS_TYPE, L2_NOTIFICATION_DATA, WLAN_AVAILABLE_NETWORK,
+    WLAN_CONNECTION_MODE, WLAN_CONNECTION_PARAMETERS, WLAN_INTERFACE_INFO_LIST, WLAN_NOTIFICATION_SOURCE_ACM,
+    WLAN_PROFILE_INFO_LIST, WLAN_SET_EAPHOST_FLAGS,
 };
 
+/// Maps a `WlanAPI` return code to a `BalamError`.
+fn wlan_error(operation: &str, code: u32) -> BalamError {
+    match code {
+        c if c == ERROR_ACCESS_DENIED.0 => BalamError::AccessDenied(operation.to_string()),
+        c if c == ERROR_NOT_FOUND.0 => BalamError::NotFound(operation.to_string()),
+        c if c == ERROR_INVALID_PARAMETER.0 => BalamError::InvalidArgument(operation.to_string()),
+        // WLAN AutoConfig service stopped: no usable Wi-Fi on this machine
+        c if c == ERROR_SERVICE_NOT_ACTIVE.0 => BalamError::WifiUnavailable,
+        _ => BalamError::Platform(format!("{operation}: error {code}")),
+    }
+}
+
 /// Notification context shared between callback and adapter.
 struct NotificationContext {
     scan_complete: Condvar,
@@ -100,7 +118,7 @@ impl WindowsWiFiAdapter {
     ///
     /// # Errors
     /// Returns error if `WlanAPI` initialization or notification registration fails.
-    pub fn new() -> Result<Self, String> {
+    pub fn new() -> Result<Self, BalamError> {
         let mut client_handle = HANDLE::default();
         let mut negotiated_version = 0u32;
 
@@ -114,7 +132,7 @@ impl WindowsWiFiAdapter {
             );
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to open WLAN handle: error {result}"));
+                return Err(wlan_error("Failed to open WLAN handle", result));
             }
 
             // Create notification context
@@ -137,7 +155,7 @@ impl WindowsWiFiAdapter {
 
             if result != ERROR_SUCCESS.0 {
                 let _ = WlanCloseHandle(client_handle, None);
-                return Err(format!("Failed to register WiFi notifications: error {result}"));
+                return Err(wlan_error("Failed to register WiFi notifications", result));
             }
 
             info!("WlanAPI initialized with event-driven notifications");
@@ -152,23 +170,24 @@ impl WindowsWiFiAdapter {
     ///
     /// # Errors
     /// Returns error if no `WiFi` interfaces found.
-    fn get_interface_guid(&self) -> Result<windows::core::GUID, String> {
+    fn get_interface_guid(&self) -> Result<windows::core::GUID, BalamError> {
         let mut interface_list: *mut WLAN_INTERFACE_INFO_LIST = ptr::null_mut();
 
         unsafe {
             let result = WlanEnumInterfaces(self.client_handle, None, &mut interface_list);
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to enumerate interfaces: error {result}"));
+                return Err(wlan_error("Failed to enumerate interfaces", result));
             }
 
             if interface_list.is_null() {
-                return Err("No WiFi interfaces found".to_string());
+                return Err(BalamError::WifiUnavailable);
             }
 
             let interfaces = &*interface_list;
             if interfaces.dwNumberOfItems == 0 {
-                return Err("No WiFi interfaces available".to_string());
+                WlanFreeMemory(interface_list as *mut _);
+                return Err(BalamError::WifiUnavailable);
             }
 
             // Get first interface
@@ -194,26 +213,7 @@ impl WindowsWiFiAdapter {
         let signal_quality = network.wlanSignalQuality;
         let signal_strength = -100 + ((signal_quality as i32 * 70) / 100);
 
-        // Determine security type
-        let auth_algo = network.dot11DefaultAuthAlgorithm.0;
-        info!("Network: {} - Auth Algorithm: {}", ssid, auth_algo);
-
-        let security = match auth_algo {
-            0 => WiFiSecurity::Open, // DOT11_AUTH_ALGO_80211_OPEN
-            1 => WiFiSecurity::WEP,  // DOT11_AUTH_ALGO_80211_SHARED_KEY
-            2 => WiFiSecurity::WPA,  // DOT11_AUTH_ALGO_WPA
-            3 => WiFiSecurity::WPA,  // DOT11_AUTH_ALGO_WPA_PSK
-            4 => WiFiSecurity::WPA2, // DOT11_AUTH_ALGO_RSNA
-            5 => WiFiSecurity::WPA2, // DOT11_AUTH_ALGO_RSNA_PSK
-            6 => WiFiSecurity::WPA2, // DOT11_AUTH_ALGO_WPA_NONE (sometimes used)
-            7 => WiFiSecurity::WPA2, // DOT11_AUTH_ALGO_RSNA_PSK_PREP
-            8 => WiFiSecurity::WPA3, // DOT11_AUTH_ALGO_WPA3
-            9 => WiFiSecurity::WPA3, // DOT11_AUTH_ALGO_WPA3_SAE
-            _ => {
-                info!("Unknown auth algorithm {} for network {}", auth_algo, ssid);
-                WiFiSecurity::Unknown
-            },
-        };
+        let security = security_from_auth_algorithm(network.dot11DefaultAuthAlgorithm.0);
 
         // Check if connected
         let is_connected = (network.dwFlags & 0x0000_0001) != 0; // WLAN_AVAILABLE_NETWORK_CONNECTED
@@ -227,6 +227,79 @@ impl WindowsWiFiAdapter {
             is_connected,
         }
     }
+
+    /// Creates (or replaces) the profile for `config.ssid`.
+    fn save_profile(
+        &self,
+        interface_guid: &windows::core::GUID,
+        config: &WiFiConfig,
+        security: &WiFiSecurity,
+    ) -> Result<(), BalamError> {
+        let enterprise = wlan_profile::is_enterprise(security);
+        let username = config.username.as_deref().filter(|user| !user.is_empty());
+        if enterprise && username.is_none() {
+            return Err(BalamError::InvalidArgument(format!(
+                "{} needs a username and password",
+                config.ssid
+            )));
+        }
+
+        let profile_xml = wlan_profile::profile_xml(&config.ssid, security, &config.password, config.auto_connect)?;
+        let profile_xml = to_wide(&profile_xml);
+        let mut reason_code = 0u32;
+        unsafe {
+            let result = WlanSetProfile(
+                self.client_handle,
+                interface_guid,
+                0,
+                PCWSTR(profile_xml.as_ptr()),
+                PCWSTR::null(),
+                true, // Overwrite: new credentials for a known network
+                None,
+                &mut reason_code,
+            );
+            if result != ERROR_SUCCESS.0 {
+                error!("WlanSetProfile failed: error {} (reason {})", result, reason_code);
+                return Err(wlan_error("Failed to save network profile", result));
+            }
+
+            if let Some(username) = username {
+                let profile_name = to_wide(&config.ssid);
+                let user_xml = to_wide(&wlan_profile::eap_user_xml(username, &config.password));
+                let result = WlanSetProfileEapXmlUserData(
+                    self.client_handle,
+                    interface_guid,
+                    PCWSTR(profile_name.as_ptr()),
+                    WLAN_SET_EAPHOST_FLAGS(0),
+                    PCWSTR(user_xml.as_ptr()),
+                    None,
+                );
+                if result != ERROR_SUCCESS.0 {
+                    return Err(wlan_error("Failed to save enterprise credentials", result));
+                }
+            }
+        }
+
+        info!("Saved {:?} profile for: {}", security, config.ssid);
+        Ok(())
+    }
+}
+
+/// Maps `DOT11_AUTH_ALGORITHM` to the security shown in the UI.
+fn security_from_auth_algorithm(auth_algo: i32) -> WiFiSecurity {
+    match auth_algo {
+        1 => WiFiSecurity::Open,                // DOT11_AUTH_ALGO_80211_OPEN
+        2 => WiFiSecurity::WEP,                 // DOT11_AUTH_ALGO_80211_SHARED_KEY
+        3 | 4 => WiFiSecurity::WPA,             // DOT11_AUTH_ALGO_WPA / WPA_PSK
+        6 => WiFiSecurity::WPA2Enterprise,      // DOT11_AUTH_ALGO_RSNA (802.1X)
+        7 => WiFiSecurity::WPA2,                // DOT11_AUTH_ALGO_RSNA_PSK
+        8 | 11 => WiFiSecurity::WPA3Enterprise, // DOT11_AUTH_ALGO_WPA3 (192-bit) / WPA3_ENT
+        9 => WiFiSecurity::WPA3,                // DOT11_AUTH_ALGO_WPA3_SAE
+        _ => {
+            info!("Unknown auth algorithm {}", auth_algo);
+            WiFiSecurity::Unknown
+        },
+    }
 }
 
 impl Drop for WindowsWiFiAdapter {
@@ -251,12 +324,12 @@ impl Drop for WindowsWiFiAdapter {
 }
 
 impl WiFiPort for WindowsWiFiAdapter {
-    fn get_current_network(&self) -> Result<Option<WiFiNetwork>, String> {
+    fn get_current_network(&self) -> Result<Option<WiFiNetwork>, BalamError> {
         let networks = self.scan_networks()?;
         Ok(networks.into_iter().find(|n| n.is_connected))
     }
 
-    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String> {
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, BalamError> {
         info!("Scanning WiFi networks using event-driven WlanAPI...");
 
         let interface_guid = self.get_interface_guid()?;
@@ -268,7 +341,7 @@ impl WiFiPort for WindowsWiFiAdapter {
                     .notification_context
                     .scan_ready
                     .lock()
-                    .map_err(|e| format!("Lock poisoned: {e}"))?;
+                    .map_err(|e| BalamError::platform("Lock poisoned", e))?;
                 *ready = false;
             }
 
@@ -283,13 +356,13 @@ impl WiFiPort for WindowsWiFiAdapter {
                     .notification_context
                     .scan_ready
                     .lock()
-                    .map_err(|e| format!("Lock poisoned: {e}"))?;
+                    .map_err(|e| BalamError::platform("Lock poisoned", e))?;
 
                 let (ready, timeout_result) = self
                     .notification_context
                     .scan_complete
                     .wait_timeout(ready, Duration::from_secs(10))
-                    .map_err(|e| format!("Wait failed: {e}"))?;
+                    .map_err(|e| BalamError::platform("Wait failed", e))?;
 
                 if timeout_result.timed_out() {
                     warn!("Scan notification timeout (10s), using cached results");
@@ -310,7 +383,7 @@ impl WiFiPort for WindowsWiFiAdapter {
             );
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to get network list: error {result}"));
+                return Err(wlan_error("Failed to get network list", result));
             }
 
             if network_list.is_null() {
@@ -359,14 +432,26 @@ impl WiFiPort for WindowsWiFiAdapter {
         }
     }
 
-    fn connect_network(&self, config: WiFiConfig) -> Result<(), String> {
+    fn connect_network(&self, config: WiFiConfig) -> Result<(), BalamError> {
         info!("Connecting to WiFi: {}", config.ssid);
 
         let interface_guid = self.get_interface_guid()?;
 
-        // Convert SSID to wide string
-        let mut profile_name: Vec<u16> = config.ssid.encode_utf16().collect();
-        profile_name.push(0); // Null terminator
+        let has_profile = self.get_saved_networks()?.contains(&config.ssid);
+        if !has_profile || !config.password.is_empty() || config.username.is_some() {
+            let security = match &config.security {
+                Some(security) => security.clone(),
+                None => self
+                    .scan_networks()?
+                    .into_iter()
+                    .find(|network| network.ssid == config.ssid)
+                    .map(|network| network.security)
+                    .ok_or_else(|| BalamError::NotFound(format!("WiFi network {}", config.ssid)))?,
+            };
+            self.save_profile(&interface_guid, &config, &security)?;
+        }
+
+        let profile_name = to_wide(&config.ssid);
 
         unsafe {
             let connection_params = WLAN_CONNECTION_PARAMETERS {
@@ -381,7 +466,7 @@ impl WiFiPort for WindowsWiFiAdapter {
             let result = WlanConnect(self.client_handle, &interface_guid, &connection_params, None);
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to connect: error {result}"));
+                return Err(wlan_error("Failed to connect", result));
             }
         }
 
@@ -389,7 +474,7 @@ impl WiFiPort for WindowsWiFiAdapter {
         Ok(())
     }
 
-    fn disconnect(&self) -> Result<(), String> {
+    fn disconnect(&self) -> Result<(), BalamError> {
         info!("Disconnecting from WiFi...");
 
         let interface_guid = self.get_interface_guid()?;
@@ -398,7 +483,7 @@ impl WiFiPort for WindowsWiFiAdapter {
             let result = WlanDisconnect(self.client_handle, &interface_guid, None);
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to disconnect: error {result}"));
+                return Err(wlan_error("Failed to disconnect", result));
             }
         }
 
@@ -406,20 +491,18 @@ impl WiFiPort for WindowsWiFiAdapter {
         Ok(())
     }
 
-    fn forget_network(&self, ssid: &str) -> Result<(), String> {
+    fn forget_network(&self, ssid: &str) -> Result<(), BalamError> {
         info!("Forgetting network: {}", ssid);
 
         let interface_guid = self.get_interface_guid()?;
 
-        // Convert SSID to wide string
-        let mut profile_name: Vec<u16> = ssid.encode_utf16().collect();
-        profile_name.push(0);
+        let profile_name = to_wide(ssid);
 
         unsafe {
             let result = WlanDeleteProfile(self.client_handle, &interface_guid, PCWSTR(profile_name.as_ptr()), None);
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to forget network: error {result}"));
+                return Err(wlan_error("Failed to forget network", result));
             }
         }
 
@@ -427,7 +510,7 @@ impl WiFiPort for WindowsWiFiAdapter {
         Ok(())
     }
 
-    fn get_saved_networks(&self) -> Result<Vec<String>, String> {
+    fn get_saved_networks(&self) -> Result<Vec<String>, BalamError> {
         let interface_guid = self.get_interface_guid()?;
         let mut profile_list: *mut WLAN_PROFILE_INFO_LIST = ptr::null_mut();
 
@@ -435,7 +518,7 @@ impl WiFiPort for WindowsWiFiAdapter {
             let result = WlanGetProfileList(self.client_handle, &interface_guid, None, &mut profile_list);
 
             if result != ERROR_SUCCESS.0 {
-                return Err(format!("Failed to get profile list: error {result}"));
+                return Err(wlan_error("Failed to get profile list", result));
             }
 
             if profile_list.is_null() {
@@ -465,7 +548,7 @@ impl WiFiPort for WindowsWiFiAdapter {
         }
     }
 
-    fn get_signal_strength(&self) -> Result<Option<u32>, String> {
+    fn get_signal_strength(&self) -> Result<Option<u32>, BalamError> {
         let current = self.get_current_network()?;
         Ok(current.map(|net| {
             // Convert dBm to percentage
@@ -485,6 +568,15 @@ mod tests {
         assert!(result.is_ok() || result.is_err());
     }
 
+    #[test]
+    fn test_security_from_auth_algorithm() {
+        assert_eq!(security_from_auth_algorithm(1), WiFiSecurity::Open);
+        assert_eq!(security_from_auth_algorithm(7), WiFiSecurity::WPA2);
+        assert_eq!(security_from_auth_algorithm(6), WiFiSecurity::WPA2Enterprise);
+        assert_eq!(security_from_auth_algorithm(9), WiFiSecurity::WPA3);
+        assert_eq!(security_from_auth_algorithm(42), WiFiSecurity::Unknown);
+    }
+
     #[test]
     fn test_scan_networks() {
         if let Ok(adapter) = WindowsWiFiAdapter::new() {
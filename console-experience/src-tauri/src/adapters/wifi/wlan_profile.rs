@@ -0,0 +1,186 @@
+//! WLAN profile XML for networks that have never been joined.
+//!
+//! Schemas: <https://learn.microsoft.com/windows/win32/nativewifi/wlan-profileschema-elements>
+//! and the EAP-PEAP/MSCHAPv2 samples of the `EapHostConfig` schema.
+
+use crate::domain::BalamError;
+use crate::ports::wifi_port::WiFiSecurity;
+
+/// `authentication` / `encryption` pair of the profile for a security type.
+fn auth_encryption(security: &WiFiSecurity) -> Result<(&'static str, &'static str), BalamError> {
+    match security {
+        WiFiSecurity::Open => Ok(("open", "none")),
+        WiFiSecurity::WPA => Ok(("WPAPSK", "TKIP")),
+        WiFiSecurity::WPA2 => Ok(("WPA2PSK", "AES")),
+        WiFiSecurity::WPA3 => Ok(("WPA3SAE", "AES")),
+        WiFiSecurity::WPA2Enterprise => Ok(("WPA2", "AES")),
+        WiFiSecurity::WPA3Enterprise => Ok(("WPA3ENT", "AES")),
+        WiFiSecurity::WEP | WiFiSecurity::Unknown => {
+            Err(BalamError::NotSupported(format!("Joining {security:?} networks")))
+        },
+    }
+}
+
+/// Whether the network authenticates users with 802.1X instead of a passphrase.
+#[must_use]
+pub fn is_enterprise(security: &WiFiSecurity) -> bool {
+    matches!(security, WiFiSecurity::WPA2Enterprise | WiFiSecurity::WPA3Enterprise)
+}
+
+/// Profile XML for `WlanSetProfile`, named after the SSID.
+///
+/// Enterprise networks get PEAP with MSCHAPv2; the credentials are stored
+/// separately ([`eap_user_xml`]). Server certificate validation stays on,
+/// so Windows asks once to trust an unknown RADIUS server.
+///
+/// # Errors
+/// Returns `NotSupported` for WEP and unknown security, `InvalidArgument`
+/// for a passphrase outside 8-63 characters.
+pub fn profile_xml(
+    ssid: &str,
+    security: &WiFiSecurity,
+    passphrase: &str,
+    auto_connect: bool,
+) -> Result<String, BalamError> {
+    let (authentication, encryption) = auth_encryption(security)?;
+    let enterprise = is_enterprise(security);
+    let needs_key = !enterprise && *security != WiFiSecurity::Open;
+    if needs_key && !(8..=63).contains(&passphrase.chars().count()) {
+        return Err(BalamError::InvalidArgument(
+            "WiFi password must be 8-63 characters".to_string(),
+        ));
+    }
+
+    let name = escape(ssid);
+    let hex: String = ssid.bytes().map(|byte| format!("{byte:02X}")).collect();
+    let mode = if auto_connect { "auto" } else { "manual" };
+    let shared_key = if needs_key {
+        format!(
+            "<sharedKey><keyType>passPhrase</keyType><protected>false</protected>\
+             <keyMaterial>{}</keyMaterial></sharedKey>",
+            escape(passphrase)
+        )
+    } else {
+        String::new()
+    };
+    let one_x = if enterprise { PEAP_ONE_X } else { "" };
+
+    Ok(format!(
+        "<?xml version=\"1.0\"?>\
+         <WLANProfile xmlns=\"http://www.microsoft.com/networking/WLAN/profile/v1\">\
+         <name>{name}</name>\
+         <SSIDConfig><SSID><hex>{hex}</hex><name>{name}</name></SSID></SSIDConfig>\
+         <connectionType>ESS</connectionType>\
+         <connectionMode>{mode}</connectionMode>\
+         <MSM><security>\
+         <authEncryption><authentication>{authentication}</authentication>\
+         <encryption>{encryption}</encryption><useOneX>{enterprise}</useOneX></authEncryption>\
+         {shared_key}{one_x}\
+         </security></MSM>\
+         </WLANProfile>"
+    ))
+}
+
+/// PEAP (EAP type 25) with inner MSCHAPv2 (type 26), user authentication.
+const PEAP_ONE_X: &str = "<OneX xmlns=\"http://www.microsoft.com/networking/OneX/v1\">\
+    <authMode>user</authMode>\
+    <EAPConfig><EapHostConfig xmlns=\"http://www.microsoft.com/provisioning/EapHostConfig\">\
+    <EapMethod>\
+    <Type xmlns=\"http://www.microsoft.com/provisioning/EapCommon\">25</Type>\
+    <VendorId xmlns=\"http://www.microsoft.com/provisioning/EapCommon\">0</VendorId>\
+    <VendorType xmlns=\"http://www.microsoft.com/provisioning/EapCommon\">0</VendorType>\
+    <AuthorId xmlns=\"http://www.microsoft.com/provisioning/EapCommon\">0</AuthorId>\
+    </EapMethod>\
+    <Config xmlns=\"http://www.microsoft.com/provisioning/EapHostConfig\">\
+    <Eap xmlns=\"http://www.microsoft.com/provisioning/BaseEapConnectionPropertiesV1\"><Type>25</Type>\
+    <EapType xmlns=\"http://www.microsoft.com/provisioning/MsPeapConnectionPropertiesV1\">\
+    <ServerValidation><DisableUserPromptForServerValidation>false</DisableUserPromptForServerValidation>\
+    <ServerNames></ServerNames></ServerValidation>\
+    <FastReconnect>true</FastReconnect><InnerEapOptional>false</InnerEapOptional>\
+    <Eap xmlns=\"http://www.microsoft.com/provisioning/BaseEapConnectionPropertiesV1\"><Type>26</Type>\
+    <EapType xmlns=\"http://www.microsoft.com/provisioning/MsChapV2ConnectionPropertiesV1\">\
+    <UseWinLogonCredentials>false</UseWinLogonCredentials></EapType></Eap>\
+    <EnableQuarantineChecks>false</EnableQuarantineChecks><RequireCryptoBinding>false</RequireCryptoBinding>\
+    </EapType></Eap>\
+    </Config></EapHostConfig></EAPConfig>\
+    </OneX>";
+
+/// PEAP-MSCHAPv2 credentials for `WlanSetProfileEapXmlUserData`.
+///
+/// `DOMAIN\user` usernames are split into logon domain and user.
+#[must_use]
+pub fn eap_user_xml(username: &str, password: &str) -> String {
+    let (domain, user) = username.split_once('\\').unwrap_or(("", username));
+    let (domain, user, password, identity) = (escape(domain), escape(user), escape(password), escape(username));
+
+    format!(
+        "<?xml version=\"1.0\"?>\
+         <EapHostUserCredentials xmlns=\"http://www.microsoft.com/provisioning/EapHostUserCredentials\" \
+         xmlns:eapCommon=\"http://www.microsoft.com/provisioning/EapCommon\" \
+         xmlns:baseEap=\"http://www.microsoft.com/provisioning/BaseEapMethodUserCredentials\">\
+         <EapMethod><eapCommon:Type>25</eapCommon:Type><eapCommon:AuthorId>0</eapCommon:AuthorId></EapMethod>\
+         <Credentials xmlns:eapUser=\"http://www.microsoft.com/provisioning/EapUserPropertiesV1\" \
+         xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" \
+         xmlns:baseEap=\"http://www.microsoft.com/provisioning/BaseEapUserPropertiesV1\" \
+         xmlns:MsPeap=\"http://www.microsoft.com/provisioning/MsPeapUserPropertiesV1\" \
+         xmlns:MsChapV2=\"http://www.microsoft.com/provisioning/MsChapV2UserPropertiesV1\">\
+         <baseEap:Eap><baseEap:Type>25</baseEap:Type>\
+         <MsPeap:EapType><MsPeap:RoutingIdentity>{identity}</MsPeap:RoutingIdentity>\
+         <baseEap:Eap><baseEap:Type>26</baseEap:Type>\
+         <MsChapV2:EapType><MsChapV2:Username>{user}</MsChapV2:Username>\
+         <MsChapV2:Password>{password}</MsChapV2:Password>\
+         <MsChapV2:LogonDomain>{domain}</MsChapV2:LogonDomain></MsChapV2:EapType>\
+         </baseEap:Eap></MsPeap:EapType></baseEap:Eap>\
+         </Credentials></EapHostUserCredentials>"
+    )
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_psk_profile_escapes_and_hex_encodes_ssid() {
+        let xml = profile_xml("Café & Co", &WiFiSecurity::WPA2, "p<ss>word", true).unwrap();
+        assert!(xml.contains("<name>Café &amp; Co</name>"));
+        assert!(xml.contains("<hex>436166C3A92026204366</hex>"));
+        assert!(xml.contains("<authentication>WPA2PSK</authentication>"));
+        assert!(xml.contains("<keyMaterial>p&lt;ss&gt;word</keyMaterial>"));
+        assert!(xml.contains("<connectionMode>auto</connectionMode>"));
+    }
+
+    #[test]
+    fn test_profile_per_security() {
+        let open = profile_xml("Lobby", &WiFiSecurity::Open, "", false).unwrap();
+        assert!(open.contains("<encryption>none</encryption>"));
+        assert!(!open.contains("sharedKey"));
+
+        let sae = profile_xml("Home", &WiFiSecurity::WPA3, "correct horse", true).unwrap();
+        assert!(sae.contains("<authentication>WPA3SAE</authentication>"));
+
+        let campus = profile_xml("eduroam", &WiFiSecurity::WPA2Enterprise, "", true).unwrap();
+        assert!(campus.contains("<useOneX>true</useOneX>"));
+        assert!(campus.contains("MsPeapConnectionPropertiesV1"));
+        assert!(!campus.contains("sharedKey"));
+
+        assert!(profile_xml("Home", &WiFiSecurity::WPA2, "short", true).is_err());
+        assert!(profile_xml("Old", &WiFiSecurity::WEP, "12345678", true).is_err());
+    }
+
+    #[test]
+    fn test_eap_user_splits_domain() {
+        let xml = eap_user_xml("CORP\\alex", "s3cret&");
+        assert!(xml.contains("<MsChapV2:Username>alex</MsChapV2:Username>"));
+        assert!(xml.contains("<MsChapV2:LogonDomain>CORP</MsChapV2:LogonDomain>"));
+        assert!(xml.contains("<MsChapV2:Password>s3cret&amp;</MsChapV2:Password>"));
+    }
+}
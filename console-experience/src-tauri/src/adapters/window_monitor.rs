@@ -1,29 +1,18 @@
-/// Process Monitor Adapter (WMI-based)
+/// Launcher Process Monitor
 ///
-/// Professional-grade process monitoring using Windows Management Instrumentation.
-/// Event-driven approach with 0% CPU overhead when idle.
+/// Watches launcher processes (Steam, Epic, ...) in the system-wide process
+/// events and reports them to the frontend. A launcher that exits within
+/// seconds of starting usually failed (update loop, login error).
 ///
-/// Architecture: Adapter Layer (Windows WMI → Domain Events)
-///
-/// Based on industry best practices from:
-/// - Playnite game launcher
-/// - Microsoft WMI Performance Guidelines
-/// - `ETW`/WMI event-driven monitoring
-///
-/// # Performance
-/// - Event-driven (no polling)
-/// - <1ms latency for process events
-/// - 0% CPU overhead when no events
-/// - Scalable to thousands of processes
+/// Architecture: Adapter Layer (process events → frontend events). The
+/// events come from whichever `ProcessEventsPort` source is available.
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
-use std::thread;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter};
-use tracing::{debug, error, info, warn};
-use wmi::WMIConnection;
+use tracing::{debug, error, warn};
 
-use crate::application::ActiveGamesTracker;
+use crate::ports::ProcessEvent;
 
 // =============================================================================
 // CONSTANTS
@@ -48,92 +37,77 @@ const LAUNCHER_PROCESSES: &[&str] = &[
     "LeagueClient.exe",
 ];
 
-// =============================================================================
-// PROCESS EVENT STRUCTURES
-// =============================================================================
-
-/// Process start event from WMI
-#[derive(serde::Deserialize, Debug)]
-#[serde(rename = "Win32_ProcessStartTrace")]
-#[serde(rename_all = "PascalCase")]
-struct ProcessStartTrace {
-    #[serde(rename = "ProcessID")]
-    process_id: u32,
-    process_name: String,
-}
-
-/// Process stop event from WMI
-#[derive(serde::Deserialize, Debug)]
-#[serde(rename = "Win32_ProcessStopTrace")]
-#[serde(rename_all = "PascalCase")]
-struct ProcessStopTrace {
-    #[serde(rename = "ProcessID")]
-    process_id: u32,
-    #[allow(dead_code)]
-    process_name: String,
-}
-
 // =============================================================================
 // PROCESS MONITOR
 // =============================================================================
 
-/// Process Monitor Handle
+/// Launcher process monitor
 ///
-/// Monitors launcher processes using WMI events (event-driven, no polling).
 /// Detects when launcher processes start and exit quickly (indicating errors).
 pub struct WindowMonitor {
-    tracker: Arc<ActiveGamesTracker>,
     app_handle: AppHandle,
-    monitor_thread: Option<thread::JoinHandle<()>>,
+    tracker: ProcessTracker,
 }
 
 impl WindowMonitor {
     /// Create new process monitor
     #[must_use]
-    pub fn new(tracker: Arc<ActiveGamesTracker>, app_handle: AppHandle) -> Self {
+    pub fn new(app_handle: AppHandle) -> Self {
         Self {
-            tracker,
             app_handle,
-            monitor_thread: None,
+            tracker: ProcessTracker::new(),
         }
     }
 
-    /// Start monitoring process events
-    ///
-    /// Launches background thread that listens to WMI process events.
-    /// Event-driven approach with 0% CPU overhead when idle.
-    pub fn start(&mut self) -> Result<(), String> {
-        if self.monitor_thread.is_some() {
-            return Err("Process monitor already running".to_string());
-        }
-
-        info!("Starting WMI process monitor (event-driven, 0% CPU overhead)...");
-
-        let app_handle = self.app_handle.clone();
-        let tracker = self.tracker.clone();
-
-        // Launch monitoring thread
-        let monitor = thread::spawn(move || {
-            if let Err(e) = run_process_monitor(app_handle, tracker) {
-                error!("Process monitor error: {}", e);
-            }
-        });
-
-        self.monitor_thread = Some(monitor);
-        info!("WMI process monitor started successfully (Level 3 Enhanced)");
-
-        Ok(())
-    }
-
-    /// Stop monitoring process events
-    pub fn stop(&mut self) {
-        // Note: WMI notification loop will exit on its own
-        // We can't force-stop it gracefully, but it will exit when app closes
-        if let Some(handle) = self.monitor_thread.take() {
-            // Just detach the thread, it will exit naturally
-            drop(handle);
+    /// Handles one process event (called from the process event source).
+    pub fn on_process_event(&self, event: &ProcessEvent) {
+        match event {
+            ProcessEvent::Started {
+                pid,
+                executable_path: Some(path),
+            } => {
+                let process_name = path.rsplit(['\\', '/']).next().unwrap_or(path);
+                if let Some(launcher) = Self::is_launcher_process(process_name) {
+                    debug!("Launcher process started: {} (PID: {})", launcher, pid);
+
+                    self.tracker.track_process(*pid, launcher.to_string());
+
+                    // Emit event to frontend
+                    if let Err(e) = self.app_handle.emit("launcher-process-started", &launcher) {
+                        error!("Failed to emit launcher-process-started: {}", e);
+                    }
+                }
+            },
+            ProcessEvent::Started { .. } => {},
+            ProcessEvent::Exited { pid } => {
+                if let Some((launcher, runtime)) = self.tracker.process_exited(*pid) {
+                    let runtime_secs = runtime.as_secs();
+
+                    debug!(
+                        "Launcher process stopped: {} (PID: {}, Runtime: {}s)",
+                        launcher, pid, runtime_secs
+                    );
+
+                    // Check for quick exit (likely error)
+                    if runtime_secs < QUICK_EXIT_THRESHOLD_SECONDS {
+                        warn!(
+                            "Quick exit detected: {} exited after {}s (threshold: {}s)",
+                            launcher, runtime_secs, QUICK_EXIT_THRESHOLD_SECONDS
+                        );
+
+                        // Emit error event to frontend
+                        if let Err(e) = self.app_handle.emit("launcher-quick-exit", &launcher) {
+                            error!("Failed to emit launcher-quick-exit: {}", e);
+                        }
+                    } else {
+                        // Normal exit
+                        if let Err(e) = self.app_handle.emit("launcher-process-stopped", &launcher) {
+                            error!("Failed to emit launcher-process-stopped: {}", e);
+                        }
+                    }
+                }
+            },
         }
-        info!("Process monitor stopped");
     }
 
     /// Check if a process is a launcher we care about
@@ -160,14 +134,8 @@ impl WindowMonitor {
     }
 }
 
-impl Drop for WindowMonitor {
-    fn drop(&mut self) {
-        self.stop();
-    }
-}
-
 // =============================================================================
-// WMI PROCESS MONITORING
+// LAUNCHER TRACKING
 // =============================================================================
 
 /// Track launcher child processes
@@ -210,139 +178,6 @@ impl ProcessTracker {
     }
 }
 
-/// Run WMI process monitor (event-driven)
-///
-/// This is the professional approach used by game launchers like Playnite.
-/// Uses WMI events instead of polling for maximum performance.
-fn run_process_monitor(
-    app_handle: AppHandle,
-    _tracker: Arc<ActiveGamesTracker>,
-) -> Result<(), Box<dyn std::error::Error>> {
-    info!("Initializing WMI process monitoring...");
-
-    // Process tracker (shared between threads)
-    let tracker = Arc::new(ProcessTracker::new());
-
-    // Clone for threads
-    let app_handle_start = app_handle.clone();
-    let tracker_start = tracker.clone();
-
-    // Thread 1: Listen for process starts
-    let start_thread = thread::spawn(move || {
-        info!("Initializing WMI connection for ProcessStartTrace...");
-
-        // Create WMI connection in this thread (not Send-safe)
-        let wmi_con = match WMIConnection::new() {
-            Ok(conn) => conn,
-            Err(e) => {
-                error!("Failed to create WMI connection in start thread: {:?}", e);
-                return;
-            },
-        };
-
-        info!("WMI ProcessStartTrace listener active");
-
-        match wmi_con.notification::<ProcessStartTrace>() {
-            Ok(iterator) => {
-                for result in iterator {
-                    match result {
-                        Ok(event) => {
-                            if let Some(launcher) = WindowMonitor::is_launcher_process(&event.process_name) {
-                                debug!("Launcher process started: {} (PID: {})", launcher, event.process_id);
-
-                                tracker_start.track_process(event.process_id, launcher.to_string());
-
-                                // Emit event to frontend
-                                if let Err(e) = app_handle_start.emit("launcher-process-started", &launcher) {
-                                    error!("Failed to emit launcher-process-started: {}", e);
-                                }
-                            }
-                        },
-                        Err(e) => {
-                            error!("Error receiving process start event: {:?}", e);
-                            break;
-                        },
-                    }
-                }
-            },
-            Err(e) => {
-                error!("Failed to create process start notification: {:?}", e);
-            },
-        }
-
-        warn!("ProcessStartTrace listener stopped");
-    });
-
-    // Thread 2: Listen for process stops
-    let stop_thread = thread::spawn(move || {
-        info!("Initializing WMI connection for ProcessStopTrace...");
-
-        // Create WMI connection in this thread (not Send-safe)
-        let wmi_con = match WMIConnection::new() {
-            Ok(conn) => conn,
-            Err(e) => {
-                error!("Failed to create WMI connection in stop thread: {:?}", e);
-                return;
-            },
-        };
-
-        info!("WMI ProcessStopTrace listener active");
-
-        match wmi_con.notification::<ProcessStopTrace>() {
-            Ok(iterator) => {
-                for result in iterator {
-                    match result {
-                        Ok(event) => {
-                            if let Some((launcher, runtime)) = tracker.process_exited(event.process_id) {
-                                let runtime_secs = runtime.as_secs();
-
-                                debug!(
-                                    "Launcher process stopped: {} (PID: {}, Runtime: {}s)",
-                                    launcher, event.process_id, runtime_secs
-                                );
-
-                                // Check for quick exit (likely error)
-                                if runtime_secs < QUICK_EXIT_THRESHOLD_SECONDS {
-                                    warn!(
-                                        "Quick exit detected: {} exited after {}s (threshold: {}s)",
-                                        launcher, runtime_secs, QUICK_EXIT_THRESHOLD_SECONDS
-                                    );
-
-                                    // Emit error event to frontend
-                                    if let Err(e) = app_handle.emit("launcher-quick-exit", &launcher) {
-                                        error!("Failed to emit launcher-quick-exit: {}", e);
-                                    }
-                                } else {
-                                    // Normal exit
-                                    if let Err(e) = app_handle.emit("launcher-process-stopped", &launcher) {
-                                        error!("Failed to emit launcher-process-stopped: {}", e);
-                                    }
-                                }
-                            }
-                        },
-                        Err(e) => {
-                            error!("Error receiving process stop event: {:?}", e);
-                            break;
-                        },
-                    }
-                }
-            },
-            Err(e) => {
-                error!("Failed to create process stop notification: {:?}", e);
-            },
-        }
-
-        warn!("ProcessStopTrace listener stopped");
-    });
-
-    // Wait for both threads (they run indefinitely until error or app exit)
-    let _ = start_thread.join();
-    let _ = stop_thread.join();
-
-    info!("WMI process monitor exited");
-    Ok(())
-}
-
 // =============================================================================
 // TESTS
 // =============================================================================
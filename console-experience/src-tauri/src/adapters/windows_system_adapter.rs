@@ -1,16 +1,21 @@
+use crate::adapters::network::WindowsNetworkAdapter;
+use crate::domain::BalamError;
+use crate::ports::network_port::{NetworkAdapterKind, NetworkPort};
 use crate::ports::system_port::{AudioDevice, AudioDeviceType, ConnectionType, SystemPort, SystemStatus};
 use std::process::Command;
 use windows::core::{GUID, HRESULT, PCWSTR};
 use windows::Win32::Devices::FunctionDiscovery::PKEY_Device_FriendlyName;
+use windows::Win32::Foundation::BOOLEAN;
 use windows::Win32::Media::Audio::Endpoints::IAudioEndpointVolume;
 use windows::Win32::Media::Audio::{
-    eConsole, eRender, IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, MMDeviceEnumerator, DEVICE_STATE_ACTIVE,
+    eCapture, eConsole, eRender, EDataFlow, IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, MMDeviceEnumerator,
+    DEVICE_STATE_ACTIVE,
 };
 use windows::Win32::System::Com::{
     CoCreateInstance, CoInitializeEx, StructuredStorage::PropVariantToStringAlloc, CLSCTX_ALL,
     COINIT_APARTMENTTHREADED, STGM,
 };
-use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+use windows::Win32::System::Power::{GetSystemPowerStatus, SetSuspendState, SYSTEM_POWER_STATUS};
 use windows::Win32::UI::Shell::PropertiesSystem::IPropertyStore;
 
 // ============================================================================
@@ -37,11 +42,11 @@ struct IPolicyConfig(*mut std::ffi::c_void);
 
 impl IPolicyConfig {
     /// Creates a new IPolicyConfig instance via CoCreateInstance
-    unsafe fn new() -> Result<Self, String> {
+    unsafe fn new() -> Result<Self, BalamError> {
         use windows::Win32::System::Com::{CoCreateInstance, CLSCTX_ALL};
 
         let instance: windows::core::IUnknown = CoCreateInstance(&POLICY_CONFIG_CLIENT_CLSID, None, CLSCTX_ALL)
-            .map_err(|e| format!("Failed to create PolicyConfigClient: {e}"))?;
+            .map_err(|e| BalamError::platform("Failed to create PolicyConfigClient", e))?;
 
         // Query for IPolicyConfig interface
         let policy_config_ptr: *mut std::ffi::c_void = std::mem::transmute(instance);
@@ -54,7 +59,7 @@ impl IPolicyConfig {
         &self,
         device_id: PCWSTR,
         role: windows::Win32::Media::Audio::ERole,
-    ) -> Result<(), String> {
+    ) -> Result<(), BalamError> {
         // Get the VTable
         let vtable = *(self.0 as *const *const usize);
 
@@ -71,7 +76,9 @@ impl IPolicyConfig {
         if hr.is_ok() {
             Ok(())
         } else {
-            Err(format!("SetDefaultEndpoint failed with HRESULT: {hr:?}"))
+            Err(BalamError::Platform(format!(
+                "SetDefaultEndpoint failed with HRESULT: {hr:?}"
+            )))
         }
     }
 }
@@ -91,6 +98,18 @@ impl Drop for IPolicyConfig {
 
 // ============================================================================
 
+/// HRESULT_FROM_WIN32(ERROR_NOT_FOUND): no endpoint for the requested flow
+const E_NOTFOUND: HRESULT = HRESULT(0x8007_0490_u32 as i32);
+
+/// Maps a missing default endpoint (no microphone plugged in) to `NotFound`.
+fn endpoint_error(context: &str, error: &windows::core::Error) -> BalamError {
+    if error.code() == E_NOTFOUND {
+        BalamError::NotFound(format!("{context}: no active audio device"))
+    } else {
+        BalamError::platform(context, error)
+    }
+}
+
 /// Implementation of the `SystemPort` for Windows utilizing strictly native `CoreAudio` APIs.
 /// This approach avoids shell-outs and keystroke emulation.
 pub struct WindowsSystemAdapter;
@@ -126,23 +145,28 @@ impl WindowsSystemAdapter {
 
     /// Internal helper to access the Windows Master Volume COM interface.
     /// Uses absolute memory access for maximum performance and stability.
+    pub(crate) fn get_volume_interface(&self) -> Result<IAudioEndpointVolume, BalamError> {
+        self.get_endpoint_volume(eRender)
+    }
+
+    /// Volume interface of the default endpoint for `flow` (`eRender` speakers, `eCapture` microphone).
     #[allow(clippy::unused_self)]
-    fn get_volume_interface(&self) -> Result<IAudioEndpointVolume, String> {
+    fn get_endpoint_volume(&self, flow: EDataFlow) -> Result<IAudioEndpointVolume, BalamError> {
         unsafe {
             // Ensure COM is initialized for this thread
             let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
 
             let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
-                .map_err(|e| format!("COM Enumerator Error: {e}"))?;
+                .map_err(|e| BalamError::platform("COM Enumerator Error", e))?;
 
             let device: IMMDevice = enumerator
-                .GetDefaultAudioEndpoint(eRender, eConsole)
-                .map_err(|e| format!("Default Audio Endpoint Error: {e}"))?;
+                .GetDefaultAudioEndpoint(flow, eConsole)
+                .map_err(|e| endpoint_error("Default Audio Endpoint Error", &e))?;
 
             // Activate the Volume control interface
             let volume: IAudioEndpointVolume = device
                 .Activate(CLSCTX_ALL, None)
-                .map_err(|e| format!("Audio Interface Activation Error: {e}"))?;
+                .map_err(|e| BalamError::platform("Audio Interface Activation Error", e))?;
 
             Ok(volume)
         }
@@ -160,18 +184,23 @@ impl WindowsSystemAdapter {
         }
     }
 
-    fn set_master_volume(&self, level: u32) -> Result<(), String> {
+    fn set_master_volume(&self, level: u32) -> Result<(), BalamError> {
+        if level > 100 {
+            return Err(BalamError::InvalidArgument(format!(
+                "Volume {level} out of range (0 - 100)"
+            )));
+        }
         let normalized = (level as f32) / 100.0;
         let vol = self.get_volume_interface()?;
         unsafe {
             vol.SetMasterVolumeLevelScalar(normalized, std::ptr::null())
-                .map_err(|e| format!("Hardware SetVolume Error: {e}"))?;
+                .map_err(|e| BalamError::platform("Hardware SetVolume Error", e))?;
         }
         Ok(())
     }
 
     #[allow(clippy::unused_self)]
-    fn execute_power_command(&self, args: &[&str]) -> Result<(), String> {
+    fn execute_power_command(&self, args: &[&str]) -> Result<(), BalamError> {
         let _ = Command::new("shutdown").args(args).spawn();
         Ok(())
     }
@@ -191,7 +220,19 @@ impl WindowsSystemAdapter {
                 }
             }
         }
-        (ConnectionType::Ethernet, Some("Wired Network".to_string()))
+        // No WLAN association: only report wired when a cable actually carries traffic
+        let wired = WindowsNetworkAdapter::new()
+            .get_network_details()
+            .ok()
+            .and_then(|details| {
+                details.adapters.into_iter().find(|adapter| {
+                    adapter.kind == NetworkAdapterKind::Ethernet && adapter.is_up && !adapter.gateways.is_empty()
+                })
+            });
+        match wired {
+            Some(adapter) => (ConnectionType::Ethernet, Some(adapter.name)),
+            None => (ConnectionType::None, None),
+        }
     }
 
     /// Classifies audio device type based on friendly name heuristics.
@@ -201,6 +242,8 @@ impl WindowsSystemAdapter {
         // Check for specific device types
         if name_lower.contains("headphone") || name_lower.contains("headset") {
             AudioDeviceType::Headphones
+        } else if name_lower.contains("microphone") || name_lower.contains("mic array") {
+            AudioDeviceType::Microphone
         } else if name_lower.contains("hdmi") {
             AudioDeviceType::HDMI
         } else if name_lower.contains("displayport") || name_lower.contains("display port") {
@@ -223,105 +266,74 @@ impl WindowsSystemAdapter {
     }
 
     /// Gets the friendly name of an audio device.
-    fn get_device_friendly_name(device: &IMMDevice) -> Result<String, String> {
+    fn get_device_friendly_name(device: &IMMDevice) -> Result<String, BalamError> {
         unsafe {
             // STGM_READ = 0x00000000
             let prop_store: IPropertyStore = device
                 .OpenPropertyStore(STGM(0))
-                .map_err(|e| format!("Failed to open property store: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to open property store", e))?;
 
             let prop_value = prop_store
                 .GetValue(&PKEY_Device_FriendlyName)
-                .map_err(|e| format!("Failed to get friendly name property: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get friendly name property", e))?;
 
             let name_pwstr = PropVariantToStringAlloc(&prop_value)
-                .map_err(|e| format!("Failed to convert property to string: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to convert property to string", e))?;
 
             let name = name_pwstr
                 .to_string()
-                .map_err(|e| format!("Failed to convert PWSTR to String: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to convert PWSTR to String", e))?;
 
             Ok(name)
         }
     }
 
-    /// Gets the default audio device ID.
+    /// Gets the default audio device ID for `flow`.
     #[allow(clippy::unused_self)]
-    fn get_default_device_id(&self) -> Result<String, String> {
+    fn get_default_device_id(&self, flow: EDataFlow) -> Result<String, BalamError> {
         unsafe {
             let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
 
             let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
-                .map_err(|e| format!("Failed to create device enumerator: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to create device enumerator", e))?;
 
             let default_device: IMMDevice = enumerator
-                .GetDefaultAudioEndpoint(eRender, eConsole)
-                .map_err(|e| format!("Failed to get default device: {e}"))?;
+                .GetDefaultAudioEndpoint(flow, eConsole)
+                .map_err(|e| endpoint_error("Failed to get default device", &e))?;
 
             let device_id_pwstr = default_device
                 .GetId()
-                .map_err(|e| format!("Failed to get device ID: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get device ID", e))?;
 
             let device_id = device_id_pwstr
                 .to_string()
-                .map_err(|e| format!("Failed to convert device ID to string: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to convert device ID to string", e))?;
 
             Ok(device_id)
         }
     }
-}
-
-impl SystemPort for WindowsSystemAdapter {
-    fn get_status(&self) -> SystemStatus {
-        let (battery_level, is_charging) = self.get_battery_info();
-        let volume = self.get_master_volume();
-        let (connection_type, network_name) = self.get_network_info();
-
-        SystemStatus {
-            battery_level,
-            is_charging,
-            network_name,
-            volume,
-            connection_type,
-        }
-    }
-
-    fn set_volume(&self, level: u32) -> Result<(), String> {
-        self.set_master_volume(level)
-    }
-
-    fn shutdown(&self) -> Result<(), String> {
-        self.execute_power_command(&["/s", "/t", "0"])
-    }
-
-    fn restart(&self) -> Result<(), String> {
-        self.execute_power_command(&["/r", "/t", "0"])
-    }
 
-    fn logout(&self) -> Result<(), String> {
-        self.execute_power_command(&["/l"])
-    }
-
-    fn list_audio_devices(&self) -> Result<Vec<AudioDevice>, String> {
+    /// Active endpoints for `flow`, default first, then alphabetically.
+    fn enumerate_devices(&self, flow: EDataFlow) -> Result<Vec<AudioDevice>, BalamError> {
         unsafe {
             // Initialize COM
             let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
 
             // Create device enumerator
             let enumerator: IMMDeviceEnumerator = CoCreateInstance(&MMDeviceEnumerator, None, CLSCTX_ALL)
-                .map_err(|e| format!("Failed to create device enumerator: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to create device enumerator", e))?;
 
             // Get default device ID for comparison
-            let default_device_id = self.get_default_device_id().unwrap_or_default();
+            let default_device_id = self.get_default_device_id(flow).unwrap_or_default();
 
-            // Enumerate all active audio render devices
+            // Enumerate all active endpoints of this flow
             let collection: IMMDeviceCollection = enumerator
-                .EnumAudioEndpoints(eRender, DEVICE_STATE_ACTIVE)
-                .map_err(|e| format!("Failed to enumerate devices: {e}"))?;
+                .EnumAudioEndpoints(flow, DEVICE_STATE_ACTIVE)
+                .map_err(|e| BalamError::platform("Failed to enumerate devices", e))?;
 
             let count = collection
                 .GetCount()
-                .map_err(|e| format!("Failed to get device count: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to get device count", e))?;
 
             let mut devices = Vec::new();
 
@@ -373,11 +385,20 @@ impl SystemPort for WindowsSystemAdapter {
         }
     }
 
-    fn set_default_audio_device(&self, device_id: &str) -> Result<(), String> {
+    /// Makes `device_id` the default endpoint of `flow` for all three roles.
+    fn set_default_device(&self, flow: EDataFlow, device_id: &str) -> Result<(), BalamError> {
         // Native COM implementation using manually-defined IPolicyConfig interface
         // Performance: <10ms (instant native Windows API, zero dependencies)
         use windows::Win32::Media::Audio::{eCommunications, eConsole, eMultimedia};
 
+        if !self
+            .enumerate_devices(flow)?
+            .iter()
+            .any(|device| device.id == device_id)
+        {
+            return Err(BalamError::NotFound(format!("Audio device {device_id}")));
+        }
+
         unsafe {
             // Initialize COM (safe to call multiple times)
             let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
@@ -393,17 +414,17 @@ impl SystemPort for WindowsSystemAdapter {
             // eConsole = Default Device (general system sounds)
             policy_config
                 .set_default_endpoint(device_id_pcwstr, eConsole)
-                .map_err(|e| format!("Failed to set Console default: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to set Console default", e))?;
 
             // eMultimedia = Default Communication Device (apps, games, media)
             policy_config
                 .set_default_endpoint(device_id_pcwstr, eMultimedia)
-                .map_err(|e| format!("Failed to set Multimedia default: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to set Multimedia default", e))?;
 
             // eCommunications = Default Communications Device (VoIP, chat apps)
             policy_config
                 .set_default_endpoint(device_id_pcwstr, eCommunications)
-                .map_err(|e| format!("Failed to set Communications default: {e}"))?;
+                .map_err(|e| BalamError::platform("Failed to set Communications default", e))?;
 
             tracing::info!(
                 "✅ Successfully set default audio device to: {} (Native COM <10ms)",
@@ -413,3 +434,97 @@ impl SystemPort for WindowsSystemAdapter {
         }
     }
 }
+
+impl SystemPort for WindowsSystemAdapter {
+    fn get_status(&self) -> SystemStatus {
+        let (battery_level, is_charging) = self.get_battery_info();
+        let volume = self.get_master_volume();
+        let (connection_type, network_name) = self.get_network_info();
+
+        SystemStatus {
+            battery_level,
+            is_charging,
+            network_name,
+            volume,
+            connection_type,
+        }
+    }
+
+    fn set_volume(&self, level: u32) -> Result<(), BalamError> {
+        self.set_master_volume(level)
+    }
+
+    fn shutdown(&self) -> Result<(), BalamError> {
+        self.execute_power_command(&["/s", "/t", "0"])
+    }
+
+    fn restart(&self) -> Result<(), BalamError> {
+        self.execute_power_command(&["/r", "/t", "0"])
+    }
+
+    fn logout(&self) -> Result<(), BalamError> {
+        self.execute_power_command(&["/l"])
+    }
+
+    fn suspend(&self) -> Result<(), BalamError> {
+        // No hibernate, no force, wake events allowed (controllers, RTC alarms)
+        let suspended = unsafe { SetSuspendState(BOOLEAN(0), BOOLEAN(0), BOOLEAN(0)) };
+        if suspended.as_bool() {
+            Ok(())
+        } else {
+            Err(BalamError::platform(
+                "SetSuspendState failed",
+                windows::core::Error::from_win32(),
+            ))
+        }
+    }
+
+    fn list_audio_devices(&self) -> Result<Vec<AudioDevice>, BalamError> {
+        self.enumerate_devices(eRender)
+    }
+
+    fn set_default_audio_device(&self, device_id: &str) -> Result<(), BalamError> {
+        self.set_default_device(eRender, device_id)
+    }
+
+    fn list_input_devices(&self) -> Result<Vec<AudioDevice>, BalamError> {
+        self.enumerate_devices(eCapture)
+    }
+
+    fn set_default_input_device(&self, device_id: &str) -> Result<(), BalamError> {
+        self.set_default_device(eCapture, device_id)
+    }
+
+    fn get_mic_volume(&self) -> Result<u32, BalamError> {
+        let volume = self.get_endpoint_volume(eCapture)?;
+        let level = unsafe { volume.GetMasterVolumeLevelScalar() }
+            .map_err(|e| BalamError::platform("Failed to read microphone level", e))?;
+        Ok((level * 100.0).round() as u32)
+    }
+
+    fn set_mic_volume(&self, level: u32) -> Result<(), BalamError> {
+        if level > 100 {
+            return Err(BalamError::InvalidArgument(format!(
+                "Microphone level {level} out of range (0 - 100)"
+            )));
+        }
+        let volume = self.get_endpoint_volume(eCapture)?;
+        unsafe { volume.SetMasterVolumeLevelScalar(level as f32 / 100.0, std::ptr::null()) }
+            .map_err(|e| BalamError::platform("Failed to set microphone level", e))
+    }
+
+    fn is_mic_muted(&self) -> Result<bool, BalamError> {
+        let volume = self.get_endpoint_volume(eCapture)?;
+        let muted =
+            unsafe { volume.GetMute() }.map_err(|e| BalamError::platform("Failed to read microphone mute", e))?;
+        Ok(muted.as_bool())
+    }
+
+    fn set_mic_muted(&self, muted: bool) -> Result<(), BalamError> {
+        let volume = self.get_endpoint_volume(eCapture)?;
+        unsafe { volume.SetMute(muted, std::ptr::null()) }
+            .map_err(|e| BalamError::platform("Failed to set microphone mute", e))?;
+        tracing::info!("🎙️ Microphone {}", if muted { "muted" } else { "unmuted" });
+        Ok(())
+    }
+}
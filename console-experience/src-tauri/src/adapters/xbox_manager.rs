@@ -0,0 +1,219 @@
+/// Xbox Manager - Game Pass catalog, Store installs and package removal
+///
+/// The scanner only sees what is installed. The manager lists the PC Game Pass
+/// catalog next to the packages `PackageManager` reports, starts installs in the
+/// Store app (which owns entitlements and the download) and removes packages
+/// with `PackageManager::RemovePackageAsync`.
+///
+/// # Architecture
+/// ```
+/// install:   ms-windows-store://pdp → Store downloads → poll PackageManager
+/// uninstall: PackageManager::RemovePackageAsync → DeploymentProgress
+/// updates:   AppInstallManager queue → InstallState per family
+/// ```
+use std::collections::HashMap;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+use windows::core::HSTRING;
+use windows::ApplicationModel::Store::Preview::InstallControl::{AppInstallManager, AppInstallState, AppInstallType};
+use windows::Foundation::AsyncOperationProgressHandler;
+use windows::Management::Deployment::{DeploymentProgress, PackageManager};
+
+use super::microsoft_store_adapter::MicrosoftStoreAdapter;
+use super::xbox_scanner::is_game_package;
+use crate::config::ExclusionConfig;
+use crate::domain::xbox::{merge_xbox_titles, XboxTitle};
+use crate::domain::InstallState;
+
+/// How often a Store install is checked for.
+const INSTALL_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Xbox / Microsoft Store package manager.
+pub struct XboxManager {
+    store: MicrosoftStoreAdapter,
+    exclusions: ExclusionConfig,
+}
+
+impl XboxManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            store: MicrosoftStoreAdapter::new(),
+            exclusions: ExclusionConfig::load_or_default(),
+        }
+    }
+
+    /// Game Pass titles, marked installed or available, followed by installed
+    /// Xbox games outside the catalog.
+    ///
+    /// Without network only the installed games are listed.
+    ///
+    /// # Errors
+    /// Returns error if `PackageManager` cannot be queried.
+    pub fn list_titles(&self) -> Result<Vec<XboxTitle>, String> {
+        let installed = installed_packages()?;
+        let catalog = match self.store.fetch_game_pass_catalog() {
+            Ok(products) => products
+                .into_iter()
+                .map(|product| XboxTitle {
+                    product_id: product.product_id,
+                    title: product.title,
+                    package_family_name: product.package_family_name,
+                    installed: false,
+                })
+                .collect(),
+            Err(e) => {
+                warn!("Game Pass catalog unavailable: {}", e);
+                Vec::new()
+            },
+        };
+
+        Ok(merge_xbox_titles(catalog, &installed, |family| {
+            is_game_package(family)
+                && !self
+                    .exclusions
+                    .xbox_patterns
+                    .iter()
+                    .any(|pattern| family.contains(pattern.as_str()))
+        }))
+    }
+
+    /// PackageFamilyName of a Store product, if DisplayCatalog knows it.
+    #[must_use]
+    pub fn resolve_family(&self, product_id: &str) -> Option<String> {
+        match self.store.fetch_product(product_id) {
+            Ok(product) => product.package_family_name,
+            Err(e) => {
+                warn!("Could not resolve Store product {}: {}", product_id, e);
+                None
+            },
+        }
+    }
+
+    /// Packages with an update in the Store's install queue, by family name.
+    ///
+    /// Only reads the queue (the Store fills it on its own update checks);
+    /// empty when the queue cannot be read.
+    #[must_use]
+    pub fn store_updates() -> HashMap<String, InstallState> {
+        let items = AppInstallManager::new().and_then(|manager| manager.AppInstallItems());
+        let Ok(items) = items else {
+            return HashMap::new();
+        };
+        items
+            .into_iter()
+            .filter(|item| item.InstallType().is_ok_and(|kind| kind == AppInstallType::Update))
+            .filter_map(|item| {
+                let family = item.PackageFamilyName().ok()?.to_string();
+                let state = item.GetCurrentStatus().and_then(|status| status.InstallState()).ok()?;
+                let downloading = matches!(
+                    state,
+                    AppInstallState::Starting
+                        | AppInstallState::AcquiringLicense
+                        | AppInstallState::Downloading
+                        | AppInstallState::RestoringData
+                        | AppInstallState::Installing
+                );
+                (state != AppInstallState::Completed && state != AppInstallState::Canceled)
+                    .then(|| (family, InstallState::from_store_update(downloading)))
+            })
+            .collect()
+    }
+
+    /// Blocks until a package of `family_name` is installed for the current
+    /// user, or `timeout` passes. Returns whether it was installed.
+    #[must_use]
+    pub fn wait_for_install(family_name: &str, timeout: Duration) -> bool {
+        let Ok(manager) = PackageManager::new() else {
+            return false;
+        };
+        let started = Instant::now();
+        loop {
+            if !family_packages(&manager, family_name).is_empty() {
+                info!("📦 {} installed", family_name);
+                return true;
+            }
+            if started.elapsed() >= timeout {
+                return false;
+            }
+            thread::sleep(INSTALL_POLL_INTERVAL);
+        }
+    }
+
+    /// Removes every package of `family_name` for the current user, reporting
+    /// `DeploymentProgress` percentages to `on_progress`.
+    ///
+    /// # Errors
+    /// Returns error if nothing of the family is installed or a removal fails.
+    pub fn uninstall(family_name: &str, on_progress: impl Fn(u32) + Clone + Send + 'static) -> Result<(), String> {
+        let manager = PackageManager::new().map_err(|e| format!("Failed to create PackageManager: {e}"))?;
+        let full_names = family_packages(&manager, family_name);
+        if full_names.is_empty() {
+            return Err(format!("No installed package for family {family_name}"));
+        }
+
+        for full_name in full_names {
+            info!("🗑️ Removing package {}", full_name);
+            let operation = manager
+                .RemovePackageAsync(&full_name)
+                .map_err(|e| format!("Failed to start removing {full_name}: {e}"))?;
+            let report = on_progress.clone();
+            operation
+                .SetProgress(&AsyncOperationProgressHandler::new(
+                    move |_, progress: &DeploymentProgress| {
+                        report(progress.percentage);
+                        Ok(())
+                    },
+                ))
+                .map_err(|e| format!("Failed to watch removal of {full_name}: {e}"))?;
+            operation
+                .get()
+                .map_err(|e| format!("Failed to remove {full_name}: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for XboxManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `(PackageFamilyName, display name)` of every app package of the current user.
+fn installed_packages() -> Result<Vec<(String, String)>, String> {
+    let manager = PackageManager::new().map_err(|e| format!("Failed to create PackageManager: {e}"))?;
+    // Empty SID = current user
+    let packages = manager
+        .FindPackagesByUserSecurityId(&HSTRING::new())
+        .map_err(|e| format!("Failed to query packages: {e}"))?;
+
+    Ok(packages
+        .into_iter()
+        .filter(|package| !package.IsFramework().unwrap_or(true))
+        .filter_map(|package| {
+            let family = package.Id().and_then(|id| id.FamilyName()).ok()?.to_string();
+            let title = package
+                .DisplayName()
+                .map(|name| name.to_string())
+                .ok()
+                .filter(|name| !name.is_empty() && !name.contains("ms-resource"))
+                .unwrap_or_else(|| family.clone());
+            Some((family, title))
+        })
+        .collect())
+}
+
+/// Full names of the installed packages of a family.
+fn family_packages(manager: &PackageManager, family_name: &str) -> Vec<HSTRING> {
+    manager
+        .FindPackagesByUserSecurityIdPackageFamilyName(&HSTRING::new(), &HSTRING::from(family_name))
+        .map(|packages| {
+            packages
+                .into_iter()
+                .filter_map(|package| package.Id().and_then(|id| id.FullName()).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
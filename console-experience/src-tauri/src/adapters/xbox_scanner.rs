@@ -1,6 +1,7 @@
+use crate::adapters::xbox_manager::XboxManager;
 use crate::config::ExclusionConfig;
 use crate::domain::errors::ScanError;
-use crate::domain::{Game, GameSource};
+use crate::domain::{Game, GameSource, InstallState};
 use crate::ports::GameScanner;
 use std::collections::HashSet;
 use tracing::info;
@@ -31,6 +32,7 @@ impl XboxScanner {
     fn scan_internal(&self) -> Result<Vec<Game>, ScanError> {
         let mut games = Vec::new();
         let mut seen_ids = HashSet::new();
+        let updates = XboxManager::store_updates();
         info!("Scanning Xbox/UWP Apps (Deduplicated Registry)...");
 
         let hkcu = RegKey::predef(HKEY_CURRENT_USER);
@@ -42,17 +44,7 @@ impl XboxScanner {
                     let display_name: String = pkg_key.get_value("DisplayName").unwrap_or_default();
                     let package_id: String = pkg_key.get_value("PackageID").unwrap_or_default();
 
-                    let lower_id = package_id.to_lowercase();
-
-                    let is_game = lower_id.contains("gaming")
-                        || lower_id.contains("xbox")
-                        || lower_id.contains("halo")
-                        || lower_id.contains("forza")
-                        || lower_id.contains("minecraft")
-                        || lower_id.contains("ageofempires")
-                        || lower_id.contains("seaofthieves");
-
-                    if is_game {
+                    if is_game_package(&package_id) {
                         let family_name = if let Some(first_underscore) = name.find('_') {
                             if let Some(last_underscore) = name.rfind('_') {
                                 let prefix = &name[..first_underscore];
@@ -87,6 +79,7 @@ impl XboxScanner {
                             display_name
                         };
 
+                        let install_state = updates.get(&family_name).copied().unwrap_or(InstallState::Installed);
                         games.push(Game {
                             id: format!("xbox_{family_name}"),
                             raw_id: family_name.clone(),
@@ -97,6 +90,9 @@ impl XboxScanner {
                             logo: None,
                             last_played: None,
                             source: GameSource::Xbox,
+                            install_state,
+                            launcher_dependency: None,
+                            update_pending: install_state.is_update_pending(),
                         });
                     }
                 }
@@ -108,6 +104,18 @@ impl XboxScanner {
     }
 }
 
+/// Name-based guess whether a package (full or family name) is a game.
+pub(crate) fn is_game_package(package_id: &str) -> bool {
+    let lower_id = package_id.to_lowercase();
+    lower_id.contains("gaming")
+        || lower_id.contains("xbox")
+        || lower_id.contains("halo")
+        || lower_id.contains("forza")
+        || lower_id.contains("minecraft")
+        || lower_id.contains("ageofempires")
+        || lower_id.contains("seaofthieves")
+}
+
 impl Default for XboxScanner {
     fn default() -> Self {
         Self::new()
@@ -4,6 +4,9 @@
  * Thread-safe global state for tracking currently running games.
  * Supports all game types: Steam (no PID), Xbox/UWP (optional PID), Native (PID).
  *
+ * Several games can run at once: the tracker also remembers which session
+ * has focus (the one the overlay and `get_active_game` refer to).
+ *
  * This module solves the critical issue where:
  * - Steam games don't return real PIDs (use protocol handler)
  * - Xbox/UWP games may or may not have PIDs (depending on COM activation)
@@ -24,6 +27,23 @@ pub struct ActiveGameInfo {
     pub pid: Option<u32>,
     /// Game executable path (used for kill operations)
     pub path: String,
+    /// Whether the process is frozen (quick-resume)
+    #[serde(default)]
+    pub suspended: bool,
+    /// Unix seconds the session started (0 if unknown)
+    #[serde(default)]
+    pub started_at: u64,
+}
+
+impl ActiveGameInfo {
+    /// Seconds played by `now` (0 if the start time is unknown).
+    #[must_use]
+    pub fn elapsed_secs(&self, now: u64) -> u64 {
+        if self.started_at == 0 {
+            return 0;
+        }
+        now.saturating_sub(self.started_at)
+    }
 }
 
 /// Frontend-compatible ActiveGame response
@@ -31,6 +51,7 @@ pub struct ActiveGameInfo {
 pub struct ActiveGame {
     pub game: Game,
     pub pid: u32, // 0 for games without real PID (Steam)
+    pub suspended: bool,
 }
 
 impl From<ActiveGameInfo> for ActiveGame {
@@ -38,6 +59,7 @@ impl From<ActiveGameInfo> for ActiveGame {
         ActiveGame {
             game: info.game,
             pid: info.pid.unwrap_or(0), // Steam and Xbox fallback get PID 0
+            suspended: info.suspended,
         }
     }
 }
@@ -45,6 +67,8 @@ impl From<ActiveGameInfo> for ActiveGame {
 /// Thread-safe global tracker for active games
 pub struct ActiveGamesTracker {
     games: Arc<RwLock<HashMap<String, ActiveGameInfo>>>,
+    /// Game ID of the focused session (most recently launched or switched to)
+    focused: Arc<RwLock<Option<String>>>,
 }
 
 impl ActiveGamesTracker {
@@ -53,6 +77,7 @@ impl ActiveGamesTracker {
     pub fn new() -> Self {
         Self {
             games: Arc::new(RwLock::new(HashMap::new())),
+            focused: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -61,6 +86,7 @@ impl ActiveGamesTracker {
         let mut games = self.games.write().expect("Failed to lock active games for write");
         let pid = info.pid;
         games.insert(game_id.clone(), info);
+        *self.focused.write().expect("Failed to lock focused game for write") = Some(game_id.clone());
         tracing::info!("🎮 Active game registered: {} (PID: {:?})", game_id, pid);
     }
 
@@ -77,6 +103,84 @@ impl ActiveGamesTracker {
         if let Some(info) = games.remove(game_id) {
             tracing::info!("🎮 Active game unregistered: {} (PID: {:?})", game_id, info.pid);
         }
+
+        // Hand focus to a remaining session
+        let mut focused = self.focused.write().expect("Failed to lock focused game for write");
+        if focused.as_deref() == Some(game_id) {
+            *focused = games.keys().next().cloned();
+        }
+    }
+
+    /// Find the active game owning a PID
+    #[must_use]
+    pub fn find_by_pid(&self, pid: u32) -> Option<(String, ActiveGameInfo)> {
+        let games = self.games.read().expect("Failed to lock active games for read");
+        games
+            .iter()
+            .find(|(_, info)| info.pid == Some(pid))
+            .map(|(id, info)| (id.clone(), info.clone()))
+    }
+
+    /// Update the tracked PID (e.g. once a launcher hands over to the real game exe)
+    pub fn set_pid(&self, game_id: &str, pid: Option<u32>) -> bool {
+        let mut games = self.games.write().expect("Failed to lock active games for write");
+        match games.get_mut(game_id) {
+            Some(info) => {
+                info.pid = pid;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Mark a game as suspended/resumed (returns false if not tracked)
+    pub fn set_suspended(&self, game_id: &str, suspended: bool) -> bool {
+        let mut games = self.games.write().expect("Failed to lock active games for write");
+        match games.get_mut(game_id) {
+            Some(info) => {
+                info.suspended = suspended;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Game ID of the focused session
+    #[must_use]
+    pub fn focused(&self) -> Option<String> {
+        self.focused.read().expect("Failed to lock focused game for read").clone()
+    }
+
+    /// Focus a session (returns false if not tracked)
+    pub fn set_focused(&self, game_id: &str) -> bool {
+        let games = self.games.read().expect("Failed to lock active games for read");
+        if !games.contains_key(game_id) {
+            return false;
+        }
+        *self.focused.write().expect("Failed to lock focused game for write") = Some(game_id.to_string());
+        true
+    }
+
+    /// Snapshot of every active session
+    #[must_use]
+    pub fn list_sessions(&self) -> Vec<(String, ActiveGameInfo)> {
+        let games = self.games.read().expect("Failed to lock active games for read");
+        games.iter().map(|(id, info)| (id.clone(), info.clone())).collect()
+    }
+
+    /// Session captured by screenshots and replays: the focused one, or the
+    /// only one running
+    #[must_use]
+    pub fn capture_target(&self) -> Option<ActiveGameInfo> {
+        if let Some(info) = self.focused().and_then(|id| self.get(&id)) {
+            return Some(info);
+        }
+        let games = self.games.read().expect("Failed to lock active games for read");
+        if games.len() == 1 {
+            games.values().next().cloned()
+        } else {
+            None
+        }
     }
 
     /// Get all active games (for debugging)
@@ -91,6 +195,7 @@ impl ActiveGamesTracker {
     pub fn clone_tracker(&self) -> Self {
         Self {
             games: Arc::clone(&self.games),
+            focused: Arc::clone(&self.focused),
         }
     }
 }
@@ -100,3 +205,78 @@ impl Default for ActiveGamesTracker {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{GameSource, InstallState};
+
+    fn sample_info(pid: Option<u32>) -> ActiveGameInfo {
+        ActiveGameInfo {
+            game: Game {
+                id: format!("manual_{}", pid.unwrap_or(0)),
+                raw_id: "C:\\Games\\test.exe".to_string(),
+                title: "Test".to_string(),
+                path: "C:\\Games\\test.exe".to_string(),
+                image: None,
+                hero_image: None,
+                logo: None,
+                last_played: None,
+                source: GameSource::Manual,
+                install_state: InstallState::Installed,
+                launcher_dependency: None,
+                update_pending: false,
+            },
+            pid,
+            path: "C:\\Games\\test.exe".to_string(),
+            suspended: false,
+            started_at: 1_000,
+        }
+    }
+
+    #[test]
+    fn test_find_by_pid() {
+        let tracker = ActiveGamesTracker::new();
+        tracker.register("manual_test".to_string(), sample_info(Some(42)));
+
+        let (game_id, _) = tracker.find_by_pid(42).unwrap();
+        assert_eq!(game_id, "manual_test");
+        assert!(tracker.find_by_pid(7).is_none());
+    }
+
+    #[test]
+    fn test_elapsed_secs() {
+        let info = sample_info(Some(42));
+        assert_eq!(info.elapsed_secs(1_600), 600);
+        assert_eq!(info.elapsed_secs(500), 0);
+        assert_eq!(ActiveGameInfo { started_at: 0, ..info }.elapsed_secs(1_600), 0);
+    }
+
+    #[test]
+    fn test_set_suspended_updates_state() {
+        let tracker = ActiveGamesTracker::new();
+        tracker.register("manual_test".to_string(), sample_info(Some(42)));
+
+        assert!(tracker.set_suspended("manual_test", true));
+        assert!(tracker.get("manual_test").unwrap().suspended);
+        assert!(ActiveGame::from(tracker.get("manual_test").unwrap()).suspended);
+        assert!(!tracker.set_suspended("unknown", true));
+    }
+
+    #[test]
+    fn test_focus_follows_sessions() {
+        let tracker = ActiveGamesTracker::new();
+        tracker.register("first".to_string(), sample_info(Some(1)));
+        tracker.register("second".to_string(), sample_info(Some(2)));
+        assert_eq!(tracker.focused().as_deref(), Some("second"));
+
+        assert!(tracker.set_focused("first"));
+        assert!(!tracker.set_focused("unknown"));
+        assert_eq!(tracker.focused().as_deref(), Some("first"));
+
+        tracker.unregister("first");
+        assert_eq!(tracker.focused().as_deref(), Some("second"));
+        tracker.unregister("second");
+        assert_eq!(tracker.focused(), None);
+    }
+}
@@ -0,0 +1,215 @@
+/**
+ * Command Guard
+ *
+ * Admission control for Tauri commands the UI can fire faster than the
+ * backend can serve them (held buttons, sliders, impatient double taps).
+ * Each guarded command declares its policy in [`COMMAND_POLICIES`]:
+ * - `Reject`: extra calls fail right away with `BUSY` (a second library scan)
+ * - `Queue`: extra calls wait their turn (moving two games at once)
+ * - `Debounce`: a burst collapses into its last call (volume slider)
+ *
+ * Commands not listed run unrestricted.
+ */
+use crate::domain::BalamError;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// How overlapping calls of one command are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandPolicy {
+    /// Up to `max_concurrent` calls run; the others fail with `BUSY`.
+    Reject { max_concurrent: usize },
+    /// Up to `max_concurrent` calls run; the others wait, in call order.
+    Queue { max_concurrent: usize },
+    /// Each call waits `window`; only the last call of a burst runs.
+    Debounce { window: Duration },
+}
+
+/// Policy of every guarded command.
+pub const COMMAND_POLICIES: &[(&str, CommandPolicy)] = &[
+    ("scan_games", CommandPolicy::Reject { max_concurrent: 1 }),
+    ("scan_bluetooth_devices", CommandPolicy::Reject { max_concurrent: 1 }),
+    ("check_for_updates", CommandPolicy::Reject { max_concurrent: 1 }),
+    // Disk-heavy: one game at a time, the rest in line
+    ("move_game", CommandPolicy::Queue { max_concurrent: 1 }),
+    ("verify_game_files", CommandPolicy::Queue { max_concurrent: 1 }),
+    // On/off taps must apply in order
+    ("set_quiet_mode", CommandPolicy::Queue { max_concurrent: 1 }),
+    // Sliders
+    (
+        "set_volume",
+        CommandPolicy::Debounce {
+            window: Duration::from_millis(50),
+        },
+    ),
+    (
+        "set_brightness",
+        CommandPolicy::Debounce {
+            window: Duration::from_millis(50),
+        },
+    ),
+    (
+        // DDC/CI writes take ~50ms each on the monitor's bus
+        "set_monitor_brightness",
+        CommandPolicy::Debounce {
+            window: Duration::from_millis(100),
+        },
+    ),
+    (
+        "set_monitor_contrast",
+        CommandPolicy::Debounce {
+            window: Duration::from_millis(100),
+        },
+    ),
+    (
+        "set_tdp",
+        CommandPolicy::Debounce {
+            window: Duration::from_millis(150),
+        },
+    ),
+];
+
+/// Admission to run a guarded command; the slot is freed when dropped.
+#[must_use = "the command is only guarded while the permit is held"]
+pub struct CommandPermit {
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+enum Gate {
+    Limit { slots: Arc<Semaphore>, queue: bool },
+    Debounce { window: Duration },
+}
+
+/// Enforces [`COMMAND_POLICIES`].
+pub struct CommandGuard {
+    gates: HashMap<&'static str, Gate>,
+    /// Latest call number per debounced command
+    debounced: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl Default for CommandGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandGuard {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_policies(COMMAND_POLICIES)
+    }
+
+    #[must_use]
+    pub fn with_policies(policies: &[(&'static str, CommandPolicy)]) -> Self {
+        let gates = policies
+            .iter()
+            .map(|&(command, policy)| {
+                let gate = match policy {
+                    CommandPolicy::Reject { max_concurrent } => Gate::Limit {
+                        slots: Arc::new(Semaphore::new(max_concurrent)),
+                        queue: false,
+                    },
+                    CommandPolicy::Queue { max_concurrent } => Gate::Limit {
+                        slots: Arc::new(Semaphore::new(max_concurrent)),
+                        queue: true,
+                    },
+                    CommandPolicy::Debounce { window } => Gate::Debounce { window },
+                };
+                (command, gate)
+            })
+            .collect();
+        Self {
+            gates,
+            debounced: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Admits one call of `command` under its policy.
+    ///
+    /// # Returns
+    /// `None` when a newer call of a debounced command superseded this one:
+    /// the caller returns without doing anything.
+    ///
+    /// # Errors
+    /// Returns `BalamError::Busy` when a `Reject` command is already running.
+    pub async fn enter(&self, command: &'static str) -> Result<Option<CommandPermit>, BalamError> {
+        match self.gates.get(command) {
+            None => Ok(Some(CommandPermit { _slot: None })),
+            Some(Gate::Limit { slots, queue: false }) => Arc::clone(slots)
+                .try_acquire_owned()
+                .map(|slot| Some(CommandPermit { _slot: Some(slot) }))
+                .map_err(|_| BalamError::Busy(command.to_string())),
+            Some(Gate::Limit { slots, queue: true }) => Arc::clone(slots)
+                .acquire_owned()
+                .await
+                .map(|slot| Some(CommandPermit { _slot: Some(slot) }))
+                .map_err(|e| BalamError::platform("Command queue closed", e)),
+            Some(Gate::Debounce { window }) => {
+                let call = {
+                    let mut debounced = self.debounced.lock();
+                    let latest = debounced.entry(command).or_default();
+                    *latest += 1;
+                    *latest
+                };
+                tokio::time::sleep(*window).await;
+                let latest = self.debounced.lock().get(command).copied();
+                Ok((latest == Some(call)).then_some(CommandPermit { _slot: None }))
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reject_and_queue_limits() {
+        let guard = CommandGuard::with_policies(&[
+            ("scan", CommandPolicy::Reject { max_concurrent: 1 }),
+            ("move", CommandPolicy::Queue { max_concurrent: 1 }),
+        ]);
+
+        let scan = guard.enter("scan").await.unwrap();
+        assert_eq!(guard.enter("scan").await.err().map(|e| e.code()), Some("BUSY"));
+        drop(scan);
+        assert!(guard.enter("scan").await.unwrap().is_some());
+
+        let first_move = guard.enter("move").await.unwrap();
+        let waiting = tokio::time::timeout(Duration::from_millis(20), guard.enter("move")).await;
+        assert!(waiting.is_err(), "second call waits for the first");
+        drop(first_move);
+        assert!(guard.enter("move").await.unwrap().is_some());
+
+        // Unlisted commands are never held back
+        let _a = guard.enter("other").await.unwrap();
+        assert!(guard.enter("other").await.unwrap().is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_debounce_runs_only_the_last_call() {
+        let guard = Arc::new(CommandGuard::with_policies(&[(
+            "volume",
+            CommandPolicy::Debounce {
+                window: Duration::from_millis(50),
+            },
+        )]));
+
+        let calls: Vec<_> = (0..3)
+            .map(|_| {
+                let guard = Arc::clone(&guard);
+                tokio::spawn(async move { guard.enter("volume").await.unwrap().is_some() })
+            })
+            .collect();
+        let mut admitted = Vec::new();
+        for call in calls {
+            admitted.push(call.await.unwrap());
+        }
+        assert_eq!(admitted.iter().filter(|&&ran| ran).count(), 1);
+        // A call after the burst runs on its own
+        assert!(guard.enter("volume").await.unwrap().is_some());
+    }
+}
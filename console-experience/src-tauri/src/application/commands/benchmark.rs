@@ -0,0 +1,132 @@
+/// Benchmark Commands - timed FPS / frametime / thermal recordings
+///
+/// `start_benchmark` records the focused game for a fixed duration, emitting
+/// `benchmark-progress` every second and `benchmark-finished` with the saved
+/// run. Runs are stored per game with the TDP they ran at, to compare
+/// handheld TDP settings, and can be exported as CSV or JSON.
+use crate::application::commands::performance::{PERF_MONITOR, TDP_CONTROLLER};
+use crate::application::services::BenchmarkTarget;
+use crate::application::DIContainer;
+use crate::domain::benchmark::{BenchmarkExportFormat, BenchmarkRun, BenchmarkRunInfo};
+use crate::domain::BalamError;
+use crate::ports::performance_port::PerformancePort;
+use tauri::{AppHandle, Emitter, State};
+use tracing::error;
+
+/// Starts recording the focused game.
+///
+/// # Arguments
+/// * `duration_secs` - Recording length (10 - 600 s)
+///
+/// # Errors
+/// - `NOT_FOUND` - No game is running
+/// - `INVALID_ARGUMENT` - Duration out of range
+/// - `PLATFORM` - A benchmark is already running
+///
+/// # Examples
+/// ```javascript
+/// await listen('benchmark-progress', (e) => setProgress(e.payload.elapsed_secs / e.payload.duration_secs));
+/// await listen('benchmark-finished', (e) => showSummary(e.payload.summary));
+/// await invoke('start_benchmark', { durationSecs: 60 });
+/// ```
+#[tauri::command]
+pub fn start_benchmark(duration_secs: u32, app: AppHandle, container: State<DIContainer>) -> Result<(), BalamError> {
+    let game = container
+        .active_games_tracker
+        .capture_target()
+        .ok_or_else(|| BalamError::NotFound("Running game".to_string()))?
+        .game;
+    let target = BenchmarkTarget {
+        game_id: game.id,
+        game_title: game.title,
+        tdp_watts: PerformancePort::get_tdp_config(&*TDP_CONTROLLER)
+            .ok()
+            .map(|config| config.watts),
+    };
+
+    let progress_app = app.clone();
+    container.benchmarks.start(
+        target,
+        duration_secs,
+        container.active_games_tracker.clone(),
+        Box::new(|| PERF_MONITOR.get_metrics()),
+        Box::new(move |progress| {
+            let _ = progress_app.emit("benchmark-progress", progress);
+        }),
+        Box::new(move |result| match result {
+            Ok(run) => {
+                let _ = app.emit("benchmark-finished", &run);
+            },
+            Err(e) => error!("Failed to save benchmark run: {}", e),
+        }),
+    )
+}
+
+/// Stops the benchmark in progress; what was recorded is still saved.
+///
+/// Returns whether a benchmark was running.
+#[must_use]
+#[tauri::command]
+pub fn cancel_benchmark(container: State<DIContainer>) -> bool {
+    container.benchmarks.cancel()
+}
+
+/// Saved runs (summaries only), newest first.
+///
+/// # Arguments
+/// * `game_id` - Only runs of this game
+/// * `tdp_watts` - Only runs at this TDP
+///
+/// # Examples
+/// ```javascript
+/// const runs = await invoke('list_benchmark_runs', { gameId: 'steam_1091500', tdpWatts: 15 });
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn list_benchmark_runs(
+    game_id: Option<String>,
+    tdp_watts: Option<u32>,
+    container: State<DIContainer>,
+) -> Vec<BenchmarkRunInfo> {
+    container.benchmarks.list(game_id.as_deref(), tdp_watts)
+}
+
+/// A saved run with every sample (for frametime graphs).
+///
+/// # Errors
+/// Returns `NOT_FOUND` for an unknown run.
+#[tauri::command]
+pub fn get_benchmark_run(run_id: String, container: State<DIContainer>) -> Result<BenchmarkRun, BalamError> {
+    container.benchmarks.load(&run_id)
+}
+
+/// Deletes a saved run.
+///
+/// # Errors
+/// Returns `NOT_FOUND` for an unknown run.
+#[tauri::command]
+pub fn delete_benchmark_run(run_id: String, container: State<DIContainer>) -> Result<(), BalamError> {
+    container.benchmarks.delete(&run_id)
+}
+
+/// Writes a run to `<app data>\benchmarks\exports` and returns the file path.
+///
+/// # Errors
+/// Returns `NOT_FOUND` for an unknown run or an error if the file cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const path = await invoke('export_benchmark_run', { runId, format: 'csv' });
+/// await invoke('plugin:opener|reveal_item_in_dir', { path });
+/// ```
+#[tauri::command]
+pub fn export_benchmark_run(
+    run_id: String,
+    format: BenchmarkExportFormat,
+    container: State<DIContainer>,
+) -> Result<String, BalamError> {
+    container
+        .benchmarks
+        .export(&run_id, format)
+        .map(|path| path.display().to_string())
+}
@@ -0,0 +1,160 @@
+/// Store Browser Commands - sign into Steam, Epic or Xbox from console mode
+///
+/// Running as the shell there is no Edge to finish a store login in. This
+/// window loads only the allow-listed store and identity pages, and is driven
+/// by the controller: the gamepad listener maps the D-pad, A, B and Menu to
+/// [`BrowserAction`]s, run through a helper injected into every page.
+use tauri::{AppHandle, Emitter, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+use tracing::{info, warn};
+
+use crate::domain::browser::{is_store_browser_url_allowed, BrowserAction};
+use crate::domain::BalamError;
+
+/// Window the store pages are opened in.
+const STORE_BROWSER_WINDOW: &str = "store-browser";
+
+/// Page-side helper run by [`BrowserAction`]s: emulates Tab focus (with a
+/// visible focus ring), scrolling, clicks and history.
+const BROWSER_NAV_SCRIPT: &str = r#"
+window.__balamNav = (action) => {
+  if (!document.getElementById('__balam-focus') && document.head) {
+    const style = document.createElement('style');
+    style.id = '__balam-focus';
+    style.textContent = ':focus { outline: 3px solid #3b82f6 !important; outline-offset: 2px !important; }';
+    document.head.appendChild(style);
+  }
+  const move = (step) => {
+    const items = [...document.querySelectorAll(
+      'a[href], button, input:not([type="hidden"]), select, textarea, [tabindex]:not([tabindex="-1"]), [role="button"]'
+    )].filter((el) => !el.disabled && el.getClientRects().length > 0);
+    if (items.length === 0) return;
+    const index = items.indexOf(document.activeElement);
+    const next = index < 0 ? items[0] : items[(index + step + items.length) % items.length];
+    next.focus();
+    next.scrollIntoView({ block: 'center', behavior: 'smooth' });
+  };
+  const scroll = (direction) =>
+    window.scrollBy({ top: direction * window.innerHeight * 0.8, behavior: 'smooth' });
+  switch (action) {
+    case 'focus_previous': move(-1); break;
+    case 'focus_next': move(1); break;
+    case 'scroll_up': scroll(-1); break;
+    case 'scroll_down': scroll(1); break;
+    case 'activate': document.activeElement?.click(); break;
+    case 'back': history.back(); break;
+  }
+};
+"#;
+
+/// Opens a store login page in the store browser.
+///
+/// Navigation is limited to the allow-list (`https` Steam, Epic and
+/// Microsoft/Xbox pages); a blocked page emits `store-browser-blocked` with
+/// its URL.
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` for a URL off the allow-list.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('open_store_browser', { url: 'https://store.steampowered.com/login' });
+/// ```
+#[tauri::command]
+pub fn open_store_browser(app: AppHandle, url: String) -> Result<(), BalamError> {
+    let parsed: tauri::Url = url
+        .parse()
+        .map_err(|_| BalamError::InvalidArgument(format!("Invalid URL: {url}")))?;
+    if !is_store_browser_url_allowed(parsed.scheme(), parsed.host_str().unwrap_or_default()) {
+        return Err(BalamError::InvalidArgument(format!(
+            "{url} is not a store sign-in page"
+        )));
+    }
+
+    let window = if let Some(existing) = app.get_webview_window(STORE_BROWSER_WINDOW) {
+        existing
+            .navigate(parsed)
+            .map_err(|e| BalamError::platform("Failed to load store page", e))?;
+        existing
+    } else {
+        let events = app.clone();
+        WebviewWindowBuilder::new(&app, STORE_BROWSER_WINDOW, WebviewUrl::External(parsed))
+            .title("Store sign-in")
+            .maximized(true)
+            .always_on_top(true)
+            .initialization_script(BROWSER_NAV_SCRIPT)
+            .on_navigation(move |page| {
+                let allowed = is_store_browser_url_allowed(page.scheme(), page.host_str().unwrap_or_default());
+                if !allowed {
+                    warn!("🌐 Store browser blocked {}", page);
+                    let _ = events.emit("store-browser-blocked", page.as_str());
+                }
+                allowed
+            })
+            .additional_browser_args(crate::WEBVIEW2_BROWSER_ARGS)
+            .build()
+            .map_err(|e| BalamError::platform("Failed to open store browser", e))?
+    };
+    window
+        .show()
+        .and_then(|()| window.set_focus())
+        .map_err(|e| BalamError::platform("Failed to show store browser", e))?;
+    info!("🌐 Store browser opened: {}", url);
+    Ok(())
+}
+
+/// Closes the store browser and returns to the main window.
+///
+/// # Errors
+/// Returns error if the window cannot be closed.
+#[tauri::command]
+pub fn close_store_browser(app: AppHandle) -> Result<(), BalamError> {
+    run_store_browser_action(&app, BrowserAction::Close)
+}
+
+/// Runs a controller action in the store browser (for on-screen buttons; the
+/// gamepad listener sends the same actions itself).
+///
+/// # Errors
+/// Returns `NOT_FOUND` if the browser is not open.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('store_browser_action', { action: 'scroll_down' });
+/// ```
+#[tauri::command]
+pub fn store_browser_action(app: AppHandle, action: BrowserAction) -> Result<(), BalamError> {
+    run_store_browser_action(&app, action)
+}
+
+/// Whether the store browser has the focus (and gets the controller).
+pub(crate) fn store_browser_focused<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.get_webview_window(STORE_BROWSER_WINDOW)
+        .is_some_and(|window| window.is_visible().unwrap_or(false) && window.is_focused().unwrap_or(false))
+}
+
+/// Runs `action` in the store browser (closing one that is not open is a no-op).
+pub(crate) fn run_store_browser_action<R: Runtime>(
+    app: &AppHandle<R>,
+    action: BrowserAction,
+) -> Result<(), BalamError> {
+    let Some(window) = app.get_webview_window(STORE_BROWSER_WINDOW) else {
+        return if action == BrowserAction::Close {
+            Ok(())
+        } else {
+            Err(BalamError::NotFound("Store browser is not open".to_string()))
+        };
+    };
+    if action == BrowserAction::Close {
+        window
+            .close()
+            .map_err(|e| BalamError::platform("Failed to close store browser", e))?;
+        if let Some(main) = app.get_webview_window("main") {
+            let _ = main.show();
+            let _ = main.set_focus();
+        }
+        return Ok(());
+    }
+    window
+        .eval(&format!("window.__balamNav?.('{}')", action.name()))
+        .map_err(|e| BalamError::platform("Failed to run store browser action", e))
+}
@@ -0,0 +1,141 @@
+/// Console Mode Commands - Run Balam instead of explorer.exe
+///
+/// Registers the boot supervisor (`watchdog.exe --shell`) as the Windows shell.
+/// At sign-in the supervisor starts Balam with `--console-mode` and monitors
+/// its heartbeat; after repeated crashes it restores explorer as the shell and
+/// launches it, so a broken install can never lock the user out of the desktop.
+/// The supervisor also keeps fps-service and helper processes alive and
+/// publishes their status (`get_supervision_status`).
+use crate::adapters::console_mode::WindowsShellAdapter;
+use crate::application::commands::fps_service_manager::is_elevated;
+use crate::domain::console_mode::{is_balam_shell, ConsoleModeStatus, ShellScope, CONSOLE_MODE_FLAG};
+use crate::domain::supervision::{SupervisionStatus, SUPERVISION_PIPE_NAME};
+use std::path::PathBuf;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::net::windows::named_pipe::ClientOptions;
+
+/// Path to the boot supervisor binary (shipped next to the main executable).
+fn get_supervisor_path() -> Result<PathBuf, String> {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|p| p.parent().map(std::path::Path::to_path_buf))
+        .ok_or_else(|| "Failed to resolve executable directory".to_string())?;
+
+    let supervisor = exe_dir.join("watchdog.exe");
+    if !supervisor.exists() {
+        return Err(format!("Boot supervisor not found at: {}", supervisor.display()));
+    }
+
+    Ok(supervisor)
+}
+
+/// Whether this process was started by the shell supervisor.
+#[must_use]
+pub fn is_running_as_shell() -> bool {
+    std::env::args().any(|arg| arg == CONSOLE_MODE_FLAG)
+}
+
+/// Gets the current shell replacement status.
+///
+/// # Examples
+/// ```javascript
+/// const status = await invoke('get_console_mode_status');
+/// if (status.enabled) console.log(`Console mode (${status.scope})`);
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_console_mode_status() -> ConsoleModeStatus {
+    let adapter = WindowsShellAdapter::new();
+    let scope = adapter.registered_scope();
+
+    ConsoleModeStatus {
+        enabled: scope.is_some(),
+        scope,
+        current_shell: adapter.effective_shell(),
+        running_as_shell: is_running_as_shell(),
+    }
+}
+
+/// Registers Balam as the Windows shell.
+///
+/// # Arguments
+/// * `scope` - `"current_user"` (dedicated account) or `"all_users"` (requires admin)
+///
+/// # Errors
+/// Returns error if the supervisor binary is missing, admin rights are
+/// required but not available, or the registry write fails.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('enable_console_mode', { scope: 'current_user' });
+/// ```
+#[tauri::command]
+pub fn enable_console_mode(scope: ShellScope) -> Result<ConsoleModeStatus, String> {
+    if scope.requires_elevation() && !is_elevated() {
+        return Err("Administrator privileges required to change the shell for all users".to_string());
+    }
+
+    let supervisor = get_supervisor_path()?;
+    WindowsShellAdapter::new().register(scope, &supervisor.to_string_lossy())?;
+
+    Ok(get_console_mode_status())
+}
+
+/// Restores explorer.exe as the Windows shell.
+///
+/// Unregisters every scope where Balam is registered. Machine-wide
+/// registration can only be removed when running as administrator.
+///
+/// # Errors
+/// Returns error if the registry cannot be restored.
+#[tauri::command]
+pub fn disable_console_mode() -> Result<ConsoleModeStatus, String> {
+    let adapter = WindowsShellAdapter::new();
+
+    for scope in [ShellScope::CurrentUser, ShellScope::AllUsers] {
+        let registered = adapter
+            .shell_in_scope(scope)
+            .is_some_and(|shell| is_balam_shell(&shell));
+        if !registered {
+            continue;
+        }
+        if scope.requires_elevation() && !is_elevated() {
+            return Err("Administrator privileges required to restore the shell for all users".to_string());
+        }
+        adapter.unregister(scope)?;
+    }
+
+    Ok(get_console_mode_status())
+}
+
+/// Gets the status of the processes supervised by the watchdog.
+///
+/// Returns `None` when the watchdog is not running (Balam was not started by
+/// the boot supervisor), in which case nothing is being restarted.
+///
+/// # Errors
+/// Returns error if the supervision pipe cannot be read or answers with invalid data.
+///
+/// # Examples
+/// ```javascript
+/// const supervision = await invoke('get_supervision_status');
+/// if (supervision?.degraded) showBanner('Servicio degradado');
+/// ```
+#[tauri::command]
+pub async fn get_supervision_status() -> Result<Option<SupervisionStatus>, String> {
+    let client = match ClientOptions::new().open(SUPERVISION_PIPE_NAME) {
+        Ok(client) => client,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to connect to watchdog: {e}")),
+    };
+
+    let mut line = String::new();
+    BufReader::new(client)
+        .read_line(&mut line)
+        .await
+        .map_err(|e| format!("Failed to read supervision status: {e}"))?;
+
+    serde_json::from_str(&line)
+        .map(Some)
+        .map_err(|e| format!("Invalid supervision status: {e}"))
+}
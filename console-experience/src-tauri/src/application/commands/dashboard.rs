@@ -0,0 +1,32 @@
+/// Dashboard Commands - data behind the home screen tiles
+///
+/// `get_dashboard_data` returns the clock, the weather (when
+/// `dashboard.weather_provider` is set) and the update status in one call;
+/// the same payload is pushed on `dashboard-updated` at every minute change.
+use crate::application::DIContainer;
+use crate::domain::dashboard::{DashboardData, UpdateSummary};
+use tauri::{AppHandle, Manager, Runtime, State};
+
+/// Current home screen data (cached weather, never waits on the network).
+///
+/// # Examples
+/// ```javascript
+/// const { clock, weather, updates } = await invoke('get_dashboard_data');
+/// await listen('dashboard-updated', ({ payload }) => setDashboard(payload));
+/// ```
+#[tauri::command]
+#[must_use]
+pub fn get_dashboard_data(app: AppHandle, container: State<'_, DIContainer>) -> DashboardData {
+    let settings = container.settings_service.get().dashboard;
+    container.dashboard.data(&settings, update_summary(&app))
+}
+
+/// Update tile: the last release check and whether an update is installing.
+pub(crate) fn update_summary<R: Runtime>(app: &AppHandle<R>) -> UpdateSummary {
+    let container = app.state::<DIContainer>();
+    UpdateSummary::new(
+        &app.package_info().version.to_string(),
+        container.updates.last_check().as_ref(),
+        container.updates.is_busy(),
+    )
+}
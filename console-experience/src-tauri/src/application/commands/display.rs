@@ -1,6 +1,18 @@
-use crate::adapters::display::WindowsDisplayAdapter;
-use crate::domain::display::DisplayInfo;
-use crate::ports::display_port::DisplayPort;
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::display::{ColorProfile, DisplayInfo, GameWindowMode};
+use crate::domain::scaling::{GameScalingProfile, ScalingCapabilities};
+use crate::domain::BalamError;
+use std::time::Duration;
+use tauri::{AppHandle, State};
+use tracing::{info, warn};
+
+/// Longest wait for a launched game to show its window.
+const GAME_WINDOW_TIMEOUT: Duration = Duration::from_secs(60);
+/// Poll interval while waiting for the window.
+const GAME_WINDOW_POLL: Duration = Duration::from_secs(1);
+/// Time a game gets to settle its own display mode once its window appears.
+const GAME_WINDOW_SETTLE: Duration = Duration::from_secs(3);
 
 /// Gets information about all active displays with HDR capabilities.
 ///
@@ -16,9 +28,8 @@ use crate::ports::display_port::DisplayPort;
 /// console.log(`Found ${displays.length} displays`);
 /// ```
 #[tauri::command]
-pub fn get_displays() -> Result<Vec<DisplayInfo>, String> {
-    let adapter = WindowsDisplayAdapter::new();
-    adapter.get_displays()
+pub fn get_displays(container: State<DIContainer>) -> Result<Vec<DisplayInfo>, BalamError> {
+    container.display().get_displays()
 }
 
 /// Gets the primary display information.
@@ -35,9 +46,8 @@ pub fn get_displays() -> Result<Vec<DisplayInfo>, String> {
 /// ```
 #[must_use]
 #[tauri::command]
-pub fn get_primary_display() -> Option<DisplayInfo> {
-    let adapter = WindowsDisplayAdapter::new();
-    adapter.get_primary_display()
+pub fn get_primary_display(container: State<DIContainer>) -> Option<DisplayInfo> {
+    container.display().get_primary_display()
 }
 
 /// Enables or disables HDR on a specific display.
@@ -50,16 +60,295 @@ pub fn get_primary_display() -> Option<DisplayInfo> {
 /// `Ok(())` if HDR state was set successfully.
 ///
 /// # Errors
-/// - `Err("Display not found")` - Invalid display ID
-/// - `Err("HDR not supported")` - Display doesn't support HDR
-/// - `Err(...)` - Platform API error
+/// - `NOT_FOUND` - Invalid display ID
+/// - `NOT_SUPPORTED` - Display doesn't support HDR
+/// - `PLATFORM_ERROR` - DisplayConfig API error
 ///
 /// # Examples
 /// ```javascript
 /// await invoke('set_hdr_enabled', { displayId: 0, enabled: true });
 /// ```
 #[tauri::command]
-pub fn set_hdr_enabled(display_id: u32, enabled: bool) -> Result<(), String> {
-    let adapter = WindowsDisplayAdapter::new();
-    adapter.set_hdr_enabled(display_id, enabled)
+pub fn set_hdr_enabled(container: State<DIContainer>, display_id: u32, enabled: bool) -> Result<(), BalamError> {
+    container.display().set_hdr_enabled(display_id, enabled)
+}
+
+/// Applies a color profile immediately without saving it (live preview while editing).
+///
+/// # Errors
+/// - `INVALID_ARGUMENT` - Temperature, gamma or vibrance out of range
+/// - `NOT_SUPPORTED` - The display driver rejected the gamma ramp
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_color_profile', { profile: { temperature_k: 5000, gamma: 1.1, vibrance: 40 } });
+/// ```
+#[tauri::command]
+pub fn set_color_profile(container: State<DIContainer>, profile: ColorProfile) -> Result<(), BalamError> {
+    container.display().set_color_profile(&profile)
+}
+
+/// Restores the saved color state (running game's profile, night light or neutral).
+///
+/// Call after a preview with `set_color_profile` is cancelled.
+#[tauri::command]
+pub fn restore_color_profile(container: State<DIContainer>) {
+    apply_display_color(&container, container.active_games_tracker.focused().as_deref());
+}
+
+/// Turns the night light on or off and optionally changes its temperature.
+///
+/// Saved in settings (emits `settings-changed`). While a game with its own
+/// color profile runs, the night light takes effect when it closes.
+///
+/// # Errors
+/// Returns error if the temperature is out of range or settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_night_light', { enabled: true, temperatureK: 3800 });
+/// ```
+#[tauri::command]
+pub fn set_night_light(
+    enabled: bool,
+    temperature_k: Option<u32>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| {
+        settings.display.night_light = enabled;
+        if let Some(temperature_k) = temperature_k {
+            settings.display.night_light_temperature_k = temperature_k;
+        }
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+
+    apply_display_color(&container, container.active_games_tracker.focused().as_deref());
+    Ok(())
+}
+
+/// Saves (or removes, with `null`) the color profile applied while a game runs.
+///
+/// # Errors
+/// Returns error if the profile is out of range or settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_game_color_profile', {
+///   gameId: 'steam_730',
+///   profile: { temperature_k: 6500, gamma: 1.0, vibrance: 60 },
+/// });
+/// ```
+#[tauri::command]
+pub fn set_game_color_profile(
+    game_id: String,
+    profile: Option<ColorProfile>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| match profile {
+        Some(profile) => {
+            settings.display.game_color_profiles.insert(game_id.clone(), profile);
+        },
+        None => {
+            settings.display.game_color_profiles.remove(&game_id);
+        },
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+
+    if container.active_games_tracker.focused().as_deref() == Some(game_id.as_str()) {
+        apply_display_color(&container, Some(&game_id));
+    }
+    Ok(())
+}
+
+/// Saves (or removes, with `null`) the window mode a game is switched to
+/// after it starts: `borderless` lets the TOPMOST overlay draw over games
+/// that default to exclusive fullscreen.
+///
+/// Applied right away when the game is running.
+///
+/// # Errors
+/// Returns error if settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_game_window_mode', { gameId: 'steam_730', mode: 'borderless' });
+/// ```
+#[tauri::command]
+pub fn set_game_window_mode(
+    game_id: String,
+    mode: Option<GameWindowMode>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| match mode {
+        Some(mode) => {
+            settings.display.game_window_modes.insert(game_id.clone(), mode);
+        },
+        None => {
+            settings.display.game_window_modes.remove(&game_id);
+        },
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+
+    if container.active_games_tracker.get(&game_id).is_some() {
+        apply_game_window_mode(&container, &game_id);
+    }
+    Ok(())
+}
+
+/// Current window mode of a running game.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if the process has no visible window.
+///
+/// # Examples
+/// ```javascript
+/// const mode = await invoke('get_game_window_mode', { pid: 1234 }); // 'exclusive_fullscreen'
+/// ```
+#[tauri::command]
+pub fn get_game_window_mode(pid: u32, container: State<DIContainer>) -> Result<GameWindowMode, BalamError> {
+    container.window_manager.window_mode(pid)
+}
+
+/// Switches a just-launched game to its saved window mode, in the background:
+/// waits for the window (launchers hand over to the game exe), then for the
+/// game to settle its own display mode.
+pub(crate) fn apply_game_window_mode(container: &DIContainer, game_id: &str) {
+    let Some(mode) = container
+        .settings_service
+        .get()
+        .display
+        .game_window_modes
+        .get(game_id)
+        .copied()
+    else {
+        return;
+    };
+    let tracker = container.active_games_tracker.clone();
+    let window_manager = container.window_manager.clone();
+    let game_id = game_id.to_string();
+    let spawned = std::thread::Builder::new()
+        .name("game-window-mode".to_string())
+        .spawn(move || {
+            let started = std::time::Instant::now();
+            let pid = loop {
+                // The tracked PID may change once the launcher exits
+                let Some(info) = tracker.get(&game_id) else {
+                    return;
+                };
+                if let Some(pid) = info.pid.filter(|pid| window_manager.window_mode(*pid).is_ok()) {
+                    break pid;
+                }
+                if started.elapsed() >= GAME_WINDOW_TIMEOUT {
+                    info!(
+                        "No window for {} after {:?}, window mode not applied",
+                        game_id, GAME_WINDOW_TIMEOUT
+                    );
+                    return;
+                }
+                std::thread::sleep(GAME_WINDOW_POLL);
+            };
+            std::thread::sleep(GAME_WINDOW_SETTLE);
+            if let Err(e) = window_manager.set_window_mode(pid, mode) {
+                warn!("Failed to switch {} to {:?}: {}", game_id, mode, e);
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start window mode thread: {}", e);
+    }
+}
+
+/// Checks whether vibrance can be adjusted on this GPU (NVIDIA only).
+#[must_use]
+#[tauri::command]
+pub fn supports_vibrance(container: State<DIContainer>) -> bool {
+    container.display().supports_vibrance()
+}
+
+/// GPU driver upscaling available on this machine (NVIDIA NIS or AMD RSR).
+///
+/// # Examples
+/// ```javascript
+/// const { technology, adjustable_sharpness } = await invoke('get_scaling_capabilities');
+/// if (technology === 'rsr') showSharpnessSlider();
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_scaling_capabilities(container: State<DIContainer>) -> ScalingCapabilities {
+    container.gpu_scaling.capabilities()
+}
+
+/// Saves (or removes, with `null`) the driver upscaling applied while a game runs.
+///
+/// Meant for games played below the panel's resolution (720p on a 1080p
+/// handheld): the driver upscales and sharpens them. The player's own
+/// setting comes back when the game exits. Applied right away when the
+/// game is running.
+///
+/// # Errors
+/// Returns error if sharpness is out of range or settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_game_scaling_profile', {
+///   gameId: 'steam_1091500',
+///   profile: { enabled: true, sharpness: 60 },
+/// });
+/// ```
+#[tauri::command]
+pub fn set_game_scaling_profile(
+    game_id: String,
+    profile: Option<GameScalingProfile>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| match profile {
+        Some(profile) => {
+            settings.display.game_scaling_profiles.insert(game_id.clone(), profile);
+        },
+        None => {
+            settings.display.game_scaling_profiles.remove(&game_id);
+        },
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+
+    if container.active_games_tracker.get(&game_id).is_some() {
+        if profile.is_some() {
+            apply_game_scaling(&container, &game_id);
+        } else if let Err(e) = container.gpu_scaling.restore() {
+            warn!("Failed to restore driver upscaling: {}", e);
+        }
+    }
+    Ok(())
+}
+
+/// Applies the driver upscaling profile of `game_id`, if it has one.
+///
+/// Failures are logged: a game must launch on drivers without NIS/RSR.
+pub(crate) fn apply_game_scaling(container: &DIContainer, game_id: &str) {
+    let Some(profile) = container
+        .settings_service
+        .get()
+        .display
+        .game_scaling_profiles
+        .get(game_id)
+        .copied()
+    else {
+        return;
+    };
+    if let Err(e) = container.gpu_scaling.apply(&profile) {
+        warn!("Failed to apply driver upscaling for {}: {}", game_id, e);
+    }
+}
+
+/// Applies the effective color profile for `game_id` (or the desktop when `None`).
+///
+/// Failures are logged: color is cosmetic and must never block a launch.
+pub(crate) fn apply_display_color(container: &DIContainer, game_id: Option<&str>) {
+    let profile = container.settings_service.get().display.effective_profile(game_id);
+    if let Err(e) = container.display().set_color_profile(&profile) {
+        warn!("Failed to apply color profile: {}", e);
+    }
 }
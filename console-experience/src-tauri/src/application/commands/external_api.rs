@@ -0,0 +1,37 @@
+/// External API Commands - Connection details for external tools
+///
+/// The API itself is enabled with the `external_api.enabled` setting; these
+/// commands give the player what a Stream Deck plugin or script needs to
+/// connect (pipe name and token).
+use crate::application::{external_api, DIContainer};
+use crate::domain::external_api::{ExternalApiInfo, EXTERNAL_API_TOKEN_FILE};
+use tauri::State;
+
+/// Returns the pipe name and token, creating the token on first use.
+///
+/// # Errors
+/// Returns error if the token file cannot be read or created.
+///
+/// # Examples
+/// ```javascript
+/// const { enabled, pipe_name, token } = await invoke('get_external_api_info');
+/// ```
+#[tauri::command]
+pub fn get_external_api_info(container: State<DIContainer>) -> Result<ExternalApiInfo, String> {
+    external_api::info(container.settings_service.get().external_api.enabled)
+}
+
+/// Replaces the token (e.g., after sharing it by mistake).
+///
+/// # Errors
+/// Returns error if the token file cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const { token } = await invoke('regenerate_external_api_token');
+/// ```
+#[tauri::command]
+pub fn regenerate_external_api_token(container: State<DIContainer>) -> Result<ExternalApiInfo, String> {
+    external_api::regenerate_token(EXTERNAL_API_TOKEN_FILE)?;
+    external_api::info(container.settings_service.get().external_api.enabled)
+}
@@ -4,14 +4,18 @@
 /// of the Balam FPS monitoring Windows Service.
 ///
 /// Features:
-/// - Safe install/uninstall with admin privilege checking
+/// - Install/uninstall/start/stop through the privilege broker when Balam
+///   is not elevated (one UAC prompt per session)
 /// - Automatic service updates when app version changes
 /// - Graceful error handling and recovery
 /// - Status monitoring and health checks
+use crate::application::DIContainer;
+use crate::domain::privilege::PrivilegedOperation;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::process::Command;
-use tauri::AppHandle;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
 
 /// Service status information
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,7 +38,7 @@ const SERVICE_DISPLAY_NAME: &str = "Balam FPS Monitoring Service";
 const SERVICE_DESCRIPTION: &str = "ETW-based FPS monitoring for Balam Console Experience";
 
 /// Check if the current process has administrator privileges
-fn is_elevated() -> bool {
+pub fn is_elevated() -> bool {
     #[cfg(windows)]
     {
         use windows::Win32::Foundation::HANDLE;
@@ -70,10 +74,49 @@ fn is_elevated() -> bool {
     false
 }
 
+/// Whether the FPS service is configured to start with Windows.
+pub(crate) fn is_fps_service_boot_start() -> bool {
+    Command::new("sc")
+        .args(["qc", SERVICE_NAME])
+        .output()
+        .is_ok_and(|output| output.status.success() && String::from_utf8_lossy(&output.stdout).contains("AUTO_START"))
+}
+
+/// Switches the FPS service between automatic (boot) and manual start.
+///
+/// Requires administrator privileges; no-op if the service is not installed.
+pub(crate) fn set_fps_service_boot_start(enabled: bool) -> Result<(), String> {
+    let start_type = if enabled { "auto" } else { "demand" };
+    let output = Command::new("sc")
+        .args(["config", SERVICE_NAME, "start=", start_type])
+        .output()
+        .map_err(|e| format!("Failed to configure service: {}", e))?;
+
+    if !output.status.success() {
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        // ERROR_SERVICE_DOES_NOT_EXIST: nothing to configure yet
+        if stdout.contains("1060") {
+            return Ok(());
+        }
+        return Err(format!("Failed to configure service start type: {}", stdout.trim()));
+    }
+
+    Ok(())
+}
+
+/// Runs a service change through the privilege broker (Balam is not elevated).
+///
+/// The first one prompts for UAC; later ones reuse the running broker.
+async fn run_privileged(app: &AppHandle, operation: PrivilegedOperation) -> Result<ServiceStatus, String> {
+    let broker = Arc::clone(&app.state::<DIContainer>().privileges);
+    tokio::task::spawn_blocking(move || broker.execute(&operation))
+        .await
+        .map_err(|e| format!("Task join error: {e}"))??;
+    get_fps_service_status().await
+}
+
 /// Get the service binary path from the app's resources
 fn get_service_binary_path(app: &AppHandle) -> Result<PathBuf, String> {
-    use tauri::Manager;
-
     let resource_dir = app
         .path()
         .resource_dir()
@@ -120,14 +163,6 @@ pub async fn get_fps_service_status() -> Result<ServiceStatus, String> {
 /// Install the FPS monitoring service
 #[tauri::command]
 pub async fn install_fps_service(app: AppHandle) -> Result<ServiceStatus, String> {
-    // Check admin privileges
-    if !is_elevated() {
-        return Err("Administrator privileges required to install service".to_string());
-    }
-
-    // Get service binary path
-    let service_path = get_service_binary_path(&app)?;
-
     // Check if already installed
     let status = get_fps_service_status().await?;
     if status.installed {
@@ -135,6 +170,14 @@ pub async fn install_fps_service(app: AppHandle) -> Result<ServiceStatus, String
         return Ok(status);
     }
 
+    // Without admin privileges the broker installs and starts it
+    if !is_elevated() {
+        return run_privileged(&app, PrivilegedOperation::InstallFpsService).await;
+    }
+
+    // Get service binary path
+    let service_path = get_service_binary_path(&app)?;
+
     // Install service
     let install_output = Command::new("sc")
         .args([
@@ -159,28 +202,27 @@ pub async fn install_fps_service(app: AppHandle) -> Result<ServiceStatus, String
         .ok();
 
     // Start service
-    start_fps_service().await?;
+    start_fps_service(app).await?;
 
     get_fps_service_status().await
 }
 
 /// Uninstall the FPS monitoring service
 #[tauri::command]
-pub async fn uninstall_fps_service() -> Result<ServiceStatus, String> {
-    // Check admin privileges
-    if !is_elevated() {
-        return Err("Administrator privileges required to uninstall service".to_string());
-    }
-
+pub async fn uninstall_fps_service(app: AppHandle) -> Result<ServiceStatus, String> {
     // Check if installed
     let status = get_fps_service_status().await?;
     if !status.installed {
         return Ok(status);
     }
 
+    if !is_elevated() {
+        return run_privileged(&app, PrivilegedOperation::UninstallFpsService).await;
+    }
+
     // Stop service first
     if status.running {
-        stop_fps_service().await.ok();
+        stop_fps_service(app).await.ok();
     }
 
     // Wait for service to fully stop
@@ -207,7 +249,12 @@ pub async fn uninstall_fps_service() -> Result<ServiceStatus, String> {
 
 /// Start the FPS monitoring service
 #[tauri::command]
-pub async fn start_fps_service() -> Result<ServiceStatus, String> {
+pub async fn start_fps_service(app: AppHandle) -> Result<ServiceStatus, String> {
+    // Its ETW session is one of the things safe mode rules out
+    if crate::application::safe_mode::is_active() {
+        return Err("The FPS service is disabled in safe mode".to_string());
+    }
+
     let status = get_fps_service_status().await?;
 
     if !status.installed {
@@ -218,6 +265,10 @@ pub async fn start_fps_service() -> Result<ServiceStatus, String> {
         return Ok(status);
     }
 
+    if !is_elevated() {
+        return run_privileged(&app, PrivilegedOperation::StartFpsService).await;
+    }
+
     let start_output = Command::new("sc")
         .args(["start", SERVICE_NAME])
         .output()
@@ -241,7 +292,7 @@ pub async fn start_fps_service() -> Result<ServiceStatus, String> {
 
 /// Stop the FPS monitoring service
 #[tauri::command]
-pub async fn stop_fps_service() -> Result<ServiceStatus, String> {
+pub async fn stop_fps_service(app: AppHandle) -> Result<ServiceStatus, String> {
     let status = get_fps_service_status().await?;
 
     if !status.installed {
@@ -252,6 +303,10 @@ pub async fn stop_fps_service() -> Result<ServiceStatus, String> {
         return Ok(status);
     }
 
+    if !is_elevated() {
+        return run_privileged(&app, PrivilegedOperation::StopFpsService).await;
+    }
+
     let stop_output = Command::new("sc")
         .args(["stop", SERVICE_NAME])
         .output()
@@ -289,7 +344,7 @@ pub async fn update_fps_service(app: AppHandle) -> Result<ServiceStatus, String>
 
     // Stop service
     if status.running {
-        stop_fps_service().await?;
+        stop_fps_service(app.clone()).await?;
     }
 
     // Wait for service to fully stop
@@ -316,7 +371,7 @@ pub async fn update_fps_service(app: AppHandle) -> Result<ServiceStatus, String>
     std::fs::copy(&service_path, current_path).map_err(|e| format!("Failed to copy service binary: {}", e))?;
 
     // Restart service
-    start_fps_service().await
+    start_fps_service(app).await
 }
 
 /// Enable/disable FPS monitoring (toggle service on/off)
@@ -329,14 +384,14 @@ pub async fn toggle_fps_service(app: AppHandle, enabled: bool) -> Result<Service
         if !status.installed {
             install_fps_service(app).await
         } else if !status.running {
-            start_fps_service().await
+            start_fps_service(app).await
         } else {
             Ok(status)
         }
     } else {
         // Disable: Stop service (but don't uninstall)
         if status.running {
-            stop_fps_service().await
+            stop_fps_service(app).await
         } else {
             Ok(status)
         }
@@ -1,15 +1,28 @@
 use crate::adapters;
-use crate::adapters::game::WindowsGameAdapter;
 use crate::adapters::identity_engine::IdentityEngine;
-use crate::adapters::metadata_adapter::MetadataAdapter;
-use crate::application::{ActiveGame, ActiveGameInfo, DIContainer};
+use crate::adapters::legendary_adapter::LegendaryAdapter;
+use crate::application::commands::display::{apply_display_color, apply_game_scaling, apply_game_window_mode};
+use crate::application::commands::library::{
+    load_dedup_overrides, load_library_organization, load_play_history, record_game_launch, sync_play_history,
+};
+use crate::application::commands::performance::apply_game_power_profile;
+use crate::application::commands::storage::{record_integrity_baseline, remove_integrity_manifest};
+use crate::application::services::ProcessInspectionService;
+use crate::application::{launch_hooks, ActiveGame, ActiveGameInfo, DIContainer};
 use crate::domain::game_process::GameProcess;
-use crate::domain::{Game, GameSource};
-use crate::ports::game_management_port::GameManagementPort;
+use crate::domain::launch_history::LaunchOutcome;
+use crate::domain::legendary::EpicLaunchBackend;
+use crate::domain::library_cache::CachedLibrary;
+use crate::domain::services::{ScanOutcome, ScannerStatus};
+use crate::domain::telemetry::{LaunchFailureKind, TelemetryEvent};
+use crate::domain::{Game, GameQuery, GameSource, InstallState};
+use crate::infrastructure::clock::now_secs;
 use serde::Serialize;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tauri::{Emitter, Manager, State};
+use tauri_plugin_opener::OpenerExt;
 use tracing::{error, info, warn};
 
 #[derive(Serialize)]
@@ -29,59 +42,167 @@ pub fn get_cache_path(app_handle: &tauri::AppHandle) -> Option<PathBuf> {
         .map(|p| p.join("games_cache.json"))
 }
 
-/// The core discovery engine with robust de-duplication.
-fn scan_all_games(container: &DIContainer) -> Vec<Game> {
+/// Next to the cache: Unix seconds of the scan that wrote it.
+const SCAN_MARKER_FILE: &str = "games_cache_scanned_at";
+
+/// The library as of the last scan (`None` before the first one).
+pub(crate) fn read_cached_games(app_handle: &tauri::AppHandle) -> Option<Vec<Game>> {
+    let content = fs::read_to_string(get_cache_path(app_handle)?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn read_scanned_at(app_handle: &tauri::AppHandle) -> Option<u64> {
+    let marker = get_cache_path(app_handle)?.with_file_name(SCAN_MARKER_FILE);
+    fs::read_to_string(marker).ok()?.trim().parse().ok()
+}
+
+/// Replaces the cache with a fresh scan and stamps the scan time.
+fn save_scanned_games(app_handle: &tauri::AppHandle, games: &[Game]) {
+    if let Some(cache_path) = get_cache_path(app_handle) {
+        if let Some(parent) = cache_path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(&cache_path, serde_json::to_string(games).unwrap_or_default());
+        let _ = fs::write(cache_path.with_file_name(SCAN_MARKER_FILE), now_secs().to_string());
+    }
+}
+
+/// Adds the cached manual games to a fresh scan, and the cached games of
+/// scanners that failed or timed out (kept until a scan finds them again).
+fn merge_cached_games(app_handle: &tauri::AppHandle, games: &mut Vec<Game>, statuses: &[ScannerStatus]) {
+    let Some(cached_games) = read_cached_games(app_handle) else {
+        return;
+    };
+    let unfinished: Vec<GameSource> = statuses
+        .iter()
+        .filter(|status| !matches!(status.outcome, ScanOutcome::Found { .. }))
+        .map(|status| status.source)
+        .collect();
+    for cg in cached_games {
+        let keep = cg.source == GameSource::Manual || unfinished.contains(&cg.source);
+        if keep && !games.iter().any(|g| g.path == cg.path) {
+            games.push(cg);
+        }
+    }
+}
+
+/// Cached games with parental filtering, the player's organization and
+/// tracked play times applied.
+fn present_cached_games(
+    games: Vec<Game>,
+    query: Option<GameQuery>,
+    app_handle: &tauri::AppHandle,
+    container: &DIContainer,
+) -> Vec<Game> {
+    // Games restricted by the active parental profile are not even listed
+    let mut games = container.parental.filter_games(games);
+    // Artwork fetched since the cache was written
+    container.artwork.prepare(&mut games);
+    load_play_history(app_handle).apply_last_played(&mut games);
+    load_library_organization(app_handle).apply(games, &query.unwrap_or_default())
+}
+
+/// The core discovery engine with robust de-duplication
+/// (respecting the user's merge/split corrections).
+///
+/// Each scanner's outcome is emitted as `scan-source-finished` as soon as it
+/// is known and returned with the games.
+fn scan_all_games(app_handle: &tauri::AppHandle, container: &DIContainer) -> (Vec<Game>, Vec<ScannerStatus>) {
     info!("CRITICAL: Starting fresh de-duplicated scan...");
 
-    // 1. Discover games using GameDiscoveryService (handles all scanners)
-    let raw_games = match container.game_discovery_service.discover() {
-        Ok(games) => games,
+    // 1. Discover games using GameDiscoveryService (scanners run concurrently)
+    let report = container.game_discovery_service.discover_with_progress(&|status| {
+        let _ = app_handle.emit("scan-source-finished", status);
+    });
+    let (raw_games, statuses) = match report {
+        Ok(report) => (report.games, report.scanners),
         Err(e) => {
             warn!("Game discovery failed: {}", e);
-            return Vec::new();
+            return (Vec::new(), Vec::new());
         },
     };
 
     // 2. Deduplicate using GameDeduplicationService
-    let unique_games = container.game_deduplication_service.deduplicate(raw_games);
+    let mut unique_games = container
+        .game_deduplication_service
+        .deduplicate_with(raw_games, &load_dedup_overrides(app_handle));
+
+    // 3. Store clients the games need running (user's choice first)
+    let dependencies = container.settings_service.get().library.launcher_dependencies;
+    for game in &mut unique_games {
+        game.launcher_dependency = dependencies
+            .get(&game.id)
+            .copied()
+            .or_else(|| adapters::process_launcher::detect_launcher_dependency(game));
+    }
 
     info!("Scan complete. Found {} unique games.", unique_games.len());
-    unique_games
+    (unique_games, statuses)
 }
 
+/// Returns the library, filtered and sorted with the user's organization.
+///
+/// Served from the last scan's cache; only the very first start, before any
+/// scan, scans here. Refreshing is `scan_games`' job.
+///
+/// Without `query` the library is sorted by title with hidden games excluded.
+///
+/// # Examples
+/// ```javascript
+/// const favorites = await invoke('get_games', { query: { favorites_only: true, sort: 'last_played' } });
+/// const coop = await invoke('get_games', { query: { collection_id: 'col_couch_co_op' } });
+/// ```
 #[tauri::command]
 #[must_use]
-pub fn get_games(app_handle: tauri::AppHandle, container: State<DIContainer>) -> Vec<Game> {
-    let mut games = scan_all_games(&container);
+pub fn get_games(query: Option<GameQuery>, app_handle: tauri::AppHandle, container: State<DIContainer>) -> Vec<Game> {
+    let games = read_cached_games(&app_handle).unwrap_or_else(|| load_games(&app_handle, &container));
+    present_cached_games(games, query, &app_handle, &container)
+}
+
+/// Returns the cached library and when it was scanned; never scans.
+///
+/// # Examples
+/// ```javascript
+/// const { games, age_secs, stale } = await invoke('get_games_cached');
+/// if (stale) showRefreshHint(age_secs); // "library updated 2h ago — refresh?"
+/// ```
+#[tauri::command]
+#[must_use]
+pub fn get_games_cached(
+    query: Option<GameQuery>,
+    app_handle: tauri::AppHandle,
+    container: State<DIContainer>,
+) -> CachedLibrary {
+    let games = read_cached_games(&app_handle).unwrap_or_default();
+    CachedLibrary::new(
+        present_cached_games(games, query, &app_handle, &container),
+        read_scanned_at(&app_handle),
+        now_secs(),
+    )
+}
+
+/// Scans every store and merges manual games (unfiltered, cache refreshed).
+pub(crate) fn load_games(app_handle: &tauri::AppHandle, container: &DIContainer) -> Vec<Game> {
+    let (mut games, statuses) = scan_all_games(app_handle, container);
 
     // Merge with Manual games from cache
-    if let Some(cache_path) = get_cache_path(&app_handle) {
-        if let Ok(content) = fs::read_to_string(&cache_path) {
-            if let Ok(cached_games) = serde_json::from_str::<Vec<Game>>(&content) {
-                for cg in cached_games {
-                    if cg.source == GameSource::Manual && !games.iter().any(|g| g.path == cg.path) {
-                        games.push(cg);
-                    }
-                }
-            }
-        }
-    }
+    merge_cached_games(app_handle, &mut games, &statuses);
 
-    MetadataAdapter::ensure_metadata_cached(&mut games, &app_handle);
+    // Cached artwork now, the rest from the artwork workers
+    container.artwork.retry_missing();
+    container.artwork.prepare(&mut games);
+    sync_play_history(app_handle, &mut games);
+    container.external_games.set_library(&games);
 
-    // Save clean list to cache
-    if let Some(cache_path) = get_cache_path(&app_handle) {
-        if let Some(parent) = cache_path.parent() {
-            let _ = fs::create_dir_all(parent);
-        }
-        let _ = fs::write(&cache_path, serde_json::to_string(&games).unwrap_or_default());
-    }
+    save_scanned_games(app_handle, &games);
 
     games
 }
 
 #[tauri::command]
 pub async fn scan_games(app_handle: tauri::AppHandle, container: State<'_, DIContainer>) -> Result<Vec<Game>, String> {
+    // A second scan while one runs is rejected (`BUSY`), not queued
+    let _permit = container.command_guard.enter("scan_games").await?;
     let start_time = std::time::Instant::now();
 
     info!("🔍 Starting async game scan...");
@@ -101,7 +222,7 @@ pub async fn scan_games(app_handle: tauri::AppHandle, container: State<'_, DICon
     let app_handle_clone = app_handle.clone();
 
     // Run heavy I/O operations in blocking thread pool
-    let games = tokio::task::spawn_blocking(move || {
+    let (games, scanners) = tokio::task::spawn_blocking(move || {
         // Emit progress: Discovering
         let _ = app_handle_clone.emit(
             "scan-progress",
@@ -113,7 +234,7 @@ pub async fn scan_games(app_handle: tauri::AppHandle, container: State<'_, DICon
         );
 
         // 1. Scan all games (heavy I/O: Steam, Epic, Xbox, Registry)
-        let mut games = scan_all_games(&container_clone);
+        let (mut games, scanners) = scan_all_games(&app_handle_clone, &container_clone);
 
         // Emit progress: Deduplicating
         let _ = app_handle_clone.emit(
@@ -125,46 +246,38 @@ pub async fn scan_games(app_handle: tauri::AppHandle, container: State<'_, DICon
             }),
         );
 
-        // 2. Merge with Manual games from cache
-        if let Some(cache_path) = get_cache_path(&app_handle_clone) {
-            if let Ok(content) = fs::read_to_string(&cache_path) {
-                if let Ok(cached_games) = serde_json::from_str::<Vec<Game>>(&content) {
-                    for cg in cached_games {
-                        if cg.source == GameSource::Manual && !games.iter().any(|g| g.path == cg.path) {
-                            games.push(cg);
-                        }
-                    }
-                }
-            }
-        }
+        // 2. Merge with Manual games (and games of scanners that didn't finish) from cache
+        merge_cached_games(&app_handle_clone, &mut games, &scanners);
 
         // Emit progress: Metadata
         let _ = app_handle_clone.emit(
             "scan-progress",
             serde_json::json!({
-                "step": "Extracting metadata...",
+                "step": "Loading artwork...",
                 "current": 75,
                 "total": 100
             }),
         );
 
-        // 3. Enrich metadata
-        MetadataAdapter::ensure_metadata_cached(&mut games, &app_handle_clone);
+        // 3. Cached artwork now; missing artwork arrives as `game-artwork-ready`
+        container_clone.artwork.retry_missing();
+        container_clone.artwork.prepare(&mut games);
+        sync_play_history(&app_handle_clone, &mut games);
+        container_clone.external_games.set_library(&games);
 
         // 4. Save cache
-        if let Some(cache_path) = get_cache_path(&app_handle_clone) {
-            if let Some(parent) = cache_path.parent() {
-                let _ = fs::create_dir_all(parent);
-            }
-            let _ = fs::write(&cache_path, serde_json::to_string(&games).unwrap_or_default());
-        }
+        save_scanned_games(&app_handle_clone, &games);
 
-        games
+        (games, scanners)
     })
     .await
     .map_err(|e| format!("Scan task failed: {e}"))?;
 
     let duration_ms = start_time.elapsed().as_millis();
+    container.telemetry.record(&TelemetryEvent::Scan {
+        duration_ms: duration_ms as u64,
+        game_count: games.len(),
+    });
 
     info!("✅ Async scan complete: {} games in {}ms", games.len(), duration_ms);
 
@@ -173,7 +286,8 @@ pub async fn scan_games(app_handle: tauri::AppHandle, container: State<'_, DICon
         "scan-complete",
         serde_json::json!({
             "count": games.len(),
-            "duration_ms": duration_ms
+            "duration_ms": duration_ms,
+            "scanners": scanners
         }),
     );
 
@@ -187,7 +301,7 @@ pub fn add_game_manually(
     app_handle: tauri::AppHandle,
     container: State<DIContainer>,
 ) -> Result<Game, String> {
-    let mut current_games = get_games(app_handle.clone(), container.clone());
+    let mut current_games = load_games(&app_handle, &container);
 
     let identity = IdentityEngine::get_identity(&path);
     let canonical_path = &identity.canonical_path;
@@ -220,23 +334,33 @@ pub fn add_game_manually(
         logo: None,
         last_played: None,
         source: GameSource::Manual,
+        install_state: InstallState::Installed,
+        launcher_dependency: None,
+        update_pending: false,
     };
 
-    let mut temp = vec![game.clone()];
-    MetadataAdapter::ensure_metadata_cached(&mut temp, &app_handle);
-    game = temp[0].clone();
+    // The icon is extracted in the background (`game-artwork-ready`)
+    container.artwork.prepare(std::slice::from_mut(&mut game));
 
     current_games.push(game.clone());
     if let Some(cache_path) = get_cache_path(&app_handle) {
         let _ = fs::write(&cache_path, serde_json::to_string(&current_games).unwrap_or_default());
     }
 
+    // Hash the install while it is known good so verify_game_files has a baseline
+    let baseline_game = game.clone();
+    std::thread::spawn(move || {
+        if let Err(e) = record_integrity_baseline(&app_handle, &baseline_game) {
+            warn!("Could not record integrity baseline for {}: {}", baseline_game.title, e);
+        }
+    });
+
     Ok(game)
 }
 
 #[tauri::command]
 pub fn remove_game(id: String, app_handle: tauri::AppHandle, container: State<DIContainer>) -> Result<(), String> {
-    let mut current_games = get_games(app_handle.clone(), container);
+    let mut current_games = load_games(&app_handle, &container);
     let initial_len = current_games.len();
     current_games.retain(|g| g.id != id);
 
@@ -247,6 +371,7 @@ pub fn remove_game(id: String, app_handle: tauri::AppHandle, container: State<DI
     if let Some(cache_path) = get_cache_path(&app_handle) {
         let _ = fs::write(&cache_path, serde_json::to_string(&current_games).unwrap_or_default());
     }
+    remove_integrity_manifest(&app_handle, &id);
     Ok(())
 }
 
@@ -296,16 +421,37 @@ pub fn get_system_drives() -> Vec<String> {
     drives
 }
 
+/// Launches a game through the whole pipeline (parental check, pre-launch
+/// hooks, store client, watchdog, per-game profiles).
+///
+/// Runs on a worker thread: hooks and a cold-starting store client can take
+/// minutes, and the main thread must keep answering the UI heartbeat. While
+/// a store client starts, `launcher-wait-progress` reports the wait.
+///
+/// # Errors
+/// Returns error if the game is unknown, locked, not installed, a required
+/// hook fails or the game cannot be started.
+///
+/// # Examples
+/// ```javascript
+/// await listen('launcher-wait-progress', ({ payload }) => showWaiting(payload.launcher, payload.elapsed_secs));
+/// const session = await invoke('launch_game', { gameId: 'steam_730' });
+/// ```
 #[tauri::command]
-pub fn launch_game(
-    game_id: String,
-    app_handle: tauri::AppHandle,
-    container: State<DIContainer>,
-) -> Result<ActiveGame, String> {
+pub async fn launch_game(game_id: String, app_handle: tauri::AppHandle) -> Result<ActiveGame, String> {
+    tauri::async_runtime::spawn_blocking(move || launch_game_blocking(game_id, &app_handle))
+        .await
+        .map_err(|e| format!("Launch task failed: {e}"))?
+}
+
+/// Body of [`launch_game`]; blocks until the game is started.
+pub(crate) fn launch_game_blocking(game_id: String, app_handle: &tauri::AppHandle) -> Result<ActiveGame, String> {
+    let container = app_handle.state::<DIContainer>();
     info!("🎮 Launch request for game: {}", game_id);
+    container.parental.check_launch(&game_id).map_err(|e| e.to_string())?;
 
     // 1. Get all games to find the requested one
-    let games = get_games(app_handle.clone(), container.clone());
+    let games = load_games(app_handle, &container);
     let game = games
         .into_iter()
         .find(|g| g.id == game_id)
@@ -313,45 +459,223 @@ pub fn launch_game(
 
     info!("Found game: {} at path: {}", game.title, game.path);
 
+    let record_outcome = |failure: Option<LaunchFailureKind>| {
+        container.telemetry.record(&TelemetryEvent::Launch {
+            source: game.source,
+            failure,
+        });
+    };
+    // Launch history: the attempt stays open until the watchdog reports the outcome
+    container.launch_history.begin(&game.id, game.source);
+    let record_failure = |message: String| {
+        container
+            .launch_history
+            .finish(&game.id, LaunchOutcome::Failed, Some(message.clone()));
+        message
+    };
+
+    // Downloads/updates in progress would launch a half-written install
+    if !game.install_state.is_playable() {
+        record_outcome(Some(LaunchFailureKind::NotReady));
+        return Err(record_failure(format!(
+            "{} is not ready to play ({:?})",
+            game.title, game.install_state
+        )));
+    }
+
     // 2. Validate path (skip for UWP apps with '!')
     let p = Path::new(&game.path);
     if !p.exists() && !game.path.contains('!') {
-        return Err("Invalid path".to_string());
+        record_outcome(Some(LaunchFailureKind::MissingFiles));
+        return Err(record_failure("Invalid path".to_string()));
     }
 
-    // 3. Launch the game and get PID (if available)
+    // Epic games set to Legendary need it installed
+    let library_settings = container.settings_service.get().library;
+    let legendary = if game.source == GameSource::Epic
+        && library_settings.epic_launch_backend(&game.id) == EpicLaunchBackend::Legendary
+    {
+        Some(
+            LegendaryAdapter::locate(&library_settings.legendary_path).ok_or_else(|| {
+                record_outcome(Some(LaunchFailureKind::LauncherError));
+                record_failure("Legendary not found: install it or set its path in the library settings".to_string())
+            })?,
+        )
+    } else {
+        None
+    };
+
+    // 3. Pre-launch hooks (scripts, companion apps); one launch pipeline at a time
+    let _queued = launch_hooks::queue_launch();
+    launch_hooks::run_pre_launch(app_handle, &container, &game).map_err(record_failure)?;
+
+    // 4. Launch the game and get PID (if available)
     let pid = adapters::process_launcher::launch_game_process(
         &game.id,
         &game.path,
-        &app_handle,
+        app_handle,
         container.active_games_tracker.clone(),
         None, // executable_name removed from Game struct
-    )?;
+        legendary,
+        game.launcher_dependency,
+    )
+    .map_err(|e| {
+        record_outcome(Some(LaunchFailureKind::LauncherError));
+        launch_hooks::stop_companions(&game.id);
+        record_failure(e)
+    })?;
+    record_outcome(None);
+    // With a PID the game is already running; otherwise its watchdog reports the start
+    if pid.is_some() {
+        container.launch_history.finish(&game.id, LaunchOutcome::Success, None);
+    }
+    record_game_launch(app_handle, &game.id);
 
-    // 4. Register in active games tracker
+    // 5. Register in active games tracker
     let active_info = ActiveGameInfo {
         game: game.clone(),
         pid,
         path: game.path.clone(),
+        suspended: false,
+        started_at: now_secs(),
     };
 
     container
         .active_games_tracker
         .register(game_id.clone(), active_info.clone());
 
+    // 6. Per-game color profile (vibrance, gamma) for the session
+    if container
+        .settings_service
+        .get()
+        .display
+        .game_color_profiles
+        .contains_key(&game_id)
+    {
+        apply_display_color(&container, Some(&game_id));
+    }
+
+    // 7. Per-game power mode / CPU boost
+    apply_game_power_profile(&container, &game_id);
+
+    // 8. Per-game window mode (borderless for the TOPMOST overlay)
+    apply_game_window_mode(&container, &game_id);
+
+    // 9. Per-game driver upscaling (NIS / RSR)
+    apply_game_scaling(&container, &game_id);
+
     info!("✅ Game launched successfully: {} (PID: {:?})", game.title, pid);
 
     // Return ActiveGame to frontend
     Ok(ActiveGame::from(active_info))
 }
 
+/// Starts a game's pending update in its store.
+///
+/// Epic games set to Legendary are updated by `legendary update` in the
+/// background; other stores open their downloads page, where the update is
+/// applied. A rescan clears `update_pending` once it is done.
+///
+/// # Errors
+/// Returns error if the game is unknown, its store has no update page or the
+/// store cannot be opened.
+///
+/// # Examples
+/// ```javascript
+/// if (game.update_pending) await invoke('start_update', { gameId: game.id });
+/// ```
+#[tauri::command]
+pub fn start_update(
+    game_id: String,
+    app_handle: tauri::AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let game = read_cached_games(&app_handle)
+        .unwrap_or_else(|| load_games(&app_handle, &container))
+        .into_iter()
+        .find(|g| g.id == game_id)
+        .ok_or_else(|| format!("Game not found: {game_id}"))?;
+
+    let library_settings = container.settings_service.get().library;
+    if game.source == GameSource::Epic && library_settings.epic_launch_backend(&game.id) == EpicLaunchBackend::Legendary
+    {
+        let legendary = LegendaryAdapter::locate(&library_settings.legendary_path)
+            .ok_or_else(|| "Legendary not found: install it or set its path in the library settings".to_string())?;
+        if let Some((config_dir, install)) = LegendaryAdapter::find_install(&game.raw_id) {
+            info!("⬇️ Updating {} through Legendary", game.title);
+            return legendary.update(&config_dir, &install);
+        }
+    }
+
+    let uri = game
+        .source
+        .update_uri()
+        .ok_or_else(|| format!("{} games are updated outside Balam", game.source.display_name()))?;
+    app_handle
+        .opener()
+        .open_url(uri, None::<&str>)
+        .map_err(|e| format!("Failed to open {}: {e}", game.source.display_name()))?;
+    info!("⬇️ Update of {} handed to {}", game.title, game.source.display_name());
+    Ok(())
+}
+
+/// Tracks a library game started outside Balam (Steam, desktop) like a launch.
+///
+/// Per-game profiles apply and a PID watchdog reports `game-ended`, so the
+/// overlay, FPS monitoring and play time work as for Balam's own launches.
+/// Emits `external-game-detected` with the `ActiveGame`.
+pub(crate) fn register_external_game(
+    app_handle: &tauri::AppHandle,
+    container: &DIContainer,
+    game: Game,
+    pid: u32,
+) -> ActiveGame {
+    let game_id = game.id.clone();
+    let active_info = ActiveGameInfo {
+        path: game.path.clone(),
+        game,
+        pid: Some(pid),
+        suspended: false,
+        started_at: now_secs(),
+    };
+    container
+        .active_games_tracker
+        .register(game_id.clone(), active_info.clone());
+    container.active_games_tracker.set_focused(&game_id);
+    record_game_launch(app_handle, &game_id);
+
+    if container
+        .settings_service
+        .get()
+        .display
+        .game_color_profiles
+        .contains_key(&game_id)
+    {
+        apply_display_color(container, Some(&game_id));
+    }
+    apply_game_power_profile(container, &game_id);
+    apply_game_window_mode(container, &game_id);
+    apply_game_scaling(container, &game_id);
+
+    adapters::process_launcher::watchdogs::start_watchdog(
+        pid,
+        app_handle.clone(),
+        container.active_games_tracker.clone(),
+        game_id,
+    );
+
+    let active_game = ActiveGame::from(active_info);
+    let _ = app_handle.emit("external-game-detected", &active_game);
+    active_game
+}
+
 /// Get the currently active game (used by overlay window which has no Zustand store)
 #[tauri::command]
 pub fn get_active_game(container: State<DIContainer>) -> Option<ActiveGame> {
-    let active_games = container.active_games_tracker.list_active();
-    active_games
-        .into_iter()
-        .next()
+    // With several sessions, the overlay targets the focused one
+    container
+        .active_games_tracker
+        .focused()
         .and_then(|game_id| container.active_games_tracker.get(&game_id))
         .map(ActiveGame::from)
 }
@@ -369,8 +693,12 @@ pub fn kill_game(pid: u32, container: State<DIContainer>) -> Result<(), String>
             if let Some(info) = container.active_games_tracker.get(&game_id) {
                 if info.pid.is_none() || info.pid == Some(0) {
                     info!("Found active game without PID: {} - killing by path", game_id);
-                    kill_by_path(&info.path)?;
+                    kill_by_path(&container.processes, &info.path)?;
                     container.active_games_tracker.unregister(&game_id);
+                    // Closed while still starting: the launch was cancelled
+                    container
+                        .launch_history
+                        .finish(&game_id, LaunchOutcome::Cancelled, None);
                     return Ok(());
                 }
             }
@@ -386,7 +714,8 @@ pub fn kill_game(pid: u32, container: State<DIContainer>) -> Result<(), String>
             if info.pid == Some(pid) {
                 info!("Found game in tracker: {} - killing by path and PID", game_id);
 
-                let killed = kill_by_pid(pid).is_ok() || kill_by_path(&info.path).is_ok();
+                let killed = kill_by_pid(&container.processes, pid).is_ok()
+                    || kill_by_path(&container.processes, &info.path).is_ok();
 
                 if killed {
                     container.active_games_tracker.unregister(&game_id);
@@ -398,63 +727,44 @@ pub fn kill_game(pid: u32, container: State<DIContainer>) -> Result<(), String>
     }
 
     warn!("Game not found in tracker - attempting kill by PID alone");
-    kill_by_pid(pid)?;
+    kill_by_pid(&container.processes, pid)?;
 
     Ok(())
 }
 
-fn kill_by_pid(pid: u32) -> Result<(), String> {
-    use sysinfo::{Pid, System};
-
-    let mut sys = System::new_all();
-    sys.refresh_processes();
-
-    let target_pid = Pid::from_u32(pid);
-    if let Some(process) = sys.process(target_pid) {
-        info!("Killing process by PID: {} ({})", pid, process.name());
-        process.kill();
+fn kill_by_pid(processes: &ProcessInspectionService, pid: u32) -> Result<(), String> {
+    info!("Killing process by PID: {}", pid);
+    if processes.kill(pid) {
         Ok(())
     } else {
         Err(format!("Process not found: {pid}"))
     }
 }
 
-fn kill_by_path(path: &str) -> Result<(), String> {
+fn kill_by_path(processes: &ProcessInspectionService, path: &str) -> Result<(), String> {
     info!("BALAM KILLER: Targeting path: {}", path);
 
     // 1. Handle UWP/Xbox (Microsoft Store)
     if path.contains('!') {
-        let family_name = path.split('!').next().ok_or("Invalid AppID")?;
-        info!("Killing UWP process family: {}", family_name);
-        let _ = std::process::Command::new("powershell")
-            .args([
-                "-Command",
-                &format!("Get-AppxPackage -Name *{family_name}* | Stop-Process -ErrorAction SilentlyContinue"),
-            ])
-            .output();
-        return Ok(());
+        info!("Terminating UWP package of: {}", path);
+        return adapters::process_launcher::terminate_uwp_package(path);
     }
 
     // 2. Ultra-Robust Kill: Search all processes by Path
-    use sysinfo::System;
-    let mut sys = System::new_all();
-    sys.refresh_all();
-
     let target_path_buf = PathBuf::from(path);
     let target_path_str = target_path_buf.to_string_lossy().to_lowercase();
     let mut found_and_killed = false;
 
-    for (pid, process) in sys.processes() {
-        if let Some(exe_path) = process.exe() {
+    for process in processes.processes() {
+        if let Some(exe_path) = &process.exe {
             let exe_path_str = exe_path.to_string_lossy().to_lowercase();
 
             if exe_path_str.starts_with(&target_path_str) || exe_path_str == target_path_str {
                 info!(
                     "BALAM KILLER: MATCH! Terminating process: {:?} (PID: {})",
-                    process.name(),
-                    pid
+                    process.name, process.pid
                 );
-                let _ = process.kill();
+                let _ = processes.kill(process.pid);
                 found_and_killed = true;
             }
         }
@@ -487,15 +797,22 @@ fn kill_by_path(path: &str) -> Result<(), String> {
 }
 
 #[tauri::command]
-pub fn get_running_game() -> Result<Option<GameProcess>, String> {
-    let adapter = WindowsGameAdapter::new();
-    adapter.get_current_game()
+pub fn get_running_game(container: State<DIContainer>) -> Result<Option<GameProcess>, String> {
+    container.game_adapter.get_current_game()
 }
 
 #[tauri::command]
-pub fn close_current_game(pid: u32) -> Result<bool, String> {
+pub fn close_current_game(pid: u32, container: State<DIContainer>) -> Result<bool, String> {
     info!("🔴 CLOSE_CURRENT_GAME COMMAND CALLED - PID: {}", pid);
-    let adapter = WindowsGameAdapter::new();
+    let adapter = &container.game_adapter;
+
+    // A frozen process never answers WM_CLOSE: thaw it first so it can save
+    if let Some((game_id, info)) = container.active_games_tracker.find_by_pid(pid) {
+        if info.suspended && adapter.resume_game(pid).is_ok() {
+            container.active_games_tracker.set_suspended(&game_id, false);
+        }
+    }
+
     let result = adapter.close_game(pid);
     match &result {
         Ok(graceful) => {
@@ -507,3 +824,112 @@ pub fn close_current_game(pid: u32) -> Result<bool, String> {
     }
     result
 }
+
+/// Interval of the auto-resume focus check for suspended games
+const AUTO_RESUME_POLL_MS: u64 = 500;
+
+/// Suspends ("sleeps") a running game, console quick-resume style.
+///
+/// The game is frozen in memory and Balam is brought to the front. The game is
+/// resumed automatically as soon as its window regains focus, or explicitly
+/// with `resume_game`. Emits `game-suspended` / `game-resumed`.
+///
+/// # Errors
+/// Returns error if the game has no tracked PID (Steam/Xbox fallback), is not
+/// tracked, or the process cannot be suspended.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('suspend_game', { pid: activeGame.pid });
+/// ```
+#[tauri::command]
+pub fn suspend_game(
+    pid: u32,
+    app_handle: tauri::AppHandle,
+    container: State<DIContainer>,
+) -> Result<ActiveGame, String> {
+    if pid == 0 {
+        return Err("Cannot suspend a game without a tracked process".to_string());
+    }
+
+    let tracker = container.active_games_tracker.clone();
+    let (game_id, info) = tracker
+        .find_by_pid(pid)
+        .ok_or_else(|| format!("No active game with PID {pid}"))?;
+    if info.suspended {
+        return Ok(ActiveGame::from(info));
+    }
+
+    let adapter = Arc::clone(&container.game_adapter);
+    adapter.suspend_game(pid)?;
+    tracker.set_suspended(&game_id, true);
+    let _ = app_handle.emit("game-suspended", serde_json::json!({ "game_id": game_id, "pid": pid }));
+
+    adapters::process_launcher::window_manager::restore_window(&app_handle);
+
+    // Auto-resume when the user switches back to the game window
+    let monitor_app = app_handle.clone();
+    let monitor_tracker = tracker.clone();
+    let monitor_game_id = game_id.clone();
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_millis(AUTO_RESUME_POLL_MS));
+
+        // Stop once resumed manually or the game is gone
+        if !monitor_tracker.get(&monitor_game_id).is_some_and(|info| info.suspended) {
+            break;
+        }
+
+        if adapter.foreground_pid() == Some(pid) {
+            match adapter.resume_game(pid) {
+                Ok(()) => {
+                    monitor_tracker.set_suspended(&monitor_game_id, false);
+                    info!("▶️ Auto-resumed {} (window refocused)", monitor_game_id);
+                    let _ = monitor_app.emit(
+                        "game-resumed",
+                        serde_json::json!({ "game_id": monitor_game_id, "pid": pid }),
+                    );
+                },
+                Err(e) => error!("Auto-resume failed for {}: {}", monitor_game_id, e),
+            }
+            break;
+        }
+    });
+
+    tracker
+        .get(&game_id)
+        .map(ActiveGame::from)
+        .ok_or_else(|| format!("Game {game_id} exited while suspending"))
+}
+
+/// Resumes a game previously suspended with `suspend_game`.
+///
+/// # Errors
+/// Returns error if the game is not tracked or the process cannot be resumed.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('resume_game', { pid: activeGame.pid });
+/// ```
+#[tauri::command]
+pub fn resume_game(
+    pid: u32,
+    app_handle: tauri::AppHandle,
+    container: State<DIContainer>,
+) -> Result<ActiveGame, String> {
+    let tracker = &container.active_games_tracker;
+    let (game_id, info) = tracker
+        .find_by_pid(pid)
+        .ok_or_else(|| format!("No active game with PID {pid}"))?;
+    if !info.suspended {
+        return Ok(ActiveGame::from(info));
+    }
+
+    container.game_adapter.resume_game(pid)?;
+    tracker.set_suspended(&game_id, false);
+    let _ = app_handle.emit("game-resumed", serde_json::json!({ "game_id": game_id, "pid": pid }));
+
+    tracker
+        .get(&game_id)
+        .map(ActiveGame::from)
+        .ok_or_else(|| format!("Game {game_id} exited while resuming"))
+}
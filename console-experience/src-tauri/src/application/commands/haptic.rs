@@ -1,9 +1,10 @@
-use crate::adapters::haptic::GilrsHapticAdapter;
-use crate::domain::{HapticFeedback, HapticIntensity};
-use crate::ports::haptic_port::HapticPort;
+use crate::application::DIContainer;
+use crate::domain::haptic::HAPTIC_PATTERNS;
+use crate::domain::{HapticEvent, HapticFeedback, HapticIntensity, HapticPattern};
+use tauri::State;
 
 #[tauri::command]
-pub async fn trigger_haptic(intensity: String, duration_ms: u64) -> Result<(), String> {
+pub fn trigger_haptic(container: State<DIContainer>, intensity: String, duration_ms: u64) -> Result<(), String> {
     let intensity_enum = match intensity.to_lowercase().as_str() {
         "weak" => HapticIntensity::Weak,
         "medium" => HapticIntensity::Medium,
@@ -12,27 +13,64 @@ pub async fn trigger_haptic(intensity: String, duration_ms: u64) -> Result<(), S
     };
 
     let feedback = HapticFeedback::new(intensity_enum, duration_ms);
-    let adapter = GilrsHapticAdapter::new().map_err(|e| format!("Failed to initialize haptic adapter: {e}"))?;
-    HapticPort::trigger(&adapter, feedback).await
+    container.haptics.play(HapticPattern::from_feedback(feedback));
+    Ok(())
 }
 
 #[tauri::command]
-pub fn is_haptic_supported() -> Result<bool, String> {
-    let adapter = GilrsHapticAdapter::new().map_err(|e| format!("Failed to initialize haptic adapter: {e}"))?;
-    Ok(HapticPort::is_supported(&adapter))
+pub fn is_haptic_supported(container: State<DIContainer>) -> Result<bool, String> {
+    Ok(container.haptics.is_supported())
 }
 
+/// Queues a built-in pattern (`double-pulse`, `ramp`, `heartbeat`...).
+///
+/// # Examples
+/// ```javascript
+/// await invoke('play_haptic_pattern', { name: 'heartbeat' });
+/// ```
 #[tauri::command]
-pub async fn haptic_navigation() -> Result<(), String> {
-    trigger_haptic("weak".to_string(), 200).await
+pub fn play_haptic_pattern(container: State<DIContainer>, name: String) -> Result<(), String> {
+    let pattern = HapticPattern::builtin(&name).ok_or_else(|| format!("Unknown haptic pattern: {name}"))?;
+    container.haptics.play(pattern);
+    Ok(())
 }
 
+/// Built-in patterns with their pulses (for the settings preview).
+#[must_use]
 #[tauri::command]
-pub async fn haptic_action() -> Result<(), String> {
-    trigger_haptic("medium".to_string(), 300).await
+pub fn list_haptic_patterns() -> Vec<HapticPattern> {
+    HAPTIC_PATTERNS
+        .iter()
+        .filter_map(|name| HapticPattern::builtin(name))
+        .collect()
 }
 
+/// Stops the rumble and drops queued patterns.
 #[tauri::command]
-pub async fn haptic_event() -> Result<(), String> {
-    trigger_haptic("strong".to_string(), 500).await
+pub fn stop_haptics(container: State<DIContainer>) {
+    container.haptics.cancel();
+}
+
+fn play_event(container: &DIContainer, event: HapticEvent) {
+    container
+        .haptics
+        .play_event(event, &container.settings_service.get().haptics);
+}
+
+#[tauri::command]
+pub fn haptic_navigation(container: State<DIContainer>) -> Result<(), String> {
+    play_event(&container, HapticEvent::Navigation);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn haptic_action(container: State<DIContainer>) -> Result<(), String> {
+    play_event(&container, HapticEvent::Action);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn haptic_event(container: State<DIContainer>) -> Result<(), String> {
+    play_event(&container, HapticEvent::Event);
+    Ok(())
 }
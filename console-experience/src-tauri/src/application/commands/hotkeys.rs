@@ -0,0 +1,129 @@
+use crate::adapters::hotkeys::HotkeyManager;
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::controller_input::{ExtraButton, NavAction};
+use crate::domain::hotkey::{GamepadButton, HotkeyAction};
+use crate::domain::settings::AppSettings;
+use tauri::{AppHandle, State};
+use tracing::info;
+
+/// Rebinds the keyboard shortcut of an action.
+///
+/// The new binding is validated (syntax, duplicates, reserved media keys)
+/// before being saved. Registration with the OS happens through the settings
+/// listener, so the old shortcut is released immediately.
+///
+/// # Arguments
+/// * `action` - Action to rebind (e.g. `"toggle_overlay"`)
+/// * `accelerator` - New shortcut (e.g. `"Ctrl+Alt+O"`), empty string to unbind
+///
+/// # Errors
+/// Returns error if the shortcut is invalid or conflicts with another binding,
+/// or settings are locked by parental controls.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('rebind_hotkey', { action: 'toggle_overlay', accelerator: 'Ctrl+Alt+O' });
+/// ```
+#[tauri::command]
+pub fn rebind_hotkey(
+    action: HotkeyAction,
+    accelerator: String,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<AppSettings, String> {
+    let mut settings = container.settings_service.get();
+    settings
+        .hotkeys
+        .set_keyboard_binding(action, accelerator.trim().to_string());
+    HotkeyManager::validate(&settings.hotkeys)?;
+
+    let saved = container.settings_service.replace(settings)?;
+    info!("⌨️ Rebound {:?} to '{}'", action, accelerator);
+    emit_settings_changed(&app_handle, &saved);
+    Ok(saved)
+}
+
+/// Sets the gamepad chord of an action (empty list to unbind).
+///
+/// # Errors
+/// Returns error if the chord has fewer than two buttons or is already used,
+/// or settings are locked by parental controls.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_gamepad_chord', { action: 'toggle_overlay', buttons: ['LB', 'RB', 'Start'] });
+/// ```
+#[tauri::command]
+pub fn set_gamepad_chord(
+    action: HotkeyAction,
+    buttons: Vec<GamepadButton>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<AppSettings, String> {
+    let mut settings = container.settings_service.get();
+    settings.hotkeys.set_gamepad_chord(action, buttons);
+    HotkeyManager::validate(&settings.hotkeys)?;
+
+    let saved = container.settings_service.replace(settings)?;
+    info!("🎮 Updated gamepad chord for {:?}", action);
+    emit_settings_changed(&app_handle, &saved);
+    Ok(saved)
+}
+
+/// Binds a raw HID extra button (paddle, touchpad click...) to a
+/// navigation action, or unbinds it with `action: null`.
+///
+/// Press the button while listening to `controller-extra-button` to find
+/// out which one it is.
+///
+/// # Errors
+/// Returns error if settings are locked by parental controls or cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_extra_button_binding', { button: 'LeftPaddle', action: 'BACK' });
+/// ```
+#[tauri::command]
+pub fn set_extra_button_binding(
+    button: ExtraButton,
+    action: Option<NavAction>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<AppSettings, String> {
+    let mut settings = container.settings_service.get();
+    settings.controller.set_extra_button(button, action);
+
+    let saved = container.settings_service.replace(settings)?;
+    info!("🎮 Bound {:?} to {:?}", button, action);
+    emit_settings_changed(&app_handle, &saved);
+    Ok(saved)
+}
+
+/// Checks an accelerator without saving it.
+///
+/// # Returns
+/// `Some(action)` if the shortcut is already bound to another action, `None` if free.
+///
+/// # Errors
+/// Returns error if the accelerator cannot be parsed.
+#[tauri::command]
+pub fn check_hotkey_conflict(
+    action: HotkeyAction,
+    accelerator: String,
+    container: State<DIContainer>,
+) -> Result<Option<HotkeyAction>, String> {
+    let shortcut = HotkeyManager::parse_accelerator(&accelerator)?;
+    let settings = container.settings_service.get();
+
+    for (other, bound) in settings.hotkeys.keyboard_bindings() {
+        if other == action || bound.trim().is_empty() {
+            continue;
+        }
+        if HotkeyManager::parse_accelerator(bound).is_ok_and(|s| s == shortcut) {
+            return Ok(Some(other));
+        }
+    }
+
+    Ok(None)
+}
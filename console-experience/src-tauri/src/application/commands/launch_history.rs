@@ -0,0 +1,35 @@
+/// Launch History Commands - How launches ended, per game and per store
+///
+/// Every launch attempt is recorded with its store, how long it took and how
+/// it ended (started, timed out, exited right away, cancelled or refused),
+/// so the UI can show which stores fail to launch on this machine.
+use crate::application::DIContainer;
+use crate::domain::launch_history::{LaunchRecord, StoreLaunchStats};
+use tauri::State;
+
+/// Launch attempts of a game (every game if omitted), newest first.
+///
+/// # Examples
+/// ```javascript
+/// const attempts = await invoke('get_launch_history', { gameId: 'epic_fortnite' });
+/// const failed = attempts.filter((a) => a.outcome !== 'success');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_launch_history(game_id: Option<String>, container: State<DIContainer>) -> Vec<LaunchRecord> {
+    container.launch_history.records(game_id.as_deref())
+}
+
+/// Launch outcomes per store, most launched first.
+///
+/// # Examples
+/// ```javascript
+/// const stats = await invoke('get_launch_stats');
+/// const epic = stats.find((s) => s.source === 'Epic');
+/// console.log(`Epic fails ${Math.round((1 - epic.success_rate) * 100)}% of launches`);
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_launch_stats(container: State<DIContainer>) -> Vec<StoreLaunchStats> {
+    container.launch_history.stats()
+}
@@ -0,0 +1,565 @@
+use crate::adapters::legendary_adapter::LegendaryAdapter;
+use crate::application::commands::game::{get_cache_path, load_games};
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::completion_time::{
+    CachedCompletion, CompletionCache, CompletionTimes, GameDetails, COMPLETION_CACHE_FILE,
+};
+use crate::domain::launcher_dependency::LauncherDependency;
+use crate::domain::legendary::EpicLaunchBackend;
+use crate::domain::services::DedupExplanation;
+use crate::domain::session_summary::SessionSummary;
+use crate::domain::value_objects::DedupOverrides;
+use crate::domain::{Game, GameCollection, GameQuery, LibraryOrganization, LibraryRow, PlayHistory};
+use crate::infrastructure::clock::now_secs;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{info, warn};
+
+const LIBRARY_FILE: &str = "library.json";
+const PLAY_HISTORY_FILE: &str = "play_history.json";
+const DEDUP_OVERRIDES_FILE: &str = "dedup_overrides.json";
+
+/// Default number of games per library row.
+const DEFAULT_ROW_LIMIT: usize = 12;
+
+/// Serializes read-modify-write of the library data files.
+static LIBRARY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Path of a library data file (next to `games_cache.json`).
+fn data_file(app_handle: &AppHandle, name: &str) -> Option<PathBuf> {
+    app_handle.path().app_local_data_dir().ok().map(|p| p.join(name))
+}
+
+/// Reads a data file (defaults if missing or unreadable).
+fn read_data_file<T: DeserializeOwned + Default>(app_handle: &AppHandle, name: &str) -> T {
+    let Some(path) = data_file(app_handle, name) else {
+        return T::default();
+    };
+    let Ok(content) = fs::read_to_string(&path) else {
+        return T::default();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        warn!("Corrupt {} ({}), starting empty", name, e);
+        T::default()
+    })
+}
+
+fn write_data_file<T: Serialize>(app_handle: &AppHandle, name: &str, value: &T) -> Result<(), String> {
+    let path = data_file(app_handle, name).ok_or("Cannot resolve app data directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create data directory: {e}"))?;
+    }
+    let json = serde_json::to_string_pretty(value).map_err(|e| format!("Failed to serialize {name}: {e}"))?;
+    fs::write(&path, json).map_err(|e| format!("Failed to save {name}: {e}"))
+}
+
+/// Loads the library organization (defaults if missing or unreadable).
+#[must_use]
+pub fn load_library_organization(app_handle: &AppHandle) -> LibraryOrganization {
+    read_data_file(app_handle, LIBRARY_FILE)
+}
+
+/// Applies `change` to the stored organization, saves it and emits `library-changed`.
+fn update_library<T>(
+    app_handle: &AppHandle,
+    change: impl FnOnce(&mut LibraryOrganization) -> Result<T, String>,
+) -> Result<T, String> {
+    let _guard = LIBRARY_LOCK.lock().map_err(|e| format!("Library lock poisoned: {e}"))?;
+
+    let mut organization = load_library_organization(app_handle);
+    let result = change(&mut organization)?;
+    write_data_file(app_handle, LIBRARY_FILE, &organization)?;
+
+    let _ = app_handle.emit("library-changed", &organization);
+    Ok(result)
+}
+
+/// Loads launch/play-time history (defaults if missing or unreadable).
+#[must_use]
+pub fn load_play_history(app_handle: &AppHandle) -> PlayHistory {
+    read_data_file(app_handle, PLAY_HISTORY_FILE)
+}
+
+/// Applies `change` to the stored play history and saves it if it returns `true`.
+fn update_play_history(app_handle: &AppHandle, change: impl FnOnce(&mut PlayHistory) -> bool) -> PlayHistory {
+    let Ok(_guard) = LIBRARY_LOCK.lock() else {
+        return load_play_history(app_handle);
+    };
+
+    let mut history = load_play_history(app_handle);
+    if change(&mut history) {
+        if let Err(e) = write_data_file(app_handle, PLAY_HISTORY_FILE, &history) {
+            warn!("Failed to save play history: {}", e);
+        }
+    }
+    history
+}
+
+/// Loads the user's merge/split corrections to de-duplication.
+#[must_use]
+pub fn load_dedup_overrides(app_handle: &AppHandle) -> DedupOverrides {
+    read_data_file(app_handle, DEDUP_OVERRIDES_FILE)
+}
+
+/// Applies `change` to the stored de-duplication overrides and saves them.
+fn update_dedup_overrides(
+    app_handle: &AppHandle,
+    change: impl FnOnce(&mut DedupOverrides) -> Result<(), String>,
+) -> Result<(), String> {
+    let _guard = LIBRARY_LOCK.lock().map_err(|e| format!("Library lock poisoned: {e}"))?;
+
+    let mut overrides = load_dedup_overrides(app_handle);
+    change(&mut overrides)?;
+    write_data_file(app_handle, DEDUP_OVERRIDES_FILE, &overrides)
+}
+
+/// Records a launch (feeds "Continue Playing").
+pub fn record_game_launch(app_handle: &AppHandle, game_id: &str) {
+    update_play_history(app_handle, |history| {
+        history.record_launch(game_id, now_secs());
+        true
+    });
+}
+
+/// Records a finished session's play time (feeds "Most Played").
+pub fn record_game_session(app_handle: &AppHandle, game_id: &str, play_time_seconds: u64) {
+    update_play_history(app_handle, |history| {
+        history.record_session(game_id, play_time_seconds);
+        true
+    });
+    info!("⏱️ Recorded {}s of play for {}", play_time_seconds, game_id);
+}
+
+/// Keeps a finished session's report card with the game's play history.
+pub fn record_session_summary(app_handle: &AppHandle, summary: SessionSummary) {
+    update_play_history(app_handle, |history| {
+        history.record_session_summary(summary);
+        true
+    });
+}
+
+/// Diffs a scan against the history (feeds "Recently Added") and fills in
+/// tracked `last_played` timestamps.
+pub fn sync_play_history(app_handle: &AppHandle, games: &mut [Game]) {
+    let history = update_play_history(app_handle, |history| {
+        history.record_scan(games.iter().map(|g| g.id.as_str()), now_secs())
+    });
+    history.apply_last_played(games);
+}
+
+/// Returns favorites, hidden games, tags and collections.
+///
+/// # Examples
+/// ```javascript
+/// const library = await invoke('get_library_organization');
+/// const isFavorite = library.favorites.includes(game.id);
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_library_organization(app_handle: AppHandle) -> LibraryOrganization {
+    load_library_organization(&app_handle)
+}
+
+/// Marks or unmarks a game as favorite.
+///
+/// # Errors
+/// Returns error if the library file cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_favorite', { gameId: 'steam_620', favorite: true });
+/// ```
+#[tauri::command]
+pub fn set_favorite(game_id: String, favorite: bool, app_handle: AppHandle) -> Result<(), String> {
+    update_library(&app_handle, |org| {
+        org.set_favorite(&game_id, favorite);
+        Ok(())
+    })?;
+    info!("⭐ {} favorite: {}", game_id, favorite);
+    Ok(())
+}
+
+/// Hides a game from the library (or shows it again).
+///
+/// Hidden games are only returned by `get_games` with `query.include_hidden`.
+///
+/// # Errors
+/// Returns error if the library file cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_hidden', { gameId: 'steam_228980', hidden: true });
+/// ```
+#[tauri::command]
+pub fn set_hidden(game_id: String, hidden: bool, app_handle: AppHandle) -> Result<(), String> {
+    update_library(&app_handle, |org| {
+        org.set_hidden(&game_id, hidden);
+        Ok(())
+    })?;
+    info!("🙈 {} hidden: {}", game_id, hidden);
+    Ok(())
+}
+
+/// Replaces the tags of a game (an empty list clears them).
+///
+/// # Errors
+/// Returns error if a tag is too long or the library file cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_game_tags', { gameId: 'epic_abc', tags: ['horror', 'single-player'] });
+/// ```
+#[tauri::command]
+pub fn set_game_tags(game_id: String, tags: Vec<String>, app_handle: AppHandle) -> Result<(), String> {
+    update_library(&app_handle, |org| org.set_tags(&game_id, &tags))
+}
+
+/// Creates an empty collection.
+///
+/// # Errors
+/// Returns error if the name is empty, too long or already used.
+///
+/// # Examples
+/// ```javascript
+/// const collection = await invoke('create_collection', { name: 'Couch Co-op' });
+/// ```
+#[tauri::command]
+pub fn create_collection(name: String, app_handle: AppHandle) -> Result<GameCollection, String> {
+    let collection = update_library(&app_handle, |org| org.create_collection(&name))?;
+    info!("📁 Collection created: {} ({})", collection.name, collection.id);
+    Ok(collection)
+}
+
+/// Deletes a collection (its games stay in the library).
+///
+/// # Errors
+/// Returns error if the collection does not exist.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('delete_collection', { collectionId: 'col_couch_co_op' });
+/// ```
+#[tauri::command]
+pub fn delete_collection(collection_id: String, app_handle: AppHandle) -> Result<(), String> {
+    update_library(&app_handle, |org| org.delete_collection(&collection_id))
+}
+
+/// Adds a game to a collection, or removes it with `assigned: false`.
+///
+/// # Errors
+/// Returns error if the collection does not exist.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('assign_to_collection', { gameId: 'steam_620', collectionId: 'col_couch_co_op', assigned: true });
+/// ```
+#[tauri::command]
+pub fn assign_to_collection(
+    game_id: String,
+    collection_id: String,
+    assigned: Option<bool>,
+    app_handle: AppHandle,
+) -> Result<GameCollection, String> {
+    update_library(&app_handle, |org| {
+        org.assign_to_collection(&game_id, &collection_id, assigned.unwrap_or(true))
+    })
+}
+
+/// Returns the home screen rows: "Continue Playing", "Recently Added" and
+/// "Most Played" (hidden games excluded, empty rows omitted).
+///
+/// # Arguments
+/// * `limit` - Maximum games per row (default 12)
+///
+/// # Examples
+/// ```javascript
+/// const rows = await invoke('get_library_rows', { limit: 10 });
+/// rows.forEach(row => console.log(row.kind, row.title, row.items.length));
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_library_rows(limit: Option<usize>, app_handle: AppHandle, container: State<DIContainer>) -> Vec<LibraryRow> {
+    let games = container.parental.filter_games(load_games(&app_handle, &container));
+    let visible = load_library_organization(&app_handle).apply(games, &GameQuery::default());
+    load_play_history(&app_handle).library_rows(&visible, limit.unwrap_or(DEFAULT_ROW_LIMIT), now_secs())
+}
+
+/// Completion times from the cache, or the provider when stale.
+///
+/// Provider failures are not errors: the page shows an expired answer, or none.
+fn completion_times(app_handle: &AppHandle, container: &DIContainer, game: &Game) -> Option<CompletionTimes> {
+    let now = now_secs();
+    let cache: CompletionCache = read_data_file(app_handle, COMPLETION_CACHE_FILE);
+    if let Some(entry) = cache.fresh(&game.id, now) {
+        return entry.times.clone();
+    }
+
+    match container.completion_times.lookup(&game.title) {
+        Ok(times) => {
+            // Re-read under the lock: another game page may have cached meanwhile
+            if let Ok(_guard) = LIBRARY_LOCK.lock() {
+                let mut cache: CompletionCache = read_data_file(app_handle, COMPLETION_CACHE_FILE);
+                cache.entries.insert(
+                    game.id.clone(),
+                    CachedCompletion {
+                        times: times.clone(),
+                        fetched_at: now,
+                    },
+                );
+                if let Err(e) = write_data_file(app_handle, COMPLETION_CACHE_FILE, &cache) {
+                    warn!("Failed to save completion times: {}", e);
+                }
+            }
+            times
+        },
+        Err(e) => {
+            warn!("Completion times unavailable for {}: {}", game.title, e);
+            cache.entries.get(&game.id).and_then(|entry| entry.times.clone())
+        },
+    }
+}
+
+/// Returns one game with its organization, play stats and completion times.
+///
+/// Completion times are only fetched when `library.completion_times` is
+/// enabled; answers are cached for a month (a week for unknown titles).
+///
+/// # Errors
+/// Returns error if no game has this ID.
+///
+/// # Examples
+/// ```javascript
+/// const details = await invoke('get_game_details', { gameId: 'steam_730' });
+/// if (details.completion_times?.main_story_hours) {
+///   label = `Main Story: ${details.completion_times.main_story_hours}h`;
+/// }
+/// ```
+#[tauri::command]
+pub async fn get_game_details(game_id: String, app_handle: AppHandle) -> Result<GameDetails, String> {
+    tokio::task::spawn_blocking(move || {
+        let container = app_handle.state::<DIContainer>();
+        let game = load_games(&app_handle, &container)
+            .into_iter()
+            .find(|game| game.id == game_id)
+            .ok_or_else(|| format!("Game not found: {game_id}"))?;
+        let organization = load_library_organization(&app_handle);
+        let play_stats = load_play_history(&app_handle)
+            .games
+            .get(&game.id)
+            .cloned()
+            .unwrap_or_default();
+        let completion_times = if container.settings_service.get().library.completion_times {
+            completion_times(&app_handle, &container, &game)
+        } else {
+            None
+        };
+
+        Ok(GameDetails {
+            favorite: organization.is_favorite(&game.id),
+            hidden: organization.is_hidden(&game.id),
+            tags: organization.tags.get(&game.id).cloned().unwrap_or_default(),
+            play_stats,
+            completion_times,
+            game,
+        })
+    })
+    .await
+    .map_err(|e| format!("Task join error: {e}"))?
+}
+
+/// Picks the launcher for an Epic game: the Epic Games Launcher (default) or
+/// Legendary, for users who avoid the official launcher.
+///
+/// # Errors
+/// Returns error if settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_epic_launch_backend', { gameId: 'epic_cd2bcbd7', backend: 'legendary' });
+/// ```
+#[tauri::command]
+pub fn set_epic_launch_backend(
+    game_id: String,
+    backend: EpicLaunchBackend,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| {
+        if backend == EpicLaunchBackend::default() {
+            settings.library.epic_launch_backends.remove(&game_id);
+        } else {
+            settings.library.epic_launch_backends.insert(game_id.clone(), backend);
+        }
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+    Ok(())
+}
+
+/// Marks the store client a game needs running (e.g. an EA game bought on
+/// Steam); `null` goes back to the detected one.
+///
+/// # Errors
+/// Returns error if settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_launcher_dependency', { gameId: 'reg_{A1B2C3}', dependency: 'ubisoft_connect' });
+/// ```
+#[tauri::command]
+pub fn set_launcher_dependency(
+    game_id: String,
+    dependency: Option<LauncherDependency>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| match dependency {
+        Some(dependency) => {
+            settings
+                .library
+                .launcher_dependencies
+                .insert(game_id.clone(), dependency);
+        },
+        None => {
+            settings.library.launcher_dependencies.remove(&game_id);
+        },
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+    Ok(())
+}
+
+/// Signs Legendary in to Epic, so games set to Legendary pass ownership checks.
+///
+/// Without a code the Epic Games Launcher's session is imported; otherwise
+/// `authorization_code` is the code shown after logging in at
+/// `https://legendary.gl/epiclogin` (e.g. in the store browser).
+///
+/// # Errors
+/// Returns error if Legendary is not installed or sign-in fails.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('legendary_login', { authorizationCode: code });
+/// ```
+#[tauri::command]
+pub async fn legendary_login(
+    authorization_code: Option<String>,
+    container: State<'_, DIContainer>,
+) -> Result<(), String> {
+    let legendary_path = container.settings_service.get().library.legendary_path;
+    tokio::task::spawn_blocking(move || {
+        let legendary = LegendaryAdapter::locate(&legendary_path).ok_or("Legendary not found")?;
+        legendary.login(
+            authorization_code
+                .as_deref()
+                .map(str::trim)
+                .filter(|code| !code.is_empty()),
+        )
+    })
+    .await
+    .map_err(|e| format!("Task join error: {e}"))?
+}
+
+/// Reclassifies a game found in the uninstall registry as "not a game".
+///
+/// The entry is remembered in the library's registry rules, so later scans
+/// skip it, and dropped from the games cache right away.
+///
+/// # Errors
+/// Returns error if the game was not found in the registry or settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('mark_not_a_game', { gameId: 'reg_{4F3B5A1C-0000}' });
+/// ```
+#[tauri::command]
+pub fn mark_not_a_game(game_id: String, app_handle: AppHandle, container: State<DIContainer>) -> Result<(), String> {
+    let key_name = game_id
+        .strip_prefix("reg_")
+        .ok_or("Only games found in the registry can be reclassified")?;
+    let saved = container
+        .settings_service
+        .update(|settings| settings.library.registry_rules.mark_not_game(key_name))?;
+    emit_settings_changed(&app_handle, &saved);
+
+    if let Some(cache_path) = get_cache_path(&app_handle) {
+        if let Ok(content) = fs::read_to_string(&cache_path) {
+            if let Ok(mut games) = serde_json::from_str::<Vec<Game>>(&content) {
+                games.retain(|game| game.id != game_id);
+                let _ = fs::write(&cache_path, serde_json::to_string(&games).unwrap_or_default());
+            }
+        }
+    }
+
+    info!("Registry entry {} marked as not a game", key_name);
+    Ok(())
+}
+
+/// Marks games as the same game, so only one of them (the first found, in
+/// store priority order) is listed from the next scan on.
+///
+/// # Errors
+/// Returns error if fewer than two games are given or the overrides cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('merge_games', { ids: ['steam_1091500', 'reg_{A1B2C3}'] });
+/// ```
+#[tauri::command]
+pub fn merge_games(ids: Vec<String>, app_handle: AppHandle) -> Result<(), String> {
+    update_dedup_overrides(&app_handle, |overrides| overrides.merge(&ids))?;
+    info!("🔗 Merged games: {:?}", ids);
+    Ok(())
+}
+
+/// Lists a game on its own from the next scan on, even if its identity
+/// matches another game.
+///
+/// # Errors
+/// Returns error if the overrides cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('split_game', { id: 'epic_cd2bcbd7' });
+/// ```
+#[tauri::command]
+pub fn split_game(id: String, app_handle: AppHandle) -> Result<(), String> {
+    update_dedup_overrides(&app_handle, |overrides| {
+        overrides.split(&id);
+        Ok(())
+    })?;
+    info!("✂️ Split game: {}", id);
+    Ok(())
+}
+
+/// Explains how a game is de-duplicated: its identity keys, the games
+/// sharing them and which one is listed.
+///
+/// # Errors
+/// Returns error if no store reports the game.
+///
+/// # Examples
+/// ```javascript
+/// const { identity_keys, matches, kept_id } = await invoke('explain_dedup', { id: 'epic_cd2bcbd7' });
+/// ```
+#[tauri::command]
+pub async fn explain_dedup(
+    id: String,
+    app_handle: AppHandle,
+    container: State<'_, DIContainer>,
+) -> Result<DedupExplanation, String> {
+    let container = container.inner().clone();
+    tokio::task::spawn_blocking(move || {
+        let games = container.game_discovery_service.discover().map_err(|e| e.to_string())?;
+        container
+            .game_deduplication_service
+            .explain(games, &load_dedup_overrides(&app_handle), &id)
+            .ok_or_else(|| format!("Game not found: {id}"))
+    })
+    .await
+    .map_err(|e| format!("Task join error: {e}"))?
+}
@@ -0,0 +1,211 @@
+/// Media Commands - Screenshots and replay clips of the running game
+///
+/// Screenshots are taken with the screenshot hotkey / gamepad chord or from
+/// the overlay, saved per game under `Pictures\Balam\Screenshots`, and
+/// announced with `screenshot-taken` (payload: the `Screenshot`, including
+/// its thumbnail path for the toast).
+///
+/// With `replay.enabled`, the focused game is recorded into a rolling
+/// buffer; `save_replay` (hotkey / chord) writes it to `Videos\Balam\Clips`
+/// and emits `replay-saved` with the `ReplayClip`. Both rumble with the
+/// pattern mapped to their event in `haptics.event_patterns`.
+///
+/// Both are tagged with the library id of the game that was running, which
+/// is what the per-game gallery (`get_media`) filters on.
+use crate::adapters::capture::{DxgiScreenCapture, MediaLibrary, ScreenshotStore};
+use crate::application::DIContainer;
+use crate::domain::haptic::HapticEvent;
+use crate::domain::media::{MediaItem, ReplayClip, ReplayStatus, Screenshot, UNKNOWN_GAME_FOLDER};
+use crate::domain::BalamError;
+use crate::ports::screen_capture_port::ScreenCapturePort;
+use std::path::Path;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+use tracing::warn;
+
+/// Captures the focused game (or the foreground window) and saves it.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if there is no window to capture, or a platform
+/// error if the capture or the PNG fails.
+///
+/// # Examples
+/// ```javascript
+/// const { path, thumbnail_path } = await invoke('take_screenshot');
+/// ```
+#[tauri::command]
+pub async fn take_screenshot(app: AppHandle) -> Result<Screenshot, BalamError> {
+    tokio::task::spawn_blocking(move || capture_screenshot(&app))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Saved screenshots, newest first; `game` limits the list to one game's folder.
+///
+/// # Errors
+/// Returns error if the screenshot folder cannot be read.
+///
+/// # Examples
+/// ```javascript
+/// const shots = await invoke('get_screenshots', { game: 'Elden Ring' });
+/// shots.map((shot) => convertFileSrc(shot.thumbnail_path ?? shot.path));
+/// ```
+#[tauri::command]
+pub fn get_screenshots(game: Option<String>) -> Result<Vec<Screenshot>, BalamError> {
+    ScreenshotStore::new().list(game.as_deref())
+}
+
+/// Gallery: screenshots and clips, newest first. With `game_id`, only the
+/// captures taken while that game was running.
+///
+/// Clip thumbnails are generated on first listing, so this can take a
+/// moment after new clips were saved.
+///
+/// # Errors
+/// Returns error if a capture folder cannot be read.
+///
+/// # Examples
+/// ```javascript
+/// const media = await invoke('get_media', { gameId: 'steam_1245620' });
+/// media.filter((item) => item.kind === 'clip');
+/// ```
+#[tauri::command]
+pub async fn get_media(game_id: Option<String>) -> Result<Vec<MediaItem>, BalamError> {
+    tokio::task::spawn_blocking(move || MediaLibrary::new().list(game_id.as_deref()))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Renames a screenshot or clip (the extension is kept).
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` for an empty or taken name, `NOT_FOUND` if
+/// the capture no longer exists.
+///
+/// # Examples
+/// ```javascript
+/// const item = await invoke('rename_media', { id: item.id, name: 'First win' });
+/// ```
+#[tauri::command]
+pub fn rename_media(id: String, name: String) -> Result<MediaItem, BalamError> {
+    MediaLibrary::new().rename(&id, &name)
+}
+
+/// Deletes a screenshot or clip and its thumbnail.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if the capture no longer exists.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('delete_media', { id: item.id });
+/// ```
+#[tauri::command]
+pub fn delete_media(id: String) -> Result<(), BalamError> {
+    MediaLibrary::new().delete(&id)
+}
+
+/// Screenshot hotkey and gamepad chord (off the input thread: encoding takes a moment).
+pub(crate) fn take_screenshot_in_background<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("screenshot".to_string())
+        .spawn(move || {
+            if let Err(e) = capture_screenshot(&app) {
+                warn!("Screenshot failed: {}", e);
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start screenshot thread: {}", e);
+    }
+}
+
+/// Saves the replay buffer (the last `replay.buffer_seconds`) as a clip.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if nothing has been recorded (replays off or no
+/// game running), or a platform error if the clip cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const { path, duration_seconds } = await invoke('save_replay');
+/// ```
+#[tauri::command]
+pub async fn save_replay(app: AppHandle) -> Result<ReplayClip, BalamError> {
+    tokio::task::spawn_blocking(move || write_replay(&app))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Whether the replay buffer is recording and how much it holds.
+///
+/// # Examples
+/// ```javascript
+/// const { recording, buffered_seconds } = await invoke('get_replay_status');
+/// ```
+#[tauri::command]
+pub fn get_replay_status(container: State<DIContainer>) -> ReplayStatus {
+    container.replay.status(&container.settings_service.get().replay)
+}
+
+/// Replay hotkey and gamepad chord (joining the segments takes a moment).
+pub(crate) fn save_replay_in_background<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("replay-save".to_string())
+        .spawn(move || {
+            if let Err(e) = write_replay(&app) {
+                warn!("Saving replay failed: {}", e);
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start replay thread: {}", e);
+    }
+}
+
+fn write_replay<R: Runtime>(app: &AppHandle<R>) -> Result<ReplayClip, BalamError> {
+    let container = app.state::<DIContainer>();
+    let target = container.active_games_tracker.capture_target();
+    let title = target
+        .as_ref()
+        .map_or(UNKNOWN_GAME_FOLDER, |info| info.game.title.as_str());
+    let settings = container.settings_service.get();
+
+    let clip = container.replay.save(title, settings.replay.buffer_seconds)?;
+    if let Some(info) = &target {
+        tag_capture(&clip.path, &info.game.id);
+    }
+    container
+        .haptics
+        .play_event(HapticEvent::ReplaySaved, &settings.haptics);
+    let _ = app.emit("replay-saved", &clip);
+    Ok(clip)
+}
+
+fn capture_screenshot<R: Runtime>(app: &AppHandle<R>) -> Result<Screenshot, BalamError> {
+    let container = app.state::<DIContainer>();
+
+    // Without a tracked session, whatever window is in front
+    let target = container.active_games_tracker.capture_target();
+    let (title, pid) = target.as_ref().map_or((UNKNOWN_GAME_FOLDER, None), |info| {
+        (info.game.title.as_str(), info.pid.filter(|pid| *pid != 0))
+    });
+
+    let frame = DxgiScreenCapture::new().capture_window(pid)?;
+    let screenshot = ScreenshotStore::new().save(title, &frame)?;
+    if let Some(info) = &target {
+        tag_capture(&screenshot.path, &info.game.id);
+    }
+    container
+        .haptics
+        .play_event(HapticEvent::Screenshot, &container.settings_service.get().haptics);
+    let _ = app.emit("screenshot-taken", &screenshot);
+    Ok(screenshot)
+}
+
+/// Files the capture under the game's id for the gallery (the capture
+/// itself is already saved, so a failure only costs the per-game filter).
+fn tag_capture(path: &str, game_id: &str) {
+    if let Err(e) = MediaLibrary::new().tag(Path::new(path), game_id) {
+        warn!("Failed to tag capture with game {}: {}", game_id, e);
+    }
+}
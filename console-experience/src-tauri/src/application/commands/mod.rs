@@ -1,19 +1,67 @@
+pub mod benchmark;
+pub mod browser;
+pub mod console_mode;
+pub mod dashboard;
 pub mod display;
+pub mod external_api;
 pub mod fps_service_manager;
 pub mod game;
 pub mod haptic;
+pub mod hotkeys;
+pub mod launch_history;
+pub mod library;
+pub mod media;
 pub mod network;
+pub mod notifications;
 pub mod overlay;
+pub mod parental;
 pub mod performance;
 pub mod pip;
+pub mod quick_settings;
+pub mod quiet_mode;
+pub mod remote;
+pub mod sessions;
+pub mod settings;
+pub mod storage;
+pub mod stutter;
+pub mod sunshine;
 pub mod system;
+pub mod telemetry;
+pub mod text_input;
+pub mod thermal;
+pub mod update;
+pub mod xbox;
 
+pub use benchmark::*;
+pub use browser::*;
+pub use console_mode::*;
+pub use dashboard::*;
 pub use display::*;
+pub use external_api::*;
 pub use fps_service_manager::*;
 pub use game::*;
 pub use haptic::*;
+pub use hotkeys::*;
+pub use launch_history::*;
+pub use library::*;
+pub use media::*;
 pub use network::*;
+pub use notifications::*;
 pub use overlay::*;
+pub use parental::*;
 pub use performance::*;
 pub use pip::*;
+pub use quick_settings::*;
+pub use quiet_mode::*;
+pub use remote::*;
+pub use sessions::*;
+pub use settings::*;
+pub use storage::*;
+pub use stutter::*;
+pub use sunshine::*;
 pub use system::*;
+pub use telemetry::*;
+pub use text_input::*;
+pub use thermal::*;
+pub use update::*;
+pub use xbox::*;
@@ -1,59 +1,195 @@
-use crate::adapters::bluetooth::WindowsBluetoothAdapter;
-use crate::adapters::wifi::WindowsWiFiAdapter;
-use crate::ports::bluetooth_port::{BluetoothDevice, BluetoothPairingConfig, BluetoothPort};
-use crate::ports::wifi_port::{WiFiConfig, WiFiNetwork, WiFiPort};
+use crate::adapters::wifi::CaptivePortalProbe;
+use crate::application::DIContainer;
+use crate::domain::network_quality::NetworkQuality;
+use crate::domain::wifi::Connectivity;
+use crate::domain::BalamError;
+use crate::ports::bluetooth_port::{BluetoothDevice, BluetoothPairingConfig, PairingListener};
+use crate::ports::network_port::NetworkDetails;
+use crate::ports::wifi_port::{WiFiConfig, WiFiNetwork, WiFiSecurity};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, State, WebviewUrl, WebviewWindowBuilder};
+use tracing::{info, warn};
+
+/// Window the captive portal login page is opened in.
+const CAPTIVE_PORTAL_WINDOW: &str = "captive-portal";
 
 // ============================================================================
 // WiFi Management Commands
 // ============================================================================
 
 #[tauri::command]
-pub fn scan_wifi_networks() -> Result<Vec<WiFiNetwork>, String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::scan_networks(&adapter)
+pub fn scan_wifi_networks(container: State<DIContainer>) -> Result<Vec<WiFiNetwork>, BalamError> {
+    container.wifi()?.scan_networks()
 }
 
 #[tauri::command]
-pub fn get_current_wifi() -> Result<Option<WiFiNetwork>, String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::get_current_network(&adapter)
+pub fn get_current_wifi(container: State<DIContainer>) -> Result<Option<WiFiNetwork>, BalamError> {
+    container.wifi()?.get_current_network()
 }
 
+/// Connects to a network, then checks for a captive portal in the background.
+///
+/// New networks get a profile from `security` (scanned if omitted);
+/// enterprise networks also need `username`.
+/// Emits `captive-portal-detected` (`{ ssid, portal_url }`) when the network
+/// needs a browser login.
+///
+/// # Errors
+/// Returns error if the profile cannot be created or the connection attempt fails.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('connect_wifi', { ssid: 'eduroam', password: 'hunter2', security: 'WPA2Enterprise', username: 'alex@uni.edu' });
+/// ```
 #[tauri::command]
-pub fn connect_wifi(ssid: String, password: String) -> Result<(), String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::connect_network(
-        &adapter,
-        WiFiConfig {
-            ssid,
-            password,
-            auto_connect: true,
-        },
-    )
+pub fn connect_wifi(
+    app: AppHandle,
+    container: State<DIContainer>,
+    ssid: String,
+    password: String,
+    security: Option<WiFiSecurity>,
+    username: Option<String>,
+) -> Result<(), BalamError> {
+    container.wifi()?.connect_network(WiFiConfig {
+        ssid: ssid.clone(),
+        password,
+        auto_connect: true,
+        security,
+        username,
+    })?;
+
+    std::thread::spawn(move || detect_captive_portal(&app, &ssid));
+    Ok(())
+}
+
+/// Probes a few times: right after association DHCP may not be done yet.
+fn detect_captive_portal(app: &AppHandle, ssid: &str) {
+    for _ in 0..3 {
+        std::thread::sleep(Duration::from_secs(3));
+        match CaptivePortalProbe::probe() {
+            Ok(Connectivity::CaptivePortal { portal_url }) => {
+                info!("🏨 Captive portal on {}: {}", ssid, portal_url);
+                let _ = app.emit(
+                    "captive-portal-detected",
+                    serde_json::json!({ "ssid": ssid, "portal_url": portal_url }),
+                );
+                return;
+            },
+            Ok(Connectivity::Online) => return,
+            Ok(Connectivity::NoInternet) => {},
+            Err(e) => {
+                warn!("Captive portal probe failed: {}", e);
+                return;
+            },
+        }
+    }
 }
 
+/// Checks whether the current network reaches the internet or sits behind a login page.
+///
+/// # Errors
+/// Returns error if the probe cannot run.
+///
+/// # Examples
+/// ```javascript
+/// const connectivity = await invoke('check_connectivity');
+/// if (connectivity.state === 'captive_portal') await invoke('open_captive_portal', { url: connectivity.portal_url });
+/// ```
 #[tauri::command]
-pub fn disconnect_wifi() -> Result<(), String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::disconnect(&adapter)
+pub async fn check_connectivity() -> Result<Connectivity, BalamError> {
+    tokio::task::spawn_blocking(CaptivePortalProbe::probe)
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
 }
 
+/// Opens a captive portal login page in its own window, on top of the app.
+///
+/// # Errors
+/// Returns `InvalidArgument` for anything but an http(s) URL.
 #[tauri::command]
-pub fn forget_wifi(ssid: String) -> Result<(), String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::forget_network(&adapter, &ssid)
+pub fn open_captive_portal(app: AppHandle, url: String) -> Result<(), BalamError> {
+    let parsed: tauri::Url = url
+        .parse()
+        .map_err(|_| BalamError::InvalidArgument(format!("Invalid portal URL: {url}")))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(BalamError::InvalidArgument(format!("Invalid portal URL: {url}")));
+    }
+
+    let window = if let Some(existing) = app.get_webview_window(CAPTIVE_PORTAL_WINDOW) {
+        existing
+            .navigate(parsed)
+            .map_err(|e| BalamError::platform("Failed to load portal page", e))?;
+        existing
+    } else {
+        WebviewWindowBuilder::new(&app, CAPTIVE_PORTAL_WINDOW, WebviewUrl::External(parsed))
+            .title("Network login")
+            .maximized(true)
+            .always_on_top(true)
+            .additional_browser_args(crate::WEBVIEW2_BROWSER_ARGS)
+            .build()
+            .map_err(|e| BalamError::platform("Failed to open portal window", e))?
+    };
+    window
+        .show()
+        .and_then(|()| window.set_focus())
+        .map_err(|e| BalamError::platform("Failed to show portal window", e))?;
+    info!("🏨 Captive portal window opened: {}", url);
+    Ok(())
 }
 
 #[tauri::command]
-pub fn get_saved_networks() -> Result<Vec<String>, String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::get_saved_networks(&adapter)
+pub fn disconnect_wifi(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.wifi()?.disconnect()
 }
 
 #[tauri::command]
-pub fn get_wifi_signal_strength() -> Result<Option<u32>, String> {
-    let adapter = WindowsWiFiAdapter::new()?;
-    WiFiPort::get_signal_strength(&adapter)
+pub fn forget_wifi(container: State<DIContainer>, ssid: String) -> Result<(), BalamError> {
+    container.wifi()?.forget_network(&ssid)
+}
+
+#[tauri::command]
+pub fn get_saved_networks(container: State<DIContainer>) -> Result<Vec<String>, BalamError> {
+    container.wifi()?.get_saved_networks()
+}
+
+#[tauri::command]
+pub fn get_wifi_signal_strength(container: State<DIContainer>) -> Result<Option<u32>, BalamError> {
+    container.wifi()?.get_signal_strength()
+}
+
+// ============================================================================
+// Network Adapter Commands
+// ============================================================================
+
+/// Every network adapter (wired, wireless, VPN) with link state, speed and addressing.
+///
+/// # Errors
+/// Returns error if the adapters cannot be enumerated.
+///
+/// # Examples
+/// ```javascript
+/// const { adapters, primary_adapter, vpn_active } = await invoke('get_network_details');
+/// ```
+#[tauri::command]
+pub fn get_network_details(container: State<DIContainer>) -> Result<NetworkDetails, BalamError> {
+    container.network.get_network_details()
+}
+
+/// Latency, jitter and packet loss to `network.ping_target` over the last
+/// 30 pings. Only measured while a game runs (`measuring` is `false` otherwise).
+///
+/// `network-latency-spike` is emitted when latency reaches
+/// `network.latency_spike_ms`.
+///
+/// # Examples
+/// ```javascript
+/// const { latency_ms, jitter_ms, packet_loss_percent } = await invoke('get_network_quality');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_network_quality(container: State<DIContainer>) -> NetworkQuality {
+    container.network_quality.quality()
 }
 
 // ============================================================================
@@ -61,63 +197,121 @@ pub fn get_wifi_signal_strength() -> Result<Option<u32>, String> {
 // ============================================================================
 
 #[tauri::command]
-pub async fn is_bluetooth_available() -> bool {
-    BluetoothPort::is_bluetooth_available(&WindowsBluetoothAdapter::new())
-        .await
-        .unwrap_or(false)
+pub async fn is_bluetooth_available(container: State<'_, DIContainer>) -> Result<bool, BalamError> {
+    Ok(container.bluetooth.is_bluetooth_available().await.unwrap_or(false))
 }
 
 #[tauri::command]
-pub async fn set_bluetooth_enabled(enabled: bool) -> Result<(), String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::set_bluetooth_enabled(&adapter, enabled).await
+pub async fn set_bluetooth_enabled(container: State<'_, DIContainer>, enabled: bool) -> Result<(), BalamError> {
+    container.bluetooth.set_bluetooth_enabled(enabled).await
 }
 
 #[tauri::command]
-pub async fn get_paired_bluetooth_devices() -> Result<Vec<BluetoothDevice>, String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::get_paired_devices(&adapter).await
+pub async fn get_paired_bluetooth_devices(
+    container: State<'_, DIContainer>,
+) -> Result<Vec<BluetoothDevice>, BalamError> {
+    container.bluetooth.get_paired_devices().await
 }
 
 #[tauri::command]
-pub async fn scan_bluetooth_devices() -> Result<Vec<BluetoothDevice>, String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::scan_devices(&adapter).await
+pub async fn scan_bluetooth_devices(container: State<'_, DIContainer>) -> Result<Vec<BluetoothDevice>, BalamError> {
+    let _permit = container.command_guard.enter("scan_bluetooth_devices").await?;
+    container.bluetooth.scan_devices().await
 }
 
 #[tauri::command]
-pub async fn get_connected_bluetooth_devices() -> Result<Vec<BluetoothDevice>, String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::get_connected_devices(&adapter).await
+pub async fn get_connected_bluetooth_devices(
+    container: State<'_, DIContainer>,
+) -> Result<Vec<BluetoothDevice>, BalamError> {
+    container.bluetooth.get_connected_devices().await
+}
+
+/// Gets a Bluetooth device with its battery level and active audio profile.
+///
+/// # Errors
+/// Returns `InvalidArgument` for a malformed address, `NotFound` for an unknown device.
+///
+/// # Examples
+/// ```javascript
+/// const device = await invoke('get_bluetooth_device_details', { address: '00:1A:7D:DA:71:13' });
+/// console.log(device.battery_percent, device.audio_profile); // 80, "A2dp"
+/// ```
+#[tauri::command]
+pub async fn get_bluetooth_device_details(
+    container: State<'_, DIContainer>,
+    address: String,
+) -> Result<BluetoothDevice, BalamError> {
+    container.bluetooth.get_device_details(&address).await
+}
+
+/// Pairs a Bluetooth device.
+///
+/// PIN and confirmation steps are emitted as `bluetooth-pairing-requested`
+/// events and answered with [`respond_to_pairing`]; `pin`, when given,
+/// answers a PIN request directly.
+///
+/// # Errors
+/// Returns error if the radio is off, the device is not found or pairing fails.
+///
+/// # Examples
+/// ```javascript
+/// await listen('bluetooth-pairing-requested', ({ payload }) => showPairingDialog(payload));
+/// await invoke('pair_bluetooth_device', { address: '00:1A:7D:DA:71:13' });
+/// ```
+#[tauri::command]
+pub async fn pair_bluetooth_device(
+    app: AppHandle,
+    container: State<'_, DIContainer>,
+    address: String,
+    pin: Option<String>,
+) -> Result<(), BalamError> {
+    let listener: PairingListener = Arc::new(move |ceremony| {
+        if let Err(e) = app.emit("bluetooth-pairing-requested", ceremony) {
+            warn!("Failed to emit pairing request: {}", e);
+        }
+    });
+    container
+        .bluetooth
+        .pair_device(
+            BluetoothPairingConfig {
+                address,
+                pin: pin.unwrap_or_default(),
+            },
+            Some(listener),
+        )
+        .await
 }
 
+/// Answers a `bluetooth-pairing-requested` event.
+///
+/// # Errors
+/// Returns `NotFound` if the request was already answered or timed out.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('respond_to_pairing', { requestId: 3, accept: true, pin: '0000' });
+/// ```
 #[tauri::command]
-pub async fn pair_bluetooth_device(address: String, pin: Option<String>) -> Result<(), String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::pair_device(
-        &adapter,
-        BluetoothPairingConfig {
-            address,
-            pin: pin.unwrap_or_default(),
-        },
-    )
-    .await
+pub async fn respond_to_pairing(
+    container: State<'_, DIContainer>,
+    request_id: u64,
+    accept: bool,
+    pin: Option<String>,
+) -> Result<(), BalamError> {
+    container.bluetooth.respond_to_pairing(request_id, accept, pin).await
 }
 
 #[tauri::command]
-pub async fn unpair_bluetooth_device(address: String) -> Result<(), String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::unpair_device(&adapter, &address).await
+pub async fn unpair_bluetooth_device(container: State<'_, DIContainer>, address: String) -> Result<(), BalamError> {
+    container.bluetooth.unpair_device(&address).await
 }
 
 #[tauri::command]
-pub async fn connect_bluetooth_device(address: String) -> Result<(), String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::connect_device(&adapter, &address).await
+pub async fn connect_bluetooth_device(container: State<'_, DIContainer>, address: String) -> Result<(), BalamError> {
+    container.bluetooth.connect_device(&address).await
 }
 
 #[tauri::command]
-pub async fn disconnect_bluetooth_device(address: String) -> Result<(), String> {
-    let adapter = WindowsBluetoothAdapter::new();
-    BluetoothPort::disconnect_device(&adapter, &address).await
+pub async fn disconnect_bluetooth_device(container: State<'_, DIContainer>, address: String) -> Result<(), BalamError> {
+    container.bluetooth.disconnect_device(&address).await
 }
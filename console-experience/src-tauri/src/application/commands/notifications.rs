@@ -0,0 +1,29 @@
+use crate::application::DIContainer;
+use crate::domain::notification::Notification;
+use crate::ports::notification_port::NotificationPort;
+use tauri::State;
+
+/// Returns recent notifications (newest first) for the notification center.
+///
+/// New notifications are pushed live with the `notification` event.
+///
+/// # Examples
+/// ```javascript
+/// const notifications = await invoke('get_notifications');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_notifications(container: State<DIContainer>) -> Vec<Notification> {
+    container.notifications.history()
+}
+
+/// Clears the notification history.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('clear_notifications');
+/// ```
+#[tauri::command]
+pub fn clear_notifications(container: State<DIContainer>) {
+    container.notifications.clear_history();
+}
@@ -3,15 +3,29 @@
 /// Provides frontend API for overlay system:
 /// - Show/hide overlay
 /// - Configure opacity and click-through
-/// - Automatic strategy selection (TOPMOST vs DLL injection)
+/// - Automatic strategy selection (TOPMOST, RTSS or DLL injection) from the swapchain's present mode
+/// - Conflicting overlays (Game Bar, GeForce, Discord, RTSS) that break injection
+/// - HUD layout (preset, fields, corner) and its RTSS OSD rendering
+/// - HUD level (off, FPS, FPS + battery, full), shared by every overlay path
+/// - Renderer health pings from the overlay WebView
 ///
 /// # Architecture
 /// ```
 /// Frontend → Tauri Commands → Overlay Strategy → Game Overlay
 /// ```
-use crate::adapters::overlay::{dll_overlay, select_strategy, topmost_overlay, GameInfo, OverlayMethod};
+use crate::adapters::overlay::{
+    choose_overlay_type, dll_overlay, topmost_overlay, GameInfo, IpcBridge, OverlayConflictScanner, OverlayMethod,
+    OverlayType, PresentMode, RtssOsd, StrategyChoice,
+};
+use crate::application::commands::performance::PERF_MONITOR;
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::overlay_conflict::OverlayConflict;
+use crate::domain::overlay_layout::{OverlayField, OverlayLayout, OverlayLevel};
+use crate::domain::settings::OverlaySettings;
+use crate::domain::BalamError;
 use serde::{Deserialize, Serialize};
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
 
 /// Overlay configuration for frontend
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,11 +51,33 @@ pub struct OverlayStatus {
     pub config: Option<OverlayConfig>,
 }
 
+/// Payload of `overlay-strategy-selected`.
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlayStrategySelected {
+    pub pid: u32,
+    pub game: String,
+    pub present_mode: PresentMode,
+    pub overlay_type: OverlayType,
+    /// Why this method was picked, for display
+    pub reason: &'static str,
+}
+
+/// Reads overlay defaults (opacity, click-through) from user settings
+fn overlay_settings(app: &AppHandle) -> OverlaySettings {
+    app.try_state::<DIContainer>()
+        .map(|container| container.settings_service.get().overlay)
+        .unwrap_or_default()
+}
+
 /// Show overlay for currently running game
 ///
-/// Automatically selects strategy based on game compatibility:
-/// - TOPMOST for modern games (DX11/12 with FSO)
-/// - DLL injection for legacy games (whitelisted only)
+/// Automatically selects strategy from the game's present mode (reported by
+/// the FPS service), falling back to DirectX version and FSO:
+/// - TOPMOST for composed and hardware flip swapchains
+/// - RTSS for exclusive fullscreen while RTSS is running
+/// - DLL injection otherwise (whitelisted only)
+///
+/// Emits `overlay-strategy-selected` with the chosen method and why.
 ///
 /// # Errors
 /// Returns error if:
@@ -53,21 +89,62 @@ pub async fn show_game_overlay(app: AppHandle) -> Result<OverlayConfig, String>
     // Get current game info from FPS service or process detection
     let game_info = crate::adapters::overlay::get_game_info_from_fps_service()?.ok_or("No game detected")?;
 
-    // Select appropriate strategy
-    let strategy = select_strategy(&game_info);
+    // Select appropriate strategy (safe mode never injects or drives RTSS)
+    let choice = if crate::application::safe_mode::is_active() {
+        StrategyChoice {
+            overlay_type: OverlayType::TopMost,
+            reason: "Safe mode: DLL injection and RTSS are disabled",
+        }
+    } else {
+        choose_overlay_type(&game_info, RtssOsd::is_running)
+    };
+    let strategy = OverlayMethod::new(choice.overlay_type);
+    tracing::info!(
+        "🎯 Overlay for {} ({:?}): {:?} - {}",
+        game_info.name,
+        game_info.present_mode,
+        choice.overlay_type,
+        choice.reason
+    );
+    let _ = app.emit(
+        "overlay-strategy-selected",
+        OverlayStrategySelected {
+            pid: game_info.pid,
+            game: game_info.name.clone(),
+            present_mode: game_info.present_mode,
+            overlay_type: choice.overlay_type,
+            reason: choice.reason,
+        },
+    );
 
     // Show overlay
     strategy.show(&app)?;
 
+    let settings = overlay_settings(&app);
+    apply_overlay_settings(&app, &strategy, &settings);
+
     // Return configuration
     Ok(OverlayConfig {
         visible: true,
         overlay_type: format!("{:?}", strategy.get_type()),
-        opacity: 0.98, // Default opacity
-        click_through: false,
+        opacity: settings.opacity,
+        click_through: settings.click_through,
     })
 }
 
+/// Applies user opacity/click-through to a freshly shown TOPMOST overlay
+fn apply_overlay_settings(app: &AppHandle, strategy: &OverlayMethod, settings: &OverlaySettings) {
+    if !matches!(strategy, OverlayMethod::TopMost(_)) {
+        return;
+    }
+    if let Err(e) = topmost_overlay::set_overlay_opacity(app, settings.opacity) {
+        tracing::warn!("Failed to apply overlay opacity: {}", e);
+    }
+    if let Err(e) = topmost_overlay::set_click_through(app, settings.click_through) {
+        tracing::warn!("Failed to apply overlay click-through: {}", e);
+    }
+}
+
 /// Hide overlay for currently running game
 ///
 /// Hides overlay window (TOPMOST) or sends IPC to hide DLL overlay.
@@ -100,6 +177,8 @@ pub async fn show_main_window(app: AppHandle) -> Result<(), String> {
 /// Creates TOPMOST overlay window without requiring FPS Service detection.
 #[tauri::command]
 pub async fn toggle_game_overlay(app: AppHandle) -> Result<OverlayConfig, String> {
+    let settings = overlay_settings(&app);
+
     // Check if overlay window exists
     if let Some(window) = app.get_webview_window("overlay") {
         // TOPMOST overlay exists
@@ -109,31 +188,33 @@ pub async fn toggle_game_overlay(app: AppHandle) -> Result<OverlayConfig, String
             Ok(OverlayConfig {
                 visible: false,
                 overlay_type: "TopMost".to_string(),
-                opacity: 0.98,
-                click_through: false,
+                opacity: settings.opacity,
+                click_through: settings.click_through,
             })
         } else {
             // Show existing overlay and give it OS focus so WebView2 stays active.
             // Without focus, Chromium suspends JS execution when the game occludes the window.
+            topmost_overlay::follow_game_monitor(&app, &window);
             window.show().map_err(|e| format!("Failed to show overlay: {}", e))?;
             window.set_focus().map_err(|e| format!("Failed to focus overlay: {}", e))?;
             Ok(OverlayConfig {
                 visible: true,
                 overlay_type: "TopMost".to_string(),
-                opacity: 0.98,
-                click_through: false,
+                opacity: settings.opacity,
+                click_through: settings.click_through,
             })
         }
     } else {
         // No overlay window - create it using TOPMOST strategy (no FPS Service needed)
         let strategy = OverlayMethod::TopMost(topmost_overlay::TopMostOverlay::new());
         strategy.show(&app)?;
+        apply_overlay_settings(&app, &strategy, &settings);
 
         Ok(OverlayConfig {
             visible: true,
             overlay_type: "TopMost".to_string(),
-            opacity: 0.98,
-            click_through: false,
+            opacity: settings.opacity,
+            click_through: settings.click_through,
         })
     }
 }
@@ -172,6 +253,7 @@ pub async fn get_overlay_status(app: AppHandle) -> Result<OverlayStatus, String>
     // Check if TOPMOST overlay exists
     if let Some(window) = app.get_webview_window("overlay") {
         let visible = window.is_visible().unwrap_or(false);
+        let settings = overlay_settings(&app);
 
         // Try to get game info (ignore errors)
         let game_info = crate::adapters::overlay::get_game_info_from_fps_service()
@@ -184,8 +266,8 @@ pub async fn get_overlay_status(app: AppHandle) -> Result<OverlayStatus, String>
             config: Some(OverlayConfig {
                 visible,
                 overlay_type: "TopMost".to_string(),
-                opacity: 0.98,
-                click_through: false,
+                opacity: settings.opacity,
+                click_through: settings.click_through,
             }),
         })
     } else {
@@ -215,9 +297,214 @@ pub async fn get_whitelisted_games() -> Result<Vec<String>, String> {
     Ok(dll_overlay::get_whitelist().iter().map(|s| s.to_string()).collect())
 }
 
+/// Lists overlays and services that hook games alongside Balam's overlay.
+///
+/// Stacked overlays break the DLL injection path; the frontend shows these
+/// with a fix (Game Bar has a one-click toggle, see `set_game_bar_enabled`).
+///
+/// # Examples
+/// ```javascript
+/// const conflicts = await invoke('get_overlay_conflicts');
+/// // [{ kind: 'xbox_game_bar', name: 'Xbox Game Bar', running: false, can_disable: true, ... }]
+/// ```
+#[tauri::command]
+pub async fn get_overlay_conflicts(container: State<'_, DIContainer>) -> Result<Vec<OverlayConflict>, String> {
+    let processes = container.processes.clone();
+    tokio::task::spawn_blocking(move || OverlayConflictScanner::environment(&processes).conflicts())
+        .await
+        .map_err(|e| format!("Task join error: {e}"))
+}
+
+/// Turns Xbox Game Bar and its background capture off (or back on).
+///
+/// Returns the conflicts left afterwards.
+///
+/// # Errors
+/// Returns error if the registry cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const remaining = await invoke('set_game_bar_enabled', { enabled: false });
+/// ```
+#[tauri::command]
+pub async fn set_game_bar_enabled(
+    enabled: bool,
+    container: State<'_, DIContainer>,
+) -> Result<Vec<OverlayConflict>, String> {
+    OverlayConflictScanner::set_game_bar_enabled(enabled).map_err(|e| e.to_string())?;
+    get_overlay_conflicts(container).await
+}
+
+/// Saves the HUD layout (preset, custom fields, corner).
+///
+/// # Errors
+/// Returns error for a custom layout without fields or if settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_overlay_layout', { layout: { preset: 'battery_focused', corner: 'top_right' } });
+/// await invoke('set_overlay_layout', {
+///   layout: { preset: 'custom', custom_fields: ['fps', 'frame_time', 'battery'], corner: 'bottom_left' },
+/// });
+/// ```
+#[tauri::command]
+pub fn set_overlay_layout(layout: OverlayLayout, app: AppHandle) -> Result<(), String> {
+    let container = app.state::<DIContainer>();
+    let saved = container.settings_service.update(|settings| settings.overlay.layout = layout)?;
+    emit_settings_changed(&app, &saved);
+    Ok(())
+}
+
+/// Renders the current metrics with the saved HUD layout into RTSS' OSD.
+///
+/// Call it on every metrics tick while RTSS is the HUD. Returns `false`
+/// when RTSS is not running.
+///
+/// # Errors
+/// Returns error if RTSS' shared memory is unsupported or has no free OSD slot.
+///
+/// # Examples
+/// ```javascript
+/// setInterval(() => invoke('update_rtss_overlay'), 1000);
+/// ```
+#[tauri::command]
+pub async fn update_rtss_overlay(app: AppHandle) -> Result<bool, String> {
+    tokio::task::spawn_blocking(move || render_rtss_overlay(&app).map_err(|e| e.to_string()))
+        .await
+        .map_err(|e| format!("Task join error: {e}"))?
+}
+
+/// Writes the HUD (and active alerts when `alerts.rtss_warnings` is on) to RTSS.
+///
+/// With nothing to show (level off, no warnings) Balam's slot is released.
+/// Nothing is written in safe mode.
+pub(crate) fn render_rtss_overlay<R: Runtime>(app: &AppHandle<R>) -> Result<bool, BalamError> {
+    if crate::application::safe_mode::is_active() {
+        return Ok(false);
+    }
+    let container = app.state::<DIContainer>();
+    let settings = container.settings_service.get();
+    let game_pid = container
+        .active_games_tracker
+        .capture_target()
+        .and_then(|info| info.pid);
+    let metrics = PERF_MONITOR.get_metrics_for(game_pid);
+    let status = container
+        .system_status
+        .current()
+        .unwrap_or_else(|| container.system.get_status());
+
+    let layout = &settings.overlay.layout;
+    let lines = layout.lines(
+        settings.overlay.level,
+        &metrics,
+        status.battery_level,
+        status.is_charging,
+    );
+    let alerts = if settings.alerts.rtss_warnings {
+        container.alerts.active()
+    } else {
+        Vec::new()
+    };
+    let warnings: Vec<&str> = alerts.iter().map(|alert| alert.kind.label()).collect();
+    if lines.is_empty() && warnings.is_empty() {
+        return RtssOsd::update("");
+    }
+    RtssOsd::update(&RtssOsd::format(layout, &lines, &warnings))
+}
+
+/// Removes Balam's text from RTSS' OSD.
+///
+/// # Errors
+/// Returns error if RTSS' shared memory is unsupported.
+#[tauri::command]
+pub fn clear_rtss_overlay() -> Result<(), String> {
+    RtssOsd::update("").map(|_| ()).map_err(|e| e.to_string())
+}
+
+/// Reply to the health ping the gamepad listener evaluates in the overlay.
+///
+/// Only the ping script calls it: while replies stop arriving, overlay
+/// navigation runs Rust-native and `overlay-renderer-health` reports `false`.
+#[tauri::command]
+pub fn report_overlay_renderer_alive(container: State<DIContainer>) {
+    container.overlay_renderer.record_reply();
+}
+
+/// Payload of `overlay-level-changed`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverlayLevelChanged {
+    pub level: OverlayLevel,
+    /// Fields shown at this level, in display order (empty when off)
+    pub fields: Vec<OverlayField>,
+}
+
+/// Sets the HUD level: 0 = off, 1 = FPS, 2 = FPS + battery, 3 = full layout.
+///
+/// Emits `overlay-level-changed`, the one event every overlay honors: the
+/// window overlay listens to it, `update_rtss_overlay` renders the level (off
+/// clears RTSS right away) and injected overlays read it from shared memory.
+///
+/// # Errors
+/// Returns error if the settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_overlay_level', { level: 2 });
+/// await listen('overlay-level-changed', ({ payload }) => render(payload.level, payload.fields));
+/// ```
+#[tauri::command]
+pub fn set_overlay_level(level: OverlayLevel, app: AppHandle) -> Result<OverlayLevelChanged, String> {
+    apply_overlay_level(&app, |_| level)
+}
+
+/// Steps the HUD to its next level (off → FPS → FPS + battery → full → off).
+///
+/// Also bound to a gamepad chord (default LB+RB+Back).
+///
+/// # Errors
+/// Returns error if the settings cannot be saved.
+#[tauri::command]
+pub fn cycle_overlay_level(app: AppHandle) -> Result<OverlayLevelChanged, String> {
+    apply_overlay_level(&app, OverlayLevel::next)
+}
+
+/// Saves the level picked by `choose` and pushes it to every overlay path.
+pub(crate) fn apply_overlay_level<R: Runtime>(
+    app: &AppHandle<R>,
+    choose: impl FnOnce(OverlayLevel) -> OverlayLevel,
+) -> Result<OverlayLevelChanged, String> {
+    let container = app.state::<DIContainer>();
+    let saved = container
+        .settings_service
+        .update(|settings| settings.overlay.level = choose(settings.overlay.level))?;
+    let level = saved.overlay.level;
+    let changed = OverlayLevelChanged {
+        level,
+        fields: saved.overlay.layout.fields_at(level).to_vec(),
+    };
+    tracing::info!("📊 Overlay level {}", u8::from(level));
+
+    let _ = app.emit("overlay-level-changed", &changed);
+    if let Err(e) = IpcBridge::publish_overlay_level(level) {
+        tracing::warn!("Failed to publish overlay level to injected overlays: {}", e);
+    }
+    // Other levels reach RTSS on the next `update_rtss_overlay` tick
+    if level == OverlayLevel::Off {
+        if let Err(e) = RtssOsd::update("") {
+            tracing::warn!("Failed to clear RTSS overlay: {}", e);
+        }
+    }
+    Ok(changed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::services::ParentalService;
+    use crate::application::Ports;
+    use crate::domain::parental::ParentalControls;
+    use std::sync::Arc;
 
     #[test]
     fn test_overlay_config_serialization() {
@@ -245,4 +532,31 @@ mod tests {
         assert!(status.game_info.is_none());
         assert!(status.config.is_none());
     }
+
+    #[test]
+    fn test_locked_settings_refuse_overlay_level_change() {
+        let dir = std::env::temp_dir().join(format!("balam-overlay-lock-{}", uuid::Uuid::new_v4().simple()));
+        let parental = Arc::new(ParentalService::new(&dir));
+        parental.set_pin(Some("2468")).unwrap();
+        parental
+            .save(ParentalControls {
+                lock_settings: true,
+                ..ParentalControls::default()
+            })
+            .unwrap();
+        parental.lock();
+
+        let container = DIContainer::with_ports(Ports::mock());
+        container.settings_service.set_guard(parental.settings_guard());
+        let level = container.settings_service.get().overlay.level;
+        let app = tauri::test::mock_app();
+        app.manage(container);
+
+        // The HUD level hotkey is not a settings command, but it saves settings
+        let refused = apply_overlay_level(app.handle(), OverlayLevel::next);
+        assert!(refused.is_err_and(|e| e.contains("parental PIN")));
+        assert_eq!(app.state::<DIContainer>().settings_service.get().overlay.level, level);
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
 }
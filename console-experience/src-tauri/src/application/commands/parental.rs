@@ -0,0 +1,129 @@
+/// Parental Controls Commands - PIN lock, restricted games and playtime limits
+///
+/// A parent sets a PIN, then configures restriction profiles (age rating,
+/// blocked games, daily playtime) and which one is active. Configuration
+/// commands need a recent `unlock_with_pin`; wrong PINs are rate limited.
+///
+/// Emits `parental-controls-changed` with the new status whenever the
+/// restrictions change, so the library reloads.
+use crate::application::DIContainer;
+use crate::domain::parental::{ParentalControls, ParentalStatus};
+use crate::domain::BalamError;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+
+fn emit_changed(app: &AppHandle, status: &ParentalStatus) {
+    let _ = app.emit("parental-controls-changed", status);
+}
+
+/// Returns whether a PIN is set, what is locked and today's remaining playtime.
+///
+/// # Examples
+/// ```javascript
+/// const { settings_locked, playtime_remaining_minutes } = await invoke('get_parental_status');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_parental_status(container: State<DIContainer>) -> ParentalStatus {
+    container.parental.status()
+}
+
+/// Unlocks settings and parental configuration for a few minutes.
+///
+/// # Errors
+/// - `ACCESS_DENIED` - Wrong PIN
+/// - `TIMEOUT` - Too many wrong PINs; `get_parental_status` tells how long to wait
+/// - `NOT_FOUND` - No PIN is set
+///
+/// # Examples
+/// ```javascript
+/// await invoke('unlock_with_pin', { pin: '2468' });
+/// ```
+#[tauri::command]
+pub async fn unlock_with_pin(pin: String, container: State<'_, DIContainer>) -> Result<ParentalStatus, BalamError> {
+    let parental = Arc::clone(&container.parental);
+    tokio::task::spawn_blocking(move || {
+        parental.unlock(&pin)?;
+        Ok(parental.status())
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Locks again right away instead of waiting for the unlock to expire.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('lock_parental_controls');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn lock_parental_controls(container: State<DIContainer>) -> ParentalStatus {
+    container.parental.lock();
+    container.parental.status()
+}
+
+/// Sets or changes the PIN; `null` removes it along with every restriction.
+///
+/// Needs `unlock_with_pin` first once a PIN exists.
+///
+/// # Errors
+/// Returns `ACCESS_DENIED` while locked, `INVALID_ARGUMENT` unless 4-8 digits.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_parental_pin', { pin: '2468' });
+/// ```
+#[tauri::command]
+pub async fn set_parental_pin(
+    pin: Option<String>,
+    app: AppHandle,
+    container: State<'_, DIContainer>,
+) -> Result<ParentalStatus, BalamError> {
+    let parental = Arc::clone(&container.parental);
+    let status = tokio::task::spawn_blocking(move || {
+        parental.set_pin(pin.as_deref())?;
+        Ok::<_, BalamError>(parental.status())
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))??;
+    emit_changed(&app, &status);
+    Ok(status)
+}
+
+/// Returns profiles, game ratings and the settings lock for editing.
+///
+/// # Errors
+/// Returns `ACCESS_DENIED` while locked.
+///
+/// # Examples
+/// ```javascript
+/// const controls = await invoke('get_parental_controls');
+/// ```
+#[tauri::command]
+pub fn get_parental_controls(container: State<DIContainer>) -> Result<ParentalControls, BalamError> {
+    container.parental.controls()
+}
+
+/// Saves profiles, game ratings, the settings lock and the active profile.
+///
+/// # Errors
+/// Returns `ACCESS_DENIED` while locked, `INVALID_ARGUMENT` for duplicate
+/// profile IDs, an unknown active profile or a limit above 24 h.
+///
+/// # Examples
+/// ```javascript
+/// controls.profiles.push({ id: 'kids', name: 'Kids', max_age_rating: 7, daily_limit_minutes: 90 });
+/// controls.active_profile = 'kids';
+/// await invoke('save_parental_controls', { controls });
+/// ```
+#[tauri::command]
+pub fn save_parental_controls(
+    controls: ParentalControls,
+    app: AppHandle,
+    container: State<DIContainer>,
+) -> Result<ParentalControls, BalamError> {
+    let saved = container.parental.save(controls)?;
+    emit_changed(&app, &container.parental.status());
+    Ok(saved)
+}
@@ -1,82 +1,453 @@
-use crate::adapters::display::WindowsDisplayAdapter;
-use crate::adapters::performance::RyzenAdjAdapter;
+use crate::adapters::performance::{PowerPlanAdapter, RyzenAdjDownloader, TdpController};
 use crate::adapters::performance_monitoring::WindowsPerfMonitor;
-use crate::domain::performance::{FPSStats, PerformanceMetrics};
-use crate::domain::{BrightnessConfig, PerformanceProfile, RefreshRateConfig, TDPConfig};
-use crate::ports::display_port::DisplayPort;
+use crate::adapters::privilege::PrivilegeBrokerClient;
+use crate::adapters::process_launcher::process_tuning;
+use crate::application::commands::fps_service_manager::is_elevated;
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::alerts::GameAlert;
+use crate::domain::display::MonitorInfo;
+use crate::domain::performance::{
+    CpuBoostMode, FPSStats, GamePowerProfile, PerformanceMetrics, PowerMode, PowerPlan, TdpBackend,
+};
+use crate::domain::privilege::PrivilegedOperation;
+use crate::domain::tdp_governor::{AutoTdpStatus, AutoTdpTarget};
+use crate::domain::{BalamError, BrightnessConfig, PerformanceProfile, RefreshRateConfig, TDPConfig};
 use crate::ports::performance_port::PerformancePort;
-use std::sync::LazyLock;
-use tracing::info;
+use parking_lot::Mutex;
+use std::sync::{Arc, LazyLock};
+use tauri::{AppHandle, State};
+use tracing::{info, warn};
 
 // ============================================================================
 // DISPLAY COMMANDS (Brightness, Refresh Rate)
 // ============================================================================
 
 #[tauri::command]
-pub fn get_brightness() -> Result<Option<u32>, String> {
-    DisplayPort::get_brightness(&WindowsDisplayAdapter::new())
+pub fn get_brightness(container: State<DIContainer>) -> Result<Option<u32>, BalamError> {
+    container.display().get_brightness()
 }
 
+/// Sets the screen brightness; a slider drag collapses into its last value.
 #[tauri::command]
-pub fn set_brightness(level: u32) -> Result<(), String> {
-    let config = BrightnessConfig::new(level).map_err(|e| format!("Invalid brightness level: {e}"))?;
-    DisplayPort::set_brightness(&WindowsDisplayAdapter::new(), config)
+pub async fn set_brightness(container: State<'_, DIContainer>, level: u32) -> Result<(), BalamError> {
+    let Some(_permit) = container.command_guard.enter("set_brightness").await? else {
+        return Ok(());
+    };
+    let config = BrightnessConfig::new(level).map_err(BalamError::InvalidArgument)?;
+    container.display().set_brightness(config)
 }
 
 #[tauri::command]
-pub fn get_refresh_rate() -> Result<u32, String> {
-    DisplayPort::get_refresh_rate(&WindowsDisplayAdapter::new())
+pub fn get_refresh_rate(container: State<DIContainer>) -> Result<u32, BalamError> {
+    container.display().get_refresh_rate()
 }
 
 #[tauri::command]
-pub fn set_refresh_rate(hz: u32) -> Result<(), String> {
-    let config = RefreshRateConfig::new(hz).map_err(|e| format!("Invalid refresh rate: {e}"))?;
-    DisplayPort::set_refresh_rate(&WindowsDisplayAdapter::new(), config)
+pub fn set_refresh_rate(container: State<DIContainer>, hz: u32) -> Result<(), BalamError> {
+    let config = RefreshRateConfig::new(hz).map_err(BalamError::InvalidArgument)?;
+    container.display().set_refresh_rate(config)
 }
 
 #[tauri::command]
-pub fn get_supported_refresh_rates() -> Result<Vec<u32>, String> {
-    DisplayPort::get_supported_refresh_rates(&WindowsDisplayAdapter::new())
+pub fn get_supported_refresh_rates(container: State<DIContainer>) -> Result<Vec<u32>, BalamError> {
+    container.display().get_supported_refresh_rates()
 }
 
 #[tauri::command]
 #[must_use]
-pub fn supports_brightness_control() -> bool {
-    DisplayPort::supports_brightness_control(&WindowsDisplayAdapter::new())
+pub fn supports_brightness_control(container: State<DIContainer>) -> bool {
+    container.display().supports_brightness_control()
+}
+
+/// Lists every monitor with its GDI device name and brightness control (WMI, DDC/CI or none).
+#[tauri::command]
+pub fn get_monitors(container: State<DIContainer>) -> Result<Vec<MonitorInfo>, BalamError> {
+    container.display().get_monitors()
+}
+
+#[tauri::command]
+pub fn get_monitor_brightness(container: State<DIContainer>, device_name: String) -> Result<Option<u32>, BalamError> {
+    container.display().get_monitor_brightness(&device_name)
+}
+
+/// Sets the brightness of one monitor; a slider drag collapses into its last value.
+#[tauri::command]
+pub async fn set_monitor_brightness(
+    container: State<'_, DIContainer>,
+    device_name: String,
+    level: u32,
+) -> Result<(), BalamError> {
+    let Some(_permit) = container.command_guard.enter("set_monitor_brightness").await? else {
+        return Ok(());
+    };
+    let config = BrightnessConfig::new(level).map_err(BalamError::InvalidArgument)?;
+    container.display().set_monitor_brightness(&device_name, config)
+}
+
+#[tauri::command]
+pub fn get_monitor_contrast(container: State<DIContainer>, device_name: String) -> Result<Option<u32>, BalamError> {
+    container.display().get_monitor_contrast(&device_name)
+}
+
+/// Sets the contrast of an external (DDC/CI) monitor; a slider drag collapses into its last value.
+#[tauri::command]
+pub async fn set_monitor_contrast(
+    container: State<'_, DIContainer>,
+    device_name: String,
+    level: u32,
+) -> Result<(), BalamError> {
+    let Some(_permit) = container.command_guard.enter("set_monitor_contrast").await? else {
+        return Ok(());
+    };
+    if level > 100 {
+        return Err(BalamError::InvalidArgument(format!(
+            "Contrast {level} exceeds maximum 100"
+        )));
+    }
+    container.display().set_monitor_contrast(&device_name, level)
+}
+
+#[tauri::command]
+pub fn get_monitor_refresh_rates(container: State<DIContainer>, device_name: String) -> Result<Vec<u32>, BalamError> {
+    container.display().get_monitor_refresh_rates(&device_name)
+}
+
+#[tauri::command]
+pub fn set_monitor_refresh_rate(container: State<DIContainer>, device_name: String, hz: u32) -> Result<(), BalamError> {
+    let config = RefreshRateConfig::new(hz).map_err(BalamError::InvalidArgument)?;
+    container.display().set_monitor_refresh_rate(&device_name, config)
 }
 
 // ============================================================================
 // PERFORMANCE COMMANDS (TDP Control)
 // ============================================================================
 
+/// TDP backend (AMD `RyzenAdj` or Intel MSR), kept loaded between commands.
+pub(crate) static TDP_CONTROLLER: LazyLock<TdpController> = LazyLock::new(TdpController::new);
+
 #[tauri::command]
-pub fn get_tdp_config() -> Result<TDPConfig, String> {
-    PerformancePort::get_tdp_config(&RyzenAdjAdapter::new())
+pub fn get_tdp_config() -> Result<TDPConfig, BalamError> {
+    PerformancePort::get_tdp_config(&*TDP_CONTROLLER)
 }
 
+/// Sets the TDP, through the privilege broker when Balam is not elevated.
+///
+/// # Errors
+/// Returns error if the backend fails or UAC is declined.
 #[tauri::command]
-pub fn set_tdp(watts: u32) -> Result<(), String> {
+pub async fn set_tdp(container: State<'_, DIContainer>, watts: u32) -> Result<(), BalamError> {
+    let Some(_permit) = container.command_guard.enter("set_tdp").await? else {
+        return Ok(());
+    };
     info!("Frontend requested TDP change to {}W", watts);
-    PerformancePort::set_tdp(&RyzenAdjAdapter::new(), watts)
+    // A manual TDP replaces auto mode
+    container.auto_tdp.stop(false)?;
+    let broker = Arc::clone(&container.privileges);
+    tokio::task::spawn_blocking(move || set_tdp_with_broker(&broker, watts))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// The WinRing0 driver only opens for administrators: unelevated, the
+/// broker applies the TDP.
+pub(crate) fn set_tdp_with_broker(broker: &PrivilegeBrokerClient, watts: u32) -> Result<(), BalamError> {
+    if is_elevated() {
+        PerformancePort::set_tdp(&*TDP_CONTROLLER, watts)
+    } else {
+        broker.execute(&PrivilegedOperation::SetTdp { watts })
+    }
 }
 
 #[tauri::command]
-pub fn apply_performance_profile(profile: String) -> Result<(), String> {
+pub async fn apply_performance_profile(container: State<'_, DIContainer>, profile: String) -> Result<(), BalamError> {
     let profile_enum = match profile.as_str() {
         "eco" => PerformanceProfile::Eco,
         "balanced" => PerformanceProfile::Balanced,
         "performance" => PerformanceProfile::Performance,
-        _ => return Err(format!("Unknown profile: {profile}")),
+        _ => return Err(BalamError::InvalidArgument(format!("Unknown profile: {profile}"))),
     };
 
     info!("Applying performance profile: {:?}", profile_enum);
-    PerformancePort::apply_profile(&RyzenAdjAdapter::new(), profile_enum)
+    container.auto_tdp.stop(false)?;
+    let broker = Arc::clone(&container.privileges);
+    tokio::task::spawn_blocking(move || {
+        let config = PerformancePort::get_tdp_config(&*TDP_CONTROLLER)?;
+        set_tdp_with_broker(&broker, profile_enum.to_watts(&config))
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Auto TDP: holds `target_fps` at the lowest TDP, stepping within
+/// `min_watts`..`max_watts` (default: the hardware range).
+///
+/// Calling it again retargets; `stop_auto_tdp` switches it off.
+///
+/// # Errors
+/// - `INVALID_ARGUMENT` - Target FPS out of range (20 - 240) or empty watt range
+/// - `NOT_SUPPORTED` / `PLATFORM` - No TDP backend
+///
+/// # Examples
+/// ```javascript
+/// const status = await invoke('start_auto_tdp', { targetFps: 60, minWatts: 6, maxWatts: 18 });
+/// ```
+#[tauri::command]
+pub async fn start_auto_tdp(
+    container: State<'_, DIContainer>,
+    target_fps: u32,
+    min_watts: Option<u32>,
+    max_watts: Option<u32>,
+) -> Result<AutoTdpStatus, BalamError> {
+    let auto_tdp = Arc::clone(&container.auto_tdp);
+    let broker = Arc::clone(&container.privileges);
+    tokio::task::spawn_blocking(move || {
+        let hardware = PerformancePort::get_tdp_config(&*TDP_CONTROLLER)?;
+        auto_tdp.start(
+            AutoTdpTarget {
+                target_fps,
+                min_watts,
+                max_watts,
+            },
+            hardware,
+            Box::new(|| PERF_MONITOR.get_metrics().fps.map(|fps| fps.current_fps)),
+            Arc::new(move |watts| set_tdp_with_broker(&broker, watts)),
+        )
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Switches auto TDP off at once and restores the TDP from before it started.
+///
+/// # Errors
+/// Returns error if the previous TDP cannot be restored.
+#[tauri::command]
+pub async fn stop_auto_tdp(container: State<'_, DIContainer>) -> Result<(), BalamError> {
+    let auto_tdp = Arc::clone(&container.auto_tdp);
+    tokio::task::spawn_blocking(move || auto_tdp.stop(true))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+#[tauri::command]
+#[must_use]
+pub fn get_auto_tdp_status(container: State<DIContainer>) -> AutoTdpStatus {
+    container.auto_tdp.status()
 }
 
 #[tauri::command]
 #[must_use]
 pub fn supports_tdp_control() -> bool {
-    PerformancePort::supports_tdp_control(&RyzenAdjAdapter::new())
+    PerformancePort::supports_tdp_control(&*TDP_CONTROLLER)
+}
+
+/// Backend used for TDP changes (`ryzen_adj_library`, `ryzen_adj_cli`,
+/// `intel_msr`), or `null` when TDP cannot be controlled.
+#[tauri::command]
+#[must_use]
+pub fn get_tdp_backend() -> Option<TdpBackend> {
+    TDP_CONTROLLER.backend()
+}
+
+/// Downloads `RyzenAdj` (CLI, library and WinRing0 driver) from its GitHub releases.
+///
+/// The WinRing0 driver also enables the Intel backend.
+///
+/// # Errors
+/// Returns error if the download or extraction fails.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('install_tdp_tools');
+/// const supported = await invoke('supports_tdp_control');
+/// ```
+#[tauri::command]
+pub async fn install_tdp_tools() -> Result<Option<TdpBackend>, BalamError> {
+    tauri::async_runtime::spawn_blocking(RyzenAdjDownloader::download)
+        .await
+        .map_err(|e| BalamError::platform("Download task failed", e))??;
+    Ok(TDP_CONTROLLER.backend())
+}
+
+/// Fetches `RyzenAdj` in the background when no backend works and nothing was downloaded yet.
+pub(crate) fn ensure_tdp_tools() {
+    if RyzenAdjDownloader::is_installed() || TDP_CONTROLLER.backend().is_some() {
+        return;
+    }
+    std::thread::spawn(|| match RyzenAdjDownloader::download() {
+        Ok(_) => info!("TDP backend after download: {:?}", TDP_CONTROLLER.backend()),
+        Err(e) => warn!("Could not download RyzenAdj: {}", e),
+    });
+}
+
+// ============================================================================
+// POWER PLAN COMMANDS (Power plans, power mode, CPU boost)
+// ============================================================================
+
+/// Power mode and CPU boost in effect before a game profile changed them.
+static SAVED_POWER_STATE: Mutex<Option<(PowerMode, CpuBoostMode)>> = Mutex::new(None);
+
+#[tauri::command]
+pub fn list_power_plans() -> Result<Vec<PowerPlan>, BalamError> {
+    PowerPlanAdapter::list_plans()
+}
+
+/// Activates an installed power plan by GUID.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if no plan has that GUID.
+///
+/// # Examples
+/// ```javascript
+/// const plans = await invoke('list_power_plans');
+/// await invoke('set_power_plan', { guid: plans[0].guid });
+/// ```
+#[tauri::command]
+pub fn set_power_plan(guid: String) -> Result<(), BalamError> {
+    PowerPlanAdapter::set_active_plan(&guid)
+}
+
+#[tauri::command]
+pub fn get_power_mode() -> Result<PowerMode, BalamError> {
+    PowerPlanAdapter::power_mode()
+}
+
+/// Moves the Windows power mode slider.
+///
+/// # Errors
+/// Returns `NOT_SUPPORTED` on Windows builds without power modes.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_power_mode', { mode: 'best_performance' });
+/// ```
+#[tauri::command]
+pub fn set_power_mode(mode: PowerMode) -> Result<(), BalamError> {
+    PowerPlanAdapter::set_power_mode(mode)
+}
+
+#[tauri::command]
+pub fn get_cpu_boost_mode() -> Result<CpuBoostMode, BalamError> {
+    PowerPlanAdapter::boost_mode()
+}
+
+/// Sets processor performance boost mode on the active plan.
+///
+/// # Errors
+/// Returns error if `powercfg` rejects the change.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_cpu_boost_mode', { mode: 'disabled' }); // cooler, longer battery
+/// ```
+#[tauri::command]
+pub fn set_cpu_boost_mode(mode: CpuBoostMode) -> Result<(), BalamError> {
+    PowerPlanAdapter::set_boost_mode(mode)
+}
+
+/// Saves (or removes, with `null`) the power mode / CPU boost applied while a game runs,
+/// and the priority class / CPU affinity of its process.
+///
+/// # Errors
+/// Returns error if settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_game_power_profile', {
+///   gameId: 'steam_730',
+///   profile: {
+///     power_mode: 'best_performance',
+///     cpu_boost: 'aggressive',
+///     priority: 'above_normal',
+///     affinity: 'performance_cores', // or { mask: 0xFF }
+///   },
+/// });
+/// ```
+#[tauri::command]
+pub fn set_game_power_profile(
+    game_id: String,
+    profile: Option<GamePowerProfile>,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container.settings_service.update(|settings| match profile {
+        Some(profile) => {
+            settings
+                .performance
+                .game_power_profiles
+                .insert(game_id.clone(), profile);
+        },
+        None => {
+            settings.performance.game_power_profiles.remove(&game_id);
+        },
+    })?;
+    emit_settings_changed(&app_handle, &saved);
+
+    if container.active_games_tracker.focused().as_deref() == Some(game_id.as_str()) {
+        apply_game_power_profile(&container, &game_id);
+    }
+    // Priority/affinity change on the running game right away
+    if let Some(pid) = container.active_games_tracker.get(&game_id).and_then(|info| info.pid) {
+        process_tuning::restore_game_process(&game_id);
+        process_tuning::tune_game_process(&app_handle, &game_id, pid);
+    }
+    Ok(())
+}
+
+/// Applies the power profile of `game_id`, remembering what it replaces.
+///
+/// Failures are logged: a game must launch even if `powercfg` misbehaves.
+pub(crate) fn apply_game_power_profile(container: &DIContainer, game_id: &str) {
+    let Some(profile) = container
+        .settings_service
+        .get()
+        .performance
+        .game_power_profiles
+        .get(game_id)
+        .copied()
+    else {
+        return;
+    };
+
+    {
+        // Keep the original state when switching between profiled games
+        let mut saved = SAVED_POWER_STATE.lock();
+        if saved.is_none() {
+            match (PowerPlanAdapter::power_mode(), PowerPlanAdapter::boost_mode()) {
+                (Ok(mode), Ok(boost)) => *saved = Some((mode, boost)),
+                (mode, boost) => warn!(
+                    "Could not read current power state, it will not be restored: {:?} / {:?}",
+                    mode.err(),
+                    boost.err()
+                ),
+            }
+        }
+    }
+
+    if let Some(mode) = profile.power_mode {
+        if let Err(e) = PowerPlanAdapter::set_power_mode(mode) {
+            warn!("Failed to apply game power mode: {}", e);
+        }
+    }
+    if let Some(boost) = profile.cpu_boost {
+        if let Err(e) = PowerPlanAdapter::set_boost_mode(boost) {
+            warn!("Failed to apply game CPU boost mode: {}", e);
+        }
+    }
+}
+
+/// Puts back the power mode and CPU boost saved by [`apply_game_power_profile`].
+pub(crate) fn restore_power_profile() {
+    let Some((mode, boost)) = SAVED_POWER_STATE.lock().take() else {
+        return;
+    };
+    if let Err(e) = PowerPlanAdapter::set_power_mode(mode) {
+        warn!("Failed to restore power mode: {}", e);
+    }
+    if let Err(e) = PowerPlanAdapter::set_boost_mode(boost) {
+        warn!("Failed to restore CPU boost mode: {}", e);
+    }
 }
 
 // ============================================================================
@@ -87,14 +458,42 @@ pub fn supports_tdp_control() -> bool {
 pub(crate) static PERF_MONITOR: LazyLock<WindowsPerfMonitor> = LazyLock::new(WindowsPerfMonitor::new);
 
 #[tauri::command]
-pub fn get_fps_stats() -> Result<Option<FPSStats>, String> {
+pub fn get_fps_stats() -> Result<Option<FPSStats>, BalamError> {
     let metrics = PERF_MONITOR.get_metrics();
     Ok(metrics.fps)
 }
 
+/// System-wide metrics plus the focused game's own CPU, private working set,
+/// GPU engine and disk I/O (`game`, keyed by the tracked PID).
+///
+/// # Examples
+/// ```javascript
+/// const { cpu_usage, game } = await invoke('get_performance_metrics');
+/// if (game) console.log(`game ${game.cpu_usage}% of ${cpu_usage}% CPU`);
+/// ```
 #[tauri::command]
-pub fn get_performance_metrics() -> Result<PerformanceMetrics, String> {
-    Ok(PERF_MONITOR.get_metrics())
+pub fn get_performance_metrics(container: State<DIContainer>) -> Result<PerformanceMetrics, BalamError> {
+    let game_pid = container
+        .active_games_tracker
+        .capture_target()
+        .and_then(|info| info.pid);
+    Ok(PERF_MONITOR.get_metrics_for(game_pid))
+}
+
+/// Battery, temperature and throttling alerts still in effect.
+///
+/// New alerts arrive as `game-alert` events; this returns the current set
+/// (e.g. when the overlay opens).
+///
+/// # Examples
+/// ```javascript
+/// await listen('game-alert', (e) => showToast(e.payload.title, e.payload.priority));
+/// const alerts = await invoke('get_active_alerts');
+/// ```
+#[tauri::command]
+#[must_use]
+pub fn get_active_alerts(container: State<DIContainer>) -> Vec<GameAlert> {
+    container.alerts.active()
 }
 
 #[tauri::command]
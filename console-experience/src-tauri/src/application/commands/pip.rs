@@ -1,7 +1,25 @@
 /// PiP Window Commands
 ///
-/// Commands to control the Performance PiP (Picture-in-Picture) window.
-use tauri::{AppHandle, Manager, PhysicalPosition, WebviewUrl, WebviewWindowBuilder};
+/// Commands to control the Picture-in-Picture windows:
+/// - Performance PiP: Balam's stats panel
+/// - Companion PiP: a web page (walkthrough video, wiki) kept above the game,
+///   snapped to a corner and resized with the controller
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{
+    AppHandle, Manager, PhysicalPosition, PhysicalSize, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+};
+
+use crate::adapters::overlay::topmost_overlay;
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::overlay_layout::OverlayCorner;
+use crate::domain::pip::{validate_pip_url, PipRect, PipSettings};
+
+/// Companion PiP window label
+const COMPANION_LABEL: &str = "companion-pip";
+
+/// Set while the companion window is hidden because the overlay is open
+static HIDDEN_FOR_OVERLAY: AtomicBool = AtomicBool::new(false);
 
 /// Show the Performance PiP window
 #[tauri::command]
@@ -36,6 +54,7 @@ pub async fn show_performance_pip(app: AppHandle) -> Result<(), String> {
         .focusable(false)
         .accept_first_mouse(false)
         .visible_on_all_workspaces(true)
+        .additional_browser_args(crate::WEBVIEW2_BROWSER_ARGS)
         .build()
         .map_err(|e| format!("Failed to create window: {}", e))?;
 
@@ -104,3 +123,182 @@ pub async fn is_pip_visible(app: AppHandle) -> Result<bool, String> {
         .is_visible()
         .map_err(|e| format!("Failed to check visibility: {}", e))
 }
+
+/// Open a page in the companion PiP window, above the game
+///
+/// Without `url` the last page is reopened. Only `http(s)` pages load. While
+/// the in-game overlay is open (and `pip.auto_hide_with_overlay` is on) the
+/// window waits hidden and appears when the overlay closes.
+///
+/// # Errors
+/// Returns error for an invalid URL, no page to reopen or a window failure.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('open_companion_pip', { url: 'https://www.youtube.com/watch?v=...' });
+/// ```
+#[tauri::command]
+pub async fn open_companion_pip(url: Option<String>, app: AppHandle) -> Result<(), String> {
+    let container = app.state::<DIContainer>();
+    let mut settings = container.settings_service.get().pip;
+    let url = match url {
+        Some(url) => validate_pip_url(&url)?,
+        None if settings.last_url.is_empty() => return Err("No page to reopen".to_string()),
+        None => settings.last_url.clone(),
+    };
+    let page: tauri::Url = url.parse().map_err(|e| format!("Invalid PiP URL: {}", e))?;
+
+    let window = if let Some(window) = app.get_webview_window(COMPANION_LABEL) {
+        window
+            .navigate(page)
+            .map_err(|e| format!("Failed to open page: {}", e))?;
+        window
+    } else {
+        WebviewWindowBuilder::new(&app, COMPANION_LABEL, WebviewUrl::External(page))
+            .title("Balam PiP")
+            .decorations(false)
+            .always_on_top(true)
+            .skip_taskbar(true)
+            .resizable(false)
+            .focused(false)
+            .visible(false)
+            .additional_browser_args(crate::WEBVIEW2_BROWSER_ARGS)
+            .build()
+            .map_err(|e| format!("Failed to create window: {}", e))?
+    };
+    place_companion(&window, &settings)?;
+
+    if settings.auto_hide_with_overlay && overlay_visible(&app) {
+        window.hide().map_err(|e| format!("Failed to hide window: {}", e))?;
+        HIDDEN_FOR_OVERLAY.store(true, Ordering::SeqCst);
+    } else {
+        topmost_overlay::pin_above_games(&window, settings.opacity)?;
+        HIDDEN_FOR_OVERLAY.store(false, Ordering::SeqCst);
+    }
+
+    if settings.last_url != url {
+        settings.last_url = url;
+        // Only remembered for next time: a locked or failed save keeps the window open
+        match container.settings_service.update(|all| all.pip = settings) {
+            Ok(saved) => emit_settings_changed(&app, &saved),
+            Err(e) => tracing::warn!("Companion PiP URL not saved: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/// Close the companion PiP window
+#[tauri::command]
+pub async fn close_companion_pip(app: AppHandle) -> Result<(), String> {
+    HIDDEN_FOR_OVERLAY.store(false, Ordering::SeqCst);
+    if let Some(window) = app.get_webview_window(COMPANION_LABEL) {
+        window.close().map_err(|e| format!("Failed to close window: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Snap the companion PiP window to a screen corner (saved for next time)
+///
+/// # Examples
+/// ```javascript
+/// await invoke('snap_companion_pip', { corner: 'bottom_left' });
+/// ```
+#[tauri::command]
+pub async fn snap_companion_pip(corner: OverlayCorner, app: AppHandle) -> Result<(), String> {
+    let settings = update_pip_settings(&app, |pip| pip.corner = corner)?;
+    if let Some(window) = app.get_webview_window(COMPANION_LABEL) {
+        place_companion(&window, &settings)?;
+    }
+    Ok(())
+}
+
+/// Set the companion PiP window opacity (0.2 - 1.0, saved for next time)
+#[tauri::command]
+pub async fn set_companion_pip_opacity(opacity: f64, app: AppHandle) -> Result<(), String> {
+    let settings = update_pip_settings(&app, |pip| pip.opacity = opacity)?;
+    if let Some(window) = app.get_webview_window(COMPANION_LABEL) {
+        if window.is_visible().unwrap_or(false) {
+            topmost_overlay::pin_above_games(&window, settings.opacity)?;
+        }
+    }
+    Ok(())
+}
+
+/// Grow (positive `steps`) or shrink the companion PiP window
+///
+/// Bound to the bumpers while the overlay's PiP controls are focused. The
+/// window keeps its corner and a 16:9 shape.
+///
+/// Returns the new width as a share of the monitor width.
+#[tauri::command]
+pub async fn resize_companion_pip(steps: i32, app: AppHandle) -> Result<u8, String> {
+    let settings = update_pip_settings(&app, |pip| pip.width_percent = pip.resized(steps))?;
+    if let Some(window) = app.get_webview_window(COMPANION_LABEL) {
+        place_companion(&window, &settings)?;
+    }
+    Ok(settings.width_percent)
+}
+
+/// Hides the companion window while the in-game overlay is open and brings
+/// it back when the overlay closes (called on overlay visibility changes).
+pub(crate) fn sync_companion_pip_with_overlay<R: Runtime>(app: &AppHandle<R>, overlay_visible: bool) {
+    let Some(window) = app.get_webview_window(COMPANION_LABEL) else {
+        return;
+    };
+    let settings = app
+        .try_state::<DIContainer>()
+        .map(|container| container.settings_service.get().pip)
+        .unwrap_or_default();
+
+    let result = if overlay_visible {
+        if !settings.auto_hide_with_overlay || !window.is_visible().unwrap_or(false) {
+            return;
+        }
+        HIDDEN_FOR_OVERLAY.store(true, Ordering::SeqCst);
+        window.hide().map_err(|e| e.to_string())
+    } else if HIDDEN_FOR_OVERLAY.swap(false, Ordering::SeqCst) {
+        topmost_overlay::pin_above_games(&window, settings.opacity)
+    } else {
+        Ok(())
+    };
+    if let Err(e) = result {
+        tracing::warn!("Failed to sync companion PiP with overlay: {}", e);
+    }
+}
+
+fn overlay_visible(app: &AppHandle) -> bool {
+    app.get_webview_window("overlay")
+        .is_some_and(|window| window.is_visible().unwrap_or(false))
+}
+
+fn update_pip_settings(app: &AppHandle, modify: impl FnOnce(&mut PipSettings)) -> Result<PipSettings, String> {
+    let container = app.state::<DIContainer>();
+    let saved = container
+        .settings_service
+        .update(|settings| modify(&mut settings.pip))?;
+    emit_settings_changed(app, &saved);
+    Ok(saved.pip)
+}
+
+/// Sizes and moves the companion window into its corner of its monitor.
+fn place_companion(window: &WebviewWindow, settings: &PipSettings) -> Result<(), String> {
+    let monitor = window
+        .current_monitor()
+        .map_err(|e| format!("Failed to get monitor: {}", e))?
+        .or_else(|| window.primary_monitor().ok().flatten())
+        .ok_or("No monitor found")?;
+    let area = PipRect {
+        x: monitor.position().x,
+        y: monitor.position().y,
+        width: monitor.size().width,
+        height: monitor.size().height,
+    };
+    let rect = PipRect::in_corner(area, settings.corner, settings.width_percent);
+
+    window
+        .set_size(PhysicalSize::new(rect.width, rect.height))
+        .map_err(|e| format!("Failed to resize window: {}", e))?;
+    window
+        .set_position(PhysicalPosition::new(rect.x, rect.y))
+        .map_err(|e| format!("Failed to set position: {}", e))
+}
@@ -0,0 +1,217 @@
+/// Quick Settings Commands - In-game refresh rate and resolution switching
+///
+/// Made for the overlay: every change is a single mode switch, validated by
+/// the driver and applied for the session only. The player confirms it with
+/// `confirm_display_change` within `DISPLAY_CONFIRM_SECONDS`; otherwise (black
+/// screen, monitor out of range) the previous mode comes back on its own and
+/// `display-change-reverted` is emitted.
+use crate::application::DIContainer;
+use crate::domain::display::DisplayMode;
+use crate::domain::quick_settings::{
+    half_rate_toggle, mode_at_resolution, next_refresh_rate, quick_resolutions, refresh_rates_at, QuickResolution,
+    DISPLAY_CONFIRM_SECONDS,
+};
+use crate::domain::BalamError;
+use crate::ports::display_port::DisplayPort;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, State};
+use tracing::{error, info, warn};
+
+/// Change waiting for the player's confirmation.
+struct PendingDisplayChange {
+    id: u64,
+    /// Last confirmed mode, restored on rollback
+    previous: DisplayMode,
+}
+
+static PENDING_CHANGE: Mutex<Option<PendingDisplayChange>> = Mutex::new(None);
+static CHANGE_IDS: AtomicU64 = AtomicU64::new(0);
+
+/// What the quick settings panel shows.
+#[derive(Debug, Clone, Serialize)]
+pub struct QuickSettingsState {
+    pub mode: DisplayMode,
+    /// Refresh rates available at the current resolution
+    pub refresh_rates: Vec<u32>,
+    pub resolutions: Vec<QuickResolution>,
+    /// Rate the half-rate toggle switches to, if any
+    pub half_rate: Option<u32>,
+    /// A change is waiting for `confirm_display_change`
+    pub pending_confirmation: bool,
+}
+
+/// A display change applied on probation.
+#[derive(Debug, Clone, Serialize)]
+pub struct DisplayChange {
+    pub previous: DisplayMode,
+    pub applied: DisplayMode,
+    /// Seconds left to confirm before the rollback
+    pub confirm_within_seconds: u64,
+}
+
+/// Current mode and the choices offered in game.
+///
+/// # Examples
+/// ```javascript
+/// const { mode, refresh_rates, resolutions, half_rate } = await invoke('get_quick_settings');
+/// ```
+#[tauri::command]
+pub fn get_quick_settings(container: State<DIContainer>) -> Result<QuickSettingsState, BalamError> {
+    let display = container.display();
+    let mode = display.get_display_mode()?;
+    let modes = display.get_display_modes()?;
+    Ok(QuickSettingsState {
+        mode,
+        refresh_rates: refresh_rates_at(mode, &modes),
+        resolutions: quick_resolutions(&modes),
+        half_rate: half_rate_toggle(mode, &modes),
+        pending_confirmation: PENDING_CHANGE.lock().is_some(),
+    })
+}
+
+/// Switches to the next supported refresh rate (wrapping to the lowest).
+///
+/// # Errors
+/// - `NOT_SUPPORTED` - The display has a single refresh rate
+/// - `INVALID_ARGUMENT` / `PLATFORM` - The driver rejected the mode
+#[tauri::command]
+pub fn quick_cycle_refresh_rate(app: AppHandle, container: State<DIContainer>) -> Result<DisplayChange, BalamError> {
+    let display = container.display();
+    let mode = display.get_display_mode()?;
+    let hz = next_refresh_rate(mode, &display.get_display_modes()?)
+        .ok_or_else(|| BalamError::NotSupported("The display has a single refresh rate".to_string()))?;
+    apply_with_rollback(&app, display, mode, mode.with_refresh_rate(hz))
+}
+
+/// Toggles half refresh rate (120 ↔ 60 Hz), e.g. to lock 60 FPS on a handheld.
+///
+/// # Errors
+/// - `NOT_SUPPORTED` - The display has no half or double of the current rate
+/// - `INVALID_ARGUMENT` / `PLATFORM` - The driver rejected the mode
+///
+/// # Examples
+/// ```javascript
+/// const change = await invoke('quick_toggle_half_rate');
+/// if (await askKeep(change.confirm_within_seconds)) await invoke('confirm_display_change');
+/// ```
+#[tauri::command]
+pub fn quick_toggle_half_rate(app: AppHandle, container: State<DIContainer>) -> Result<DisplayChange, BalamError> {
+    let display = container.display();
+    let mode = display.get_display_mode()?;
+    let hz = half_rate_toggle(mode, &display.get_display_modes()?).ok_or_else(|| {
+        BalamError::NotSupported(format!("No half or double of {}Hz on this display", mode.refresh_hz))
+    })?;
+    apply_with_rollback(&app, display, mode, mode.with_refresh_rate(hz))
+}
+
+/// Switches to one of the resolutions from `get_quick_settings`.
+///
+/// Keeps the refresh rate if the display has it at that resolution.
+///
+/// # Errors
+/// - `INVALID_ARGUMENT` - Not one of the offered resolutions, or rejected by the driver
+#[tauri::command]
+pub fn quick_set_resolution(
+    width: u32,
+    height: u32,
+    app: AppHandle,
+    container: State<DIContainer>,
+) -> Result<DisplayChange, BalamError> {
+    let display = container.display();
+    let mode = display.get_display_mode()?;
+    let modes = display.get_display_modes()?;
+    let target = quick_resolutions(&modes)
+        .iter()
+        .any(|r| r.width == width && r.height == height)
+        .then(|| mode_at_resolution(mode, width, height, &modes))
+        .flatten()
+        .ok_or_else(|| BalamError::InvalidArgument(format!("{width}x{height} is not a quick settings resolution")))?;
+    apply_with_rollback(&app, display, mode, target)
+}
+
+/// Keeps the pending display change and saves it as the display's mode.
+///
+/// # Errors
+/// - `NOT_FOUND` - No change is waiting (it may have rolled back already)
+#[tauri::command]
+pub fn confirm_display_change(container: State<DIContainer>) -> Result<(), BalamError> {
+    if PENDING_CHANGE.lock().take().is_none() {
+        return Err(BalamError::NotFound("Pending display change".to_string()));
+    }
+    let display = container.display();
+    let mode = display.get_display_mode()?;
+    info!(
+        "Display change confirmed: {}x{} @ {}Hz",
+        mode.width, mode.height, mode.refresh_hz
+    );
+    display.set_display_mode(mode, true)
+}
+
+/// Rolls the pending display change back right away.
+///
+/// # Errors
+/// - `NOT_FOUND` - No change is waiting
+#[tauri::command]
+pub fn revert_display_change(app: AppHandle, container: State<DIContainer>) -> Result<(), BalamError> {
+    let change = PENDING_CHANGE
+        .lock()
+        .take()
+        .ok_or_else(|| BalamError::NotFound("Pending display change".to_string()))?;
+    revert(&app, container.display().as_ref(), change.previous)
+}
+
+/// Applies `target` for the session and schedules its rollback.
+fn apply_with_rollback(
+    app: &AppHandle,
+    display: Arc<dyn DisplayPort>,
+    current: DisplayMode,
+    target: DisplayMode,
+) -> Result<DisplayChange, BalamError> {
+    let mut pending = PENDING_CHANGE.lock();
+    // Stacked changes roll back to the last confirmed mode
+    let previous = pending.as_ref().map_or(current, |change| change.previous);
+    display.set_display_mode(target, false)?;
+
+    let id = CHANGE_IDS.fetch_add(1, Ordering::Relaxed) + 1;
+    *pending = Some(PendingDisplayChange { id, previous });
+    drop(pending);
+
+    let app = app.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_secs(DISPLAY_CONFIRM_SECONDS));
+        let expired = {
+            let mut pending = PENDING_CHANGE.lock();
+            match pending.as_ref() {
+                Some(change) if change.id == id => pending.take(),
+                _ => None,
+            }
+        };
+        if let Some(change) = expired {
+            warn!(
+                "Display change not confirmed in {}s, rolling back",
+                DISPLAY_CONFIRM_SECONDS
+            );
+            if let Err(e) = revert(&app, display.as_ref(), change.previous) {
+                error!("Failed to roll back display change: {}", e);
+            }
+        }
+    });
+
+    Ok(DisplayChange {
+        previous,
+        applied: target,
+        confirm_within_seconds: DISPLAY_CONFIRM_SECONDS,
+    })
+}
+
+fn revert(app: &AppHandle, display: &dyn DisplayPort, previous: DisplayMode) -> Result<(), BalamError> {
+    display.set_display_mode(previous, false)?;
+    if let Err(e) = app.emit("display-change-reverted", previous) {
+        error!("Failed to emit display-change-reverted event: {}", e);
+    }
+    Ok(())
+}
@@ -0,0 +1,196 @@
+/// Quiet Mode Commands - one-tap whisper-quiet preset
+///
+/// Quiet mode lowers the TDP to its floor, caps the fan curve, dims the
+/// screen and caps games at 30 FPS (RTSS). The changes the device supports
+/// are applied as one transaction and restored together.
+use crate::adapters::overlay::RtssFrameLimiter;
+use crate::adapters::privilege::PrivilegeBrokerClient;
+use crate::application::commands::performance::{set_tdp_with_broker, TDP_CONTROLLER};
+use crate::application::services::FanControlService;
+use crate::application::DIContainer;
+use crate::domain::quiet_mode::{quiet_brightness, quiet_fan_curve, PresetStep, QuietModeStatus, QUIET_FPS_CAP};
+use crate::domain::thermal::FanCurve;
+use crate::domain::{BalamError, BrightnessConfig};
+use crate::ports::display_port::DisplayPort;
+use crate::ports::performance_port::PerformancePort;
+use std::sync::Arc;
+use tauri::State;
+
+/// TDP down to the hardware minimum.
+struct TdpStep {
+    broker: Arc<PrivilegeBrokerClient>,
+    previous: Option<u32>,
+}
+
+impl PresetStep for TdpStep {
+    fn name(&self) -> &'static str {
+        "tdp"
+    }
+
+    fn apply(&mut self) -> Result<(), BalamError> {
+        let config = PerformancePort::get_tdp_config(&*TDP_CONTROLLER)?;
+        set_tdp_with_broker(&self.broker, config.min_watts)?;
+        self.previous = Some(config.watts);
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), BalamError> {
+        match self.previous.take() {
+            Some(watts) => set_tdp_with_broker(&self.broker, watts),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Fan curve capped for silence; the player's curve (or automatic) comes back.
+struct FanCurveStep {
+    fan_control: Arc<FanControlService>,
+    /// What ran before `apply`: the custom curve, or `None` for automatic
+    previous: Option<Option<FanCurve>>,
+    /// Custom curve from settings
+    saved: Option<FanCurve>,
+}
+
+impl PresetStep for FanCurveStep {
+    fn name(&self) -> &'static str {
+        "fan_curve"
+    }
+
+    fn apply(&mut self) -> Result<(), BalamError> {
+        let running = self.saved.clone().filter(|_| self.fan_control.is_active());
+        let base = running.clone().unwrap_or_default();
+        self.fan_control.apply(quiet_fan_curve(&base))?;
+        self.previous = Some(running);
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), BalamError> {
+        match self.previous.take() {
+            Some(Some(curve)) => self.fan_control.apply(curve),
+            Some(None) => self.fan_control.restore(),
+            None => Ok(()),
+        }
+    }
+}
+
+struct BrightnessStep {
+    display: Arc<dyn DisplayPort>,
+    previous: Option<u32>,
+}
+
+impl PresetStep for BrightnessStep {
+    fn name(&self) -> &'static str {
+        "brightness"
+    }
+
+    fn apply(&mut self) -> Result<(), BalamError> {
+        let current = self
+            .display
+            .get_brightness()?
+            .ok_or_else(|| BalamError::NotSupported("Brightness control".to_string()))?;
+        let level = BrightnessConfig::new(quiet_brightness(current)).map_err(BalamError::InvalidArgument)?;
+        self.display.set_brightness(level)?;
+        self.previous = Some(current);
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), BalamError> {
+        match self.previous.take() {
+            Some(level) => self
+                .display
+                .set_brightness(BrightnessConfig::new(level).map_err(BalamError::InvalidArgument)?),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Global RTSS frame limit.
+struct FpsCapStep {
+    previous: Option<u32>,
+}
+
+impl PresetStep for FpsCapStep {
+    fn name(&self) -> &'static str {
+        "fps_cap"
+    }
+
+    fn apply(&mut self) -> Result<(), BalamError> {
+        let current = RtssFrameLimiter::limit()?;
+        RtssFrameLimiter::set_limit(QUIET_FPS_CAP)?;
+        self.previous = Some(current);
+        Ok(())
+    }
+
+    fn restore(&mut self) -> Result<(), BalamError> {
+        match self.previous.take() {
+            Some(limit) => RtssFrameLimiter::set_limit(limit),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Steps for the changes this device supports.
+fn quiet_steps(container: &DIContainer) -> Vec<Box<dyn PresetStep>> {
+    let mut steps: Vec<Box<dyn PresetStep>> = Vec::new();
+    if PerformancePort::supports_tdp_control(&*TDP_CONTROLLER) {
+        steps.push(Box::new(TdpStep {
+            broker: Arc::clone(&container.privileges),
+            previous: None,
+        }));
+    }
+    if container.fan_control.is_supported() {
+        steps.push(Box::new(FanCurveStep {
+            fan_control: Arc::clone(&container.fan_control),
+            previous: None,
+            saved: container.settings_service.get().thermal.fan_curve,
+        }));
+    }
+    let display = container.display();
+    if display.supports_brightness_control() {
+        steps.push(Box::new(BrightnessStep {
+            display,
+            previous: None,
+        }));
+    }
+    if RtssFrameLimiter::is_available() {
+        steps.push(Box::new(FpsCapStep { previous: None }));
+    }
+    steps
+}
+
+/// Turns quiet mode on or off; every change is applied and restored together.
+///
+/// Turning it on stops auto TDP (it would raise the TDP again).
+///
+/// # Errors
+/// Returns the failing change's error; nothing stays half-applied.
+///
+/// # Examples
+/// ```javascript
+/// const { active, applied } = await invoke('set_quiet_mode', { enabled: true });
+/// // applied: ['tdp', 'fan_curve', 'brightness', 'fps_cap']
+/// ```
+#[tauri::command]
+pub async fn set_quiet_mode(container: State<'_, DIContainer>, enabled: bool) -> Result<QuietModeStatus, BalamError> {
+    let _permit = container.command_guard.enter("set_quiet_mode").await?;
+    let quiet_mode = Arc::clone(&container.quiet_mode);
+    if !enabled {
+        return tokio::task::spawn_blocking(move || quiet_mode.disable())
+            .await
+            .map_err(|e| BalamError::platform("Task join error", e))?;
+    }
+
+    if !quiet_mode.status().active {
+        container.auto_tdp.stop(false)?;
+    }
+    let steps = quiet_steps(&container);
+    tokio::task::spawn_blocking(move || quiet_mode.enable(steps))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+#[tauri::command]
+#[must_use]
+pub fn get_quiet_mode_status(container: State<DIContainer>) -> QuietModeStatus {
+    container.quiet_mode.status()
+}
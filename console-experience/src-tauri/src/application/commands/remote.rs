@@ -0,0 +1,56 @@
+/// Companion Remote Commands - Pair a phone with Balam
+///
+/// The server itself follows the `remote` settings (`enabled`, `port`);
+/// these commands produce what the player scans with the phone.
+use crate::application::{remote_control, DIContainer};
+use crate::domain::remote::RemotePairing;
+use tauri::State;
+
+/// Returns the pairing URL and its QR code (SVG markup).
+///
+/// `url` and `qr_svg` are `null` when the PC has no LAN address.
+///
+/// # Errors
+/// Returns error if the pairing key cannot be read or the QR code rendered.
+///
+/// # Examples
+/// ```javascript
+/// const { enabled, url, qr_svg } = await invoke('get_remote_pairing');
+/// qrContainer.innerHTML = qr_svg;
+/// ```
+#[tauri::command]
+pub fn get_remote_pairing(container: State<DIContainer>) -> Result<RemotePairing, String> {
+    remote_control::pairing(&container.settings_service.get().remote)
+}
+
+/// Forgets every paired phone (new key, connected phones are dropped).
+///
+/// # Errors
+/// Returns error if the key file cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const pairing = await invoke('reset_remote_pairing');
+/// ```
+#[tauri::command]
+pub fn reset_remote_pairing(container: State<DIContainer>) -> Result<RemotePairing, String> {
+    remote_control::reset_pairing()?;
+    remote_control::pairing(&container.settings_service.get().remote)
+}
+
+/// Approves or refuses a phone announced by `remote-device-pending`.
+///
+/// Approved phones are remembered until the pairing is reset.
+///
+/// # Errors
+/// Returns error if the phone is no longer waiting or cannot be remembered.
+///
+/// # Examples
+/// ```javascript
+/// await listen('remote-device-pending', ({ payload }) => showPrompt(payload));
+/// await invoke('respond_to_remote_device', { deviceId: payload.device_id, accept: true });
+/// ```
+#[tauri::command]
+pub fn respond_to_remote_device(device_id: String, accept: bool) -> Result<(), String> {
+    remote_control::respond_to_device(&device_id, accept)
+}
@@ -0,0 +1,124 @@
+use crate::adapters::performance_monitoring::ProcessMetricsSampler;
+use crate::adapters::process_launcher::window_manager::minimize_window;
+use crate::application::{ActiveGame, DIContainer};
+use crate::domain::focus::InputFocus;
+use crate::domain::performance::ProcessMetrics;
+use serde::Serialize;
+use std::sync::LazyLock;
+use tauri::{AppHandle, Emitter, State};
+use tracing::info;
+
+/// Per-process sampler shared by all session queries (keeps CPU/GPU deltas between polls).
+static SESSION_SAMPLER: LazyLock<ProcessMetricsSampler> = LazyLock::new(ProcessMetricsSampler::new);
+
+/// A running game session with live resource usage.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameSession {
+    #[serde(flatten)]
+    pub active: ActiveGame,
+    /// Whether this is the session the overlay refers to
+    pub focused: bool,
+    /// Live CPU/GPU/RAM usage (`None` without a tracked PID, e.g. Steam)
+    pub metrics: Option<ProcessMetrics>,
+}
+
+/// Lists every running game with live CPU/GPU/RAM usage per PID.
+///
+/// Poll at ≥500ms: usage is computed between consecutive calls, so the first
+/// sample of a new session reports 0%.
+///
+/// # Examples
+/// ```javascript
+/// const sessions = await invoke('list_active_games');
+/// sessions.forEach(s => console.log(s.game.title, s.metrics?.cpu_usage));
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn list_active_games(container: State<DIContainer>) -> Vec<GameSession> {
+    let tracker = &container.active_games_tracker;
+    let focused = tracker.focused();
+    let sessions = tracker.list_sessions();
+
+    let pids: Vec<u32> = sessions
+        .iter()
+        .filter_map(|(_, info)| info.pid)
+        .filter(|pid| *pid != 0)
+        .collect();
+    let mut metrics = SESSION_SAMPLER.sample(&pids);
+
+    let mut result: Vec<GameSession> = sessions
+        .into_iter()
+        .map(|(game_id, info)| GameSession {
+            focused: focused.as_deref() == Some(game_id.as_str()),
+            metrics: info.pid.and_then(|pid| metrics.remove(&pid)),
+            active: ActiveGame::from(info),
+        })
+        .collect();
+
+    // Stable order for the UI: focused first, then by title
+    result.sort_by(|a, b| {
+        b.focused
+            .cmp(&a.focused)
+            .then_with(|| a.active.game.title.cmp(&b.active.game.title))
+    });
+    result
+}
+
+/// Switches to another running game.
+///
+/// Resumes the game if it was suspended, brings its main window to the
+/// foreground, hides Balam and makes it the focused session (overlay target).
+/// Emits `game-focused`.
+///
+/// # Errors
+/// Returns error if the game is not running, has no tracked PID (Steam), or
+/// its window cannot be brought to the foreground.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('focus_game', { gameId: 'steam_1245620' });
+/// ```
+#[tauri::command]
+pub fn focus_game(game_id: String, app_handle: AppHandle, container: State<DIContainer>) -> Result<ActiveGame, String> {
+    let tracker = &container.active_games_tracker;
+    let info = tracker
+        .get(&game_id)
+        .ok_or_else(|| format!("Game not running: {game_id}"))?;
+    let pid = info
+        .pid
+        .filter(|pid| *pid != 0)
+        .ok_or_else(|| format!("Cannot switch to {game_id}: no tracked process"))?;
+
+    let games = &container.game_adapter;
+    if info.suspended {
+        games.resume_game(pid)?;
+        tracker.set_suspended(&game_id, false);
+        let _ = app_handle.emit("game-resumed", serde_json::json!({ "game_id": game_id, "pid": pid }));
+    }
+
+    games.focus_window(pid)?;
+    minimize_window(&app_handle);
+    tracker.set_focused(&game_id);
+
+    info!("🔀 Switched to session {}", game_id);
+    let _ = app_handle.emit("game-focused", serde_json::json!({ "game_id": game_id, "pid": pid }));
+
+    tracker
+        .get(&game_id)
+        .map(ActiveGame::from)
+        .ok_or_else(|| format!("Game {game_id} exited while switching"))
+}
+
+/// Who the controller drives right now: Balam, or a Steam Input game that
+/// has it (Balam's navigation is then paused). Updates arrive as
+/// `input-focus-changed`.
+///
+/// # Examples
+/// ```javascript
+/// const { navigation_suppressed, game_id } = await invoke('get_input_focus');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_input_focus(container: State<DIContainer>) -> InputFocus {
+    container.focus.current()
+}
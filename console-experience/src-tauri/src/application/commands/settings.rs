@@ -0,0 +1,67 @@
+use crate::adapters::hotkeys::HotkeyManager;
+use crate::application::DIContainer;
+use crate::domain::settings::AppSettings;
+use tauri::{AppHandle, Emitter, State};
+use tracing::{error, info};
+
+/// Gets the current application settings.
+///
+/// # Examples
+/// ```javascript
+/// const settings = await invoke('get_settings');
+/// console.log(settings.hotkeys.toggle_overlay); // "Ctrl+Shift+Q"
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_settings(container: State<DIContainer>) -> AppSettings {
+    container.settings_service.get()
+}
+
+/// Replaces the application settings and persists them.
+///
+/// Emits `settings-changed` with the stored settings on success.
+///
+/// # Errors
+/// Returns error if settings are locked by parental controls, a value is out
+/// of range, hotkeys conflict, or the file cannot be written.
+///
+/// # Examples
+/// ```javascript
+/// const settings = await invoke('get_settings');
+/// settings.overlay.opacity = 0.9;
+/// await invoke('update_settings', { settings });
+/// ```
+#[tauri::command]
+pub fn update_settings(
+    settings: AppSettings,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<AppSettings, String> {
+    HotkeyManager::validate(&settings.hotkeys)?;
+    let saved = container.settings_service.replace(settings)?;
+    info!("⚙️ Settings updated");
+    emit_settings_changed(&app_handle, &saved);
+    Ok(saved)
+}
+
+/// Restores default settings.
+///
+/// Emits `settings-changed` with the defaults on success.
+///
+/// # Errors
+/// Returns error if settings are locked by parental controls or the defaults
+/// cannot be written.
+#[tauri::command]
+pub fn reset_settings(app_handle: AppHandle, container: State<DIContainer>) -> Result<AppSettings, String> {
+    let saved = container.settings_service.reset()?;
+    info!("⚙️ Settings reset to defaults");
+    emit_settings_changed(&app_handle, &saved);
+    Ok(saved)
+}
+
+/// Notifies every window that settings changed.
+pub(crate) fn emit_settings_changed(app_handle: &AppHandle, settings: &AppSettings) {
+    if let Err(e) = app_handle.emit("settings-changed", settings) {
+        error!("Failed to emit settings-changed event: {}", e);
+    }
+}
@@ -0,0 +1,381 @@
+use crate::adapters::epic_scanner::EpicScanner;
+use crate::adapters::game::{FileHasher, GameMover};
+use crate::adapters::process_launcher::pre_flight::game_process_exists;
+use crate::application::commands::game::{get_cache_path, load_games};
+use crate::application::DIContainer;
+use crate::domain::game_move::{
+    is_inside, is_protected_folder, move_destination, relocate_path, MoveOutcome, MovePhase, MoveProgress,
+};
+use crate::domain::integrity::{FileDigest, IntegrityManifest, IntegrityReport, VerifyProgress};
+use crate::domain::{Game, GameSource};
+use crate::infrastructure::clock::now_secs;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tauri::{AppHandle, Emitter, Manager, State};
+use tracing::{info, warn};
+
+/// Folder (in app local data) holding one integrity manifest per manual game.
+const INTEGRITY_DIR: &str = "integrity";
+
+/// Moves an installed game to another drive (internal SSD ↔ microSD).
+///
+/// - **Manual games**: the executable's folder is copied to `<drive>\Games\<folder>`,
+///   verified, the library entry is updated and the copied files deleted.
+///   The first call only returns `needs_confirmation` with the folder; call
+///   again with `confirmedFolder` set to it once the player agreed
+/// - **Epic**: same, and the Epic manifests are rewritten (Epic launcher must be closed)
+/// - **Steam**: Steam owns its library folders, so the game's properties
+///   dialog is opened on "Installed Files" → "Move install folder"
+///
+/// Emits `game-move-progress` (`MoveProgress`) while copying.
+///
+/// # Errors
+/// Returns error if the game is running, its store cannot be moved, a manual
+/// game sits in a user or system folder (Desktop, Downloads...) or shares it
+/// with another game, there is not enough free space, or the copy fails
+/// verification (the source is kept).
+///
+/// # Examples
+/// ```javascript
+/// await listen('game-move-progress', (e) => setProgress(e.payload.copied_bytes / e.payload.total_bytes));
+/// let outcome = await invoke('move_game', { gameId: 'manual_42', targetDrive: 'E:\\' });
+/// if (outcome.kind === 'needs_confirmation' && (await confirmFolder(outcome.folder, outcome.other_programs))) {
+///   outcome = await invoke('move_game', { gameId: 'manual_42', targetDrive: 'E:\\', confirmedFolder: outcome.folder });
+/// }
+/// if (outcome.kind === 'delegated_to_steam') showHint('Finish the move in Steam');
+/// ```
+#[tauri::command]
+pub async fn move_game(
+    game_id: String,
+    target_drive: String,
+    confirmed_folder: Option<String>,
+    app_handle: AppHandle,
+    container: State<'_, DIContainer>,
+) -> Result<MoveOutcome, String> {
+    let _permit = container.command_guard.enter("move_game").await?;
+    let games = load_games(&app_handle, &container);
+    let game = games
+        .iter()
+        .find(|g| g.id == game_id)
+        .cloned()
+        .ok_or_else(|| format!("Game not found: {game_id}"))?;
+
+    if container.active_games_tracker.get(&game_id).is_some() {
+        return Err(format!("Close {} before moving it", game.title));
+    }
+    if !game.install_state.is_playable() {
+        return Err(format!(
+            "{} is downloading or updating ({:?})",
+            game.title, game.install_state
+        ));
+    }
+
+    match game.source {
+        GameSource::Steam => open_steam_move_dialog(&game),
+        GameSource::Epic | GameSource::Manual => tauri::async_runtime::spawn_blocking(move || {
+            move_install(&app_handle, &game, &games, &target_drive, confirmed_folder.as_deref())
+        })
+        .await
+        .map_err(|e| format!("Move task failed: {e}"))?,
+        other => Err(format!("Moving {} games is not supported", other.display_name())),
+    }
+}
+
+fn open_steam_move_dialog(game: &Game) -> Result<MoveOutcome, String> {
+    let url = format!("steam://gameproperties/{}", game.raw_id);
+    Command::new("cmd")
+        .args(["/C", "start", &url])
+        .status()
+        .map_err(|e| format!("Failed to open Steam: {e}"))?;
+    info!("📦 Steam move delegated for {} ({})", game.title, url);
+    Ok(MoveOutcome::DelegatedToSteam)
+}
+
+/// A manual game's folder may be moved only when it is the game's own: not a
+/// user or system folder and not shared with another library game.
+fn check_manual_folder(game: &Game, games: &[Game], install_dir: &Path) -> Result<(), String> {
+    if is_protected_folder(install_dir, &GameMover::protected_folders()) {
+        return Err(format!(
+            "{} is in {}, which is not a game folder; put it in a folder of its own first",
+            game.title,
+            install_dir.display()
+        ));
+    }
+    if let Some(other) = games
+        .iter()
+        .find(|other| other.id != game.id && is_inside(Path::new(&other.path), install_dir))
+    {
+        return Err(format!(
+            "{} shares {} with {}; put each game in a folder of its own first",
+            game.title,
+            install_dir.display(),
+            other.title
+        ));
+    }
+    Ok(())
+}
+
+/// Copy, verify, repoint the library and store manifests, then delete the source.
+fn move_install(
+    app_handle: &AppHandle,
+    game: &Game,
+    games: &[Game],
+    target_drive: &str,
+    confirmed_folder: Option<&str>,
+) -> Result<MoveOutcome, String> {
+    let exe_path = PathBuf::from(&game.path);
+    let install_dir = match game.source {
+        GameSource::Epic => {
+            if game_process_exists("EpicGamesLauncher.exe") {
+                return Err("Close the Epic Games Launcher before moving Epic games".to_string());
+            }
+            EpicScanner::find_install_location(&game.raw_id)
+                .ok_or_else(|| format!("No Epic manifest for {}", game.title))?
+        },
+        // Games found in the uninstall registry would be re-detected at the old path
+        _ if !game.id.starts_with("manual_") => {
+            return Err(format!(
+                "{} was installed by its own setup; reinstall it on the target drive",
+                game.title
+            ));
+        },
+        _ => exe_path
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| format!("Invalid game path: {}", game.path))?,
+    };
+
+    let manual = game.source == GameSource::Manual;
+    if manual {
+        check_manual_folder(game, games, &install_dir)?;
+        // The exact folder that gets deleted, as the player saw it
+        if confirmed_folder.map(Path::new) != Some(install_dir.as_path()) {
+            return Ok(MoveOutcome::NeedsConfirmation {
+                folder: install_dir.to_string_lossy().to_string(),
+                other_programs: GameMover::other_programs(&install_dir, &exe_path),
+            });
+        }
+    }
+
+    let destination = move_destination(&install_dir, target_drive)?;
+    if destination.components().next() == install_dir.components().next() {
+        return Err(format!("{} is already on {target_drive}", game.title));
+    }
+    let new_exe_path = relocate_path(&exe_path, &install_dir, &destination)
+        .ok_or_else(|| format!("{} is outside its install folder", game.path))?;
+
+    let mut last_percent = None;
+    GameMover::copy_verified(&install_dir, &destination, &mut |phase, copied, total| {
+        // Copy progress is throttled to whole percents
+        let percent = (phase == MovePhase::Copying).then(|| copied * 100 / total.max(1));
+        if phase != MovePhase::Copying || percent != last_percent {
+            last_percent = percent;
+            emit_progress(app_handle, &game.id, phase, copied, total);
+        }
+    })?;
+
+    emit_progress(app_handle, &game.id, MovePhase::Updating, 0, 0);
+    let updated = if game.source == GameSource::Epic {
+        EpicScanner::relocate_install(&game.raw_id, &install_dir, &destination)
+    } else {
+        update_cached_path(app_handle, &game.id, &new_exe_path)
+    };
+    if let Err(e) = updated {
+        // Still pointing at the source: drop the copy
+        let _ = fs::remove_dir_all(&destination);
+        return Err(e);
+    }
+
+    emit_progress(app_handle, &game.id, MovePhase::Cleaning, 0, 0);
+    let removed = if manual {
+        GameMover::remove_copied(&install_dir, &destination)
+    } else {
+        GameMover::remove_source(&install_dir)
+    };
+    if let Err(e) = removed {
+        // The game already runs from the new location; leftovers are only wasted space
+        warn!("Moved {} but could not delete the source: {}", game.title, e);
+    }
+
+    emit_progress(app_handle, &game.id, MovePhase::Done, 0, 0);
+    info!("✅ {} moved to {}", game.title, destination.display());
+    Ok(MoveOutcome::Moved {
+        new_path: new_exe_path.to_string_lossy().to_string(),
+    })
+}
+
+/// Manual games live only in the library cache: point the entry at the new executable.
+fn update_cached_path(app_handle: &AppHandle, game_id: &str, new_path: &Path) -> Result<(), String> {
+    let cache_path = get_cache_path(app_handle).ok_or("Library cache path unavailable")?;
+    let content = fs::read_to_string(&cache_path).map_err(|e| format!("Failed to read library cache: {e}"))?;
+    let mut games: Vec<Game> = serde_json::from_str(&content).map_err(|e| format!("Invalid library cache: {e}"))?;
+
+    let game = games
+        .iter_mut()
+        .find(|g| g.id == game_id)
+        .ok_or_else(|| format!("Game not found: {game_id}"))?;
+    game.path = new_path.to_string_lossy().to_string();
+    game.raw_id.clone_from(&game.path);
+
+    let content = serde_json::to_string(&games).map_err(|e| e.to_string())?;
+    fs::write(&cache_path, content).map_err(|e| format!("Failed to write library cache: {e}"))
+}
+
+fn emit_progress(app_handle: &AppHandle, game_id: &str, phase: MovePhase, copied_bytes: u64, total_bytes: u64) {
+    let _ = app_handle.emit(
+        "game-move-progress",
+        MoveProgress {
+            game_id: game_id.to_string(),
+            phase,
+            copied_bytes,
+            total_bytes,
+        },
+    );
+}
+
+/// Checks a manual game's files against the hashes recorded when it was added.
+///
+/// The first run for a game without a baseline records one instead
+/// (`baseline_created: true`). Emits `game-verify-progress` (`VerifyProgress`).
+///
+/// # Errors
+/// Returns error if the game is not a manual game or its folder is gone
+/// (e.g., the microSD card is not inserted).
+///
+/// # Examples
+/// ```javascript
+/// const report = await invoke('verify_game_files', { gameId: 'manual_1234' });
+/// if (report.changed.length || report.missing.length) showCorruptionWarning(report);
+/// ```
+#[tauri::command]
+pub async fn verify_game_files(
+    game_id: String,
+    app_handle: AppHandle,
+    container: State<'_, DIContainer>,
+) -> Result<IntegrityReport, String> {
+    let _permit = container.command_guard.enter("verify_game_files").await?;
+    let game = find_manual_game(&app_handle, &container, &game_id)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let Some(manifest) = load_integrity_manifest(&app_handle, &game.id) else {
+            record_integrity_baseline(&app_handle, &game)?;
+            return Ok(IntegrityReport {
+                game_id: game.id,
+                baseline_created: true,
+                ..IntegrityReport::default()
+            });
+        };
+
+        let current = hash_install(&app_handle, &game)?;
+        let report = manifest.compare(&current);
+        if report.is_intact() {
+            info!("✅ {} files intact ({} checked)", game.title, report.checked_files);
+        } else {
+            warn!(
+                "⚠️ {} integrity check: {} changed, {} missing",
+                game.title,
+                report.changed.len(),
+                report.missing.len()
+            );
+        }
+        Ok(report)
+    })
+    .await
+    .map_err(|e| format!("Verify task failed: {e}"))?
+}
+
+/// Re-records the integrity baseline (after the user updated or modded the game).
+///
+/// # Errors
+/// Returns error if the game is not a manual game or its files cannot be read.
+#[tauri::command]
+pub async fn rebuild_game_file_manifest(
+    game_id: String,
+    app_handle: AppHandle,
+    container: State<'_, DIContainer>,
+) -> Result<(), String> {
+    let game = find_manual_game(&app_handle, &container, &game_id)?;
+    tauri::async_runtime::spawn_blocking(move || record_integrity_baseline(&app_handle, &game))
+        .await
+        .map_err(|e| format!("Hash task failed: {e}"))?
+}
+
+/// Hashes a game's install folder and stores it as the known-good baseline.
+pub(crate) fn record_integrity_baseline(app_handle: &AppHandle, game: &Game) -> Result<(), String> {
+    let manifest = IntegrityManifest {
+        game_id: game.id.clone(),
+        created_at: now_secs(),
+        files: hash_install(app_handle, game)?,
+    };
+
+    let path = integrity_manifest_path(app_handle, &game.id).ok_or("Cannot resolve app data directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create integrity folder: {e}"))?;
+    }
+    let json = serde_json::to_string(&manifest).map_err(|e| e.to_string())?;
+    fs::write(&path, json).map_err(|e| format!("Failed to save integrity manifest: {e}"))?;
+
+    info!(
+        "🔐 Integrity baseline recorded for {} ({} files)",
+        game.title,
+        manifest.files.len()
+    );
+    Ok(())
+}
+
+/// Deletes the stored baseline (game removed from the library).
+pub(crate) fn remove_integrity_manifest(app_handle: &AppHandle, game_id: &str) {
+    if let Some(path) = integrity_manifest_path(app_handle, game_id) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+fn find_manual_game(app_handle: &AppHandle, container: &DIContainer, game_id: &str) -> Result<Game, String> {
+    let game = load_games(app_handle, container)
+        .into_iter()
+        .find(|g| g.id == game_id)
+        .ok_or_else(|| format!("Game not found: {game_id}"))?;
+    if game.source != GameSource::Manual {
+        // Stores verify their own installs (Steam "Verify integrity", Epic "Verify")
+        return Err(format!("Use {} to verify {}", game.source.display_name(), game.title));
+    }
+    Ok(game)
+}
+
+fn hash_install(app_handle: &AppHandle, game: &Game) -> Result<BTreeMap<String, FileDigest>, String> {
+    let install_dir = Path::new(&game.path)
+        .parent()
+        .ok_or_else(|| format!("Invalid game path: {}", game.path))?;
+    let total_bytes = GameMover::measure(install_dir)?.bytes;
+
+    let mut last_percent = u64::MAX;
+    FileHasher::hash_tree(install_dir, &mut |hashed_bytes| {
+        let percent = hashed_bytes * 100 / total_bytes.max(1);
+        if percent != last_percent {
+            last_percent = percent;
+            let _ = app_handle.emit(
+                "game-verify-progress",
+                VerifyProgress {
+                    game_id: game.id.clone(),
+                    hashed_bytes,
+                    total_bytes,
+                },
+            );
+        }
+    })
+}
+
+fn load_integrity_manifest(app_handle: &AppHandle, game_id: &str) -> Option<IntegrityManifest> {
+    let content = fs::read_to_string(integrity_manifest_path(app_handle, game_id)?).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn integrity_manifest_path(app_handle: &AppHandle, game_id: &str) -> Option<PathBuf> {
+    app_handle
+        .path()
+        .app_local_data_dir()
+        .ok()
+        .map(|p| p.join(INTEGRITY_DIR).join(format!("{game_id}.json")))
+}
@@ -0,0 +1,66 @@
+/// Stutter Commands - disk I/O and shader compilation stutter diagnostics
+///
+/// `start_stutter_diagnostics` samples the focused game in the background
+/// until it exits or `stop_stutter_diagnostics` is called, then emits
+/// `stutter-report` with the annotated report. `get_stutter_report` returns
+/// the report of a session, also while it is still recording.
+use crate::application::commands::performance::PERF_MONITOR;
+use crate::application::DIContainer;
+use crate::domain::stutter::StutterReport;
+use crate::domain::BalamError;
+use tauri::{AppHandle, Emitter, State};
+
+/// Starts stutter diagnostics for the focused game and returns the session ID.
+///
+/// # Errors
+/// - `NOT_FOUND` - No game is running
+/// - `PLATFORM` - A session is already running
+///
+/// # Examples
+/// ```javascript
+/// await listen('stutter-report', (e) => console.log(e.payload.summary));
+/// const sessionId = await invoke('start_stutter_diagnostics');
+/// ```
+#[tauri::command]
+pub fn start_stutter_diagnostics(app: AppHandle, container: State<DIContainer>) -> Result<String, BalamError> {
+    let target = container
+        .active_games_tracker
+        .capture_target()
+        .ok_or_else(|| BalamError::NotFound("Running game".to_string()))?;
+    let game_pid = target.pid;
+
+    container.stutter.start(
+        target.game.id,
+        target.game.title,
+        container.active_games_tracker.clone(),
+        Box::new(move || PERF_MONITOR.get_metrics_for(game_pid)),
+        Box::new(move |report| {
+            let _ = app.emit("stutter-report", &report);
+        }),
+    )
+}
+
+/// Stops the session in progress; its report stays available.
+///
+/// Returns whether a session was running.
+#[must_use]
+#[tauri::command]
+pub fn stop_stutter_diagnostics(container: State<DIContainer>) -> bool {
+    container.stutter.stop()
+}
+
+/// Annotated report of a session, e.g. "23 stutters in 10 min, 78% correlated
+/// with disk reads", with every stutter and its likely cause.
+///
+/// # Errors
+/// Returns `NOT_FOUND` for an unknown session (only the last 10 are kept).
+///
+/// # Examples
+/// ```javascript
+/// const report = await invoke('get_stutter_report', { sessionId });
+/// report.stutters.filter(s => s.cause === 'cpu_spike');
+/// ```
+#[tauri::command]
+pub fn get_stutter_report(session_id: String, container: State<DIContainer>) -> Result<StutterReport, BalamError> {
+    container.stutter.report(&session_id)
+}
@@ -0,0 +1,163 @@
+/// Sunshine Commands - Stream the library to Moonlight
+///
+/// Detects a local Sunshine install, writes every playable library game into
+/// its app list (entries the player added are kept), starts/stops the
+/// Sunshine service and completes Moonlight pairing with the PIN Moonlight
+/// shows.
+use crate::adapters::sunshine::{sunshine_app_for, SunshineInstallation, SunshineService, SunshineWebApi};
+use crate::application::commands::fps_service_manager::is_elevated;
+use crate::application::commands::game::load_games;
+use crate::application::DIContainer;
+use crate::domain::privilege::PrivilegedOperation;
+use crate::domain::sunshine::{SunshineApp, SunshineStatus};
+use crate::domain::BalamError;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+fn installation() -> Result<SunshineInstallation, BalamError> {
+    SunshineInstallation::detect().ok_or_else(|| BalamError::NotFound("Sunshine installation".to_string()))
+}
+
+fn status() -> SunshineStatus {
+    match SunshineInstallation::detect() {
+        Some(installation) => SunshineStatus {
+            installed: true,
+            install_dir: Some(installation.install_dir.to_string_lossy().to_string()),
+            service_running: SunshineService::is_running(),
+            web_ui_url: Some(installation.web_ui_url()),
+            synced_games: installation.synced_games(),
+        },
+        None => SunshineStatus {
+            installed: false,
+            install_dir: None,
+            service_running: false,
+            web_ui_url: None,
+            synced_games: 0,
+        },
+    }
+}
+
+/// Returns whether Sunshine is installed and running, and how many games it streams.
+///
+/// # Examples
+/// ```javascript
+/// const { installed, service_running, synced_games } = await invoke('get_sunshine_status');
+/// ```
+#[tauri::command]
+pub async fn get_sunshine_status() -> Result<SunshineStatus, BalamError> {
+    tokio::task::spawn_blocking(status)
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))
+}
+
+/// Writes every playable library game into Sunshine's app list.
+///
+/// Returns the number of games synced. Earlier Balam entries are replaced,
+/// so uninstalled games disappear from Moonlight too.
+///
+/// # Errors
+/// - `NOT_FOUND` - Sunshine is not installed
+/// - `ACCESS_DENIED` - Sunshine's config folder is not writable
+///
+/// # Examples
+/// ```javascript
+/// const count = await invoke('sync_sunshine_library');
+/// ```
+#[tauri::command]
+pub async fn sync_sunshine_library(app: AppHandle) -> Result<usize, BalamError> {
+    tokio::task::spawn_blocking(move || {
+        let installation = installation()?;
+        let games = load_games(&app, &app.state::<DIContainer>());
+        let apps: Vec<SunshineApp> = games
+            .iter()
+            .filter(|game| game.install_state.is_playable())
+            .map(sunshine_app_for)
+            .collect();
+        installation.write_apps(&apps)?;
+        Ok(apps.len())
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Starts or stops the service directly when elevated, otherwise through the broker.
+async fn control_service(app: &AppHandle, start: bool) -> Result<SunshineStatus, BalamError> {
+    installation()?;
+    let broker = Arc::clone(&app.state::<DIContainer>().privileges);
+    tokio::task::spawn_blocking(move || {
+        if is_elevated() {
+            let result = if start {
+                SunshineService::start()
+            } else {
+                SunshineService::stop()
+            };
+            result.map_err(BalamError::Platform)?;
+        } else if start {
+            broker.execute(&PrivilegedOperation::StartSunshine)?;
+        } else {
+            broker.execute(&PrivilegedOperation::StopSunshine)?;
+        }
+        Ok(status())
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Starts the Sunshine service (UAC prompt once per session when not elevated).
+///
+/// # Errors
+/// Returns `NOT_FOUND` if Sunshine is not installed, or the service error.
+///
+/// # Examples
+/// ```javascript
+/// const status = await invoke('start_sunshine');
+/// ```
+#[tauri::command]
+pub async fn start_sunshine(app: AppHandle) -> Result<SunshineStatus, BalamError> {
+    control_service(&app, true).await
+}
+
+/// Stops the Sunshine service; active streams end.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if Sunshine is not installed, or the service error.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('stop_sunshine');
+/// ```
+#[tauri::command]
+pub async fn stop_sunshine(app: AppHandle) -> Result<SunshineStatus, BalamError> {
+    control_service(&app, false).await
+}
+
+/// Pairs a Moonlight client: enter the PIN Moonlight displays.
+///
+/// `username` / `password` are the Sunshine web UI credentials (not stored).
+///
+/// # Errors
+/// - `ACCESS_DENIED` - Wrong credentials
+/// - `INVALID_ARGUMENT` - PIN rejected (no pairing in progress)
+///
+/// # Examples
+/// ```javascript
+/// await invoke('submit_sunshine_pin', { pin: '4821', deviceName: 'Living room TV', username, password });
+/// ```
+#[tauri::command]
+pub async fn submit_sunshine_pin(
+    pin: String,
+    device_name: String,
+    username: String,
+    password: String,
+) -> Result<(), BalamError> {
+    if pin.len() != 4 || !pin.chars().all(|c| c.is_ascii_digit()) {
+        return Err(BalamError::InvalidArgument(
+            "The Moonlight PIN has 4 digits".to_string(),
+        ));
+    }
+    tokio::task::spawn_blocking(move || {
+        SunshineWebApi::new(&installation()?).submit_pin(&pin, &device_name, &username, &password)
+    })
+    .await
+    .map_err(|e| BalamError::platform("Task join error", e))?
+}
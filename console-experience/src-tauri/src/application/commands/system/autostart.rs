@@ -0,0 +1,111 @@
+/// Autostart Commands - Start Balam at sign-in
+///
+/// Autostart is a Task Scheduler logon task running with highest privileges
+/// (a `Run` key entry would start Balam unelevated and trigger UAC prompts for
+/// TDP and FPS features). Optionally the FPS service is switched to automatic
+/// start so overlay metrics are available as soon as the first game launches.
+use crate::adapters::autostart::TaskSchedulerAutostart;
+use crate::application::commands::fps_service_manager::{
+    is_elevated, is_fps_service_boot_start, set_fps_service_boot_start,
+};
+use crate::application::DIContainer;
+use crate::domain::autostart::{AutostartOptions, AutostartStatus, LaunchOptions};
+use crate::domain::privilege::PrivilegedOperation;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+
+/// Gets the current autostart configuration.
+///
+/// # Examples
+/// ```javascript
+/// const status = await invoke('get_autostart_status');
+/// if (status.enabled && status.options.start_in_library) { ... }
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_autostart_status() -> AutostartStatus {
+    let launch = TaskSchedulerAutostart::new().registered_launch_options();
+
+    AutostartStatus {
+        enabled: launch.is_some(),
+        options: AutostartOptions {
+            start_minimized: launch.is_some_and(|l| l.start_minimized),
+            start_in_library: launch.is_some_and(|l| l.start_in_library),
+            start_fps_service: is_fps_service_boot_start(),
+        },
+    }
+}
+
+/// Enables autostart (or updates its options).
+///
+/// # Arguments
+/// * `options` - `start_minimized`, `start_in_library`, `start_fps_service`
+///
+/// # Errors
+/// Returns error if UAC is declined or the task cannot be created.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_autostart', {
+///   options: { start_minimized: false, start_in_library: true, start_fps_service: true },
+/// });
+/// ```
+#[tauri::command]
+pub async fn set_autostart(app: AppHandle, options: AutostartOptions) -> Result<AutostartStatus, String> {
+    if !is_elevated() {
+        run_privileged(
+            &app,
+            vec![
+                PrivilegedOperation::RegisterAutostart { options },
+                PrivilegedOperation::SetFpsServiceBootStart {
+                    enabled: options.start_fps_service,
+                },
+            ],
+        )
+        .await?;
+        return Ok(get_autostart_status());
+    }
+
+    let executable = std::env::current_exe().map_err(|e| format!("Failed to resolve executable path: {e}"))?;
+    TaskSchedulerAutostart::new().register(&executable, &options)?;
+    set_fps_service_boot_start(options.start_fps_service)?;
+
+    Ok(get_autostart_status())
+}
+
+/// Disables autostart.
+///
+/// The FPS service start type is left untouched (it may have been installed
+/// as automatic independently of autostart).
+///
+/// # Errors
+/// Returns error if UAC is declined or the task cannot be deleted.
+#[tauri::command]
+pub async fn disable_autostart(app: AppHandle) -> Result<AutostartStatus, String> {
+    if !is_elevated() {
+        run_privileged(&app, vec![PrivilegedOperation::UnregisterAutostart]).await?;
+        return Ok(get_autostart_status());
+    }
+
+    TaskSchedulerAutostart::new().unregister()?;
+
+    Ok(get_autostart_status())
+}
+
+/// Runs the task changes through the privilege broker (Balam is not elevated).
+async fn run_privileged(app: &AppHandle, operations: Vec<PrivilegedOperation>) -> Result<(), String> {
+    let broker = Arc::clone(&app.state::<DIContainer>().privileges);
+    tokio::task::spawn_blocking(move || operations.iter().try_for_each(|operation| broker.execute(operation)))
+        .await
+        .map_err(|e| format!("Task join error: {e}"))?
+        .map_err(String::from)
+}
+
+/// Flags this process was started with (e.g. by the autostart task).
+///
+/// The frontend calls this once on load to decide the initial screen.
+#[must_use]
+#[tauri::command]
+pub fn get_launch_options() -> LaunchOptions {
+    LaunchOptions::from_args(std::env::args())
+}
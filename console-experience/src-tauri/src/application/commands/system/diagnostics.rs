@@ -0,0 +1,167 @@
+use crate::adapters::overlay::get_fps_snapshot_from_fps_service;
+use crate::application::DIContainer;
+use crate::domain::present_latency::PresentReport;
+use crate::infrastructure::json_file::write_json;
+use crate::infrastructure::logging::{log_files, read_recent_logs, LogComponent, LogEntry, LogFilter};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tauri::{AppHandle, Manager, State};
+use tracing::{info, warn};
+
+/// Returns recent log entries of the app, watchdog and fps-service (newest first).
+///
+/// # Arguments
+/// * `filter` - Optional level/component/text filter and entry limit (default 500)
+///
+/// # Examples
+/// ```javascript
+/// const errors = await invoke('get_recent_logs', { filter: { min_level: 'warn', limit: 100 } });
+/// const service = await invoke('get_recent_logs', { filter: { component: 'fps_service' } });
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_recent_logs(filter: Option<LogFilter>) -> Vec<LogEntry> {
+    read_recent_logs(&filter.unwrap_or_default())
+}
+
+/// Bundles logs, settings and system information into a zip for bug reports.
+///
+/// The archive is written to `<app data>\diagnostics` and contains:
+/// - `logs\<component>\*.log` (all retained log files)
+/// - `system_info.json` (app version, OS, running sessions)
+/// - `settings.json`
+///
+/// # Returns
+/// Full path of the created zip.
+///
+/// # Errors
+/// Returns error if the staging folder cannot be written or compression fails.
+///
+/// # Examples
+/// ```javascript
+/// const zipPath = await invoke('export_diagnostics_zip');
+/// await invoke('plugin:opener|reveal_item_in_dir', { path: zipPath });
+/// ```
+#[tauri::command]
+pub fn export_diagnostics_zip(app_handle: AppHandle, container: State<DIContainer>) -> Result<String, String> {
+    let output_dir = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| format!("Cannot resolve app data directory: {e}"))?
+        .join("diagnostics");
+    let name = format!("balam-diagnostics-{}", chrono::Local::now().format("%Y%m%d-%H%M%S"));
+    let staging = std::env::temp_dir().join(&name);
+    let zip_path = output_dir.join(format!("{name}.zip"));
+
+    fs::create_dir_all(&output_dir).map_err(|e| format!("Failed to create diagnostics folder: {e}"))?;
+    fs::create_dir_all(&staging).map_err(|e| format!("Failed to create staging folder: {e}"))?;
+
+    let result = write_diagnostics(&staging, &app_handle, &container).and_then(|()| compress(&staging, &zip_path));
+    if let Err(e) = fs::remove_dir_all(&staging) {
+        warn!("Failed to remove diagnostics staging folder: {}", e);
+    }
+    result?;
+
+    info!("🩺 Diagnostics exported to {}", zip_path.display());
+    Ok(zip_path.display().to_string())
+}
+
+/// Reports how the running game reaches the display: present mode, VSync,
+/// tearing, flip model and an estimated Present()-to-display latency.
+///
+/// Read from the FPS service's ETW data; `null` while no game is presenting.
+/// The latency is a model of the present path, useful to compare settings
+/// (VSync off, borderless vs exclusive), not a click-to-photon measurement.
+///
+/// # Errors
+/// Returns error if the FPS service is not running.
+///
+/// # Examples
+/// ```javascript
+/// const report = await invoke('get_present_report');
+/// if (report?.present_mode === 'composed') hint('Exclusive fullscreen or borderless flip skips DWM');
+/// console.log(`${report.estimated_latency_ms?.toFixed(1)} ms, VSync ${report.vsync}`);
+/// ```
+#[tauri::command]
+pub fn get_present_report(container: State<DIContainer>) -> Result<Option<PresentReport>, String> {
+    let (fps, game) = get_fps_snapshot_from_fps_service()?;
+    let Some(game) = game else {
+        return Ok(None);
+    };
+    // Without the refresh rate the report still shows mode and VSync, minus the estimate
+    let refresh_hz = container.display().get_refresh_rate().unwrap_or_else(|e| {
+        warn!("Refresh rate unavailable for the present report: {}", e);
+        0
+    });
+    Ok(Some(PresentReport::new(
+        game.pid,
+        game.name,
+        game.present_mode,
+        game.sync_interval,
+        game.allow_tearing,
+        fps,
+        refresh_hz,
+    )))
+}
+
+fn write_diagnostics(staging: &Path, app_handle: &AppHandle, container: &DIContainer) -> Result<(), String> {
+    for component in LogComponent::ALL {
+        let dir = staging.join("logs").join(component.file_prefix());
+        fs::create_dir_all(&dir).map_err(|e| format!("Failed to create log folder: {e}"))?;
+        for file in log_files(component) {
+            if let Some(file_name) = file.file_name() {
+                // The active file may be locked for a moment by the writer; skip rather than fail
+                if let Err(e) = fs::copy(&file, dir.join(file_name)) {
+                    warn!("Skipping log {}: {}", file.display(), e);
+                }
+            }
+        }
+    }
+
+    let sessions: Vec<String> = container
+        .active_games_tracker
+        .list_sessions()
+        .into_iter()
+        .map(|(id, info)| format!("{id} (pid {:?}, suspended: {})", info.pid, info.suspended))
+        .collect();
+    let system_info = serde_json::json!({
+        "app_version": app_handle.package_info().version.to_string(),
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "os_version": windows_version(),
+        "exported_at": chrono::Local::now().to_rfc3339(),
+        "active_sessions": sessions,
+    });
+    write_json(&staging.join("system_info.json"), &system_info).map_err(|e| e.to_string())?;
+    write_json(&staging.join("settings.json"), &container.settings_service.get()).map_err(|e| e.to_string())
+}
+
+/// Windows version string from `cmd /c ver` (e.g. `Microsoft Windows [Version 10.0.22631.4317]`).
+fn windows_version() -> Option<String> {
+    let output = Command::new("cmd").args(["/c", "ver"]).output().ok()?;
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!version.is_empty()).then_some(version)
+}
+
+/// Zips the contents of `source` with PowerShell's `Compress-Archive`.
+fn compress(source: &Path, zip_path: &Path) -> Result<(), String> {
+    let script = format!(
+        "Compress-Archive -Path '{}\\*' -DestinationPath '{}' -Force",
+        source.display().to_string().replace('\'', "''"),
+        zip_path.display().to_string().replace('\'', "''"),
+    );
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", &script])
+        .output()
+        .map_err(|e| format!("Failed to run PowerShell: {e}"))?;
+
+    if output.status.success() && zip_path.exists() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Compress-Archive failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::application::DIContainer;
+use crate::domain::BalamError;
+use crate::ports::system_port::AudioDevice;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+
+/// Lists active capture devices (microphones, headset mics, line-in).
+///
+/// # Errors
+/// Returns error if the audio endpoints cannot be enumerated.
+#[tauri::command]
+pub fn list_input_devices(container: State<DIContainer>) -> Result<Vec<AudioDevice>, BalamError> {
+    container.system.list_input_devices()
+}
+
+/// Sets the default microphone (console, multimedia and communications roles).
+///
+/// # Errors
+/// Returns `NOT_FOUND` if `device_id` is not an active capture device.
+#[tauri::command]
+pub fn set_default_input_device(container: State<DIContainer>, device_id: String) -> Result<(), BalamError> {
+    container.system.set_default_input_device(&device_id)
+}
+
+/// Returns the default microphone level (0-100).
+///
+/// # Errors
+/// Returns `NOT_FOUND` if no microphone is connected.
+#[tauri::command]
+pub fn get_mic_volume(container: State<DIContainer>) -> Result<u32, BalamError> {
+    container.system.get_mic_volume()
+}
+
+/// Sets the default microphone level (0-100).
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` above 100 or `NOT_FOUND` if no microphone is connected.
+#[tauri::command]
+pub fn set_mic_volume(container: State<DIContainer>, level: u32) -> Result<(), BalamError> {
+    container.system.set_mic_volume(level)
+}
+
+/// Returns whether the default microphone is muted.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if no microphone is connected.
+#[tauri::command]
+pub fn get_mic_muted(container: State<DIContainer>) -> Result<bool, BalamError> {
+    container.system.is_mic_muted()
+}
+
+/// Mutes or unmutes the default microphone and emits `mic-mute-changed`.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if no microphone is connected.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_mic_muted', { muted: true });
+/// await listen('mic-mute-changed', (event) => setMicMuted(event.payload));
+/// ```
+#[tauri::command]
+pub fn set_mic_muted(app: AppHandle, muted: bool) -> Result<(), BalamError> {
+    apply_mic_mute(&app, muted)
+}
+
+/// Toggles the default microphone mute.
+///
+/// # Returns
+/// The new mute state.
+///
+/// # Errors
+/// Returns `NOT_FOUND` if no microphone is connected.
+#[tauri::command]
+pub fn toggle_mic_mute(app: AppHandle) -> Result<bool, BalamError> {
+    toggle_microphone_mute(&app)
+}
+
+/// Toggles the mic mute (mute hotkey and gamepad chord).
+pub(crate) fn toggle_microphone_mute<R: Runtime>(app: &AppHandle<R>) -> Result<bool, BalamError> {
+    let muted = !app.state::<DIContainer>().system.is_mic_muted()?;
+    apply_mic_mute(app, muted)?;
+    Ok(muted)
+}
+
+/// Push-to-talk: the microphone is live only while the hotkey is held.
+pub(crate) fn set_push_to_talk<R: Runtime>(app: &AppHandle<R>, held: bool) -> Result<(), BalamError> {
+    apply_mic_mute(app, !held)
+}
+
+fn apply_mic_mute<R: Runtime>(app: &AppHandle<R>, muted: bool) -> Result<(), BalamError> {
+    app.state::<DIContainer>().system.set_mic_muted(muted)?;
+    let _ = app.emit("mic-mute-changed", muted);
+    Ok(())
+}
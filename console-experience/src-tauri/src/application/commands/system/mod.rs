@@ -0,0 +1,99 @@
+pub mod autostart;
+pub mod diagnostics;
+pub mod microphone;
+pub mod safe_mode;
+pub mod sleep;
+
+pub use autostart::*;
+pub use diagnostics::*;
+pub use microphone::*;
+pub use safe_mode::*;
+pub use sleep::*;
+
+use crate::application::DIContainer;
+use crate::domain::BalamError;
+use crate::ports::system_port::{AudioDevice, SystemStatus};
+use tauri::State;
+use tracing::info;
+
+#[tauri::command]
+pub fn log_message(message: String) {
+    info!("[FRONTEND]: {}", message);
+}
+
+/// Returns the current battery, volume and network status.
+///
+/// Served from the status monitor's latest reading; changes are pushed with
+/// the `system-status-changed` event, so this is only needed once at startup.
+///
+/// # Examples
+/// ```javascript
+/// setStatus(await invoke('get_system_status'));
+/// await listen('system-status-changed', (event) => setStatus(event.payload));
+/// ```
+#[tauri::command]
+#[must_use]
+pub fn get_system_status(container: State<DIContainer>) -> SystemStatus {
+    container
+        .system_status
+        .current()
+        .unwrap_or_else(|| container.system.get_status())
+}
+
+/// Sets the master volume; a slider drag collapses into its last value.
+#[tauri::command]
+pub async fn set_volume(container: State<'_, DIContainer>, level: u32) -> Result<(), BalamError> {
+    let Some(_permit) = container.command_guard.enter("set_volume").await? else {
+        return Ok(());
+    };
+    container.system.set_volume(level)
+}
+
+#[tauri::command]
+pub fn list_audio_devices(container: State<DIContainer>) -> Result<Vec<AudioDevice>, BalamError> {
+    container.system.list_audio_devices()
+}
+
+#[tauri::command]
+pub fn set_default_audio_device(container: State<DIContainer>, device_id: String) -> Result<(), BalamError> {
+    container.system.set_default_audio_device(&device_id)
+}
+
+#[tauri::command]
+pub fn shutdown_pc(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.system.shutdown()
+}
+
+#[tauri::command]
+pub fn restart_pc(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.system.restart()
+}
+
+#[tauri::command]
+pub fn logout_pc(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.system.logout()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::Ports;
+    use tauri::Manager;
+
+    #[test]
+    fn test_audio_commands_use_registered_port() {
+        let app = tauri::test::mock_app();
+        app.manage(DIContainer::with_ports(Ports::mock()));
+
+        tauri::async_runtime::block_on(set_volume(app.state(), 35)).unwrap();
+        assert!(tauri::async_runtime::block_on(set_volume(app.state(), 120)).is_err());
+        assert_eq!(get_system_status(app.state()).volume, 35);
+
+        set_default_audio_device(app.state(), "headset".to_string()).unwrap();
+        let devices = list_audio_devices(app.state()).unwrap();
+        assert!(devices.iter().any(|d| d.id == "headset" && d.is_default));
+
+        assert!(toggle_microphone_mute(app.handle()).unwrap());
+        assert!(get_mic_muted(app.state()).unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+/// Safe Mode Commands - Leave the minimal mode started after a crash loop
+///
+/// The frontend learns about safe mode from `get_launch_options`
+/// (`safe_mode`) and shows the library only.
+use crate::domain::autostart::SAFE_MODE_FLAG;
+use std::process::Command;
+use tauri::AppHandle;
+use tracing::info;
+
+/// Restarts Balam without `--safe-mode` (other launch flags are kept).
+///
+/// # Errors
+/// Returns error if the executable cannot be started again.
+///
+/// # Examples
+/// ```javascript
+/// const { safe_mode } = await invoke('get_launch_options');
+/// if (safe_mode) await invoke('exit_safe_mode');
+/// ```
+#[tauri::command]
+pub fn exit_safe_mode(app: AppHandle) -> Result<(), String> {
+    let executable = std::env::current_exe().map_err(|e| format!("Failed to resolve executable path: {e}"))?;
+    Command::new(executable)
+        .args(std::env::args().skip(1).filter(|arg| arg != SAFE_MODE_FLAG))
+        .spawn()
+        .map_err(|e| format!("Failed to restart Balam: {e}"))?;
+
+    info!("🛟 Leaving safe mode, restarting Balam");
+    app.exit(0);
+    Ok(())
+}
@@ -0,0 +1,71 @@
+/// Sleep Commands - Sleep timer and idle auto-suspend
+///
+/// The idle monitor (started at app setup) suspends the device when the
+/// sleep timer runs out, or after `idle.auto_suspend_minutes` without gamepad
+/// input while no game is running. It emits `system-suspending` with the
+/// reason just before.
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::idle::IdleStatus;
+use crate::domain::BalamError;
+use tauri::{AppHandle, State};
+
+/// Idle time, auto-suspend delay and sleep timer countdown.
+///
+/// # Examples
+/// ```javascript
+/// const { sleep_timer_remaining_seconds } = await invoke('get_idle_status');
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_idle_status(container: State<DIContainer>) -> IdleStatus {
+    let auto_suspend_minutes = container.settings_service.get().idle.auto_suspend_minutes;
+    container.idle.status(auto_suspend_minutes)
+}
+
+/// Suspends the device after `minutes` (replaces a running timer).
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` outside 1-480 minutes.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_sleep_timer', { minutes: 30 });
+/// ```
+#[tauri::command]
+pub fn set_sleep_timer(minutes: u32, container: State<DIContainer>) -> Result<IdleStatus, BalamError> {
+    container.idle.set_sleep_timer(minutes)?;
+    Ok(get_idle_status(container))
+}
+
+#[tauri::command]
+pub fn cancel_sleep_timer(container: State<DIContainer>) {
+    container.idle.cancel_sleep_timer();
+}
+
+/// Sets the idle delay before auto-suspend (`0` disables it).
+///
+/// # Errors
+/// Returns error if the delay is above 240 minutes or settings cannot be saved.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('set_auto_suspend_minutes', { minutes: 15 });
+/// ```
+#[tauri::command]
+pub fn set_auto_suspend_minutes(
+    minutes: u32,
+    app_handle: AppHandle,
+    container: State<DIContainer>,
+) -> Result<(), String> {
+    let saved = container
+        .settings_service
+        .update(|settings| settings.idle.auto_suspend_minutes = minutes)?;
+    emit_settings_changed(&app_handle, &saved);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn suspend_pc(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.system.suspend()
+}
@@ -0,0 +1,32 @@
+/// Telemetry Commands - Inspect and clear opt-in usage counters
+///
+/// Collection is enabled with the `telemetry.enabled` setting and sent to
+/// `telemetry.endpoint`; these commands show the player exactly what the
+/// next upload contains and let them throw it away.
+use crate::application::DIContainer;
+use crate::domain::telemetry::TelemetryPreview;
+use tauri::State;
+
+/// Returns the batch the next upload would send, byte for byte.
+///
+/// # Examples
+/// ```javascript
+/// const { enabled, endpoint, batch } = await invoke('get_telemetry_preview');
+/// console.log(JSON.stringify(batch, null, 2));
+/// ```
+#[must_use]
+#[tauri::command]
+pub fn get_telemetry_preview(container: State<DIContainer>) -> TelemetryPreview {
+    container.telemetry.preview()
+}
+
+/// Deletes pending counters and the random install ID.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('clear_telemetry_data');
+/// ```
+#[tauri::command]
+pub fn clear_telemetry_data(container: State<DIContainer>) {
+    container.telemetry.clear();
+}
@@ -0,0 +1,102 @@
+/// Text Input Commands - On-screen keyboard for controller text entry
+///
+/// The frontend reports every focus change of its text fields with
+/// `set_text_input_focus`. With `controller.windows_touch_keyboard` on, the
+/// Windows touch keyboard (TabTip / TextInputHost) is summoned for text
+/// fields and dismissed again on blur; otherwise the frontend draws Balam's
+/// keyboard. Either way the resulting state is emitted as
+/// `text-input-changed`.
+///
+/// In console mode explorer is not running, so there is no touch keyboard:
+/// Balam's keyboard is always used, and `send_virtual_text` /
+/// `send_virtual_key` let it type into other windows (a launcher login, a
+/// game's name entry) by injecting keystrokes into the foreground window.
+use crate::application::DIContainer;
+use crate::domain::text_input::{TextInputState, VirtualKey};
+use crate::domain::BalamError;
+use std::sync::Arc;
+use tauri::{AppHandle, Emitter, State};
+
+/// Reports a focus change of a WebView text field.
+///
+/// `input_type` is the `type` of the focused `<input>` (omit it for a
+/// textarea or contenteditable element).
+///
+/// # Errors
+/// Returns error only if the background task fails; an unreachable touch
+/// keyboard is logged and the state is still updated.
+///
+/// # Examples
+/// ```javascript
+/// document.addEventListener('focusin', (e) =>
+///   invoke('set_text_input_focus', { focused: true, inputType: e.target.type }));
+/// document.addEventListener('focusout', () => invoke('set_text_input_focus', { focused: false }));
+/// ```
+#[tauri::command]
+pub async fn set_text_input_focus(
+    app: AppHandle,
+    container: State<'_, DIContainer>,
+    focused: bool,
+    input_type: Option<String>,
+) -> Result<TextInputState, BalamError> {
+    let service = Arc::clone(&container.text_input);
+    let prefer_windows = container.settings_service.get().controller.windows_touch_keyboard;
+    let state = tokio::task::spawn_blocking(move || service.set_focus(focused, input_type, prefer_windows))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?;
+    let _ = app.emit("text-input-changed", &state);
+    Ok(state)
+}
+
+/// Current text entry state (focused field, backend, keyboard visibility).
+#[must_use]
+#[tauri::command]
+pub fn get_text_input_state(container: State<DIContainer>) -> TextInputState {
+    container.text_input.current()
+}
+
+/// Summons the Windows touch keyboard (starting TabTip if needed).
+///
+/// # Errors
+/// Returns `NOT_SUPPORTED` in console mode, or a platform error if the
+/// touch keyboard is not available.
+#[tauri::command]
+pub async fn show_touch_keyboard(container: State<'_, DIContainer>) -> Result<(), BalamError> {
+    let service = Arc::clone(&container.text_input);
+    tokio::task::spawn_blocking(move || service.show_touch_keyboard())
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Dismisses the Windows touch keyboard.
+///
+/// # Errors
+/// Returns a platform error if the touch keyboard cannot be reached.
+#[tauri::command]
+pub fn hide_touch_keyboard(container: State<DIContainer>) -> Result<(), BalamError> {
+    container.text_input.hide_touch_keyboard()
+}
+
+/// Types text into the foreground window (Balam's keyboard outside the WebView).
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` for text over 1024 characters, or a platform
+/// error if Windows blocked the input (elevated window in front).
+///
+/// # Examples
+/// ```javascript
+/// await invoke('send_virtual_text', { text: 'player_one' });
+/// ```
+#[tauri::command]
+pub fn send_virtual_text(container: State<DIContainer>, text: String) -> Result<(), BalamError> {
+    container.text_input.type_text(&text)
+}
+
+/// Presses an editing key (`backspace`, `enter`, `left`...) in the foreground window.
+///
+/// # Errors
+/// Returns a platform error if Windows blocked the input.
+#[tauri::command]
+pub fn send_virtual_key(container: State<DIContainer>, key: VirtualKey) -> Result<(), BalamError> {
+    container.text_input.press_key(key)
+}
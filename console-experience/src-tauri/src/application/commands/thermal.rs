@@ -0,0 +1,86 @@
+use crate::application::commands::settings::emit_settings_changed;
+use crate::application::DIContainer;
+use crate::domain::thermal::{FanCurve, FanStatus};
+use crate::domain::BalamError;
+use tauri::{AppHandle, State};
+use tracing::warn;
+
+/// Fan speed, CPU temperature and device model.
+///
+/// # Examples
+/// ```javascript
+/// const { rpm, cpu_temp_c, custom_curve_active } = await invoke('get_fan_status');
+/// ```
+#[tauri::command]
+#[must_use]
+pub fn get_fan_status(container: State<DIContainer>) -> FanStatus {
+    container.fan_control.status()
+}
+
+/// `true` on handhelds with a supported fan interface (ROG Ally, Legion Go, GPD Win).
+#[tauri::command]
+#[must_use]
+pub fn supports_fan_control(container: State<DIContainer>) -> bool {
+    container.fan_control.is_supported()
+}
+
+/// Saved custom curve, or the default curve as a starting point for the editor.
+#[tauri::command]
+#[must_use]
+pub fn get_fan_curve(container: State<DIContainer>) -> FanCurve {
+    container.settings_service.get().thermal.fan_curve.unwrap_or_default()
+}
+
+/// Applies a custom fan curve and saves it for the next start.
+///
+/// # Errors
+/// Returns `INVALID_ARGUMENT` for unsafe curves (e.g. under 60% fan at 80°C),
+/// `NOT_SUPPORTED` on other devices, `ACCESS_DENIED` while settings are
+/// locked by parental controls, or a platform error if the firmware rejects it.
+///
+/// # Examples
+/// ```javascript
+/// const curve = await invoke('get_fan_curve');
+/// curve.points[0].fan_percent = 10;
+/// await invoke('set_fan_curve', { curve });
+/// ```
+#[tauri::command]
+pub fn set_fan_curve(curve: FanCurve, app_handle: AppHandle, container: State<DIContainer>) -> Result<(), BalamError> {
+    // Checked before the fan changes: a locked curve stays as it is
+    container.parental.ensure_settings_unlocked()?;
+    container.fan_control.apply(curve.clone())?;
+    let saved = container
+        .settings_service
+        .update(|settings| settings.thermal.fan_curve = Some(curve))
+        .map_err(BalamError::Platform)?;
+    emit_settings_changed(&app_handle, &saved);
+    Ok(())
+}
+
+/// Drops the custom curve and returns the fan to the firmware.
+///
+/// # Errors
+/// Returns `ACCESS_DENIED` while settings are locked by parental controls, or
+/// error if the firmware call fails (the saved curve is cleared anyway).
+#[tauri::command]
+pub fn reset_fan_curve(app_handle: AppHandle, container: State<DIContainer>) -> Result<(), BalamError> {
+    container.parental.ensure_settings_unlocked()?;
+    match container
+        .settings_service
+        .update(|settings| settings.thermal.fan_curve = None)
+    {
+        Ok(saved) => emit_settings_changed(&app_handle, &saved),
+        Err(e) => warn!("Failed to clear saved fan curve: {}", e),
+    }
+    container.fan_control.restore()
+}
+
+/// Re-applies the saved fan curve (startup).
+pub(crate) fn restore_saved_fan_curve(container: &DIContainer) {
+    let Some(curve) = container.settings_service.get().thermal.fan_curve else {
+        return;
+    };
+    if let Err(e) = container.fan_control.apply(curve) {
+        warn!("Saved fan curve not applied: {}", e);
+    }
+}
@@ -0,0 +1,131 @@
+/// Update Commands - Self-update of Balam, the watchdog and fps-service
+///
+/// `check_for_updates` reads the release feed (`updates.feed_url`);
+/// `apply_update` downloads the delta or full package for this version,
+/// verifies its checksum and signature (progress on `update-progress`), then
+/// hands over to the update helper and exits. The helper waits for Balam and
+/// the watchdog to close, stops fps-service, verifies the package again,
+/// installs, restarts the service and relaunches Balam unelevated the way it
+/// was running (shell mode or not).
+///
+/// With `updates.check_on_startup`, a newer release found at startup is
+/// announced with `update-available` (payload: the `UpdateInfo`).
+use crate::adapters::fps_service::FpsServiceInstaller;
+use crate::adapters::update::{UpdateInstaller, UpdateMarkerStore};
+use crate::application::commands::{is_elevated, is_running_as_shell};
+use crate::application::DIContainer;
+use crate::domain::update::{UpdateInfo, UpdateMarker, UpdateProgress, UpdateStage};
+use crate::domain::BalamError;
+use crate::infrastructure::clock::now_secs;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State};
+use tracing::{info, warn};
+
+/// Checks the release feed for a version newer than the running one.
+///
+/// # Errors
+/// Returns a platform error if the feed is unreachable, `INVALID_ARGUMENT`
+/// if it is malformed.
+///
+/// # Examples
+/// ```javascript
+/// const { available, latest_version, notes } = await invoke('check_for_updates');
+/// ```
+#[tauri::command]
+pub async fn check_for_updates(app: AppHandle, container: State<'_, DIContainer>) -> Result<UpdateInfo, BalamError> {
+    let _permit = container.command_guard.enter("check_for_updates").await?;
+    tokio::task::spawn_blocking(move || check(&app))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Downloads, verifies and installs the release found by `check_for_updates`.
+///
+/// On success Balam exits and is relaunched by the update helper, so the
+/// promise only settles on failure.
+///
+/// # Errors
+/// Returns `NOT_FOUND` without a newer release, `ACCESS_DENIED` if an update
+/// is already running or the package fails verification, or a platform
+/// error if the download or the helper fails.
+///
+/// # Examples
+/// ```javascript
+/// await listen('update-progress', ({ payload }) => setProgress(payload));
+/// await invoke('apply_update');
+/// ```
+#[tauri::command]
+pub async fn apply_update(app: AppHandle) -> Result<(), BalamError> {
+    tokio::task::spawn_blocking(move || install(&app))
+        .await
+        .map_err(|e| BalamError::platform("Task join error", e))?
+}
+
+/// Startup check (`updates.check_on_startup`); never installs on its own.
+pub(crate) fn check_for_updates_in_background<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("update-check".to_string())
+        .spawn(move || match check(&app) {
+            Ok(update) if update.available => {
+                let _ = app.emit("update-available", &update);
+            },
+            Ok(_) => {},
+            Err(e) => warn!("Update check failed: {}", e),
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to start update check thread: {}", e);
+    }
+}
+
+fn check<R: Runtime>(app: &AppHandle<R>) -> Result<UpdateInfo, BalamError> {
+    let container = app.state::<DIContainer>();
+    let feed_url = container.settings_service.get().updates.feed_url;
+    container
+        .updates
+        .check(&feed_url, &app.package_info().version.to_string())
+}
+
+fn install<R: Runtime>(app: &AppHandle<R>) -> Result<(), BalamError> {
+    let container = app.state::<DIContainer>();
+    let prepared = container
+        .updates
+        .prepare(&app.package_info().version.to_string(), &|progress| {
+            let _ = app.emit("update-progress", progress);
+        })?;
+
+    let install_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(std::path::Path::to_path_buf))
+        .ok_or_else(|| BalamError::NotFound("Install directory".to_string()))?;
+    UpdateMarkerStore::write(&UpdateMarker {
+        version: prepared.version.clone(),
+        package_path: prepared.package_path.to_string_lossy().to_string(),
+        kind: prepared.kind,
+        sha256: prepared.sha256,
+        signature: prepared.signature,
+        // Stopping and restarting the service needs admin rights, and an
+        // elevated Balam is likely installed where only admins can write
+        elevate: FpsServiceInstaller::is_installed() || is_elevated(),
+        balam_pid: std::process::id(),
+        shell_mode: is_running_as_shell(),
+        fps_service_was_running: FpsServiceInstaller::is_running(),
+        created_at_secs: now_secs(),
+    })?;
+
+    if let Err(e) = UpdateInstaller::launch_helper(&install_dir) {
+        UpdateMarkerStore::clear();
+        return Err(e);
+    }
+
+    info!("🔄 Exiting to install {}", prepared.version);
+    let _ = app.emit(
+        "update-progress",
+        UpdateProgress {
+            stage: UpdateStage::Restarting,
+            downloaded_bytes: 0,
+            total_bytes: 0,
+        },
+    );
+    app.exit(0);
+    Ok(())
+}
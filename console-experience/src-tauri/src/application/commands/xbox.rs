@@ -0,0 +1,128 @@
+/// Xbox Commands - Game Pass catalog, installs and uninstalls
+///
+/// Installs go through the Store app: Balam opens the product page and
+/// watches `PackageManager` for the package to appear. Uninstalls remove the
+/// package directly. Both report `xbox-package-progress` (`XboxPackageProgress`).
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_opener::OpenerExt;
+use tracing::{info, warn};
+
+use crate::adapters::xbox_manager::XboxManager;
+use crate::domain::xbox::{is_valid_product_id, store_product_uri, XboxPackagePhase, XboxPackageProgress, XboxTitle};
+
+/// How long a Store install is watched (large games download for hours).
+const INSTALL_WATCH_TIMEOUT: Duration = Duration::from_secs(4 * 60 * 60);
+
+/// Lists PC Game Pass titles (installed or available) and installed Xbox games.
+///
+/// # Errors
+/// Returns error if installed packages cannot be queried.
+///
+/// # Examples
+/// ```javascript
+/// const titles = await invoke('get_xbox_titles');
+/// const available = titles.filter((t) => !t.installed);
+/// ```
+#[tauri::command]
+pub async fn get_xbox_titles() -> Result<Vec<XboxTitle>, String> {
+    tauri::async_runtime::spawn_blocking(|| XboxManager::new().list_titles())
+        .await
+        .map_err(|e| format!("Xbox title listing failed: {e}"))?
+}
+
+/// Opens a product's Store page to install it and watches for the package.
+///
+/// Emits `store_opened`, then `installed` once the package is registered or
+/// `timed_out` after four hours.
+///
+/// # Errors
+/// Returns error for an invalid product ID or if the Store cannot be opened.
+///
+/// # Examples
+/// ```javascript
+/// await listen('xbox-package-progress', (e) => updateTile(e.payload.id, e.payload.phase));
+/// await invoke('install_xbox_game', { productId: '9NBLGGH4R315' });
+/// ```
+#[tauri::command]
+pub fn install_xbox_game(app: AppHandle, product_id: String) -> Result<(), String> {
+    if !is_valid_product_id(&product_id) {
+        return Err(format!("Invalid Store product ID: {product_id}"));
+    }
+    app.opener()
+        .open_url(store_product_uri(&product_id), None::<&str>)
+        .map_err(|e| format!("Failed to open the Microsoft Store: {e}"))?;
+    emit_progress(&app, &product_id, XboxPackagePhase::StoreOpened, 0, None);
+    info!("📦 Store install started for {}", product_id);
+
+    std::thread::spawn(move || {
+        let Some(family) = XboxManager::new().resolve_family(&product_id) else {
+            warn!("Not watching install of {}: unknown package family", product_id);
+            return;
+        };
+        let phase = if XboxManager::wait_for_install(&family, INSTALL_WATCH_TIMEOUT) {
+            XboxPackagePhase::Installed
+        } else {
+            XboxPackagePhase::TimedOut
+        };
+        emit_progress(&app, &product_id, phase, 100, None);
+    });
+    Ok(())
+}
+
+/// Uninstalls an Xbox / Microsoft Store game by PackageFamilyName.
+///
+/// Emits `uninstalling` with the removal percentage, then `uninstalled` or
+/// `failed`.
+///
+/// # Errors
+/// Returns error if the family is not installed or removal fails.
+///
+/// # Examples
+/// ```javascript
+/// await invoke('uninstall_xbox_game', { pfn: 'Microsoft.624F8B84B80_8wekyb3d8bbwe' });
+/// ```
+#[tauri::command]
+pub async fn uninstall_xbox_game(app: AppHandle, pfn: String) -> Result<(), String> {
+    if !pfn.contains('_') || pfn.contains(['\\', '/']) {
+        return Err(format!("Invalid PackageFamilyName: {pfn}"));
+    }
+
+    let progress_app = app.clone();
+    let progress_id = pfn.clone();
+    let family = pfn.clone();
+    let result = tauri::async_runtime::spawn_blocking(move || {
+        XboxManager::uninstall(&family, move |percent| {
+            emit_progress(
+                &progress_app,
+                &progress_id,
+                XboxPackagePhase::Uninstalling,
+                percent,
+                None,
+            );
+        })
+    })
+    .await
+    .map_err(|e| format!("Uninstall task failed: {e}"))?;
+
+    match &result {
+        Ok(()) => {
+            info!("🗑️ {} uninstalled", pfn);
+            emit_progress(&app, &pfn, XboxPackagePhase::Uninstalled, 100, None);
+        },
+        Err(e) => emit_progress(&app, &pfn, XboxPackagePhase::Failed, 0, Some(e.clone())),
+    }
+    result
+}
+
+fn emit_progress(app: &AppHandle, id: &str, phase: XboxPackagePhase, percent: u32, error: Option<String>) {
+    let _ = app.emit(
+        "xbox-package-progress",
+        XboxPackageProgress {
+            id: id.to_string(),
+            phase,
+            percent,
+            error,
+        },
+    );
+}
@@ -1,11 +1,43 @@
 use crate::adapters::battlenet_scanner::BattleNetScanner;
+use crate::adapters::capture::WgcReplayRecorder;
+use crate::adapters::custom_scanner::CustomScanner;
+use crate::adapters::display::{DriverScalingAdapter, Win32WindowManager};
 use crate::adapters::epic_scanner::EpicScanner;
+use crate::adapters::haptic::GilrsHapticAdapter;
+use crate::adapters::hotkeys::HotkeyManager;
+use crate::adapters::howlongtobeat_adapter::HowLongToBeatAdapter;
+use crate::adapters::itch_scanner::ItchScanner;
+use crate::adapters::notifications::NotificationRouter;
+use crate::adapters::privilege::PrivilegeBrokerClient;
+use crate::adapters::process_launcher::watchdogs::WatchdogSupervisor;
 use crate::adapters::registry_scanner::RegistryScanner;
+use crate::adapters::settings::JsonSettingsRepository;
+use crate::adapters::standalone_scanner::StandaloneScanner;
 use crate::adapters::steam_scanner::SteamScanner;
+use crate::adapters::telemetry::HttpTelemetrySink;
+use crate::adapters::text_input::WindowsTextInput;
+use crate::adapters::thermal::HandheldThermalAdapter;
+use crate::adapters::update::HttpReleaseFeed;
+use crate::adapters::weather::OpenMeteoWeather;
 use crate::adapters::xbox_scanner::XboxScanner;
 use crate::application::active_games::ActiveGamesTracker;
+use crate::application::command_guard::CommandGuard;
+use crate::application::commands::is_running_as_shell;
+use crate::application::di::ports::{DisplayFactory, Ports, WiFiFactory};
+use crate::application::services::{
+    AlertService, ArtworkService, AutoTdpService, BenchmarkService, DashboardService, ExternalGameDetector,
+    FanControlService, FocusManager, GpuScalingService, HapticService, IdleService, LaunchHistoryService,
+    NetworkQualityService, OverlayRendererMonitor, ParentalService, ProcessInspectionService, QuietModeService,
+    ReplayService, SessionStatsService, SettingsService, StutterService, SystemStatusService, TelemetryService,
+    TextInputService, UpdateService,
+};
 use crate::domain::services::{GameDeduplicationService, GameDiscoveryService};
-use crate::ports::GameScanner;
+use crate::domain::BalamError;
+use crate::ports::display_port::DisplayPort;
+use crate::ports::system_port::SystemPort;
+use crate::ports::{
+    BluetoothPort, CompletionTimePort, GameManagementPort, GameScanner, NetworkPort, WiFiPort, WindowManagerPort,
+};
 use std::sync::Arc;
 
 /// Dependency Injection Container.
@@ -15,22 +47,77 @@ pub struct DIContainer {
     pub game_discovery_service: Arc<GameDiscoveryService>,
     pub game_deduplication_service: Arc<GameDeduplicationService>,
     pub active_games_tracker: Arc<ActiveGamesTracker>,
+    pub settings_service: Arc<SettingsService>,
+    pub hotkey_manager: Arc<HotkeyManager>,
+    pub notifications: Arc<NotificationRouter>,
+    pub system_status: Arc<SystemStatusService>,
+    pub fan_control: Arc<FanControlService>,
+    pub gpu_scaling: Arc<GpuScalingService>,
+    pub idle: Arc<IdleService>,
+    pub network_quality: Arc<NetworkQualityService>,
+    pub replay: Arc<ReplayService>,
+    pub focus: Arc<FocusManager>,
+    pub external_games: Arc<ExternalGameDetector>,
+    pub text_input: Arc<TextInputService>,
+    pub haptics: Arc<HapticService>,
+    pub updates: Arc<UpdateService>,
+    pub dashboard: Arc<DashboardService>,
+    pub privileges: Arc<PrivilegeBrokerClient>,
+    pub parental: Arc<ParentalService>,
+    pub telemetry: Arc<TelemetryService>,
+    pub benchmarks: Arc<BenchmarkService>,
+    pub artwork: Arc<ArtworkService>,
+    pub processes: Arc<ProcessInspectionService>,
+    pub watchdogs: Arc<WatchdogSupervisor>,
+    pub stutter: Arc<StutterService>,
+    pub session_stats: Arc<SessionStatsService>,
+    pub quiet_mode: Arc<QuietModeService>,
+    pub command_guard: Arc<CommandGuard>,
+    pub launch_history: Arc<LaunchHistoryService>,
+    pub alerts: Arc<AlertService>,
+    pub auto_tdp: Arc<AutoTdpService>,
+    pub overlay_renderer: Arc<OverlayRendererMonitor>,
+    pub completion_times: Arc<dyn CompletionTimePort>,
+    pub window_manager: Arc<dyn WindowManagerPort>,
+    pub system: Arc<dyn SystemPort>,
+    pub game_adapter: Arc<dyn GameManagementPort>,
+    pub network: Arc<dyn NetworkPort>,
+    pub bluetooth: Arc<dyn BluetoothPort>,
+    display_factory: DisplayFactory,
+    wifi_factory: WiFiFactory,
 }
 
 impl DIContainer {
     /// Creates a new DI container with all services initialized.
     pub fn new() -> Self {
+        Self::with_ports(Ports::windows())
+    }
+
+    /// Creates the container on top of the given port implementations
+    /// (`Ports::mock()` to run commands without hardware).
+    #[must_use]
+    pub fn with_ports(ports: Ports) -> Self {
         use tracing::info;
 
         info!("Initializing DI Container...");
 
+        let settings_repository = Arc::new(JsonSettingsRepository::default());
+        let notifications = Arc::new(NotificationRouter::new());
+        let settings_service = Arc::new(SettingsService::new(settings_repository));
+        // The parental settings lock covers every command that saves settings
+        let parental = Arc::new(ParentalService::new(&ParentalService::default_dir()));
+        settings_service.set_guard(parental.settings_guard());
+
         // Register all game scanners
         let scanners: Vec<Arc<dyn GameScanner>> = vec![
             Arc::new(SteamScanner::new()),
             Arc::new(EpicScanner::new()),
             Arc::new(XboxScanner::new()),
             Arc::new(BattleNetScanner::new()),
-            Arc::new(RegistryScanner::new()),
+            Arc::new(ItchScanner::new()),
+            Arc::new(StandaloneScanner::new()),
+            Arc::new(CustomScanner::new()),
+            Arc::new(RegistryScanner::with_settings(settings_service.clone())),
         ];
 
         info!("Registered {} scanners", scanners.len());
@@ -39,8 +126,67 @@ impl DIContainer {
             game_discovery_service: Arc::new(GameDiscoveryService::new(scanners)),
             game_deduplication_service: Arc::new(GameDeduplicationService::new()),
             active_games_tracker: Arc::new(ActiveGamesTracker::new()),
+            hotkey_manager: Arc::new(HotkeyManager::new()),
+            system_status: Arc::new(SystemStatusService::new(notifications.clone())),
+            fan_control: Arc::new(FanControlService::new(Arc::new(HandheldThermalAdapter::new()))),
+            gpu_scaling: Arc::new(GpuScalingService::new(Arc::new(DriverScalingAdapter::new()))),
+            idle: Arc::new(IdleService::new()),
+            network_quality: Arc::new(NetworkQualityService::new()),
+            replay: Arc::new(ReplayService::new(Arc::new(WgcReplayRecorder::new()))),
+            focus: Arc::new(FocusManager::new()),
+            external_games: Arc::new(ExternalGameDetector::new()),
+            text_input: Arc::new(TextInputService::new(
+                Arc::new(WindowsTextInput::new()),
+                is_running_as_shell(),
+            )),
+            haptics: Arc::new(HapticService::new(Arc::new(GilrsHapticAdapter::new()))),
+            updates: Arc::new(UpdateService::new(Arc::new(HttpReleaseFeed))),
+            dashboard: Arc::new(DashboardService::new(Arc::new(OpenMeteoWeather))),
+            privileges: Arc::new(PrivilegeBrokerClient::new()),
+            parental,
+            telemetry: Arc::new(TelemetryService::new(
+                Arc::new(HttpTelemetrySink),
+                settings_service.clone(),
+                &TelemetryService::default_dir(),
+            )),
+            benchmarks: Arc::new(BenchmarkService::new(&BenchmarkService::default_dir())),
+            artwork: Arc::new(ArtworkService::new(&ArtworkService::default_dir())),
+            processes: ProcessInspectionService::shared(),
+            watchdogs: WatchdogSupervisor::shared(),
+            stutter: Arc::new(StutterService::new()),
+            session_stats: Arc::new(SessionStatsService::new()),
+            quiet_mode: Arc::new(QuietModeService::new()),
+            command_guard: Arc::new(CommandGuard::new()),
+            launch_history: Arc::new(LaunchHistoryService::new(&LaunchHistoryService::default_dir())),
+            alerts: Arc::new(AlertService::new()),
+            auto_tdp: Arc::new(AutoTdpService::new()),
+            overlay_renderer: Arc::new(OverlayRendererMonitor::new()),
+            settings_service,
+            completion_times: Arc::new(HowLongToBeatAdapter::new()),
+            window_manager: Arc::new(Win32WindowManager::new()),
+            notifications,
+            system: ports.system,
+            game_adapter: ports.game,
+            network: ports.network,
+            bluetooth: ports.bluetooth,
+            display_factory: ports.display,
+            wifi_factory: ports.wifi,
         }
     }
+
+    /// Display adapter for one operation (sees the current display topology).
+    #[must_use]
+    pub fn display(&self) -> Arc<dyn DisplayPort> {
+        (self.display_factory)()
+    }
+
+    /// Wi-Fi adapter for one operation.
+    ///
+    /// # Errors
+    /// Returns error if the WLAN service cannot be opened.
+    pub fn wifi(&self) -> Result<Arc<dyn WiFiPort>, BalamError> {
+        (self.wifi_factory)()
+    }
 }
 
 impl Default for DIContainer {
@@ -56,12 +202,26 @@ mod tests {
     #[test]
     fn test_container_creation() {
         let container = DIContainer::new();
-        assert_eq!(container.game_discovery_service.scanner_count(), 5);
+        assert_eq!(container.game_discovery_service.scanner_count(), 8);
+    }
+
+    #[test]
+    fn test_container_provides_valid_settings() {
+        let container = DIContainer::new();
+        assert!(container.settings_service.get().validate().is_ok());
+    }
+
+    #[test]
+    fn test_container_with_mock_ports() {
+        let container = DIContainer::with_ports(Ports::mock());
+        container.system.set_volume(40).unwrap();
+        assert_eq!(container.system.get_status().volume, 40);
+        assert!(container.display().get_primary_display().is_some());
     }
 
     #[test]
     fn test_container_default() {
         let container = DIContainer::default();
-        assert_eq!(container.game_discovery_service.scanner_count(), 5);
+        assert_eq!(container.game_discovery_service.scanner_count(), 8);
     }
 }
@@ -1,3 +1,5 @@
 pub mod container;
+pub mod ports;
 
 pub use container::DIContainer;
+pub use ports::Ports;
@@ -0,0 +1,70 @@
+use crate::adapters::bluetooth::WindowsBluetoothAdapter;
+use crate::adapters::display::WindowsDisplayAdapter;
+use crate::adapters::game::WindowsGameAdapter;
+use crate::adapters::network::WindowsNetworkAdapter;
+use crate::adapters::wifi::WindowsWiFiAdapter;
+use crate::adapters::windows_system_adapter::WindowsSystemAdapter;
+use crate::domain::BalamError;
+use crate::ports::display_port::DisplayPort;
+use crate::ports::system_port::SystemPort;
+use crate::ports::{BluetoothPort, GameManagementPort, NetworkPort, WiFiPort};
+use std::sync::Arc;
+
+/// Creates a display adapter for one operation.
+pub type DisplayFactory = Arc<dyn Fn() -> Arc<dyn DisplayPort> + Send + Sync>;
+
+/// Opens a Wi-Fi adapter for one operation.
+pub type WiFiFactory = Arc<dyn Fn() -> Result<Arc<dyn WiFiPort>, BalamError> + Send + Sync>;
+
+/// Hardware port implementations handed to the container.
+///
+/// Stateless adapters are shared for the app lifetime. The display adapter
+/// caches the display topology and the Wi-Fi adapter owns a WLAN session, so
+/// both are created per operation instead (a monitor plugged in later, or a
+/// WLAN service restart, is picked up by the next call).
+#[derive(Clone)]
+pub struct Ports {
+    pub system: Arc<dyn SystemPort>,
+    pub game: Arc<dyn GameManagementPort>,
+    pub network: Arc<dyn NetworkPort>,
+    pub bluetooth: Arc<dyn BluetoothPort>,
+    pub display: DisplayFactory,
+    pub wifi: WiFiFactory,
+}
+
+impl Ports {
+    /// Native Windows adapters.
+    #[must_use]
+    pub fn windows() -> Self {
+        Self {
+            system: Arc::new(WindowsSystemAdapter::new()),
+            game: Arc::new(WindowsGameAdapter::new()),
+            network: Arc::new(WindowsNetworkAdapter::new()),
+            bluetooth: Arc::new(WindowsBluetoothAdapter::new()),
+            display: Arc::new(|| Arc::new(WindowsDisplayAdapter::new())),
+            wifi: Arc::new(|| Ok(Arc::new(WindowsWiFiAdapter::new()?))),
+        }
+    }
+
+    /// In-memory adapters that never touch the hardware (see [`crate::adapters::mock`]).
+    #[cfg(any(test, feature = "mock-adapters"))]
+    #[must_use]
+    pub fn mock() -> Self {
+        use crate::adapters::mock::{
+            MockBluetoothAdapter, MockDisplayAdapter, MockGameAdapter, MockNetworkAdapter, MockSystemAdapter,
+            MockWiFiAdapter,
+        };
+
+        // One instance behind each factory, so state survives between calls
+        let display: Arc<dyn DisplayPort> = Arc::new(MockDisplayAdapter::default());
+        let wifi: Arc<dyn WiFiPort> = Arc::new(MockWiFiAdapter::default());
+        Self {
+            system: Arc::new(MockSystemAdapter::default()),
+            game: Arc::new(MockGameAdapter::default()),
+            network: Arc::new(MockNetworkAdapter),
+            bluetooth: Arc::new(MockBluetoothAdapter::default()),
+            display: Arc::new(move || Arc::clone(&display)),
+            wifi: Arc::new(move || Ok(Arc::clone(&wifi))),
+        }
+    }
+}
@@ -0,0 +1,252 @@
+/**
+ * External API
+ *
+ * Lets Stream Deck plugins, `AutoHotkey` scripts and companion apps control
+ * Balam over `\\.\pipe\balam` (see `domain::external_api` for the protocol).
+ *
+ * Off by default. Every request carries the token stored in the app data
+ * folder; the pipe only accepts local clients, and its default DACL lets
+ * other users read but not write.
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
+use tracing::{error, info, warn};
+
+use crate::adapters::process_launcher::window_manager::restore_window;
+use crate::application::commands::{
+    close_current_game, get_active_game, get_games, get_performance_metrics, kill_game, launch_game_blocking,
+};
+use crate::application::DIContainer;
+use crate::domain::external_api::{
+    ExternalApiCommand, ExternalApiInfo, ExternalApiRequest, ExternalApiResponse, EXTERNAL_API_PIPE_NAME,
+    EXTERNAL_API_TOKEN_FILE,
+};
+use crate::domain::privilege::tokens_match;
+use crate::infrastructure::app_dirs;
+
+/// The pipe is created once, the first time the API is enabled.
+static SERVING: AtomicBool = AtomicBool::new(false);
+
+fn token_path(file_name: &str) -> Result<PathBuf, String> {
+    app_dirs::try_app_data_dir()
+        .map(|dir| dir.join(file_name))
+        .ok_or_else(|| "Failed to resolve app data folder".to_string())
+}
+
+fn write_new_token(file_name: &str) -> Result<String, String> {
+    let path = token_path(file_name)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create app data folder: {e}"))?;
+    }
+    let token = format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple());
+    fs::write(&path, &token).map_err(|e| format!("Failed to save API token: {e}"))?;
+    Ok(token)
+}
+
+/// Moves a token file left in the roaming folder by older versions, so
+/// clients configured with it keep working after an upgrade.
+fn migrate_legacy_token(file_name: &str, path: &Path) {
+    let Some(legacy) = app_dirs::legacy_roaming_dir().map(|dir| dir.join(file_name)) else {
+        return;
+    };
+    if path.exists() || !legacy.is_file() {
+        return;
+    }
+    let moved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::copy(&legacy, path));
+    match moved {
+        Ok(_) => {
+            let _ = fs::remove_file(&legacy);
+            info!("🔑 Token {} moved to the local app data folder", file_name);
+        },
+        Err(e) => warn!("Failed to move token {}: {}", file_name, e),
+    }
+}
+
+/// Token stored in `file_name` (app data folder), created on first use.
+///
+/// Shared with the companion remote, which keeps its own token file.
+///
+/// # Errors
+/// Returns error if the token file cannot be read or created.
+pub(crate) fn load_or_create_token(file_name: &str) -> Result<String, String> {
+    let path = token_path(file_name)?;
+    migrate_legacy_token(file_name, &path);
+    match fs::read_to_string(path) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => write_new_token(file_name),
+    }
+}
+
+/// Replaces the token in `file_name`; clients must read it again.
+///
+/// # Errors
+/// Returns error if the token file cannot be written.
+pub(crate) fn regenerate_token(file_name: &str) -> Result<String, String> {
+    info!("🔑 Token in {} regenerated", file_name);
+    write_new_token(file_name)
+}
+
+/// Connection details for the settings screen.
+///
+/// # Errors
+/// Returns error if the token file cannot be read or created.
+pub fn info(enabled: bool) -> Result<ExternalApiInfo, String> {
+    Ok(ExternalApiInfo {
+        enabled,
+        pipe_name: EXTERNAL_API_PIPE_NAME.to_string(),
+        token: load_or_create_token(EXTERNAL_API_TOKEN_FILE)?,
+        token_path: token_path(EXTERNAL_API_TOKEN_FILE)?.to_string_lossy().to_string(),
+    })
+}
+
+/// Starts serving the pipe (no-op if already serving).
+///
+/// Disabling the API later keeps the pipe but rejects every request.
+pub fn start(app: &AppHandle) {
+    if SERVING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        serve(app).await;
+        SERVING.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Accepts clients until the pipe cannot be recreated; the next instance
+/// exists before a client is served.
+async fn serve(app: AppHandle) {
+    let mut server = match ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(EXTERNAL_API_PIPE_NAME)
+    {
+        Ok(server) => server,
+        Err(e) => {
+            error!("❌ Failed to create external API pipe: {}", e);
+            return;
+        },
+    };
+    info!("🔌 External API listening on {}", EXTERNAL_API_PIPE_NAME);
+
+    loop {
+        if let Err(e) = server.connect().await {
+            warn!("External API connect failed: {}", e);
+        } else {
+            let connected = server;
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move { handle_client(connected, app).await });
+        }
+
+        server = match ServerOptions::new().create(EXTERNAL_API_PIPE_NAME) {
+            Ok(server) => server,
+            Err(e) => {
+                error!("❌ Failed to recreate external API pipe: {}", e);
+                return;
+            },
+        };
+    }
+}
+
+/// Answers request lines until the client disconnects.
+async fn handle_client(server: NamedPipeServer, app: AppHandle) {
+    let (reader, mut writer) = tokio::io::split(server);
+    let mut lines = BufReader::new(reader).lines();
+
+    loop {
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to read external API request: {}", e);
+                return;
+            },
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = respond(&app, &line).await;
+        let mut answer = serde_json::to_vec(&response).unwrap_or_default();
+        answer.push(b'\n');
+        if let Err(e) = writer.write_all(&answer).await {
+            warn!("Failed to answer external API client: {}", e);
+            return;
+        }
+        let _ = writer.flush().await;
+    }
+}
+
+async fn respond(app: &AppHandle, line: &str) -> ExternalApiResponse {
+    let request = match serde_json::from_str::<ExternalApiRequest>(line) {
+        Ok(request) => request,
+        Err(e) => {
+            return ExternalApiResponse::Error {
+                id: None,
+                message: format!("Invalid request: {e}"),
+            }
+        },
+    };
+
+    let enabled = app.state::<DIContainer>().settings_service.get().external_api.enabled;
+    let authorized = enabled
+        && load_or_create_token(EXTERNAL_API_TOKEN_FILE).is_ok_and(|token| tokens_match(&token, &request.token));
+    if !authorized {
+        warn!("🚫 Rejected external API request ({:?})", request.command);
+        return ExternalApiResponse::Unauthorized { id: request.id };
+    }
+
+    info!("🔌 External API: {:?}", request.command);
+    let app = app.clone();
+    let command = request.command;
+    let result = tokio::task::spawn_blocking(move || execute(&app, command))
+        .await
+        .unwrap_or_else(|e| Err(format!("Task join error: {e}")));
+    match result {
+        Ok(data) => ExternalApiResponse::Ok { id: request.id, data },
+        Err(message) => ExternalApiResponse::Error {
+            id: request.id,
+            message,
+        },
+    }
+}
+
+/// Runs a command through the same code paths as the frontend.
+///
+/// Blocking (library scan, game launch); call from a worker thread.
+pub(crate) fn execute(app: &AppHandle, command: ExternalApiCommand) -> Result<serde_json::Value, String> {
+    let to_json = |value: serde_json::Result<serde_json::Value>| value.map_err(|e| e.to_string());
+    match command {
+        ExternalApiCommand::ListGames => to_json(serde_json::to_value(get_games(None, app.clone(), app.state()))),
+        ExternalApiCommand::Launch { game_id } => {
+            let game = launch_game_blocking(game_id, app)?;
+            // The frontend did not start this session itself
+            let _ = app.emit("external-game-launched", &game);
+            to_json(serde_json::to_value(game))
+        },
+        ExternalApiCommand::GetActiveGame => to_json(serde_json::to_value(get_active_game(app.state()))),
+        ExternalApiCommand::CloseGame => {
+            let game = get_active_game(app.state()).ok_or("No game is running")?;
+            if game.pid == 0 {
+                kill_game(0, app.state())?;
+            } else {
+                close_current_game(game.pid, app.state())?;
+            }
+            Ok(serde_json::Value::Null)
+        },
+        ExternalApiCommand::GetMetrics => {
+            let metrics = get_performance_metrics(app.state()).map_err(String::from)?;
+            to_json(serde_json::to_value(metrics))
+        },
+        ExternalApiCommand::Show => {
+            restore_window(app);
+            Ok(serde_json::Value::Null)
+        },
+    }
+}
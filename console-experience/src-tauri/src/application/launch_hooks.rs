@@ -0,0 +1,178 @@
+/**
+ * Launch Hooks
+ *
+ * Runs the pre-launch and post-exit hooks of `settings.hooks` around a game
+ * session (global hooks plus the game's own, see `domain::launch_hooks`).
+ *
+ * Launches are queued: one launch pipeline (hooks included) runs at a time,
+ * so two quick launches never interleave their VPN or remapper scripts.
+ * Companion apps started before the game are kept here and closed when the
+ * game exits if their hook asks for it. Every failure is reported with a
+ * notification and a `launch-hook-failed` event.
+ */
+use parking_lot::{Mutex, MutexGuard};
+use std::process::Child;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{info, warn};
+
+use crate::adapters::launch_hooks::HookRunner;
+use crate::application::DIContainer;
+use crate::domain::launch_hooks::{HookReport, HookStage, LaunchHook};
+use crate::domain::notification::{Notification, NotificationCategory, NotificationPriority};
+use crate::domain::Game;
+use crate::ports::NotificationPort;
+
+/// Held for the whole launch pipeline.
+static LAUNCH_QUEUE: Mutex<()> = Mutex::new(());
+/// Companion apps still running, by game id.
+static COMPANIONS: Mutex<Vec<(String, Child)>> = Mutex::new(Vec::new());
+/// Games launched through the pipeline, for their post-exit hooks.
+static SESSIONS: Mutex<Vec<Game>> = Mutex::new(Vec::new());
+
+/// Waits for earlier launches to finish; the launch runs while the guard lives.
+pub fn queue_launch() -> MutexGuard<'static, ()> {
+    LAUNCH_QUEUE.lock()
+}
+
+fn report(app: &AppHandle, container: &DIContainer, report: &HookReport, required: bool) {
+    if report.success {
+        info!(
+            "🪝 {} hook '{}' done for {} ({}ms)",
+            report.stage.as_str(),
+            report.hook,
+            report.game_id,
+            report.duration_ms
+        );
+        return;
+    }
+    warn!(
+        "🪝 {} hook '{}' failed for {}: {}",
+        report.stage.as_str(),
+        report.hook,
+        report.game_id,
+        report.message
+    );
+    let _ = app.emit("launch-hook-failed", report);
+    let priority = if required {
+        NotificationPriority::High
+    } else {
+        NotificationPriority::Normal
+    };
+    container.notifications.notify(Notification::new(
+        NotificationCategory::GameLaunch,
+        priority,
+        format!("Hook '{}' failed", report.hook),
+        report.message.clone(),
+    ));
+}
+
+fn run_hook(app: &AppHandle, container: &DIContainer, game: &Game, stage: HookStage, hook: &LaunchHook) -> bool {
+    let source = format!("{:?}", game.source);
+    let env = [
+        ("BALAM_GAME_ID", game.id.as_str()),
+        ("BALAM_GAME_TITLE", game.title.as_str()),
+        ("BALAM_GAME_SOURCE", source.as_str()),
+        ("BALAM_HOOK_STAGE", stage.as_str()),
+    ];
+    let started = Instant::now();
+    let result = HookRunner::run(hook, &env);
+    let success = result.is_ok();
+    let message = match result {
+        Ok(Some(child)) => {
+            // Companions without stop_on_exit simply keep running
+            if hook.stop_on_exit {
+                COMPANIONS.lock().push((game.id.clone(), child));
+            }
+            "Started".to_string()
+        },
+        Ok(None) => "Finished".to_string(),
+        Err(e) => e,
+    };
+    let hook_report = HookReport {
+        game_id: game.id.clone(),
+        hook: hook.label().to_string(),
+        stage,
+        success,
+        message,
+        duration_ms: started.elapsed().as_millis() as u64,
+    };
+    report(
+        app,
+        container,
+        &hook_report,
+        hook.required && stage == HookStage::PreLaunch,
+    );
+    success
+}
+
+/// Runs the pre-launch hooks of `game`.
+///
+/// # Errors
+/// Returns error if a `required` hook failed; the launch must not go on.
+pub fn run_pre_launch(app: &AppHandle, container: &DIContainer, game: &Game) -> Result<(), String> {
+    let hooks = container
+        .settings_service
+        .get()
+        .hooks
+        .for_game(&game.id, HookStage::PreLaunch);
+    for hook in &hooks {
+        if !run_hook(app, container, game, HookStage::PreLaunch, hook) && hook.required {
+            stop_companions(&game.id);
+            return Err(format!("Pre-launch hook '{}' failed", hook.label()));
+        }
+    }
+    let mut sessions = SESSIONS.lock();
+    sessions.retain(|session| session.id != game.id);
+    sessions.push(game.clone());
+    Ok(())
+}
+
+/// Closes the companion apps started for `game_id` (game exited or launch failed).
+pub fn stop_companions(game_id: &str) {
+    let stopped: Vec<Child> = {
+        let mut companions = COMPANIONS.lock();
+        let (stopped, kept) = companions.drain(..).partition(|(id, _)| id == game_id);
+        *companions = kept;
+        stopped.into_iter().map(|(_, child)| child).collect()
+    };
+    for mut child in stopped {
+        if let Err(e) = child.kill() {
+            warn!("Failed to close companion app {}: {}", child.id(), e);
+        }
+        let _ = child.wait();
+    }
+}
+
+/// Closes companions and runs the post-exit hooks on a worker thread.
+///
+/// Only for games launched through [`run_pre_launch`] (the watchdogs that
+/// report `game-ended` belong to those launches).
+pub fn run_post_exit_in_background(app: &AppHandle, game_id: &str) {
+    let Some(game) = ({
+        let mut sessions = SESSIONS.lock();
+        let index = sessions.iter().position(|session| session.id == game_id);
+        index.map(|index| sessions.remove(index))
+    }) else {
+        stop_companions(game_id);
+        return;
+    };
+    let app = app.clone();
+    let spawned = std::thread::Builder::new()
+        .name("post-exit-hooks".to_string())
+        .spawn(move || {
+            stop_companions(&game.id);
+            let container = app.state::<DIContainer>();
+            let hooks = container
+                .settings_service
+                .get()
+                .hooks
+                .for_game(&game.id, HookStage::PostExit);
+            for hook in &hooks {
+                run_hook(&app, &container, &game, HookStage::PostExit, hook);
+            }
+        });
+    if let Err(e) = spawned {
+        warn!("Failed to run post-exit hooks: {}", e);
+    }
+}
@@ -1,7 +1,16 @@
 // Application Layer: Use Cases
 pub mod active_games;
+pub mod command_guard;
 pub mod commands;
 pub mod di;
+pub mod external_api;
+pub mod launch_hooks;
+pub mod power_events;
+pub mod privilege;
+pub mod remote_control;
+pub mod safe_mode;
+pub mod services;
 
 pub use active_games::{ActiveGame, ActiveGameInfo, ActiveGamesTracker};
-pub use di::DIContainer;
+pub use di::{DIContainer, Ports};
+pub use services::SettingsService;
@@ -0,0 +1,126 @@
+/**
+ * Power Events
+ *
+ * Repairs what a suspend/resume cycle breaks: the watchdog heartbeat pipe,
+ * the FPS service's ETW session, gamepad handles, firmware fan curves and
+ * game sessions whose process changed or vanished while the PC was asleep.
+ *
+ * The frontend receives `system-resumed` once the repairs are under way.
+ */
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter};
+use tracing::{info, warn};
+
+use crate::adapters::fps_service::FpsServiceInstaller;
+use crate::adapters::process_launcher::window_manager::{restore_window, restore_window_after_session};
+use crate::application::services::ProcessInspectionService;
+use crate::application::{ActiveGamesTracker, DIContainer};
+use crate::domain::privilege::PrivilegedOperation;
+use crate::infrastructure::clock::now_secs;
+use crate::ports::system_events_port::{SleepTransition, SystemEventsPort};
+
+/// Watchdogs get this long after resume to report games that exited during sleep themselves.
+const SESSION_RECHECK_DELAY: Duration = Duration::from_secs(15);
+
+/// Subscribes to suspend/resume notifications for the app lifetime.
+pub fn start(app: &AppHandle, container: DIContainer, events: &dyn SystemEventsPort) {
+    let app = app.clone();
+    events.subscribe_sleep(Arc::new(move |transition| match transition {
+        SleepTransition::Suspending => info!("💤 System suspending"),
+        SleepTransition::Resumed => {
+            // Off the notification thread: the session re-check waits for the watchdogs
+            let app = app.clone();
+            let container = container.clone();
+            thread::spawn(move || handle_resume(&app, &container));
+        },
+    }));
+}
+
+fn handle_resume(app: &AppHandle, container: &DIContainer) {
+    info!("☀️ System resumed, restoring background services");
+
+    container.idle.record_activity();
+    crate::heartbeat::reconnect_after_resume();
+    crate::adapters::gamepad_adapter::reset_after_resume();
+
+    // ASUS and Lenovo firmware fall back to their stock curve on wake
+    if container.fan_control.is_active() {
+        crate::application::commands::restore_saved_fan_curve(container);
+    }
+
+    if FpsServiceInstaller::is_running() && !crate::application::safe_mode::is_active() {
+        // Unelevated, only a broker already running can do it (no UAC prompt on wake)
+        let restarted = FpsServiceInstaller::restart_etw().or_else(|_| {
+            container
+                .privileges
+                .execute_if_running(&PrivilegedOperation::RestartFpsServiceEtw)
+                .map_err(String::from)
+        });
+        if let Err(e) = restarted {
+            warn!("FPS monitoring may stay stale after resume: {}", e);
+        }
+    }
+
+    let _ = app.emit("system-resumed", ());
+
+    let settings = container.settings_service.get();
+    if settings.idle.show_on_resume && container.active_games_tracker.focused().is_none() {
+        restore_window(app);
+    }
+
+    thread::sleep(SESSION_RECHECK_DELAY);
+    revalidate_sessions(app, &container.active_games_tracker, &container.processes);
+}
+
+/// Follows games that respawned under a new PID and closes sessions whose
+/// process is gone but whose watchdog never reported it.
+///
+/// Sessions without a PID (Steam) are left to the registry watchdog.
+fn revalidate_sessions(app: &AppHandle, tracker: &ActiveGamesTracker, processes: &ProcessInspectionService) {
+    #[derive(serde::Serialize, Clone)]
+    struct GameEndedPayload {
+        game_id: String,
+        play_time_seconds: u64,
+    }
+
+    let snapshot = processes.processes();
+    let mut closed_any = false;
+
+    for (game_id, info) in tracker.list_sessions() {
+        let Some(pid) = info.pid else {
+            continue;
+        };
+        if snapshot.iter().any(|process| process.pid == pid) {
+            continue;
+        }
+
+        let respawned = snapshot.iter().find_map(|process| {
+            process
+                .exe
+                .as_ref()
+                .is_some_and(|exe| exe.to_string_lossy().eq_ignore_ascii_case(&info.path))
+                .then_some(process.pid)
+        });
+        if let Some(new_pid) = respawned {
+            info!("Session {} resumed under PID {} (was {})", game_id, new_pid, pid);
+            tracker.set_pid(&game_id, Some(new_pid));
+            continue;
+        }
+
+        warn!("Session {} lost its process during sleep, closing it", game_id);
+        tracker.unregister(&game_id);
+        closed_any = true;
+        // The watchdog is gone: the tracker's start time gives the play time
+        let payload = GameEndedPayload {
+            game_id,
+            play_time_seconds: info.elapsed_secs(now_secs()),
+        };
+        let _ = app.emit("game-ended", &payload);
+    }
+
+    if closed_any {
+        restore_window_after_session(app, tracker);
+    }
+}
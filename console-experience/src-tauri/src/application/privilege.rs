@@ -0,0 +1,58 @@
+/**
+ * Privileged Operations
+ *
+ * What the elevated broker (`balam-broker.exe`) runs for the app: the
+ * allow-list in `domain::privilege`, nothing else. Binaries are resolved
+ * from the broker's own install directory, never from the request.
+ */
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use tracing::info;
+
+use crate::adapters::autostart::TaskSchedulerAutostart;
+use crate::adapters::fps_service::FpsServiceInstaller;
+use crate::adapters::sunshine::SunshineService;
+use crate::application::commands::{set_fps_service_boot_start, TDP_CONTROLLER};
+use crate::domain::privilege::PrivilegedOperation;
+use crate::ports::performance_port::PerformancePort;
+
+/// Operations never overlap (two SCM changes racing leave the service half-configured).
+static EXECUTION: Mutex<()> = Mutex::new(());
+
+fn install_dir() -> Result<PathBuf, String> {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+        .ok_or_else(|| "Cannot resolve install directory".to_string())
+}
+
+/// Runs one allow-listed operation in this (elevated) process.
+///
+/// # Errors
+/// Returns error if the arguments are out of range or the operation fails.
+pub fn execute_privileged(operation: &PrivilegedOperation) -> Result<(), String> {
+    operation.validate()?;
+    let _serial = EXECUTION.lock();
+    info!("🛡️ Privileged operation: {:?}", operation);
+
+    match operation {
+        PrivilegedOperation::SetTdp { watts } => {
+            PerformancePort::set_tdp(&*TDP_CONTROLLER, *watts).map_err(String::from)
+        },
+        PrivilegedOperation::InstallFpsService => {
+            FpsServiceInstaller::install(install_dir()?.join("balam-fps-service.exe"))?;
+            FpsServiceInstaller::start()
+        },
+        PrivilegedOperation::UninstallFpsService => FpsServiceInstaller::uninstall(),
+        PrivilegedOperation::StartFpsService => FpsServiceInstaller::start(),
+        PrivilegedOperation::StopFpsService => FpsServiceInstaller::stop(),
+        PrivilegedOperation::RestartFpsServiceEtw => FpsServiceInstaller::restart_etw(),
+        PrivilegedOperation::SetFpsServiceBootStart { enabled } => set_fps_service_boot_start(*enabled),
+        PrivilegedOperation::RegisterAutostart { options } => {
+            TaskSchedulerAutostart::new().register(&install_dir()?.join("console-experience.exe"), options)
+        },
+        PrivilegedOperation::UnregisterAutostart => TaskSchedulerAutostart::new().unregister(),
+        PrivilegedOperation::StartSunshine => SunshineService::start(),
+        PrivilegedOperation::StopSunshine => SunshineService::stop(),
+    }
+}
@@ -0,0 +1,388 @@
+/**
+ * Companion Remote
+ *
+ * Optional LAN WebSocket server for a phone: browse the library, launch and
+ * close games, change volume and TDP, and use the screen as a touchpad and
+ * keyboard. Every command runs the same code as the frontend command of the
+ * same name (see `domain::remote` for the protocol).
+ *
+ * Pairing: the settings screen shows a QR code holding the LAN address and a
+ * pairing key. The key never crosses the network: the PC sends a challenge,
+ * the phone answers with a proof derived from the key, and both derive a
+ * session key that signs every later message (messages are signed, not
+ * encrypted). A phone seen for the first time must also be approved on the
+ * PC. Resetting the pairing replaces the key, forgets approved phones and
+ * closes every open session at once.
+ */
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use parking_lot::{Mutex, RwLock};
+use std::collections::HashMap;
+use std::fs;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::LazyLock;
+use std::time::Duration;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{oneshot, watch};
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::WebSocketStream;
+use tracing::{error, info, warn};
+
+use crate::adapters::remote::{lan_address, qr_svg, RemotePointer};
+use crate::application::commands::{
+    get_active_game, get_system_status, kill_game, send_virtual_key, send_virtual_text, set_tdp, set_volume,
+};
+use crate::application::external_api::{self, load_or_create_token, regenerate_token};
+use crate::domain::external_api::ExternalApiCommand;
+use crate::domain::remote::{
+    pairing_url, PairedDevice, RemoteChallenge, RemoteCommand, RemoteDeviceRequest, RemoteHello, RemotePairing,
+    RemoteRequest, RemoteResponse, RemoteSession, SignedMessage, MAX_DEVICE_FIELD_LEN, REMOTE_DEVICES_FILE,
+    REMOTE_KEY_FILE,
+};
+use crate::domain::settings::RemoteSettings;
+use crate::infrastructure::app_dirs;
+
+type Sink = SplitSink<WebSocketStream<TcpStream>, Message>;
+type Source = SplitStream<WebSocketStream<TcpStream>>;
+
+/// How long a phone has to answer the challenge.
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a new phone waits for the player to approve it on the PC.
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pairing key, cached so connections don't read the disk.
+static KEY: RwLock<Option<String>> = RwLock::new(None);
+/// Listener task and the port it is bound to.
+static SERVER: Mutex<Option<(u16, JoinHandle<()>)>> = Mutex::new(None);
+/// Bumped whenever the server stops or moves or the pairing is reset; every
+/// open session watches it and closes on the next change.
+static GENERATION: LazyLock<watch::Sender<u64>> = LazyLock::new(|| watch::channel(0).0);
+/// New phones waiting for the player's answer: name and answer channel, by device id.
+static PENDING: LazyLock<Mutex<HashMap<String, (String, oneshot::Sender<bool>)>>> = LazyLock::new(Mutex::default);
+
+fn current_key() -> Result<String, String> {
+    if let Some(key) = KEY.read().clone() {
+        return Ok(key);
+    }
+    let key = load_or_create_token(REMOTE_KEY_FILE)?;
+    *KEY.write() = Some(key.clone());
+    Ok(key)
+}
+
+fn close_sessions() {
+    GENERATION.send_modify(|generation| *generation += 1);
+}
+
+fn devices_path() -> Result<PathBuf, String> {
+    app_dirs::try_app_data_dir()
+        .map(|dir| dir.join(REMOTE_DEVICES_FILE))
+        .ok_or_else(|| "Failed to resolve app data folder".to_string())
+}
+
+fn load_devices() -> Vec<PairedDevice> {
+    devices_path()
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+fn save_devices(devices: &[PairedDevice]) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(devices).map_err(|e| e.to_string())?;
+    fs::write(devices_path()?, json).map_err(|e| format!("Failed to save paired phones: {e}"))
+}
+
+/// URL and QR code for pairing a phone.
+///
+/// # Errors
+/// Returns error if the key cannot be read or the QR code rendered.
+pub fn pairing(settings: &RemoteSettings) -> Result<RemotePairing, String> {
+    let key = current_key()?;
+    let url = lan_address().map(|address| pairing_url(address, settings.port, &key));
+    let qr_svg = url.as_deref().map(qr_svg).transpose().map_err(String::from)?;
+    Ok(RemotePairing {
+        enabled: settings.enabled,
+        url,
+        qr_svg,
+    })
+}
+
+/// Replaces the key and forgets approved phones; open sessions close now.
+///
+/// # Errors
+/// Returns error if the key file cannot be written.
+pub fn reset_pairing() -> Result<(), String> {
+    let key = regenerate_token(REMOTE_KEY_FILE)?;
+    *KEY.write() = Some(key);
+    // Dropping the senders refuses phones still waiting for approval
+    PENDING.lock().clear();
+    close_sessions();
+    match devices_path().map(fs::remove_file) {
+        Ok(Err(e)) if e.kind() != std::io::ErrorKind::NotFound => Err(format!("Failed to forget paired phones: {e}")),
+        _ => Ok(()),
+    }
+}
+
+/// Answers a `remote-device-pending` event; an approved phone is remembered.
+///
+/// # Errors
+/// Returns error if no such phone is waiting or it cannot be remembered.
+pub fn respond_to_device(device_id: &str, accept: bool) -> Result<(), String> {
+    let (name, sender) = PENDING
+        .lock()
+        .remove(device_id)
+        .ok_or_else(|| format!("No phone {device_id} is waiting for approval"))?;
+    if accept {
+        let mut devices = load_devices();
+        devices.retain(|device| device.device_id != device_id);
+        devices.push(PairedDevice {
+            device_id: device_id.to_string(),
+            name,
+        });
+        save_devices(&devices)?;
+        info!("📱 Phone {} approved", device_id);
+    }
+    let _ = sender.send(accept);
+    Ok(())
+}
+
+/// Starts, moves (port changed) or stops the server to match the settings.
+pub fn apply(app: &AppHandle, settings: &RemoteSettings) {
+    let mut server = SERVER.lock();
+    let wanted = settings.enabled.then_some(settings.port);
+    if server.as_ref().map(|(port, _)| *port) == wanted {
+        return;
+    }
+
+    if let Some((port, task)) = server.take() {
+        task.abort();
+        info!("📱 Companion remote stopped (port {})", port);
+    }
+    close_sessions();
+    if let Some(port) = wanted {
+        let app = app.clone();
+        *server = Some((port, tauri::async_runtime::spawn(serve(app, port))));
+    }
+}
+
+async fn serve(app: AppHandle, port: u16) {
+    // Every interface: the phone connects over Wi-Fi (Windows Firewall asks once)
+    let listener = match TcpListener::bind((Ipv4Addr::UNSPECIFIED, port)).await {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("❌ Companion remote cannot listen on port {}: {}", port, e);
+            return;
+        },
+    };
+    info!("📱 Companion remote listening on port {}", port);
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                // Subscribed before the task starts so a reset in between still closes it
+                let revoked = GENERATION.subscribe();
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move { handle_connection(app, stream, peer, revoked).await });
+            },
+            Err(e) => warn!("Companion remote accept failed: {}", e),
+        }
+    }
+}
+
+async fn handle_connection(app: AppHandle, stream: TcpStream, peer: SocketAddr, mut revoked: watch::Receiver<u64>) {
+    let socket = match tokio_tungstenite::accept_async(stream).await {
+        Ok(socket) => socket,
+        Err(e) => {
+            warn!("🚫 Companion handshake from {} failed: {}", peer, e);
+            return;
+        },
+    };
+    let (mut sink, mut source) = socket.split();
+
+    let session = tokio::select! {
+        session = authenticate(&app, &mut sink, &mut source, peer) => session,
+        _ = revoked.changed() => None,
+    };
+    let Some(mut session) = session else {
+        let _ = sink.close().await;
+        return;
+    };
+    info!("📱 Phone connected from {}", peer);
+
+    let mut pointer = RemotePointer::default();
+    let ready = RemoteResponse::Ok {
+        id: None,
+        data: serde_json::Value::Null,
+    };
+    let mut outgoing = Some(ready);
+    loop {
+        if let Some(response) = outgoing.take() {
+            let sealed = session.seal(serde_json::to_string(&response).unwrap_or_default());
+            let json = serde_json::to_string(&sealed).unwrap_or_default();
+            if sink.send(Message::Text(json)).await.is_err() {
+                break;
+            }
+        }
+
+        let message = tokio::select! {
+            message = source.next() => message,
+            _ = revoked.changed() => break,
+        };
+        let text = match message {
+            Some(Ok(Message::Text(text))) => text,
+            Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+            // Pings are answered by the WebSocket layer
+            Some(Ok(_)) => continue,
+        };
+        let Some(body) = serde_json::from_str::<SignedMessage>(&text)
+            .ok()
+            .and_then(|message| session.open(message))
+        else {
+            warn!("🚫 Unsigned or replayed message from {}, closing", peer);
+            break;
+        };
+        outgoing = Some(respond(&app, &body, &mut pointer).await);
+    }
+    let _ = sink.close().await;
+    info!("📱 Phone disconnected ({})", peer);
+}
+
+/// Challenge, proof and (for a new phone) approval on the PC.
+async fn authenticate(
+    app: &AppHandle,
+    sink: &mut Sink,
+    source: &mut Source,
+    peer: SocketAddr,
+) -> Option<RemoteSession> {
+    let key = current_key().ok()?;
+    let challenge = format!("{}{}", uuid::Uuid::new_v4().simple(), uuid::Uuid::new_v4().simple());
+    let json = serde_json::to_string(&RemoteChallenge {
+        challenge: challenge.clone(),
+    })
+    .ok()?;
+    sink.send(Message::Text(json)).await.ok()?;
+
+    let hello = match tokio::time::timeout(AUTH_TIMEOUT, source.next()).await {
+        Ok(Some(Ok(Message::Text(text)))) => serde_json::from_str::<RemoteHello>(&text).ok(),
+        _ => None,
+    };
+    let Some((hello, session)) = hello.and_then(|hello| {
+        let session = RemoteSession::accept(&key, &challenge, &hello)?;
+        Some((hello, session))
+    }) else {
+        warn!("🚫 Refused companion connection from {}: invalid pairing proof", peer);
+        return None;
+    };
+    if !approved(app, &hello, peer).await {
+        warn!("🚫 Phone {} from {} was not approved", hello.device_id, peer);
+        return None;
+    }
+    Some(session)
+}
+
+/// Whether the phone was approved before, otherwise asks the player.
+async fn approved(app: &AppHandle, hello: &RemoteHello, peer: SocketAddr) -> bool {
+    if load_devices().iter().any(|device| device.device_id == hello.device_id) {
+        return true;
+    }
+    let name: String = hello.device_name.chars().take(MAX_DEVICE_FIELD_LEN).collect();
+    let (sender, receiver) = oneshot::channel();
+    {
+        let mut pending = PENDING.lock();
+        // One prompt per phone at a time
+        if pending.contains_key(&hello.device_id) {
+            return false;
+        }
+        pending.insert(hello.device_id.clone(), (name.clone(), sender));
+    }
+    let _ = app.emit(
+        "remote-device-pending",
+        RemoteDeviceRequest {
+            device_id: hello.device_id.clone(),
+            name,
+            address: peer.ip().to_string(),
+        },
+    );
+
+    let accepted = matches!(tokio::time::timeout(APPROVAL_TIMEOUT, receiver).await, Ok(Ok(true)));
+    PENDING.lock().remove(&hello.device_id);
+    accepted
+}
+
+async fn respond(app: &AppHandle, text: &str, pointer: &mut RemotePointer) -> RemoteResponse {
+    let request = match serde_json::from_str::<RemoteRequest>(text) {
+        Ok(request) => request,
+        Err(e) => {
+            return RemoteResponse::Error {
+                id: None,
+                message: format!("Invalid request: {e}"),
+            }
+        },
+    };
+    match execute(app, request.command, pointer).await {
+        Ok(data) => RemoteResponse::Ok { id: request.id, data },
+        Err(message) => RemoteResponse::Error {
+            id: request.id,
+            message,
+        },
+    }
+}
+
+async fn execute(
+    app: &AppHandle,
+    command: RemoteCommand,
+    pointer: &mut RemotePointer,
+) -> Result<serde_json::Value, String> {
+    // Library and session commands are the external API's
+    let api_command = match command {
+        RemoteCommand::ListGames => ExternalApiCommand::ListGames,
+        RemoteCommand::Launch { game_id } => ExternalApiCommand::Launch { game_id },
+        RemoteCommand::GetActiveGame => ExternalApiCommand::GetActiveGame,
+        RemoteCommand::CloseGame => ExternalApiCommand::CloseGame,
+        RemoteCommand::Show => ExternalApiCommand::Show,
+        RemoteCommand::KillGame => {
+            let game = get_active_game(app.state()).ok_or("No game is running")?;
+            kill_game(game.pid, app.state())?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::GetSystemStatus => {
+            return serde_json::to_value(get_system_status(app.state())).map_err(|e| e.to_string());
+        },
+        RemoteCommand::SetVolume { level } => {
+            set_volume(app.state(), level).await?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::SetTdp { watts } => {
+            set_tdp(app.state(), watts).await?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::PointerMove { dx, dy } => {
+            pointer.move_by(dx, dy)?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::PointerClick { button } => {
+            pointer.click(button)?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::PointerScroll { delta } => {
+            pointer.scroll(delta)?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::TypeText { text } => {
+            send_virtual_text(app.state(), text)?;
+            return Ok(serde_json::Value::Null);
+        },
+        RemoteCommand::PressKey { key } => {
+            send_virtual_key(app.state(), key)?;
+            return Ok(serde_json::Value::Null);
+        },
+    };
+
+    let app = app.clone();
+    tokio::task::spawn_blocking(move || external_api::execute(&app, api_command))
+        .await
+        .unwrap_or_else(|e| Err(format!("Task join error: {e}")))
+}
@@ -0,0 +1,23 @@
+/**
+ * Safe Mode
+ *
+ * Minimal Balam the watchdog restarts after a crash loop (`--safe-mode`):
+ * no DLL overlay injection, RTSS, FPS service (ETW), gamepad chords or
+ * background services, and a library-only UI. Each feature checks
+ * `is_active()` before starting; `exit_safe_mode` restarts Balam normally.
+ */
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::warn;
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Turns safe mode on for this process (called once at startup).
+pub fn enable() {
+    warn!("🛟 Safe mode: injection, RTSS, FPS service, chords and background services are off");
+    ACTIVE.store(true, Ordering::Relaxed);
+}
+
+#[must_use]
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+}
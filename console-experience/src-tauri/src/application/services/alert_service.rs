@@ -0,0 +1,101 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::application::services::{HapticService, SettingsService};
+use crate::domain::alerts::{AlertMonitor, AlertReading, GameAlert};
+use crate::domain::haptic::HapticEvent;
+use crate::domain::notification::{Notification, NotificationCategory};
+use crate::ports::NotificationPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Time between readings while a game runs.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+/// How often the monitor checks for a game while idle.
+const IDLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reads battery, temperatures, load and clocks.
+pub type AlertReadingSource = Box<dyn Fn() -> AlertReading + Send + Sync>;
+/// Called for every alert raised (the app emits `game-alert`).
+pub type AlertListener = Box<dyn Fn(&GameAlert) + Send + Sync>;
+
+/// Low battery, high temperature and thermal throttling alerts while in game.
+///
+/// Thermal alerts are also posted as notifications; battery ones are not,
+/// since `SystemStatusService` already notifies about low battery.
+pub struct AlertService {
+    monitor: Mutex<AlertMonitor>,
+}
+
+impl Default for AlertService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlertService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            monitor: Mutex::new(AlertMonitor::new()),
+        }
+    }
+
+    /// Alerts whose condition still holds.
+    #[must_use]
+    pub fn active(&self) -> Vec<GameAlert> {
+        self.monitor.lock().active().to_vec()
+    }
+
+    /// Starts the monitor thread.
+    pub fn start(
+        self: &Arc<Self>,
+        settings: Arc<SettingsService>,
+        active_games: Arc<ActiveGamesTracker>,
+        readings: AlertReadingSource,
+        notifications: Arc<dyn NotificationPort>,
+        haptics: Arc<HapticService>,
+        on_alert: AlertListener,
+    ) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("game-alerts".to_string())
+            .spawn(move || {
+                let mut in_game = false;
+                loop {
+                    let current = settings.get();
+                    if !current.alerts.enabled || active_games.list_active().is_empty() {
+                        if in_game {
+                            service.monitor.lock().reset();
+                            in_game = false;
+                        }
+                        std::thread::sleep(IDLE_INTERVAL);
+                        continue;
+                    }
+                    in_game = true;
+
+                    let raised = service.monitor.lock().check(&readings(), &current.alerts);
+                    for alert in &raised {
+                        info!("⚠️ {}: {}", alert.title, alert.message);
+                        if current.alerts.haptics {
+                            haptics.play_event(HapticEvent::Event, &current.haptics);
+                        }
+                        if !alert.kind.is_battery() {
+                            notifications.notify(Notification::new(
+                                NotificationCategory::System,
+                                alert.priority,
+                                alert.title.clone(),
+                                alert.message.clone(),
+                            ));
+                        }
+                        on_alert(alert);
+                    }
+                    std::thread::sleep(CHECK_INTERVAL);
+                }
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start game alert monitor: {}", e);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::domain::artwork::{ArtworkEntry, ArtworkIndex, GameArtwork, Thumbnail};
+use crate::domain::Game;
+use crate::infrastructure::app_dirs;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use tracing::{info, warn};
+
+const ARTWORK_DIR: &str = "artwork";
+const INDEX_FILE: &str = "index.json";
+/// Artwork jobs running at once (mostly waiting on the network)
+const WORKERS: usize = 3;
+
+/// Fetches a game's artwork into the artwork directory.
+pub type ArtworkFetcher = Box<dyn Fn(&Game, &Path) -> ArtworkEntry + Send + Sync>;
+/// Called when a game's artwork is on disk.
+pub type ArtworkReadyListener = Box<dyn Fn(&GameArtwork) + Send + Sync>;
+
+/// Keeps game artwork off the library's critical path.
+///
+/// Games are served with the artwork already in the cache; the rest is
+/// fetched by a small worker pool and announced per game when ready.
+pub struct ArtworkService {
+    dir: PathBuf,
+    index: Mutex<ArtworkIndex>,
+    /// Games queued or already tried this session
+    requested: Mutex<HashSet<String>>,
+    jobs: mpsc::Sender<Game>,
+    /// Taken by the workers on start
+    queue: Mutex<Option<mpsc::Receiver<Game>>>,
+}
+
+impl ArtworkService {
+    #[must_use]
+    pub fn new(data_dir: &Path) -> Self {
+        let dir = data_dir.join(ARTWORK_DIR);
+        let index = fs::read_to_string(dir.join(INDEX_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        let (jobs, queue) = mpsc::channel();
+        Self {
+            dir,
+            index: Mutex::new(index),
+            requested: Mutex::new(HashSet::new()),
+            jobs,
+            queue: Mutex::new(Some(queue)),
+        }
+    }
+
+    /// App local data directory (see `app_dirs::app_data_dir`).
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir()
+    }
+
+    /// Starts the workers; games queued before are fetched first.
+    pub fn start(self: &Arc<Self>, fetch: ArtworkFetcher, on_ready: ArtworkReadyListener) {
+        let Some(queue) = self.queue.lock().take() else {
+            return;
+        };
+        let queue = Arc::new(Mutex::new(queue));
+        let fetch = Arc::new(fetch);
+        let on_ready = Arc::new(on_ready);
+
+        for worker in 0..WORKERS {
+            let service = Arc::clone(self);
+            let queue = Arc::clone(&queue);
+            let fetch = Arc::clone(&fetch);
+            let on_ready = Arc::clone(&on_ready);
+            let spawned = std::thread::Builder::new()
+                .name(format!("artwork-{worker}"))
+                .spawn(move || loop {
+                    let Ok(game) = queue.lock().recv() else {
+                        return;
+                    };
+                    let entry = fetch(&game, &service.dir);
+                    if entry == ArtworkEntry::default() {
+                        warn!("No artwork found for {}", game.title);
+                        continue;
+                    }
+                    on_ready(&service.record(&game.id, entry));
+                });
+            if let Err(e) = spawned {
+                warn!("Failed to start artwork worker: {}", e);
+            }
+        }
+        info!("🖼️ Artwork workers started");
+    }
+
+    /// Applies cached artwork to `games` and queues the games without any
+    /// (once per session); never waits for image work.
+    pub fn prepare(&self, games: &mut [Game]) {
+        let index = self.index.lock();
+        let mut requested = self.requested.lock();
+        for game in games {
+            match index.get(&game.id) {
+                Some(entry) => {
+                    let artwork = self.resolve(&game.id, entry);
+                    game.image = artwork.image.or(game.image.take());
+                    game.hero_image = artwork.hero_image.or(game.hero_image.take());
+                    game.logo = artwork.logo.or(game.logo.take());
+                },
+                None => {
+                    if requested.insert(game.id.clone()) {
+                        let _ = self.jobs.send(game.clone());
+                    }
+                },
+            }
+        }
+    }
+
+    /// Lets the next `prepare` try again the games still without artwork
+    /// (a rescan may bring new artwork URLs).
+    pub fn retry_missing(&self) {
+        self.requested.lock().clear();
+    }
+
+    /// Stores a game's artwork in the index and returns its local files.
+    fn record(&self, game_id: &str, entry: ArtworkEntry) -> GameArtwork {
+        let artwork = self.resolve(game_id, &entry);
+        let mut index = self.index.lock();
+        index.insert(game_id.to_string(), entry);
+        let saved = serde_json::to_string(&*index)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                fs::create_dir_all(&self.dir)
+                    .and_then(|()| fs::write(self.dir.join(INDEX_FILE), json))
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = saved {
+            warn!("Failed to save artwork index: {}", e);
+        }
+        artwork
+    }
+
+    fn resolve(&self, game_id: &str, entry: &ArtworkEntry) -> GameArtwork {
+        let path = |file: String| self.dir.join(file).display().to_string();
+        GameArtwork {
+            game_id: game_id.to_string(),
+            image: entry.cover.as_ref().map(|cover| path(cover.display_file_name())),
+            hero_image: entry.hero.as_ref().map(|hero| path(hero.file_name())),
+            logo: entry.logo.as_ref().map(|logo| path(logo.file_name())),
+            cover_thumbnails: entry
+                .cover
+                .iter()
+                .flat_map(|cover| {
+                    cover.thumbnails.iter().map(|&width| Thumbnail {
+                        width,
+                        path: path(cover.thumbnail_file_name(width)),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::artwork::StoredImage;
+    use crate::domain::{GameSource, InstallState};
+
+    fn game(id: &str) -> Game {
+        Game {
+            id: id.to_string(),
+            raw_id: id.to_string(),
+            title: id.to_string(),
+            path: String::new(),
+            image: Some(format!("https://example.com/{id}.jpg")),
+            hero_image: None,
+            logo: None,
+            last_played: None,
+            source: GameSource::Steam,
+            install_state: InstallState::Installed,
+            launcher_dependency: None,
+            update_pending: false,
+        }
+    }
+
+    #[test]
+    fn test_cached_artwork_is_applied_and_the_rest_queued_once() {
+        let dir = std::env::temp_dir().join(format!("balam-artwork-{}", uuid::Uuid::new_v4().simple()));
+        let service = ArtworkService::new(&dir);
+        service.record(
+            "cached",
+            ArtworkEntry {
+                cover: Some(StoredImage {
+                    hash: "ab12".to_string(),
+                    extension: "jpg".to_string(),
+                    thumbnails: vec![256, 600],
+                }),
+                ..ArtworkEntry::default()
+            },
+        );
+
+        let mut games = vec![game("cached"), game("new")];
+        service.prepare(&mut games);
+        assert!(games[0].image.as_deref().unwrap().ends_with("ab12_600.png"));
+        assert_eq!(games[1].image.as_deref(), Some("https://example.com/new.jpg"));
+
+        service.prepare(&mut games);
+        let queued: Vec<String> = service
+            .queue
+            .lock()
+            .as_ref()
+            .unwrap()
+            .try_iter()
+            .map(|g| g.id)
+            .collect();
+        assert_eq!(queued, ["new"]);
+
+        // The index survives a restart
+        assert!(ArtworkService::new(&dir).index.lock().contains_key("cached"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
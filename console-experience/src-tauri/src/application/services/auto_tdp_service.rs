@@ -0,0 +1,221 @@
+use crate::domain::performance::TDPConfig;
+use crate::domain::tdp_governor::{AutoTdpStatus, AutoTdpTarget, TdpGovernor};
+use crate::domain::BalamError;
+use parking_lot::{Mutex, RwLock};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Time between frame rate readings.
+const TICK: Duration = Duration::from_millis(500);
+/// Consecutive failed TDP writes before the governor gives up.
+const MAX_WRITE_FAILURES: u32 = 3;
+
+/// Current frame rate of the focused game (`None` without presents).
+pub type FpsSource = Box<dyn Fn() -> Option<f32> + Send + Sync>;
+/// Applies a TDP in watts.
+pub type TdpSetter = Arc<dyn Fn(u32) -> Result<(), BalamError> + Send + Sync>;
+
+/// A running governor.
+struct GovernorSession {
+    stop: Arc<AtomicBool>,
+    /// TDP in effect before auto mode, restored when it is switched off
+    original_watts: u32,
+    set_tdp: TdpSetter,
+}
+
+/// "Auto TDP": holds a target frame rate at the lowest power draw.
+///
+/// Switching it off is immediate: the loop is flagged to stop and the TDP
+/// from before auto mode is written right away, without waiting for the
+/// loop's next tick.
+pub struct AutoTdpService {
+    session: Mutex<Option<GovernorSession>>,
+    status: Arc<RwLock<AutoTdpStatus>>,
+}
+
+impl Default for AutoTdpService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoTdpService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            session: Mutex::new(None),
+            status: Arc::new(RwLock::new(AutoTdpStatus::default())),
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self) -> AutoTdpStatus {
+        self.status.read().clone()
+    }
+
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.status.read().active
+    }
+
+    /// Starts (or retargets) the governor from the current TDP.
+    ///
+    /// # Errors
+    /// Returns `INVALID_ARGUMENT` for a target out of range.
+    pub fn start(
+        &self,
+        target: AutoTdpTarget,
+        hardware: TDPConfig,
+        fps: FpsSource,
+        set_tdp: TdpSetter,
+    ) -> Result<AutoTdpStatus, BalamError> {
+        let (min_watts, max_watts) = target.bounds(&hardware).map_err(BalamError::InvalidArgument)?;
+        // Retargeting keeps the TDP from before the first start
+        let original_watts = self
+            .stop_session()
+            .map_or(hardware.watts, |session| session.original_watts);
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let mut governor = TdpGovernor::new(target.target_fps, min_watts, max_watts, hardware.watts);
+        let start_watts = governor.watts();
+        if start_watts != hardware.watts {
+            // Outside the allowed range: clamp before the first tick
+            if let Err(e) = set_tdp(start_watts) {
+                self.status.write().active = false;
+                return Err(e);
+            }
+        }
+        let status = AutoTdpStatus {
+            active: true,
+            target_fps: target.target_fps,
+            current_watts: start_watts,
+            min_watts,
+            max_watts,
+            average_fps: None,
+        };
+        *self.status.write() = status.clone();
+
+        let loop_stop = stop.clone();
+        let loop_status = self.status.clone();
+        let loop_set_tdp = set_tdp.clone();
+        let spawned = std::thread::Builder::new().name("auto-tdp".to_string()).spawn(move || {
+            info!(
+                "⚡ Auto TDP started: {} FPS within {}-{}W",
+                target.target_fps, min_watts, max_watts
+            );
+            let mut failures = 0;
+            while !loop_stop.load(Ordering::SeqCst) {
+                if let Some(watts) = governor.tick(fps()) {
+                    // The switch may have been flipped while reading the frame rate
+                    if loop_stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match loop_set_tdp(watts) {
+                        Ok(()) => failures = 0,
+                        Err(e) => {
+                            failures += 1;
+                            warn!("Auto TDP write failed ({}/{}): {}", failures, MAX_WRITE_FAILURES, e);
+                            if failures >= MAX_WRITE_FAILURES {
+                                break;
+                            }
+                        },
+                    }
+                }
+                {
+                    let mut status = loop_status.write();
+                    // A restart owns the status now
+                    if loop_stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    status.current_watts = governor.watts();
+                    status.average_fps = governor.average_fps().or(status.average_fps);
+                }
+                std::thread::sleep(TICK);
+            }
+            // Stopped on its own (TDP writes failing): report it
+            if !loop_stop.swap(true, Ordering::SeqCst) {
+                loop_status.write().active = false;
+            }
+            info!("⚡ Auto TDP stopped");
+        });
+        if let Err(e) = spawned {
+            self.status.write().active = false;
+            return Err(BalamError::platform("Failed to start auto TDP", e));
+        }
+
+        *self.session.lock() = Some(GovernorSession {
+            stop,
+            original_watts,
+            set_tdp,
+        });
+        Ok(status)
+    }
+
+    /// Switches auto mode off immediately.
+    ///
+    /// With `restore`, the TDP from before auto mode is written back (a
+    /// manual TDP change passes `false`: the new value wins).
+    ///
+    /// # Errors
+    /// Returns error if the original TDP cannot be restored.
+    pub fn stop(&self, restore: bool) -> Result<(), BalamError> {
+        let Some(session) = self.stop_session() else {
+            return Ok(());
+        };
+        self.status.write().active = false;
+        if restore {
+            (session.set_tdp)(session.original_watts)?;
+            info!("⚡ Auto TDP off, restored {}W", session.original_watts);
+        }
+        Ok(())
+    }
+
+    fn stop_session(&self) -> Option<GovernorSession> {
+        let session = self.session.lock().take()?;
+        session.stop.store(true, Ordering::SeqCst);
+        Some(session)
+    }
+}
+
+impl Drop for AutoTdpService {
+    fn drop(&mut self) {
+        let _ = self.stop(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stop_restores_original_tdp() {
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let recorder = written.clone();
+        let set_tdp: TdpSetter = Arc::new(move |watts| {
+            recorder.lock().push(watts);
+            Ok(())
+        });
+        let service = AutoTdpService::new();
+        let target = AutoTdpTarget {
+            target_fps: 60,
+            min_watts: Some(8),
+            max_watts: Some(12),
+        };
+
+        // 20W is above the allowed range: clamped right away
+        let status = service
+            .start(target, TDPConfig::new(20, 5, 30).unwrap(), Box::new(|| None), set_tdp)
+            .unwrap();
+        assert_eq!(status.current_watts, 12);
+        assert!(service.is_active());
+
+        service.stop(true).unwrap();
+        assert!(!service.is_active());
+        assert_eq!(*written.lock(), [12, 20]);
+        // Already off
+        service.stop(true).unwrap();
+        assert_eq!(written.lock().len(), 2);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::domain::benchmark::{
+    BenchmarkExportFormat, BenchmarkProgress, BenchmarkRun, BenchmarkRunInfo, BenchmarkSample, BenchmarkSummary,
+    BENCHMARK_SAMPLE_INTERVAL_MS, MAX_BENCHMARK_SECS, MIN_BENCHMARK_SECS,
+};
+use crate::domain::performance::PerformanceMetrics;
+use crate::domain::BalamError;
+use crate::infrastructure::app_dirs;
+use crate::infrastructure::clock::now_secs;
+use parking_lot::Mutex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+const BENCHMARK_DIR: &str = "benchmarks";
+const EXPORT_DIR: &str = "exports";
+
+/// Reads the current FPS, utilization and temperatures.
+pub type MetricsSource = Box<dyn Fn() -> PerformanceMetrics + Send>;
+/// Called about once a second while a run records.
+pub type BenchmarkProgressListener = Box<dyn Fn(&BenchmarkProgress) + Send>;
+/// Called once with the saved run (or why it could not be saved).
+pub type BenchmarkFinishedListener = Box<dyn FnOnce(Result<BenchmarkRunInfo, BalamError>) + Send>;
+
+/// Game a run is recorded for.
+#[derive(Debug, Clone)]
+pub struct BenchmarkTarget {
+    pub game_id: String,
+    pub game_title: String,
+    pub tdp_watts: Option<u32>,
+}
+
+/// Run IDs are generated here; anything else could walk out of the folder.
+fn validate_id(id: &str) -> Result<(), BalamError> {
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(BalamError::InvalidArgument(format!("Invalid benchmark run ID '{id}'")));
+    }
+    Ok(())
+}
+
+/// Records benchmark runs of the running game and stores them as JSON.
+///
+/// One run at a time; it ends after its duration, on `cancel` or when the
+/// game exits (the partial run is kept, marked cancelled).
+pub struct BenchmarkService {
+    dir: PathBuf,
+    /// Cancel flag of the run in progress
+    active: Mutex<Option<Arc<AtomicBool>>>,
+}
+
+impl BenchmarkService {
+    #[must_use]
+    pub fn new(data_dir: &Path) -> Self {
+        Self {
+            dir: data_dir.join(BENCHMARK_DIR),
+            active: Mutex::new(None),
+        }
+    }
+
+    /// App local data directory (see `app_dirs::app_data_dir`).
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir()
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.active.lock().is_some()
+    }
+
+    /// Starts recording `target` for `duration_secs` on a worker thread.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` for a duration out of range, or an error if
+    /// a run is already in progress.
+    pub fn start(
+        self: &Arc<Self>,
+        target: BenchmarkTarget,
+        duration_secs: u32,
+        active_games: Arc<ActiveGamesTracker>,
+        metrics: MetricsSource,
+        on_progress: BenchmarkProgressListener,
+        on_finished: BenchmarkFinishedListener,
+    ) -> Result<(), BalamError> {
+        if !(MIN_BENCHMARK_SECS..=MAX_BENCHMARK_SECS).contains(&duration_secs) {
+            return Err(BalamError::InvalidArgument(format!(
+                "Benchmark duration {duration_secs}s out of range ({MIN_BENCHMARK_SECS} - {MAX_BENCHMARK_SECS}s)"
+            )));
+        }
+        let cancel = Arc::new(AtomicBool::new(false));
+        {
+            let mut active = self.active.lock();
+            if active.is_some() {
+                return Err(BalamError::Platform("A benchmark is already running".to_string()));
+            }
+            *active = Some(Arc::clone(&cancel));
+        }
+
+        info!(
+            "⏱️ Benchmark started: {} for {}s (TDP {:?}W)",
+            target.game_title, duration_secs, target.tdp_watts
+        );
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("benchmark".to_string())
+            .spawn(move || {
+                let run = Self::record(target, duration_secs, &active_games, &metrics, &on_progress, &cancel);
+                *service.active.lock() = None;
+                on_finished(service.save(&run).map(|()| BenchmarkRunInfo::from(&run)));
+            });
+        if let Err(e) = spawned {
+            *self.active.lock() = None;
+            return Err(BalamError::platform("Failed to start benchmark", e));
+        }
+        Ok(())
+    }
+
+    fn record(
+        target: BenchmarkTarget,
+        duration_secs: u32,
+        active_games: &ActiveGamesTracker,
+        metrics: &MetricsSource,
+        on_progress: &BenchmarkProgressListener,
+        cancel: &AtomicBool,
+    ) -> BenchmarkRun {
+        let started_at = now_secs();
+        let started = Instant::now();
+        let duration = Duration::from_secs(u64::from(duration_secs));
+        let mut samples: Vec<BenchmarkSample> = Vec::new();
+        let mut reported_secs = 0;
+        let mut cancelled = false;
+
+        while started.elapsed() < duration {
+            if cancel.load(Ordering::Relaxed) || active_games.get(&target.game_id).is_none() {
+                cancelled = true;
+                break;
+            }
+            let reading = metrics();
+            let fps = reading.fps.as_ref().map_or(0.0, |fps| fps.current_fps);
+            samples.push(BenchmarkSample {
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                fps,
+                frame_time_ms: if fps > 0.0 { 1000.0 / fps } else { 0.0 },
+                cpu_usage: reading.cpu_usage,
+                gpu_usage: reading.gpu_usage,
+                cpu_temp_c: reading.cpu_temp_c,
+                gpu_temp_c: reading.gpu_temp_c,
+                gpu_power_w: reading.gpu_power_w,
+            });
+
+            let elapsed_secs = started.elapsed().as_secs() as u32;
+            if elapsed_secs > reported_secs {
+                reported_secs = elapsed_secs;
+                on_progress(&BenchmarkProgress {
+                    game_id: target.game_id.clone(),
+                    elapsed_secs,
+                    duration_secs,
+                    current_fps: fps,
+                });
+            }
+            std::thread::sleep(Duration::from_millis(BENCHMARK_SAMPLE_INTERVAL_MS));
+        }
+
+        let summary = BenchmarkSummary::from_samples(&samples);
+        info!(
+            "⏱️ Benchmark {}: avg {:.1} FPS, 1% low {:.1}, 0.1% low {:.1}",
+            if cancelled { "stopped" } else { "finished" },
+            summary.avg_fps,
+            summary.low_1_percent_fps,
+            summary.low_0_1_percent_fps
+        );
+        BenchmarkRun {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            game_id: target.game_id,
+            game_title: target.game_title,
+            tdp_watts: target.tdp_watts,
+            started_at,
+            duration_secs: started.elapsed().as_secs() as u32,
+            cancelled,
+            summary,
+            samples,
+        }
+    }
+
+    /// Stops the run in progress; it is saved with what was recorded.
+    ///
+    /// Returns whether a run was in progress.
+    pub fn cancel(&self) -> bool {
+        match self.active.lock().as_ref() {
+            Some(cancel) => {
+                cancel.store(true, Ordering::Relaxed);
+                true
+            },
+            None => false,
+        }
+    }
+
+    fn path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{id}.json"))
+    }
+
+    fn save(&self, run: &BenchmarkRun) -> Result<(), BalamError> {
+        fs::create_dir_all(&self.dir).map_err(|e| BalamError::platform("Failed to create benchmark folder", e))?;
+        let json = serde_json::to_string(run).map_err(|e| BalamError::platform("Failed to serialize run", e))?;
+        fs::write(self.path(&run.id), json).map_err(|e| BalamError::platform("Failed to save benchmark run", e))
+    }
+
+    /// Loads a run with its samples.
+    ///
+    /// # Errors
+    /// Returns `NotFound` for an unknown ID.
+    pub fn load(&self, id: &str) -> Result<BenchmarkRun, BalamError> {
+        validate_id(id)?;
+        let content =
+            fs::read_to_string(self.path(id)).map_err(|_| BalamError::NotFound(format!("Benchmark run {id}")))?;
+        serde_json::from_str(&content).map_err(|e| BalamError::platform("Corrupt benchmark run", e))
+    }
+
+    /// Saved runs, newest first, optionally for one game and/or TDP.
+    #[must_use]
+    pub fn list(&self, game_id: Option<&str>, tdp_watts: Option<u32>) -> Vec<BenchmarkRunInfo> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut runs: Vec<BenchmarkRunInfo> = entries
+            .flatten()
+            .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+            .filter_map(|content| serde_json::from_str::<BenchmarkRun>(&content).ok())
+            .filter(|run| game_id.is_none_or(|id| run.game_id == id))
+            .filter(|run| tdp_watts.is_none_or(|watts| run.tdp_watts == Some(watts)))
+            .map(|run| BenchmarkRunInfo::from(&run))
+            .collect();
+        runs.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        runs
+    }
+
+    /// Deletes a saved run.
+    ///
+    /// # Errors
+    /// Returns `NotFound` for an unknown ID.
+    pub fn delete(&self, id: &str) -> Result<(), BalamError> {
+        validate_id(id)?;
+        fs::remove_file(self.path(id)).map_err(|_| BalamError::NotFound(format!("Benchmark run {id}")))
+    }
+
+    /// Writes a run as CSV (samples) or JSON (summary and samples) to the
+    /// exports folder and returns the file path.
+    ///
+    /// # Errors
+    /// Returns `NotFound` for an unknown ID or an error if the file cannot be written.
+    pub fn export(&self, id: &str, format: BenchmarkExportFormat) -> Result<PathBuf, BalamError> {
+        let run = self.load(id)?;
+        let (extension, content) = match format {
+            BenchmarkExportFormat::Csv => ("csv", run.to_csv()),
+            BenchmarkExportFormat::Json => (
+                "json",
+                serde_json::to_string_pretty(&run).map_err(|e| BalamError::platform("Failed to serialize run", e))?,
+            ),
+        };
+        let title: String = run
+            .game_title
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        let tdp = run.tdp_watts.map(|watts| format!("-{watts}W")).unwrap_or_default();
+        let dir = self.dir.join(EXPORT_DIR);
+        let path = dir.join(format!("{title}{tdp}-{}.{extension}", run.started_at));
+        fs::create_dir_all(&dir).map_err(|e| BalamError::platform("Failed to create export folder", e))?;
+        fs::write(&path, content).map_err(|e| BalamError::platform("Failed to write export", e))?;
+        info!("⏱️ Benchmark run exported to {}", path.display());
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runs_are_listed_per_game_and_tdp() {
+        let dir = std::env::temp_dir().join(format!("balam-benchmark-test-{}", std::process::id()));
+        let service = BenchmarkService::new(&dir);
+        for (id, game_id, tdp_watts, started_at) in [("a1", "steam_1", Some(15), 10), ("b2", "steam_1", Some(25), 20)] {
+            let run = BenchmarkRun {
+                id: id.to_string(),
+                game_id: game_id.to_string(),
+                game_title: "Game".to_string(),
+                tdp_watts,
+                started_at,
+                duration_secs: 30,
+                cancelled: false,
+                summary: BenchmarkSummary::default(),
+                samples: Vec::new(),
+            };
+            service.save(&run).unwrap();
+        }
+
+        let ids = |runs: Vec<BenchmarkRunInfo>| runs.into_iter().map(|run| run.id).collect::<Vec<_>>();
+        assert_eq!(ids(service.list(Some("steam_1"), None)), ["b2", "a1"]);
+        assert_eq!(ids(service.list(None, Some(15))), ["a1"]);
+        assert!(service.load("../settings").is_err());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
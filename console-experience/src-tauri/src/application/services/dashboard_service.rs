@@ -0,0 +1,184 @@
+use crate::application::services::SettingsService;
+use crate::domain::dashboard::{
+    ClockInfo, DashboardData, DashboardSettings, UpdateSummary, WeatherProvider, WeatherReport, WEATHER_MAX_AGE_SECS,
+};
+use crate::infrastructure::clock::now_secs;
+use crate::ports::WeatherPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tracing::{info, warn};
+
+/// Wait before fetching again after the provider failed.
+const WEATHER_RETRY: Duration = Duration::from_secs(5 * 60);
+
+/// Called with fresh data at every minute change (the clock tile flips on time).
+pub type DashboardListener = Box<dyn Fn(DashboardData) + Send + Sync>;
+/// Builds the update tile from the update service.
+pub type UpdateSummarySource = Box<dyn Fn() -> UpdateSummary + Send + Sync>;
+
+struct WeatherCache {
+    /// Settings the report was fetched for; any change refetches
+    settings: DashboardSettings,
+    report: Option<WeatherReport>,
+    next_fetch: Instant,
+}
+
+/// Data behind the home screen tiles: clock, weather and update status.
+///
+/// Weather is fetched on the dashboard thread only, so `get_dashboard_data`
+/// never waits on the network; a failed fetch keeps showing the last report
+/// until it is twice [`WEATHER_MAX_AGE_SECS`] old.
+pub struct DashboardService {
+    weather: Arc<dyn WeatherPort>,
+    cache: Mutex<Option<WeatherCache>>,
+}
+
+impl DashboardService {
+    #[must_use]
+    pub fn new(weather: Arc<dyn WeatherPort>) -> Self {
+        Self {
+            weather,
+            cache: Mutex::new(None),
+        }
+    }
+
+    /// Current tiles, from cached weather.
+    #[must_use]
+    pub fn data(&self, settings: &DashboardSettings, updates: UpdateSummary) -> DashboardData {
+        DashboardData {
+            clock: ClockInfo::at(chrono::Local::now(), iana_time_zone::get_timezone().unwrap_or_default()),
+            weather: self.weather(settings),
+            updates,
+        }
+    }
+
+    /// Cached report for these settings, unless it is too old to show.
+    #[must_use]
+    pub fn weather(&self, settings: &DashboardSettings) -> Option<WeatherReport> {
+        let cache = self.cache.lock();
+        let cache = cache.as_ref().filter(|cache| cache.settings == *settings)?;
+        cache
+            .report
+            .clone()
+            .filter(|report| now_secs().saturating_sub(report.fetched_at_secs) < 2 * WEATHER_MAX_AGE_SECS)
+    }
+
+    /// Fetches the weather when the cached report is stale or was fetched for
+    /// other settings. Blocking (network).
+    pub fn refresh_weather(&self, settings: &DashboardSettings) {
+        if settings.weather_provider == WeatherProvider::Disabled {
+            *self.cache.lock() = None;
+            return;
+        }
+        let previous = {
+            let cache = self.cache.lock();
+            match cache.as_ref() {
+                Some(cache) if cache.settings == *settings && Instant::now() < cache.next_fetch => return,
+                Some(cache) if cache.settings == *settings => cache.report.clone(),
+                _ => None,
+            }
+        };
+
+        let (report, next_fetch) = match self.weather.current(settings) {
+            Ok(report) => {
+                info!(
+                    "🌤️ Weather: {:.1}° {:?} ({})",
+                    report.temperature, report.condition, report.location
+                );
+                (Some(report), Instant::now() + Duration::from_secs(WEATHER_MAX_AGE_SECS))
+            },
+            Err(e) => {
+                warn!("Weather fetch failed: {}", e);
+                (previous, Instant::now() + WEATHER_RETRY)
+            },
+        };
+        *self.cache.lock() = Some(WeatherCache {
+            settings: settings.clone(),
+            report,
+            next_fetch,
+        });
+    }
+
+    /// Starts the thread pushing fresh data every minute.
+    pub fn start(
+        self: &Arc<Self>,
+        settings: Arc<SettingsService>,
+        updates: UpdateSummarySource,
+        on_refresh: DashboardListener,
+    ) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("dashboard".to_string())
+            .spawn(move || loop {
+                let dashboard = settings.get().dashboard;
+                service.refresh_weather(&dashboard);
+                on_refresh(service.data(&dashboard, updates()));
+                std::thread::sleep(until_next_minute());
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start dashboard thread: {}", e);
+        }
+    }
+}
+
+fn until_next_minute() -> Duration {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() % 60_000);
+    Duration::from_millis(60_000 - millis as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dashboard::{TemperatureUnit, WeatherCondition};
+    use crate::domain::BalamError;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    struct FakeWeather {
+        fetches: AtomicU32,
+    }
+
+    impl WeatherPort for FakeWeather {
+        fn current(&self, settings: &DashboardSettings) -> Result<WeatherReport, BalamError> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            Ok(WeatherReport {
+                temperature: 21.0,
+                unit: settings.temperature_unit,
+                condition: WeatherCondition::Clear,
+                location: settings.location_name.clone(),
+                fetched_at_secs: now_secs(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_weather_is_cached_per_settings() {
+        let weather = Arc::new(FakeWeather {
+            fetches: AtomicU32::new(0),
+        });
+        let service = DashboardService::new(weather.clone());
+        let mut settings = DashboardSettings {
+            weather_provider: WeatherProvider::OpenMeteo,
+            location_name: "Madrid".to_string(),
+            ..DashboardSettings::default()
+        };
+
+        service.refresh_weather(&settings);
+        service.refresh_weather(&settings);
+        assert_eq!(weather.fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(service.weather(&settings).unwrap().location, "Madrid");
+
+        // Other settings: the cached report no longer applies
+        settings.temperature_unit = TemperatureUnit::Fahrenheit;
+        assert!(service.weather(&settings).is_none());
+        service.refresh_weather(&settings);
+        assert_eq!(weather.fetches.load(Ordering::SeqCst), 2);
+
+        settings.weather_provider = WeatherProvider::Disabled;
+        service.refresh_weather(&settings);
+        assert!(service.weather(&settings).is_none());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::domain::external_game::ExecutableIndex;
+use crate::domain::Game;
+use parking_lot::{Mutex, RwLock};
+use std::collections::HashMap;
+use tracing::info;
+
+#[derive(Default)]
+struct Library {
+    index: ExecutableIndex,
+    games: HashMap<String, Game>,
+}
+
+/// Recognizes library games started outside Balam (from Steam, the
+/// desktop, a store client) by their executable path.
+///
+/// Fed with foreground windows and process starts; the caller registers
+/// what it finds so the overlay, FPS monitoring and session tracking work
+/// as if Balam had launched the game.
+pub struct ExternalGameDetector {
+    library: RwLock<Library>,
+    /// Foreground and process events arrive on different threads: one registration at a time
+    registering: Mutex<()>,
+}
+
+impl Default for ExternalGameDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExternalGameDetector {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            library: RwLock::new(Library::default()),
+            registering: Mutex::new(()),
+        }
+    }
+
+    /// Replaces the games executables are matched against (after every scan).
+    pub fn set_library(&self, games: &[Game]) {
+        *self.library.write() = Library {
+            index: ExecutableIndex::new(games),
+            games: games.iter().map(|game| (game.id.clone(), game.clone())).collect(),
+        };
+    }
+
+    /// Hands the library game started by `pid` (`exe_path`) to `register`,
+    /// unless it is already tracked.
+    ///
+    /// Games Balam launched are tracked from the launch on, so they are never
+    /// detected twice; neither is a process of a game already running.
+    pub fn detect<T>(
+        &self,
+        pid: u32,
+        exe_path: &str,
+        active_games: &ActiveGamesTracker,
+        register: impl FnOnce(Game) -> T,
+    ) -> Option<T> {
+        let _registering = self.registering.lock();
+        if active_games.find_by_pid(pid).is_some() {
+            return None;
+        }
+        let game = {
+            let library = self.library.read();
+            let game_id = library.index.find(exe_path)?;
+            if active_games.get(game_id).is_some() {
+                return None;
+            }
+            library.games.get(game_id)?.clone()
+        };
+        info!("🕹️ {} started outside Balam (PID {}, {})", game.title, pid, exe_path);
+        Some(register(game))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::active_games::ActiveGameInfo;
+    use crate::domain::value_objects::game_source::GameSource;
+
+    #[test]
+    fn test_tracked_games_are_not_detected_again() {
+        let detector = ExternalGameDetector::new();
+        detector.set_library(&[Game::new(
+            "epic_1".to_string(),
+            "epic_1".to_string(),
+            "Game".to_string(),
+            "C:\\Epic\\Game\\Game.exe".to_string(),
+            GameSource::Epic,
+        )]);
+        let tracker = ActiveGamesTracker::new();
+        let register = |game: Game| {
+            tracker.register(
+                game.id.clone(),
+                ActiveGameInfo {
+                    path: game.path.clone(),
+                    game,
+                    pid: Some(42),
+                    suspended: false,
+                    started_at: 0,
+                },
+            );
+        };
+
+        assert!(detector
+            .detect(7, "C:\\Windows\\notepad.exe", &tracker, register)
+            .is_none());
+        assert!(detector
+            .detect(42, "C:\\Epic\\Game\\Game.exe", &tracker, register)
+            .is_some());
+        assert!(tracker.get("epic_1").is_some());
+        // The game's own child processes are not a second session
+        assert!(detector
+            .detect(43, "C:\\Epic\\Game\\Binaries\\Game-Win64.exe", &tracker, register)
+            .is_none());
+    }
+}
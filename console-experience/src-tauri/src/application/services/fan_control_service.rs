@@ -0,0 +1,254 @@
+use crate::domain::thermal::{FanCurve, FanStatus};
+use crate::domain::BalamError;
+use crate::ports::thermal_port::ThermalPort;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+/// How often the software loop re-reads the temperature.
+const TICK: Duration = Duration::from_secs(2);
+/// Loop considered stalled (and the fan handed back) after this long without a tick.
+const STALL_TIMEOUT: Duration = Duration::from_secs(10);
+/// Consecutive failed temperature reads before giving up on the curve.
+const MAX_READ_FAILURES: u32 = 3;
+
+/// Applies custom fan curves and guarantees the firmware gets control back.
+///
+/// Firmware-run curves are handed over once. Devices that only take a fixed
+/// duty get a control loop; if the loop exits (error, panic, `restore`) or
+/// stalls for [`STALL_TIMEOUT`], the fan is returned to automatic.
+pub struct FanControlService {
+    port: Arc<dyn ThermalPort>,
+    session: Mutex<Option<CurveSession>>,
+    tick: Duration,
+}
+
+/// A running custom curve.
+struct CurveSession {
+    stop: Arc<AtomicBool>,
+}
+
+/// Returns the fan to automatic when the control loop ends, however it ends.
+struct RevertOnDrop(Arc<dyn ThermalPort>);
+
+impl Drop for RevertOnDrop {
+    fn drop(&mut self) {
+        match self.0.restore_auto() {
+            Ok(()) => info!("🌀 Fan returned to automatic control"),
+            Err(e) => error!("Failed to restore automatic fan control: {}", e),
+        }
+    }
+}
+
+impl FanControlService {
+    #[must_use]
+    pub fn new(port: Arc<dyn ThermalPort>) -> Self {
+        Self {
+            port,
+            session: Mutex::new(None),
+            tick: TICK,
+        }
+    }
+
+    /// `true` if the device's fan can be controlled.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        self.port.supports_fan_control()
+    }
+
+    /// `true` while a custom curve is in effect.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.session
+            .lock()
+            .as_ref()
+            .is_some_and(|session| !session.stop.load(Ordering::SeqCst))
+    }
+
+    /// Fan speed, temperature and whether a custom curve is active.
+    #[must_use]
+    pub fn status(&self) -> FanStatus {
+        FanStatus {
+            model: self.port.model(),
+            rpm: self.port.fan_rpm().ok(),
+            cpu_temp_c: self.port.cpu_temperature().ok(),
+            custom_curve_active: self.is_active(),
+        }
+    }
+
+    /// Validates and applies `curve`, replacing any running one.
+    ///
+    /// # Errors
+    /// Returns error if the curve is unsafe, the device is unsupported, or
+    /// the firmware rejects it.
+    pub fn apply(&self, curve: FanCurve) -> Result<(), BalamError> {
+        curve.validate().map_err(BalamError::InvalidArgument)?;
+        if !self.port.supports_fan_control() {
+            return Err(BalamError::NotSupported("Fan control on this device".to_string()));
+        }
+        self.stop_session();
+
+        let stop = Arc::new(AtomicBool::new(false));
+        if self.port.supports_hardware_curve() {
+            self.port.apply_curve(&curve)?;
+            info!("🌀 Custom fan curve handed to firmware");
+        } else {
+            // Fail early instead of starting a loop that cannot read temperatures
+            self.port.cpu_temperature()?;
+            self.spawn_control_loop(curve, stop.clone());
+        }
+
+        *self.session.lock() = Some(CurveSession { stop });
+        Ok(())
+    }
+
+    /// Stops the custom curve and returns the fan to automatic.
+    ///
+    /// # Errors
+    /// Returns error if the firmware call fails.
+    pub fn restore(&self) -> Result<(), BalamError> {
+        self.stop_session();
+        self.port.restore_auto()
+    }
+
+    fn stop_session(&self) {
+        if let Some(session) = self.session.lock().take() {
+            session.stop.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn spawn_control_loop(&self, curve: FanCurve, stop: Arc<AtomicBool>) {
+        let port = self.port.clone();
+        let tick = self.tick;
+        let started = Instant::now();
+        let last_tick_ms = Arc::new(AtomicU64::new(0));
+
+        let loop_stop = stop.clone();
+        let loop_tick = last_tick_ms.clone();
+        thread::spawn(move || {
+            let _revert = RevertOnDrop(port.clone());
+            let mut failures = 0;
+            info!("🌀 Fan control loop started");
+
+            while !loop_stop.load(Ordering::SeqCst) {
+                match port.cpu_temperature() {
+                    Ok(temp) => {
+                        failures = 0;
+                        if let Err(e) = port.set_fan_duty(curve.duty_at(temp)) {
+                            warn!("Fan duty write failed, stopping custom curve: {}", e);
+                            break;
+                        }
+                    },
+                    Err(e) => {
+                        failures += 1;
+                        warn!(
+                            "CPU temperature unavailable ({}/{}): {}",
+                            failures, MAX_READ_FAILURES, e
+                        );
+                        if failures >= MAX_READ_FAILURES {
+                            break;
+                        }
+                    },
+                }
+                loop_tick.store(started.elapsed().as_millis() as u64, Ordering::SeqCst);
+                thread::sleep(tick);
+            }
+            loop_stop.store(true, Ordering::SeqCst);
+        });
+
+        // Watchdog: a loop stuck in a driver call never reaches its revert guard
+        let port = self.port.clone();
+        thread::spawn(move || {
+            while !stop.load(Ordering::SeqCst) {
+                thread::sleep(tick);
+                let since_tick = started
+                    .elapsed()
+                    .saturating_sub(Duration::from_millis(last_tick_ms.load(Ordering::SeqCst)));
+                if since_tick > STALL_TIMEOUT {
+                    error!(
+                        "Fan control loop stalled for {:?}, restoring automatic control",
+                        since_tick
+                    );
+                    stop.store(true, Ordering::SeqCst);
+                    let _ = port.restore_auto();
+                }
+            }
+        });
+    }
+}
+
+impl Drop for FanControlService {
+    fn drop(&mut self) {
+        self.stop_session();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::thermal::HandheldModel;
+    use std::sync::atomic::AtomicU32;
+
+    /// Duty-only device whose temperature reads fail after `readable` calls.
+    struct FakeFan {
+        readable: u32,
+        reads: AtomicU32,
+        duty: AtomicU32,
+        restored: AtomicBool,
+    }
+
+    impl ThermalPort for FakeFan {
+        fn model(&self) -> HandheldModel {
+            HandheldModel::GpdWin
+        }
+        fn fan_rpm(&self) -> Result<u32, BalamError> {
+            Ok(3000)
+        }
+        fn cpu_temperature(&self) -> Result<f32, BalamError> {
+            if self.reads.fetch_add(1, Ordering::SeqCst) < self.readable {
+                Ok(70.0)
+            } else {
+                Err(BalamError::Platform("sensor gone".to_string()))
+            }
+        }
+        fn supports_hardware_curve(&self) -> bool {
+            false
+        }
+        fn apply_curve(&self, _curve: &FanCurve) -> Result<(), BalamError> {
+            unreachable!()
+        }
+        fn set_fan_duty(&self, percent: u8) -> Result<(), BalamError> {
+            self.duty.store(u32::from(percent), Ordering::SeqCst);
+            Ok(())
+        }
+        fn restore_auto(&self) -> Result<(), BalamError> {
+            self.restored.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_control_loop_applies_curve_and_reverts_when_it_stops() {
+        let fan = Arc::new(FakeFan {
+            readable: 2,
+            reads: AtomicU32::new(0),
+            duty: AtomicU32::new(0),
+            restored: AtomicBool::new(false),
+        });
+        let mut service = FanControlService::new(fan.clone());
+        service.tick = Duration::from_millis(20);
+
+        service.apply(FanCurve::default()).unwrap();
+        thread::sleep(Duration::from_millis(10));
+        assert_eq!(fan.duty.load(Ordering::SeqCst), 50);
+        assert!(service.is_active());
+
+        // Temperature reads start failing: the loop gives up and hands the fan back
+        thread::sleep(service.tick * (MAX_READ_FAILURES + 5));
+        assert!(fan.restored.load(Ordering::SeqCst));
+        assert!(!service.is_active());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::domain::focus::InputFocus;
+use crate::ports::foreground_port::ForegroundEventsPort;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Called when the input focus changes (the frontend shows who has the pad).
+pub type FocusListener = Box<dyn Fn(&InputFocus) + Send + Sync>;
+/// Called with the PID and executable of a foreground process that is not a
+/// tracked game; returns the game ID if it was registered as one.
+pub type UntrackedForegroundListener = Box<dyn Fn(u32, &str) -> Option<String> + Send + Sync>;
+
+/// Follows the foreground window and hands the controller over.
+///
+/// When a Steam game with Steam Input comes to the front, Balam's XInput
+/// navigation is paused so presses do not reach both the game and the
+/// launcher; it resumes as soon as a Balam window is in front again.
+/// Focusing a tracked game also makes it the focused session; other
+/// foreground processes are offered to `on_untracked` (external launches).
+pub struct FocusManager {
+    state: Mutex<InputFocus>,
+    /// Mirror of `state.navigation_suppressed`, read by the gamepad loop every poll
+    suppressed: AtomicBool,
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FocusManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(InputFocus::default()),
+            suppressed: AtomicBool::new(false),
+        }
+    }
+
+    #[must_use]
+    pub fn current(&self) -> InputFocus {
+        self.state.lock().clone()
+    }
+
+    /// Whether gamepad navigation of the main window is paused.
+    #[must_use]
+    pub fn navigation_suppressed(&self) -> bool {
+        self.suppressed.load(Ordering::Relaxed)
+    }
+
+    /// Applies a new focus; returns `true` if anything changed.
+    pub fn apply(&self, focus: InputFocus) -> bool {
+        let mut state = self.state.lock();
+        if *state == focus {
+            return false;
+        }
+        if focus.navigation_suppressed != state.navigation_suppressed {
+            if focus.navigation_suppressed {
+                info!("🎮 Steam Input game in front, pausing Balam navigation");
+            } else {
+                info!("🎮 Balam navigation resumed");
+            }
+        }
+        self.suppressed.store(focus.navigation_suppressed, Ordering::Relaxed);
+        *state = focus;
+        true
+    }
+
+    /// Subscribes to foreground changes.
+    pub fn start(
+        self: &Arc<Self>,
+        foreground: Box<dyn ForegroundEventsPort>,
+        active_games: Arc<ActiveGamesTracker>,
+        on_untracked: UntrackedForegroundListener,
+        on_change: FocusListener,
+    ) {
+        let service = Arc::clone(self);
+        let own_pid = std::process::id();
+        let foreground: Arc<dyn ForegroundEventsPort> = Arc::from(foreground);
+        let port = Arc::clone(&foreground);
+
+        let subscribed = foreground.subscribe(Arc::new(move |pid| {
+            let game_id = active_games.find_by_pid(pid).map(|(id, _)| id).or_else(|| {
+                // A game started outside Balam coming to the front
+                (pid != own_pid)
+                    .then(|| port.executable_path(pid))
+                    .flatten()
+                    .and_then(|path| on_untracked(pid, &path))
+            });
+            if let Some(game_id) = &game_id {
+                active_games.set_focused(game_id);
+            }
+            // Balam itself never counts: Steam may hook it too when launched from Steam
+            let steam_input = pid != own_pid && port.uses_steam_input(pid);
+            let focus = InputFocus::resolve(pid, own_pid, steam_input, game_id);
+            if service.apply(focus) {
+                on_change(&service.current());
+            }
+        }));
+        if !subscribed {
+            warn!("Foreground tracking unavailable, gamepad navigation stays on");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_mirrors_suppression() {
+        let manager = FocusManager::new();
+        assert!(manager.apply(InputFocus::resolve(4242, 100, true, None)));
+        assert!(manager.navigation_suppressed());
+        assert!(!manager.apply(InputFocus::resolve(4242, 100, true, None)));
+
+        assert!(manager.apply(InputFocus::resolve(100, 100, false, None)));
+        assert!(!manager.navigation_suppressed());
+    }
+}
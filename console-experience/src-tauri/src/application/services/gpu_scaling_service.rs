@@ -0,0 +1,134 @@
+use crate::domain::scaling::{GameScalingProfile, ScalingCapabilities, ScalingState};
+use crate::domain::BalamError;
+use crate::ports::scaling_port::GpuScalingPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tracing::info;
+
+/// Applies per-game driver upscaling and puts the player's setting back.
+///
+/// The state found before the first profiled game is kept until `restore`,
+/// so switching between profiled games never loses the original setting.
+pub struct GpuScalingService {
+    port: Arc<dyn GpuScalingPort>,
+    saved: Mutex<Option<ScalingState>>,
+}
+
+impl GpuScalingService {
+    #[must_use]
+    pub fn new(port: Arc<dyn GpuScalingPort>) -> Self {
+        Self {
+            port,
+            saved: Mutex::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn capabilities(&self) -> ScalingCapabilities {
+        self.port.capabilities()
+    }
+
+    /// Applies `profile`, remembering the state it replaces.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - No NVIDIA NIS / AMD RSR capable driver
+    /// - `BalamError::Platform` - Driver API call failed
+    pub fn apply(&self, profile: &GameScalingProfile) -> Result<(), BalamError> {
+        let current = self.port.state()?;
+        let target = profile.state_over(current);
+        {
+            let mut saved = self.saved.lock();
+            if saved.is_none() {
+                *saved = Some(current);
+            }
+        }
+        if target != current {
+            self.port.set_state(target)?;
+        }
+        info!(
+            "🔍 Driver upscaling {} (sharpness {:?})",
+            if target.enabled { "enabled" } else { "disabled" },
+            target.sharpness
+        );
+        Ok(())
+    }
+
+    /// Puts back the state saved by [`Self::apply`]; a no-op if nothing was applied.
+    ///
+    /// # Errors
+    /// Returns `BalamError::Platform` if the driver rejected the original state.
+    pub fn restore(&self) -> Result<(), BalamError> {
+        let Some(saved) = self.saved.lock().take() else {
+            return Ok(());
+        };
+        self.port.set_state(saved)?;
+        info!("🔍 Driver upscaling restored");
+        Ok(())
+    }
+
+    /// `true` while a game's profile replaced the player's setting.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.saved.lock().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::scaling::ScalingTechnology;
+
+    /// RSR-like driver with adjustable sharpness.
+    struct FakeDriver {
+        state: Mutex<ScalingState>,
+    }
+
+    impl GpuScalingPort for FakeDriver {
+        fn capabilities(&self) -> ScalingCapabilities {
+            ScalingCapabilities {
+                technology: Some(ScalingTechnology::Rsr),
+                adjustable_sharpness: true,
+            }
+        }
+        fn state(&self) -> Result<ScalingState, BalamError> {
+            Ok(*self.state.lock())
+        }
+        fn set_state(&self, state: ScalingState) -> Result<(), BalamError> {
+            *self.state.lock() = state;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_original_state_survives_profile_switches() {
+        let original = ScalingState {
+            enabled: false,
+            sharpness: Some(30),
+        };
+        let driver = Arc::new(FakeDriver {
+            state: Mutex::new(original),
+        });
+        let service = GpuScalingService::new(driver.clone());
+
+        service
+            .apply(&GameScalingProfile {
+                enabled: true,
+                sharpness: Some(80),
+            })
+            .unwrap();
+        service.apply(&GameScalingProfile::default()).unwrap();
+        assert_eq!(
+            *driver.state.lock(),
+            ScalingState {
+                enabled: true,
+                sharpness: Some(80)
+            }
+        );
+        assert!(service.is_active());
+
+        service.restore().unwrap();
+        assert_eq!(*driver.state.lock(), original);
+        assert!(!service.is_active());
+        service.restore().unwrap();
+    }
+}
@@ -0,0 +1,162 @@
+use crate::domain::haptic::{HapticEvent, HapticPattern};
+use crate::domain::settings::HapticSettings;
+use crate::ports::haptic_port::HapticPort;
+use parking_lot::{Condvar, Mutex};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Patterns waiting behind the one playing; older ones are dropped first,
+/// so fast navigation never builds up seconds of backlog.
+const MAX_QUEUED: usize = 3;
+
+struct HapticQueue {
+    pending: VecDeque<HapticPattern>,
+    /// Bumped by `cancel` to cut the pattern being played short
+    generation: u64,
+}
+
+/// Plays rumble patterns one after another.
+///
+/// `play` only queues; a worker thread hands each pattern to the port and
+/// waits for it to finish before starting the next. `cancel` empties the
+/// queue and stops the running pattern.
+pub struct HapticService {
+    port: Arc<dyn HapticPort>,
+    queue: Mutex<HapticQueue>,
+    wake: Condvar,
+}
+
+impl HapticService {
+    #[must_use]
+    pub fn new(port: Arc<dyn HapticPort>) -> Self {
+        Self {
+            port,
+            queue: Mutex::new(HapticQueue {
+                pending: VecDeque::new(),
+                generation: 0,
+            }),
+            wake: Condvar::new(),
+        }
+    }
+
+    /// Queues a pattern.
+    pub fn play(&self, pattern: HapticPattern) {
+        let mut queue = self.queue.lock();
+        if queue.pending.len() >= MAX_QUEUED {
+            queue.pending.pop_front();
+        }
+        queue.pending.push_back(pattern);
+        self.wake.notify_all();
+    }
+
+    /// Queues the pattern mapped to an event; returns `false` if the event does not rumble.
+    pub fn play_event(&self, event: HapticEvent, settings: &HapticSettings) -> bool {
+        match settings.pattern_for(event) {
+            Some(pattern) => {
+                self.play(pattern);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Drops queued patterns and stops the one playing.
+    pub fn cancel(&self) {
+        {
+            let mut queue = self.queue.lock();
+            queue.pending.clear();
+            queue.generation += 1;
+            self.wake.notify_all();
+        }
+        self.port.stop();
+    }
+
+    #[must_use]
+    pub fn queued(&self) -> usize {
+        self.queue.lock().pending.len()
+    }
+
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        self.port.is_supported()
+    }
+
+    /// Starts the playback thread.
+    pub fn start(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("haptics".to_string())
+            .spawn(move || loop {
+                let (pattern, generation) = service.next_pattern();
+                if let Err(e) = service.port.play(&pattern) {
+                    warn!("⚠️ Haptic pattern '{}' failed: {}", pattern.name, e);
+                    continue;
+                }
+                debug!("Haptic pattern '{}' started", pattern.name);
+                service.wait_until_done(Duration::from_millis(u64::from(pattern.duration_ms())), generation);
+            });
+        if let Err(e) = spawned {
+            warn!("Failed to start haptics thread: {}", e);
+        }
+    }
+
+    /// Blocks until a pattern is queued.
+    fn next_pattern(&self) -> (HapticPattern, u64) {
+        let mut queue = self.queue.lock();
+        loop {
+            if let Some(pattern) = queue.pending.pop_front() {
+                return (pattern, queue.generation);
+            }
+            self.wake.wait(&mut queue);
+        }
+    }
+
+    /// Sleeps for the pattern length, or less if it gets cancelled.
+    fn wait_until_done(&self, length: Duration, generation: u64) {
+        let deadline = Instant::now() + length;
+        let mut queue = self.queue.lock();
+        while queue.generation == generation {
+            if self.wake.wait_until(&mut queue, deadline).timed_out() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SilentPad;
+
+    impl HapticPort for SilentPad {
+        fn play(&self, _pattern: &HapticPattern) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn stop(&self) {}
+
+        fn is_supported(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn test_queue_is_bounded_and_cancellable() {
+        let service = HapticService::new(Arc::new(SilentPad));
+        for _ in 0..5 {
+            service.play(HapticPattern::builtin("navigation").unwrap());
+        }
+        assert_eq!(service.queued(), MAX_QUEUED);
+
+        let mut settings = HapticSettings::default();
+        settings.event_patterns.remove(&HapticEvent::Navigation);
+        assert!(!service.play_event(HapticEvent::Navigation, &settings));
+        assert!(service.play_event(HapticEvent::Screenshot, &settings));
+
+        service.cancel();
+        assert_eq!(service.queued(), 0);
+    }
+}
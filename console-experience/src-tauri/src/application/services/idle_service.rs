@@ -0,0 +1,157 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::application::services::SettingsService;
+use crate::domain::idle::{IdleStatus, SuspendReason, MAX_SLEEP_TIMER_MINUTES};
+use crate::domain::BalamError;
+use crate::ports::system_port::SystemPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+/// How often the monitor checks the idle time and the sleep timer.
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Called right before the device is suspended (the frontend shows a toast).
+pub type SuspendListener = Box<dyn Fn(SuspendReason) + Send + Sync>;
+
+/// Idle auto-suspend and the manual sleep timer.
+///
+/// The gamepad listener reports every input through [`IdleService::record_activity`];
+/// a running game counts as activity, so a game played with keyboard and
+/// mouse never gets the device suspended under it.
+pub struct IdleService {
+    last_input: Mutex<Instant>,
+    sleep_deadline: Mutex<Option<Instant>>,
+}
+
+impl Default for IdleService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdleService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            last_input: Mutex::new(Instant::now()),
+            sleep_deadline: Mutex::new(None),
+        }
+    }
+
+    /// Resets the idle time (gamepad input, game running, device resumed).
+    pub fn record_activity(&self) {
+        *self.last_input.lock() = Instant::now();
+    }
+
+    /// Suspends the device in `minutes`, replacing any running timer.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` outside 1..=480 minutes.
+    pub fn set_sleep_timer(&self, minutes: u32) -> Result<(), BalamError> {
+        if minutes == 0 || minutes > MAX_SLEEP_TIMER_MINUTES {
+            return Err(BalamError::InvalidArgument(format!(
+                "Sleep timer must be 1-{MAX_SLEEP_TIMER_MINUTES} minutes, got {minutes}"
+            )));
+        }
+        *self.sleep_deadline.lock() = Some(Instant::now() + Duration::from_secs(u64::from(minutes) * 60));
+        info!("💤 Sleep timer set for {} min", minutes);
+        Ok(())
+    }
+
+    pub fn cancel_sleep_timer(&self) {
+        if self.sleep_deadline.lock().take().is_some() {
+            info!("💤 Sleep timer cancelled");
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self, auto_suspend_minutes: u32) -> IdleStatus {
+        let now = Instant::now();
+        IdleStatus {
+            idle_seconds: now.duration_since(*self.last_input.lock()).as_secs(),
+            auto_suspend_minutes,
+            sleep_timer_remaining_seconds: self
+                .sleep_deadline
+                .lock()
+                .map(|deadline| deadline.saturating_duration_since(now).as_secs()),
+        }
+    }
+
+    /// Whether the device should be suspended at `now`.
+    ///
+    /// The sleep timer always wins; idle auto-suspend needs it enabled
+    /// (`auto_suspend_minutes > 0`) and no game running.
+    #[must_use]
+    pub fn suspend_due(&self, now: Instant, auto_suspend_minutes: u32, game_running: bool) -> Option<SuspendReason> {
+        if self.sleep_deadline.lock().is_some_and(|deadline| now >= deadline) {
+            return Some(SuspendReason::SleepTimer);
+        }
+        if auto_suspend_minutes == 0 || game_running {
+            return None;
+        }
+        let idle = now.saturating_duration_since(*self.last_input.lock());
+        (idle >= Duration::from_secs(u64::from(auto_suspend_minutes) * 60)).then_some(SuspendReason::Idle)
+    }
+
+    /// Starts the monitor thread that suspends the device when due.
+    pub fn start(
+        self: &Arc<Self>,
+        system: Arc<dyn SystemPort>,
+        settings: Arc<SettingsService>,
+        active_games: Arc<ActiveGamesTracker>,
+        on_suspend: SuspendListener,
+    ) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("idle-monitor".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(CHECK_INTERVAL);
+
+                let game_running = !active_games.list_active().is_empty();
+                if game_running {
+                    service.record_activity();
+                }
+                let auto_suspend_minutes = settings.get().idle.auto_suspend_minutes;
+                let Some(reason) = service.suspend_due(Instant::now(), auto_suspend_minutes, game_running) else {
+                    continue;
+                };
+
+                info!("💤 Suspending device ({:?})", reason);
+                service.cancel_sleep_timer();
+                on_suspend(reason);
+                if let Err(e) = system.suspend() {
+                    warn!("Failed to suspend: {}", e);
+                }
+                // Back from sleep (or suspend refused): start counting again
+                service.record_activity();
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start idle monitor: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspend_due_respects_games_and_sleep_timer() {
+        let service = IdleService::new();
+        let later = Instant::now() + Duration::from_secs(31 * 60);
+
+        assert_eq!(service.suspend_due(later, 30, false), Some(SuspendReason::Idle));
+        assert_eq!(service.suspend_due(later, 0, false), None);
+        assert_eq!(service.suspend_due(later, 30, true), None);
+        assert_eq!(service.suspend_due(Instant::now(), 30, false), None);
+
+        service.set_sleep_timer(10).unwrap();
+        assert_eq!(service.suspend_due(later, 0, true), Some(SuspendReason::SleepTimer));
+        service.cancel_sleep_timer();
+        assert_eq!(service.suspend_due(later, 0, true), None);
+
+        assert!(service.set_sleep_timer(0).is_err());
+    }
+}
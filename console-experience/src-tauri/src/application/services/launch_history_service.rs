@@ -0,0 +1,116 @@
+use crate::domain::launch_history::{LaunchHistory, LaunchOutcome, LaunchRecord, StoreLaunchStats};
+use crate::domain::GameSource;
+use crate::infrastructure::app_dirs;
+use crate::infrastructure::clock::now_secs;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tracing::{info, warn};
+
+const HISTORY_FILE: &str = "launch_history.json";
+
+/// Launch waiting for its outcome (the watchdog is still looking for the game).
+struct PendingLaunch {
+    source: GameSource,
+    started_at: u64,
+    started: Instant,
+}
+
+/// Records how every launch attempt ended, per store, on disk.
+///
+/// `begin` opens an attempt when a launch is requested; the first `finish`
+/// for the game closes it. Attempts still open when Balam exits are lost.
+pub struct LaunchHistoryService {
+    dir: PathBuf,
+    history: Mutex<LaunchHistory>,
+    pending: Mutex<HashMap<String, PendingLaunch>>,
+}
+
+impl LaunchHistoryService {
+    #[must_use]
+    pub fn new(data_dir: &Path) -> Self {
+        let history = fs::read_to_string(data_dir.join(HISTORY_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            dir: data_dir.to_path_buf(),
+            history: Mutex::new(history),
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// App local data directory (see `app_dirs::app_data_dir`).
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir()
+    }
+
+    /// Opens a launch attempt of `game_id` (replacing one never finished).
+    pub fn begin(&self, game_id: &str, source: GameSource) {
+        self.pending.lock().insert(
+            game_id.to_string(),
+            PendingLaunch {
+                source,
+                started_at: now_secs(),
+                started: Instant::now(),
+            },
+        );
+    }
+
+    /// Closes the open attempt of `game_id` with `outcome`.
+    ///
+    /// Without an open attempt only a quick exit is recorded, turning the
+    /// game's last successful launch into one; anything else is ignored
+    /// (the attempt was already closed by an earlier outcome).
+    pub fn finish(&self, game_id: &str, outcome: LaunchOutcome, failure_reason: Option<String>) {
+        let pending = self.pending.lock().remove(game_id);
+        let mut history = self.history.lock();
+        match pending {
+            Some(launch) => {
+                info!("🚀 Launch of {} recorded as {:?}", game_id, outcome);
+                history.push(LaunchRecord {
+                    game_id: game_id.to_string(),
+                    source: launch.source,
+                    started_at: launch.started_at,
+                    duration_ms: u64::try_from(launch.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+                    outcome,
+                    failure_reason,
+                });
+            },
+            None if outcome == LaunchOutcome::QuickExit => {
+                if !history.amend_quick_exit(game_id, failure_reason) {
+                    return;
+                }
+            },
+            None => return,
+        }
+        self.save(&history);
+    }
+
+    /// Launches of `game_id` (all games if `None`), newest first.
+    #[must_use]
+    pub fn records(&self, game_id: Option<&str>) -> Vec<LaunchRecord> {
+        self.history.lock().for_game(game_id)
+    }
+
+    /// Launch outcomes per store.
+    #[must_use]
+    pub fn stats(&self) -> Vec<StoreLaunchStats> {
+        self.history.lock().stats()
+    }
+
+    fn save(&self, history: &LaunchHistory) {
+        let result = fs::create_dir_all(&self.dir).and_then(|()| {
+            fs::write(
+                self.dir.join(HISTORY_FILE),
+                serde_json::to_string(history).unwrap_or_default(),
+            )
+        });
+        if let Err(e) = result {
+            warn!("Failed to save launch history: {}", e);
+        }
+    }
+}
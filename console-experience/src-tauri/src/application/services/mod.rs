@@ -3,4 +3,56 @@
 // Event-driven services that coordinate between adapters and domain logic.
 // Services listen to events and orchestrate cross-cutting concerns.
 
-// No services currently defined
+pub mod alert_service;
+pub mod artwork_service;
+pub mod auto_tdp_service;
+pub mod benchmark_service;
+pub mod dashboard_service;
+pub mod external_game_detector;
+pub mod fan_control_service;
+pub mod focus_manager;
+pub mod gpu_scaling_service;
+pub mod haptic_service;
+pub mod idle_service;
+pub mod launch_history_service;
+pub mod network_quality_service;
+pub mod overlay_renderer_monitor;
+pub mod parental_service;
+pub mod process_inspection_service;
+pub mod quiet_mode_service;
+pub mod replay_service;
+pub mod session_stats_service;
+pub mod settings_service;
+pub mod stutter_service;
+pub mod system_status_service;
+pub mod telemetry_service;
+pub mod text_input_service;
+pub mod update_service;
+
+pub use alert_service::{AlertListener, AlertReadingSource, AlertService};
+pub use artwork_service::{ArtworkFetcher, ArtworkReadyListener, ArtworkService};
+pub use auto_tdp_service::{AutoTdpService, FpsSource, TdpSetter};
+pub use benchmark_service::{
+    BenchmarkFinishedListener, BenchmarkProgressListener, BenchmarkService, BenchmarkTarget, MetricsSource,
+};
+pub use dashboard_service::{DashboardListener, DashboardService, UpdateSummarySource};
+pub use external_game_detector::ExternalGameDetector;
+pub use fan_control_service::FanControlService;
+pub use focus_manager::{FocusListener, FocusManager, UntrackedForegroundListener};
+pub use gpu_scaling_service::GpuScalingService;
+pub use haptic_service::HapticService;
+pub use idle_service::{IdleService, SuspendListener};
+pub use launch_history_service::LaunchHistoryService;
+pub use network_quality_service::{LatencySpikeListener, NetworkQualityService};
+pub use overlay_renderer_monitor::{OverlayRendererMonitor, RendererCheck};
+pub use parental_service::{ParentalService, TimeUpListener};
+pub use process_inspection_service::{ProcessInfo, ProcessInspectionService};
+pub use quiet_mode_service::QuietModeService;
+pub use replay_service::ReplayService;
+pub use session_stats_service::{SessionReadingSource, SessionStatsService};
+pub use settings_service::{SettingsGuard, SettingsListener, SettingsService};
+pub use stutter_service::{StutterFinishedListener, StutterService};
+pub use system_status_service::{SystemStatusListener, SystemStatusService};
+pub use telemetry_service::TelemetryService;
+pub use text_input_service::TextInputService;
+pub use update_service::{PreparedUpdate, UpdateService};
@@ -0,0 +1,165 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::application::services::SettingsService;
+use crate::domain::network_quality::{LatencySpike, LatencyWindow, NetworkQuality};
+use crate::ports::network_port::NetworkPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Time between pings while a game runs.
+const PING_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a ping waits for its reply (counted as lost after that).
+const PING_TIMEOUT: Duration = Duration::from_millis(900);
+/// How often the monitor checks for a game while idle.
+const IDLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Called when latency crosses `network.latency_spike_ms` (the overlay shows a warning).
+pub type LatencySpikeListener = Box<dyn Fn(LatencySpike) + Send + Sync>;
+
+struct MonitorState {
+    window: LatencyWindow,
+    target: String,
+    measuring: bool,
+    /// Above the threshold; the next spike fires only after latency recovers
+    spiking: bool,
+}
+
+/// In-game latency, jitter and packet loss to `network.ping_target`.
+///
+/// Pings only while a game is running, so the desktop UI never generates
+/// traffic of its own.
+pub struct NetworkQualityService {
+    state: Mutex<MonitorState>,
+}
+
+impl Default for NetworkQualityService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkQualityService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(MonitorState {
+                window: LatencyWindow::new(),
+                target: String::new(),
+                measuring: false,
+                spiking: false,
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn quality(&self) -> NetworkQuality {
+        let state = self.state.lock();
+        state.window.quality(&state.target, state.measuring)
+    }
+
+    /// Adds a ping result to the window.
+    ///
+    /// Returns the spike to report when this reply is the first one at or
+    /// above `threshold_ms`; lost pings neither fire nor re-arm the alert.
+    pub fn record(&self, target: &str, latency_ms: Option<f64>, threshold_ms: u32) -> Option<LatencySpike> {
+        let mut state = self.state.lock();
+        if state.target != target {
+            // New target: old samples say nothing about it
+            state.window.clear();
+            state.target = target.to_string();
+            state.spiking = false;
+        }
+        state.measuring = true;
+        state.window.push(latency_ms);
+
+        let latency_ms = latency_ms?;
+        let above = latency_ms >= f64::from(threshold_ms);
+        let fire = above && !state.spiking;
+        state.spiking = above;
+        fire.then(|| LatencySpike {
+            target: target.to_string(),
+            latency_ms,
+            threshold_ms,
+        })
+    }
+
+    /// Drops the statistics when the game ends.
+    pub fn stop_measuring(&self) {
+        let mut state = self.state.lock();
+        state.window.clear();
+        state.measuring = false;
+        state.spiking = false;
+    }
+
+    /// Starts the monitor thread.
+    pub fn start(
+        self: &Arc<Self>,
+        network: Arc<dyn NetworkPort>,
+        settings: Arc<SettingsService>,
+        active_games: Arc<ActiveGamesTracker>,
+        on_spike: LatencySpikeListener,
+    ) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("network-quality".to_string())
+            .spawn(move || loop {
+                if active_games.list_active().is_empty() {
+                    if service.state.lock().measuring {
+                        info!("📶 Game ended, latency monitor idle");
+                        service.stop_measuring();
+                    }
+                    std::thread::sleep(IDLE_INTERVAL);
+                    continue;
+                }
+
+                let network_settings = settings.get().network;
+                let latency_ms = match network.ping(&network_settings.ping_target, PING_TIMEOUT) {
+                    Ok(rtt) => rtt.map(|rtt| rtt.as_secs_f64() * 1000.0),
+                    Err(e) => {
+                        warn!("Ping to {} failed: {}", network_settings.ping_target, e);
+                        std::thread::sleep(IDLE_INTERVAL);
+                        continue;
+                    },
+                };
+                if let Some(spike) = service.record(
+                    &network_settings.ping_target,
+                    latency_ms,
+                    network_settings.latency_spike_ms,
+                ) {
+                    warn!("📶 Latency spike: {:.0}ms to {}", spike.latency_ms, spike.target);
+                    on_spike(spike);
+                }
+                std::thread::sleep(PING_INTERVAL);
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start network quality monitor: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spike_fires_once_until_latency_recovers() {
+        let service = NetworkQualityService::new();
+
+        assert!(service.record("1.1.1.1", Some(30.0), 150).is_none());
+        let spike = service.record("1.1.1.1", Some(180.0), 150).unwrap();
+        assert_eq!(spike.threshold_ms, 150);
+        assert!(service.record("1.1.1.1", Some(200.0), 150).is_none());
+        assert!(service.record("1.1.1.1", None, 150).is_none());
+        assert!(service.record("1.1.1.1", Some(40.0), 150).is_none());
+        assert!(service.record("1.1.1.1", Some(160.0), 150).is_some());
+
+        assert_eq!(service.quality().samples, 6);
+        service.record("8.8.8.8", Some(20.0), 150);
+        assert_eq!(service.quality().samples, 1);
+
+        service.stop_measuring();
+        assert!(!service.quality().measuring);
+    }
+}
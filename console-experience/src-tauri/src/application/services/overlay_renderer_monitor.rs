@@ -0,0 +1,124 @@
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Time between pings while the overlay is visible.
+pub const PING_INTERVAL: Duration = Duration::from_secs(1);
+/// The renderer counts as suspended after this long without a reply.
+pub const SUSPENDED_AFTER: Duration = Duration::from_secs(3);
+
+/// Script evaluated in the overlay: it only answers if its JS still runs.
+pub const PING_SCRIPT: &str = "window.__TAURI_INTERNALS__?.invoke('report_overlay_renderer_alive')";
+
+/// Outcome of one [`OverlayRendererMonitor::check`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RendererCheck {
+    /// Evaluate [`PING_SCRIPT`] in the overlay now
+    pub ping: bool,
+    /// `Some(suspended)` when the renderer's health just changed
+    pub changed: Option<bool>,
+}
+
+#[derive(Debug, Default)]
+struct RendererState {
+    visible_since: Option<Instant>,
+    last_ping: Option<Instant>,
+    last_reply: Option<Instant>,
+}
+
+/// Detects a suspended overlay WebView.
+///
+/// WebView2 may stop running JS in a window covered by a fullscreen game.
+/// While the overlay is visible it is pinged through `eval`; a renderer that
+/// has not answered for [`SUSPENDED_AFTER`] is treated as suspended and the
+/// gamepad listener switches to Rust-native navigation until it answers again.
+pub struct OverlayRendererMonitor {
+    state: Mutex<RendererState>,
+    native_nav: AtomicBool,
+}
+
+impl Default for OverlayRendererMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OverlayRendererMonitor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(RendererState::default()),
+            native_nav: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether overlay navigation must not rely on the WebView.
+    #[must_use]
+    pub fn native_nav(&self) -> bool {
+        self.native_nav.load(Ordering::Relaxed)
+    }
+
+    /// Reply to a ping (`report_overlay_renderer_alive`).
+    pub fn record_reply(&self) {
+        self.state.lock().last_reply = Some(Instant::now());
+    }
+
+    /// Called on every gamepad poll with the overlay's visibility.
+    pub fn check(&self, visible: bool, now: Instant) -> RendererCheck {
+        let mut state = self.state.lock();
+        let mut check = RendererCheck::default();
+        let suspended = if visible {
+            if state.visible_since.is_none() {
+                // A reply from an earlier showing says nothing about this one
+                *state = RendererState {
+                    visible_since: Some(now),
+                    ..RendererState::default()
+                };
+            }
+            if !state
+                .last_ping
+                .is_some_and(|ping| now.saturating_duration_since(ping) < PING_INTERVAL)
+            {
+                state.last_ping = Some(now);
+                check.ping = true;
+            }
+            let answered = state.last_reply.or(state.visible_since).unwrap_or(now);
+            now.saturating_duration_since(answered) >= SUSPENDED_AFTER
+        } else {
+            *state = RendererState::default();
+            false
+        };
+
+        if self.native_nav.swap(suspended, Ordering::Relaxed) != suspended {
+            check.changed = Some(suspended);
+        }
+        check
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspended_without_replies_until_one_arrives() {
+        let monitor = OverlayRendererMonitor::new();
+        let shown = Instant::now();
+
+        let first = monitor.check(true, shown);
+        assert!(first.ping);
+        assert_eq!(first.changed, None);
+        assert!(!monitor.check(true, shown + Duration::from_millis(500)).ping);
+
+        let silent = monitor.check(true, shown + SUSPENDED_AFTER);
+        assert_eq!(silent.changed, Some(true));
+        assert!(monitor.native_nav());
+
+        monitor.record_reply();
+        assert_eq!(monitor.check(true, Instant::now()).changed, Some(false));
+
+        // Hiding resets: a later showing starts healthy
+        monitor.check(false, Instant::now());
+        assert!(!monitor.native_nav());
+    }
+}
@@ -0,0 +1,391 @@
+use crate::application::active_games::{ActiveGameInfo, ActiveGamesTracker};
+use crate::application::services::SettingsGuard;
+use crate::domain::notification::{Notification, NotificationCategory, NotificationPriority};
+use crate::domain::parental::{
+    lockout_secs, validate_pin, ParentalControls, ParentalStatus, PinHash, PinLockout, PlaytimeLedger,
+    PLAYTIME_WARNINGS_MINUTES, UNLOCK_WINDOW_SECS,
+};
+use crate::domain::{BalamError, Game};
+use crate::infrastructure::app_dirs;
+use crate::infrastructure::clock::now_secs;
+use crate::infrastructure::json_file::{read_json, write_json};
+use crate::ports::NotificationPort;
+use parking_lot::{Mutex, RwLock};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{info, warn};
+
+const CONTROLS_FILE: &str = "parental.json";
+const PLAYTIME_FILE: &str = "parental_playtime.json";
+/// How often the monitor counts playtime of the active profile.
+const CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Called for every running session once the daily limit is used up.
+pub type TimeUpListener = Box<dyn Fn(&ActiveGameInfo) + Send + Sync>;
+
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// PIN lock, game restrictions and daily playtime limits.
+///
+/// Restricted screens check [`ParentalService::is_unlocked`]; a correct PIN
+/// opens them for a few minutes. The monitor thread counts the active
+/// profile's playtime while a game runs, warns before the limit and then
+/// hands every session to the time-up listener (the kill pipeline).
+pub struct ParentalService {
+    controls_path: PathBuf,
+    playtime_path: PathBuf,
+    controls: RwLock<ParentalControls>,
+    ledger: Mutex<PlaytimeLedger>,
+    unlocked_until: Mutex<Option<Instant>>,
+    /// Warning thresholds (minutes) already announced today
+    warned: Mutex<BTreeSet<u32>>,
+}
+
+impl ParentalService {
+    /// Loads the configuration and today's playtime from `data_dir`.
+    #[must_use]
+    pub fn new(data_dir: &Path) -> Self {
+        let controls_path = data_dir.join(CONTROLS_FILE);
+        let playtime_path = data_dir.join(PLAYTIME_FILE);
+        Self {
+            controls: RwLock::new(read_json(&controls_path)),
+            ledger: Mutex::new(read_json(&playtime_path)),
+            controls_path,
+            playtime_path,
+            unlocked_until: Mutex::new(None),
+            warned: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    /// App local data directory (see `app_dirs::app_data_dir`).
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir()
+    }
+
+    /// Whether a parent entered the PIN recently, or no PIN is set.
+    #[must_use]
+    pub fn is_unlocked(&self) -> bool {
+        self.controls.read().pin.is_none() || self.unlocked_until.lock().is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Fails unless [`Self::is_unlocked`].
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` while locked.
+    pub fn ensure_unlocked(&self) -> Result<(), BalamError> {
+        if self.is_unlocked() {
+            Ok(())
+        } else {
+            Err(BalamError::AccessDenied("Enter the parental PIN first".to_string()))
+        }
+    }
+
+    /// Fails when settings are PIN-locked and no parent unlocked them.
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` while settings are locked.
+    pub fn ensure_settings_unlocked(&self) -> Result<(), BalamError> {
+        if self.controls.read().lock_settings {
+            self.ensure_unlocked()
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Guard for `SettingsService`: every settings write needs
+    /// [`Self::ensure_settings_unlocked`].
+    #[must_use]
+    pub fn settings_guard(self: &Arc<Self>) -> SettingsGuard {
+        let parental = Arc::clone(self);
+        Box::new(move || parental.ensure_settings_unlocked().map_err(|e| e.to_string()))
+    }
+
+    #[must_use]
+    pub fn status(&self) -> ParentalStatus {
+        let unlocked = self.is_unlocked();
+        let controls = self.controls.read();
+        let mut ledger = self.ledger.lock();
+        ledger.roll_over(&today());
+        let profile = controls.active();
+        ParentalStatus {
+            pin_set: controls.pin.is_some(),
+            settings_locked: controls.lock_settings && !unlocked,
+            unlocked,
+            active_profile: profile.map(|p| p.id.clone()),
+            active_profile_name: profile.map(|p| p.name.clone()),
+            playtime_used_minutes: profile.map_or(0, |p| ledger.used(&p.id) / 60),
+            playtime_remaining_minutes: profile.and_then(|p| ledger.remaining(p)).map(|secs| secs.div_ceil(60)),
+            retry_after_secs: controls.lockout.retry_after.saturating_sub(now_secs()),
+        }
+    }
+
+    /// Checks `pin` and opens the restricted screens for a few minutes.
+    ///
+    /// Wrong PINs past the first few make the next attempt wait, up to
+    /// 15 minutes; the wait survives a restart.
+    ///
+    /// # Errors
+    /// - `NotFound` - No PIN is set
+    /// - `Timeout` - Too many wrong PINs, retry later
+    /// - `AccessDenied` - Wrong PIN
+    pub fn unlock(&self, pin: &str) -> Result<(), BalamError> {
+        let mut controls = self.controls.write();
+        let Some(hash) = controls.pin.clone() else {
+            return Err(BalamError::NotFound("Parental PIN".to_string()));
+        };
+        let now = now_secs();
+        if controls.lockout.retry_after > now {
+            return Err(BalamError::Timeout(format!(
+                "Too many wrong PINs, try again in {} s",
+                controls.lockout.retry_after - now
+            )));
+        }
+
+        if hash.matches(pin) {
+            controls.lockout = PinLockout::default();
+            self.save_controls(&controls)?;
+            *self.unlocked_until.lock() = Some(Instant::now() + Duration::from_secs(UNLOCK_WINDOW_SECS));
+            info!("🔓 Parental controls unlocked");
+            return Ok(());
+        }
+
+        controls.lockout.failures += 1;
+        controls.lockout.retry_after = now + lockout_secs(controls.lockout.failures);
+        self.save_controls(&controls)?;
+        warn!("🔒 Wrong parental PIN ({} in a row)", controls.lockout.failures);
+        Err(BalamError::AccessDenied("Wrong PIN".to_string()))
+    }
+
+    /// Closes the restricted screens before the unlock window ends.
+    pub fn lock(&self) {
+        *self.unlocked_until.lock() = None;
+    }
+
+    /// Sets or changes the PIN (`None` removes it and every restriction).
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` while locked, `InvalidArgument` for a malformed PIN.
+    pub fn set_pin(&self, pin: Option<&str>) -> Result<(), BalamError> {
+        self.ensure_unlocked()?;
+        let hash = pin
+            .map(|pin| {
+                validate_pin(pin).map_err(BalamError::InvalidArgument)?;
+                Ok::<_, BalamError>(PinHash::new(pin, &uuid::Uuid::new_v4().simple().to_string()))
+            })
+            .transpose()?;
+        let mut controls = self.controls.write();
+        controls.pin = hash;
+        controls.lockout = PinLockout::default();
+        self.save_controls(&controls)?;
+        // The parent who just chose the PIN goes on to configure profiles
+        *self.unlocked_until.lock() = controls
+            .pin
+            .as_ref()
+            .map(|_| Instant::now() + Duration::from_secs(UNLOCK_WINDOW_SECS));
+        info!(
+            "🔑 Parental PIN {}",
+            if controls.pin.is_some() { "set" } else { "removed" }
+        );
+        Ok(())
+    }
+
+    /// Configuration for the parent's screen (without the PIN hash).
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` while locked.
+    pub fn controls(&self) -> Result<ParentalControls, BalamError> {
+        self.ensure_unlocked()?;
+        Ok(ParentalControls {
+            pin: None,
+            ..self.controls.read().clone()
+        })
+    }
+
+    /// Replaces ratings, profiles and the settings lock; PIN and lockout are kept.
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` while locked, `InvalidArgument` for invalid profiles.
+    pub fn save(&self, update: ParentalControls) -> Result<ParentalControls, BalamError> {
+        self.ensure_unlocked()?;
+        update.validate().map_err(BalamError::InvalidArgument)?;
+        let mut controls = self.controls.write();
+        *controls = ParentalControls {
+            pin: controls.pin.clone(),
+            lockout: controls.lockout,
+            ..update
+        };
+        self.save_controls(&controls)?;
+        self.warned.lock().clear();
+        info!(
+            "👪 Parental controls updated (active profile: {:?})",
+            controls.active_profile
+        );
+        Ok(ParentalControls {
+            pin: None,
+            ..controls.clone()
+        })
+    }
+
+    fn save_controls(&self, controls: &ParentalControls) -> Result<(), BalamError> {
+        write_json(&self.controls_path, controls)
+    }
+
+    /// Games the active profile may see.
+    #[must_use]
+    pub fn filter_games(&self, games: Vec<Game>) -> Vec<Game> {
+        let controls = self.controls.read();
+        games
+            .into_iter()
+            .filter(|game| controls.is_game_allowed(&game.id))
+            .collect()
+    }
+
+    /// Fails when the active profile may not start `game_id` now.
+    ///
+    /// # Errors
+    /// Returns `AccessDenied` for a restricted game or when today's time is used up.
+    pub fn check_launch(&self, game_id: &str) -> Result<(), BalamError> {
+        let controls = self.controls.read();
+        if !controls.is_game_allowed(game_id) {
+            return Err(BalamError::AccessDenied("This game is restricted".to_string()));
+        }
+        let Some(profile) = controls.active() else {
+            return Ok(());
+        };
+        let mut ledger = self.ledger.lock();
+        ledger.roll_over(&today());
+        if ledger.remaining(profile) == Some(0) {
+            return Err(BalamError::AccessDenied("Today's playtime is used up".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Adds `elapsed` to the active profile and returns the seconds left today
+    /// (`None` without an active, limited profile).
+    fn record_playtime(&self, elapsed: Duration) -> Option<u64> {
+        let controls = self.controls.read();
+        let profile = controls.active()?;
+        let mut ledger = self.ledger.lock();
+        let day = today();
+        if ledger.day != day {
+            self.warned.lock().clear();
+        }
+        ledger.roll_over(&day);
+        ledger.add(&profile.id, elapsed.as_secs());
+        if let Err(e) = write_json(&self.playtime_path, &*ledger) {
+            warn!("Failed to save playtime: {}", e);
+        }
+        ledger.remaining(profile)
+    }
+
+    /// Warning threshold (minutes) newly crossed with `remaining` seconds left.
+    fn due_warning(&self, remaining: u64) -> Option<u32> {
+        let minutes = PLAYTIME_WARNINGS_MINUTES
+            .into_iter()
+            .filter(|threshold| remaining <= u64::from(*threshold) * 60)
+            .min()?;
+        self.warned.lock().insert(minutes).then_some(minutes)
+    }
+
+    /// Starts the monitor thread counting playtime while a game runs.
+    pub fn start(
+        self: &Arc<Self>,
+        active_games: Arc<ActiveGamesTracker>,
+        notifications: Arc<dyn NotificationPort>,
+        on_time_up: TimeUpListener,
+    ) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("parental-monitor".to_string())
+            .spawn(move || {
+                let mut last_check = Instant::now();
+                loop {
+                    std::thread::sleep(CHECK_INTERVAL);
+                    let elapsed = last_check.elapsed();
+                    last_check = Instant::now();
+
+                    let sessions = active_games.list_sessions();
+                    if sessions.is_empty() {
+                        continue;
+                    }
+                    let Some(remaining) = service.record_playtime(elapsed) else {
+                        continue;
+                    };
+
+                    if remaining == 0 {
+                        info!("⏰ Daily playtime used up, closing {} game(s)", sessions.len());
+                        notifications.notify(Notification::new(
+                            NotificationCategory::System,
+                            NotificationPriority::Critical,
+                            "Playtime is over",
+                            "Today's playtime is used up. The game is closing.",
+                        ));
+                        for (_, info) in &sessions {
+                            on_time_up(info);
+                        }
+                    } else if let Some(minutes) = service.due_warning(remaining) {
+                        notifications.notify(Notification::new(
+                            NotificationCategory::System,
+                            NotificationPriority::High,
+                            format!("{minutes} minutes of playtime left"),
+                            "Save your game: it closes when today's playtime is used up.",
+                        ));
+                    }
+                }
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start parental monitor: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::parental::ParentalProfile;
+    use std::fs;
+
+    #[test]
+    fn test_unlock_flow_and_rate_limit() {
+        let dir = std::env::temp_dir().join(format!("balam-parental-{}", uuid::Uuid::new_v4().simple()));
+        let service = ParentalService::new(&dir);
+        assert!(service.is_unlocked());
+
+        service.set_pin(Some("2468")).unwrap();
+        service.lock();
+        assert!(!service.is_unlocked());
+        assert!(service.controls().is_err());
+
+        for _ in 0..3 {
+            assert!(matches!(service.unlock("0000"), Err(BalamError::AccessDenied(_))));
+        }
+        // Third failure starts the wait: even the right PIN is refused
+        assert!(matches!(service.unlock("2468"), Err(BalamError::Timeout(_))));
+        assert!(ParentalService::new(&dir).status().retry_after_secs > 0);
+
+        service.controls.write().lockout = PinLockout::default();
+        service.unlock("2468").unwrap();
+        let saved = service
+            .save(ParentalControls {
+                profiles: vec![ParentalProfile {
+                    id: "kids".to_string(),
+                    name: "Kids".to_string(),
+                    daily_limit_minutes: Some(0),
+                    ..ParentalProfile::default()
+                }],
+                active_profile: Some("kids".to_string()),
+                ..ParentalControls::default()
+            })
+            .unwrap();
+        assert!(saved.pin.is_none());
+        assert!(service.check_launch("steam_1").is_err());
+
+        let _ = fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,167 @@
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, System, UpdateKind};
+
+/// A process list younger than this is served as is
+pub const PROCESS_LIST_MAX_AGE: Duration = Duration::from_millis(500);
+
+/// One running process, as of the last refresh.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parent: Option<u32>,
+    pub name: String,
+    /// `None` for protected processes
+    pub exe: Option<PathBuf>,
+    /// Seconds since the Unix epoch
+    pub start_time: u64,
+    pub memory_bytes: u64,
+    /// Bytes read and written since the process started
+    pub disk_total_bytes: u64,
+}
+
+struct Inspector {
+    system: System,
+    /// When the whole process list was last refreshed
+    refreshed_at: Option<Instant>,
+}
+
+/// What a refresh reads: no CPU usage, command lines or environments.
+fn refresh_kind() -> ProcessRefreshKind {
+    ProcessRefreshKind::new()
+        .with_memory()
+        .with_disk_usage()
+        .with_exe(UpdateKind::OnlyIfNotSet)
+}
+
+fn to_info(pid: Pid, process: &sysinfo::Process) -> ProcessInfo {
+    let disk = process.disk_usage();
+    ProcessInfo {
+        pid: pid.as_u32(),
+        parent: process.parent().map(Pid::as_u32),
+        name: process.name().to_string(),
+        exe: process.exe().map(PathBuf::from),
+        start_time: process.start_time(),
+        memory_bytes: process.memory(),
+        disk_total_bytes: disk.total_read_bytes + disk.total_written_bytes,
+    }
+}
+
+/// One process table for the whole app.
+///
+/// Building a `System` and reading every process costs 100–300 ms; kills,
+/// watchdogs and pre-flight checks share this one instead. The full list is
+/// refreshed at most every [`PROCESS_LIST_MAX_AGE`] however many callers ask,
+/// and single-PID questions only refresh that PID.
+pub struct ProcessInspectionService {
+    inspector: Mutex<Inspector>,
+    max_age: Duration,
+}
+
+impl Default for ProcessInspectionService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ProcessInspectionService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_max_age(PROCESS_LIST_MAX_AGE)
+    }
+
+    #[must_use]
+    pub fn with_max_age(max_age: Duration) -> Self {
+        Self {
+            inspector: Mutex::new(Inspector {
+                system: System::new(),
+                refreshed_at: None,
+            }),
+            max_age,
+        }
+    }
+
+    /// The app-wide instance (also held by the DI container), for code that
+    /// runs without access to the container.
+    #[must_use]
+    pub fn shared() -> Arc<Self> {
+        static SHARED: OnceLock<Arc<ProcessInspectionService>> = OnceLock::new();
+        Arc::clone(SHARED.get_or_init(|| Arc::new(Self::new())))
+    }
+
+    /// Every running process.
+    #[must_use]
+    pub fn processes(&self) -> Vec<ProcessInfo> {
+        let mut inspector = self.inspector.lock();
+        if inspector.refreshed_at.is_none_or(|at| at.elapsed() >= self.max_age) {
+            inspector.system.refresh_processes_specifics(refresh_kind());
+            inspector.refreshed_at = Some(Instant::now());
+        }
+        inspector
+            .system
+            .processes()
+            .iter()
+            .map(|(&pid, process)| to_info(pid, process))
+            .collect()
+    }
+
+    /// First running process matching `predicate`.
+    #[must_use]
+    pub fn find(&self, predicate: impl Fn(&ProcessInfo) -> bool) -> Option<ProcessInfo> {
+        self.processes().into_iter().find(|process| predicate(process))
+    }
+
+    /// Whether a process named `name` runs (case-insensitive).
+    #[must_use]
+    pub fn is_name_running(&self, name: &str) -> bool {
+        self.find(|process| process.name.eq_ignore_ascii_case(name)).is_some()
+    }
+
+    /// One process, read right now (only this PID is refreshed).
+    #[must_use]
+    pub fn process(&self, pid: u32) -> Option<ProcessInfo> {
+        let pid = Pid::from_u32(pid);
+        let mut inspector = self.inspector.lock();
+        if !inspector.system.refresh_process_specifics(pid, refresh_kind()) {
+            return None;
+        }
+        inspector.system.process(pid).map(|process| to_info(pid, process))
+    }
+
+    #[must_use]
+    pub fn is_running(&self, pid: u32) -> bool {
+        self.process(pid).is_some()
+    }
+
+    /// Terminates a process; `false` if it is gone or could not be killed.
+    ///
+    /// The PID is refreshed first, so a process that exited is never
+    /// confused with a stale entry.
+    pub fn kill(&self, pid: u32) -> bool {
+        let pid = Pid::from_u32(pid);
+        let mut inspector = self.inspector.lock();
+        if !inspector.system.refresh_process_specifics(pid, refresh_kind()) {
+            return false;
+        }
+        inspector.system.process(pid).is_some_and(sysinfo::Process::kill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_the_current_process() {
+        let processes = ProcessInspectionService::new();
+        let own_pid = std::process::id();
+
+        let own = processes.process(own_pid).unwrap();
+        assert!(own.start_time > 0);
+        assert!(processes.processes().iter().any(|process| process.pid == own_pid));
+        assert!(processes.is_name_running(&own.name));
+        assert!(!processes.is_name_running("NonExistentGameProcess_12345.exe"));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::domain::quiet_mode::{AppliedPreset, PresetStep, QuietModeStatus};
+use crate::domain::BalamError;
+use parking_lot::Mutex;
+use tracing::info;
+
+/// Whisper-quiet mode: holds the quiet preset while it is in effect.
+///
+/// The steps (TDP, fan, brightness, FPS cap) are built by the caller from
+/// what the device supports; the preset applies and restores them as one.
+pub struct QuietModeService {
+    applied: Mutex<Option<AppliedPreset>>,
+}
+
+impl Default for QuietModeService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl QuietModeService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            applied: Mutex::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self) -> QuietModeStatus {
+        self.applied
+            .lock()
+            .as_ref()
+            .map_or_else(QuietModeStatus::default, |preset| QuietModeStatus {
+                active: true,
+                applied: preset.step_names().into_iter().map(str::to_string).collect(),
+            })
+    }
+
+    /// Applies `steps` all together (nothing changes if one fails).
+    ///
+    /// Already active: returns the current status without touching anything.
+    ///
+    /// # Errors
+    /// Returns the failing step's error; the steps before it are rolled back.
+    pub fn enable(&self, steps: Vec<Box<dyn PresetStep>>) -> Result<QuietModeStatus, BalamError> {
+        {
+            let mut applied = self.applied.lock();
+            if applied.is_none() {
+                let preset = AppliedPreset::apply(steps)?;
+                info!("🤫 Quiet mode on: {}", preset.step_names().join(", "));
+                *applied = Some(preset);
+            }
+        }
+        Ok(self.status())
+    }
+
+    /// Restores everything quiet mode changed.
+    ///
+    /// # Errors
+    /// Returns the first restore failure (the other changes are restored anyway).
+    pub fn disable(&self) -> Result<QuietModeStatus, BalamError> {
+        let preset = self.applied.lock().take();
+        if let Some(preset) = preset {
+            preset.restore()?;
+            info!("🤫 Quiet mode off");
+        }
+        Ok(self.status())
+    }
+}
@@ -0,0 +1,174 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::application::services::SettingsService;
+use crate::domain::media::{ReplayClip, ReplayStatus};
+use crate::domain::settings::ReplaySettings;
+use crate::domain::BalamError;
+use crate::ports::replay_port::{ReplayConfig, ReplayRecorderPort};
+use parking_lot::Mutex;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// How often the monitor follows the focused game and the settings.
+const MONITOR_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What the recorder was last asked to do.
+#[derive(Default)]
+struct RecorderState {
+    /// Process and encoder settings of the running recording
+    current: Option<(u32, ReplayConfig)>,
+    /// Process whose recording failed to start (retried quietly)
+    failed_pid: Option<u32>,
+}
+
+/// Keeps the replay buffer on the game in front while `replay.enabled` is on.
+pub struct ReplayService {
+    recorder: Arc<dyn ReplayRecorderPort>,
+    state: Mutex<RecorderState>,
+}
+
+impl ReplayService {
+    #[must_use]
+    pub fn new(recorder: Arc<dyn ReplayRecorderPort>) -> Self {
+        Self {
+            recorder,
+            state: Mutex::new(RecorderState::default()),
+        }
+    }
+
+    #[must_use]
+    pub fn status(&self, settings: &ReplaySettings) -> ReplayStatus {
+        ReplayStatus {
+            enabled: settings.enabled,
+            recording: self.recorder.recording_pid().is_some(),
+            buffered_seconds: self.recorder.buffered_seconds(),
+        }
+    }
+
+    /// Saves the last `seconds` of the buffer for `game_title`.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if nothing has been recorded, or the recorder's error.
+    pub fn save(&self, game_title: &str, seconds: u32) -> Result<ReplayClip, BalamError> {
+        self.recorder.save_clip(game_title, seconds)
+    }
+
+    /// Starts, restarts or stops the recorder for the game to record
+    /// (`None` when there is none or replays are off).
+    pub fn reconcile(&self, target_pid: Option<u32>, settings: &ReplaySettings) {
+        let config = ReplayConfig {
+            buffer_seconds: settings.buffer_seconds,
+            fps: settings.fps,
+            bitrate_kbps: settings.bitrate_kbps,
+        };
+        let wanted = target_pid.filter(|_| settings.enabled).map(|pid| (pid, config));
+
+        let mut state = self.state.lock();
+        // The recorder stops by itself when the game window closes
+        let alive = self.recorder.recording_pid().is_some();
+        if state.current == wanted && (alive || wanted.is_none()) {
+            return;
+        }
+
+        match wanted {
+            None => {
+                self.recorder.stop();
+                state.current = None;
+                state.failed_pid = None;
+            },
+            Some((pid, config)) => match self.recorder.start(pid, config) {
+                Ok(()) => {
+                    state.current = wanted;
+                    state.failed_pid = None;
+                },
+                Err(e) => {
+                    if state.failed_pid != Some(pid) {
+                        warn!("Replay buffer unavailable for PID {}: {}", pid, e);
+                    }
+                    state.current = None;
+                    state.failed_pid = Some(pid);
+                },
+            },
+        }
+    }
+
+    /// Starts the monitor thread.
+    pub fn start(self: &Arc<Self>, settings: Arc<SettingsService>, active_games: Arc<ActiveGamesTracker>) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("replay-monitor".to_string())
+            .spawn(move || loop {
+                let target = active_games
+                    .capture_target()
+                    .and_then(|info| info.pid)
+                    .filter(|pid| *pid != 0);
+                service.reconcile(target, &settings.get().replay);
+                std::thread::sleep(MONITOR_INTERVAL);
+            });
+
+        match spawned {
+            Ok(_) => info!("🎬 Replay monitor started"),
+            Err(e) => warn!("Failed to start replay monitor: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct FakeRecorder {
+        pid: Mutex<Option<u32>>,
+        starts: Mutex<u32>,
+    }
+
+    impl ReplayRecorderPort for FakeRecorder {
+        fn start(&self, pid: u32, _config: ReplayConfig) -> Result<(), BalamError> {
+            *self.pid.lock() = Some(pid);
+            *self.starts.lock() += 1;
+            Ok(())
+        }
+
+        fn stop(&self) {
+            *self.pid.lock() = None;
+        }
+
+        fn recording_pid(&self) -> Option<u32> {
+            *self.pid.lock()
+        }
+
+        fn buffered_seconds(&self) -> u32 {
+            0
+        }
+
+        fn save_clip(&self, _game_title: &str, _seconds: u32) -> Result<ReplayClip, BalamError> {
+            Err(BalamError::NotFound("Nothing recorded yet".to_string()))
+        }
+    }
+
+    #[test]
+    fn test_reconcile_follows_game_and_settings() {
+        let recorder = Arc::new(FakeRecorder::default());
+        let service = ReplayService::new(recorder.clone());
+        let mut settings = ReplaySettings {
+            enabled: true,
+            ..ReplaySettings::default()
+        };
+
+        service.reconcile(Some(42), &settings);
+        service.reconcile(Some(42), &settings);
+        assert_eq!(recorder.recording_pid(), Some(42));
+        assert_eq!(*recorder.starts.lock(), 1);
+
+        // New encoder settings restart the recording
+        settings.fps = 30;
+        service.reconcile(Some(42), &settings);
+        assert_eq!(*recorder.starts.lock(), 2);
+
+        settings.enabled = false;
+        service.reconcile(Some(42), &settings);
+        assert_eq!(recorder.recording_pid(), None);
+        assert!(!service.status(&settings).recording);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::application::active_games::{ActiveGameInfo, ActiveGamesTracker};
+use crate::domain::session_summary::{SessionReading, SessionStats, SessionSummary};
+use crate::infrastructure::clock::now_secs;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+/// Time between readings while a game runs.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Reads FPS, TDP, battery and temperatures.
+pub type SessionReadingSource = Box<dyn Fn() -> SessionReading + Send + Sync>;
+
+/// Collects what the post-game report card needs while games run.
+///
+/// Readings are system-wide, so every running game gets the same ones. The
+/// title is captured here since watchdogs unregister a game before
+/// `game-ended` is emitted.
+pub struct SessionStatsService {
+    stats: Mutex<HashMap<String, SessionStats>>,
+}
+
+impl Default for SessionStatsService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionStatsService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            stats: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Starts the sampling thread.
+    pub fn start(self: &Arc<Self>, active_games: Arc<ActiveGamesTracker>, readings: SessionReadingSource) {
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("session-stats".to_string())
+            .spawn(move || loop {
+                let sessions = active_games.list_sessions();
+                if !sessions.is_empty() {
+                    service.add(&sessions, &readings());
+                }
+                std::thread::sleep(SAMPLE_INTERVAL);
+            });
+
+        if let Err(e) = spawned {
+            warn!("Failed to start session stats sampler: {}", e);
+        }
+    }
+
+    fn add(&self, sessions: &[(String, ActiveGameInfo)], reading: &SessionReading) {
+        let mut stats = self.stats.lock();
+        for (game_id, info) in sessions {
+            stats
+                .entry(game_id.clone())
+                .or_insert_with(|| SessionStats::new(info.game.title.clone()))
+                .add(reading);
+        }
+    }
+
+    /// Ends the game's session and returns its report card (`None` if it was
+    /// never sampled, e.g. a game closed within seconds).
+    pub fn finish(&self, game_id: &str, duration_secs: u64) -> Option<SessionSummary> {
+        let stats = self.stats.lock().remove(game_id)?;
+        let ended_at = now_secs();
+        Some(stats.summary(game_id.to_string(), duration_secs, ended_at))
+    }
+}
@@ -0,0 +1,196 @@
+/**
+ * Settings Service
+ *
+ * Single source of truth for user configuration. Keeps an in-memory copy of
+ * `AppSettings` backed by a `SettingsRepository`, validates updates before
+ * persisting them and notifies in-process listeners on every change.
+ *
+ * Every write, whichever command makes it, first asks the guard (the
+ * parental settings lock), so no command can skip it.
+ *
+ * Frontend notification (`settings-changed` event) is done by the Tauri
+ * command layer, which owns the `AppHandle`.
+ */
+use parking_lot::RwLock;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::domain::settings::AppSettings;
+use crate::ports::settings_port::SettingsRepository;
+
+/// Callback invoked with the new settings after every successful update.
+pub type SettingsListener = Box<dyn Fn(&AppSettings) + Send + Sync>;
+
+/// Consulted before every save; an error refuses the change.
+pub type SettingsGuard = Box<dyn Fn() -> Result<(), String> + Send + Sync>;
+
+/// Application-wide settings store.
+pub struct SettingsService {
+    repository: Arc<dyn SettingsRepository>,
+    current: RwLock<AppSettings>,
+    listeners: RwLock<Vec<SettingsListener>>,
+    guard: RwLock<Option<SettingsGuard>>,
+}
+
+impl SettingsService {
+    /// Creates the service and loads persisted settings.
+    ///
+    /// Falls back to defaults if nothing is stored yet or the file is corrupt
+    /// (a corrupt file is left untouched until the next successful save).
+    pub fn new(repository: Arc<dyn SettingsRepository>) -> Self {
+        let current = match repository.load() {
+            Ok(Some(settings)) => {
+                info!("⚙️ Settings loaded (schema v{})", settings.schema_version);
+                settings
+            },
+            Ok(None) => {
+                info!("⚙️ No settings found, using defaults");
+                AppSettings::default()
+            },
+            Err(e) => {
+                warn!("Failed to load settings ({}), using defaults", e);
+                AppSettings::default()
+            },
+        };
+
+        Self {
+            repository,
+            current: RwLock::new(current),
+            listeners: RwLock::new(Vec::new()),
+            guard: RwLock::new(None),
+        }
+    }
+
+    /// Returns a snapshot of the current settings.
+    #[must_use]
+    pub fn get(&self) -> AppSettings {
+        self.current.read().clone()
+    }
+
+    /// Replaces all settings.
+    ///
+    /// # Errors
+    /// Returns error if the guard refuses the change, or validation or
+    /// persistence fails. In-memory settings are only changed once the new
+    /// values are safely on disk.
+    pub fn replace(&self, settings: AppSettings) -> Result<AppSettings, String> {
+        if let Some(guard) = self.guard.read().as_ref() {
+            guard()?;
+        }
+        settings.validate()?;
+        self.repository.save(&settings)?;
+
+        *self.current.write() = settings.clone();
+        self.notify(&settings);
+
+        Ok(settings)
+    }
+
+    /// Applies an in-place modification to a copy of the current settings and
+    /// persists the result.
+    ///
+    /// # Errors
+    /// Returns error if the guard refuses the change, or the modified settings
+    /// are invalid or cannot be saved.
+    pub fn update<F>(&self, modify: F) -> Result<AppSettings, String>
+    where
+        F: FnOnce(&mut AppSettings),
+    {
+        let mut settings = self.get();
+        modify(&mut settings);
+        self.replace(settings)
+    }
+
+    /// Restores default settings.
+    ///
+    /// # Errors
+    /// Returns error if the guard refuses the change or the defaults cannot be
+    /// saved.
+    pub fn reset(&self) -> Result<AppSettings, String> {
+        self.replace(AppSettings::default())
+    }
+
+    /// Installs the check run before every save (replaces the previous one).
+    pub fn set_guard(&self, guard: SettingsGuard) {
+        *self.guard.write() = Some(guard);
+    }
+
+    /// Registers a listener called after every successful change.
+    pub fn subscribe(&self, listener: SettingsListener) {
+        self.listeners.write().push(listener);
+    }
+
+    fn notify(&self, settings: &AppSettings) {
+        for listener in self.listeners.read().iter() {
+            listener(settings);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Default)]
+    struct MemoryRepository {
+        stored: Mutex<Option<AppSettings>>,
+    }
+
+    impl SettingsRepository for MemoryRepository {
+        fn load(&self) -> Result<Option<AppSettings>, String> {
+            Ok(self.stored.lock().clone())
+        }
+
+        fn save(&self, settings: &AppSettings) -> Result<(), String> {
+            *self.stored.lock() = Some(settings.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_defaults_when_repository_empty() {
+        let service = SettingsService::new(Arc::new(MemoryRepository::default()));
+        assert_eq!(service.get(), AppSettings::default());
+    }
+
+    #[test]
+    fn test_update_persists_and_notifies() {
+        let repo = Arc::new(MemoryRepository::default());
+        let service = SettingsService::new(repo.clone());
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        service.subscribe(Box::new(move |_| {
+            calls_clone.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        let updated = service.update(|s| s.overlay.opacity = 0.6).unwrap();
+
+        assert!((updated.overlay.opacity - 0.6).abs() < f64::EPSILON);
+        assert_eq!(repo.stored.lock().clone(), Some(updated));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_guard_refuses_every_write() {
+        let repo = Arc::new(MemoryRepository::default());
+        let service = SettingsService::new(repo.clone());
+        service.set_guard(Box::new(|| Err("locked".to_string())));
+
+        assert_eq!(service.update(|s| s.overlay.opacity = 0.6), Err("locked".to_string()));
+        assert!(service.reset().is_err());
+        assert_eq!(repo.stored.lock().clone(), None);
+    }
+
+    #[test]
+    fn test_invalid_update_is_rejected() {
+        let service = SettingsService::new(Arc::new(MemoryRepository::default()));
+
+        let result = service.update(|s| s.overlay.opacity = 2.0);
+
+        assert!(result.is_err());
+        assert!((service.get().overlay.opacity - 0.98).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::application::active_games::ActiveGamesTracker;
+use crate::application::services::MetricsSource;
+use crate::domain::stutter::{StutterReport, StutterSample, MAX_STUTTER_SESSION_SECS, STUTTER_SAMPLE_INTERVAL_MS};
+use crate::domain::BalamError;
+use crate::infrastructure::clock::now_secs;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Finished sessions kept for `report` (oldest dropped first).
+const MAX_KEPT_SESSIONS: usize = 10;
+
+/// Called once with the final report when a session ends.
+pub type StutterFinishedListener = Box<dyn FnOnce(StutterReport) + Send>;
+
+struct StutterSession {
+    id: String,
+    game_id: String,
+    game_title: String,
+    started_at: u64,
+    recording: bool,
+    samples: Vec<StutterSample>,
+}
+
+impl StutterSession {
+    fn report(&self) -> StutterReport {
+        StutterReport::new(
+            self.id.clone(),
+            self.game_id.clone(),
+            self.game_title.clone(),
+            self.started_at,
+            self.recording,
+            &self.samples,
+        )
+    }
+}
+
+/// Stutter diagnostics mode: samples the running game in the background and
+/// reports its frame time spikes with what they coincided with.
+///
+/// One session at a time; it ends on `stop`, when the game exits or after
+/// [`MAX_STUTTER_SESSION_SECS`]. Reports of the last sessions stay in memory.
+pub struct StutterService {
+    sessions: Mutex<VecDeque<StutterSession>>,
+    /// Stop flag of the session in progress
+    active: Mutex<Option<Arc<AtomicBool>>>,
+}
+
+impl StutterService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sessions: Mutex::new(VecDeque::new()),
+            active: Mutex::new(None),
+        }
+    }
+
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        self.active.lock().is_some()
+    }
+
+    /// Starts sampling `game_id` on a worker thread and returns the session ID.
+    ///
+    /// # Errors
+    /// Returns an error if a session is already in progress.
+    pub fn start(
+        self: &Arc<Self>,
+        game_id: String,
+        game_title: String,
+        active_games: Arc<ActiveGamesTracker>,
+        metrics: MetricsSource,
+        on_finished: StutterFinishedListener,
+    ) -> Result<String, BalamError> {
+        let stop = Arc::new(AtomicBool::new(false));
+        {
+            let mut active = self.active.lock();
+            if active.is_some() {
+                return Err(BalamError::Platform(
+                    "Stutter diagnostics are already running".to_string(),
+                ));
+            }
+            *active = Some(Arc::clone(&stop));
+        }
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        {
+            let mut sessions = self.sessions.lock();
+            if sessions.len() >= MAX_KEPT_SESSIONS {
+                sessions.pop_front();
+            }
+            sessions.push_back(StutterSession {
+                id: id.clone(),
+                game_id: game_id.clone(),
+                game_title: game_title.clone(),
+                started_at: now_secs(),
+                recording: true,
+                samples: Vec::new(),
+            });
+        }
+
+        info!("🐢 Stutter diagnostics started for {}", game_title);
+        let service = Arc::clone(self);
+        let session_id = id.clone();
+        let spawned = std::thread::Builder::new()
+            .name("stutter-diagnostics".to_string())
+            .spawn(move || {
+                service.record(&session_id, &game_id, &active_games, &metrics, &stop);
+                *service.active.lock() = None;
+                if let Some(report) = service.finish(&session_id) {
+                    info!("🐢 Stutter diagnostics finished: {}", report.summary);
+                    on_finished(report);
+                }
+            });
+        if let Err(e) = spawned {
+            *self.active.lock() = None;
+            self.sessions.lock().retain(|session| session.id != id);
+            return Err(BalamError::platform("Failed to start stutter diagnostics", e));
+        }
+        Ok(id)
+    }
+
+    fn record(
+        &self,
+        session_id: &str,
+        game_id: &str,
+        active_games: &ActiveGamesTracker,
+        metrics: &MetricsSource,
+        stop: &AtomicBool,
+    ) {
+        let started = Instant::now();
+        let max_duration = Duration::from_secs(u64::from(MAX_STUTTER_SESSION_SECS));
+        while started.elapsed() < max_duration && !stop.load(Ordering::Relaxed) && active_games.get(game_id).is_some() {
+            let sample = StutterSample::from_metrics(started.elapsed().as_millis() as u64, &metrics());
+            match self.sessions.lock().iter_mut().find(|session| session.id == session_id) {
+                Some(session) => session.samples.push(sample),
+                None => return,
+            }
+            std::thread::sleep(Duration::from_millis(STUTTER_SAMPLE_INTERVAL_MS));
+        }
+    }
+
+    fn finish(&self, session_id: &str) -> Option<StutterReport> {
+        let mut sessions = self.sessions.lock();
+        let session = sessions.iter_mut().find(|session| session.id == session_id)?;
+        session.recording = false;
+        Some(session.report())
+    }
+
+    /// Stops the session in progress; its report stays available.
+    ///
+    /// Returns whether a session was in progress.
+    pub fn stop(&self) -> bool {
+        match self.active.lock().as_ref() {
+            Some(stop) => {
+                stop.store(true, Ordering::Relaxed);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Report of a session, analyzed up to now while it is still recording.
+    ///
+    /// # Errors
+    /// Returns `NotFound` for an unknown or dropped session.
+    pub fn report(&self, session_id: &str) -> Result<StutterReport, BalamError> {
+        self.sessions
+            .lock()
+            .iter()
+            .find(|session| session.id == session_id)
+            .map(StutterSession::report)
+            .ok_or_else(|| BalamError::NotFound(format!("Stutter session {session_id}")))
+    }
+}
+
+impl Default for StutterService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::performance::PerformanceMetrics;
+
+    #[test]
+    fn test_session_ends_when_game_is_not_running() {
+        let service = Arc::new(StutterService::new());
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let id = service
+            .start(
+                "steam_1".to_string(),
+                "Game".to_string(),
+                Arc::new(ActiveGamesTracker::new()),
+                Box::new(PerformanceMetrics::default),
+                Box::new(move |report| {
+                    let _ = sender.send(report);
+                }),
+            )
+            .unwrap();
+
+        let report = receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(report.session_id, id);
+        assert!(!report.recording);
+        assert_eq!(report.stutter_count, 0);
+        assert!(service.report(&id).is_ok());
+        assert!(service.report("unknown").is_err());
+        assert!(!service.stop());
+    }
+}
@@ -0,0 +1,255 @@
+/**
+ * System Status Service
+ *
+ * Push-based system status (battery, volume, network). Native change
+ * notifications from a `SystemEventsPort` wake a monitor thread that
+ * re-reads `SystemStatus` and notifies subscribers only when a value
+ * actually changed.
+ *
+ * Sources without notifications (wired network, switching the default audio
+ * device, or a registration that failed) are covered by a fallback poll.
+ *
+ * Frontend notification (`system-status-changed` event) is wired in the
+ * app setup, which owns the `AppHandle`.
+ */
+use parking_lot::RwLock;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::application::services::SettingsService;
+use crate::domain::notification::{Notification, NotificationCategory, NotificationPriority};
+use crate::ports::system_events_port::{SystemEventKind, SystemEventsPort};
+use crate::ports::system_port::{SystemPort, SystemStatus};
+use crate::ports::NotificationPort;
+
+/// Poll interval when every source has native notifications (catches wired network changes).
+const FALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// Bursts of notifications (dragging the volume slider) are coalesced into one read.
+const COALESCE_WINDOW: Duration = Duration::from_millis(50);
+
+/// Callback invoked with the new status and the previous one (`None` on the first reading).
+pub type SystemStatusListener = Box<dyn Fn(&SystemStatus, Option<&SystemStatus>) + Send + Sync>;
+
+/// Keeps the latest system status and reports changes.
+pub struct SystemStatusService {
+    notifications: Arc<dyn NotificationPort>,
+    current: RwLock<Option<SystemStatus>>,
+    listeners: RwLock<Vec<SystemStatusListener>>,
+}
+
+impl SystemStatusService {
+    pub fn new(notifications: Arc<dyn NotificationPort>) -> Self {
+        Self {
+            notifications,
+            current: RwLock::new(None),
+            listeners: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Latest status read by the monitor (`None` before it started).
+    #[must_use]
+    pub fn current(&self) -> Option<SystemStatus> {
+        self.current.read().clone()
+    }
+
+    /// Registers a listener called on every status change.
+    pub fn subscribe(&self, listener: SystemStatusListener) {
+        self.listeners.write().push(listener);
+    }
+
+    /// Subscribes to native change notifications and starts the monitor thread.
+    ///
+    /// When a source could not be registered the monitor polls it every
+    /// `general.system_poll_interval_ms` instead.
+    pub fn start(
+        self: &Arc<Self>,
+        system: Arc<dyn SystemPort>,
+        events: &dyn SystemEventsPort,
+        settings: Arc<SettingsService>,
+    ) {
+        let (tx, rx) = mpsc::channel::<SystemEventKind>();
+        let active = events.subscribe(Arc::new(move |kind| {
+            let _ = tx.send(kind);
+        }));
+        let fully_native = [
+            SystemEventKind::Power,
+            SystemEventKind::Volume,
+            SystemEventKind::Network,
+        ]
+        .iter()
+        .all(|kind| active.contains(kind));
+        if !fully_native {
+            warn!("Some system change notifications are unavailable, polling as fallback");
+        }
+
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("system-status".to_string())
+            .spawn(move || loop {
+                service.update(system.get_status());
+
+                let timeout = if fully_native {
+                    FALLBACK_POLL_INTERVAL
+                } else {
+                    Duration::from_millis(settings.get().general.system_poll_interval_ms)
+                };
+                match rx.recv_timeout(timeout) {
+                    Ok(_) => {
+                        std::thread::sleep(COALESCE_WINDOW);
+                        while rx.try_recv().is_ok() {}
+                    },
+                    Err(RecvTimeoutError::Timeout) => {},
+                    // Event source gone: keep polling
+                    Err(RecvTimeoutError::Disconnected) => std::thread::sleep(timeout),
+                }
+            });
+
+        match spawned {
+            Ok(_) => info!("🔋 System status monitor started (native sources: {:?})", active),
+            Err(e) => warn!("Failed to start system status monitor: {}", e),
+        }
+    }
+
+    /// Records a reading and notifies listeners if it differs from the previous one.
+    ///
+    /// # Returns
+    /// `true` if the status changed.
+    fn update(&self, status: SystemStatus) -> bool {
+        let previous = {
+            let mut current = self.current.write();
+            if current.as_ref() == Some(&status) {
+                return false;
+            }
+            current.replace(status.clone())
+        };
+
+        if let Some(priority) = low_battery_alert(previous.as_ref(), &status) {
+            let level = status.battery_level.unwrap_or_default();
+            self.notifications.notify(Notification::new(
+                NotificationCategory::Battery,
+                priority,
+                "Batería baja",
+                format!("Queda {level}% de batería. Conecta el cargador."),
+            ));
+        }
+
+        for listener in self.listeners.read().iter() {
+            listener(&status, previous.as_ref());
+        }
+        true
+    }
+}
+
+/// Low battery: notify once when crossing 20% and 10% while discharging.
+fn low_battery_alert(previous: Option<&SystemStatus>, current: &SystemStatus) -> Option<NotificationPriority> {
+    let level = current.battery_level?;
+    if current.is_charging {
+        return None;
+    }
+
+    let last = previous.and_then(|previous| previous.battery_level);
+    let crossed = |threshold: u8| level <= threshold && last.is_some_and(|last| last > threshold);
+    if crossed(10) {
+        Some(NotificationPriority::Critical)
+    } else if crossed(20) {
+        Some(NotificationPriority::High)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::notification::NotificationDelivery;
+    use crate::ports::system_port::ConnectionType;
+    use parking_lot::Mutex;
+
+    #[derive(Default)]
+    struct RecordingNotifications {
+        posted: Mutex<Vec<Notification>>,
+    }
+
+    impl NotificationPort for RecordingNotifications {
+        fn notify(&self, notification: Notification) -> NotificationDelivery {
+            self.posted.lock().push(notification);
+            NotificationDelivery::default()
+        }
+
+        fn is_do_not_disturb(&self) -> bool {
+            false
+        }
+
+        fn history(&self) -> Vec<Notification> {
+            self.posted.lock().clone()
+        }
+
+        fn clear_history(&self) {
+            self.posted.lock().clear();
+        }
+    }
+
+    fn status(battery_level: Option<u8>, is_charging: bool, volume: u32) -> SystemStatus {
+        SystemStatus {
+            battery_level,
+            is_charging,
+            network_name: None,
+            volume,
+            connection_type: ConnectionType::None,
+        }
+    }
+
+    #[test]
+    fn test_listeners_only_called_on_change() {
+        let service = SystemStatusService::new(Arc::new(RecordingNotifications::default()));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let recorded = calls.clone();
+        service.subscribe(Box::new(move |status, previous| {
+            recorded.lock().push((status.volume, previous.map(|p| p.volume)));
+        }));
+
+        assert!(service.update(status(Some(80), false, 50)));
+        assert!(!service.update(status(Some(80), false, 50)));
+        assert!(service.update(status(Some(80), false, 60)));
+
+        assert_eq!(*calls.lock(), vec![(50, None), (60, Some(50))]);
+        assert_eq!(service.current().map(|s| s.volume), Some(60));
+    }
+
+    #[test]
+    fn test_low_battery_alert_thresholds() {
+        assert_eq!(
+            low_battery_alert(Some(&status(Some(21), false, 0)), &status(Some(20), false, 0)),
+            Some(NotificationPriority::High)
+        );
+        assert_eq!(
+            low_battery_alert(Some(&status(Some(11), false, 0)), &status(Some(10), false, 0)),
+            Some(NotificationPriority::Critical)
+        );
+        // Already below, charging, or first reading: no alert
+        assert_eq!(
+            low_battery_alert(Some(&status(Some(19), false, 0)), &status(Some(18), false, 0)),
+            None
+        );
+        assert_eq!(
+            low_battery_alert(Some(&status(Some(21), false, 0)), &status(Some(20), true, 0)),
+            None
+        );
+        assert_eq!(low_battery_alert(None, &status(Some(5), false, 0)), None);
+    }
+
+    #[test]
+    fn test_low_battery_posts_notification() {
+        let notifications = Arc::new(RecordingNotifications::default());
+        let service = SystemStatusService::new(notifications.clone());
+
+        service.update(status(Some(21), false, 50));
+        service.update(status(Some(20), false, 50));
+
+        let posted = notifications.history();
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].category, NotificationCategory::Battery);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::application::services::SettingsService;
+use crate::domain::telemetry::{
+    TelemetryBatch, TelemetryCounters, TelemetryEvent, TelemetryPreview, TELEMETRY_SCHEMA_VERSION,
+};
+use crate::domain::BalamError;
+use crate::infrastructure::app_dirs;
+use crate::infrastructure::clock::now_secs;
+use crate::ports::TelemetrySinkPort;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+const STATE_FILE: &str = "telemetry.json";
+/// Present while Balam runs; found at startup it means the last session crashed.
+const SESSION_MARKER_FILE: &str = "telemetry_session";
+/// Batches are uploaded at most this often.
+const UPLOAD_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+/// Shown in the preview instead of an ID while telemetry is off.
+const INSTALL_ID_PLACEHOLDER: &str = "(created when telemetry is turned on)";
+
+/// Pending counters, kept on disk until uploaded.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct TelemetryState {
+    install_id: String,
+    counters: TelemetryCounters,
+}
+
+fn batch(install_id: String, counters: TelemetryCounters) -> TelemetryBatch {
+    TelemetryBatch {
+        schema_version: TELEMETRY_SCHEMA_VERSION,
+        install_id,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        period_end: now_secs(),
+        counters,
+    }
+}
+
+/// Opt-in anonymous telemetry: counts events, batches and uploads them.
+///
+/// Nothing is recorded or created while `telemetry.enabled` is off, and
+/// turning it off discards pending counters and the install ID. Uploads need
+/// an endpoint in the settings.
+pub struct TelemetryService {
+    sink: Arc<dyn TelemetrySinkPort>,
+    settings: Arc<SettingsService>,
+    dir: PathBuf,
+    state: Mutex<TelemetryState>,
+}
+
+impl TelemetryService {
+    #[must_use]
+    pub fn new(sink: Arc<dyn TelemetrySinkPort>, settings: Arc<SettingsService>, data_dir: &Path) -> Self {
+        let state = fs::read_to_string(data_dir.join(STATE_FILE))
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        Self {
+            sink,
+            settings,
+            dir: data_dir.to_path_buf(),
+            state: Mutex::new(state),
+        }
+    }
+
+    /// App local data directory (see `app_dirs::app_data_dir`).
+    #[must_use]
+    pub fn default_dir() -> PathBuf {
+        app_dirs::app_data_dir()
+    }
+
+    fn enabled(&self) -> bool {
+        self.settings.get().telemetry.enabled
+    }
+
+    fn save(&self, state: &TelemetryState) {
+        let result = fs::create_dir_all(&self.dir).and_then(|()| {
+            fs::write(
+                self.dir.join(STATE_FILE),
+                serde_json::to_string(state).unwrap_or_default(),
+            )
+        });
+        if let Err(e) = result {
+            warn!("Failed to save telemetry counters: {}", e);
+        }
+    }
+
+    /// Counts `event` (no-op unless the player opted in).
+    pub fn record(&self, event: &TelemetryEvent) {
+        if !self.enabled() {
+            return;
+        }
+        let mut state = self.state.lock();
+        state.counters.record(event, now_secs());
+        self.save(&state);
+    }
+
+    /// The batch that the next upload would send, as-is.
+    ///
+    /// While telemetry is off nothing is created or saved: the install ID
+    /// shows as a placeholder.
+    #[must_use]
+    pub fn preview(&self) -> TelemetryPreview {
+        let settings = self.settings.get().telemetry;
+        let mut state = self.state.lock();
+        let install_id = if settings.enabled {
+            self.install_id(&mut state)
+        } else {
+            INSTALL_ID_PLACEHOLDER.to_string()
+        };
+        TelemetryPreview {
+            enabled: settings.enabled,
+            endpoint: Some(settings.endpoint).filter(|endpoint| !endpoint.is_empty()),
+            batch: batch(install_id, state.counters.clone()),
+        }
+    }
+
+    /// The install ID, created and saved on first use (only once opted in).
+    fn install_id(&self, state: &mut TelemetryState) -> String {
+        if state.install_id.is_empty() {
+            state.install_id = uuid::Uuid::new_v4().simple().to_string();
+            self.save(state);
+        }
+        state.install_id.clone()
+    }
+
+    /// Discards pending counters and the install ID.
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        *state = TelemetryState::default();
+        self.save(&state);
+        info!("📊 Telemetry data cleared");
+    }
+
+    /// Uploads pending counters when enabled, configured and non-empty.
+    ///
+    /// Returns whether a batch was sent.
+    ///
+    /// # Errors
+    /// Returns the upload error; counters are kept for the next attempt.
+    pub fn upload(&self) -> Result<bool, BalamError> {
+        let settings = self.settings.get().telemetry;
+        if !settings.enabled || settings.endpoint.is_empty() {
+            return Ok(false);
+        }
+        // Taken out under one lock: events recorded meanwhile go to the next batch
+        let batch = {
+            let mut state = self.state.lock();
+            if state.counters.is_empty() {
+                return Ok(false);
+            }
+            let install_id = self.install_id(&mut state);
+            batch(install_id, std::mem::take(&mut state.counters))
+        };
+
+        let result = self.sink.send(&settings.endpoint, &batch);
+        let mut state = self.state.lock();
+        // Put back for the next attempt, unless the player opted out meanwhile
+        if result.is_err() && self.enabled() {
+            state.counters.merge(batch.counters);
+        }
+        self.save(&state);
+        result?;
+        info!("📊 Telemetry batch uploaded");
+        Ok(true)
+    }
+
+    /// Counts a crash if the previous session left its marker, then sets it again.
+    pub fn begin_session(&self) {
+        let marker = self.dir.join(SESSION_MARKER_FILE);
+        if marker.exists() {
+            self.record(&TelemetryEvent::Crash);
+        }
+        if self.enabled() {
+            if let Err(e) = fs::create_dir_all(&self.dir).and_then(|()| fs::write(&marker, b"")) {
+                warn!("Failed to write telemetry session marker: {}", e);
+            }
+        } else {
+            let _ = fs::remove_file(marker);
+        }
+    }
+
+    /// Clean exit: the next start does not count a crash.
+    pub fn end_session(&self) {
+        let _ = fs::remove_file(self.dir.join(SESSION_MARKER_FILE));
+    }
+
+    /// Starts the upload thread and drops pending data when the player opts out.
+    pub fn start(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        self.settings.subscribe(Box::new(move |settings| {
+            let pending = {
+                let state = service.state.lock();
+                !state.counters.is_empty() || !state.install_id.is_empty()
+            };
+            if !settings.telemetry.enabled && pending {
+                service.clear();
+            }
+        }));
+
+        let service = Arc::clone(self);
+        let spawned = std::thread::Builder::new()
+            .name("telemetry-upload".to_string())
+            .spawn(move || loop {
+                std::thread::sleep(UPLOAD_INTERVAL);
+                if let Err(e) = service.upload() {
+                    warn!("Telemetry upload failed: {}", e);
+                }
+            });
+        if let Err(e) = spawned {
+            warn!("Failed to start telemetry uploader: {}", e);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use crate::domain::text_input::{KeyboardBackend, TextInputState, VirtualKey, MAX_VIRTUAL_TEXT_CHARS};
+use crate::domain::BalamError;
+use crate::ports::text_input_port::TextInputPort;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+struct TextInputInner {
+    state: TextInputState,
+    /// The touch keyboard is up because a text field got the focus (so blur
+    /// may dismiss it); a keyboard the user opened stays where it is
+    auto_shown: bool,
+}
+
+/// Controller text entry.
+///
+/// The WebView reports focus changes of its text fields; depending on the
+/// backend the Windows touch keyboard is summoned and dismissed here, or the
+/// frontend draws Balam's keyboard. In console mode (no explorer, so no touch
+/// keyboard) Balam's keyboard can also type into other windows through
+/// [`TextInputService::type_text`] and [`TextInputService::press_key`].
+pub struct TextInputService {
+    port: Arc<dyn TextInputPort>,
+    shell_mode: bool,
+    inner: Mutex<TextInputInner>,
+}
+
+impl TextInputService {
+    #[must_use]
+    pub fn new(port: Arc<dyn TextInputPort>, shell_mode: bool) -> Self {
+        Self {
+            port,
+            shell_mode,
+            inner: Mutex::new(TextInputInner {
+                state: TextInputState::resolve(false, None, KeyboardBackend::choose(shell_mode, false)),
+                auto_shown: false,
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn current(&self) -> TextInputState {
+        self.inner.lock().state.clone()
+    }
+
+    /// Applies a focus change of the WebView and returns the new state.
+    ///
+    /// Failing to reach the touch keyboard is logged, not returned: the
+    /// focus change itself still happened.
+    #[must_use]
+    pub fn set_focus(&self, focused: bool, input_type: Option<String>, prefer_windows: bool) -> TextInputState {
+        let backend = KeyboardBackend::choose(self.shell_mode, prefer_windows);
+        let state = TextInputState::resolve(focused, input_type, backend);
+
+        let mut inner = self.inner.lock();
+        if backend == KeyboardBackend::Windows {
+            if state.keyboard_visible && !self.port.is_touch_keyboard_visible() {
+                match self.port.show_touch_keyboard() {
+                    Ok(()) => inner.auto_shown = true,
+                    Err(e) => warn!("⚠️ Touch keyboard unavailable: {}", e),
+                }
+            } else if !state.keyboard_visible && inner.auto_shown {
+                if let Err(e) = self.port.hide_touch_keyboard() {
+                    warn!("⚠️ Failed to dismiss touch keyboard: {}", e);
+                }
+                inner.auto_shown = false;
+            }
+        }
+        inner.state = state.clone();
+        state
+    }
+
+    /// Summons the Windows touch keyboard.
+    ///
+    /// # Errors
+    /// Returns `NotSupported` in console mode, or the port error.
+    pub fn show_touch_keyboard(&self) -> Result<(), BalamError> {
+        if self.shell_mode {
+            return Err(BalamError::NotSupported(
+                "Windows touch keyboard in console mode".to_string(),
+            ));
+        }
+        self.port.show_touch_keyboard()?;
+        self.inner.lock().auto_shown = false;
+        info!("⌨️ Touch keyboard shown");
+        Ok(())
+    }
+
+    /// Dismisses the Windows touch keyboard.
+    ///
+    /// # Errors
+    /// Returns the port error.
+    pub fn hide_touch_keyboard(&self) -> Result<(), BalamError> {
+        if self.shell_mode {
+            return Ok(());
+        }
+        self.port.hide_touch_keyboard()?;
+        self.inner.lock().auto_shown = false;
+        Ok(())
+    }
+
+    /// Types text into the foreground window.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` for text over [`MAX_VIRTUAL_TEXT_CHARS`], or the port error.
+    pub fn type_text(&self, text: &str) -> Result<(), BalamError> {
+        if text.chars().count() > MAX_VIRTUAL_TEXT_CHARS {
+            return Err(BalamError::InvalidArgument(format!(
+                "Text longer than {MAX_VIRTUAL_TEXT_CHARS} characters"
+            )));
+        }
+        self.port.type_text(text)
+    }
+
+    /// Presses an editing key in the foreground window.
+    ///
+    /// # Errors
+    /// Returns the port error.
+    pub fn press_key(&self, key: VirtualKey) -> Result<(), BalamError> {
+        self.port.press_key(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    #[derive(Default)]
+    struct FakeKeyboard {
+        visible: AtomicBool,
+    }
+
+    impl TextInputPort for FakeKeyboard {
+        fn show_touch_keyboard(&self) -> Result<(), BalamError> {
+            self.visible.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn hide_touch_keyboard(&self) -> Result<(), BalamError> {
+            self.visible.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn is_touch_keyboard_visible(&self) -> bool {
+            self.visible.load(Ordering::SeqCst)
+        }
+
+        fn type_text(&self, _text: &str) -> Result<(), BalamError> {
+            Ok(())
+        }
+
+        fn press_key(&self, _key: VirtualKey) -> Result<(), BalamError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_blur_only_dismisses_keyboard_it_summoned() {
+        let keyboard = Arc::new(FakeKeyboard::default());
+        let service = TextInputService::new(keyboard.clone(), false);
+
+        assert!(service.set_focus(true, Some("text".to_string()), true).keyboard_visible);
+        assert!(keyboard.is_touch_keyboard_visible());
+        assert!(!service.set_focus(false, None, true).keyboard_visible);
+        assert!(!keyboard.is_touch_keyboard_visible());
+
+        // Opened by hand: leaving a field keeps it up
+        service.show_touch_keyboard().unwrap();
+        assert!(!service.set_focus(false, None, true).focused);
+        assert!(keyboard.is_touch_keyboard_visible());
+
+        // Console mode never touches it
+        let shell = TextInputService::new(Arc::new(FakeKeyboard::default()), true);
+        let state = shell.set_focus(true, None, true);
+        assert_eq!(state.backend, KeyboardBackend::Balam);
+        assert!(shell.show_touch_keyboard().is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::domain::update::{PackageKind, ReleaseManifest, UpdateInfo, UpdateProgress, UpdateStage};
+use crate::domain::BalamError;
+use crate::ports::ReleaseFeedPort;
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::info;
+
+/// Package downloaded and verified, ready to hand to the helper.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedUpdate {
+    pub version: String,
+    pub kind: PackageKind,
+    pub package_path: PathBuf,
+    /// Checksum and signature the helper verifies again before installing
+    pub sha256: String,
+    pub signature: String,
+}
+
+/// Checks the release feed and downloads the package for this version.
+///
+/// Installing happens outside the app: the command layer writes the update
+/// marker, starts the helper and exits (see `adapters::update`).
+pub struct UpdateService {
+    feed: Arc<dyn ReleaseFeedPort>,
+    latest: Mutex<Option<ReleaseManifest>>,
+    last_check: Mutex<Option<UpdateInfo>>,
+    busy: AtomicBool,
+}
+
+/// Clears the busy flag however `prepare` returns.
+struct BusyGuard<'a>(&'a AtomicBool);
+
+impl Drop for BusyGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+impl UpdateService {
+    #[must_use]
+    pub fn new(feed: Arc<dyn ReleaseFeedPort>) -> Self {
+        Self {
+            feed,
+            latest: Mutex::new(None),
+            last_check: Mutex::new(None),
+            busy: AtomicBool::new(false),
+        }
+    }
+
+    /// Fetches the feed and compares it with `current_version`.
+    ///
+    /// Blocking (network); call from a worker thread.
+    ///
+    /// # Errors
+    /// Returns error if the feed is unreachable or malformed.
+    pub fn check(&self, feed_url: &str, current_version: &str) -> Result<UpdateInfo, BalamError> {
+        let manifest = self.feed.fetch_manifest(feed_url)?;
+        let available = manifest
+            .is_newer_than(current_version)
+            .map_err(BalamError::InvalidArgument)?;
+        let (package, artifact) = manifest.package_for(current_version);
+        let info = UpdateInfo {
+            current_version: current_version.to_string(),
+            latest_version: manifest.version.clone(),
+            available,
+            notes: manifest.notes.clone(),
+            published_at: manifest.published_at.clone(),
+            package,
+            download_bytes: artifact.size,
+        };
+        if available {
+            info!("🆕 Update available: {} → {}", current_version, manifest.version);
+        }
+        *self.latest.lock() = Some(manifest);
+        *self.last_check.lock() = Some(info.clone());
+        Ok(info)
+    }
+
+    /// Downloads and verifies the package found by the last [`Self::check`].
+    ///
+    /// # Errors
+    /// Returns `NotFound` when no newer release was found, `AccessDenied`
+    /// when another update is in progress or verification fails.
+    pub fn prepare(
+        &self,
+        current_version: &str,
+        on_progress: &dyn Fn(UpdateProgress),
+    ) -> Result<PreparedUpdate, BalamError> {
+        let manifest = self
+            .latest
+            .lock()
+            .clone()
+            .filter(|manifest| manifest.is_newer_than(current_version).unwrap_or(false))
+            .ok_or_else(|| BalamError::NotFound("Newer release (check for updates first)".to_string()))?;
+
+        if self.busy.swap(true, Ordering::SeqCst) {
+            return Err(BalamError::AccessDenied("An update is already in progress".to_string()));
+        }
+        let _guard = BusyGuard(&self.busy);
+
+        let (kind, artifact) = manifest.package_for(current_version);
+        let package_path = self.feed.download(artifact, &|downloaded, total| {
+            let stage = if downloaded >= total {
+                UpdateStage::Verifying
+            } else {
+                UpdateStage::Downloading
+            };
+            on_progress(UpdateProgress {
+                stage,
+                downloaded_bytes: downloaded,
+                total_bytes: total,
+            });
+        })?;
+
+        let (sha256, signature) = (artifact.sha256.clone(), artifact.signature.clone());
+        Ok(PreparedUpdate {
+            version: manifest.version,
+            kind,
+            package_path,
+            sha256,
+            signature,
+        })
+    }
+
+    /// Result of the last successful [`Self::check`], if any.
+    #[must_use]
+    pub fn last_check(&self) -> Option<UpdateInfo> {
+        self.last_check.lock().clone()
+    }
+
+    #[must_use]
+    pub fn is_busy(&self) -> bool {
+        self.busy.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::update::{DeltaArtifact, UpdateArtifact};
+
+    struct FakeFeed;
+
+    fn artifact(url: &str, size: u64) -> UpdateArtifact {
+        UpdateArtifact {
+            url: url.to_string(),
+            size,
+            sha256: String::new(),
+            signature: String::new(),
+        }
+    }
+
+    impl ReleaseFeedPort for FakeFeed {
+        fn fetch_manifest(&self, _feed_url: &str) -> Result<ReleaseManifest, BalamError> {
+            Ok(ReleaseManifest {
+                version: "0.2.0".to_string(),
+                notes: "Fixes".to_string(),
+                published_at: None,
+                full: artifact("https://example.com/setup.exe", 100),
+                deltas: vec![DeltaArtifact {
+                    from_version: "0.1.0".to_string(),
+                    artifact: artifact("https://example.com/delta.zip", 10),
+                }],
+            })
+        }
+
+        fn download(&self, artifact: &UpdateArtifact, on_progress: &dyn Fn(u64, u64)) -> Result<PathBuf, BalamError> {
+            on_progress(artifact.size / 2, artifact.size);
+            on_progress(artifact.size, artifact.size);
+            Ok(PathBuf::from(artifact.url.rsplit('/').next().unwrap()))
+        }
+    }
+
+    #[test]
+    fn test_prepare_downloads_delta_after_check() {
+        let service = UpdateService::new(Arc::new(FakeFeed));
+        assert!(service.prepare("0.1.0", &|_| {}).is_err(), "needs a check first");
+
+        let info = service.check("https://example.com/latest.json", "0.1.0").unwrap();
+        assert!(info.available);
+        assert_eq!((info.package, info.download_bytes), (PackageKind::Delta, 10));
+
+        let stages = Mutex::new(Vec::new());
+        let prepared = service.prepare("0.1.0", &|p| stages.lock().push(p.stage)).unwrap();
+        assert_eq!(prepared.kind, PackageKind::Delta);
+        assert_eq!(prepared.package_path, PathBuf::from("delta.zip"));
+        assert_eq!(*stages.lock(), vec![UpdateStage::Downloading, UpdateStage::Verifying]);
+        assert!(!service.is_busy());
+
+        // Already on the latest version
+        assert!(
+            !service
+                .check("https://example.com/latest.json", "0.2.0")
+                .unwrap()
+                .available
+        );
+    }
+}
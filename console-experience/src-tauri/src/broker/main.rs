@@ -0,0 +1,193 @@
+use console_experience_lib::adapters::privilege::is_process_running;
+use console_experience_lib::application::privilege::execute_privileged;
+use console_experience_lib::domain::privilege::{
+    tokens_match, BrokerRequest, BrokerResponse, BROKER_PARENT_FLAG, BROKER_PIPE_NAME, BROKER_TOKEN_FLAG,
+};
+use console_experience_lib::infrastructure::logging::{init_logging, LogComponent};
+use std::os::windows::io::AsRawHandle;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
+use tokio::time::timeout;
+use tracing::{error, info, warn};
+use windows::core::w;
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::Security::Authorization::{ConvertStringSecurityDescriptorToSecurityDescriptorW, SDDL_REVISION_1};
+use windows::Win32::Security::{PSECURITY_DESCRIPTOR, SECURITY_ATTRIBUTES};
+use windows::Win32::System::Pipes::GetNamedPipeClientProcessId;
+
+/// How often the broker checks that Balam is still running.
+const PARENT_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+/// A client that connects and says nothing is dropped after this long.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Who may connect (SDDL): SYSTEM and administrators, plus read/write for
+/// interactive users so the unelevated app can send requests.
+const PIPE_SDDL: windows::core::PCWSTR = w!("D:P(A;;GA;;;SY)(A;;GA;;;BA)(A;;GRGW;;;IU)");
+
+/// Balam Privilege Broker
+///
+/// Started once with UAC by the app on the first admin-only operation (TDP,
+/// fps-service install/start/stop, autostart, Sunshine), then serves every later one
+/// over `\\.\pipe\balam_broker` until Balam exits, so the player is not
+/// prompted again mid-game.
+///
+/// A request is executed only if:
+/// - the connected process is `console-experience.exe` from this install directory
+/// - it carries the token Balam passed on the broker's command line (readable
+///   by elevated processes only)
+/// - it names an operation of the fixed allow-list with arguments in range
+#[tokio::main]
+async fn main() {
+    let _log_guard = init_logging(LogComponent::Broker);
+
+    let args: Vec<String> = std::env::args().collect();
+    let (Some(token), Some(parent)) = (
+        arg_value(&args, BROKER_TOKEN_FLAG),
+        arg_value(&args, BROKER_PARENT_FLAG).and_then(|pid| pid.parse::<u32>().ok()),
+    ) else {
+        error!(
+            "❌ Broker started without {} and {}",
+            BROKER_TOKEN_FLAG, BROKER_PARENT_FLAG
+        );
+        return;
+    };
+    let Some(install_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+    else {
+        error!("❌ Cannot resolve install directory");
+        return;
+    };
+    let client_exe = Arc::new(install_dir.join("console-experience.exe"));
+    if !is_process_running(parent, &client_exe) {
+        error!("❌ Parent PID {} is not Balam", parent);
+        return;
+    }
+
+    info!("🛡️ Privilege broker started for Balam (PID {})", parent);
+    let token: Arc<str> = token.into();
+    tokio::select! {
+        () = serve(token, Arc::clone(&client_exe)) => {},
+        () = wait_for_exit(parent, &client_exe) => info!("👋 Balam exited, stopping broker"),
+    }
+}
+
+fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|arg| arg == flag)
+        .and_then(|index| args.get(index + 1).cloned())
+}
+
+async fn wait_for_exit(parent: u32, client_exe: &std::path::Path) {
+    while is_process_running(parent, client_exe) {
+        tokio::time::sleep(PARENT_CHECK_INTERVAL).await;
+    }
+}
+
+/// Creates a pipe instance readable and writable by the interactive user only.
+fn create_pipe(first: bool) -> std::io::Result<NamedPipeServer> {
+    let mut descriptor = PSECURITY_DESCRIPTOR::default();
+    unsafe { ConvertStringSecurityDescriptorToSecurityDescriptorW(PIPE_SDDL, SDDL_REVISION_1, &mut descriptor, None) }
+        .map_err(std::io::Error::other)?;
+    let mut attributes = SECURITY_ATTRIBUTES {
+        nLength: std::mem::size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: descriptor.0,
+        bInheritHandle: false.into(),
+    };
+    // The descriptor is copied into the pipe's kernel object; its small
+    // allocation is left to the process exit
+    unsafe {
+        ServerOptions::new()
+            .first_pipe_instance(first)
+            .create_with_security_attributes_raw(BROKER_PIPE_NAME, std::ptr::addr_of_mut!(attributes).cast())
+    }
+}
+
+/// Accepts clients forever; the next instance exists before a request is
+/// handled so clients never find the pipe missing (and try to start a
+/// second broker).
+async fn serve(token: Arc<str>, client_exe: Arc<PathBuf>) {
+    let mut server = match create_pipe(true) {
+        Ok(server) => server,
+        Err(e) => {
+            error!("❌ Failed to create broker pipe (another broker running?): {}", e);
+            return;
+        },
+    };
+
+    loop {
+        if let Err(e) = server.connect().await {
+            warn!("Broker pipe connect failed: {}", e);
+        } else {
+            let connected = server;
+            let token = Arc::clone(&token);
+            let client_exe = Arc::clone(&client_exe);
+            tokio::spawn(async move { handle_client(connected, &token, &client_exe).await });
+        }
+
+        server = match create_pipe(false) {
+            Ok(server) => server,
+            Err(e) => {
+                error!("❌ Failed to recreate broker pipe: {}", e);
+                return;
+            },
+        };
+    }
+}
+
+async fn handle_client(server: NamedPipeServer, token: &str, client_exe: &std::path::Path) {
+    let mut client_pid = 0u32;
+    let identified =
+        unsafe { GetNamedPipeClientProcessId(HANDLE(server.as_raw_handle() as isize), &mut client_pid) }.is_ok();
+
+    let (reader, mut writer) = tokio::io::split(server);
+    let mut line = String::new();
+    let read = timeout(REQUEST_TIMEOUT, BufReader::new(reader).read_line(&mut line)).await;
+
+    let response = if !identified || !is_process_running(client_pid, client_exe) {
+        warn!("🚫 Rejected broker client PID {} (not Balam)", client_pid);
+        BrokerResponse::Unauthorized
+    } else {
+        match read.map(|result| result.map(|_| serde_json::from_str::<BrokerRequest>(&line))) {
+            Ok(Ok(Ok(request))) if tokens_match(token, &request.token) => execute(request).await,
+            Ok(Ok(Ok(_))) => {
+                warn!("🚫 Rejected broker request with a wrong token (PID {})", client_pid);
+                BrokerResponse::Unauthorized
+            },
+            Ok(Ok(Err(e))) => BrokerResponse::Failed {
+                message: format!("Invalid request: {e}"),
+            },
+            Ok(Err(e)) => {
+                warn!("Failed to read broker request: {}", e);
+                return;
+            },
+            Err(_) => {
+                warn!("Broker client PID {} sent nothing", client_pid);
+                return;
+            },
+        }
+    };
+
+    let mut answer = serde_json::to_vec(&response).unwrap_or_default();
+    answer.push(b'\n');
+    if let Err(e) = writer.write_all(&answer).await {
+        warn!("Failed to answer broker client: {}", e);
+    }
+    let _ = writer.flush().await;
+}
+
+async fn execute(request: BrokerRequest) -> BrokerResponse {
+    let result = tokio::task::spawn_blocking(move || execute_privileged(&request.operation))
+        .await
+        .unwrap_or_else(|e| Err(format!("Task join error: {e}")));
+    match result {
+        Ok(()) => BrokerResponse::Done,
+        Err(message) => {
+            error!("❌ Privileged operation failed: {}", message);
+            BrokerResponse::Failed { message }
+        },
+    }
+}
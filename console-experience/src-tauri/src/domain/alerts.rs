@@ -0,0 +1,356 @@
+/// In-game alerts
+///
+/// Battery, temperature and thermal throttling warnings raised while a game
+/// runs. Each alert fires once when its condition starts and re-arms after
+/// the value recovers, so a reading hovering around a threshold does not
+/// buzz the player every few seconds.
+use serde::{Deserialize, Serialize};
+
+use crate::domain::notification::NotificationPriority;
+use crate::domain::performance::PerformanceMetrics;
+
+/// A temperature alert re-arms this far below its threshold.
+pub const TEMP_HYSTERESIS_C: f32 = 5.0;
+/// Load (%) above which clocks are expected to stay near their peak.
+pub const THROTTLE_LOAD_PERCENT: f32 = 50.0;
+/// Clocks below this share of the session peak under load count as throttled.
+pub const THROTTLE_CLOCK_PERCENT: u32 = 75;
+/// Consecutive throttled readings before the alert fires.
+pub const THROTTLE_SAMPLES: u32 = 3;
+
+/// What an alert is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    LowBattery,
+    CriticalBattery,
+    CpuTemperature,
+    GpuTemperature,
+    CpuThrottling,
+    GpuThrottling,
+}
+
+impl AlertKind {
+    /// Short text for the RTSS OSD.
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::LowBattery => "LOW BATTERY",
+            Self::CriticalBattery => "BATTERY CRITICAL",
+            Self::CpuTemperature => "CPU HOT",
+            Self::GpuTemperature => "GPU HOT",
+            Self::CpuThrottling => "CPU THROTTLING",
+            Self::GpuThrottling => "GPU THROTTLING",
+        }
+    }
+
+    #[must_use]
+    pub fn is_battery(&self) -> bool {
+        matches!(self, Self::LowBattery | Self::CriticalBattery)
+    }
+}
+
+/// Payload of `game-alert`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameAlert {
+    pub kind: AlertKind,
+    pub priority: NotificationPriority,
+    pub title: String,
+    pub message: String,
+}
+
+/// Alert thresholds and where alerts show up.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AlertSettings {
+    pub enabled: bool,
+    /// Battery percentage for the low battery alert (while discharging)
+    pub low_battery_percent: u8,
+    /// Battery percentage for the critical battery alert
+    pub critical_battery_percent: u8,
+    pub cpu_temp_c: u32,
+    pub gpu_temp_c: u32,
+    /// Warn when clocks drop under load
+    pub throttle_detection: bool,
+    /// Rumble the controller with the `event` pattern
+    pub haptics: bool,
+    /// Show active warnings in the RTSS OSD
+    pub rtss_warnings: bool,
+}
+
+impl Default for AlertSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            low_battery_percent: 20,
+            critical_battery_percent: 10,
+            cpu_temp_c: 95,
+            gpu_temp_c: 87,
+            throttle_detection: true,
+            haptics: true,
+            rtss_warnings: false,
+        }
+    }
+}
+
+impl AlertSettings {
+    /// # Errors
+    /// Returns error for battery thresholds out of order or temperatures out of range.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.critical_battery_percent >= self.low_battery_percent || self.low_battery_percent > 90 {
+            return Err(format!(
+                "Battery alerts need critical ({}%) below low ({}%), at most 90%",
+                self.critical_battery_percent, self.low_battery_percent
+            ));
+        }
+        for (name, temp) in [("CPU", self.cpu_temp_c), ("GPU", self.gpu_temp_c)] {
+            if !(50..=110).contains(&temp) {
+                return Err(format!("{name} temperature alert {temp}°C out of range (50 - 110°C)"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Values the alerts are checked against.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AlertReading {
+    pub battery_percent: Option<u8>,
+    pub charging: bool,
+    pub cpu_temp_c: Option<f32>,
+    pub gpu_temp_c: Option<f32>,
+    pub cpu_usage: f32,
+    pub gpu_usage: f32,
+    pub cpu_clock_mhz: Option<u32>,
+    pub gpu_clock_mhz: Option<u32>,
+}
+
+impl AlertReading {
+    #[must_use]
+    pub fn new(metrics: &PerformanceMetrics, battery_percent: Option<u8>, charging: bool) -> Self {
+        Self {
+            battery_percent,
+            charging,
+            cpu_temp_c: metrics.cpu_temp_c,
+            gpu_temp_c: metrics.gpu_temp_c,
+            cpu_usage: metrics.cpu_usage,
+            gpu_usage: metrics.gpu_usage,
+            cpu_clock_mhz: metrics.cpu_clock_mhz,
+            gpu_clock_mhz: metrics.gpu_clock_mhz,
+        }
+    }
+}
+
+/// Clock drop detection for one processor.
+#[derive(Debug, Clone, Default)]
+struct ThrottleTracker {
+    /// Highest clock seen under load this session
+    peak_mhz: u32,
+    slow_samples: u32,
+}
+
+impl ThrottleTracker {
+    /// Records a reading; returns whether the clock has been throttled for
+    /// [`THROTTLE_SAMPLES`] readings in a row.
+    fn record(&mut self, clock_mhz: Option<u32>, usage: f32) -> bool {
+        let Some(clock_mhz) = clock_mhz.filter(|_| usage >= THROTTLE_LOAD_PERCENT) else {
+            self.slow_samples = 0;
+            return false;
+        };
+        self.peak_mhz = self.peak_mhz.max(clock_mhz);
+        if clock_mhz * 100 < self.peak_mhz * THROTTLE_CLOCK_PERCENT {
+            self.slow_samples += 1;
+        } else {
+            self.slow_samples = 0;
+        }
+        self.slow_samples >= THROTTLE_SAMPLES
+    }
+}
+
+/// Decides which alerts to raise, one reading at a time.
+#[derive(Debug, Clone, Default)]
+pub struct AlertMonitor {
+    active: Vec<GameAlert>,
+    cpu: ThrottleTracker,
+    gpu: ThrottleTracker,
+}
+
+impl AlertMonitor {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Alerts whose condition still holds.
+    #[must_use]
+    pub fn active(&self) -> &[GameAlert] {
+        &self.active
+    }
+
+    /// Forgets alerts and clock peaks (the game ended).
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Checks a reading; returns the alerts that just started.
+    pub fn check(&mut self, reading: &AlertReading, settings: &AlertSettings) -> Vec<GameAlert> {
+        let mut raised = Vec::new();
+
+        let battery = reading.battery_percent.filter(|_| !reading.charging);
+        let critical = battery.is_some_and(|percent| percent <= settings.critical_battery_percent);
+        let low = battery.is_some_and(|percent| percent <= settings.low_battery_percent);
+        self.update(AlertKind::CriticalBattery, critical, !critical, &mut raised, || {
+            (
+                NotificationPriority::Critical,
+                format!("Battery at {}%", battery.unwrap_or_default()),
+                "Plug in the charger now or save your game.".to_string(),
+            )
+        });
+        self.update(AlertKind::LowBattery, low && !critical, !low, &mut raised, || {
+            (
+                NotificationPriority::High,
+                format!("Battery at {}%", battery.unwrap_or_default()),
+                "Battery is running low.".to_string(),
+            )
+        });
+
+        for (kind, name, temp, threshold) in [
+            (
+                AlertKind::CpuTemperature,
+                "CPU",
+                reading.cpu_temp_c,
+                settings.cpu_temp_c,
+            ),
+            (
+                AlertKind::GpuTemperature,
+                "GPU",
+                reading.gpu_temp_c,
+                settings.gpu_temp_c,
+            ),
+        ] {
+            let threshold = threshold as f32;
+            let hot = temp.is_some_and(|temp| temp >= threshold);
+            let cooled = !temp.is_some_and(|temp| temp >= threshold - TEMP_HYSTERESIS_C);
+            self.update(kind, hot, cooled, &mut raised, || {
+                (
+                    NotificationPriority::High,
+                    format!("{name} at {:.0}°C", temp.unwrap_or_default()),
+                    "Running hot: lower the TDP or frame rate limit.".to_string(),
+                )
+            });
+        }
+
+        let cpu_throttled = settings.throttle_detection && self.cpu.record(reading.cpu_clock_mhz, reading.cpu_usage);
+        let gpu_throttled = settings.throttle_detection && self.gpu.record(reading.gpu_clock_mhz, reading.gpu_usage);
+        for (kind, name, throttled, clock, peak) in [
+            (
+                AlertKind::CpuThrottling,
+                "CPU",
+                cpu_throttled,
+                reading.cpu_clock_mhz,
+                self.cpu.peak_mhz,
+            ),
+            (
+                AlertKind::GpuThrottling,
+                "GPU",
+                gpu_throttled,
+                reading.gpu_clock_mhz,
+                self.gpu.peak_mhz,
+            ),
+        ] {
+            self.update(kind, throttled, !throttled, &mut raised, || {
+                (
+                    NotificationPriority::Normal,
+                    format!("{name} clock dropped"),
+                    format!(
+                        "{} MHz under load (peak {peak} MHz): thermal or power limit reached.",
+                        clock.unwrap_or_default()
+                    ),
+                )
+            });
+        }
+
+        raised
+    }
+
+    /// Raises `kind` when `on` starts, clears it once `recovered`.
+    fn update(
+        &mut self,
+        kind: AlertKind,
+        on: bool,
+        recovered: bool,
+        raised: &mut Vec<GameAlert>,
+        describe: impl FnOnce() -> (NotificationPriority, String, String),
+    ) {
+        let active = self.active.iter().any(|alert| alert.kind == kind);
+        if on && !active {
+            let (priority, title, message) = describe();
+            let alert = GameAlert {
+                kind,
+                priority,
+                title,
+                message,
+            };
+            self.active.push(alert.clone());
+            raised.push(alert);
+        } else if recovered && active {
+            self.active.retain(|alert| alert.kind != kind);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_fire_once_and_rearm() {
+        let settings = AlertSettings::default();
+        let mut monitor = AlertMonitor::new();
+        let mut reading = AlertReading {
+            battery_percent: Some(19),
+            gpu_temp_c: Some(88.0),
+            ..AlertReading::default()
+        };
+
+        let kinds = |alerts: Vec<GameAlert>| alerts.into_iter().map(|alert| alert.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds(monitor.check(&reading, &settings)),
+            [AlertKind::LowBattery, AlertKind::GpuTemperature]
+        );
+        assert!(monitor.check(&reading, &settings).is_empty());
+
+        // Still within the hysteresis: no re-arm
+        reading.gpu_temp_c = Some(85.0);
+        reading.battery_percent = Some(10);
+        assert_eq!(kinds(monitor.check(&reading, &settings)), [AlertKind::CriticalBattery]);
+        reading.gpu_temp_c = Some(88.0);
+        assert!(monitor.check(&reading, &settings).is_empty());
+
+        reading.gpu_temp_c = Some(80.0);
+        reading.charging = true;
+        monitor.check(&reading, &settings);
+        assert!(monitor.active().is_empty());
+    }
+
+    #[test]
+    fn test_throttling_needs_sustained_clock_drop_under_load() {
+        let settings = AlertSettings::default();
+        let mut monitor = AlertMonitor::new();
+        let reading = |clock: u32, usage: f32| AlertReading {
+            cpu_usage: usage,
+            cpu_clock_mhz: Some(clock),
+            ..AlertReading::default()
+        };
+
+        monitor.check(&reading(4000, 90.0), &settings);
+        // Idle clocks are not throttling
+        for _ in 0..THROTTLE_SAMPLES {
+            assert!(monitor.check(&reading(1200, 10.0), &settings).is_empty());
+        }
+        assert!(monitor.check(&reading(2500, 90.0), &settings).is_empty());
+        assert!(monitor.check(&reading(2500, 90.0), &settings).is_empty());
+        let raised = monitor.check(&reading(2500, 90.0), &settings);
+        assert_eq!(raised[0].kind, AlertKind::CpuThrottling);
+    }
+}
@@ -0,0 +1,107 @@
+/// Game artwork cache
+///
+/// Covers, heroes and logos are stored once per content (SHA-256 of the
+/// source image), so games sharing an image and repeated downloads cost
+/// nothing. Covers get smaller thumbnails for the library grid; an index
+/// maps each game to the images it uses.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Thumbnail widths generated for covers (grid card, detail view).
+pub const COVER_THUMBNAIL_WIDTHS: &[u32] = &[256, 600];
+
+/// An image in the cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredImage {
+    /// Content hash (hex SHA-256 of the source bytes)
+    pub hash: String,
+    /// File extension of the original (`jpg`, `png`, ...)
+    pub extension: String,
+    /// Widths of the PNG thumbnails generated next to it, ascending
+    #[serde(default)]
+    pub thumbnails: Vec<u32>,
+}
+
+impl StoredImage {
+    /// File name of the original.
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        format!("{}.{}", self.hash, self.extension)
+    }
+
+    /// File name of the thumbnail `width` pixels wide.
+    #[must_use]
+    pub fn thumbnail_file_name(&self, width: u32) -> String {
+        format!("{}_{width}.png", self.hash)
+    }
+
+    /// File shown in the library: the largest thumbnail, or the original
+    /// when it is already small.
+    #[must_use]
+    pub fn display_file_name(&self) -> String {
+        self.thumbnails
+            .last()
+            .map_or_else(|| self.file_name(), |&width| self.thumbnail_file_name(width))
+    }
+}
+
+/// Thumbnails to generate for an image `width` pixels wide (never upscaled).
+#[must_use]
+pub fn thumbnail_widths(width: u32) -> Vec<u32> {
+    COVER_THUMBNAIL_WIDTHS
+        .iter()
+        .copied()
+        .filter(|&thumbnail| thumbnail < width)
+        .collect()
+}
+
+/// The images one game uses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtworkEntry {
+    pub cover: Option<StoredImage>,
+    pub hero: Option<StoredImage>,
+    pub logo: Option<StoredImage>,
+}
+
+/// Game ID -> its images.
+pub type ArtworkIndex = HashMap<String, ArtworkEntry>;
+
+/// A thumbnail on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Thumbnail {
+    pub width: u32,
+    pub path: String,
+}
+
+/// Local artwork of a game (payload of `game-artwork-ready`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GameArtwork {
+    pub game_id: String,
+    /// Cover as shown in the library
+    pub image: Option<String>,
+    pub hero_image: Option<String>,
+    pub logo: Option<String>,
+    /// Every cover thumbnail, smallest first
+    pub cover_thumbnails: Vec<Thumbnail>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnails_are_never_upscaled() {
+        assert_eq!(thumbnail_widths(1200), vec![256, 600]);
+        assert_eq!(thumbnail_widths(600), vec![256]);
+        assert!(thumbnail_widths(64).is_empty());
+
+        let mut cover = StoredImage {
+            hash: "ab12".to_string(),
+            extension: "jpg".to_string(),
+            thumbnails: thumbnail_widths(1200),
+        };
+        assert_eq!(cover.display_file_name(), "ab12_600.png");
+        cover.thumbnails.clear();
+        assert_eq!(cover.display_file_name(), "ab12.jpg");
+    }
+}
@@ -0,0 +1,118 @@
+use serde::{Deserialize, Serialize};
+
+/// Task Scheduler path of the autostart task (folder `\Balam`).
+pub const AUTOSTART_TASK_NAME: &str = r"Balam\Autostart";
+
+/// Command-line flag: start with the main window hidden.
+pub const START_MINIMIZED_FLAG: &str = "--minimized";
+
+/// Command-line flag: open the game library instead of the home screen.
+pub const START_IN_LIBRARY_FLAG: &str = "--start-in-library";
+
+/// Command-line flag: minimal safe mode, set by the watchdog after a crash loop.
+pub const SAFE_MODE_FLAG: &str = "--safe-mode";
+
+/// User-selectable autostart behaviour.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct AutostartOptions {
+    /// Start hidden (tray / hotkey brings the UI up)
+    pub start_minimized: bool,
+    /// Open the game library directly (boot-to-Big-Picture)
+    pub start_in_library: bool,
+    /// Configure the FPS service to start with Windows
+    pub start_fps_service: bool,
+}
+
+impl AutostartOptions {
+    /// Command-line arguments passed to Balam by the autostart task.
+    #[must_use]
+    pub fn launch_arguments(&self) -> Vec<&'static str> {
+        let mut args = Vec::new();
+        if self.start_minimized {
+            args.push(START_MINIMIZED_FLAG);
+        }
+        if self.start_in_library {
+            args.push(START_IN_LIBRARY_FLAG);
+        }
+        args
+    }
+}
+
+/// Current autostart registration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutostartStatus {
+    /// Whether the logon task exists
+    pub enabled: bool,
+    /// Options of the registered task (defaults when disabled)
+    pub options: AutostartOptions,
+}
+
+/// How this process was launched (parsed from the command line).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LaunchOptions {
+    pub start_minimized: bool,
+    pub start_in_library: bool,
+    pub safe_mode: bool,
+}
+
+impl LaunchOptions {
+    /// Parses launch flags from command-line arguments (unknown flags are ignored).
+    #[must_use]
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut options = Self::default();
+        for arg in args {
+            match arg.as_ref() {
+                START_MINIMIZED_FLAG => options.start_minimized = true,
+                START_IN_LIBRARY_FLAG => options.start_in_library = true,
+                SAFE_MODE_FLAG => options.safe_mode = true,
+                _ => {},
+            }
+        }
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launch_arguments_follow_options() {
+        let options = AutostartOptions {
+            start_minimized: true,
+            start_in_library: true,
+            start_fps_service: true,
+        };
+        assert_eq!(
+            options.launch_arguments(),
+            vec![START_MINIMIZED_FLAG, START_IN_LIBRARY_FLAG]
+        );
+        assert!(AutostartOptions::default().launch_arguments().is_empty());
+    }
+
+    #[test]
+    fn test_launch_options_roundtrip() {
+        let options = AutostartOptions {
+            start_minimized: false,
+            start_in_library: true,
+            start_fps_service: false,
+        };
+        let parsed = LaunchOptions::from_args(options.launch_arguments());
+        assert!(!parsed.start_minimized);
+        assert!(parsed.start_in_library);
+    }
+
+    #[test]
+    fn test_launch_options_ignore_unknown_flags() {
+        let parsed = LaunchOptions::from_args(["balam.exe", "--console-mode", "--minimized"]);
+        assert!(parsed.start_minimized);
+        assert!(!parsed.start_in_library);
+        assert!(!parsed.safe_mode);
+        assert!(LaunchOptions::from_args(["balam.exe", SAFE_MODE_FLAG]).safe_mode);
+    }
+}
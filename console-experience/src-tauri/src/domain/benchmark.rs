@@ -0,0 +1,215 @@
+/// Benchmark domain
+///
+/// A benchmark run samples FPS, frame time, utilization and temperatures of
+/// the running game for a fixed duration. Runs are kept per game together
+/// with the TDP they ran at, so handheld TDP profiles can be compared.
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+pub const MIN_BENCHMARK_SECS: u32 = 10;
+pub const MAX_BENCHMARK_SECS: u32 = 600;
+/// Time between samples.
+pub const BENCHMARK_SAMPLE_INTERVAL_MS: u64 = 250;
+
+/// One reading taken during a run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkSample {
+    /// Milliseconds since the run started
+    pub elapsed_ms: u64,
+    pub fps: f32,
+    pub frame_time_ms: f32,
+    pub cpu_usage: f32,
+    pub gpu_usage: f32,
+    pub cpu_temp_c: Option<f32>,
+    pub gpu_temp_c: Option<f32>,
+    pub gpu_power_w: Option<f32>,
+}
+
+/// Summary statistics of a run.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkSummary {
+    pub avg_fps: f32,
+    /// Average FPS of the slowest 1% of frame times
+    pub low_1_percent_fps: f32,
+    /// Average FPS of the slowest 0.1% of frame times
+    pub low_0_1_percent_fps: f32,
+    pub min_fps: f32,
+    pub max_fps: f32,
+    pub avg_frame_time_ms: f32,
+    pub avg_cpu_usage: f32,
+    pub avg_gpu_usage: f32,
+    pub max_cpu_temp_c: Option<f32>,
+    pub max_gpu_temp_c: Option<f32>,
+    pub avg_gpu_power_w: Option<f32>,
+}
+
+fn average(values: impl Iterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = values.fold((0.0, 0u32), |(sum, count), value| (sum + value, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
+
+fn maximum(values: impl Iterator<Item = f32>) -> Option<f32> {
+    values.reduce(f32::max)
+}
+
+/// FPS of the slowest `fraction` of frame times (at least one sample).
+fn low_fps(sorted_frame_times: &[f32], fraction: f32) -> f32 {
+    let count = ((sorted_frame_times.len() as f32 * fraction).ceil() as usize).clamp(1, sorted_frame_times.len());
+    average(sorted_frame_times[..count].iter().copied())
+        .filter(|ms| *ms > 0.0)
+        .map_or(0.0, |ms| 1000.0 / ms)
+}
+
+impl BenchmarkSummary {
+    /// Statistics over the samples with a frame rate (menus and loading
+    /// screens without presents are skipped).
+    #[must_use]
+    pub fn from_samples(samples: &[BenchmarkSample]) -> Self {
+        let rendered: Vec<&BenchmarkSample> = samples.iter().filter(|s| s.fps > 0.0).collect();
+        if rendered.is_empty() {
+            return Self::default();
+        }
+        let mut frame_times: Vec<f32> = rendered.iter().map(|s| s.frame_time_ms).collect();
+        frame_times.sort_by(|a, b| b.total_cmp(a));
+        let avg_frame_time_ms = average(frame_times.iter().copied()).unwrap_or_default();
+
+        Self {
+            avg_fps: if avg_frame_time_ms > 0.0 {
+                1000.0 / avg_frame_time_ms
+            } else {
+                0.0
+            },
+            low_1_percent_fps: low_fps(&frame_times, 0.01),
+            low_0_1_percent_fps: low_fps(&frame_times, 0.001),
+            min_fps: rendered.iter().map(|s| s.fps).reduce(f32::min).unwrap_or_default(),
+            max_fps: maximum(rendered.iter().map(|s| s.fps)).unwrap_or_default(),
+            avg_frame_time_ms,
+            avg_cpu_usage: average(samples.iter().map(|s| s.cpu_usage)).unwrap_or_default(),
+            avg_gpu_usage: average(samples.iter().map(|s| s.gpu_usage)).unwrap_or_default(),
+            max_cpu_temp_c: maximum(samples.iter().filter_map(|s| s.cpu_temp_c)),
+            max_gpu_temp_c: maximum(samples.iter().filter_map(|s| s.gpu_temp_c)),
+            avg_gpu_power_w: average(samples.iter().filter_map(|s| s.gpu_power_w)),
+        }
+    }
+}
+
+/// A finished run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    pub id: String,
+    pub game_id: String,
+    pub game_title: String,
+    /// TDP in effect during the run (`None` without TDP control)
+    pub tdp_watts: Option<u32>,
+    /// Unix epoch seconds
+    pub started_at: u64,
+    pub duration_secs: u32,
+    /// Stopped before the planned duration
+    pub cancelled: bool,
+    pub summary: BenchmarkSummary,
+    pub samples: Vec<BenchmarkSample>,
+}
+
+impl BenchmarkRun {
+    /// One row per sample, for spreadsheets and `CapFrameX`-style tools.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        fn optional(value: Option<f32>) -> String {
+            value.map(|v| format!("{v:.1}")).unwrap_or_default()
+        }
+
+        let mut csv =
+            String::from("elapsed_ms,fps,frame_time_ms,cpu_usage,gpu_usage,cpu_temp_c,gpu_temp_c,gpu_power_w\n");
+        for s in &self.samples {
+            let _ = writeln!(
+                csv,
+                "{},{:.1},{:.2},{:.1},{:.1},{},{},{}",
+                s.elapsed_ms,
+                s.fps,
+                s.frame_time_ms,
+                s.cpu_usage,
+                s.gpu_usage,
+                optional(s.cpu_temp_c),
+                optional(s.gpu_temp_c),
+                optional(s.gpu_power_w)
+            );
+        }
+        csv
+    }
+}
+
+/// Run without its samples, for listing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRunInfo {
+    pub id: String,
+    pub game_id: String,
+    pub game_title: String,
+    pub tdp_watts: Option<u32>,
+    pub started_at: u64,
+    pub duration_secs: u32,
+    pub cancelled: bool,
+    pub summary: BenchmarkSummary,
+}
+
+impl From<&BenchmarkRun> for BenchmarkRunInfo {
+    fn from(run: &BenchmarkRun) -> Self {
+        Self {
+            id: run.id.clone(),
+            game_id: run.game_id.clone(),
+            game_title: run.game_title.clone(),
+            tdp_watts: run.tdp_watts,
+            started_at: run.started_at,
+            duration_secs: run.duration_secs,
+            cancelled: run.cancelled,
+            summary: run.summary.clone(),
+        }
+    }
+}
+
+/// Export format of `export_benchmark_run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BenchmarkExportFormat {
+    Csv,
+    Json,
+}
+
+/// Progress sent with `benchmark-progress`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkProgress {
+    pub game_id: String,
+    pub elapsed_secs: u32,
+    pub duration_secs: u32,
+    pub current_fps: f32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(fps: f32) -> BenchmarkSample {
+        BenchmarkSample {
+            fps,
+            frame_time_ms: if fps > 0.0 { 1000.0 / fps } else { 0.0 },
+            cpu_usage: 50.0,
+            ..BenchmarkSample::default()
+        }
+    }
+
+    #[test]
+    fn test_summary_lows() {
+        // 99 samples at 60 FPS and one 20 FPS stutter
+        let mut samples: Vec<_> = (0..99).map(|_| sample(60.0)).collect();
+        samples.push(sample(20.0));
+        samples.push(sample(0.0)); // loading screen, skipped
+
+        let summary = BenchmarkSummary::from_samples(&samples);
+        assert!((summary.low_1_percent_fps - 20.0).abs() < 0.01);
+        assert!((summary.low_0_1_percent_fps - 20.0).abs() < 0.01);
+        assert!((summary.min_fps - 20.0).abs() < 0.01);
+        assert!(summary.avg_fps > 58.0 && summary.avg_fps < 60.0);
+        assert!((summary.avg_cpu_usage - 50.0).abs() < 0.01);
+
+        assert_eq!(BenchmarkSummary::from_samples(&[]), BenchmarkSummary::default());
+    }
+}
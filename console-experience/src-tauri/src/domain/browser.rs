@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// Sites the store login browser may navigate to (each domain and its
+/// subdomains). Sign-in flows hop between a store and its identity provider,
+/// so both are listed.
+pub const STORE_BROWSER_ALLOWED_DOMAINS: &[&str] = &[
+    // Steam
+    "steampowered.com",
+    "steamcommunity.com",
+    // Epic Games
+    "epicgames.com",
+    "epicgames.dev",
+    // Xbox / Microsoft account
+    "xbox.com",
+    "live.com",
+    "microsoft.com",
+    "microsoftonline.com",
+    "msauth.net",
+    "msftauth.net",
+];
+
+/// Whether the store login browser may load a page.
+///
+/// Only `https` pages on [`STORE_BROWSER_ALLOWED_DOMAINS`] are allowed.
+#[must_use]
+pub fn is_store_browser_url_allowed(scheme: &str, host: &str) -> bool {
+    let host = host.trim_end_matches('.').to_ascii_lowercase();
+    scheme == "https"
+        && STORE_BROWSER_ALLOWED_DOMAINS.iter().any(|domain| {
+            host == *domain
+                || host
+                    .strip_suffix(domain)
+                    .is_some_and(|subdomain| subdomain.ends_with('.'))
+        })
+}
+
+/// Controller input the store browser emulates (it has no Balam UI to navigate).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BrowserAction {
+    /// Focus the previous link, button or field (Shift+Tab)
+    FocusPrevious,
+    /// Focus the next link, button or field (Tab)
+    FocusNext,
+    ScrollUp,
+    ScrollDown,
+    /// Click the focused element
+    Activate,
+    /// Previous page
+    Back,
+    /// Close the browser
+    Close,
+}
+
+impl BrowserAction {
+    /// Name understood by the page-side navigation helper.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::FocusPrevious => "focus_previous",
+            Self::FocusNext => "focus_next",
+            Self::ScrollUp => "scroll_up",
+            Self::ScrollDown => "scroll_down",
+            Self::Activate => "activate",
+            Self::Back => "back",
+            Self::Close => "close",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_store_browser_allow_list() {
+        assert!(is_store_browser_url_allowed("https", "store.steampowered.com"));
+        assert!(is_store_browser_url_allowed("https", "login.live.com"));
+        assert!(is_store_browser_url_allowed("https", "Epicgames.com."));
+
+        assert!(!is_store_browser_url_allowed("http", "store.steampowered.com"));
+        assert!(!is_store_browser_url_allowed("https", "evilsteampowered.com"));
+        assert!(!is_store_browser_url_allowed("https", "steampowered.com.example.org"));
+        assert!(!is_store_browser_url_allowed("https", "example.org"));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::domain::entities::game::Game;
+use crate::domain::value_objects::GamePlayStats;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// File in the app data folder caching provider answers.
+pub const COMPLETION_CACHE_FILE: &str = "completion_times.json";
+/// Completion times barely change; found titles are refreshed monthly.
+const FOUND_TTL_SECS: u64 = 30 * 24 * 60 * 60;
+/// Titles the provider did not know are retried weekly (new releases get added).
+const MISSING_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Average completion times of a title, in hours (`None` when not enough reports).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CompletionTimes {
+    pub main_story_hours: Option<f32>,
+    pub main_extra_hours: Option<f32>,
+    pub completionist_hours: Option<f32>,
+    /// Title the provider matched, shown so a wrong match is noticeable
+    pub matched_title: String,
+    /// Provider page for the title
+    pub source_url: Option<String>,
+}
+
+/// One cached lookup, including misses so unknown titles aren't queried on every visit.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CachedCompletion {
+    pub times: Option<CompletionTimes>,
+    /// Unix epoch seconds
+    pub fetched_at: u64,
+}
+
+/// Provider answers keyed by game ID.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct CompletionCache {
+    pub entries: BTreeMap<String, CachedCompletion>,
+}
+
+impl CompletionCache {
+    /// Cached entry for `game_id` if it is still fresh at `now`.
+    #[must_use]
+    pub fn fresh(&self, game_id: &str, now: u64) -> Option<&CachedCompletion> {
+        self.entries.get(game_id).filter(|entry| {
+            let ttl = if entry.times.is_some() {
+                FOUND_TTL_SECS
+            } else {
+                MISSING_TTL_SECS
+            };
+            now.saturating_sub(entry.fetched_at) < ttl
+        })
+    }
+}
+
+/// Lowercase alphanumeric words, for comparing store titles with provider titles
+/// ("DOOM Eternal™" and "Doom Eternal" both become "doom eternal").
+#[must_use]
+pub fn normalize_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether a provider result is the searched game and not a sequel or spin-off.
+#[must_use]
+pub fn is_title_match(searched: &str, candidate: &str) -> bool {
+    let searched = normalize_title(searched);
+    !searched.is_empty() && searched == normalize_title(candidate)
+}
+
+/// Everything the game page shows about one game.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GameDetails {
+    pub game: Game,
+    pub favorite: bool,
+    pub hidden: bool,
+    pub tags: BTreeSet<String>,
+    pub play_stats: GamePlayStats,
+    /// `None` when disabled in the settings, unknown to the provider or offline
+    pub completion_times: Option<CompletionTimes>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_matching_ignores_symbols_and_case() {
+        assert_eq!(normalize_title("DOOM Eternal™"), "doom eternal");
+        assert!(is_title_match("Half-Life 2", "Half Life 2"));
+        assert!(!is_title_match("Half-Life 2", "Half-Life 2: Episode One"));
+        assert!(!is_title_match("™", "™"));
+    }
+
+    #[test]
+    fn test_cache_freshness_depends_on_hit() {
+        let mut cache = CompletionCache::default();
+        let hit = CompletionTimes {
+            main_story_hours: Some(25.0),
+            ..CompletionTimes::default()
+        };
+        cache.entries.insert(
+            "steam_1".to_string(),
+            CachedCompletion {
+                times: Some(hit),
+                fetched_at: 0,
+            },
+        );
+        cache.entries.insert("steam_2".to_string(), CachedCompletion::default());
+
+        let ten_days = 10 * 24 * 60 * 60;
+        assert!(cache.fresh("steam_1", ten_days).is_some());
+        assert!(cache.fresh("steam_2", ten_days).is_none());
+        assert!(cache.fresh("steam_3", 0).is_none());
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+
+/// Default Windows shell restored when console mode is disabled.
+pub const EXPLORER_SHELL: &str = "explorer.exe";
+
+/// Command-line flag passed to the watchdog when it runs as the Windows shell.
+pub const SHELL_SUPERVISOR_FLAG: &str = "--shell";
+
+/// Command-line flag passed to Balam when it was started by the shell supervisor.
+pub const CONSOLE_MODE_FLAG: &str = "--console-mode";
+
+/// Named pipe served by the watchdog; Balam connects as the heartbeat client.
+pub const HEARTBEAT_PIPE_NAME: &str = r"\\.\pipe\balam_heartbeat";
+
+/// Bit set in a heartbeat frame while Balam's UI thread is not responding.
+const UI_HANG_BIT: u64 = 1 << 63;
+
+/// One heartbeat written to the watchdog pipe (a big-endian `u64`).
+///
+/// The low bits carry the Unix timestamp in seconds and the top bit flags a
+/// hung UI thread. The process keeps sending heartbeats while the UI is hung
+/// (they come from a background task), so the watchdog can tell a frozen
+/// window from a dead process. Plain timestamps decode as responsive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeartbeatFrame {
+    pub timestamp_secs: u64,
+    pub ui_responsive: bool,
+}
+
+impl HeartbeatFrame {
+    #[must_use]
+    pub fn encode(self) -> u64 {
+        let timestamp = self.timestamp_secs & !UI_HANG_BIT;
+        if self.ui_responsive {
+            timestamp
+        } else {
+            timestamp | UI_HANG_BIT
+        }
+    }
+
+    #[must_use]
+    pub fn decode(value: u64) -> Self {
+        Self {
+            timestamp_secs: value & !UI_HANG_BIT,
+            ui_responsive: value & UI_HANG_BIT == 0,
+        }
+    }
+}
+
+/// Registry hive where the Winlogon `Shell` value is replaced.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ShellScope {
+    /// Only the current (dedicated) user boots into Balam (HKCU, no admin needed)
+    CurrentUser,
+    /// Every user on the machine boots into Balam (HKLM, requires admin)
+    AllUsers,
+}
+
+impl ShellScope {
+    /// Whether changing this scope requires administrator privileges.
+    #[must_use]
+    pub fn requires_elevation(&self) -> bool {
+        matches!(self, Self::AllUsers)
+    }
+}
+
+/// Current state of shell replacement ("console mode").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ConsoleModeStatus {
+    /// Whether Balam is registered as the shell in any scope
+    pub enabled: bool,
+    /// Scope where Balam is registered (per-user wins over machine-wide)
+    pub scope: Option<ShellScope>,
+    /// Effective shell command for the current user
+    pub current_shell: String,
+    /// Whether this process was started by the shell supervisor
+    pub running_as_shell: bool,
+}
+
+/// Builds the Winlogon `Shell` command that starts the boot supervisor.
+#[must_use]
+pub fn supervisor_shell_command(supervisor_path: &str) -> String {
+    format!("\"{supervisor_path}\" {SHELL_SUPERVISOR_FLAG}")
+}
+
+/// Checks whether a Winlogon `Shell` value points to the Balam supervisor.
+#[must_use]
+pub fn is_balam_shell(shell: &str) -> bool {
+    let lower = shell.to_lowercase();
+    lower.contains("watchdog") && lower.contains(SHELL_SUPERVISOR_FLAG)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supervisor_command_is_recognized() {
+        let command = supervisor_shell_command(r"C:\Program Files\Balam\watchdog.exe");
+        assert_eq!(command, r#""C:\Program Files\Balam\watchdog.exe" --shell"#);
+        assert!(is_balam_shell(&command));
+    }
+
+    #[test]
+    fn test_explorer_is_not_balam_shell() {
+        assert!(!is_balam_shell(EXPLORER_SHELL));
+    }
+
+    #[test]
+    fn test_heartbeat_frame_roundtrip() {
+        let hung = HeartbeatFrame {
+            timestamp_secs: 1_738_000_000,
+            ui_responsive: false,
+        };
+        assert_eq!(HeartbeatFrame::decode(hung.encode()), hung);
+
+        // Frames from older clients are bare timestamps
+        let legacy = HeartbeatFrame::decode(1_738_000_000);
+        assert!(legacy.ui_responsive);
+        assert_eq!(legacy.timestamp_secs, 1_738_000_000);
+    }
+
+    #[test]
+    fn test_scope_elevation() {
+        assert!(ShellScope::AllUsers.requires_elevation());
+        assert!(!ShellScope::CurrentUser.requires_elevation());
+    }
+}
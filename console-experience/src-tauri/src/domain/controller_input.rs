@@ -0,0 +1,262 @@
+//! Raw HID input of DualSense and Switch Pro controllers: the gyro,
+//! touchpad and extra buttons that XInput and gilrs do not expose.
+//!
+//! Report layouts follow the Linux `hid-playstation` / `hid-nintendo` drivers.
+
+use serde::{Deserialize, Serialize};
+
+const SONY_VENDOR_ID: u16 = 0x054C;
+const NINTENDO_VENDOR_ID: u16 = 0x057E;
+
+/// Raw gyro counts per degree per second (both controllers use a ±2000°/s range).
+const GYRO_COUNTS_PER_DPS: f32 = 16.384;
+/// Below this rate the gyro is treated as still (sensor drift).
+pub const GYRO_DEADZONE_DPS: f32 = 1.5;
+/// Mouse pixels per degree of rotation at sensitivity 1.0.
+const PIXELS_PER_DEGREE: f32 = 12.0;
+
+/// DualSense touchpad resolution.
+const TOUCHPAD_WIDTH: f32 = 1920.0;
+const TOUCHPAD_HEIGHT: f32 = 1080.0;
+
+/// Accepted range of `controller.gyro_sensitivity`.
+pub const MIN_GYRO_SENSITIVITY: f32 = 0.1;
+pub const MAX_GYRO_SENSITIVITY: f32 = 5.0;
+
+/// Controllers with a raw HID backend.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum HidControllerKind {
+    DualSense,
+    DualSenseEdge,
+    SwitchPro,
+}
+
+impl HidControllerKind {
+    /// Identifies a supported controller from its USB/Bluetooth ids.
+    #[must_use]
+    pub fn from_ids(vendor_id: u16, product_id: u16) -> Option<Self> {
+        match (vendor_id, product_id) {
+            (SONY_VENDOR_ID, 0x0CE6) => Some(Self::DualSense),
+            (SONY_VENDOR_ID, 0x0DF2) => Some(Self::DualSenseEdge),
+            (NINTENDO_VENDOR_ID, 0x2009) => Some(Self::SwitchPro),
+            _ => None,
+        }
+    }
+}
+
+/// Button only readable through raw HID.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ExtraButton {
+    /// DualSense touchpad click
+    Touchpad,
+    /// DualSense microphone button
+    Mute,
+    /// DualSense Edge back paddles and function buttons
+    LeftPaddle,
+    RightPaddle,
+    LeftFunction,
+    RightFunction,
+    /// Switch Pro Home and Capture
+    Home,
+    Capture,
+}
+
+impl ExtraButton {
+    pub const ALL: [Self; 8] = [
+        Self::Touchpad,
+        Self::Mute,
+        Self::LeftPaddle,
+        Self::RightPaddle,
+        Self::LeftFunction,
+        Self::RightFunction,
+        Self::Home,
+        Self::Capture,
+    ];
+
+    /// Bit of this button in [`MotionState::buttons`].
+    #[must_use]
+    pub fn mask(self) -> u16 {
+        1 << (self as u16)
+    }
+}
+
+/// Main-window navigation an extra button can trigger (the `nav` event payloads).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NavAction {
+    Confirm,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    Menu,
+}
+
+impl NavAction {
+    #[must_use]
+    pub fn event_payload(self) -> &'static str {
+        match self {
+            Self::Confirm => "CONFIRM",
+            Self::Back => "BACK",
+            Self::Up => "UP",
+            Self::Down => "DOWN",
+            Self::Left => "LEFT",
+            Self::Right => "RIGHT",
+            Self::Menu => "MENU",
+        }
+    }
+}
+
+/// Extra button bound to a navigation action.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExtraButtonBinding {
+    pub button: ExtraButton,
+    pub action: NavAction,
+}
+
+/// One decoded input report.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MotionState {
+    /// Held extra buttons ([`ExtraButton::mask`] bits)
+    pub buttons: u16,
+    /// Angular velocity in °/s: pitch (tilt up), yaw (turn left), roll
+    pub gyro_dps: [f32; 3],
+    /// First touchpad contact, normalized 0..1 (`None` when not touched)
+    pub touch: Option<(f32, f32)>,
+}
+
+impl MotionState {
+    #[must_use]
+    pub fn is_held(&self, button: ExtraButton) -> bool {
+        self.buttons & button.mask() != 0
+    }
+}
+
+fn i16_at(report: &[u8], offset: usize) -> f32 {
+    f32::from(i16::from_le_bytes([report[offset], report[offset + 1]]))
+}
+
+fn set_if(buttons: &mut u16, held: bool, button: ExtraButton) {
+    if held {
+        *buttons |= button.mask();
+    }
+}
+
+/// Decodes a DualSense input report: `0x01` over USB, `0x31` over Bluetooth
+/// (same layout, one byte further). Other reports return `None`.
+#[must_use]
+pub fn parse_dualsense(report: &[u8]) -> Option<MotionState> {
+    let base = match report.first()? {
+        0x01 if report.len() >= 64 => 1,
+        0x31 if report.len() >= 78 => 2,
+        _ => return None,
+    };
+
+    let mut buttons = 0;
+    let misc = report[base + 9];
+    set_if(&mut buttons, misc & 0x02 != 0, ExtraButton::Touchpad);
+    set_if(&mut buttons, misc & 0x04 != 0, ExtraButton::Mute);
+    set_if(&mut buttons, misc & 0x10 != 0, ExtraButton::LeftFunction);
+    set_if(&mut buttons, misc & 0x20 != 0, ExtraButton::RightFunction);
+    set_if(&mut buttons, misc & 0x40 != 0, ExtraButton::LeftPaddle);
+    set_if(&mut buttons, misc & 0x80 != 0, ExtraButton::RightPaddle);
+
+    let gyro = base + 15;
+    let gyro_dps = [
+        i16_at(report, gyro) / GYRO_COUNTS_PER_DPS,
+        i16_at(report, gyro + 2) / GYRO_COUNTS_PER_DPS,
+        i16_at(report, gyro + 4) / GYRO_COUNTS_PER_DPS,
+    ];
+
+    // Contact: bit 7 set = finger lifted; 12-bit X and Y packed in 3 bytes
+    let contact = &report[base + 32..base + 36];
+    let touch = (contact[0] & 0x80 == 0).then(|| {
+        let x = u16::from(contact[1]) | (u16::from(contact[2] & 0x0F) << 8);
+        let y = u16::from(contact[2] >> 4) | (u16::from(contact[3]) << 4);
+        (
+            (f32::from(x) / TOUCHPAD_WIDTH).min(1.0),
+            (f32::from(y) / TOUCHPAD_HEIGHT).min(1.0),
+        )
+    });
+
+    Some(MotionState {
+        buttons,
+        gyro_dps,
+        touch,
+    })
+}
+
+/// Decodes a Switch Pro full input report (`0x30`, enabled by the
+/// handshake); the first of its three IMU samples is used.
+#[must_use]
+pub fn parse_switch_pro(report: &[u8]) -> Option<MotionState> {
+    if report.first() != Some(&0x30) || report.len() < 25 {
+        return None;
+    }
+    let mut buttons = 0;
+    set_if(&mut buttons, report[4] & 0x10 != 0, ExtraButton::Home);
+    set_if(&mut buttons, report[4] & 0x20 != 0, ExtraButton::Capture);
+
+    // IMU axes: X along the grips (roll), Y across (pitch), Z up (yaw)
+    Some(MotionState {
+        buttons,
+        gyro_dps: [
+            i16_at(report, 21) / GYRO_COUNTS_PER_DPS,
+            i16_at(report, 23) / GYRO_COUNTS_PER_DPS,
+            i16_at(report, 19) / GYRO_COUNTS_PER_DPS,
+        ],
+        touch: None,
+    })
+}
+
+/// Mouse movement for `seconds` of rotation: yaw moves horizontally,
+/// pitch vertically (tilting up moves the cursor up).
+#[must_use]
+pub fn gyro_mouse_delta(gyro_dps: [f32; 3], seconds: f32, sensitivity: f32) -> (f32, f32) {
+    let axis = |dps: f32| {
+        if dps.abs() < GYRO_DEADZONE_DPS {
+            0.0
+        } else {
+            dps * seconds * PIXELS_PER_DEGREE * sensitivity
+        }
+    };
+    (-axis(gyro_dps[1]), -axis(gyro_dps[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dualsense_usb_report() {
+        let mut report = [0u8; 64];
+        report[0] = 0x01;
+        report[10] = 0x02 | 0x40; // touchpad click + left paddle
+        report[16..18].copy_from_slice(&(-1638i16).to_le_bytes()); // pitch -100°/s
+        report[33] = 0x01; // finger down
+        report[34] = 0x80; // x = 0x780 = 1920 (low byte)
+        report[35] = 0x07 | (0x8 << 4); // x high nibble, y low nibble
+        report[36] = 0x21; // y = 0x218 = 536
+
+        let state = parse_dualsense(&report).unwrap();
+        assert!(state.is_held(ExtraButton::Touchpad));
+        assert!(state.is_held(ExtraButton::LeftPaddle));
+        assert!(!state.is_held(ExtraButton::Mute));
+        assert!((state.gyro_dps[0] + 100.0).abs() < 0.1);
+        let (x, y) = state.touch.unwrap();
+        assert!((x - 1.0).abs() < 0.001 && (y - 536.0 / 1080.0).abs() < 0.001);
+
+        report[33] = 0x80;
+        assert_eq!(parse_dualsense(&report).unwrap().touch, None);
+        assert_eq!(parse_dualsense(&[0x05; 64]), None);
+    }
+
+    #[test]
+    fn test_gyro_mouse_delta_deadzone() {
+        assert_eq!(gyro_mouse_delta([0.5, -1.0, 30.0], 0.01, 1.0), (0.0, 0.0));
+        let (dx, dy) = gyro_mouse_delta([10.0, -100.0, 0.0], 0.01, 1.0);
+        assert!((dx - 12.0).abs() < 0.001);
+        assert!((dy + 1.2).abs() < 0.001);
+    }
+}
@@ -0,0 +1,316 @@
+use serde::Deserialize;
+
+/// Manifest extensions read from the `scanners.d` folder.
+pub const MANIFEST_EXTENSIONS: &[&str] = &["json", "toml"];
+
+/// Executables never listed as games (uninstallers, crash reporters, setups),
+/// matched case-insensitively against the file name.
+pub const EXCLUDED_EXECUTABLES: &[&str] = &["unins*.exe", "*crash*.exe", "*setup*.exe", "*redist*.exe"];
+
+/// A user-provided scanner for a store or launcher the app does not know.
+///
+/// Dropped as `<key>.toml` or `<key>.json` into the `scanners.d` folder and
+/// read on every scan, so edits apply without restarting:
+///
+/// ```toml
+/// name = "Amazon Games"
+/// key = "amazon"
+/// launch = "amazon-games://play/{id}"
+///
+/// [[glob]]
+/// pattern = "%ProgramFiles%\\Amazon Games\\Library\\*\\*.exe"
+///
+/// [[registry]]
+/// root = "HKCU"
+/// key = "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall"
+/// subkeys = "AmazonGames/*"
+/// executable = "*.exe"
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ScannerManifest {
+    /// Store name, shown in logs
+    pub name: String,
+    /// Game ID prefix (`custom_<key>_<id>`); lowercase letters, digits and `-`
+    pub key: String,
+    /// Protocol URL template (`scheme:...`) opened instead of the executable;
+    /// `{id}`, `{path}` and `{dir}` are filled percent-encoded. The executable
+    /// is still watched by name
+    #[serde(default)]
+    pub launch: Option<String>,
+    /// Extra executable name patterns never listed
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    #[serde(default)]
+    pub glob: Vec<GlobRule>,
+    #[serde(default)]
+    pub registry: Vec<RegistryRule>,
+}
+
+/// Executables matching a path pattern (`*` and `?` within a folder name,
+/// `**` for any number of folders, `%VAR%` environment variables).
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct GlobRule {
+    pub pattern: String,
+    #[serde(default)]
+    pub title: TitleSource,
+}
+
+/// Where a glob match takes its title from.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleSource {
+    /// Name of the folder holding the executable
+    #[default]
+    Folder,
+    /// Executable name without extension
+    FileStem,
+}
+
+/// Games listed under a registry key, one per subkey.
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryRule {
+    pub root: RegistryRoot,
+    pub key: String,
+    /// Subkey name pattern; without it, `key` itself is the only game
+    #[serde(default)]
+    pub subkeys: Option<String>,
+    #[serde(default = "default_title_value")]
+    pub title_value: String,
+    #[serde(default = "default_path_value")]
+    pub path_value: String,
+    /// Value holding the `{id}` of the launch template (default: subkey name)
+    #[serde(default)]
+    pub id_value: Option<String>,
+    /// Executable pattern, relative to the install folder
+    pub executable: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+pub enum RegistryRoot {
+    #[serde(rename = "HKLM")]
+    LocalMachine,
+    #[serde(rename = "HKCU")]
+    CurrentUser,
+}
+
+fn default_title_value() -> String {
+    "DisplayName".to_string()
+}
+
+fn default_path_value() -> String {
+    "InstallLocation".to_string()
+}
+
+impl ScannerManifest {
+    /// Parses a manifest by its file extension.
+    ///
+    /// # Errors
+    /// Returns a readable reason when the format is unknown, the content does
+    /// not parse or the manifest is invalid.
+    pub fn parse(extension: &str, content: &str) -> Result<Self, String> {
+        let manifest: Self = match extension.to_ascii_lowercase().as_str() {
+            "json" => serde_json::from_str(content).map_err(|e| e.to_string())?,
+            "toml" => toml::from_str(content).map_err(|e| e.to_string())?,
+            other => return Err(format!("Unsupported manifest format: .{other}")),
+        };
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.key.is_empty()
+            || !self
+                .key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(format!(
+                "Invalid key \"{}\": use lowercase letters, digits and '-'",
+                self.key
+            ));
+        }
+        if self.glob.is_empty() && self.registry.is_empty() {
+            return Err("No [[glob]] or [[registry]] rule".to_string());
+        }
+        if let Some(launch) = &self.launch {
+            url_scheme(launch)?;
+        }
+        Ok(())
+    }
+
+    /// Whether an executable name is excluded by default or by this manifest.
+    #[must_use]
+    pub fn is_excluded(&self, file_name: &str) -> bool {
+        EXCLUDED_EXECUTABLES
+            .iter()
+            .copied()
+            .chain(self.exclude.iter().map(String::as_str))
+            .any(|pattern| wildcard_match(pattern, file_name))
+    }
+}
+
+/// Game ID of a custom scanner game.
+#[must_use]
+pub fn custom_game_id(key: &str, raw_id: &str) -> String {
+    format!("custom_{key}_{raw_id}")
+}
+
+/// `(key, raw_id)` of a custom scanner game ID.
+#[must_use]
+pub fn parse_custom_game_id(id: &str) -> Option<(&str, &str)> {
+    id.strip_prefix("custom_")?.split_once('_')
+}
+
+/// Scheme of a launch template, which must be a protocol URL: never a file
+/// or a command line.
+fn url_scheme(template: &str) -> Result<&str, String> {
+    let scheme = template.split_once(':').map_or("", |(scheme, _)| scheme);
+    // One letter would be a drive (`C:\game.exe`)
+    let valid = scheme.len() > 1
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if valid && !scheme.eq_ignore_ascii_case("file") {
+        Ok(scheme)
+    } else {
+        Err(format!(
+            "Launch template \"{template}\" must be a protocol URL (e.g. store://play/{{id}})"
+        ))
+    }
+}
+
+/// Percent-encodes everything but unreserved URL characters (RFC 3986).
+fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+                (b as char).to_string()
+            } else {
+                format!("%{b:02X}")
+            }
+        })
+        .collect()
+}
+
+/// Launch URL with its `{id}`, `{path}` and `{dir}` placeholders filled,
+/// percent-encoded so a value can never change the URL's meaning.
+///
+/// # Errors
+/// Returns error if the template is not a protocol URL.
+pub fn render_launch(template: &str, id: &str, path: &str, dir: &str) -> Result<String, String> {
+    url_scheme(template)?;
+    Ok(template
+        .replace("{id}", &percent_encode(id))
+        .replace("{path}", &percent_encode(path))
+        .replace("{dir}", &percent_encode(dir)))
+}
+
+/// Case-insensitive match of one name against a pattern with `*` (any run of
+/// characters) and `?` (one character).
+#[must_use]
+pub fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let name: Vec<char> = name.to_lowercase().chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position after the last `*` and the name position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                p += 1;
+                backtrack = Some((p, n));
+            },
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            },
+            _ => match backtrack {
+                Some((star_p, star_n)) => {
+                    p = star_p;
+                    n = star_n + 1;
+                    backtrack = Some((star_p, star_n + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Whether a path segment of a glob pattern contains wildcards.
+#[must_use]
+pub fn has_wildcards(segment: &str) -> bool {
+    segment.contains(['*', '?'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.exe", "Game.EXE"));
+        assert!(wildcard_match("unins*.exe", "unins000.exe"));
+        assert!(wildcard_match("AmazonGames/*", "AmazonGames/Some Game"));
+        assert!(wildcard_match("game??.exe", "game64.exe"));
+        assert!(wildcard_match("*crash*.exe", "UnityCrashHandler64.exe"));
+        assert!(!wildcard_match("*.exe", "game.dll"));
+        assert!(!wildcard_match("game?.exe", "game.exe"));
+    }
+
+    #[test]
+    fn test_parse_manifests() {
+        let toml = r#"
+            name = "Amazon Games"
+            key = "amazon"
+            launch = "amazon-games://play/{id}"
+
+            [[registry]]
+            root = "HKCU"
+            key = "Software\\Microsoft\\Windows\\CurrentVersion\\Uninstall"
+            subkeys = "AmazonGames/*"
+            executable = "*.exe"
+        "#;
+        let manifest = ScannerManifest::parse("toml", toml).unwrap();
+        assert_eq!(manifest.registry[0].root, RegistryRoot::CurrentUser);
+        assert_eq!(manifest.registry[0].title_value, "DisplayName");
+        assert!(manifest.is_excluded("unins000.exe"));
+
+        let json = r#"{ "name": "Indie", "key": "indie", "glob": [{ "pattern": "D:\\Indie\\*\\*.exe", "title": "file_stem" }] }"#;
+        let manifest = ScannerManifest::parse("json", json).unwrap();
+        assert_eq!(manifest.glob[0].title, TitleSource::FileStem);
+
+        assert!(ScannerManifest::parse("json", r#"{ "name": "x", "key": "Bad_Key", "glob": [] }"#).is_err());
+        assert!(ScannerManifest::parse("json", r#"{ "name": "x", "key": "empty" }"#).is_err());
+        assert!(ScannerManifest::parse("yaml", "").is_err());
+    }
+
+    #[test]
+    fn test_custom_game_ids_and_launch_templates() {
+        let id = custom_game_id("amazon", "amzn1.adg.product.1234");
+        assert_eq!(parse_custom_game_id(&id), Some(("amazon", "amzn1.adg.product.1234")));
+        assert_eq!(parse_custom_game_id("steam_570"), None);
+
+        assert_eq!(
+            render_launch("amazon-games://play/{id}", "1234", "C:\\g.exe", "C:\\"),
+            Ok("amazon-games://play/1234".to_string())
+        );
+    }
+
+    #[test]
+    fn test_launch_values_cannot_escape_the_url() {
+        assert_eq!(
+            render_launch("store://run?path={path}", "1", "C:\\Tom & Jerry\\%x%|^.exe", "").unwrap(),
+            "store://run?path=C%3A%5CTom%20%26%20Jerry%5C%25x%25%7C%5E.exe"
+        );
+        assert!(render_launch("{path}", "1", "C:\\g.exe", "").is_err());
+        assert!(render_launch("C:\\Tools\\run.exe {id}", "1", "", "").is_err());
+        assert!(render_launch("file:///C:/g.exe", "1", "", "").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::update::UpdateInfo;
+
+/// How long a weather report is shown before it is fetched again.
+pub const WEATHER_MAX_AGE_SECS: u64 = 30 * 60;
+
+/// Home screen tiles: clock, weather and what is waiting to be updated.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardSettings {
+    pub weather_provider: WeatherProvider,
+    /// Latitude of the weather location (-90 - 90)
+    pub latitude: f64,
+    /// Longitude of the weather location (-180 - 180)
+    pub longitude: f64,
+    /// Shown on the weather tile (e.g. "Madrid")
+    pub location_name: String,
+    pub temperature_unit: TemperatureUnit,
+}
+
+/// Weather source; off by default since it sends the location to a third party.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeatherProvider {
+    #[default]
+    Disabled,
+    /// api.open-meteo.com (no API key)
+    OpenMeteo,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl DashboardSettings {
+    /// # Errors
+    /// Returns error for coordinates out of range.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(-90.0..=90.0).contains(&self.latitude) || !(-180.0..=180.0).contains(&self.longitude) {
+            return Err(format!(
+                "Weather location {}, {} out of range",
+                self.latitude, self.longitude
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Local time of the machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClockInfo {
+    /// Unix time in milliseconds
+    pub unix_ms: i64,
+    /// Offset of local time from UTC, in minutes (daylight saving included)
+    pub utc_offset_minutes: i32,
+    /// IANA name (e.g. "Europe/Madrid"); empty when unknown
+    pub timezone: String,
+}
+
+impl ClockInfo {
+    #[must_use]
+    pub fn at(now: DateTime<Local>, timezone: String) -> Self {
+        Self {
+            unix_ms: now.timestamp_millis(),
+            utc_offset_minutes: now.offset().local_minus_utc() / 60,
+            timezone,
+        }
+    }
+}
+
+/// Condition shown by the weather tile's icon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeatherCondition {
+    Clear,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Drizzle,
+    Rain,
+    Snow,
+    Thunderstorm,
+    Unknown,
+}
+
+impl WeatherCondition {
+    /// Condition of a WMO weather interpretation code (as reported by Open-Meteo).
+    #[must_use]
+    pub fn from_wmo_code(code: u32) -> Self {
+        match code {
+            0 => Self::Clear,
+            1 | 2 => Self::PartlyCloudy,
+            3 => Self::Cloudy,
+            45 | 48 => Self::Fog,
+            51..=57 => Self::Drizzle,
+            61..=67 | 80..=82 => Self::Rain,
+            71..=77 | 85 | 86 => Self::Snow,
+            95..=99 => Self::Thunderstorm,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Current weather at the configured location.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WeatherReport {
+    pub temperature: f64,
+    pub unit: TemperatureUnit,
+    pub condition: WeatherCondition,
+    pub location: String,
+    /// Unix seconds of the fetch
+    pub fetched_at_secs: u64,
+}
+
+/// What the home screen says about updates.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UpdateSummary {
+    pub current_version: String,
+    /// Newer release found by the last check
+    pub latest_version: Option<String>,
+    /// The release feed was checked since startup
+    pub checked: bool,
+    /// `apply_update` is downloading or verifying
+    pub installing: bool,
+}
+
+impl UpdateSummary {
+    #[must_use]
+    pub fn new(current_version: &str, last_check: Option<&UpdateInfo>, installing: bool) -> Self {
+        Self {
+            current_version: current_version.to_string(),
+            latest_version: last_check
+                .filter(|info| info.available)
+                .map(|info| info.latest_version.clone()),
+            checked: last_check.is_some(),
+            installing,
+        }
+    }
+}
+
+/// Payload of `get_dashboard_data` and of the `dashboard-updated` event.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DashboardData {
+    pub clock: ClockInfo,
+    /// `None` while disabled, not fetched yet or unreachable for too long
+    pub weather: Option<WeatherReport>,
+    pub updates: UpdateSummary,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wmo_codes_map_to_conditions() {
+        assert_eq!(WeatherCondition::from_wmo_code(0), WeatherCondition::Clear);
+        assert_eq!(WeatherCondition::from_wmo_code(2), WeatherCondition::PartlyCloudy);
+        assert_eq!(WeatherCondition::from_wmo_code(48), WeatherCondition::Fog);
+        assert_eq!(WeatherCondition::from_wmo_code(81), WeatherCondition::Rain);
+        assert_eq!(WeatherCondition::from_wmo_code(86), WeatherCondition::Snow);
+        assert_eq!(WeatherCondition::from_wmo_code(96), WeatherCondition::Thunderstorm);
+        assert_eq!(WeatherCondition::from_wmo_code(42), WeatherCondition::Unknown);
+    }
+
+    #[test]
+    fn test_settings_validation_and_update_summary() {
+        let mut settings = DashboardSettings {
+            weather_provider: WeatherProvider::OpenMeteo,
+            latitude: 40.4,
+            longitude: -3.7,
+            ..DashboardSettings::default()
+        };
+        assert!(settings.validate().is_ok());
+        settings.longitude = 200.0;
+        assert!(settings.validate().is_err());
+
+        assert!(!UpdateSummary::new("1.0.0", None, false).checked);
+    }
+}
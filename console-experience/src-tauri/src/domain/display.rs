@@ -94,6 +94,213 @@ impl RefreshRateConfig {
     }
 }
 
+/// Resolution and refresh rate of a display mode.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_hz: u32,
+}
+
+impl DisplayMode {
+    /// Same mode at another resolution.
+    #[must_use]
+    pub fn with_resolution(self, width: u32, height: u32) -> Self {
+        Self { width, height, ..self }
+    }
+
+    /// Same mode at another refresh rate.
+    #[must_use]
+    pub fn with_refresh_rate(self, refresh_hz: u32) -> Self {
+        Self { refresh_hz, ..self }
+    }
+}
+
+/// How a game's window covers the screen.
+///
+/// The TOPMOST overlay can only draw over games that do not own the display:
+/// switching a game to `Borderless` makes the overlay work for titles that
+/// start in exclusive fullscreen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GameWindowMode {
+    /// Framed window
+    Windowed,
+    /// Frameless window covering its monitor
+    Borderless,
+    /// The game owns the display (DXGI/D3D9 exclusive mode)
+    ExclusiveFullscreen,
+}
+
+/// Domain entity representing a display color adjustment (night light, per-game profiles).
+///
+/// Temperature and gamma are applied as a gamma ramp on every display;
+/// vibrance needs driver support (NVIDIA digital vibrance).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ColorProfile {
+    /// White point in Kelvin (6500 = neutral, lower = warmer)
+    pub temperature_k: u32,
+    /// Gamma correction (1.0 = neutral, higher = brighter shadows)
+    pub gamma: f32,
+    /// Saturation boost (0 = driver default, 100 = maximum vibrance)
+    pub vibrance: u32,
+}
+
+impl ColorProfile {
+    /// Neutral white point (sRGB D65).
+    pub const NEUTRAL_TEMPERATURE_K: u32 = 6500;
+    /// Warmest temperature; Windows rejects gamma ramps that stray further from identity.
+    pub const MIN_TEMPERATURE_K: u32 = 3000;
+    pub const MAX_TEMPERATURE_K: u32 = 10000;
+    pub const GAMMA_RANGE: (f32, f32) = (0.5, 2.0);
+
+    /// Profile that leaves colors untouched.
+    #[must_use]
+    pub fn neutral() -> Self {
+        Self {
+            temperature_k: Self::NEUTRAL_TEMPERATURE_K,
+            gamma: 1.0,
+            vibrance: 0,
+        }
+    }
+
+    /// Night light: warm white point, no other change.
+    #[must_use]
+    pub fn warm(temperature_k: u32) -> Self {
+        Self {
+            temperature_k,
+            ..Self::neutral()
+        }
+    }
+
+    /// Validates value ranges.
+    ///
+    /// # Errors
+    /// Returns error describing the first out-of-range field.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(Self::MIN_TEMPERATURE_K..=Self::MAX_TEMPERATURE_K).contains(&self.temperature_k) {
+            return Err(format!(
+                "Color temperature {}K out of range ({}K - {}K)",
+                self.temperature_k,
+                Self::MIN_TEMPERATURE_K,
+                Self::MAX_TEMPERATURE_K
+            ));
+        }
+        let (min_gamma, max_gamma) = Self::GAMMA_RANGE;
+        if !(min_gamma..=max_gamma).contains(&self.gamma) {
+            return Err(format!("Gamma {} out of range ({min_gamma} - {max_gamma})", self.gamma));
+        }
+        if self.vibrance > 100 {
+            return Err(format!("Vibrance {} exceeds maximum 100", self.vibrance));
+        }
+        Ok(())
+    }
+
+    /// Red, green and blue multipliers (0.0 - 1.0) for the white point.
+    ///
+    /// Tanner Helland's blackbody approximation, normalized so 6500K is (1, 1, 1).
+    #[must_use]
+    pub fn channel_multipliers(&self) -> [f64; 3] {
+        let neutral = blackbody_rgb(Self::NEUTRAL_TEMPERATURE_K);
+        let target = blackbody_rgb(self.temperature_k);
+        [0, 1, 2].map(|c| (target[c] / neutral[c]).min(1.0))
+    }
+
+    /// 256-entry gamma ramp per channel (red, green, blue), as taken by `SetDeviceGammaRamp`.
+    #[must_use]
+    pub fn gamma_ramp(&self) -> [[u16; 256]; 3] {
+        let multipliers = self.channel_multipliers();
+        let exponent = 1.0 / f64::from(self.gamma);
+        let mut ramp = [[0u16; 256]; 3];
+        for (channel, multiplier) in ramp.iter_mut().zip(multipliers) {
+            for (i, entry) in channel.iter_mut().enumerate() {
+                let value = (i as f64 / 255.0).powf(exponent) * multiplier;
+                *entry = (value * 65535.0).round().clamp(0.0, 65535.0) as u16;
+            }
+        }
+        ramp
+    }
+}
+
+impl Default for ColorProfile {
+    fn default() -> Self {
+        Self::neutral()
+    }
+}
+
+/// How the brightness of a monitor can be changed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrightnessControl {
+    /// Built-in panel, through WMI
+    Wmi,
+    /// External monitor, through DDC/CI (VCP code 0x10)
+    DdcCi,
+    /// Neither (e.g. a TV, or DDC/CI turned off in the monitor's menu)
+    Unsupported,
+}
+
+impl BrightnessControl {
+    /// WMI only drives built-in panels; external monitors need a physical
+    /// monitor handle to talk DDC/CI.
+    #[must_use]
+    pub fn detect(internal: bool, wmi_available: bool, ddc_ci_available: bool) -> Self {
+        match (internal, wmi_available, ddc_ci_available) {
+            (true, true, _) => Self::Wmi,
+            (false, _, true) => Self::DdcCi,
+            _ => Self::Unsupported,
+        }
+    }
+}
+
+/// Desktop rectangle of a monitor in physical pixels.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MonitorBounds {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// A monitor of the desktop, addressed by its GDI device name.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MonitorInfo {
+    /// GDI device name (`\\.\DISPLAY1`), the id taken by per-monitor commands
+    pub device_name: String,
+    /// Name the monitor reports in its EDID (e.g. "DELL U2720Q")
+    pub name: String,
+    pub is_primary: bool,
+    /// Built-in panel (laptop or handheld screen)
+    pub is_internal: bool,
+    pub bounds: MonitorBounds,
+    pub refresh_hz: u32,
+    pub brightness: BrightnessControl,
+}
+
+/// Blackbody color (0 - 255 per channel) of a temperature in Kelvin.
+fn blackbody_rgb(temperature_k: u32) -> [f64; 3] {
+    let t = f64::from(temperature_k) / 100.0;
+    let red = if t <= 66.0 {
+        255.0
+    } else {
+        329.698_727_446 * (t - 60.0).powf(-0.133_204_759_2)
+    };
+    let green = if t <= 66.0 {
+        99.470_802_586_1 * t.ln() - 161.119_568_166_1
+    } else {
+        288.122_169_528_3 * (t - 60.0).powf(-0.075_514_849_2)
+    };
+    let blue = if t >= 66.0 {
+        255.0
+    } else if t <= 19.0 {
+        0.0
+    } else {
+        138.517_731_223_1 * (t - 10.0).ln() - 305.044_792_730_7
+    };
+    [red, green, blue].map(|c| c.clamp(0.0, 255.0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,4 +339,55 @@ mod tests {
         let rate = RefreshRateConfig::new(100).unwrap();
         assert_eq!(rate.nearest_common_rate(), 90);
     }
+
+    #[test]
+    fn test_brightness_control_detection() {
+        assert_eq!(BrightnessControl::detect(true, true, false), BrightnessControl::Wmi);
+        assert_eq!(BrightnessControl::detect(false, false, true), BrightnessControl::DdcCi);
+        // WMI brightness belongs to the built-in panel, never to an external monitor
+        assert_eq!(
+            BrightnessControl::detect(false, true, false),
+            BrightnessControl::Unsupported
+        );
+        assert_eq!(
+            BrightnessControl::detect(true, false, true),
+            BrightnessControl::Unsupported
+        );
+    }
+
+    #[test]
+    fn test_neutral_gamma_ramp_is_identity() {
+        let ramp = ColorProfile::neutral().gamma_ramp();
+        for channel in ramp {
+            for (i, entry) in channel.iter().enumerate() {
+                assert_eq!(u32::from(*entry), i as u32 * 257);
+            }
+        }
+    }
+
+    #[test]
+    fn test_warm_profile_reduces_blue() {
+        let [red, green, blue] = ColorProfile::warm(3400).channel_multipliers();
+        assert!((red - 1.0).abs() < f64::EPSILON);
+        assert!(green < 1.0);
+        assert!(blue < green);
+    }
+
+    #[test]
+    fn test_color_profile_validation() {
+        assert!(ColorProfile::neutral().validate().is_ok());
+        assert!(ColorProfile::warm(1900).validate().is_err());
+        assert!(ColorProfile {
+            gamma: 3.0,
+            ..ColorProfile::neutral()
+        }
+        .validate()
+        .is_err());
+        assert!(ColorProfile {
+            vibrance: 101,
+            ..ColorProfile::neutral()
+        }
+        .validate()
+        .is_err());
+    }
 }
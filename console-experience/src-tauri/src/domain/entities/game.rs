@@ -1,4 +1,6 @@
+use crate::domain::launcher_dependency::LauncherDependency;
 use crate::domain::value_objects::game_source::GameSource;
+use crate::domain::value_objects::install_state::InstallState;
 use serde::{Deserialize, Serialize};
 
 /// Domain entity representing a game discovered from various sources.
@@ -23,6 +25,16 @@ pub struct Game {
     pub last_played: Option<u64>,
     /// Source platform where game was discovered
     pub source: GameSource,
+    /// Whether the game is ready to play (download/update state)
+    #[serde(default)]
+    pub install_state: InstallState,
+    /// Store client that must be running before the game starts (DRM)
+    #[serde(default)]
+    pub launcher_dependency: Option<LauncherDependency>,
+    /// The store has an update for the game (`install_state` is
+    /// `UpdateRequired` or `Updating`); shown as a badge in the library
+    #[serde(default)]
+    pub update_pending: bool,
 }
 
 impl Game {
@@ -46,6 +58,9 @@ impl Game {
             logo: None,
             last_played: None,
             source,
+            install_state: InstallState::Installed,
+            launcher_dependency: None,
+            update_pending: false,
         }
     }
 
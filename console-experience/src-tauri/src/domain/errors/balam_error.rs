@@ -0,0 +1,168 @@
+/**
+ * Domain: Balam Error
+ *
+ * Typed error returned by the hardware ports (system, Wi-Fi, Bluetooth,
+ * display, performance) and their Tauri commands.
+ *
+ * Serialized for the frontend as `{ code, category, message }`:
+ * - `code`: stable identifier to branch on (`BLUETOOTH_OFF`, `ACCESS_DENIED`, ...)
+ * - `category`: broad class for generic handling (retry, ask for admin, setup hint)
+ * - `message`: English description for logs and fallback display
+ */
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt::Display;
+use thiserror::Error;
+
+/// Broad class of a `BalamError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCategory {
+    /// Hardware or feature missing or turned off (show a setup hint, retrying won't help)
+    Unavailable,
+    /// Needs administrator rights or was denied by Windows
+    Permission,
+    /// The caller passed an invalid value
+    InvalidInput,
+    /// Device, network, display or process does not exist
+    NotFound,
+    /// Did not complete in time (worth retrying)
+    Timeout,
+    /// Same operation already running (wait for it rather than retrying)
+    Busy,
+    /// A Windows API, driver or external tool failed
+    Platform,
+}
+
+/// Crate-wide error for hardware ports and their commands.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BalamError {
+    /// No Bluetooth radio on this machine
+    #[error("Bluetooth adapter not available")]
+    BluetoothUnavailable,
+    /// Bluetooth radio present but turned off
+    #[error("Bluetooth is off")]
+    BluetoothOff,
+    /// No wireless LAN interface
+    #[error("Wi-Fi adapter not available")]
+    WifiUnavailable,
+    /// Feature not supported by this hardware or driver
+    #[error("Not supported: {0}")]
+    NotSupported(String),
+    #[error("Access denied: {0}")]
+    AccessDenied(String),
+    #[error("Invalid value: {0}")]
+    InvalidArgument(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Timed out: {0}")]
+    Timeout(String),
+    /// The command is already running and doesn't take overlapping calls
+    #[error("Already in progress: {0}")]
+    Busy(String),
+    /// Windows API, driver or external tool failure (message includes the context)
+    #[error("{0}")]
+    Platform(String),
+}
+
+impl BalamError {
+    /// Platform error with the failed operation as context (`"SetVolume failed: <error>"`).
+    pub fn platform(context: &str, error: impl Display) -> Self {
+        Self::Platform(format!("{context}: {error}"))
+    }
+
+    /// Stable code the frontend can branch on.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BluetoothUnavailable => "BLUETOOTH_UNAVAILABLE",
+            Self::BluetoothOff => "BLUETOOTH_OFF",
+            Self::WifiUnavailable => "WIFI_UNAVAILABLE",
+            Self::NotSupported(_) => "NOT_SUPPORTED",
+            Self::AccessDenied(_) => "ACCESS_DENIED",
+            Self::InvalidArgument(_) => "INVALID_ARGUMENT",
+            Self::NotFound(_) => "NOT_FOUND",
+            Self::Timeout(_) => "TIMEOUT",
+            Self::Busy(_) => "BUSY",
+            Self::Platform(_) => "PLATFORM_ERROR",
+        }
+    }
+
+    #[must_use]
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::BluetoothUnavailable | Self::BluetoothOff | Self::WifiUnavailable | Self::NotSupported(_) => {
+                ErrorCategory::Unavailable
+            },
+            Self::AccessDenied(_) => ErrorCategory::Permission,
+            Self::InvalidArgument(_) => ErrorCategory::InvalidInput,
+            Self::NotFound(_) => ErrorCategory::NotFound,
+            Self::Timeout(_) => ErrorCategory::Timeout,
+            Self::Busy(_) => ErrorCategory::Busy,
+            Self::Platform(_) => ErrorCategory::Platform,
+        }
+    }
+}
+
+impl Serialize for BalamError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("BalamError", 3)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("category", &self.category())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
+
+impl From<std::io::Error> for BalamError {
+    fn from(err: std::io::Error) -> Self {
+        match err.kind() {
+            std::io::ErrorKind::PermissionDenied => Self::AccessDenied(err.to_string()),
+            std::io::ErrorKind::NotFound => Self::NotFound(err.to_string()),
+            std::io::ErrorKind::TimedOut => Self::Timeout(err.to_string()),
+            _ => Self::Platform(err.to_string()),
+        }
+    }
+}
+
+/// Commands that still return `String` errors can use `?` on port results.
+impl From<BalamError> for String {
+    fn from(err: BalamError) -> Self {
+        err.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serializes_code_category_and_message() {
+        let json = serde_json::to_value(BalamError::BluetoothOff).unwrap();
+        assert_eq!(json["code"], "BLUETOOTH_OFF");
+        assert_eq!(json["category"], "unavailable");
+        assert_eq!(json["message"], "Bluetooth is off");
+    }
+
+    #[test]
+    fn test_bluetooth_off_and_no_adapter_are_distinct() {
+        assert_ne!(BalamError::BluetoothOff.code(), BalamError::BluetoothUnavailable.code());
+        assert_eq!(
+            BalamError::BluetoothOff.category(),
+            BalamError::BluetoothUnavailable.category()
+        );
+    }
+
+    #[test]
+    fn test_platform_message_keeps_context() {
+        let err = BalamError::platform("SetVolume failed", "0x80070005");
+        assert_eq!(err.to_string(), "SetVolume failed: 0x80070005");
+        assert_eq!(err.category(), ErrorCategory::Platform);
+    }
+
+    #[test]
+    fn test_io_error_conversion() {
+        let err: BalamError = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied").into();
+        assert_eq!(err.code(), "ACCESS_DENIED");
+    }
+}
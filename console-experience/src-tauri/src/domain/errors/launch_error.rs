@@ -27,6 +27,14 @@ pub enum LaunchFailureReason {
     },
     /// No watchdog available to monitor (Xbox explorer fallback)
     NoMonitoring,
+    /// Store client is not running and could not be started
+    ClientNotRunning,
+    /// Store client is still updating itself
+    ClientUpdating,
+    /// Nobody is signed in to the store client
+    NotSignedIn,
+    /// Store client is in offline mode (online-only games cannot start)
+    OfflineMode,
 }
 
 impl LaunchFailureReason {
@@ -44,6 +52,10 @@ impl LaunchFailureReason {
                 format!("Error del sistema: {error_message}")
             },
             Self::NoMonitoring => "No se pudo monitorear el estado del juego".to_string(),
+            Self::ClientNotRunning => "La tienda no está abierta".to_string(),
+            Self::ClientUpdating => "La tienda se está actualizando".to_string(),
+            Self::NotSignedIn => "No has iniciado sesión en la tienda".to_string(),
+            Self::OfflineMode => "La tienda está en modo sin conexión".to_string(),
         }
     }
 }
@@ -80,6 +92,69 @@ impl GameLaunchError {
         }
     }
 
+    /// Create error for a Steam client that cannot launch games right now
+    /// (not running, updating, signed out or offline)
+    #[must_use]
+    pub fn steam_unavailable(game_id: String, game_title: String, reason: LaunchFailureReason) -> Self {
+        let suggested_actions = match reason {
+            LaunchFailureReason::ClientUpdating => vec![
+                "Espera a que Steam termine de actualizarse".to_string(),
+                "Vuelve a intentar en unos segundos".to_string(),
+            ],
+            LaunchFailureReason::NotSignedIn => vec![
+                "Inicia sesión en Steam".to_string(),
+                "Activa \"Recordar mi contraseña\" para iniciar sesión automáticamente".to_string(),
+            ],
+            LaunchFailureReason::OfflineMode => vec![
+                "Este juego puede necesitar conexión: pon Steam en modo en línea".to_string(),
+                "Verifica tu conexión a internet".to_string(),
+            ],
+            _ => vec![
+                "Abre Steam y vuelve a intentar".to_string(),
+                "Reinstala Steam si no abre".to_string(),
+            ],
+        };
+        Self {
+            game_id,
+            game_title,
+            store: "Steam".to_string(),
+            reason,
+            suggested_actions,
+        }
+    }
+
+    /// Create error for Epic timeout (game exe never appeared)
+    #[must_use]
+    pub fn epic_timeout(game_id: String, game_title: String, timeout_seconds: u64) -> Self {
+        Self {
+            game_id,
+            game_title,
+            store: "Epic".to_string(),
+            reason: LaunchFailureReason::Timeout { timeout_seconds },
+            suggested_actions: vec![
+                "Inicia sesión en Epic Games Launcher".to_string(),
+                "Verifica que el juego no tenga actualizaciones pendientes".to_string(),
+                "Reinicia Epic Games Launcher y vuelve a intentar".to_string(),
+            ],
+        }
+    }
+
+    /// Create error for a standalone launcher timeout (game process never appeared)
+    #[must_use]
+    pub fn launcher_timeout(game_id: String, game_title: String, timeout_seconds: u64) -> Self {
+        Self {
+            game_id,
+            game_title,
+            store: "Launcher".to_string(),
+            reason: LaunchFailureReason::Timeout { timeout_seconds },
+            suggested_actions: vec![
+                "Pulsa Jugar en el launcher del juego".to_string(),
+                "Inicia sesión en el launcher si lo pide".to_string(),
+                "Espera a que terminen las actualizaciones del launcher".to_string(),
+            ],
+        }
+    }
+
     /// Create error for Xbox/UWP activation failure
     #[must_use]
     pub fn xbox_activation_failed(game_id: String, game_title: String, error: String) -> Self {
@@ -1,7 +1,9 @@
+pub mod balam_error;
 pub mod launch_error;
 pub mod scan_error;
 pub mod system_error;
 
+pub use balam_error::{BalamError, ErrorCategory};
 pub use launch_error::{GameLaunchError, LaunchFailureReason};
 pub use scan_error::ScanError;
 pub use system_error::SystemError;
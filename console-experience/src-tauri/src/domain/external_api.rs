@@ -0,0 +1,93 @@
+use serde::{Deserialize, Serialize};
+
+/// Named pipe for external tools (Stream Deck, `AutoHotkey`, companion apps).
+///
+/// Line-delimited JSON: one [`ExternalApiRequest`] per line, each answered by
+/// one [`ExternalApiResponse`] line, for as long as the client stays connected.
+pub const EXTERNAL_API_PIPE_NAME: &str = r"\\.\pipe\balam";
+/// File in the app data folder holding the API token, readable by the
+/// player's scripts.
+pub const EXTERNAL_API_TOKEN_FILE: &str = "api_token";
+
+/// What an external tool can ask Balam to do.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ExternalApiCommand {
+    /// Visible library, sorted by title
+    ListGames,
+    Launch {
+        game_id: String,
+    },
+    /// Focused game session, `null` when none
+    GetActiveGame,
+    /// Closes the focused game
+    CloseGame,
+    /// CPU/GPU/RAM usage, temperatures and FPS
+    GetMetrics,
+    /// Brings the Balam window to the front
+    Show,
+}
+
+/// One line on the API pipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalApiRequest {
+    pub token: String,
+    /// Echoed in the response so clients can pipeline requests
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(flatten)]
+    pub command: ExternalApiCommand,
+}
+
+/// Answer to an [`ExternalApiRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ExternalApiResponse {
+    Ok {
+        id: Option<u64>,
+        data: serde_json::Value,
+    },
+    Error {
+        id: Option<u64>,
+        message: String,
+    },
+    /// Wrong token, or the API is disabled in the settings
+    Unauthorized {
+        id: Option<u64>,
+    },
+}
+
+/// Connection details shown in the settings so the player can set up a tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalApiInfo {
+    pub enabled: bool,
+    pub pipe_name: String,
+    pub token: String,
+    /// Scripts can read the token from here instead of embedding it
+    pub token_path: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_wire_format() {
+        let request: ExternalApiRequest =
+            serde_json::from_str(r#"{"token":"t","id":7,"command":"launch","game_id":"steam_730"}"#).unwrap();
+        assert_eq!(request.id, Some(7));
+        assert_eq!(
+            request.command,
+            ExternalApiCommand::Launch {
+                game_id: "steam_730".to_string()
+            }
+        );
+
+        let minimal: ExternalApiRequest = serde_json::from_str(r#"{"token":"t","command":"list_games"}"#).unwrap();
+        assert_eq!((minimal.id, minimal.command), (None, ExternalApiCommand::ListGames));
+        assert!(serde_json::from_str::<ExternalApiRequest>(r#"{"token":"t","command":"format_disk"}"#).is_err());
+
+        let response = serde_json::to_value(ExternalApiResponse::Unauthorized { id: Some(7) }).unwrap();
+        assert_eq!(response, serde_json::json!({ "status": "unauthorized", "id": 7 }));
+    }
+}
@@ -0,0 +1,156 @@
+/// Library lookup for games started outside Balam
+///
+/// Maps a running executable back to a library game: the game's own .exe,
+/// or any executable inside its install folder (launchers that start
+/// `Binaries\Win64\Game-Shipping.exe`). Install folders shared by several
+/// games (e.g. manual games in `C:\Games`) only match exact executables.
+use std::collections::HashMap;
+
+use crate::domain::Game;
+
+/// Executables shipped next to games that are not the game itself.
+const HELPER_EXECUTABLES: &[&str] = &[
+    "unins",
+    "uninstall",
+    "setup",
+    "installer",
+    "crashhandler",
+    "crashreport",
+    "crashpad",
+    "vc_redist",
+    "dxsetup",
+    "easyanticheat",
+    "battleye",
+];
+
+fn normalize(path: &str) -> String {
+    path.replace('/', "\\").trim_end_matches('\\').to_lowercase()
+}
+
+fn is_helper(exe_path: &str) -> bool {
+    let file_name = exe_path.rsplit('\\').next().unwrap_or(exe_path);
+    HELPER_EXECUTABLES.iter().any(|helper| file_name.contains(helper))
+}
+
+/// Executable path → game ID lookup over the library.
+#[derive(Debug, Clone, Default)]
+pub struct ExecutableIndex {
+    /// Normalized .exe path → game ID
+    exact: HashMap<String, String>,
+    /// Normalized install folder → game ID, longest first
+    roots: Vec<(String, String)>,
+}
+
+impl ExecutableIndex {
+    #[must_use]
+    pub fn new(games: &[Game]) -> Self {
+        let mut exact = HashMap::new();
+        let mut roots: HashMap<String, Option<String>> = HashMap::new();
+        for game in games {
+            // UWP (`Package!App`) and URL launches have no executable to match
+            if game.path.is_empty() || game.path.contains('!') || game.path.contains("://") {
+                continue;
+            }
+            let path = normalize(&game.path);
+            let root = if path.ends_with(".exe") {
+                exact.insert(path.clone(), game.id.clone());
+                path.rsplit_once('\\')
+                    .map_or_else(String::new, |(dir, _)| dir.to_string())
+            } else {
+                path
+            };
+            // Drive roots (`c:`) would match everything
+            if root.len() > 3 {
+                roots
+                    .entry(root)
+                    .and_modify(|owner| {
+                        if owner.as_deref() != Some(game.id.as_str()) {
+                            *owner = None;
+                        }
+                    })
+                    .or_insert_with(|| Some(game.id.clone()));
+            }
+        }
+
+        let mut roots: Vec<(String, String)> = roots
+            .into_iter()
+            .filter_map(|(root, owner)| owner.map(|game_id| (root, game_id)))
+            .collect();
+        roots.sort_by(|a, b| b.0.len().cmp(&a.0.len()));
+        Self { exact, roots }
+    }
+
+    /// ID of the library game `exe_path` belongs to.
+    #[must_use]
+    pub fn find(&self, exe_path: &str) -> Option<&str> {
+        let path = normalize(exe_path);
+        if let Some(game_id) = self.exact.get(&path) {
+            return Some(game_id);
+        }
+        if is_helper(&path) {
+            return None;
+        }
+        self.roots
+            .iter()
+            .find(|(root, _)| {
+                path.strip_prefix(root.as_str())
+                    .is_some_and(|rest| rest.starts_with('\\'))
+            })
+            .map(|(_, game_id)| game_id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::game_source::GameSource;
+
+    fn game(id: &str, path: &str, source: GameSource) -> Game {
+        Game::new(id.to_string(), id.to_string(), id.to_string(), path.to_string(), source)
+    }
+
+    #[test]
+    fn test_executables_map_to_their_game() {
+        let index = ExecutableIndex::new(&[
+            game(
+                "steam_1",
+                "D:\\SteamLibrary\\steamapps\\common\\Elden Ring",
+                GameSource::Steam,
+            ),
+            game("epic_1", "C:\\Epic\\Fortnite\\FortniteLauncher.exe", GameSource::Epic),
+            game("xbox_1", "Microsoft.Game_8wekyb3d8bbwe!App", GameSource::Xbox),
+        ]);
+
+        assert_eq!(
+            index.find("d:/SteamLibrary/steamapps/common/Elden Ring/Game/eldenring.exe"),
+            Some("steam_1")
+        );
+        assert_eq!(
+            index.find("C:\\Epic\\Fortnite\\Binaries\\Win64\\FortniteClient-Win64-Shipping.exe"),
+            Some("epic_1")
+        );
+        assert_eq!(
+            index.find("D:\\SteamLibrary\\steamapps\\common\\Elden Ring\\unins000.exe"),
+            None
+        );
+        assert_eq!(
+            index.find("D:\\SteamLibrary\\steamapps\\common\\Elden Ring2\\game.exe"),
+            None
+        );
+        assert_eq!(index.find("C:\\Windows\\explorer.exe"), None);
+    }
+
+    #[test]
+    fn test_shared_folders_only_match_exact_executables() {
+        let index = ExecutableIndex::new(&[
+            game("manual_a", "C:\\Games\\a.exe", GameSource::Manual),
+            game("manual_b", "C:\\Games\\b.exe", GameSource::Manual),
+            game("manual_c", "E:\\c.exe", GameSource::Manual),
+        ]);
+
+        assert_eq!(index.find("c:\\games\\B.EXE"), Some("manual_b"));
+        assert_eq!(index.find("C:\\Games\\tools\\editor.exe"), None);
+        assert_eq!(index.find("E:\\other.exe"), None);
+        assert_eq!(index.find("E:\\c.exe"), Some("manual_c"));
+    }
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+/// Who the controller is talking to, after the last foreground change.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct InputFocus {
+    /// Process owning the foreground window (`None` before the first event)
+    pub foreground_pid: Option<u32>,
+    /// A Balam window (main or overlay) is in front
+    pub balam_focused: bool,
+    /// The foreground process reads the controller through Steam Input
+    pub steam_input: bool,
+    /// Library id of the tracked game in front, if any
+    pub game_id: Option<String>,
+    /// Balam's own gamepad navigation is paused (chords keep working)
+    pub navigation_suppressed: bool,
+}
+
+impl InputFocus {
+    /// Focus state for a new foreground window.
+    ///
+    /// Steam Input turns the pad into the game's own input; if Balam kept
+    /// reading XInput too, every press would also move the launcher behind
+    /// the game. Any other foreground window keeps navigation on, as before.
+    #[must_use]
+    pub fn resolve(foreground_pid: u32, own_pid: u32, steam_input: bool, game_id: Option<String>) -> Self {
+        let balam_focused = foreground_pid == own_pid;
+        let steam_input = steam_input && !balam_focused;
+        Self {
+            foreground_pid: Some(foreground_pid),
+            balam_focused,
+            steam_input,
+            game_id,
+            navigation_suppressed: steam_input,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_suppressed_only_for_steam_input_games() {
+        let game = InputFocus::resolve(4242, 100, true, Some("steam_570".to_string()));
+        assert!(game.navigation_suppressed);
+        assert!(!game.balam_focused);
+
+        // Back in Balam: navigation resumes even though Steam Input is loaded there too
+        let balam = InputFocus::resolve(100, 100, true, None);
+        assert!(balam.balam_focused);
+        assert!(!balam.navigation_suppressed);
+
+        assert!(!InputFocus::resolve(4242, 100, false, None).navigation_suppressed);
+    }
+}
@@ -0,0 +1,157 @@
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Folder created on the target drive to hold moved games.
+pub const MOVED_GAMES_DIR: &str = "Games";
+
+/// Stage of a game move, reported in `game-move-progress`.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MovePhase {
+    /// Measuring the install and checking free space
+    Preparing,
+    Copying,
+    /// Comparing file count and sizes of the copy against the source
+    Verifying,
+    /// Pointing the library (and store manifests) at the new location
+    Updating,
+    /// Deleting the source install
+    Cleaning,
+    Done,
+}
+
+/// Progress payload of the `game-move-progress` event.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MoveProgress {
+    pub game_id: String,
+    pub phase: MovePhase,
+    pub copied_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Result of `move_game`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MoveOutcome {
+    /// Files copied, verified and the source removed
+    Moved { new_path: String },
+    /// Steam moves its own installs: the properties dialog was opened on "Installed files"
+    DelegatedToSteam,
+    /// Manual game: nothing was moved. `folder` is moved and then deleted, so
+    /// the player confirms it by calling again with `confirmedFolder`.
+    NeedsConfirmation {
+        folder: String,
+        /// Other executables directly in `folder` (moved along with the game)
+        other_programs: Vec<String>,
+    },
+}
+
+/// Install folder on the target drive: `<drive>\Games\<install folder name>`.
+///
+/// # Errors
+/// Returns error if the install directory has no folder name (a drive root).
+pub fn move_destination(install_dir: &Path, target_drive: &str) -> Result<PathBuf, String> {
+    let folder = install_dir
+        .file_name()
+        .ok_or_else(|| format!("Cannot move a drive root: {}", install_dir.display()))?;
+    Ok(Path::new(target_drive).join(MOVED_GAMES_DIR).join(folder))
+}
+
+/// Rewrites a path inside `old_root` so it points inside `new_root`.
+///
+/// Returns `None` if `path` is not inside `old_root`.
+#[must_use]
+pub fn relocate_path(path: &Path, old_root: &Path, new_root: &Path) -> Option<PathBuf> {
+    path.strip_prefix(old_root).ok().map(|relative| new_root.join(relative))
+}
+
+/// Lowercase, without trailing separators (Windows paths ignore case).
+fn normalized(path: &Path) -> String {
+    path.to_string_lossy().trim_end_matches(['\\', '/']).to_lowercase()
+}
+
+/// Whether `path` lies strictly inside `dir`.
+#[must_use]
+pub fn is_inside(path: &Path, dir: &Path) -> bool {
+    normalized(path)
+        .strip_prefix(&normalized(dir))
+        .is_some_and(|rest| rest.starts_with(['\\', '/']))
+}
+
+/// Whether `dir` must never be moved and deleted as a game folder: a drive
+/// root, one of the `protected` folders (Desktop, Downloads, Program Files...)
+/// or a folder containing one (e.g. `C:\Users`).
+#[must_use]
+pub fn is_protected_folder(dir: &Path, protected: &[PathBuf]) -> bool {
+    let dir_key = normalized(dir);
+    !dir_key.contains(['\\', '/'])
+        || protected
+            .iter()
+            .any(|folder| normalized(folder) == dir_key || is_inside(folder, dir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_destination_keeps_folder_name() {
+        let destination = move_destination(Path::new("C:\\Games\\Hades"), "E:\\").unwrap();
+        assert_eq!(destination, Path::new("E:\\").join("Games").join("Hades"));
+
+        assert!(move_destination(Path::new("C:\\"), "E:\\").is_err());
+    }
+
+    #[test]
+    fn test_relocate_path() {
+        let old_root = Path::new("C:\\Games\\Hades");
+        let new_root = Path::new("E:\\Games\\Hades");
+
+        assert_eq!(
+            relocate_path(&old_root.join("x64").join("Hades.exe"), old_root, new_root),
+            Some(new_root.join("x64").join("Hades.exe"))
+        );
+        assert_eq!(
+            relocate_path(Path::new("D:\\Other\\game.exe"), old_root, new_root),
+            None
+        );
+    }
+
+    #[test]
+    fn test_protected_folders_are_never_game_folders() {
+        let protected = [
+            PathBuf::from("C:\\Users\\Ana\\Downloads"),
+            PathBuf::from("C:\\Program Files"),
+        ];
+        assert!(is_protected_folder(Path::new("D:\\"), &protected));
+        assert!(is_protected_folder(
+            Path::new("c:\\users\\ana\\downloads\\"),
+            &protected
+        ));
+        assert!(is_protected_folder(Path::new("C:\\Users"), &protected));
+        assert!(!is_protected_folder(
+            Path::new("C:\\Users\\Ana\\Downloads\\Celeste"),
+            &protected
+        ));
+        assert!(!is_protected_folder(
+            Path::new("C:\\Program Files\\Celeste"),
+            &protected
+        ));
+
+        assert!(is_inside(
+            Path::new("C:\\Games\\Celeste\\Celeste.exe"),
+            Path::new("C:\\Games")
+        ));
+        assert!(!is_inside(Path::new("C:\\GamesOld\\x.exe"), Path::new("C:\\Games")));
+    }
+
+    #[test]
+    fn test_outcome_serializes_with_kind() {
+        let json = serde_json::to_value(MoveOutcome::Moved {
+            new_path: "E:\\Games\\Hades\\Hades.exe".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json["kind"], "moved");
+        assert_eq!(json["new_path"], "E:\\Games\\Hades\\Hades.exe");
+    }
+}
@@ -0,0 +1,43 @@
+//! How often the navigation loop reads the controllers.
+//!
+//! Full rate only while a controller can drive a visible Balam window;
+//! chords still work over a game at the slower rate, and a plugged-in
+//! controller wakes the loop right away.
+
+use std::time::Duration;
+
+/// Default `controller.poll_interval_ms` (125 Hz).
+pub const DEFAULT_POLL_INTERVAL_MS: u64 = 8;
+/// Accepted range of `controller.poll_interval_ms`.
+pub const MIN_POLL_INTERVAL_MS: u64 = 4;
+pub const MAX_POLL_INTERVAL_MS: u64 = 50;
+/// Controller attached but no Balam window on screen (chords only).
+pub const HIDDEN_POLL_INTERVAL_MS: u64 = 100;
+/// No controller attached: only waiting for one to show up.
+pub const DISCONNECTED_POLL_INTERVAL_MS: u64 = 250;
+
+/// Time until the next poll; `interval_ms` is the user's rate while navigating.
+#[must_use]
+pub fn poll_interval(interval_ms: u64, controller_connected: bool, window_visible: bool) -> Duration {
+    let ms = if !controller_connected {
+        DISCONNECTED_POLL_INTERVAL_MS
+    } else if !window_visible {
+        HIDDEN_POLL_INTERVAL_MS.max(interval_ms)
+    } else {
+        interval_ms
+    };
+    Duration::from_millis(ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_interval_drops_when_idle() {
+        assert_eq!(poll_interval(8, true, true), Duration::from_millis(8));
+        assert_eq!(poll_interval(8, true, false), Duration::from_millis(100));
+        assert_eq!(poll_interval(8, false, true), Duration::from_millis(250));
+        assert_eq!(poll_interval(8, false, false), Duration::from_millis(250));
+    }
+}
@@ -4,6 +4,13 @@
 /// - Weak: High-frequency motor for sharp, buzzy feedback (navigation)
 /// - Medium: Balanced dual-motor for confirmations
 /// - Strong: Low-frequency motor for heavy rumble (events)
+///
+/// Rumble is played as patterns: envelopes of pulses for both motors,
+/// queued one after another by the haptic service.
+use serde::{Deserialize, Serialize};
+
+/// Names of the built-in patterns (`play_haptic_pattern`, `haptics.event_patterns`).
+pub const HAPTIC_PATTERNS: &[&str] = &["navigation", "action", "event", "double-pulse", "ramp", "heartbeat"];
 
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
 pub enum HapticIntensity {
@@ -57,3 +64,130 @@ impl HapticFeedback {
         Self::new(HapticIntensity::Strong, 500)
     }
 }
+
+/// One pulse of a pattern: both motors on for `duration_ms`, starting
+/// `start_ms` into the pattern, ramping up over `attack_ms` and down over
+/// `fade_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct HapticPulse {
+    pub start_ms: u32,
+    pub duration_ms: u32,
+    /// Low-frequency motor, 0.0 - 1.0
+    pub strong: f32,
+    /// High-frequency motor, 0.0 - 1.0
+    pub weak: f32,
+    pub attack_ms: u32,
+    pub fade_ms: u32,
+}
+
+impl HapticPulse {
+    /// Flat pulse; the weak motor runs at 70% for texture.
+    #[must_use]
+    pub fn flat(start_ms: u32, duration_ms: u32, magnitude: f32) -> Self {
+        Self {
+            start_ms,
+            duration_ms,
+            strong: magnitude,
+            weak: magnitude * 0.7,
+            attack_ms: 0,
+            fade_ms: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn end_ms(&self) -> u32 {
+        self.start_ms + self.duration_ms
+    }
+}
+
+/// A named rumble envelope.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HapticPattern {
+    pub name: String,
+    pub pulses: Vec<HapticPulse>,
+}
+
+impl HapticPattern {
+    /// Built-in pattern by name.
+    #[must_use]
+    pub fn builtin(name: &str) -> Option<Self> {
+        let pulses = match name {
+            "navigation" => vec![single(HapticFeedback::navigation())],
+            "action" => vec![single(HapticFeedback::action())],
+            "event" => vec![single(HapticFeedback::event())],
+            "double-pulse" => vec![HapticPulse::flat(0, 100, 0.6), HapticPulse::flat(200, 100, 0.6)],
+            "ramp" => vec![HapticPulse {
+                attack_ms: 600,
+                ..HapticPulse::flat(0, 700, 0.8)
+            }],
+            // Lub-dub, twice
+            "heartbeat" => vec![
+                HapticPulse::flat(0, 100, 0.8),
+                HapticPulse::flat(200, 100, 0.45),
+                HapticPulse::flat(750, 100, 0.8),
+                HapticPulse::flat(950, 100, 0.45),
+            ],
+            _ => return None,
+        };
+        Some(Self {
+            name: name.to_string(),
+            pulses,
+        })
+    }
+
+    /// Single flat pulse (what `trigger_haptic` plays).
+    #[must_use]
+    pub fn from_feedback(feedback: HapticFeedback) -> Self {
+        Self {
+            name: "custom".to_string(),
+            pulses: vec![single(feedback)],
+        }
+    }
+
+    /// Time until the last pulse ends.
+    #[must_use]
+    pub fn duration_ms(&self) -> u32 {
+        self.pulses.iter().map(HapticPulse::end_ms).max().unwrap_or(0)
+    }
+}
+
+fn single(feedback: HapticFeedback) -> HapticPulse {
+    let duration_ms = u32::try_from(feedback.duration_ms).unwrap_or(u32::MAX);
+    HapticPulse::flat(0, duration_ms, feedback.intensity.to_magnitude())
+}
+
+/// Moments that rumble, each mapped to a pattern in settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HapticEvent {
+    /// Moving the focus in the UI
+    Navigation,
+    /// Confirming / launching
+    Action,
+    /// Important notifications
+    Event,
+    Screenshot,
+    ReplaySaved,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_patterns() {
+        for name in HAPTIC_PATTERNS {
+            let pattern = HapticPattern::builtin(name).unwrap();
+            assert!(pattern.duration_ms() > 0, "{name}");
+            assert!(pattern.pulses.iter().all(
+                |pulse| (0.0..=1.0).contains(&pulse.strong) && pulse.attack_ms + pulse.fade_ms <= pulse.duration_ms
+            ));
+        }
+        assert_eq!(HapticPattern::builtin("heartbeat").unwrap().duration_ms(), 1050);
+        assert!(HapticPattern::builtin("earthquake").is_none());
+
+        // Same safety clamp as before
+        let long = HapticPattern::from_feedback(HapticFeedback::new(HapticIntensity::Strong, 60_000));
+        assert_eq!(long.duration_ms(), 2000);
+    }
+}
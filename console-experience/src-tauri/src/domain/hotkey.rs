@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+/// Action triggered by a keyboard shortcut or gamepad chord.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum HotkeyAction {
+    /// Show/hide the in-game overlay
+    ToggleOverlay,
+    /// Show/hide the WiFi quick panel
+    ToggleWifiPanel,
+    /// Show/hide the Bluetooth quick panel
+    ToggleBluetoothPanel,
+    /// Mute/unmute the default microphone (held = talk in push-to-talk mode)
+    ToggleMicMute,
+    /// Save a screenshot of the running game
+    TakeScreenshot,
+    /// Save the last seconds of gameplay from the replay buffer
+    SaveReplay,
+    /// Step the performance HUD to its next level (off → FPS → FPS + battery → full)
+    CycleOverlayLevel,
+}
+
+impl HotkeyAction {
+    /// Frontend event emitted for actions handled entirely by the UI.
+    ///
+    /// Returns `None` for actions that are executed natively in Rust.
+    #[must_use]
+    pub fn frontend_event(&self) -> Option<&'static str> {
+        match self {
+            Self::ToggleOverlay
+            | Self::ToggleMicMute
+            | Self::TakeScreenshot
+            | Self::SaveReplay
+            | Self::CycleOverlayLevel => None,
+            Self::ToggleWifiPanel => Some("toggle-wifi-panel"),
+            Self::ToggleBluetoothPanel => Some("toggle-bluetooth-panel"),
+        }
+    }
+}
+
+/// Gamepad button usable in a chord (XInput layout).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    A,
+    B,
+    X,
+    Y,
+    LB,
+    RB,
+    Start,
+    Back,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+impl GamepadButton {
+    /// XInput `wButtons` bit for this button.
+    #[must_use]
+    pub fn xinput_mask(&self) -> u16 {
+        match self {
+            Self::DPadUp => 0x0001,
+            Self::DPadDown => 0x0002,
+            Self::DPadLeft => 0x0004,
+            Self::DPadRight => 0x0008,
+            Self::Start => 0x0010,
+            Self::Back => 0x0020,
+            Self::LeftStick => 0x0040,
+            Self::RightStick => 0x0080,
+            Self::LB => 0x0100,
+            Self::RB => 0x0200,
+            Self::A => 0x1000,
+            Self::B => 0x2000,
+            Self::X => 0x4000,
+            Self::Y => 0x8000,
+        }
+    }
+}
+
+/// Gamepad chord bound to an action (all buttons held at once).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GamepadChordBinding {
+    pub action: HotkeyAction,
+    pub buttons: Vec<GamepadButton>,
+}
+
+impl GamepadChordBinding {
+    /// Minimum buttons in a chord, so single-button navigation is never hijacked.
+    pub const MIN_BUTTONS: usize = 2;
+
+    /// Combined XInput bitmask of all chord buttons.
+    #[must_use]
+    pub fn mask(&self) -> u16 {
+        self.buttons.iter().fold(0, |mask, b| mask | b.xinput_mask())
+    }
+
+    /// Checks whether every chord button is held in `buttons`.
+    #[must_use]
+    pub fn is_held(&self, buttons: u16) -> bool {
+        let mask = self.mask();
+        mask != 0 && buttons & mask == mask
+    }
+
+    /// Validates the chord size.
+    ///
+    /// # Errors
+    /// Returns error if the chord has fewer than `MIN_BUTTONS` distinct buttons.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.mask().count_ones() < Self::MIN_BUTTONS as u32 {
+            return Err(format!(
+                "Gamepad chord for {:?} needs at least {} different buttons",
+                self.action,
+                Self::MIN_BUTTONS
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlay_chord() -> GamepadChordBinding {
+        GamepadChordBinding {
+            action: HotkeyAction::ToggleOverlay,
+            buttons: vec![GamepadButton::LB, GamepadButton::RB, GamepadButton::Start],
+        }
+    }
+
+    #[test]
+    fn test_chord_mask() {
+        assert_eq!(overlay_chord().mask(), 0x0100 | 0x0200 | 0x0010);
+    }
+
+    #[test]
+    fn test_chord_held_requires_all_buttons() {
+        let chord = overlay_chord();
+        assert!(chord.is_held(0x0100 | 0x0200 | 0x0010));
+        assert!(chord.is_held(0x0100 | 0x0200 | 0x0010 | 0x1000));
+        assert!(!chord.is_held(0x0100 | 0x0200));
+    }
+
+    #[test]
+    fn test_single_button_chord_rejected() {
+        let chord = GamepadChordBinding {
+            action: HotkeyAction::ToggleOverlay,
+            buttons: vec![GamepadButton::Start, GamepadButton::Start],
+        };
+        assert!(chord.validate().is_err());
+        assert!(overlay_chord().validate().is_ok());
+    }
+
+    #[test]
+    fn test_action_serialization() {
+        let json = serde_json::to_string(&HotkeyAction::ToggleWifiPanel).unwrap();
+        assert_eq!(json, "\"toggle_wifi_panel\"");
+    }
+}
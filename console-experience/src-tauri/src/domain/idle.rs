@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+/// Longest accepted sleep timer (8 hours).
+pub const MAX_SLEEP_TIMER_MINUTES: u32 = 480;
+/// Longest accepted idle delay before auto-suspend (4 hours).
+pub const MAX_AUTO_SUSPEND_MINUTES: u32 = 240;
+
+/// Why the device is being put to sleep.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SuspendReason {
+    /// The manual sleep timer ran out (fires even mid-game)
+    SleepTimer,
+    /// No gamepad input for `idle.auto_suspend_minutes` with no game running
+    Idle,
+}
+
+/// Idle state shown in Settings and Quick Settings.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct IdleStatus {
+    /// Seconds since the last gamepad input
+    pub idle_seconds: u64,
+    /// Auto-suspend delay (0 = disabled)
+    pub auto_suspend_minutes: u32,
+    /// Seconds until the sleep timer fires, `None` when no timer is set
+    pub sleep_timer_remaining_seconds: Option<u64>,
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Size and SHA-256 of one file of an install.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileDigest {
+    pub size: u64,
+    /// Lowercase hex SHA-256
+    pub sha256: String,
+}
+
+/// Baseline hashes of a game install, recorded while the files are known good.
+///
+/// Paths are relative to the install folder (with `/` separators), so the
+/// manifest stays valid after the game is moved to another drive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IntegrityManifest {
+    pub game_id: String,
+    /// When the baseline was recorded (Unix epoch seconds)
+    pub created_at: u64,
+    /// Relative path → digest
+    pub files: BTreeMap<String, FileDigest>,
+}
+
+impl IntegrityManifest {
+    /// Compares the current install against the baseline.
+    #[must_use]
+    pub fn compare(&self, current: &BTreeMap<String, FileDigest>) -> IntegrityReport {
+        let changed = self
+            .files
+            .iter()
+            .filter(|(path, digest)| current.get(*path).is_some_and(|now| now != *digest))
+            .map(|(path, _)| path.clone())
+            .collect();
+        let missing = self
+            .files
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned()
+            .collect();
+        let added = current
+            .keys()
+            .filter(|path| !self.files.contains_key(*path))
+            .cloned()
+            .collect();
+
+        IntegrityReport {
+            game_id: self.game_id.clone(),
+            checked_files: current.len(),
+            changed,
+            missing,
+            added,
+            baseline_created: false,
+        }
+    }
+}
+
+/// Result of `verify_game_files`.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub game_id: String,
+    pub checked_files: usize,
+    /// Files whose size or hash differs from the baseline (corrupted or patched)
+    pub changed: Vec<String>,
+    /// Files in the baseline that no longer exist
+    pub missing: Vec<String>,
+    /// New files since the baseline (saves, logs, mods); informational only
+    pub added: Vec<String>,
+    /// No baseline existed: this run recorded it, nothing was compared
+    pub baseline_created: bool,
+}
+
+impl IntegrityReport {
+    /// `true` if no baseline file changed or disappeared.
+    #[must_use]
+    pub fn is_intact(&self) -> bool {
+        self.changed.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Progress payload of the `game-verify-progress` event.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct VerifyProgress {
+    pub game_id: String,
+    pub hashed_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(size: u64, sha256: &str) -> FileDigest {
+        FileDigest {
+            size,
+            sha256: sha256.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_changed_missing_and_added() {
+        let manifest = IntegrityManifest {
+            game_id: "manual_1".to_string(),
+            created_at: 0,
+            files: BTreeMap::from([
+                ("game.exe".to_string(), digest(10, "aa")),
+                ("data/level.pak".to_string(), digest(20, "bb")),
+                ("data/music.pak".to_string(), digest(30, "cc")),
+            ]),
+        };
+        let current = BTreeMap::from([
+            ("game.exe".to_string(), digest(10, "aa")),
+            ("data/level.pak".to_string(), digest(20, "ff")),
+            ("saves/slot1.sav".to_string(), digest(5, "dd")),
+        ]);
+
+        let report = manifest.compare(&current);
+
+        assert_eq!(report.changed, vec!["data/level.pak"]);
+        assert_eq!(report.missing, vec!["data/music.pak"]);
+        assert_eq!(report.added, vec!["saves/slot1.sav"]);
+        assert_eq!(report.checked_files, 3);
+        assert!(!report.is_intact());
+    }
+
+    #[test]
+    fn test_identical_install_is_intact() {
+        let files = BTreeMap::from([("game.exe".to_string(), digest(10, "aa"))]);
+        let manifest = IntegrityManifest {
+            game_id: "manual_1".to_string(),
+            created_at: 0,
+            files: files.clone(),
+        };
+
+        assert!(manifest.compare(&files).is_intact());
+    }
+}
@@ -0,0 +1,204 @@
+use crate::domain::errors::LaunchFailureReason;
+use crate::domain::value_objects::GameSource;
+use serde::{Deserialize, Serialize};
+
+/// Oldest records are dropped past this many.
+pub const MAX_LAUNCH_RECORDS: usize = 1000;
+
+/// How a launch attempt ended.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchOutcome {
+    /// The game process started
+    Success,
+    /// The game never showed up within the startup timeout
+    Timeout,
+    /// The game started and exited right away (crash, missing DRM)
+    QuickExit,
+    /// The player closed the session before the game started
+    Cancelled,
+    /// Refused before or while launching (missing files, store client, hooks)
+    Failed,
+}
+
+impl From<&LaunchFailureReason> for LaunchOutcome {
+    fn from(reason: &LaunchFailureReason) -> Self {
+        match reason {
+            LaunchFailureReason::Timeout { .. } => Self::Timeout,
+            LaunchFailureReason::QuickExit { .. } => Self::QuickExit,
+            _ => Self::Failed,
+        }
+    }
+}
+
+/// One launch attempt.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LaunchRecord {
+    pub game_id: String,
+    pub source: GameSource,
+    /// Unix seconds of the launch request
+    pub started_at: u64,
+    /// From the launch request to the outcome
+    pub duration_ms: u64,
+    pub outcome: LaunchOutcome,
+    pub failure_reason: Option<String>,
+}
+
+/// Launch outcomes of one store.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct StoreLaunchStats {
+    pub source: GameSource,
+    pub attempts: u32,
+    pub successes: u32,
+    pub timeouts: u32,
+    pub quick_exits: u32,
+    pub cancelled: u32,
+    pub failures: u32,
+    /// Successes over attempts, 0.0 - 1.0
+    pub success_rate: f64,
+    /// Average time for a successful launch to start the game
+    pub average_start_ms: u64,
+}
+
+impl StoreLaunchStats {
+    fn new(source: GameSource) -> Self {
+        Self {
+            source,
+            attempts: 0,
+            successes: 0,
+            timeouts: 0,
+            quick_exits: 0,
+            cancelled: 0,
+            failures: 0,
+            success_rate: 0.0,
+            average_start_ms: 0,
+        }
+    }
+}
+
+/// Local launch log, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LaunchHistory {
+    pub records: Vec<LaunchRecord>,
+}
+
+impl LaunchHistory {
+    pub fn push(&mut self, record: LaunchRecord) {
+        self.records.push(record);
+        if self.records.len() > MAX_LAUNCH_RECORDS {
+            let excess = self.records.len() - MAX_LAUNCH_RECORDS;
+            self.records.drain(..excess);
+        }
+    }
+
+    /// Turns the latest launch of `game_id` into a quick exit if it was
+    /// recorded as a success (the process started, then died).
+    /// Returns `true` if a record changed.
+    pub fn amend_quick_exit(&mut self, game_id: &str, failure_reason: Option<String>) -> bool {
+        match self.records.iter_mut().rev().find(|record| record.game_id == game_id) {
+            Some(record) if record.outcome == LaunchOutcome::Success => {
+                record.outcome = LaunchOutcome::QuickExit;
+                record.failure_reason = failure_reason;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Launches of `game_id` (all games if `None`), newest first.
+    #[must_use]
+    pub fn for_game(&self, game_id: Option<&str>) -> Vec<LaunchRecord> {
+        self.records
+            .iter()
+            .rev()
+            .filter(|record| game_id.is_none() || game_id == Some(record.game_id.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Outcomes per store, most launched first.
+    #[must_use]
+    pub fn stats(&self) -> Vec<StoreLaunchStats> {
+        let mut stats: Vec<StoreLaunchStats> = Vec::new();
+        let mut start_ms: Vec<u64> = Vec::new();
+        for record in &self.records {
+            let index = stats
+                .iter()
+                .position(|entry| entry.source == record.source)
+                .unwrap_or_else(|| {
+                    stats.push(StoreLaunchStats::new(record.source));
+                    start_ms.push(0);
+                    stats.len() - 1
+                });
+            let entry = &mut stats[index];
+            entry.attempts += 1;
+            match record.outcome {
+                LaunchOutcome::Success => {
+                    entry.successes += 1;
+                    start_ms[index] += record.duration_ms;
+                },
+                LaunchOutcome::Timeout => entry.timeouts += 1,
+                LaunchOutcome::QuickExit => entry.quick_exits += 1,
+                LaunchOutcome::Cancelled => entry.cancelled += 1,
+                LaunchOutcome::Failed => entry.failures += 1,
+            }
+        }
+
+        for (entry, total_ms) in stats.iter_mut().zip(start_ms) {
+            entry.success_rate = f64::from(entry.successes) / f64::from(entry.attempts);
+            if entry.successes > 0 {
+                entry.average_start_ms = total_ms / u64::from(entry.successes);
+            }
+        }
+        stats.sort_by(|a, b| b.attempts.cmp(&a.attempts));
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(game_id: &str, source: GameSource, outcome: LaunchOutcome, duration_ms: u64) -> LaunchRecord {
+        LaunchRecord {
+            game_id: game_id.to_string(),
+            source,
+            started_at: 0,
+            duration_ms,
+            outcome,
+            failure_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_stats_per_store() {
+        let mut history = LaunchHistory::default();
+        history.push(record("epic_a", GameSource::Epic, LaunchOutcome::Success, 4000));
+        history.push(record("epic_a", GameSource::Epic, LaunchOutcome::Timeout, 90_000));
+        history.push(record("epic_b", GameSource::Epic, LaunchOutcome::Success, 2000));
+        history.push(record("epic_b", GameSource::Epic, LaunchOutcome::Success, 0));
+        assert!(history.amend_quick_exit("epic_b", Some("crash".to_string())));
+        history.push(record("steam_1", GameSource::Steam, LaunchOutcome::Success, 1000));
+
+        let stats = history.stats();
+        assert_eq!(stats[0].source, GameSource::Epic);
+        assert_eq!((stats[0].attempts, stats[0].successes, stats[0].timeouts), (4, 2, 1));
+        assert_eq!(stats[0].quick_exits, 1);
+        assert!((stats[0].success_rate - 0.5).abs() < f64::EPSILON);
+        assert_eq!(stats[0].average_start_ms, 3000);
+        assert_eq!(history.for_game(Some("epic_b"))[0].outcome, LaunchOutcome::QuickExit);
+        assert_eq!(history.for_game(None).len(), 5);
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = LaunchHistory::default();
+        for i in 0..=MAX_LAUNCH_RECORDS {
+            history.push(record(&i.to_string(), GameSource::Manual, LaunchOutcome::Success, 0));
+        }
+        assert_eq!(history.records.len(), MAX_LAUNCH_RECORDS);
+        assert_eq!(history.records[0].game_id, "1");
+        assert!(!history.amend_quick_exit("missing", None));
+    }
+}
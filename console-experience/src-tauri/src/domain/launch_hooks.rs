@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Longest a hook may run before it is killed and reported as failed.
+pub const MAX_HOOK_TIMEOUT_SECS: u32 = 300;
+const DEFAULT_HOOK_TIMEOUT_SECS: u32 = 30;
+
+/// When a hook runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStage {
+    PreLaunch,
+    PostExit,
+}
+
+impl HookStage {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PreLaunch => "pre_launch",
+            Self::PostExit => "post_exit",
+        }
+    }
+}
+
+/// A program run around a game session: a script (`.bat`, `.ps1`), a
+/// companion app (Special K, a controller remapper) or a command such as
+/// `rasdial "Work VPN"`.
+///
+/// The program receives `BALAM_GAME_ID`, `BALAM_GAME_TITLE`,
+/// `BALAM_GAME_SOURCE` and `BALAM_HOOK_STAGE` in its environment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LaunchHook {
+    /// Label shown in failure reports
+    pub name: String,
+    /// Executable or script path
+    pub command: String,
+    pub args: Vec<String>,
+    pub working_dir: Option<String>,
+    /// Wait for the program to exit (scripts). Off for companion apps that
+    /// keep running alongside the game.
+    pub wait: bool,
+    /// Waiting longer than this counts as a failure (the program is killed)
+    pub timeout_secs: u32,
+    /// Close a companion app started by a pre-launch hook when the game exits
+    pub stop_on_exit: bool,
+    /// Pre-launch only: a failure cancels the launch instead of just being reported
+    pub required: bool,
+}
+
+impl Default for LaunchHook {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            command: String::new(),
+            args: Vec::new(),
+            working_dir: None,
+            wait: true,
+            timeout_secs: DEFAULT_HOOK_TIMEOUT_SECS,
+            stop_on_exit: false,
+            required: false,
+        }
+    }
+}
+
+impl LaunchHook {
+    /// Label for logs and reports (the command when unnamed).
+    #[must_use]
+    pub fn label(&self) -> &str {
+        if self.name.trim().is_empty() {
+            &self.command
+        } else {
+            &self.name
+        }
+    }
+
+    /// Checks the command and timeout.
+    ///
+    /// # Errors
+    /// Returns error describing the invalid field.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.command.trim().is_empty() {
+            return Err(format!("Hook '{}' has no command", self.name));
+        }
+        if self.wait && !(1..=MAX_HOOK_TIMEOUT_SECS).contains(&self.timeout_secs) {
+            return Err(format!(
+                "Hook '{}' timeout {}s out of range (1 - {MAX_HOOK_TIMEOUT_SECS}s)",
+                self.label(),
+                self.timeout_secs
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Hooks of one stage pair (global or one game).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GameHooks {
+    pub pre_launch: Vec<LaunchHook>,
+    pub post_exit: Vec<LaunchHook>,
+}
+
+impl GameHooks {
+    #[must_use]
+    pub fn stage(&self, stage: HookStage) -> &[LaunchHook] {
+        match stage {
+            HookStage::PreLaunch => &self.pre_launch,
+            HookStage::PostExit => &self.post_exit,
+        }
+    }
+
+    /// Validates every hook.
+    ///
+    /// # Errors
+    /// Returns the first hook error.
+    pub fn validate(&self) -> Result<(), String> {
+        self.pre_launch
+            .iter()
+            .chain(&self.post_exit)
+            .try_for_each(LaunchHook::validate)
+    }
+}
+
+/// Hooks for every game plus per-game ones, keyed by game id.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct HookSettings {
+    pub global: GameHooks,
+    pub games: HashMap<String, GameHooks>,
+}
+
+impl HookSettings {
+    /// Hooks to run for `game_id` at `stage`, in order.
+    ///
+    /// Global hooks wrap the game's own: global pre-launch hooks run first
+    /// and global post-exit hooks last.
+    #[must_use]
+    pub fn for_game(&self, game_id: &str, stage: HookStage) -> Vec<LaunchHook> {
+        let global = self.global.stage(stage);
+        let game = self.games.get(game_id).map_or(&[][..], |hooks| hooks.stage(stage));
+        match stage {
+            HookStage::PreLaunch => global.iter().chain(game).cloned().collect(),
+            HookStage::PostExit => game.iter().chain(global).cloned().collect(),
+        }
+    }
+}
+
+/// Result of one hook, sent with `launch-hook-failed`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HookReport {
+    pub game_id: String,
+    pub hook: String,
+    pub stage: HookStage,
+    pub success: bool,
+    /// Exit code, timeout or spawn error
+    pub message: String,
+    pub duration_ms: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(name: &str) -> LaunchHook {
+        LaunchHook {
+            name: name.to_string(),
+            command: format!("{name}.bat"),
+            ..LaunchHook::default()
+        }
+    }
+
+    #[test]
+    fn test_global_hooks_wrap_game_hooks() {
+        let settings = HookSettings {
+            global: GameHooks {
+                pre_launch: vec![hook("vpn_on")],
+                post_exit: vec![hook("vpn_off")],
+            },
+            games: HashMap::from([(
+                "steam_1".to_string(),
+                GameHooks {
+                    pre_launch: vec![hook("specialk")],
+                    post_exit: vec![hook("backup")],
+                },
+            )]),
+        };
+        let names = |stage| -> Vec<String> {
+            settings
+                .for_game("steam_1", stage)
+                .into_iter()
+                .map(|hook| hook.name)
+                .collect()
+        };
+        assert_eq!(names(HookStage::PreLaunch), ["vpn_on", "specialk"]);
+        assert_eq!(names(HookStage::PostExit), ["backup", "vpn_off"]);
+        assert_eq!(settings.for_game("epic_2", HookStage::PreLaunch).len(), 1);
+
+        let mut invalid = hook("slow");
+        invalid.timeout_secs = MAX_HOOK_TIMEOUT_SECS + 1;
+        assert!(invalid.validate().is_err());
+        invalid.wait = false;
+        assert!(invalid.validate().is_ok());
+    }
+}
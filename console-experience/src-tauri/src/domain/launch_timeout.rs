@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+/// Shortest configurable game startup timeout.
+pub const MIN_LAUNCH_TIMEOUT_SECONDS: u64 = 10;
+/// Longest configurable game startup timeout (also caps adaptive extensions).
+pub const MAX_LAUNCH_TIMEOUT_SECONDS: u64 = 1800;
+/// Extra wait granted each time the store is seen busy with the game.
+pub const ADAPTIVE_EXTENSION_SECONDS: u64 = 20;
+
+/// Startup deadline of a launch that waits longer while the store is visibly
+/// working on the game (downloading an update, validating files, compiling
+/// shaders), instead of failing a launch that is merely slow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaptiveTimeout {
+    deadline: Duration,
+    /// `None` when adaptive mode is off
+    limit: Option<Duration>,
+}
+
+impl AdaptiveTimeout {
+    /// Fixed timeout of `timeout_seconds`.
+    #[must_use]
+    pub fn fixed(timeout_seconds: u64) -> Self {
+        Self {
+            deadline: Duration::from_secs(timeout_seconds),
+            limit: None,
+        }
+    }
+
+    /// Timeout of `timeout_seconds` that store activity can extend up to `limit_seconds`.
+    #[must_use]
+    pub fn adaptive(timeout_seconds: u64, limit_seconds: u64) -> Self {
+        Self {
+            deadline: Duration::from_secs(timeout_seconds),
+            limit: Some(Duration::from_secs(limit_seconds.max(timeout_seconds))),
+        }
+    }
+
+    /// Records store activity `elapsed` into the launch: keeps waiting at
+    /// least `ADAPTIVE_EXTENSION_SECONDS` more. Returns `true` if the deadline moved.
+    pub fn observe_activity(&mut self, elapsed: Duration) -> bool {
+        let Some(limit) = self.limit else {
+            return false;
+        };
+        let extended = (elapsed + Duration::from_secs(ADAPTIVE_EXTENSION_SECONDS)).min(limit);
+        if extended > self.deadline {
+            self.deadline = extended;
+            true
+        } else {
+            false
+        }
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, elapsed: Duration) -> bool {
+        elapsed >= self.deadline
+    }
+
+    /// Current deadline, for logs and the timeout error.
+    #[must_use]
+    pub fn seconds(&self) -> u64 {
+        self.deadline.as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_extends_adaptive_timeout_up_to_limit() {
+        let mut timeout = AdaptiveTimeout::adaptive(30, 60);
+        assert!(!timeout.observe_activity(Duration::from_secs(5)));
+        assert!(timeout.observe_activity(Duration::from_secs(25)));
+        assert_eq!(timeout.seconds(), 45);
+        assert!(timeout.observe_activity(Duration::from_secs(55)));
+        assert_eq!(timeout.seconds(), 60);
+        assert!(timeout.is_expired(Duration::from_secs(60)));
+
+        let mut fixed = AdaptiveTimeout::fixed(30);
+        assert!(!fixed.observe_activity(Duration::from_secs(25)));
+        assert!(fixed.is_expired(Duration::from_secs(30)));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::domain::errors::LaunchFailureReason;
+use crate::domain::value_objects::GameSource;
+use serde::{Deserialize, Serialize};
+
+/// Store client a game needs running to start (DRM, account checks).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LauncherDependency {
+    Steam,
+    EaApp,
+    UbisoftConnect,
+}
+
+/// Payload of `launcher-wait-progress`, emitted about once a second while a
+/// launch waits for its store client.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LauncherWaitProgress {
+    pub game_id: String,
+    pub launcher: LauncherDependency,
+    pub elapsed_secs: u64,
+    pub timeout_secs: u64,
+    /// Set on the last event once the client is ready
+    pub ready: bool,
+}
+
+/// What a store client can do right now.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StoreClientState {
+    NotRunning,
+    /// Running, but not ready for launch requests yet (self-update, boot)
+    Updating,
+    SignedOut,
+    /// Signed in, in offline mode (only offline-capable games start)
+    Offline,
+    Online,
+}
+
+impl StoreClientState {
+    /// Why a launch cannot even be attempted, if so.
+    ///
+    /// Offline mode still launches games, so it is reported only once a
+    /// launch times out.
+    #[must_use]
+    pub fn blocking_reason(&self) -> Option<LaunchFailureReason> {
+        match self {
+            Self::NotRunning => Some(LaunchFailureReason::ClientNotRunning),
+            Self::Updating => Some(LaunchFailureReason::ClientUpdating),
+            Self::SignedOut => Some(LaunchFailureReason::NotSignedIn),
+            Self::Offline | Self::Online => None,
+        }
+    }
+}
+
+/// Whether Steam's `config/loginusers.vdf` has the most recent account in
+/// offline mode.
+#[must_use]
+pub fn steam_wants_offline(loginusers_vdf: &str) -> bool {
+    let mut most_recent = false;
+    let mut wants_offline = false;
+    for line in loginusers_vdf.lines() {
+        // Each account block ends with its own "}" line
+        if line.trim() == "}" {
+            if most_recent {
+                return wants_offline;
+            }
+            wants_offline = false;
+            continue;
+        }
+        let tokens: Vec<&str> = line.split('"').filter(|token| !token.trim().is_empty()).collect();
+        match tokens.as_slice() {
+            [key, value] if key.eq_ignore_ascii_case("MostRecent") => most_recent = *value == "1",
+            [key, value] if key.eq_ignore_ascii_case("WantsOfflineMode") => wants_offline = *value == "1",
+            _ => {},
+        }
+    }
+    false
+}
+
+/// Files in a game folder that give away Ubisoft Connect DRM.
+const UBISOFT_MARKERS: &[&str] = &["uplay_r1_loader64.dll", "uplay_r2_loader64.dll", "upc_r2_loader64.dll"];
+/// Files/folders in a game folder that give away an EA app install.
+const EA_MARKERS: &[&str] = &["__installer", "eaanticheat.gameservicelauncher.exe"];
+
+impl LauncherDependency {
+    #[must_use]
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Steam => "Steam",
+            Self::EaApp => "EA app",
+            Self::UbisoftConnect => "Ubisoft Connect",
+        }
+    }
+
+    /// Processes of the running client (first one is the one started).
+    #[must_use]
+    pub fn process_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Steam => &["steam.exe"],
+            Self::EaApp => &["EADesktop.exe"],
+            Self::UbisoftConnect => &["UbisoftConnect.exe", "upc.exe"],
+        }
+    }
+}
+
+/// Client a game needs, from its store and the files next to its executable.
+///
+/// Steam games always need Steam; games bought elsewhere can still be
+/// wrapped in EA or Ubisoft DRM (e.g. Epic copies of EA/Ubisoft titles).
+#[must_use]
+pub fn infer_launcher_dependency(source: GameSource, file_names: &[String]) -> Option<LauncherDependency> {
+    if source == GameSource::Steam {
+        return Some(LauncherDependency::Steam);
+    }
+    let has_any = |markers: &[&str]| {
+        file_names
+            .iter()
+            .any(|name| markers.iter().any(|marker| name.eq_ignore_ascii_case(marker)))
+    };
+    if has_any(UBISOFT_MARKERS) {
+        Some(LauncherDependency::UbisoftConnect)
+    } else if has_any(EA_MARKERS) {
+        Some(LauncherDependency::EaApp)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(names: &[&str]) -> Vec<String> {
+        names.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_infer_launcher_dependency() {
+        assert_eq!(
+            infer_launcher_dependency(GameSource::Steam, &[]),
+            Some(LauncherDependency::Steam)
+        );
+        assert_eq!(
+            infer_launcher_dependency(GameSource::Epic, &files(&["ACValhalla.exe", "UPLAY_R2_LOADER64.dll"])),
+            Some(LauncherDependency::UbisoftConnect)
+        );
+        assert_eq!(
+            infer_launcher_dependency(GameSource::Epic, &files(&["__Installer", "bf2042.exe"])),
+            Some(LauncherDependency::EaApp)
+        );
+        assert_eq!(
+            infer_launcher_dependency(GameSource::Manual, &files(&["game.exe", "steam_api64.dll"])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_steam_wants_offline() {
+        let vdf = r#"
+"users"
+{
+	"76561197960287930"
+	{
+		"AccountName"		"old"
+		"MostRecent"		"0"
+		"WantsOfflineMode"		"1"
+	}
+	"76561197960287931"
+	{
+		"AccountName"		"player"
+		"MostRecent"		"1"
+		"WantsOfflineMode"		"0"
+	}
+}
+"#;
+        assert!(!steam_wants_offline(vdf));
+        assert!(steam_wants_offline(
+            r#"
+"users"
+{
+	"76561197960287931"
+	{
+		"MostRecent"		"1"
+		"WantsOfflineMode"		"1"
+	}
+}
+"#
+        ));
+        assert_eq!(StoreClientState::Offline.blocking_reason(), None);
+    }
+}
@@ -0,0 +1,194 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// How an Epic game is started.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EpicLaunchBackend {
+    /// `com.epicgames.launcher://` (needs the Epic Games Launcher)
+    #[default]
+    EpicLauncher,
+    /// `legendary launch` (open-source CLI, also bundled with Heroic)
+    Legendary,
+}
+
+/// A game in Legendary's `installed.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LegendaryInstall {
+    pub app_name: String,
+    pub title: String,
+    pub install_path: String,
+    /// Executable relative to `install_path`
+    pub executable: String,
+    /// Starts without a valid Epic session (`legendary launch --offline`)
+    #[serde(default)]
+    pub can_run_offline: bool,
+    #[serde(default)]
+    pub is_dlc: bool,
+    /// Installed build version
+    #[serde(default)]
+    pub version: String,
+}
+
+/// The part of Legendary's `user.json` (the saved Epic session) Balam reads.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegendarySession {
+    /// Legendary refreshes the access token by itself until this passes
+    pub refresh_expires_at: String,
+}
+
+impl LegendarySession {
+    /// Whether Legendary can still refresh its access token at `now`.
+    #[must_use]
+    pub fn is_valid_at(&self, now: DateTime<Utc>) -> bool {
+        DateTime::parse_from_rfc3339(&self.refresh_expires_at).is_ok_and(|expires| expires > now)
+    }
+}
+
+/// Games (no DLC) of an `installed.json`, keyed by `app_name` in the file.
+///
+/// # Errors
+/// Returns error if the file is not valid JSON.
+pub fn parse_legendary_installed(json: &str) -> Result<Vec<LegendaryInstall>, String> {
+    let installed: HashMap<String, LegendaryInstall> =
+        serde_json::from_str(json).map_err(|e| format!("Invalid Legendary installed.json: {e}"))?;
+    let mut games: Vec<LegendaryInstall> = installed.into_values().filter(|game| !game.is_dlc).collect();
+    games.sort_by(|a, b| a.title.cmp(&b.title));
+    Ok(games)
+}
+
+/// Latest build version of each app in Legendary's `assets.json` (refreshed
+/// by every `legendary list` / `launch`), keyed by app name.
+///
+/// Current files map platforms to asset lists; files from older Legendary
+/// versions hold the Windows list directly.
+#[must_use]
+pub fn parse_legendary_build_versions(json: &str) -> HashMap<String, String> {
+    #[derive(Deserialize)]
+    struct Asset {
+        app_name: String,
+        build_version: String,
+    }
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum AssetsFile {
+        ByPlatform(HashMap<String, Vec<Asset>>),
+        Windows(Vec<Asset>),
+    }
+
+    let assets = match serde_json::from_str::<AssetsFile>(json) {
+        Ok(AssetsFile::ByPlatform(mut platforms)) => platforms.remove("Windows").unwrap_or_default(),
+        Ok(AssetsFile::Windows(assets)) => assets,
+        Err(_) => Vec::new(),
+    };
+    assets
+        .into_iter()
+        .map(|asset| (asset.app_name, asset.build_version))
+        .collect()
+}
+
+/// Whether Legendary knows a newer build than the installed one.
+#[must_use]
+pub fn legendary_update_pending(game: &LegendaryInstall, build_versions: &HashMap<String, String>) -> bool {
+    build_versions
+        .get(&game.app_name)
+        .is_some_and(|latest| !game.version.is_empty() && *latest != game.version)
+}
+
+/// Arguments of `legendary` to start a game.
+///
+/// Without a valid session only games that run offline are started, with
+/// `--offline` (skips the ownership check).
+///
+/// # Errors
+/// Returns error if the game needs a session and there is none.
+pub fn legendary_launch_args(game: &LegendaryInstall, session_valid: bool) -> Result<Vec<String>, String> {
+    let mut args = vec!["launch".to_string(), game.app_name.clone()];
+    if !session_valid {
+        if !game.can_run_offline {
+            return Err(format!(
+                "{} needs an Epic sign-in: log in to Legendary first",
+                game.title
+            ));
+        }
+        args.push("--offline".to_string());
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_legendary_installed_skips_dlc() {
+        let json = r#"{
+            "Sugar": {
+                "app_name": "Sugar", "title": "Hades", "install_path": "C:\\Games\\Hades",
+                "executable": "x64\\Hades.exe", "can_run_offline": true, "is_dlc": false,
+                "version": "1.0"
+            },
+            "HadesSoundtrack": {
+                "app_name": "HadesSoundtrack", "title": "Hades OST", "install_path": "C:\\Games\\Hades",
+                "executable": "", "is_dlc": true
+            }
+        }"#;
+
+        let games = parse_legendary_installed(json).unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0].app_name, "Sugar");
+        assert!(games[0].can_run_offline);
+        assert!(parse_legendary_installed("not json").is_err());
+    }
+
+    #[test]
+    fn test_legendary_launch_args_and_session() {
+        let mut game = LegendaryInstall {
+            app_name: "Sugar".to_string(),
+            title: "Hades".to_string(),
+            install_path: "C:\\Games\\Hades".to_string(),
+            executable: "x64\\Hades.exe".to_string(),
+            can_run_offline: true,
+            is_dlc: false,
+            version: "1.0".to_string(),
+        };
+        assert_eq!(legendary_launch_args(&game, true).unwrap(), ["launch", "Sugar"]);
+        assert_eq!(
+            legendary_launch_args(&game, false).unwrap(),
+            ["launch", "Sugar", "--offline"]
+        );
+        game.can_run_offline = false;
+        assert!(legendary_launch_args(&game, false).is_err());
+
+        let now = DateTime::parse_from_rfc3339("2026-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let session = LegendarySession {
+            refresh_expires_at: "2026-01-15T10:00:00.000Z".to_string(),
+        };
+        assert!(session.is_valid_at(now));
+        assert!(!session.is_valid_at(now + chrono::Duration::days(30)));
+    }
+
+    #[test]
+    fn test_legendary_update_pending() {
+        let games = parse_legendary_installed(
+            r#"{ "Sugar": { "app_name": "Sugar", "title": "Hades", "install_path": "C:\\Games\\Hades",
+                 "executable": "Hades.exe", "version": "1.0" } }"#,
+        )
+        .unwrap();
+        let current = r#"{ "Windows": [{ "app_name": "Sugar", "build_version": "1.1", "namespace": "x" }] }"#;
+        let legacy = r#"[{ "app_name": "Sugar", "build_version": "1.0" }]"#;
+
+        assert!(legendary_update_pending(
+            &games[0],
+            &parse_legendary_build_versions(current)
+        ));
+        assert!(!legendary_update_pending(
+            &games[0],
+            &parse_legendary_build_versions(legacy)
+        ));
+        assert!(parse_legendary_build_versions("not json").is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+/// Cached library and its freshness
+///
+/// The library is served from the last scan's cache; scanning the stores
+/// only happens on request or in the background. The scan time lets the UI
+/// offer a refresh ("library updated 2h ago — refresh?").
+use serde::Serialize;
+
+use crate::domain::Game;
+
+/// A cache older than this is worth refreshing.
+pub const LIBRARY_STALE_AFTER_SECS: u64 = 24 * 60 * 60;
+
+/// Library as of the last scan.
+#[derive(Debug, Clone, Serialize)]
+pub struct CachedLibrary {
+    pub games: Vec<Game>,
+    /// Unix seconds of the last completed scan (`None`: never scanned)
+    pub scanned_at: Option<u64>,
+    /// Seconds since that scan
+    pub age_secs: Option<u64>,
+    /// Never scanned, or older than [`LIBRARY_STALE_AFTER_SECS`]
+    pub stale: bool,
+}
+
+impl CachedLibrary {
+    #[must_use]
+    pub fn new(games: Vec<Game>, scanned_at: Option<u64>, now: u64) -> Self {
+        // A scan "in the future" (clock changed) counts as just done
+        let age_secs = scanned_at.map(|scanned_at| now.saturating_sub(scanned_at));
+        Self {
+            games,
+            scanned_at,
+            age_secs,
+            stale: age_secs.is_none_or(|age| age > LIBRARY_STALE_AFTER_SECS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_staleness_follows_the_last_scan() {
+        let now = 1_700_000_000;
+        assert!(CachedLibrary::new(Vec::new(), None, now).stale);
+
+        let recent = CachedLibrary::new(Vec::new(), Some(now - 2 * 3600), now);
+        assert_eq!(recent.age_secs, Some(7200));
+        assert!(!recent.stale);
+
+        assert!(CachedLibrary::new(Vec::new(), Some(now - LIBRARY_STALE_AFTER_SECS - 1), now).stale);
+        assert_eq!(CachedLibrary::new(Vec::new(), Some(now + 60), now).age_secs, Some(0));
+    }
+}
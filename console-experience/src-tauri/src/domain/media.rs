@@ -0,0 +1,257 @@
+use serde::Serialize;
+
+/// Width of gallery thumbnails (height keeps the aspect ratio).
+pub const THUMBNAIL_WIDTH: u32 = 320;
+
+/// Folder used when the captured window does not belong to a known game.
+pub const UNKNOWN_GAME_FOLDER: &str = "Desktop";
+
+/// A saved screenshot.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Screenshot {
+    /// Full-size PNG
+    pub path: String,
+    /// Small PNG for the gallery grid, `None` if it could not be written
+    pub thumbnail_path: Option<String>,
+    /// Game folder the screenshot was filed under
+    pub game: String,
+    /// When it was taken (Unix epoch seconds)
+    pub taken_at: u64,
+}
+
+/// Gallery item type.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaKind {
+    Screenshot,
+    Clip,
+}
+
+impl MediaKind {
+    fn id_prefix(self) -> &'static str {
+        match self {
+            Self::Screenshot => "screenshot",
+            Self::Clip => "clip",
+        }
+    }
+}
+
+/// A screenshot or clip in the gallery.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MediaItem {
+    /// Identifies the item for `rename_media` / `delete_media`
+    pub id: String,
+    pub kind: MediaKind,
+    /// File name without extension
+    pub name: String,
+    pub path: String,
+    pub thumbnail_path: Option<String>,
+    /// Game folder the capture is filed under
+    pub game: String,
+    /// Library id of the game that was running, `None` for desktop captures
+    /// and captures taken before tagging existed
+    pub game_id: Option<String>,
+    /// When it was taken (Unix epoch seconds)
+    pub captured_at: u64,
+    pub size_bytes: u64,
+}
+
+/// Gallery id of a capture: `<kind>:<game folder>/<file name>`.
+#[must_use]
+pub fn media_id(kind: MediaKind, folder: &str, file_name: &str) -> String {
+    format!("{}:{folder}/{file_name}", kind.id_prefix())
+}
+
+/// Splits a gallery id into kind, game folder and file name.
+///
+/// Returns `None` for anything that could point outside the game folder
+/// (separators, `..`, hidden entries), since ids come from the frontend.
+#[must_use]
+pub fn parse_media_id(id: &str) -> Option<(MediaKind, &str, &str)> {
+    let (prefix, rest) = id.split_once(':')?;
+    let kind = [MediaKind::Screenshot, MediaKind::Clip]
+        .into_iter()
+        .find(|kind| kind.id_prefix() == prefix)?;
+    let (folder, file_name) = rest.split_once('/')?;
+    let valid = |part: &str| !part.is_empty() && !part.starts_with('.') && !part.contains(['/', '\\', ':']);
+    (valid(folder) && valid(file_name)).then_some((kind, folder, file_name))
+}
+
+/// Accepted range of `replay.buffer_seconds`.
+pub const MIN_REPLAY_SECONDS: u32 = 10;
+pub const MAX_REPLAY_SECONDS: u32 = 300;
+
+/// A saved replay clip.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReplayClip {
+    /// H.264 MP4 file
+    pub path: String,
+    pub game: String,
+    pub duration_seconds: f64,
+    /// When it was saved (Unix epoch seconds)
+    pub saved_at: u64,
+}
+
+/// Replay buffer state for the overlay.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct ReplayStatus {
+    /// `replay.enabled`
+    pub enabled: bool,
+    /// A game is being recorded right now
+    pub recording: bool,
+    /// Seconds that `save_replay` can write at the moment
+    pub buffered_seconds: u32,
+}
+
+/// One finished chunk of the rolling recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplaySegment {
+    pub path: String,
+    pub duration_ms: u64,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Newest segments that make up a `seconds` clip. Segments recorded at
+/// another resolution (before the window was resized) cannot be joined
+/// with the latest ones and are left out.
+#[must_use]
+pub fn segments_for_clip(segments: &[ReplaySegment], seconds: u32) -> &[ReplaySegment] {
+    let Some(latest) = segments.last() else {
+        return segments;
+    };
+    let wanted_ms = u64::from(seconds) * 1000;
+    let mut total_ms = 0;
+    let mut start = segments.len();
+    while start > 0 && total_ms < wanted_ms {
+        let segment = &segments[start - 1];
+        if (segment.width, segment.height) != (latest.width, latest.height) {
+            break;
+        }
+        total_ms += segment.duration_ms;
+        start -= 1;
+    }
+    &segments[start..]
+}
+
+/// How many of the oldest segments can be deleted while the rest still
+/// covers `buffer_seconds`.
+#[must_use]
+pub fn segments_to_prune(segments: &[ReplaySegment], buffer_seconds: u32) -> usize {
+    let buffer_ms = u64::from(buffer_seconds) * 1000;
+    let mut remaining_ms: u64 = segments.iter().map(|segment| segment.duration_ms).sum();
+    segments
+        .iter()
+        .take_while(|segment| {
+            let prune = remaining_ms - segment.duration_ms >= buffer_ms;
+            if prune {
+                remaining_ms -= segment.duration_ms;
+            }
+            prune
+        })
+        .count()
+}
+
+/// Folder name for a game title: characters Windows forbids in paths are
+/// dropped and trailing dots/spaces trimmed (Explorer cannot open those).
+#[must_use]
+pub fn game_folder_name(title: &str) -> String {
+    let cleaned = clean_file_name(title);
+    if cleaned.is_empty() {
+        UNKNOWN_GAME_FOLDER.to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// File name (without extension) for a capture the user renamed, `None` if
+/// nothing usable is left. Leading dots are dropped so the file cannot
+/// disappear into the hidden thumbnail/index entries.
+#[must_use]
+pub fn media_file_stem(name: &str) -> Option<String> {
+    let cleaned = clean_file_name(name);
+    let cleaned = cleaned.trim_start_matches('.').trim_start();
+    (!cleaned.is_empty()).then(|| cleaned.to_string())
+}
+
+fn clean_file_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .filter(|c| !matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') && !c.is_control())
+        .collect();
+    cleaned.trim().trim_end_matches('.').trim_end().to_string()
+}
+
+/// Thumbnail dimensions for a `width`×`height` image (never upscaled).
+#[must_use]
+pub fn thumbnail_size(width: u32, height: u32) -> (u32, u32) {
+    if width <= THUMBNAIL_WIDTH || width == 0 {
+        return (width, height);
+    }
+    let scaled = u64::from(height) * u64::from(THUMBNAIL_WIDTH) / u64::from(width);
+    (THUMBNAIL_WIDTH, (scaled as u32).max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_game_folder_name() {
+        assert_eq!(game_folder_name("DOOM: The Dark Ages"), "DOOM The Dark Ages");
+        assert_eq!(game_folder_name("What?/*"), "What");
+        assert_eq!(game_folder_name("S.T.A.L.K.E.R. "), "S.T.A.L.K.E.R");
+        assert_eq!(game_folder_name("???"), UNKNOWN_GAME_FOLDER);
+    }
+
+    fn segment(duration_ms: u64, height: u32) -> ReplaySegment {
+        ReplaySegment {
+            path: String::new(),
+            duration_ms,
+            width: 1280,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_replay_segment_selection() {
+        let segments = [
+            segment(5000, 720),
+            segment(5000, 800),
+            segment(5000, 800),
+            segment(2000, 800),
+        ];
+
+        // 2s + 5s + 5s covers 10s
+        assert_eq!(segments_for_clip(&segments, 10).len(), 3);
+        // The 720p segment cannot be joined
+        assert_eq!(segments_for_clip(&segments, 30).len(), 3);
+        assert!(segments_for_clip(&[], 30).is_empty());
+
+        assert_eq!(segments_to_prune(&segments, 10), 1);
+        assert_eq!(segments_to_prune(&segments, 30), 0);
+    }
+
+    #[test]
+    fn test_media_id_round_trip_and_rejects_traversal() {
+        let id = media_id(MediaKind::Clip, "Hades II", "2026-01-01_20-00-00.mp4");
+        assert_eq!(
+            parse_media_id(&id),
+            Some((MediaKind::Clip, "Hades II", "2026-01-01_20-00-00.mp4"))
+        );
+        assert_eq!(parse_media_id("clip:../Windows/win.ini"), None);
+        assert_eq!(parse_media_id("screenshot:Game/..\\evil.png"), None);
+        assert_eq!(parse_media_id("screenshot:Game/.media.json"), None);
+        assert_eq!(parse_media_id("video:Game/a.mp4"), None);
+
+        assert_eq!(media_file_stem("  Boss fight?  ").as_deref(), Some("Boss fight"));
+        assert_eq!(media_file_stem(".."), None);
+    }
+
+    #[test]
+    fn test_thumbnail_size() {
+        assert_eq!(thumbnail_size(1920, 1080), (320, 180));
+        assert_eq!(thumbnail_size(1280, 800), (320, 200));
+        assert_eq!(thumbnail_size(200, 100), (200, 100));
+    }
+}
@@ -1,18 +1,72 @@
+pub mod alerts;
+pub mod artwork;
+pub mod autostart;
+pub mod benchmark;
 pub mod bluetooth;
+pub mod browser;
+pub mod completion_time;
+pub mod console_mode;
+pub mod controller_input;
+pub mod custom_scanner;
 pub mod display;
 pub mod entities;
 pub mod errors;
+pub mod external_api;
+pub mod external_game;
+pub mod focus;
+pub mod game_move;
 pub mod game_process;
+pub mod gamepad_polling;
 pub mod haptic;
+pub mod hotkey;
+pub mod idle;
+pub mod integrity;
+pub mod launch_history;
+pub mod launch_hooks;
+pub mod launch_timeout;
+pub mod launcher_dependency;
+pub mod legendary;
+pub mod library_cache;
+pub mod media;
+pub mod network_quality;
+pub mod notification;
+pub mod overlay_conflict;
+pub mod overlay_layout;
+pub mod parental;
 pub mod performance;
+pub mod pip;
+pub mod present_latency;
+pub mod privilege;
+pub mod quick_settings;
+pub mod quiet_mode;
+pub mod registry_rules;
+pub mod remote;
+pub mod scaling;
 pub mod services;
+pub mod session_summary;
+pub mod settings;
+pub mod standalone;
+pub mod stutter;
+pub mod sunshine;
+pub mod supervision;
+pub mod tdp_governor;
+pub mod telemetry;
+pub mod text_input;
+pub mod thermal;
+pub mod update;
 pub mod value_objects;
 pub mod wifi;
+pub mod xbox;
 
 pub use display::{BrightnessConfig, RefreshRateConfig};
 pub use entities::Game;
-pub use errors::{GameLaunchError, LaunchFailureReason, ScanError, SystemError};
+pub use errors::{BalamError, ErrorCategory, GameLaunchError, LaunchFailureReason, ScanError, SystemError};
 pub use game_process::GameProcess;
-pub use haptic::{HapticFeedback, HapticIntensity};
-pub use performance::{PerformanceProfile, TDPConfig};
-pub use value_objects::GameSource;
+pub use haptic::{HapticEvent, HapticFeedback, HapticIntensity, HapticPattern};
+pub use hotkey::{GamepadButton, GamepadChordBinding, HotkeyAction};
+pub use notification::{Notification, NotificationCategory, NotificationDelivery, NotificationPriority};
+pub use performance::{PerformanceProfile, TDPConfig, TdpBackend};
+pub use settings::AppSettings;
+pub use value_objects::{
+    GameCollection, GameQuery, GameSortOrder, GameSource, InstallState, LibraryOrganization, LibraryRow, PlayHistory,
+};
@@ -0,0 +1,116 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Ping samples kept for the rolling statistics (one per second).
+pub const LATENCY_WINDOW_SAMPLES: usize = 30;
+/// Accepted range of `network.latency_spike_ms`.
+pub const MIN_LATENCY_SPIKE_MS: u32 = 20;
+pub const MAX_LATENCY_SPIKE_MS: u32 = 2000;
+
+/// Connection quality to the configured ping target, shown in the overlay.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct NetworkQuality {
+    /// Host being pinged (`network.ping_target`)
+    pub target: String,
+    /// Most recent round-trip time, `None` if it was lost (or nothing measured yet)
+    pub latency_ms: Option<f64>,
+    /// Average round-trip time over the window
+    pub average_latency_ms: Option<f64>,
+    /// Mean difference between consecutive round-trip times
+    pub jitter_ms: Option<f64>,
+    /// Share of the window's pings that got no reply
+    pub packet_loss_percent: f64,
+    /// Pings in the window
+    pub samples: usize,
+    /// `false` while no game is running (the monitor only measures in-game)
+    pub measuring: bool,
+}
+
+/// Payload of `network-latency-spike`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LatencySpike {
+    pub target: String,
+    pub latency_ms: f64,
+    /// `network.latency_spike_ms` at the time of the spike
+    pub threshold_ms: u32,
+}
+
+/// Rolling window of ping results (`None` = lost).
+#[derive(Debug, Clone, Default)]
+pub struct LatencyWindow {
+    samples: VecDeque<Option<f64>>,
+}
+
+impl LatencyWindow {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a ping result, dropping the oldest past [`LATENCY_WINDOW_SAMPLES`].
+    pub fn push(&mut self, latency_ms: Option<f64>) {
+        if self.samples.len() == LATENCY_WINDOW_SAMPLES {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency_ms);
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Statistics of the window for `target`.
+    #[must_use]
+    pub fn quality(&self, target: &str, measuring: bool) -> NetworkQuality {
+        let received: Vec<f64> = self.samples.iter().flatten().copied().collect();
+        let average_latency_ms = (!received.is_empty()).then(|| received.iter().sum::<f64>() / received.len() as f64);
+        let jitter_ms = (received.len() >= 2).then(|| {
+            let deltas: f64 = received.windows(2).map(|pair| (pair[1] - pair[0]).abs()).sum();
+            deltas / (received.len() - 1) as f64
+        });
+        let lost = self.samples.len() - received.len();
+        let packet_loss_percent = if self.samples.is_empty() {
+            0.0
+        } else {
+            lost as f64 * 100.0 / self.samples.len() as f64
+        };
+
+        NetworkQuality {
+            target: target.to_string(),
+            latency_ms: self.samples.back().copied().flatten(),
+            average_latency_ms,
+            jitter_ms,
+            packet_loss_percent,
+            samples: self.samples.len(),
+            measuring,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_statistics() {
+        let mut window = LatencyWindow::new();
+        assert_eq!(window.quality("1.1.1.1", false).average_latency_ms, None);
+
+        for sample in [Some(20.0), Some(30.0), None, Some(10.0)] {
+            window.push(sample);
+        }
+        let quality = window.quality("1.1.1.1", true);
+        assert_eq!(quality.latency_ms, Some(10.0));
+        assert_eq!(quality.average_latency_ms, Some(20.0));
+        // |30-20| + |10-30| over two deltas
+        assert_eq!(quality.jitter_ms, Some(15.0));
+        assert!((quality.packet_loss_percent - 25.0).abs() < f64::EPSILON);
+
+        for _ in 0..LATENCY_WINDOW_SAMPLES {
+            window.push(Some(5.0));
+        }
+        let quality = window.quality("1.1.1.1", true);
+        assert_eq!(quality.samples, LATENCY_WINDOW_SAMPLES);
+        assert!(quality.packet_loss_percent.abs() < f64::EPSILON);
+    }
+}
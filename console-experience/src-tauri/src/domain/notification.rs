@@ -0,0 +1,122 @@
+/// Notification domain entities
+///
+/// Backend events surfaced to the user (launch failures, low battery,
+/// controller disconnects) and the rules deciding where they are shown.
+use serde::{Deserialize, Serialize};
+
+/// Importance of a notification (ordered: `Low < Normal < High < Critical`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationPriority {
+    /// Informational, only kept in the notification history
+    Low,
+    /// Regular events (e.g. download finished)
+    Normal,
+    /// Needs attention soon (launch failed, controller disconnected)
+    High,
+    /// Needs attention now (battery about to die); ignores do-not-disturb
+    Critical,
+}
+
+/// What a notification is about (lets the UI pick an icon).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationCategory {
+    GameLaunch,
+    Battery,
+    Controller,
+    System,
+}
+
+/// A user-facing notification.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Notification {
+    /// Assigned by the notification port (0 until posted)
+    pub id: u64,
+    pub category: NotificationCategory,
+    pub priority: NotificationPriority,
+    pub title: String,
+    pub body: String,
+    /// Unix epoch seconds
+    pub timestamp: u64,
+}
+
+impl Notification {
+    #[must_use]
+    pub fn new(
+        category: NotificationCategory,
+        priority: NotificationPriority,
+        title: impl Into<String>,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: 0,
+            category,
+            priority,
+            title: title.into(),
+            body: body.into(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Channels a notification was delivered to.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NotificationDelivery {
+    /// Shown as a Windows toast
+    pub toast: bool,
+    /// Shown as a banner in the Balam UI / in-game overlay
+    pub overlay: bool,
+}
+
+impl NotificationDelivery {
+    /// Decides the channels for a notification.
+    ///
+    /// While a game is fullscreen (do-not-disturb) toasts are suppressed, since
+    /// they steal focus or are invisible in exclusive fullscreen; only
+    /// `High`+ reaches the overlay and only `Critical` also raises a toast.
+    /// Everything is kept in the history either way.
+    #[must_use]
+    pub fn route(priority: NotificationPriority, do_not_disturb: bool) -> Self {
+        if do_not_disturb {
+            Self {
+                toast: priority >= NotificationPriority::Critical,
+                overlay: priority >= NotificationPriority::High,
+            }
+        } else {
+            Self {
+                toast: priority >= NotificationPriority::Normal,
+                overlay: true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_without_dnd() {
+        let low = NotificationDelivery::route(NotificationPriority::Low, false);
+        assert!(!low.toast && low.overlay);
+
+        let normal = NotificationDelivery::route(NotificationPriority::Normal, false);
+        assert!(normal.toast && normal.overlay);
+    }
+
+    #[test]
+    fn test_route_with_dnd() {
+        let normal = NotificationDelivery::route(NotificationPriority::Normal, true);
+        assert_eq!(normal, NotificationDelivery::default());
+
+        let high = NotificationDelivery::route(NotificationPriority::High, true);
+        assert!(!high.toast && high.overlay);
+
+        let critical = NotificationDelivery::route(NotificationPriority::Critical, true);
+        assert!(critical.toast && critical.overlay);
+    }
+}
@@ -0,0 +1,129 @@
+/// Overlay conflicts
+///
+/// Other overlays hook the same present call Balam's DLL overlay injects
+/// into; stacking them makes injection fail or crash the game. These types
+/// describe what was found so the player can turn the others off.
+use serde::{Deserialize, Serialize};
+
+/// Third-party overlay or capture service known to hook games.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayConflictKind {
+    XboxGameBar,
+    GeforceOverlay,
+    DiscordOverlay,
+    Rtss,
+}
+
+impl OverlayConflictKind {
+    #[must_use]
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::XboxGameBar => "Xbox Game Bar",
+            Self::GeforceOverlay => "NVIDIA / GeForce Experience overlay",
+            Self::DiscordOverlay => "Discord overlay",
+            Self::Rtss => "RivaTuner Statistics Server",
+        }
+    }
+}
+
+/// One conflicting overlay, as returned by `get_overlay_conflicts`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OverlayConflict {
+    pub kind: OverlayConflictKind,
+    pub name: String,
+    /// Why it conflicts and how to turn it off
+    pub detail: String,
+    /// Whether its process is running right now
+    pub running: bool,
+    /// Balam can turn it off itself (`set_game_bar_enabled`)
+    pub can_disable: bool,
+}
+
+/// What the scanner saw on the system.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OverlayEnvironment {
+    /// Running executable names, lowercase
+    pub processes: Vec<String>,
+    pub game_bar_enabled: bool,
+    /// Hooking setting of RTSS' global profile, `None` if unreadable
+    pub rtss_hooking: Option<bool>,
+}
+
+impl OverlayEnvironment {
+    fn running(&self, names: &[&str]) -> bool {
+        self.processes.iter().any(|process| names.contains(&process.as_str()))
+    }
+
+    /// Overlays that would compete with Balam's injected overlay.
+    #[must_use]
+    pub fn conflicts(&self) -> Vec<OverlayConflict> {
+        let mut conflicts = Vec::new();
+        let mut push = |kind: OverlayConflictKind, detail: &str, running: bool| {
+            conflicts.push(OverlayConflict {
+                kind,
+                name: kind.display_name().to_string(),
+                detail: detail.to_string(),
+                running,
+                can_disable: kind == OverlayConflictKind::XboxGameBar,
+            });
+        };
+
+        if self.game_bar_enabled {
+            push(
+                OverlayConflictKind::XboxGameBar,
+                "Game Bar and its background capture hook every game",
+                self.running(&["gamebar.exe", "gamebarftserver.exe"]),
+            );
+        }
+        let geforce = self.running(&["nvidia share.exe", "nvidia overlay.exe"]);
+        if geforce {
+            push(
+                OverlayConflictKind::GeforceOverlay,
+                "Turn off the in-game overlay in GeForce Experience or the NVIDIA app",
+                true,
+            );
+        }
+        if self.running(&["discord.exe"]) {
+            let hooked = self.running(&["discordhookhelper.exe", "discordhookhelper64.exe"]);
+            let detail = if hooked {
+                "The Discord overlay is hooked into a game; turn it off in Discord > Game Overlay"
+            } else {
+                "The Discord overlay may be enabled; turn it off in Discord > Game Overlay"
+            };
+            push(OverlayConflictKind::DiscordOverlay, detail, true);
+        }
+        if self.running(&["rtss.exe"]) && self.rtss_hooking != Some(false) {
+            push(
+                OverlayConflictKind::Rtss,
+                "RTSS hooks every 3D application; add the game with application detection \
+                 level None or close RTSS",
+                true,
+            );
+        }
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conflicts_from_environment() {
+        let environment = OverlayEnvironment {
+            processes: vec!["discord.exe".into(), "rtss.exe".into(), "explorer.exe".into()],
+            game_bar_enabled: true,
+            rtss_hooking: Some(false),
+        };
+        let kinds: Vec<_> = environment.conflicts().iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            [OverlayConflictKind::XboxGameBar, OverlayConflictKind::DiscordOverlay]
+        );
+        assert!(environment.conflicts()[0].can_disable);
+        assert!(!environment.conflicts()[0].running);
+
+        assert!(OverlayEnvironment::default().conflicts().is_empty());
+    }
+}
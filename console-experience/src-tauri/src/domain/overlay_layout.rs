@@ -0,0 +1,285 @@
+/// Overlay layout
+///
+/// Which metrics the performance HUD shows and in which screen corner. A
+/// preset picks a fixed set of fields; `Custom` uses the player's own list.
+/// The same layout drives the built-in overlay and the RTSS OSD text.
+///
+/// On top of the layout, the overlay level (Steam Deck style, cycled with a
+/// gamepad chord) picks how much of it is shown: nothing, FPS, FPS and
+/// battery, or the full layout.
+use serde::{Deserialize, Serialize};
+
+use crate::domain::performance::PerformanceMetrics;
+
+/// One metric the HUD can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayField {
+    Fps,
+    FrameTime,
+    CpuUsage,
+    CpuTemp,
+    GpuUsage,
+    GpuTemp,
+    GpuPower,
+    Ram,
+    /// Dedicated GPU memory, flagged when the game holds more than the GPU has
+    Vram,
+    Battery,
+}
+
+/// Predefined set of fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayPreset {
+    #[default]
+    FpsOnly,
+    FullHud,
+    /// FPS plus what drains the battery: battery, CPU/GPU load and GPU power
+    BatteryFocused,
+    /// `OverlayLayout::custom_fields`
+    Custom,
+}
+
+impl OverlayPreset {
+    /// Fields of the preset, `None` for `Custom`.
+    #[must_use]
+    pub fn fields(self) -> Option<&'static [OverlayField]> {
+        use OverlayField::{Battery, CpuTemp, CpuUsage, Fps, FrameTime, GpuPower, GpuTemp, GpuUsage, Ram, Vram};
+        match self {
+            Self::FpsOnly => Some(&[Fps]),
+            Self::FullHud => Some(&[
+                Fps, FrameTime, CpuUsage, CpuTemp, GpuUsage, GpuTemp, GpuPower, Ram, Vram, Battery,
+            ]),
+            Self::BatteryFocused => Some(&[Fps, Battery, CpuUsage, GpuUsage, GpuPower]),
+            Self::Custom => None,
+        }
+    }
+}
+
+/// Screen corner the HUD is anchored to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayCorner {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+/// How much of the HUD is shown, serialized as its number (0 - 3).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum OverlayLevel {
+    Off,
+    Fps,
+    FpsBattery,
+    /// Every field of the layout
+    #[default]
+    Full,
+}
+
+impl OverlayLevel {
+    /// Next level for the cycle chord, wrapping from `Full` to `Off`.
+    #[must_use]
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Fps,
+            Self::Fps => Self::FpsBattery,
+            Self::FpsBattery => Self::Full,
+            Self::Full => Self::Off,
+        }
+    }
+}
+
+impl From<OverlayLevel> for u8 {
+    fn from(level: OverlayLevel) -> Self {
+        level as u8
+    }
+}
+
+impl TryFrom<u8> for OverlayLevel {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Fps),
+            2 => Ok(Self::FpsBattery),
+            3 => Ok(Self::Full),
+            other => Err(format!("Overlay level {other} out of range (0 - 3)")),
+        }
+    }
+}
+
+/// HUD layout saved in `OverlaySettings`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverlayLayout {
+    pub preset: OverlayPreset,
+    /// Fields in display order, used with `OverlayPreset::Custom`
+    pub custom_fields: Vec<OverlayField>,
+    pub corner: OverlayCorner,
+}
+
+impl OverlayLayout {
+    /// Fields shown, in display order.
+    #[must_use]
+    pub fn fields(&self) -> &[OverlayField] {
+        self.preset.fields().unwrap_or(&self.custom_fields)
+    }
+
+    /// Fields shown at `level`; only `Full` uses the layout's own fields.
+    #[must_use]
+    pub fn fields_at(&self, level: OverlayLevel) -> &[OverlayField] {
+        match level {
+            OverlayLevel::Off => &[],
+            OverlayLevel::Fps => &[OverlayField::Fps],
+            OverlayLevel::FpsBattery => &[OverlayField::Fps, OverlayField::Battery],
+            OverlayLevel::Full => self.fields(),
+        }
+    }
+
+    /// # Errors
+    /// Returns error for a custom layout without fields or with a field listed twice.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.preset != OverlayPreset::Custom {
+            return Ok(());
+        }
+        if self.custom_fields.is_empty() {
+            return Err("Custom overlay layout needs at least one field".to_string());
+        }
+        for (index, field) in self.custom_fields.iter().enumerate() {
+            if self.custom_fields[..index].contains(field) {
+                return Err(format!("Overlay field {field:?} is listed twice"));
+            }
+        }
+        Ok(())
+    }
+
+    /// One text line per field shown at `level` with a value; fields without
+    /// a reading (no sensor, no battery) are left out.
+    #[must_use]
+    pub fn lines(
+        &self,
+        level: OverlayLevel,
+        metrics: &PerformanceMetrics,
+        battery_percent: Option<u8>,
+        charging: bool,
+    ) -> Vec<String> {
+        let celsius = |value: Option<f32>| value.map(|c| format!("{c:.0}°C"));
+        self.fields_at(level)
+            .iter()
+            .filter_map(|field| match field {
+                OverlayField::Fps => Some(
+                    metrics
+                        .fps
+                        .as_ref()
+                        .map_or_else(|| "FPS --".to_string(), |fps| format!("FPS {:.0}", fps.current_fps)),
+                ),
+                OverlayField::FrameTime => metrics.fps.as_ref().map(|fps| format!("{:.1} ms", fps.frame_time_ms)),
+                OverlayField::CpuUsage => Some(format!("CPU {:.0}%", metrics.cpu_usage)),
+                OverlayField::CpuTemp => celsius(metrics.cpu_temp_c).map(|temp| format!("CPU {temp}")),
+                OverlayField::GpuUsage => Some(format!("GPU {:.0}%", metrics.gpu_usage)),
+                OverlayField::GpuTemp => celsius(metrics.gpu_temp_c).map(|temp| format!("GPU {temp}")),
+                OverlayField::GpuPower => metrics.gpu_power_w.map(|watts| format!("GPU {watts:.1} W")),
+                OverlayField::Ram => Some(format!("RAM {:.1}/{:.1} GB", metrics.ram_used_gb, metrics.ram_total_gb)),
+                OverlayField::Vram => metrics.vram_used_mb.zip(metrics.vram_total_mb).map(|(used, total)| {
+                    let warning = if metrics.game_exceeds_vram() { " !" } else { "" };
+                    format!(
+                        "VRAM {:.1}/{:.1} GB{warning}",
+                        used as f32 / 1024.0,
+                        total as f32 / 1024.0
+                    )
+                }),
+                OverlayField::Battery => {
+                    battery_percent.map(|percent| format!("BAT {percent}%{}", if charging { " +" } else { "" }))
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::performance::{FPSStats, ProcessMetrics};
+
+    #[test]
+    fn test_layout_lines() {
+        let metrics = PerformanceMetrics {
+            cpu_usage: 45.2,
+            gpu_usage: 78.0,
+            gpu_power_w: Some(12.34),
+            fps: Some(FPSStats::new(60.0)),
+            ..PerformanceMetrics::default()
+        };
+
+        let battery = OverlayLayout {
+            preset: OverlayPreset::BatteryFocused,
+            ..OverlayLayout::default()
+        };
+        assert_eq!(
+            battery.lines(OverlayLevel::Full, &metrics, Some(81), true),
+            ["FPS 60", "BAT 81% +", "CPU 45%", "GPU 78%", "GPU 12.3 W"]
+        );
+
+        // Missing readings are skipped
+        let custom = OverlayLayout {
+            preset: OverlayPreset::Custom,
+            custom_fields: vec![OverlayField::CpuTemp, OverlayField::FrameTime, OverlayField::Battery],
+            corner: OverlayCorner::BottomRight,
+        };
+        assert_eq!(custom.lines(OverlayLevel::Full, &metrics, None, false), ["16.7 ms"]);
+
+        // A game spilling past a 512 MB iGPU carve-out is flagged
+        let vram = OverlayLayout {
+            custom_fields: vec![OverlayField::Vram],
+            ..custom.clone()
+        };
+        let spilling = PerformanceMetrics {
+            vram_used_mb: Some(512),
+            vram_total_mb: Some(512),
+            game: Some(ProcessMetrics {
+                gpu_dedicated_mb: Some(1536),
+                ..ProcessMetrics::default()
+            }),
+            ..metrics.clone()
+        };
+        assert_eq!(
+            vram.lines(OverlayLevel::Full, &spilling, None, false),
+            ["VRAM 0.5/0.5 GB !"]
+        );
+        assert!(vram.lines(OverlayLevel::Full, &metrics, None, false).is_empty());
+        assert!(custom.validate().is_ok());
+        assert!(OverlayLayout {
+            custom_fields: Vec::new(),
+            ..custom
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_overlay_levels() {
+        let metrics = PerformanceMetrics {
+            fps: Some(FPSStats::new(60.0)),
+            ..PerformanceMetrics::default()
+        };
+        let layout = OverlayLayout {
+            preset: OverlayPreset::FullHud,
+            ..OverlayLayout::default()
+        };
+
+        assert!(layout.lines(OverlayLevel::Off, &metrics, Some(50), false).is_empty());
+        assert_eq!(layout.lines(OverlayLevel::Fps, &metrics, Some(50), false), ["FPS 60"]);
+        assert_eq!(
+            layout.lines(OverlayLevel::FpsBattery, &metrics, Some(50), false),
+            ["FPS 60", "BAT 50%"]
+        );
+        assert_eq!(OverlayLevel::Full.next(), OverlayLevel::Off);
+        assert_eq!(serde_json::to_string(&OverlayLevel::FpsBattery).unwrap(), "2");
+        assert!(serde_json::from_str::<OverlayLevel>("4").is_err());
+    }
+}
@@ -0,0 +1,298 @@
+/// Parental controls domain
+///
+/// PIN-protected restrictions for a shared console: a locked Settings
+/// screen, games hidden by age rating or by name, and a daily playtime
+/// budget per restriction profile. The active profile applies until a parent
+/// unlocks with the PIN and changes it.
+use crate::domain::privilege::tokens_match;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+
+pub const MIN_PIN_LEN: usize = 4;
+pub const MAX_PIN_LEN: usize = 8;
+/// Wrong PINs allowed before unlocking is throttled.
+pub const FREE_PIN_ATTEMPTS: u32 = 3;
+/// Longest wait imposed after repeated wrong PINs.
+const MAX_LOCKOUT_SECS: u64 = 15 * 60;
+/// How long a correct PIN keeps the restricted screens open.
+pub const UNLOCK_WINDOW_SECS: u64 = 5 * 60;
+/// Remaining playtime (minutes) at which the player is warned.
+pub const PLAYTIME_WARNINGS_MINUTES: [u32; 2] = [15, 5];
+/// Longest daily limit accepted (a whole day means no limit).
+const MAX_DAILY_LIMIT_MINUTES: u32 = 24 * 60;
+/// PIN stretching rounds; a 4-digit PIN space is small, this only slows offline guessing.
+const PIN_HASH_ROUNDS: u32 = 100_000;
+
+/// Salted hash of the parent PIN (the PIN itself is never stored).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PinHash {
+    pub salt: String,
+    pub hash: String,
+}
+
+impl PinHash {
+    #[must_use]
+    pub fn new(pin: &str, salt: &str) -> Self {
+        Self {
+            salt: salt.to_string(),
+            hash: hash_pin(pin, salt),
+        }
+    }
+
+    #[must_use]
+    pub fn matches(&self, pin: &str) -> bool {
+        tokens_match(&self.hash, &hash_pin(pin, &self.salt))
+    }
+}
+
+fn hash_pin(pin: &str, salt: &str) -> String {
+    let mut digest = Sha256::new().chain_update(salt).chain_update(pin).finalize();
+    for _ in 1..PIN_HASH_ROUNDS {
+        digest = Sha256::new().chain_update(digest).chain_update(salt).finalize();
+    }
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Checks a new PIN: 4-8 digits.
+///
+/// # Errors
+/// Returns error describing the expected format.
+pub fn validate_pin(pin: &str) -> Result<(), String> {
+    if (MIN_PIN_LEN..=MAX_PIN_LEN).contains(&pin.len()) && pin.chars().all(|c| c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(format!("The PIN must have {MIN_PIN_LEN}-{MAX_PIN_LEN} digits"))
+    }
+}
+
+/// Wait imposed after `failures` consecutive wrong PINs: none for the first
+/// few, then 30 s doubling up to 15 minutes.
+#[must_use]
+pub fn lockout_secs(failures: u32) -> u64 {
+    if failures < FREE_PIN_ATTEMPTS {
+        return 0;
+    }
+    let doublings = (failures - FREE_PIN_ATTEMPTS).min(5);
+    (30u64 << doublings).min(MAX_LOCKOUT_SECS)
+}
+
+/// Wrong-PIN state, persisted so restarting Balam does not reset the wait.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PinLockout {
+    pub failures: u32,
+    /// Unix epoch seconds before which no PIN is checked
+    pub retry_after: u64,
+}
+
+/// Restrictions applied while a profile is active (e.g. "Kids", "Teen").
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ParentalProfile {
+    pub id: String,
+    pub name: String,
+    /// Highest age rating allowed (PEGI-style: 3, 7, 12, 16, 18)
+    pub max_age_rating: Option<u8>,
+    /// Hide games the parent has not rated (with `max_age_rating` set)
+    pub hide_unrated: bool,
+    /// Games hidden regardless of rating
+    pub blocked_games: BTreeSet<String>,
+    /// Daily playtime budget, `None` for unlimited
+    pub daily_limit_minutes: Option<u32>,
+}
+
+impl ParentalProfile {
+    /// Whether the profile may see and launch `game_id`.
+    #[must_use]
+    pub fn allows(&self, game_id: &str, rating: Option<u8>) -> bool {
+        if self.blocked_games.contains(game_id) {
+            return false;
+        }
+        match (self.max_age_rating, rating) {
+            (Some(max), Some(rating)) => rating <= max,
+            (Some(_), None) => !self.hide_unrated,
+            (None, _) => true,
+        }
+    }
+}
+
+/// Everything the parent configures, stored in `parental.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ParentalControls {
+    /// `None` until a parent sets a PIN; nothing is restricted without one
+    pub pin: Option<PinHash>,
+    pub lockout: PinLockout,
+    /// Settings need the PIN
+    pub lock_settings: bool,
+    /// Game ID → minimum age, assigned by the parent
+    pub game_ratings: BTreeMap<String, u8>,
+    pub profiles: Vec<ParentalProfile>,
+    /// Profile restricting the console right now
+    pub active_profile: Option<String>,
+}
+
+impl ParentalControls {
+    /// Profile in effect, if a PIN is set and a profile is selected.
+    #[must_use]
+    pub fn active(&self) -> Option<&ParentalProfile> {
+        self.pin.as_ref()?;
+        let id = self.active_profile.as_deref()?;
+        self.profiles.iter().find(|profile| profile.id == id)
+    }
+
+    /// Whether the active profile (if any) may see and launch `game_id`.
+    #[must_use]
+    pub fn is_game_allowed(&self, game_id: &str) -> bool {
+        self.active()
+            .is_none_or(|profile| profile.allows(game_id, self.game_ratings.get(game_id).copied()))
+    }
+
+    /// Checks profile IDs, names and limits.
+    ///
+    /// # Errors
+    /// Returns error describing the first invalid value.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut ids = BTreeSet::new();
+        for profile in &self.profiles {
+            if profile.id.trim().is_empty() || profile.name.trim().is_empty() {
+                return Err("Profiles need an ID and a name".to_string());
+            }
+            if !ids.insert(profile.id.as_str()) {
+                return Err(format!("Duplicate profile ID: {}", profile.id));
+            }
+            if profile
+                .daily_limit_minutes
+                .is_some_and(|minutes| minutes > MAX_DAILY_LIMIT_MINUTES)
+            {
+                return Err(format!(
+                    "Daily limit of {} must be at most {MAX_DAILY_LIMIT_MINUTES} minutes",
+                    profile.name
+                ));
+            }
+        }
+        if let Some(active) = &self.active_profile {
+            if !ids.contains(active.as_str()) {
+                return Err(format!("Unknown active profile: {active}"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Playtime counted per profile for the current day, stored in `parental_playtime.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PlaytimeLedger {
+    /// Local date (`YYYY-MM-DD`) the counters belong to
+    pub day: String,
+    /// Profile ID → seconds played
+    pub seconds: BTreeMap<String, u64>,
+}
+
+impl PlaytimeLedger {
+    /// Starts a new day's counters when the date changed.
+    pub fn roll_over(&mut self, today: &str) {
+        if self.day != today {
+            self.day = today.to_string();
+            self.seconds.clear();
+        }
+    }
+
+    pub fn add(&mut self, profile_id: &str, seconds: u64) {
+        *self.seconds.entry(profile_id.to_string()).or_default() += seconds;
+    }
+
+    #[must_use]
+    pub fn used(&self, profile_id: &str) -> u64 {
+        self.seconds.get(profile_id).copied().unwrap_or_default()
+    }
+
+    /// Seconds left today for `profile`, `None` when it has no limit.
+    #[must_use]
+    pub fn remaining(&self, profile: &ParentalProfile) -> Option<u64> {
+        profile
+            .daily_limit_minutes
+            .map(|minutes| (u64::from(minutes) * 60).saturating_sub(self.used(&profile.id)))
+    }
+}
+
+/// What the UI needs to decide which screens are locked.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ParentalStatus {
+    pub pin_set: bool,
+    /// Settings need the PIN right now
+    pub settings_locked: bool,
+    /// A parent entered the PIN recently
+    pub unlocked: bool,
+    pub active_profile: Option<String>,
+    pub active_profile_name: Option<String>,
+    pub playtime_used_minutes: u64,
+    /// `None` without a daily limit
+    pub playtime_remaining_minutes: Option<u64>,
+    /// Seconds before another PIN attempt is accepted
+    pub retry_after_secs: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kids() -> ParentalProfile {
+        ParentalProfile {
+            id: "kids".to_string(),
+            name: "Kids".to_string(),
+            max_age_rating: Some(7),
+            hide_unrated: true,
+            blocked_games: BTreeSet::from(["steam_1".to_string()]),
+            daily_limit_minutes: Some(60),
+        }
+    }
+
+    #[test]
+    fn test_profile_applies_only_with_pin() {
+        let mut controls = ParentalControls {
+            game_ratings: BTreeMap::from([("steam_2".to_string(), 3), ("steam_3".to_string(), 18)]),
+            profiles: vec![kids()],
+            active_profile: Some("kids".to_string()),
+            ..ParentalControls::default()
+        };
+        assert!(controls.is_game_allowed("steam_3"));
+
+        controls.pin = Some(PinHash::new("1234", "salt"));
+        assert!(controls.is_game_allowed("steam_2"));
+        assert!(!controls.is_game_allowed("steam_1"));
+        assert!(!controls.is_game_allowed("steam_3"));
+        assert!(!controls.is_game_allowed("unrated"));
+        assert!(controls.validate().is_ok());
+
+        controls.active_profile = Some("teen".to_string());
+        assert!(controls.validate().is_err());
+    }
+
+    #[test]
+    fn test_pin_hash_and_lockout() {
+        let pin = PinHash::new("1234", "salt");
+        assert!(pin.matches("1234"));
+        assert!(!pin.matches("4321"));
+        assert!(validate_pin("12a4").is_err());
+        assert!(validate_pin("123").is_err());
+
+        assert_eq!(lockout_secs(2), 0);
+        assert_eq!(lockout_secs(3), 30);
+        assert_eq!(lockout_secs(4), 60);
+        assert_eq!(lockout_secs(50), MAX_LOCKOUT_SECS);
+    }
+
+    #[test]
+    fn test_ledger_rolls_over_daily() {
+        let mut ledger = PlaytimeLedger::default();
+        ledger.roll_over("2026-01-01");
+        ledger.add("kids", 50 * 60);
+        assert_eq!(ledger.remaining(&kids()), Some(10 * 60));
+
+        ledger.roll_over("2026-01-02");
+        assert_eq!(ledger.remaining(&kids()), Some(60 * 60));
+    }
+}
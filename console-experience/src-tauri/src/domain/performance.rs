@@ -43,8 +43,23 @@ pub struct PerformanceMetrics {
     pub cpu_temp_c: Option<f32>,
     /// GPU power draw in Watts
     pub gpu_power_w: Option<f32>,
+    /// Average current CPU clock in MHz
+    #[serde(default)]
+    pub cpu_clock_mhz: Option<u32>,
+    /// Current GPU graphics clock in MHz (NVML only)
+    #[serde(default)]
+    pub gpu_clock_mhz: Option<u32>,
+    /// Dedicated GPU memory in use in MB (VRAM, or the iGPU's reserved memory)
+    #[serde(default)]
+    pub vram_used_mb: Option<u64>,
+    /// Dedicated GPU memory size in MB
+    #[serde(default)]
+    pub vram_total_mb: Option<u64>,
     /// FPS stats (if monitoring a game)
     pub fps: Option<FPSStats>,
+    /// Share of the focused game's own process (`None` without a tracked PID)
+    #[serde(default)]
+    pub game: Option<ProcessMetrics>,
 }
 
 impl Default for PerformanceMetrics {
@@ -57,11 +72,48 @@ impl Default for PerformanceMetrics {
             gpu_temp_c: None,
             cpu_temp_c: None,
             gpu_power_w: None,
+            cpu_clock_mhz: None,
+            gpu_clock_mhz: None,
+            vram_used_mb: None,
+            vram_total_mb: None,
             fps: None,
+            game: None,
         }
     }
 }
 
+impl PerformanceMetrics {
+    /// The game holds more dedicated GPU memory than the GPU has, so the rest
+    /// spills into shared system memory (typical on iGPUs with a small carve-out).
+    #[must_use]
+    pub fn game_exceeds_vram(&self) -> bool {
+        let game_vram = self.game.as_ref().and_then(|game| game.gpu_dedicated_mb);
+        matches!((game_vram, self.vram_total_mb), (Some(used), Some(total)) if total > 0 && used > total)
+    }
+}
+
+/// Live resource usage of a single process (one game session).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProcessMetrics {
+    /// Process ID the sample belongs to
+    pub pid: u32,
+    /// CPU usage percentage (0-100, normalized across all logical cores)
+    pub cpu_usage: f32,
+    /// Resident memory in MB
+    pub ram_used_mb: u64,
+    /// 3D engine utilization attributed to the process (`None` if unavailable)
+    pub gpu_usage: Option<f32>,
+    /// Private working set in MB (memory not shared with other processes)
+    pub private_working_set_mb: Option<u64>,
+    /// Dedicated GPU memory committed by the process in MB (`None` if unavailable)
+    #[serde(default)]
+    pub gpu_dedicated_mb: Option<u64>,
+    /// Disk reads since the previous sample, in bytes per second
+    pub disk_read_bytes_per_sec: u64,
+    /// Disk writes since the previous sample, in bytes per second
+    pub disk_write_bytes_per_sec: u64,
+}
+
 /// Domain entity representing TDP (Thermal Design Power) configuration.
 /// Pure business logic, no infrastructure dependencies.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -94,6 +146,157 @@ impl TDPConfig {
     }
 }
 
+/// How TDP changes reach the hardware.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TdpBackend {
+    /// libryzenadj.dll through FFI (AMD)
+    RyzenAdjLibrary,
+    /// ryzenadj.exe command line (AMD, library failed to load)
+    RyzenAdjCli,
+    /// Package power limit MSRs through WinRing0 (Intel)
+    IntelMsr,
+}
+
+/// Windows power mode (Settings > System > Power > Power mode).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PowerMode {
+    BestEfficiency,
+    Balanced,
+    BestPerformance,
+}
+
+/// Processor performance boost mode (`PERFBOOSTMODE` power setting).
+///
+/// Disabling boost caps the CPU at base clock: cooler and quieter handhelds
+/// for light games, at the cost of peak performance.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CpuBoostMode {
+    Disabled,
+    Enabled,
+    Aggressive,
+    EfficientEnabled,
+    EfficientAggressive,
+}
+
+impl CpuBoostMode {
+    /// Power setting value index.
+    #[must_use]
+    pub fn index(self) -> u32 {
+        match self {
+            Self::Disabled => 0,
+            Self::Enabled => 1,
+            Self::Aggressive => 2,
+            Self::EfficientEnabled => 3,
+            Self::EfficientAggressive => 4,
+        }
+    }
+
+    /// Mode for a power setting value index (`None` for the "at guaranteed" variants).
+    #[must_use]
+    pub fn from_index(index: u32) -> Option<Self> {
+        match index {
+            0 => Some(Self::Disabled),
+            1 => Some(Self::Enabled),
+            2 => Some(Self::Aggressive),
+            3 => Some(Self::EfficientEnabled),
+            4 => Some(Self::EfficientAggressive),
+            _ => None,
+        }
+    }
+}
+
+/// An installed Windows power plan.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PowerPlan {
+    pub guid: String,
+    pub name: String,
+    pub active: bool,
+}
+
+/// Scheduler priority class for a game process.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessPriority {
+    AboveNormal,
+    High,
+}
+
+/// Logical processors a game process may run on.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CpuAffinity {
+    /// Only the fastest core class (skips E-cores on hybrid CPUs)
+    PerformanceCores,
+    /// One bit per logical processor of group 0 (bit 0 = CPU 0)
+    Mask(u64),
+}
+
+impl CpuAffinity {
+    /// Affinity mask to apply, `None` when nothing would be left to run on
+    /// (or the CPU has a single core class for `PerformanceCores`).
+    #[must_use]
+    pub fn resolve(self, system_mask: u64, performance_mask: Option<u64>) -> Option<u64> {
+        let mask = match self {
+            Self::PerformanceCores => performance_mask?,
+            Self::Mask(mask) => mask,
+        } & system_mask;
+        (mask != 0 && mask != system_mask).then_some(mask)
+    }
+}
+
+/// Mask of the cores with the highest efficiency class, from
+/// `(efficiency class, logical processor mask)` per physical core.
+///
+/// `None` when every core has the same class (not a hybrid CPU).
+#[must_use]
+pub fn performance_core_mask(cores: &[(u8, u64)]) -> Option<u64> {
+    let fastest = cores.iter().map(|(class, _)| *class).max()?;
+    if cores.iter().all(|(class, _)| *class == fastest) {
+        return None;
+    }
+    Some(
+        cores
+            .iter()
+            .filter(|(class, _)| *class == fastest)
+            .fold(0, |mask, (_, cpus)| mask | cpus),
+    )
+}
+
+/// Power and scheduling settings applied while a specific game runs
+/// (`None` = leave as is).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GamePowerProfile {
+    pub power_mode: Option<PowerMode>,
+    pub cpu_boost: Option<CpuBoostMode>,
+    /// Priority class of the game process
+    pub priority: Option<ProcessPriority>,
+    /// Cores the game process runs on
+    pub affinity: Option<CpuAffinity>,
+}
+
+impl GamePowerProfile {
+    /// Whether the game process itself is changed (priority or affinity).
+    #[must_use]
+    pub fn tunes_process(&self) -> bool {
+        self.priority.is_some() || self.affinity.is_some()
+    }
+
+    /// Checks the affinity mask.
+    ///
+    /// # Errors
+    /// Returns error if a custom mask selects no processor.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.affinity == Some(CpuAffinity::Mask(0)) {
+            return Err("CPU affinity mask selects no processor".to_string());
+        }
+        Ok(())
+    }
+}
+
 /// Predefined performance profiles for common scenarios.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 pub enum PerformanceProfile {
@@ -141,6 +344,24 @@ mod tests {
         assert_eq!(config.clamp(35), 30);
     }
 
+    #[test]
+    fn test_game_exceeds_vram() {
+        let metrics = |game_vram: Option<u64>, total: Option<u64>| PerformanceMetrics {
+            vram_total_mb: total,
+            game: Some(ProcessMetrics {
+                gpu_dedicated_mb: game_vram,
+                ..ProcessMetrics::default()
+            }),
+            ..PerformanceMetrics::default()
+        };
+        // 512 MB UMA carve-out
+        assert!(metrics(Some(1500), Some(512)).game_exceeds_vram());
+        assert!(!metrics(Some(400), Some(512)).game_exceeds_vram());
+        assert!(!metrics(None, Some(512)).game_exceeds_vram());
+        assert!(!metrics(Some(1500), None).game_exceeds_vram());
+        assert!(!PerformanceMetrics::default().game_exceeds_vram());
+    }
+
     #[test]
     fn test_performance_profiles() {
         let config = TDPConfig::new(15, 5, 30).unwrap();
@@ -151,4 +372,25 @@ mod tests {
         assert_eq!(PerformanceProfile::Custom(20).to_watts(&config), 20);
         assert_eq!(PerformanceProfile::Custom(40).to_watts(&config), 30);
     }
+
+    #[test]
+    fn test_performance_cores_skip_e_cores() {
+        // 2 P-cores with SMT (CPUs 0-3), 4 E-cores (CPUs 4-7)
+        let hybrid = [
+            (1, 0b11),
+            (1, 0b1100),
+            (0, 1 << 4),
+            (0, 1 << 5),
+            (0, 1 << 6),
+            (0, 1 << 7),
+        ];
+        let p_cores = performance_core_mask(&hybrid);
+        assert_eq!(p_cores, Some(0b1111));
+        assert_eq!(performance_core_mask(&[(0, 0b11), (0, 0b1100)]), None);
+
+        assert_eq!(CpuAffinity::PerformanceCores.resolve(0xFF, p_cores), Some(0b1111));
+        assert_eq!(CpuAffinity::PerformanceCores.resolve(0xFF, None), None);
+        assert_eq!(CpuAffinity::Mask(0x1_00).resolve(0xFF, p_cores), None);
+        assert_eq!(CpuAffinity::Mask(0xFF).resolve(0xFF, p_cores), None);
+    }
 }
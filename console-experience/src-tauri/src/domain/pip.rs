@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::overlay_layout::OverlayCorner;
+
+/// Narrowest companion window, as a share of the monitor width.
+pub const MIN_PIP_WIDTH_PERCENT: u8 = 20;
+/// Widest companion window, as a share of the monitor width.
+pub const MAX_PIP_WIDTH_PERCENT: u8 = 60;
+/// Width change per controller resize step.
+pub const PIP_RESIZE_STEP: u8 = 5;
+/// Gap between the companion window and the screen edge, in pixels.
+const PIP_MARGIN: i32 = 16;
+
+/// Companion picture-in-picture window (walkthrough videos, wikis).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipSettings {
+    pub corner: OverlayCorner,
+    /// Window opacity (0.2 - 1.0)
+    pub opacity: f64,
+    /// Window width as a share of the monitor width; the height follows 16:9
+    pub width_percent: u8,
+    /// Hide while the in-game overlay is open, restore when it closes
+    pub auto_hide_with_overlay: bool,
+    /// Last page opened, reopened by `open_companion_pip` without a URL
+    pub last_url: String,
+}
+
+impl Default for PipSettings {
+    fn default() -> Self {
+        Self {
+            corner: OverlayCorner::TopRight,
+            opacity: 0.9,
+            width_percent: 30,
+            auto_hide_with_overlay: true,
+            last_url: String::new(),
+        }
+    }
+}
+
+impl PipSettings {
+    /// # Errors
+    /// Returns error for an opacity or width out of range.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.2..=1.0).contains(&self.opacity) {
+            return Err(format!("PiP opacity {} out of range (0.2 - 1.0)", self.opacity));
+        }
+        if !(MIN_PIP_WIDTH_PERCENT..=MAX_PIP_WIDTH_PERCENT).contains(&self.width_percent) {
+            return Err(format!(
+                "PiP width {}% out of range ({MIN_PIP_WIDTH_PERCENT} - {MAX_PIP_WIDTH_PERCENT}%)",
+                self.width_percent
+            ));
+        }
+        Ok(())
+    }
+
+    /// Width after `steps` controller resize steps (negative shrinks), clamped.
+    #[must_use]
+    pub fn resized(&self, steps: i32) -> u8 {
+        let width = i32::from(self.width_percent) + steps * i32::from(PIP_RESIZE_STEP);
+        width.clamp(i32::from(MIN_PIP_WIDTH_PERCENT), i32::from(MAX_PIP_WIDTH_PERCENT)) as u8
+    }
+}
+
+/// Screen rectangle in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PipRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PipRect {
+    /// A 16:9 window `width_percent` of `monitor` wide, in `corner`.
+    #[must_use]
+    pub fn in_corner(monitor: Self, corner: OverlayCorner, width_percent: u8) -> Self {
+        let width = monitor.width * u32::from(width_percent) / 100;
+        let height = (width * 9 / 16).min(monitor.height);
+        let left = monitor.x + PIP_MARGIN;
+        let top = monitor.y + PIP_MARGIN;
+        let right = monitor.x + monitor.width as i32 - width as i32 - PIP_MARGIN;
+        let bottom = monitor.y + monitor.height as i32 - height as i32 - PIP_MARGIN;
+        let (x, y) = match corner {
+            OverlayCorner::TopLeft => (left, top),
+            OverlayCorner::TopRight => (right, top),
+            OverlayCorner::BottomLeft => (left, bottom),
+            OverlayCorner::BottomRight => (right, bottom),
+        };
+        Self { x, y, width, height }
+    }
+}
+
+/// Checks a page for the companion window: only `http(s)` URLs load.
+///
+/// # Errors
+/// Returns error for other schemes or a URL without a host.
+pub fn validate_pip_url(url: &str) -> Result<String, String> {
+    let url = url.trim();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .ok_or_else(|| format!("Only http(s) pages open in the PiP window: {url}"))?;
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || host.contains(char::is_whitespace) {
+        return Err(format!("Invalid PiP URL: {url}"));
+    }
+    Ok(url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pip_geometry_and_resize() {
+        let monitor = PipRect {
+            x: 0,
+            y: 0,
+            width: 1920,
+            height: 1080,
+        };
+        let rect = PipRect::in_corner(monitor, OverlayCorner::BottomRight, 25);
+        assert_eq!(
+            rect,
+            PipRect {
+                x: 1424,
+                y: 794,
+                width: 480,
+                height: 270,
+            }
+        );
+
+        let settings = PipSettings::default();
+        assert_eq!(settings.resized(1), 35);
+        assert_eq!(settings.resized(-10), MIN_PIP_WIDTH_PERCENT);
+        assert_eq!(settings.resized(10), MAX_PIP_WIDTH_PERCENT);
+    }
+
+    #[test]
+    fn test_validate_pip_url() {
+        assert_eq!(
+            validate_pip_url(" https://www.youtube.com/watch?v=abc ").unwrap(),
+            "https://www.youtube.com/watch?v=abc"
+        );
+        assert!(validate_pip_url("http://wiki.example.org").is_ok());
+        assert!(validate_pip_url("file:///C:/Windows/win.ini").is_err());
+        assert!(validate_pip_url("javascript:alert(1)").is_err());
+        assert!(validate_pip_url("https:///path").is_err());
+    }
+}
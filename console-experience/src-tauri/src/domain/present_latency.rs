@@ -0,0 +1,169 @@
+use crate::adapters::overlay::PresentMode;
+use serde::Serialize;
+
+/// FPS counts as held back by VSync from this share of the refresh rate up.
+const VSYNC_BOUND_RATIO: f64 = 0.95;
+
+/// How the running game's frames reach the display, for latency tuning.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct PresentReport {
+    pub pid: u32,
+    pub game: String,
+    pub present_mode: PresentMode,
+    /// Sync interval passed to Present() (0 = no VSync); `None` until the game presents
+    pub sync_interval: Option<u32>,
+    /// The game waits for VBlank (`None` if unknown)
+    pub vsync: Option<bool>,
+    /// Frames can tear (no VSync on a path that scans out directly)
+    pub tearing: bool,
+    /// Flip presentation model (`None` when composed: flip or blit look the same)
+    pub flip_model: Option<bool>,
+    pub fps: f32,
+    pub refresh_hz: u32,
+    /// Estimated time from Present() to the frame starting to scan out
+    pub estimated_latency_ms: Option<f64>,
+}
+
+impl PresentReport {
+    /// Builds the report from what the FPS service saw.
+    #[must_use]
+    pub fn new(
+        pid: u32,
+        game: String,
+        present_mode: PresentMode,
+        sync_interval: Option<u32>,
+        allow_tearing: bool,
+        fps: f32,
+        refresh_hz: u32,
+    ) -> Self {
+        let tearing = is_tearing(present_mode, sync_interval, allow_tearing);
+        Self {
+            pid,
+            game,
+            present_mode,
+            sync_interval,
+            vsync: sync_interval.map(|interval| interval > 0),
+            tearing,
+            flip_model: match present_mode {
+                PresentMode::HardwareFlip => Some(true),
+                PresentMode::FullscreenExclusive => Some(false),
+                PresentMode::Composed | PresentMode::Unknown => None,
+            },
+            fps,
+            refresh_hz,
+            estimated_latency_ms: estimate_present_latency_ms(
+                present_mode,
+                sync_interval,
+                tearing,
+                f64::from(fps),
+                refresh_hz,
+            ),
+        }
+    }
+}
+
+/// Frames tear when presented without VSync straight to scanout: legacy
+/// exclusive fullscreen always can, flip model only with ALLOW_TEARING.
+/// DWM composition never tears.
+#[must_use]
+pub fn is_tearing(present_mode: PresentMode, sync_interval: Option<u32>, allow_tearing: bool) -> bool {
+    sync_interval == Some(0)
+        && match present_mode {
+            PresentMode::FullscreenExclusive => true,
+            PresentMode::HardwareFlip => allow_tearing,
+            PresentMode::Composed | PresentMode::Unknown => false,
+        }
+}
+
+/// Estimated Present()-to-scanout latency in milliseconds.
+///
+/// PresentMon-style model, not a measurement:
+/// - waiting for the next VBlank costs half a refresh on average (nothing when tearing)
+/// - DWM composition adds a refresh (the frame is composed, then scanned out)
+/// - a game held at the VSync rate has a frame queued ahead, one more interval
+///
+/// `None` without a present mode, refresh rate or, outside composition, sync interval.
+#[must_use]
+pub fn estimate_present_latency_ms(
+    present_mode: PresentMode,
+    sync_interval: Option<u32>,
+    tearing: bool,
+    fps: f64,
+    refresh_hz: u32,
+) -> Option<f64> {
+    if refresh_hz == 0 || present_mode == PresentMode::Unknown {
+        return None;
+    }
+    let refresh_ms = 1000.0 / f64::from(refresh_hz);
+    let interval = match (present_mode, sync_interval) {
+        (PresentMode::Composed, interval) => interval.unwrap_or(0),
+        (_, Some(interval)) => interval,
+        (_, None) => return None,
+    };
+
+    let vblank_wait = if tearing { 0.0 } else { refresh_ms / 2.0 };
+    let composition = if present_mode == PresentMode::Composed {
+        refresh_ms
+    } else {
+        0.0
+    };
+    let vsync_bound = interval > 0 && fps * f64::from(interval) >= f64::from(refresh_hz) * VSYNC_BOUND_RATIO;
+    let queued = if vsync_bound {
+        refresh_ms * f64::from(interval)
+    } else {
+        0.0
+    };
+    Some(vblank_wait + composition + queued)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Option<f64>, b: f64) -> bool {
+        a.is_some_and(|a| (a - b).abs() < 0.01)
+    }
+
+    #[test]
+    fn test_latency_estimate_per_path() {
+        // 60 Hz: 16.67 ms per refresh
+        let fse_tearing = estimate_present_latency_ms(PresentMode::FullscreenExclusive, Some(0), true, 140.0, 60);
+        assert!(close(fse_tearing, 0.0));
+        let flip_vsync_bound = estimate_present_latency_ms(PresentMode::HardwareFlip, Some(1), false, 60.0, 60);
+        assert!(close(flip_vsync_bound, 25.0));
+        let flip_vsync_free = estimate_present_latency_ms(PresentMode::HardwareFlip, Some(1), false, 45.0, 60);
+        assert!(close(flip_vsync_free, 8.33));
+        let composed = estimate_present_latency_ms(PresentMode::Composed, None, false, 45.0, 60);
+        assert!(close(composed, 25.0));
+
+        assert_eq!(
+            estimate_present_latency_ms(PresentMode::HardwareFlip, None, false, 60.0, 60),
+            None
+        );
+        assert_eq!(
+            estimate_present_latency_ms(PresentMode::Unknown, Some(1), false, 60.0, 60),
+            None
+        );
+    }
+
+    #[test]
+    fn test_report_flags() {
+        let report = PresentReport::new(
+            1,
+            "game.exe".to_string(),
+            PresentMode::HardwareFlip,
+            Some(0),
+            true,
+            90.0,
+            120,
+        );
+        assert!(report.tearing);
+        assert_eq!(report.vsync, Some(false));
+        assert_eq!(report.flip_model, Some(true));
+
+        // Composition never tears, whatever the game asks for
+        assert!(!is_tearing(PresentMode::Composed, Some(0), true));
+        assert!(!is_tearing(PresentMode::HardwareFlip, Some(0), false));
+        assert!(is_tearing(PresentMode::FullscreenExclusive, Some(0), false));
+    }
+}
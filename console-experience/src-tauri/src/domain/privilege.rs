@@ -0,0 +1,111 @@
+use crate::domain::autostart::AutostartOptions;
+use serde::{Deserialize, Serialize};
+
+/// Named pipe served by the elevated broker (one JSON request line per connection).
+pub const BROKER_PIPE_NAME: &str = r"\\.\pipe\balam_broker";
+/// Secret shared with the broker on its (admin-only readable) command line.
+pub const BROKER_TOKEN_FLAG: &str = "--token";
+/// Balam process that launched the broker; the broker exits with it.
+pub const BROKER_PARENT_FLAG: &str = "--parent";
+/// Broker executable, shipped next to `console-experience.exe`.
+pub const BROKER_EXECUTABLE: &str = "balam-broker.exe";
+
+/// TDP range the broker accepts, whatever the device reports.
+pub const MIN_BROKER_TDP_WATTS: u32 = 3;
+pub const MAX_BROKER_TDP_WATTS: u32 = 65;
+
+/// The fixed allow-list of operations the broker runs as administrator.
+///
+/// Nothing here takes a path or a command line: the broker resolves every
+/// binary from its own install directory, so a compromised client can at
+/// most ask for one of these operations.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PrivilegedOperation {
+    SetTdp { watts: u32 },
+    InstallFpsService,
+    UninstallFpsService,
+    StartFpsService,
+    StopFpsService,
+    RestartFpsServiceEtw,
+    SetFpsServiceBootStart { enabled: bool },
+    RegisterAutostart { options: AutostartOptions },
+    UnregisterAutostart,
+    StartSunshine,
+    StopSunshine,
+}
+
+impl PrivilegedOperation {
+    /// Rejects arguments outside what the app itself would ever send.
+    ///
+    /// # Errors
+    /// Returns error if the TDP is out of range.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::SetTdp { watts } if !(MIN_BROKER_TDP_WATTS..=MAX_BROKER_TDP_WATTS).contains(watts) => Err(format!(
+                "TDP must be {MIN_BROKER_TDP_WATTS}-{MAX_BROKER_TDP_WATTS}W, got {watts}W"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// One request on the broker pipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrokerRequest {
+    pub token: String,
+    pub operation: PrivilegedOperation,
+}
+
+/// Broker answer to a [`BrokerRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BrokerResponse {
+    Done,
+    Failed {
+        message: String,
+    },
+    /// Wrong token or the client is not Balam from the broker's install directory
+    Unauthorized,
+}
+
+/// Compares tokens in constant time (no early exit on the first mismatch).
+#[must_use]
+pub fn tokens_match(expected: &str, received: &str) -> bool {
+    expected.len() == received.len()
+        && expected
+            .bytes()
+            .zip(received.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_wire_format() {
+        let request = BrokerRequest {
+            token: "secret".to_string(),
+            operation: PrivilegedOperation::SetTdp { watts: 15 },
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["operation"]["op"], "set_tdp");
+        assert_eq!(json["operation"]["watts"], 15);
+        assert_eq!(serde_json::from_value::<BrokerRequest>(json).unwrap(), request);
+
+        // Anything outside the allow-list does not even parse
+        let unknown = serde_json::json!({ "token": "secret", "operation": { "op": "run_command" } });
+        assert!(serde_json::from_value::<BrokerRequest>(unknown).is_err());
+    }
+
+    #[test]
+    fn test_validation_and_token_check() {
+        assert!(PrivilegedOperation::SetTdp { watts: 15 }.validate().is_ok());
+        assert!(PrivilegedOperation::SetTdp { watts: 200 }.validate().is_err());
+        assert!(tokens_match("abc123", "abc123"));
+        assert!(!tokens_match("abc123", "abc124"));
+        assert!(!tokens_match("abc123", "abc12"));
+    }
+}
@@ -0,0 +1,147 @@
+use crate::domain::display::DisplayMode;
+use serde::Serialize;
+
+/// Seconds the player has to confirm a display change before it rolls back.
+pub const DISPLAY_CONFIRM_SECONDS: u64 = 10;
+/// Lowest resolution offered, in percent of the native height.
+const MIN_RESOLUTION_SCALE_PERCENT: u32 = 50;
+/// Rates this close count as the same (59.94 Hz modes report 59).
+const REFRESH_RATE_TOLERANCE_HZ: u32 = 1;
+
+/// Resolution offered by the quick settings, with the native aspect ratio.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct QuickResolution {
+    pub width: u32,
+    pub height: u32,
+    /// Height relative to the native resolution
+    pub scale_percent: u32,
+}
+
+/// Refresh rates the display supports at `mode`'s resolution, ascending.
+#[must_use]
+pub fn refresh_rates_at(mode: DisplayMode, modes: &[DisplayMode]) -> Vec<u32> {
+    let mut rates: Vec<u32> = modes
+        .iter()
+        .filter(|m| m.width == mode.width && m.height == mode.height)
+        .map(|m| m.refresh_hz)
+        .collect();
+    rates.sort_unstable();
+    rates.dedup();
+    rates
+}
+
+/// Next supported refresh rate above the current one, wrapping to the lowest.
+#[must_use]
+pub fn next_refresh_rate(mode: DisplayMode, modes: &[DisplayMode]) -> Option<u32> {
+    let rates = refresh_rates_at(mode, modes);
+    rates
+        .iter()
+        .copied()
+        .find(|&hz| hz > mode.refresh_hz)
+        .or_else(|| rates.first().copied())
+        .filter(|&hz| hz != mode.refresh_hz)
+}
+
+/// Half-rate toggle: back up to double the current rate if supported
+/// (60 → 120), otherwise down to half of it (120 → 60).
+#[must_use]
+pub fn half_rate_toggle(mode: DisplayMode, modes: &[DisplayMode]) -> Option<u32> {
+    let rates = refresh_rates_at(mode, modes);
+    let near = |target: u32| {
+        rates
+            .iter()
+            .copied()
+            .find(|hz| hz.abs_diff(target) <= REFRESH_RATE_TOLERANCE_HZ)
+    };
+    near(mode.refresh_hz * 2).or_else(|| near(mode.refresh_hz / 2))
+}
+
+/// Resolutions with the native aspect ratio down to half the native
+/// height, largest first. Native is the largest mode the display has.
+#[must_use]
+pub fn quick_resolutions(modes: &[DisplayMode]) -> Vec<QuickResolution> {
+    let Some(native) = modes.iter().max_by_key(|m| u64::from(m.width) * u64::from(m.height)) else {
+        return Vec::new();
+    };
+    let mut resolutions: Vec<QuickResolution> = modes
+        .iter()
+        .filter(|m| u64::from(m.width) * u64::from(native.height) == u64::from(m.height) * u64::from(native.width))
+        .filter(|m| m.height * 100 >= native.height * MIN_RESOLUTION_SCALE_PERCENT)
+        .map(|m| QuickResolution {
+            width: m.width,
+            height: m.height,
+            scale_percent: m.height * 100 / native.height,
+        })
+        .collect();
+    resolutions.sort_by(|a, b| b.height.cmp(&a.height));
+    resolutions.dedup();
+    resolutions
+}
+
+/// Mode at `width`x`height` keeping the current refresh rate if the display
+/// has it there, else the highest rate available at that resolution.
+#[must_use]
+pub fn mode_at_resolution(mode: DisplayMode, width: u32, height: u32, modes: &[DisplayMode]) -> Option<DisplayMode> {
+    let target = mode.with_resolution(width, height);
+    let rates = refresh_rates_at(target, modes);
+    let refresh_hz = if rates.contains(&mode.refresh_hz) {
+        mode.refresh_hz
+    } else {
+        *rates.last()?
+    };
+    Some(target.with_refresh_rate(refresh_hz))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mode(width: u32, height: u32, refresh_hz: u32) -> DisplayMode {
+        DisplayMode {
+            width,
+            height,
+            refresh_hz,
+        }
+    }
+
+    fn modes() -> Vec<DisplayMode> {
+        vec![
+            mode(1920, 1200, 60),
+            mode(1920, 1200, 120),
+            mode(1920, 1200, 144),
+            mode(1280, 800, 60),
+            mode(1280, 800, 120),
+            mode(1280, 720, 60),
+            mode(800, 500, 60),
+        ]
+    }
+
+    #[test]
+    fn test_refresh_rate_cycle_and_half_rate() {
+        let modes = modes();
+        assert_eq!(next_refresh_rate(mode(1920, 1200, 60), &modes), Some(120));
+        assert_eq!(next_refresh_rate(mode(1920, 1200, 144), &modes), Some(60));
+        assert_eq!(next_refresh_rate(mode(800, 500, 60), &modes), None);
+
+        assert_eq!(half_rate_toggle(mode(1920, 1200, 120), &modes), Some(60));
+        assert_eq!(half_rate_toggle(mode(1920, 1200, 60), &modes), Some(120));
+        assert_eq!(half_rate_toggle(mode(1920, 1200, 144), &modes), None);
+    }
+
+    #[test]
+    fn test_quick_resolutions_keep_aspect_ratio() {
+        let modes = modes();
+        let resolutions = quick_resolutions(&modes);
+        let sizes: Vec<(u32, u32, u32)> = resolutions
+            .iter()
+            .map(|r| (r.width, r.height, r.scale_percent))
+            .collect();
+        assert_eq!(sizes, vec![(1920, 1200, 100), (1280, 800, 66)]);
+
+        assert_eq!(
+            mode_at_resolution(mode(1920, 1200, 144), 1280, 800, &modes),
+            Some(mode(1280, 800, 120))
+        );
+        assert_eq!(mode_at_resolution(mode(1920, 1200, 60), 1024, 768, &modes), None);
+    }
+}
@@ -0,0 +1,194 @@
+/// Whisper-quiet mode
+///
+/// A preset is a set of changes applied as one transaction: every step
+/// remembers what it replaced, a failing step rolls back the ones before it,
+/// and turning the preset off restores all of them together. Quiet mode is
+/// the preset for silent play: TDP floor, capped fan curve, dimmer screen and
+/// a 30 FPS cap.
+use serde::Serialize;
+
+use crate::domain::thermal::{FanCurve, HOT_TEMP_C, MIN_HOT_FAN_PERCENT};
+use crate::domain::BalamError;
+
+/// FPS cap while quiet.
+pub const QUIET_FPS_CAP: u32 = 30;
+/// Highest fan duty below [`HOT_TEMP_C`] (the safety minimum still applies above).
+pub const QUIET_FAN_CAP_PERCENT: u8 = 40;
+/// Brightness is lowered to this share of the current level...
+const QUIET_BRIGHTNESS_RATIO: f32 = 0.7;
+/// ...but not below this level.
+const MIN_QUIET_BRIGHTNESS: u32 = 20;
+
+/// One reversible change of a preset.
+pub trait PresetStep: Send {
+    /// Name shown in the status, e.g. `tdp`.
+    fn name(&self) -> &'static str;
+
+    /// Applies the change, remembering what it replaces.
+    ///
+    /// # Errors
+    /// Returns error if the change could not be made (nothing was changed).
+    fn apply(&mut self) -> Result<(), BalamError>;
+
+    /// Puts back what `apply` replaced.
+    ///
+    /// # Errors
+    /// Returns error if the previous state could not be restored.
+    fn restore(&mut self) -> Result<(), BalamError>;
+}
+
+/// Steps of a preset that are in effect.
+pub struct AppliedPreset {
+    steps: Vec<Box<dyn PresetStep>>,
+}
+
+impl AppliedPreset {
+    /// Applies `steps` in order, all or nothing.
+    ///
+    /// # Errors
+    /// Returns the failing step's error after restoring the steps applied
+    /// before it (newest first).
+    pub fn apply(steps: Vec<Box<dyn PresetStep>>) -> Result<Self, BalamError> {
+        let mut applied: Vec<Box<dyn PresetStep>> = Vec::with_capacity(steps.len());
+        for mut step in steps {
+            if let Err(e) = step.apply() {
+                let failed = step.name();
+                let _ = Self { steps: applied }.restore();
+                return Err(BalamError::Platform(format!("Preset step '{failed}' failed: {e}")));
+            }
+            applied.push(step);
+        }
+        Ok(Self { steps: applied })
+    }
+
+    /// Names of the steps in effect, in the order they were applied.
+    #[must_use]
+    pub fn step_names(&self) -> Vec<&'static str> {
+        self.steps.iter().map(|step| step.name()).collect()
+    }
+
+    /// Restores every step, newest first, carrying on past failures.
+    ///
+    /// # Errors
+    /// Returns the first failure (the other steps are restored anyway).
+    pub fn restore(mut self) -> Result<(), BalamError> {
+        let mut first_error = None;
+        while let Some(mut step) = self.steps.pop() {
+            if let Err(e) = step.restore() {
+                first_error.get_or_insert(BalamError::Platform(format!(
+                    "Failed to restore '{}': {e}",
+                    step.name()
+                )));
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+/// Quiet mode state for the UI.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct QuietModeStatus {
+    pub active: bool,
+    /// Changes in effect (`tdp`, `fan_curve`, `brightness`, `fps_cap`);
+    /// the ones the device doesn't support are left out
+    pub applied: Vec<String>,
+}
+
+/// `curve` with its duty capped at [`QUIET_FAN_CAP_PERCENT`], still running
+/// at least [`MIN_HOT_FAN_PERCENT`] from [`HOT_TEMP_C`].
+#[must_use]
+pub fn quiet_fan_curve(curve: &FanCurve) -> FanCurve {
+    let mut quiet = curve.clone();
+    for point in &mut quiet.points {
+        point.fan_percent = point.fan_percent.min(QUIET_FAN_CAP_PERCENT);
+        if point.temp_c >= HOT_TEMP_C {
+            point.fan_percent = point.fan_percent.max(MIN_HOT_FAN_PERCENT);
+        }
+    }
+    quiet
+}
+
+/// Dimmer brightness for quiet mode (never brighter than `current`).
+#[must_use]
+pub fn quiet_brightness(current: u32) -> u32 {
+    ((current as f32 * QUIET_BRIGHTNESS_RATIO).round() as u32)
+        .max(MIN_QUIET_BRIGHTNESS)
+        .min(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct FakeStep {
+        name: &'static str,
+        fails: bool,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl PresetStep for FakeStep {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn apply(&mut self) -> Result<(), BalamError> {
+            if self.fails {
+                return Err(BalamError::Platform("unsupported".to_string()));
+            }
+            self.log.lock().unwrap().push(format!("apply {}", self.name));
+            Ok(())
+        }
+
+        fn restore(&mut self) -> Result<(), BalamError> {
+            self.log.lock().unwrap().push(format!("restore {}", self.name));
+            Ok(())
+        }
+    }
+
+    fn steps(log: &Arc<Mutex<Vec<String>>>, failing: Option<&'static str>) -> Vec<Box<dyn PresetStep>> {
+        ["tdp", "fan_curve", "brightness"]
+            .into_iter()
+            .map(|name| {
+                Box::new(FakeStep {
+                    name,
+                    fails: failing == Some(name),
+                    log: Arc::clone(log),
+                }) as Box<dyn PresetStep>
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_preset_is_all_or_nothing() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        assert!(AppliedPreset::apply(steps(&log, Some("brightness"))).is_err());
+        assert_eq!(
+            *log.lock().unwrap(),
+            ["apply tdp", "apply fan_curve", "restore fan_curve", "restore tdp"]
+        );
+
+        log.lock().unwrap().clear();
+        let preset = AppliedPreset::apply(steps(&log, None)).unwrap();
+        assert_eq!(preset.step_names(), ["tdp", "fan_curve", "brightness"]);
+        preset.restore().unwrap();
+        assert_eq!(
+            log.lock().unwrap()[3..],
+            ["restore brightness", "restore fan_curve", "restore tdp"]
+        );
+    }
+
+    #[test]
+    fn test_quiet_values_stay_safe() {
+        let curve = quiet_fan_curve(&FanCurve::default());
+        assert!(curve.validate().is_ok());
+        assert!(curve
+            .points
+            .iter()
+            .all(|p| p.fan_percent <= QUIET_FAN_CAP_PERCENT || p.temp_c >= HOT_TEMP_C));
+
+        assert_eq!(quiet_brightness(100), 70);
+        assert_eq!(quiet_brightness(25), 20);
+        assert_eq!(quiet_brightness(10), 10);
+    }
+}
@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+/// Title fragments of software that is never a game (redistributables,
+/// runtimes, drivers, anti-cheat services), matched case-insensitively.
+pub const REGISTRY_BLACKLIST: &[&str] = &[
+    "redistributable",
+    "vcredist",
+    "visual c++",
+    "directx",
+    ".net framework",
+    "runtime",
+    "physx",
+    "vulkan",
+    "openal",
+    "xna framework",
+    "driver",
+    "chipset",
+    "easy anti-cheat",
+    "easyanticheat",
+    "battleye",
+    "update",
+    "sdk",
+];
+
+/// Install path fragments that suggest a game.
+const GAME_PATH_HINTS: &[&str] = &["game", "gog", "riot games", "ubisoft"];
+
+/// One entry of the Windows uninstall registry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegistryEntry {
+    /// Uninstall subkey name (the game's `raw_id`)
+    pub key_name: String,
+    pub title: String,
+    pub publisher: String,
+    pub install_location: String,
+    /// `EstimatedSize` value, in KiB
+    pub estimated_size_kb: Option<u64>,
+    /// `SystemComponent` flag: hidden from "Apps & features"
+    pub system_component: bool,
+}
+
+/// Rules deciding which uninstall entries the registry scanner lists as games.
+///
+/// Checked in order: entries reclassified as not a game, exclude patterns,
+/// [`REGISTRY_BLACKLIST`], include patterns (which skip the remaining
+/// heuristics), publisher filter, minimum size, then the install path hints.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct RegistryScanRules {
+    /// Title patterns always listed (e.g. a game installed outside a `Games` folder)
+    pub include_patterns: Vec<String>,
+    /// Title patterns never listed
+    pub exclude_patterns: Vec<String>,
+    /// Publishers whose entries are software, not games
+    pub excluded_publishers: Vec<String>,
+    /// Entries smaller than this are skipped (0 = no minimum)
+    pub min_size_mb: u64,
+    /// Uninstall keys the user reclassified as "not a game"
+    pub not_games: Vec<String>,
+}
+
+impl Default for RegistryScanRules {
+    fn default() -> Self {
+        Self {
+            include_patterns: Vec::new(),
+            exclude_patterns: Vec::new(),
+            excluded_publishers: [
+                "microsoft",
+                "google",
+                "adobe",
+                "nvidia",
+                "intel",
+                "advanced micro devices",
+                "realtek",
+            ]
+            .map(String::from)
+            .to_vec(),
+            min_size_mb: 50,
+            not_games: Vec::new(),
+        }
+    }
+}
+
+impl RegistryScanRules {
+    /// Whether an uninstall entry should be listed as a game.
+    #[must_use]
+    pub fn is_game(&self, entry: &RegistryEntry) -> bool {
+        let title = entry.title.to_lowercase();
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| !pattern.is_empty() && title.contains(&pattern.to_lowercase()))
+        };
+
+        if entry.system_component
+            || self.not_games.contains(&entry.key_name)
+            || matches(&self.exclude_patterns)
+            || REGISTRY_BLACKLIST.iter().any(|pattern| title.contains(pattern))
+        {
+            return false;
+        }
+        if matches(&self.include_patterns) {
+            return true;
+        }
+
+        let publisher = entry.publisher.to_lowercase();
+        if self
+            .excluded_publishers
+            .iter()
+            .any(|excluded| !excluded.is_empty() && publisher.contains(&excluded.to_lowercase()))
+        {
+            return false;
+        }
+        if entry
+            .estimated_size_kb
+            .is_some_and(|size| size < self.min_size_mb * 1024)
+        {
+            return false;
+        }
+
+        let path = entry.install_location.to_lowercase();
+        GAME_PATH_HINTS.iter().any(|hint| path.contains(hint))
+    }
+
+    /// Marks an uninstall key as "not a game" (no-op if already marked).
+    pub fn mark_not_game(&mut self, key_name: &str) {
+        if !self.not_games.iter().any(|key| key == key_name) {
+            self.not_games.push(key_name.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, publisher: &str, size_mb: u64) -> RegistryEntry {
+        RegistryEntry {
+            key_name: format!("{{{title}}}"),
+            title: title.to_string(),
+            publisher: publisher.to_string(),
+            install_location: "C:\\GOG Games\\Game".to_string(),
+            estimated_size_kb: Some(size_mb * 1024),
+            system_component: false,
+        }
+    }
+
+    #[test]
+    fn test_heuristics_filter_software() {
+        let rules = RegistryScanRules::default();
+        assert!(rules.is_game(&entry("Disco Elysium", "ZA/UM", 20_000)));
+        assert!(!rules.is_game(&entry(
+            "Microsoft Visual C++ 2015-2022 Redistributable",
+            "Microsoft",
+            25
+        )));
+        assert!(!rules.is_game(&entry("NVIDIA Graphics Driver", "NVIDIA Corporation", 900)));
+        assert!(!rules.is_game(&entry("Tiny Tool", "Someone", 2)));
+        assert!(!rules.is_game(&RegistryEntry {
+            install_location: "C:\\Program Files\\Editor".to_string(),
+            ..entry("Text Editor", "Someone", 200)
+        }));
+    }
+
+    #[test]
+    fn test_user_rules_override_heuristics() {
+        let mut rules = RegistryScanRules {
+            include_patterns: vec!["age of empires".to_string()],
+            ..RegistryScanRules::default()
+        };
+        let aoe = RegistryEntry {
+            install_location: "D:\\AoE2".to_string(),
+            ..entry("Age of Empires II", "Microsoft", 30_000)
+        };
+        assert!(rules.is_game(&aoe));
+
+        let game = entry("Disco Elysium", "ZA/UM", 20_000);
+        rules.mark_not_game(&game.key_name);
+        rules.mark_not_game(&game.key_name);
+        assert_eq!(rules.not_games.len(), 1);
+        assert!(!rules.is_game(&game));
+    }
+}
@@ -0,0 +1,322 @@
+use crate::domain::privilege::tokens_match;
+use crate::domain::text_input::VirtualKey;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::net::IpAddr;
+
+/// Port of the companion WebSocket server unless the settings override it.
+pub const DEFAULT_REMOTE_PORT: u16 = 47810;
+/// Ports below this need administrator rights to bind.
+pub const MIN_REMOTE_PORT: u16 = 1024;
+/// File in the app data folder holding the pairing key.
+pub const REMOTE_KEY_FILE: &str = "remote_pairing_key";
+/// File in the app data folder listing the phones approved on the PC.
+pub const REMOTE_DEVICES_FILE: &str = "remote_devices.json";
+/// Longest device id and name a phone may send.
+pub const MAX_DEVICE_FIELD_LEN: usize = 64;
+
+/// Mouse button pressed from the phone touchpad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PointerButton {
+    Left,
+    Right,
+    Middle,
+}
+
+/// What a paired phone can do.
+///
+/// Library, session, volume and TDP commands run the same code as the
+/// frontend commands of the same name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum RemoteCommand {
+    ListGames,
+    Launch {
+        game_id: String,
+    },
+    GetActiveGame,
+    /// Asks the focused game to close (saves where the game supports it)
+    CloseGame,
+    /// Terminates the focused game
+    KillGame,
+    GetSystemStatus,
+    SetVolume {
+        level: u32,
+    },
+    SetTdp {
+        watts: u32,
+    },
+    /// Relative cursor motion in pixels (touchpad drag)
+    PointerMove {
+        dx: f32,
+        dy: f32,
+    },
+    PointerClick {
+        button: PointerButton,
+    },
+    /// Wheel notches, positive scrolls up
+    PointerScroll {
+        delta: f32,
+    },
+    TypeText {
+        text: String,
+    },
+    PressKey {
+        key: VirtualKey,
+    },
+    /// Brings the Balam window to the front
+    Show,
+}
+
+/// One WebSocket text message from the phone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteRequest {
+    /// Echoed in the response so the phone can match answers
+    #[serde(default)]
+    pub id: Option<u64>,
+    #[serde(flatten)]
+    pub command: RemoteCommand,
+}
+
+/// Answer to a [`RemoteRequest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RemoteResponse {
+    Ok { id: Option<u64>, data: serde_json::Value },
+    Error { id: Option<u64>, message: String },
+}
+
+/// What the settings screen shows to pair a phone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemotePairing {
+    pub enabled: bool,
+    /// `balam-remote://` URL including the key, `None` without a LAN address
+    pub url: Option<String>,
+    /// The URL as a QR code (SVG markup), for the phone camera
+    pub qr_svg: Option<String>,
+}
+
+/// Sent by the PC right after the WebSocket handshake.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteChallenge {
+    pub challenge: String,
+}
+
+/// The phone's answer to a [`RemoteChallenge`]: proves it holds the pairing
+/// key (see [`auth_proof`]) without sending it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteHello {
+    /// Random id the phone keeps across sessions
+    pub device_id: String,
+    /// Shown on the PC when the device asks to be approved
+    pub device_name: String,
+    /// Random, chosen by the phone for this session
+    pub nonce: String,
+    pub proof: String,
+}
+
+/// Every later message, either way: a JSON body signed with the session key.
+///
+/// `seq` starts at 1 in each direction and must grow, so a recorded message
+/// cannot be replayed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedMessage {
+    pub seq: u64,
+    pub body: String,
+    pub mac: String,
+}
+
+/// A phone the player approved on the PC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PairedDevice {
+    pub device_id: String,
+    pub name: String,
+}
+
+/// Payload of the `remote-device-pending` event: a phone that knows the
+/// pairing key but was never approved on this PC.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteDeviceRequest {
+    pub device_id: String,
+    pub name: String,
+    /// IP address the phone connects from
+    pub address: String,
+}
+
+/// What the phone scans. It connects to `ws://` on the same host and port;
+/// the key stays on the phone and never crosses the network.
+#[must_use]
+pub fn pairing_url(address: IpAddr, port: u16, key: &str) -> String {
+    match address {
+        IpAddr::V4(v4) => format!("balam-remote://{v4}:{port}/?key={key}"),
+        IpAddr::V6(v6) => format!("balam-remote://[{v6}]:{port}/?key={key}"),
+    }
+}
+
+/// HMAC-SHA256 (RFC 2104) over `label` and `fields`, each length-prefixed
+/// so that no two field lists sign the same bytes.
+fn hmac(key: &[u8], label: &str, fields: &[&[u8]]) -> [u8; 32] {
+    const BLOCK_LEN: usize = 64;
+    let mut block = [0u8; BLOCK_LEN];
+    if key.len() > BLOCK_LEN {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new().chain_update(block.map(|b| b ^ 0x36)).chain_update(label);
+    for field in fields {
+        inner.update((field.len() as u64).to_be_bytes());
+        inner.update(field);
+    }
+    Sha256::new()
+        .chain_update(block.map(|b| b ^ 0x5c))
+        .chain_update(inner.finalize())
+        .finalize()
+        .into()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Proof a phone sends in [`RemoteHello`] (hex).
+#[must_use]
+pub fn auth_proof(pairing_key: &str, challenge: &str, nonce: &str, device_id: &str) -> String {
+    to_hex(&hmac(
+        pairing_key.as_bytes(),
+        "balam-remote-auth",
+        &[challenge.as_bytes(), nonce.as_bytes(), device_id.as_bytes()],
+    ))
+}
+
+/// Key both sides derive for one session; never sent.
+#[must_use]
+pub fn session_key(pairing_key: &str, challenge: &str, nonce: &str) -> [u8; 32] {
+    hmac(
+        pairing_key.as_bytes(),
+        "balam-remote-session",
+        &[challenge.as_bytes(), nonce.as_bytes()],
+    )
+}
+
+/// Signature of one [`SignedMessage`] (hex).
+#[must_use]
+pub fn message_mac(session_key: &[u8; 32], seq: u64, body: &str) -> String {
+    to_hex(&hmac(
+        session_key,
+        "balam-remote-message",
+        &[&seq.to_be_bytes()[..], body.as_bytes()],
+    ))
+}
+
+/// One authenticated connection, from the PC side.
+#[derive(Debug)]
+pub struct RemoteSession {
+    key: [u8; 32],
+    received: u64,
+    sent: u64,
+}
+
+impl RemoteSession {
+    /// Checks the phone's proof; `None` if it does not hold the pairing key.
+    #[must_use]
+    pub fn accept(pairing_key: &str, challenge: &str, hello: &RemoteHello) -> Option<Self> {
+        let valid_id = !hello.device_id.is_empty() && hello.device_id.len() <= MAX_DEVICE_FIELD_LEN;
+        let expected = auth_proof(pairing_key, challenge, &hello.nonce, &hello.device_id);
+        (valid_id && !hello.nonce.is_empty() && tokens_match(&expected, &hello.proof)).then(|| Self {
+            key: session_key(pairing_key, challenge, &hello.nonce),
+            received: 0,
+            sent: 0,
+        })
+    }
+
+    /// Body of a message from the phone; `None` if forged or replayed.
+    #[must_use]
+    pub fn open(&mut self, message: SignedMessage) -> Option<String> {
+        if message.seq <= self.received
+            || !tokens_match(&message_mac(&self.key, message.seq, &message.body), &message.mac)
+        {
+            return None;
+        }
+        self.received = message.seq;
+        Some(message.body)
+    }
+
+    /// Signs a message to the phone.
+    pub fn seal(&mut self, body: String) -> SignedMessage {
+        self.sent += 1;
+        SignedMessage {
+            seq: self.sent,
+            mac: message_mac(&self.key, self.sent, &body),
+            body,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hello(key: &str, challenge: &str) -> RemoteHello {
+        RemoteHello {
+            device_id: "phone-1".to_string(),
+            device_name: "Pixel".to_string(),
+            nonce: "n1".to_string(),
+            proof: auth_proof(key, challenge, "n1", "phone-1"),
+        }
+    }
+
+    #[test]
+    fn test_pairing_url() {
+        let url = pairing_url("192.168.1.20".parse().unwrap(), 47810, "abc");
+        assert_eq!(url, "balam-remote://192.168.1.20:47810/?key=abc");
+        let v6 = pairing_url("fe80::1".parse().unwrap(), 47810, "abc");
+        assert_eq!(v6, "balam-remote://[fe80::1]:47810/?key=abc");
+    }
+
+    #[test]
+    fn test_hmac_matches_rfc_4231() {
+        // Test case 2; an empty label and no fields hash only the data
+        let mac = hmac(b"Jefe", "what do ya want for nothing?", &[]);
+        assert_eq!(
+            to_hex(&mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_session_rejects_wrong_key_forgery_and_replay() {
+        assert!(RemoteSession::accept("key", "c1", &hello("other", "c1")).is_none());
+        assert!(RemoteSession::accept("key", "c2", &hello("key", "c1")).is_none());
+
+        let mut pc = RemoteSession::accept("key", "c1", &hello("key", "c1")).unwrap();
+        let mut phone = RemoteSession {
+            key: session_key("key", "c1", "n1"),
+            received: 0,
+            sent: 0,
+        };
+        let message = phone.seal(r#"{"command":"show"}"#.to_string());
+        assert_eq!(pc.open(message.clone()).as_deref(), Some(r#"{"command":"show"}"#));
+        assert_eq!(pc.open(message), None);
+
+        let mut forged = phone.seal(r#"{"command":"show"}"#.to_string());
+        forged.body = r#"{"command":"kill_game"}"#.to_string();
+        assert_eq!(pc.open(forged), None);
+    }
+
+    #[test]
+    fn test_request_wire_format() {
+        let request: RemoteRequest =
+            serde_json::from_str(r#"{"id":3,"command":"pointer_click","button":"right"}"#).unwrap();
+        assert_eq!(request.id, Some(3));
+        assert_eq!(
+            request.command,
+            RemoteCommand::PointerClick {
+                button: PointerButton::Right
+            }
+        );
+        let key: RemoteRequest = serde_json::from_str(r#"{"command":"press_key","key":"enter"}"#).unwrap();
+        assert_eq!(key.command, RemoteCommand::PressKey { key: VirtualKey::Enter });
+    }
+}
@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+
+/// Highest sharpening strength, in percent of the driver's range.
+pub const MAX_SHARPNESS: u32 = 100;
+
+/// GPU driver upscaler: a game rendering below the panel's resolution in
+/// fullscreen is upscaled and sharpened by the driver (spatial, no game support needed).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ScalingTechnology {
+    /// NVIDIA Image Scaling (NvAPI driver settings, R510+)
+    Nis,
+    /// AMD Radeon Super Resolution (ADLX, RX 5000 / RDNA iGPUs and newer)
+    Rsr,
+}
+
+/// What the installed GPU driver offers.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct ScalingCapabilities {
+    /// `None` without a supported GPU or driver
+    pub technology: Option<ScalingTechnology>,
+    /// Sharpening can be set from here (RSR; NIS sharpening stays in the NVIDIA control panel)
+    pub adjustable_sharpness: bool,
+}
+
+impl ScalingCapabilities {
+    #[must_use]
+    pub fn unsupported() -> Self {
+        Self {
+            technology: None,
+            adjustable_sharpness: false,
+        }
+    }
+}
+
+/// Driver upscaling state.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ScalingState {
+    pub enabled: bool,
+    /// Sharpening strength 0-100 (`None` if the driver doesn't expose it)
+    pub sharpness: Option<u32>,
+}
+
+/// Upscaling applied while a game runs; the previous state comes back when it exits.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GameScalingProfile {
+    pub enabled: bool,
+    /// Sharpening strength 0-100; `None` keeps the driver's
+    pub sharpness: Option<u32>,
+}
+
+impl Default for GameScalingProfile {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sharpness: None,
+        }
+    }
+}
+
+impl GameScalingProfile {
+    /// Checks the sharpening strength.
+    ///
+    /// # Errors
+    /// Returns error if sharpness is above [`MAX_SHARPNESS`].
+    pub fn validate(&self) -> Result<(), String> {
+        match self.sharpness {
+            Some(sharpness) if sharpness > MAX_SHARPNESS => Err(format!(
+                "Scaling sharpness {sharpness} is above maximum {MAX_SHARPNESS}"
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// State to apply over `current`: sharpness only changes where the
+    /// profile sets it and the driver exposes it.
+    #[must_use]
+    pub fn state_over(&self, current: ScalingState) -> ScalingState {
+        ScalingState {
+            enabled: self.enabled,
+            sharpness: current.sharpness.map(|driver| self.sharpness.unwrap_or(driver)),
+        }
+    }
+}
+
+/// Maps a 0-100 sharpness onto the driver's `min..=max` range.
+#[must_use]
+pub fn sharpness_to_driver(percent: u32, min: i32, max: i32) -> i32 {
+    let range = i64::from(max) - i64::from(min);
+    let offset = range * i64::from(percent.min(MAX_SHARPNESS)) / i64::from(MAX_SHARPNESS);
+    i32::try_from(i64::from(min) + offset).unwrap_or(max)
+}
+
+/// Maps a driver sharpness in `min..=max` back to 0-100.
+#[must_use]
+pub fn sharpness_from_driver(value: i32, min: i32, max: i32) -> u32 {
+    let range = i64::from(max) - i64::from(min);
+    if range <= 0 {
+        return 0;
+    }
+    let offset = (i64::from(value) - i64::from(min)).clamp(0, range);
+    u32::try_from(offset * i64::from(MAX_SHARPNESS) / range).unwrap_or(MAX_SHARPNESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sharpness_maps_onto_driver_range() {
+        assert_eq!(sharpness_to_driver(0, 0, 100), 0);
+        assert_eq!(sharpness_to_driver(75, 0, 100), 75);
+        assert_eq!(sharpness_to_driver(50, 10, 30), 20);
+        assert_eq!(sharpness_to_driver(250, 0, 100), 100);
+        assert_eq!(sharpness_from_driver(20, 10, 30), 50);
+        assert_eq!(sharpness_from_driver(5, 10, 30), 0);
+        assert_eq!(sharpness_from_driver(7, 7, 7), 0);
+    }
+
+    #[test]
+    fn test_profile_state_and_validation() {
+        let rsr = ScalingState {
+            enabled: false,
+            sharpness: Some(40),
+        };
+        let nis = ScalingState {
+            enabled: false,
+            sharpness: None,
+        };
+        let profile = GameScalingProfile {
+            enabled: true,
+            sharpness: Some(80),
+        };
+        assert_eq!(
+            profile.state_over(rsr),
+            ScalingState {
+                enabled: true,
+                sharpness: Some(80)
+            }
+        );
+        assert_eq!(profile.state_over(nis).sharpness, None);
+        assert_eq!(GameScalingProfile::default().state_over(rsr).sharpness, Some(40));
+
+        assert!(profile.validate().is_ok());
+        assert!(GameScalingProfile {
+            sharpness: Some(101),
+            ..profile
+        }
+        .validate()
+        .is_err());
+    }
+}
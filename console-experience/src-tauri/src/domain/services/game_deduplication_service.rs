@@ -1,11 +1,37 @@
-use crate::adapters::identity_engine::IdentityEngine;
+use crate::adapters::identity_engine::{GameIdentity, IdentityEngine};
 use crate::domain::entities::Game;
-use std::collections::HashSet;
+use crate::domain::value_objects::{DedupOverrideKind, DedupOverrides};
+use serde::Serialize;
+use std::collections::HashMap;
 
 /// Domain service for deduplicating games based on identity.
 /// Uses `IdentityEngine` to determine if games are the same across different sources.
 pub struct GameDeduplicationService;
 
+/// Why a game is (or is not) merged with others.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct DedupExplanation {
+    pub game_id: String,
+    pub canonical_path: String,
+    /// `InternalName`/`OriginalFilename` of the executable's version info
+    pub internal_name: Option<String>,
+    /// Keys the game is matched on (`BIN_`, `PATH_`, `MERGE_` or `ID_` for split games)
+    pub identity_keys: Vec<String>,
+    pub override_kind: Option<DedupOverrideKind>,
+    /// Games sharing one of its keys, in scan priority order (itself included)
+    pub matches: Vec<String>,
+    /// Game listed in the library for this identity
+    pub kept_id: String,
+}
+
+/// A scanned game with its identity and the game it is merged into.
+struct ResolvedGame {
+    game: Game,
+    identity: GameIdentity,
+    keys: Vec<String>,
+    kept_id: String,
+}
+
 impl GameDeduplicationService {
     /// Creates a new deduplication service.
     ///
@@ -26,34 +52,36 @@ impl GameDeduplicationService {
     /// # Returns
     /// Vector of unique games with duplicates removed
     pub fn deduplicate<I>(&self, games: I) -> Vec<Game>
+    where
+        I: IntoIterator<Item = Game>,
+    {
+        self.deduplicate_with(games, &DedupOverrides::default())
+    }
+
+    /// Deduplicates games, applying the user's merge/split corrections on top
+    /// of the identity match.
+    pub fn deduplicate_with<I>(&self, games: I, overrides: &DedupOverrides) -> Vec<Game>
     where
         I: IntoIterator<Item = Game>,
     {
         use tracing::info;
 
-        let mut seen_identities = HashSet::new();
-        let mut unique_games = Vec::new();
         let mut duplicate_count = 0;
-
-        for game in games {
-            let identity = IdentityEngine::get_identity(&game.path);
-
-            // Identity key: Binaries match by internal name, others by canonical path
-            let identity_key = if let Some(ref internal) = identity.internal_name {
-                format!("BIN_{internal}")
-            } else {
-                let canonical_path = &identity.canonical_path;
-                format!("PATH_{canonical_path}")
-            };
-
-            if seen_identities.contains(&identity_key) {
-                duplicate_count += 1;
-                info!("Skipping duplicate: {} (identity already exists)", game.title);
-            } else {
-                seen_identities.insert(identity_key);
-                unique_games.push(game);
-            }
-        }
+        let unique_games: Vec<Game> = Self::resolve(games, overrides)
+            .into_iter()
+            .filter_map(|resolved| {
+                if resolved.kept_id == resolved.game.id {
+                    Some(resolved.game)
+                } else {
+                    duplicate_count += 1;
+                    info!(
+                        "Skipping duplicate: {} (identity already exists as {})",
+                        resolved.game.title, resolved.kept_id
+                    );
+                    None
+                }
+            })
+            .collect();
 
         info!(
             "Deduplication complete: {} unique, {} duplicates removed",
@@ -63,6 +91,82 @@ impl GameDeduplicationService {
 
         unique_games
     }
+
+    /// Explains how the game `game_id` of a scan is de-duplicated.
+    ///
+    /// Returns `None` if the scan did not find the game.
+    pub fn explain<I>(&self, games: I, overrides: &DedupOverrides, game_id: &str) -> Option<DedupExplanation>
+    where
+        I: IntoIterator<Item = Game>,
+    {
+        let resolved = Self::resolve(games, overrides);
+        let target = resolved.iter().find(|resolved| resolved.game.id == game_id)?;
+        let matches = resolved
+            .iter()
+            .filter(|other| other.keys.iter().any(|key| target.keys.contains(key)))
+            .map(|other| other.game.id.clone())
+            .collect();
+
+        Some(DedupExplanation {
+            game_id: game_id.to_string(),
+            canonical_path: target.identity.canonical_path.clone(),
+            internal_name: target.identity.internal_name.clone(),
+            identity_keys: target.keys.clone(),
+            override_kind: overrides.kind(game_id),
+            matches,
+            kept_id: target.kept_id.clone(),
+        })
+    }
+
+    /// Computes each game's keys and the first game (in priority order) it shares one with.
+    fn resolve<I>(games: I, overrides: &DedupOverrides) -> Vec<ResolvedGame>
+    where
+        I: IntoIterator<Item = Game>,
+    {
+        let mut key_owners: HashMap<String, String> = HashMap::new();
+
+        games
+            .into_iter()
+            .map(|game| {
+                let identity = IdentityEngine::get_identity(&game.path);
+                let keys = Self::match_keys(&game, &identity, overrides);
+                let kept_id = keys
+                    .iter()
+                    .find_map(|key| key_owners.get(key).cloned())
+                    .unwrap_or_else(|| game.id.clone());
+                for key in &keys {
+                    key_owners.entry(key.clone()).or_insert_with(|| kept_id.clone());
+                }
+                ResolvedGame {
+                    game,
+                    identity,
+                    keys,
+                    kept_id,
+                }
+            })
+            .collect()
+    }
+
+    /// Keys a game is matched on: its identity, plus its merge group.
+    /// Split games only match themselves.
+    fn match_keys(game: &Game, identity: &GameIdentity, overrides: &DedupOverrides) -> Vec<String> {
+        if overrides.kind(&game.id) == Some(DedupOverrideKind::Split) {
+            return vec![format!("ID_{}", game.id)];
+        }
+
+        // Identity key: Binaries match by internal name, others by canonical path
+        let identity_key = if let Some(ref internal) = identity.internal_name {
+            format!("BIN_{internal}")
+        } else {
+            let canonical_path = &identity.canonical_path;
+            format!("PATH_{canonical_path}")
+        };
+
+        match overrides.merge_group(&game.id).and_then(|group| group.first()) {
+            Some(group_id) => vec![identity_key, format!("MERGE_{group_id}")],
+            None => vec![identity_key],
+        }
+    }
 }
 
 impl Default for GameDeduplicationService {
@@ -136,4 +240,45 @@ mod tests {
 
         assert_eq!(unique.len(), 0);
     }
+
+    #[test]
+    fn test_user_overrides_merge_and_split() {
+        let steam = Game::new(
+            "steam_123".to_string(),
+            "123".to_string(),
+            "Test Game".to_string(),
+            "C:\\Games\\test.exe".to_string(),
+            GameSource::Steam,
+        );
+        let epic = Game::new(
+            "epic_456".to_string(),
+            "456".to_string(),
+            "Test Game".to_string(),
+            "D:\\Epic\\TestGame\\test.exe".to_string(),
+            GameSource::Epic,
+        );
+        let other = Game::new(
+            "reg_tool".to_string(),
+            "tool".to_string(),
+            "Test Game Editor".to_string(),
+            "C:\\Games\\test.exe".to_string(),
+            GameSource::Manual,
+        );
+        let games = vec![steam, epic, other];
+        let service = GameDeduplicationService::new();
+
+        let mut overrides = DedupOverrides::default();
+        overrides
+            .merge(&["steam_123".to_string(), "epic_456".to_string()])
+            .unwrap();
+        overrides.split("reg_tool");
+        let unique = service.deduplicate_with(games.clone(), &overrides);
+        let ids: Vec<&str> = unique.iter().map(|g| g.id.as_str()).collect();
+        assert_eq!(ids, vec!["steam_123", "reg_tool"]);
+
+        let explanation = service.explain(games, &overrides, "epic_456").unwrap();
+        assert_eq!(explanation.kept_id, "steam_123");
+        assert_eq!(explanation.override_kind, Some(DedupOverrideKind::Merged));
+        assert_eq!(explanation.matches, vec!["steam_123", "epic_456"]);
+    }
 }
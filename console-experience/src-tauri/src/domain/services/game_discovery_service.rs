@@ -1,10 +1,49 @@
 use crate::domain::entities::Game;
 use crate::domain::errors::ScanError;
+use crate::domain::value_objects::GameSource;
 use crate::ports::GameScanner;
+use serde::Serialize;
+use std::sync::mpsc::{self, RecvTimeoutError};
 use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+use tracing::{error, info, warn};
+
+/// How one scanner's run ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ScanOutcome {
+    Found {
+        game_count: usize,
+    },
+    Failed {
+        error: String,
+    },
+    /// Still running at its timeout; its games are not in this scan
+    TimedOut,
+}
+
+/// Result of one scanner, reported as soon as it is known.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScannerStatus {
+    pub scanner: &'static str,
+    pub source: GameSource,
+    #[serde(flatten)]
+    pub outcome: ScanOutcome,
+    /// Time from the start of discovery until the outcome was known
+    pub duration_ms: u64,
+}
+
+/// Games found by every scanner that finished in time, with each scanner's status.
+#[derive(Debug, Clone)]
+pub struct DiscoveryReport {
+    pub games: Vec<Game>,
+    /// In priority order
+    pub scanners: Vec<ScannerStatus>,
+}
 
 /// Domain service for discovering games from multiple sources.
-/// Orchestrates scanning across different platforms with prioritization.
+/// Runs the scanners concurrently and merges their results by priority.
 pub struct GameDiscoveryService {
     scanners: Vec<Arc<dyn GameScanner>>,
 }
@@ -20,40 +59,133 @@ impl GameDiscoveryService {
     }
 
     /// Discovers games from all scanners, sorted by priority.
-    /// Continues even if individual scanners fail.
+    /// Continues even if individual scanners fail or time out.
     pub fn discover(&self) -> Result<Vec<Game>, ScanError> {
-        use tracing::{error, info};
+        self.discover_with_progress(&|_| {}).map(|report| report.games)
+    }
 
+    /// Runs every scanner on its own thread; `on_scanner_done` is called as
+    /// each one finishes, fails or times out, so a hung scanner (e.g. UWP
+    /// enumeration) never holds back the others.
+    ///
+    /// # Errors
+    /// Returns error if no scanner succeeded.
+    pub fn discover_with_progress(
+        &self,
+        on_scanner_done: &dyn Fn(&ScannerStatus),
+    ) -> Result<DiscoveryReport, ScanError> {
         info!("Starting game discovery with {} scanners", self.scanners.len());
+        let started = Instant::now();
 
-        // Sort scanners by priority (metadata-rich sources first)
+        // Results are merged in priority order (metadata-rich sources first)
         let mut sorted = self.scanners.clone();
         sorted.sort_by_key(|s| s.priority());
 
-        let mut all_games = Vec::new();
-        let mut any_success = false;
-
-        for scanner in sorted {
-            let source_name = scanner.source().display_name();
-            info!("Scanning {}...", source_name);
+        let (tx, rx) = mpsc::channel();
+        for (index, scanner) in sorted.iter().enumerate() {
+            let scanner = Arc::clone(scanner);
+            let tx = tx.clone();
+            let spawned = thread::Builder::new()
+                .name(format!("scan-{}", scanner.name()))
+                .spawn(move || {
+                    // The receiver is gone if this scanner already timed out
+                    let _ = tx.send((index, scanner.scan()));
+                });
+            if let Err(e) = spawned {
+                let _ = tx.send((
+                    index,
+                    Err(ScanError::PlatformError(format!("Failed to start scanner: {e}"))),
+                ));
+            }
+        }
+        drop(tx);
 
-            match scanner.scan() {
+        let mut results: Vec<Option<(ScannerStatus, Vec<Game>)>> = sorted.iter().map(|_| None).collect();
+        // `Err(None)`: timed out
+        let finish = |results: &mut Vec<Option<(ScannerStatus, Vec<Game>)>>,
+                      index: usize,
+                      result: Result<Vec<Game>, Option<ScanError>>| {
+            let scanner: &Arc<dyn GameScanner> = &sorted[index];
+            let (outcome, games) = match result {
                 Ok(games) => {
-                    info!("✓ Found {} games from {}", games.len(), source_name);
-                    all_games.extend(games);
-                    any_success = true;
+                    info!("✓ Found {} games from {}", games.len(), scanner.name());
+                    (
+                        ScanOutcome::Found {
+                            game_count: games.len(),
+                        },
+                        games,
+                    )
+                },
+                Err(Some(e)) => {
+                    error!("✗ Scanner {} failed: {}", scanner.name(), e);
+                    (ScanOutcome::Failed { error: e.to_string() }, Vec::new())
+                },
+                Err(None) => {
+                    warn!("✗ Scanner {} timed out after {:?}", scanner.name(), scanner.timeout());
+                    (ScanOutcome::TimedOut, Vec::new())
+                },
+            };
+            let status = ScannerStatus {
+                scanner: scanner.name(),
+                source: scanner.source(),
+                outcome,
+                duration_ms: started.elapsed().as_millis() as u64,
+            };
+            on_scanner_done(&status);
+            results[index] = Some((status, games));
+        };
+
+        loop {
+            // Earliest timeout among the scanners still running
+            let Some(deadline) = sorted
+                .iter()
+                .zip(&results)
+                .filter(|(_, result)| result.is_none())
+                .map(|(scanner, _)| started + scanner.timeout())
+                .min()
+            else {
+                break;
+            };
+            match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok((index, result)) => {
+                    if results[index].is_none() {
+                        finish(&mut results, index, result.map_err(Some));
+                    }
                 },
-                Err(e) => {
-                    error!("✗ Scanner {} failed: {}", source_name, e);
+                Err(RecvTimeoutError::Timeout) => {
+                    let now = Instant::now();
+                    let expired: Vec<usize> = (0..sorted.len())
+                        .filter(|&index| results[index].is_none() && started + sorted[index].timeout() <= now)
+                        .collect();
+                    for index in expired {
+                        finish(&mut results, index, Err(None));
+                    }
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    let lost: Vec<usize> = (0..sorted.len()).filter(|&index| results[index].is_none()).collect();
+                    for index in lost {
+                        let e = ScanError::PlatformError("Scanner thread ended without a result".to_string());
+                        finish(&mut results, index, Err(Some(e)));
+                    }
                 },
             }
         }
 
+        let mut games = Vec::new();
+        let mut scanners = Vec::with_capacity(results.len());
+        for (status, found) in results.into_iter().flatten() {
+            games.extend(found);
+            scanners.push(status);
+        }
+
+        let any_success = scanners
+            .iter()
+            .any(|status| matches!(status.outcome, ScanOutcome::Found { .. }));
         if !any_success && !self.scanners.is_empty() {
             return Err(ScanError::PlatformError("All scanners failed".to_string()));
         }
 
-        Ok(all_games)
+        Ok(DiscoveryReport { games, scanners })
     }
 
     /// Returns the number of registered scanners.
@@ -66,7 +198,7 @@ impl GameDiscoveryService {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::value_objects::GameSource;
+    use std::time::Duration;
 
     struct MockScanner {
         games: Vec<Game>,
@@ -154,6 +286,53 @@ mod tests {
         assert!(result.is_err());
     }
 
+    struct SlowScanner;
+
+    impl GameScanner for SlowScanner {
+        fn scan(&self) -> Result<Vec<Game>, ScanError> {
+            std::thread::sleep(Duration::from_secs(2));
+            Ok(Vec::new())
+        }
+
+        fn source(&self) -> GameSource {
+            GameSource::Steam
+        }
+
+        fn timeout(&self) -> Duration {
+            Duration::from_millis(50)
+        }
+    }
+
+    #[test]
+    fn test_hung_scanner_times_out_without_holding_back_others() {
+        let game = Game::new(
+            "epic_1".to_string(),
+            "1".to_string(),
+            "Epic Game".to_string(),
+            "/epic".to_string(),
+            GameSource::Epic,
+        );
+        let working = Arc::new(MockScanner {
+            games: vec![game],
+            should_fail: false,
+            source: GameSource::Epic,
+        });
+
+        let service = GameDiscoveryService::new(vec![working, Arc::new(SlowScanner)]);
+        let reported = std::sync::Mutex::new(Vec::new());
+        let started = Instant::now();
+        let report = service
+            .discover_with_progress(&|status| reported.lock().unwrap().push(status.outcome.clone()))
+            .unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(report.games.len(), 1);
+        // Priority order: Steam before Epic
+        assert_eq!(report.scanners[0].outcome, ScanOutcome::TimedOut);
+        assert_eq!(report.scanners[1].outcome, ScanOutcome::Found { game_count: 1 });
+        assert_eq!(reported.lock().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_scanner_count() {
         let scanner1 = Arc::new(MockScanner {
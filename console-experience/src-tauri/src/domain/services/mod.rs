@@ -1,5 +1,5 @@
 pub mod game_deduplication_service;
 pub mod game_discovery_service;
 
-pub use game_deduplication_service::GameDeduplicationService;
-pub use game_discovery_service::GameDiscoveryService;
+pub use game_deduplication_service::{DedupExplanation, GameDeduplicationService};
+pub use game_discovery_service::{DiscoveryReport, GameDiscoveryService, ScanOutcome, ScannerStatus};
@@ -0,0 +1,180 @@
+/// Post-game session summary
+///
+/// While a game runs its FPS, TDP, battery and temperatures are sampled; when
+/// it exits they are folded into a report card (`session-summary` event) that
+/// is also kept with the game's play history.
+use serde::{Deserialize, Serialize};
+
+use crate::domain::performance::PerformanceMetrics;
+
+/// Summaries kept per game in the play history.
+pub const MAX_SESSION_SUMMARIES: usize = 10;
+
+/// One reading taken while the game runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SessionReading {
+    /// `None` (or 0) while the game presents no frames
+    pub fps: Option<f32>,
+    pub tdp_watts: Option<u32>,
+    pub battery_percent: Option<u8>,
+    pub charging: bool,
+    pub cpu_temp_c: Option<f32>,
+    pub gpu_temp_c: Option<f32>,
+}
+
+impl SessionReading {
+    #[must_use]
+    pub fn new(
+        metrics: &PerformanceMetrics,
+        tdp_watts: Option<u32>,
+        battery_percent: Option<u8>,
+        charging: bool,
+    ) -> Self {
+        Self {
+            fps: metrics.fps.as_ref().map(|fps| fps.current_fps),
+            tdp_watts,
+            battery_percent,
+            charging,
+            cpu_temp_c: metrics.cpu_temp_c,
+            gpu_temp_c: metrics.gpu_temp_c,
+        }
+    }
+}
+
+/// Running totals of a session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionStats {
+    pub game_title: String,
+    fps: Vec<f32>,
+    tdp_sum: u64,
+    tdp_samples: u32,
+    last_battery: Option<u8>,
+    /// Percent drained on battery (charging periods don't count)
+    battery_used: u32,
+    max_cpu_temp_c: Option<f32>,
+    max_gpu_temp_c: Option<f32>,
+}
+
+impl SessionStats {
+    #[must_use]
+    pub fn new(game_title: String) -> Self {
+        Self {
+            game_title,
+            ..Self::default()
+        }
+    }
+
+    pub fn add(&mut self, reading: &SessionReading) {
+        if let Some(fps) = reading.fps.filter(|fps| *fps > 0.0) {
+            self.fps.push(fps);
+        }
+        if let Some(watts) = reading.tdp_watts {
+            self.tdp_sum += u64::from(watts);
+            self.tdp_samples += 1;
+        }
+        if let Some(percent) = reading.battery_percent {
+            if let Some(last) = self.last_battery {
+                if !reading.charging && percent < last {
+                    self.battery_used += u32::from(last - percent);
+                }
+            }
+            self.last_battery = Some(percent);
+        }
+        let max = |current: Option<f32>, value: Option<f32>| match (current, value) {
+            (Some(current), Some(value)) => Some(current.max(value)),
+            (current, value) => current.or(value),
+        };
+        self.max_cpu_temp_c = max(self.max_cpu_temp_c, reading.cpu_temp_c);
+        self.max_gpu_temp_c = max(self.max_gpu_temp_c, reading.gpu_temp_c);
+    }
+
+    /// Report card of the finished session.
+    #[must_use]
+    pub fn summary(&self, game_id: String, duration_secs: u64, ended_at: u64) -> SessionSummary {
+        let avg_fps = (!self.fps.is_empty()).then(|| self.fps.iter().sum::<f32>() / self.fps.len() as f32);
+        let low_1_percent_fps = (!self.fps.is_empty()).then(|| {
+            let mut sorted = self.fps.clone();
+            sorted.sort_by(f32::total_cmp);
+            let count = sorted.len().div_ceil(100);
+            sorted[..count].iter().sum::<f32>() / count as f32
+        });
+
+        SessionSummary {
+            game_id,
+            game_title: self.game_title.clone(),
+            ended_at,
+            duration_secs,
+            avg_fps,
+            low_1_percent_fps,
+            avg_tdp_watts: (self.tdp_samples > 0).then(|| self.tdp_sum as f32 / self.tdp_samples as f32),
+            battery_used_percent: self.last_battery.map(|_| self.battery_used.min(100) as u8),
+            max_cpu_temp_c: self.max_cpu_temp_c,
+            max_gpu_temp_c: self.max_gpu_temp_c,
+        }
+    }
+}
+
+/// Post-game report card.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub game_id: String,
+    pub game_title: String,
+    /// Unix epoch seconds
+    pub ended_at: u64,
+    pub duration_secs: u64,
+    /// `None` without FPS readings (FPS service not running)
+    pub avg_fps: Option<f32>,
+    /// Average of the slowest 1% of FPS readings
+    pub low_1_percent_fps: Option<f32>,
+    /// `None` without TDP control
+    pub avg_tdp_watts: Option<f32>,
+    /// Battery drained while unplugged (`None` without a battery)
+    pub battery_used_percent: Option<u8>,
+    pub max_cpu_temp_c: Option<f32>,
+    pub max_gpu_temp_c: Option<f32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_of_readings() {
+        let mut stats = SessionStats::new("Game".to_string());
+        for (fps, battery, charging, temp) in [
+            (60.0, 80, false, 70.0),
+            (58.0, 78, false, 82.0),
+            (30.0, 75, false, 75.0),
+            // Plugged in: charging doesn't give battery back to the session
+            (60.0, 79, true, 74.0),
+            (60.0, 77, false, 71.0),
+        ] {
+            stats.add(&SessionReading {
+                fps: Some(fps),
+                tdp_watts: Some(15),
+                battery_percent: Some(battery),
+                charging,
+                cpu_temp_c: Some(temp),
+                gpu_temp_c: None,
+            });
+        }
+        stats.add(&SessionReading::default());
+
+        let summary = stats.summary("steam_1".to_string(), 600, 1000);
+        assert_eq!(summary.avg_fps, Some(53.6));
+        assert_eq!(summary.low_1_percent_fps, Some(30.0));
+        assert_eq!(summary.avg_tdp_watts, Some(15.0));
+        assert_eq!(summary.battery_used_percent, Some(7));
+        assert_eq!(summary.max_cpu_temp_c, Some(82.0));
+        assert_eq!(summary.max_gpu_temp_c, None);
+    }
+
+    #[test]
+    fn test_summary_without_readings() {
+        let summary = SessionStats::new("Game".to_string()).summary("steam_1".to_string(), 30, 1000);
+        assert_eq!(summary.avg_fps, None);
+        assert_eq!(summary.low_1_percent_fps, None);
+        assert_eq!(summary.avg_tdp_watts, None);
+        assert_eq!(summary.battery_used_percent, None);
+    }
+}
@@ -0,0 +1,792 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::adapters::process_launcher::constants::{
+    EPIC_TIMEOUT_SECONDS, STANDALONE_TIMEOUT_SECONDS, STEAM_TIMEOUT_SECONDS,
+};
+
+use crate::domain::alerts::AlertSettings;
+use crate::domain::controller_input::{
+    ExtraButton, ExtraButtonBinding, NavAction, MAX_GYRO_SENSITIVITY, MIN_GYRO_SENSITIVITY,
+};
+use crate::domain::dashboard::DashboardSettings;
+use crate::domain::display::{ColorProfile, GameWindowMode};
+use crate::domain::gamepad_polling::{DEFAULT_POLL_INTERVAL_MS, MAX_POLL_INTERVAL_MS, MIN_POLL_INTERVAL_MS};
+use crate::domain::haptic::{HapticEvent, HapticPattern};
+use crate::domain::hotkey::{GamepadButton, GamepadChordBinding, HotkeyAction};
+use crate::domain::idle::MAX_AUTO_SUSPEND_MINUTES;
+use crate::domain::launch_hooks::HookSettings;
+use crate::domain::launch_timeout::{AdaptiveTimeout, MAX_LAUNCH_TIMEOUT_SECONDS, MIN_LAUNCH_TIMEOUT_SECONDS};
+use crate::domain::launcher_dependency::LauncherDependency;
+use crate::domain::legendary::EpicLaunchBackend;
+use crate::domain::media::{MAX_REPLAY_SECONDS, MIN_REPLAY_SECONDS};
+use crate::domain::network_quality::{MAX_LATENCY_SPIKE_MS, MIN_LATENCY_SPIKE_MS};
+use crate::domain::overlay_layout::{OverlayLayout, OverlayLevel};
+use crate::domain::performance::GamePowerProfile;
+use crate::domain::pip::PipSettings;
+use crate::domain::registry_rules::RegistryScanRules;
+use crate::domain::remote::{DEFAULT_REMOTE_PORT, MIN_REMOTE_PORT};
+use crate::domain::scaling::GameScalingProfile;
+use crate::domain::thermal::FanCurve;
+use crate::domain::update::DEFAULT_UPDATE_FEED_URL;
+
+/// Current settings schema version.
+///
+/// Bump this whenever a field is renamed or its meaning changes, and add the
+/// corresponding step to the repository's migration chain.
+pub const SETTINGS_SCHEMA_VERSION: u32 = 1;
+
+/// Domain entity holding every user-configurable option of the application.
+///
+/// All sections use `#[serde(default)]` so that a settings file written by an
+/// older build (missing newer fields) still deserializes cleanly.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AppSettings {
+    /// Schema version the settings were written with
+    pub schema_version: u32,
+    pub general: GeneralSettings,
+    pub hotkeys: HotkeySettings,
+    pub overlay: OverlaySettings,
+    pub library: LibrarySettings,
+    pub watchdog: WatchdogSettings,
+    pub launch: LaunchSettings,
+    pub display: DisplaySettings,
+    pub thermal: ThermalSettings,
+    pub performance: PerformanceSettings,
+    pub idle: IdleSettings,
+    pub network: NetworkSettings,
+    pub replay: ReplaySettings,
+    pub controller: ControllerSettings,
+    pub haptics: HapticSettings,
+    pub updates: UpdateSettings,
+    pub external_api: ExternalApiSettings,
+    pub remote: RemoteSettings,
+    pub telemetry: TelemetrySettings,
+    pub hooks: HookSettings,
+    pub alerts: AlertSettings,
+    pub pip: PipSettings,
+    pub dashboard: DashboardSettings,
+}
+
+/// General application behaviour.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct GeneralSettings {
+    /// UI language code (e.g., "es", "en")
+    pub language: String,
+    /// Poll interval of the system monitor (volume, battery, network) in milliseconds,
+    /// used only for sources without native change notifications
+    pub system_poll_interval_ms: u64,
+}
+
+/// Keyboard shortcuts, stored as accelerator strings (e.g., "Ctrl+Shift+Q").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HotkeySettings {
+    pub toggle_overlay: String,
+    pub toggle_wifi_panel: String,
+    pub toggle_bluetooth_panel: String,
+    pub toggle_mic_mute: String,
+    /// Push-to-talk: the mic stays muted and `toggle_mic_mute` unmutes it while held
+    pub mic_push_to_talk: bool,
+    /// Screenshot of the running game (bind a gamepad chord with `set_gamepad_chord`)
+    pub take_screenshot: String,
+    /// Saves the replay buffer as a clip
+    pub save_replay: String,
+    /// Steps the performance HUD through its levels (off, FPS, FPS + battery, full)
+    pub cycle_overlay_level: String,
+    /// Gamepad button chords (checked by the gamepad listener)
+    pub gamepad_chords: Vec<GamepadChordBinding>,
+}
+
+impl HotkeySettings {
+    /// Keyboard accelerator bound to each action (empty string = unbound).
+    #[must_use]
+    pub fn keyboard_bindings(&self) -> [(HotkeyAction, &str); 7] {
+        [
+            (HotkeyAction::ToggleOverlay, self.toggle_overlay.as_str()),
+            (HotkeyAction::ToggleWifiPanel, self.toggle_wifi_panel.as_str()),
+            (HotkeyAction::ToggleBluetoothPanel, self.toggle_bluetooth_panel.as_str()),
+            (HotkeyAction::ToggleMicMute, self.toggle_mic_mute.as_str()),
+            (HotkeyAction::TakeScreenshot, self.take_screenshot.as_str()),
+            (HotkeyAction::SaveReplay, self.save_replay.as_str()),
+            (HotkeyAction::CycleOverlayLevel, self.cycle_overlay_level.as_str()),
+        ]
+    }
+
+    /// Sets the keyboard accelerator for an action.
+    pub fn set_keyboard_binding(&mut self, action: HotkeyAction, accelerator: String) {
+        match action {
+            HotkeyAction::ToggleOverlay => self.toggle_overlay = accelerator,
+            HotkeyAction::ToggleWifiPanel => self.toggle_wifi_panel = accelerator,
+            HotkeyAction::ToggleBluetoothPanel => self.toggle_bluetooth_panel = accelerator,
+            HotkeyAction::ToggleMicMute => self.toggle_mic_mute = accelerator,
+            HotkeyAction::TakeScreenshot => self.take_screenshot = accelerator,
+            HotkeyAction::SaveReplay => self.save_replay = accelerator,
+            HotkeyAction::CycleOverlayLevel => self.cycle_overlay_level = accelerator,
+        }
+    }
+
+    /// Sets (or clears, with an empty list) the gamepad chord for an action.
+    pub fn set_gamepad_chord(&mut self, action: HotkeyAction, buttons: Vec<GamepadButton>) {
+        self.gamepad_chords.retain(|chord| chord.action != action);
+        if !buttons.is_empty() {
+            self.gamepad_chords.push(GamepadChordBinding { action, buttons });
+        }
+    }
+}
+
+/// In-game overlay defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct OverlaySettings {
+    /// Opacity applied when the overlay window is shown (0.0 - 1.0)
+    pub opacity: f64,
+    /// Whether mouse clicks pass through the overlay by default
+    pub click_through: bool,
+    /// Metrics shown by the performance HUD and its corner
+    pub layout: OverlayLayout,
+    /// How much of the layout is shown (cycled with `cycle_overlay_level`)
+    pub level: OverlayLevel,
+}
+
+/// Game library configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LibrarySettings {
+    /// Extra directories scanned for standalone games
+    pub scan_directories: Vec<String>,
+    /// Fetch average completion times (`HowLongToBeat`) for the game page;
+    /// off by default since titles are sent to a third party
+    pub completion_times: bool,
+    /// Launcher used for Epic games, keyed by game id (default: Epic Games Launcher)
+    pub epic_launch_backends: HashMap<String, EpicLaunchBackend>,
+    /// `legendary.exe` to use (empty = `PATH`, then Heroic's copy)
+    pub legendary_path: String,
+    /// Which uninstall registry entries are listed as games
+    pub registry_rules: RegistryScanRules,
+    /// Store client a game needs running, keyed by game id (overrides detection)
+    pub launcher_dependencies: HashMap<String, LauncherDependency>,
+}
+
+impl LibrarySettings {
+    /// Launcher for an Epic game.
+    #[must_use]
+    pub fn epic_launch_backend(&self, game_id: &str) -> EpicLaunchBackend {
+        self.epic_launch_backends.get(game_id).copied().unwrap_or_default()
+    }
+}
+
+/// Crash-recovery watchdog configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct WatchdogSettings {
+    /// Heartbeat interval sent to the watchdog in milliseconds
+    pub heartbeat_interval_ms: u64,
+}
+
+/// How long game launches wait for the game process.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LaunchSettings {
+    pub steam_timeout_seconds: u64,
+    /// Covers the Epic Games Launcher start, sign-in and DRM relaunch
+    pub epic_timeout_seconds: u64,
+    /// Games started through their own launcher (Riot Client, Minecraft Launcher)
+    pub standalone_timeout_seconds: u64,
+    /// Keep waiting while Steam downloads, validates or compiles shaders for the game
+    pub adaptive_timeouts: bool,
+    /// Longest wait adaptive mode can reach
+    pub max_adaptive_timeout_seconds: u64,
+}
+
+impl LaunchSettings {
+    /// Startup timeout of a Steam launch.
+    #[must_use]
+    pub fn steam_timeout(&self) -> AdaptiveTimeout {
+        if self.adaptive_timeouts {
+            AdaptiveTimeout::adaptive(self.steam_timeout_seconds, self.max_adaptive_timeout_seconds)
+        } else {
+            AdaptiveTimeout::fixed(self.steam_timeout_seconds)
+        }
+    }
+}
+
+/// Display color: night light and per-game color profiles.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DisplaySettings {
+    /// Warm the screen while no game with its own color profile is running
+    pub night_light: bool,
+    /// Night light white point in Kelvin
+    pub night_light_temperature_k: u32,
+    /// Color profile applied while a game runs, keyed by game id
+    pub game_color_profiles: HashMap<String, ColorProfile>,
+    /// Window mode a game is switched to once its window shows up, keyed by game id
+    pub game_window_modes: HashMap<String, GameWindowMode>,
+    /// GPU driver upscaling (NIS / RSR) applied while a game runs, keyed by game id
+    pub game_scaling_profiles: HashMap<String, GameScalingProfile>,
+}
+
+impl DisplaySettings {
+    /// Profile to apply: the running game's own profile, else night light, else neutral.
+    #[must_use]
+    pub fn effective_profile(&self, game_id: Option<&str>) -> ColorProfile {
+        if let Some(profile) = game_id.and_then(|id| self.game_color_profiles.get(id)) {
+            return *profile;
+        }
+        if self.night_light {
+            ColorProfile::warm(self.night_light_temperature_k)
+        } else {
+            ColorProfile::neutral()
+        }
+    }
+}
+
+/// Handheld fan control.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ThermalSettings {
+    /// Custom fan curve re-applied at startup (`None` = firmware curve)
+    pub fan_curve: Option<FanCurve>,
+}
+
+/// Per-game power plan and scheduling overrides.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PerformanceSettings {
+    /// Power mode, CPU boost and process priority/affinity applied while a
+    /// game runs, keyed by game id
+    pub game_power_profiles: HashMap<String, GamePowerProfile>,
+}
+
+/// Automatic sleep and wake behaviour.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct IdleSettings {
+    /// Suspend after this many minutes without gamepad input while no game
+    /// is running (0 = never)
+    pub auto_suspend_minutes: u32,
+    /// Bring Balam to the front after resume from sleep (unless a game is running)
+    pub show_on_resume: bool,
+}
+
+/// In-game latency monitor.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct NetworkSettings {
+    /// Host pinged while a game runs (IP or name, e.g. the game's region server)
+    pub ping_target: String,
+    /// Latency above which `network-latency-spike` is emitted
+    pub latency_spike_ms: u32,
+}
+
+/// Background replay buffer ("save the last 30 seconds").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ReplaySettings {
+    /// Keep recording the focused game in the background (costs GPU encode time)
+    pub enabled: bool,
+    /// Length of the buffer, and of the clip `save_replay` writes
+    pub buffer_seconds: u32,
+    /// Recording frame rate (30 or 60)
+    pub fps: u32,
+    /// H.264 bitrate in kbit/s
+    pub bitrate_kbps: u32,
+}
+
+/// Raw HID extras of DualSense / Switch Pro controllers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ControllerSettings {
+    /// Read gyro, touchpad and extra buttons through raw HID
+    pub raw_hid: bool,
+    /// Extra buttons (paddles, touchpad click...) bound to navigation actions
+    pub extra_buttons: Vec<ExtraButtonBinding>,
+    /// Move the mouse with the gyro while a game is in front
+    pub gyro_mouse: bool,
+    /// Move the mouse with the DualSense touchpad while a game is in front
+    pub touchpad_mouse: bool,
+    /// Gyro mouse speed multiplier
+    pub gyro_sensitivity: f32,
+    /// Use the Windows touch keyboard instead of Balam's own for text fields
+    /// (ignored in console mode, where explorer is not running)
+    pub windows_touch_keyboard: bool,
+    /// Controller polling while navigating Balam; higher saves battery
+    /// (the rate drops on its own with no controller or no window shown)
+    pub poll_interval_ms: u64,
+}
+
+impl ControllerSettings {
+    /// Navigation action bound to an extra button.
+    #[must_use]
+    pub fn extra_button_action(&self, button: ExtraButton) -> Option<NavAction> {
+        self.extra_buttons
+            .iter()
+            .find(|binding| binding.button == button)
+            .map(|binding| binding.action)
+    }
+
+    /// Binds an extra button (`None` to unbind), replacing its previous binding.
+    pub fn set_extra_button(&mut self, button: ExtraButton, action: Option<NavAction>) {
+        self.extra_buttons.retain(|binding| binding.button != button);
+        if let Some(action) = action {
+            self.extra_buttons.push(ExtraButtonBinding { button, action });
+        }
+    }
+}
+
+/// Controller rumble.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HapticSettings {
+    /// Built-in pattern played for each event (events without an entry don't rumble)
+    pub event_patterns: BTreeMap<HapticEvent, String>,
+}
+
+impl HapticSettings {
+    /// Pattern mapped to an event, if it rumbles.
+    #[must_use]
+    pub fn pattern_for(&self, event: HapticEvent) -> Option<HapticPattern> {
+        self.event_patterns
+            .get(&event)
+            .and_then(|name| HapticPattern::builtin(name))
+    }
+}
+
+/// Self-update of Balam, the watchdog and fps-service.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct UpdateSettings {
+    /// Release feed (JSON manifest, see `domain::update::ReleaseManifest`)
+    pub feed_url: String,
+    /// Look for a new release once at startup (never installs on its own)
+    pub check_on_startup: bool,
+}
+
+/// Local API for external tools (`\\.\pipe\balam`, see `domain::external_api`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ExternalApiSettings {
+    /// Off by default: anything holding the token can launch and close games
+    pub enabled: bool,
+}
+
+/// Companion phone remote (LAN WebSocket server, see `domain::remote`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RemoteSettings {
+    /// Off by default: the server listens on every network interface
+    pub enabled: bool,
+    pub port: u16,
+}
+
+/// Anonymous usage counters (see `domain::telemetry`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TelemetrySettings {
+    /// Off by default: nothing is collected or sent without consent
+    pub enabled: bool,
+    /// HTTPS endpoint receiving batches; empty sends nothing
+    pub endpoint: String,
+}
+
+impl AppSettings {
+    /// Validates value ranges.
+    ///
+    /// # Errors
+    /// Returns error describing the first invalid field.
+    pub fn validate(&self) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&self.overlay.opacity) {
+            return Err(format!(
+                "Overlay opacity {} out of range (0.0 - 1.0)",
+                self.overlay.opacity
+            ));
+        }
+        self.overlay.layout.validate()?;
+        if self.general.system_poll_interval_ms < 50 {
+            return Err(format!(
+                "System poll interval {}ms is below minimum 50ms",
+                self.general.system_poll_interval_ms
+            ));
+        }
+        if self.watchdog.heartbeat_interval_ms < 500 {
+            return Err(format!(
+                "Heartbeat interval {}ms is below minimum 500ms",
+                self.watchdog.heartbeat_interval_ms
+            ));
+        }
+        for (name, seconds) in [
+            ("Steam", self.launch.steam_timeout_seconds),
+            ("Epic", self.launch.epic_timeout_seconds),
+            ("Standalone", self.launch.standalone_timeout_seconds),
+            ("Adaptive", self.launch.max_adaptive_timeout_seconds),
+        ] {
+            if !(MIN_LAUNCH_TIMEOUT_SECONDS..=MAX_LAUNCH_TIMEOUT_SECONDS).contains(&seconds) {
+                return Err(format!(
+                    "{name} launch timeout {seconds}s out of range ({MIN_LAUNCH_TIMEOUT_SECONDS} - {MAX_LAUNCH_TIMEOUT_SECONDS}s)"
+                ));
+            }
+        }
+        ColorProfile::warm(self.display.night_light_temperature_k).validate()?;
+        for profile in self.display.game_color_profiles.values() {
+            profile.validate()?;
+        }
+        for profile in self.display.game_scaling_profiles.values() {
+            profile.validate()?;
+        }
+        for profile in self.performance.game_power_profiles.values() {
+            profile.validate()?;
+        }
+        if let Some(curve) = &self.thermal.fan_curve {
+            curve.validate()?;
+        }
+        if self.idle.auto_suspend_minutes > MAX_AUTO_SUSPEND_MINUTES {
+            return Err(format!(
+                "Auto-suspend delay {} min is above maximum {MAX_AUTO_SUSPEND_MINUTES} min",
+                self.idle.auto_suspend_minutes
+            ));
+        }
+        if !(MIN_REPLAY_SECONDS..=MAX_REPLAY_SECONDS).contains(&self.replay.buffer_seconds) {
+            return Err(format!(
+                "Replay length {}s out of range ({MIN_REPLAY_SECONDS} - {MAX_REPLAY_SECONDS}s)",
+                self.replay.buffer_seconds
+            ));
+        }
+        if self.replay.fps != 30 && self.replay.fps != 60 {
+            return Err(format!("Replay frame rate must be 30 or 60, got {}", self.replay.fps));
+        }
+        if !(2_000..=50_000).contains(&self.replay.bitrate_kbps) {
+            return Err(format!(
+                "Replay bitrate {} kbit/s out of range (2000 - 50000)",
+                self.replay.bitrate_kbps
+            ));
+        }
+        if !(MIN_GYRO_SENSITIVITY..=MAX_GYRO_SENSITIVITY).contains(&self.controller.gyro_sensitivity) {
+            return Err(format!(
+                "Gyro sensitivity {} out of range ({MIN_GYRO_SENSITIVITY} - {MAX_GYRO_SENSITIVITY})",
+                self.controller.gyro_sensitivity
+            ));
+        }
+        if !(MIN_POLL_INTERVAL_MS..=MAX_POLL_INTERVAL_MS).contains(&self.controller.poll_interval_ms) {
+            return Err(format!(
+                "Controller poll interval {}ms out of range ({MIN_POLL_INTERVAL_MS} - {MAX_POLL_INTERVAL_MS}ms)",
+                self.controller.poll_interval_ms
+            ));
+        }
+        for (index, binding) in self.controller.extra_buttons.iter().enumerate() {
+            if self.controller.extra_buttons[..index]
+                .iter()
+                .any(|other| other.button == binding.button)
+            {
+                return Err(format!("{:?} is bound twice", binding.button));
+            }
+        }
+        for (event, pattern) in &self.haptics.event_patterns {
+            if HapticPattern::builtin(pattern).is_none() {
+                return Err(format!("Unknown haptic pattern '{pattern}' for {event:?}"));
+            }
+        }
+        if !self.updates.feed_url.starts_with("https://") {
+            return Err(format!("Update feed must be an https URL: {}", self.updates.feed_url));
+        }
+        if !self.telemetry.endpoint.is_empty() && !self.telemetry.endpoint.starts_with("https://") {
+            return Err(format!(
+                "Telemetry endpoint must be an https URL: {}",
+                self.telemetry.endpoint
+            ));
+        }
+        self.alerts.validate()?;
+        self.pip.validate()?;
+        self.dashboard.validate()?;
+        self.hooks.global.validate()?;
+        for hooks in self.hooks.games.values() {
+            hooks.validate()?;
+        }
+        if self.remote.port < MIN_REMOTE_PORT {
+            return Err(format!(
+                "Remote port {} is below minimum {MIN_REMOTE_PORT}",
+                self.remote.port
+            ));
+        }
+        if self.network.ping_target.trim().is_empty() {
+            return Err("Ping target cannot be empty".to_string());
+        }
+        if !(MIN_LATENCY_SPIKE_MS..=MAX_LATENCY_SPIKE_MS).contains(&self.network.latency_spike_ms) {
+            return Err(format!(
+                "Latency spike threshold {}ms out of range ({MIN_LATENCY_SPIKE_MS} - {MAX_LATENCY_SPIKE_MS}ms)",
+                self.network.latency_spike_ms
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            schema_version: SETTINGS_SCHEMA_VERSION,
+            general: GeneralSettings::default(),
+            hotkeys: HotkeySettings::default(),
+            overlay: OverlaySettings::default(),
+            library: LibrarySettings::default(),
+            watchdog: WatchdogSettings::default(),
+            launch: LaunchSettings::default(),
+            display: DisplaySettings::default(),
+            thermal: ThermalSettings::default(),
+            performance: PerformanceSettings::default(),
+            idle: IdleSettings::default(),
+            network: NetworkSettings::default(),
+            replay: ReplaySettings::default(),
+            controller: ControllerSettings::default(),
+            haptics: HapticSettings::default(),
+            updates: UpdateSettings::default(),
+            external_api: ExternalApiSettings::default(),
+            remote: RemoteSettings::default(),
+            telemetry: TelemetrySettings::default(),
+            hooks: HookSettings::default(),
+            alerts: AlertSettings::default(),
+            pip: PipSettings::default(),
+            dashboard: DashboardSettings::default(),
+        }
+    }
+}
+
+impl Default for GeneralSettings {
+    fn default() -> Self {
+        Self {
+            language: "es".to_string(),
+            system_poll_interval_ms: 250,
+        }
+    }
+}
+
+impl Default for HotkeySettings {
+    fn default() -> Self {
+        Self {
+            toggle_overlay: "Ctrl+Shift+Q".to_string(),
+            toggle_wifi_panel: "Ctrl+W".to_string(),
+            toggle_bluetooth_panel: "Ctrl+B".to_string(),
+            toggle_mic_mute: "Ctrl+Shift+M".to_string(),
+            mic_push_to_talk: false,
+            take_screenshot: "Ctrl+Shift+F12".to_string(),
+            save_replay: "Ctrl+Shift+F11".to_string(),
+            // Gamepad chord only by default
+            cycle_overlay_level: String::new(),
+            gamepad_chords: vec![
+                GamepadChordBinding {
+                    action: HotkeyAction::ToggleOverlay,
+                    buttons: vec![GamepadButton::LB, GamepadButton::RB, GamepadButton::Start],
+                },
+                GamepadChordBinding {
+                    action: HotkeyAction::CycleOverlayLevel,
+                    buttons: vec![GamepadButton::LB, GamepadButton::RB, GamepadButton::Back],
+                },
+            ],
+        }
+    }
+}
+
+impl Default for OverlaySettings {
+    fn default() -> Self {
+        Self {
+            opacity: 0.98,
+            click_through: false,
+            layout: OverlayLayout::default(),
+            level: OverlayLevel::default(),
+        }
+    }
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self {
+            night_light: false,
+            night_light_temperature_k: 4000,
+            game_color_profiles: HashMap::new(),
+            game_window_modes: HashMap::new(),
+            game_scaling_profiles: HashMap::new(),
+        }
+    }
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            ping_target: "1.1.1.1".to_string(),
+            latency_spike_ms: 150,
+        }
+    }
+}
+
+impl Default for ReplaySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            buffer_seconds: 30,
+            fps: 60,
+            bitrate_kbps: 15_000,
+        }
+    }
+}
+
+impl Default for ControllerSettings {
+    fn default() -> Self {
+        Self {
+            raw_hid: true,
+            extra_buttons: Vec::new(),
+            gyro_mouse: false,
+            touchpad_mouse: false,
+            gyro_sensitivity: 1.0,
+            windows_touch_keyboard: false,
+            poll_interval_ms: DEFAULT_POLL_INTERVAL_MS,
+        }
+    }
+}
+
+impl Default for HapticSettings {
+    fn default() -> Self {
+        Self {
+            event_patterns: BTreeMap::from([
+                (HapticEvent::Navigation, "navigation".to_string()),
+                (HapticEvent::Action, "action".to_string()),
+                (HapticEvent::Event, "event".to_string()),
+                (HapticEvent::Screenshot, "double-pulse".to_string()),
+                (HapticEvent::ReplaySaved, "heartbeat".to_string()),
+            ]),
+        }
+    }
+}
+
+impl Default for UpdateSettings {
+    fn default() -> Self {
+        Self {
+            feed_url: DEFAULT_UPDATE_FEED_URL.to_string(),
+            check_on_startup: true,
+        }
+    }
+}
+
+impl Default for RemoteSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: DEFAULT_REMOTE_PORT,
+        }
+    }
+}
+
+impl Default for WatchdogSettings {
+    fn default() -> Self {
+        Self {
+            heartbeat_interval_ms: 2000,
+        }
+    }
+}
+
+impl Default for LaunchSettings {
+    fn default() -> Self {
+        Self {
+            steam_timeout_seconds: STEAM_TIMEOUT_SECONDS,
+            epic_timeout_seconds: EPIC_TIMEOUT_SECONDS,
+            standalone_timeout_seconds: STANDALONE_TIMEOUT_SECONDS,
+            adaptive_timeouts: true,
+            max_adaptive_timeout_seconds: 600,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings_are_valid() {
+        let settings = AppSettings::default();
+        assert_eq!(settings.schema_version, SETTINGS_SCHEMA_VERSION);
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_invalid_opacity_rejected() {
+        let mut settings = AppSettings::default();
+        settings.overlay.opacity = 1.5;
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_update_feed_must_be_https() {
+        let mut settings = AppSettings::default();
+        settings.updates.feed_url = "http://example.com/latest.json".to_string();
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_partial_json_fills_defaults() {
+        let settings: AppSettings = serde_json::from_str(r#"{"overlay":{"opacity":0.5}}"#).unwrap();
+        assert!((settings.overlay.opacity - 0.5).abs() < f64::EPSILON);
+        assert!(!settings.overlay.click_through);
+        assert_eq!(settings.hotkeys.toggle_overlay, "Ctrl+Shift+Q");
+    }
+
+    #[test]
+    fn test_set_gamepad_chord_replaces_existing() {
+        let mut hotkeys = HotkeySettings::default();
+        hotkeys.set_gamepad_chord(HotkeyAction::CycleOverlayLevel, Vec::new());
+        hotkeys.set_gamepad_chord(
+            HotkeyAction::ToggleOverlay,
+            vec![GamepadButton::Back, GamepadButton::Start],
+        );
+
+        assert_eq!(hotkeys.gamepad_chords.len(), 1);
+        assert_eq!(
+            hotkeys.gamepad_chords[0].buttons,
+            vec![GamepadButton::Back, GamepadButton::Start]
+        );
+
+        hotkeys.set_gamepad_chord(HotkeyAction::ToggleOverlay, Vec::new());
+        assert!(hotkeys.gamepad_chords.is_empty());
+    }
+
+    #[test]
+    fn test_extra_button_bindings() {
+        let mut settings = AppSettings::default();
+        settings
+            .controller
+            .set_extra_button(ExtraButton::LeftPaddle, Some(NavAction::Back));
+        settings
+            .controller
+            .set_extra_button(ExtraButton::LeftPaddle, Some(NavAction::Menu));
+        assert_eq!(
+            settings.controller.extra_button_action(ExtraButton::LeftPaddle),
+            Some(NavAction::Menu)
+        );
+        assert!(settings.validate().is_ok());
+
+        settings.controller.extra_buttons.push(ExtraButtonBinding {
+            button: ExtraButton::LeftPaddle,
+            action: NavAction::Back,
+        });
+        assert!(settings.validate().is_err());
+
+        settings.controller.set_extra_button(ExtraButton::LeftPaddle, None);
+        assert!(settings.controller.extra_buttons.is_empty());
+    }
+
+    #[test]
+    fn test_effective_color_profile() {
+        let mut display = DisplaySettings::default();
+        assert_eq!(display.effective_profile(None), ColorProfile::neutral());
+
+        display.night_light = true;
+        let vivid = ColorProfile {
+            vibrance: 60,
+            ..ColorProfile::neutral()
+        };
+        display.game_color_profiles.insert("steam_570".to_string(), vivid);
+
+        assert_eq!(display.effective_profile(Some("steam_570")), vivid);
+        assert_eq!(display.effective_profile(Some("steam_730")), ColorProfile::warm(4000));
+    }
+}
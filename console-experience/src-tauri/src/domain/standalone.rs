@@ -0,0 +1,152 @@
+/// A game that ships its own launcher instead of living in a store.
+///
+/// Detected from the uninstall registry and well-known install folders, then
+/// started through its launcher (if any) and followed by process name, since
+/// the launcher is not the game process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StandaloneGameDescriptor {
+    /// Stable id suffix (`standalone_<key>`)
+    pub key: &'static str,
+    pub title: &'static str,
+    /// Uninstall registry subkeys whose `InstallLocation` is the install folder
+    pub uninstall_keys: &'static [&'static str],
+    /// Install folders probed when the registry has nothing (`%VAR%` expanded)
+    pub common_paths: &'static [&'static str],
+    /// Game executable, relative to the install folder (must exist)
+    pub executable: &'static str,
+    /// Launcher started instead of the executable, relative to the install folder
+    pub launcher: Option<&'static str>,
+    pub launch_args: &'static [&'static str],
+    /// Processes that mean the game is running
+    pub watch_processes: &'static [&'static str],
+}
+
+/// The standalone games Balam looks for.
+pub const STANDALONE_GAMES: &[StandaloneGameDescriptor] = &[
+    StandaloneGameDescriptor {
+        key: "minecraft",
+        title: "Minecraft",
+        uninstall_keys: &[],
+        common_paths: &[
+            "%ProgramFiles(x86)%\\Minecraft Launcher",
+            "%ProgramFiles%\\Minecraft Launcher",
+            "%LOCALAPPDATA%\\Programs\\Minecraft Launcher",
+        ],
+        executable: "MinecraftLauncher.exe",
+        launcher: None,
+        launch_args: &[],
+        // Java Edition runs in the JVM the launcher starts
+        watch_processes: &["javaw.exe"],
+    },
+    StandaloneGameDescriptor {
+        key: "genshin_impact",
+        title: "Genshin Impact",
+        uninstall_keys: &["Genshin Impact"],
+        common_paths: &[
+            "%ProgramFiles%\\HoYoPlay\\games\\Genshin Impact game",
+            "%ProgramFiles%\\Genshin Impact\\Genshin Impact Game",
+        ],
+        executable: "GenshinImpact.exe",
+        launcher: None,
+        launch_args: &[],
+        watch_processes: &["GenshinImpact.exe"],
+    },
+    StandaloneGameDescriptor {
+        key: "honkai_star_rail",
+        title: "Honkai: Star Rail",
+        uninstall_keys: &["Star Rail"],
+        common_paths: &[
+            "%ProgramFiles%\\HoYoPlay\\games\\Star Rail Games",
+            "%ProgramFiles%\\Star Rail\\Games",
+        ],
+        executable: "StarRail.exe",
+        launcher: None,
+        launch_args: &[],
+        watch_processes: &["StarRail.exe"],
+    },
+    StandaloneGameDescriptor {
+        key: "zenless_zone_zero",
+        title: "Zenless Zone Zero",
+        uninstall_keys: &[],
+        common_paths: &["%ProgramFiles%\\HoYoPlay\\games\\ZenlessZoneZero Game"],
+        executable: "ZenlessZoneZero.exe",
+        launcher: None,
+        launch_args: &[],
+        watch_processes: &["ZenlessZoneZero.exe"],
+    },
+    StandaloneGameDescriptor {
+        key: "league_of_legends",
+        title: "League of Legends",
+        uninstall_keys: &["Riot Game league_of_legends.live"],
+        common_paths: &["%SystemDrive%\\Riot Games\\League of Legends"],
+        executable: "LeagueClient.exe",
+        launcher: Some("..\\Riot Client\\RiotClientServices.exe"),
+        launch_args: &["--launch-product=league_of_legends", "--launch-patchline=live"],
+        watch_processes: &["LeagueClient.exe", "League of Legends.exe"],
+    },
+    StandaloneGameDescriptor {
+        key: "valorant",
+        title: "VALORANT",
+        uninstall_keys: &["Riot Game valorant.live"],
+        common_paths: &["%SystemDrive%\\Riot Games\\VALORANT\\live"],
+        executable: "VALORANT.exe",
+        launcher: Some("..\\..\\Riot Client\\RiotClientServices.exe"),
+        launch_args: &["--launch-product=valorant", "--launch-patchline=live"],
+        watch_processes: &["VALORANT-Win64-Shipping.exe"],
+    },
+];
+
+/// Descriptor of a standalone game by key.
+#[must_use]
+pub fn standalone_descriptor(key: &str) -> Option<&'static StandaloneGameDescriptor> {
+    STANDALONE_GAMES.iter().find(|descriptor| descriptor.key == key)
+}
+
+/// Expands `%VAR%` references with `lookup`; `None` if one is undefined.
+#[must_use]
+pub fn expand_env_vars(path: &str, lookup: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let mut expanded = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(start) = rest.find('%') {
+        let end = rest[start + 1..].find('%')? + start + 1;
+        expanded.push_str(&rest[..start]);
+        expanded.push_str(&lookup(&rest[start + 1..end])?);
+        rest = &rest[end + 1..];
+    }
+    expanded.push_str(rest);
+    Some(expanded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_env_vars() {
+        let lookup = |name: &str| match name {
+            "ProgramFiles" => Some("C:\\Program Files".to_string()),
+            "ProgramFiles(x86)" => Some("C:\\Program Files (x86)".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            expand_env_vars("%ProgramFiles(x86)%\\Minecraft Launcher", lookup).as_deref(),
+            Some("C:\\Program Files (x86)\\Minecraft Launcher")
+        );
+        assert_eq!(expand_env_vars("D:\\Games", lookup).as_deref(), Some("D:\\Games"));
+        assert_eq!(expand_env_vars("%MISSING%\\Games", lookup), None);
+        assert_eq!(expand_env_vars("%ProgramFiles\\Games", lookup), None);
+    }
+
+    #[test]
+    fn test_descriptor_table_is_consistent() {
+        for descriptor in STANDALONE_GAMES {
+            assert_eq!(standalone_descriptor(descriptor.key), Some(descriptor));
+            assert!(!descriptor.watch_processes.is_empty(), "{}", descriptor.key);
+            assert!(
+                descriptor.launcher.is_some() || descriptor.launch_args.is_empty(),
+                "{}",
+                descriptor.key
+            );
+        }
+    }
+}
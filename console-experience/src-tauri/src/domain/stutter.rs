@@ -0,0 +1,280 @@
+/// Stutter diagnostics
+///
+/// A stutter session samples the running game's frame time together with
+/// its disk reads and CPU usage. Frame time spikes are then matched with what
+/// happened at the same moment: a burst of disk reads (asset streaming) or a
+/// CPU jump without disk activity (typically shader compilation).
+///
+/// Frame times come from the FPS service's one-second window, so a hitch
+/// shows up as a dip lasting one or two samples rather than a single frame.
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+use crate::domain::performance::PerformanceMetrics;
+
+/// Time between samples (the FPS service refreshes every 500 ms).
+pub const STUTTER_SAMPLE_INTERVAL_MS: u64 = 500;
+/// A session stops on its own after this long.
+pub const MAX_STUTTER_SESSION_SECS: u32 = 3600;
+
+/// Previous samples the baseline is taken from (10 s).
+const BASELINE_SAMPLES: usize = 20;
+/// Samples needed before spikes are judged.
+const MIN_BASELINE_SAMPLES: usize = 4;
+/// Frame time this many times the baseline is a stutter...
+const SPIKE_RATIO: f32 = 1.5;
+/// ...if it is also this much longer (tiny spikes at high FPS don't count)
+const MIN_SPIKE_MS: f32 = 4.0;
+/// Disk reads of at least this rate, and `DISK_BURST_RATIO` times the baseline, are a burst.
+const MIN_DISK_BURST_BYTES_PER_SEC: u64 = 16 * 1024 * 1024;
+const DISK_BURST_RATIO: u64 = 4;
+/// CPU this many points above the baseline is a spike.
+const CPU_SPIKE_POINTS: f32 = 20.0;
+/// Earlier samples still matched with a spike (the FPS window lags by up to a second).
+const CORRELATION_LAG_SAMPLES: usize = 2;
+
+/// One reading of the game.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StutterSample {
+    /// Milliseconds since the session started
+    pub elapsed_ms: u64,
+    /// 0 while the game presents no frames (loading screens, menus in the background)
+    pub frame_time_ms: f32,
+    pub disk_read_bytes_per_sec: u64,
+    /// Game process CPU usage (system-wide without a tracked PID)
+    pub cpu_usage: f32,
+}
+
+impl StutterSample {
+    #[must_use]
+    pub fn from_metrics(elapsed_ms: u64, metrics: &PerformanceMetrics) -> Self {
+        let fps = metrics.fps.as_ref().map_or(0.0, |fps| fps.current_fps);
+        Self {
+            elapsed_ms,
+            frame_time_ms: if fps > 0.0 { 1000.0 / fps } else { 0.0 },
+            disk_read_bytes_per_sec: metrics.game.as_ref().map_or(0, |game| game.disk_read_bytes_per_sec),
+            cpu_usage: metrics.game.as_ref().map_or(metrics.cpu_usage, |game| game.cpu_usage),
+        }
+    }
+}
+
+/// What a stutter coincided with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StutterCause {
+    /// Burst of disk reads (asset streaming, level loading)
+    DiskRead,
+    /// CPU jump without disk reads, typical of shader compilation
+    CpuSpike,
+    Unexplained,
+}
+
+/// A frame time spike.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StutterEvent {
+    pub elapsed_ms: u64,
+    pub frame_time_ms: f32,
+    /// Typical frame time before the spike
+    pub baseline_frame_time_ms: f32,
+    pub cause: StutterCause,
+}
+
+fn median<T: Copy + PartialOrd>(mut values: Vec<T>) -> Option<T> {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    values.get(values.len() / 2).copied()
+}
+
+/// Finds frame time spikes and what they coincided with.
+///
+/// A spike spanning consecutive samples counts once, at its worst frame time.
+#[must_use]
+pub fn detect_stutters(samples: &[StutterSample]) -> Vec<StutterEvent> {
+    let mut stutters: Vec<StutterEvent> = Vec::new();
+    let mut in_spike = false;
+
+    for (index, sample) in samples.iter().enumerate() {
+        let history = &samples[index.saturating_sub(BASELINE_SAMPLES)..index];
+        let rendered: Vec<f32> = history.iter().map(|s| s.frame_time_ms).filter(|ms| *ms > 0.0).collect();
+        if sample.frame_time_ms <= 0.0 || rendered.len() < MIN_BASELINE_SAMPLES {
+            in_spike = false;
+            continue;
+        }
+        let baseline = median(rendered).unwrap_or_default();
+        let is_spike =
+            sample.frame_time_ms >= baseline * SPIKE_RATIO && sample.frame_time_ms - baseline >= MIN_SPIKE_MS;
+        if !is_spike {
+            in_spike = false;
+            continue;
+        }
+        if in_spike {
+            if let Some(last) = stutters.last_mut() {
+                last.frame_time_ms = last.frame_time_ms.max(sample.frame_time_ms);
+            }
+            continue;
+        }
+        in_spike = true;
+
+        let disk_baseline = median(history.iter().map(|s| s.disk_read_bytes_per_sec).collect()).unwrap_or_default();
+        let cpu_baseline = median(history.iter().map(|s| s.cpu_usage).collect()).unwrap_or_default();
+        let window = &samples[index.saturating_sub(CORRELATION_LAG_SAMPLES)..=index];
+        let cause = if window.iter().any(|s| {
+            s.disk_read_bytes_per_sec >= MIN_DISK_BURST_BYTES_PER_SEC
+                && s.disk_read_bytes_per_sec >= disk_baseline.saturating_mul(DISK_BURST_RATIO)
+        }) {
+            StutterCause::DiskRead
+        } else if window.iter().any(|s| s.cpu_usage >= cpu_baseline + CPU_SPIKE_POINTS) {
+            StutterCause::CpuSpike
+        } else {
+            StutterCause::Unexplained
+        };
+
+        stutters.push(StutterEvent {
+            elapsed_ms: sample.elapsed_ms,
+            frame_time_ms: sample.frame_time_ms,
+            baseline_frame_time_ms: baseline,
+            cause,
+        });
+    }
+    stutters
+}
+
+/// Result of a stutter session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StutterReport {
+    pub session_id: String,
+    pub game_id: String,
+    pub game_title: String,
+    /// Unix epoch seconds
+    pub started_at: u64,
+    pub duration_secs: u32,
+    /// The session is still sampling
+    pub recording: bool,
+    pub stutter_count: usize,
+    /// Share of stutters matched with disk read bursts (0 - 100)
+    pub disk_read_percent: u32,
+    /// Share of stutters matched with CPU spikes (0 - 100)
+    pub cpu_spike_percent: u32,
+    /// e.g. "23 stutters in 10 min, 78% correlated with disk reads"
+    pub summary: String,
+    pub stutters: Vec<StutterEvent>,
+}
+
+impl StutterReport {
+    #[must_use]
+    pub fn new(
+        session_id: String,
+        game_id: String,
+        game_title: String,
+        started_at: u64,
+        recording: bool,
+        samples: &[StutterSample],
+    ) -> Self {
+        let stutters = detect_stutters(samples);
+        let duration_secs = samples.last().map_or(0, |s| (s.elapsed_ms / 1000) as u32);
+        let percent = |cause: StutterCause| {
+            let count = stutters.iter().filter(|s| s.cause == cause).count();
+            if stutters.is_empty() {
+                0
+            } else {
+                ((count * 100 + stutters.len() / 2) / stutters.len()) as u32
+            }
+        };
+        let disk_read_percent = percent(StutterCause::DiskRead);
+        let cpu_spike_percent = percent(StutterCause::CpuSpike);
+
+        Self {
+            summary: summarize(stutters.len(), duration_secs, disk_read_percent, cpu_spike_percent),
+            session_id,
+            game_id,
+            game_title,
+            started_at,
+            duration_secs,
+            recording,
+            stutter_count: stutters.len(),
+            disk_read_percent,
+            cpu_spike_percent,
+            stutters,
+        }
+    }
+}
+
+fn summarize(count: usize, duration_secs: u32, disk_read_percent: u32, cpu_spike_percent: u32) -> String {
+    let duration = if duration_secs < 60 {
+        format!("{duration_secs} s")
+    } else {
+        format!("{} min", (duration_secs + 30) / 60)
+    };
+    match count {
+        0 => return format!("No stutters in {duration}"),
+        1 => return format!("1 stutter in {duration}"),
+        _ => {},
+    }
+    let mut summary = format!("{count} stutters in {duration}");
+    if disk_read_percent > 0 {
+        let _ = write!(summary, ", {disk_read_percent}% correlated with disk reads");
+    }
+    if cpu_spike_percent > 0 {
+        let _ = write!(
+            summary,
+            ", {cpu_spike_percent}% with CPU spikes (likely shader compilation)"
+        );
+    }
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(elapsed_ms: u64, frame_time_ms: f32, disk_mb: u64, cpu_usage: f32) -> StutterSample {
+        StutterSample {
+            elapsed_ms,
+            frame_time_ms,
+            disk_read_bytes_per_sec: disk_mb * 1024 * 1024,
+            cpu_usage,
+        }
+    }
+
+    #[test]
+    fn test_spikes_are_matched_with_disk_and_cpu() {
+        let mut samples: Vec<StutterSample> = (0..10).map(|i| sample(i * 500, 16.7, 1, 30.0)).collect();
+        // Disk burst a sample before a two-sample hitch: one stutter
+        samples.push(sample(5000, 16.7, 120, 35.0));
+        samples.push(sample(5500, 40.0, 2, 30.0));
+        samples.push(sample(6000, 55.0, 1, 30.0));
+        samples.extend((13..20).map(|i| sample(i * 500, 16.7, 1, 30.0)));
+        // CPU jump without disk reads
+        samples.push(sample(10_000, 33.3, 1, 75.0));
+        samples.extend((21..26).map(|i| sample(i * 500, 16.7, 1, 30.0)));
+        // Slightly slower frames are not a stutter
+        samples.push(sample(13_000, 19.0, 1, 30.0));
+
+        let stutters = detect_stutters(&samples);
+        assert_eq!(stutters.len(), 2);
+        assert_eq!(stutters[0].cause, StutterCause::DiskRead);
+        assert!((stutters[0].frame_time_ms - 55.0).abs() < f32::EPSILON);
+        assert_eq!(stutters[1].cause, StutterCause::CpuSpike);
+        assert!(detect_stutters(&samples[..3]).is_empty());
+    }
+
+    #[test]
+    fn test_report_summary() {
+        assert_eq!(summarize(0, 45, 0, 0), "No stutters in 45 s");
+        assert_eq!(
+            summarize(23, 600, 78, 13),
+            "23 stutters in 10 min, 78% correlated with disk reads, 13% with CPU spikes (likely shader compilation)"
+        );
+        assert_eq!(summarize(5, 125, 0, 0), "5 stutters in 2 min");
+
+        let report = StutterReport::new(
+            "s1".to_string(),
+            "steam_1".to_string(),
+            "Game".to_string(),
+            0,
+            false,
+            &[sample(0, 16.7, 0, 10.0), sample(90_000, 16.7, 0, 10.0)],
+        );
+        assert_eq!(report.duration_secs, 90);
+        assert_eq!(report.summary, "No stutters in 2 min");
+    }
+}
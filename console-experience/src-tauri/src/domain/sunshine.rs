@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Windows service installed by Sunshine.
+pub const SUNSHINE_SERVICE_NAME: &str = "SunshineService";
+/// Sunshine base port; the web UI (and its API) listens on the next one.
+pub const DEFAULT_SUNSHINE_PORT: u16 = 47989;
+/// Extra key marking the `apps.json` entries Balam manages (Sunshine ignores
+/// unknown keys), so a sync never touches apps the player added.
+pub const BALAM_APP_KEY: &str = "balam-id";
+
+/// Sunshine as seen from Balam.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SunshineStatus {
+    pub installed: bool,
+    pub install_dir: Option<String>,
+    pub service_running: bool,
+    /// Web UI, where pairing can also be done by hand
+    pub web_ui_url: Option<String>,
+    /// Library games currently in Sunshine's app list
+    pub synced_games: usize,
+}
+
+/// One streamable entry of Sunshine's `apps.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SunshineApp {
+    pub balam_id: String,
+    pub name: String,
+    /// Process Sunshine waits on (native games)
+    pub cmd: Option<String>,
+    /// Fire-and-forget launchers (store URLs), the stream stays up until quit
+    pub detached: Vec<String>,
+    pub working_dir: Option<String>,
+    /// Local PNG cover
+    pub image_path: Option<String>,
+}
+
+impl SunshineApp {
+    /// Entry in Sunshine's format.
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let mut entry = Map::new();
+        entry.insert("name".to_string(), Value::from(self.name.clone()));
+        entry.insert(BALAM_APP_KEY.to_string(), Value::from(self.balam_id.clone()));
+        if let Some(cmd) = &self.cmd {
+            entry.insert("cmd".to_string(), Value::from(cmd.clone()));
+        }
+        if !self.detached.is_empty() {
+            entry.insert("detached".to_string(), Value::from(self.detached.clone()));
+        }
+        if let Some(dir) = &self.working_dir {
+            entry.insert("working-dir".to_string(), Value::from(dir.clone()));
+        }
+        if let Some(image) = &self.image_path {
+            entry.insert("image-path".to_string(), Value::from(image.clone()));
+        }
+        Value::Object(entry)
+    }
+}
+
+fn is_balam_entry(entry: &Value) -> bool {
+    entry.get(BALAM_APP_KEY).is_some()
+}
+
+/// Replaces the Balam entries of an `apps.json` document with `apps`.
+///
+/// Entries the player created (Desktop, Steam Big Picture...) and every other
+/// key of the document are kept as they are.
+#[must_use]
+pub fn merge_apps(mut document: Value, apps: &[SunshineApp]) -> Value {
+    if !document.is_object() {
+        document = Value::Object(Map::new());
+    }
+    let Value::Object(root) = &mut document else {
+        unreachable!("document was just made an object");
+    };
+    let mut entries: Vec<Value> = match root.remove("apps") {
+        Some(Value::Array(entries)) => entries.into_iter().filter(|entry| !is_balam_entry(entry)).collect(),
+        _ => Vec::new(),
+    };
+    entries.extend(apps.iter().map(SunshineApp::to_json));
+    root.insert("apps".to_string(), Value::Array(entries));
+    document
+}
+
+/// Number of Balam entries in an `apps.json` document.
+#[must_use]
+pub fn count_balam_apps(document: &Value) -> usize {
+    document.get("apps").and_then(Value::as_array).map_or(0, |entries| {
+        entries.iter().filter(|entry| is_balam_entry(entry)).count()
+    })
+}
+
+/// Value of `key` in `sunshine.conf` (`key = value` lines, `#` comments).
+#[must_use]
+pub fn conf_value<'a>(conf: &'a str, key: &str) -> Option<&'a str> {
+    conf.lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .find(|(name, _)| name.trim() == key)
+        .map(|(_, value)| value.trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// Web UI port for a `sunshine.conf` (base `port` + 1).
+#[must_use]
+pub fn web_ui_port(conf: &str) -> u16 {
+    conf_value(conf, "port")
+        .and_then(|port| port.parse::<u16>().ok())
+        .unwrap_or(DEFAULT_SUNSHINE_PORT)
+        .saturating_add(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(id: &str) -> SunshineApp {
+        SunshineApp {
+            balam_id: id.to_string(),
+            name: id.to_uppercase(),
+            cmd: None,
+            detached: vec![format!("steam://rungameid/{id}")],
+            working_dir: None,
+            image_path: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_replaces_only_balam_entries() {
+        let document = serde_json::json!({
+            "env": { "PATH": "$(PATH)" },
+            "apps": [
+                { "name": "Desktop", "image-path": "desktop.png" },
+                { "name": "OLD", "balam-id": "steam_1" }
+            ]
+        });
+        let merged = merge_apps(document, &[app("steam_2"), app("steam_3")]);
+
+        let names: Vec<&str> = merged["apps"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["Desktop", "STEAM_2", "STEAM_3"]);
+        assert_eq!(merged["env"]["PATH"], "$(PATH)");
+        assert_eq!(count_balam_apps(&merged), 2);
+        assert_eq!(merged["apps"][1]["detached"][0], "steam://rungameid/steam_2");
+    }
+
+    #[test]
+    fn test_web_ui_port_from_conf() {
+        assert_eq!(web_ui_port(""), 47990);
+        assert_eq!(web_ui_port("# port = 1\nport = 50000\n"), 50001);
+        assert_eq!(
+            conf_value("file_apps = D:\\apps.json", "file_apps"),
+            Some("D:\\apps.json")
+        );
+    }
+}
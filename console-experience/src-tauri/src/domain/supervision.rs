@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Named pipe where the watchdog publishes the supervision status (JSON, one read per connection).
+pub const SUPERVISION_PIPE_NAME: &str = r"\\.\pipe\balam_supervision";
+
+/// First restart delay after a child stops.
+const BACKOFF_BASE: Duration = Duration::from_secs(1);
+/// Restart delay cap.
+const BACKOFF_MAX: Duration = Duration::from_secs(60);
+/// Failed starts in a row after which the supervisor stops trying.
+pub const MAX_START_FAILURES: u32 = 5;
+
+/// How a supervised child is started and checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChildKind {
+    /// Executable next to the watchdog, owned by the watchdog
+    Executable { file_name: String },
+    /// Windows Service controlled through the SCM (fps-service)
+    WindowsService { service_name: String },
+}
+
+/// When the supervisor restarts a child that is not running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RestartPolicy {
+    /// Restart whenever it stops
+    Always,
+    /// Restart only after a non-zero exit (clean exits are left alone)
+    OnFailure,
+    /// Report only
+    Never,
+}
+
+impl RestartPolicy {
+    /// Whether a child that stopped with `exit_success` should be restarted.
+    #[must_use]
+    pub fn should_restart(self, exit_success: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::OnFailure => !exit_success,
+            Self::Never => false,
+        }
+    }
+}
+
+/// Static description of a supervised child.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChildSpec {
+    /// Display/identifier name (e.g. `fps-service`)
+    pub name: String,
+    pub kind: ChildKind,
+    pub restart_policy: RestartPolicy,
+    /// Whether Balam is degraded while this child is down (optional helpers are not)
+    pub essential: bool,
+}
+
+/// Runtime state of a supervised child.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChildState {
+    Running,
+    /// Down, waiting for the backoff delay before the next restart
+    Restarting,
+    /// Down and not restarted (policy or clean exit)
+    Stopped,
+    /// Down and no longer restarted: starting failed too often or the
+    /// watchdog lacks the rights to; cleared once it runs again
+    Failed,
+    /// Binary or service is not installed
+    NotInstalled,
+}
+
+/// Status of one child as published on the supervision pipe.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChildStatus {
+    pub name: String,
+    pub state: ChildState,
+    pub essential: bool,
+    /// Restarts since the watchdog started
+    pub restarts: u32,
+    /// Failed starts in a row (drives the backoff)
+    pub consecutive_failures: u32,
+    /// Seconds until the next restart attempt (while `Restarting`)
+    pub next_retry_secs: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+impl ChildStatus {
+    #[must_use]
+    pub fn new(spec: &ChildSpec) -> Self {
+        Self {
+            name: spec.name.clone(),
+            state: ChildState::Stopped,
+            essential: spec.essential,
+            restarts: 0,
+            consecutive_failures: 0,
+            next_retry_secs: None,
+            last_error: None,
+        }
+    }
+}
+
+/// Snapshot of the whole supervised tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SupervisionStatus {
+    pub children: Vec<ChildStatus>,
+    /// An essential child is down (the UI shows "service degraded")
+    pub degraded: bool,
+}
+
+impl SupervisionStatus {
+    #[must_use]
+    pub fn new(children: Vec<ChildStatus>) -> Self {
+        // Not installed is a user choice, not a failure
+        let degraded = children.iter().any(|child| {
+            child.essential
+                && matches!(
+                    child.state,
+                    ChildState::Restarting | ChildState::Stopped | ChildState::Failed
+                )
+        });
+        Self { children, degraded }
+    }
+}
+
+/// Delay before the next restart after `consecutive_failures` failed starts
+/// (1s, 2s, 4s ... capped at 60s).
+#[must_use]
+pub fn restart_backoff(consecutive_failures: u32) -> Duration {
+    let factor = 2u32.saturating_pow(consecutive_failures.min(16));
+    BACKOFF_BASE.saturating_mul(factor).min(BACKOFF_MAX)
+}
+
+/// Whether to stop restarting a child after a failed start.
+///
+/// A permission error never clears by retrying (the unelevated watchdog
+/// cannot start a service), so it gives up at once.
+#[must_use]
+pub fn gives_up(consecutive_failures: u32, access_denied: bool) -> bool {
+    access_denied || consecutive_failures >= MAX_START_FAILURES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(essential: bool, state: ChildState) -> ChildStatus {
+        let spec = ChildSpec {
+            name: "helper".to_string(),
+            kind: ChildKind::Executable {
+                file_name: "helper.exe".to_string(),
+            },
+            restart_policy: RestartPolicy::Always,
+            essential,
+        };
+        ChildStatus {
+            state,
+            ..ChildStatus::new(&spec)
+        }
+    }
+
+    #[test]
+    fn test_restart_backoff() {
+        assert_eq!(restart_backoff(0), Duration::from_secs(1));
+        assert_eq!(restart_backoff(3), Duration::from_secs(8));
+        assert_eq!(restart_backoff(10), BACKOFF_MAX);
+        assert_eq!(restart_backoff(u32::MAX), BACKOFF_MAX);
+    }
+
+    #[test]
+    fn test_gives_up_on_access_denied_or_repeated_failures() {
+        assert!(!gives_up(1, false));
+        assert!(gives_up(1, true));
+        assert!(gives_up(MAX_START_FAILURES, false));
+        assert!(SupervisionStatus::new(vec![status(true, ChildState::Failed)]).degraded);
+    }
+
+    #[test]
+    fn test_restart_policy() {
+        assert!(RestartPolicy::Always.should_restart(true));
+        assert!(RestartPolicy::OnFailure.should_restart(false));
+        assert!(!RestartPolicy::OnFailure.should_restart(true));
+        assert!(!RestartPolicy::Never.should_restart(false));
+    }
+
+    #[test]
+    fn test_degraded_only_for_essential_children() {
+        let status_ok = SupervisionStatus::new(vec![
+            status(true, ChildState::Running),
+            status(false, ChildState::Restarting),
+        ]);
+        assert!(!status_ok.degraded);
+
+        let status_degraded = SupervisionStatus::new(vec![status(true, ChildState::Restarting)]);
+        assert!(status_degraded.degraded);
+
+        let status_not_installed = SupervisionStatus::new(vec![status(true, ChildState::NotInstalled)]);
+        assert!(!status_not_installed.degraded);
+    }
+}
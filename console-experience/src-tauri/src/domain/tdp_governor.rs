@@ -0,0 +1,179 @@
+/// Auto TDP governor
+///
+/// Closed loop that holds a target frame rate at the lowest TDP: while the
+/// game runs comfortably above the target the TDP steps down, when it falls
+/// below the TDP steps up (faster than it came down). After each change the
+/// governor waits for the frame rate to settle before deciding again.
+use serde::{Deserialize, Serialize};
+
+use crate::domain::performance::TDPConfig;
+
+pub const MIN_TARGET_FPS: u32 = 20;
+pub const MAX_TARGET_FPS: u32 = 240;
+/// Readings averaged per decision (one per governor tick).
+pub const FPS_WINDOW: usize = 4;
+/// Ticks to wait after a change before the next one.
+pub const SETTLE_TICKS: u32 = 3;
+/// Lower the TDP only above target × (1 + this).
+pub const HEADROOM: f32 = 0.08;
+/// Raise the TDP below target × (1 - this).
+pub const SHORTFALL: f32 = 0.03;
+pub const STEP_DOWN_WATTS: u32 = 1;
+pub const STEP_UP_WATTS: u32 = 2;
+
+/// What the player asked for in `start_auto_tdp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoTdpTarget {
+    pub target_fps: u32,
+    /// Never go below this (defaults to the hardware minimum)
+    pub min_watts: Option<u32>,
+    /// Never go above this (defaults to the hardware maximum)
+    pub max_watts: Option<u32>,
+}
+
+impl AutoTdpTarget {
+    /// Watt range the governor may use, clamped to what the hardware allows.
+    ///
+    /// # Errors
+    /// Returns error for a target FPS out of range or an empty watt range.
+    pub fn bounds(&self, hardware: &TDPConfig) -> Result<(u32, u32), String> {
+        if !(MIN_TARGET_FPS..=MAX_TARGET_FPS).contains(&self.target_fps) {
+            return Err(format!(
+                "Target {} FPS out of range ({MIN_TARGET_FPS} - {MAX_TARGET_FPS})",
+                self.target_fps
+            ));
+        }
+        let min = hardware.clamp(self.min_watts.unwrap_or(hardware.min_watts));
+        let max = hardware.clamp(self.max_watts.unwrap_or(hardware.max_watts));
+        if min > max {
+            return Err(format!("Minimum TDP {min}W is above maximum {max}W"));
+        }
+        Ok((min, max))
+    }
+}
+
+/// Live state, returned by `get_auto_tdp_status`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutoTdpStatus {
+    pub active: bool,
+    pub target_fps: u32,
+    pub current_watts: u32,
+    pub min_watts: u32,
+    pub max_watts: u32,
+    /// Averaged frame rate the last decision was based on
+    pub average_fps: Option<f32>,
+}
+
+/// The control loop's decisions, one tick at a time.
+#[derive(Debug, Clone)]
+pub struct TdpGovernor {
+    target_fps: f32,
+    min_watts: u32,
+    max_watts: u32,
+    watts: u32,
+    window: Vec<f32>,
+    settle: u32,
+}
+
+impl TdpGovernor {
+    /// Starts from `watts`, clamped into `[min_watts, max_watts]`.
+    #[must_use]
+    pub fn new(target_fps: u32, min_watts: u32, max_watts: u32, watts: u32) -> Self {
+        Self {
+            target_fps: target_fps as f32,
+            min_watts,
+            max_watts,
+            watts: watts.clamp(min_watts, max_watts),
+            window: Vec::with_capacity(FPS_WINDOW),
+            settle: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn watts(&self) -> u32 {
+        self.watts
+    }
+
+    /// Average of the readings collected since the last decision.
+    #[must_use]
+    pub fn average_fps(&self) -> Option<f32> {
+        (!self.window.is_empty()).then(|| self.window.iter().sum::<f32>() / self.window.len() as f32)
+    }
+
+    /// Records one frame rate reading; returns the new TDP when it should change.
+    ///
+    /// Ticks without a frame rate (menus, loading screens) hold the TDP.
+    pub fn tick(&mut self, fps: Option<f32>) -> Option<u32> {
+        if self.settle > 0 {
+            self.settle -= 1;
+            return None;
+        }
+        let fps = fps.filter(|fps| *fps > 0.0)?;
+        if self.window.len() == FPS_WINDOW {
+            self.window.remove(0);
+        }
+        self.window.push(fps);
+        if self.window.len() < FPS_WINDOW {
+            return None;
+        }
+
+        let average = self.average_fps()?;
+        let next = if average < self.target_fps * (1.0 - SHORTFALL) {
+            self.watts.saturating_add(STEP_UP_WATTS)
+        } else if average > self.target_fps * (1.0 + HEADROOM) {
+            self.watts.saturating_sub(STEP_DOWN_WATTS)
+        } else {
+            self.watts
+        }
+        .clamp(self.min_watts, self.max_watts);
+
+        if next == self.watts {
+            return None;
+        }
+        self.watts = next;
+        self.window.clear();
+        self.settle = SETTLE_TICKS;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(governor: &mut TdpGovernor, fps: f32, ticks: usize) -> Vec<u32> {
+        (0..ticks).filter_map(|_| governor.tick(Some(fps))).collect()
+    }
+
+    #[test]
+    fn test_governor_steps_and_clamps() {
+        let mut governor = TdpGovernor::new(60, 8, 15, 15);
+
+        // Well above target: steps down 1W per settled window, never below 8W
+        let changes = run(&mut governor, 90.0, 100);
+        assert_eq!(changes, [14, 13, 12, 11, 10, 9, 8]);
+
+        // Inside the band: hold
+        let mut governor = TdpGovernor::new(60, 8, 15, 12);
+        assert!(run(&mut governor, 62.0, 20).is_empty());
+        assert!(governor.tick(None).is_none());
+
+        // Below target: steps up 2W, clamped at 15W
+        assert_eq!(run(&mut governor, 50.0, 30), [14, 15]);
+        assert_eq!(governor.watts(), 15);
+
+        let hardware = TDPConfig::new(15, 5, 30).unwrap();
+        let target = AutoTdpTarget {
+            target_fps: 60,
+            min_watts: Some(2),
+            max_watts: None,
+        };
+        assert_eq!(target.bounds(&hardware), Ok((5, 30)));
+        assert!(AutoTdpTarget {
+            target_fps: 5,
+            ..target
+        }
+        .bounds(&hardware)
+        .is_err());
+    }
+}
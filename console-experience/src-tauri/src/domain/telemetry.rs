@@ -0,0 +1,192 @@
+/// Telemetry domain
+///
+/// Opt-in, anonymous usage counters: how often launches succeed per store,
+/// how long library scans take and how often Balam crashes. Only counters
+/// leave the machine - never titles, paths, game IDs or error messages - and
+/// the exact batch can be inspected before anything is sent.
+use crate::domain::value_objects::GameSource;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Version of the batch format, bumped when fields change meaning.
+pub const TELEMETRY_SCHEMA_VERSION: u32 = 1;
+
+/// Why a launch failed, as a fixed category (messages can contain paths).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchFailureKind {
+    /// Download or update still in progress
+    NotReady,
+    /// Executable or install folder missing
+    MissingFiles,
+    /// The store client or process launcher refused or failed
+    LauncherError,
+}
+
+/// Something worth counting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TelemetryEvent {
+    Launch {
+        source: GameSource,
+        failure: Option<LaunchFailureKind>,
+    },
+    Scan {
+        duration_ms: u64,
+        game_count: usize,
+    },
+    /// Previous session ended without a clean exit
+    Crash,
+}
+
+/// Launch outcomes for one store.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchCounts {
+    pub succeeded: u32,
+    pub failed: BTreeMap<LaunchFailureKind, u32>,
+}
+
+/// Library scan timings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScanStats {
+    pub count: u32,
+    pub total_ms: u64,
+    pub max_ms: u64,
+    /// Library size of the latest scan (rounded to tens, not an exact fingerprint)
+    pub library_size: usize,
+}
+
+/// Counters collected since the last upload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelemetryCounters {
+    /// Unix epoch seconds of the first event, 0 while empty
+    pub period_start: u64,
+    /// Store (`steam`, `epic`, ...) → outcomes
+    pub launches: BTreeMap<String, LaunchCounts>,
+    pub scans: ScanStats,
+    pub crashes: u32,
+}
+
+impl TelemetryCounters {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.launches.is_empty() && self.scans.count == 0 && self.crashes == 0
+    }
+
+    pub fn record(&mut self, event: &TelemetryEvent, now: u64) {
+        if self.is_empty() {
+            self.period_start = now;
+        }
+        match event {
+            TelemetryEvent::Launch { source, failure } => {
+                let store = source.id_prefix().trim_end_matches('_').to_string();
+                let counts = self.launches.entry(store).or_default();
+                match failure {
+                    Some(kind) => *counts.failed.entry(*kind).or_default() += 1,
+                    None => counts.succeeded += 1,
+                }
+            },
+            TelemetryEvent::Scan {
+                duration_ms,
+                game_count,
+            } => {
+                self.scans.count += 1;
+                self.scans.total_ms += duration_ms;
+                self.scans.max_ms = self.scans.max_ms.max(*duration_ms);
+                self.scans.library_size = game_count.div_ceil(10) * 10;
+            },
+            TelemetryEvent::Crash => self.crashes += 1,
+        }
+    }
+
+    /// Adds `other` back in (counters of an upload that failed).
+    pub fn merge(&mut self, other: TelemetryCounters) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() || other.period_start < self.period_start {
+            self.period_start = other.period_start;
+        }
+        for (store, counts) in other.launches {
+            let entry = self.launches.entry(store).or_default();
+            entry.succeeded += counts.succeeded;
+            for (kind, count) in counts.failed {
+                *entry.failed.entry(kind).or_default() += count;
+            }
+        }
+        self.scans.count += other.scans.count;
+        self.scans.total_ms += other.scans.total_ms;
+        self.scans.max_ms = self.scans.max_ms.max(other.scans.max_ms);
+        if self.scans.library_size == 0 {
+            self.scans.library_size = other.scans.library_size;
+        }
+        self.crashes += other.crashes;
+    }
+}
+
+/// Exactly what is uploaded to the configured endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryBatch {
+    pub schema_version: u32,
+    /// Random per-install ID, only used to drop duplicate uploads
+    pub install_id: String,
+    pub app_version: String,
+    pub os: String,
+    /// Unix epoch seconds
+    pub period_end: u64,
+    pub counters: TelemetryCounters,
+}
+
+/// What `get_telemetry_preview` shows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TelemetryPreview {
+    pub enabled: bool,
+    /// `None` when no endpoint is configured (nothing is sent)
+    pub endpoint: Option<String>,
+    pub batch: TelemetryBatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counters_aggregate_without_identifiers() {
+        let mut counters = TelemetryCounters::default();
+        assert!(counters.is_empty());
+
+        counters.record(
+            &TelemetryEvent::Launch {
+                source: GameSource::Steam,
+                failure: None,
+            },
+            100,
+        );
+        counters.record(
+            &TelemetryEvent::Launch {
+                source: GameSource::Steam,
+                failure: Some(LaunchFailureKind::LauncherError),
+            },
+            200,
+        );
+        counters.record(
+            &TelemetryEvent::Scan {
+                duration_ms: 900,
+                game_count: 143,
+            },
+            300,
+        );
+        counters.record(&TelemetryEvent::Crash, 400);
+
+        assert_eq!(counters.period_start, 100);
+        assert_eq!(counters.launches["steam"].succeeded, 1);
+        assert_eq!(counters.launches["steam"].failed[&LaunchFailureKind::LauncherError], 1);
+        assert_eq!((counters.scans.max_ms, counters.scans.library_size), (900, 150));
+        assert_eq!(counters.crashes, 1);
+
+        let json = serde_json::to_value(&counters).unwrap();
+        assert_eq!(json["launches"]["steam"]["failed"]["launcher_error"], 1);
+    }
+}
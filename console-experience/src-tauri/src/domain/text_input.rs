@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+
+/// Longest text `send_virtual_text` types in one call.
+pub const MAX_VIRTUAL_TEXT_CHARS: usize = 1024;
+
+/// `<input>` types that take no typed text (the frontend keyboard skips
+/// the same list).
+const NON_TEXT_INPUT_TYPES: &[&str] = &[
+    "file",
+    "checkbox",
+    "radio",
+    "range",
+    "color",
+    "date",
+    "datetime-local",
+    "month",
+    "time",
+    "week",
+    "submit",
+    "button",
+    "reset",
+    "image",
+    "hidden",
+];
+
+/// Which on-screen keyboard handles controller text entry.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardBackend {
+    /// The Windows touch keyboard (TabTip / TextInputHost)
+    Windows,
+    /// Balam's own keyboard, drawn by the frontend
+    Balam,
+}
+
+impl KeyboardBackend {
+    /// Backend for the current session.
+    ///
+    /// The Windows touch keyboard is hosted by explorer, so in shell
+    /// replacement mode only Balam's keyboard is available.
+    #[must_use]
+    pub fn choose(shell_mode: bool, prefer_windows: bool) -> Self {
+        if prefer_windows && !shell_mode {
+            Self::Windows
+        } else {
+            Self::Balam
+        }
+    }
+}
+
+/// Text entry state, sent to the frontend as `text-input-changed`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct TextInputState {
+    /// A text field of the WebView has the focus
+    pub focused: bool,
+    /// `type` of the focused `<input>` (`None` for a textarea or editable element)
+    pub input_type: Option<String>,
+    pub backend: KeyboardBackend,
+    /// The keyboard of `backend` should be on screen
+    pub keyboard_visible: bool,
+}
+
+impl TextInputState {
+    /// State after the WebView reports a focus change.
+    #[must_use]
+    pub fn resolve(focused: bool, input_type: Option<String>, backend: KeyboardBackend) -> Self {
+        let keyboard_visible = focused && input_type.as_deref().is_none_or(takes_text);
+        Self {
+            focused,
+            input_type,
+            backend,
+            keyboard_visible,
+        }
+    }
+}
+
+/// Whether an `<input type>` is typed into.
+#[must_use]
+pub fn takes_text(input_type: &str) -> bool {
+    !NON_TEXT_INPUT_TYPES
+        .iter()
+        .any(|excluded| input_type.eq_ignore_ascii_case(excluded))
+}
+
+/// Editing keys Balam's keyboard sends to the foreground window.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VirtualKey {
+    Backspace,
+    Delete,
+    Enter,
+    Tab,
+    Escape,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+}
+
+impl VirtualKey {
+    /// Win32 virtual-key code.
+    #[must_use]
+    pub fn code(self) -> u16 {
+        match self {
+            Self::Backspace => 0x08,
+            Self::Tab => 0x09,
+            Self::Enter => 0x0D,
+            Self::Escape => 0x1B,
+            Self::End => 0x23,
+            Self::Home => 0x24,
+            Self::Left => 0x25,
+            Self::Up => 0x26,
+            Self::Right => 0x27,
+            Self::Down => 0x28,
+            Self::Delete => 0x2E,
+        }
+    }
+
+    /// Arrows and Home/End are extended keys (otherwise they arrive as numpad keys).
+    #[must_use]
+    pub fn is_extended(self) -> bool {
+        matches!(
+            self,
+            Self::Left | Self::Right | Self::Up | Self::Down | Self::Home | Self::End | Self::Delete
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyboard_only_for_text_fields() {
+        let backend = KeyboardBackend::choose(false, true);
+        assert_eq!(backend, KeyboardBackend::Windows);
+        assert!(TextInputState::resolve(true, Some("search".to_string()), backend).keyboard_visible);
+        assert!(TextInputState::resolve(true, None, backend).keyboard_visible);
+        assert!(!TextInputState::resolve(true, Some("Checkbox".to_string()), backend).keyboard_visible);
+        assert!(!TextInputState::resolve(false, Some("text".to_string()), backend).keyboard_visible);
+
+        // No explorer, no touch keyboard
+        assert_eq!(KeyboardBackend::choose(true, true), KeyboardBackend::Balam);
+    }
+}
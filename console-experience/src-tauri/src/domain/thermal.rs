@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of points in a fan curve (the ASUS EC format; other devices are resampled).
+pub const FAN_CURVE_POINTS: usize = 8;
+
+/// Above this temperature every curve must run the fan at least at [`MIN_HOT_FAN_PERCENT`].
+pub const HOT_TEMP_C: u8 = 80;
+pub const MIN_HOT_FAN_PERCENT: u8 = 60;
+
+/// Software control loops force full speed at or above this temperature.
+pub const EMERGENCY_TEMP_C: f32 = 90.0;
+
+/// Handhelds with a known fan control interface.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HandheldModel {
+    /// ASUS ROG Ally / Ally X (ATK ACPI fan curves)
+    RogAlly,
+    /// Lenovo Legion Go (Lenovo WMI fan table)
+    LegionGo,
+    /// GPD Win 4 / Win Max 2 (EC fan duty)
+    GpdWin,
+    Unknown,
+}
+
+impl HandheldModel {
+    /// Identifies the device from the SMBIOS manufacturer and product name.
+    #[must_use]
+    pub fn from_smbios(manufacturer: &str, product: &str) -> Self {
+        let manufacturer = manufacturer.to_lowercase();
+        let product = product.to_lowercase();
+        if manufacturer.contains("asus") && (product.contains("rc71") || product.contains("rc72")) {
+            Self::RogAlly
+        } else if manufacturer.contains("lenovo") && (product.contains("83e1") || product.contains("legion go")) {
+            Self::LegionGo
+        } else if manufacturer.contains("gpd") && (product.contains("g1618") || product.contains("g1619")) {
+            Self::GpdWin
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// One point of a fan curve.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FanCurvePoint {
+    pub temp_c: u8,
+    /// Fan duty (0-100)
+    pub fan_percent: u8,
+}
+
+/// Temperature → fan duty mapping, linear between points.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FanCurve {
+    pub points: Vec<FanCurvePoint>,
+}
+
+impl Default for FanCurve {
+    /// Quiet at idle, full speed from 90°C.
+    fn default() -> Self {
+        let points = [
+            (40, 0),
+            (50, 20),
+            (60, 30),
+            (65, 40),
+            (70, 50),
+            (75, 60),
+            (80, 75),
+            (90, 100),
+        ];
+        Self {
+            points: points
+                .iter()
+                .map(|&(temp_c, fan_percent)| FanCurvePoint { temp_c, fan_percent })
+                .collect(),
+        }
+    }
+}
+
+impl FanCurve {
+    /// Checks the curve is usable and cannot let the device overheat.
+    ///
+    /// # Errors
+    /// Returns error if the curve does not have 8 points, temperatures are
+    /// not strictly increasing, duty decreases, or the fan runs below
+    /// [`MIN_HOT_FAN_PERCENT`] at or above [`HOT_TEMP_C`].
+    pub fn validate(&self) -> Result<(), String> {
+        if self.points.len() != FAN_CURVE_POINTS {
+            return Err(format!(
+                "Fan curve needs {FAN_CURVE_POINTS} points, got {}",
+                self.points.len()
+            ));
+        }
+        for pair in self.points.windows(2) {
+            if pair[1].temp_c <= pair[0].temp_c {
+                return Err("Fan curve temperatures must increase".to_string());
+            }
+            if pair[1].fan_percent < pair[0].fan_percent {
+                return Err("Fan speed cannot drop as temperature rises".to_string());
+            }
+        }
+        for point in &self.points {
+            if point.fan_percent > 100 || point.temp_c > 100 {
+                return Err(format!(
+                    "Fan curve point {}°C/{}% out of range",
+                    point.temp_c, point.fan_percent
+                ));
+            }
+            if point.temp_c >= HOT_TEMP_C && point.fan_percent < MIN_HOT_FAN_PERCENT {
+                return Err(format!(
+                    "Fan must run at least {MIN_HOT_FAN_PERCENT}% from {HOT_TEMP_C}°C (got {}% at {}°C)",
+                    point.fan_percent, point.temp_c
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fan duty for a temperature (flat beyond the first and last point).
+    #[must_use]
+    pub fn duty_at(&self, temp_c: f32) -> u8 {
+        if temp_c >= EMERGENCY_TEMP_C {
+            return 100;
+        }
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return 100;
+        };
+        if temp_c <= f32::from(first.temp_c) {
+            return first.fan_percent;
+        }
+        if temp_c >= f32::from(last.temp_c) {
+            return last.fan_percent;
+        }
+
+        self.points
+            .windows(2)
+            .find(|pair| temp_c <= f32::from(pair[1].temp_c))
+            .map_or(last.fan_percent, |pair| {
+                let (low, high) = (pair[0], pair[1]);
+                let span = f32::from(high.temp_c - low.temp_c);
+                let ratio = (temp_c - f32::from(low.temp_c)) / span;
+                let duty =
+                    f32::from(low.fan_percent) + ratio * (f32::from(high.fan_percent) - f32::from(low.fan_percent));
+                duty.round() as u8
+            })
+    }
+}
+
+/// Fan state shown in Quick Settings.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct FanStatus {
+    pub model: HandheldModel,
+    /// Fan speed, `None` if the device does not report it
+    pub rpm: Option<u32>,
+    /// CPU temperature used by the curve, if available
+    pub cpu_temp_c: Option<f32>,
+    /// `true` while a custom curve is applied (otherwise the firmware curve runs)
+    pub custom_curve_active: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_curve_is_valid() {
+        assert!(FanCurve::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_unsafe_curves() {
+        let mut silent_when_hot = FanCurve::default();
+        silent_when_hot.points[7].fan_percent = 50;
+        silent_when_hot.points[6].fan_percent = 50;
+        assert!(silent_when_hot.validate().is_err());
+
+        let mut unordered = FanCurve::default();
+        unordered.points.swap(0, 1);
+        assert!(unordered.validate().is_err());
+    }
+
+    #[test]
+    fn test_duty_interpolates_and_forces_full_speed_when_hot() {
+        let curve = FanCurve::default();
+        assert_eq!(curve.duty_at(30.0), 0);
+        assert_eq!(curve.duty_at(55.0), 25);
+        assert_eq!(curve.duty_at(85.0), 88);
+        assert_eq!(curve.duty_at(92.0), 100);
+    }
+
+    #[test]
+    fn test_model_from_smbios() {
+        assert_eq!(
+            HandheldModel::from_smbios("ASUSTeK COMPUTER INC.", "ROG Ally RC71L_RC71L"),
+            HandheldModel::RogAlly
+        );
+        assert_eq!(HandheldModel::from_smbios("LENOVO", "83E1"), HandheldModel::LegionGo);
+        assert_eq!(
+            HandheldModel::from_smbios("Dell Inc.", "XPS 13"),
+            HandheldModel::Unknown
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Release feed used when the settings do not override it.
+pub const DEFAULT_UPDATE_FEED_URL: &str =
+    "https://github.com/jahziel1/BALAM-GAMING-MODE/releases/latest/download/latest.json";
+
+/// Starts `watchdog.exe` as the update helper instead of the supervisor.
+pub const UPDATE_HELPER_FLAG: &str = "--apply-update";
+/// Install directory handed to a helper that runs from a copy (the helper
+/// never takes it from the update marker, which the user can rewrite).
+pub const UPDATE_INSTALL_DIR_FLAG: &str = "--install-dir";
+
+/// Semantic version (`major.minor.patch`, optional `v` prefix).
+///
+/// Pre-release and build suffixes (`-beta.1`, `+abc`) are accepted but ignored
+/// for ordering: the feed only ever publishes stable releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parses `1.2.3` / `v1.2.3`.
+    ///
+    /// # Errors
+    /// Returns an error when a component is missing or not a number.
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let core = value
+            .trim()
+            .trim_start_matches('v')
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default();
+        let mut parts = core.split('.').map(str::parse::<u64>);
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), Some(Ok(minor)), Some(Ok(patch)), None) => Ok(Self { major, minor, patch }),
+            _ => Err(format!("Invalid version: {value}")),
+        }
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// One downloadable file of a release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpdateArtifact {
+    pub url: String,
+    /// Size in bytes, checked before hashing
+    pub size: u64,
+    /// Lowercase hex SHA-256 of the file
+    pub sha256: String,
+    /// Minisign signature (full `.minisig` text) of the file
+    pub signature: String,
+}
+
+/// Zip with only the files that changed since `from_version`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DeltaArtifact {
+    pub from_version: String,
+    #[serde(flatten)]
+    pub artifact: UpdateArtifact,
+}
+
+/// `latest.json` published next to every release.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReleaseManifest {
+    pub version: String,
+    #[serde(default)]
+    pub notes: String,
+    #[serde(default)]
+    pub published_at: Option<String>,
+    /// NSIS installer (Balam, watchdog and fps-service)
+    pub full: UpdateArtifact,
+    #[serde(default)]
+    pub deltas: Vec<DeltaArtifact>,
+}
+
+/// How the update is applied.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PackageKind {
+    /// Silent run of the full installer
+    Full,
+    /// Changed files copied over the install directory
+    Delta,
+}
+
+impl ReleaseManifest {
+    /// Whether this release is newer than `current`.
+    ///
+    /// # Errors
+    /// Returns an error when either version cannot be parsed.
+    pub fn is_newer_than(&self, current: &str) -> Result<bool, String> {
+        Ok(Version::parse(&self.version)?.cmp(&Version::parse(current)?) == Ordering::Greater)
+    }
+
+    /// Smallest package that upgrades `current`: its delta when one is
+    /// published, the full installer otherwise.
+    #[must_use]
+    pub fn package_for(&self, current: &str) -> (PackageKind, &UpdateArtifact) {
+        let current = Version::parse(current).ok();
+        self.deltas
+            .iter()
+            .find(|delta| current.is_some() && Version::parse(&delta.from_version).ok() == current)
+            .map_or((PackageKind::Full, &self.full), |delta| {
+                (PackageKind::Delta, &delta.artifact)
+            })
+    }
+}
+
+/// Result of `check_for_updates`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct UpdateInfo {
+    pub current_version: String,
+    pub latest_version: String,
+    pub available: bool,
+    pub notes: String,
+    pub published_at: Option<String>,
+    /// Package `apply_update` would download
+    pub package: PackageKind,
+    pub download_bytes: u64,
+}
+
+/// Step of `apply_update`, sent with every `update-progress` event.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateStage {
+    Downloading,
+    Verifying,
+    /// Balam is about to exit; the helper takes over from here
+    Restarting,
+}
+
+/// Payload of the `update-progress` event.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub struct UpdateProgress {
+    pub stage: UpdateStage,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Handoff file written before Balam exits for an update.
+///
+/// The helper reads it to know what to install and how to relaunch, and the
+/// watchdog treats Balam's exit as planned (not a crash) while it exists.
+/// It lives in a folder the user can write to: an elevated helper checks the
+/// package against `sha256` and `signature` again and never trusts a path
+/// from it beyond the package to copy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UpdateMarker {
+    pub version: String,
+    /// Verified package in the updates directory
+    pub package_path: String,
+    pub kind: PackageKind,
+    /// Expected SHA-256 of the package (hex)
+    pub sha256: String,
+    /// Minisign signature of the package
+    pub signature: String,
+    /// Install through the elevated helper (fps-service installed or Balam elevated)
+    pub elevate: bool,
+    /// Balam process to wait for before touching the install directory
+    pub balam_pid: u32,
+    /// Relaunch through `watchdog.exe --shell` instead of the app directly
+    pub shell_mode: bool,
+    /// Restart fps-service after installing
+    pub fps_service_was_running: bool,
+    /// Unix seconds; a marker older than [`UpdateMarker::STALE_AFTER_SECS`] is ignored
+    pub created_at_secs: u64,
+}
+
+impl UpdateMarker {
+    /// An update that has not finished within this time is considered dead.
+    pub const STALE_AFTER_SECS: u64 = 15 * 60;
+
+    #[must_use]
+    pub fn is_stale(&self, now_secs: u64) -> bool {
+        now_secs.saturating_sub(self.created_at_secs) > Self::STALE_AFTER_SECS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn artifact(url: &str) -> UpdateArtifact {
+        UpdateArtifact {
+            url: url.to_string(),
+            size: 1,
+            sha256: String::new(),
+            signature: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        assert!(Version::parse("v1.10.0").unwrap() > Version::parse("1.9.3").unwrap());
+        assert_eq!(
+            Version::parse("2.0.0-beta.1").unwrap(),
+            Version::parse("2.0.0").unwrap()
+        );
+        assert!(Version::parse("1.2").is_err());
+        assert!(Version::parse("1.2.x").is_err());
+    }
+
+    #[test]
+    fn test_package_prefers_matching_delta() {
+        let manifest = ReleaseManifest {
+            version: "1.3.0".to_string(),
+            notes: String::new(),
+            published_at: None,
+            full: artifact("full.exe"),
+            deltas: vec![DeltaArtifact {
+                from_version: "1.2.0".to_string(),
+                artifact: artifact("delta-1.2.0.zip"),
+            }],
+        };
+
+        assert!(manifest.is_newer_than("1.2.0").unwrap());
+        assert!(!manifest.is_newer_than("1.3.0").unwrap());
+        assert_eq!(
+            manifest.package_for("v1.2.0"),
+            (PackageKind::Delta, &manifest.deltas[0].artifact)
+        );
+        // Skipped a release: only the full installer applies
+        assert_eq!(manifest.package_for("1.1.0"), (PackageKind::Full, &manifest.full));
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+/// Explicit user corrections to game de-duplication, respected on every scan.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct DedupOverrides {
+    /// Groups of game IDs that are the same game (only the first found is listed)
+    pub merges: Vec<Vec<String>>,
+    /// Game IDs never merged with another game
+    pub splits: Vec<String>,
+}
+
+/// User correction applying to one game.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupOverrideKind {
+    Merged,
+    Split,
+}
+
+impl DedupOverrides {
+    /// Records `ids` as one game, joining any group one of them is already in.
+    ///
+    /// # Errors
+    /// Returns error if fewer than two distinct games are given.
+    pub fn merge(&mut self, ids: &[String]) -> Result<(), String> {
+        let mut group: Vec<String> = Vec::new();
+        for id in ids {
+            if !group.contains(id) {
+                group.push(id.clone());
+            }
+        }
+        if group.len() < 2 {
+            return Err("Select at least two games to merge".to_string());
+        }
+
+        self.splits.retain(|id| !group.contains(id));
+        let (joined, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.merges)
+            .into_iter()
+            .partition(|existing| existing.iter().any(|id| group.contains(id)));
+        for id in joined.into_iter().flatten() {
+            if !group.contains(&id) {
+                group.push(id);
+            }
+        }
+        self.merges = rest;
+        self.merges.push(group);
+        Ok(())
+    }
+
+    /// Records `id` as its own game, removing it from any merge group.
+    pub fn split(&mut self, id: &str) {
+        for group in &mut self.merges {
+            group.retain(|member| member != id);
+        }
+        self.merges.retain(|group| group.len() > 1);
+        if !self.splits.iter().any(|split| split == id) {
+            self.splits.push(id.to_string());
+        }
+    }
+
+    /// Merge group containing `id`.
+    #[must_use]
+    pub fn merge_group(&self, id: &str) -> Option<&[String]> {
+        self.merges
+            .iter()
+            .find(|group| group.iter().any(|member| member == id))
+            .map(Vec::as_slice)
+    }
+
+    /// User correction applying to `id`, if any.
+    #[must_use]
+    pub fn kind(&self, id: &str) -> Option<DedupOverrideKind> {
+        if self.splits.iter().any(|split| split == id) {
+            Some(DedupOverrideKind::Split)
+        } else {
+            self.merge_group(id).map(|_| DedupOverrideKind::Merged)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_merge_joins_existing_groups() {
+        let mut overrides = DedupOverrides::default();
+        overrides.merge(&ids(&["steam_1", "epic_a"])).unwrap();
+        overrides.merge(&ids(&["epic_a", "xbox_x"])).unwrap();
+
+        assert_eq!(overrides.merges, vec![ids(&["epic_a", "xbox_x", "steam_1"])]);
+        assert!(overrides.merge(&ids(&["steam_1", "steam_1"])).is_err());
+    }
+
+    #[test]
+    fn test_split_leaves_group() {
+        let mut overrides = DedupOverrides::default();
+        overrides.merge(&ids(&["steam_1", "epic_a"])).unwrap();
+        overrides.split("epic_a");
+
+        assert!(overrides.merges.is_empty());
+        assert_eq!(overrides.kind("epic_a"), Some(DedupOverrideKind::Split));
+        assert_eq!(overrides.kind("steam_1"), None);
+
+        overrides.merge(&ids(&["steam_1", "epic_a"])).unwrap();
+        assert_eq!(overrides.kind("epic_a"), Some(DedupOverrideKind::Merged));
+    }
+}
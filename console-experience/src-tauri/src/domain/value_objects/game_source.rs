@@ -12,6 +12,8 @@ pub enum GameSource {
     Xbox,
     /// Battle.net / Blizzard Entertainment
     BattleNet,
+    /// itch.io app (butler)
+    Itch,
     /// Manually added by user
     Manual,
 }
@@ -25,6 +27,7 @@ impl GameSource {
             Self::Epic => "Epic Games",
             Self::Xbox => "Xbox",
             Self::BattleNet => "Battle.net",
+            Self::Itch => "itch.io",
             Self::Manual => "Manual",
         }
     }
@@ -37,10 +40,24 @@ impl GameSource {
             Self::Epic => "epic_",
             Self::Xbox => "xbox_",
             Self::BattleNet => "battlenet_",
+            Self::Itch => "itch_",
             Self::Manual => "manual_",
         }
     }
 
+    /// Store page where the player applies a game's pending update.
+    ///
+    /// Epic opens the launcher's library: it resumes queued updates on its own.
+    #[must_use]
+    pub fn update_uri(&self) -> Option<&'static str> {
+        match self {
+            Self::Steam => Some("steam://nav/downloads"),
+            Self::Epic => Some("com.epicgames.launcher://apps"),
+            Self::Xbox => Some("ms-windows-store://downloadsandupdates"),
+            Self::BattleNet | Self::Itch | Self::Manual => None,
+        }
+    }
+
     /// Checks if this source supports automatic metadata fetching.
     #[must_use]
     pub fn supports_metadata(&self) -> bool {
@@ -58,6 +75,7 @@ mod tests {
         assert_eq!(GameSource::Epic.display_name(), "Epic Games");
         assert_eq!(GameSource::Xbox.display_name(), "Xbox");
         assert_eq!(GameSource::BattleNet.display_name(), "Battle.net");
+        assert_eq!(GameSource::Itch.display_name(), "itch.io");
         assert_eq!(GameSource::Manual.display_name(), "Manual");
     }
 
@@ -67,6 +85,7 @@ mod tests {
         assert_eq!(GameSource::Epic.id_prefix(), "epic_");
         assert_eq!(GameSource::Xbox.id_prefix(), "xbox_");
         assert_eq!(GameSource::BattleNet.id_prefix(), "battlenet_");
+        assert_eq!(GameSource::Itch.id_prefix(), "itch_");
         assert_eq!(GameSource::Manual.id_prefix(), "manual_");
     }
 
@@ -79,6 +98,16 @@ mod tests {
         assert!(!GameSource::Manual.supports_metadata());
     }
 
+    #[test]
+    fn test_update_uris() {
+        assert_eq!(GameSource::Steam.update_uri(), Some("steam://nav/downloads"));
+        assert_eq!(
+            GameSource::Xbox.update_uri(),
+            Some("ms-windows-store://downloadsandupdates")
+        );
+        assert_eq!(GameSource::Manual.update_uri(), None);
+    }
+
     #[test]
     #[allow(clippy::unwrap_used)] // Acceptable in tests
     fn test_serialization() {
@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+// Steam `AppState` flags (appmanifest `StateFlags`)
+const STEAM_UPDATE_REQUIRED: u32 = 2;
+const STEAM_FULLY_INSTALLED: u32 = 4;
+const STEAM_FILES_MISSING: u32 = 32;
+const STEAM_FILES_CORRUPT: u32 = 128;
+const STEAM_UPDATE_RUNNING: u32 = 256;
+const STEAM_UPDATE_PAUSED: u32 = 512;
+const STEAM_UPDATE_STARTED: u32 = 1024;
+const STEAM_UNINSTALLING: u32 = 2048;
+const STEAM_VALIDATING: u32 = 131_072;
+const STEAM_ADDING_FILES: u32 = 262_144;
+const STEAM_PREALLOCATING: u32 = 524_288;
+const STEAM_DOWNLOADING: u32 = 1_048_576;
+const STEAM_STAGING: u32 = 2_097_152;
+const STEAM_COMMITTING: u32 = 4_194_304;
+
+/// Any flag meaning content is being written right now.
+const STEAM_UPDATE_ACTIVE: u32 = STEAM_UPDATE_RUNNING
+    | STEAM_UPDATE_STARTED
+    | STEAM_ADDING_FILES
+    | STEAM_DOWNLOADING
+    | STEAM_STAGING
+    | STEAM_COMMITTING;
+
+/// Value object representing whether a discovered game can be played right now.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallState {
+    /// Fully installed and up to date
+    #[default]
+    Installed,
+    /// Installed, but the store will update it before launching
+    UpdateRequired,
+    /// Installed and currently being updated
+    Updating,
+    /// First install in progress (or paused)
+    Downloading,
+    /// Disk space is being reserved before the download starts
+    Preallocating,
+    /// Store is verifying the game files
+    Validating,
+    /// Files are missing or corrupt (needs repair)
+    FilesMissing,
+    /// Not installed (stale manifest or uninstall in progress)
+    Uninstalled,
+}
+
+impl InstallState {
+    /// Maps appmanifest `StateFlags` to an install state.
+    #[must_use]
+    pub fn from_steam_state_flags(flags: u32) -> Self {
+        let has = |flag: u32| flags & flag != 0;
+
+        if has(STEAM_UNINSTALLING) {
+            return Self::Uninstalled;
+        }
+        if has(STEAM_VALIDATING) {
+            return Self::Validating;
+        }
+        if has(STEAM_PREALLOCATING) {
+            return Self::Preallocating;
+        }
+        if !has(STEAM_FULLY_INSTALLED) {
+            let in_progress = has(STEAM_UPDATE_ACTIVE) || has(STEAM_UPDATE_PAUSED) || has(STEAM_UPDATE_REQUIRED);
+            return if in_progress {
+                Self::Downloading
+            } else {
+                Self::Uninstalled
+            };
+        }
+        if has(STEAM_FILES_MISSING) || has(STEAM_FILES_CORRUPT) {
+            return Self::FilesMissing;
+        }
+        if has(STEAM_UPDATE_ACTIVE) {
+            return Self::Updating;
+        }
+        if has(STEAM_UPDATE_REQUIRED) || has(STEAM_UPDATE_PAUSED) {
+            return Self::UpdateRequired;
+        }
+        Self::Installed
+    }
+
+    /// Maps the flags of an Epic Games Launcher `.item` manifest.
+    ///
+    /// `update_staged` means part of an update was downloaded to the
+    /// manifest's `StagingLocation` and waits for the launcher to finish it.
+    #[must_use]
+    pub fn from_epic_manifest(incomplete_install: bool, needs_validation: bool, update_staged: bool) -> Self {
+        if incomplete_install {
+            Self::Downloading
+        } else if needs_validation || update_staged {
+            Self::UpdateRequired
+        } else {
+            Self::Installed
+        }
+    }
+
+    /// State of a game with an update in the Microsoft Store queue.
+    #[must_use]
+    pub fn from_store_update(downloading: bool) -> Self {
+        if downloading {
+            Self::Updating
+        } else {
+            Self::UpdateRequired
+        }
+    }
+
+    /// Whether the store has an update for the game (the library badge).
+    #[must_use]
+    pub fn is_update_pending(&self) -> bool {
+        matches!(self, Self::UpdateRequired | Self::Updating)
+    }
+
+    /// Whether the game can be launched (the store handles pending updates).
+    #[must_use]
+    pub fn is_playable(&self) -> bool {
+        matches!(self, Self::Installed | Self::UpdateRequired)
+    }
+
+    /// Whether a game in this state should be listed in the library.
+    #[must_use]
+    pub fn is_listed(&self) -> bool {
+        !matches!(self, Self::Uninstalled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steam_fully_installed() {
+        assert_eq!(InstallState::from_steam_state_flags(4), InstallState::Installed);
+        assert!(InstallState::Installed.is_playable());
+    }
+
+    #[test]
+    fn test_steam_update_states() {
+        // FullyInstalled | UpdateRequired
+        assert_eq!(InstallState::from_steam_state_flags(6), InstallState::UpdateRequired);
+        // FullyInstalled | UpdateRequired | UpdateRunning | UpdateStarted
+        assert_eq!(InstallState::from_steam_state_flags(1286), InstallState::Updating);
+        assert!(!InstallState::Updating.is_playable());
+    }
+
+    #[test]
+    fn test_steam_first_install() {
+        // UpdateRequired | UpdateStarted (new install, not fully installed)
+        assert_eq!(InstallState::from_steam_state_flags(1026), InstallState::Downloading);
+        // Preallocating
+        assert_eq!(
+            InstallState::from_steam_state_flags(STEAM_UPDATE_REQUIRED | STEAM_PREALLOCATING),
+            InstallState::Preallocating
+        );
+    }
+
+    #[test]
+    fn test_epic_and_store_update_states() {
+        assert_eq!(
+            InstallState::from_epic_manifest(false, false, false),
+            InstallState::Installed
+        );
+        assert_eq!(
+            InstallState::from_epic_manifest(false, false, true),
+            InstallState::UpdateRequired
+        );
+        assert_eq!(
+            InstallState::from_epic_manifest(true, false, true),
+            InstallState::Downloading
+        );
+        assert!(InstallState::from_store_update(true).is_update_pending());
+        assert!(InstallState::from_store_update(false).is_playable());
+        assert!(!InstallState::Installed.is_update_pending());
+    }
+
+    #[test]
+    fn test_steam_uninstalled() {
+        assert_eq!(InstallState::from_steam_state_flags(1), InstallState::Uninstalled);
+        assert_eq!(InstallState::from_steam_state_flags(0), InstallState::Uninstalled);
+        assert!(!InstallState::Uninstalled.is_listed());
+    }
+}
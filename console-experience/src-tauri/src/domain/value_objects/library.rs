@@ -0,0 +1,397 @@
+use crate::domain::entities::game::Game;
+use crate::domain::value_objects::game_source::GameSource;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maximum length of a collection name or tag.
+const MAX_LABEL_LEN: usize = 64;
+
+/// User-defined group of games (e.g. "Couch co-op", "Backlog").
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GameCollection {
+    /// Stable identifier (`col_<slug>`)
+    pub id: String,
+    /// Display name
+    pub name: String,
+    /// Member game IDs, in the order they were added
+    pub game_ids: Vec<String>,
+}
+
+/// How `get_games` orders the library.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum GameSortOrder {
+    /// Alphabetical (case-insensitive)
+    #[default]
+    Title,
+    /// Most recently played first, never played last
+    LastPlayed,
+    /// Grouped by store, then by title
+    Source,
+    /// Favorites first, then by title
+    FavoritesFirst,
+}
+
+/// Sorting and filter parameters accepted by `get_games`.
+///
+/// All filters are combined (AND). Hidden games are excluded unless
+/// `include_hidden` is set.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct GameQuery {
+    pub sort: GameSortOrder,
+    pub favorites_only: bool,
+    pub include_hidden: bool,
+    pub collection_id: Option<String>,
+    pub tag: Option<String>,
+    pub source: Option<GameSource>,
+    /// Case-insensitive title substring
+    pub search: Option<String>,
+}
+
+/// User organization of the library: favorites, hidden games, tags and collections.
+///
+/// Keyed by game ID and stored separately from the scan cache, so a rescan
+/// never loses it. Entries of games that disappear are kept in case the game
+/// comes back (e.g. an external drive reconnected).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct LibraryOrganization {
+    pub favorites: BTreeSet<String>,
+    pub hidden: BTreeSet<String>,
+    /// Game ID → tags
+    pub tags: BTreeMap<String, BTreeSet<String>>,
+    pub collections: Vec<GameCollection>,
+}
+
+impl LibraryOrganization {
+    #[must_use]
+    pub fn is_favorite(&self, game_id: &str) -> bool {
+        self.favorites.contains(game_id)
+    }
+
+    #[must_use]
+    pub fn is_hidden(&self, game_id: &str) -> bool {
+        self.hidden.contains(game_id)
+    }
+
+    pub fn set_favorite(&mut self, game_id: &str, favorite: bool) {
+        if favorite {
+            self.favorites.insert(game_id.to_string());
+        } else {
+            self.favorites.remove(game_id);
+        }
+    }
+
+    pub fn set_hidden(&mut self, game_id: &str, hidden: bool) {
+        if hidden {
+            self.hidden.insert(game_id.to_string());
+        } else {
+            self.hidden.remove(game_id);
+        }
+    }
+
+    /// Replaces the tags of a game (trimmed, deduplicated, empty tags dropped).
+    ///
+    /// # Errors
+    /// Returns error if a tag is longer than 64 characters.
+    pub fn set_tags(&mut self, game_id: &str, tags: &[String]) -> Result<(), String> {
+        let tags: BTreeSet<String> = tags
+            .iter()
+            .map(|tag| tag.trim().to_string())
+            .filter(|tag| !tag.is_empty())
+            .collect();
+        if let Some(tag) = tags.iter().find(|tag| tag.chars().count() > MAX_LABEL_LEN) {
+            return Err(format!("Tag too long: {tag}"));
+        }
+
+        if tags.is_empty() {
+            self.tags.remove(game_id);
+        } else {
+            self.tags.insert(game_id.to_string(), tags);
+        }
+        Ok(())
+    }
+
+    /// Every tag in use, sorted.
+    #[must_use]
+    pub fn all_tags(&self) -> BTreeSet<String> {
+        self.tags.values().flatten().cloned().collect()
+    }
+
+    /// Creates an empty collection.
+    ///
+    /// # Errors
+    /// Returns error if the name is empty, too long, or already used
+    /// (case-insensitive).
+    pub fn create_collection(&mut self, name: &str) -> Result<GameCollection, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Collection name cannot be empty".to_string());
+        }
+        if name.chars().count() > MAX_LABEL_LEN {
+            return Err(format!("Collection name too long (max {MAX_LABEL_LEN} characters)"));
+        }
+        if self.collections.iter().any(|c| c.name.eq_ignore_ascii_case(name)) {
+            return Err(format!("Collection already exists: {name}"));
+        }
+
+        let base_id = format!("col_{}", slugify(name));
+        let mut id = base_id.clone();
+        let mut suffix = 2;
+        while self.collections.iter().any(|c| c.id == id) {
+            id = format!("{base_id}_{suffix}");
+            suffix += 1;
+        }
+
+        let collection = GameCollection {
+            id,
+            name: name.to_string(),
+            game_ids: Vec::new(),
+        };
+        self.collections.push(collection.clone());
+        Ok(collection)
+    }
+
+    /// Deletes a collection (its games are untouched).
+    ///
+    /// # Errors
+    /// Returns error if the collection does not exist.
+    pub fn delete_collection(&mut self, collection_id: &str) -> Result<(), String> {
+        let before = self.collections.len();
+        self.collections.retain(|c| c.id != collection_id);
+        if self.collections.len() == before {
+            return Err(format!("Collection not found: {collection_id}"));
+        }
+        Ok(())
+    }
+
+    /// Adds a game to (or removes it from) a collection.
+    ///
+    /// # Errors
+    /// Returns error if the collection does not exist.
+    pub fn assign_to_collection(
+        &mut self,
+        game_id: &str,
+        collection_id: &str,
+        assigned: bool,
+    ) -> Result<GameCollection, String> {
+        let collection = self
+            .collections
+            .iter_mut()
+            .find(|c| c.id == collection_id)
+            .ok_or_else(|| format!("Collection not found: {collection_id}"))?;
+
+        if assigned {
+            if !collection.game_ids.iter().any(|id| id == game_id) {
+                collection.game_ids.push(game_id.to_string());
+            }
+        } else {
+            collection.game_ids.retain(|id| id != game_id);
+        }
+        Ok(collection.clone())
+    }
+
+    /// Filters and sorts games according to `query`.
+    #[must_use]
+    pub fn apply(&self, games: Vec<Game>, query: &GameQuery) -> Vec<Game> {
+        let collection = query
+            .collection_id
+            .as_ref()
+            .map(|id| self.collections.iter().find(|c| &c.id == id));
+        let search = query.search.as_ref().map(|s| s.trim().to_lowercase());
+
+        let mut games: Vec<Game> = games
+            .into_iter()
+            .filter(|g| query.include_hidden || !self.is_hidden(&g.id))
+            .filter(|g| !query.favorites_only || self.is_favorite(&g.id))
+            .filter(|g| query.source.is_none_or(|source| g.source == source))
+            .filter(|g| {
+                // Unknown collection → empty result rather than the whole library
+                collection.is_none_or(|c| c.is_some_and(|c| c.game_ids.contains(&g.id)))
+            })
+            .filter(|g| {
+                query
+                    .tag
+                    .as_ref()
+                    .is_none_or(|tag| self.tags.get(&g.id).is_some_and(|tags| tags.contains(tag)))
+            })
+            .filter(|g| {
+                search
+                    .as_ref()
+                    .is_none_or(|s| s.is_empty() || g.title.to_lowercase().contains(s))
+            })
+            .collect();
+
+        let by_title = |a: &Game, b: &Game| a.title.to_lowercase().cmp(&b.title.to_lowercase());
+        match query.sort {
+            GameSortOrder::Title => games.sort_by(by_title),
+            GameSortOrder::LastPlayed => {
+                games.sort_by(|a, b| b.last_played.cmp(&a.last_played).then_with(|| by_title(a, b)));
+            },
+            GameSortOrder::Source => games.sort_by(|a, b| {
+                a.source
+                    .display_name()
+                    .cmp(b.source.display_name())
+                    .then_with(|| by_title(a, b))
+            }),
+            GameSortOrder::FavoritesFirst => games.sort_by(|a, b| {
+                self.is_favorite(&b.id)
+                    .cmp(&self.is_favorite(&a.id))
+                    .then_with(|| by_title(a, b))
+            }),
+        }
+        games
+    }
+}
+
+/// Lowercase ASCII slug (`"Couch Co-op!"` → `"couch_co_op"`).
+fn slugify(name: &str) -> String {
+    let slug = name
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_");
+    if slug.is_empty() {
+        "collection".to_string()
+    } else {
+        slug
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(id: &str, title: &str, source: GameSource, last_played: Option<u64>) -> Game {
+        let mut game = Game::new(
+            id.to_string(),
+            id.to_string(),
+            title.to_string(),
+            format!("C:\\Games\\{title}"),
+            source,
+        );
+        game.last_played = last_played;
+        game
+    }
+
+    fn library() -> Vec<Game> {
+        vec![
+            game("steam_1", "Portal 2", GameSource::Steam, Some(100)),
+            game("epic_2", "alan wake 2", GameSource::Epic, None),
+            game("xbox_3", "Halo Infinite", GameSource::Xbox, Some(300)),
+        ]
+    }
+
+    fn ids(games: &[Game]) -> Vec<&str> {
+        games.iter().map(|g| g.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_default_query_sorts_by_title_and_hides_hidden() {
+        let mut org = LibraryOrganization::default();
+        org.set_hidden("xbox_3", true);
+
+        let games = org.apply(library(), &GameQuery::default());
+        assert_eq!(ids(&games), vec!["epic_2", "steam_1"]);
+
+        let query = GameQuery {
+            include_hidden: true,
+            ..GameQuery::default()
+        };
+        assert_eq!(org.apply(library(), &query).len(), 3);
+    }
+
+    #[test]
+    fn test_favorites_filter_and_sort() {
+        let mut org = LibraryOrganization::default();
+        org.set_favorite("xbox_3", true);
+
+        let query = GameQuery {
+            sort: GameSortOrder::FavoritesFirst,
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["xbox_3", "epic_2", "steam_1"]);
+
+        let query = GameQuery {
+            favorites_only: true,
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["xbox_3"]);
+    }
+
+    #[test]
+    fn test_last_played_sort() {
+        let org = LibraryOrganization::default();
+        let query = GameQuery {
+            sort: GameSortOrder::LastPlayed,
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["xbox_3", "steam_1", "epic_2"]);
+    }
+
+    #[test]
+    fn test_collections() {
+        let mut org = LibraryOrganization::default();
+        let collection = org.create_collection("  Couch Co-op! ").unwrap();
+        assert_eq!(collection.id, "col_couch_co_op");
+        assert_eq!(collection.name, "Couch Co-op!");
+        assert!(org.create_collection("couch co-op!").is_err());
+        assert!(org.create_collection("   ").is_err());
+
+        org.assign_to_collection("steam_1", &collection.id, true).unwrap();
+        org.assign_to_collection("steam_1", &collection.id, true).unwrap();
+        assert_eq!(org.collections[0].game_ids, vec!["steam_1"]);
+        assert!(org.assign_to_collection("steam_1", "col_missing", true).is_err());
+
+        let query = GameQuery {
+            collection_id: Some(collection.id.clone()),
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["steam_1"]);
+
+        let query = GameQuery {
+            collection_id: Some("col_missing".to_string()),
+            ..GameQuery::default()
+        };
+        assert!(org.apply(library(), &query).is_empty());
+
+        org.delete_collection(&collection.id).unwrap();
+        assert!(org.collections.is_empty());
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut org = LibraryOrganization::default();
+        org.set_tags("epic_2", &[" horror ".to_string(), "horror".to_string(), String::new()])
+            .unwrap();
+        assert_eq!(org.all_tags(), BTreeSet::from(["horror".to_string()]));
+
+        let query = GameQuery {
+            tag: Some("horror".to_string()),
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["epic_2"]);
+
+        org.set_tags("epic_2", &[]).unwrap();
+        assert!(org.tags.is_empty());
+        assert!(org.set_tags("epic_2", &["x".repeat(65)]).is_err());
+    }
+
+    #[test]
+    fn test_search_and_source_filters() {
+        let org = LibraryOrganization::default();
+        let query = GameQuery {
+            search: Some("HALO".to_string()),
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["xbox_3"]);
+
+        let query = GameQuery {
+            source: Some(GameSource::Steam),
+            ..GameQuery::default()
+        };
+        assert_eq!(ids(&org.apply(library(), &query)), vec!["steam_1"]);
+    }
+}
@@ -1,3 +1,11 @@
+pub mod dedup_overrides;
 pub mod game_source;
+pub mod install_state;
+pub mod library;
+pub mod play_history;
 
+pub use dedup_overrides::{DedupOverrideKind, DedupOverrides};
 pub use game_source::GameSource;
+pub use install_state::InstallState;
+pub use library::{GameCollection, GameQuery, GameSortOrder, LibraryOrganization};
+pub use play_history::{GamePlayStats, LibraryRow, LibraryRowItem, LibraryRowKind, PlayHistory};
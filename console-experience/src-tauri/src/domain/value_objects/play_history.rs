@@ -0,0 +1,259 @@
+use crate::domain::entities::game::Game;
+use crate::domain::session_summary::{SessionSummary, MAX_SESSION_SUMMARIES};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Games first seen within this window appear in "Recently added".
+const RECENTLY_ADDED_WINDOW_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Play statistics tracked for one game.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct GamePlayStats {
+    /// Last launch (Unix epoch seconds)
+    pub last_played: Option<u64>,
+    /// Accumulated play time of finished sessions
+    pub total_play_seconds: u64,
+    pub session_count: u32,
+    /// First scan that found the game (`None` if it predates tracking)
+    pub added_at: Option<u64>,
+    /// Report cards of the last sessions, oldest first
+    pub recent_sessions: Vec<SessionSummary>,
+}
+
+/// Backend record of launches, play time and when games appeared in the library.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct PlayHistory {
+    /// Game ID → stats
+    pub games: BTreeMap<String, GamePlayStats>,
+    /// Set after the first scan; games found by that scan are not "new"
+    pub baseline_scanned: bool,
+}
+
+/// Kind of auto-curated library row.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LibraryRowKind {
+    ContinuePlaying,
+    RecentlyAdded,
+    MostPlayed,
+}
+
+impl LibraryRowKind {
+    #[must_use]
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::ContinuePlaying => "Continue Playing",
+            Self::RecentlyAdded => "Recently Added",
+            Self::MostPlayed => "Most Played",
+        }
+    }
+}
+
+/// Game in a library row, with the stats the row is based on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LibraryRowItem {
+    pub game: Game,
+    pub play_time_seconds: u64,
+    pub added_at: Option<u64>,
+}
+
+/// One console-style row of the home screen.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LibraryRow {
+    pub kind: LibraryRowKind,
+    pub title: String,
+    pub items: Vec<LibraryRowItem>,
+}
+
+impl PlayHistory {
+    #[must_use]
+    pub fn stats(&self, game_id: &str) -> Option<&GamePlayStats> {
+        self.games.get(game_id)
+    }
+
+    /// Records a launch at `now`.
+    pub fn record_launch(&mut self, game_id: &str, now: u64) {
+        self.games.entry(game_id.to_string()).or_default().last_played = Some(now);
+    }
+
+    /// Adds a finished session's play time.
+    pub fn record_session(&mut self, game_id: &str, play_time_seconds: u64) {
+        let stats = self.games.entry(game_id.to_string()).or_default();
+        stats.total_play_seconds = stats.total_play_seconds.saturating_add(play_time_seconds);
+        stats.session_count = stats.session_count.saturating_add(1);
+    }
+
+    /// Keeps a finished session's report card (the last [`MAX_SESSION_SUMMARIES`] per game).
+    pub fn record_session_summary(&mut self, summary: SessionSummary) {
+        let sessions = &mut self.games.entry(summary.game_id.clone()).or_default().recent_sessions;
+        sessions.push(summary);
+        if sessions.len() > MAX_SESSION_SUMMARIES {
+            sessions.remove(0);
+        }
+    }
+
+    /// Diffs a scan result against known games, stamping new ones with `now`.
+    ///
+    /// The very first scan only establishes the baseline (nothing is "new").
+    /// Returns whether anything changed.
+    pub fn record_scan<'a>(&mut self, game_ids: impl IntoIterator<Item = &'a str>, now: u64) -> bool {
+        let added_at = self.baseline_scanned.then_some(now);
+        let mut changed = !self.baseline_scanned;
+
+        for id in game_ids {
+            if !self.games.contains_key(id) {
+                self.games.insert(
+                    id.to_string(),
+                    GamePlayStats {
+                        added_at,
+                        ..GamePlayStats::default()
+                    },
+                );
+                changed = true;
+            }
+        }
+
+        self.baseline_scanned = true;
+        changed
+    }
+
+    /// Copies tracked `last_played` timestamps into scanned games.
+    pub fn apply_last_played(&self, games: &mut [Game]) {
+        for game in games {
+            if let Some(last_played) = self.stats(&game.id).and_then(|s| s.last_played) {
+                game.last_played = Some(game.last_played.map_or(last_played, |t| t.max(last_played)));
+            }
+        }
+    }
+
+    /// Builds the auto-curated rows (empty rows are omitted).
+    ///
+    /// `games` should already exclude hidden games.
+    #[must_use]
+    pub fn library_rows(&self, games: &[Game], limit: usize, now: u64) -> Vec<LibraryRow> {
+        let item = |game: &Game| {
+            let stats = self.stats(&game.id);
+            LibraryRowItem {
+                game: game.clone(),
+                play_time_seconds: stats.map_or(0, |s| s.total_play_seconds),
+                added_at: stats.and_then(|s| s.added_at),
+            }
+        };
+
+        let mut continue_playing: Vec<LibraryRowItem> =
+            games.iter().filter(|g| g.last_played.is_some()).map(item).collect();
+        continue_playing.sort_by(|a, b| b.game.last_played.cmp(&a.game.last_played));
+
+        let mut recently_added: Vec<LibraryRowItem> = games
+            .iter()
+            .map(item)
+            .filter(|i| {
+                i.added_at
+                    .is_some_and(|added| now.saturating_sub(added) <= RECENTLY_ADDED_WINDOW_SECS)
+            })
+            .collect();
+        recently_added.sort_by(|a, b| b.added_at.cmp(&a.added_at));
+
+        let mut most_played: Vec<LibraryRowItem> = games.iter().map(item).filter(|i| i.play_time_seconds > 0).collect();
+        most_played.sort_by(|a, b| b.play_time_seconds.cmp(&a.play_time_seconds));
+
+        [
+            (LibraryRowKind::ContinuePlaying, continue_playing),
+            (LibraryRowKind::RecentlyAdded, recently_added),
+            (LibraryRowKind::MostPlayed, most_played),
+        ]
+        .into_iter()
+        .filter(|(_, items)| !items.is_empty())
+        .map(|(kind, mut items)| {
+            items.truncate(limit);
+            LibraryRow {
+                kind,
+                title: kind.title().to_string(),
+                items,
+            }
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::value_objects::game_source::GameSource;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn game(id: &str) -> Game {
+        Game::new(
+            id.to_string(),
+            id.to_string(),
+            id.to_string(),
+            format!("C:\\Games\\{id}.exe"),
+            GameSource::Manual,
+        )
+    }
+
+    #[test]
+    fn test_first_scan_is_baseline() {
+        let mut history = PlayHistory::default();
+        assert!(history.record_scan(["a", "b"], NOW));
+        assert!(history.games.values().all(|s| s.added_at.is_none()));
+
+        assert!(!history.record_scan(["a", "b"], NOW + 10));
+        assert!(history.record_scan(["a", "b", "c"], NOW + 20));
+        assert_eq!(history.stats("c").unwrap().added_at, Some(NOW + 20));
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut history = PlayHistory::default();
+        history.record_scan(["a", "b"], NOW);
+        history.record_scan(["a", "b", "c"], NOW);
+        history.record_launch("a", NOW - 100);
+        history.record_launch("b", NOW - 10);
+        history.record_session("a", 3600);
+        history.record_session("a", 600);
+        history.record_session("b", 60);
+
+        let mut games = vec![game("a"), game("b"), game("c")];
+        history.apply_last_played(&mut games);
+        let rows = history.library_rows(&games, 10, NOW);
+
+        let ids = |kind: LibraryRowKind| -> Vec<String> {
+            rows.iter()
+                .find(|r| r.kind == kind)
+                .map(|r| r.items.iter().map(|i| i.game.id.clone()).collect())
+                .unwrap_or_default()
+        };
+        assert_eq!(ids(LibraryRowKind::ContinuePlaying), vec!["b", "a"]);
+        assert_eq!(ids(LibraryRowKind::RecentlyAdded), vec!["c"]);
+        assert_eq!(ids(LibraryRowKind::MostPlayed), vec!["a", "b"]);
+        assert_eq!(history.stats("a").unwrap().session_count, 2);
+    }
+
+    #[test]
+    fn test_rows_omit_empty_and_old_additions() {
+        let mut history = PlayHistory::default();
+        history.record_scan(["a"], NOW);
+        history.record_scan(["a", "b"], NOW - RECENTLY_ADDED_WINDOW_SECS - 1);
+
+        let rows = history.library_rows(&[game("a"), game("b")], 10, NOW);
+        assert!(rows.is_empty());
+    }
+
+    #[test]
+    fn test_rows_respect_limit() {
+        let mut history = PlayHistory::default();
+        let games: Vec<Game> = (0..5).map(|i| game(&format!("g{i}"))).collect();
+        for (i, g) in games.iter().enumerate() {
+            history.record_session(&g.id, 60 * (i as u64 + 1));
+        }
+
+        let rows = history.library_rows(&games, 3, NOW);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].items.len(), 3);
+        assert_eq!(rows[0].items[0].game.id, "g4");
+    }
+}
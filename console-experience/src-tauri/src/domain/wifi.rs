@@ -35,6 +35,18 @@ impl WiFiStrengthConfig {
     }
 }
 
+/// Internet reachability of the current network.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum Connectivity {
+    Online,
+    /// Traffic is redirected to a login page (hotels, airports, trains)
+    CaptivePortal {
+        portal_url: String,
+    },
+    NoInternet,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
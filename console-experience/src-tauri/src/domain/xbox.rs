@@ -0,0 +1,144 @@
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Store page the install flow opens (the Store app owns the download).
+const STORE_PRODUCT_URI: &str = "ms-windows-store://pdp/?ProductId=";
+
+/// An Xbox / Microsoft Store title, from the Game Pass catalog or installed.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct XboxTitle {
+    /// Store product ID (e.g. `9NBLGGH4R315`), empty for installed packages
+    /// that are not in the catalog
+    pub product_id: String,
+    pub title: String,
+    /// `PackageFamilyName`, unknown for some catalog entries
+    pub package_family_name: Option<String>,
+    pub installed: bool,
+}
+
+/// Stage of an install or uninstall, reported in `xbox-package-progress`.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum XboxPackagePhase {
+    /// The Store page is open; the user confirms the download there
+    StoreOpened,
+    /// The package showed up in `PackageManager`
+    Installed,
+    /// The Store download did not finish before Balam stopped watching
+    TimedOut,
+    Uninstalling,
+    Uninstalled,
+    Failed,
+}
+
+/// Progress payload of the `xbox-package-progress` event.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct XboxPackageProgress {
+    /// Product ID for installs, `PackageFamilyName` for uninstalls
+    pub id: String,
+    pub phase: XboxPackagePhase,
+    /// 0 - 100, only known while uninstalling
+    pub percent: u32,
+    pub error: Option<String>,
+}
+
+/// Whether `id` looks like a Store product ID (12 letters and digits).
+#[must_use]
+pub fn is_valid_product_id(id: &str) -> bool {
+    id.len() == 12 && id.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// `ms-windows-store:` URI of a product's Store page.
+#[must_use]
+pub fn store_product_uri(product_id: &str) -> String {
+    format!("{STORE_PRODUCT_URI}{}", product_id.to_ascii_uppercase())
+}
+
+/// Marks catalog titles that are installed and appends installed games
+/// missing from the catalog.
+///
+/// `installed` holds `(PackageFamilyName, title)` of every package on the
+/// machine; only those `is_game` accepts are appended.
+#[must_use]
+pub fn merge_xbox_titles(
+    catalog: Vec<XboxTitle>,
+    installed: &[(String, String)],
+    is_game: impl Fn(&str) -> bool,
+) -> Vec<XboxTitle> {
+    let installed_families: HashSet<String> = installed.iter().map(|(family, _)| family.to_lowercase()).collect();
+    let mut titles: Vec<XboxTitle> = catalog
+        .into_iter()
+        .map(|mut title| {
+            title.installed = title
+                .package_family_name
+                .as_ref()
+                .is_some_and(|family| installed_families.contains(&family.to_lowercase()));
+            title
+        })
+        .collect();
+
+    let catalog_families: HashSet<String> = titles
+        .iter()
+        .filter_map(|title| title.package_family_name.as_ref().map(|family| family.to_lowercase()))
+        .collect();
+    for (family, title) in installed {
+        if is_game(family) && !catalog_families.contains(&family.to_lowercase()) {
+            titles.push(XboxTitle {
+                product_id: String::new(),
+                title: title.clone(),
+                package_family_name: Some(family.clone()),
+                installed: true,
+            });
+        }
+    }
+    titles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_product_id_and_store_uri() {
+        assert!(is_valid_product_id("9NBLGGH4R315"));
+        assert!(!is_valid_product_id("9NBLGGH4R31"));
+        assert!(!is_valid_product_id("9NBLGGH4R3&5"));
+        assert_eq!(
+            store_product_uri("9nblggh4r315"),
+            "ms-windows-store://pdp/?ProductId=9NBLGGH4R315"
+        );
+    }
+
+    #[test]
+    fn test_merge_xbox_titles() {
+        let catalog = vec![
+            XboxTitle {
+                product_id: "9NBLGGH4R315".to_string(),
+                title: "Halo".to_string(),
+                package_family_name: Some("Microsoft.Halo_8wekyb3d8bbwe".to_string()),
+                installed: false,
+            },
+            XboxTitle {
+                product_id: "9PDW16B5HMXR".to_string(),
+                title: "Forza".to_string(),
+                package_family_name: None,
+                installed: false,
+            },
+        ];
+        let installed = vec![
+            ("microsoft.halo_8wekyb3d8bbwe".to_string(), "Halo".to_string()),
+            ("Mojang.Minecraft_8wekyb3d8bbwe".to_string(), "Minecraft".to_string()),
+            (
+                "Microsoft.WindowsCalculator_8wekyb3d8bbwe".to_string(),
+                "Calculator".to_string(),
+            ),
+        ];
+
+        let titles = merge_xbox_titles(catalog, &installed, |family| family.contains("Minecraft"));
+        assert_eq!(titles.len(), 3);
+        assert!(titles[0].installed);
+        assert!(!titles[1].installed);
+        assert_eq!(titles[2].title, "Minecraft");
+        assert!(titles[2].installed && titles[2].product_id.is_empty());
+    }
+}
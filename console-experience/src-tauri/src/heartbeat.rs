@@ -1,69 +1,199 @@
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
 use tokio::io::AsyncWriteExt;
-use tokio::net::windows::named_pipe::ClientOptions;
-use tracing::{error, info};
+use tokio::net::windows::named_pipe::{ClientOptions, NamedPipeClient};
+use tracing::{info, warn};
 
-const PIPE_NAME: &str = r"\\.\pipe\balam_heartbeat";
-const HEARTBEAT_INTERVAL_MS: u64 = 2000; // 2 seconds (2x faster than 10s timeout)
+use crate::domain::console_mode::{HeartbeatFrame, HEARTBEAT_PIPE_NAME};
+use crate::infrastructure::clock::now_secs;
 
-/// Starts the heartbeat thread that communicates with the watchdog via Named Pipe.
+/// First reconnect delay after the pipe is lost (the watchdog recreates it within ~2s).
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_millis(500);
+/// Reconnect delay cap while the watchdog is down.
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// How often the UI thread is probed.
+const UI_PROBE_INTERVAL: Duration = Duration::from_secs(1);
+/// UI thread is reported as hung after this long without running a probe.
+const UI_HANG_THRESHOLD: Duration = Duration::from_secs(5);
+
+/// Responsiveness of the Tauri event loop (main thread).
+struct UiWatch {
+    origin: Instant,
+    /// Milliseconds since `origin` when the main thread last ran a probe
+    last_tick_ms: AtomicU64,
+    hung: AtomicBool,
+}
+
+impl UiWatch {
+    fn tick(&self) {
+        self.last_tick_ms.store(self.elapsed_ms(), Ordering::Relaxed);
+    }
+
+    fn stalled_for(&self) -> Duration {
+        Duration::from_millis(
+            self.elapsed_ms()
+                .saturating_sub(self.last_tick_ms.load(Ordering::Relaxed)),
+        )
+    }
+
+    fn elapsed_ms(&self) -> u64 {
+        u64::try_from(self.origin.elapsed().as_millis()).unwrap_or(u64::MAX)
+    }
+}
+
+/// Set on resume from sleep: the pipe may look alive while the watchdog already gave up on it.
+static RECONNECT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+static UI_WATCH: LazyLock<UiWatch> = LazyLock::new(|| UiWatch {
+    origin: Instant::now(),
+    last_tick_ms: AtomicU64::new(0),
+    hung: AtomicBool::new(false),
+});
+
+/// Exponential reconnect delay, reset after every successful connection.
+struct Backoff {
+    next: Duration,
+}
+
+impl Backoff {
+    fn new() -> Self {
+        Self {
+            next: RECONNECT_INITIAL_DELAY,
+        }
+    }
+
+    fn next_delay(&mut self) -> Duration {
+        let delay = self.next;
+        self.next = (self.next * 2).min(RECONNECT_MAX_DELAY);
+        delay
+    }
+
+    fn reset(&mut self) {
+        self.next = RECONNECT_INITIAL_DELAY;
+    }
+}
+
+/// Starts the heartbeat client that communicates with the watchdog via Named Pipe.
 ///
 /// Architecture:
 /// - Balam (this process) = Named Pipe Client (writes heartbeat)
 /// - Watchdog = Named Pipe Server (reads heartbeat with timeout)
 ///
-/// Benefits over file I/O:
-/// - Automatic disconnect detection when Balam crashes (OS closes pipe)
-/// - <100ms latency vs 2-10s with files
-/// - Zero disk I/O (all in RAM)
-/// - Tokio async (non-blocking)
-pub fn start_heartbeat_thread() {
-    // Use Tauri's async runtime instead of tokio::spawn directly
-    tauri::async_runtime::spawn(async {
+/// Two independent signals:
+/// - **Process liveness:** frames are written from the async runtime, never
+///   from the main thread, so they keep flowing while the window is busy
+/// - **UI responsiveness:** a probe thread posts a no-op to the Tauri event
+///   loop every second; if it has not run for `UI_HANG_THRESHOLD` the frames
+///   carry the UI-hang flag and the watchdog decides whether to restart
+///
+/// When the watchdog restarts, the pipe disappears: the client reconnects
+/// with exponential backoff (0.5s → 30s) instead of a fixed retry.
+///
+/// `interval` comes from `WatchdogSettings::heartbeat_interval_ms` and must stay
+/// well below the watchdog's 10s timeout.
+pub fn start_heartbeat(app_handle: AppHandle, interval: Duration) {
+    start_ui_probe(app_handle);
+
+    tauri::async_runtime::spawn(async move {
         info!("🔄 Starting Named Pipe heartbeat client...");
+        let mut backoff = Backoff::new();
 
         loop {
-            match connect_and_heartbeat().await {
-                Ok(_) => {
-                    info!("Heartbeat loop ended gracefully");
+            match ClientOptions::new().open(HEARTBEAT_PIPE_NAME) {
+                Ok(client) => {
+                    info!("✅ Connected to watchdog via Named Pipe");
+                    backoff.reset();
+                    if let Err(e) = send_heartbeats(client, interval).await {
+                        warn!("Heartbeat pipe lost: {}", e);
+                    }
                 },
                 Err(e) => {
-                    error!("Heartbeat error: {}. Retrying in 5s...", e);
-                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    if RECONNECT_REQUESTED.swap(false, Ordering::Relaxed) {
+                        backoff.reset();
+                    }
+                    let delay = backoff.next_delay();
+                    warn!("Watchdog pipe unavailable ({}), retrying in {:?}", e, delay);
+                    tokio::time::sleep(delay).await;
                 },
             }
         }
     });
 }
 
-async fn connect_and_heartbeat() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // Connect to watchdog's Named Pipe server
-    let mut client = ClientOptions::new()
-        .open(PIPE_NAME)
-        .map_err(|e| format!("Failed to connect to watchdog pipe: {e}"))?;
+/// Writes frames until the pipe breaks.
+async fn send_heartbeats(mut client: NamedPipeClient, interval: Duration) -> std::io::Result<()> {
+    loop {
+        if RECONNECT_REQUESTED.swap(false, Ordering::Relaxed) {
+            return Err(std::io::Error::other("reconnect requested after resume"));
+        }
 
-    info!("✅ Connected to watchdog via Named Pipe");
+        let frame = HeartbeatFrame {
+            timestamp_secs: now_secs(),
+            ui_responsive: !UI_WATCH.hung.load(Ordering::Relaxed),
+        };
 
-    loop {
-        // Sleep first to avoid spamming on startup
-        tokio::time::sleep(Duration::from_millis(HEARTBEAT_INTERVAL_MS)).await;
-
-        // Get current timestamp
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_err(|e| format!("System time error: {e}"))?
-            .as_secs();
-
-        // Write 8-byte timestamp to pipe
-        if let Err(e) = client.write_u64(timestamp).await {
-            error!("Failed to write heartbeat: {}", e);
-            return Err(Box::new(e));
+        // Big-endian u64, read with read_u64 on the watchdog side
+        client.write_u64(frame.encode()).await?;
+        client.flush().await?;
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Re-opens the watchdog pipe and forgets the time spent asleep.
+///
+/// Called on resume: the suspended main thread would otherwise be reported
+/// as a UI hang, and a pipe handle from before the sleep may be stale.
+pub fn reconnect_after_resume() {
+    UI_WATCH.tick();
+    RECONNECT_REQUESTED.store(true, Ordering::Relaxed);
+}
+
+/// Probes the Tauri event loop from a dedicated thread.
+fn start_ui_probe(app_handle: AppHandle) {
+    std::thread::spawn(move || {
+        UI_WATCH.tick();
+
+        loop {
+            std::thread::sleep(UI_PROBE_INTERVAL);
+
+            if let Err(e) = app_handle.run_on_main_thread(|| UI_WATCH.tick()) {
+                // Event loop closed: the app is shutting down
+                warn!("UI probe stopped: {}", e);
+                break;
+            }
+
+            let stalled = UI_WATCH.stalled_for();
+            let hung = stalled >= UI_HANG_THRESHOLD;
+            if hung != UI_WATCH.hung.swap(hung, Ordering::Relaxed) {
+                if hung {
+                    warn!("🥶 UI thread not responding for {:.1}s", stalled.as_secs_f32());
+                } else {
+                    info!("✅ UI thread responsive again");
+                }
+            }
         }
+    });
+}
 
-        // Flush to ensure data is sent immediately
-        if let Err(e) = client.flush().await {
-            error!("Failed to flush heartbeat: {}", e);
-            return Err(Box::new(e));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap_and_resets() {
+        let mut backoff = Backoff::new();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+        assert_eq!(backoff.next_delay(), Duration::from_secs(1));
+        for _ in 0..10 {
+            backoff.next_delay();
         }
+        assert_eq!(backoff.next_delay(), RECONNECT_MAX_DELAY);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), RECONNECT_INITIAL_DELAY);
     }
 }
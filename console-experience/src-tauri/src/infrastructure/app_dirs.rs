@@ -0,0 +1,57 @@
+/**
+ * App Data Folder
+ *
+ * One app-local data folder for every Balam process:
+ * `%LOCALAPPDATA%\com.console.experience` (same folder as Tauri's
+ * `app_local_data_dir`).
+ */
+use std::path::PathBuf;
+use windows::core::GUID;
+use windows::Win32::System::Com::CoTaskMemFree;
+use windows::Win32::UI::Shell::{
+    FOLDERID_LocalAppData, FOLDERID_RoamingAppData, SHGetKnownFolderPath, KF_FLAG_DEFAULT,
+};
+
+/// Tauri bundle identifier (must match `tauri.conf.json`).
+pub const APP_IDENTIFIER: &str = "com.console.experience";
+
+/// A shell known folder (`FOLDERID_*`), `None` if the shell has none.
+#[must_use]
+pub fn known_folder(id: &GUID) -> Option<PathBuf> {
+    unsafe {
+        let path = SHGetKnownFolderPath(id, KF_FLAG_DEFAULT, None).ok()?;
+        let dir = path.to_string().ok().map(PathBuf::from);
+        CoTaskMemFree(Some(path.0 as *const _));
+        dir
+    }
+}
+
+/// The user's `AppData\Local`, from the shell first and `LOCALAPPDATA`
+/// second (`None` without a user profile, e.g. under LocalSystem).
+#[must_use]
+pub fn local_app_data() -> Option<PathBuf> {
+    known_folder(&FOLDERID_LocalAppData).or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+}
+
+/// Roaming app data folder (Tauri's `app_data_dir`), where older versions
+/// kept the external API token. Only read to migrate it.
+#[must_use]
+pub fn legacy_roaming_dir() -> Option<PathBuf> {
+    known_folder(&FOLDERID_RoamingAppData)
+        .or_else(|| std::env::var_os("APPDATA").map(PathBuf::from))
+        .map(|dir| dir.join(APP_IDENTIFIER))
+}
+
+/// App local data folder, or `None` when the user profile cannot be resolved.
+/// Callers that must not write to a shared location use this one.
+#[must_use]
+pub fn try_app_data_dir() -> Option<PathBuf> {
+    local_app_data().map(|dir| dir.join(APP_IDENTIFIER))
+}
+
+/// App local data folder, or `config` (relative to the working directory)
+/// when the user profile cannot be resolved.
+#[must_use]
+pub fn app_data_dir() -> PathBuf {
+    try_app_data_dir().unwrap_or_else(|| PathBuf::from("config"))
+}
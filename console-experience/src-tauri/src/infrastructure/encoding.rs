@@ -0,0 +1,37 @@
+/**
+ * String Encoding
+ *
+ * Conversions shared by the Win32 and XML adapters.
+ */
+use std::ffi::OsStr;
+use std::os::windows::ffi::OsStrExt;
+
+/// Null-terminated UTF-16 for `PCWSTR`/`PWSTR` parameters.
+#[must_use]
+pub fn to_wide(value: impl AsRef<OsStr>) -> Vec<u16> {
+    value.as_ref().encode_wide().chain(std::iter::once(0)).collect()
+}
+
+/// Text escaped for XML element content and attribute values.
+#[must_use]
+pub fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_wide_and_escape_xml() {
+        assert_eq!(to_wide("Ab"), vec![u16::from(b'A'), u16::from(b'b'), 0]);
+        assert_eq!(
+            escape_xml(r#"<a href="x">Tom & Jerry</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&lt;/a&gt;"
+        );
+    }
+}
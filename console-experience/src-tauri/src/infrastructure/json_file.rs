@@ -0,0 +1,31 @@
+/**
+ * JSON Files
+ *
+ * Pretty-printed JSON state files read with a default fallback.
+ */
+use crate::domain::BalamError;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Contents of a JSON file, or the default when it is missing or invalid.
+#[must_use]
+pub fn read_json<T: DeserializeOwned + Default>(path: &Path) -> T {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Writes `value` as pretty JSON, creating the parent folder if needed.
+///
+/// # Errors
+/// Returns error if serialization or the write fails.
+pub fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), BalamError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| BalamError::platform("Failed to create data directory", e))?;
+    }
+    let json = serde_json::to_string_pretty(value).map_err(|e| BalamError::platform("Failed to serialize", e))?;
+    fs::write(path, json).map_err(|e| BalamError::platform(&format!("Failed to write {}", path.display()), e))
+}
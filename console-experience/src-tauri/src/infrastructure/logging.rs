@@ -0,0 +1,337 @@
+/**
+ * Logging Infrastructure
+ *
+ * One log layout for every Balam process:
+ * - Main app and watchdog: `%LOCALAPPDATA%\com.console.experience\logs`
+ *   (same folder as Tauri's `app_local_data_dir`)
+ * - fps-service: `%ProgramData%\Balam\logs` (runs as LocalSystem in Session 0,
+ *   without console or user profile; the folder is readable by the app)
+ *
+ * Files rotate daily (`<component>.<date>.log`) and only the last
+ * `MAX_LOG_FILES` are kept per component. Lines use the plain tracing format
+ * without ANSI colors, which `parse_log_line` turns back into `LogEntry`s.
+ */
+use super::app_dirs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::writer::MakeWriterExt;
+
+/// Daily log files kept per component.
+const MAX_LOG_FILES: usize = 7;
+
+/// Default number of entries returned by `read_recent_logs`.
+const DEFAULT_LOG_LIMIT: usize = 500;
+
+/// Process that wrote a log file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LogComponent {
+    App,
+    Watchdog,
+    FpsService,
+    Broker,
+}
+
+impl LogComponent {
+    pub const ALL: [Self; 4] = [Self::App, Self::Watchdog, Self::FpsService, Self::Broker];
+
+    /// Log file prefix (`balam.2025-01-31.log`).
+    #[must_use]
+    pub fn file_prefix(&self) -> &'static str {
+        match self {
+            Self::App => "balam",
+            Self::Watchdog => "watchdog",
+            Self::FpsService => "fps-service",
+            Self::Broker => "broker",
+        }
+    }
+
+    /// Folder holding this component's logs.
+    #[must_use]
+    pub fn log_dir(&self) -> PathBuf {
+        match self {
+            Self::App | Self::Watchdog | Self::Broker => app_log_dir(),
+            Self::FpsService => service_log_dir(),
+        }
+    }
+}
+
+/// Logs of the main app and the watchdog.
+#[must_use]
+pub fn app_log_dir() -> PathBuf {
+    app_dirs::app_data_dir().join("logs")
+}
+
+/// Logs of fps-service (must match the path used by the service).
+#[must_use]
+pub fn service_log_dir() -> PathBuf {
+    std::env::var_os("ProgramData")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(r"C:\ProgramData"))
+        .join("Balam")
+        .join("logs")
+}
+
+/// Installs the global tracing subscriber: rolling file + stdout.
+///
+/// Keep the returned guard alive for the whole process: dropping it stops
+/// the background writer. Returns `None` (stdout only) if the log folder
+/// cannot be created.
+#[must_use]
+pub fn init_logging(component: LogComponent) -> Option<WorkerGuard> {
+    let dir = component.log_dir();
+    let appender = fs::create_dir_all(&dir).ok().and_then(|()| {
+        RollingFileAppender::builder()
+            .rotation(Rotation::DAILY)
+            .filename_prefix(component.file_prefix())
+            .filename_suffix("log")
+            .max_log_files(MAX_LOG_FILES)
+            .build(&dir)
+            .ok()
+    });
+
+    match appender {
+        Some(appender) => {
+            let (file_writer, guard) = tracing_appender::non_blocking(appender);
+            tracing_subscriber::fmt()
+                .with_writer(file_writer.and(std::io::stdout))
+                .with_max_level(LevelFilter::INFO)
+                .with_ansi(false)
+                .init();
+            tracing::info!("Logging to {}", dir.display());
+            Some(guard)
+        },
+        None => {
+            tracing_subscriber::fmt().with_max_level(LevelFilter::INFO).init();
+            tracing::warn!("Cannot write logs to {}, using stdout only", dir.display());
+            None
+        },
+    }
+}
+
+/// One parsed log line.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LogEntry {
+    /// RFC 3339 timestamp as written by tracing
+    pub timestamp: String,
+    /// `TRACE` | `DEBUG` | `INFO` | `WARN` | `ERROR`
+    pub level: String,
+    pub component: LogComponent,
+    /// Module path (`None` if the line has no target)
+    pub target: Option<String>,
+    pub message: String,
+}
+
+/// Filter for `read_recent_logs` (all fields optional).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogFilter {
+    /// Minimum level (e.g. `"warn"` returns warnings and errors)
+    pub min_level: Option<String>,
+    /// Only this component
+    pub component: Option<LogComponent>,
+    /// Case-insensitive substring of the message or target
+    pub contains: Option<String>,
+    /// Maximum entries (default 500)
+    pub limit: Option<usize>,
+}
+
+impl LogFilter {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        if let Some(min) = self.min_level.as_deref().and_then(level_rank) {
+            if level_rank(&entry.level).is_some_and(|rank| rank < min) {
+                return false;
+            }
+        }
+        if let Some(needle) = &self.contains {
+            let needle = needle.to_lowercase();
+            let in_target = entry
+                .target
+                .as_ref()
+                .is_some_and(|t| t.to_lowercase().contains(&needle));
+            if !in_target && !entry.message.to_lowercase().contains(&needle) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn level_rank(level: &str) -> Option<u8> {
+    match level.trim().to_ascii_uppercase().as_str() {
+        "TRACE" => Some(0),
+        "DEBUG" => Some(1),
+        "INFO" => Some(2),
+        "WARN" => Some(3),
+        "ERROR" => Some(4),
+        _ => None,
+    }
+}
+
+/// Parses `2025-01-31T10:00:00.123456Z  INFO target::path: message`.
+///
+/// Returns `None` for continuation lines of multi-line messages.
+#[must_use]
+pub fn parse_log_line(line: &str, component: LogComponent) -> Option<LogEntry> {
+    let mut parts = line.splitn(2, char::is_whitespace);
+    let timestamp = parts.next()?;
+    // Timestamps start with the year
+    if timestamp.len() < 10 || !timestamp.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let rest = parts.next()?.trim_start();
+    let (level, rest) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    level_rank(level)?;
+
+    // The target is a single token ending with ':' (module paths have no spaces)
+    let (target, message) = match rest.split_once(": ") {
+        Some((target, message)) if !target.is_empty() && !target.contains(char::is_whitespace) => {
+            (Some(target.to_string()), message)
+        },
+        _ => (None, rest),
+    };
+
+    Some(LogEntry {
+        timestamp: timestamp.to_string(),
+        level: level.to_string(),
+        component,
+        target,
+        message: message.to_string(),
+    })
+}
+
+/// Log files of a component, newest first.
+#[must_use]
+pub fn log_files(component: LogComponent) -> Vec<PathBuf> {
+    let prefix = format!("{}.", component.file_prefix());
+    let Ok(entries) = fs::read_dir(component.log_dir()) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".log"))
+        })
+        .collect();
+    // Date in the file name sorts chronologically
+    files.sort();
+    files.reverse();
+    files
+}
+
+/// Reads the most recent log entries matching `filter`, newest first.
+///
+/// Multi-line messages (panics, backtraces) are joined to their entry.
+#[must_use]
+pub fn read_recent_logs(filter: &LogFilter) -> Vec<LogEntry> {
+    let limit = filter.limit.unwrap_or(DEFAULT_LOG_LIMIT);
+    let components: Vec<LogComponent> = filter
+        .component
+        .map_or_else(|| LogComponent::ALL.to_vec(), |component| vec![component]);
+
+    let mut entries = Vec::new();
+    for component in components {
+        let mut component_entries = Vec::new();
+        for file in log_files(component) {
+            let Ok(content) = fs::read_to_string(&file) else {
+                continue;
+            };
+            let mut file_entries = parse_log_content(&content, component);
+            file_entries.retain(|entry| filter.matches(entry));
+            file_entries.reverse();
+            component_entries.extend(file_entries);
+            if component_entries.len() >= limit {
+                break;
+            }
+        }
+        component_entries.truncate(limit);
+        entries.extend(component_entries);
+    }
+
+    // RFC 3339 UTC timestamps sort lexicographically
+    entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    entries.truncate(limit);
+    entries
+}
+
+fn parse_log_content(content: &str, component: LogComponent) -> Vec<LogEntry> {
+    let mut entries: Vec<LogEntry> = Vec::new();
+    for line in content.lines() {
+        match parse_log_line(line, component) {
+            Some(entry) => entries.push(entry),
+            None => {
+                if let Some(last) = entries.last_mut() {
+                    last.message.push('\n');
+                    last.message.push_str(line);
+                }
+            },
+        }
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_line() {
+        let entry = parse_log_line(
+            "2025-01-31T10:00:00.123456Z  INFO console_experience_lib::application: 🎮 Game launched",
+            LogComponent::App,
+        )
+        .unwrap();
+        assert_eq!(entry.level, "INFO");
+        assert_eq!(entry.target.as_deref(), Some("console_experience_lib::application"));
+        assert_eq!(entry.message, "🎮 Game launched");
+
+        let entry = parse_log_line("2025-01-31T10:00:00Z  WARN no target here", LogComponent::Watchdog).unwrap();
+        assert_eq!(entry.level, "WARN");
+        assert_eq!(entry.target, None);
+        assert_eq!(entry.message, "no target here");
+
+        assert!(parse_log_line("   at std::panicking::begin_panic", LogComponent::App).is_none());
+        assert!(parse_log_line("", LogComponent::App).is_none());
+    }
+
+    #[test]
+    fn test_continuation_lines_are_joined() {
+        let content = "2025-01-31T10:00:00Z ERROR app: panicked\nstack line 1\n2025-01-31T10:00:01Z  INFO app: next";
+        let entries = parse_log_content(content, LogComponent::App);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].message, "panicked\nstack line 1");
+    }
+
+    #[test]
+    fn test_filter() {
+        let entry = |level: &str, message: &str| LogEntry {
+            timestamp: "2025-01-31T10:00:00Z".to_string(),
+            level: level.to_string(),
+            component: LogComponent::App,
+            target: Some("console_experience_lib::adapters::wifi".to_string()),
+            message: message.to_string(),
+        };
+
+        let filter = LogFilter {
+            min_level: Some("warn".to_string()),
+            ..LogFilter::default()
+        };
+        assert!(!filter.matches(&entry("INFO", "connected")));
+        assert!(filter.matches(&entry("ERROR", "failed")));
+
+        let filter = LogFilter {
+            contains: Some("WIFI".to_string()),
+            ..LogFilter::default()
+        };
+        assert!(filter.matches(&entry("INFO", "connected")));
+    }
+}
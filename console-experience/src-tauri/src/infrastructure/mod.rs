@@ -1 +1,6 @@
-// Placeholder
+// Infrastructure Layer: cross-cutting technical concerns
+pub mod app_dirs;
+pub mod clock;
+pub mod encoding;
+pub mod json_file;
+pub mod logging;
@@ -5,7 +5,7 @@ pub mod adapters;
 pub mod application;
 pub mod config;
 pub mod domain;
-// mod heartbeat; // DISABLED: Watchdog not running
+mod heartbeat;
 pub mod infrastructure;
 pub mod ports;
 
@@ -14,40 +14,67 @@ use crate::application::commands::{
     add_game_manually,
     // Performance commands
     apply_performance_profile,
+    // Update commands
+    apply_update,
     close_current_game,
     // Network commands
+    check_connectivity,
+    check_for_updates,
     connect_bluetooth_device,
     connect_wifi,
     disconnect_bluetooth_device,
     disconnect_wifi,
     forget_wifi,
+    get_bluetooth_device_details,
     get_brightness,
     get_connected_bluetooth_devices,
+    get_console_mode_status,
     get_current_wifi,
+    // Dashboard commands
+    get_dashboard_data,
     // HDR commands
     get_displays,
     // FPS Service commands
     get_fps_service_status,
     get_fps_stats,
     get_games,
+    get_games_cached,
     // Overlay commands
+    get_network_details,
+    get_network_quality,
     get_overlay_status,
     get_paired_bluetooth_devices,
     get_performance_metrics,
+    get_active_alerts,
+    start_auto_tdp,
+    stop_auto_tdp,
+    get_auto_tdp_status,
     get_primary_display,
     get_refresh_rate,
     get_running_game,
     get_saved_networks,
+    // Settings commands
+    get_settings,
+    get_supervision_status,
     get_supported_refresh_rates,
     get_system_drives,
     get_system_status,
+    get_tdp_backend,
     get_tdp_config,
     get_whitelisted_games,
     get_wifi_signal_strength,
+    // Hotkey commands
+    check_hotkey_conflict,
+    // Console mode commands
+    disable_console_mode,
+    enable_console_mode,
     // Haptic commands
     haptic_action,
     haptic_event,
     haptic_navigation,
+    list_haptic_patterns,
+    play_haptic_pattern,
+    stop_haptics,
     // PiP commands
     hide_game_overlay,
     show_main_window,
@@ -57,17 +84,186 @@ use crate::application::commands::{
     is_game_whitelisted,
     is_haptic_supported,
     get_active_game,
+    // Session commands
+    focus_game,
+    get_input_focus,
+    list_active_games,
+    resume_game,
+    suspend_game,
+    // Library organization commands
+    assign_to_collection,
+    create_collection,
+    delete_collection,
+    get_game_details,
+    set_epic_launch_backend,
+    set_launcher_dependency,
+    mark_not_a_game,
+    merge_games,
+    split_game,
+    explain_dedup,
+    legendary_login,
+    get_library_organization,
+    get_library_rows,
+    set_favorite,
+    set_game_tags,
+    set_hidden,
+    // Notification commands
+    clear_notifications,
+    get_notifications,
+    // Diagnostics commands
+    export_diagnostics_zip,
+    get_present_report,
+    // Overlay conflict commands
+    get_overlay_conflicts,
+    set_game_bar_enabled,
+    // Overlay layout commands
+    set_overlay_layout,
+    update_rtss_overlay,
+    clear_rtss_overlay,
+    set_overlay_level,
+    cycle_overlay_level,
+    report_overlay_renderer_alive,
+    get_recent_logs,
+    // External API commands
+    get_external_api_info,
+    regenerate_external_api_token,
+    // Companion remote commands
+    get_remote_pairing,
+    reset_remote_pairing,
+    respond_to_remote_device,
+    // Sunshine commands
+    get_sunshine_status,
+    start_sunshine,
+    stop_sunshine,
+    submit_sunshine_pin,
+    sync_sunshine_library,
+    // Parental controls commands
+    get_parental_controls,
+    get_parental_status,
+    lock_parental_controls,
+    save_parental_controls,
+    set_parental_pin,
+    unlock_with_pin,
+    // Telemetry commands
+    clear_telemetry_data,
+    get_telemetry_preview,
+    // Launch history commands
+    get_launch_history,
+    get_launch_stats,
+    // Benchmark commands
+    cancel_benchmark,
+    delete_benchmark_run,
+    export_benchmark_run,
+    get_benchmark_run,
+    list_benchmark_runs,
+    start_benchmark,
+    // Stutter diagnostics commands
+    get_stutter_report,
+    start_stutter_diagnostics,
+    stop_stutter_diagnostics,
+    // Autostart commands
+    get_autostart_status,
+    get_launch_options,
+    set_autostart,
+    disable_autostart,
+    // Safe mode commands
+    exit_safe_mode,
+    // Microphone commands
+    get_mic_muted,
+    get_mic_volume,
+    list_input_devices,
+    set_default_input_device,
+    set_mic_muted,
+    set_mic_volume,
+    toggle_mic_mute,
+    // Screenshot, replay and gallery commands
+    delete_media,
+    get_media,
+    get_replay_status,
+    get_screenshots,
+    rename_media,
+    save_replay,
+    take_screenshot,
+    // Display color commands
+    restore_color_profile,
+    set_color_profile,
+    set_game_color_profile,
+    set_game_window_mode,
+    get_game_window_mode,
+    set_night_light,
+    supports_vibrance,
+    // GPU upscaling commands
+    get_scaling_capabilities,
+    set_game_scaling_profile,
+    // Quick settings commands
+    confirm_display_change,
+    get_quick_settings,
+    quick_cycle_refresh_rate,
+    quick_set_resolution,
+    quick_toggle_half_rate,
+    revert_display_change,
+    // Quiet mode commands
+    get_quiet_mode_status,
+    set_quiet_mode,
+    // Fan control commands
+    get_fan_curve,
+    get_fan_status,
+    reset_fan_curve,
+    set_fan_curve,
+    supports_fan_control,
+    // Sleep commands
+    cancel_sleep_timer,
+    get_idle_status,
+    set_auto_suspend_minutes,
+    set_sleep_timer,
+    suspend_pc,
+    // Power plan commands
+    get_cpu_boost_mode,
+    get_power_mode,
+    list_power_plans,
+    set_cpu_boost_mode,
+    set_game_power_profile,
+    set_power_mode,
+    set_power_plan,
+    // Storage commands
+    move_game,
+    get_xbox_titles,
+    install_xbox_game,
+    uninstall_xbox_game,
+    rebuild_game_file_manifest,
+    verify_game_files,
+    // Text input commands
+    get_text_input_state,
+    hide_touch_keyboard,
+    send_virtual_key,
+    send_virtual_text,
+    set_text_input_focus,
+    show_touch_keyboard,
+    install_tdp_tools,
     is_nvml_available,
     is_pip_visible,
+    open_companion_pip,
+    close_companion_pip,
+    snap_companion_pip,
+    set_companion_pip_opacity,
+    resize_companion_pip,
     kill_game,
     launch_game,
+    start_update,
     // System commands
     list_audio_devices,
     list_directory,
     log_message,
     logout_pc,
+    open_captive_portal,
+    open_store_browser,
+    close_store_browser,
+    store_browser_action,
     pair_bluetooth_device,
+    rebind_hotkey,
     remove_game,
+    reset_settings,
+    respond_to_pairing,
     restart_pc,
     scan_bluetooth_devices,
     scan_games,
@@ -75,6 +271,8 @@ use crate::application::commands::{
     set_bluetooth_enabled,
     set_brightness,
     set_default_audio_device,
+    set_extra_button_binding,
+    set_gamepad_chord,
     set_hdr_enabled,
     set_overlay_click_through,
     set_overlay_opacity,
@@ -87,6 +285,14 @@ use crate::application::commands::{
     start_fps_service,
     stop_fps_service,
     supports_brightness_control,
+    // Per-monitor display commands
+    get_monitor_brightness,
+    get_monitor_contrast,
+    get_monitor_refresh_rates,
+    get_monitors,
+    set_monitor_brightness,
+    set_monitor_contrast,
+    set_monitor_refresh_rate,
     supports_tdp_control,
     toggle_fps_service,
     toggle_game_overlay,
@@ -95,42 +301,74 @@ use crate::application::commands::{
     uninstall_fps_service,
     unpair_bluetooth_device,
     update_fps_service,
+    update_settings,
 };
 use crate::application::DIContainer;
-use tauri::Emitter;
-use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use crate::domain::overlay_layout::OverlayLevel;
+use crate::domain::HotkeyAction;
+use tauri::{Emitter, Listener, Manager};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
-use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut, ShortcutState};
+use tauri_plugin_global_shortcut::{Code, Shortcut, ShortcutState};
+
+/// WebView2 flags for every Balam window.
+///
+/// Chromium's native window occlusion tracking throttles, then suspends, a
+/// WebView covered by a fullscreen game: requestAnimationFrame stops and IPC
+/// events pile up in the overlay. `msWebView2BrowserHitTransparent` lets
+/// clicks pass through the transparent parts of the overlay.
+/// Windows sharing a WebView2 environment must be created with identical flags,
+/// so windows built at runtime pass the same string.
+/// Reference: https://chromium.googlesource.com/chromium/src.git/+/master/docs/windows_native_window_occlusion_tracking.md
+pub(crate) const WEBVIEW2_BROWSER_ARGS: &str = concat!(
+    // Tauri's defaults (msWebOOUI, ...) are replaced by custom flags: keep them
+    "--disable-features=CalculateNativeWinOcclusion,msWebOOUI,msPdfOOUI,msSmartScreenProtection ",
+    "--enable-features=msWebView2BrowserHitTransparent ",
+    "--disable-backgrounding-occluded-windows --disable-renderer-backgrounding --disable-background-timer-throttling",
+);
+
+/// Executes the action bound to a keyboard shortcut.
+fn dispatch_hotkey_action(app: &tauri::AppHandle, action: HotkeyAction) {
+    match action {
+        HotkeyAction::ToggleOverlay => {
+            // Toggle native game overlay (TOPMOST or DLL injection)
+            let app_handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = toggle_game_overlay(app_handle).await {
+                    tracing::error!("Failed to toggle overlay: {}", e);
+                }
+            });
+        },
+        HotkeyAction::ToggleMicMute => {
+            if let Err(e) = crate::application::commands::toggle_microphone_mute(app) {
+                tracing::warn!("Failed to toggle microphone mute: {}", e);
+            }
+        },
+        HotkeyAction::TakeScreenshot => crate::application::commands::take_screenshot_in_background(app),
+        HotkeyAction::SaveReplay => crate::application::commands::save_replay_in_background(app),
+        HotkeyAction::CycleOverlayLevel => {
+            if let Err(e) = crate::application::commands::apply_overlay_level(app, OverlayLevel::next) {
+                tracing::warn!("Failed to cycle overlay level: {}", e);
+            }
+        },
+        // Quick panels (WiFi, Bluetooth) are handled by the frontend
+        other => {
+            if let Some(event) = other.frontend_event() {
+                let _ = app.emit(event, true);
+            }
+        },
+    }
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 #[allow(clippy::too_many_lines)]
 pub fn run() {
-    use std::io;
-    use tracing_subscriber::fmt::writer::MakeWriterExt;
-
-    // Disable Chromium's Windows Native Window Occlusion tracking so the WebView2
-    // process is never throttled/suspended when covered by the fullscreen game.
-    // Without this, requestAnimationFrame stops and JS execution slows down after
-    // a few minutes, causing Tauri IPC events to stop being processed in the overlay.
-    // Reference: https://chromium.googlesource.com/chromium/src.git/+/master/docs/windows_native_window_occlusion_tracking.md
-    std::env::set_var(
-        "WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS",
-        "--disable-backgrounding-occluded-windows --disable-renderer-backgrounding --disable-background-timer-throttling",
-    );
-
-    // Initialize tracing with BOTH file AND terminal output
-    let file_appender = RollingFileAppender::new(Rotation::DAILY, "logs", "balam.log");
-    let (non_blocking_file, guard) = tracing_appender::non_blocking(file_appender);
-
-    let file_writer = non_blocking_file;
-    let stdout_writer = io::stdout;
-
-    // Combine both writers
-    tracing_subscriber::fmt()
-        .with_writer(file_writer.and(stdout_writer))
-        .with_target(false)
-        .init();
+    // The main window comes from tauri.conf.json: WebView2 picks the flags up
+    // from the environment when it is created.
+    std::env::set_var("WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS", WEBVIEW2_BROWSER_ARGS);
+
+    // Initialize tracing with BOTH file (app data, rotated daily) AND terminal output
+    let guard = crate::infrastructure::logging::init_logging(crate::infrastructure::logging::LogComponent::App);
 
     tracing::info!("🎮 Balam Console Experience starting...");
 
@@ -140,7 +378,7 @@ pub fn run() {
 
     // Initialize Dependency Injection Container
     let container = DIContainer::new();
-    let _container_clone = container.clone(); // Unused while WindowMonitor is disabled
+    let container_clone = container.clone();
 
     tauri::Builder::default()
         .plugin(
@@ -167,44 +405,36 @@ pub fn run() {
         .plugin(
             tauri_plugin_global_shortcut::Builder::new()
                 .with_handler(move |app, shortcut, event| {
+                    // Push-to-talk: the mic hotkey unmutes on press and mutes again on release
+                    let container = app.state::<DIContainer>();
+                    let action = container.hotkey_manager.action_for(shortcut);
+                    if action == Some(HotkeyAction::ToggleMicMute)
+                        && container.settings_service.get().hotkeys.mic_push_to_talk
+                    {
+                        let held = event.state == ShortcutState::Pressed;
+                        if let Err(e) = crate::application::commands::set_push_to_talk(app, held) {
+                            tracing::warn!("Push-to-talk failed: {}", e);
+                        }
+                        return;
+                    }
+
                     if event.state == ShortcutState::Pressed {
                         // 1. Capture and handle volume keys manually using correct fields: mods, key
                         if shortcut.key == Code::AudioVolumeUp {
-                            let adapter = crate::adapters::windows_system_adapter::WindowsSystemAdapter::new();
-                            let status = crate::ports::system_port::SystemPort::get_status(&adapter);
-                            let _ = crate::ports::system_port::SystemPort::set_volume(
-                                &adapter,
-                                (status.volume + 5).min(100),
-                            );
+                            let status = container.system.get_status();
+                            let _ = container.system.set_volume((status.volume + 5).min(100));
                         } else if shortcut.key == Code::AudioVolumeDown {
-                            let adapter = crate::adapters::windows_system_adapter::WindowsSystemAdapter::new();
-                            let status = crate::ports::system_port::SystemPort::get_status(&adapter);
-                            let _ = crate::ports::system_port::SystemPort::set_volume(
-                                &adapter,
-                                status.volume.saturating_sub(5),
-                            );
+                            let status = container.system.get_status();
+                            let _ = container.system.set_volume(status.volume.saturating_sub(5));
                         } else if shortcut.key == Code::AudioVolumeMute {
-                            let adapter = crate::adapters::windows_system_adapter::WindowsSystemAdapter::new();
-                            let status = crate::ports::system_port::SystemPort::get_status(&adapter);
+                            let status = container.system.get_status();
                             let next = if status.volume > 0 { 0 } else { 30 };
-                            let _ = crate::ports::system_port::SystemPort::set_volume(&adapter, next);
-                        } else if shortcut.key == Code::KeyQ
-                            && shortcut.mods.contains(Modifiers::CONTROL | Modifiers::SHIFT)
-                        {
-                            // Toggle native game overlay (TOPMOST or DLL injection)
-                            // This uses the new overlay system that creates a dedicated overlay window
-                            let app_handle = app.clone();
-                            tauri::async_runtime::spawn(async move {
-                                if let Err(e) = toggle_game_overlay(app_handle).await {
-                                    tracing::error!("Failed to toggle overlay: {}", e);
-                                }
-                            });
-                        } else if shortcut.key == Code::KeyW && shortcut.mods.contains(Modifiers::CONTROL) {
-                            // WiFi Panel toggle
-                            let _ = app.emit("toggle-wifi-panel", true);
-                        } else if shortcut.key == Code::KeyB && shortcut.mods.contains(Modifiers::CONTROL) {
-                            // Bluetooth Panel toggle
-                            let _ = app.emit("toggle-bluetooth-panel", true);
+                            let _ = container.system.set_volume(next);
+                        } else {
+                            // User-configurable shortcuts (overlay, quick panels)
+                            if let Some(action) = action {
+                                dispatch_hotkey_action(app, action);
+                            }
                         }
                     }
                 })
@@ -217,16 +447,7 @@ pub fn run() {
             {
                 use tauri_plugin_global_shortcut::GlobalShortcutExt;
 
-                // Register shortcuts
-                let _ = app
-                    .global_shortcut()
-                    .register(Shortcut::new(Some(Modifiers::CONTROL | Modifiers::SHIFT), Code::KeyQ));
-                let _ = app
-                    .global_shortcut()
-                    .register(Shortcut::new(Some(Modifiers::CONTROL), Code::KeyW)); // WiFi Panel
-                let _ = app
-                    .global_shortcut()
-                    .register(Shortcut::new(Some(Modifiers::CONTROL), Code::KeyB)); // Bluetooth Panel
+                // Register media keys (fixed); user hotkeys are registered by the HotkeyManager
                 let _ = app.global_shortcut().register(Shortcut::new(None, Code::AudioVolumeUp));
                 let _ = app
                     .global_shortcut()
@@ -236,65 +457,465 @@ pub fn run() {
                     .register(Shortcut::new(None, Code::AudioVolumeMute));
             }
 
+            // Autostart with "start minimized": keep the UI hidden until summoned
+            let launch_options =
+                crate::domain::autostart::LaunchOptions::from_args(std::env::args());
+            if launch_options.start_minimized {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.hide();
+                }
+                tracing::info!("Started minimized (autostart)");
+            }
+            // Safe mode (watchdog, after a crash loop): checked by every optional feature
+            if launch_options.safe_mode {
+                crate::application::safe_mode::enable();
+            }
+
+            // Opt-in telemetry: counts a crash if the last session did not exit cleanly
+            container_clone.telemetry.begin_session();
+            container_clone.telemetry.start();
+
+            // Overlay notifications need the app handle (the router is created with the container)
+            container_clone.notifications.attach(app.handle().clone());
+
+            // Play time for "Most Played": every watchdog reports the session length in game-ended
+            let play_history_handle = app.handle().clone();
+            let color_container = container_clone.clone();
+            app.listen("game-ended", move |event| {
+                #[derive(serde::Deserialize)]
+                struct GameEndedPayload {
+                    game_id: String,
+                    play_time_seconds: u64,
+                }
+
+                if let Ok(payload) = serde_json::from_str::<GameEndedPayload>(event.payload()) {
+                    crate::application::commands::record_game_session(
+                        &play_history_handle,
+                        &payload.game_id,
+                        payload.play_time_seconds,
+                    );
+
+                    // Post-game report card, kept with the play history
+                    if let Some(summary) =
+                        color_container.session_stats.finish(&payload.game_id, payload.play_time_seconds)
+                    {
+                        let _ = play_history_handle.emit("session-summary", &summary);
+                        crate::application::commands::record_session_summary(&play_history_handle, summary);
+                    }
+
+                    // Drop the game's color profile: back to the next session's, night light or neutral
+                    let display = color_container.settings_service.get().display;
+                    if display.game_color_profiles.contains_key(&payload.game_id) {
+                        let focused = color_container
+                            .active_games_tracker
+                            .focused()
+                            .filter(|game_id| *game_id != payload.game_id);
+                        crate::application::commands::apply_display_color(&color_container, focused.as_deref());
+                    }
+
+                    // Power mode / CPU boost go back to what the player had before the game,
+                    // and so do priority/affinity of a process outliving the session
+                    if color_container
+                        .settings_service
+                        .get()
+                        .performance
+                        .game_power_profiles
+                        .contains_key(&payload.game_id)
+                    {
+                        crate::application::commands::restore_power_profile();
+                    }
+                    crate::adapters::process_launcher::restore_game_process(&payload.game_id);
+
+                    // Driver upscaling is global: the player's setting comes back with the game
+                    if display.game_scaling_profiles.contains_key(&payload.game_id) {
+                        if let Err(e) = color_container.gpu_scaling.restore() {
+                            tracing::warn!("Failed to restore driver upscaling: {}", e);
+                        }
+                    }
+
+                    // Auto TDP follows a game: the last one closing hands the TDP back
+                    let others_running = color_container
+                        .active_games_tracker
+                        .list_active()
+                        .iter()
+                        .any(|game_id| *game_id != payload.game_id);
+                    if !others_running {
+                        if let Err(e) = color_container.auto_tdp.stop(true) {
+                            tracing::warn!("Failed to restore TDP after auto mode: {}", e);
+                        }
+                    }
+
+                    // Companion apps close, then post-exit hooks (VPN off, backups) run
+                    crate::application::launch_hooks::run_post_exit_in_background(
+                        &play_history_handle,
+                        &payload.game_id,
+                    );
+                }
+            });
+
+            // Launch history: watchdogs report the game's start in game-process-started
+            let launch_history = container_clone.launch_history.clone();
+            app.listen("game-process-started", move |event| {
+                #[derive(serde::Deserialize)]
+                struct GameStartedPayload {
+                    game_id: String,
+                }
+
+                if let Ok(payload) = serde_json::from_str::<GameStartedPayload>(event.payload()) {
+                    launch_history.finish(
+                        &payload.game_id,
+                        crate::domain::launch_history::LaunchOutcome::Success,
+                        None,
+                    );
+                }
+            });
+
+            // Bundled installs ship RyzenAdj; portable builds fetch it on first run
+            crate::application::commands::ensure_tdp_tools();
+            crate::application::commands::restore_saved_fan_curve(&container_clone);
+
+            // Night light persists across restarts (gamma ramps reset when the app exits)
+            if container_clone.settings_service.get().display.night_light {
+                crate::application::commands::apply_display_color(&container_clone, None);
+            }
+
+            // Configurable hotkeys: register from settings and re-apply on every change
+            let hotkey_manager = container_clone.hotkey_manager.clone();
+            let hotkeys = container_clone.settings_service.get().hotkeys;
+            if let Err(e) = hotkey_manager.apply(app.handle(), &hotkeys) {
+                tracing::warn!("Failed to register hotkeys: {}", e);
+            }
+            if hotkeys.mic_push_to_talk {
+                // Push-to-talk starts muted; the hotkey opens the mic while held
+                if let Err(e) = crate::application::commands::set_push_to_talk(app.handle(), false) {
+                    tracing::warn!("Failed to mute microphone for push-to-talk: {}", e);
+                }
+            }
+            let hotkey_app = app.handle().clone();
+            container_clone.settings_service.subscribe(Box::new(move |settings| {
+                if let Err(e) = hotkey_manager.apply(&hotkey_app, &settings.hotkeys) {
+                    tracing::warn!("Failed to re-register hotkeys: {}", e);
+                }
+            }));
+
             // Native Gamepad: Windows.Gaming.Input Engine
             crate::adapters::gamepad_adapter::start_gamepad_listener(app.handle().clone());
+            // Raw HID extras: DualSense / Switch Pro gyro, touchpad and paddles
+            crate::adapters::hid_input::start_hid_listener(app.handle().clone());
+
+            // Heartbeat to the boot supervisor: only when running as the Windows shell
+            // (console mode), where the watchdog is the process that started us
+            if crate::application::commands::is_running_as_shell() {
+                let interval_ms = container_clone.settings_service.get().watchdog.heartbeat_interval_ms;
+                heartbeat::start_heartbeat(app.handle().clone(), std::time::Duration::from_millis(interval_ms));
+                tracing::info!("Heartbeat started for crash recovery (console mode)");
+            }
+
+            // System status: pushed to the frontend on native change notifications
+            let status_app = app.handle().clone();
+            container_clone.system_status.subscribe(Box::new(move |status, previous| {
+                let _ = status_app.emit("system-status-changed", status);
+                if !previous.is_some_and(|previous| previous.volume == status.volume) {
+                    let _ = status_app.emit("volume-changed", status.volume);
+                }
+            }));
+            container_clone.system_status.start(
+                container_clone.system.clone(),
+                &crate::adapters::system_events::WindowsSystemEvents::new(),
+                container_clone.settings_service.clone(),
+            );
+
+            // Resume from sleep: reconnect heartbeat, restart ETW, re-check game sessions
+            crate::application::power_events::start(
+                app.handle(),
+                container_clone.clone(),
+                &crate::adapters::system_events::WindowsSystemEvents::new(),
+            );
+
+            // Sleep timer and idle auto-suspend (gamepad input reported by the gamepad listener)
+            let suspend_app = app.handle().clone();
+            container_clone.idle.start(
+                container_clone.system.clone(),
+                container_clone.settings_service.clone(),
+                container_clone.active_games_tracker.clone(),
+                Box::new(move |reason| {
+                    let _ = suspend_app.emit("system-suspending", reason);
+                }),
+            );
+
+            // Parental playtime limits: warn, then close games through the kill pipeline
+            let time_up_app = app.handle().clone();
+            container_clone.parental.start(
+                container_clone.active_games_tracker.clone(),
+                container_clone.notifications.clone(),
+                Box::new(move |session| {
+                    let pid = session.pid.unwrap_or(0);
+                    if let Err(e) = crate::application::commands::kill_game(pid, time_up_app.state()) {
+                        tracing::warn!("Failed to close {} at playtime limit: {}", session.game.title, e);
+                    }
+                }),
+            );
+
+            // Covers, heroes and icons: fetched off the library's critical path
+            let artwork_app = app.handle().clone();
+            // Blocking HTTP client: created on the first artwork worker that needs it
+            let ms_store = std::sync::OnceLock::new();
+            container_clone.artwork.start(
+                Box::new(move |game, dir| {
+                    let ms_store =
+                        ms_store.get_or_init(crate::adapters::microsoft_store_adapter::MicrosoftStoreAdapter::new);
+                    crate::adapters::metadata_adapter::MetadataAdapter::fetch_artwork(game, dir, ms_store)
+                }),
+                Box::new(move |artwork| {
+                    let _ = artwork_app.emit("game-artwork-ready", artwork);
+                }),
+            );
+
+            // Safe mode stops here: the optional services below are where a crash loop may come from
+            if crate::application::safe_mode::is_active() {
+                return Ok(());
+            }
 
-            // DISABLED: WMI Window Monitor (requires special permissions)
-            // TODO: Replace with alternative process monitoring method
-            // let mut window_monitor = crate::adapters::window_monitor::WindowMonitor::new(
-            //     container_clone.active_games_tracker.clone(),
-            //     app.handle().clone(),
-            // );
-            // if let Err(e) = window_monitor.start() {
-            //     tracing::error!("Failed to start window monitor: {}", e);
-            // } else {
-            //     tracing::info!("Window monitor started successfully (Level 3 Robustness)");
-            // }
-            // app.manage(window_monitor);
-
-            // DISABLED: Heartbeat watchdog (watchdog process not running)
-            // TODO: Launch watchdog process separately or remove this feature
-            // heartbeat::start_heartbeat_thread();
-            // tracing::info!("Heartbeat thread started for crash recovery");
-
-            // Start System Monitor Thread (Volume, Battery, etc.)
-            let app_handle = app.handle().clone();
-            std::thread::spawn(move || {
-                let adapter = crate::adapters::windows_system_adapter::WindowsSystemAdapter::new();
-                let mut last_vol = 0;
-
-                loop {
-                    // Update Status Check
-                    let status = crate::ports::system_port::SystemPort::get_status(&adapter);
-                    if status.volume != last_vol {
-                        let _ = app_handle.emit("volume-changed", status.volume);
-                        last_vol = status.volume;
+            // Latency monitor (pings only while a game runs)
+            let spike_app = app.handle().clone();
+            container_clone.network_quality.start(
+                container_clone.network.clone(),
+                container_clone.settings_service.clone(),
+                container_clone.active_games_tracker.clone(),
+                Box::new(move |spike| {
+                    let _ = spike_app.emit("network-latency-spike", spike);
+                }),
+            );
+
+            // Battery, temperature and throttling alerts (only while a game runs)
+            let alert_app = app.handle().clone();
+            let alert_container = container_clone.clone();
+            container_clone.alerts.start(
+                container_clone.settings_service.clone(),
+                container_clone.active_games_tracker.clone(),
+                Box::new(move || {
+                    let metrics = crate::application::commands::performance::PERF_MONITOR.get_metrics();
+                    let status = alert_container
+                        .system_status
+                        .current()
+                        .unwrap_or_else(|| alert_container.system.get_status());
+                    let mut reading =
+                        crate::domain::alerts::AlertReading::new(&metrics, status.battery_level, status.is_charging);
+                    // Handhelds report the CPU temperature through the embedded controller
+                    reading.cpu_temp_c = reading
+                        .cpu_temp_c
+                        .or_else(|| alert_container.fan_control.status().cpu_temp_c);
+                    reading
+                }),
+                container_clone.notifications.clone(),
+                container_clone.haptics.clone(),
+                Box::new(move |alert| {
+                    let _ = alert_app.emit("game-alert", alert);
+                    let container = alert_app.state::<DIContainer>();
+                    if container.settings_service.get().alerts.rtss_warnings {
+                        if let Err(e) = crate::application::commands::overlay::render_rtss_overlay(&alert_app) {
+                            tracing::warn!("Failed to show alert in RTSS: {}", e);
+                        }
                     }
+                }),
+            );
+
+            // Session report cards: FPS, TDP, battery and temperatures while games run
+            let session_container = container_clone.clone();
+            container_clone.session_stats.start(
+                container_clone.active_games_tracker.clone(),
+                Box::new(move || {
+                    let metrics = crate::application::commands::performance::PERF_MONITOR.get_metrics();
+                    let tdp_watts = crate::ports::performance_port::PerformancePort::get_tdp_config(
+                        &*crate::application::commands::performance::TDP_CONTROLLER,
+                    )
+                    .ok()
+                    .map(|config| config.watts);
+                    let status = session_container
+                        .system_status
+                        .current()
+                        .unwrap_or_else(|| session_container.system.get_status());
+                    let mut reading = crate::domain::session_summary::SessionReading::new(
+                        &metrics,
+                        tdp_watts,
+                        status.battery_level,
+                        status.is_charging,
+                    );
+                    reading.cpu_temp_c = reading
+                        .cpu_temp_c
+                        .or_else(|| session_container.fan_control.status().cpu_temp_c);
+                    reading
+                }),
+            );
+
+            // Injected overlays read the saved HUD level from shared memory
+            let overlay_level = container_clone.settings_service.get().overlay.level;
+            if let Err(e) = crate::adapters::overlay::IpcBridge::publish_overlay_level(overlay_level) {
+                tracing::warn!("Failed to publish overlay level: {}", e);
+            }
+
+            // Replay buffer follows the focused game (only when enabled in settings)
+            container_clone.replay.start(
+                container_clone.settings_service.clone(),
+                container_clone.active_games_tracker.clone(),
+            );
 
-                    std::thread::sleep(std::time::Duration::from_millis(250));
+            // Games started outside Balam: matched against the cached library until the next scan
+            if let Some(games) = crate::application::commands::read_cached_games(app.handle()) {
+                container_clone.external_games.set_library(&games);
+            }
+            let external_app = app.handle().clone();
+            let register_external = move |pid: u32, path: &str| {
+                let container = external_app.state::<DIContainer>();
+                container
+                    .external_games
+                    .detect(pid, path, &container.active_games_tracker, |game| {
+                        crate::application::commands::register_external_game(&external_app, &container, game, pid)
+                            .game
+                            .id
+                    })
+            };
+            // Process starts (best available source) also feed the launcher quick-exit monitor,
+            // and exits wake the game watchdogs
+            let process_start_handler = register_external.clone();
+            let launcher_monitor = crate::adapters::window_monitor::WindowMonitor::new(app.handle().clone());
+            let watchdogs = container_clone.watchdogs.clone();
+            let process_events: crate::ports::ProcessEventCallback = std::sync::Arc::new(move |event| {
+                launcher_monitor.on_process_event(event);
+                watchdogs.on_process_event(event);
+                if let crate::ports::ProcessEvent::Started {
+                    pid,
+                    executable_path: Some(path),
+                } = event
+                {
+                    let _ = process_start_handler(*pid, path);
                 }
             });
+            if let Err(e) = crate::adapters::process_events::subscribe_best(&process_events) {
+                tracing::warn!("External game detection limited to foreground windows: {}", e);
+            }
+
+            // Controller hand-off between Balam and Steam Input games (foreground hook)
+            let focus_app = app.handle().clone();
+            container_clone.focus.start(
+                Box::new(crate::adapters::focus::WindowsForegroundEvents::new()),
+                container_clone.active_games_tracker.clone(),
+                Box::new(register_external),
+                Box::new(move |focus| {
+                    let _ = focus_app.emit("input-focus-changed", focus);
+                }),
+            );
+
+            // Rumble patterns play one after another on their own thread
+            container_clone.haptics.start();
+
+            // Home screen tiles: clock every minute, weather every 30 minutes
+            let dashboard_app = app.handle().clone();
+            let summary_app = app.handle().clone();
+            container_clone.dashboard.start(
+                container_clone.settings_service.clone(),
+                Box::new(move || crate::application::commands::dashboard::update_summary(&summary_app)),
+                Box::new(move |data| {
+                    let _ = dashboard_app.emit("dashboard-updated", data);
+                }),
+            );
+
+            // Release feed: announce a newer version (installing is always the player's call)
+            if container_clone.settings_service.get().updates.check_on_startup {
+                crate::application::commands::check_for_updates_in_background(app.handle());
+            }
+
+            // External API (Stream Deck, scripts) and companion remote follow their settings
+            let startup_settings = container_clone.settings_service.get();
+            if startup_settings.external_api.enabled {
+                crate::application::external_api::start(app.handle());
+            }
+            crate::application::remote_control::apply(app.handle(), &startup_settings.remote);
+            let api_app = app.handle().clone();
+            container_clone.settings_service.subscribe(Box::new(move |settings| {
+                if settings.external_api.enabled {
+                    crate::application::external_api::start(&api_app);
+                }
+                crate::application::remote_control::apply(&api_app, &settings.remote);
+            }));
 
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             get_games,
+            get_games_cached,
             scan_games,
             add_game_manually,
             remove_game,
+            // Library organization commands
+            get_library_organization,
+            set_favorite,
+            set_hidden,
+            set_game_tags,
+            create_collection,
+            delete_collection,
+            assign_to_collection,
+            get_game_details,
+            set_epic_launch_backend,
+            set_launcher_dependency,
+            mark_not_a_game,
+            merge_games,
+            split_game,
+            explain_dedup,
+            legendary_login,
+            get_library_rows,
             list_directory,
             get_system_drives,
+            move_game,
+            get_xbox_titles,
+            install_xbox_game,
+            uninstall_xbox_game,
+            verify_game_files,
+            rebuild_game_file_manifest,
             launch_game,
+            start_update,
             get_active_game,
             kill_game,
+            suspend_game,
+            resume_game,
+            list_active_games,
+            focus_game,
+            get_input_focus,
+            // Notification commands
+            get_notifications,
+            clear_notifications,
             get_system_status,
             log_message,
+            get_recent_logs,
+            export_diagnostics_zip,
+            get_present_report,
             set_volume,
             list_audio_devices,
             set_default_audio_device,
+            list_input_devices,
+            set_default_input_device,
+            get_mic_volume,
+            set_mic_volume,
+            get_mic_muted,
+            set_mic_muted,
+            toggle_mic_mute,
+            // Screenshot commands
+            take_screenshot,
+            get_screenshots,
+            save_replay,
+            get_replay_status,
+            get_media,
+            rename_media,
+            delete_media,
             shutdown_pc,
             restart_pc,
             logout_pc,
+            suspend_pc,
+            // Sleep commands
+            get_idle_status,
+            set_sleep_timer,
+            cancel_sleep_timer,
+            set_auto_suspend_minutes,
             // Display commands
             get_brightness,
             set_brightness,
@@ -302,15 +923,57 @@ pub fn run() {
             set_refresh_rate,
             get_supported_refresh_rates,
             supports_brightness_control,
+            get_monitors,
+            get_monitor_brightness,
+            set_monitor_brightness,
+            get_monitor_contrast,
+            set_monitor_contrast,
+            get_monitor_refresh_rates,
+            set_monitor_refresh_rate,
             // HDR commands
             get_displays,
             get_primary_display,
             set_hdr_enabled,
+            set_color_profile,
+            restore_color_profile,
+            set_night_light,
+            set_game_color_profile,
+            set_game_window_mode,
+            get_game_window_mode,
+            supports_vibrance,
+            // GPU upscaling commands
+            get_scaling_capabilities,
+            set_game_scaling_profile,
+            // Quick settings commands
+            get_quick_settings,
+            quick_cycle_refresh_rate,
+            quick_toggle_half_rate,
+            quick_set_resolution,
+            confirm_display_change,
+            revert_display_change,
+            // Quiet mode commands
+            set_quiet_mode,
+            get_quiet_mode_status,
             // Performance commands
             get_tdp_config,
             set_tdp,
             apply_performance_profile,
             supports_tdp_control,
+            get_tdp_backend,
+            install_tdp_tools,
+            list_power_plans,
+            set_power_plan,
+            get_power_mode,
+            set_power_mode,
+            get_cpu_boost_mode,
+            set_cpu_boost_mode,
+            set_game_power_profile,
+            // Fan control commands
+            get_fan_status,
+            supports_fan_control,
+            get_fan_curve,
+            set_fan_curve,
+            reset_fan_curve,
             // WiFi commands
             scan_wifi_networks,
             get_current_wifi,
@@ -319,13 +982,22 @@ pub fn run() {
             forget_wifi,
             get_saved_networks,
             get_wifi_signal_strength,
+            check_connectivity,
+            get_network_details,
+            get_network_quality,
+            open_captive_portal,
+            open_store_browser,
+            close_store_browser,
+            store_browser_action,
             // Bluetooth commands
             is_bluetooth_available,
             set_bluetooth_enabled,
             get_paired_bluetooth_devices,
             scan_bluetooth_devices,
             get_connected_bluetooth_devices,
+            get_bluetooth_device_details,
             pair_bluetooth_device,
+            respond_to_pairing,
             unpair_bluetooth_device,
             connect_bluetooth_device,
             disconnect_bluetooth_device,
@@ -335,12 +1007,19 @@ pub fn run() {
             haptic_navigation,
             haptic_action,
             haptic_event,
+            play_haptic_pattern,
+            list_haptic_patterns,
+            stop_haptics,
             // Game management commands
             get_running_game,
             close_current_game,
             // Performance monitoring commands
             get_fps_stats,
             get_performance_metrics,
+            get_active_alerts,
+            start_auto_tdp,
+            stop_auto_tdp,
+            get_auto_tdp_status,
             is_nvml_available,
             // FPS Service management commands
             get_fps_service_status,
@@ -355,6 +1034,11 @@ pub fn run() {
             hide_performance_pip,
             toggle_performance_pip,
             is_pip_visible,
+            open_companion_pip,
+            close_companion_pip,
+            snap_companion_pip,
+            set_companion_pip_opacity,
+            resize_companion_pip,
             // Overlay commands
             show_game_overlay,
             hide_game_overlay,
@@ -364,8 +1048,102 @@ pub fn run() {
             set_overlay_click_through,
             get_overlay_status,
             is_game_whitelisted,
-            get_whitelisted_games
+            get_whitelisted_games,
+            get_overlay_conflicts,
+            set_game_bar_enabled,
+            set_overlay_layout,
+            update_rtss_overlay,
+            clear_rtss_overlay,
+            set_overlay_level,
+            cycle_overlay_level,
+            report_overlay_renderer_alive,
+            // Settings commands
+            get_settings,
+            update_settings,
+            reset_settings,
+            // Hotkey commands
+            rebind_hotkey,
+            set_gamepad_chord,
+            set_extra_button_binding,
+            check_hotkey_conflict,
+            // Text input commands
+            set_text_input_focus,
+            get_text_input_state,
+            show_touch_keyboard,
+            hide_touch_keyboard,
+            send_virtual_text,
+            send_virtual_key,
+            // Console mode commands
+            get_console_mode_status,
+            enable_console_mode,
+            disable_console_mode,
+            get_supervision_status,
+            // Update commands
+            check_for_updates,
+            apply_update,
+            // Dashboard commands
+            get_dashboard_data,
+            // External API commands
+            get_external_api_info,
+            regenerate_external_api_token,
+            // Companion remote commands
+            get_remote_pairing,
+            reset_remote_pairing,
+            respond_to_remote_device,
+            // Sunshine commands
+            get_sunshine_status,
+            sync_sunshine_library,
+            start_sunshine,
+            stop_sunshine,
+            submit_sunshine_pin,
+            // Parental controls commands
+            get_parental_status,
+            unlock_with_pin,
+            lock_parental_controls,
+            set_parental_pin,
+            get_parental_controls,
+            save_parental_controls,
+            // Telemetry commands
+            get_telemetry_preview,
+            clear_telemetry_data,
+            get_launch_history,
+            get_launch_stats,
+            // Benchmark commands
+            start_benchmark,
+            cancel_benchmark,
+            list_benchmark_runs,
+            get_benchmark_run,
+            delete_benchmark_run,
+            export_benchmark_run,
+            start_stutter_diagnostics,
+            stop_stutter_diagnostics,
+            get_stutter_report,
+            // Autostart commands
+            get_autostart_status,
+            set_autostart,
+            disable_autostart,
+            get_launch_options,
+            // Safe mode commands
+            exit_safe_mode
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            if let tauri::RunEvent::Exit = event {
+                app.state::<DIContainer>().telemetry.end_session();
+
+                // Never leave a custom fan curve running without the app supervising it
+                let fan_control = app.state::<DIContainer>().fan_control.clone();
+                if fan_control.is_active() {
+                    if let Err(e) = fan_control.restore() {
+                        tracing::warn!("Failed to restore automatic fan control on exit: {}", e);
+                    }
+                }
+
+                // The driver keeps upscaling across reboots; never leave a game's setting behind
+                if let Err(e) = app.state::<DIContainer>().gpu_scaling.restore() {
+                    tracing::warn!("Failed to restore driver upscaling on exit: {}", e);
+                }
+            }
+        });
 }
@@ -1,4 +1,6 @@
+use crate::domain::BalamError;
 use serde::Serialize;
+use std::sync::Arc;
 
 /// `Bluetooth` device class/type.
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -22,6 +24,15 @@ pub enum BluetoothPairingState {
     PairingInProgress,
 }
 
+/// Audio profile a `Bluetooth` headset is currently used with.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum BluetoothAudioProfile {
+    /// Stereo playback (A2DP)
+    A2dp,
+    /// Mono with microphone (Hands-Free / Headset profile)
+    HandsFree,
+}
+
 /// Domain entity representing a `Bluetooth` device.
 #[derive(Debug, Clone, Serialize)]
 pub struct BluetoothDevice {
@@ -39,6 +50,10 @@ pub struct BluetoothDevice {
     pub is_connected: bool,
     /// Whether device is remembered (paired before)
     pub is_remembered: bool,
+    /// Battery level (0-100), only filled by `get_device_details`
+    pub battery_percent: Option<u8>,
+    /// Profile of the default audio output, only filled by `get_device_details`
+    pub audio_profile: Option<BluetoothAudioProfile>,
 }
 
 /// Pairing configuration for `Bluetooth` devices.
@@ -50,6 +65,32 @@ pub struct BluetoothPairingConfig {
     pub pin: String,
 }
 
+/// What the device asks for during pairing.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum PairingCeremonyKind {
+    /// Show `pin` so the user can type it on the device (no response needed)
+    DisplayPin,
+    /// Both sides show `pin`; the user confirms they match
+    ConfirmPinMatch,
+    /// The user types the PIN shown by (or printed on) the device
+    ProvidePin,
+}
+
+/// A pairing step that needs the user, forwarded to the frontend.
+#[derive(Debug, Clone, Serialize)]
+pub struct BluetoothPairingCeremony {
+    /// Id to answer with (`respond_to_pairing`)
+    pub request_id: u64,
+    /// Address of the device being paired
+    pub address: String,
+    pub kind: PairingCeremonyKind,
+    /// PIN to show for `DisplayPin` / `ConfirmPinMatch`
+    pub pin: Option<String>,
+}
+
+/// Receives pairing ceremonies while `pair_device` runs.
+pub type PairingListener = Arc<dyn Fn(BluetoothPairingCeremony) + Send + Sync>;
+
 /// Port defining `Bluetooth` management capabilities.
 ///
 /// This trait provides a hardware abstraction layer for `Bluetooth` operations
@@ -74,7 +115,7 @@ pub trait BluetoothPort: Send + Sync {
     ///
     /// # Returns
     /// List of devices that have been paired with this computer.
-    async fn get_paired_devices(&self) -> Result<Vec<BluetoothDevice>, String>;
+    async fn get_paired_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError>;
 
     /// Scans for available `Bluetooth` devices nearby.
     ///
@@ -84,49 +125,85 @@ pub trait BluetoothPort: Send + Sync {
     ///
     /// # Returns
     /// List of discovered devices, including paired and unpaired.
-    async fn scan_devices(&self) -> Result<Vec<BluetoothDevice>, String>;
+    ///
+    /// # Errors
+    /// `BalamError::BluetoothOff` when the radio is off (instead of an empty list).
+    async fn scan_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError>;
 
     /// Pairs with a `Bluetooth` device.
     ///
+    /// PIN entry and confirmation steps go to `listener`; `config.pin`, when
+    /// set, answers a PIN request without asking. Without a listener, PIN
+    /// requests not covered by the config are declined.
+    ///
     /// # Errors
-    /// - Device not found
-    /// - Incorrect PIN
-    /// - `Bluetooth` adapter disabled
-    /// - Device already paired
-    async fn pair_device(&self, config: BluetoothPairingConfig) -> Result<(), String>;
+    /// - `BalamError::BluetoothUnavailable` / `BalamError::BluetoothOff`
+    /// - `BalamError::NotFound`: device not discovered
+    /// - `BalamError::Platform`: pairing rejected (e.g. incorrect PIN)
+    async fn pair_device(
+        &self,
+        config: BluetoothPairingConfig,
+        listener: Option<PairingListener>,
+    ) -> Result<(), BalamError>;
+
+    /// Answers a pairing ceremony forwarded by `pair_device`.
+    ///
+    /// `pin` is only used for `PairingCeremonyKind::ProvidePin`.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound`: no ceremony with that id is waiting (answered or timed out)
+    async fn respond_to_pairing(&self, request_id: u64, accept: bool, pin: Option<String>) -> Result<(), BalamError>;
 
     /// Removes pairing with a device (forgets device).
     ///
     /// # Errors
-    /// - Device not found in paired devices
-    async fn unpair_device(&self, address: &str) -> Result<(), String>;
+    /// - `BalamError::NotFound`: device not found in paired devices
+    async fn unpair_device(&self, address: &str) -> Result<(), BalamError>;
 
     /// Connects to a paired `Bluetooth` device.
     ///
+    /// Undoes a previous `disconnect_device`; otherwise the platform
+    /// reconnects paired devices on its own when they are in range.
+    ///
     /// # Errors
     /// - Device not paired
     /// - Device out of range
     /// - Connection failed
-    async fn connect_device(&self, address: &str) -> Result<(), String>;
+    async fn connect_device(&self, address: &str) -> Result<(), BalamError>;
 
-    /// Disconnects from a `Bluetooth` device.
+    /// Disconnects from a `Bluetooth` device, keeping the pairing.
+    ///
+    /// The device stays disconnected until `connect_device` is called.
+    /// Succeeds without doing anything if the device is not connected.
+    ///
+    /// # Errors
+    /// - `BalamError::InvalidArgument`: malformed address
+    /// - `BalamError::NotFound`: device not paired
+    /// - `BalamError::Platform`: the device's services could not be disabled
+    async fn disconnect_device(&self, address: &str) -> Result<(), BalamError>;
+
+    /// Gets one device with its battery level and active audio profile.
+    ///
+    /// # Performance
+    /// Medium (up to ~1s): the battery may have to be read over GATT.
     ///
     /// # Errors
-    /// - Device not connected
-    async fn disconnect_device(&self, address: &str) -> Result<(), String>;
+    /// - `BalamError::InvalidArgument`: malformed address
+    /// - `BalamError::NotFound`: device not known to Windows
+    async fn get_device_details(&self, address: &str) -> Result<BluetoothDevice, BalamError>;
 
     /// Gets the currently connected `Bluetooth` devices.
     ///
     /// # Returns
     /// List of devices currently connected to this computer.
-    async fn get_connected_devices(&self) -> Result<Vec<BluetoothDevice>, String>;
+    async fn get_connected_devices(&self) -> Result<Vec<BluetoothDevice>, BalamError>;
 
     /// Checks if `Bluetooth` adapter is available and enabled.
     ///
     /// # Returns
     /// - `Ok(true)` if Bluetooth is available and enabled
     /// - `Ok(false)` if Bluetooth is disabled or no adapter
-    async fn is_bluetooth_available(&self) -> Result<bool, String>;
+    async fn is_bluetooth_available(&self) -> Result<bool, BalamError>;
 
     /// Enables or disables `Bluetooth` radio.
     ///
@@ -134,7 +211,7 @@ pub trait BluetoothPort: Send + Sync {
     /// - `enabled`: true to enable, false to disable
     ///
     /// # Errors
-    /// - No `Bluetooth` adapter available
-    /// - Insufficient permissions
-    async fn set_bluetooth_enabled(&self, enabled: bool) -> Result<(), String>;
+    /// - `BalamError::BluetoothUnavailable`: no `Bluetooth` adapter
+    /// - `BalamError::AccessDenied`: radio access denied by Windows
+    async fn set_bluetooth_enabled(&self, enabled: bool) -> Result<(), BalamError>;
 }
@@ -0,0 +1,16 @@
+use crate::domain::completion_time::CompletionTimes;
+use crate::domain::BalamError;
+
+/// Port for a provider of average completion times (`HowLongToBeat`).
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`; calls run on blocking worker threads.
+pub trait CompletionTimePort: Send + Sync {
+    /// Completion times of the game titled `title`.
+    ///
+    /// Returns `Ok(None)` when the provider has no entry matching the title.
+    ///
+    /// # Errors
+    /// - `BalamError::Platform` - Network failure or unexpected response
+    fn lookup(&self, title: &str) -> Result<Option<CompletionTimes>, BalamError>;
+}
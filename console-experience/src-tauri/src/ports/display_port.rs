@@ -1,4 +1,7 @@
-use crate::domain::display::{BrightnessConfig, DisplayInfo, RefreshRateConfig};
+use crate::domain::display::{
+    BrightnessConfig, ColorProfile, DisplayInfo, DisplayMode, MonitorInfo, RefreshRateConfig,
+};
+use crate::domain::BalamError;
 
 /// Port defining display control capabilities.
 ///
@@ -7,6 +10,7 @@ use crate::domain::display::{BrightnessConfig, DisplayInfo, RefreshRateConfig};
 ///
 /// # Hardware Support
 /// - **Brightness**: Requires WMI (laptops) or DDC/CI (monitors with I2C support)
+/// - **Contrast**: DDC/CI monitors only
 /// - **Refresh Rate**: Requires DXGI (Windows) or equivalent platform API
 ///
 /// # Thread Safety
@@ -25,7 +29,7 @@ use crate::domain::display::{BrightnessConfig, DisplayInfo, RefreshRateConfig};
 /// }
 /// # Ok::<(), String>(())
 /// ```
-pub trait DisplayPort {
+pub trait DisplayPort: Send + Sync {
     /// Gets the current brightness level (0-100).
     ///
     /// # Returns
@@ -40,7 +44,7 @@ pub trait DisplayPort {
     ///
     /// # Performance
     /// Should complete within 100ms for WMI, 200ms for DDC/CI.
-    fn get_brightness(&self) -> Result<Option<u32>, String>;
+    fn get_brightness(&self) -> Result<Option<u32>, BalamError>;
 
     /// Sets the brightness level (0-100).
     ///
@@ -48,9 +52,9 @@ pub trait DisplayPort {
     /// * `config` - Validated brightness configuration (automatically clamped to 0-100)
     ///
     /// # Errors
-    /// - `Err("Not supported")` - Hardware lacks brightness control
-    /// - `Err("Access denied")` - Insufficient permissions
-    /// - `Err(...)` - Platform API error
+    /// - `BalamError::NotSupported` - Hardware lacks brightness control
+    /// - `BalamError::AccessDenied` - Insufficient permissions
+    /// - `BalamError::Platform` - WMI/DDC/CI error
     ///
     /// # Examples
     /// ```rust
@@ -62,7 +66,7 @@ pub trait DisplayPort {
     /// adapter.set_brightness(config)?;
     /// # Ok::<(), String>(())
     /// ```
-    fn set_brightness(&self, config: BrightnessConfig) -> Result<(), String>;
+    fn set_brightness(&self, config: BrightnessConfig) -> Result<(), BalamError>;
 
     /// Gets the current display refresh rate in Hz.
     ///
@@ -71,7 +75,7 @@ pub trait DisplayPort {
     ///
     /// # Errors
     /// Returns `Err` if display enumeration fails.
-    fn get_refresh_rate(&self) -> Result<u32, String>;
+    fn get_refresh_rate(&self) -> Result<u32, BalamError>;
 
     /// Sets the display refresh rate.
     ///
@@ -79,8 +83,8 @@ pub trait DisplayPort {
     /// * `config` - Validated refresh rate configuration
     ///
     /// # Errors
-    /// - `Err("Unsupported refresh rate")` - Hardware doesn't support requested Hz
-    /// - `Err(...)` - Platform API error (DXGI failure, etc.)
+    /// - `BalamError::InvalidArgument` - Hardware doesn't support requested Hz
+    /// - `BalamError::Platform` - Platform API error (DXGI failure, etc.)
     ///
     /// # Important
     /// Always query `get_supported_refresh_rates()` first to validate input.
@@ -102,7 +106,7 @@ pub trait DisplayPort {
     /// }
     /// # Ok::<(), String>(())
     /// ```
-    fn set_refresh_rate(&self, config: RefreshRateConfig) -> Result<(), String>;
+    fn set_refresh_rate(&self, config: RefreshRateConfig) -> Result<(), BalamError>;
 
     /// Lists all supported refresh rates for the current display.
     ///
@@ -114,7 +118,92 @@ pub trait DisplayPort {
     ///
     /// # Performance
     /// Caches results internally. Safe to call frequently.
-    fn get_supported_refresh_rates(&self) -> Result<Vec<u32>, String>;
+    fn get_supported_refresh_rates(&self) -> Result<Vec<u32>, BalamError>;
+
+    /// Lists every monitor of the desktop, primary first.
+    ///
+    /// # Returns
+    /// Vector of `MonitorInfo` with the GDI device name used by the
+    /// per-monitor calls and how each monitor's brightness can be changed.
+    ///
+    /// # Errors
+    /// Returns `Err` if monitor enumeration fails.
+    fn get_monitors(&self) -> Result<Vec<MonitorInfo>, BalamError>;
+
+    /// Gets the brightness (0-100) of one monitor.
+    ///
+    /// # Returns
+    /// - `Ok(Some(level))` - Current brightness percentage
+    /// - `Ok(None)` - The monitor has no brightness control
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    fn get_monitor_brightness(&self, device_name: &str) -> Result<Option<u32>, BalamError>;
+
+    /// Sets the brightness of one monitor, through WMI for the built-in panel
+    /// and DDC/CI for external monitors.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    /// - `BalamError::NotSupported` - The monitor has no brightness control
+    fn set_monitor_brightness(&self, device_name: &str, config: BrightnessConfig) -> Result<(), BalamError>;
+
+    /// Gets the contrast (0-100) of one monitor.
+    ///
+    /// # Returns
+    /// - `Ok(Some(level))` - Current contrast percentage
+    /// - `Ok(None)` - Not adjustable (only DDC/CI monitors expose contrast)
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    fn get_monitor_contrast(&self, device_name: &str) -> Result<Option<u32>, BalamError>;
+
+    /// Sets the contrast (0-100, clamped) of one monitor over DDC/CI.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    /// - `BalamError::NotSupported` - Built-in panel, or DDC/CI disabled on the monitor
+    fn set_monitor_contrast(&self, device_name: &str, level: u32) -> Result<(), BalamError>;
+
+    /// Lists the supported refresh rates of one monitor.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    fn get_monitor_refresh_rates(&self, device_name: &str) -> Result<Vec<u32>, BalamError>;
+
+    /// Sets the refresh rate of one monitor.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - No monitor with this device name
+    /// - `BalamError::InvalidArgument` - The monitor doesn't support requested Hz
+    fn set_monitor_refresh_rate(&self, device_name: &str, config: RefreshRateConfig) -> Result<(), BalamError>;
+
+    /// Gets the current resolution and refresh rate of the primary display.
+    ///
+    /// # Errors
+    /// Returns `Err` if display enumeration fails.
+    fn get_display_mode(&self) -> Result<DisplayMode, BalamError>;
+
+    /// Lists every resolution / refresh rate combination of the primary display.
+    ///
+    /// # Errors
+    /// Returns `Err` if display mode enumeration fails.
+    fn get_display_modes(&self) -> Result<Vec<DisplayMode>, BalamError>;
+
+    /// Switches resolution and refresh rate in a single mode change.
+    ///
+    /// The driver validates the mode first (test mode), so a rejected mode
+    /// never reaches the screen.
+    ///
+    /// # Arguments
+    /// * `mode` - Mode from `get_display_modes()`
+    /// * `persist` - Save it as the display's mode; `false` keeps it for the
+    ///   current session only (e.g. until the player confirms it)
+    ///
+    /// # Errors
+    /// - `BalamError::InvalidArgument` - The display rejected the mode
+    /// - `BalamError::Platform` - Platform API error
+    fn set_display_mode(&self, mode: DisplayMode, persist: bool) -> Result<(), BalamError>;
 
     /// Checks if brightness control is available on this hardware.
     ///
@@ -136,7 +225,7 @@ pub trait DisplayPort {
     ///
     /// # Performance
     /// ~10ms on first call (initializes DisplayConfig), <5ms thereafter (cached).
-    fn get_displays(&self) -> Result<Vec<DisplayInfo>, String>;
+    fn get_displays(&self) -> Result<Vec<DisplayInfo>, BalamError>;
 
     /// Gets the primary display information.
     ///
@@ -172,11 +261,28 @@ pub trait DisplayPort {
     /// `Ok(())` if HDR state was set successfully.
     ///
     /// # Errors
-    /// - `Err("Display not found")` - Invalid display ID
-    /// - `Err("HDR not supported")` - Display doesn't support HDR
-    /// - `Err(...)` - Platform API error
+    /// - `BalamError::NotFound` - Invalid display ID
+    /// - `BalamError::NotSupported` - Display doesn't support HDR
+    /// - `BalamError::Platform` - DisplayConfig API error
     ///
     /// # Performance
     /// ~10-20ms (immediate application, no restart required)
-    fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), String>;
+    fn set_hdr_enabled(&self, display_id: u32, enabled: bool) -> Result<(), BalamError>;
+
+    /// Applies a color profile (temperature, gamma, vibrance) to every display.
+    ///
+    /// # Arguments
+    /// * `profile` - Color adjustment; `ColorProfile::neutral()` restores defaults
+    ///
+    /// # Errors
+    /// - `BalamError::InvalidArgument` - Profile values out of range
+    /// - `BalamError::NotSupported` - The driver rejected the gamma ramp
+    /// - `BalamError::Platform` - Vendor API error while setting vibrance
+    fn set_color_profile(&self, profile: &ColorProfile) -> Result<(), BalamError>;
+
+    /// Checks if digital vibrance (saturation) can be adjusted.
+    ///
+    /// # Returns
+    /// `true` with an NVIDIA driver; other GPUs only get temperature and gamma.
+    fn supports_vibrance(&self) -> bool;
 }
@@ -0,0 +1,28 @@
+/// Foreground window port interface
+///
+/// Notifications when the user (or a game) brings another window to the
+/// front, used to decide who gets the controller input.
+use std::sync::Arc;
+
+/// Callback invoked with the process id of the new foreground window.
+///
+/// Called from the notification thread; must return quickly.
+pub type ForegroundCallback = Arc<dyn Fn(u32) + Send + Sync>;
+
+/// Port trait for foreground window tracking
+pub trait ForegroundEventsPort: Send + Sync {
+    /// Starts delivering foreground changes to `callback` for the lifetime
+    /// of the app; the current foreground window is reported right away.
+    ///
+    /// # Returns
+    /// `false` if the platform notification could not be registered.
+    fn subscribe(&self, callback: ForegroundCallback) -> bool;
+
+    /// Whether the process has Steam Input hooked into it (controller
+    /// input reaches it through Steam rather than directly).
+    fn uses_steam_input(&self, pid: u32) -> bool;
+
+    /// Full path of the process's executable (`None` for protected or
+    /// exited processes).
+    fn executable_path(&self, pid: u32) -> Option<String>;
+}
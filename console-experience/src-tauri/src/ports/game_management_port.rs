@@ -93,4 +93,37 @@ pub trait GameManagementPort: Send + Sync {
     /// - **Windows**: Uses `IsHungAppWindow` or `SendMessageTimeout`
     /// - **Linux**: Checks `/proc/[pid]/status` for 'D' state
     fn is_process_responding(&self, pid: u32) -> Result<bool, String>;
+
+    /// Freezes every thread of a process (console-style "quick resume").
+    ///
+    /// The process keeps its memory and GPU resources but gets no CPU time
+    /// until `resume_game` is called.
+    ///
+    /// # Platform Notes
+    /// - **Windows**: Uses `NtSuspendProcess` (ntdll)
+    /// - **Linux**: Uses SIGSTOP
+    ///
+    /// # Errors
+    /// Returns `Err` if the process cannot be opened or suspended.
+    fn suspend_game(&self, pid: u32) -> Result<(), String>;
+
+    /// Resumes a process previously frozen with `suspend_game`.
+    ///
+    /// # Platform Notes
+    /// - **Windows**: Uses `NtResumeProcess` (ntdll)
+    /// - **Linux**: Uses SIGCONT
+    ///
+    /// # Errors
+    /// Returns `Err` if the process cannot be opened or resumed.
+    fn resume_game(&self, pid: u32) -> Result<(), String>;
+
+    /// PID owning the foreground window (`None` if no window has focus).
+    fn foreground_pid(&self) -> Option<u32>;
+
+    /// Brings the main window of a process to the foreground.
+    ///
+    /// # Errors
+    /// Returns `Err` if the process has no visible window or the platform
+    /// refuses to switch focus.
+    fn focus_window(&self, pid: u32) -> Result<(), String>;
 }
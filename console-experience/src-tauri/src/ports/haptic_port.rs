@@ -2,28 +2,31 @@
 ///
 /// Defines the contract for haptic feedback implementations.
 /// Follows hexagonal architecture pattern to decouple domain logic from hardware specifics.
-use crate::domain::haptic::HapticFeedback;
+use crate::domain::haptic::HapticPattern;
 
 /// Port trait for haptic feedback operations
 ///
 /// Implementations handle platform-specific rumble/vibration hardware.
 /// Currently targets gamepad controllers via Gilrs library.
-#[async_trait::async_trait]
 pub trait HapticPort: Send + Sync {
-    /// Trigger haptic feedback on available devices
+    /// Start a pattern on available devices
     ///
     /// # Arguments
-    /// * `feedback` - Haptic pattern with intensity and duration
+    /// * `pattern` - Pulses with their timing, magnitudes and envelopes
     ///
     /// # Returns
-    /// * `Ok(())` - Feedback triggered successfully
-    /// * `Err(String)` - Hardware error or no connected devices
+    /// * `Ok(())` - Pattern started (or no connected devices)
+    /// * `Err(String)` - Hardware error
     ///
     /// # Notes
-    /// - Non-blocking: Returns immediately, rumble runs in background
-    /// - Multi-device: Triggers on all connected gamepads
-    /// - Fails silently if no gamepads connected (graceful degradation)
-    async fn trigger(&self, feedback: HapticFeedback) -> Result<(), String>;
+    /// - Non-blocking: Returns immediately, the pattern runs in the device driver
+    /// - Replaces whatever pattern is still running
+    /// - Multi-device: Plays on all connected gamepads
+    /// - Succeeds silently if no gamepads connected (graceful degradation)
+    fn play(&self, pattern: &HapticPattern) -> Result<(), String>;
+
+    /// Stop the running pattern, if any
+    fn stop(&self);
 
     /// Check if haptic feedback is supported
     ///
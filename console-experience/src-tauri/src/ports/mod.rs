@@ -1,17 +1,49 @@
 // Ports Layer: Interfaces and Traits
 pub mod bluetooth_port;
+pub mod completion_time_port;
 pub mod display_port;
+pub mod foreground_port;
 pub mod game_management_port;
 pub mod haptic_port;
+pub mod network_port;
+pub mod notification_port;
 pub mod performance_port;
+pub mod process_events_port;
+pub mod replay_port;
+pub mod scaling_port;
 pub mod scanner_port;
+pub mod screen_capture_port;
+pub mod settings_port;
+pub mod system_events_port;
 pub mod system_port;
+pub mod telemetry_port;
+pub mod text_input_port;
+pub mod thermal_port;
+pub mod update_port;
+pub mod weather_port;
 pub mod wifi_port;
+pub mod window_manager_port;
 
 pub use bluetooth_port::{
     BluetoothDevice, BluetoothDeviceType, BluetoothPairingConfig, BluetoothPairingState, BluetoothPort,
 };
+pub use completion_time_port::CompletionTimePort;
+pub use foreground_port::ForegroundEventsPort;
 pub use game_management_port::GameManagementPort;
 pub use haptic_port::HapticPort;
+pub use network_port::NetworkPort;
+pub use notification_port::NotificationPort;
+pub use process_events_port::{ProcessEvent, ProcessEventCallback, ProcessEventsPort};
+pub use replay_port::ReplayRecorderPort;
+pub use scaling_port::GpuScalingPort;
 pub use scanner_port::GameScanner;
+pub use screen_capture_port::ScreenCapturePort;
+pub use settings_port::SettingsRepository;
+pub use system_events_port::{SystemEventCallback, SystemEventKind, SystemEventsPort};
+pub use telemetry_port::TelemetrySinkPort;
+pub use text_input_port::TextInputPort;
+pub use thermal_port::ThermalPort;
+pub use update_port::ReleaseFeedPort;
+pub use weather_port::WeatherPort;
 pub use wifi_port::{WiFiConfig, WiFiNetwork, WiFiPort, WiFiSecurity};
+pub use window_manager_port::WindowManagerPort;
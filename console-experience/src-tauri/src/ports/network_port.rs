@@ -0,0 +1,64 @@
+use crate::domain::BalamError;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Kind of network adapter.
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum NetworkAdapterKind {
+    Ethernet,
+    WiFi,
+    /// VPN client tunnel (WireGuard, OpenVPN, PPP, corporate clients)
+    Vpn,
+    Other,
+}
+
+/// One network adapter with its addressing.
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkAdapterInfo {
+    /// Name shown by Windows ("Ethernet", "Wi-Fi", "Tailscale")
+    pub name: String,
+    /// Driver description ("Realtek PCIe 2.5GbE Family Controller")
+    pub description: String,
+    pub kind: NetworkAdapterKind,
+    /// Link up (cable plugged in / associated / tunnel established)
+    pub is_up: bool,
+    /// Negotiated link speed, `None` if the driver does not report it
+    pub link_speed_mbps: Option<u64>,
+    /// MAC address ("AA:BB:CC:DD:EE:FF"), `None` for tunnels
+    pub mac_address: Option<String>,
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub gateways: Vec<String>,
+    pub dns_servers: Vec<String>,
+}
+
+/// Every adapter plus a summary for the network panel.
+#[derive(Debug, Clone, Serialize)]
+pub struct NetworkDetails {
+    pub adapters: Vec<NetworkAdapterInfo>,
+    /// Name of the adapter carrying the default route
+    pub primary_adapter: Option<String>,
+    /// `true` if a VPN adapter is up
+    pub vpn_active: bool,
+}
+
+/// Port for adapter-level network status (wired, wireless and VPN).
+///
+/// `WiFiPort` covers wireless networks themselves (scan, join); this port
+/// reports what every interface is doing.
+pub trait NetworkPort: Send + Sync {
+    /// Lists the machine's network adapters (loopback excluded).
+    ///
+    /// # Errors
+    /// Returns `BalamError::Platform` if the adapters cannot be enumerated.
+    fn get_network_details(&self) -> Result<NetworkDetails, BalamError>;
+
+    /// Sends one ICMP echo to `host` (IPv4 address or name).
+    ///
+    /// Returns the round-trip time, or `None` if no reply arrived in `timeout`.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` if `host` does not resolve to an IPv4
+    /// address, `Platform` if the ICMP handle cannot be created.
+    fn ping(&self, host: &str, timeout: Duration) -> Result<Option<Duration>, BalamError>;
+}
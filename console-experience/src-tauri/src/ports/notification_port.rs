@@ -0,0 +1,29 @@
+/// Notification port interface
+///
+/// Single entry point for every backend component that needs to tell the
+/// user something. Implementations decide the channels (Windows toast,
+/// overlay event) from the priority and the do-not-disturb state.
+use crate::domain::notification::{Notification, NotificationDelivery};
+
+/// Port trait for user notifications
+pub trait NotificationPort: Send + Sync {
+    /// Posts a notification
+    ///
+    /// # Returns
+    /// The channels it was delivered to (both `false` if it was only recorded
+    /// in the history because of do-not-disturb).
+    ///
+    /// # Notes
+    /// - Never fails: delivery errors are logged, the notification is still recorded
+    /// - Non-blocking: toasts are shown asynchronously by Windows
+    fn notify(&self, notification: Notification) -> NotificationDelivery;
+
+    /// Whether do-not-disturb is active (a game is running fullscreen)
+    fn is_do_not_disturb(&self) -> bool;
+
+    /// Recent notifications, newest first
+    fn history(&self) -> Vec<Notification>;
+
+    /// Clears the notification history
+    fn clear_history(&self);
+}
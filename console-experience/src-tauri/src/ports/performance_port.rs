@@ -1,4 +1,5 @@
 use crate::domain::performance::{FPSStats, PerformanceMetrics, PerformanceProfile, TDPConfig};
+use crate::domain::BalamError;
 
 /// Hardware vendor for TDP control.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -44,7 +45,7 @@ pub trait PerformancePort {
     ///
     /// # Performance
     /// Cached after first call. Safe to call frequently.
-    fn detect_hardware(&self) -> Result<HardwareVendor, String>;
+    fn detect_hardware(&self) -> Result<HardwareVendor, BalamError>;
 
     /// Gets the current TDP configuration including hardware limits.
     ///
@@ -55,9 +56,9 @@ pub trait PerformancePort {
     /// - **`max_watts`**: Hardware maximum (usually 25-30W)
     ///
     /// # Errors
-    /// - `Err("Not supported")` - Hardware lacks TDP control
-    /// - `Err("Driver not found")` - RyzenAdj/WinRing0 not installed
-    /// - `Err(...)` - FFI error
+    /// - `BalamError::NotSupported` - Hardware lacks TDP control
+    /// - `BalamError::NotFound` - RyzenAdj/WinRing0 not installed
+    /// - `BalamError::Platform` - FFI error
     ///
     /// # Examples
     /// ```rust
@@ -69,7 +70,7 @@ pub trait PerformancePort {
     ///     config.current_watts, config.min_watts, config.max_watts);
     /// # Ok::<(), String>(())
     /// ```
-    fn get_tdp_config(&self) -> Result<TDPConfig, String>;
+    fn get_tdp_config(&self) -> Result<TDPConfig, BalamError>;
 
     /// Sets the TDP to a specific wattage.
     ///
@@ -77,9 +78,9 @@ pub trait PerformancePort {
     /// * `watts` - Target TDP in watts (automatically clamped to hardware min/max)
     ///
     /// # Errors
-    /// - `Err("Not supported")` - Hardware lacks TDP control
-    /// - `Err("Access denied")` - Insufficient privileges (needs admin/root)
-    /// - `Err(...)` - FFI or driver error
+    /// - `BalamError::NotSupported` - Hardware lacks TDP control
+    /// - `BalamError::AccessDenied` - Insufficient privileges (needs admin/root)
+    /// - `BalamError::Platform` - FFI or driver error
     ///
     /// # Safety
     /// Setting TDP too low (<5W) may cause system instability.
@@ -97,7 +98,7 @@ pub trait PerformancePort {
     /// adapter.set_tdp(15)?; // Set to 15W (Balanced mode)
     /// # Ok::<(), String>(())
     /// ```
-    fn set_tdp(&self, watts: u32) -> Result<(), String>;
+    fn set_tdp(&self, watts: u32) -> Result<(), BalamError>;
 
     /// Applies a predefined performance profile.
     ///
@@ -121,7 +122,7 @@ pub trait PerformancePort {
     /// adapter.apply_profile(PerformanceProfile::Eco)?; // Power saving
     /// # Ok::<(), String>(())
     /// ```
-    fn apply_profile(&self, profile: PerformanceProfile) -> Result<(), String> {
+    fn apply_profile(&self, profile: PerformanceProfile) -> Result<(), BalamError> {
         let config = self.get_tdp_config()?;
         let watts = profile.to_watts(&config);
         self.set_tdp(watts)
@@ -156,7 +157,7 @@ pub trait PerformancePort {
     ///
     /// # Performance
     /// Fast check (<5ms). Called frequently by overlay (every 500ms).
-    fn get_fps_stats(&self) -> Result<Option<FPSStats>, String> {
+    fn get_fps_stats(&self) -> Result<Option<FPSStats>, BalamError> {
         // Default implementation: FPS monitoring not supported
         Ok(None)
     }
@@ -187,7 +188,7 @@ pub trait PerformancePort {
     /// println!("CPU: {:.1}%, GPU: {:.1}%", metrics.cpu_usage, metrics.gpu_usage);
     /// # Ok::<(), String>(())
     /// ```
-    fn get_performance_metrics(&self) -> Result<PerformanceMetrics, String> {
+    fn get_performance_metrics(&self) -> Result<PerformanceMetrics, BalamError> {
         // Default implementation: return empty metrics
         Ok(PerformanceMetrics::default())
     }
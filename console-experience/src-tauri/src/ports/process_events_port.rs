@@ -0,0 +1,40 @@
+/// Process events port interface
+///
+/// Process starts and exits across the whole system, used to spot games and
+/// launchers started outside Balam. Several OS sources can provide them,
+/// with different rights and latency; the adapters pick the best one.
+use std::sync::Arc;
+
+use crate::domain::BalamError;
+
+/// A process started or exited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessEvent {
+    Started {
+        pid: u32,
+        /// Full path of the executable (`None` for protected processes or
+        /// processes that already exited)
+        executable_path: Option<String>,
+    },
+    Exited {
+        pid: u32,
+    },
+}
+
+/// Callback invoked with every process event.
+///
+/// Called from the source's own thread, one event at a time.
+pub type ProcessEventCallback = Arc<dyn Fn(&ProcessEvent) + Send + Sync>;
+
+/// Port trait for system-wide process events
+pub trait ProcessEventsPort: Send + Sync {
+    /// Source name for logs (`etw`, `wmi`, `wts`).
+    fn name(&self) -> &'static str;
+
+    /// Starts delivering process events to `callback` for the lifetime of the app.
+    ///
+    /// # Errors
+    /// Returns error if the source is unavailable (missing rights, service
+    /// not running); nothing is delivered then.
+    fn subscribe(&self, callback: ProcessEventCallback) -> Result<(), BalamError>;
+}
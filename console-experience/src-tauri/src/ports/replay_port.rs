@@ -0,0 +1,39 @@
+use crate::domain::media::ReplayClip;
+use crate::domain::BalamError;
+
+/// Encoder settings for a recording (from `ReplaySettings`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayConfig {
+    pub buffer_seconds: u32,
+    pub fps: u32,
+    pub bitrate_kbps: u32,
+}
+
+/// Port for the background replay recorder.
+///
+/// The recorder keeps only the last `buffer_seconds` of the game; nothing
+/// is written to the user's folders until [`ReplayRecorderPort::save_clip`].
+pub trait ReplayRecorderPort: Send + Sync {
+    /// Starts recording the main window of `pid`, replacing any running recording.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if the process has no window, `NotSupported` if
+    /// window capture is unavailable (Windows 10 before 1903).
+    fn start(&self, pid: u32, config: ReplayConfig) -> Result<(), BalamError>;
+
+    /// Stops recording; the buffer stays available for `save_clip`.
+    fn stop(&self);
+
+    /// Process being recorded, if any.
+    fn recording_pid(&self) -> Option<u32>;
+
+    /// Seconds currently available for a clip.
+    fn buffered_seconds(&self) -> u32;
+
+    /// Writes the last `seconds` of the buffer as an MP4 in the game's clip folder.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if nothing has been recorded yet, `Platform` if
+    /// the clip cannot be written.
+    fn save_clip(&self, game_title: &str, seconds: u32) -> Result<ReplayClip, BalamError>;
+}
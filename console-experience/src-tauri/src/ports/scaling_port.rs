@@ -0,0 +1,28 @@
+use crate::domain::scaling::{ScalingCapabilities, ScalingState};
+use crate::domain::BalamError;
+
+/// Port for the GPU driver's own upscaler (NVIDIA NIS, AMD RSR).
+///
+/// The driver keeps the setting globally and across reboots, so callers
+/// read the state before changing it and put it back afterwards.
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`.
+pub trait GpuScalingPort: Send + Sync {
+    /// Upscaler of the installed driver, detected once.
+    fn capabilities(&self) -> ScalingCapabilities;
+
+    /// Current driver state.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - No supported GPU driver
+    /// - `BalamError::Platform` - Driver API call failed
+    fn state(&self) -> Result<ScalingState, BalamError>;
+
+    /// Enables or disables upscaling; sharpness is ignored where it can't be set.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - No supported GPU driver
+    /// - `BalamError::Platform` - Driver API call failed
+    fn set_state(&self, state: ScalingState) -> Result<(), BalamError>;
+}
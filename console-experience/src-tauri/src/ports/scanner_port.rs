@@ -1,6 +1,10 @@
 use crate::domain::entities::Game;
 use crate::domain::errors::ScanError;
 use crate::domain::value_objects::GameSource;
+use std::time::Duration;
+
+/// How long a scanner may take before discovery moves on without it.
+pub const DEFAULT_SCAN_TIMEOUT: Duration = Duration::from_secs(15);
 
 /// Port trait for game scanners.
 ///
@@ -8,9 +12,10 @@ use crate::domain::value_objects::GameSource;
 /// All scanners must be thread-safe (Send + Sync) for concurrent scanning.
 ///
 /// # Priority System
-/// Scanners are executed in priority order (lower number = higher priority).
-/// This ensures metadata-rich sources (Steam) are scanned before fallback
-/// sources (Registry), improving deduplication accuracy.
+/// Scanners run concurrently; their results are merged in priority order
+/// (lower number = higher priority). This ensures metadata-rich sources
+/// (Steam) come before fallback sources (Registry), improving deduplication
+/// accuracy.
 ///
 /// # Error Handling
 /// Scanners should be fault-tolerant. Individual scanner failures should not
@@ -18,7 +23,8 @@ use crate::domain::value_objects::GameSource;
 /// multiple scanners with automatic error recovery.
 ///
 /// # Thread Safety
-/// All scanners must be `Send + Sync` to allow concurrent scanning via `tokio::spawn`.
+/// All scanners must be `Send + Sync`: `GameDiscoveryService` runs each one
+/// on its own thread.
 ///
 /// # Examples
 /// ```rust
@@ -74,7 +80,18 @@ pub trait GameScanner: Send + Sync {
     /// ```
     fn source(&self) -> GameSource;
 
-    /// Returns the priority for this scanner (lower = scanned first).
+    /// Name shown in scan progress and status (several scanners can share a source).
+    fn name(&self) -> &'static str {
+        self.source().display_name()
+    }
+
+    /// How long discovery waits for this scanner; a scanner still running
+    /// after that is reported as timed out and its games are left out.
+    fn timeout(&self) -> Duration {
+        DEFAULT_SCAN_TIMEOUT
+    }
+
+    /// Returns the priority for this scanner (lower = merged first).
     ///
     /// Used to prioritize metadata-rich sources. Default priorities:
     /// - **Steam: 1** (highest - rich metadata, cover art, icons)
@@ -101,7 +118,7 @@ pub trait GameScanner: Send + Sync {
             GameSource::Steam => 1, // Highest priority - best metadata
             GameSource::Epic => 2,
             GameSource::Xbox => 3,
-            GameSource::BattleNet => 4,
+            GameSource::BattleNet | GameSource::Itch => 4,
             GameSource::Manual => 5, // Lowest priority
         }
     }
@@ -0,0 +1,20 @@
+use crate::domain::BalamError;
+
+/// One captured image, 8-bit RGB rows top to bottom.
+#[derive(Debug, Clone)]
+pub struct CapturedFrame {
+    pub width: u32,
+    pub height: u32,
+    pub rgb: Vec<u8>,
+}
+
+/// Port for grabbing the picture of a game window.
+pub trait ScreenCapturePort: Send + Sync {
+    /// Captures the client area of the main window of `pid`, or of the
+    /// foreground window when `pid` is `None`.
+    ///
+    /// # Errors
+    /// Returns `NotFound` if there is no window to capture, `Platform` if
+    /// the capture API fails (e.g. secure desktop or display mode change).
+    fn capture_window(&self, pid: Option<u32>) -> Result<CapturedFrame, BalamError>;
+}
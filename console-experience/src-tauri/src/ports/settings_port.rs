@@ -0,0 +1,21 @@
+use crate::domain::settings::AppSettings;
+
+/// Port trait for persisting application settings.
+///
+/// Implementations are responsible for storage format and for migrating
+/// settings written by older schema versions to the current one.
+pub trait SettingsRepository: Send + Sync {
+    /// Loads settings from storage.
+    ///
+    /// # Returns
+    /// * `Ok(Some(settings))` - Settings found and migrated to the current schema
+    /// * `Ok(None)` - No settings stored yet (first run)
+    /// * `Err(String)` - Storage is unreadable or corrupt
+    fn load(&self) -> Result<Option<AppSettings>, String>;
+
+    /// Persists settings, replacing any previously stored values.
+    ///
+    /// # Errors
+    /// Returns error if the settings cannot be written.
+    fn save(&self, settings: &AppSettings) -> Result<(), String>;
+}
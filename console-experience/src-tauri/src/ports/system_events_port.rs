@@ -0,0 +1,50 @@
+/// System events port interface
+///
+/// Native change notifications for the values in `SystemStatus` (battery,
+/// volume, network), so the status is only re-read when something changed.
+use std::sync::Arc;
+
+/// What changed, as reported by the OS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemEventKind {
+    /// Battery percentage or AC/DC power source
+    Power,
+    /// Master volume or mute of the default output device
+    Volume,
+    /// Wi-Fi connected, disconnected or interface added/removed
+    Network,
+}
+
+/// Sleep state change of the whole machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SleepTransition {
+    /// About to enter sleep or hibernate
+    Suspending,
+    /// Back from sleep or hibernate (before the user is necessarily present)
+    Resumed,
+}
+
+/// Callback invoked with sleep transitions (same threading rules as [`SystemEventCallback`]).
+pub type SleepCallback = Arc<dyn Fn(SleepTransition) + Send + Sync>;
+
+/// Callback invoked from OS notification threads.
+///
+/// Must return quickly: implementations call it from Windows callback
+/// threads where blocking delays other notifications.
+pub type SystemEventCallback = Arc<dyn Fn(SystemEventKind) + Send + Sync>;
+
+/// Port trait for system change notifications
+pub trait SystemEventsPort: Send + Sync {
+    /// Starts delivering change notifications to `callback` for the lifetime of the app.
+    ///
+    /// # Returns
+    /// The sources that were registered. Sources missing from the list (no
+    /// battery, no Wi-Fi interface, registration failed) must be polled.
+    fn subscribe(&self, callback: SystemEventCallback) -> Vec<SystemEventKind>;
+
+    /// Starts delivering suspend/resume transitions to `callback` for the lifetime of the app.
+    ///
+    /// # Returns
+    /// `false` if the platform notification could not be registered.
+    fn subscribe_sleep(&self, callback: SleepCallback) -> bool;
+}
@@ -1,7 +1,8 @@
+use crate::domain::BalamError;
 use serde::Serialize;
 
 /// Domain entity representing the current system status.
-#[derive(Debug, Serialize, Clone)]
+#[derive(Debug, Serialize, Clone, PartialEq)]
 pub struct SystemStatus {
     /// Battery level percentage (0-100). `None` if no battery or desktop.
     pub battery_level: Option<u8>,
@@ -38,13 +39,15 @@ pub enum AudioDeviceType {
     USB,
     /// Bluetooth audio device
     Bluetooth,
+    /// Built-in or standalone microphone (input devices)
+    Microphone,
     /// Virtual audio device (e.g., VoiceMeeter, VB-Audio)
     Virtual,
     /// Unknown or unclassified device
     Generic,
 }
 
-/// Represents an audio output device.
+/// Represents an audio endpoint (output or input device).
 #[derive(Debug, Serialize, Clone)]
 pub struct AudioDevice {
     /// Unique device identifier (Windows: device ID string)
@@ -74,7 +77,7 @@ pub struct AudioDevice {
 /// let status = adapter.get_status();
 /// println!("Battery: {:?}%, Volume: {}%", status.battery_level, status.volume);
 /// ```
-pub trait SystemPort {
+pub trait SystemPort: Send + Sync {
     /// Gets the current status of the platform (Battery, Network, Sound).
     ///
     /// # Returns
@@ -95,7 +98,8 @@ pub trait SystemPort {
     /// * `level` - Volume percentage (0 = mute, 100 = maximum)
     ///
     /// # Errors
-    /// Returns `Err` if audio device access fails or platform API errors.
+    /// - `BalamError::InvalidArgument` if `level` is above 100
+    /// - `BalamError::Platform` if audio device access fails
     ///
     /// # Examples
     /// ```rust
@@ -103,9 +107,9 @@ pub trait SystemPort {
     /// # use console_experience::adapters::windows_system_adapter::WindowsSystemAdapter;
     /// let adapter = WindowsSystemAdapter::new();
     /// adapter.set_volume(75)?; // Set to 75%
-    /// # Ok::<(), String>(())
+    /// # Ok::<(), BalamError>(())
     /// ```
-    fn set_volume(&self, level: u32) -> Result<(), String>;
+    fn set_volume(&self, level: u32) -> Result<(), BalamError>;
 
     /// Initiates a system shutdown.
     ///
@@ -119,7 +123,7 @@ pub trait SystemPort {
     ///
     /// # Security
     /// Requires administrator/root privileges on most platforms.
-    fn shutdown(&self) -> Result<(), String>;
+    fn shutdown(&self) -> Result<(), BalamError>;
 
     /// Initiates a system restart.
     ///
@@ -130,7 +134,7 @@ pub trait SystemPort {
     /// - **Windows**: Uses `ExitWindowsEx` with `EWX_REBOOT` flag
     /// - **Linux**: Calls `systemctl reboot`
     /// - **macOS**: Calls `shutdown -r now`
-    fn restart(&self) -> Result<(), String>;
+    fn restart(&self) -> Result<(), BalamError>;
 
     /// Logs out the current user.
     ///
@@ -141,7 +145,17 @@ pub trait SystemPort {
     /// - **Windows**: Uses `ExitWindowsEx` with `EWX_LOGOFF` flag
     /// - **Linux**: Calls `loginctl terminate-user`
     /// - **macOS**: Calls `osascript` with logout command
-    fn logout(&self) -> Result<(), String>;
+    fn logout(&self) -> Result<(), BalamError>;
+
+    /// Puts the device to sleep (returns once it wakes up).
+    ///
+    /// # Errors
+    /// Returns `Err` if the platform refuses to suspend.
+    ///
+    /// # Platform Notes
+    /// - **Windows**: Uses `SetSuspendState` (S3 or Modern Standby; hibernates
+    ///   instead when hibernation is the configured sleep action)
+    fn suspend(&self) -> Result<(), BalamError>;
 
     /// Lists all available audio output devices.
     ///
@@ -164,9 +178,9 @@ pub trait SystemPort {
     /// for device in devices {
     ///     println!("{}: {} (default: {})", device.name, device.device_type, device.is_default);
     /// }
-    /// # Ok::<(), String>(())
+    /// # Ok::<(), BalamError>(())
     /// ```
-    fn list_audio_devices(&self) -> Result<Vec<AudioDevice>, String>;
+    fn list_audio_devices(&self) -> Result<Vec<AudioDevice>, BalamError>;
 
     /// Sets the default audio output device.
     ///
@@ -174,10 +188,8 @@ pub trait SystemPort {
     /// * `device_id` - Unique device identifier from `list_audio_devices()`
     ///
     /// # Errors
-    /// Returns `Err` if:
-    /// - Device ID not found
-    /// - Insufficient permissions
-    /// - COM interface access fails
+    /// - `BalamError::NotFound` if the device ID is not an active output
+    /// - `BalamError::Platform` if the COM interface access fails
     ///
     /// # Platform Notes
     /// - **Windows**: Uses `IPolicyConfig::SetDefaultEndpoint()` (undocumented API)
@@ -193,7 +205,52 @@ pub trait SystemPort {
     /// if let `Some`(headphones) = devices.iter().find(|d| d.device_type == AudioDeviceType::Headphones) {
     ///     adapter.set_default_audio_device(&headphones.id)?;
     /// }
-    /// # Ok::<(), String>(())
+    /// # Ok::<(), BalamError>(())
     /// ```
-    fn set_default_audio_device(&self, device_id: &str) -> Result<(), String>;
+    fn set_default_audio_device(&self, device_id: &str) -> Result<(), BalamError>;
+
+    /// Lists all active audio input devices (microphones, headset mics, line-in).
+    ///
+    /// # Returns
+    /// Same shape as `list_audio_devices()`, default input first.
+    ///
+    /// # Errors
+    /// Returns `Err` if COM initialization fails or device enumeration errors.
+    fn list_input_devices(&self) -> Result<Vec<AudioDevice>, BalamError>;
+
+    /// Sets the default input device for all roles (console, multimedia, communications).
+    ///
+    /// # Arguments
+    /// * `device_id` - Unique device identifier from `list_input_devices()`
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` if the device ID is not an active input
+    /// - `BalamError::Platform` if the COM interface access fails
+    fn set_default_input_device(&self, device_id: &str) -> Result<(), BalamError>;
+
+    /// Gets the level of the default microphone (0-100).
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` if there is no input device
+    /// - `BalamError::Platform` if the endpoint volume cannot be read
+    fn get_mic_volume(&self) -> Result<u32, BalamError>;
+
+    /// Sets the level of the default microphone (0-100).
+    ///
+    /// # Errors
+    /// - `BalamError::InvalidArgument` if `level` is above 100
+    /// - `BalamError::NotFound` if there is no input device
+    fn set_mic_volume(&self, level: u32) -> Result<(), BalamError>;
+
+    /// Whether the default microphone is muted.
+    ///
+    /// # Errors
+    /// `BalamError::NotFound` if there is no input device.
+    fn is_mic_muted(&self) -> Result<bool, BalamError>;
+
+    /// Mutes or unmutes the default microphone (system-wide, every app stops receiving audio).
+    ///
+    /// # Errors
+    /// `BalamError::NotFound` if there is no input device.
+    fn set_mic_muted(&self, muted: bool) -> Result<(), BalamError>;
 }
@@ -0,0 +1,14 @@
+use crate::domain::telemetry::TelemetryBatch;
+use crate::domain::BalamError;
+
+/// Port for uploading telemetry batches.
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`; calls run on the telemetry thread.
+pub trait TelemetrySinkPort: Send + Sync {
+    /// Uploads `batch` to `endpoint`.
+    ///
+    /// # Errors
+    /// - `BalamError::Platform` - Network failure or non-2xx response
+    fn send(&self, endpoint: &str, batch: &TelemetryBatch) -> Result<(), BalamError>;
+}
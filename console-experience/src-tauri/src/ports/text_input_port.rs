@@ -0,0 +1,37 @@
+/// Text input port interface
+///
+/// On-screen keyboards for controller text entry: the Windows touch
+/// keyboard, and keystroke injection for Balam's own keyboard when typing
+/// into other windows.
+use crate::domain::text_input::VirtualKey;
+use crate::domain::BalamError;
+
+/// Port trait for on-screen keyboard operations
+pub trait TextInputPort: Send + Sync {
+    /// Brings up the Windows touch keyboard (starting it if needed).
+    ///
+    /// # Errors
+    /// Returns error if the touch keyboard is not installed or cannot be reached.
+    fn show_touch_keyboard(&self) -> Result<(), BalamError>;
+
+    /// Dismisses the Windows touch keyboard; no-op if it is not shown.
+    ///
+    /// # Errors
+    /// Returns error if the touch keyboard cannot be reached.
+    fn hide_touch_keyboard(&self) -> Result<(), BalamError>;
+
+    /// Whether the Windows touch keyboard is on screen.
+    fn is_touch_keyboard_visible(&self) -> bool;
+
+    /// Types `text` into the foreground window.
+    ///
+    /// # Errors
+    /// Returns error if the input was blocked (e.g. an elevated window is in front).
+    fn type_text(&self, text: &str) -> Result<(), BalamError>;
+
+    /// Presses and releases an editing key in the foreground window.
+    ///
+    /// # Errors
+    /// Returns error if the input was blocked.
+    fn press_key(&self, key: VirtualKey) -> Result<(), BalamError>;
+}
@@ -0,0 +1,59 @@
+use crate::domain::thermal::{FanCurve, HandheldModel};
+use crate::domain::BalamError;
+
+/// Port for reading and overriding handheld fan behaviour.
+///
+/// Devices either accept a whole curve that their embedded controller runs
+/// (`supports_hardware_curve`), or only a fixed duty that the app must keep
+/// updating from the CPU temperature. In both cases `restore_auto` hands
+/// control back to the firmware and must be safe to call at any time.
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`; the fan control loop calls them from
+/// a background thread.
+pub trait ThermalPort: Send + Sync {
+    /// Device detected at startup (`Unknown` if none of the supported handhelds).
+    fn model(&self) -> HandheldModel;
+
+    /// Current fan speed in RPM.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - Device has no readable fan
+    /// - `BalamError::Platform` - ACPI/WMI/EC access failed
+    fn fan_rpm(&self) -> Result<u32, BalamError>;
+
+    /// CPU temperature in °C, as seen by the firmware.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - No temperature source on this device
+    /// - `BalamError::Platform` - ACPI/WMI access failed
+    fn cpu_temperature(&self) -> Result<f32, BalamError>;
+
+    /// `true` if the firmware runs a custom curve by itself (no control loop needed).
+    fn supports_hardware_curve(&self) -> bool;
+
+    /// Hands a validated curve to the firmware.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - Device only accepts fixed duties
+    /// - `BalamError::Platform` - Firmware call failed
+    fn apply_curve(&self, curve: &FanCurve) -> Result<(), BalamError>;
+
+    /// Sets a fixed fan duty (0-100), used by the software control loop.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - Device cannot be driven manually
+    /// - `BalamError::Platform` - Firmware call failed
+    fn set_fan_duty(&self, percent: u8) -> Result<(), BalamError>;
+
+    /// Returns fan control to the firmware's own curve.
+    ///
+    /// # Errors
+    /// Returns `BalamError::Platform` if the firmware call failed.
+    fn restore_auto(&self) -> Result<(), BalamError>;
+
+    /// `true` if this device's fan can be controlled.
+    fn supports_fan_control(&self) -> bool {
+        self.model() != HandheldModel::Unknown
+    }
+}
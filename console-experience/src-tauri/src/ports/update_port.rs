@@ -0,0 +1,28 @@
+use crate::domain::update::{ReleaseManifest, UpdateArtifact};
+use crate::domain::BalamError;
+use std::path::PathBuf;
+
+/// Port for the release feed and its packages.
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`; calls run on blocking worker threads.
+pub trait ReleaseFeedPort: Send + Sync {
+    /// Downloads and parses the manifest at `feed_url`.
+    ///
+    /// # Errors
+    /// - `BalamError::Platform` - Network failure or non-2xx response
+    /// - `BalamError::InvalidArgument` - The manifest is not valid JSON
+    fn fetch_manifest(&self, feed_url: &str) -> Result<ReleaseManifest, BalamError>;
+
+    /// Downloads `artifact` into the updates directory and returns its path.
+    ///
+    /// `on_progress(downloaded, total)` is called while the file streams in.
+    /// The file is only returned once its size, SHA-256 and signature all
+    /// match; otherwise it is deleted.
+    ///
+    /// # Errors
+    /// - `BalamError::Platform` - Network or disk failure
+    /// - `BalamError::AccessDenied` - Hash or signature mismatch, or no
+    ///   release key compiled into this build
+    fn download(&self, artifact: &UpdateArtifact, on_progress: &dyn Fn(u64, u64)) -> Result<PathBuf, BalamError>;
+}
@@ -0,0 +1,15 @@
+use crate::domain::dashboard::{DashboardSettings, WeatherReport};
+use crate::domain::BalamError;
+
+/// Port for the weather shown on the home screen.
+///
+/// # Thread Safety
+/// Implementations must be `Send + Sync`; calls run on the dashboard thread.
+pub trait WeatherPort: Send + Sync {
+    /// Current weather at the location of `settings`.
+    ///
+    /// # Errors
+    /// - `BalamError::NotSupported` - `settings.weather_provider` is disabled
+    /// - `BalamError::Platform` - Network failure or unexpected response
+    fn current(&self, settings: &DashboardSettings) -> Result<WeatherReport, BalamError>;
+}
@@ -1,13 +1,20 @@
-use serde::Serialize;
+use crate::domain::BalamError;
+use serde::{Deserialize, Serialize};
 
 /// `WiFi` security protocol type.
-#[derive(Debug, Clone, Serialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WiFiSecurity {
     Open,
     WEP,
     WPA,
+    /// WPA2-Personal (PSK)
     WPA2,
+    /// WPA3-Personal (SAE)
     WPA3,
+    /// WPA2 with 802.1X (username/password)
+    WPA2Enterprise,
+    /// WPA3 with 802.1X (username/password)
+    WPA3Enterprise,
     Unknown,
 }
 
@@ -33,10 +40,14 @@ pub struct WiFiNetwork {
 pub struct WiFiConfig {
     /// Network SSID
     pub ssid: String,
-    /// WPA/WPA2 password (empty for open networks)
+    /// Passphrase, or the account password on enterprise networks (empty for open networks)
     pub password: String,
     /// Whether to auto-connect in the future
     pub auto_connect: bool,
+    /// Security of the network, used to create a profile; looked up by scanning if `None`
+    pub security: Option<WiFiSecurity>,
+    /// Enterprise (802.1X) account, `DOMAIN\user` or `user`
+    pub username: Option<String>,
 }
 
 /// Port defining `WiFi` management capabilities.
@@ -56,8 +67,8 @@ pub trait WiFiPort: Send + Sync {
     /// # Returns
     /// - `Ok(Some(network))` if connected to `WiFi`
     /// - `Ok(None)` if not connected or connected to Ethernet
-    /// - `Err` if query failed
-    fn get_current_network(&self) -> Result<Option<WiFiNetwork>, String>;
+    /// - `Err(BalamError::WifiUnavailable)` if there is no Wi-Fi adapter
+    fn get_current_network(&self) -> Result<Option<WiFiNetwork>, BalamError>;
 
     /// Scans for available `WiFi` networks.
     ///
@@ -66,33 +77,37 @@ pub trait WiFiPort: Send + Sync {
     ///
     /// # Returns
     /// List of discovered networks, sorted by signal strength (strongest first).
-    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, String>;
+    fn scan_networks(&self) -> Result<Vec<WiFiNetwork>, BalamError>;
 
     /// Connects to a `WiFi` network.
     ///
+    /// Networks never joined before (or joined with new credentials) get a
+    /// profile created from `config` first; otherwise the saved profile is used.
+    ///
     /// # Errors
-    /// - Network not found
-    /// - Incorrect password
-    /// - Hardware disabled
-    /// - Already connected to another network
-    fn connect_network(&self, config: WiFiConfig) -> Result<(), String>;
+    /// - `BalamError::WifiUnavailable`: no Wi-Fi adapter or WLAN service stopped
+    /// - `BalamError::NotFound`: network not in range and no saved profile
+    /// - `BalamError::InvalidArgument`: password too short/long, enterprise network without username
+    /// - `BalamError::NotSupported`: WEP or unknown security
+    /// - `BalamError::Platform`: connection rejected (wrong password, out of range)
+    fn connect_network(&self, config: WiFiConfig) -> Result<(), BalamError>;
 
     /// Disconnects from the current `WiFi` network.
-    fn disconnect(&self) -> Result<(), String>;
+    fn disconnect(&self) -> Result<(), BalamError>;
 
     /// Forgets a previously connected network (removes saved profile).
     ///
     /// # Errors
-    /// - Network profile not found
-    fn forget_network(&self, ssid: &str) -> Result<(), String>;
+    /// - `BalamError::NotFound`: network profile not found
+    fn forget_network(&self, ssid: &str) -> Result<(), BalamError>;
 
     /// Gets the list of saved network profiles (SSIDs).
-    fn get_saved_networks(&self) -> Result<Vec<String>, String>;
+    fn get_saved_networks(&self) -> Result<Vec<String>, BalamError>;
 
     /// Gets signal strength of the currently connected network (0-100).
     ///
     /// # Returns
     /// - `Ok(Some(strength))` if connected and signal available
     /// - `Ok(None)` if not connected or signal unavailable
-    fn get_signal_strength(&self) -> Result<Option<u32>, String>;
+    fn get_signal_strength(&self) -> Result<Option<u32>, BalamError>;
 }
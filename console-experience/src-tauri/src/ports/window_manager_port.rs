@@ -0,0 +1,25 @@
+/// Window manager port interface
+///
+/// Reads and changes how a game's window covers the screen, so the TOPMOST
+/// overlay can draw over titles that start in exclusive fullscreen.
+use crate::domain::display::GameWindowMode;
+use crate::domain::BalamError;
+
+/// Port trait for game window mode control
+pub trait WindowManagerPort: Send + Sync {
+    /// Current mode of the main window of `pid`.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - The process has no visible window
+    fn window_mode(&self, pid: u32) -> Result<GameWindowMode, BalamError>;
+
+    /// Switches the main window of `pid` to `mode`.
+    ///
+    /// Leaving or entering exclusive fullscreen is up to the game: it is
+    /// asked with Alt+Enter, which most titles honor.
+    ///
+    /// # Errors
+    /// - `BalamError::NotFound` - The process has no visible window
+    /// - `BalamError::Platform` - The window could not be restyled
+    fn set_window_mode(&self, pid: u32, mode: GameWindowMode) -> Result<(), BalamError>;
+}
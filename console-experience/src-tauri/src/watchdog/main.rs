@@ -1,13 +1,28 @@
+mod supervisor;
+mod updater;
+
+use console_experience_lib::adapters::console_mode::WindowsShellAdapter;
+use console_experience_lib::adapters::update::UpdateMarkerStore;
+use console_experience_lib::domain::console_mode::{
+    HeartbeatFrame, CONSOLE_MODE_FLAG, HEARTBEAT_PIPE_NAME, SHELL_SUPERVISOR_FLAG,
+};
+use console_experience_lib::domain::autostart::SAFE_MODE_FLAG;
+use console_experience_lib::domain::update::UPDATE_HELPER_FLAG;
+use console_experience_lib::infrastructure::logging::{init_logging, LogComponent};
+use std::os::windows::io::AsRawHandle;
 use std::path::PathBuf;
 use std::process::Command;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
+use supervisor::Supervisor;
 use tokio::io::AsyncReadExt;
-use tokio::net::windows::named_pipe::ServerOptions;
+use tokio::net::windows::named_pipe::{NamedPipeServer, ServerOptions};
 use tokio::time::timeout;
 use tracing::{error, info, warn};
+use windows::Win32::Foundation::HANDLE;
+use windows::Win32::System::Pipes::GetNamedPipeClientProcessId;
 
-const PIPE_NAME: &str = r"\\.\pipe\balam_heartbeat";
 const HEARTBEAT_TIMEOUT_SECS: u64 = 10; // Timeout after 10 seconds without heartbeat
+const UI_HANG_RESTART_SECS: u64 = 30; // Restart after the UI reports hung for 30 seconds
 const MAX_CRASHES_BEFORE_SAFE_MODE: u32 = 3;
 const CRASH_WINDOW_SECONDS: u64 = 300; // 5 minutes
 
@@ -49,15 +64,37 @@ impl WatchdogState {
 ///
 /// Architecture:
 /// - Watchdog (this process) = Named Pipe Server (reads heartbeat)
-/// - Balam = Named Pipe Client (writes heartbeat every 2s)
+/// - Balam = Named Pipe Client (writes heartbeat every 2s, reconnects with backoff)
 ///
 /// Crash Detection:
-/// 1. Timeout (10s without heartbeat) → Balam frozen/hung
+/// 1. Timeout (10s without heartbeat) → Balam process frozen
 /// 2. Pipe disconnect → Balam crashed (OS closed pipe automatically)
+/// 3. UI-hang flag in every frame for 30s → window frozen while the process
+///    is alive; the hung process is terminated before restarting
 ///
 /// Recovery:
 /// - First 2 crashes → Auto-restart Balam
-/// - 3rd crash in 5min → Safe mode (launch explorer.exe)
+/// - 3rd crash in 5min → Safe mode: launch explorer.exe and restart Balam
+///   with `--safe-mode` (no overlay injection, RTSS, FPS service, chords or
+///   background services; library-only UI) without supervising it
+///
+/// Child supervision:
+/// - fps-service (and future helper processes) are probed every 2s and
+///   restarted per their restart policy with exponential backoff (1s → 60s)
+/// - Status is published on `\\.\pipe\balam_supervision` so Balam can show
+///   "service degraded" in the UI
+///
+/// Updates (`--apply-update`):
+/// - The same binary is the update helper: an unelevated copy in the updates
+///   folder and, when admin rights are needed, an elevated copy in an
+///   admin-only folder (see `updater.rs`)
+/// - When Balam exits with an update pending, the watchdog exits too instead
+///   of counting a crash, so its own executable can be replaced
+///
+/// Shell mode (`--shell`):
+/// - Registered as the Winlogon shell by console mode; starts Balam on sign-in
+/// - Safe mode also restores explorer.exe as the shell so the next sign-in boots
+///   into a normal desktop instead of the same crash loop
 ///
 /// Benefits over file I/O:
 /// - Automatic disconnect detection (no manual timeout logic needed)
@@ -65,19 +102,38 @@ impl WatchdogState {
 /// - Zero disk I/O
 #[tokio::main]
 async fn main() {
-    // Initialize logging
-    tracing_subscriber::fmt::init();
+    // Initialize logging (file in app data: the watchdog runs without a console as the shell)
+    let _log_guard = init_logging(LogComponent::Watchdog);
+
+    if std::env::args().any(|arg| arg == UPDATE_HELPER_FLAG) {
+        updater::run();
+        return;
+    }
 
     info!("🛡️ Balam Watchdog started (Named Pipes mode)");
-    info!("📡 Pipe: {}", PIPE_NAME);
+    info!("📡 Pipe: {}", HEARTBEAT_PIPE_NAME);
     info!("⏱️ Timeout: {}s", HEARTBEAT_TIMEOUT_SECS);
 
+    let supervisor = Supervisor::new(supervisor::default_children());
+    tokio::spawn(supervisor::serve_status(supervisor.status()));
+    std::thread::spawn(move || supervisor.run());
+
+    let shell_mode = std::env::args().any(|arg| arg == SHELL_SUPERVISOR_FLAG);
     let mut state = WatchdogState::new();
 
+    if shell_mode {
+        // Running as the Windows shell: nothing else will start Balam
+        info!("🎮 Shell mode: starting Balam as the console shell");
+        restart_balam(shell_mode, false);
+    }
+
     loop {
         // Create Named Pipe server (blocks until client connects)
         info!("🔄 Creating Named Pipe server...");
-        let mut server = match ServerOptions::new().first_pipe_instance(true).create(PIPE_NAME) {
+        let mut server = match ServerOptions::new()
+            .first_pipe_instance(true)
+            .create(HEARTBEAT_PIPE_NAME)
+        {
             Ok(s) => s,
             Err(e) => {
                 error!("Failed to create Named Pipe: {}", e);
@@ -99,6 +155,12 @@ async fn main() {
         // Monitor heartbeat loop
         let crash_detected = monitor_heartbeat(&mut server).await;
 
+        if crash_detected && UpdateMarkerStore::is_pending() {
+            // Planned exit: the update helper relaunches everything once installed
+            info!("📦 Balam exited to install an update");
+            break;
+        }
+
         if crash_detected {
             error!("❌ Balam crash detected!");
 
@@ -106,15 +168,19 @@ async fn main() {
             state.record_crash();
 
             if state.safe_mode_triggered {
-                // Too many crashes - launch explorer.exe as fallback
-                warn!("🚨 Safe mode triggered. Launching explorer.exe as fallback.");
+                // Too many crashes - launch explorer.exe as fallback and a minimal Balam
+                warn!("🚨 Safe mode triggered. Launching explorer.exe and Balam in safe mode.");
+                if shell_mode {
+                    restore_explorer_shell();
+                }
                 launch_explorer();
+                restart_balam(false, true);
                 break; // Exit watchdog
             }
 
             // Restart Balam
             info!("🔄 Restarting Balam...");
-            restart_balam();
+            restart_balam(shell_mode, false);
 
             // Wait a bit before accepting new connection
             tokio::time::sleep(Duration::from_secs(2)).await;
@@ -127,13 +193,31 @@ async fn main() {
 /// Monitors heartbeat from Balam via Named Pipe.
 ///
 /// Returns true if crash detected, false if graceful shutdown.
-async fn monitor_heartbeat(server: &mut tokio::net::windows::named_pipe::NamedPipeServer) -> bool {
+async fn monitor_heartbeat(server: &mut NamedPipeServer) -> bool {
+    let mut ui_hung_since: Option<Instant> = None;
+
     loop {
-        // Read u64 timestamp with timeout
+        // Read u64 frame (timestamp + UI state) with timeout
         match timeout(Duration::from_secs(HEARTBEAT_TIMEOUT_SECS), server.read_u64()).await {
-            Ok(Ok(_timestamp)) => {
-                // Heartbeat received successfully
-                // (Optional: Could log timestamp for debugging)
+            Ok(Ok(value)) => {
+                let frame = HeartbeatFrame::decode(value);
+                if frame.ui_responsive {
+                    if ui_hung_since.take().is_some() {
+                        info!("✅ Balam UI responsive again");
+                    }
+                    continue;
+                }
+
+                let since = *ui_hung_since.get_or_insert_with(|| {
+                    warn!("🥶 Balam reports its UI thread is not responding");
+                    Instant::now()
+                });
+                if since.elapsed() >= Duration::from_secs(UI_HANG_RESTART_SECS) {
+                    error!("❌ Balam UI hung for {}s", UI_HANG_RESTART_SECS);
+                    error!("   Reason: window frozen while the process is still alive");
+                    terminate_client(server);
+                    return true; // Treated as a crash
+                }
             },
             Ok(Err(e)) => {
                 // Pipe error (likely disconnect = crash)
@@ -151,8 +235,28 @@ async fn monitor_heartbeat(server: &mut tokio::net::windows::named_pipe::NamedPi
     }
 }
 
-fn restart_balam() {
-    info!("🔄 Attempting to restart Balam...");
+/// Kills the hung Balam process connected to the pipe so it can be restarted.
+fn terminate_client(server: &NamedPipeServer) {
+    let mut pid = 0u32;
+    let handle = HANDLE(server.as_raw_handle() as isize);
+    if let Err(e) = unsafe { GetNamedPipeClientProcessId(handle, &mut pid) } {
+        error!("❌ Cannot resolve hung Balam PID: {}", e);
+        return;
+    }
+
+    match Command::new("taskkill").args(["/F", "/PID", &pid.to_string()]).output() {
+        Ok(output) if output.status.success() => info!("🔪 Terminated hung Balam (PID {})", pid),
+        Ok(output) => error!(
+            "❌ taskkill failed for PID {}: {}",
+            pid,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => error!("❌ Failed to run taskkill: {}", e),
+    }
+}
+
+fn restart_balam(shell_mode: bool, safe_mode: bool) {
+    info!("🔄 Attempting to restart Balam (safe mode: {})...", safe_mode);
 
     // Find Balam executable (same directory as watchdog)
     let exe_path = std::env::current_exe().ok();
@@ -162,7 +266,16 @@ fn restart_balam() {
 
     info!("📂 Balam path: {}", balam_path.display());
 
-    match Command::new(&balam_path).spawn() {
+    let mut command = Command::new(&balam_path);
+    if shell_mode {
+        // Tells Balam to send heartbeats (it is the only UI on this session)
+        command.arg(CONSOLE_MODE_FLAG);
+    }
+    if safe_mode {
+        command.arg(SAFE_MODE_FLAG);
+    }
+
+    match command.spawn() {
         Ok(child) => {
             info!("✅ Balam restarted with PID: {}", child.id());
         },
@@ -173,6 +286,18 @@ fn restart_balam() {
     }
 }
 
+/// Unregisters Balam as the Windows shell after a crash loop.
+fn restore_explorer_shell() {
+    let errors = WindowsShellAdapter::new().restore_explorer();
+    if errors.is_empty() {
+        info!("✅ explorer.exe restored as Windows shell");
+    } else {
+        for e in errors {
+            error!("❌ Failed to restore explorer shell: {}", e);
+        }
+    }
+}
+
 fn launch_explorer() {
     info!("🔄 Launching explorer.exe as fallback shell...");
 
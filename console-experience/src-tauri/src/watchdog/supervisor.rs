@@ -0,0 +1,360 @@
+use console_experience_lib::adapters::update::UpdateMarkerStore;
+use console_experience_lib::domain::supervision::{
+    gives_up, restart_backoff, ChildKind, ChildSpec, ChildState, ChildStatus, RestartPolicy, SupervisionStatus,
+    SUPERVISION_PIPE_NAME,
+};
+use parking_lot::Mutex;
+use std::path::PathBuf;
+use std::process::{Child, Command};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::net::windows::named_pipe::ServerOptions;
+use tracing::{error, info, warn};
+
+/// How often every child is probed.
+const CHECK_INTERVAL: Duration = Duration::from_secs(2);
+/// A child that stays up this long starts again from the shortest backoff.
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+/// Children supervised next to Balam.
+///
+/// Helpers split out of the main exe (gamepad listener, overlay host) are
+/// added here as `ChildKind::Executable` with their own restart policy.
+pub fn default_children() -> Vec<ChildSpec> {
+    vec![ChildSpec {
+        name: "fps-service".to_string(),
+        kind: ChildKind::WindowsService {
+            service_name: "BalamFpsService".to_string(),
+        },
+        restart_policy: RestartPolicy::Always,
+        essential: true,
+    }]
+}
+
+/// Why a start failed.
+struct StartError {
+    message: String,
+    /// The watchdog lacks the rights (`sc start` as a standard user)
+    access_denied: bool,
+}
+
+impl From<String> for StartError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            access_denied: false,
+        }
+    }
+}
+
+/// Result of probing a child.
+enum Probe {
+    Running,
+    /// Executable never started by this watchdog
+    NotStarted,
+    Exited {
+        success: bool,
+    },
+    NotInstalled,
+}
+
+struct Supervised {
+    spec: ChildSpec,
+    status: ChildStatus,
+    /// Handle of an executable child started by the watchdog
+    process: Option<Child>,
+    running_since: Option<Instant>,
+    retry_at: Option<Instant>,
+}
+
+impl Supervised {
+    fn new(spec: ChildSpec) -> Self {
+        Self {
+            status: ChildStatus::new(&spec),
+            spec,
+            process: None,
+            running_since: None,
+            retry_at: None,
+        }
+    }
+
+    fn tick(&mut self, now: Instant) {
+        match self.probe() {
+            Probe::Running => self.mark_running(now),
+            Probe::NotInstalled => {
+                self.running_since = None;
+                self.retry_at = None;
+                self.status.state = ChildState::NotInstalled;
+            },
+            // Given up: only a start from elsewhere (the app's broker) brings it back
+            Probe::NotStarted | Probe::Exited { .. } if self.status.state == ChildState::Failed => {
+                self.retry_at = None;
+            },
+            Probe::NotStarted => {
+                if self.spec.restart_policy == RestartPolicy::Never {
+                    self.status.state = ChildState::Stopped;
+                } else {
+                    self.restart_when_due(now);
+                }
+            },
+            Probe::Exited { success } => {
+                if self.running_since.take().is_some() {
+                    warn!("⚠️ {} stopped (clean exit: {})", self.spec.name, success);
+                    self.status.consecutive_failures += 1;
+                }
+                if self.spec.restart_policy.should_restart(success) {
+                    self.restart_when_due(now);
+                } else {
+                    self.retry_at = None;
+                    self.status.state = ChildState::Stopped;
+                }
+            },
+        }
+
+        self.status.next_retry_secs = self.retry_at.map(|at| at.saturating_duration_since(now).as_secs());
+    }
+
+    fn mark_running(&mut self, now: Instant) {
+        if self.status.state != ChildState::Running {
+            info!("✅ {} running", self.spec.name);
+        }
+        let since = *self.running_since.get_or_insert(now);
+        if now.duration_since(since) >= STABLE_AFTER {
+            self.status.consecutive_failures = 0;
+        }
+        self.retry_at = None;
+        self.status.state = ChildState::Running;
+    }
+
+    fn restart_when_due(&mut self, now: Instant) {
+        let failures = self.status.consecutive_failures;
+        let due = *self.retry_at.get_or_insert_with(|| {
+            let delay = failures.checked_sub(1).map_or(Duration::ZERO, restart_backoff);
+            if !delay.is_zero() {
+                info!(
+                    "⏳ Restarting {} in {:?} (failures: {})",
+                    self.spec.name, delay, failures
+                );
+            }
+            now + delay
+        });
+        self.status.state = ChildState::Restarting;
+        if now < due {
+            return;
+        }
+
+        self.retry_at = None;
+        match self.start() {
+            Ok(()) => {
+                info!("🔄 Started {}", self.spec.name);
+                if failures > 0 {
+                    self.status.restarts += 1;
+                }
+                self.status.last_error = None;
+                self.mark_running(now);
+            },
+            Err(e) => {
+                error!("❌ Failed to start {}: {}", self.spec.name, e.message);
+                self.status.consecutive_failures += 1;
+                self.status.last_error = Some(e.message);
+                if gives_up(self.status.consecutive_failures, e.access_denied) {
+                    warn!(
+                        "🚧 Giving up on {} after {} failed start(s)",
+                        self.spec.name, self.status.consecutive_failures
+                    );
+                    self.status.state = ChildState::Failed;
+                }
+            },
+        }
+    }
+
+    fn probe(&mut self) -> Probe {
+        match &self.spec.kind {
+            ChildKind::Executable { file_name } => {
+                let Some(process) = self.process.as_mut() else {
+                    return if executable_path(file_name).exists() {
+                        Probe::NotStarted
+                    } else {
+                        Probe::NotInstalled
+                    };
+                };
+                match process.try_wait() {
+                    Ok(None) => Probe::Running,
+                    Ok(Some(exit)) => {
+                        self.process = None;
+                        Probe::Exited {
+                            success: exit.success(),
+                        }
+                    },
+                    Err(e) => {
+                        warn!("Cannot query {}: {}", self.spec.name, e);
+                        self.process = None;
+                        Probe::Exited { success: false }
+                    },
+                }
+            },
+            ChildKind::WindowsService { service_name } => probe_service(service_name),
+        }
+    }
+
+    fn start(&mut self) -> Result<(), StartError> {
+        match &self.spec.kind {
+            ChildKind::Executable { file_name } => {
+                let child = Command::new(executable_path(file_name))
+                    .spawn()
+                    .map_err(|e| format!("Failed to spawn {file_name}: {e}"))?;
+                self.process = Some(child);
+                Ok(())
+            },
+            ChildKind::WindowsService { service_name } => start_service(service_name),
+        }
+    }
+}
+
+/// Executables are shipped next to the watchdog.
+fn executable_path(file_name: &str) -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(file_name)))
+        .unwrap_or_else(|| PathBuf::from(file_name))
+}
+
+fn probe_service(service_name: &str) -> Probe {
+    let Ok(output) = Command::new("sc").args(["query", service_name]).output() else {
+        return Probe::Exited { success: false };
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !output.status.success() {
+        // ERROR_SERVICE_DOES_NOT_EXIST
+        return if stdout.contains("1060") {
+            Probe::NotInstalled
+        } else {
+            Probe::Exited { success: false }
+        };
+    }
+
+    if stdout.contains("RUNNING") || stdout.contains("START_PENDING") {
+        return Probe::Running;
+    }
+
+    // "WIN32_EXIT_CODE    : 0  (0x0)" after a clean stop
+    let exit_code = stdout
+        .lines()
+        .find(|line| line.contains("WIN32_EXIT_CODE"))
+        .and_then(|line| line.split(':').nth(1))
+        .and_then(|value| value.split_whitespace().next());
+    Probe::Exited {
+        success: exit_code == Some("0"),
+    }
+}
+
+fn start_service(service_name: &str) -> Result<(), StartError> {
+    let output = Command::new("sc")
+        .args(["start", service_name])
+        .output()
+        .map_err(|e| format!("Failed to run sc: {e}"))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // ERROR_SERVICE_ALREADY_RUNNING counts as started
+    if output.status.success() || stdout.contains("1056") {
+        Ok(())
+    } else {
+        Err(StartError {
+            message: format!("sc start failed: {}", stdout.trim()),
+            // "[SC] StartService: OpenService FAILED 5:" (ERROR_ACCESS_DENIED)
+            access_denied: stdout.contains("FAILED 5:"),
+        })
+    }
+}
+
+/// Supervises the watchdog's children with per-child restart policies and
+/// exponential backoff, and publishes their status for the main app.
+pub struct Supervisor {
+    children: Vec<Supervised>,
+    status: Arc<Mutex<SupervisionStatus>>,
+}
+
+impl Supervisor {
+    pub fn new(specs: Vec<ChildSpec>) -> Self {
+        Self {
+            children: specs.into_iter().map(Supervised::new).collect(),
+            status: Arc::new(Mutex::new(SupervisionStatus::default())),
+        }
+    }
+
+    /// Shared status read by the status pipe server.
+    pub fn status(&self) -> Arc<Mutex<SupervisionStatus>> {
+        self.status.clone()
+    }
+
+    /// Probes and restarts children forever (blocking: run on its own thread).
+    pub fn run(mut self) {
+        info!("👪 Supervising {} child process(es)", self.children.len());
+        loop {
+            // The update helper stops and replaces the children: leave them down
+            if UpdateMarkerStore::is_pending() {
+                std::thread::sleep(CHECK_INTERVAL);
+                continue;
+            }
+
+            let now = Instant::now();
+            for child in &mut self.children {
+                child.tick(now);
+            }
+
+            let snapshot = SupervisionStatus::new(self.children.iter().map(|child| child.status.clone()).collect());
+            let was_degraded = std::mem::replace(&mut *self.status.lock(), snapshot.clone()).degraded;
+            if snapshot.degraded != was_degraded {
+                if snapshot.degraded {
+                    warn!("🚧 Supervision degraded");
+                } else {
+                    info!("✅ Supervision healthy");
+                }
+            }
+
+            std::thread::sleep(CHECK_INTERVAL);
+        }
+    }
+}
+
+/// Serves the supervision status on `SUPERVISION_PIPE_NAME`.
+///
+/// Each connection receives one JSON line and is closed. The next pipe
+/// instance is created before answering so clients never see the pipe missing.
+pub async fn serve_status(status: Arc<Mutex<SupervisionStatus>>) {
+    let mut server = match ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(SUPERVISION_PIPE_NAME)
+    {
+        Ok(server) => server,
+        Err(e) => {
+            error!("❌ Failed to create supervision pipe: {}", e);
+            return;
+        },
+    };
+
+    loop {
+        if let Err(e) = server.connect().await {
+            warn!("Supervision pipe connect failed: {}", e);
+        } else {
+            let mut line = serde_json::to_vec(&*status.lock()).unwrap_or_default();
+            line.push(b'\n');
+            let mut connected = server;
+            tokio::spawn(async move {
+                if let Err(e) = connected.write_all(&line).await {
+                    warn!("Failed to send supervision status: {}", e);
+                }
+                let _ = connected.flush().await;
+            });
+        }
+
+        server = match ServerOptions::new().create(SUPERVISION_PIPE_NAME) {
+            Ok(server) => server,
+            Err(e) => {
+                error!("❌ Failed to recreate supervision pipe: {}", e);
+                return;
+            },
+        };
+    }
+}
@@ -0,0 +1,202 @@
+use console_experience_lib::adapters::fps_service::FpsServiceInstaller;
+use console_experience_lib::adapters::update::{updates_dir, UpdateInstaller, UpdateMarkerStore};
+use console_experience_lib::application::commands::is_elevated;
+use console_experience_lib::domain::console_mode::SHELL_SUPERVISOR_FLAG;
+use console_experience_lib::domain::privilege::BROKER_EXECUTABLE;
+use console_experience_lib::domain::update::{UpdateMarker, UPDATE_INSTALL_DIR_FLAG};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, System};
+use tracing::{error, info, warn};
+
+/// Executables that must be closed before the install directory is touched.
+const INSTALLED_EXECUTABLES: [&str; 4] = [
+    "console-experience.exe",
+    "watchdog.exe",
+    "balam-fps-service.exe",
+    BROKER_EXECUTABLE,
+];
+/// Grace period for Balam and the watchdog to exit before they are killed.
+const EXIT_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often the unelevated helper checks whether the elevated one finished.
+const INSTALL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Update helper (`watchdog.exe --apply-update`).
+///
+/// Which step runs depends on how the helper was started:
+/// - Unelevated copy in the updates folder, with `--install-dir` (started by
+///   Balam): waits for Balam to exit, installs itself or hands over to the
+///   elevated helper, then relaunches Balam at medium integrity
+/// - Elevated from the install directory, without `--install-dir` (started
+///   through UAC): copies itself to an admin-only folder and starts the copy
+/// - Elevated copy in that admin-only folder, with `--install-dir`: stops
+///   fps-service, verifies and installs the package, restarts the service
+pub fn run() {
+    let args: Vec<String> = std::env::args().collect();
+    let install_dir = args
+        .iter()
+        .position(|arg| arg == UPDATE_INSTALL_DIR_FLAG)
+        .and_then(|index| args.get(index + 1))
+        .map(PathBuf::from);
+
+    match (is_elevated(), install_dir) {
+        (false, Some(install_dir)) => run_unelevated(&install_dir),
+        (true, None) => stage_elevated(),
+        (true, Some(install_dir)) => install_elevated(&install_dir),
+        (false, None) => warn!("⚠️ Update helper started without an install directory"),
+    }
+}
+
+/// Runs at the user's own rights: the only step that relaunches Balam.
+fn run_unelevated(install_dir: &Path) {
+    let Some(marker) = UpdateMarkerStore::read() else {
+        warn!("⚠️ Update helper started without a pending update");
+        return;
+    };
+    info!("📦 Update helper: installing {}", marker.version);
+    wait_for_exit(install_dir, Some(marker.balam_pid));
+
+    if marker.elevate {
+        match UpdateInstaller::launch_elevated_helper(install_dir) {
+            // The elevated helper clears the marker once done (or it goes stale)
+            Ok(()) => {
+                while UpdateMarkerStore::is_pending() {
+                    std::thread::sleep(INSTALL_POLL_INTERVAL);
+                }
+            },
+            Err(e) => {
+                error!("❌ Update to {} cancelled: {}", marker.version, e);
+                UpdateMarkerStore::clear();
+            },
+        }
+    } else {
+        let installed = updates_dir().and_then(|dir| {
+            let package = UpdateInstaller::stage_package(&marker, &dir)?;
+            let result = UpdateInstaller::install(marker.kind, &package, install_dir);
+            let _ = std::fs::remove_file(&package);
+            result
+        });
+        log_install_result(&marker, installed);
+        UpdateMarkerStore::clear();
+    }
+
+    let _ = std::fs::remove_file(&marker.package_path);
+    relaunch(install_dir, marker.shell_mode);
+}
+
+/// Started through UAC from the install directory, which is also where the
+/// install directory is taken from.
+fn stage_elevated() {
+    let Some(install_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+    else {
+        error!("❌ Elevated update helper cannot locate its install directory");
+        UpdateMarkerStore::clear();
+        return;
+    };
+    if let Err(e) = UpdateInstaller::stage_elevated_helper(&install_dir) {
+        error!("❌ Failed to stage the elevated update helper: {}", e);
+        UpdateMarkerStore::clear();
+    }
+}
+
+/// The staged copy: everything it reads from the marker is verified again.
+fn install_elevated(install_dir: &Path) {
+    let Some(staging_dir) = UpdateInstaller::staged_helper_dir() else {
+        error!("❌ Elevated update helper refused: not running from an admin-only folder");
+        return;
+    };
+    let Some(marker) = UpdateMarkerStore::read() else {
+        warn!("⚠️ Update helper started without a pending update");
+        return;
+    };
+
+    // Balam's PID comes from the marker: only the unelevated helper waits on it
+    wait_for_exit(install_dir, None);
+    if FpsServiceInstaller::is_installed() {
+        if let Err(e) = FpsServiceInstaller::stop() {
+            warn!("Failed to stop fps-service: {}", e);
+        }
+        wait_for_exit(install_dir, None);
+    }
+
+    let installed = UpdateInstaller::stage_package(&marker, &staging_dir).and_then(|package| {
+        let result = UpdateInstaller::install(marker.kind, &package, install_dir);
+        let _ = std::fs::remove_file(&package);
+        result
+    });
+    log_install_result(&marker, installed);
+
+    if marker.fps_service_was_running {
+        if let Err(e) = FpsServiceInstaller::start() {
+            error!("❌ Failed to restart fps-service: {}", e);
+        }
+    }
+    // Releases the unelevated helper, which relaunches Balam
+    UpdateMarkerStore::clear();
+}
+
+fn log_install_result(marker: &UpdateMarker, result: Result<(), impl std::fmt::Display>) {
+    match result {
+        Ok(()) => info!("✅ Updated to {}", marker.version),
+        Err(e) => error!("❌ Update to {} failed: {}", marker.version, e),
+    }
+}
+
+/// Waits for Balam and every executable of the install directory to exit,
+/// killing whatever is left after [`EXIT_TIMEOUT`].
+fn wait_for_exit(install_dir: &Path, balam_pid: Option<u32>) {
+    let started = Instant::now();
+    let mut sys = System::new();
+    loop {
+        sys.refresh_processes();
+        let blocking: Vec<_> = sys
+            .processes()
+            .values()
+            .filter(|process| {
+                balam_pid.is_some_and(|pid| process.pid() == Pid::from_u32(pid))
+                    || process.exe().is_some_and(|exe| {
+                        exe.parent() == Some(install_dir)
+                            && exe.file_name().is_some_and(|name| {
+                                INSTALLED_EXECUTABLES
+                                    .iter()
+                                    .any(|installed| name.eq_ignore_ascii_case(installed))
+                            })
+                    })
+            })
+            .collect();
+        if blocking.is_empty() {
+            return;
+        }
+
+        if started.elapsed() >= EXIT_TIMEOUT {
+            for process in blocking {
+                warn!("🔪 {} (PID {}) did not exit, killing it", process.name(), process.pid());
+                process.kill();
+            }
+            std::thread::sleep(Duration::from_secs(1));
+            return;
+        }
+        std::thread::sleep(Duration::from_millis(500));
+    }
+}
+
+/// Only called from the unelevated helper, so Balam comes back at medium
+/// integrity whatever rights the install needed.
+fn relaunch(install_dir: &Path, shell_mode: bool) {
+    let mut command = if shell_mode {
+        // The watchdog starts Balam as the shell again
+        let mut command = Command::new(install_dir.join("watchdog.exe"));
+        command.arg(SHELL_SUPERVISOR_FLAG);
+        command
+    } else {
+        Command::new(install_dir.join("console-experience.exe"))
+    };
+
+    match command.current_dir(install_dir).spawn() {
+        Ok(child) => info!("🚀 Relaunched Balam (PID {})", child.id()),
+        Err(e) => error!("❌ Failed to relaunch Balam: {}", e),
+    }
+}
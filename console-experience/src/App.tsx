@@ -31,6 +31,7 @@ import { useNavigation } from './hooks/useNavigation';
 import { useOsd } from './hooks/useOsd';
 import { useOverlayPanels } from './hooks/useOverlayPanels';
 import { usePipWindow } from './hooks/usePipWindow';
+import { useSafeMode } from './hooks/useSafeMode';
 import { useSidebarActions } from './hooks/useSidebarActions';
 import { useVirtualKeyboard } from './hooks/useVirtualKeyboard';
 import { initDatabase } from './services/database';
@@ -88,6 +89,7 @@ function App() {
 
   // ── Extracted hooks ────────────────────────────────────────────────────────
   const { osdValue, isOsdVisible, handleVolumeChange } = useOsd();
+  const { safeMode, exitSafeMode } = useSafeMode();
 
   const {
     isExplorerOpen,
@@ -342,7 +344,7 @@ function App() {
       <div className="app-overlay" />
       <SystemOSD type="volume" value={osdValue} isVisible={isOsdVisible} />
 
-      {!isSidebarOpen && (
+      {!isSidebarOpen && !safeMode && (
         <div
           className="menu-hint-area"
           onMouseEnter={() => setSidebarOpen(true)}
@@ -355,18 +357,30 @@ function App() {
         <div className="sidebar-backdrop" onClick={() => setSidebarOpen(false)} />
       ) : null}
 
-      <Sidebar
-        isOpen={isSidebarOpen}
-        onToggle={() => setSidebarOpen(!isSidebarOpen)}
-        onAction={(id) => void handleSidebarAction(id)}
-      />
+      {!safeMode && (
+        <Sidebar
+          isOpen={isSidebarOpen}
+          onToggle={() => setSidebarOpen(!isSidebarOpen)}
+          onAction={(id) => void handleSidebarAction(id)}
+        />
+      )}
 
       <div className="app-container" data-focus-area={focusArea}>
-        <TopBar
-          onVolumeChange={handleVolumeChange}
-          onOpenWiFiPanel={() => setIsWiFiPanelOpen(true)}
-          onOpenBluetoothPanel={() => setIsBluetoothPanelOpen(true)}
-        />
+        {/* Safe mode (after a crash loop): library only */}
+        {safeMode ? (
+          <div className="safe-mode-banner">
+            <span>Safe mode: overlays and background services are off</span>
+            <button type="button" onClick={exitSafeMode}>
+              Exit safe mode
+            </button>
+          </div>
+        ) : (
+          <TopBar
+            onVolumeChange={handleVolumeChange}
+            onOpenWiFiPanel={() => setIsWiFiPanelOpen(true)}
+            onOpenBluetoothPanel={() => setIsBluetoothPanelOpen(true)}
+          />
+        )}
 
         <main className={`main-content ${isSidebarOpen ? 'sidebar-open' : ''}`}>
           <HeroSection
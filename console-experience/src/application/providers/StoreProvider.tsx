@@ -9,7 +9,7 @@
 import { listen } from '@tauri-apps/api/event';
 import { createContext, ReactNode, useContext, useEffect } from 'react';
 
-import type { GameLaunchError } from '../../domain';
+import type { ActiveGame, GameLaunchError } from '../../domain';
 import { getFailureDescription } from '../../domain';
 import { TauriGameRepository, TauriSystemRepository } from '../../infrastructure/repositories';
 import { toast } from '../../utils/toast';
@@ -110,11 +110,22 @@ export function StoreProvider({ children }: { children: ReactNode }) {
         }
       });
 
+      // Listener 5: Library game started outside Balam (Steam, desktop shortcut)
+      const unlistenExternalGame = await listen<ActiveGame>('external-game-detected', (event) => {
+        const activeGame = event.payload;
+        if (!appStore.getState().game.activeRunningGame) {
+          appStore.setState((state) => ({
+            game: { ...state.game, isLaunching: false, activeRunningGame: activeGame },
+          }));
+        }
+      });
+
       return () => {
         void unlistenLaunchFailed();
         void unlistenProcessStarted();
         void unlistenProcessStopped();
         void unlistenQuickExit();
+        void unlistenExternalGame();
       };
     };
 
@@ -162,6 +173,7 @@ export function useGameStore() {
     killGame: store.killGame,
     addManualGame: store.addManualGame,
     removeGame: store.removeGame,
+    applyArtwork: store.applyArtwork,
     clearError: store.clearGameError,
   };
 }
@@ -14,7 +14,7 @@
 
 import * as v from 'valibot';
 
-import type { ActiveGame, Game } from '../../../domain/entities/game';
+import type { ActiveGame, Game, GameArtwork } from '../../../domain/entities/game';
 import type { GameRepository } from '../../../domain/repositories/game-repository';
 import {
   ActiveGameSchema,
@@ -41,6 +41,7 @@ export interface GameSlice {
   killGame: (pid: number) => Promise<void>;
   addManualGame: (title: string, exePath: string) => Promise<void>;
   removeGame: (gameId: string) => Promise<void>;
+  applyArtwork: (artwork: GameArtwork) => void;
   clearGameError: () => void;
 }
 
@@ -194,6 +195,25 @@ export const createGameSlice = (
     }
   },
 
+  // Artwork cached in the background after the library was loaded
+  applyArtwork: (artwork: GameArtwork) => {
+    set((state) => ({
+      game: {
+        ...state.game,
+        games: state.game.games.map((g: Game) =>
+          g.id === artwork.game_id
+            ? {
+                ...g,
+                image: artwork.image ?? g.image,
+                hero_image: artwork.hero_image ?? g.hero_image,
+                logo: artwork.logo ?? g.logo,
+              }
+            : g
+        ),
+      },
+    }));
+  },
+
   // Clear error message
   clearGameError: () => {
     set((state) => ({
@@ -59,9 +59,9 @@ const TopBar: React.FC<TopBarProps> = ({
     };
   }, []);
 
-  // System Status Poller (Lightweight, every 10 seconds)
+  // System Status: initial read, then pushed by the backend only when something changes
   useEffect(() => {
-    const updateStatus = async () => {
+    const loadStatus = async () => {
       try {
         const result = await invoke<SystemStatus>('get_system_status');
         setStatus(result);
@@ -70,9 +70,13 @@ const TopBar: React.FC<TopBarProps> = ({
       }
     };
 
-    void updateStatus();
-    const poller = setInterval(() => void updateStatus(), 10000);
-    return () => clearInterval(poller);
+    void loadStatus();
+    const unlistenPromise = listen<SystemStatus>('system-status-changed', (event) => {
+      setStatus(event.payload);
+    });
+    return () => {
+      void unlistenPromise.then((unlisten) => unlisten());
+    };
   }, []);
 
   // Listen for background volume changes (Keyboard/System) for instant icon update
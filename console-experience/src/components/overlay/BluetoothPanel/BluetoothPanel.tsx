@@ -4,6 +4,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { Bluetooth, BluetoothOff } from 'lucide-react';
 import React, { useCallback, useEffect, useState } from 'react';
 
+import { getErrorCode, getErrorMessage } from '@/domain/errors/balam-error';
 import { useToast } from '@/hooks/useToast';
 
 import ButtonHint from '../../ui/ButtonHint/ButtonHint';
@@ -202,11 +203,19 @@ export const BluetoothPanel: React.FC<BluetoothPanelProps> = ({
         let errorMsg = 'Operation failed';
         let hint = BLUETOOTH_ERROR_HINTS.default;
 
-        // Parse error message for specific error types
-        const errorStr =
-          error instanceof Error ? error.message.toLowerCase() : String(error).toLowerCase();
+        // Typed codes first, then parse the message for specific error types
+        const code = getErrorCode(error);
+        const errorStr = getErrorMessage(error).toLowerCase();
 
-        if (errorStr.includes('timeout')) {
+        if (code === 'BLUETOOTH_OFF') {
+          hint = BLUETOOTH_ERROR_HINTS.bluetooth_off;
+        } else if (code === 'BLUETOOTH_UNAVAILABLE') {
+          hint = BLUETOOTH_ERROR_HINTS.adapter_unavailable;
+        } else if (code === 'ACCESS_DENIED') {
+          hint = BLUETOOTH_ERROR_HINTS.permission_denied;
+        } else if (code === 'NOT_FOUND') {
+          hint = BLUETOOTH_ERROR_HINTS.device_not_found;
+        } else if (errorStr.includes('timeout')) {
           hint =
             device.pairing_state === 'Unpaired'
               ? BLUETOOTH_ERROR_HINTS.pairing_timeout
@@ -268,11 +277,15 @@ export const BluetoothPanel: React.FC<BluetoothPanelProps> = ({
         success('Bluetooth disabled', 'All devices disconnected');
       }
     } catch (error) {
-      const errorStr =
-        error instanceof Error ? error.message.toLowerCase() : String(error).toLowerCase();
+      const code = getErrorCode(error);
+      const errorStr = getErrorMessage(error).toLowerCase();
       let hint = BLUETOOTH_ERROR_HINTS.default;
 
-      if (errorStr.includes('adapter') || errorStr.includes('not found')) {
+      if (code === 'BLUETOOTH_UNAVAILABLE') {
+        hint = BLUETOOTH_ERROR_HINTS.adapter_unavailable;
+      } else if (code === 'ACCESS_DENIED') {
+        hint = BLUETOOTH_ERROR_HINTS.permission_denied;
+      } else if (errorStr.includes('adapter') || errorStr.includes('not found')) {
         hint = BLUETOOTH_ERROR_HINTS.adapter_unavailable;
       } else if (errorStr.includes('permission') || errorStr.includes('denied')) {
         hint = BLUETOOTH_ERROR_HINTS.permission_denied;
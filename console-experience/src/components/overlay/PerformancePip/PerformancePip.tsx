@@ -3,6 +3,7 @@ import './PerformancePip.css';
 import React from 'react';
 
 import type { OverlayLevel } from '../../../application/stores';
+import { useNetworkQuality } from '../../../hooks/useNetworkQuality';
 import { usePerformanceMetrics } from '../../../hooks/usePerformanceMetrics';
 
 interface PerformancePipProps {
@@ -28,6 +29,7 @@ interface PerformancePipProps {
  */
 export const PerformancePip: React.FC<PerformancePipProps> = React.memo(({ level, opacity }) => {
   const { metrics, loading, error } = usePerformanceMetrics({ interval: 1000, enabled: level > 0 });
+  const { quality: network, spiking } = useNetworkQuality({ interval: 1000, enabled: level >= 4 });
 
   // Level 0: Hidden
   if (level === 0) {
@@ -70,6 +72,12 @@ export const PerformancePip: React.FC<PerformancePipProps> = React.memo(({ level
   const gpuTemp = metrics.gpu_temp_c;
   const cpuTemp = metrics.cpu_temp_c;
   const gpuPower = metrics.gpu_power_w;
+  const vramUsed = metrics.vram_used_mb ?? null;
+  const vramTotal = metrics.vram_total_mb ?? null;
+  const gameVram = metrics.game?.gpu_dedicated_mb ?? null;
+  // The game spills past the GPU's own memory (small iGPU carve-out)
+  const vramExceeded =
+    gameVram !== null && vramTotal !== null && vramTotal > 0 && gameVram > vramTotal;
 
   return (
     <div className="performance-pip">
@@ -109,7 +117,7 @@ export const PerformancePip: React.FC<PerformancePipProps> = React.memo(({ level
           </>
         ) : null}
 
-        {/* Level 4: RAM + GPU Power - Compact */}
+        {/* Level 4: RAM + GPU Power + Ping - Compact */}
         {level >= 4 ? (
           <>
             <div className="pip-row">
@@ -118,12 +126,31 @@ export const PerformancePip: React.FC<PerformancePipProps> = React.memo(({ level
                 {ramUsed.toFixed(1)}/{ramTotal.toFixed(0)}GB
               </span>
             </div>
+            {vramUsed !== null && vramTotal !== null ? (
+              <div className="pip-row">
+                <span className="metric-label">VRAM</span>
+                <span className={vramExceeded ? 'metric-value ping-spike' : 'metric-value'}>
+                  {(vramUsed / 1024).toFixed(1)}/{(vramTotal / 1024).toFixed(1)}GB
+                </span>
+              </div>
+            ) : null}
             {gpuPower ? (
               <div className="pip-row">
                 <span className="metric-label">PWR</span>
                 <span className="metric-value">{gpuPower.toFixed(0)}W</span>
               </div>
             ) : null}
+            {network?.measuring && network.samples > 0 ? (
+              <div className="pip-row">
+                <span className="metric-label">PING</span>
+                <span className={spiking ? 'metric-value ping-spike' : 'metric-value'}>
+                  {network.latency_ms === null ? '--' : `${network.latency_ms.toFixed(0)}ms`}
+                </span>
+                {network.jitter_ms !== null ? (
+                  <span className="metric-separator">±{network.jitter_ms.toFixed(0)}</span>
+                ) : null}
+              </div>
+            ) : null}
           </>
         ) : null}
       </div>
@@ -5,6 +5,7 @@ import {
   Bluetooth,
   Cable,
   Headphones,
+  Mic,
   Monitor,
   RotateCw,
   Speaker,
@@ -43,6 +44,7 @@ interface TDPConfig {
 type AudioDeviceType =
   | 'Speakers'
   | 'Headphones'
+  | 'Microphone'
   | 'HDMI'
   | 'DisplayPort'
   | 'USB'
@@ -307,6 +309,8 @@ export const QuickSettings: React.FC<QuickSettingsProps> = ({
     switch (deviceType) {
       case 'Headphones':
         return <Headphones size={20} />;
+      case 'Microphone':
+        return <Mic size={20} />;
       case 'HDMI':
         return <Cable size={20} />;
       case 'DisplayPort':
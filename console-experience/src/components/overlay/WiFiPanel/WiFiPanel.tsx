@@ -4,6 +4,7 @@ import { invoke } from '@tauri-apps/api/core';
 import { Lock, Wifi, WifiOff } from 'lucide-react';
 import React, { useCallback, useEffect, useState } from 'react';
 
+import { getErrorCode, getErrorMessage } from '@/domain/errors/balam-error';
 import { useToast } from '@/hooks/useToast';
 
 import ButtonHint from '../../ui/ButtonHint/ButtonHint';
@@ -20,7 +21,7 @@ interface WiFiPanelProps {
 interface WiFiNetwork {
   ssid: string;
   signal_strength: number;
-  security: 'Open' | 'WEP' | 'WPA' | 'WPA2' | 'WPA3' | 'Unknown';
+  security: 'Open' | 'WEP' | 'WPA' | 'WPA2' | 'WPA3' | 'WPA2Enterprise' | 'WPA3Enterprise' | 'Unknown';
   is_connected: boolean;
 }
 
@@ -184,9 +185,13 @@ export const WiFiPanel: React.FC<WiFiPanelProps> = ({
         const errorMsg = `Failed to connect to ${selectedNetwork.ssid}`;
         let hint = 'Check your password and try again';
 
-        const errorStr =
-          error instanceof Error ? error.message.toLowerCase() : String(error).toLowerCase();
-        if (
+        const code = getErrorCode(error);
+        const errorStr = getErrorMessage(error).toLowerCase();
+        if (code === 'WIFI_UNAVAILABLE') {
+          hint = 'Wi-Fi adapter not available. Check that Wi-Fi is enabled in Windows';
+        } else if (code === 'ACCESS_DENIED') {
+          hint = 'Permission denied. Run the app as administrator to manage Wi-Fi';
+        } else if (
           errorStr.includes('password') ||
           errorStr.includes('incorrect') ||
           errorStr.includes('invalid')
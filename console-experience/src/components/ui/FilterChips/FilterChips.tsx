@@ -18,6 +18,7 @@ export type FilterType =
   | 'epic'
   | 'xbox'
   | 'battlenet'
+  | 'itch'
   | 'manual';
 
 interface FilterChip {
@@ -40,6 +41,7 @@ const FILTER_CHIPS: FilterChip[] = [
   { id: 'epic', label: 'Epic' },
   { id: 'xbox', label: 'Xbox', icon: <Gamepad2 size={16} /> },
   { id: 'battlenet', label: 'Battle.net' },
+  { id: 'itch', label: 'itch.io' },
   { id: 'manual', label: 'Manual' },
 ];
 
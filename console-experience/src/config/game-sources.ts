@@ -68,6 +68,16 @@ export const GAME_SOURCES: Record<GameSource, SourceConfig> = {
     carouselTitle: 'Battle.net',
     scanPriority: 4,
   },
+  Itch: {
+    displayName: 'itch.io',
+    primaryColor: '#2b2b2b',
+    secondaryColor: '#3d3d3d',
+    accentColor: '#fa5c5c',
+    iconPath:
+      'M4,4h16l2,5c0,1.1-0.9,2-2,2s-2-0.9-2-2c0,1.1-0.9,2-2,2s-2-0.9-2-2c0,1.1-0.9,2-2,2s-2-0.9-2-2c0,1.1-0.9,2-2,2 s-2-0.9-2-2L4,4z M4,12.5V20h16v-7.5c-0.6,0.3-1.3,0.5-2,0.5H6C5.3,13,4.6,12.8,4,12.5z',
+    carouselTitle: 'itch.io',
+    scanPriority: 4,
+  },
   Manual: {
     displayName: 'Manual',
     primaryColor: '#3a3a3a',
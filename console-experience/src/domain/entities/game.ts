@@ -10,7 +10,25 @@
 /**
  * Game source platform
  */
-export type GameSource = 'Steam' | 'Epic' | 'Xbox' | 'BattleNet' | 'Manual';
+export type GameSource = 'Steam' | 'Epic' | 'Xbox' | 'BattleNet' | 'Itch' | 'Manual';
+
+/**
+ * Install/download state reported by the store
+ */
+export type InstallState =
+  | 'installed'
+  | 'update_required'
+  | 'updating'
+  | 'downloading'
+  | 'preallocating'
+  | 'validating'
+  | 'files_missing'
+  | 'uninstalled';
+
+/**
+ * Store client a game needs running before it starts (DRM)
+ */
+export type LauncherDependency = 'steam' | 'ea_app' | 'ubisoft_connect';
 
 /**
  * Game entity representing a playable game
@@ -26,6 +44,12 @@ export interface Game {
   path: string;
   /** Source platform */
   source: GameSource;
+  /** Install state (games mid-download/update are listed but not launchable) */
+  install_state?: InstallState;
+  /** Store client started before the game (null if none) */
+  launcher_dependency?: LauncherDependency | null;
+  /** The store has an update for the game (badge; see `start_update`) */
+  update_pending?: boolean;
   /** Cover image path (optional) */
   image: string | null;
   /** Hero/background image path (optional) */
@@ -49,3 +73,30 @@ export interface ActiveGame {
   /** Process ID of the running game */
   pid: number;
 }
+
+/**
+ * Library served from the last scan's cache (`get_games_cached`)
+ */
+export interface CachedLibrary {
+  games: Game[];
+  /** Unix seconds of the last completed scan (null: never scanned) */
+  scanned_at: number | null;
+  /** Seconds since that scan */
+  age_secs: number | null;
+  /** Never scanned or older than a day: worth offering a refresh */
+  stale: boolean;
+}
+
+/**
+ * Local artwork of a game, pushed as `game-artwork-ready` once the
+ * background workers have cached it
+ */
+export interface GameArtwork {
+  game_id: string;
+  /** Cover as shown in the library */
+  image: string | null;
+  hero_image: string | null;
+  logo: string | null;
+  /** Every cover thumbnail, smallest first */
+  cover_thumbnails: { width: number; path: string }[];
+}
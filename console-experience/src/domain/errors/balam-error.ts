@@ -0,0 +1,75 @@
+/**
+ * Domain: Balam Error
+ *
+ * TypeScript mirror of the Rust `BalamError` returned by the hardware
+ * commands (system, Wi-Fi, Bluetooth, display, performance).
+ *
+ * @module domain/errors/balam-error
+ */
+
+/**
+ * Broad class of an error (generic handling: retry, ask for admin, setup hint)
+ */
+export type ErrorCategory =
+  | 'unavailable'
+  | 'permission'
+  | 'invalid_input'
+  | 'not_found'
+  | 'timeout'
+  | 'busy'
+  | 'platform';
+
+/**
+ * Stable error codes to branch on
+ */
+export type BalamErrorCode =
+  | 'BLUETOOTH_UNAVAILABLE'
+  | 'BLUETOOTH_OFF'
+  | 'WIFI_UNAVAILABLE'
+  | 'NOT_SUPPORTED'
+  | 'ACCESS_DENIED'
+  | 'INVALID_ARGUMENT'
+  | 'NOT_FOUND'
+  | 'TIMEOUT'
+  | 'BUSY'
+  | 'PLATFORM_ERROR';
+
+/**
+ * Error payload rejected by `invoke` for typed commands
+ */
+export interface BalamError {
+  code: BalamErrorCode;
+  category: ErrorCategory;
+  /** English description (logs and fallback display) */
+  message: string;
+}
+
+/**
+ * Check whether an `invoke` rejection is a typed `BalamError`
+ */
+export function isBalamError(error: unknown): error is BalamError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    'code' in error &&
+    'category' in error &&
+    'message' in error
+  );
+}
+
+/**
+ * Get the message of any `invoke` rejection (typed error, Error or plain string)
+ */
+export function getErrorMessage(error: unknown): string {
+  if (isBalamError(error) || error instanceof Error) {
+    return error.message;
+  }
+  return String(error);
+}
+
+/**
+ * Get the error code, or `null` for commands that still reject with strings
+ */
+export function getErrorCode(error: unknown): BalamErrorCode | null {
+  return isBalamError(error) ? error.code : null;
+}
@@ -31,7 +31,11 @@ export type LaunchFailureReason =
     }
   | {
       type: 'NoMonitoring';
-    };
+    }
+  | { type: 'ClientNotRunning' }
+  | { type: 'ClientUpdating' }
+  | { type: 'NotSignedIn' }
+  | { type: 'OfflineMode' };
 
 /**
  * Complete error information for game launch failure
@@ -62,6 +66,14 @@ export function getFailureDescription(reason: LaunchFailureReason): string {
       return `Error del sistema: ${reason.details.error_message}`;
     case 'NoMonitoring':
       return 'No se pudo monitorear el estado del juego';
+    case 'ClientNotRunning':
+      return 'La tienda no está abierta';
+    case 'ClientUpdating':
+      return 'La tienda se está actualizando';
+    case 'NotSignedIn':
+      return 'No has iniciado sesión en la tienda';
+    case 'OfflineMode':
+      return 'La tienda está en modo sin conexión';
   }
 }
 
@@ -78,6 +90,13 @@ export function getErrorIcon(reason: LaunchFailureReason): string {
       return '❌';
     case 'NoMonitoring':
       return '⚠️';
+    case 'ClientNotRunning':
+    case 'NotSignedIn':
+      return '🔒';
+    case 'ClientUpdating':
+      return '🔄';
+    case 'OfflineMode':
+      return '📴';
   }
 }
 
@@ -94,5 +113,11 @@ export function getErrorSeverity(reason: LaunchFailureReason): 'error' | 'warnin
       return 'error'; // Error confirmado
     case 'NoMonitoring':
       return 'info'; // Informativo
+    case 'ClientUpdating':
+      return 'info'; // Se resuelve solo
+    case 'ClientNotRunning':
+    case 'NotSignedIn':
+    case 'OfflineMode':
+      return 'warning'; // El usuario puede resolverlo
   }
 }
@@ -12,6 +12,8 @@ export type { ActiveGame, Game, GameSource } from './entities';
 export type { SystemStatus } from './entities';
 
 // Errors
+export type { BalamError, BalamErrorCode, ErrorCategory } from './errors/balam-error';
+export { getErrorCode, getErrorMessage, isBalamError } from './errors/balam-error';
 export type { GameLaunchError, LaunchFailureReason } from './errors/game-launch-error';
 export { getErrorIcon, getErrorSeverity, getFailureDescription } from './errors/game-launch-error';
 
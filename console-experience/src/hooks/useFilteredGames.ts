@@ -28,6 +28,8 @@ export function useFilteredGames(games: Game[]) {
         return games.filter((g) => g.source === 'Xbox');
       case 'battlenet':
         return games.filter((g) => g.source === 'BattleNet');
+      case 'itch':
+        return games.filter((g) => g.source === 'Itch');
       case 'manual':
         return games.filter((g) => g.source === 'Manual');
       case 'all':
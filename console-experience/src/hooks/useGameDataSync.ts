@@ -1,6 +1,7 @@
 import { listen } from '@tauri-apps/api/event';
 import { useCallback, useEffect } from 'react';
 
+import type { GameArtwork } from '../domain/entities/game';
 import { useGameStore } from '../application/providers/StoreProvider';
 import { addPlayTime, initDatabase, toggleFavorite } from '../services/database';
 
@@ -9,10 +10,11 @@ import { addPlayTime, initDatabase, toggleFavorite } from '../services/database'
  * - Initializes database
  * - Loads games
  * - Listens for game-ended events to update playtime
+ * - Applies artwork cached in the background
  * - Handles favorite toggling
  */
 export function useGameDataSync() {
-  const { games, loadGames, clearActiveGame } = useGameStore();
+  const { games, loadGames, clearActiveGame, applyArtwork } = useGameStore();
 
   // Initialize database on mount
   useEffect(() => {
@@ -26,6 +28,17 @@ export function useGameDataSync() {
     void loadGames();
   }, [loadGames]);
 
+  // Covers, heroes and logos arrive per game after the library is shown
+  useEffect(() => {
+    const unlisten = listen<GameArtwork>('game-artwork-ready', (event) => {
+      applyArtwork(event.payload);
+    });
+
+    return () => {
+      void unlisten.then((fn) => fn());
+    };
+  }, [applyArtwork]);
+
   // Listen for game-ended events from backend
   useEffect(() => {
     const unlisten = listen<{ game_id: string; play_time_seconds: number }>(
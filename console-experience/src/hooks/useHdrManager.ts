@@ -1,6 +1,8 @@
 import { invoke } from '@tauri-apps/api/core';
 import { useCallback, useEffect, useState } from 'react';
 
+import { getErrorMessage } from '@/domain/errors/balam-error';
+
 export interface HdrCapabilities {
   supported: boolean;
   enabled: boolean;
@@ -35,7 +37,7 @@ export const useHdrManager = () => {
       setDisplays(displayList);
     } catch (err) {
       console.error('Failed to get displays:', err);
-      const errorMessage = err instanceof Error ? err.message : String(err);
+      const errorMessage = getErrorMessage(err);
       setError(errorMessage);
       setDisplays([]);
     } finally {
@@ -66,7 +68,7 @@ export const useHdrManager = () => {
         await refresh();
       } catch (err) {
         console.error('Failed to toggle HDR:', err);
-        const errorMessage = err instanceof Error ? err.message : String(err);
+        const errorMessage = getErrorMessage(err);
         setError(errorMessage);
         throw err; // Re-throw to allow component to handle
       } finally {
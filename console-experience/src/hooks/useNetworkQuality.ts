@@ -0,0 +1,74 @@
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { useEffect, useState } from 'react';
+
+/**
+ * Network Quality Interface
+ *
+ * Matches Rust NetworkQuality struct from backend.
+ */
+export interface NetworkQuality {
+  target: string;
+  latency_ms: number | null;
+  average_latency_ms: number | null;
+  jitter_ms: number | null;
+  packet_loss_percent: number;
+  samples: number;
+  /** False while no game is running */
+  measuring: boolean;
+}
+
+/** Payload of the `network-latency-spike` event */
+export interface LatencySpike {
+  target: string;
+  latency_ms: number;
+  threshold_ms: number;
+}
+
+/**
+ * Network Quality Hook
+ *
+ * Polls the in-game latency monitor and flags the latest spike
+ * (cleared once latency drops back under the threshold).
+ *
+ * @example
+ * ```tsx
+ * const { quality, spiking } = useNetworkQuality({ enabled: level >= 4 });
+ * ```
+ */
+export const useNetworkQuality = ({ interval = 1000, enabled = true } = {}) => {
+  const [quality, setQuality] = useState<NetworkQuality | null>(null);
+  const [spike, setSpike] = useState<LatencySpike | null>(null);
+
+  useEffect(() => {
+    if (!enabled) {
+      return;
+    }
+
+    const refresh = async () => {
+      try {
+        setQuality(await invoke<NetworkQuality>('get_network_quality'));
+      } catch {
+        setQuality(null);
+      }
+    };
+
+    void refresh();
+    const intervalId = setInterval(() => {
+      void refresh();
+    }, interval);
+    const unlisten = listen<LatencySpike>('network-latency-spike', (event) => {
+      setSpike(event.payload);
+    });
+
+    return () => {
+      clearInterval(intervalId);
+      void unlisten.then((fn) => fn());
+    };
+  }, [interval, enabled]);
+
+  const latency = quality?.latency_ms ?? null;
+  const spiking = spike !== null && latency !== null && latency >= spike.threshold_ms;
+
+  return { quality, spiking };
+};
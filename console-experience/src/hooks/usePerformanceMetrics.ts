@@ -1,6 +1,8 @@
 import { invoke } from '@tauri-apps/api/core';
 import { useEffect, useState } from 'react';
 
+import { getErrorMessage } from '@/domain/errors/balam-error';
+
 /**
  * Performance Metrics Interface
  *
@@ -14,7 +16,11 @@ export interface PerformanceMetrics {
   gpu_temp_c: number | null;
   cpu_temp_c: number | null;
   gpu_power_w: number | null;
+  vram_used_mb?: number | null;
+  vram_total_mb?: number | null;
   fps: FPSStats | null;
+  /** Share of the focused game's process */
+  game?: { gpu_dedicated_mb?: number | null } | null;
 }
 
 /**
@@ -79,7 +85,7 @@ export const usePerformanceMetrics = (
       setMetrics(data);
       setLoading(false);
     } catch (err) {
-      setError(err instanceof Error ? err.message : String(err));
+      setError(getErrorMessage(err));
       setLoading(false);
     }
   };
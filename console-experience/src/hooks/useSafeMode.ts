@@ -0,0 +1,29 @@
+import { invoke } from '@tauri-apps/api/core';
+import { useCallback, useEffect, useState } from 'react';
+
+/** Flags Balam was started with (matches Rust `LaunchOptions`). */
+interface LaunchOptions {
+  start_minimized: boolean;
+  start_in_library: boolean;
+  safe_mode: boolean;
+}
+
+/**
+ * Safe mode: set by the watchdog after a crash loop (`--safe-mode`).
+ * The app then shows the library only; `exitSafeMode` restarts Balam normally.
+ */
+export function useSafeMode() {
+  const [safeMode, setSafeMode] = useState(false);
+
+  useEffect(() => {
+    invoke<LaunchOptions>('get_launch_options')
+      .then((options) => setSafeMode(options.safe_mode))
+      .catch(() => setSafeMode(false));
+  }, []);
+
+  const exitSafeMode = useCallback(() => {
+    void invoke('exit_safe_mode');
+  }, []);
+
+  return { safeMode, exitSafeMode };
+}
@@ -22,6 +22,22 @@ export interface ScanProgress {
   total: number;
 }
 
+/**
+ * One scanner's outcome (`scan-source-finished`, as soon as it is known)
+ */
+export interface ScannerStatus {
+  /** Scanner name ("Steam", "Xbox", "Registry", ...) */
+  scanner: string;
+  source: string;
+  status: 'found' | 'failed' | 'timed_out';
+  /** Set when status is 'found' */
+  game_count?: number;
+  /** Set when status is 'failed' */
+  error?: string;
+  /** Time since the scan started */
+  duration_ms: number;
+}
+
 /**
  * Scan complete event payload
  */
@@ -30,6 +46,8 @@ export interface ScanComplete {
   count: number;
   /** Duration in milliseconds */
   duration_ms: number;
+  /** Every scanner's outcome, in priority order */
+  scanners: ScannerStatus[];
 }
 
 /**
@@ -51,12 +69,19 @@ export function useScanProgress() {
   const [progress, setProgress] = useState<ScanProgress | null>(null);
   const [gamesFound, setGamesFound] = useState<number | null>(null);
   const [duration, setDuration] = useState<number | null>(null);
+  const [sources, setSources] = useState<ScannerStatus[]>([]);
 
   useEffect(() => {
     // Listen for scan progress events
     const unlistenProgress = listen<ScanProgress>('scan-progress', (event) => {
       setIsScanning(true);
       setProgress(event.payload);
+      if (event.payload.current === 0) setSources([]);
+    });
+
+    // Listen for each scanner finishing (a slow store doesn't hold back the others)
+    const unlistenSource = listen<ScannerStatus>('scan-source-finished', (event) => {
+      setSources((current) => [...current, event.payload]);
     });
 
     // Listen for scan complete events
@@ -65,6 +90,7 @@ export function useScanProgress() {
       setProgress(null);
       setGamesFound(event.payload.count);
       setDuration(event.payload.duration_ms);
+      setSources(event.payload.scanners);
 
       // Clear stats after 5 seconds
       setTimeout(() => {
@@ -76,6 +102,7 @@ export function useScanProgress() {
     return () => {
       void unlistenProgress.then((fn) => fn());
       void unlistenComplete.then((fn) => fn());
+      void unlistenSource.then((fn) => fn());
     };
   }, []);
 
@@ -87,6 +114,7 @@ export function useScanProgress() {
     setProgress(null);
     setGamesFound(null);
     setDuration(null);
+    setSources([]);
   }, []);
 
   return {
@@ -98,6 +126,8 @@ export function useScanProgress() {
     gamesFound,
     /** Scan duration in milliseconds (null if scan not complete) */
     duration,
+    /** Scanners finished so far (all of them once the scan completes) */
+    sources,
     /** Reset scan state manually */
     reset,
   };
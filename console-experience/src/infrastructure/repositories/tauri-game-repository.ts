@@ -9,7 +9,7 @@
 
 import { invoke } from '@tauri-apps/api/core';
 
-import type { ActiveGame, Game } from '../../domain/entities/game';
+import type { ActiveGame, CachedLibrary, Game } from '../../domain/entities/game';
 import type { GameRepository } from '../../domain/repositories/game-repository';
 
 /**
@@ -25,6 +25,15 @@ export class TauriGameRepository implements GameRepository {
     return invoke<Game[]>('get_games');
   }
 
+  /**
+   * Library from the last scan's cache, with its age (never scans)
+   *
+   * @returns Promise resolving to the cached games and staleness marker
+   */
+  async getCached(): Promise<CachedLibrary> {
+    return invoke<CachedLibrary>('get_games_cached');
+  }
+
   /**
    * Scan all games asynchronously with progress events
    *